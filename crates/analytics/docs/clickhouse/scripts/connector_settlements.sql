@@ -0,0 +1,27 @@
+-- Transactions of the settlement files ingested from the connectors, along with the fees charged
+-- by the connectors on them. The transactions are matched against the payment attempts at the
+-- time of ingestion, and reingesting a settlement file replaces its earlier rows.
+CREATE TABLE connector_settlements (
+    `settlement_id` String,
+    `merchant_id` LowCardinality(String),
+    `organization_id` String,
+    `profile_id` String,
+    `payment_id` String,
+    `attempt_id` String,
+    `connector` LowCardinality(String),
+    `connector_transaction_id` String,
+    `region` LowCardinality(Nullable(String)),
+    `currency` LowCardinality(String),
+    `settled_amount` UInt64,
+    `interchange_fee` Nullable(UInt64),
+    `scheme_fee` Nullable(UInt64),
+    `markup_fee` Nullable(UInt64),
+    `total_fee` UInt64,
+    `settled_at` DateTime CODEC(T64, LZ4),
+    `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4),
+    INDEX connectorIndex connector TYPE bloom_filter GRANULARITY 1,
+    INDEX regionIndex region TYPE bloom_filter GRANULARITY 1,
+    INDEX currencyIndex currency TYPE bloom_filter GRANULARITY 1
+) ENGINE = ReplacingMergeTree(inserted_at) PARTITION BY toStartOfDay(settled_at)
+ORDER BY
+    (settled_at, merchant_id, settlement_id, attempt_id) TTL settled_at + toIntervalMonth(18) SETTINGS index_granularity = 8192;
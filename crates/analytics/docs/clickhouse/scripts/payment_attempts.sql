@@ -43,6 +43,9 @@ CREATE TABLE payment_attempt_queue (
     `organization_id` String,
     `profile_id` String,
     `card_network` Nullable(String),
+    `card_issuer` Nullable(String),
+    `card_bin` Nullable(String),
+    `overcaptured_amount` Nullable(UInt64),
     `sign_flag` Int8
 ) ENGINE = Kafka SETTINGS kafka_broker_list = 'kafka0:29092',
 kafka_topic_list = 'hyperswitch-payment-attempt-events',
@@ -96,6 +99,9 @@ CREATE TABLE payment_attempts (
     `organization_id` String,
     `profile_id` String,
     `card_network` Nullable(String),
+    `card_issuer` Nullable(String),
+    `card_bin` Nullable(String),
+    `overcaptured_amount` Nullable(UInt64),
     `sign_flag` Int8,
     INDEX connectorIndex connector TYPE bloom_filter GRANULARITY 1,
     INDEX paymentMethodIndex payment_method TYPE bloom_filter GRANULARITY 1,
@@ -152,6 +158,9 @@ CREATE MATERIALIZED VIEW payment_attempt_mv TO payment_attempts (
     `organization_id` String,
     `profile_id` String,
     `card_network` Nullable(String),
+    `card_issuer` Nullable(String),
+    `card_bin` Nullable(String),
+    `overcaptured_amount` Nullable(UInt64),
     `sign_flag` Int8
 ) AS
 SELECT
@@ -200,8 +209,35 @@ SELECT
     organization_id,
     profile_id,
     card_network,
+    card_issuer,
+    card_bin,
+    overcaptured_amount,
     sign_flag
 FROM
     payment_attempt_queue
 WHERE
     length(_error) = 0;
+
+CREATE TABLE payment_attempt_captures (
+    `merchant_id` LowCardinality(String),
+    `attempt_id` String,
+    `captured_at` SimpleAggregateFunction(min, DateTime64(3))
+) ENGINE = AggregatingMergeTree
+ORDER BY
+    (merchant_id, attempt_id) SETTINGS index_granularity = 8192;
+
+CREATE MATERIALIZED VIEW payment_attempt_captures_mv TO payment_attempt_captures (
+    `merchant_id` String,
+    `attempt_id` String,
+    `captured_at` DateTime64(3)
+) AS
+SELECT
+    merchant_id,
+    attempt_id,
+    modified_at AS captured_at
+FROM
+    payment_attempt_queue
+WHERE
+    length(_error) = 0
+    AND sign_flag = 1
+    AND status IN ('charged', 'partial_charged');
@@ -16,7 +16,8 @@ CREATE TABLE sdk_events_queue (
     `value` Nullable(String),
     `component` LowCardinality(Nullable(String)),
     `payment_method` LowCardinality(Nullable(String)),
-    `payment_experience` LowCardinality(Nullable(String))
+    `payment_experience` LowCardinality(Nullable(String)),
+    `locale` LowCardinality(Nullable(String))
 ) ENGINE = Kafka SETTINGS
     kafka_broker_list = 'kafka0:29092', 
     kafka_topic_list = 'hyper-sdk-logs', 
@@ -40,6 +41,7 @@ CREATE TABLE sdk_events (
     `component` LowCardinality(Nullable(String)),
     `payment_method` LowCardinality(Nullable(String)),
     `payment_experience` LowCardinality(Nullable(String)) DEFAULT '',
+    `locale` LowCardinality(Nullable(String)),
     `created_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `latency` Nullable(UInt32) DEFAULT 0,
@@ -82,6 +84,7 @@ CREATE MATERIALIZED VIEW sdk_events_mv TO sdk_events (
     `component` LowCardinality(Nullable(String)),
     `payment_method` LowCardinality(Nullable(String)),
     `payment_experience` LowCardinality(Nullable(String)),
+    `locale` LowCardinality(Nullable(String)),
     `created_at` DateTime64(3),
     `created_at_precise` DateTime64(3)
 ) AS 
@@ -103,6 +106,7 @@ SELECT
     component,
     payment_method,
     payment_experience,
+    locale,
     toDateTime64(timestamp, 3) AS created_at,
     toDateTime64(timestamp, 3) AS created_at_precise 
 FROM 
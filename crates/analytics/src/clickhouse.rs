@@ -20,7 +20,10 @@ use super::{
         distribution::RefundDistributionRow, filters::RefundFilterRow, metrics::RefundMetricRow,
     },
     sdk_events::{filters::SdkEventFilter, metrics::SdkEventMetricRow},
-    types::{AnalyticsCollection, AnalyticsDataSource, LoadRow, QueryExecutionError},
+    types::{
+        AnalyticsCollection, AnalyticsDataSource, AnalyticsJoinScope, LoadRow, QueryExecutionError,
+        SdkCheckoutScope,
+    },
 };
 use crate::{
     api_event::{
@@ -30,14 +33,249 @@ use crate::{
     },
     auth_events::filters::AuthEventFilterRow,
     connector_events::events::ConnectorEventsResult,
+    connector_fees::{filters::ConnectorFeeFilterRow, metrics::ConnectorFeeMetricRow},
     disputes::{filters::DisputeFilterRow, metrics::DisputeMetricRow},
+    enums::AuthInfo,
     outgoing_webhook_event::events::OutgoingWebhookLogsResult,
+    payouts::{filters::PayoutFilterRow, metrics::PayoutMetricRow},
     sdk_events::events::SdkEventsResult,
     types::TableEngine,
 };
 
 pub type ClickhouseResult<T> = error_stack::Result<T, ClickhouseError>;
 
+/// The captured payment attempts, each joined with the successful refunds made against it. The
+/// time taken to refund a payment is measured from its capture until its earliest successful
+/// refund, and the payments without any successful refunds have a `refund_count` of zero. The
+/// capture time of an attempt is the modification time of its first version with a captured
+/// status, recorded in `payment_attempt_captures`, and falls back to its last modification time
+/// for the attempts captured before that table was populated. The attempts are bounded by the
+/// scope and the time range, and the captures and the refunds, which are made after the attempts
+/// are created, by the scope and the start of the time range, before they are joined.
+fn get_refund_payment_attempts_query(scope: &AnalyticsJoinScope) -> String {
+    let auth_filter = get_auth_filter(&scope.auth);
+    let start_time = scope.time_range.start_time;
+    let attempts_time_filter = get_time_range_filter(start_time, scope.time_range.end_time);
+    let captures_time_filter = get_start_time_filter("captured_at", start_time);
+    let captures_filter = get_merchant_filter(&scope.auth).map_or_else(
+        || captures_time_filter.clone(),
+        |merchant_filter| format!("{merchant_filter} AND {captures_time_filter}"),
+    );
+    let refunds_time_filter = get_start_time_filter("created_at", start_time);
+
+    format!(
+        "(SELECT \
+        pa.merchant_id AS merchant_id, \
+        pa.organization_id AS organization_id, \
+        pa.profile_id AS profile_id, \
+        pa.connector AS connector, \
+        pa.currency AS currency, \
+        pa.payment_method AS payment_method, \
+        pa.payment_method_type AS payment_method_type, \
+        ifNull(pa.amount_to_capture, pa.amount) AS captured_amount, \
+        pa.created_at AS created_at, \
+        if(c.attempt_id = '', pa.modified_at, c.captured_at) AS captured_at, \
+        r.refunded_amount AS refunded_amount, \
+        r.refund_count AS refund_count, \
+        r.refunded_at AS refunded_at \
+        FROM (SELECT * FROM payment_attempts FINAL \
+            WHERE {auth_filter} AND {attempts_time_filter} \
+            AND status IN ('charged', 'partial_charged')) AS pa \
+        LEFT JOIN (SELECT merchant_id, attempt_id, \
+            min(captured_at) AS captured_at \
+            FROM payment_attempt_captures \
+            WHERE {captures_filter} \
+            GROUP BY merchant_id, attempt_id) AS c \
+        ON pa.merchant_id = c.merchant_id AND pa.attempt_id = c.attempt_id \
+        LEFT JOIN (SELECT merchant_id, attempt_id, \
+            sum(refund_amount) AS refunded_amount, \
+            count(*) AS refund_count, \
+            min(modified_at) AS refunded_at \
+            FROM refunds FINAL \
+            WHERE refund_status = 'success' AND {auth_filter} AND {refunds_time_filter} \
+            GROUP BY merchant_id, attempt_id) AS r \
+        ON pa.merchant_id = r.merchant_id AND pa.attempt_id = r.attempt_id)"
+    )
+}
+
+/// The settled transactions of the connectors, each joined with the payment attempt it settles,
+/// for the payment method of the transaction. The transactions are bucketed by the time at which
+/// their payments were created, so that the fees line up with the processed volume of the period.
+/// The payment attempts are bounded by the scope and the time range, and the transactions, which
+/// are settled after their payments are created, by the scope and the start of the time range,
+/// before they are joined.
+fn get_connector_settlements_query(scope: &AnalyticsJoinScope) -> String {
+    let auth_filter = get_auth_filter(&scope.auth);
+    let start_time = scope.time_range.start_time;
+    let settlements_time_filter = get_start_time_filter("settled_at", start_time);
+    let attempts_time_filter = get_time_range_filter(start_time, scope.time_range.end_time);
+
+    format!(
+        "(SELECT \
+        s.merchant_id AS merchant_id, \
+        s.organization_id AS organization_id, \
+        s.profile_id AS profile_id, \
+        s.connector AS connector, \
+        s.region AS region, \
+        s.currency AS currency, \
+        pa.payment_method AS payment_method, \
+        pa.payment_method_type AS payment_method_type, \
+        s.settled_amount AS settled_amount, \
+        s.interchange_fee AS interchange_fee, \
+        s.scheme_fee AS scheme_fee, \
+        s.markup_fee AS markup_fee, \
+        s.total_fee AS total_fee, \
+        pa.created_at AS created_at \
+        FROM (SELECT * FROM connector_settlements FINAL \
+            WHERE {auth_filter} AND {settlements_time_filter}) AS s \
+        INNER JOIN (SELECT * FROM payment_attempts FINAL \
+            WHERE {auth_filter} AND {attempts_time_filter}) AS pa \
+        ON s.merchant_id = pa.merchant_id AND s.attempt_id = pa.attempt_id)"
+    )
+}
+
+/// The payments are created before the SDK is loaded for them, so the payments joined with the
+/// checkouts of a time range are looked up from this long before its start
+const SDK_CHECKOUT_PAYMENTS_LOOKBACK: time::Duration = time::Duration::days(1);
+
+/// The checkouts of the SDK, one per payment, each joined with the outcome of its payment. The
+/// checkouts record the stages of the payment sheet reached by the payer, so that the checkouts
+/// which were not converted show where the payers dropped off. The SDK events are identified by
+/// the publishable key of the merchant and the payments by its identifier, and both sides of the
+/// join are bounded by the merchant and the time range before they are joined, so that neither
+/// the payments of other merchants nor the whole tables are read.
+fn get_sdk_checkouts_query(scope: &SdkCheckoutScope) -> String {
+    let merchant_id = scope.merchant_id.get_string_repr();
+    let publishable_key = &scope.publishable_key;
+    let start_time = scope.time_range.start_time;
+    let checkouts_time_filter = get_time_range_filter(start_time, scope.time_range.end_time);
+    let payments_time_filter = get_time_range_filter(
+        start_time.saturating_sub(SDK_CHECKOUT_PAYMENTS_LOOKBACK),
+        scope.time_range.end_time,
+    );
+
+    format!(
+        "(SELECT \
+        c.merchant_id AS merchant_id, \
+        c.payment_id AS payment_id, \
+        c.payment_method AS payment_method, \
+        c.platform AS platform, \
+        c.browser_name AS browser_name, \
+        c.source AS source, \
+        c.component AS component, \
+        c.payment_experience AS payment_experience, \
+        c.locale AS locale, \
+        c.created_at AS created_at, \
+        c.rendered AS rendered, \
+        c.payment_method_selected AS payment_method_selected, \
+        c.confirmed AS confirmed, \
+        pi.status IN ('succeeded', 'requires_capture', 'partially_captured', \
+            'partially_captured_and_capturable') AS converted \
+        FROM (SELECT merchant_id, payment_id, \
+            argMax(payment_method, created_at) AS payment_method, \
+            any(platform) AS platform, \
+            any(browser_name) AS browser_name, \
+            any(source) AS source, \
+            any(component) AS component, \
+            argMax(payment_experience, created_at) AS payment_experience, \
+            any(locale) AS locale, \
+            min(created_at) AS created_at, \
+            countIf(event_name = 'APP_RENDERED') > 0 AS rendered, \
+            countIf(event_name = 'PAYMENT_METHOD_CHANGED') > 0 AS payment_method_selected, \
+            countIf(event_name = 'PAYMENT_ATTEMPT') > 0 AS confirmed \
+            FROM sdk_events \
+            WHERE merchant_id = '{publishable_key}' AND payment_id IS NOT NULL \
+            AND {checkouts_time_filter} \
+            GROUP BY merchant_id, payment_id) AS c \
+        LEFT JOIN (SELECT payment_id, status FROM payment_intents FINAL \
+            WHERE merchant_id = '{merchant_id}' AND {payments_time_filter}) AS pi \
+        ON c.payment_id = pi.payment_id)"
+    )
+}
+
+fn quote_value(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+}
+
+fn get_in_filter<'a>(column: &str, values: impl Iterator<Item = &'a str>) -> String {
+    let values = values.map(quote_value).collect::<Vec<_>>();
+    if values.is_empty() {
+        // No rows match an empty list of values
+        "0".to_string()
+    } else {
+        format!("{column} IN ({})", values.join(", "))
+    }
+}
+
+/// The filter on the merchants of the authentication, for the tables which are not bound by the
+/// organization and the profiles. The organization level authentication is not bound by any
+/// merchant.
+fn get_merchant_filter(auth: &AuthInfo) -> Option<String> {
+    match auth {
+        AuthInfo::OrgLevel { .. } => None,
+        AuthInfo::MerchantLevel { merchant_ids, .. } => Some(get_in_filter(
+            "merchant_id",
+            merchant_ids
+                .iter()
+                .map(|merchant_id| merchant_id.get_string_repr()),
+        )),
+        AuthInfo::ProfileLevel { merchant_id, .. } => Some(format!(
+            "merchant_id = {}",
+            quote_value(merchant_id.get_string_repr())
+        )),
+    }
+}
+
+/// The filter on the organization, the merchants and the profiles of the authentication
+fn get_auth_filter(auth: &AuthInfo) -> String {
+    match auth {
+        AuthInfo::OrgLevel { org_id } => {
+            format!(
+                "organization_id = {}",
+                quote_value(org_id.get_string_repr())
+            )
+        }
+        AuthInfo::MerchantLevel { org_id, .. } => format!(
+            "organization_id = {} AND {}",
+            quote_value(org_id.get_string_repr()),
+            get_merchant_filter(auth).unwrap_or_default()
+        ),
+        AuthInfo::ProfileLevel {
+            org_id,
+            profile_ids,
+            ..
+        } => format!(
+            "organization_id = {} AND {} AND {}",
+            quote_value(org_id.get_string_repr()),
+            get_merchant_filter(auth).unwrap_or_default(),
+            get_in_filter(
+                "profile_id",
+                profile_ids
+                    .iter()
+                    .map(|profile_id| profile_id.get_string_repr()),
+            )
+        ),
+    }
+}
+
+fn get_start_time_filter(column: &str, start_time: PrimitiveDateTime) -> String {
+    format!("{column} >= '{}'", start_time.assume_utc().unix_timestamp())
+}
+
+fn get_time_range_filter(
+    start_time: PrimitiveDateTime,
+    end_time: Option<PrimitiveDateTime>,
+) -> String {
+    let start_filter = get_start_time_filter("created_at", start_time);
+    match end_time {
+        Some(end_time) => format!(
+            "{start_filter} AND created_at <= '{}'",
+            end_time.assume_utc().unix_timestamp()
+        ),
+        None => start_filter,
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct ClickhouseClient {
     pub config: Arc<ClickhouseConfig>,
@@ -130,7 +368,7 @@ impl AnalyticsDataSource for ClickhouseClient {
             .change_context(QueryExecutionError::RowExtractionFailure)
     }
 
-    fn get_table_engine(table: AnalyticsCollection) -> TableEngine {
+    fn get_table_engine(table: &AnalyticsCollection) -> TableEngine {
         match table {
             AnalyticsCollection::Payment
             | AnalyticsCollection::PaymentSessionized
@@ -140,9 +378,8 @@ impl AnalyticsDataSource for ClickhouseClient {
             | AnalyticsCollection::PaymentIntent
             | AnalyticsCollection::PaymentIntentSessionized
             | AnalyticsCollection::Authentications
-            | AnalyticsCollection::Dispute => {
-                TableEngine::CollapsingMergeTree { sign: "sign_flag" }
-            }
+            | AnalyticsCollection::Dispute
+            | AnalyticsCollection::Payout => TableEngine::CollapsingMergeTree { sign: "sign_flag" },
             AnalyticsCollection::DisputeSessionized => {
                 TableEngine::CollapsingMergeTree { sign: "sign_flag" }
             }
@@ -152,7 +389,10 @@ impl AnalyticsDataSource for ClickhouseClient {
             | AnalyticsCollection::ConnectorEvents
             | AnalyticsCollection::ApiEventsAnalytics
             | AnalyticsCollection::OutgoingWebhookEvent
-            | AnalyticsCollection::ActivePaymentsAnalytics => TableEngine::BasicTree,
+            | AnalyticsCollection::ActivePaymentsAnalytics
+            | AnalyticsCollection::RefundPaymentAttempt(_)
+            | AnalyticsCollection::ConnectorSettlement(_)
+            | AnalyticsCollection::SdkCheckout(_) => TableEngine::BasicTree,
         }
     }
 }
@@ -193,6 +433,10 @@ impl super::outgoing_webhook_event::events::OutgoingWebhookLogsFilterAnalytics
 }
 impl super::disputes::filters::DisputeFilterAnalytics for ClickhouseClient {}
 impl super::disputes::metrics::DisputeMetricAnalytics for ClickhouseClient {}
+impl super::payouts::filters::PayoutFilterAnalytics for ClickhouseClient {}
+impl super::payouts::metrics::PayoutMetricAnalytics for ClickhouseClient {}
+impl super::connector_fees::filters::ConnectorFeeFilterAnalytics for ClickhouseClient {}
+impl super::connector_fees::metrics::ConnectorFeeMetricAnalytics for ClickhouseClient {}
 
 #[derive(Debug, serde::Serialize)]
 struct CkhQuery {
@@ -355,6 +599,46 @@ impl TryInto<DisputeFilterRow> for serde_json::Value {
     }
 }
 
+impl TryInto<PayoutMetricRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<PayoutMetricRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse PayoutMetricRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<PayoutFilterRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<PayoutFilterRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse PayoutFilterRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<ConnectorFeeMetricRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<ConnectorFeeMetricRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse ConnectorFeeMetricRow in clickhouse results",
+        ))
+    }
+}
+
+impl TryInto<ConnectorFeeFilterRow> for serde_json::Value {
+    type Error = Report<ParsingError>;
+
+    fn try_into(self) -> Result<ConnectorFeeFilterRow, Self::Error> {
+        serde_json::from_value(self).change_context(ParsingError::StructParseFailure(
+            "Failed to parse ConnectorFeeFilterRow in clickhouse results",
+        ))
+    }
+}
+
 impl TryInto<ApiEventMetricRow> for serde_json::Value {
     type Error = Report<ParsingError>;
 
@@ -458,6 +742,7 @@ impl ToSql<ClickhouseClient> for AnalyticsCollection {
             Self::PaymentSessionized => Ok("sessionizer_payment_attempts".to_string()),
             Self::Refund => Ok("refunds".to_string()),
             Self::RefundSessionized => Ok("sessionizer_refunds".to_string()),
+            Self::RefundPaymentAttempt(scope) => Ok(get_refund_payment_attempts_query(scope)),
             Self::FraudCheck => Ok("fraud_check".to_string()),
             Self::SdkEvents => Ok("sdk_events_audit".to_string()),
             Self::SdkEventsAnalytics => Ok("sdk_events".to_string()),
@@ -471,6 +756,9 @@ impl ToSql<ClickhouseClient> for AnalyticsCollection {
             Self::DisputeSessionized => Ok("sessionizer_dispute".to_string()),
             Self::ActivePaymentsAnalytics => Ok("active_payments".to_string()),
             Self::Authentications => Ok("authentications".to_string()),
+            Self::Payout => Ok("payout".to_string()),
+            Self::ConnectorSettlement(scope) => Ok(get_connector_settlements_query(scope)),
+            Self::SdkCheckout(scope) => Ok(get_sdk_checkouts_query(scope)),
         }
     }
 }
@@ -0,0 +1,9 @@
+pub mod accumulators;
+mod core;
+pub mod filters;
+pub mod metrics;
+pub mod types;
+pub use accumulators::{ConnectorFeeMetricAccumulator, ConnectorFeeMetricsAccumulator};
+
+pub trait ConnectorFeeAnalytics: metrics::ConnectorFeeMetricAnalytics {}
+pub use self::core::{get_filters, get_metrics};
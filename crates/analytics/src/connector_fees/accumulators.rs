@@ -0,0 +1,114 @@
+use api_models::analytics::connector_fees::ConnectorFeeMetricsBucketValue;
+use bigdecimal::ToPrimitive;
+
+use super::metrics::ConnectorFeeMetricRow;
+#[derive(Debug, Default)]
+pub struct ConnectorFeeMetricsAccumulator {
+    pub connector_fees: FeeBreakdownAccumulator,
+    pub effective_cost: EffectiveCostAccumulator,
+}
+#[derive(Debug, Default)]
+pub struct FeeBreakdownAccumulator {
+    pub interchange_fee: Option<i64>,
+    pub scheme_fee: Option<i64>,
+    pub markup_fee: Option<i64>,
+    pub total_fee: Option<i64>,
+}
+#[derive(Debug, Default)]
+pub struct EffectiveCostAccumulator {
+    pub total_fee: Option<i64>,
+    pub settled_amount: Option<i64>,
+    pub count: Option<i64>,
+}
+
+pub trait ConnectorFeeMetricAccumulator {
+    type MetricOutput;
+
+    fn add_metrics_bucket(&mut self, metrics: &ConnectorFeeMetricRow);
+
+    fn collect(self) -> Self::MetricOutput;
+}
+
+fn add_amount(total: Option<i64>, amount: Option<&bigdecimal::BigDecimal>) -> Option<i64> {
+    match (total, amount.and_then(ToPrimitive::to_i64)) {
+        (None, None) => None,
+        (None, i @ Some(_)) | (i @ Some(_), None) => i,
+        (Some(a), Some(b)) => Some(a + b),
+    }
+}
+
+impl ConnectorFeeMetricAccumulator for FeeBreakdownAccumulator {
+    type MetricOutput = (Option<u64>, Option<u64>, Option<u64>, Option<u64>);
+    #[inline]
+    fn add_metrics_bucket(&mut self, metrics: &ConnectorFeeMetricRow) {
+        self.interchange_fee = add_amount(self.interchange_fee, metrics.interchange_fee.as_ref());
+        self.scheme_fee = add_amount(self.scheme_fee, metrics.scheme_fee.as_ref());
+        self.markup_fee = add_amount(self.markup_fee, metrics.markup_fee.as_ref());
+        self.total_fee = add_amount(self.total_fee, metrics.total_fee.as_ref());
+    }
+    #[inline]
+    fn collect(self) -> Self::MetricOutput {
+        (
+            self.interchange_fee.and_then(|i| u64::try_from(i).ok()),
+            self.scheme_fee.and_then(|i| u64::try_from(i).ok()),
+            self.markup_fee.and_then(|i| u64::try_from(i).ok()),
+            self.total_fee.and_then(|i| u64::try_from(i).ok()),
+        )
+    }
+}
+
+impl ConnectorFeeMetricAccumulator for EffectiveCostAccumulator {
+    type MetricOutput = (
+        Option<u64>,
+        Option<u64>,
+        Option<u64>,
+        Option<f64>,
+        Option<f64>,
+    );
+
+    fn add_metrics_bucket(&mut self, metrics: &ConnectorFeeMetricRow) {
+        self.total_fee = add_amount(self.total_fee, metrics.total_fee.as_ref());
+        self.settled_amount = add_amount(self.settled_amount, metrics.settled_amount.as_ref());
+        self.count = match (self.count, metrics.count) {
+            (None, None) => None,
+            (None, i @ Some(_)) | (i @ Some(_), None) => i,
+            (Some(a), Some(b)) => Some(a + b),
+        };
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        let total_fee = self.total_fee.and_then(|fee| fee.to_f64());
+        let cost_per_transaction = total_fee
+            .zip(self.count.and_then(|count| count.to_f64()))
+            .and_then(|(fee, count)| (count > 0.0).then_some(fee / count));
+        let effective_fee_rate = total_fee
+            .zip(self.settled_amount.and_then(|amount| amount.to_f64()))
+            .and_then(|(fee, amount)| (amount > 0.0).then_some(fee * 100.0 / amount));
+        (
+            self.total_fee.and_then(|i| u64::try_from(i).ok()),
+            self.settled_amount.and_then(|i| u64::try_from(i).ok()),
+            self.count.and_then(|i| u64::try_from(i).ok()),
+            cost_per_transaction,
+            effective_fee_rate,
+        )
+    }
+}
+
+impl ConnectorFeeMetricsAccumulator {
+    pub fn collect(self) -> ConnectorFeeMetricsBucketValue {
+        let (interchange_fee, scheme_fee, markup_fee, breakdown_total_fee) =
+            self.connector_fees.collect();
+        let (total_fee, settled_amount, settled_count, cost_per_transaction, effective_fee_rate) =
+            self.effective_cost.collect();
+        ConnectorFeeMetricsBucketValue {
+            interchange_fee,
+            scheme_fee,
+            markup_fee,
+            total_fee: breakdown_total_fee.or(total_fee),
+            settled_amount,
+            settled_count,
+            cost_per_transaction,
+            effective_fee_rate,
+        }
+    }
+}
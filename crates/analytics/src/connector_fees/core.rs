@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+
+use api_models::analytics::{
+    connector_fees::{
+        ConnectorFeeDimensions, ConnectorFeeMetrics, ConnectorFeeMetricsBucketIdentifier,
+        ConnectorFeeMetricsBucketResponse,
+    },
+    ConnectorFeeFilterValue, ConnectorFeeFiltersResponse, ConnectorFeesAnalyticsMetadata,
+    ConnectorFeesMetricsResponse, GetConnectorFeeFilterRequest, GetConnectorFeeMetricRequest,
+};
+use bigdecimal::ToPrimitive;
+use error_stack::ResultExt;
+use router_env::{
+    logger,
+    tracing::{self, Instrument},
+};
+
+use super::{
+    filters::{get_connector_fee_filter_for_dimension, ConnectorFeeFilterRow},
+    ConnectorFeeMetricsAccumulator,
+};
+use crate::{
+    connector_fees::ConnectorFeeMetricAccumulator,
+    enums::AuthInfo,
+    errors::{AnalyticsError, AnalyticsResult},
+    metrics, AnalyticsProvider,
+};
+
+pub async fn get_metrics(
+    pool: &AnalyticsProvider,
+    auth: &AuthInfo,
+    req: GetConnectorFeeMetricRequest,
+) -> AnalyticsResult<ConnectorFeesMetricsResponse<ConnectorFeeMetricsBucketResponse>> {
+    let mut metrics_accumulator: HashMap<
+        ConnectorFeeMetricsBucketIdentifier,
+        ConnectorFeeMetricsAccumulator,
+    > = HashMap::new();
+    let mut set = tokio::task::JoinSet::new();
+    for metric_type in req.metrics.iter().cloned() {
+        let req = req.clone();
+        let pool = pool.clone();
+        let task_span = tracing::debug_span!(
+            "analytics_connector_fee_query",
+            connector_fee_metric = metric_type.as_ref()
+        );
+        // Currently JoinSet works with only static lifetime references even if the task pool does not outlive the given reference
+        // We can optimize away this clone once that is fixed
+        let auth_scoped = auth.to_owned();
+        set.spawn(
+            async move {
+                let data = pool
+                    .get_connector_fee_metrics(
+                        &metric_type,
+                        &req.group_by_names.clone(),
+                        &auth_scoped,
+                        &req.filters,
+                        req.time_series.map(|t| t.granularity),
+                        &req.time_range,
+                    )
+                    .await
+                    .change_context(AnalyticsError::UnknownError);
+                (metric_type, data)
+            }
+            .instrument(task_span),
+        );
+    }
+
+    while let Some((metric, data)) = set
+        .join_next()
+        .await
+        .transpose()
+        .change_context(AnalyticsError::UnknownError)?
+    {
+        let data = data?;
+        let attributes = router_env::metric_attributes!(
+            ("metric_type", metric.to_string()),
+            ("source", pool.to_string()),
+        );
+
+        let value = u64::try_from(data.len());
+        if let Ok(val) = value {
+            metrics::BUCKETS_FETCHED.record(val, attributes);
+            logger::debug!("Attributes: {:?}, Buckets fetched: {}", attributes, val);
+        }
+
+        for (id, value) in data {
+            logger::debug!(bucket_id=?id, bucket_value=?value, "Bucket row for metric {metric}");
+            let metrics_builder = metrics_accumulator.entry(id).or_default();
+            match metric {
+                ConnectorFeeMetrics::ConnectorFees => {
+                    metrics_builder.connector_fees.add_metrics_bucket(&value)
+                }
+                ConnectorFeeMetrics::EffectiveCost => {
+                    metrics_builder.effective_cost.add_metrics_bucket(&value)
+                }
+            }
+        }
+
+        logger::debug!(
+            "Analytics Accumulated Results: metric: {}, results: {:#?}",
+            metric,
+            metrics_accumulator
+        );
+    }
+    let mut total_fee = 0;
+    let mut total_settled_amount = 0;
+    let query_data: Vec<ConnectorFeeMetricsBucketResponse> = metrics_accumulator
+        .into_iter()
+        .map(|(id, val)| {
+            let collected_values = val.collect();
+            if let Some(fee) = collected_values.total_fee {
+                total_fee += fee;
+            }
+            if let Some(amount) = collected_values.settled_amount {
+                total_settled_amount += amount;
+            }
+
+            ConnectorFeeMetricsBucketResponse {
+                values: collected_values,
+                dimensions: id,
+            }
+        })
+        .collect();
+    let total_effective_fee_rate = total_fee
+        .to_f64()
+        .zip(total_settled_amount.to_f64())
+        .and_then(|(fee, amount)| (amount > 0.0).then_some(fee * 100.0 / amount));
+
+    Ok(ConnectorFeesMetricsResponse {
+        query_data,
+        meta_data: [ConnectorFeesAnalyticsMetadata {
+            total_fee: Some(total_fee),
+            total_settled_amount: Some(total_settled_amount),
+            total_effective_fee_rate,
+        }],
+    })
+}
+
+pub async fn get_filters(
+    pool: &AnalyticsProvider,
+    req: GetConnectorFeeFilterRequest,
+    auth: &AuthInfo,
+) -> AnalyticsResult<ConnectorFeeFiltersResponse> {
+    let mut res = ConnectorFeeFiltersResponse::default();
+    for dim in req.group_by_names {
+        let values = match pool {
+                        AnalyticsProvider::Sqlx(pool) => {
+                            get_connector_fee_filter_for_dimension(dim, auth, &req.time_range, pool)
+                    .await
+            }
+                        AnalyticsProvider::Clickhouse(pool) => {
+                            get_connector_fee_filter_for_dimension(dim, auth, &req.time_range, pool)
+                    .await
+            }
+                    AnalyticsProvider::CombinedCkh(sqlx_pool, ckh_pool) => {
+                let ckh_result = get_connector_fee_filter_for_dimension(
+                    dim,
+                    auth,
+                    &req.time_range,
+                    ckh_pool,
+                )
+                .await;
+                let sqlx_result = get_connector_fee_filter_for_dimension(
+                    dim,
+                    auth,
+                    &req.time_range,
+                    sqlx_pool,
+                )
+                .await;
+                match (&sqlx_result, &ckh_result) {
+                    (Ok(ref sqlx_res), Ok(ref ckh_res)) if sqlx_res != ckh_res => {
+                        router_env::logger::error!(clickhouse_result=?ckh_res, postgres_result=?sqlx_res, "Mismatch between clickhouse & postgres connector fees analytics filters")
+                    },
+                    _ => {}
+                };
+                ckh_result
+            }
+                    AnalyticsProvider::CombinedSqlx(sqlx_pool, ckh_pool) => {
+                let ckh_result = get_connector_fee_filter_for_dimension(
+                    dim,
+                    auth,
+                    &req.time_range,
+                    ckh_pool,
+                )
+                .await;
+                let sqlx_result = get_connector_fee_filter_for_dimension(
+                    dim,
+                    auth,
+                    &req.time_range,
+                    sqlx_pool,
+                )
+                .await;
+                match (&sqlx_result, &ckh_result) {
+                    (Ok(ref sqlx_res), Ok(ref ckh_res)) if sqlx_res != ckh_res => {
+                        router_env::logger::error!(clickhouse_result=?ckh_res, postgres_result=?sqlx_res, "Mismatch between clickhouse & postgres connector fees analytics filters")
+                    },
+                    _ => {}
+                };
+                sqlx_result
+            }
+        }
+        .change_context(AnalyticsError::UnknownError)?
+        .into_iter()
+        .filter_map(|fil: ConnectorFeeFilterRow| match dim {
+            ConnectorFeeDimensions::Connector => fil.connector,
+            ConnectorFeeDimensions::PaymentMethod => fil.payment_method,
+            ConnectorFeeDimensions::PaymentMethodType => fil.payment_method_type,
+            ConnectorFeeDimensions::Region => fil.region,
+            ConnectorFeeDimensions::Currency => fil.currency.map(|i| i.as_ref().to_string()),
+            ConnectorFeeDimensions::ProfileId => fil.profile_id,
+        })
+        .collect::<Vec<String>>();
+        res.query_data.push(ConnectorFeeFilterValue {
+            dimension: dim,
+            values,
+        })
+    }
+    Ok(res)
+}
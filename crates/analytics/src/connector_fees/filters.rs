@@ -0,0 +1,62 @@
+use api_models::analytics::{connector_fees::ConnectorFeeDimensions, Granularity, TimeRange};
+use common_utils::errors::ReportSwitchExt;
+use diesel_models::enums::Currency;
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums::AuthInfo,
+    query::{Aggregate, GroupByClause, QueryBuilder, QueryFilter, ToSql, Window},
+    types::{
+        AnalyticsCollection, AnalyticsDataSource, AnalyticsJoinScope, DBEnumWrapper, FiltersError,
+        FiltersResult, LoadRow,
+    },
+};
+pub trait ConnectorFeeFilterAnalytics: LoadRow<ConnectorFeeFilterRow> {}
+
+pub async fn get_connector_fee_filter_for_dimension<T>(
+    dimension: ConnectorFeeDimensions,
+    auth: &AuthInfo,
+    time_range: &TimeRange,
+    pool: &T,
+) -> FiltersResult<Vec<ConnectorFeeFilterRow>>
+where
+    T: AnalyticsDataSource + ConnectorFeeFilterAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let mut query_builder: QueryBuilder<T> = QueryBuilder::new(
+        AnalyticsCollection::ConnectorSettlement(AnalyticsJoinScope {
+            auth: auth.clone(),
+            time_range: *time_range,
+        }),
+    );
+
+    query_builder.add_select_column(dimension).switch()?;
+    time_range
+        .set_filter_clause(&mut query_builder)
+        .attach_printable("Error filtering time range")
+        .switch()?;
+
+    auth.set_filter_clause(&mut query_builder).switch()?;
+
+    query_builder.set_distinct();
+
+    query_builder
+        .execute_query::<ConnectorFeeFilterRow, _>(pool)
+        .await
+        .change_context(FiltersError::QueryBuildingError)?
+        .change_context(FiltersError::QueryExecutionFailure)
+}
+#[derive(Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
+pub struct ConnectorFeeFilterRow {
+    pub connector: Option<String>,
+    pub payment_method: Option<String>,
+    pub payment_method_type: Option<String>,
+    pub region: Option<String>,
+    pub currency: Option<DBEnumWrapper<Currency>>,
+    pub profile_id: Option<String>,
+}
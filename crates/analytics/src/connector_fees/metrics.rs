@@ -0,0 +1,98 @@
+mod connector_fees;
+mod effective_cost;
+
+use std::collections::HashSet;
+
+use api_models::analytics::{
+    connector_fees::{
+        ConnectorFeeDimensions, ConnectorFeeFilters, ConnectorFeeMetrics,
+        ConnectorFeeMetricsBucketIdentifier,
+    },
+    Granularity,
+};
+use common_utils::types::TimeRange;
+use diesel_models::enums as storage_enums;
+use time::PrimitiveDateTime;
+
+use self::{connector_fees::ConnectorFees, effective_cost::EffectiveCost};
+use crate::{
+    enums::AuthInfo,
+    query::{Aggregate, GroupByClause, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, DBEnumWrapper, LoadRow, MetricsResult},
+};
+#[derive(Debug, Eq, PartialEq, serde::Deserialize, Hash)]
+pub struct ConnectorFeeMetricRow {
+    pub connector: Option<String>,
+    pub payment_method: Option<String>,
+    pub payment_method_type: Option<String>,
+    pub region: Option<String>,
+    pub currency: Option<DBEnumWrapper<storage_enums::Currency>>,
+    pub profile_id: Option<String>,
+    pub interchange_fee: Option<bigdecimal::BigDecimal>,
+    pub scheme_fee: Option<bigdecimal::BigDecimal>,
+    pub markup_fee: Option<bigdecimal::BigDecimal>,
+    pub total_fee: Option<bigdecimal::BigDecimal>,
+    pub settled_amount: Option<bigdecimal::BigDecimal>,
+    pub count: Option<i64>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub start_bucket: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub end_bucket: Option<PrimitiveDateTime>,
+}
+
+pub trait ConnectorFeeMetricAnalytics: LoadRow<ConnectorFeeMetricRow> {}
+
+#[async_trait::async_trait]
+pub trait ConnectorFeeMetric<T>
+where
+    T: AnalyticsDataSource + ConnectorFeeMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[ConnectorFeeDimensions],
+        auth: &AuthInfo,
+        filters: &ConnectorFeeFilters,
+        granularity: Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<HashSet<(ConnectorFeeMetricsBucketIdentifier, ConnectorFeeMetricRow)>>;
+}
+
+#[async_trait::async_trait]
+impl<T> ConnectorFeeMetric<T> for ConnectorFeeMetrics
+where
+    T: AnalyticsDataSource + ConnectorFeeMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[ConnectorFeeDimensions],
+        auth: &AuthInfo,
+        filters: &ConnectorFeeFilters,
+        granularity: Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<HashSet<(ConnectorFeeMetricsBucketIdentifier, ConnectorFeeMetricRow)>> {
+        match self {
+            Self::ConnectorFees => {
+                ConnectorFees::default()
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+            Self::EffectiveCost => {
+                EffectiveCost::default()
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use std::collections::HashSet;
+
+use api_models::analytics::{
+    connector_fees::{
+        ConnectorFeeDimensions, ConnectorFeeFilters, ConnectorFeeMetricsBucketIdentifier,
+    },
+    Granularity, TimeRange,
+};
+use common_utils::errors::ReportSwitchExt;
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use super::ConnectorFeeMetricRow;
+use crate::{
+    enums::AuthInfo,
+    query::{Aggregate, GroupByClause, QueryBuilder, QueryFilter, SeriesBucket, ToSql, Window},
+    types::{
+        AnalyticsCollection, AnalyticsDataSource, AnalyticsJoinScope, MetricsError, MetricsResult,
+    },
+};
+
+#[derive(Default)]
+pub(super) struct ConnectorFees {}
+
+#[async_trait::async_trait]
+impl<T> super::ConnectorFeeMetric<T> for ConnectorFees
+where
+    T: AnalyticsDataSource + super::ConnectorFeeMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[ConnectorFeeDimensions],
+        auth: &AuthInfo,
+        filters: &ConnectorFeeFilters,
+        granularity: Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<HashSet<(ConnectorFeeMetricsBucketIdentifier, ConnectorFeeMetricRow)>> {
+        let mut query_builder: QueryBuilder<T> = QueryBuilder::new(
+            AnalyticsCollection::ConnectorSettlement(AnalyticsJoinScope {
+                auth: auth.clone(),
+                time_range: *time_range,
+            }),
+        );
+
+        for dim in dimensions.iter() {
+            query_builder.add_select_column(dim).switch()?;
+        }
+
+        query_builder
+            .add_select_column(Aggregate::Sum {
+                field: "interchange_fee",
+                alias: Some("interchange_fee"),
+            })
+            .switch()?;
+        query_builder
+            .add_select_column(Aggregate::Sum {
+                field: "scheme_fee",
+                alias: Some("scheme_fee"),
+            })
+            .switch()?;
+        query_builder
+            .add_select_column(Aggregate::Sum {
+                field: "markup_fee",
+                alias: Some("markup_fee"),
+            })
+            .switch()?;
+        query_builder
+            .add_select_column(Aggregate::Sum {
+                field: "total_fee",
+                alias: Some("total_fee"),
+            })
+            .switch()?;
+        query_builder
+            .add_select_column(Aggregate::Min {
+                field: "created_at",
+                alias: Some("start_bucket"),
+            })
+            .switch()?;
+        query_builder
+            .add_select_column(Aggregate::Max {
+                field: "created_at",
+                alias: Some("end_bucket"),
+            })
+            .switch()?;
+
+        filters.set_filter_clause(&mut query_builder).switch()?;
+
+        auth.set_filter_clause(&mut query_builder).switch()?;
+
+        time_range
+            .set_filter_clause(&mut query_builder)
+            .attach_printable("Error filtering time range")
+            .switch()?;
+
+        for dim in dimensions.iter() {
+            query_builder
+                .add_group_by_clause(dim)
+                .attach_printable("Error grouping by dimensions")
+                .switch()?;
+        }
+
+        if let Some(granularity) = granularity {
+            granularity
+                .set_group_by_clause(&mut query_builder)
+                .attach_printable("Error adding granularity")
+                .switch()?;
+        }
+
+        query_builder
+            .execute_query::<ConnectorFeeMetricRow, _>(pool)
+            .await
+            .change_context(MetricsError::QueryBuildingError)?
+            .change_context(MetricsError::QueryExecutionFailure)?
+            .into_iter()
+            .map(|i| {
+                Ok((
+                    ConnectorFeeMetricsBucketIdentifier::new(
+                        i.connector.clone(),
+                        i.payment_method.clone(),
+                        i.payment_method_type.clone(),
+                        i.region.clone(),
+                        i.currency.as_ref().map(|i| i.0),
+                        i.profile_id.clone(),
+                        TimeRange {
+                            start_time: match (granularity, i.start_bucket) {
+                                (Some(g), Some(st)) => g.clip_to_start(st)?,
+                                _ => time_range.start_time,
+                            },
+                            end_time: granularity.as_ref().map_or_else(
+                                || Ok(time_range.end_time),
+                                |g| i.end_bucket.map(|et| g.clip_to_end(et)).transpose(),
+                            )?,
+                        },
+                    ),
+                    i,
+                ))
+            })
+            .collect::<error_stack::Result<HashSet<_>, crate::query::PostProcessingError>>()
+            .change_context(MetricsError::PostProcessingFailure)
+    }
+}
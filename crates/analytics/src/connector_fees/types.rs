@@ -0,0 +1,59 @@
+use api_models::analytics::connector_fees::{ConnectorFeeDimensions, ConnectorFeeFilters};
+use error_stack::ResultExt;
+
+use crate::{
+    query::{QueryBuilder, QueryFilter, QueryResult, ToSql},
+    types::{AnalyticsCollection, AnalyticsDataSource},
+};
+
+impl<T> QueryFilter<T> for ConnectorFeeFilters
+where
+    T: AnalyticsDataSource,
+    AnalyticsCollection: ToSql<T>,
+{
+    fn set_filter_clause(&self, builder: &mut QueryBuilder<T>) -> QueryResult<()> {
+        if !self.connector.is_empty() {
+            builder
+                .add_filter_in_range_clause(ConnectorFeeDimensions::Connector, &self.connector)
+                .attach_printable("Error adding connector filter")?;
+        }
+
+        if !self.payment_method.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    ConnectorFeeDimensions::PaymentMethod,
+                    &self.payment_method,
+                )
+                .attach_printable("Error adding payment method filter")?;
+        }
+
+        if !self.payment_method_type.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    ConnectorFeeDimensions::PaymentMethodType,
+                    &self.payment_method_type,
+                )
+                .attach_printable("Error adding payment method type filter")?;
+        }
+
+        if !self.region.is_empty() {
+            builder
+                .add_filter_in_range_clause(ConnectorFeeDimensions::Region, &self.region)
+                .attach_printable("Error adding region filter")?;
+        }
+
+        if !self.currency.is_empty() {
+            builder
+                .add_filter_in_range_clause(ConnectorFeeDimensions::Currency, &self.currency)
+                .attach_printable("Error adding currency filter")?;
+        }
+
+        if !self.profile_id.is_empty() {
+            builder
+                .add_filter_in_range_clause(ConnectorFeeDimensions::ProfileId, &self.profile_id)
+                .attach_printable("Error adding profile id filter")?;
+        }
+
+        Ok(())
+    }
+}
@@ -46,6 +46,16 @@ pub async fn get_domain_info(
             download_dimensions: None,
             dimensions: utils::get_dispute_dimensions(),
         },
+        AnalyticsDomain::Payouts => GetInfoResponse {
+            metrics: utils::get_payout_metrics_info(),
+            download_dimensions: None,
+            dimensions: utils::get_payout_dimensions(),
+        },
+        AnalyticsDomain::ConnectorFees => GetInfoResponse {
+            metrics: utils::get_connector_fee_metrics_info(),
+            download_dimensions: None,
+            dimensions: utils::get_connector_fee_dimensions(),
+        },
     };
     Ok(info)
 }
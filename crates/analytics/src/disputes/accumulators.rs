@@ -7,6 +7,7 @@ pub struct DisputeMetricsAccumulator {
     pub disputes_status_rate: RateAccumulator,
     pub disputed_amount: DisputedAmountAccumulator,
     pub dispute_lost_amount: DisputedAmountAccumulator,
+    pub dispute_held_amount: DisputedAmountAccumulator,
 }
 #[derive(Debug, Default)]
 pub struct RateAccumulator {
@@ -94,6 +95,7 @@ impl DisputeMetricsAccumulator {
             disputes_lost: lost_rate,
             disputed_amount: self.disputed_amount.collect(),
             dispute_lost_amount: self.dispute_lost_amount.collect(),
+            dispute_held_amount: self.dispute_held_amount.collect(),
             total_dispute,
         }
     }
@@ -98,6 +98,10 @@ pub async fn get_metrics(
                 | DisputeMetrics::SessionizedTotalDisputeLostAmount => metrics_builder
                     .dispute_lost_amount
                     .add_metrics_bucket(&value),
+                DisputeMetrics::TotalDisputeHeldAmount
+                | DisputeMetrics::SessionizedTotalDisputeHeldAmount => metrics_builder
+                    .dispute_held_amount
+                    .add_metrics_bucket(&value),
             }
         }
 
@@ -109,6 +113,7 @@ pub async fn get_metrics(
     }
     let mut total_disputed_amount = 0;
     let mut total_dispute_lost_amount = 0;
+    let mut total_dispute_held_amount = 0;
     let query_data: Vec<DisputeMetricsBucketResponse> = metrics_accumulator
         .into_iter()
         .map(|(id, val)| {
@@ -119,6 +124,9 @@ pub async fn get_metrics(
             if let Some(amount) = collected_values.dispute_lost_amount {
                 total_dispute_lost_amount += amount;
             }
+            if let Some(amount) = collected_values.dispute_held_amount {
+                total_dispute_held_amount += amount;
+            }
 
             DisputeMetricsBucketResponse {
                 values: collected_values,
@@ -132,6 +140,7 @@ pub async fn get_metrics(
         meta_data: [DisputesAnalyticsMetadata {
             total_disputed_amount: Some(total_disputed_amount),
             total_dispute_lost_amount: Some(total_dispute_lost_amount),
+            total_dispute_held_amount: Some(total_dispute_held_amount),
         }],
     })
 }
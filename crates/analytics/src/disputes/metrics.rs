@@ -1,6 +1,7 @@
 mod dispute_status_metric;
 mod sessionized_metrics;
 mod total_amount_disputed;
+mod total_dispute_held_amount;
 mod total_dispute_lost_amount;
 
 use std::collections::HashSet;
@@ -15,6 +16,7 @@ use time::PrimitiveDateTime;
 
 use self::{
     dispute_status_metric::DisputeStatusMetric, total_amount_disputed::TotalAmountDisputed,
+    total_dispute_held_amount::TotalDisputeHeldAmount,
     total_dispute_lost_amount::TotalDisputeLostAmount,
 };
 use crate::{
@@ -94,6 +96,11 @@ where
                     .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
                     .await
             }
+            Self::TotalDisputeHeldAmount => {
+                TotalDisputeHeldAmount::default()
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
             Self::SessionizedTotalAmountDisputed => {
                 sessionized_metrics::TotalAmountDisputed::default()
                     .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
@@ -109,6 +116,11 @@ where
                     .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
                     .await
             }
+            Self::SessionizedTotalDisputeHeldAmount => {
+                sessionized_metrics::TotalDisputeHeldAmount::default()
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
         }
     }
 }
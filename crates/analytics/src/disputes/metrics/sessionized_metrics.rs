@@ -1,8 +1,10 @@
 mod dispute_status_metric;
 mod total_amount_disputed;
+mod total_dispute_held_amount;
 mod total_dispute_lost_amount;
 pub(super) use dispute_status_metric::DisputeStatusMetric;
 pub(super) use total_amount_disputed::TotalAmountDisputed;
+pub(super) use total_dispute_held_amount::TotalDisputeHeldAmount;
 pub(super) use total_dispute_lost_amount::TotalDisputeLostAmount;
 
 pub use super::{DisputeMetric, DisputeMetricAnalytics, DisputeMetricRow};
@@ -3,6 +3,7 @@ mod core;
 pub mod distribution;
 pub mod filters;
 pub mod metrics;
+mod summary;
 pub mod types;
 pub use accumulator::{
     PaymentDistributionAccumulator, PaymentMetricAccumulator, PaymentMetricsAccumulator,
@@ -13,4 +14,7 @@ pub trait PaymentAnalytics:
 {
 }
 
-pub use self::core::{get_filters, get_metrics};
+pub use self::{
+    core::{get_filters, get_metrics},
+    summary::get_payment_summary,
+};
@@ -410,6 +410,8 @@ pub async fn get_filters(
             PaymentDimensions::CardLast4 => fil.card_last_4,
             PaymentDimensions::CardIssuer => fil.card_issuer,
             PaymentDimensions::ErrorReason => fil.error_reason,
+            PaymentDimensions::CardBin => fil.card_bin,
+            PaymentDimensions::UnifiedCode => fil.unified_code,
         })
         .collect::<Vec<String>>();
         res.query_data.push(FilterValue {
@@ -64,5 +64,7 @@ pub struct PaymentFilterRow {
     pub card_last_4: Option<String>,
     pub card_issuer: Option<String>,
     pub error_reason: Option<String>,
+    pub card_bin: Option<String>,
+    pub unified_code: Option<String>,
     pub first_attempt: Option<bool>,
 }
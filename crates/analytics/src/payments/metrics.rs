@@ -47,6 +47,8 @@ pub struct PaymentMetricRow {
     pub card_last_4: Option<String>,
     pub card_issuer: Option<String>,
     pub error_reason: Option<String>,
+    pub card_bin: Option<String>,
+    pub unified_code: Option<String>,
     pub first_attempt: Option<bool>,
     pub total: Option<bigdecimal::BigDecimal>,
     pub count: Option<i64>,
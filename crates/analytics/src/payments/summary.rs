@@ -0,0 +1,183 @@
+//! Summary of the payments of the last day and of the last week, shown on the home page of the
+//! dashboard. The summary is assembled from several payment metrics queries, so it is meant to be
+//! cached by the caller rather than computed on every load of the dashboard.
+
+use std::collections::HashSet;
+
+use api_models::{
+    analytics::{
+        payments::{MetricsBucketResponse, PaymentDimensions, PaymentFilters, PaymentMetrics},
+        ConnectorSummary, DeclineReasonSummary, GetPaymentMetricRequest, PaymentSummary,
+        PaymentSummaryResponse, TimeRange,
+    },
+    enums::AttemptStatus,
+};
+use currency_conversion::types::ExchangeRates;
+use router_env::{instrument, tracing};
+use time::{Duration, PrimitiveDateTime};
+
+use crate::{enums::AuthInfo, errors::AnalyticsResult, AnalyticsProvider};
+
+/// The number of decline reasons and connectors included in the summary
+const SUMMARY_TOP_ITEMS_COUNT: usize = 5;
+
+#[instrument(skip_all)]
+pub async fn get_payment_summary(
+    pool: &AnalyticsProvider,
+    ex_rates: &Option<ExchangeRates>,
+    auth: &AuthInfo,
+    now: PrimitiveDateTime,
+) -> AnalyticsResult<PaymentSummaryResponse> {
+    let (last_24_hours, last_7_days) = futures::try_join!(
+        get_payment_summary_for_time_range(
+            pool,
+            ex_rates,
+            auth,
+            TimeRange {
+                start_time: now - Duration::days(1),
+                end_time: Some(now),
+            },
+        ),
+        get_payment_summary_for_time_range(
+            pool,
+            ex_rates,
+            auth,
+            TimeRange {
+                start_time: now - Duration::days(7),
+                end_time: Some(now),
+            },
+        ),
+    )?;
+
+    Ok(PaymentSummaryResponse {
+        last_24_hours,
+        last_7_days,
+        computed_at: now,
+    })
+}
+
+async fn get_payment_summary_for_time_range(
+    pool: &AnalyticsProvider,
+    ex_rates: &Option<ExchangeRates>,
+    auth: &AuthInfo,
+    time_range: TimeRange,
+) -> AnalyticsResult<PaymentSummary> {
+    // The amounts are grouped by currency, as they are converted to USD per currency
+    let volume_request = get_payment_metric_request(
+        time_range.clone(),
+        vec![PaymentDimensions::Currency],
+        PaymentFilters::default(),
+        [
+            PaymentMetrics::PaymentCount,
+            PaymentMetrics::PaymentSuccessCount,
+            PaymentMetrics::PaymentProcessedAmount,
+        ],
+    );
+    let connectors_request = get_payment_metric_request(
+        time_range.clone(),
+        vec![PaymentDimensions::Connector],
+        PaymentFilters::default(),
+        [
+            PaymentMetrics::PaymentCount,
+            PaymentMetrics::PaymentSuccessCount,
+        ],
+    );
+    let decline_reasons_request = get_payment_metric_request(
+        time_range,
+        vec![PaymentDimensions::ErrorReason],
+        PaymentFilters {
+            status: vec![AttemptStatus::Failure],
+            ..Default::default()
+        },
+        [PaymentMetrics::PaymentCount],
+    );
+
+    let (volume, connectors, decline_reasons) = futures::try_join!(
+        super::get_metrics(pool, ex_rates, auth, volume_request),
+        super::get_metrics(pool, ex_rates, auth, connectors_request),
+        super::get_metrics(pool, ex_rates, auth, decline_reasons_request),
+    )?;
+
+    let payment_count = volume
+        .query_data
+        .iter()
+        .filter_map(|bucket| bucket.values.payment_count)
+        .sum();
+    let payment_success_count = volume
+        .query_data
+        .iter()
+        .filter_map(|bucket| bucket.values.payment_success_count)
+        .sum();
+    let [volume_metadata] = volume.meta_data;
+
+    let mut top_connectors: Vec<ConnectorSummary> = connectors
+        .query_data
+        .into_iter()
+        .filter_map(|bucket| {
+            let payment_count = bucket.values.payment_count.unwrap_or_default();
+            Some(ConnectorSummary {
+                connector: bucket.dimensions.connector?,
+                payment_count,
+                payment_success_rate: get_success_rate(
+                    bucket.values.payment_success_count.unwrap_or_default(),
+                    payment_count,
+                ),
+            })
+        })
+        .collect();
+    top_connectors.sort_by(|a, b| b.payment_count.cmp(&a.payment_count));
+    top_connectors.truncate(SUMMARY_TOP_ITEMS_COUNT);
+
+    let mut top_decline_reasons = get_decline_reasons(decline_reasons.query_data);
+    top_decline_reasons.sort_by(|a, b| b.count.cmp(&a.count));
+    top_decline_reasons.truncate(SUMMARY_TOP_ITEMS_COUNT);
+
+    Ok(PaymentSummary {
+        payment_count,
+        payment_success_count,
+        payment_success_rate: get_success_rate(payment_success_count, payment_count),
+        payment_processed_amount_in_usd: volume_metadata.total_payment_processed_amount_in_usd,
+        top_decline_reasons,
+        top_connectors,
+    })
+}
+
+fn get_payment_metric_request<const N: usize>(
+    time_range: TimeRange,
+    group_by_names: Vec<PaymentDimensions>,
+    filters: PaymentFilters,
+    metrics: [PaymentMetrics; N],
+) -> GetPaymentMetricRequest {
+    GetPaymentMetricRequest {
+        time_series: None,
+        time_range,
+        group_by_names,
+        filters,
+        metrics: HashSet::from(metrics),
+        distribution: None,
+        delta: false,
+    }
+}
+
+fn get_decline_reasons(buckets: Vec<MetricsBucketResponse>) -> Vec<DeclineReasonSummary> {
+    buckets
+        .into_iter()
+        .filter_map(|bucket| {
+            Some(DeclineReasonSummary {
+                error_reason: bucket.dimensions.error_reason?,
+                count: bucket.values.payment_count?,
+            })
+        })
+        .collect()
+}
+
+fn get_success_rate(success_count: u64, total_count: u64) -> Option<f64> {
+    if total_count == 0 {
+        None
+    } else {
+        Some(
+            f64::from(u32::try_from(success_count).ok()?) * 100.0
+                / f64::from(u32::try_from(total_count).ok()?),
+        )
+    }
+}
@@ -104,6 +104,16 @@ where
                 .add_filter_in_range_clause(PaymentDimensions::ErrorReason, &self.error_reason)
                 .attach_printable("Error adding error reason filter")?;
         }
+        if !self.card_bin.is_empty() {
+            builder
+                .add_filter_in_range_clause(PaymentDimensions::CardBin, &self.card_bin)
+                .attach_printable("Error adding card bin filter")?;
+        }
+        if !self.unified_code.is_empty() {
+            builder
+                .add_filter_in_range_clause(PaymentDimensions::UnifiedCode, &self.unified_code)
+                .attach_printable("Error adding unified code filter")?;
+        }
         if !self.first_attempt.is_empty() {
             builder
                 .add_filter_in_range_clause("first_attempt", &self.first_attempt)
@@ -0,0 +1,9 @@
+pub mod accumulators;
+mod core;
+pub mod filters;
+pub mod metrics;
+pub mod types;
+pub use accumulators::{PayoutMetricAccumulator, PayoutMetricsAccumulator};
+
+pub trait PayoutAnalytics: metrics::PayoutMetricAnalytics {}
+pub use self::core::{get_filters, get_metrics};
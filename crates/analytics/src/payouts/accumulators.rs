@@ -0,0 +1,157 @@
+use api_models::analytics::payouts::PayoutMetricsBucketValue;
+use bigdecimal::ToPrimitive;
+use diesel_models::enums as storage_enums;
+
+use super::metrics::PayoutMetricRow;
+#[derive(Debug, Default)]
+pub struct PayoutMetricsAccumulator {
+    pub payout_count: CountAccumulator,
+    pub payout_success_rate: SuccessRateAccumulator,
+    pub payout_processed_amount: ProcessedAmountAccumulator,
+    pub payout_processing_time: ProcessingTimeAccumulator,
+}
+#[derive(Debug, Default)]
+#[repr(transparent)]
+pub struct CountAccumulator {
+    pub count: Option<i64>,
+}
+#[derive(Debug, Default)]
+pub struct SuccessRateAccumulator {
+    pub success: i64,
+    pub total: i64,
+}
+#[derive(Debug, Default)]
+pub struct ProcessedAmountAccumulator {
+    pub total: Option<i64>,
+    pub count: Option<i64>,
+}
+#[derive(Debug, Default)]
+pub struct ProcessingTimeAccumulator {
+    pub total: Option<f64>,
+    pub count: i64,
+    pub p50: Option<f64>,
+    pub p90: Option<f64>,
+}
+
+pub trait PayoutMetricAccumulator {
+    type MetricOutput;
+
+    fn add_metrics_bucket(&mut self, metrics: &PayoutMetricRow);
+
+    fn collect(self) -> Self::MetricOutput;
+}
+
+fn add_optional(left: Option<i64>, right: Option<i64>) -> Option<i64> {
+    match (left, right) {
+        (None, None) => None,
+        (None, i @ Some(_)) | (i @ Some(_), None) => i,
+        (Some(a), Some(b)) => Some(a + b),
+    }
+}
+
+impl PayoutMetricAccumulator for CountAccumulator {
+    type MetricOutput = Option<u64>;
+    #[inline]
+    fn add_metrics_bucket(&mut self, metrics: &PayoutMetricRow) {
+        self.count = add_optional(self.count, metrics.count);
+    }
+    #[inline]
+    fn collect(self) -> Self::MetricOutput {
+        self.count.and_then(|i| u64::try_from(i).ok())
+    }
+}
+
+impl PayoutMetricAccumulator for SuccessRateAccumulator {
+    type MetricOutput = (Option<u64>, Option<u64>, Option<f64>);
+
+    fn add_metrics_bucket(&mut self, metrics: &PayoutMetricRow) {
+        if let Some(ref status) = metrics.status {
+            if status.as_ref() == &storage_enums::PayoutStatus::Success {
+                self.success += metrics.count.unwrap_or_default();
+            }
+        };
+        self.total += metrics.count.unwrap_or_default();
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        if self.total <= 0 {
+            (None, None, None)
+        } else {
+            (
+                u64::try_from(self.success).ok(),
+                u64::try_from(self.total).ok(),
+                self.success
+                    .to_f64()
+                    .zip(self.total.to_f64())
+                    .map(|(success, total)| success * 100.0 / total),
+            )
+        }
+    }
+}
+
+impl PayoutMetricAccumulator for ProcessedAmountAccumulator {
+    type MetricOutput = (Option<u64>, Option<u64>);
+    #[inline]
+    fn add_metrics_bucket(&mut self, metrics: &PayoutMetricRow) {
+        self.total = add_optional(
+            self.total,
+            metrics.total.as_ref().and_then(ToPrimitive::to_i64),
+        );
+        self.count = add_optional(self.count, metrics.count);
+    }
+    #[inline]
+    fn collect(self) -> Self::MetricOutput {
+        (
+            self.total.and_then(|i| u64::try_from(i).ok()),
+            self.count.and_then(|i| u64::try_from(i).ok()),
+        )
+    }
+}
+
+impl PayoutMetricAccumulator for ProcessingTimeAccumulator {
+    type MetricOutput = (Option<f64>, Option<f64>, Option<f64>);
+
+    fn add_metrics_bucket(&mut self, metrics: &PayoutMetricRow) {
+        if let Some(total) = metrics.total.as_ref().and_then(ToPrimitive::to_f64) {
+            self.total = Some(self.total.unwrap_or_default() + total);
+        }
+        self.count += metrics.count.unwrap_or_default();
+        // Percentiles can not be combined across the rows, the highest of them is reported instead
+        let max = |left: Option<f64>, right: Option<f64>| match (left, right) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        };
+        self.p50 = max(self.p50, metrics.p50.as_ref().and_then(ToPrimitive::to_f64));
+        self.p90 = max(self.p90, metrics.p90.as_ref().and_then(ToPrimitive::to_f64));
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        let average = self
+            .total
+            .zip(self.count.to_f64())
+            .and_then(|(total, count)| (count > 0.0).then_some(total / count));
+        (average, self.p50, self.p90)
+    }
+}
+
+impl PayoutMetricsAccumulator {
+    pub fn collect(self) -> PayoutMetricsBucketValue {
+        let (successful_payouts, total_payouts, payout_success_rate) =
+            self.payout_success_rate.collect();
+        let (payout_processed_amount, payout_processed_count) =
+            self.payout_processed_amount.collect();
+        let (avg_payout_processing_time, p50_payout_processing_time, p90_payout_processing_time) =
+            self.payout_processing_time.collect();
+        PayoutMetricsBucketValue {
+            payout_count: self.payout_count.collect(),
+            successful_payouts,
+            total_payouts,
+            payout_success_rate,
+            payout_processed_amount,
+            payout_processed_count,
+            avg_payout_processing_time,
+            p50_payout_processing_time,
+            p90_payout_processing_time,
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use std::collections::HashMap;
+
+use api_models::analytics::{
+    payouts::{
+        PayoutDimensions, PayoutMetrics, PayoutMetricsBucketIdentifier, PayoutMetricsBucketResponse,
+    },
+    GetPayoutFilterRequest, GetPayoutMetricRequest, PayoutFilterValue, PayoutFiltersResponse,
+    PayoutsAnalyticsMetadata, PayoutsMetricsResponse,
+};
+use bigdecimal::ToPrimitive;
+use error_stack::ResultExt;
+use router_env::{
+    logger,
+    tracing::{self, Instrument},
+};
+
+use super::{
+    filters::{get_payout_filter_for_dimension, PayoutFilterRow},
+    PayoutMetricsAccumulator,
+};
+use crate::{
+    enums::AuthInfo,
+    errors::{AnalyticsError, AnalyticsResult},
+    metrics,
+    payouts::PayoutMetricAccumulator,
+    AnalyticsProvider,
+};
+
+pub async fn get_metrics(
+    pool: &AnalyticsProvider,
+    auth: &AuthInfo,
+    req: GetPayoutMetricRequest,
+) -> AnalyticsResult<PayoutsMetricsResponse<PayoutMetricsBucketResponse>> {
+    let mut metrics_accumulator: HashMap<PayoutMetricsBucketIdentifier, PayoutMetricsAccumulator> =
+        HashMap::new();
+    let mut set = tokio::task::JoinSet::new();
+    for metric_type in req.metrics.iter().cloned() {
+        let req = req.clone();
+        let pool = pool.clone();
+        let task_span = tracing::debug_span!(
+            "analytics_payout_query",
+            payout_metric = metric_type.as_ref()
+        );
+        // Currently JoinSet works with only static lifetime references even if the task pool does not outlive the given reference
+        // We can optimize away this clone once that is fixed
+        let auth_scoped = auth.to_owned();
+        set.spawn(
+            async move {
+                let data = pool
+                    .get_payout_metrics(
+                        &metric_type,
+                        &req.group_by_names.clone(),
+                        &auth_scoped,
+                        &req.filters,
+                        req.time_series.map(|t| t.granularity),
+                        &req.time_range,
+                    )
+                    .await
+                    .change_context(AnalyticsError::UnknownError);
+                (metric_type, data)
+            }
+            .instrument(task_span),
+        );
+    }
+
+    while let Some((metric, data)) = set
+        .join_next()
+        .await
+        .transpose()
+        .change_context(AnalyticsError::UnknownError)?
+    {
+        let data = data?;
+        let attributes = router_env::metric_attributes!(
+            ("metric_type", metric.to_string()),
+            ("source", pool.to_string()),
+        );
+
+        let value = u64::try_from(data.len());
+        if let Ok(val) = value {
+            metrics::BUCKETS_FETCHED.record(val, attributes);
+            logger::debug!("Attributes: {:?}, Buckets fetched: {}", attributes, val);
+        }
+
+        for (id, value) in data {
+            logger::debug!(bucket_id=?id, bucket_value=?value, "Bucket row for metric {metric}");
+            let metrics_builder = metrics_accumulator.entry(id).or_default();
+            match metric {
+                PayoutMetrics::PayoutCount => {
+                    metrics_builder.payout_count.add_metrics_bucket(&value)
+                }
+                PayoutMetrics::PayoutSuccessRate => metrics_builder
+                    .payout_success_rate
+                    .add_metrics_bucket(&value),
+                PayoutMetrics::PayoutProcessedAmount => metrics_builder
+                    .payout_processed_amount
+                    .add_metrics_bucket(&value),
+                PayoutMetrics::PayoutProcessingTime => metrics_builder
+                    .payout_processing_time
+                    .add_metrics_bucket(&value),
+            }
+        }
+
+        logger::debug!(
+            "Analytics Accumulated Results: metric: {}, results: {:#?}",
+            metric,
+            metrics_accumulator
+        );
+    }
+    let mut successful_payouts = 0;
+    let mut total_payouts = 0;
+    let mut total_payout_processed_amount = 0;
+    let mut total_payout_processed_count = 0;
+    let query_data: Vec<PayoutMetricsBucketResponse> = metrics_accumulator
+        .into_iter()
+        .map(|(id, val)| {
+            let collected_values = val.collect();
+            if let Some(count) = collected_values.successful_payouts {
+                successful_payouts += count;
+            }
+            if let Some(count) = collected_values.total_payouts {
+                total_payouts += count;
+            }
+            if let Some(amount) = collected_values.payout_processed_amount {
+                total_payout_processed_amount += amount;
+            }
+            if let Some(count) = collected_values.payout_processed_count {
+                total_payout_processed_count += count;
+            }
+
+            PayoutMetricsBucketResponse {
+                values: collected_values,
+                dimensions: id,
+            }
+        })
+        .collect();
+    let total_payout_success_rate = successful_payouts
+        .to_f64()
+        .zip(total_payouts.to_f64())
+        .and_then(|(success, total)| (total > 0.0).then_some(success * 100.0 / total));
+
+    Ok(PayoutsMetricsResponse {
+        query_data,
+        meta_data: [PayoutsAnalyticsMetadata {
+            total_payout_success_rate,
+            total_payout_processed_amount: Some(total_payout_processed_amount),
+            total_payout_processed_count: Some(total_payout_processed_count),
+        }],
+    })
+}
+
+pub async fn get_filters(
+    pool: &AnalyticsProvider,
+    req: GetPayoutFilterRequest,
+    auth: &AuthInfo,
+) -> AnalyticsResult<PayoutFiltersResponse> {
+    let mut res = PayoutFiltersResponse::default();
+    for dim in req.group_by_names {
+        let values = match pool {
+                        AnalyticsProvider::Sqlx(pool) => {
+                            get_payout_filter_for_dimension(dim, auth, &req.time_range, pool)
+                    .await
+            }
+                        AnalyticsProvider::Clickhouse(pool) => {
+                            get_payout_filter_for_dimension(dim, auth, &req.time_range, pool)
+                    .await
+            }
+                    AnalyticsProvider::CombinedCkh(sqlx_pool, ckh_pool) => {
+                let ckh_result = get_payout_filter_for_dimension(
+                    dim,
+                    auth,
+                    &req.time_range,
+                    ckh_pool,
+                )
+                .await;
+                let sqlx_result = get_payout_filter_for_dimension(
+                    dim,
+                    auth,
+                    &req.time_range,
+                    sqlx_pool,
+                )
+                .await;
+                match (&sqlx_result, &ckh_result) {
+                    (Ok(ref sqlx_res), Ok(ref ckh_res)) if sqlx_res != ckh_res => {
+                        router_env::logger::error!(clickhouse_result=?ckh_res, postgres_result=?sqlx_res, "Mismatch between clickhouse & postgres payouts analytics filters")
+                    },
+                    _ => {}
+                };
+                ckh_result
+            }
+                    AnalyticsProvider::CombinedSqlx(sqlx_pool, ckh_pool) => {
+                let ckh_result = get_payout_filter_for_dimension(
+                    dim,
+                    auth,
+                    &req.time_range,
+                    ckh_pool,
+                )
+                .await;
+                let sqlx_result = get_payout_filter_for_dimension(
+                    dim,
+                    auth,
+                    &req.time_range,
+                    sqlx_pool,
+                )
+                .await;
+                match (&sqlx_result, &ckh_result) {
+                    (Ok(ref sqlx_res), Ok(ref ckh_res)) if sqlx_res != ckh_res => {
+                        router_env::logger::error!(clickhouse_result=?ckh_res, postgres_result=?sqlx_res, "Mismatch between clickhouse & postgres payouts analytics filters")
+                    },
+                    _ => {}
+                };
+                sqlx_result
+            }
+        }
+        .change_context(AnalyticsError::UnknownError)?
+        .into_iter()
+        .filter_map(|fil: PayoutFilterRow| match dim {
+            PayoutDimensions::Connector => fil.connector,
+            PayoutDimensions::PayoutType => fil.payout_type.map(|i| i.as_ref().to_string()),
+            PayoutDimensions::PayoutStatus => fil.status.map(|i| i.as_ref().to_string()),
+            PayoutDimensions::DestinationCurrency => {
+                fil.destination_currency.map(|i| i.as_ref().to_string())
+            }
+            PayoutDimensions::ProfileId => fil.profile_id,
+        })
+        .collect::<Vec<String>>();
+        res.query_data.push(PayoutFilterValue {
+            dimension: dim,
+            values,
+        })
+    }
+    Ok(res)
+}
@@ -0,0 +1,107 @@
+mod payout_count;
+mod payout_processed_amount;
+mod payout_processing_time;
+mod payout_success_rate;
+
+use std::collections::HashSet;
+
+use api_models::analytics::{
+    payouts::{PayoutDimensions, PayoutFilters, PayoutMetrics, PayoutMetricsBucketIdentifier},
+    Granularity,
+};
+use common_utils::types::TimeRange;
+use diesel_models::enums as storage_enums;
+use time::PrimitiveDateTime;
+
+use self::{
+    payout_count::PayoutCount, payout_processed_amount::PayoutProcessedAmount,
+    payout_processing_time::PayoutProcessingTime, payout_success_rate::PayoutSuccessRate,
+};
+use crate::{
+    enums::AuthInfo,
+    query::{Aggregate, GroupByClause, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, DBEnumWrapper, LoadRow, MetricsResult},
+};
+#[derive(Debug, Eq, PartialEq, serde::Deserialize, Hash)]
+pub struct PayoutMetricRow {
+    pub connector: Option<String>,
+    pub payout_type: Option<DBEnumWrapper<storage_enums::PayoutType>>,
+    pub status: Option<DBEnumWrapper<storage_enums::PayoutStatus>>,
+    pub destination_currency: Option<DBEnumWrapper<storage_enums::Currency>>,
+    pub profile_id: Option<String>,
+    pub total: Option<bigdecimal::BigDecimal>,
+    pub count: Option<i64>,
+    pub p50: Option<bigdecimal::BigDecimal>,
+    pub p90: Option<bigdecimal::BigDecimal>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub start_bucket: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub end_bucket: Option<PrimitiveDateTime>,
+}
+
+pub trait PayoutMetricAnalytics: LoadRow<PayoutMetricRow> {}
+
+#[async_trait::async_trait]
+pub trait PayoutMetric<T>
+where
+    T: AnalyticsDataSource + PayoutMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[PayoutDimensions],
+        auth: &AuthInfo,
+        filters: &PayoutFilters,
+        granularity: Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<HashSet<(PayoutMetricsBucketIdentifier, PayoutMetricRow)>>;
+}
+
+#[async_trait::async_trait]
+impl<T> PayoutMetric<T> for PayoutMetrics
+where
+    T: AnalyticsDataSource + PayoutMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[PayoutDimensions],
+        auth: &AuthInfo,
+        filters: &PayoutFilters,
+        granularity: Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<HashSet<(PayoutMetricsBucketIdentifier, PayoutMetricRow)>> {
+        match self {
+            Self::PayoutCount => {
+                PayoutCount::default()
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+            Self::PayoutSuccessRate => {
+                PayoutSuccessRate::default()
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+            Self::PayoutProcessedAmount => {
+                PayoutProcessedAmount::default()
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+            Self::PayoutProcessingTime => {
+                PayoutProcessingTime::default()
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+        }
+    }
+}
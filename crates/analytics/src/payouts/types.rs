@@ -0,0 +1,83 @@
+use api_models::analytics::payouts::{PayoutDimensions, PayoutFilters};
+use error_stack::{report, ResultExt};
+
+use crate::{
+    enums::AuthInfo,
+    query::{QueryBuilder, QueryBuildingError, QueryFilter, QueryResult, ToSql},
+    types::{AnalyticsCollection, AnalyticsDataSource},
+};
+
+impl<T> QueryFilter<T> for PayoutFilters
+where
+    T: AnalyticsDataSource,
+    AnalyticsCollection: ToSql<T>,
+{
+    fn set_filter_clause(&self, builder: &mut QueryBuilder<T>) -> QueryResult<()> {
+        if !self.connector.is_empty() {
+            builder
+                .add_filter_in_range_clause(PayoutDimensions::Connector, &self.connector)
+                .attach_printable("Error adding connector filter")?;
+        }
+
+        if !self.payout_type.is_empty() {
+            builder
+                .add_filter_in_range_clause(PayoutDimensions::PayoutType, &self.payout_type)
+                .attach_printable("Error adding payout type filter")?;
+        }
+
+        if !self.status.is_empty() {
+            builder
+                .add_filter_in_range_clause(PayoutDimensions::PayoutStatus, &self.status)
+                .attach_printable("Error adding payout status filter")?;
+        }
+
+        if !self.destination_currency.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    PayoutDimensions::DestinationCurrency,
+                    &self.destination_currency,
+                )
+                .attach_printable("Error adding destination currency filter")?;
+        }
+
+        if !self.profile_id.is_empty() {
+            builder
+                .add_filter_in_range_clause(PayoutDimensions::ProfileId, &self.profile_id)
+                .attach_printable("Error adding profile id filter")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The payouts are not recorded along with their organization, so they are scoped only by the
+/// merchant and the profiles, and can not be analysed at the organization level.
+pub(crate) fn set_payout_auth_filter_clause<T>(
+    auth: &AuthInfo,
+    builder: &mut QueryBuilder<T>,
+) -> QueryResult<()>
+where
+    T: AnalyticsDataSource,
+    AnalyticsCollection: ToSql<T>,
+{
+    match auth {
+        AuthInfo::OrgLevel { .. } => Err(report!(QueryBuildingError::NotImplemented(
+            "Organization level payout analytics".to_string()
+        ))),
+        AuthInfo::MerchantLevel { merchant_ids, .. } => builder
+            .add_filter_in_range_clause("merchant_id", merchant_ids)
+            .attach_printable("Error adding merchant_id filter"),
+        AuthInfo::ProfileLevel {
+            merchant_id,
+            profile_ids,
+            ..
+        } => {
+            builder
+                .add_filter_clause("merchant_id", merchant_id)
+                .attach_printable("Error adding merchant_id filter")?;
+            builder
+                .add_filter_in_range_clause("profile_id", profile_ids)
+                .attach_printable("Error adding profile_id filter")
+        }
+    }
+}
@@ -5,17 +5,19 @@ use api_models::{
         self as analytics_api,
         api_event::ApiEventDimensions,
         auth_events::{AuthEventDimensions, AuthEventFlows},
+        connector_fees::ConnectorFeeDimensions,
         disputes::DisputeDimensions,
         frm::{FrmDimensions, FrmTransactionType},
         payment_intents::PaymentIntentDimensions,
         payments::{PaymentDimensions, PaymentDistributions},
+        payouts::PayoutDimensions,
         refunds::{RefundDimensions, RefundDistributions, RefundType},
         sdk_events::{SdkEventDimensions, SdkEventNames},
         Granularity,
     },
     enums::{
         AttemptStatus, AuthenticationType, Connector, Currency, DisputeStage, IntentStatus,
-        PaymentMethod, PaymentMethodType,
+        PaymentMethod, PaymentMethodType, PayoutStatus, PayoutType,
     },
     refunds::RefundStatus,
 };
@@ -528,7 +530,13 @@ impl_to_sql_for_to_string!(
     DisputeDimensions,
     DisputeStage,
     AuthEventDimensions,
-    &AuthEventDimensions
+    &AuthEventDimensions,
+    &PayoutDimensions,
+    PayoutDimensions,
+    PayoutType,
+    PayoutStatus,
+    &ConnectorFeeDimensions,
+    ConnectorFeeDimensions
 );
 
 #[derive(Debug, Clone, Copy)]
@@ -575,10 +583,10 @@ where
             limit_by: Default::default(),
             outer_select: Default::default(),
             top_n: Default::default(),
+            table_engine: T::get_table_engine(&table),
             table,
             distinct: Default::default(),
             db_type: Default::default(),
-            table_engine: T::get_table_engine(table),
         }
     }
 
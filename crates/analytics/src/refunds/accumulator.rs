@@ -15,6 +15,9 @@ pub struct RefundMetricsAccumulator {
     pub refund_reason_distribution: RefundReasonDistributionAccumulator,
     pub refund_error_message: RefundReasonAccumulator,
     pub refund_error_message_distribution: RefundErrorMessageDistributionAccumulator,
+    pub refund_rate: RefundRateAccumulator,
+    pub time_to_refund: TimeToRefundAccumulator,
+    pub refund_coverage: RefundCoverageAccumulator,
 }
 
 #[derive(Debug, Default)]
@@ -63,6 +66,25 @@ pub struct RefundProcessedAmountAccumulator {
     pub total: Option<i64>,
 }
 
+#[derive(Debug, Default)]
+pub struct RefundRateAccumulator {
+    pub captured: u64,
+    pub refunded: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct TimeToRefundAccumulator {
+    pub total_seconds: u64,
+    pub count: u64,
+    pub p90_seconds: Option<f64>,
+}
+
+#[derive(Debug, Default)]
+pub struct RefundCoverageAccumulator {
+    pub refunded: u64,
+    pub full: u64,
+}
+
 pub trait RefundMetricAccumulator {
     type MetricOutput;
 
@@ -252,12 +274,99 @@ impl RefundMetricAccumulator for RefundReasonAccumulator {
     }
 }
 
+impl RefundMetricAccumulator for RefundRateAccumulator {
+    type MetricOutput = (Option<u64>, Option<u64>, Option<f64>);
+
+    fn add_metrics_bucket(&mut self, metrics: &RefundMetricRow) {
+        if let Some(captured) = metrics.count.and_then(|i| u64::try_from(i).ok()) {
+            self.captured += captured;
+        }
+        if let Some(refunded) = metrics.refunded_count.and_then(|i| u64::try_from(i).ok()) {
+            self.refunded += refunded;
+        }
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        if self.captured == 0 {
+            (None, None, None)
+        } else {
+            let refund_rate = self
+                .refunded
+                .to_f64()
+                .zip(self.captured.to_f64())
+                .map(|(refunded, captured)| refunded * 100.0 / captured);
+            (Some(self.captured), Some(self.refunded), refund_rate)
+        }
+    }
+}
+
+impl RefundMetricAccumulator for TimeToRefundAccumulator {
+    type MetricOutput = (Option<f64>, Option<f64>);
+
+    fn add_metrics_bucket(&mut self, metrics: &RefundMetricRow) {
+        if let Some(total) = metrics.total.as_ref().and_then(ToPrimitive::to_u64) {
+            self.total_seconds += total;
+        }
+        if let Some(count) = metrics.count.and_then(|i| u64::try_from(i).ok()) {
+            self.count += count;
+        }
+        // The percentile is computed over the refunded payments of the bucket by the query, which
+        // returns a single row for each bucket
+        if let Some(p90) = metrics.percentile.as_ref().and_then(ToPrimitive::to_f64) {
+            self.p90_seconds = Some(p90);
+        }
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        if self.count == 0 {
+            (None, None)
+        } else {
+            let avg_seconds = self
+                .total_seconds
+                .to_f64()
+                .zip(self.count.to_f64())
+                .map(|(total_seconds, count)| total_seconds / count);
+            (avg_seconds, self.p90_seconds)
+        }
+    }
+}
+
+impl RefundMetricAccumulator for RefundCoverageAccumulator {
+    type MetricOutput = (Option<u64>, Option<u64>);
+
+    fn add_metrics_bucket(&mut self, metrics: &RefundMetricRow) {
+        if let Some(refunded) = metrics.count.and_then(|i| u64::try_from(i).ok()) {
+            self.refunded += refunded;
+        }
+        if let Some(full) = metrics
+            .full_refund_count
+            .and_then(|i| u64::try_from(i).ok())
+        {
+            self.full += full;
+        }
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        if self.refunded == 0 {
+            (None, None)
+        } else {
+            (
+                Some(self.full),
+                Some(self.refunded.saturating_sub(self.full)),
+            )
+        }
+    }
+}
+
 impl RefundMetricsAccumulator {
     pub fn collect(self) -> RefundMetricsBucketValue {
         let (successful_refunds, total_refunds, refund_success_rate) =
             self.refund_success_rate.collect();
         let (refund_processed_amount, refund_processed_count, refund_processed_amount_in_usd) =
             self.processed_amount.collect();
+        let (captured_payments, refunded_payments, refund_rate) = self.refund_rate.collect();
+        let (avg_time_to_refund, p90_time_to_refund) = self.time_to_refund.collect();
+        let (full_refund_count, partial_refund_count) = self.refund_coverage.collect();
         RefundMetricsBucketValue {
             successful_refunds,
             total_refunds,
@@ -271,6 +380,13 @@ impl RefundMetricsAccumulator {
             refund_error_message_distribution: self.refund_error_message_distribution.collect(),
             refund_reason_count: self.refund_reason.collect(),
             refund_error_message_count: self.refund_error_message.collect(),
+            captured_payments,
+            refunded_payments,
+            refund_rate,
+            avg_time_to_refund,
+            p90_time_to_refund,
+            full_refund_count,
+            partial_refund_count,
         }
     }
 }
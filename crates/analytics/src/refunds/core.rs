@@ -45,12 +45,52 @@ pub enum TaskType {
     ),
 }
 
+/// The metrics over the payments of the refunds, and the metrics over the refunds, are computed
+/// from different collections, which do not share the dimensions and filters specific to either
+fn validate_metrics_request(req: &GetRefundMetricRequest) -> AnalyticsResult<()> {
+    let has_refund_dimensions = req
+        .group_by_names
+        .iter()
+        .any(RefundDimensions::is_refund_dimension);
+    let has_payment_dimensions = req
+        .group_by_names
+        .iter()
+        .any(RefundDimensions::is_payment_dimension);
+
+    for metric in req.metrics.iter() {
+        if metric.is_payment_metric() {
+            if has_refund_dimensions || req.filters.has_refund_filters() {
+                return Err(AnalyticsError::NotImplemented(
+                    "Grouping or filtering the payment level refund metrics by the refund fields",
+                )
+                .into());
+            }
+        } else if has_payment_dimensions || req.filters.has_payment_filters() {
+            return Err(AnalyticsError::NotImplemented(
+                "Grouping or filtering the refund metrics by the payment method fields",
+            )
+            .into());
+        }
+    }
+
+    if req.distribution.is_some() && (has_payment_dimensions || req.filters.has_payment_filters()) {
+        return Err(AnalyticsError::NotImplemented(
+            "Grouping or filtering the refund distributions by the payment method fields",
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
 pub async fn get_metrics(
     pool: &AnalyticsProvider,
     ex_rates: &Option<ExchangeRates>,
     auth: &AuthInfo,
     req: GetRefundMetricRequest,
 ) -> AnalyticsResult<RefundsMetricsResponse<RefundMetricsBucketResponse>> {
+    validate_metrics_request(&req)?;
+
     let mut metrics_accumulator: HashMap<RefundMetricsBucketIdentifier, RefundMetricsAccumulator> =
         HashMap::new();
     let mut set = tokio::task::JoinSet::new();
@@ -156,6 +196,15 @@ pub async fn get_metrics(
                         RefundMetrics::SessionizedRefundErrorMessage => metrics_builder
                             .refund_error_message
                             .add_metrics_bucket(&value),
+                        RefundMetrics::RefundRate => {
+                            metrics_builder.refund_rate.add_metrics_bucket(&value)
+                        }
+                        RefundMetrics::TimeToRefund => {
+                            metrics_builder.time_to_refund.add_metrics_bucket(&value)
+                        }
+                        RefundMetrics::RefundCoverage => {
+                            metrics_builder.refund_coverage.add_metrics_bucket(&value)
+                        }
                     }
                 }
 
@@ -349,6 +398,8 @@ pub async fn get_filters(
             RefundDimensions::ProfileId => fil.profile_id,
             RefundDimensions::RefundReason => fil.refund_reason,
             RefundDimensions::RefundErrorMessage => fil.refund_error_message,
+            RefundDimensions::PaymentMethod => fil.payment_method,
+            RefundDimensions::PaymentMethodType => fil.payment_method_type,
         })
         .collect::<Vec<String>>();
         res.query_data.push(RefundFilterValue {
@@ -11,8 +11,8 @@ use crate::{
     enums::AuthInfo,
     query::{Aggregate, GroupByClause, QueryBuilder, QueryFilter, ToSql, Window},
     types::{
-        AnalyticsCollection, AnalyticsDataSource, DBEnumWrapper, FiltersError, FiltersResult,
-        LoadRow,
+        AnalyticsCollection, AnalyticsDataSource, AnalyticsJoinScope, DBEnumWrapper, FiltersError,
+        FiltersResult, LoadRow,
     },
 };
 pub trait RefundFilterAnalytics: LoadRow<RefundFilterRow> {}
@@ -31,7 +31,15 @@ where
     Aggregate<&'static str>: ToSql<T>,
     Window<&'static str>: ToSql<T>,
 {
-    let mut query_builder: QueryBuilder<T> = QueryBuilder::new(AnalyticsCollection::Refund);
+    let collection = if dimension.is_payment_dimension() {
+        AnalyticsCollection::RefundPaymentAttempt(AnalyticsJoinScope {
+            auth: auth.clone(),
+            time_range: *time_range,
+        })
+    } else {
+        AnalyticsCollection::Refund
+    };
+    let mut query_builder: QueryBuilder<T> = QueryBuilder::new(collection);
 
     query_builder.add_select_column(dimension).switch()?;
     time_range
@@ -58,4 +66,6 @@ pub struct RefundFilterRow {
     pub profile_id: Option<String>,
     pub refund_reason: Option<String>,
     pub refund_error_message: Option<String>,
+    pub payment_method: Option<String>,
+    pub payment_method_type: Option<String>,
 }
@@ -7,16 +7,22 @@ use api_models::analytics::{
 use diesel_models::enums as storage_enums;
 use time::PrimitiveDateTime;
 mod refund_count;
+mod refund_coverage;
 mod refund_processed_amount;
+mod refund_rate;
 mod refund_success_count;
 mod refund_success_rate;
 mod sessionized_metrics;
+mod time_to_refund;
 use std::collections::HashSet;
 
 use refund_count::RefundCount;
+use refund_coverage::RefundCoverage;
 use refund_processed_amount::RefundProcessedAmount;
+use refund_rate::RefundRate;
 use refund_success_count::RefundSuccessCount;
 use refund_success_rate::RefundSuccessRate;
+use time_to_refund::TimeToRefund;
 
 use crate::{
     enums::AuthInfo,
@@ -33,8 +39,13 @@ pub struct RefundMetricRow {
     pub profile_id: Option<String>,
     pub refund_reason: Option<String>,
     pub refund_error_message: Option<String>,
+    pub payment_method: Option<String>,
+    pub payment_method_type: Option<String>,
     pub total: Option<bigdecimal::BigDecimal>,
     pub count: Option<i64>,
+    pub refunded_count: Option<i64>,
+    pub full_refund_count: Option<i64>,
+    pub percentile: Option<bigdecimal::BigDecimal>,
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
     pub start_bucket: Option<PrimitiveDateTime>,
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
@@ -134,6 +145,21 @@ where
                     .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
                     .await
             }
+            Self::RefundRate => {
+                RefundRate::default()
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+            Self::TimeToRefund => {
+                TimeToRefund::default()
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
+            Self::RefundCoverage => {
+                RefundCoverage::default()
+                    .load_metrics(dimensions, auth, filters, granularity, time_range, pool)
+                    .await
+            }
         }
     }
 }
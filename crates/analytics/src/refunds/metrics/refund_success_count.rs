@@ -104,6 +104,8 @@ where
                         i.profile_id.clone(),
                         i.refund_reason.clone(),
                         i.refund_error_message.clone(),
+                        i.payment_method.clone(),
+                        i.payment_method_type.clone(),
                         TimeRange {
                             start_time: match (granularity, i.start_bucket) {
                                 (Some(g), Some(st)) => g.clip_to_start(st)?,
@@ -57,6 +57,21 @@ where
                 .attach_printable("Error adding refund error message filter")?;
         }
 
+        if !self.payment_method.is_empty() {
+            builder
+                .add_filter_in_range_clause(RefundDimensions::PaymentMethod, &self.payment_method)
+                .attach_printable("Error adding payment method filter")?;
+        }
+
+        if !self.payment_method_type.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    RefundDimensions::PaymentMethodType,
+                    &self.payment_method_type,
+                )
+                .attach_printable("Error adding payment method type filter")?;
+        }
+
         Ok(())
     }
 }
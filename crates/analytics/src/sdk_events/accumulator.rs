@@ -1,4 +1,5 @@
 use api_models::analytics::sdk_events::SdkEventMetricsBucketValue;
+use bigdecimal::ToPrimitive;
 use router_env::logger;
 
 use super::metrics::SdkEventMetricRow;
@@ -13,6 +14,11 @@ pub struct SdkEventMetricsAccumulator {
     pub sdk_rendered_count: CountAccumulator,
     pub payment_method_selected_count: CountAccumulator,
     pub payment_data_filled_count: CountAccumulator,
+    pub checkout_rendered_count: CountAccumulator,
+    pub checkout_payment_method_selected_count: CountAccumulator,
+    pub checkout_confirmed_count: CountAccumulator,
+    pub checkout_converted_count: CountAccumulator,
+    pub checkout_conversion_rate: ConversionRateAccumulator,
 }
 
 #[derive(Debug, Default)]
@@ -27,6 +33,12 @@ pub struct AverageAccumulator {
     pub count: u32,
 }
 
+#[derive(Debug, Default)]
+pub struct ConversionRateAccumulator {
+    pub converted: u64,
+    pub rendered: u64,
+}
+
 pub trait SdkEventMetricAccumulator {
     type MetricOutput;
 
@@ -81,6 +93,36 @@ impl SdkEventMetricAccumulator for AverageAccumulator {
     }
 }
 
+impl SdkEventMetricAccumulator for ConversionRateAccumulator {
+    type MetricOutput = Option<f64>;
+
+    fn add_metrics_bucket(&mut self, metrics: &SdkEventMetricRow) {
+        let converted = metrics.total.as_ref().and_then(ToPrimitive::to_u64);
+        let rendered = metrics.count.and_then(|count| u64::try_from(count).ok());
+
+        match (converted, rendered) {
+            (Some(converted), Some(rendered)) => {
+                self.converted += converted;
+                self.rendered += rendered;
+            }
+            _ => {
+                logger::error!(message="Dropping metrics for conversion rate", metric=?metrics);
+            }
+        }
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        if self.rendered == 0 {
+            None
+        } else {
+            self.converted
+                .to_f64()
+                .zip(self.rendered.to_f64())
+                .map(|(converted, rendered)| converted * 100.0 / rendered)
+        }
+    }
+}
+
 impl SdkEventMetricsAccumulator {
     #[allow(dead_code)]
     pub fn collect(self) -> SdkEventMetricsBucketValue {
@@ -93,6 +135,13 @@ impl SdkEventMetricsAccumulator {
             sdk_rendered_count: self.sdk_rendered_count.collect(),
             payment_method_selected_count: self.payment_method_selected_count.collect(),
             payment_data_filled_count: self.payment_data_filled_count.collect(),
+            checkout_rendered_count: self.checkout_rendered_count.collect(),
+            checkout_payment_method_selected_count: self
+                .checkout_payment_method_selected_count
+                .collect(),
+            checkout_confirmed_count: self.checkout_confirmed_count.collect(),
+            checkout_converted_count: self.checkout_converted_count.collect(),
+            checkout_conversion_rate: self.checkout_conversion_rate.collect(),
         }
     }
 }
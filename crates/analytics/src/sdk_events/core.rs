@@ -45,6 +45,7 @@ pub async fn sdk_events_core(
 #[instrument(skip_all)]
 pub async fn get_metrics(
     pool: &AnalyticsProvider,
+    merchant_id: &common_utils::id_type::MerchantId,
     publishable_key: &String,
     req: GetSdkEventMetricRequest,
 ) -> AnalyticsResult<MetricsResponse<MetricsBucketResponse>> {
@@ -56,6 +57,7 @@ pub async fn get_metrics(
     let mut set = tokio::task::JoinSet::new();
     for metric_type in req.metrics.iter().cloned() {
         let req = req.clone();
+        let merchant_id_scoped = merchant_id.to_owned();
         let publishable_key_scoped = publishable_key.to_owned();
         let pool = pool.clone();
         set.spawn(async move {
@@ -63,6 +65,7 @@ pub async fn get_metrics(
                 .get_sdk_event_metrics(
                     &metric_type,
                     &req.group_by_names.clone(),
+                    &merchant_id_scoped,
                     &publishable_key_scoped,
                     &req.filters,
                     req.time_series.map(|t| t.granularity),
@@ -106,6 +109,21 @@ pub async fn get_metrics(
                     .average_payment_time
                     .add_metrics_bucket(&value),
                 SdkEventMetrics::LoadTime => metrics_builder.load_time.add_metrics_bucket(&value),
+                SdkEventMetrics::CheckoutRenderedCount => metrics_builder
+                    .checkout_rendered_count
+                    .add_metrics_bucket(&value),
+                SdkEventMetrics::CheckoutPaymentMethodSelectedCount => metrics_builder
+                    .checkout_payment_method_selected_count
+                    .add_metrics_bucket(&value),
+                SdkEventMetrics::CheckoutConfirmedCount => metrics_builder
+                    .checkout_confirmed_count
+                    .add_metrics_bucket(&value),
+                SdkEventMetrics::CheckoutConvertedCount => metrics_builder
+                    .checkout_converted_count
+                    .add_metrics_bucket(&value),
+                SdkEventMetrics::CheckoutConversionRate => metrics_builder
+                    .checkout_conversion_rate
+                    .add_metrics_bucket(&value),
             }
         }
 
@@ -170,6 +188,7 @@ pub async fn get_filters(
             SdkEventDimensions::Source => fil.source,
             SdkEventDimensions::Component => fil.component,
             SdkEventDimensions::PaymentExperience => fil.payment_experience,
+            SdkEventDimensions::Locale => fil.locale,
         })
         .collect::<Vec<String>>();
         res.query_data.push(SdkEventFilterValue {
@@ -54,4 +54,5 @@ pub struct SdkEventFilter {
     pub source: Option<String>,
     pub component: Option<String>,
     pub payment_experience: Option<String>,
+    pub locale: Option<String>,
 }
@@ -14,6 +14,8 @@ use crate::{
 };
 
 mod average_payment_time;
+mod checkout_conversion_rate;
+mod checkout_stage_count;
 mod load_time;
 mod payment_attempts;
 mod payment_data_filled_count;
@@ -23,6 +25,8 @@ mod sdk_initiated_count;
 mod sdk_rendered_count;
 
 use average_payment_time::AveragePaymentTime;
+use checkout_conversion_rate::CheckoutConversionRate;
+use checkout_stage_count::CheckoutStageCount;
 use load_time::LoadTime;
 use payment_attempts::PaymentAttempts;
 use payment_data_filled_count::PaymentDataFilledCount;
@@ -42,6 +46,7 @@ pub struct SdkEventMetricRow {
     pub source: Option<String>,
     pub component: Option<String>,
     pub payment_experience: Option<String>,
+    pub locale: Option<String>,
 }
 
 pub trait SdkEventMetricAnalytics: LoadRow<SdkEventMetricRow> {}
@@ -54,6 +59,7 @@ where
     async fn load_metrics(
         &self,
         dimensions: &[SdkEventDimensions],
+        merchant_id: &common_utils::id_type::MerchantId,
         publishable_key: &str,
         filters: &SdkEventFilters,
         granularity: Option<Granularity>,
@@ -75,6 +81,7 @@ where
     async fn load_metrics(
         &self,
         dimensions: &[SdkEventDimensions],
+        merchant_id: &common_utils::id_type::MerchantId,
         publishable_key: &str,
         filters: &SdkEventFilters,
         granularity: Option<Granularity>,
@@ -86,6 +93,7 @@ where
                 PaymentAttempts
                     .load_metrics(
                         dimensions,
+                        merchant_id,
                         publishable_key,
                         filters,
                         granularity,
@@ -98,6 +106,7 @@ where
                 PaymentMethodsCallCount
                     .load_metrics(
                         dimensions,
+                        merchant_id,
                         publishable_key,
                         filters,
                         granularity,
@@ -110,6 +119,7 @@ where
                 SdkRenderedCount
                     .load_metrics(
                         dimensions,
+                        merchant_id,
                         publishable_key,
                         filters,
                         granularity,
@@ -122,6 +132,7 @@ where
                 SdkInitiatedCount
                     .load_metrics(
                         dimensions,
+                        merchant_id,
                         publishable_key,
                         filters,
                         granularity,
@@ -134,6 +145,7 @@ where
                 PaymentMethodSelectedCount
                     .load_metrics(
                         dimensions,
+                        merchant_id,
                         publishable_key,
                         filters,
                         granularity,
@@ -146,6 +158,7 @@ where
                 PaymentDataFilledCount
                     .load_metrics(
                         dimensions,
+                        merchant_id,
                         publishable_key,
                         filters,
                         granularity,
@@ -158,6 +171,7 @@ where
                 AveragePaymentTime
                     .load_metrics(
                         dimensions,
+                        merchant_id,
                         publishable_key,
                         filters,
                         granularity,
@@ -170,6 +184,73 @@ where
                 LoadTime
                     .load_metrics(
                         dimensions,
+                        merchant_id,
+                        publishable_key,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::CheckoutRenderedCount => {
+                CheckoutStageCount { stage: "rendered" }
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        publishable_key,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::CheckoutPaymentMethodSelectedCount => {
+                CheckoutStageCount {
+                    stage: "payment_method_selected",
+                }
+                .load_metrics(
+                    dimensions,
+                    publishable_key,
+                    filters,
+                    granularity,
+                    time_range,
+                    pool,
+                )
+                .await
+            }
+            Self::CheckoutConfirmedCount => {
+                CheckoutStageCount { stage: "confirmed" }
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        publishable_key,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::CheckoutConvertedCount => {
+                CheckoutStageCount { stage: "converted" }
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        publishable_key,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::CheckoutConversionRate => {
+                CheckoutConversionRate
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
                         publishable_key,
                         filters,
                         granularity,
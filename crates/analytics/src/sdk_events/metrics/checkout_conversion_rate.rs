@@ -0,0 +1,129 @@
+use std::collections::HashSet;
+
+use api_models::analytics::{
+    sdk_events::{SdkEventDimensions, SdkEventFilters, SdkEventMetricsBucketIdentifier},
+    Granularity, TimeRange,
+};
+use common_utils::errors::ReportSwitchExt;
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use super::SdkEventMetricRow;
+use crate::{
+    query::{Aggregate, GroupByClause, QueryBuilder, QueryFilter, ToSql, Window},
+    types::{
+        AnalyticsCollection, AnalyticsDataSource, MetricsError, MetricsResult, SdkCheckoutScope,
+    },
+};
+
+#[derive(Default)]
+pub(super) struct CheckoutConversionRate;
+
+#[async_trait::async_trait]
+impl<T> super::SdkEventMetric<T> for CheckoutConversionRate
+where
+    T: AnalyticsDataSource + super::SdkEventMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[SdkEventDimensions],
+        merchant_id: &common_utils::id_type::MerchantId,
+        publishable_key: &str,
+        filters: &SdkEventFilters,
+        granularity: Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<HashSet<(SdkEventMetricsBucketIdentifier, SdkEventMetricRow)>> {
+        let mut query_builder: QueryBuilder<T> =
+            QueryBuilder::new(AnalyticsCollection::SdkCheckout(SdkCheckoutScope {
+                merchant_id: merchant_id.to_owned(),
+                publishable_key: publishable_key.to_string(),
+                time_range: *time_range,
+            }));
+        let dimensions = dimensions.to_vec();
+
+        for dim in dimensions.iter() {
+            query_builder.add_select_column(dim).switch()?;
+        }
+
+        query_builder
+            .add_select_column(Aggregate::Count {
+                field: None,
+                alias: Some("count"),
+            })
+            .switch()?;
+        query_builder
+            .add_select_column(Aggregate::Sum {
+                field: "converted",
+                alias: Some("total"),
+            })
+            .switch()?;
+
+        if let Some(granularity) = granularity {
+            query_builder
+                .add_granularity_in_mins(granularity)
+                .switch()?;
+        }
+
+        filters.set_filter_clause(&mut query_builder).switch()?;
+
+        query_builder
+            .add_filter_clause("merchant_id", publishable_key)
+            .switch()?;
+
+        query_builder
+            .add_bool_filter_clause("rendered", 1)
+            .switch()?;
+
+        time_range
+            .set_filter_clause(&mut query_builder)
+            .attach_printable("Error filtering time range")
+            .switch()?;
+
+        for dim in dimensions.iter() {
+            query_builder
+                .add_group_by_clause(dim)
+                .attach_printable("Error grouping by dimensions")
+                .switch()?;
+        }
+
+        if let Some(_granularity) = granularity.as_ref() {
+            query_builder
+                .add_group_by_clause("time_bucket")
+                .attach_printable("Error adding granularity")
+                .switch()?;
+        }
+
+        query_builder
+            .execute_query::<SdkEventMetricRow, _>(pool)
+            .await
+            .change_context(MetricsError::QueryBuildingError)?
+            .change_context(MetricsError::QueryExecutionFailure)?
+            .into_iter()
+            .map(|i| {
+                Ok((
+                    SdkEventMetricsBucketIdentifier::new(
+                        i.payment_method.clone(),
+                        i.platform.clone(),
+                        i.browser_name.clone(),
+                        i.source.clone(),
+                        i.component.clone(),
+                        i.payment_experience.clone(),
+                        i.locale.clone(),
+                        i.time_bucket.clone(),
+                    ),
+                    i,
+                ))
+            })
+            .collect::<error_stack::Result<
+                HashSet<(SdkEventMetricsBucketIdentifier, SdkEventMetricRow)>,
+                crate::query::PostProcessingError,
+            >>()
+            .change_context(MetricsError::PostProcessingFailure)
+    }
+}
@@ -32,6 +32,7 @@ where
     async fn load_metrics(
         &self,
         dimensions: &[SdkEventDimensions],
+        _merchant_id: &common_utils::id_type::MerchantId,
         publishable_key: &str,
         filters: &SdkEventFilters,
         granularity: Option<Granularity>,
@@ -115,6 +116,7 @@ where
                         i.source.clone(),
                         i.component.clone(),
                         i.payment_experience.clone(),
+                        i.locale.clone(),
                         i.time_bucket.clone(),
                     ),
                     i,
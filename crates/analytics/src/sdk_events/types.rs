@@ -45,6 +45,11 @@ where
                 )
                 .attach_printable("Error adding payment experience filter")?;
         }
+        if !self.locale.is_empty() {
+            builder
+                .add_filter_in_range_clause(SdkEventDimensions::Locale, &self.locale)
+                .attach_printable("Error adding locale filter")?;
+        }
         Ok(())
     }
 }
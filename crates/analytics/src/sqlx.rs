@@ -13,7 +13,7 @@ use common_utils::{
 };
 use diesel_models::enums::{
     AttemptStatus, AuthenticationType, Currency, FraudCheckStatus, IntentStatus, PaymentMethod,
-    RefundStatus,
+    PayoutStatus, PayoutType, RefundStatus,
 };
 use error_stack::ResultExt;
 use sqlx::{
@@ -103,6 +103,8 @@ db_type!(AuthenticationStatus);
 db_type!(TransactionStatus);
 db_type!(AuthenticationConnectors);
 db_type!(DecoupledAuthenticationType);
+db_type!(PayoutStatus);
+db_type!(PayoutType);
 
 impl<'q, Type> Encode<'q, Postgres> for DBEnumWrapper<Type>
 where
@@ -168,6 +170,10 @@ impl super::frm::metrics::FrmMetricAnalytics for SqlxClient {}
 impl super::frm::filters::FrmFilterAnalytics for SqlxClient {}
 impl super::auth_events::metrics::AuthEventMetricAnalytics for SqlxClient {}
 impl super::auth_events::filters::AuthEventFilterAnalytics for SqlxClient {}
+impl super::payouts::filters::PayoutFilterAnalytics for SqlxClient {}
+impl super::payouts::metrics::PayoutMetricAnalytics for SqlxClient {}
+impl super::connector_fees::filters::ConnectorFeeFilterAnalytics for SqlxClient {}
+impl super::connector_fees::metrics::ConnectorFeeMetricAnalytics for SqlxClient {}
 
 #[async_trait::async_trait]
 impl AnalyticsDataSource for SqlxClient {
@@ -345,6 +351,16 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::metrics::RefundMetricRow {
                 ColumnNotFound(_) => Ok(Default::default()),
                 e => Err(e),
             })?;
+        let payment_method: Option<String> =
+            row.try_get("payment_method").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let payment_method_type: Option<String> =
+            row.try_get("payment_method_type").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
         let total: Option<bigdecimal::BigDecimal> = row.try_get("total").or_else(|e| match e {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
@@ -353,6 +369,20 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::metrics::RefundMetricRow {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
         })?;
+        let refunded_count: Option<i64> = row.try_get("refunded_count").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let full_refund_count: Option<i64> =
+            row.try_get("full_refund_count").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let percentile: Option<bigdecimal::BigDecimal> =
+            row.try_get("percentile").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
         // Removing millisecond precision to get accurate diffs against clickhouse
         let start_bucket: Option<PrimitiveDateTime> = row
             .try_get::<Option<PrimitiveDateTime>, _>("start_bucket")?
@@ -368,8 +398,13 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::metrics::RefundMetricRow {
             profile_id,
             refund_reason,
             refund_error_message,
+            payment_method,
+            payment_method_type,
             total,
             count,
+            refunded_count,
+            full_refund_count,
+            percentile,
             start_bucket,
             end_bucket,
         })
@@ -483,6 +518,14 @@ impl<'a> FromRow<'a, PgRow> for super::payments::metrics::PaymentMetricRow {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
         })?;
+        let card_bin: Option<String> = row.try_get("card_bin").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let unified_code: Option<String> = row.try_get("unified_code").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
         let first_attempt: Option<bool> = row.try_get("first_attempt").or_else(|e| match e {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
@@ -517,6 +560,8 @@ impl<'a> FromRow<'a, PgRow> for super::payments::metrics::PaymentMetricRow {
             card_last_4,
             card_issuer,
             error_reason,
+            card_bin,
+            unified_code,
             first_attempt,
             total,
             count,
@@ -590,6 +635,14 @@ impl<'a> FromRow<'a, PgRow> for super::payments::distribution::PaymentDistributi
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
         })?;
+        let card_bin: Option<String> = row.try_get("card_bin").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let unified_code: Option<String> = row.try_get("unified_code").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
         let total: Option<bigdecimal::BigDecimal> = row.try_get("total").or_else(|e| match e {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
@@ -628,6 +681,8 @@ impl<'a> FromRow<'a, PgRow> for super::payments::distribution::PaymentDistributi
             card_last_4,
             card_issuer,
             error_reason,
+            card_bin,
+            unified_code,
             first_attempt,
             total,
             count,
@@ -702,6 +757,14 @@ impl<'a> FromRow<'a, PgRow> for super::payments::filters::PaymentFilterRow {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
         })?;
+        let card_bin: Option<String> = row.try_get("card_bin").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let unified_code: Option<String> = row.try_get("unified_code").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
         let first_attempt: Option<bool> = row.try_get("first_attempt").or_else(|e| match e {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
@@ -721,6 +784,8 @@ impl<'a> FromRow<'a, PgRow> for super::payments::filters::PaymentFilterRow {
             card_last_4,
             card_issuer,
             error_reason,
+            card_bin,
+            unified_code,
             first_attempt,
         })
     }
@@ -933,6 +998,16 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::filters::RefundFilterRow {
                 ColumnNotFound(_) => Ok(Default::default()),
                 e => Err(e),
             })?;
+        let payment_method: Option<String> =
+            row.try_get("payment_method").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let payment_method_type: Option<String> =
+            row.try_get("payment_method_type").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
         Ok(Self {
             currency,
             refund_status,
@@ -941,6 +1016,8 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::filters::RefundFilterRow {
             profile_id,
             refund_reason,
             refund_error_message,
+            payment_method,
+            payment_method_type,
         })
     }
 }
@@ -1117,6 +1194,229 @@ impl<'a> FromRow<'a, PgRow> for super::disputes::metrics::DisputeMetricRow {
     }
 }
 
+impl<'a> FromRow<'a, PgRow> for super::payouts::filters::PayoutFilterRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let connector: Option<String> = row.try_get("connector").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let payout_type: Option<DBEnumWrapper<PayoutType>> =
+            row.try_get("payout_type").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let status: Option<DBEnumWrapper<PayoutStatus>> =
+            row.try_get("status").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let destination_currency: Option<DBEnumWrapper<Currency>> =
+            row.try_get("destination_currency").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let profile_id: Option<String> = row.try_get("profile_id").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        Ok(Self {
+            connector,
+            payout_type,
+            status,
+            destination_currency,
+            profile_id,
+        })
+    }
+}
+
+impl<'a> FromRow<'a, PgRow> for super::payouts::metrics::PayoutMetricRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let connector: Option<String> = row.try_get("connector").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let payout_type: Option<DBEnumWrapper<PayoutType>> =
+            row.try_get("payout_type").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let status: Option<DBEnumWrapper<PayoutStatus>> =
+            row.try_get("status").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let destination_currency: Option<DBEnumWrapper<Currency>> =
+            row.try_get("destination_currency").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let profile_id: Option<String> = row.try_get("profile_id").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let total: Option<bigdecimal::BigDecimal> = row.try_get("total").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let count: Option<i64> = row.try_get("count").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let p50: Option<bigdecimal::BigDecimal> = row.try_get("p50").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let p90: Option<bigdecimal::BigDecimal> = row.try_get("p90").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        // Removing millisecond precision to get accurate diffs against clickhouse
+        let start_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("start_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        let end_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("end_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        Ok(Self {
+            connector,
+            payout_type,
+            status,
+            destination_currency,
+            profile_id,
+            total,
+            count,
+            p50,
+            p90,
+            start_bucket,
+            end_bucket,
+        })
+    }
+}
+
+impl<'a> FromRow<'a, PgRow> for super::connector_fees::filters::ConnectorFeeFilterRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let connector: Option<String> = row.try_get("connector").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let payment_method: Option<String> =
+            row.try_get("payment_method").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let payment_method_type: Option<String> =
+            row.try_get("payment_method_type").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let region: Option<String> = row.try_get("region").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let currency: Option<DBEnumWrapper<Currency>> =
+            row.try_get("currency").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let profile_id: Option<String> = row.try_get("profile_id").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        Ok(Self {
+            connector,
+            payment_method,
+            payment_method_type,
+            region,
+            currency,
+            profile_id,
+        })
+    }
+}
+
+impl<'a> FromRow<'a, PgRow> for super::connector_fees::metrics::ConnectorFeeMetricRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let connector: Option<String> = row.try_get("connector").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let payment_method: Option<String> =
+            row.try_get("payment_method").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let payment_method_type: Option<String> =
+            row.try_get("payment_method_type").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let region: Option<String> = row.try_get("region").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let currency: Option<DBEnumWrapper<Currency>> =
+            row.try_get("currency").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let profile_id: Option<String> = row.try_get("profile_id").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let interchange_fee: Option<bigdecimal::BigDecimal> =
+            row.try_get("interchange_fee").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let scheme_fee: Option<bigdecimal::BigDecimal> =
+            row.try_get("scheme_fee").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let markup_fee: Option<bigdecimal::BigDecimal> =
+            row.try_get("markup_fee").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let total_fee: Option<bigdecimal::BigDecimal> =
+            row.try_get("total_fee").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let settled_amount: Option<bigdecimal::BigDecimal> =
+            row.try_get("settled_amount").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let count: Option<i64> = row.try_get("count").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        // Removing millisecond precision to get accurate diffs against clickhouse
+        let start_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("start_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        let end_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("end_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        Ok(Self {
+            connector,
+            payment_method,
+            payment_method_type,
+            region,
+            currency,
+            profile_id,
+            interchange_fee,
+            scheme_fee,
+            markup_fee,
+            total_fee,
+            settled_amount,
+            count,
+            start_bucket,
+            end_bucket,
+        })
+    }
+}
+
 impl ToSql<SqlxClient> for PrimitiveDateTime {
     fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
         Ok(self.to_string())
@@ -1132,6 +1432,8 @@ impl ToSql<SqlxClient> for AnalyticsCollection {
             Self::Refund => Ok("refund".to_string()),
             Self::RefundSessionized => Err(error_stack::report!(ParsingError::UnknownError)
                 .attach_printable("RefundSessionized table is not implemented for Sqlx"))?,
+            Self::RefundPaymentAttempt(_) => Err(error_stack::report!(ParsingError::UnknownError)
+                .attach_printable("RefundPaymentAttempt table is not implemented for Sqlx"))?,
             Self::SdkEvents => Err(error_stack::report!(ParsingError::UnknownError)
                 .attach_printable("SdkEventsAudit table is not implemented for Sqlx"))?,
             Self::SdkEventsAnalytics => Err(error_stack::report!(ParsingError::UnknownError)
@@ -1157,6 +1459,12 @@ impl ToSql<SqlxClient> for AnalyticsCollection {
                 .attach_printable("DisputeSessionized table is not implemented for Sqlx"))?,
             Self::Authentications => Err(error_stack::report!(ParsingError::UnknownError)
                 .attach_printable("Authentications table is not implemented for Sqlx"))?,
+            Self::Payout => Err(error_stack::report!(ParsingError::UnknownError)
+                .attach_printable("Payout table is not implemented for Sqlx"))?,
+            Self::ConnectorSettlement(_) => Err(error_stack::report!(ParsingError::UnknownError)
+                .attach_printable("ConnectorSettlement table is not implemented for Sqlx"))?,
+            Self::SdkCheckout(_) => Err(error_stack::report!(ParsingError::UnknownError)
+                .attach_printable("SdkCheckout table is not implemented for Sqlx"))?,
         }
     }
 }
@@ -21,14 +21,18 @@ pub enum AnalyticsDomain {
     SdkEvents,
     ApiEvents,
     Dispute,
+    Payouts,
+    ConnectorFees,
 }
 
-#[derive(Debug, strum::AsRefStr, strum::Display, Clone, Copy)]
+#[derive(Debug, strum::AsRefStr, strum::Display, Clone)]
 pub enum AnalyticsCollection {
     Payment,
     PaymentSessionized,
     Refund,
     RefundSessionized,
+    /// The captured payment attempts, along with the successful refunds against them
+    RefundPaymentAttempt(AnalyticsJoinScope),
     FraudCheck,
     SdkEvents,
     SdkEventsAnalytics,
@@ -42,6 +46,28 @@ pub enum AnalyticsCollection {
     DisputeSessionized,
     ApiEventsAnalytics,
     ActivePaymentsAnalytics,
+    Payout,
+    /// The settled transactions of the connectors, along with the payment attempts they settle
+    ConnectorSettlement(AnalyticsJoinScope),
+    /// The checkouts of the SDK, along with the outcome of their payments
+    SdkCheckout(SdkCheckoutScope),
+}
+
+/// The organization, merchants or profiles and the time range which bound each of the tables
+/// joined to build a collection, so that the join does not read the whole tables
+#[derive(Debug, Clone)]
+pub struct AnalyticsJoinScope {
+    pub auth: crate::enums::AuthInfo,
+    pub time_range: api_models::analytics::TimeRange,
+}
+
+/// The merchant and the time range which bound both the SDK events and the payments joined to
+/// build the checkouts of the SDK
+#[derive(Debug, Clone)]
+pub struct SdkCheckoutScope {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub publishable_key: String,
+    pub time_range: api_models::analytics::TimeRange,
 }
 
 #[allow(dead_code)]
@@ -91,7 +117,7 @@ where
     where
         Self: LoadRow<T>;
 
-    fn get_table_engine(_table: AnalyticsCollection) -> TableEngine {
+    fn get_table_engine(_table: &AnalyticsCollection) -> TableEngine {
         TableEngine::BasicTree
     }
 }
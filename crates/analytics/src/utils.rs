@@ -1,10 +1,12 @@
 use api_models::analytics::{
     api_event::{ApiEventDimensions, ApiEventMetrics},
     auth_events::{AuthEventDimensions, AuthEventMetrics},
+    connector_fees::{ConnectorFeeDimensions, ConnectorFeeMetrics},
     disputes::{DisputeDimensions, DisputeMetrics},
     frm::{FrmDimensions, FrmMetrics},
     payment_intents::{PaymentIntentDimensions, PaymentIntentMetrics},
     payments::{PaymentDimensions, PaymentMetrics},
+    payouts::{PayoutDimensions, PayoutMetrics},
     refunds::{RefundDimensions, RefundMetrics},
     sdk_events::{SdkEventDimensions, SdkEventMetrics},
     NameDescription,
@@ -24,6 +26,9 @@ pub fn get_payment_dimensions() -> Vec<NameDescription> {
         PaymentDimensions::ProfileId,
         PaymentDimensions::CardNetwork,
         PaymentDimensions::MerchantId,
+        PaymentDimensions::CardIssuer,
+        PaymentDimensions::CardBin,
+        PaymentDimensions::UnifiedCode,
     ]
     .into_iter()
     .map(Into::into)
@@ -109,3 +114,19 @@ pub fn get_dispute_metrics_info() -> Vec<NameDescription> {
 pub fn get_dispute_dimensions() -> Vec<NameDescription> {
     DisputeDimensions::iter().map(Into::into).collect()
 }
+
+pub fn get_payout_metrics_info() -> Vec<NameDescription> {
+    PayoutMetrics::iter().map(Into::into).collect()
+}
+
+pub fn get_payout_dimensions() -> Vec<NameDescription> {
+    PayoutDimensions::iter().map(Into::into).collect()
+}
+
+pub fn get_connector_fee_metrics_info() -> Vec<NameDescription> {
+    ConnectorFeeMetrics::iter().map(Into::into).collect()
+}
+
+pub fn get_connector_fee_dimensions() -> Vec<NameDescription> {
+    ConnectorFeeDimensions::iter().map(Into::into).collect()
+}
@@ -273,7 +273,7 @@ pub enum CardTestingGuardStatus {
 
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 pub struct AuthenticationConnectorDetails {
-    /// List of authentication connectors
+    /// List of authentication connectors, in the order of preference. When an authentication connector errors or times out during the pre-authentication, the authentication falls back to the next connector in the list.
     #[schema(value_type = Vec<AuthenticationConnectors>)]
     pub authentication_connectors: Vec<common_enums::AuthenticationConnectors>,
     /// URL of the (customer service) website that will be shown to the shopper in case of technical errors during the 3D Secure 2 process.
@@ -564,6 +564,10 @@ pub struct MerchantAccountResponse {
     /// Product Type of this merchant account
     #[schema(value_type = Option<MerchantProductType>)]
     pub product_type: Option<api_enums::MerchantProductType>,
+
+    /// The lifecycle status of the merchant account
+    #[schema(value_type = MerchantAccountStatus, example = "active")]
+    pub status: api_enums::MerchantAccountStatus,
 }
 
 #[cfg(feature = "v2")]
@@ -600,6 +604,10 @@ pub struct MerchantAccountResponse {
     /// Product Type of this merchant account
     #[schema(value_type = Option<MerchantProductType>)]
     pub product_type: Option<api_enums::MerchantProductType>,
+
+    /// The lifecycle status of the merchant account
+    #[schema(value_type = MerchantAccountStatus, example = "active")]
+    pub status: api_enums::MerchantAccountStatus,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -694,6 +702,333 @@ pub struct MerchantAccountDeleteResponse {
     pub deleted: bool,
 }
 
+/// Request to update the lifecycle status of a merchant account
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantAccountStatusUpdateRequest {
+    /// The status to which the merchant account is moved. A merchant account is moved to the
+    /// `closing` status only by offboarding it.
+    #[schema(value_type = MerchantAccountStatus, example = "restricted")]
+    pub status: api_enums::MerchantAccountStatus,
+
+    /// The reason for the update of the status, sent along in the webhook
+    #[schema(max_length = 255, example = "Pending review of the chargeback ratio")]
+    pub reason: Option<String>,
+}
+
+/// An update of the lifecycle status of a merchant account, also sent as the content of the
+/// `merchant_account_status_updated` webhook
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+pub struct MerchantAccountStatusResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The current status of the merchant account
+    #[schema(value_type = MerchantAccountStatus, example = "restricted")]
+    pub status: api_enums::MerchantAccountStatus,
+
+    /// The status of the merchant account before the update
+    #[schema(value_type = Option<MerchantAccountStatus>, example = "active")]
+    pub previous_status: Option<api_enums::MerchantAccountStatus>,
+
+    /// The reason for the update of the status
+    #[schema(example = "Pending review of the chargeback ratio")]
+    pub reason: Option<String>,
+
+    /// The time at which the status was updated
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated_at: time::PrimitiveDateTime,
+}
+
+/// Request to offboard a merchant account. The merchant account is moved to the `closing` status,
+/// after which its pending payouts are drained and its data is exported.
+#[derive(Clone, Debug, Default, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantOffboardingRequest {
+    /// The reason for the offboarding, sent along in the webhook
+    #[schema(max_length = 255, example = "Contract terminated")]
+    pub reason: Option<String>,
+}
+
+/// The stage of the offboarding of a merchant account
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum MerchantOffboardingStatus {
+    /// The payouts which were not sent to the connectors are being cancelled, and those in flight
+    /// are awaited
+    DrainingPayouts,
+    /// The data of the merchant account is being exported
+    ExportingData,
+    /// The pending payouts were drained and the data was exported
+    Completed,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+pub struct MerchantOffboardingResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The stage of the offboarding
+    #[schema(example = "draining_payouts")]
+    pub status: MerchantOffboardingStatus,
+
+    /// The reason for the offboarding
+    #[schema(example = "Contract terminated")]
+    pub reason: Option<String>,
+
+    /// The number of payouts which were cancelled as they had not been sent to the connectors
+    #[schema(example = 2)]
+    pub cancelled_payouts_count: usize,
+
+    /// The number of payouts in flight with the connectors, awaited before the data is exported
+    #[schema(example = 1)]
+    pub pending_payouts_count: usize,
+
+    /// The keys of the exported files in the file storage
+    #[schema(example = json!(["offboarding/y3oqhf46pyzuxjbcn2giaqnb44/payments.csv"]))]
+    pub exported_files: Vec<String>,
+
+    /// The time at which the offboarding was started
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+
+    /// The time at which the offboarding was last updated
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+/// Request to backfill the connector transaction IDs of the payment attempts of a merchant created
+/// within a time range, for instance after an incident during which the responses of a connector
+/// were lost. The payments whose attempt has no connector transaction ID are synced with their
+/// connectors, at most `requests_per_minute` per minute, which repairs the lookups of the attempts
+/// by their connector transaction ID used by the webhooks and the disputes.
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorTransactionIdBackfillRequest {
+    /// The start of the time range of the creation of the payments
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T00:00:00Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub start_time: time::PrimitiveDateTime,
+
+    /// The end of the time range of the creation of the payments, which cannot be in the future
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-11T00:00:00Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub end_time: time::PrimitiveDateTime,
+
+    /// Only backfill the payments of this profile
+    #[schema(value_type = Option<String>, example = "pro_abcdefghijklmnopqrst")]
+    pub profile_id: Option<id_type::ProfileId>,
+
+    /// Only backfill the payments processed by this connector
+    #[schema(value_type = Option<Connector>, example = "stripe")]
+    pub connector: Option<api_enums::Connector>,
+
+    /// The maximum number of payments synced with the connectors per minute. Defaults to 60, and
+    /// cannot exceed 600.
+    #[schema(example = 60, maximum = 600)]
+    pub requests_per_minute: Option<u32>,
+}
+
+/// The stage of a backfill of connector transaction IDs
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ConnectorTransactionIdBackfillStatus {
+    /// The backfill is yet to be picked up by the scheduler
+    Queued,
+    /// The payments of the time range are being scanned and synced
+    Processing,
+    /// All the payments of the time range were scanned
+    Completed,
+    /// The scan of the payments kept failing, and was given up
+    Failed,
+}
+
+/// A payment whose sync with its connector failed during a backfill of connector transaction IDs
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+pub struct ConnectorTransactionIdBackfillFailure {
+    /// The identifier of the payment
+    #[schema(value_type = String, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: id_type::PaymentId,
+
+    /// The identifier of the payment attempt which has no connector transaction ID
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4_1")]
+    pub attempt_id: String,
+
+    /// The reason for which the sync failed
+    #[schema(example = "Payment sync failed")]
+    pub error_message: String,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+pub struct ConnectorTransactionIdBackfillResponse {
+    /// The identifier of the backfill
+    #[schema(example = "ctb_mbabizu24mvu3mela5njyhpit4")]
+    pub backfill_id: String,
+
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The stage of the backfill
+    #[schema(example = "processing")]
+    pub status: ConnectorTransactionIdBackfillStatus,
+
+    /// The start of the time range of the creation of the payments
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T00:00:00Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub start_time: time::PrimitiveDateTime,
+
+    /// The end of the time range of the creation of the payments
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-11T00:00:00Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub end_time: time::PrimitiveDateTime,
+
+    /// The profile whose payments are backfilled
+    #[schema(value_type = Option<String>, example = "pro_abcdefghijklmnopqrst")]
+    pub profile_id: Option<id_type::ProfileId>,
+
+    /// The connector whose payments are backfilled
+    #[schema(value_type = Option<Connector>, example = "stripe")]
+    pub connector: Option<api_enums::Connector>,
+
+    /// The maximum number of payments synced with the connectors per minute
+    #[schema(example = 60)]
+    pub requests_per_minute: u32,
+
+    /// The number of payments of the time range scanned so far
+    #[schema(example = 1200)]
+    pub scanned_count: u64,
+
+    /// The number of payments found with an attempt without a connector transaction ID
+    #[schema(example = 40)]
+    pub missing_count: u64,
+
+    /// The number of payments whose attempt got its connector transaction ID from the sync
+    #[schema(example = 35)]
+    pub repaired_count: u64,
+
+    /// The number of payments which were synced, but for which the connector returned no
+    /// transaction ID
+    #[schema(example = 2)]
+    pub unresolved_count: u64,
+
+    /// The number of payments which could not be synced as they are in a final status, in which
+    /// their status is not synced with the connectors
+    #[schema(example = 1)]
+    pub skipped_count: u64,
+
+    /// The number of payments whose sync with the connector failed
+    #[schema(example = 2)]
+    pub failed_count: u64,
+
+    /// The most recent payments whose sync with the connector failed, up to 100
+    pub failures: Vec<ConnectorTransactionIdBackfillFailure>,
+
+    /// The time at which the backfill was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+
+    /// The time at which the backfill was last updated
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ConnectorTransactionIdBackfillId {
+    pub merchant_id: id_type::MerchantId,
+    pub backfill_id: String,
+}
+
+/// Request to store the personal data of a merchant in a data residency region of the deployment.
+/// The customers and addresses of the merchant are copied to the database of the region, and its
+/// cards and network tokens to the locker of the region, after which they are only stored in the
+/// region. The rest of the data of the merchant stays in the database of the deployment, and the
+/// data keys of the merchant in the key manager of the deployment.
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct DataResidencyRequest {
+    /// The data residency region, as configured for the deployment
+    #[schema(example = "eu")]
+    pub region: String,
+}
+
+/// The stage of the migration of the personal data of a merchant to its data residency region
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DataResidencyStatus {
+    /// The personal data is being copied to the region, and is still read from and written to the
+    /// database and the locker of the deployment
+    Migrating,
+    /// The personal data is read from and written to the region, while the data left behind in
+    /// the database and the locker of the deployment is copied to the region and deleted
+    Finalizing,
+    /// The personal data is only stored in the region
+    Active,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+pub struct DataResidencyResponse {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The data residency region of the merchant
+    #[schema(example = "eu")]
+    pub region: String,
+
+    /// The stage of the migration of the personal data of the merchant to the region
+    #[schema(example = "migrating")]
+    pub status: DataResidencyStatus,
+
+    /// Whether the migration was given up after its retries were exhausted. Requesting the data
+    /// residency of the merchant in the same region again resumes it.
+    #[schema(example = false)]
+    pub is_migration_failed: bool,
+
+    /// The number of passes over the personal data of the merchant made by the migration. Each
+    /// pass copies the data updated since the start of the previous pass, until a pass copies
+    /// nothing.
+    #[schema(example = 2)]
+    pub pass_count: u32,
+
+    /// The number of customers copied to the region
+    #[schema(example = 1200)]
+    pub copied_customers_count: u64,
+
+    /// The number of addresses copied to the region
+    #[schema(example = 2400)]
+    pub copied_addresses_count: u64,
+
+    /// The number of cards and network tokens copied to the locker of the region
+    #[schema(example = 800)]
+    pub copied_locker_entries_count: u64,
+
+    /// The number of cards and network tokens which could not be copied to the locker of the
+    /// region
+    #[schema(example = 0)]
+    pub failed_locker_entries_count: u64,
+
+    /// The time at which the data residency was requested
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+
+    /// The time at which the migration was last updated
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: time::PrimitiveDateTime,
+}
+
 #[derive(Default, Debug, Deserialize, Serialize)]
 pub struct MerchantId {
     pub merchant_id: id_type::MerchantId,
@@ -923,6 +1258,13 @@ pub struct MerchantConnectorCreate {
     /// The connector_wallets_details is used to store wallet details such as certificates and wallet credentials
     #[schema(value_type = Option<ConnectorWalletDetails>)]
     pub connector_wallets_details: Option<ConnectorWalletDetails>,
+
+    /// The time at which the credentials in `connector_account_details` expire, if known. The
+    /// connector is excluded from routing once they have expired, and webhooks are sent ahead of
+    /// the expiry as a reminder to rotate them.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub credentials_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v1")]
@@ -1052,6 +1394,10 @@ pub struct MerchantConnectorWebhookDetails {
     pub merchant_secret: Secret<String>,
     #[schema(value_type = String, example = "12345678900987654321")]
     pub additional_secret: Option<Secret<String>>,
+    /// Whether the webhooks of the connector are accepted only from the IP ranges published by
+    /// the connector
+    #[schema(default = false, example = true)]
+    pub enforce_source_ip_verification: Option<bool>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize, ToSchema)]
@@ -1529,6 +1875,13 @@ pub struct MerchantConnectorUpdate {
     /// The connector_wallets_details is used to store wallet details such as certificates and wallet credentials
     #[schema(value_type = Option<ConnectorWalletDetails>)]
     pub connector_wallets_details: Option<ConnectorWalletDetails>,
+
+    /// The time at which the credentials in `connector_account_details` expire, if known. The
+    /// connector is excluded from routing once they have expired, and webhooks are sent ahead of
+    /// the expiry as a reminder to rotate them.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub credentials_expires_at: Option<time::PrimitiveDateTime>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -1749,6 +2102,11 @@ pub struct MerchantConnectorDeleteResponse {
     /// If the connector is deleted or not
     #[schema(example = false)]
     pub deleted: bool,
+    /// The time until which the deleted connector can be restored. The connector is disabled on its
+    /// deletion, and is kept for the payments which were made through it
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub restorable_until: Option<time::PrimitiveDateTime>,
 }
 
 #[cfg(feature = "v2")]
@@ -1818,6 +2176,45 @@ pub struct ToggleAllKVResponse {
     pub kv_enabled: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct KvPgDivergencesListRequest {
+    /// Only the divergences of the entries of this merchant are listed, if provided
+    #[schema(value_type = Option<String>)]
+    pub merchant_id: Option<id_type::MerchantId>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct KvPgDivergence {
+    /// The table of the entry
+    #[schema(example = "payment_attempt")]
+    pub table: String,
+    /// The identifier for the merchant of the entry
+    #[schema(value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+    /// The identifier of the entry, such as the attempt id or the refund id
+    pub object_id: String,
+    /// The field whose value differs between the KV store and Postgres
+    #[schema(example = "status")]
+    pub field: String,
+    /// The value of the field in the KV store
+    pub kv_value: String,
+    /// The value of the field in Postgres
+    pub pg_value: String,
+    /// The time at which the divergence was found by the drainer
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub detected_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct KvPgDivergencesListResponse {
+    /// Total number of divergences listed
+    #[schema(example = 1)]
+    pub total_count: usize,
+    /// The divergences between the KV store and Postgres found by the drainer, latest first
+    pub divergences: Vec<KvPgDivergence>,
+}
+
 /// Merchant connector details used to make payments.
 #[derive(Debug, Clone, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct MerchantConnectorDetailsWrap {
@@ -2258,6 +2655,11 @@ pub struct ProfileResponse {
 
     /// Indicates if 3ds challenge is forced
     pub force_3ds_challenge: bool,
+
+    /// Indicates if the profile is in test mode
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schema(example = false)]
+    pub test_mode: Option<bool>,
 }
 
 #[cfg(feature = "v2")]
@@ -2992,3 +3394,327 @@ impl std::ops::Deref for TtlForExtendedCardInfo {
         &self.0
     }
 }
+
+/// A scheduled maintenance window of a merchant connector account. The connector is excluded
+/// from routing for the affected payment methods while the window is active.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorMaintenanceWindow {
+    /// The time at which the maintenance window starts
+    #[schema(value_type = String, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub start_time: time::PrimitiveDateTime,
+
+    /// The time at which the maintenance window ends
+    #[schema(value_type = String, example = "2022-09-10T12:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub end_time: time::PrimitiveDateTime,
+
+    /// The payment methods affected by the maintenance. All the payment methods of the connector
+    /// are affected if not provided.
+    #[schema(value_type = Option<Vec<PaymentMethod>>, example = json!(["card"]))]
+    pub payment_methods: Option<Vec<api_enums::PaymentMethod>>,
+
+    /// A description of the maintenance
+    #[schema(example = "Scheduled maintenance of the processor")]
+    pub description: Option<String>,
+}
+
+impl ConnectorMaintenanceWindow {
+    /// Returns `true` if the window is active at the given time for the payment method. If the
+    /// payment method is not known, the window is considered to affect it.
+    pub fn is_active_for(
+        &self,
+        time: time::PrimitiveDateTime,
+        payment_method: Option<api_enums::PaymentMethod>,
+    ) -> bool {
+        let is_payment_method_affected = match (&self.payment_methods, payment_method) {
+            (Some(payment_methods), Some(payment_method)) => {
+                payment_methods.contains(&payment_method)
+            }
+            _ => true,
+        };
+
+        is_payment_method_affected && self.start_time <= time && time < self.end_time
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorMaintenanceWindowsRequest {
+    /// The maintenance windows of the merchant connector account. This replaces the existing
+    /// maintenance windows.
+    pub windows: Vec<ConnectorMaintenanceWindow>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct ConnectorMaintenanceWindowsResponse {
+    /// The identifier for the merchant connector account
+    #[schema(value_type = String, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+
+    /// The maintenance windows of the merchant connector account which have not ended yet
+    pub windows: Vec<ConnectorMaintenanceWindow>,
+}
+
+/// The overrides of the connector configuration template for a business profile
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantConnectorProfileOverride {
+    /// The identifier for the business profile
+    #[schema(max_length = 64, value_type = String, example = "pro_abcdefghijklmnop")]
+    pub profile_id: id_type::ProfileId,
+
+    /// The credentials of the connector account for the profile, overriding the
+    /// `connector_account_details` of the template
+    #[schema(value_type = Option<MerchantConnectorDetails>, example = json!({ "auth_type": "HeaderKey", "api_key": "Basic MyVerySecretApiKey" }))]
+    pub connector_account_details: Option<pii::SecretSerdeValue>,
+
+    /// The label of the connector account for the profile, overriding the `connector_label` of the
+    /// template
+    #[schema(example = "stripe_US_travel")]
+    pub connector_label: Option<String>,
+
+    /// The metadata of the connector account for the profile, overriding the `metadata` of the
+    /// template
+    #[schema(value_type = Option<Object>, example = json!({ "city": "NY", "unit": "245" }))]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+/// Apply a connector configuration template to many business profiles of the merchant account at
+/// once. The merchant connector account of the connector is created in the profiles which do not
+/// have one, and updated in the profiles which do.
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantConnectorBulkConfigRequest {
+    /// The configuration of the merchant connector account applied to every profile. Its
+    /// `profile_id` and `merchant_connector_id` are ignored.
+    pub template: MerchantConnectorCreate,
+
+    /// The profiles to which the template is applied, along with their overrides of the template
+    pub profiles: Vec<MerchantConnectorProfileOverride>,
+
+    /// Only validate the configuration of every profile and report the changes which would be
+    /// made, without making them
+    #[serde(default)]
+    #[schema(default = false, example = true)]
+    pub dry_run: bool,
+}
+
+/// The change made to the merchant connector account of a profile by a bulk configuration
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, strum::Display, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum MerchantConnectorBulkConfigAction {
+    /// The merchant connector account is created in the profile
+    Create,
+    /// The existing merchant connector account of the profile is updated
+    Update,
+}
+
+/// The outcome of a bulk configuration for a profile
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, strum::Display, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum MerchantConnectorBulkConfigStatus {
+    /// The configuration of the profile is valid, and no change was made as it was a dry run
+    Validated,
+    /// The configuration was applied to the profile
+    Succeeded,
+    /// The configuration could not be applied to the profile
+    Failed,
+}
+
+/// The result of a bulk configuration for a profile
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct MerchantConnectorBulkConfigResult {
+    /// The identifier for the business profile
+    #[schema(max_length = 64, value_type = String, example = "pro_abcdefghijklmnop")]
+    pub profile_id: id_type::ProfileId,
+
+    /// The change made, or which would be made, to the merchant connector account of the
+    /// profile. Absent if the profile could not be found.
+    pub action: Option<MerchantConnectorBulkConfigAction>,
+
+    /// The outcome of the configuration for the profile
+    pub status: MerchantConnectorBulkConfigStatus,
+
+    /// The identifier for the merchant connector account of the profile, absent if it does not
+    /// exist yet
+    #[schema(value_type = Option<String>, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+
+    /// The reason for which the configuration could not be applied to the profile
+    pub error_message: Option<String>,
+}
+
+/// The per profile report of a bulk configuration of merchant connector accounts
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct MerchantConnectorBulkConfigResponse {
+    /// Name of the Connector
+    #[schema(value_type = Connector, example = "stripe")]
+    pub connector_name: api_enums::Connector,
+
+    /// Whether the configuration was only validated, without making any change
+    pub dry_run: bool,
+
+    /// The number of profiles for which the configuration failed
+    pub failed_count: usize,
+
+    /// The results of the configuration, in the order of the profiles in the request
+    pub results: Vec<MerchantConnectorBulkConfigResult>,
+}
+
+/// The state of the credentials of a merchant connector account
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, strum::Display, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ConnectorCredentialsStatus {
+    /// The credentials do not expire, or their expiry is not known
+    Active,
+    /// The credentials expire within the reminder period and have to be rotated
+    ExpiringSoon,
+    /// The credentials have expired, and the connector is excluded from routing
+    Expired,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorCredentialsUpdateRequest {
+    /// The time at which the current credentials of the merchant connector account expire. The
+    /// expiry is cleared if not provided.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<time::PrimitiveDateTime>,
+}
+
+/// The metadata of the credentials of a merchant connector account
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct ConnectorCredentialsResponse {
+    /// The identifier for the merchant connector account
+    #[schema(value_type = String, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: id_type::MerchantConnectorAccountId,
+
+    /// The name of the connector
+    #[schema(example = "stripe")]
+    pub connector_name: String,
+
+    /// The label of the merchant connector account
+    #[schema(example = "stripe_US_travel")]
+    pub connector_label: Option<String>,
+
+    /// The profile of the merchant connector account
+    #[schema(value_type = String, example = "pro_abcdefghijklmnop")]
+    pub profile_id: id_type::ProfileId,
+
+    /// The state of the credentials
+    pub status: ConnectorCredentialsStatus,
+
+    /// The time at which the current credentials were set, not known for the credentials set
+    /// before their metadata was tracked
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_at: Option<time::PrimitiveDateTime>,
+
+    /// The time at which the current credentials expire, if known
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<time::PrimitiveDateTime>,
+
+    /// The time at which the current credentials were last verified with the connector
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub last_verified_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, utoipa::IntoParams)]
+#[serde(deny_unknown_fields)]
+pub struct ExpiringConnectorCredentialsRequest {
+    /// The number of days within which the credentials expire, including the credentials which
+    /// have already expired. Defaults to the reminder period of the credentials.
+    #[param(example = 30)]
+    pub within_days: Option<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ProfileTestModeRequest {
+    /// Whether the profile is in test mode. The payments of a profile in test mode can only be
+    /// made through its test merchant connector accounts, and test API Keys can only be bound to
+    /// profiles in test mode.
+    #[schema(example = true)]
+    pub test_mode: bool,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct ProfileTestModeResponse {
+    /// The identifier for the profile
+    #[schema(value_type = String, max_length = 64, example = "pro_abcdefghijklmnop")]
+    pub profile_id: id_type::ProfileId,
+
+    /// Whether the profile is in test mode
+    #[schema(example = true)]
+    pub test_mode: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TestDataPurgeRequest {
+    /// The payments and the customers of the profile created more than these many days ago are
+    /// deleted
+    #[schema(minimum = 1, example = 30)]
+    pub older_than_days: u16,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct TestDataPurgeResponse {
+    /// The identifier for the profile
+    #[schema(value_type = String, max_length = 64, example = "pro_abcdefghijklmnop")]
+    pub profile_id: id_type::ProfileId,
+
+    /// The number of payments deleted, along with their attempts and refunds
+    #[schema(example = 42)]
+    pub payments_deleted: usize,
+
+    /// The number of customers deleted. A customer is deleted only if it has no payments left and
+    /// no saved payment methods.
+    #[schema(example = 7)]
+    pub customers_deleted: usize,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct ProfileCoverageResponse {
+    /// The identifier for the profile
+    #[schema(value_type = String, max_length = 64, example = "pro_abcdefghijklmnop")]
+    pub profile_id: id_type::ProfileId,
+
+    /// The payment methods that can be accepted through the merchant connector accounts of the
+    /// profile, along with the currencies and the countries they can be accepted in
+    pub coverage: Vec<PaymentMethodCoverage>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct PaymentMethodCoverage {
+    /// The payment method
+    #[schema(value_type = PaymentMethod, example = "card")]
+    pub payment_method: common_enums::PaymentMethod,
+
+    /// The type of the payment method
+    #[schema(value_type = PaymentMethodType, example = "credit")]
+    pub payment_method_type: common_enums::PaymentMethodType,
+
+    /// The currencies the payment method can be accepted in
+    pub currencies: Vec<CurrencyCoverage>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct CurrencyCoverage {
+    /// The currency
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: common_enums::Currency,
+
+    /// The billing countries the payment method can be accepted from in this currency. The payment
+    /// method can be accepted from any country when this is not present.
+    #[schema(value_type = Option<Vec<CountryAlpha2>>, example = json!(["US", "CA"]))]
+    pub countries: Option<Vec<common_enums::CountryAlpha2>>,
+}
@@ -8,10 +8,12 @@ use self::{
     active_payments::ActivePaymentsMetrics,
     api_event::{ApiEventDimensions, ApiEventMetrics},
     auth_events::{AuthEventDimensions, AuthEventFilters, AuthEventMetrics},
+    connector_fees::{ConnectorFeeDimensions, ConnectorFeeMetrics},
     disputes::{DisputeDimensions, DisputeMetrics},
     frm::{FrmDimensions, FrmMetrics},
     payment_intents::{PaymentIntentDimensions, PaymentIntentMetrics},
     payments::{PaymentDimensions, PaymentDistributions, PaymentMetrics},
+    payouts::{PayoutDimensions, PayoutMetrics},
     refunds::{RefundDimensions, RefundDistributions, RefundMetrics},
     sdk_events::{SdkEventDimensions, SdkEventMetrics},
 };
@@ -19,11 +21,13 @@ pub mod active_payments;
 pub mod api_event;
 pub mod auth_events;
 pub mod connector_events;
+pub mod connector_fees;
 pub mod disputes;
 pub mod frm;
 pub mod outgoing_webhook_event;
 pub mod payment_intents;
 pub mod payments;
+pub mod payouts;
 pub mod refunds;
 pub mod sdk_events;
 pub mod search;
@@ -400,6 +404,21 @@ pub struct SdkEventFilterValue {
 pub struct DisputesAnalyticsMetadata {
     pub total_disputed_amount: Option<u64>,
     pub total_dispute_lost_amount: Option<u64>,
+    pub total_dispute_held_amount: Option<u64>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct PayoutsAnalyticsMetadata {
+    pub total_payout_success_rate: Option<f64>,
+    pub total_payout_processed_amount: Option<u64>,
+    pub total_payout_processed_count: Option<u64>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ConnectorFeesAnalyticsMetadata {
+    pub total_fee: Option<u64>,
+    pub total_settled_amount: Option<u64>,
+    pub total_effective_fee_rate: Option<f64>,
 }
 #[derive(Debug, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -434,6 +453,20 @@ pub struct DisputesMetricsResponse<T> {
     pub query_data: Vec<T>,
     pub meta_data: [DisputesAnalyticsMetadata; 1],
 }
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutsMetricsResponse<T> {
+    pub query_data: Vec<T>,
+    pub meta_data: [PayoutsAnalyticsMetadata; 1],
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectorFeesMetricsResponse<T> {
+    pub query_data: Vec<T>,
+    pub meta_data: [ConnectorFeesAnalyticsMetadata; 1],
+}
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetApiEventFiltersRequest {
@@ -504,6 +537,76 @@ pub struct GetDisputeMetricRequest {
     pub delta: bool,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPayoutFilterRequest {
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<PayoutDimensions>,
+}
+
+#[derive(Debug, Default, serde::Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutFiltersResponse {
+    pub query_data: Vec<PayoutFilterValue>,
+}
+
+#[derive(Debug, serde::Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PayoutFilterValue {
+    pub dimension: PayoutDimensions,
+    pub values: Vec<String>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetPayoutMetricRequest {
+    pub time_series: Option<TimeSeries>,
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<PayoutDimensions>,
+    #[serde(default)]
+    pub filters: payouts::PayoutFilters,
+    pub metrics: HashSet<PayoutMetrics>,
+    #[serde(default)]
+    pub delta: bool,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetConnectorFeeFilterRequest {
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<ConnectorFeeDimensions>,
+}
+
+#[derive(Debug, Default, serde::Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectorFeeFiltersResponse {
+    pub query_data: Vec<ConnectorFeeFilterValue>,
+}
+
+#[derive(Debug, serde::Serialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectorFeeFilterValue {
+    pub dimension: ConnectorFeeDimensions,
+    pub values: Vec<String>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetConnectorFeeMetricRequest {
+    pub time_series: Option<TimeSeries>,
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<ConnectorFeeDimensions>,
+    #[serde(default)]
+    pub filters: connector_fees::ConnectorFeeFilters,
+    pub metrics: HashSet<ConnectorFeeMetrics>,
+    #[serde(default)]
+    pub delta: bool,
+}
+
 #[derive(Clone, Debug, Default, serde::Serialize)]
 #[serde(rename_all = "snake_case")]
 pub struct SankeyResponse {
@@ -514,6 +617,44 @@ pub struct SankeyResponse {
     pub first_attempt: i64,
 }
 
+/// Snapshot of the payments of the merchant, shown on the home page of the dashboard
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PaymentSummaryResponse {
+    pub last_24_hours: PaymentSummary,
+    pub last_7_days: PaymentSummary,
+    /// The time at which the snapshot was computed
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub computed_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PaymentSummary {
+    pub payment_count: u64,
+    pub payment_success_count: u64,
+    pub payment_success_rate: Option<f64>,
+    /// The processed amount in USD, available only if currency conversion is enabled
+    pub payment_processed_amount_in_usd: Option<u64>,
+    pub top_decline_reasons: Vec<DeclineReasonSummary>,
+    pub top_connectors: Vec<ConnectorSummary>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct DeclineReasonSummary {
+    pub error_reason: String,
+    pub count: u64,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct ConnectorSummary {
+    pub connector: String,
+    pub payment_count: u64,
+    pub payment_success_rate: Option<f64>,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetAuthEventFilterRequest {
@@ -0,0 +1,173 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use common_utils::id_type;
+
+use super::{NameDescription, TimeRange};
+use crate::enums::{Currency, PaymentMethod, PaymentMethodType};
+
+#[derive(
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::AsRefStr,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectorFeeMetrics {
+    /// Fees charged by the connectors, broken down into interchange, scheme and markup fees
+    ConnectorFees,
+    /// Fees charged by the connectors per settled transaction, and as a share of the settled volume
+    EffectiveCost,
+}
+
+#[derive(
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::AsRefStr,
+    PartialEq,
+    PartialOrd,
+    Eq,
+    Ord,
+    strum::Display,
+    strum::EnumIter,
+    Clone,
+    Copy,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ConnectorFeeDimensions {
+    // Do not change the order of these enums
+    // Consult the Dashboard FE folks since these also affects the order of metrics on FE
+    Connector,
+    PaymentMethod,
+    PaymentMethodType,
+    /// The region of the transaction as reported by the connector in its settlement file
+    Region,
+    Currency,
+    ProfileId,
+}
+
+impl From<ConnectorFeeDimensions> for NameDescription {
+    fn from(value: ConnectorFeeDimensions) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+impl From<ConnectorFeeMetrics> for NameDescription {
+    fn from(value: ConnectorFeeMetrics) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct ConnectorFeeFilters {
+    #[serde(default)]
+    pub connector: Vec<String>,
+    #[serde(default)]
+    pub payment_method: Vec<PaymentMethod>,
+    #[serde(default)]
+    pub payment_method_type: Vec<PaymentMethodType>,
+    #[serde(default)]
+    pub region: Vec<String>,
+    #[serde(default)]
+    pub currency: Vec<Currency>,
+    #[serde(default)]
+    pub profile_id: Vec<id_type::ProfileId>,
+}
+
+#[derive(Debug, serde::Serialize, Eq)]
+pub struct ConnectorFeeMetricsBucketIdentifier {
+    pub connector: Option<String>,
+    pub payment_method: Option<String>,
+    pub payment_method_type: Option<String>,
+    pub region: Option<String>,
+    pub currency: Option<Currency>,
+    pub profile_id: Option<String>,
+    #[serde(rename = "time_range")]
+    pub time_bucket: TimeRange,
+    #[serde(rename = "time_bucket")]
+    #[serde(with = "common_utils::custom_serde::iso8601custom")]
+    pub start_time: time::PrimitiveDateTime,
+}
+
+impl Hash for ConnectorFeeMetricsBucketIdentifier {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.connector.hash(state);
+        self.payment_method.hash(state);
+        self.payment_method_type.hash(state);
+        self.region.hash(state);
+        self.currency.hash(state);
+        self.profile_id.hash(state);
+        self.time_bucket.hash(state);
+    }
+}
+impl PartialEq for ConnectorFeeMetricsBucketIdentifier {
+    fn eq(&self, other: &Self) -> bool {
+        let mut left = DefaultHasher::new();
+        self.hash(&mut left);
+        let mut right = DefaultHasher::new();
+        other.hash(&mut right);
+        left.finish() == right.finish()
+    }
+}
+
+impl ConnectorFeeMetricsBucketIdentifier {
+    pub fn new(
+        connector: Option<String>,
+        payment_method: Option<String>,
+        payment_method_type: Option<String>,
+        region: Option<String>,
+        currency: Option<Currency>,
+        profile_id: Option<String>,
+        normalized_time_range: TimeRange,
+    ) -> Self {
+        Self {
+            connector,
+            payment_method,
+            payment_method_type,
+            region,
+            currency,
+            profile_id,
+            time_bucket: normalized_time_range,
+            start_time: normalized_time_range.start_time,
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ConnectorFeeMetricsBucketValue {
+    pub interchange_fee: Option<u64>,
+    pub scheme_fee: Option<u64>,
+    pub markup_fee: Option<u64>,
+    pub total_fee: Option<u64>,
+    /// Volume of the settled transactions on which the fees were charged
+    pub settled_amount: Option<u64>,
+    pub settled_count: Option<u64>,
+    /// Average fee charged per settled transaction
+    pub cost_per_transaction: Option<f64>,
+    /// Fees charged as a percentage of the settled volume
+    pub effective_fee_rate: Option<f64>,
+}
+#[derive(Debug, serde::Serialize)]
+pub struct ConnectorFeeMetricsBucketResponse {
+    #[serde(flatten)]
+    pub values: ConnectorFeeMetricsBucketValue,
+    #[serde(flatten)]
+    pub dimensions: ConnectorFeeMetricsBucketIdentifier,
+}
@@ -24,15 +24,17 @@ pub enum DisputeMetrics {
     DisputeStatusMetric,
     TotalAmountDisputed,
     TotalDisputeLostAmount,
+    TotalDisputeHeldAmount,
     SessionizedDisputeStatusMetric,
     SessionizedTotalAmountDisputed,
     SessionizedTotalDisputeLostAmount,
+    SessionizedTotalDisputeHeldAmount,
 }
 impl ForexMetric for DisputeMetrics {
     fn is_forex_metric(&self) -> bool {
         matches!(
             self,
-            Self::TotalAmountDisputed | Self::TotalDisputeLostAmount
+            Self::TotalAmountDisputed | Self::TotalDisputeLostAmount | Self::TotalDisputeHeldAmount
         )
     }
 }
@@ -143,6 +145,7 @@ pub struct DisputeMetricsBucketValue {
     pub disputes_lost: Option<u64>,
     pub disputed_amount: Option<u64>,
     pub dispute_lost_amount: Option<u64>,
+    pub dispute_held_amount: Option<u64>,
     pub total_dispute: Option<u64>,
 }
 #[derive(Debug, serde::Serialize)]
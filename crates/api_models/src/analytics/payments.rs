@@ -42,6 +42,10 @@ pub struct PaymentFilters {
     #[serde(default)]
     pub error_reason: Vec<String>,
     #[serde(default)]
+    pub card_bin: Vec<String>,
+    #[serde(default)]
+    pub unified_code: Vec<String>,
+    #[serde(default)]
     pub first_attempt: Vec<bool>,
 }
 
@@ -84,6 +88,11 @@ pub enum PaymentDimensions {
     CardLast4,
     CardIssuer,
     ErrorReason,
+    /// Bank identification number of the card, from the enrichment of the card details
+    CardBin,
+    /// Unified code of the error of the payment, which is the category of the decline across the
+    /// connectors
+    UnifiedCode,
 }
 
 #[derive(
@@ -200,6 +209,8 @@ pub struct PaymentMetricsBucketIdentifier {
     pub card_last_4: Option<String>,
     pub card_issuer: Option<String>,
     pub error_reason: Option<String>,
+    pub card_bin: Option<String>,
+    pub unified_code: Option<String>,
     #[serde(rename = "time_range")]
     pub time_bucket: TimeRange,
     // Coz FE sucks
@@ -225,6 +236,8 @@ impl PaymentMetricsBucketIdentifier {
         card_last_4: Option<String>,
         card_issuer: Option<String>,
         error_reason: Option<String>,
+        card_bin: Option<String>,
+        unified_code: Option<String>,
         normalized_time_range: TimeRange,
     ) -> Self {
         Self {
@@ -242,6 +255,8 @@ impl PaymentMetricsBucketIdentifier {
             card_last_4,
             card_issuer,
             error_reason,
+            card_bin,
+            unified_code,
             time_bucket: normalized_time_range,
             start_time: normalized_time_range.start_time,
         }
@@ -264,6 +279,8 @@ impl Hash for PaymentMetricsBucketIdentifier {
         self.card_last_4.hash(state);
         self.card_issuer.hash(state);
         self.error_reason.hash(state);
+        self.card_bin.hash(state);
+        self.unified_code.hash(state);
         self.time_bucket.hash(state);
     }
 }
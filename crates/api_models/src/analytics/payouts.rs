@@ -0,0 +1,170 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use common_utils::id_type;
+
+use super::{NameDescription, TimeRange};
+use crate::enums::{Currency, PayoutStatus, PayoutType};
+
+#[derive(
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::AsRefStr,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PayoutMetrics {
+    PayoutCount,
+    PayoutSuccessRate,
+    PayoutProcessedAmount,
+    /// Average and percentiles of the time taken by the successful payouts to be processed
+    PayoutProcessingTime,
+}
+
+#[derive(
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::AsRefStr,
+    PartialEq,
+    PartialOrd,
+    Eq,
+    Ord,
+    strum::Display,
+    strum::EnumIter,
+    Clone,
+    Copy,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutDimensions {
+    // Do not change the order of these enums
+    // Consult the Dashboard FE folks since these also affects the order of metrics on FE
+    Connector,
+    PayoutType,
+    #[strum(serialize = "status")]
+    #[serde(rename = "status")]
+    PayoutStatus,
+    DestinationCurrency,
+    ProfileId,
+}
+
+impl From<PayoutDimensions> for NameDescription {
+    fn from(value: PayoutDimensions) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+impl From<PayoutMetrics> for NameDescription {
+    fn from(value: PayoutMetrics) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct PayoutFilters {
+    #[serde(default)]
+    pub connector: Vec<String>,
+    #[serde(default)]
+    pub payout_type: Vec<PayoutType>,
+    #[serde(default)]
+    pub status: Vec<PayoutStatus>,
+    #[serde(default)]
+    pub destination_currency: Vec<Currency>,
+    #[serde(default)]
+    pub profile_id: Vec<id_type::ProfileId>,
+}
+
+#[derive(Debug, serde::Serialize, Eq)]
+pub struct PayoutMetricsBucketIdentifier {
+    pub connector: Option<String>,
+    pub payout_type: Option<String>,
+    #[serde(rename = "status")]
+    pub payout_status: Option<String>,
+    pub destination_currency: Option<Currency>,
+    pub profile_id: Option<String>,
+    #[serde(rename = "time_range")]
+    pub time_bucket: TimeRange,
+    #[serde(rename = "time_bucket")]
+    #[serde(with = "common_utils::custom_serde::iso8601custom")]
+    pub start_time: time::PrimitiveDateTime,
+}
+
+impl Hash for PayoutMetricsBucketIdentifier {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.connector.hash(state);
+        self.payout_type.hash(state);
+        self.payout_status.hash(state);
+        self.destination_currency.hash(state);
+        self.profile_id.hash(state);
+        self.time_bucket.hash(state);
+    }
+}
+impl PartialEq for PayoutMetricsBucketIdentifier {
+    fn eq(&self, other: &Self) -> bool {
+        let mut left = DefaultHasher::new();
+        self.hash(&mut left);
+        let mut right = DefaultHasher::new();
+        other.hash(&mut right);
+        left.finish() == right.finish()
+    }
+}
+
+impl PayoutMetricsBucketIdentifier {
+    pub fn new(
+        connector: Option<String>,
+        payout_type: Option<String>,
+        payout_status: Option<String>,
+        destination_currency: Option<Currency>,
+        profile_id: Option<String>,
+        normalized_time_range: TimeRange,
+    ) -> Self {
+        Self {
+            connector,
+            payout_type,
+            payout_status,
+            destination_currency,
+            profile_id,
+            time_bucket: normalized_time_range,
+            start_time: normalized_time_range.start_time,
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct PayoutMetricsBucketValue {
+    pub payout_count: Option<u64>,
+    pub successful_payouts: Option<u64>,
+    pub total_payouts: Option<u64>,
+    pub payout_success_rate: Option<f64>,
+    pub payout_processed_amount: Option<u64>,
+    pub payout_processed_count: Option<u64>,
+    /// Average time in seconds taken by the successful payouts to be processed
+    pub avg_payout_processing_time: Option<f64>,
+    /// Median of the time in seconds taken by the successful payouts to be processed
+    pub p50_payout_processing_time: Option<f64>,
+    /// 90th percentile of the time in seconds taken by the successful payouts to be processed
+    pub p90_payout_processing_time: Option<f64>,
+}
+#[derive(Debug, serde::Serialize)]
+pub struct PayoutMetricsBucketResponse {
+    #[serde(flatten)]
+    pub values: PayoutMetricsBucketValue,
+    #[serde(flatten)]
+    pub dimensions: PayoutMetricsBucketIdentifier,
+}
@@ -5,7 +5,7 @@ use std::{
 
 use common_utils::id_type;
 
-use crate::enums::{Currency, RefundStatus};
+use crate::enums::{Currency, PaymentMethod, PaymentMethodType, RefundStatus};
 
 #[derive(
     Clone,
@@ -47,6 +47,27 @@ pub struct RefundFilters {
     pub refund_reason: Vec<String>,
     #[serde(default)]
     pub refund_error_message: Vec<String>,
+    #[serde(default)]
+    pub payment_method: Vec<PaymentMethod>,
+    #[serde(default)]
+    pub payment_method_type: Vec<PaymentMethodType>,
+}
+
+impl RefundFilters {
+    /// Indicates whether any of the filters which are only available on the refunds, and not on
+    /// the payments of the refunds, is set
+    pub fn has_refund_filters(&self) -> bool {
+        !self.refund_status.is_empty()
+            || !self.refund_type.is_empty()
+            || !self.refund_reason.is_empty()
+            || !self.refund_error_message.is_empty()
+    }
+
+    /// Indicates whether any of the filters which are only available on the payments of the
+    /// refunds is set
+    pub fn has_payment_filters(&self) -> bool {
+        !self.payment_method.is_empty() || !self.payment_method_type.is_empty()
+    }
 }
 
 #[derive(
@@ -73,6 +94,24 @@ pub enum RefundDimensions {
     ProfileId,
     RefundReason,
     RefundErrorMessage,
+    PaymentMethod,
+    PaymentMethodType,
+}
+
+impl RefundDimensions {
+    /// Indicates whether the dimension is only available on the refunds, and not on the payments
+    /// of the refunds
+    pub fn is_refund_dimension(&self) -> bool {
+        matches!(
+            self,
+            Self::RefundStatus | Self::RefundType | Self::RefundReason | Self::RefundErrorMessage
+        )
+    }
+
+    /// Indicates whether the dimension is only available on the payments of the refunds
+    pub fn is_payment_dimension(&self) -> bool {
+        matches!(self, Self::PaymentMethod | Self::PaymentMethodType)
+    }
 }
 
 #[derive(
@@ -100,6 +139,24 @@ pub enum RefundMetrics {
     SessionizedRefundProcessedAmount,
     SessionizedRefundReason,
     SessionizedRefundErrorMessage,
+    /// Percentage of the captured payments which were refunded
+    RefundRate,
+    /// Average and P90 time between the capture of the payments and the completion of their
+    /// first refund
+    TimeToRefund,
+    /// Distribution of the refunded payments between partial and full refunds
+    RefundCoverage,
+}
+
+impl RefundMetrics {
+    /// Indicates whether the metric is computed over the captured payments and their successful
+    /// refunds, rather than over the refunds
+    pub fn is_payment_metric(&self) -> bool {
+        matches!(
+            self,
+            Self::RefundRate | Self::TimeToRefund | Self::RefundCoverage
+        )
+    }
 }
 
 #[derive(Debug, Default, serde::Serialize)]
@@ -180,6 +237,8 @@ pub struct RefundMetricsBucketIdentifier {
     pub profile_id: Option<String>,
     pub refund_reason: Option<String>,
     pub refund_error_message: Option<String>,
+    pub payment_method: Option<String>,
+    pub payment_method_type: Option<String>,
     #[serde(rename = "time_range")]
     pub time_bucket: TimeRange,
     #[serde(rename = "time_bucket")]
@@ -196,6 +255,8 @@ impl Hash for RefundMetricsBucketIdentifier {
         self.profile_id.hash(state);
         self.refund_reason.hash(state);
         self.refund_error_message.hash(state);
+        self.payment_method.hash(state);
+        self.payment_method_type.hash(state);
         self.time_bucket.hash(state);
     }
 }
@@ -219,6 +280,8 @@ impl RefundMetricsBucketIdentifier {
         profile_id: Option<String>,
         refund_reason: Option<String>,
         refund_error_message: Option<String>,
+        payment_method: Option<String>,
+        payment_method_type: Option<String>,
         normalized_time_range: TimeRange,
     ) -> Self {
         Self {
@@ -229,6 +292,8 @@ impl RefundMetricsBucketIdentifier {
             profile_id,
             refund_reason,
             refund_error_message,
+            payment_method,
+            payment_method_type,
             time_bucket: normalized_time_range,
             start_time: normalized_time_range.start_time,
         }
@@ -248,6 +313,16 @@ pub struct RefundMetricsBucketValue {
     pub refund_error_message_distribution: Option<Vec<ErrorMessagesResult>>,
     pub refund_reason_count: Option<u64>,
     pub refund_error_message_count: Option<u64>,
+    pub captured_payments: Option<u64>,
+    pub refunded_payments: Option<u64>,
+    pub refund_rate: Option<f64>,
+    /// Average time in seconds between the capture of the payments and their first refund
+    pub avg_time_to_refund: Option<f64>,
+    /// 90th percentile of the time in seconds between the capture of the payments and their first
+    /// refund
+    pub p90_time_to_refund: Option<f64>,
+    pub full_refund_count: Option<u64>,
+    pub partial_refund_count: Option<u64>,
 }
 #[derive(Debug, serde::Serialize)]
 pub struct RefundMetricsBucketResponse {
@@ -26,6 +26,8 @@ pub struct SdkEventFilters {
     pub component: Vec<String>,
     #[serde(default)]
     pub payment_experience: Vec<String>,
+    #[serde(default)]
+    pub locale: Vec<String>,
 }
 
 #[derive(
@@ -53,6 +55,7 @@ pub enum SdkEventDimensions {
     Source,
     Component,
     PaymentExperience,
+    Locale,
 }
 
 #[derive(
@@ -78,6 +81,11 @@ pub enum SdkEventMetrics {
     PaymentDataFilledCount,
     AveragePaymentTime,
     LoadTime,
+    CheckoutRenderedCount,
+    CheckoutPaymentMethodSelectedCount,
+    CheckoutConfirmedCount,
+    CheckoutConvertedCount,
+    CheckoutConversionRate,
 }
 
 #[derive(
@@ -129,6 +137,11 @@ pub mod metric_behaviour {
     pub struct PaymentDataFilledCount;
     pub struct AveragePaymentTime;
     pub struct LoadTime;
+    pub struct CheckoutRenderedCount;
+    pub struct CheckoutPaymentMethodSelectedCount;
+    pub struct CheckoutConfirmedCount;
+    pub struct CheckoutConvertedCount;
+    pub struct CheckoutConversionRate;
 }
 
 impl From<SdkEventMetrics> for NameDescription {
@@ -157,6 +170,7 @@ pub struct SdkEventMetricsBucketIdentifier {
     pub source: Option<String>,
     pub component: Option<String>,
     pub payment_experience: Option<String>,
+    pub locale: Option<String>,
     pub time_bucket: Option<String>,
 }
 
@@ -168,6 +182,7 @@ impl SdkEventMetricsBucketIdentifier {
         source: Option<String>,
         component: Option<String>,
         payment_experience: Option<String>,
+        locale: Option<String>,
         time_bucket: Option<String>,
     ) -> Self {
         Self {
@@ -177,6 +192,7 @@ impl SdkEventMetricsBucketIdentifier {
             source,
             component,
             payment_experience,
+            locale,
             time_bucket,
         }
     }
@@ -190,6 +206,7 @@ impl Hash for SdkEventMetricsBucketIdentifier {
         self.source.hash(state);
         self.component.hash(state);
         self.payment_experience.hash(state);
+        self.locale.hash(state);
         self.time_bucket.hash(state);
     }
 }
@@ -214,6 +231,16 @@ pub struct SdkEventMetricsBucketValue {
     pub sdk_initiated_count: Option<u64>,
     pub payment_method_selected_count: Option<u64>,
     pub payment_data_filled_count: Option<u64>,
+    /// The number of checkouts in which the payment sheet was rendered
+    pub checkout_rendered_count: Option<u64>,
+    /// The number of checkouts in which a payment method was selected
+    pub checkout_payment_method_selected_count: Option<u64>,
+    /// The number of checkouts in which the payment was confirmed
+    pub checkout_confirmed_count: Option<u64>,
+    /// The number of checkouts whose payments were authorized or captured
+    pub checkout_converted_count: Option<u64>,
+    /// The percentage of the rendered checkouts whose payments were authorized or captured
+    pub checkout_conversion_rate: Option<f64>,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -23,6 +23,21 @@ pub struct CreateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The version of the contract of the payment responses to which the API Key is pinned. The
+    /// payment responses of the API Key follow the latest version if it is not pinned.
+    #[schema(value_type = Option<ApiResponseVersion>, example = "2025-06-01")]
+    pub response_version: Option<common_enums::ApiResponseVersion>,
+
+    /// Whether the API Key is a test key. A test key is bound to a profile in test mode, and can
+    /// only be used to make payments through the test merchant connector accounts of the profile.
+    #[schema(default = false, example = false)]
+    pub test_mode: Option<bool>,
+
+    /// The profile in test mode to which the test API Key is bound. This is required for test
+    /// keys, and is not accepted otherwise.
+    #[schema(value_type = Option<String>, max_length = 64, example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
 }
 
 /// The response body for creating an API Key.
@@ -60,6 +75,18 @@ pub struct CreateApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The version of the contract of the payment responses to which the API Key is pinned.
+    #[schema(value_type = Option<ApiResponseVersion>, example = "2025-06-01")]
+    pub response_version: Option<common_enums::ApiResponseVersion>,
+
+    /// Whether the API Key is a test key.
+    #[schema(example = false)]
+    pub test_mode: bool,
+
+    /// The profile in test mode to which the test API Key is bound.
+    #[schema(value_type = Option<String>, max_length = 64, example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -102,6 +129,18 @@ pub struct RetrieveApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The version of the contract of the payment responses to which the API Key is pinned.
+    #[schema(value_type = Option<ApiResponseVersion>, example = "2025-06-01")]
+    pub response_version: Option<common_enums::ApiResponseVersion>,
+
+    /// Whether the API Key is a test key.
+    #[schema(example = false)]
+    pub test_mode: bool,
+
+    /// The profile in test mode to which the test API Key is bound.
+    #[schema(value_type = Option<String>, max_length = 64, example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -130,6 +169,12 @@ pub struct UpdateApiKeyRequest {
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: Option<ApiKeyExpiration>,
 
+    /// The version of the contract of the payment responses to which the API Key is pinned. The
+    /// API Key is unpinned if this is `null`, and left pinned to its version if this is omitted.
+    #[schema(value_type = Option<ApiResponseVersion>, example = "2025-06-01")]
+    #[serde(default, with = "common_utils::custom_serde::double_option")]
+    pub response_version: Option<Option<common_enums::ApiResponseVersion>>,
+
     #[serde(skip_deserializing)]
     #[schema(value_type = String)]
     pub key_id: common_utils::id_type::ApiKeyId,
@@ -0,0 +1,201 @@
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type,
+    pii::Email,
+    types::MinorUnit,
+};
+use masking::Secret;
+use time::PrimitiveDateTime;
+use url::Url;
+use utoipa::ToSchema;
+
+use crate::{enums as api_enums, payments::OrderDetailsWithAmount};
+
+/// Maximum number of line items in a checkout session
+pub const MAX_CHECKOUT_SESSION_LINE_ITEMS: usize = 100;
+
+/// Default time after which a checkout session expires, in seconds
+pub const DEFAULT_CHECKOUT_SESSION_EXPIRY: u32 = 24 * 60 * 60; // 1 day
+
+/// Maximum time after which a checkout session expires, in seconds
+pub const MAX_CHECKOUT_SESSION_EXPIRY: u32 = 7 * 24 * 60 * 60; // 7 days
+
+/// Details of the customer collected during the checkout
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CheckoutSessionCustomer {
+    /// The customer's name
+    #[schema(value_type = Option<String>, max_length = 255, example = "John Doe")]
+    pub name: Option<Secret<String>>,
+    /// The customer's email address
+    #[schema(value_type = Option<String>, max_length = 255, example = "johntest@test.com")]
+    pub email: Option<Email>,
+    /// The customer's phone number
+    #[schema(value_type = Option<String>, max_length = 255, example = "9123456789")]
+    pub phone: Option<Secret<String>>,
+    /// The country code for the customer phone number
+    #[schema(max_length = 255, example = "+1")]
+    pub phone_country_code: Option<String>,
+}
+
+/// The status of a checkout session
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+    strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CheckoutSessionStatus {
+    /// The cart of the session can be updated and the session can be confirmed
+    Open,
+    /// The session has been confirmed and converted to a payment
+    Converted,
+}
+
+/// Request to create a checkout session, which holds the cart and the details of the customer
+/// until the checkout is confirmed, without creating a payment
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CheckoutSessionCreateRequest {
+    /// The currency of the cart
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    /// The items of the cart, the `amount` of each item being the price of a single unit
+    pub line_items: Vec<OrderDetailsWithAmount>,
+    /// The shipping cost of the cart
+    #[schema(value_type = Option<i64>, example = 500)]
+    pub shipping_cost: Option<MinorUnit>,
+    /// The identifier for an existing customer
+    #[schema(value_type = Option<String>, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<id_type::CustomerId>,
+    /// The details of the customer collected so far
+    pub customer: Option<CheckoutSessionCustomer>,
+    /// A description of the checkout
+    #[schema(example = "Order from the web shop")]
+    pub description: Option<String>,
+    /// The URL to which the customer is redirected after the payment
+    #[schema(value_type = Option<String>, example = "https://hyperswitch.io")]
+    pub return_url: Option<Url>,
+    /// Metadata of the checkout, passed on to the payment
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<serde_json::Value>,
+    /// The identifier for the profile of the payment. Defaults to the profile of the
+    /// authentication or the default profile of the merchant
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+    /// The time after which the session expires, in seconds. Defaults to 1 day, and can be at
+    /// most 7 days
+    #[schema(example = 86400)]
+    pub session_expiry: Option<u32>,
+}
+
+/// Request to update a checkout session as the cart changes. The fields which are passed replace
+/// those of the session.
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CheckoutSessionUpdateRequest {
+    /// The items of the cart, the `amount` of each item being the price of a single unit
+    pub line_items: Option<Vec<OrderDetailsWithAmount>>,
+    /// The shipping cost of the cart
+    #[schema(value_type = Option<i64>, example = 500)]
+    pub shipping_cost: Option<MinorUnit>,
+    /// The identifier for an existing customer
+    #[schema(value_type = Option<String>, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<id_type::CustomerId>,
+    /// The details of the customer collected so far
+    pub customer: Option<CheckoutSessionCustomer>,
+    /// A description of the checkout
+    #[schema(example = "Order from the web shop")]
+    pub description: Option<String>,
+    /// The URL to which the customer is redirected after the payment
+    #[schema(value_type = Option<String>, example = "https://hyperswitch.io")]
+    pub return_url: Option<Url>,
+    /// Metadata of the checkout, passed on to the payment
+    #[schema(value_type = Option<Object>, example = r#"{ "udf1": "some-value", "udf2": "some-value" }"#)]
+    pub metadata: Option<serde_json::Value>,
+}
+
+/// A checkout session
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct CheckoutSessionResponse {
+    /// The identifier for the checkout session
+    #[schema(example = "cs_mbabizu24mvu3mela5njyhpit4")]
+    pub id: String,
+    /// The status of the checkout session
+    pub status: CheckoutSessionStatus,
+    /// The identifier for the profile of the payment
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+    /// The currency of the cart
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    /// The items of the cart
+    pub line_items: Vec<OrderDetailsWithAmount>,
+    /// The sum of the prices of the items of the cart
+    #[schema(value_type = i64, example = 6540)]
+    pub subtotal: MinorUnit,
+    /// The shipping cost of the cart
+    #[schema(value_type = Option<i64>, example = 500)]
+    pub shipping_cost: Option<MinorUnit>,
+    /// The amount to be paid for the cart, including the shipping cost
+    #[schema(value_type = i64, example = 7040)]
+    pub total_amount: MinorUnit,
+    /// The identifier for the customer
+    #[schema(value_type = Option<String>, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<id_type::CustomerId>,
+    /// The details of the customer
+    pub customer: Option<CheckoutSessionCustomer>,
+    /// A description of the checkout
+    pub description: Option<String>,
+    /// The URL to which the customer is redirected after the payment
+    #[schema(value_type = Option<String>, example = "https://hyperswitch.io")]
+    pub return_url: Option<Url>,
+    /// Metadata of the checkout
+    #[schema(value_type = Option<Object>)]
+    pub metadata: Option<serde_json::Value>,
+    /// The identifier for the payment to which the session was converted
+    #[schema(value_type = Option<String>, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: Option<id_type::PaymentId>,
+    /// The time at which the session was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The time at which the session was last updated
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated_at: PrimitiveDateTime,
+    /// The time at which the session expires
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-11T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+}
+
+impl ApiEventMetric for CheckoutSessionCreateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for CheckoutSessionUpdateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for CheckoutSessionResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        match &self.payment_id {
+            Some(payment_id) => Some(ApiEventsType::Payment {
+                payment_id: payment_id.clone(),
+            }),
+            None => Some(ApiEventsType::Miscellaneous),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use common_utils::id_type;
+use time::PrimitiveDateTime;
 
 use super::{admin, enums};
 
@@ -60,3 +61,59 @@ pub struct ResetTrackingIdRequest {
     pub connector_id: id_type::MerchantConnectorAccountId,
     pub connector: enums::Connector,
 }
+
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct OAuthAuthorizeRequest {
+    pub connector: enums::Connector,
+    pub connector_id: id_type::MerchantConnectorAccountId,
+    /// The url to which the merchant is redirected once the consent is given or denied
+    pub return_url: String,
+    /// The scopes requested from the merchant, the scopes configured for the connector if not given
+    pub scopes: Option<Vec<String>>,
+}
+
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct OAuthAuthorizeResponse {
+    /// The url of the consent page of the connector, to which the merchant is redirected
+    pub authorization_url: String,
+    /// The number of seconds within which the consent has to be given
+    pub expires_in: i64,
+}
+
+/// The parameters with which the connector redirects the merchant back once the consent is given
+/// or denied
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+pub struct OAuthCallbackRequest {
+    pub state: String,
+    pub code: Option<String>,
+    pub scope: Option<String>,
+    pub error: Option<String>,
+    pub error_description: Option<String>,
+}
+
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct OAuthStatusRequest {
+    pub connector: enums::Connector,
+    pub connector_id: id_type::MerchantConnectorAccountId,
+}
+
+#[derive(serde::Serialize, Debug, Clone)]
+pub struct OAuthStatusResponse {
+    pub connector: enums::Connector,
+    pub connector_id: id_type::MerchantConnectorAccountId,
+    /// The scopes granted by the merchant
+    pub scopes: Vec<String>,
+    /// The time at which the current access token expires, if it expires
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub access_token_expires_at: Option<PrimitiveDateTime>,
+    /// The time at which the account of the merchant was connected
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub connected_at: PrimitiveDateTime,
+    /// The time at which the access token was last refreshed
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_refreshed_at: Option<PrimitiveDateTime>,
+    /// The error of the last refresh of the access token, if it failed
+    pub last_refresh_error: Option<String>,
+}
@@ -159,6 +159,9 @@ pub struct CustomerResponse {
     /// The identifier for the default payment method.
     #[schema(max_length = 64, example = "pm_djh2837dwduh890123")]
     pub default_payment_method_id: Option<String>,
+    /// The result of the validation of the address, populated for the merchants which have
+    /// enabled the `address_validation` feature flag
+    pub address_validation: Option<payments::AddressValidationDetails>,
 }
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
@@ -375,3 +378,169 @@ pub struct CustomerUpdateRequestInternal {
     pub id: id_type::GlobalCustomerId,
     pub request: CustomerUpdateRequest,
 }
+
+/// Query parameters to list the payments of a customer
+#[derive(Debug, Default, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomerPaymentsListRequest {
+    /// The number of payments to skip, from the most recent payment
+    #[schema(example = 0)]
+    pub offset: Option<u32>,
+    /// The number of payments to return, 10 by default and up to 100
+    #[schema(example = 10, maximum = 100)]
+    pub limit: Option<u32>,
+}
+
+/// The amount captured from a customer in a currency
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerLifetimeValue {
+    /// The currency of the payments
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: common_enums::Currency,
+    /// The amount captured from the payments in the currency, before refunds, in the lowest
+    /// denomination of the currency
+    #[schema(value_type = i64, example = 125000)]
+    pub amount: common_utils::types::MinorUnit,
+}
+
+/// The payment method most used by a customer in their successful payments
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerPreferredPaymentMethod {
+    /// The payment method
+    #[schema(value_type = PaymentMethod, example = "card")]
+    pub payment_method: common_enums::PaymentMethod,
+    /// The type of the payment method
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<common_enums::PaymentMethodType>,
+    /// The number of successful payments made with the payment method
+    pub payments_count: u64,
+}
+
+/// The statistics of the payments of a customer
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerPaymentStats {
+    /// The number of payments of the customer
+    pub total_payments: u64,
+    /// The number of payments which were captured, fully or partially
+    pub successful_payments: u64,
+    /// The number of payments which failed
+    pub failed_payments: u64,
+    /// The amount captured from the customer, per currency
+    pub lifetime_value: Vec<CustomerLifetimeValue>,
+    /// The number of disputes raised on the payments of the customer
+    pub dispute_count: u64,
+    /// The payment method most used by the customer in their successful payments
+    pub preferred_payment_method: Option<CustomerPreferredPaymentMethod>,
+    /// The time at which the first payment of the customer was created
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub first_payment_at: Option<time::PrimitiveDateTime>,
+    /// The time at which the last payment of the customer was created
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub last_payment_at: Option<time::PrimitiveDateTime>,
+    /// Whether the statistics only cover the most recent payments of the customer, as the
+    /// customer has more payments than can be aggregated
+    pub is_partial: bool,
+}
+
+/// The payment history of a customer, with the statistics of their payments
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerPaymentsListResponse {
+    /// The identifier of the customer
+    #[schema(value_type = String, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+    /// The statistics of the payments of the customer
+    pub stats: CustomerPaymentStats,
+    /// The number of payments returned
+    pub count: usize,
+    /// The payments of the customer, from the most recent payment
+    pub data: Vec<payments::PaymentsResponse>,
+}
+
+/// Query parameters to list the store credit entries of a customer
+#[derive(Debug, Default, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct StoreCreditEntriesListRequest {
+    /// The number of entries to skip, from the most recent entry
+    #[schema(example = 0)]
+    pub offset: Option<u32>,
+    /// The number of entries to return, 10 by default and up to 100
+    #[schema(example = 10, maximum = 100)]
+    pub limit: Option<u32>,
+}
+
+/// The store credit balance of a customer in a currency
+#[derive(Debug, Clone, Serialize, ToSchema, Eq, PartialEq)]
+pub struct StoreCreditBalance {
+    /// The currency of the balance
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: common_enums::Currency,
+    /// The amount which the payments of the customer can draw down, in the lowest denomination of
+    /// the currency
+    #[schema(value_type = i64, example = 2500)]
+    pub balance: common_utils::types::MinorUnit,
+    /// The amount credited by the refunds to store credit
+    #[schema(value_type = i64, example = 4000)]
+    pub credited_amount: common_utils::types::MinorUnit,
+    /// The amount debited by the payments, net of the debits credited back for the payments which
+    /// failed or were cancelled
+    #[schema(value_type = i64, example = 1500)]
+    pub debited_amount: common_utils::types::MinorUnit,
+}
+
+/// The store credit balances of a customer
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerStoreCreditResponse {
+    /// The identifier of the customer
+    #[schema(value_type = String, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+    /// The store credit balances of the customer, in each currency
+    pub balances: Vec<StoreCreditBalance>,
+}
+
+/// An entry in the store credit ledger of a customer
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct StoreCreditEntryResponse {
+    /// The identifier of the entry
+    #[schema(example = "sce_mbabizu24mvu3mela5njyhpit4")]
+    pub entry_id: String,
+    /// The type of the entry
+    #[schema(value_type = StoreCreditEntryType, example = "refund_credit")]
+    pub entry_type: common_enums::StoreCreditEntryType,
+    /// The amount of the entry, in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 4000)]
+    pub amount: common_utils::types::MinorUnit,
+    /// The currency of the entry
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: common_enums::Currency,
+    /// The payment which was refunded to store credit, or to which the store credit was applied
+    #[schema(value_type = String, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: id_type::PaymentId,
+    /// The refund which credited the amount, for the refund credits
+    #[schema(example = "ref_mbabizu24mvu3mela5njyhpit4")]
+    pub refund_id: Option<String>,
+    /// The time at which the entry was recorded
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+/// The store credit entries of a customer
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct StoreCreditEntriesListResponse {
+    /// The identifier of the customer
+    #[schema(value_type = String, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+    /// The number of entries returned
+    pub count: usize,
+    /// The entries of the customer, from the most recent entry
+    pub data: Vec<StoreCreditEntryResponse>,
+}
@@ -1,12 +1,12 @@
 use std::collections::HashMap;
 
-use common_utils::types::TimeRange;
+use common_utils::types::{MinorUnit, TimeRange};
 use masking::{Deserialize, Serialize};
 use serde::de::Error;
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
-use super::enums::{Currency, DisputeStage, DisputeStatus};
+use super::enums::{Currency, DisputeStage, DisputeStatus, PaymentMethod, PaymentMethodType};
 use crate::{admin::MerchantConnectorInfo, files};
 
 #[derive(Clone, Debug, Serialize, ToSchema, Eq, PartialEq)]
@@ -55,6 +55,63 @@ pub struct DisputeResponse {
     /// The `merchant_connector_id` of the connector / processor through which the dispute was processed
     #[schema(value_type = Option<String>)]
     pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    /// Details of the disputed payment, populated in the disputes list when requested through the
+    /// `payment_context` query parameter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_context: Option<DisputePaymentContext>,
+}
+
+/// Details of the disputed payment. Only the fields requested through the `payment_context`
+/// query parameter of the disputes list are populated.
+#[derive(Clone, Debug, Default, Serialize, ToSchema, Eq, PartialEq)]
+pub struct DisputePaymentContext {
+    /// The amount of the payment
+    #[schema(value_type = Option<i64>, example = 6540)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub amount: Option<common_utils::types::MinorUnit>,
+    /// The three-letter ISO currency code of the payment
+    #[schema(value_type = Option<Currency>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<Currency>,
+    /// The payment method of the disputed attempt
+    #[schema(value_type = Option<PaymentMethod>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<PaymentMethod>,
+    /// The payment method type of the disputed attempt
+    #[schema(value_type = Option<PaymentMethodType>)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method_type: Option<PaymentMethodType>,
+    /// The SHA-256 hash of the email of the customer of the payment, in lowercase hex, which can
+    /// be matched against the hashes of the emails known to the merchant
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_email_hash: Option<String>,
+    /// The connector which processed the disputed attempt
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connector: Option<String>,
+}
+
+/// The fields of the disputed payment which can be embedded in the disputes list
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    Serialize,
+    Deserialize,
+    ToSchema,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisputePaymentContextField {
+    /// The amount and the currency of the payment
+    Amount,
+    /// The payment method and the payment method type of the disputed attempt
+    PaymentMethod,
+    CustomerEmailHash,
+    Connector,
 }
 
 #[derive(Clone, Debug, Serialize, ToSchema, Eq, PartialEq)]
@@ -144,6 +201,9 @@ pub struct DisputeListGetConstraints {
     /// The time range for which objects are needed. TimeRange has two fields start_time and end_time from which objects can be filtered as per required scenarios (created_at, time less than, greater than etc).
     #[serde(flatten)]
     pub time_range: Option<TimeRange>,
+    /// The comma separated list of the fields of the disputed payment to embed in the disputes
+    #[serde(default, deserialize_with = "parse_comma_separated")]
+    pub payment_context: Option<Vec<DisputePaymentContextField>>,
 }
 
 #[derive(Clone, Debug, serde::Serialize, ToSchema)]
@@ -230,6 +290,30 @@ pub struct DisputesAggregateResponse {
     pub status_with_count: HashMap<DisputeStatus, i64>,
 }
 
+/// The funds of the merchant at risk due to the disputes, as recorded in the ledger
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct DisputesBalanceResponse {
+    /// The funds at risk due to the disputes, in each currency
+    pub balances: Vec<DisputesCurrencyBalance>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema, Eq, PartialEq)]
+pub struct DisputesCurrencyBalance {
+    /// The currency of the disputes
+    #[schema(value_type = Currency)]
+    pub currency: Currency,
+    /// The amount held from the balance of the merchant for the disputes which are open
+    #[schema(value_type = i64, example = 6540)]
+    pub held_amount: MinorUnit,
+    /// The amount released back to the balance of the merchant for the disputes which were won or
+    /// cancelled
+    #[schema(value_type = i64, example = 6540)]
+    pub released_amount: MinorUnit,
+    /// The amount charged to the merchant for the disputes which were lost, accepted or expired
+    #[schema(value_type = i64, example = 6540)]
+    pub charged_amount: MinorUnit,
+}
+
 fn parse_comma_separated<'de, D, T>(v: D) -> Result<Option<Vec<T>>, D::Error>
 where
     D: serde::Deserializer<'de>,
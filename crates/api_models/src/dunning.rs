@@ -0,0 +1,180 @@
+use common_utils::{
+    errors::ValidationError,
+    events::{ApiEventMetric, ApiEventsType},
+    id_type,
+};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// Maximum number of retries in the dunning schedule of a profile
+pub const MAX_DUNNING_RETRIES: usize = 10;
+
+/// Maximum number of days after the failure of a payment on which it can be retried
+pub const MAX_DUNNING_RETRY_DAY: u16 = 90;
+
+/// The codes of the hard declines with which the failed payments are not retried by default. These
+/// are the network response codes and the decline codes of the connectors for the cards which are
+/// lost, stolen, closed or invalid, and for the authorizations revoked by the customer.
+pub const DEFAULT_HARD_DECLINE_CODES: &[&str] = &[
+    "04",
+    "07",
+    "14",
+    "15",
+    "41",
+    "43",
+    "46",
+    "57",
+    "62",
+    "R0",
+    "R1",
+    "R3",
+    "pickup_card",
+    "lost_card",
+    "stolen_card",
+    "incorrect_number",
+    "invalid_account",
+    "revocation_of_authorization",
+    "revocation_of_all_authorizations",
+    "stop_payment_order",
+];
+
+/// The dunning configuration of a profile. The recurring payments of the profile which fail are
+/// retried on the days of the schedule, counted from their failure, until a retry succeeds. The
+/// payments whose retries all fail are marked as uncollectible, as are the payments failing with a
+/// hard decline, which are not retried.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DunningConfig {
+    /// The days after the failure of the payment on which it is retried, in increasing order
+    #[serde(default = "default_retry_schedule_days")]
+    #[schema(example = json!([1, 3, 7]))]
+    pub retry_schedule_days: Vec<u16>,
+    /// The codes of the hard declines, with which the failed payments are marked as uncollectible
+    /// instead of being retried. A code is matched against the error code of the connector, the
+    /// error code of the issuer and the unified error code of the failed attempt. Defaults to the
+    /// network response codes and the decline codes of the lost, stolen, closed or invalid cards
+    /// and of the revoked authorizations.
+    #[serde(default = "default_hard_decline_codes")]
+    #[schema(example = json!(["41", "43", "lost_card", "stolen_card"]))]
+    pub hard_decline_codes: Vec<String>,
+}
+
+fn default_retry_schedule_days() -> Vec<u16> {
+    vec![1, 3, 7]
+}
+
+/// The hard decline codes of the dunning configurations which do not set them
+pub fn default_hard_decline_codes() -> Vec<String> {
+    DEFAULT_HARD_DECLINE_CODES
+        .iter()
+        .map(|code| code.to_string())
+        .collect()
+}
+
+impl DunningConfig {
+    /// Validates that the schedule has between 1 and `MAX_DUNNING_RETRIES` retries, on increasing
+    /// days between 1 and `MAX_DUNNING_RETRY_DAY`
+    pub fn validate(&self) -> Result<(), error_stack::Report<ValidationError>> {
+        if self.retry_schedule_days.is_empty()
+            || self.retry_schedule_days.len() > MAX_DUNNING_RETRIES
+        {
+            return Err(ValidationError::InvalidValue {
+                message: format!(
+                    "retry_schedule_days must contain between 1 and {MAX_DUNNING_RETRIES} days"
+                ),
+            }
+            .into());
+        }
+
+        if self
+            .retry_schedule_days
+            .iter()
+            .any(|day| !(1..=MAX_DUNNING_RETRY_DAY).contains(day))
+        {
+            return Err(ValidationError::InvalidValue {
+                message: format!(
+                    "the days of retry_schedule_days must be between 1 and {MAX_DUNNING_RETRY_DAY}"
+                ),
+            }
+            .into());
+        }
+
+        if self
+            .retry_schedule_days
+            .windows(2)
+            .any(|days| matches!(days, [previous, next] if previous >= next))
+        {
+            return Err(ValidationError::InvalidValue {
+                message: "the days of retry_schedule_days must be in increasing order".to_string(),
+            }
+            .into());
+        }
+
+        Ok(())
+    }
+}
+
+/// The status of the dunning of a failed recurring payment
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DunningStatus {
+    /// The payment is to be retried on the next day of the schedule
+    Scheduled,
+    /// The payment succeeded on a retry, or was completed outside the dunning
+    Recovered,
+    /// All the retries of the payment failed, the payment failed with a hard decline, or its
+    /// mandate is no longer active. The payment is not retried anymore.
+    Uncollectible,
+}
+
+/// The dunning of a failed recurring payment
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct DunningResponse {
+    /// The identifier of the payment
+    #[schema(value_type = String, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: id_type::PaymentId,
+    /// The status of the dunning
+    pub status: DunningStatus,
+    /// The days after the failure of the payment on which it is retried
+    #[schema(example = json!([1, 3, 7]))]
+    pub retry_schedule_days: Vec<u16>,
+    /// The number of retries of the payment performed so far
+    pub retry_count: usize,
+    /// The time at which the payment is retried next, if the dunning is scheduled
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub next_retry_at: Option<PrimitiveDateTime>,
+    /// The error message of the last failed attempt of the payment
+    pub last_error_message: Option<String>,
+    /// The time at which the payment failed and the dunning was started
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The time at which the dunning was last updated
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl ApiEventMetric for DunningConfig {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for DunningResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -44,6 +44,10 @@ pub enum RoutingAlgorithm {
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum PayoutConnectors {
+    #[cfg(feature = "dummy_connector")]
+    #[serde(rename = "phonypay")]
+    #[strum(serialize = "phonypay")]
+    DummyConnector1,
     Adyen,
     Adyenplatform,
     Cybersource,
@@ -70,6 +74,8 @@ pub enum UpdateActiveAttempt {
 impl From<PayoutConnectors> for RoutableConnectors {
     fn from(value: PayoutConnectors) -> Self {
         match value {
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector1 => Self::DummyConnector1,
             PayoutConnectors::Adyen => Self::Adyen,
             PayoutConnectors::Adyenplatform => Self::Adyenplatform,
             PayoutConnectors::Cybersource => Self::Cybersource,
@@ -87,6 +93,8 @@ impl From<PayoutConnectors> for RoutableConnectors {
 impl From<PayoutConnectors> for Connector {
     fn from(value: PayoutConnectors) -> Self {
         match value {
+            #[cfg(feature = "dummy_connector")]
+            PayoutConnectors::DummyConnector1 => Self::DummyConnector1,
             PayoutConnectors::Adyen => Self::Adyen,
             PayoutConnectors::Adyenplatform => Self::Adyenplatform,
             PayoutConnectors::Cybersource => Self::Cybersource,
@@ -105,6 +113,8 @@ impl TryFrom<Connector> for PayoutConnectors {
     type Error = String;
     fn try_from(value: Connector) -> Result<Self, Self::Error> {
         match value {
+            #[cfg(feature = "dummy_connector")]
+            Connector::DummyConnector1 => Ok(Self::DummyConnector1),
             Connector::Adyen => Ok(Self::Adyen),
             Connector::Adyenplatform => Ok(Self::Adyenplatform),
             Connector::Cybersource => Ok(Self::Cybersource),
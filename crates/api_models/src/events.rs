@@ -20,7 +20,9 @@ use common_utils::{
     impl_api_event_type,
 };
 
-use crate::customers::CustomerListRequest;
+use crate::customers::{
+    CustomerListRequest, CustomerPaymentsListRequest, StoreCreditEntriesListRequest,
+};
 #[allow(unused_imports)]
 use crate::{
     admin::*,
@@ -31,6 +33,7 @@ use crate::{
     api_keys::*,
     cards_info::*,
     disputes::*,
+    feature_flags::*,
     files::*,
     mandates::*,
     organization::{
@@ -38,6 +41,8 @@ use crate::{
     },
     payment_methods::*,
     payments::*,
+    process_tracker::*,
+    sandbox::*,
     user::{UserKeyTransferRequest, UserTransferKeyResponse},
     verifications::*,
 };
@@ -86,8 +91,21 @@ impl_api_event_type!(
         ToggleKVRequest,
         ToggleAllKVRequest,
         ToggleAllKVResponse,
+        KvPgDivergencesListRequest,
+        KvPgDivergencesListResponse,
+        FeatureFlagsUpdateRequest,
+        FeatureFlagsResponse,
         MerchantAccountDeleteResponse,
         MerchantAccountUpdate,
+        MerchantAccountStatusUpdateRequest,
+        MerchantAccountStatusResponse,
+        MerchantOffboardingRequest,
+        MerchantOffboardingResponse,
+        ConnectorTransactionIdBackfillRequest,
+        ConnectorTransactionIdBackfillResponse,
+        ConnectorTransactionIdBackfillId,
+        DataResidencyRequest,
+        DataResidencyResponse,
         CardInfoResponse,
         CreateApiKeyResponse,
         CreateApiKeyRequest,
@@ -134,12 +152,64 @@ impl_api_event_type!(
         GetDisputeFilterRequest,
         DisputeFiltersResponse,
         GetDisputeMetricRequest,
+        GetPayoutFilterRequest,
+        PayoutFiltersResponse,
+        GetPayoutMetricRequest,
+        GetConnectorFeeFilterRequest,
+        ConnectorFeeFiltersResponse,
+        GetConnectorFeeMetricRequest,
         SankeyResponse,
+        PaymentSummaryResponse,
         OrganizationResponse,
         OrganizationCreateRequest,
         OrganizationUpdateRequest,
         OrganizationId,
-        CustomerListRequest
+        CustomerListRequest,
+        CustomerPaymentsListRequest,
+        StoreCreditEntriesListRequest,
+        PaymentMethodDisplayConfig,
+        PaymentMethodDisplayNameOverrides,
+        PaymentMethodDisplayNamesRequest,
+        PaymentMethodDisplayNamesResponse,
+        ExternalVaultConfigRequest,
+        ExternalVaultConfigResponse,
+        CvvRecollectionConfig,
+        CvvTokenizeRequest,
+        CvvTokenizeResponse,
+        NetworkTokenPreferenceConfig,
+        ConnectorMaintenanceWindowsRequest,
+        ConnectorMaintenanceWindowsResponse,
+        MerchantConnectorBulkConfigRequest,
+        MerchantConnectorBulkConfigResponse,
+        ConnectorCredentialsUpdateRequest,
+        ConnectorCredentialsResponse,
+        ExpiringConnectorCredentialsRequest,
+        SandboxDataSeedRequest,
+        SandboxDataSeedResponse,
+        TestClockAdvanceRequest,
+        TestClockResponse,
+        SchedulerTasksListRequest,
+        SchedulerTasksListResponse,
+        SchedulerTasksSummaryResponse,
+        SchedulerTask,
+        SchedulerTaskId,
+        PaymentFacilitatorDetails,
+        CustomPaymentStatusMapping,
+        CustomFieldsSchema,
+        UnifiedErrorMessageOverrides,
+        PostAuthorizationRules,
+        AutoCaptureDelayConfig,
+        StepUpAuthenticationConfig,
+        CheckoutTheme,
+        CheckoutThemeResponse,
+        CheckoutThemeVersionsResponse,
+        CheckoutThemeRetrieveQuery,
+        CheckoutThemeActivateRequest,
+        ProfileTestModeRequest,
+        ProfileTestModeResponse,
+        TestDataPurgeRequest,
+        TestDataPurgeResponse,
+        ProfileCoverageResponse
     )
 );
 
@@ -183,6 +253,18 @@ impl<T> ApiEventMetric for DisputesMetricsResponse<T> {
     }
 }
 
+impl<T> ApiEventMetric for PayoutsMetricsResponse<T> {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl<T> ApiEventMetric for ConnectorFeesMetricsResponse<T> {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
 impl<T> ApiEventMetric for AuthEventMetricsResponse<T> {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::Miscellaneous)
@@ -1,8 +1,9 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use crate::connector_onboarding::{
-    ActionUrlRequest, ActionUrlResponse, OnboardingStatus, OnboardingSyncRequest,
-    ResetTrackingIdRequest,
+    ActionUrlRequest, ActionUrlResponse, OAuthAuthorizeRequest, OAuthAuthorizeResponse,
+    OAuthCallbackRequest, OAuthStatusRequest, OAuthStatusResponse, OnboardingStatus,
+    OnboardingSyncRequest, ResetTrackingIdRequest,
 };
 
 common_utils::impl_api_event_type!(
@@ -12,6 +13,11 @@ common_utils::impl_api_event_type!(
         ActionUrlResponse,
         OnboardingSyncRequest,
         OnboardingStatus,
-        ResetTrackingIdRequest
+        ResetTrackingIdRequest,
+        OAuthAuthorizeRequest,
+        OAuthAuthorizeResponse,
+        OAuthCallbackRequest,
+        OAuthStatusRequest,
+        OAuthStatusResponse
     )
 );
@@ -2,7 +2,7 @@ use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use super::{
     DeleteEvidenceRequest, DisputeResponse, DisputeResponsePaymentsRetrieve,
-    DisputesAggregateResponse, SubmitEvidenceRequest,
+    DisputesAggregateResponse, DisputesBalanceResponse, SubmitEvidenceRequest,
 };
 
 impl ApiEventMetric for SubmitEvidenceRequest {
@@ -39,3 +39,9 @@ impl ApiEventMetric for DisputesAggregateResponse {
         Some(ApiEventsType::ResourceListAPI)
     }
 }
+
+impl ApiEventMetric for DisputesBalanceResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
@@ -1,9 +1,9 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use crate::refunds::{
-    RefundAggregateResponse, RefundListFilters, RefundListMetaData, RefundListRequest,
-    RefundListResponse, RefundManualUpdateRequest, RefundRequest, RefundResponse,
-    RefundUpdateRequest, RefundsRetrieveRequest,
+    RefundAggregateResponse, RefundBatchResponse, RefundListFilters, RefundListMetaData,
+    RefundListRequest, RefundListResponse, RefundManualUpdateRequest, RefundRequest,
+    RefundResolveRequest, RefundResponse, RefundUpdateRequest, RefundsRetrieveRequest,
 };
 
 #[cfg(feature = "v1")]
@@ -69,6 +69,16 @@ impl ApiEventMetric for RefundManualUpdateRequest {
     }
 }
 
+#[cfg(feature = "v1")]
+impl ApiEventMetric for RefundResolveRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Refund {
+            payment_id: None,
+            refund_id: self.refund_id.clone(),
+        })
+    }
+}
+
 impl ApiEventMetric for RefundListRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::ResourceListAPI)
@@ -98,3 +108,9 @@ impl ApiEventMetric for RefundListFilters {
         Some(ApiEventsType::ResourceListAPI)
     }
 }
+
+impl ApiEventMetric for RefundBatchResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+
+use common_utils::{
+    errors::ValidationError,
+    events::{ApiEventMetric, ApiEventsType},
+};
+use utoipa::ToSchema;
+
+use crate::enums;
+
+/// Maximum delay of the processing of the incoming webhooks of a connector
+pub const MAX_WEBHOOK_DELAY_SECONDS: u16 = 60;
+
+/// Faults injected into the calls made to the connectors of a merchant and into the handling of
+/// their webhooks, to simulate connector outages outside production
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FaultInjectionConfig {
+    /// The faults keyed by the connector into whose calls they are injected
+    #[serde(default)]
+    #[schema(value_type = HashMap<Connector, ConnectorFaults>, example = json!({"stripe": {"call_fault": {"type": "server_error", "status_code": 503}, "percentage": 50}}))]
+    pub connectors: HashMap<enums::Connector, ConnectorFaults>,
+}
+
+/// The faults injected for a connector
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorFaults {
+    /// The fault returned instead of the response of the connector. The request is not sent to
+    /// the connector when the fault is injected
+    pub call_fault: Option<ConnectorCallFault>,
+    /// The percentage of the calls to the connector into which the fault is injected
+    #[serde(default = "default_fault_percentage")]
+    #[schema(minimum = 1, maximum = 100, example = 100)]
+    pub percentage: u8,
+    /// The delay, in seconds, before the incoming webhooks from the connector are processed
+    #[schema(maximum = 60, example = 30)]
+    pub webhook_delay_seconds: Option<u16>,
+}
+
+fn default_fault_percentage() -> u8 {
+    100
+}
+
+/// A fault returned instead of the response of a connector
+#[derive(Clone, Copy, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ConnectorCallFault {
+    /// The request to the connector times out
+    Timeout,
+    /// The connector responds with a server error
+    ServerError {
+        /// The status code of the response, between 500 and 599
+        #[schema(example = 503)]
+        status_code: u16,
+    },
+    /// The connector responds with a body which cannot be parsed
+    MalformedResponse,
+}
+
+impl FaultInjectionConfig {
+    pub fn is_empty(&self) -> bool {
+        self.connectors.is_empty()
+    }
+
+    /// Validates that the percentages are between 1 and 100, that the status codes of the server
+    /// errors are between 500 and 599, and that the webhook delays do not exceed
+    /// `MAX_WEBHOOK_DELAY_SECONDS`
+    pub fn validate(&self) -> Result<(), error_stack::Report<ValidationError>> {
+        self.connectors.iter().try_for_each(|(connector, faults)| {
+            if !(1..=100).contains(&faults.percentage) {
+                return Err(ValidationError::InvalidValue {
+                    message: format!("percentage of connector `{connector}` must be between 1 and 100"),
+                }
+                .into());
+            }
+
+            if let Some(ConnectorCallFault::ServerError { status_code }) = faults.call_fault {
+                if !(500..=599).contains(&status_code) {
+                    return Err(ValidationError::InvalidValue {
+                        message: format!(
+                            "status code of the server error of connector `{connector}` must be between 500 and 599"
+                        ),
+                    }
+                    .into());
+                }
+            }
+
+            if faults
+                .webhook_delay_seconds
+                .is_some_and(|delay| delay > MAX_WEBHOOK_DELAY_SECONDS)
+            {
+                return Err(ValidationError::InvalidValue {
+                    message: format!(
+                        "webhook delay of connector `{connector}` must not exceed {MAX_WEBHOOK_DELAY_SECONDS} seconds"
+                    ),
+                }
+                .into());
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl ApiEventMetric for FaultInjectionConfig {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+
+use common_enums::MerchantFeatureFlag;
+use utoipa::ToSchema;
+
+/// Sets or clears the feature flags of a merchant, or of a profile of the merchant
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FeatureFlagsUpdateRequest {
+    /// The value of each flag to be set. A flag set to `null` is cleared, after which the value
+    /// of the merchant, or of the configuration, is used.
+    #[schema(value_type = Object, example = json!({"smart_retries": false, "network_tokenization": null}))]
+    pub flags: HashMap<MerchantFeatureFlag, Option<bool>>,
+}
+
+/// The level from which the value of a feature flag is picked
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FeatureFlagSource {
+    /// The flag is set for the profile
+    Profile,
+    /// The flag is set for the merchant
+    Merchant,
+    /// The flag is not set, and the value in the configuration is used
+    Default,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct FeatureFlag {
+    /// The feature flag
+    pub flag: MerchantFeatureFlag,
+
+    /// Whether the flow gated by the flag is enabled
+    pub enabled: bool,
+
+    /// The level from which the value of the flag is picked
+    pub source: FeatureFlagSource,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct FeatureFlagsResponse {
+    /// The identifier for the merchant
+    #[schema(value_type = String, example = "merchant_1668273825")]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The identifier for the profile, if the flags are of a profile
+    #[schema(value_type = Option<String>, example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+
+    /// The resolved value of each feature flag
+    pub flags: Vec<FeatureFlag>,
+}
@@ -52,6 +52,9 @@ pub struct ConnectorFeatureMatrixResponse {
     pub category: Option<PaymentConnectorCategory>,
     pub supported_payment_methods: Vec<SupportedPaymentMethod>,
     pub supported_webhook_flows: Option<Vec<EventClass>>,
+    /// Number of days after the creation of a payment within which the connector accepts its
+    /// refunds
+    pub refund_validity_days: Option<i64>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -4,6 +4,8 @@ pub mod api_keys;
 pub mod apple_pay_certificates_migration;
 pub mod blocklist;
 pub mod cards_info;
+#[cfg(feature = "v1")]
+pub mod checkout_sessions;
 pub mod conditional_configs;
 pub mod connector_enums;
 pub mod connector_onboarding;
@@ -11,12 +13,15 @@ pub mod consts;
 pub mod currency;
 pub mod customers;
 pub mod disputes;
+pub mod dunning;
 pub mod enums;
 pub mod ephemeral_key;
 #[cfg(feature = "errors")]
 pub mod errors;
 pub mod events;
 pub mod external_service_auth;
+pub mod fault_injection;
+pub mod feature_flags;
 pub mod feature_matrix;
 pub mod files;
 pub mod gsm;
@@ -30,17 +35,25 @@ pub mod payments;
 pub mod payouts;
 pub mod pm_auth;
 pub mod poll;
+pub mod process_tracker;
 #[cfg(feature = "recon")]
 pub mod recon;
 pub mod refunds;
 pub mod relay;
 pub mod routing;
+pub mod sanctions_screening;
+pub mod sandbox;
+pub mod sca_compliance;
+pub mod sdk_events;
 pub mod surcharge_decision_configs;
 pub mod user;
 pub mod user_role;
 pub mod verifications;
 pub mod verify_connector;
+pub mod warehouse_sync;
+pub mod webhook_endpoints;
 pub mod webhook_events;
+pub mod webhook_payload_templates;
 pub mod webhooks;
 
 pub trait ValidateFieldAndGet<Request> {
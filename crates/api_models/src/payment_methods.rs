@@ -80,6 +80,9 @@ pub struct PaymentMethodCreate {
     #[schema(value_type = Option<Wallet>)]
     pub wallet: Option<payouts::Wallet>,
 
+    /// Bank account details to be stored in the locker for bank debit payments
+    pub bank_debit: Option<BankDebitDetail>,
+
     /// For Client based calls, SDK will use the client_secret
     /// in order to call /payment_methods
     /// Client secret will be generated whenever a new
@@ -437,11 +440,135 @@ impl PaymentMethodCreate {
             bank_transfer: payment_method_migrate.bank_transfer.clone(),
             #[cfg(feature = "payouts")]
             wallet: payment_method_migrate.wallet.clone(),
+            bank_debit: None,
             network_transaction_id: payment_method_migrate.network_transaction_id.clone(),
         }
     }
 }
 
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BankDebitDetail {
+    Ach {
+        /// Bank account number
+        #[schema(value_type = String, example = "000123456789")]
+        account_number: masking::Secret<String>,
+
+        /// ABA routing number of the bank
+        #[schema(value_type = String, example = "110000000")]
+        routing_number: masking::Secret<String>,
+
+        /// Name of the bank account holder
+        #[schema(value_type = Option<String>, example = "John Doe")]
+        bank_account_holder_name: Option<masking::Secret<String>>,
+
+        /// Type of the bank account
+        #[schema(value_type = Option<BankType>, example = "checking")]
+        bank_type: Option<api_enums::BankType>,
+
+        /// Whether the bank account is held by an individual or a company
+        #[schema(value_type = Option<BankHolderType>, example = "personal")]
+        bank_holder_type: Option<api_enums::BankHolderType>,
+    },
+    Sepa {
+        /// International bank account number
+        #[schema(value_type = String, example = "DE89370400440532013000")]
+        iban: masking::Secret<String>,
+
+        /// Name of the bank account holder
+        #[schema(value_type = Option<String>, example = "John Doe")]
+        bank_account_holder_name: Option<masking::Secret<String>>,
+    },
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+impl BankDebitDetail {
+    pub fn get_payment_method_type(&self) -> api_enums::PaymentMethodType {
+        match self {
+            Self::Ach { .. } => api_enums::PaymentMethodType::Ach,
+            Self::Sepa { .. } => api_enums::PaymentMethodType::Sepa,
+        }
+    }
+
+    pub fn get_bank_account_holder_name(&self) -> Option<&masking::Secret<String>> {
+        match self {
+            Self::Ach {
+                bank_account_holder_name,
+                ..
+            }
+            | Self::Sepa {
+                bank_account_holder_name,
+                ..
+            } => bank_account_holder_name.as_ref(),
+        }
+    }
+
+    /// Last four characters of the account number or IBAN, which can be displayed to the customer
+    pub fn get_mask(&self) -> String {
+        let account_identifier = match self {
+            Self::Ach { account_number, .. } => account_number.peek(),
+            Self::Sepa { iban, .. } => iban.peek(),
+        };
+        let account_identifier = account_identifier
+            .chars()
+            .filter(|character| !character.is_whitespace())
+            .collect::<Vec<_>>();
+        account_identifier
+            .iter()
+            .skip(account_identifier.len().saturating_sub(4))
+            .collect()
+    }
+
+    /// String uniquely identifying the bank account, used for generating its fingerprint
+    pub fn get_fingerprint_source(&self) -> String {
+        match self {
+            Self::Ach {
+                account_number,
+                routing_number,
+                ..
+            } => format!(
+                "{}-{}-{}",
+                account_number.peek(),
+                routing_number.peek(),
+                api_enums::PaymentMethodType::Ach
+            ),
+            Self::Sepa { iban, .. } => format!(
+                "{}-{}",
+                iban.peek()
+                    .chars()
+                    .filter(|character| !character.is_whitespace())
+                    .collect::<String>()
+                    .to_uppercase(),
+                api_enums::PaymentMethodType::Sepa
+            ),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), error_stack::Report<errors::ValidationError>> {
+        match self {
+            Self::Ach {
+                account_number,
+                routing_number,
+                ..
+            } => {
+                common_utils::validation::validate_ach_account_number(account_number.peek())?;
+                common_utils::validation::validate_aba_routing_number(routing_number.peek())?;
+            }
+            Self::Sepa { iban, .. } => {
+                common_utils::validation::validate_iban(iban.peek())?;
+            }
+        }
+        Ok(())
+    }
+}
+
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
 impl PaymentMethodCreate {
     pub fn validate_payment_method_data_against_payment_method(
@@ -874,6 +1001,10 @@ pub struct PaymentMethodResponse {
 
     /// For Client based calls
     pub client_secret: Option<String>,
+
+    /// The consent given by the customer to store the payment method
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consent: Option<PaymentMethodConsent>,
 }
 
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
@@ -2126,6 +2257,11 @@ pub struct CustomerPaymentMethod {
     /// The billing details of the payment method
     #[schema(value_type = Option<Address>)]
     pub billing: Option<payments::Address>,
+
+    /// The name given by the customer to identify the saved payment method
+    #[schema(example = "Personal card")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<String>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -2244,6 +2380,239 @@ pub struct DefaultPaymentMethod {
     pub payment_method_id: String,
 }
 
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomerPaymentMethodUpdateRequest {
+    /// A name given by the customer to identify the saved payment method
+    #[schema(max_length = 64, example = "Personal card")]
+    pub nickname: Option<String>,
+
+    /// Sets the payment method as the default for the given scope. An empty scope sets it as
+    /// the default across all currencies and profiles
+    pub set_default_for: Option<DefaultPaymentMethodScope>,
+
+    /// A disabled payment method is not listed during checkout, but can still be used for
+    /// mandate and other merchant initiated payments
+    #[schema(example = false)]
+    pub disabled: Option<bool>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, PartialEq, Eq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DefaultPaymentMethodScope {
+    /// The currency for which the payment method is the default
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+
+    /// The profile for which the payment method is the default
+    #[schema(value_type = Option<String>, example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<id_type::ProfileId>,
+}
+
+#[cfg(feature = "v1")]
+impl DefaultPaymentMethodScope {
+    /// Checks if this scope applies to a payment in the given currency and profile. Scopes with
+    /// fewer constraints are considered to be applicable more broadly
+    pub fn is_applicable(
+        &self,
+        currency: Option<api_enums::Currency>,
+        profile_id: Option<&id_type::ProfileId>,
+    ) -> bool {
+        self.currency
+            .map_or(true, |scope_currency| Some(scope_currency) == currency)
+            && self.profile_id.as_ref().map_or(true, |scope_profile_id| {
+                Some(scope_profile_id) == profile_id
+            })
+    }
+
+    /// The number of constraints present in the scope, used to pick the most specific default
+    pub fn get_specificity(&self) -> u8 {
+        u8::from(self.currency.is_some()) + u8::from(self.profile_id.is_some())
+    }
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+pub struct CustomerPaymentMethodUpdateResponse {
+    /// The unique identifier of the Payment method
+    #[schema(example = "card_rGK4Vi5iSW70MY7J2mIg")]
+    pub payment_method_id: String,
+
+    /// The unique identifier of the customer.
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+
+    /// The name given by the customer to identify the saved payment method
+    #[schema(example = "Personal card")]
+    pub nickname: Option<String>,
+
+    /// The scopes for which this payment method is the default
+    pub default_for: Vec<DefaultPaymentMethodScope>,
+
+    /// Whether the payment method is hidden during checkout
+    #[schema(example = false)]
+    pub disabled: bool,
+
+    /// The recent default payment method changes of the customer that involve this payment method
+    pub default_changes: Vec<DefaultPaymentMethodChange>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentMethodPreferenceUpdatedBy {
+    /// The change was made by the merchant using an API key
+    Merchant,
+    /// The change was made by the customer using an ephemeral key
+    Customer,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+pub struct DefaultPaymentMethodChange {
+    /// The scope for which the default was changed
+    pub scope: DefaultPaymentMethodScope,
+
+    /// The payment method that was the default before the change
+    pub previous_payment_method_id: Option<String>,
+
+    /// The payment method that is the default after the change
+    pub payment_method_id: String,
+
+    /// The party that made the change
+    pub updated_by: PaymentMethodPreferenceUpdatedBy,
+
+    /// A timestamp (ISO 8601 code) that determines when the change was made
+    #[schema(value_type = PrimitiveDateTime, example = "2024-02-24T11:04:09.922Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub changed_at: time::PrimitiveDateTime,
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+/// The status of the consent given by the customer to store the payment method
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentMethodConsentStatus {
+    /// The customer has consented to the payment method being stored and used
+    Active,
+    /// The customer has revoked the consent. The payment method is no longer listed for the
+    /// customer, and cannot be charged by merchant initiated payments.
+    Revoked,
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+/// The action recorded by a consent event
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentMethodConsentAction {
+    /// The consent was captured while saving the payment method during a payment
+    Collected,
+    /// The consent was collected again by the merchant
+    Recollected,
+    /// The consent was revoked by the merchant on behalf of the customer
+    Revoked,
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+pub struct PaymentMethodConsentEvent {
+    /// The action recorded by the event
+    pub action: PaymentMethodConsentAction,
+
+    /// Type of acceptance provided by the customer
+    #[schema(value_type = Option<AcceptanceType>, example = "online")]
+    pub acceptance_type: Option<payments::AcceptanceType>,
+
+    /// A timestamp (ISO 8601 code) that determines when the customer accepted the consent
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2024-02-24T11:04:09.922Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub accepted_at: Option<time::PrimitiveDateTime>,
+
+    /// Ip address of the customer machine from which the consent was given
+    #[schema(value_type = Option<String>, example = "123.32.25.123")]
+    pub ip_address: Option<masking::Secret<String, pii::IpAddress>>,
+
+    /// The user-agent of the customer's browser
+    pub user_agent: Option<String>,
+
+    /// The version of the consent text that was presented to the customer
+    #[schema(example = "2024-01-v1")]
+    pub consent_text_version: Option<String>,
+
+    /// The reason provided for revoking the consent
+    pub reason: Option<String>,
+
+    /// A timestamp (ISO 8601 code) that determines when the event was recorded
+    #[schema(value_type = PrimitiveDateTime, example = "2024-02-24T11:04:09.922Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub recorded_at: time::PrimitiveDateTime,
+}
+
+/// The consent given by the customer to store the payment method and use it for future payments
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+pub struct PaymentMethodConsent {
+    /// The current status of the consent
+    pub status: PaymentMethodConsentStatus,
+
+    /// The latest consent collected from the customer
+    pub latest_consent: Option<PaymentMethodConsentEvent>,
+
+    /// All the consent events of the payment method, oldest first
+    pub history: Vec<PaymentMethodConsentEvent>,
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodConsentRecollectRequest {
+    /// The consent collected again from the customer
+    pub customer_acceptance: payments::CustomerAcceptance,
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[derive(Debug, Default, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodConsentRevokeRequest {
+    /// The reason for revoking the consent
+    #[schema(example = "Customer requested removal of the stored card")]
+    pub reason: Option<String>,
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+pub struct PaymentMethodConsentResponse {
+    /// The unique identifier of the Payment method
+    #[schema(example = "card_rGK4Vi5iSW70MY7J2mIg")]
+    pub payment_method_id: String,
+
+    /// The consent of the customer for the payment method
+    pub consent: PaymentMethodConsent,
+}
+
 //------------------------------------------------TokenizeService------------------------------------------------
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct TokenizePayloadEncrypted {
@@ -2591,6 +2960,57 @@ impl From<(PaymentMethodRecord, id_type::MerchantId)> for customers::CustomerReq
     }
 }
 
+/// Request to import the payment methods of customers from the token vault of another provider,
+/// as per a migration agreement
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct ExternalTokenMigrationRequest {
+    /// Merchant id
+    pub merchant_id: id_type::MerchantId,
+
+    /// The identifier of the migration agreement. It decides the adapter through which the
+    /// external tokens are detokenized
+    pub migration_agreement_id: String,
+
+    /// The payment methods to be imported
+    pub records: Vec<ExternalTokenMigrationRecord>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct ExternalTokenMigrationRecord {
+    /// The identifier of an existing customer to which the payment method has to be mapped
+    pub customer_id: id_type::CustomerId,
+
+    /// The token of the card in the external vault
+    pub external_token: Option<masking::Secret<String>>,
+
+    /// Card details. Required with a masked card number if the external token is not provided,
+    /// and used to fill in the details that the migration adapter does not return otherwise
+    pub card: Option<MigrateCardDetail>,
+
+    /// Network token details of the card
+    pub network_token: Option<MigrateNetworkTokenDetail>,
+
+    /// The billing details of the payment method
+    pub billing: Option<payments::Address>,
+
+    /// The CIT (customer initiated transaction) transaction id associated with the payment method
+    pub network_transaction_id: Option<String>,
+
+    /// Metadata of the payment method
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ExternalTokenMigrationResponse {
+    /// The identifier of the migration agreement
+    pub migration_agreement_id: String,
+
+    /// The status of the migration of each record, in the order of the request
+    pub records: Vec<PaymentMethodMigrationResponse>,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct CardNetworkTokenizeRequest {
     /// Merchant ID associated with the tokenization request
@@ -2861,3 +3281,573 @@ pub struct AuthenticationDetails {
     #[schema(value_type = ErrorDetails)]
     pub error: Option<payments::ErrorDetails>,
 }
+
+/// Profile level configuration which controls the order and the conditional visibility of the
+/// payment methods returned by the payment methods list endpoint
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodDisplayConfig {
+    /// The order in which the payment methods have to be listed. Payment methods which are not
+    /// present in this list are listed after the ones that are present, in their existing order
+    #[serde(default)]
+    pub display_order: Vec<PaymentMethodDisplayOrder>,
+
+    /// Rules deciding when a payment method has to be hidden from the list
+    #[serde(default)]
+    pub visibility_rules: Vec<PaymentMethodVisibilityRule>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodDisplayOrder {
+    /// The payment method to be ordered
+    #[schema(value_type = PaymentMethod, example = "card")]
+    pub payment_method: api_enums::PaymentMethod,
+
+    /// The payment method types to be ordered within the payment method. If not provided, the
+    /// payment method types are listed in their existing order
+    #[schema(value_type = Option<Vec<PaymentMethodType>>, example = json!(["credit", "debit"]))]
+    pub payment_method_types: Option<Vec<api_enums::PaymentMethodType>>,
+}
+
+/// A rule which hides a payment method (or a specific payment method type) from the list when
+/// any of its conditions is not satisfied by the payment
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodVisibilityRule {
+    /// The payment method to which the rule applies
+    #[schema(value_type = PaymentMethod, example = "pay_later")]
+    pub payment_method: api_enums::PaymentMethod,
+
+    /// The payment method type to which the rule applies. If not provided, the rule applies to
+    /// all the payment method types of the payment method
+    #[schema(value_type = Option<PaymentMethodType>, example = "klarna")]
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+
+    /// The payment method is shown only if the payment amount is at least this amount
+    #[schema(value_type = Option<i64>, example = 100)]
+    pub min_amount: Option<MinorUnit>,
+
+    /// The payment method is shown only if the payment amount is at most this amount
+    #[schema(value_type = Option<i64>, example = 50000)]
+    pub max_amount: Option<MinorUnit>,
+
+    /// The payment method is shown only if the billing country is one of these countries
+    #[schema(value_type = Option<Vec<CountryAlpha2>>, example = json!(["US", "DE"]))]
+    pub allowed_countries: Option<HashSet<api_enums::CountryAlpha2>>,
+
+    /// The payment method is shown only if the payment currency is one of these currencies
+    #[schema(value_type = Option<Vec<Currency>>, example = json!(["USD", "EUR"]))]
+    pub allowed_currencies: Option<HashSet<api_enums::Currency>>,
+}
+
+impl PaymentMethodVisibilityRule {
+    fn is_applicable(
+        &self,
+        payment_method: api_enums::PaymentMethod,
+        payment_method_type: api_enums::PaymentMethodType,
+    ) -> bool {
+        self.payment_method == payment_method
+            && self
+                .payment_method_type
+                .map_or(true, |rule_pm_type| rule_pm_type == payment_method_type)
+    }
+
+    /// Checks whether the payment method type satisfies this rule for the given payment details.
+    /// A condition for which the payment detail is not available is considered to be satisfied.
+    pub fn is_satisfied(
+        &self,
+        payment_method: api_enums::PaymentMethod,
+        payment_method_type: api_enums::PaymentMethodType,
+        amount: Option<MinorUnit>,
+        country: Option<api_enums::CountryAlpha2>,
+        currency: Option<api_enums::Currency>,
+    ) -> bool {
+        if !self.is_applicable(payment_method, payment_method_type) {
+            return true;
+        }
+
+        let is_amount_within_limits = amount.map_or(true, |amount| {
+            self.min_amount
+                .map_or(true, |min_amount| amount >= min_amount)
+                && self
+                    .max_amount
+                    .map_or(true, |max_amount| amount <= max_amount)
+        });
+        let is_country_allowed = country
+            .zip(self.allowed_countries.as_ref())
+            .map_or(true, |(country, allowed_countries)| {
+                allowed_countries.contains(&country)
+            });
+        let is_currency_allowed = currency
+            .zip(self.allowed_currencies.as_ref())
+            .map_or(true, |(currency, allowed_currencies)| {
+                allowed_currencies.contains(&currency)
+            });
+
+        is_amount_within_limits && is_country_allowed && is_currency_allowed
+    }
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+impl PaymentMethodDisplayConfig {
+    /// Removes the payment method types that do not satisfy the visibility rules and orders the
+    /// remaining payment methods as per the configured display order
+    pub fn apply(
+        &self,
+        payment_methods: &mut Vec<ResponsePaymentMethodsEnabled>,
+        amount: Option<MinorUnit>,
+        country: Option<api_enums::CountryAlpha2>,
+        currency: Option<api_enums::Currency>,
+    ) {
+        for payment_method in payment_methods.iter_mut() {
+            let pm = payment_method.payment_method;
+            payment_method.payment_method_types.retain(|pm_type| {
+                self.visibility_rules.iter().all(|rule| {
+                    rule.is_satisfied(pm, pm_type.payment_method_type, amount, country, currency)
+                })
+            });
+        }
+        payment_methods.retain(|payment_method| !payment_method.payment_method_types.is_empty());
+
+        // `sort_by_key` is stable, so payment methods which are not configured retain their order
+        payment_methods
+            .sort_by_key(|payment_method| self.get_display_position(payment_method.payment_method));
+
+        for payment_method in payment_methods.iter_mut() {
+            if let Some(pm_type_order) = self
+                .display_order
+                .iter()
+                .find(|order| order.payment_method == payment_method.payment_method)
+                .and_then(|order| order.payment_method_types.as_ref())
+            {
+                payment_method.payment_method_types.sort_by_key(|pm_type| {
+                    pm_type_order
+                        .iter()
+                        .position(|ordered_type| *ordered_type == pm_type.payment_method_type)
+                        .unwrap_or(pm_type_order.len())
+                });
+            }
+        }
+    }
+
+    fn get_display_position(&self, payment_method: api_enums::PaymentMethod) -> usize {
+        self.display_order
+            .iter()
+            .position(|order| order.payment_method == payment_method)
+            .unwrap_or(self.display_order.len())
+    }
+
+    /// Validates that the configuration does not contain conflicting entries
+    pub fn validate(&self) -> Result<(), error_stack::Report<errors::ValidationError>> {
+        let mut ordered_payment_methods = HashSet::new();
+        for order in &self.display_order {
+            if !ordered_payment_methods.insert(order.payment_method) {
+                return Err(errors::ValidationError::InvalidValue {
+                    message: format!(
+                        "payment method `{}` is present more than once in display_order",
+                        order.payment_method
+                    ),
+                }
+                .into());
+            }
+        }
+
+        for rule in &self.visibility_rules {
+            if let Some((min_amount, max_amount)) = rule.min_amount.zip(rule.max_amount) {
+                if min_amount > max_amount {
+                    return Err(errors::ValidationError::InvalidValue {
+                        message: format!(
+                            "min_amount cannot be greater than max_amount in the visibility rule for `{}`",
+                            rule.payment_method
+                        ),
+                    }
+                    .into());
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+const PAYMENT_METHOD_DISPLAY_LOCALE_MAX_LENGTH: usize = 35;
+const PAYMENT_METHOD_DISPLAY_NAME_MAX_LENGTH: usize = 64;
+const PAYMENT_METHOD_ICON_MAX_LENGTH: usize = 255;
+
+/// Override of a profile for the display name and the icon of a payment method or of a payment
+/// method type
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodDisplayOverride {
+    /// Display names keyed by locale. The name in the `en` locale replaces the name of the
+    /// registry in the locales into which it is not translated.
+    #[serde(default)]
+    #[schema(value_type = HashMap<String, String>, example = json!({"en": "Pay with iDEAL", "nl": "Betaal met iDEAL"}))]
+    pub display_names: HashMap<String, String>,
+
+    /// The identifier of the icon, or the URL of the image, shown for the payment method
+    #[schema(example = "ideal")]
+    pub icon: Option<String>,
+}
+
+impl PaymentMethodDisplayOverride {
+    fn validate(
+        &self,
+        payment_method: &str,
+    ) -> Result<(), error_stack::Report<errors::ValidationError>> {
+        if self.display_names.is_empty() && self.icon.is_none() {
+            return Err(errors::ValidationError::InvalidValue {
+                message: format!(
+                    "override of `{payment_method}` must have a display name or an icon"
+                ),
+            }
+            .into());
+        }
+
+        for (locale, display_name) in &self.display_names {
+            let is_valid_locale = !locale.is_empty()
+                && locale.len() <= PAYMENT_METHOD_DISPLAY_LOCALE_MAX_LENGTH
+                && locale.chars().all(|character| {
+                    character.is_ascii_alphanumeric() || character == '-' || character == '_'
+                });
+            if !is_valid_locale {
+                return Err(errors::ValidationError::InvalidValue {
+                    message: format!(
+                        "locale `{locale}` of `{payment_method}` must be between 1 and {PAYMENT_METHOD_DISPLAY_LOCALE_MAX_LENGTH} characters long and contain only letters, digits, hyphens and underscores"
+                    ),
+                }
+                .into());
+            }
+            if display_name.trim().is_empty()
+                || display_name.len() > PAYMENT_METHOD_DISPLAY_NAME_MAX_LENGTH
+            {
+                return Err(errors::ValidationError::InvalidValue {
+                    message: format!(
+                        "display name of `{payment_method}` in locale `{locale}` must be between 1 and {PAYMENT_METHOD_DISPLAY_NAME_MAX_LENGTH} characters long"
+                    ),
+                }
+                .into());
+            }
+        }
+
+        if let Some(icon) = &self.icon {
+            if icon.trim().is_empty() || icon.len() > PAYMENT_METHOD_ICON_MAX_LENGTH {
+                return Err(errors::ValidationError::InvalidValue {
+                    message: format!(
+                        "icon of `{payment_method}` must be between 1 and {PAYMENT_METHOD_ICON_MAX_LENGTH} characters long"
+                    ),
+                }
+                .into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Profile level overrides of the display names and the icons of the registry of the payment
+/// methods, which take precedence over the registry
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodDisplayNameOverrides {
+    /// Overrides keyed by payment method
+    #[serde(default)]
+    #[schema(value_type = HashMap<String, PaymentMethodDisplayOverride>, example = json!({"pay_later": {"display_names": {"en": "Buy now, pay later"}}}))]
+    pub payment_methods: HashMap<api_enums::PaymentMethod, PaymentMethodDisplayOverride>,
+
+    /// Overrides keyed by payment method type
+    #[serde(default)]
+    #[schema(value_type = HashMap<String, PaymentMethodDisplayOverride>, example = json!({"ideal": {"display_names": {"en": "Pay with iDEAL"}, "icon": "ideal_dark"}}))]
+    pub payment_method_types: HashMap<api_enums::PaymentMethodType, PaymentMethodDisplayOverride>,
+}
+
+impl PaymentMethodDisplayNameOverrides {
+    pub fn is_empty(&self) -> bool {
+        self.payment_methods.is_empty() && self.payment_method_types.is_empty()
+    }
+
+    /// Validates that the overrides have a display name or an icon, that the locales are language
+    /// tags of at most `PAYMENT_METHOD_DISPLAY_LOCALE_MAX_LENGTH` characters, and that the display
+    /// names and the icons are not empty and within their maximum length
+    pub fn validate(&self) -> Result<(), error_stack::Report<errors::ValidationError>> {
+        self.payment_methods
+            .iter()
+            .try_for_each(|(payment_method, display_override)| {
+                display_override.validate(&payment_method.to_string())
+            })?;
+        self.payment_method_types
+            .iter()
+            .try_for_each(|(payment_method_type, display_override)| {
+                display_override.validate(&payment_method_type.to_string())
+            })
+    }
+}
+
+/// Query parameters to list the display names and the icons of the payment methods
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodDisplayNamesRequest {
+    /// The profile whose overrides are applied to the registry. Defaults to the profile of the
+    /// authentication, and to the registry without overrides in the absence of both.
+    #[schema(value_type = Option<String>, example = "pro_abcdefghijklmnopqrst")]
+    pub profile_id: Option<id_type::ProfileId>,
+
+    /// The locale of the display names. Defaults to the locales of the `Accept-Language` header.
+    #[schema(example = "fr-BE")]
+    pub locale: Option<String>,
+}
+
+/// The display name and the icon of a payment method
+#[derive(Debug, Clone, PartialEq, serde::Serialize, ToSchema)]
+pub struct PaymentMethodDisplayName {
+    /// The payment method
+    #[schema(value_type = PaymentMethod, example = "bank_redirect")]
+    pub payment_method: api_enums::PaymentMethod,
+
+    /// The name shown to the payers
+    #[schema(example = "Online Banking")]
+    pub display_name: String,
+
+    /// The identifier of the icon, or the URL of the image, shown for the payment method
+    #[schema(example = "bank_redirect")]
+    pub icon: String,
+}
+
+/// The display name and the icon of a payment method type
+#[derive(Debug, Clone, PartialEq, serde::Serialize, ToSchema)]
+pub struct PaymentMethodTypeDisplayName {
+    /// The payment method type
+    #[schema(value_type = PaymentMethodType, example = "ideal")]
+    pub payment_method_type: api_enums::PaymentMethodType,
+
+    /// The name shown to the payers
+    #[schema(example = "iDEAL")]
+    pub display_name: String,
+
+    /// The identifier of the icon, or the URL of the image, shown for the payment method type
+    #[schema(example = "ideal")]
+    pub icon: String,
+}
+
+/// The display names and the icons of all the payment methods and payment method types, from the
+/// registry with the overrides of the profile applied
+#[derive(Debug, Clone, PartialEq, serde::Serialize, ToSchema)]
+pub struct PaymentMethodDisplayNamesResponse {
+    /// The display names and the icons of the payment methods
+    pub payment_methods: Vec<PaymentMethodDisplayName>,
+
+    /// The display names and the icons of the payment method types
+    pub payment_method_types: Vec<PaymentMethodTypeDisplayName>,
+}
+
+impl PaymentMethodDisplayNamesResponse {
+    /// Builds the display names in the first of the locales which has one, from the overrides of
+    /// the profile and then from the registry. The names of the overrides in the default locale of
+    /// the registry replace the names of the registry which are not translated into the locales.
+    pub fn from_registry(
+        locales: &[String],
+        overrides: Option<&PaymentMethodDisplayNameOverrides>,
+    ) -> Self {
+        use common_types::payment_method_display as registry;
+        use strum::IntoEnumIterator;
+
+        let locales = || {
+            locales.iter().map(String::as_str).chain(std::iter::once(
+                registry::DEFAULT_PAYMENT_METHOD_DISPLAY_LOCALE,
+            ))
+        };
+
+        let payment_methods = api_enums::PaymentMethod::iter()
+            .map(|payment_method| {
+                let info = registry::get_payment_method_display_info(payment_method);
+                let display_override =
+                    overrides.and_then(|overrides| overrides.payment_methods.get(&payment_method));
+                let display_name = locales()
+                    .find_map(|locale| {
+                        display_override
+                            .and_then(|display_override| {
+                                display_override.display_names.get(locale).cloned()
+                            })
+                            .or_else(|| {
+                                registry::get_localized_payment_method_display_name(
+                                    payment_method,
+                                    locale,
+                                )
+                                .map(str::to_string)
+                            })
+                    })
+                    .unwrap_or_else(|| info.display_name.to_string());
+                PaymentMethodDisplayName {
+                    payment_method,
+                    display_name,
+                    icon: display_override
+                        .and_then(|display_override| display_override.icon.clone())
+                        .unwrap_or_else(|| info.icon.to_string()),
+                }
+            })
+            .collect();
+
+        let payment_method_types = api_enums::PaymentMethodType::iter()
+            .map(|payment_method_type| {
+                let info = registry::get_payment_method_type_display_info(payment_method_type);
+                let display_override = overrides
+                    .and_then(|overrides| overrides.payment_method_types.get(&payment_method_type));
+                let display_name = locales()
+                    .find_map(|locale| {
+                        display_override
+                            .and_then(|display_override| {
+                                display_override.display_names.get(locale).cloned()
+                            })
+                            .or_else(|| {
+                                registry::get_localized_payment_method_type_display_name(
+                                    payment_method_type,
+                                    locale,
+                                )
+                                .map(str::to_string)
+                            })
+                    })
+                    .unwrap_or_else(|| info.display_name.to_string());
+                PaymentMethodTypeDisplayName {
+                    payment_method_type,
+                    display_name,
+                    icon: display_override
+                        .and_then(|display_override| display_override.icon.clone())
+                        .unwrap_or_else(|| info.icon.to_string()),
+                }
+            })
+            .collect();
+
+        Self {
+            payment_methods,
+            payment_method_types,
+        }
+    }
+}
+
+/// Profile level configuration of a merchant hosted vault. When configured, the card details
+/// saved during payments of the profile are tokenized with this vault instead of the
+/// Hyperswitch locker
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ExternalVaultConfigRequest {
+    /// The base url of the vault. The `/tokenize`, `/detokenize` and `/delete` endpoints are
+    /// called relative to this url, which must use https and be on one of the vault hosts allowed
+    /// for the deployment
+    #[schema(value_type = String, example = "https://vault.example.com/v1")]
+    pub base_url: url::Url,
+
+    /// The header in which the api key is sent to the vault. Defaults to `Authorization`
+    #[schema(example = "x-api-key")]
+    pub api_key_header: Option<String>,
+
+    /// The api key used to authenticate with the vault
+    #[schema(value_type = String)]
+    pub api_key: masking::Secret<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct ExternalVaultConfigResponse {
+    /// The identifier of the profile
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+
+    /// The base url of the vault
+    #[schema(value_type = String, example = "https://vault.example.com/v1")]
+    pub base_url: url::Url,
+
+    /// The header in which the api key is sent to the vault
+    #[schema(example = "x-api-key")]
+    pub api_key_header: String,
+
+    /// Timestamp at which the configuration was last updated
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+/// Profile level configuration which decides when the CVV of a saved card has to be re-collected
+/// for on-session payments
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CvvRecollectionConfig {
+    /// Re-collect the CVV for all the on-session saved card payments of the profile
+    #[serde(default)]
+    pub enforce_for_all_connectors: bool,
+
+    /// Re-collect the CVV only when the payment is routed to one of these connectors
+    #[serde(default)]
+    #[schema(value_type = Vec<Connector>, example = json!(["stripe", "adyen"]))]
+    pub connectors: Vec<api_enums::Connector>,
+}
+
+impl CvvRecollectionConfig {
+    /// Checks if the CVV has to be re-collected when the payment is routed to the connector
+    pub fn is_required_for_connector(&self, connector: Option<&str>) -> bool {
+        self.enforce_for_all_connectors
+            || connector
+                .and_then(|connector| connector.parse::<api_enums::Connector>().ok())
+                .is_some_and(|connector| self.connectors.contains(&connector))
+    }
+}
+
+/// Profile level configuration which decides whether the saved cards which have a network token
+/// are authorized with the network token or with the card number held in the vault
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct NetworkTokenPreferenceConfig {
+    /// The credential with which the payments of the profile are authorized, unless configured
+    /// for the connector to which the payment is routed
+    #[serde(default)]
+    #[schema(value_type = CardCredentialType, example = "network_token")]
+    pub default_preference: api_enums::CardCredentialType,
+
+    /// The credential with which the payments routed to the connector are authorized
+    #[serde(default)]
+    #[schema(value_type = HashMap<Connector, CardCredentialType>, example = json!({"adyen": "pan"}))]
+    pub connectors: HashMap<api_enums::Connector, api_enums::CardCredentialType>,
+}
+
+impl NetworkTokenPreferenceConfig {
+    /// Returns the credential with which the payments routed to the connector are authorized
+    pub fn get_preference_for_connector(
+        &self,
+        connector: Option<api_enums::Connector>,
+    ) -> api_enums::CardCredentialType {
+        connector
+            .and_then(|connector| self.connectors.get(&connector).copied())
+            .unwrap_or(self.default_preference)
+    }
+}
+
+/// Request to tokenize the CVV re-collected by the SDK for a saved card payment
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CvvTokenizeRequest {
+    /// The client secret of the payment
+    #[schema(example = "pay_U42c409qyHwOkWo3vK60_secret_el9ksDkiB8hi6j9N78yo")]
+    pub client_secret: String,
+
+    /// The payment token of the saved card for which the CVV is collected
+    #[schema(example = "187282ab-40ef-47a9-9206-5099ba31e432")]
+    pub payment_token: String,
+
+    /// The CVV of the card
+    #[schema(value_type = String, example = "123")]
+    pub card_cvc: masking::Secret<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct CvvTokenizeResponse {
+    /// The single use token of the CVV, to be passed as `cvv_token` while confirming the payment
+    #[schema(example = "cvv_Ef2EYuZWAoNYujHzplZb")]
+    pub cvv_token: String,
+
+    /// Timestamp after which the token can no longer be used
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: time::PrimitiveDateTime,
+}
@@ -978,6 +978,11 @@ pub struct PaymentsRequest {
     #[remove_in(PaymentsUpdateRequest, PaymentsCreateRequest, PaymentsConfirmRequest)]
     pub card_cvc: Option<Secret<String>>,
 
+    /// The token of the CVV re-collected through the SDK for a saved card payment, used along with the payment_token field. The token can be used only once
+    #[schema(example = "cvv_Ef2EYuZWAoNYujHzplZb")]
+    #[remove_in(PaymentsUpdateRequest, PaymentsCreateRequest)]
+    pub cvv_token: Option<String>,
+
     /// The shipping address for the payment
     pub shipping: Option<Address>,
 
@@ -1151,6 +1156,65 @@ pub struct PaymentsRequest {
 
     /// Indicates if 3DS method data was successfully completed or not
     pub threeds_method_comp_ind: Option<ThreeDsCompletionIndicator>,
+
+    /// Authentication values obtained by the merchant from their own 3DS server, which are passed on to the connector for the authorization
+    pub three_ds_data: Option<ExternalThreeDsData>,
+
+    /// Details of the sub-merchant on whose behalf the payment is processed. These override the payment facilitator details configured for the profile, and are considered only when the payment is confirmed
+    pub payment_facilitator_details: Option<PaymentFacilitatorDetails>,
+
+    /// Indicates that the amount is an estimate of the final amount of the payment, as for hotel
+    /// stays, fuel or rides. The estimated amount is authorized, and the authorization is adjusted
+    /// to the final amount once it is known, through the finalize amount API.
+    ///
+    /// capture method must be manual
+    #[schema(default = false, example = true)]
+    #[remove_in(PaymentsUpdateRequest, PaymentsConfirmRequest)]
+    pub is_amount_estimated: Option<bool>,
+
+    /// Values of the custom fields defined in the custom fields schema of the profile, keyed by the key of the field. The values are validated against the schema, and are accepted only on the creation of the payment
+    #[schema(value_type = Option<Object>, example = r#"{ "store_id": "store_42", "terminal_count": 3 }"#)]
+    #[remove_in(PaymentsUpdateRequest, PaymentsConfirmRequest)]
+    pub custom_fields: Option<HashMap<String, serde_json::Value>>,
+
+    /// Allows the payment to be paid by multiple attempts, each paying a part of the amount, as for a gift card and a card. The payment succeeds once the attempts have paid its whole amount, and is confirmed again with another payment method after each attempt which leaves a part of it to be paid.
+    ///
+    /// capture method must be automatic, and shipping cost, estimated amount and mandates are not supported
+    #[schema(default = false, example = true)]
+    #[remove_in(PaymentsUpdateRequest, PaymentsConfirmRequest)]
+    pub enable_partial_payments: Option<bool>,
+
+    /// The part of the amount to be paid by this attempt of a payment with partial payments enabled. Defaults to the amount which remains to be paid, or to the balance of a gift card which does not cover it, and cannot exceed it.
+    #[schema(value_type = Option<i64>, example = 2500)]
+    #[remove_in(PaymentsUpdateRequest)]
+    pub partial_amount: Option<MinorUnit>,
+
+    /// The part of the amount to be paid with the store credit balance of the customer, which is credited by the refunds to store credit. The balance is debited when the payment is confirmed, and credited back if the payment fails or is cancelled. The attempts of the payment charge the rest of the amount, so it has to be less than the amount.
+    ///
+    /// The payment requires a customer, and partial payments are not supported
+    #[schema(value_type = Option<i64>, example = 1500)]
+    #[remove_in(PaymentsUpdateRequest, PaymentsConfirmRequest)]
+    pub store_credit_amount: Option<MinorUnit>,
+}
+
+/// Authentication values of a 3DS authentication performed outside of Hyperswitch
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ExternalThreeDsData {
+    /// Cardholder Authentication Verification Value (CAVV) returned by the directory server
+    #[schema(value_type = String, example = "AAABBEg0VhI0VniQEjRWAAAAAAA=")]
+    pub cavv: Secret<String>,
+    /// Electronic Commerce Indicator (ECI) returned by the directory server
+    #[schema(example = "05")]
+    pub eci: Option<String>,
+    /// Transaction identifier assigned by the directory server
+    #[schema(example = "c4e59ceb-a382-4d6a-bc87-385d591fa09d")]
+    pub ds_trans_id: Option<String>,
+    /// Transaction identifier assigned by the 3DS server
+    pub threeds_server_transaction_id: Option<String>,
+    /// Version of the 3DS protocol used for the authentication
+    #[schema(value_type = Option<String>, example = "2.2.0")]
+    pub message_version: Option<common_utils::types::SemanticVersion>,
 }
 
 #[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -1165,6 +1229,728 @@ pub struct CtpServiceDetails {
     pub provider: Option<String>,
 }
 
+/// Details sent to the connector when a platform or a marketplace, acting as a payment facilitator,
+/// processes a payment on behalf of one of its sub-merchants
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentFacilitatorDetails {
+    /// The four digit merchant category code (ISO 18245) of the sub-merchant
+    #[schema(example = "5812")]
+    pub merchant_category_code: Option<String>,
+    /// The descriptor shown to the customer on their card statement
+    #[schema(max_length = 22, example = "PLATFORM*JOES PIZZA")]
+    pub soft_descriptor: Option<String>,
+    /// The identifier assigned to the payment facilitator by the card schemes
+    #[schema(value_type = Option<String>)]
+    pub payment_facilitator_id: Option<Secret<String>>,
+    /// The identifier of the independent sales organization sponsoring the payment facilitator
+    #[schema(value_type = Option<String>)]
+    pub independent_sales_organization_id: Option<Secret<String>>,
+    /// The sub-merchant on whose behalf the payment is processed
+    pub sub_merchant: Option<SubMerchantDetails>,
+}
+
+/// Maximum length of the soft descriptor accepted by the card schemes
+const SOFT_DESCRIPTOR_MAX_LENGTH: usize = 22;
+
+impl PaymentFacilitatorDetails {
+    /// Overrides the details configured for the profile with the ones passed in the payment. The
+    /// sub-merchant is overridden as a whole
+    pub fn merge(self, overrides: Self) -> Self {
+        Self {
+            merchant_category_code: overrides
+                .merchant_category_code
+                .or(self.merchant_category_code),
+            soft_descriptor: overrides.soft_descriptor.or(self.soft_descriptor),
+            payment_facilitator_id: overrides
+                .payment_facilitator_id
+                .or(self.payment_facilitator_id),
+            independent_sales_organization_id: overrides
+                .independent_sales_organization_id
+                .or(self.independent_sales_organization_id),
+            sub_merchant: overrides.sub_merchant.or(self.sub_merchant),
+        }
+    }
+
+    /// Validates the details against the constraints of the card schemes. Constraints specific to
+    /// a connector are validated when the connector request is built
+    pub fn validate(&self) -> Result<(), error_stack::Report<ValidationError>> {
+        if let Some(merchant_category_code) = self.merchant_category_code.as_ref() {
+            if merchant_category_code.len() != 4
+                || !merchant_category_code
+                    .chars()
+                    .all(|character| character.is_ascii_digit())
+            {
+                return Err(ValidationError::InvalidValue {
+                    message: "merchant_category_code must be a four digit code".to_string(),
+                }
+                .into());
+            }
+        }
+
+        if let Some(soft_descriptor) = self.soft_descriptor.as_ref() {
+            if soft_descriptor.trim().is_empty()
+                || soft_descriptor.chars().count() > SOFT_DESCRIPTOR_MAX_LENGTH
+            {
+                return Err(ValidationError::InvalidValue {
+                    message: format!(
+                        "soft_descriptor must be between 1 and {SOFT_DESCRIPTOR_MAX_LENGTH} characters long"
+                    ),
+                }
+                .into());
+            }
+        }
+
+        if let Some(sub_merchant) = self.sub_merchant.as_ref() {
+            if sub_merchant.id.peek().trim().is_empty()
+                || sub_merchant.name.peek().trim().is_empty()
+            {
+                return Err(ValidationError::InvalidValue {
+                    message: "sub_merchant.id and sub_merchant.name must not be empty".to_string(),
+                }
+                .into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SubMerchantDetails {
+    /// The identifier of the sub-merchant assigned by the payment facilitator
+    #[schema(value_type = String, example = "sub_merchant_123")]
+    pub id: Secret<String>,
+    /// The registered name of the sub-merchant
+    #[schema(value_type = String, example = "Joe's Pizza")]
+    pub name: Secret<String>,
+    /// The registered address of the sub-merchant
+    pub address: Option<AddressDetails>,
+    /// The tax identifier of the sub-merchant
+    #[schema(value_type = Option<String>)]
+    pub tax_id: Option<Secret<String>>,
+}
+
+/// Merchant facing statuses of the payments of a profile, sent as the `custom_status` of the
+/// payments in the API responses and the webhooks
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomPaymentStatusMapping {
+    /// Custom statuses keyed by the status of the latest attempt of the payment. These take
+    /// precedence over the custom statuses keyed by the status of the payment
+    #[serde(default)]
+    #[schema(value_type = HashMap<String, String>, example = json!({"authentication_pending": "awaiting_bank_confirmation"}))]
+    pub attempt_statuses: HashMap<enums::AttemptStatus, String>,
+    /// Custom statuses keyed by the status of the payment
+    #[serde(default)]
+    #[schema(value_type = HashMap<String, String>, example = json!({"requires_capture": "awaiting_shipment"}))]
+    pub intent_statuses: HashMap<enums::IntentStatus, String>,
+}
+
+const CUSTOM_PAYMENT_STATUS_MAX_LENGTH: usize = 64;
+
+impl CustomPaymentStatusMapping {
+    pub fn is_empty(&self) -> bool {
+        self.attempt_statuses.is_empty() && self.intent_statuses.is_empty()
+    }
+
+    /// Custom status of a payment with the given payment and attempt statuses
+    pub fn get_custom_status(
+        &self,
+        intent_status: enums::IntentStatus,
+        attempt_status: enums::AttemptStatus,
+    ) -> Option<String> {
+        self.attempt_statuses
+            .get(&attempt_status)
+            .or_else(|| self.intent_statuses.get(&intent_status))
+            .cloned()
+    }
+
+    /// Validates that the custom statuses are snake case labels of at most
+    /// `CUSTOM_PAYMENT_STATUS_MAX_LENGTH` characters
+    pub fn validate(&self) -> Result<(), error_stack::Report<ValidationError>> {
+        self.attempt_statuses
+            .values()
+            .chain(self.intent_statuses.values())
+            .try_for_each(|custom_status| {
+                let is_valid = !custom_status.is_empty()
+                    && custom_status.len() <= CUSTOM_PAYMENT_STATUS_MAX_LENGTH
+                    && custom_status.chars().all(|character| {
+                        character.is_ascii_lowercase()
+                            || character.is_ascii_digit()
+                            || character == '_'
+                    });
+
+                if is_valid {
+                    Ok(())
+                } else {
+                    Err(ValidationError::InvalidValue {
+                        message: format!(
+                            "custom status `{custom_status}` must be between 1 and {CUSTOM_PAYMENT_STATUS_MAX_LENGTH} characters long and contain only lowercase letters, digits and underscores"
+                        ),
+                    }
+                    .into())
+                }
+            })
+    }
+}
+
+/// Schema of the custom fields of the payments of a profile. The merchants send the values of the
+/// fields under the `custom_fields` of the payments on their creation, which are validated against
+/// the schema and returned in the responses and the webhooks of the payments.
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomFieldsSchema {
+    /// The custom fields accepted on the payments of the profile
+    pub fields: Vec<CustomFieldDefinition>,
+}
+
+/// Definition of a custom field of the payments of a profile
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomFieldDefinition {
+    /// The key of the field in the `custom_fields` of the payments, in snake case
+    #[schema(example = "store_id")]
+    pub key: String,
+
+    /// The type of the values of the field
+    #[schema(value_type = CustomFieldType, example = "string")]
+    pub field_type: api_enums::CustomFieldType,
+
+    /// Whether the field must be passed on the creation of the payments
+    #[serde(default)]
+    #[schema(default = false)]
+    pub required: bool,
+
+    /// The maximum length of the values of the `string` fields
+    #[schema(example = 32)]
+    pub max_length: Option<u8>,
+
+    /// The minimum value of the `number` fields
+    pub min_value: Option<f64>,
+
+    /// The maximum value of the `number` fields
+    pub max_value: Option<f64>,
+
+    /// The values allowed for the `enum` fields
+    #[schema(example = json!(["online", "in_store"]))]
+    pub allowed_values: Option<Vec<String>>,
+}
+
+const CUSTOM_FIELD_KEY_MAX_LENGTH: usize = 64;
+/// The maximum length of the values of the custom fields, as stored for the payments
+pub const CUSTOM_FIELD_VALUE_MAX_LENGTH: usize = 255;
+const MAX_CUSTOM_FIELDS: usize = 20;
+
+impl CustomFieldsSchema {
+    pub fn get_field(&self, key: &str) -> Option<&CustomFieldDefinition> {
+        self.fields.iter().find(|field| field.key == key)
+    }
+
+    /// Validates that the keys of the fields are unique snake case keys, and that only the
+    /// constraints applicable to the type of each field are set
+    pub fn validate(&self) -> Result<(), error_stack::Report<ValidationError>> {
+        if self.fields.len() > MAX_CUSTOM_FIELDS {
+            return Err(ValidationError::InvalidValue {
+                message: format!("at most {MAX_CUSTOM_FIELDS} custom fields can be defined"),
+            }
+            .into());
+        }
+
+        let mut keys = HashSet::new();
+        self.fields.iter().try_for_each(|field| {
+            let is_valid_key = !field.key.is_empty()
+                && field.key.len() <= CUSTOM_FIELD_KEY_MAX_LENGTH
+                && field.key.chars().all(|character| {
+                    character.is_ascii_lowercase() || character.is_ascii_digit() || character == '_'
+                });
+            if !is_valid_key {
+                return Err(ValidationError::InvalidValue {
+                    message: format!(
+                        "custom field key `{}` must be between 1 and {CUSTOM_FIELD_KEY_MAX_LENGTH} characters long and contain only lowercase letters, digits and underscores",
+                        field.key
+                    ),
+                }
+                .into());
+            }
+            if !keys.insert(field.key.as_str()) {
+                return Err(ValidationError::InvalidValue {
+                    message: format!("custom field key `{}` is defined more than once", field.key),
+                }
+                .into());
+            }
+
+            field.validate()
+        })
+    }
+}
+
+impl CustomFieldDefinition {
+    fn validate(&self) -> Result<(), error_stack::Report<ValidationError>> {
+        let has_string_constraints = self.max_length.is_some();
+        let has_number_constraints = self.min_value.is_some() || self.max_value.is_some();
+        let has_enum_constraints = self.allowed_values.is_some();
+
+        let error_message = match self.field_type {
+            api_enums::CustomFieldType::String
+                if has_number_constraints || has_enum_constraints =>
+            {
+                Some("only `max_length` can be set for the `string` fields".to_string())
+            }
+            api_enums::CustomFieldType::String => self
+                .max_length
+                .filter(|max_length| *max_length == 0)
+                .map(|_| "`max_length` must be greater than 0".to_string()),
+            api_enums::CustomFieldType::Number
+                if has_string_constraints || has_enum_constraints =>
+            {
+                Some(
+                    "only `min_value` and `max_value` can be set for the `number` fields"
+                        .to_string(),
+                )
+            }
+            api_enums::CustomFieldType::Number => {
+                let is_valid_range = self
+                    .min_value
+                    .zip(self.max_value)
+                    .map_or(true, |(min_value, max_value)| min_value <= max_value);
+                (!is_valid_range)
+                    .then(|| "`min_value` must not be greater than `max_value`".to_string())
+            }
+            api_enums::CustomFieldType::Enum
+                if has_string_constraints || has_number_constraints =>
+            {
+                Some("only `allowed_values` can be set for the `enum` fields".to_string())
+            }
+            api_enums::CustomFieldType::Enum => {
+                let allowed_values = self.allowed_values.as_deref().unwrap_or_default();
+                let is_valid = !allowed_values.is_empty()
+                    && allowed_values.iter().all(|value| {
+                        !value.is_empty() && value.len() <= CUSTOM_FIELD_VALUE_MAX_LENGTH
+                    });
+                (!is_valid).then(|| {
+                    format!(
+                        "`allowed_values` must contain values between 1 and {CUSTOM_FIELD_VALUE_MAX_LENGTH} characters long"
+                    )
+                })
+            }
+        };
+
+        match error_message {
+            Some(message) => Err(ValidationError::InvalidValue {
+                message: format!("custom field `{}`: {message}", self.key),
+            }
+            .into()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Payer facing error messages of a profile, which take precedence over the translations of the
+/// unified error messages in the catalog
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UnifiedErrorMessageOverrides {
+    /// Messages keyed by the unified code of the error and then by the locale of the message
+    #[serde(default)]
+    #[schema(value_type = HashMap<String, HashMap<String, String>>, example = json!({"UE_9000": {"en": "Your card was declined, please try another card", "fr": "Votre carte a été refusée, veuillez essayer une autre carte"}}))]
+    pub messages: HashMap<String, HashMap<String, String>>,
+}
+
+const UNIFIED_ERROR_MESSAGE_LOCALE_MAX_LENGTH: usize = 35;
+const UNIFIED_ERROR_MESSAGE_MAX_LENGTH: usize = 1024;
+
+impl UnifiedErrorMessageOverrides {
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Message for the unified code in the first locale which has one
+    pub fn get_message<'a>(
+        &self,
+        unified_code: &str,
+        mut locales: impl Iterator<Item = &'a str>,
+    ) -> Option<String> {
+        let messages = self.messages.get(unified_code)?;
+        locales.find_map(|locale| messages.get(locale).cloned())
+    }
+
+    /// Validates that the unified codes are not empty, that the locales are language tags of at
+    /// most `UNIFIED_ERROR_MESSAGE_LOCALE_MAX_LENGTH` characters and that the messages are not
+    /// empty and of at most `UNIFIED_ERROR_MESSAGE_MAX_LENGTH` characters
+    pub fn validate(&self) -> Result<(), error_stack::Report<ValidationError>> {
+        self.messages
+            .iter()
+            .try_for_each(|(unified_code, messages)| {
+                if unified_code.trim().is_empty() {
+                    return Err(ValidationError::InvalidValue {
+                        message: "unified code must not be empty".to_string(),
+                    }
+                    .into());
+                }
+
+                messages.iter().try_for_each(|(locale, message)| {
+                    let is_valid_locale = !locale.is_empty()
+                        && locale.len() <= UNIFIED_ERROR_MESSAGE_LOCALE_MAX_LENGTH
+                        && locale.chars().all(|character| {
+                            character.is_ascii_alphanumeric()
+                                || character == '-'
+                                || character == '_'
+                        });
+                    if !is_valid_locale {
+                        return Err(ValidationError::InvalidValue {
+                            message: format!(
+                                "locale `{locale}` of unified code `{unified_code}` must be between 1 and {UNIFIED_ERROR_MESSAGE_LOCALE_MAX_LENGTH} characters long and contain only letters, digits, hyphens and underscores"
+                            ),
+                        }
+                        .into());
+                    }
+
+                    if message.trim().is_empty()
+                        || message.len() > UNIFIED_ERROR_MESSAGE_MAX_LENGTH
+                    {
+                        return Err(ValidationError::InvalidValue {
+                            message: format!(
+                                "message of unified code `{unified_code}` in locale `{locale}` must be between 1 and {UNIFIED_ERROR_MESSAGE_MAX_LENGTH} characters long"
+                            ),
+                        }
+                        .into());
+                    }
+
+                    Ok(())
+                })
+            })
+    }
+}
+
+/// Subset of the response of the connector for a payment, sent in the API responses and the
+/// webhooks of the payments of the profiles which have opted in, for the risk engines of the
+/// merchants. Only the results of the checks are sent, never the raw response of the connector.
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ConnectorResponseDetails {
+    /// Result of the address verification (AVS) performed by the connector
+    #[schema(example = "Y")]
+    pub avs_result: Option<String>,
+
+    /// Result of the verification of the card verification value (CVV) performed by the
+    /// connector
+    #[schema(example = "M")]
+    pub cvv_result: Option<String>,
+
+    /// Reference number assigned to the payment by the acquirer, used to trace the payment
+    /// across the card network
+    #[schema(example = "74537604221431003881865")]
+    pub acquirer_reference_number: Option<String>,
+
+    /// Advice code of the card network, indicating if and when the payment can be retried
+    #[schema(example = "03")]
+    pub network_advice_code: Option<String>,
+}
+
+/// Rules of a profile evaluated on the payments right after they are authorized, on the normalized
+/// results of the address and the card verification value checks of the connector
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PostAuthorizationRules {
+    /// Rules in the order of evaluation. The action of the first rule which matches the payment is
+    /// taken.
+    #[serde(default)]
+    pub rules: Vec<PostAuthorizationRule>,
+}
+
+/// A rule evaluated on the payments right after they are authorized. A rule matches a payment when
+/// all of its conditions match.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PostAuthorizationRule {
+    /// Name of the rule, sent as the cancellation reason of the payments voided by the rule
+    #[schema(example = "void_full_avs_mismatch")]
+    pub name: String,
+
+    /// The results of the address verification which match the rule
+    #[schema(value_type = Option<Vec<AvsResult>>, example = json!(["no_match"]))]
+    pub avs_results: Option<Vec<api_enums::AvsResult>>,
+
+    /// The results of the verification of the card verification value which match the rule
+    #[schema(value_type = Option<Vec<CvvResult>>, example = json!(["no_match"]))]
+    pub cvv_results: Option<Vec<api_enums::CvvResult>>,
+
+    /// The minimum amount of the payments which match the rule, in the lowest denomination of
+    /// the currency
+    #[schema(value_type = Option<i64>, example = 10000)]
+    pub min_amount: Option<MinorUnit>,
+
+    /// The currency of the payments which match the rule. The rule matches the payments in any
+    /// currency if not set, in which case the minimum amount is compared in the currency of the
+    /// payment.
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+
+    /// The action taken on the payments which match the rule
+    #[schema(value_type = PostAuthorizationAction)]
+    pub action: api_enums::PostAuthorizationAction,
+}
+
+const POST_AUTHORIZATION_RULE_NAME_MAX_LENGTH: usize = 64;
+
+impl PostAuthorizationRules {
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Validates that the names of the rules are unique and of at most
+    /// `POST_AUTHORIZATION_RULE_NAME_MAX_LENGTH` characters, and that every rule has a condition
+    /// on the result of a check, so that a rule cannot void every authorized payment
+    pub fn validate(&self) -> Result<(), error_stack::Report<ValidationError>> {
+        let mut names = HashSet::new();
+        self.rules.iter().try_for_each(|rule| {
+            if rule.name.trim().is_empty() || rule.name.len() > POST_AUTHORIZATION_RULE_NAME_MAX_LENGTH
+            {
+                return Err(ValidationError::InvalidValue {
+                    message: format!(
+                        "name of the rule must be between 1 and {POST_AUTHORIZATION_RULE_NAME_MAX_LENGTH} characters long"
+                    ),
+                }
+                .into());
+            }
+            if !names.insert(rule.name.as_str()) {
+                return Err(ValidationError::InvalidValue {
+                    message: format!("rule `{}` is configured more than once", rule.name),
+                }
+                .into());
+            }
+
+            let has_check_condition = rule
+                .avs_results
+                .as_ref()
+                .is_some_and(|avs_results| !avs_results.is_empty())
+                || rule
+                    .cvv_results
+                    .as_ref()
+                    .is_some_and(|cvv_results| !cvv_results.is_empty());
+            if !has_check_condition {
+                return Err(ValidationError::InvalidValue {
+                    message: format!(
+                        "rule `{}` must have at least one of `avs_results` and `cvv_results`",
+                        rule.name
+                    ),
+                }
+                .into());
+            }
+
+            Ok(())
+        })
+    }
+}
+
+/// Colors of the hosted checkout page of a profile, as hex codes such as `#4E6ADD`
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CheckoutThemeColors {
+    /// Primary color of the page, used for the details of the payment and the accents
+    #[schema(value_type = Option<String>, example = "#4E6ADD")]
+    pub primary: Option<String>,
+    /// Background color of the page
+    #[schema(value_type = Option<String>, example = "#FFFFFF")]
+    pub background: Option<String>,
+    /// Background color of the button which confirms the payment
+    #[schema(value_type = Option<String>, example = "#1A1A1A")]
+    pub button: Option<String>,
+    /// Color of the text of the button which confirms the payment
+    #[schema(value_type = Option<String>, example = "#FFFFFF")]
+    pub button_text: Option<String>,
+}
+
+/// Sections of the details of the payment shown beside the payment form of the hosted checkout
+/// page
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutSection {
+    /// The description of the payment
+    MerchantDescription,
+    /// The items of the order of the payment
+    OrderDetails,
+    /// The transaction details configured for the payment link
+    TransactionDetails,
+}
+
+/// Layout of the hosted checkout page of a profile
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CheckoutThemeLayout {
+    /// Layout of the payment methods in the payment form
+    #[schema(value_type = Option<String>, example = "accordion")]
+    pub sdk_layout: Option<String>,
+    /// Layout of the details of the payment
+    #[schema(value_type = Option<PaymentLinkDetailsLayout>, example = "layout1")]
+    pub details_layout: Option<api_enums::PaymentLinkDetailsLayout>,
+    /// Sections of the details of the payment which are shown, the others are hidden. Only the
+    /// payment form is shown if no section is listed
+    #[schema(value_type = Option<Vec<CheckoutSection>>)]
+    pub visible_sections: Option<Vec<CheckoutSection>>,
+}
+
+/// Theme of the hosted checkout page of a profile. It is applied when the payment links of the
+/// profile are rendered, over the payment link configuration with which they were created.
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CheckoutTheme {
+    /// URL of the logo of the merchant
+    #[schema(value_type = Option<String>, example = "https://hyperswitch.io/favicon.ico")]
+    pub logo: Option<String>,
+    /// Colors of the page
+    pub colors: Option<CheckoutThemeColors>,
+    /// Layout of the page
+    pub layout: Option<CheckoutThemeLayout>,
+    /// Locale of the page, used when the browser of the payer does not request one
+    #[schema(value_type = Option<String>, example = "fr")]
+    pub default_locale: Option<String>,
+}
+
+const CHECKOUT_THEME_LOCALE_MAX_LENGTH: usize = 35;
+const CHECKOUT_THEME_SDK_LAYOUTS: &[&str] = &["tabs", "accordion", "spaced_accordion"];
+
+impl CheckoutTheme {
+    fn is_valid_color(color: &str) -> bool {
+        color.strip_prefix('#').is_some_and(|hex| {
+            matches!(hex.len(), 3 | 6 | 8) && hex.chars().all(|c| c.is_ascii_hexdigit())
+        })
+    }
+
+    /// Validates that the logo is an https URL, that the colors are hex codes, that the layout of
+    /// the payment form is a known one and that the sections and the locale are well formed
+    pub fn validate(&self) -> Result<(), error_stack::Report<ValidationError>> {
+        if let Some(logo) = &self.logo {
+            let is_valid_logo = Url::parse(logo).is_ok_and(|url| url.scheme() == "https");
+            if !is_valid_logo {
+                return Err(ValidationError::InvalidValue {
+                    message: "logo must be an https URL".to_string(),
+                }
+                .into());
+            }
+        }
+
+        if let Some(colors) = &self.colors {
+            [
+                ("primary", &colors.primary),
+                ("background", &colors.background),
+                ("button", &colors.button),
+                ("button_text", &colors.button_text),
+            ]
+            .into_iter()
+            .try_for_each(|(name, color)| match color {
+                Some(color) if !Self::is_valid_color(color) => Err(ValidationError::InvalidValue {
+                    message: format!("color `{name}` must be a hex code such as `#4E6ADD`"),
+                }),
+                _ => Ok(()),
+            })?;
+        }
+
+        if let Some(layout) = &self.layout {
+            if let Some(sdk_layout) = &layout.sdk_layout {
+                if !CHECKOUT_THEME_SDK_LAYOUTS.contains(&sdk_layout.as_str()) {
+                    return Err(ValidationError::InvalidValue {
+                        message: format!(
+                            "sdk_layout must be one of {}",
+                            CHECKOUT_THEME_SDK_LAYOUTS.join(", ")
+                        ),
+                    }
+                    .into());
+                }
+            }
+            if let Some(visible_sections) = &layout.visible_sections {
+                let mut sections = HashSet::new();
+                if let Some(section) = visible_sections
+                    .iter()
+                    .find(|section| !sections.insert(**section))
+                {
+                    return Err(ValidationError::InvalidValue {
+                        message: format!("section `{section:?}` is listed more than once"),
+                    }
+                    .into());
+                }
+            }
+        }
+
+        if let Some(locale) = &self.default_locale {
+            let is_valid_locale = !locale.is_empty()
+                && locale.len() <= CHECKOUT_THEME_LOCALE_MAX_LENGTH
+                && locale.chars().all(|character| {
+                    character.is_ascii_alphanumeric() || character == '-' || character == '_'
+                });
+            if !is_valid_locale {
+                return Err(ValidationError::InvalidValue {
+                    message: format!(
+                        "default_locale must be between 1 and {CHECKOUT_THEME_LOCALE_MAX_LENGTH} characters long and contain only letters, digits, hyphens and underscores"
+                    ),
+                }
+                .into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether the section of the details of the payment is shown by the theme
+    pub fn is_section_visible(&self, section: CheckoutSection) -> bool {
+        self.layout
+            .as_ref()
+            .and_then(|layout| layout.visible_sections.as_ref())
+            .map_or(true, |visible_sections| visible_sections.contains(&section))
+    }
+}
+
+/// A version of the theme of the hosted checkout page of a profile
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct CheckoutThemeResponse {
+    /// The identifier for the profile
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+    /// The version of the theme, incremented every time the theme is updated
+    #[schema(example = 3)]
+    pub version: u32,
+    /// Whether this version is the one applied to the payment links of the profile
+    pub is_active: bool,
+    /// The time at which the version was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The theme
+    pub theme: CheckoutTheme,
+}
+
+/// The versions of the theme of the hosted checkout page of a profile, from the latest
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct CheckoutThemeVersionsResponse {
+    /// The version applied to the payment links of the profile
+    #[schema(example = 3)]
+    pub active_version: u32,
+    /// The versions of the theme
+    pub versions: Vec<CheckoutThemeResponse>,
+}
+
+/// Query of the theme of the hosted checkout page of a profile
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, utoipa::IntoParams)]
+#[serde(deny_unknown_fields)]
+pub struct CheckoutThemeRetrieveQuery {
+    /// The version of the theme to retrieve. Defaults to the active version
+    #[param(example = 3)]
+    pub version: Option<u32>,
+}
+
+/// Request to apply a previous version of the theme of the hosted checkout page of a profile to
+/// its payment links
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CheckoutThemeActivateRequest {
+    /// The version of the theme to apply
+    #[schema(example = 2)]
+    pub version: u32,
+}
+
 #[cfg(feature = "v1")]
 /// Checks if the inner values of two options are equal
 /// Returns true if values are not equal, returns false in other cases
@@ -1866,6 +2652,9 @@ pub struct CustomerAcceptance {
     pub accepted_at: Option<PrimitiveDateTime>,
     /// Information required for online mandate generation
     pub online: Option<OnlineMandate>,
+    /// The version of the consent text that was presented to the customer
+    #[schema(example = "2024-01-v1")]
+    pub consent_text_version: Option<String>,
 }
 
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, PartialEq, Eq, Clone, ToSchema)]
@@ -4359,15 +5148,105 @@ pub struct PhoneDetails {
     pub country_code: Option<String>,
 }
 
-#[cfg(feature = "v1")]
-#[derive(Debug, Clone, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
-pub struct PaymentsCaptureRequest {
-    /// The unique identifier for the payment
-    #[serde(skip_deserializing)]
-    pub payment_id: id_type::PaymentId,
-    /// The unique identifier for the merchant
-    #[schema(value_type = Option<String>)]
-    pub merchant_id: Option<id_type::MerchantId>,
+/// The outcome of the validation of an address
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    ToSchema,
+    strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AddressValidationStatus {
+    /// The address is valid as it was given
+    Valid,
+    /// The address is valid once normalized, the normalized address is used with the connectors
+    Corrected,
+    /// The address could not be validated, the address is used as it was given
+    Invalid,
+    /// The address validation provider could not be reached, the address was only normalized
+    /// offline
+    Unverified,
+}
+
+/// The kind of issue found with an address during its validation
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    ToSchema,
+    strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AddressValidationHintCode {
+    /// A field required to deliver to the address is missing
+    MissingField,
+    /// The zip or postal code does not match the format of the country
+    InvalidPostalCode,
+    /// The state is not a known state of the country
+    UnknownState,
+    /// The value of the field was normalized
+    ValueNormalized,
+    /// The address validation provider could not be reached
+    ProviderUnavailable,
+    /// Any other issue reported by the address validation provider
+    ProviderWarning,
+}
+
+/// An issue found with an address during its validation
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct AddressValidationHint {
+    /// The field of the address to which the hint applies
+    #[schema(example = "zip")]
+    pub field: Option<String>,
+    /// The kind of issue
+    pub code: AddressValidationHintCode,
+    /// A description of the issue
+    #[schema(example = "zip does not match the postal code format of US")]
+    pub message: String,
+}
+
+/// The result of the validation of an address, for the merchants which have enabled the
+/// `address_validation` feature flag
+#[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct AddressValidationDetails {
+    /// The outcome of the validation
+    pub status: AddressValidationStatus,
+    /// The normalized form of the address. The address is stored as it was given along with its
+    /// normalized form.
+    pub normalized_address: Option<AddressDetails>,
+    /// The issues found with the address
+    pub hints: Vec<AddressValidationHint>,
+}
+
+/// The results of the validation of the addresses of a payment
+#[derive(Clone, Debug, Default, Eq, PartialEq, serde::Serialize, ToSchema)]
+pub struct PaymentAddressValidation {
+    /// The result of the validation of the billing address
+    pub billing: Option<AddressValidationDetails>,
+    /// The result of the validation of the shipping address
+    pub shipping: Option<AddressValidationDetails>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct PaymentsCaptureRequest {
+    /// The unique identifier for the payment
+    #[serde(skip_deserializing)]
+    pub payment_id: id_type::PaymentId,
+    /// The unique identifier for the merchant
+    #[schema(value_type = Option<String>)]
+    pub merchant_id: Option<id_type::MerchantId>,
     /// The Amount to be captured/ debited from the user's payment method. If not passed the full amount will be captured.
     #[schema(value_type = i64, example = 6540)]
     pub amount_to_capture: Option<MinorUnit>,
@@ -5076,6 +5955,84 @@ pub struct PaymentsResponse {
 
     /// Error message received from the issuer in case of failed payments
     pub issuer_error_message: Option<String>,
+
+    /// Merchant facing status of the payment, as per the custom status mapping of the profile
+    #[schema(example = "awaiting_bank_confirmation")]
+    pub custom_status: Option<String>,
+
+    /// Payer facing message of the unified error of the payment, in the locale requested in the
+    /// `Accept-Language` header
+    #[schema(example = "Your card was declined, please try another card")]
+    pub localized_unified_message: Option<String>,
+
+    /// Indicates if the payment was made on a profile in test mode
+    #[schema(example = false)]
+    pub test_mode: Option<bool>,
+
+    /// Subset of the response of the connector, populated for the profiles which have opted in
+    /// with the `connector_response_details` feature flag
+    pub connector_response_details: Option<ConnectorResponseDetails>,
+
+    /// The results of the validation of the billing and shipping addresses, populated for the
+    /// merchants which have enabled the `address_validation` feature flag
+    pub address_validation: Option<PaymentAddressValidation>,
+
+    /// The result of the address verification of the payment, normalized from the checks of the
+    /// connector
+    #[schema(value_type = Option<AvsResult>, example = "full_match")]
+    pub avs_result: Option<api_enums::AvsResult>,
+
+    /// The result of the verification of the card verification value of the payment, normalized
+    /// from the checks of the connector
+    #[schema(value_type = Option<CvvResult>, example = "match")]
+    pub cvv_result: Option<api_enums::CvvResult>,
+
+    /// The reference number assigned by the acquirer to the capture of the payment, which the
+    /// customer can use to trace the payment with their bank
+    #[schema(example = "74537604221431003881865")]
+    pub acquirer_reference_number: Option<String>,
+
+    /// Signed link to the hosted receipt of the payment, available once the payment is completed.
+    /// The link expires after a while, and a new one is returned each time the payment is
+    /// retrieved.
+    #[schema(
+        example = "https://api.hyperswitch.io/receipts/merchant_1668273825/pay_mbabizu24mvu3mela5njyhpit4?expires=1668878625&signature=8a2f"
+    )]
+    pub receipt_url: Option<String>,
+
+    /// Opaque token identifying the version of the payment in this response. Passing it in the
+    /// `consistency_token` of a subsequent retrieve of the payment ensures that the retrieve
+    /// reflects at least this version, even right after the payment is created or updated.
+    #[schema(example = "eyJwYXltZW50X2lkIjoicGF5XzEyMyIsIm1vZGlmaWVkX2F0IjoxNzAwMDAwMDAwfQ")]
+    pub consistency_token: Option<String>,
+
+    /// The time at which the authorized amount of the payment is scheduled to be captured, as
+    /// requested in `capture_on` or rescheduled since. Absent once the capture has been executed
+    /// or the scheduled capture has been canceled.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub scheduled_capture_at: Option<PrimitiveDateTime>,
+
+    /// Whether the amount of the payment is an estimate of its final amount, to which the
+    /// authorization is adjusted through the finalize amount API
+    pub is_amount_estimated: Option<bool>,
+
+    /// Values of the custom fields of the payment, as passed on its creation
+    #[schema(value_type = Option<Object>, example = r#"{ "store_id": "store_42", "terminal_count": 3 }"#)]
+    pub custom_fields: Option<HashMap<String, serde_json::Value>>,
+
+    /// Whether the payment can be paid by multiple attempts, each paying a part of its amount
+    pub enable_partial_payments: Option<bool>,
+
+    /// The part of the amount which remains to be paid by further attempts, for the payments with
+    /// partial payments enabled
+    #[schema(value_type = Option<i64>, example = 4040)]
+    pub amount_remaining: Option<MinorUnit>,
+
+    /// The part of the amount paid with the store credit balance of the customer, which the
+    /// attempts of the payment do not charge
+    #[schema(value_type = Option<i64>, example = 1500)]
+    pub store_credit_amount: Option<MinorUnit>,
 }
 
 #[cfg(feature = "v2")]
@@ -5963,6 +6920,9 @@ pub struct PaymentListFilterConstraints {
     pub merchant_order_reference_id: Option<String>,
     /// Indicates the method by which a card is discovered during a payment
     pub card_discovery: Option<Vec<enums::CardDiscovery>>,
+    /// The values of the custom fields to filter payments list, keyed by the key of the field. The payments having all of the given values are listed
+    #[schema(value_type = Option<Object>, example = r#"{ "store_id": "store_42" }"#)]
+    pub custom_fields: Option<HashMap<String, serde_json::Value>>,
 }
 
 #[cfg(feature = "v1")]
@@ -6326,6 +7286,9 @@ pub struct PaymentsRetrieveRequest {
     pub expand_captures: Option<bool>,
     /// If enabled provides list of attempts linked to payment intent
     pub expand_attempts: Option<bool>,
+    /// The consistency token returned in the response of a previous write to the payment. When
+    /// provided, the payment is read only once the changes made by that write are visible.
+    pub consistency_token: Option<String>,
 }
 
 #[derive(Debug, Default, PartialEq, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
@@ -7275,6 +8238,9 @@ pub struct PaymentRetrieveBody {
     pub expand_captures: Option<bool>,
     /// If enabled provides list of attempts linked to payment intent
     pub expand_attempts: Option<bool>,
+    /// The consistency token returned in the response of a previous write to the payment. When
+    /// provided, the payment is read only once the changes made by that write are visible.
+    pub consistency_token: Option<String>,
 }
 
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
@@ -7388,6 +8354,619 @@ pub struct PaymentsManualUpdateResponse {
     pub connector_transaction_id: Option<String>,
 }
 
+/// The type of an event recorded in the timeline of a payment
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentTimelineEventType {
+    /// A connector was chosen for the payment by routing
+    RoutingDecision,
+    /// A request was about to be sent to the connector
+    ConnectorCallStarted,
+    /// A response was received from the connector
+    ConnectorCallEnded,
+    /// A webhook was received from the connector for the payment
+    WebhookReceived,
+    /// The status of the payment changed
+    StatusTransition,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+pub struct PaymentTimelineEvent {
+    /// The type of the event
+    pub event_type: PaymentTimelineEventType,
+    /// The time at which the event was recorded
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The identifier for the payment attempt during which the event was recorded
+    pub attempt_id: Option<String>,
+    /// The connector involved in the event
+    #[schema(example = "stripe")]
+    pub connector: Option<String>,
+    /// Details specific to the type of the event
+    #[schema(value_type = Option<Object>)]
+    pub details: Option<serde_json::Value>,
+}
+
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentTimelineResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// The events recorded for the payment, in the order in which they occurred
+    pub events: Vec<PaymentTimelineEvent>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentsMetadataUpdateRequest {
+    /// The identifier for the payment
+    #[serde(skip)]
+    pub payment_id: id_type::PaymentId,
+    /// The keys to be set in the metadata of the payment. The keys which are not present are left
+    /// unchanged, and the keys whose value is `null` are removed from the metadata.
+    #[schema(value_type = Object, example = r#"{ "investigation": "chargeback_review" }"#)]
+    pub metadata: pii::SecretSerdeValue,
+}
+
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentsMetadataUpdateResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// The metadata of the payment after the update
+    #[schema(value_type = Option<Object>, example = r#"{ "investigation": "chargeback_review" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentNoteRequest {
+    /// The identifier for the payment
+    #[serde(skip)]
+    pub payment_id: id_type::PaymentId,
+    /// The text of the note
+    #[schema(
+        max_length = 2048,
+        example = "Customer confirmed the purchase over a call"
+    )]
+    pub note: String,
+}
+
+/// An internal note on a payment. The notes are visible only to the merchant.
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+pub struct PaymentNote {
+    /// The identifier for the note
+    #[schema(example = "note_9Jq3e0Hh7k3kMzMcN8aG")]
+    pub note_id: String,
+    /// The identifier of the dashboard user who added the note
+    #[schema(example = "0c3ff3c6-2d1b-4d4a-9d0e-3c4c1a6d7f42")]
+    pub author: String,
+    /// The text of the note
+    #[schema(example = "Customer confirmed the purchase over a call")]
+    pub note: String,
+    /// The time at which the note was added
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentNotesResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// The notes of the payment, in the order in which they were added
+    pub notes: Vec<PaymentNote>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentsContactSearchRequest {
+    /// The email of the customer. Either the email or the phone number must be provided.
+    #[schema(max_length = 255, value_type = Option<String>, example = "johntest@test.com")]
+    pub email: Option<Email>,
+    /// The phone number of the customer
+    #[schema(value_type = Option<String>, max_length = 255, example = "9123456789")]
+    pub phone: Option<Secret<String>>,
+    /// The country code for the phone number of the customer
+    #[schema(max_length = 255, example = "+1")]
+    pub phone_country_code: Option<String>,
+    /// The maximum number of payments to be returned, the most recent payments are returned first
+    #[schema(default = 20, maximum = 100, example = 20)]
+    pub limit: Option<u32>,
+}
+
+/// A payment which matched the contact details of a search
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentContactSearchResult {
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// The status of the payment
+    #[schema(value_type = IntentStatus, example = "succeeded")]
+    pub status: api_enums::IntentStatus,
+    /// The amount of the payment
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+    /// The currency of the payment
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+    /// The identifier for the customer of the payment
+    #[schema(value_type = Option<String>, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<id_type::CustomerId>,
+    /// The identifier for the profile of the payment
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+    /// The time at which the payment was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentsContactSearchResponse {
+    /// The number of payments included in the response
+    pub count: usize,
+    /// The payments which matched the contact details, the most recent first
+    pub data: Vec<PaymentContactSearchResult>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, utoipa::IntoParams)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentLookupRequest {
+    /// The reference of the payment at the connector, such as the one on a bank statement or a
+    /// dispute notice
+    #[param(example = "pi_3MtwBwLkdIwHu7ix28a3tqPa")]
+    pub connector_transaction_id: String,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentLookupResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// The identifier for the attempt of the payment which was processed with the connector
+    /// transaction id
+    pub attempt_id: String,
+    /// The connector which processed the attempt
+    #[schema(example = "stripe")]
+    pub connector: Option<String>,
+    /// The reference of the payment at the connector
+    pub connector_transaction_id: String,
+    /// The status of the payment
+    #[schema(value_type = IntentStatus, example = "succeeded")]
+    pub status: api_enums::IntentStatus,
+    /// The status of the attempt
+    #[schema(value_type = AttemptStatus, example = "charged")]
+    pub attempt_status: api_enums::AttemptStatus,
+    /// The amount of the payment
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+    /// The currency of the payment
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+    /// The identifier for the profile of the payment
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+    /// The time at which the payment was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, serde::Deserialize, Clone, utoipa::IntoParams)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentsChangesRequest {
+    /// The cursor returned by the previous poll, the payments whose status changed after it are
+    /// returned. All the changes are returned from the start if not given.
+    #[param(example = "104857")]
+    pub since_cursor: Option<String>,
+    /// The maximum number of changes to be returned
+    #[param(default = 100, maximum = 1000, example = 100)]
+    pub limit: Option<u32>,
+}
+
+/// A payment whose status changed
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentChange {
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// The status of the payment after the change
+    #[schema(value_type = IntentStatus, example = "succeeded")]
+    pub status: api_enums::IntentStatus,
+    /// The amount of the payment
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+    /// The amount captured for the payment
+    #[schema(value_type = Option<i64>, example = 6540)]
+    pub amount_captured: Option<MinorUnit>,
+    /// The currency of the payment
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+    /// The identifier for the profile of the payment
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+    /// The reference of the merchant for the order of the payment
+    pub merchant_order_reference_id: Option<String>,
+    /// The identifier for the active attempt of the payment
+    pub attempt_id: String,
+    /// The time at which the payment was last modified
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+/// The payments whose status changed after a cursor, in the order of their changes
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Serialize, Clone, ToSchema)]
+pub struct PaymentsChangesResponse {
+    /// The payments whose status changed. A payment whose status changed more than once since the
+    /// cursor is returned only once, with its latest status.
+    pub data: Vec<PaymentChange>,
+    /// The cursor to be sent in the next poll. It is the same as the given cursor if no payment
+    /// changed.
+    #[schema(example = "104921")]
+    pub next_cursor: String,
+    /// Whether more changes are available after the next cursor, in which case the next poll can be
+    /// made immediately
+    pub has_more: bool,
+}
+
+/// The kind of object an acquirer reference number is assigned to
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AcquirerReferenceNumberObjectType {
+    /// The capture of a payment
+    Payment,
+    /// A refund
+    Refund,
+}
+
+/// An acquirer reference number read from a settlement file of a connector. This is also the
+/// format of each row of the CSV file uploaded to import acquirer reference numbers, with
+/// `connector`, `object_type`, `connector_reference_id` and `acquirer_reference_number` as the
+/// header.
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AcquirerReferenceNumberRecord {
+    /// The connector which processed the payment or the refund
+    #[schema(example = "stripe")]
+    pub connector: String,
+    /// Whether the acquirer reference number is assigned to the capture of a payment or to a
+    /// refund
+    pub object_type: AcquirerReferenceNumberObjectType,
+    /// The reference of the payment or the refund at the connector
+    #[schema(example = "pi_3MtwBwLkdIwHu7ix28a3tqPa")]
+    pub connector_reference_id: String,
+    /// The reference number assigned by the acquirer
+    #[schema(max_length = 64, example = "74537604221431003881865")]
+    pub acquirer_reference_number: String,
+}
+
+/// A row of an import of acquirer reference numbers which could not be applied
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct AcquirerReferenceNumberRecordError {
+    /// The position of the row in the import, starting from 1
+    pub row_number: usize,
+    /// The reference of the payment or the refund at the connector
+    pub connector_reference_id: String,
+    /// The reason due to which the row could not be applied
+    pub error_message: String,
+}
+
+/// The outcome of an import of acquirer reference numbers
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct AcquirerReferenceNumberImportResponse {
+    /// The number of rows in the import
+    pub total_count: usize,
+    /// The number of rows which were applied to a payment or a refund
+    pub updated_count: usize,
+    /// The rows which could not be applied
+    pub failed_records: Vec<AcquirerReferenceNumberRecordError>,
+}
+
+/// The format in which the receipt of a payment is rendered
+#[cfg(feature = "v1")]
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ReceiptFormat {
+    /// A web page
+    #[default]
+    Html,
+    /// A PDF document
+    Pdf,
+}
+
+/// The parameters of the signed link to the receipt of a payment
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, utoipa::IntoParams)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentReceiptRequest {
+    /// The time at which the link expires, as a unix timestamp
+    #[param(example = 1668878625)]
+    pub expires: i64,
+    /// The signature of the link
+    pub signature: String,
+    /// The format in which the receipt is to be rendered, defaults to `html`
+    #[param(value_type = Option<ReceiptFormat>)]
+    pub format: Option<ReceiptFormat>,
+}
+
+/// Request to email the receipt of a payment
+#[cfg(feature = "v1")]
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentReceiptEmailRequest {
+    /// The email to which the receipt is to be sent. Defaults to the email of the customer of the
+    /// payment
+    #[schema(value_type = Option<String>, max_length = 255, example = "johntest@test.com")]
+    pub email: Option<Email>,
+}
+
+/// Request to reschedule the capture of an authorized payment
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentsCaptureScheduleRequest {
+    /// The identifier for the payment
+    #[serde(skip)]
+    pub payment_id: id_type::PaymentId,
+    /// A timestamp (ISO 8601 code) at which the authorized amount of the payment is to be
+    /// captured. It must be in the future, and before the authorization of the payment expires.
+    #[schema(value_type = String, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub capture_on: PrimitiveDateTime,
+}
+
+/// The scheduled capture of a payment
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentsCaptureScheduleResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// The status of the payment
+    #[schema(value_type = IntentStatus, example = "requires_capture")]
+    pub status: api_enums::IntentStatus,
+    /// The time at which the payment is scheduled to be captured. Absent if the scheduled capture
+    /// has been canceled.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub scheduled_capture_at: Option<PrimitiveDateTime>,
+}
+
+const AUTO_CAPTURE_MAX_DELAY_IN_HOURS: u32 = 7 * 24;
+
+/// Profile level configuration which delays the capture of the payments with automatic capture,
+/// to give the merchant a window to review them for fraud. Such payments are authorized with
+/// manual capture, and are captured once the delay has elapsed after they are confirmed, unless
+/// they have been flagged for review by the fraud and risk management (FRM) connector, captured or
+/// voided by then.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AutoCaptureDelayConfig {
+    /// The number of hours after which the payments are captured, of at most 168 hours (7 days)
+    /// as the authorizations of most card networks expire after it
+    #[schema(example = 24)]
+    pub delay_in_hours: u32,
+}
+
+impl AutoCaptureDelayConfig {
+    pub fn get_delay(&self) -> time::Duration {
+        time::Duration::hours(i64::from(self.delay_in_hours))
+    }
+
+    /// Validates that the delay is of at least an hour and at most
+    /// `AUTO_CAPTURE_MAX_DELAY_IN_HOURS` hours
+    pub fn validate(&self) -> Result<(), error_stack::Report<ValidationError>> {
+        if !(1..=AUTO_CAPTURE_MAX_DELAY_IN_HOURS).contains(&self.delay_in_hours) {
+            return Err(ValidationError::InvalidValue {
+                message: format!(
+                    "delay_in_hours must be between 1 and {AUTO_CAPTURE_MAX_DELAY_IN_HOURS}"
+                ),
+            }
+            .into());
+        }
+        Ok(())
+    }
+}
+
+const STEP_UP_AUTHENTICATION_MAX_WINDOW_IN_MINUTES: u32 = 7 * 24 * 60;
+
+/// A maximum number of payments within a window, above which the payments are stepped up
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct StepUpVelocityLimit {
+    /// The number of payments allowed within the window without step-up authentication
+    #[schema(example = 3)]
+    pub max_payments: u32,
+    /// The duration of the window in minutes, of at most 10080 minutes (7 days). The window starts
+    /// with the first payment counted in it.
+    #[schema(example = 60)]
+    pub window_in_minutes: u32,
+}
+
+impl StepUpVelocityLimit {
+    pub fn get_window_in_seconds(&self) -> i64 {
+        i64::from(self.window_in_minutes) * 60
+    }
+
+    fn validate(&self, field_name: &str) -> Result<(), error_stack::Report<ValidationError>> {
+        if self.max_payments == 0 {
+            return Err(ValidationError::InvalidValue {
+                message: format!("{field_name}.max_payments must be greater than 0"),
+            }
+            .into());
+        }
+        if !(1..=STEP_UP_AUTHENTICATION_MAX_WINDOW_IN_MINUTES).contains(&self.window_in_minutes) {
+            return Err(ValidationError::InvalidValue {
+                message: format!(
+                    "{field_name}.window_in_minutes must be between 1 and \
+                     {STEP_UP_AUTHENTICATION_MAX_WINDOW_IN_MINUTES}"
+                ),
+            }
+            .into());
+        }
+        Ok(())
+    }
+}
+
+/// An amount in a currency above which the payments are stepped up
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct StepUpAmountThreshold {
+    /// The currency of the payments to which the threshold applies
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    /// The amount above which the payments in the currency are stepped up
+    #[schema(value_type = i64, example = 50000)]
+    pub amount: MinorUnit,
+}
+
+/// Profile level rules which force a 3DS challenge on the card payments deemed risky, while the
+/// other card payments go through the 3DS decision of the merchant. A card payment is stepped up
+/// when the customer or the card exceeds their number of payments within a window, or when its
+/// amount exceeds the threshold for its currency. The payments of a customer and of a card are
+/// counted when they are confirmed, whether they succeed or not. The recurring payments initiated
+/// by the merchant are neither counted nor stepped up, as the customer is not present to
+/// authenticate them.
+#[derive(Clone, Debug, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct StepUpAuthenticationConfig {
+    /// The number of payments of a customer above which their payments are stepped up
+    pub customer_velocity: Option<StepUpVelocityLimit>,
+    /// The number of payments with a card above which the payments with the card are stepped up
+    pub card_velocity: Option<StepUpVelocityLimit>,
+    /// The amounts above which the payments are stepped up, of at most one per currency
+    #[serde(default)]
+    pub amount_thresholds: Vec<StepUpAmountThreshold>,
+}
+
+impl StepUpAuthenticationConfig {
+    /// Validates that the config has at least one rule, that its velocity limits are valid, and
+    /// that its amount thresholds are positive and in distinct currencies
+    pub fn validate(&self) -> Result<(), error_stack::Report<ValidationError>> {
+        if self.customer_velocity.is_none()
+            && self.card_velocity.is_none()
+            && self.amount_thresholds.is_empty()
+        {
+            return Err(ValidationError::InvalidValue {
+                message: "At least one step-up rule must be configured".to_string(),
+            }
+            .into());
+        }
+        if let Some(customer_velocity) = &self.customer_velocity {
+            customer_velocity.validate("customer_velocity")?;
+        }
+        if let Some(card_velocity) = &self.card_velocity {
+            card_velocity.validate("card_velocity")?;
+        }
+
+        let mut currencies = HashSet::new();
+        for threshold in &self.amount_thresholds {
+            if threshold.amount <= MinorUnit::zero() {
+                return Err(ValidationError::InvalidValue {
+                    message: "amount_thresholds.amount must be greater than 0".to_string(),
+                }
+                .into());
+            }
+            if !currencies.insert(threshold.currency) {
+                return Err(ValidationError::InvalidValue {
+                    message: format!(
+                        "amount_thresholds has more than one threshold for {}",
+                        threshold.currency
+                    ),
+                }
+                .into());
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Request to capture the final amount of a payment which was authorized with an estimated amount
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentsFinalizeAmountRequest {
+    /// The identifier for the payment
+    #[serde(skip)]
+    pub payment_id: id_type::PaymentId,
+    /// The final amount of the payment, which is captured. An amount above the estimated amount
+    /// is captured within the tolerance of the card network, or else after incrementing the
+    /// authorization of the payment. An amount below the estimated amount is partially captured.
+    #[schema(value_type = i64, example = 7250)]
+    pub final_amount: MinorUnit,
+}
+
+/// Request to check the balance of a gift card, which can pay the amount of a payment up to its
+/// balance
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentsGiftCardBalanceRequest {
+    /// The identifier for the payment
+    #[serde(skip)]
+    pub payment_id: id_type::PaymentId,
+    /// The gift card whose balance is checked
+    pub gift_card: GiftCardData,
+    /// The connector account with which the balance is checked. Defaults to the first connector
+    /// account of the profile of the payment with the gift card enabled.
+    #[schema(value_type = Option<String>, example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+}
+
+/// The balance of a gift card, and the part of the amount of the payment which it can pay
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentsGiftCardBalanceResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// The balance of the gift card
+    #[schema(value_type = i64, example = 2500)]
+    pub balance: MinorUnit,
+    /// The currency of the balance of the gift card
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+    /// The part of the amount of the payment which is paid with the gift card. Without partial
+    /// payments enabled for the payment, the gift card can only pay its whole amount.
+    #[schema(value_type = i64, example = 2500)]
+    pub redeemable_amount: MinorUnit,
+    /// The amount of the payment which remains to be paid after the gift card is redeemed
+    #[schema(value_type = i64, example = 4040)]
+    pub amount_remaining_after_redemption: MinorUnit,
+    /// Whether another payment method is required to pay the amount remaining after the gift card
+    /// is redeemed
+    pub requires_additional_payment_method: bool,
+}
+
+/// The receipt of a payment which has been emailed
+#[cfg(feature = "v1")]
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct PaymentReceiptEmailResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+    /// Signed link to the hosted receipt of the payment, as included in the email
+    pub receipt_url: String,
+}
+
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
 pub enum ThreeDsCompletionIndicator {
     /// 3DS method successfully completed
@@ -0,0 +1,158 @@
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The type of a scheduler task, as per the workflow which runs it
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumIter,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SchedulerTaskType {
+    PaymentSync,
+    RefundSync,
+    PayoutExecution,
+    WebhookRetry,
+    ApiKeyExpiry,
+    DeleteTokenizedData,
+    PaymentMethodStatusUpdate,
+    PassiveRecovery,
+    DeferredVaulting,
+    AuthenticationAbandonment,
+    RefundBatch,
+    Dunning,
+    RoutingExperiment,
+    MerchantOffboarding,
+    WarehouseSync,
+    ConnectorOAuthTokenRefresh,
+    ConnectorCredentialsExpiry,
+    ScheduledCapture,
+    AttemptRedaction,
+    RefundReconciliation,
+    ScaComplianceReport,
+    ConnectorTransactionIdBackfill,
+    DataResidencyMigration,
+}
+
+/// The state of a scheduler task
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumIter,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SchedulerTaskState {
+    /// The task is yet to be run, or is to be retried
+    Pending,
+    /// The task is held for a review
+    Review,
+    /// The task was finished without a successful outcome
+    Failed,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SchedulerTasksListRequest {
+    /// The type of the tasks to be listed
+    pub task_type: SchedulerTaskType,
+
+    /// The state of the tasks to be listed
+    pub state: SchedulerTaskState,
+
+    /// The maximum number of tasks to be listed, starting from the oldest task
+    #[schema(example = 100)]
+    pub limit: Option<u32>,
+
+    /// The number of tasks to be skipped
+    #[schema(example = 0)]
+    pub offset: Option<u32>,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct SchedulerTask {
+    /// The identifier for the task
+    pub id: String,
+
+    /// The name of the task
+    pub name: Option<String>,
+
+    /// The type of the task
+    pub task_type: Option<SchedulerTaskType>,
+
+    /// The status of the task in the scheduler
+    #[schema(example = "pending")]
+    pub status: String,
+
+    /// The status of the task set by its workflow
+    #[schema(example = "Pending")]
+    pub business_status: String,
+
+    /// The number of times the task has been retried
+    pub retry_count: i32,
+
+    /// The time at which the task is scheduled to be run
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub schedule_time: Option<PrimitiveDateTime>,
+
+    /// The time at which the task was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// The time at which the task was last updated
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct SchedulerTasksSummary {
+    /// The type of the tasks
+    pub task_type: SchedulerTaskType,
+
+    /// The state of the tasks
+    pub state: SchedulerTaskState,
+
+    /// The number of tasks of the type in the state
+    pub count: i64,
+
+    /// The time in seconds since the oldest of the tasks was created
+    pub oldest_task_age: Option<i64>,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct SchedulerTasksSummaryResponse {
+    /// The summary of the tasks of each type in each state
+    pub summary: Vec<SchedulerTasksSummary>,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct SchedulerTasksListResponse {
+    /// The summary of the tasks of the type in the state
+    pub summary: SchedulerTasksSummary,
+
+    /// The tasks listed, ordered by the time at which they were created
+    pub tasks: Vec<SchedulerTask>,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct SchedulerTaskId {
+    pub task_id: String,
+}
@@ -59,6 +59,18 @@ pub struct RefundRequest {
     /// Charge specific fields for controlling the revert of funds from either platform or connected account
     #[schema(value_type = Option<SplitRefund>)]
     pub split_refunds: Option<common_types::refunds::SplitRefund>,
+
+    /// End-to-end reference for the refund of a bank transfer payment, which appears on the bank statement of the payer. It has to satisfy the constraints of the bank transfer scheme of the payment, such as at most 35 characters of the SEPA character set for SEPA transfers, and is passed to the connectors which support structured remittance information for refunds
+    #[schema(max_length = 140, example = "REFUND ORDER 12345")]
+    pub end_to_end_reference: Option<String>,
+
+    /// The attempt of the payment to be refunded, for the payments with partial payments enabled, which are paid by multiple attempts. The refund is limited to the amount paid by the attempt. If not provided, the last successful attempt of the payment is refunded.
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4_2")]
+    pub attempt_id: Option<String>,
+
+    /// The destination of the refunded amount. With `store_credit`, the amount is credited to the store credit balance of the customer of the payment instead of being refunded to the original payment method, and future payments of the customer can draw it down. Defaults to `original_payment_method`.
+    #[schema(value_type = Option<RefundDestination>, example = "store_credit")]
+    pub refund_destination: Option<enums::RefundDestination>,
 }
 
 #[cfg(feature = "v2")]
@@ -153,6 +165,131 @@ pub struct RefundManualUpdateRequest {
     pub error_message: Option<String>,
 }
 
+/// Resolution of a refund held for manual review, once its outcome has been confirmed with the
+/// connector
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RefundResolveRequest {
+    #[serde(skip)]
+    pub refund_id: String,
+    /// The outcome of the refund, which is either `succeeded` or `failed`
+    pub status: RefundStatus,
+    /// The code for the error, when the refund has failed
+    pub error_code: Option<String>,
+    /// The error message, when the refund has failed
+    pub error_message: Option<String>,
+}
+
+/// A refund to be initiated as a part of a refund batch. This is also the format of each row of
+/// the CSV file uploaded to create a refund batch, with `payment_id`, `amount` and `reason` as the
+/// header.
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RefundBatchRecord {
+    /// The payment id against which the refund is to be initiated
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: String,
+
+    /// Amount to be refunded in the lowest denomination of the currency. If not provided, this will default to the full payment amount
+    #[schema(value_type = Option<i64>, example = 6540)]
+    pub amount: Option<MinorUnit>,
+
+    /// Reason for the refund
+    #[schema(max_length = 255, example = "Product recall")]
+    pub reason: Option<String>,
+}
+
+/// The status of a refund batch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, ToSchema, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RefundBatchStatus {
+    /// The refunds of the batch are yet to be initiated
+    Queued,
+    /// The refunds of the batch are being initiated
+    Processing,
+    /// All the rows of the batch have been processed
+    Completed,
+    /// The refunds of the batch could not all be initiated, the rows which were still pending are
+    /// not refunded
+    Failed,
+}
+
+/// The status of a row of a refund batch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, ToSchema, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RefundBatchRowStatus {
+    /// The refund is yet to be initiated
+    Pending,
+    /// The refund has been initiated with the connector
+    Initiated,
+    /// The row was invalid, or the refund could not be initiated
+    Failed,
+}
+
+/// The result of processing a row of a refund batch, as included in the report of the batch
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+pub struct RefundBatchRowResult {
+    /// The position of the row in the batch, starting from 1
+    pub row_number: usize,
+
+    /// The payment id against which the refund is to be initiated
+    pub payment_id: String,
+
+    /// Amount to be refunded
+    #[schema(value_type = Option<i64>, example = 6540)]
+    pub amount: Option<MinorUnit>,
+
+    /// Reason for the refund
+    pub reason: Option<String>,
+
+    /// The status of the row
+    pub status: RefundBatchRowStatus,
+
+    /// The identifier of the refund initiated for the row
+    pub refund_id: Option<String>,
+
+    /// The status of the refund initiated for the row
+    pub refund_status: Option<RefundStatus>,
+
+    /// The reason due to which the row failed
+    pub error_message: Option<String>,
+}
+
+/// The progress of a refund batch
+#[derive(Debug, ToSchema, Clone, Serialize)]
+pub struct RefundBatchResponse {
+    /// The identifier of the refund batch
+    #[schema(example = "rfb_9qXMb2KHOb3QV6c3")]
+    pub batch_id: String,
+
+    /// The status of the refund batch
+    pub status: RefundBatchStatus,
+
+    /// The number of rows in the batch
+    pub total_count: usize,
+
+    /// The number of rows which have been processed
+    pub processed_count: usize,
+
+    /// The number of rows for which a refund has been initiated
+    pub initiated_count: usize,
+
+    /// The number of rows which failed validation, or for which a refund could not be initiated
+    pub failed_count: usize,
+
+    /// The time at which the batch was created
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// The time at which the progress of the batch was last updated
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
 /// To indicate whether to refund needs to be instant or scheduled
 #[derive(
     Default, Debug, Clone, Copy, ToSchema, Deserialize, Serialize, Eq, PartialEq, strum::Display,
@@ -214,6 +351,35 @@ pub struct RefundResponse {
     pub issuer_error_code: Option<String>,
     /// Error message received from the issuer in case of failed refunds
     pub issuer_error_message: Option<String>,
+    /// The reference number assigned by the acquirer to the refund, which the customer can use to
+    /// trace the refund with their bank
+    pub acquirer_reference_number: Option<String>,
+    /// The end-to-end reference of the refund of a bank transfer payment, which appears on the bank
+    /// statement of the payer
+    pub end_to_end_reference: Option<String>,
+    /// The destination of the refunded amount, either the original payment method or the store
+    /// credit balance of the customer
+    #[schema(value_type = Option<RefundDestination>)]
+    pub refund_destination: Option<enums::RefundDestination>,
+    /// The estimated range of time within which the refunded amount arrives in the account of the
+    /// customer, based on the settlement times of the refunds of the connector and payment method.
+    /// Absent for the failed refunds, and in the lists of refunds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimated_arrival: Option<RefundEstimatedArrival>,
+}
+
+/// The estimated range of time within which the refunded amount arrives in the account of the
+/// customer
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+pub struct RefundEstimatedArrival {
+    /// The earliest time at which the refunded amount is expected to arrive
+    #[schema(example = "2022-09-15T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub earliest: PrimitiveDateTime,
+    /// The latest time by which the refunded amount is expected to arrive
+    #[schema(example = "2022-09-20T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub latest: PrimitiveDateTime,
 }
 
 #[cfg(feature = "v1")]
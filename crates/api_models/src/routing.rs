@@ -101,6 +101,407 @@ pub struct MerchantRoutingAlgorithm {
     pub algorithm_for: TransactionType,
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+/// Portable document of a routing algorithm, which can be imported into another profile
+pub struct RoutingConfigExport {
+    /// The version of the format of the document
+    pub version: u16,
+    pub name: String,
+    pub description: String,
+    pub kind: RoutingAlgorithmKind,
+    pub algorithm_for: TransactionType,
+    pub algorithm: RoutingAlgorithm,
+    /// The profile the routing algorithm was exported from
+    #[schema(value_type = String)]
+    pub source_profile_id: common_utils::id_type::ProfileId,
+    pub exported_at: i64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// Request to import a routing algorithm exported from a profile into another profile
+pub struct RoutingConfigImportRequest {
+    /// The profile to import the routing algorithm into
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// The name of the imported routing algorithm, defaults to the name in the document
+    pub name: Option<String>,
+    pub config: RoutingConfigExport,
+}
+
+/// The metric by which the arms of a routing experiment are compared
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RoutingExperimentMetric {
+    /// The share of the attempts that were authorized by the connector
+    AuthorizationRate,
+    /// The share of the attempts that were captured, which is resolved only once the attempt
+    /// reaches a terminal status
+    CaptureRate,
+}
+
+/// The confidence level at which the difference between the arms of a routing experiment is
+/// considered statistically significant
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema,
+)]
+pub enum RoutingExperimentConfidenceLevel {
+    #[serde(rename = "90")]
+    Ninety,
+    #[default]
+    #[serde(rename = "95")]
+    NinetyFive,
+    #[serde(rename = "99")]
+    NinetyNine,
+}
+
+impl RoutingExperimentConfidenceLevel {
+    /// The critical value of the two-tailed z-test at the confidence level
+    pub fn get_critical_z_score(&self) -> f64 {
+        match self {
+            Self::Ninety => 1.645,
+            Self::NinetyFive => 1.96,
+            Self::NinetyNine => 2.576,
+        }
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RoutingExperimentStatus {
+    Running,
+    Concluded,
+    Cancelled,
+}
+
+/// The arms of a routing experiment, the control being the connector selection of the rule when
+/// the experiment was started
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RoutingExperimentArm {
+    Control,
+    Variant,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RoutingExperimentEvent {
+    Started,
+    Concluded,
+    Promoted,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// Request to start an experiment on a rule of an advanced routing algorithm, splitting the
+/// traffic of the rule between its connector selection and a variant connector selection
+pub struct RoutingExperimentCreateRequest {
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub name: String,
+    /// The advanced routing algorithm holding the rule
+    #[schema(value_type = String)]
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    /// The name of the rule to experiment on
+    pub rule_name: String,
+    /// The connector selection to compare the connector selection of the rule with
+    pub variant: ConnectorSelection,
+    /// The percentage of the traffic of the rule to be routed to the variant, between 1 and 99
+    pub variant_traffic_percentage: u8,
+    pub success_metric: RoutingExperimentMetric,
+    /// The least number of resolved attempts each arm should have before the experiment is
+    /// evaluated, defaults to 100
+    pub min_sample_size: Option<u32>,
+    /// Defaults to 95
+    pub confidence_level: Option<RoutingExperimentConfidenceLevel>,
+    /// Whether the winning arm should replace the connector selection of the rule once the
+    /// experiment is concluded, defaults to false
+    pub auto_promote: Option<bool>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingExperimentAuditEntry {
+    pub event: RoutingExperimentEvent,
+    /// The winning arm, for the conclusion and the promotion of the experiment
+    pub arm: Option<RoutingExperimentArm>,
+    pub details: Option<String>,
+    pub created_at: i64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+/// An experiment on a rule of an advanced routing algorithm
+pub struct RoutingExperiment {
+    pub experiment_id: String,
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub name: String,
+    #[schema(value_type = String)]
+    pub algorithm_id: common_utils::id_type::RoutingId,
+    pub rule_name: String,
+    pub control: ConnectorSelection,
+    pub variant: ConnectorSelection,
+    pub variant_traffic_percentage: u8,
+    pub success_metric: RoutingExperimentMetric,
+    pub min_sample_size: u32,
+    pub confidence_level: RoutingExperimentConfidenceLevel,
+    pub auto_promote: bool,
+    pub status: RoutingExperimentStatus,
+    pub winner: Option<RoutingExperimentArm>,
+    /// The routing algorithm created by the promotion of the winning arm
+    #[schema(value_type = Option<String>)]
+    pub promoted_algorithm_id: Option<common_utils::id_type::RoutingId>,
+    pub created_at: i64,
+    pub audit_trail: Vec<RoutingExperimentAuditEntry>,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct RoutingExperimentArmResult {
+    /// The number of attempts routed to the arm whose outcome is known
+    pub attempts: u64,
+    pub successes: u64,
+    pub success_rate: Option<f64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct RoutingExperimentResponse {
+    #[serde(flatten)]
+    pub experiment: RoutingExperiment,
+    pub control_result: RoutingExperimentArmResult,
+    pub variant_result: RoutingExperimentArmResult,
+    /// The z-score of the difference between the success rates of the variant and the control
+    pub z_score: Option<f64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingExperimentQuery {
+    pub profile_id: common_utils::id_type::ProfileId,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingExperimentRetrieveWrapper {
+    pub experiment_id: String,
+    pub profile_id: common_utils::id_type::ProfileId,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+/// Request to conclude a routing experiment before it reaches significance
+pub struct RoutingExperimentConcludeRequest {
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// The arm to conclude the experiment with, the experiment is cancelled if no arm is given
+    pub winner: Option<RoutingExperimentArm>,
+    /// Whether the winning arm should replace the connector selection of the rule, defaults to
+    /// the auto promotion of the experiment
+    pub promote: Option<bool>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RoutingExperimentConcludeRequestWrapper {
+    pub experiment_id: String,
+    pub request: RoutingExperimentConcludeRequest,
+}
+
+/// A daily cap on the payments routed to a merchant connector account, such as a contractual
+/// maximum with the connector. The connector is excluded from routing for the rest of the day (UTC)
+/// once any of its limits is reached.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorVolumeCap {
+    #[schema(value_type = String)]
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    /// The maximum number of payments to be authorized through the connector in a day
+    pub max_daily_payments: Option<u64>,
+    /// The maximum amount to be authorized through the connector in a day, in the currency of the
+    /// cap. Only the payments in the currency of the cap are counted against it.
+    #[schema(value_type = Option<i64>)]
+    pub max_daily_amount: Option<common_utils::types::MinorUnit>,
+    /// The currency of the maximum amount, required along with it
+    #[schema(value_type = Option<Currency>)]
+    pub currency: Option<common_enums::Currency>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ConnectorVolumeCapsRequest {
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// The caps of the profile, replacing its existing caps
+    pub caps: Vec<ConnectorVolumeCap>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct ConnectorVolumeCapUsage {
+    #[serde(flatten)]
+    pub cap: ConnectorVolumeCap,
+    /// The number of payments authorized through the connector today
+    pub payments_today: u64,
+    /// The amount authorized through the connector today, in the currency of the cap
+    #[schema(value_type = Option<i64>)]
+    pub amount_today: Option<common_utils::types::MinorUnit>,
+    pub is_cap_reached: bool,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct ConnectorVolumeCapsResponse {
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub caps: Vec<ConnectorVolumeCapUsage>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ConnectorVolumeCapsQuery {
+    pub profile_id: common_utils::id_type::ProfileId,
+}
+
+/// A hypothetical payment to be routed through the routing algorithm of a profile
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingSimulationRequest {
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// The routing algorithm to simulate, defaults to the active routing algorithm of the profile
+    #[schema(value_type = Option<String>)]
+    pub algorithm_id: Option<common_utils::id_type::RoutingId>,
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: common_utils::types::MinorUnit,
+    #[schema(value_type = Currency)]
+    pub currency: common_enums::Currency,
+    #[schema(value_type = PaymentMethod)]
+    pub payment_method: common_enums::PaymentMethod,
+    #[schema(value_type = Option<PaymentMethodType>)]
+    pub payment_method_type: Option<common_enums::PaymentMethodType>,
+    #[schema(value_type = Option<CardNetwork>)]
+    pub card_network: Option<common_enums::CardNetwork>,
+    /// The first six digits of the card number
+    pub card_bin: Option<String>,
+    #[schema(value_type = Option<AuthenticationType>)]
+    pub authentication_type: Option<common_enums::AuthenticationType>,
+    #[schema(value_type = Option<CaptureMethod>)]
+    pub capture_method: Option<common_enums::CaptureMethod>,
+    #[schema(value_type = Option<FutureUsage>)]
+    pub setup_future_usage: Option<common_enums::FutureUsage>,
+    #[schema(value_type = Option<CountryAlpha2>)]
+    pub billing_country: Option<common_enums::CountryAlpha2>,
+    #[schema(value_type = Option<CountryAlpha2>)]
+    pub business_country: Option<common_enums::CountryAlpha2>,
+    pub business_label: Option<String>,
+    /// The routing parameters of the payment, as passed in the metadata of the payment
+    pub metadata: Option<std::collections::HashMap<String, String>>,
+    /// The connectors the payment is restricted to
+    #[schema(value_type = Option<Vec<RoutableConnectors>>)]
+    pub connector: Option<Vec<common_enums::RoutableConnectors>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RoutingSimulationSource {
+    /// The connector was chosen by the routing algorithm
+    Algorithm,
+    /// The connector was appended from the default fallback connectors of the profile
+    Fallback,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RoutingEliminationReason {
+    /// The connector is not in the connectors the payment is restricted to
+    NotInEligibleConnectors,
+    /// The merchant connector account does not support the payment, as per its enabled payment
+    /// methods and the country and currency filters of the connector
+    ConstraintGraph,
+    /// The merchant connector account is in a maintenance window
+    UnderMaintenance,
+    /// The credentials of the merchant connector account have expired
+    CredentialsExpired,
+    /// The profile is in test mode and the merchant connector account is not a test account
+    NotInTestMode,
+    /// The merchant connector account has reached its daily volume cap
+    VolumeCapReached,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct RoutingSimulationCandidate {
+    #[schema(value_type = RoutableConnectors)]
+    pub connector: common_enums::RoutableConnectors,
+    #[schema(value_type = Option<String>)]
+    pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
+    pub source: RoutingSimulationSource,
+    /// The percentage of the traffic routed to the connector, if it was chosen by a volume split
+    pub volume_split: Option<u8>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct RoutingSimulationElimination {
+    #[serde(flatten)]
+    pub candidate: RoutingSimulationCandidate,
+    pub reason: RoutingEliminationReason,
+}
+
+/// The outcome of the routing of a hypothetical payment. The connectors of a volume split are
+/// ordered by their share of the traffic, whereas a payment tries first a connector picked at
+/// random as per the shares. The dynamic routing and the running experiments of the profile,
+/// which depend on the live traffic, are not simulated.
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct RoutingSimulationResponse {
+    #[schema(value_type = String)]
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// The simulated routing algorithm, none if the profile has no active routing algorithm
+    #[schema(value_type = Option<String>)]
+    pub algorithm_id: Option<common_utils::id_type::RoutingId>,
+    /// The name of the rule of the advanced routing algorithm matched by the payment
+    pub matched_rule: Option<String>,
+    /// The connectors the payment would be tried with, in order
+    pub candidates: Vec<RoutingSimulationCandidate>,
+    /// The connectors chosen by the routing which were eliminated, with the reason
+    pub eliminated: Vec<RoutingSimulationElimination>,
+}
+
 impl EuclidDirFilter for ConnectorSelection {
     const ALLOWED: &'static [DirKeyKind] = &[
         DirKeyKind::PaymentMethod,
@@ -269,7 +670,9 @@ impl RoutableConnectorChoiceWithStatus {
     }
 }
 
-#[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize, strum::Display, ToSchema)]
+#[derive(
+    Debug, Copy, Clone, PartialEq, serde::Serialize, serde::Deserialize, strum::Display, ToSchema,
+)]
 #[serde(rename_all = "snake_case")]
 #[strum(serialize_all = "snake_case")]
 pub enum RoutingAlgorithmKind {
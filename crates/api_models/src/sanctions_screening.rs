@@ -0,0 +1,134 @@
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type,
+};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The kind of object whose names were screened
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+    strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ScreeningObjectType {
+    Payment,
+    Payout,
+}
+
+/// The status of a sanctions screening case
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    ToSchema,
+    strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ScreeningCaseStatus {
+    /// The object is held until the case is reviewed
+    PendingReview,
+    /// The match was found to be a false positive, and the object is released
+    Cleared,
+    /// The match was confirmed, and the object cannot proceed
+    Blocked,
+}
+
+/// The decision taken on the review of a sanctions screening case
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ScreeningDecision {
+    /// Release the object, the match being a false positive
+    Clear,
+    /// Block the object, the match being confirmed
+    Block,
+}
+
+/// An entry of a watchlist which matched one of the screened names
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ScreeningMatch {
+    /// The field of the object whose name matched the entry
+    #[schema(example = "billing_name")]
+    pub field: String,
+    /// The name of the entry of the watchlist
+    #[schema(example = "John Doe")]
+    pub entry: String,
+    /// The watchlist to which the entry belongs
+    #[schema(example = "OFAC SDN")]
+    pub list: Option<String>,
+    /// The confidence of the match, out of 100
+    #[schema(example = 95)]
+    pub score: Option<u8>,
+}
+
+/// Request to resolve a sanctions screening case which is pending review
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ScreeningCaseResolveRequest {
+    /// The decision taken on the review of the case
+    pub decision: ScreeningDecision,
+    /// Notes of the reviewer, recorded with the case
+    #[schema(max_length = 255, example = "Different date of birth")]
+    pub notes: Option<String>,
+}
+
+/// A sanctions screening case, opened when a screened name matches a watchlist
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ScreeningCaseResponse {
+    /// The identifier for the case
+    #[schema(example = "scr_mbabizu24mvu3mela5njyhpit4")]
+    pub case_id: String,
+    /// The kind of object whose names were screened
+    pub object_type: ScreeningObjectType,
+    /// The identifier for the payment or the payout whose names were screened
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub object_id: String,
+    /// The identifier for the profile of the object
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+    /// The status of the case
+    pub status: ScreeningCaseStatus,
+    /// The provider which screened the names
+    #[schema(example = "watchlist")]
+    pub provider: String,
+    /// The entries of the watchlists which matched the screened names
+    pub matches: Vec<ScreeningMatch>,
+    /// Whether the case was opened as the provider could not be reached, the object being held
+    /// until it is reviewed
+    pub provider_unavailable: bool,
+    /// Notes of the reviewer
+    pub resolution_notes: Option<String>,
+    /// The time at which the case was opened
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The time at which the case was resolved
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub resolved_at: Option<PrimitiveDateTime>,
+}
+
+impl ApiEventMetric for ScreeningCaseResolveRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for ScreeningCaseResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -0,0 +1,109 @@
+use common_enums::{AuthenticationType, Currency};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums::Connector;
+#[cfg(feature = "dummy_connector")]
+use crate::user::sample_data::SampleDataRequest;
+
+#[derive(Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SandboxDataSeedRequest {
+    /// The number of payments to be generated, between 10 and 100
+    #[schema(example = 50)]
+    pub record: Option<usize>,
+
+    /// The connectors through which the payments are generated
+    #[schema(value_type = Option<Vec<Connector>>, example = json!(["stripe_test"]))]
+    pub connector: Option<Vec<Connector>>,
+
+    /// The earliest creation time of the generated payments
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub start_time: Option<PrimitiveDateTime>,
+
+    /// The latest creation time of the generated payments
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub end_time: Option<PrimitiveDateTime>,
+
+    /// The minimum amount of the generated payments, in the major unit of the currency
+    #[schema(example = 10)]
+    pub min_amount: Option<i64>,
+
+    /// The maximum amount of the generated payments, in the major unit of the currency
+    #[schema(example = 1000)]
+    pub max_amount: Option<i64>,
+
+    /// The currencies in which the payments are generated
+    #[schema(value_type = Option<Vec<Currency>>, example = json!(["USD"]))]
+    pub currency: Option<Vec<Currency>>,
+
+    /// The authentication types with which the payments are generated
+    #[schema(value_type = Option<Vec<AuthenticationType>>, example = json!(["no_three_ds"]))]
+    pub auth_type: Option<Vec<AuthenticationType>>,
+
+    /// The profile for which the payments are generated. The default profile of the merchant is
+    /// used if not provided.
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+}
+
+#[cfg(feature = "dummy_connector")]
+impl From<SandboxDataSeedRequest> for SampleDataRequest {
+    fn from(request: SandboxDataSeedRequest) -> Self {
+        Self {
+            record: request.record,
+            connector: request.connector,
+            start_time: request.start_time,
+            end_time: request.end_time,
+            min_amount: request.min_amount,
+            max_amount: request.max_amount,
+            currency: request.currency,
+            auth_type: request.auth_type,
+            business_country: None,
+            business_label: None,
+            profile_id: request.profile_id,
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct SandboxDataSeedResponse {
+    /// The number of payments generated
+    pub payments: usize,
+
+    /// The number of refunds generated for the payments
+    pub refunds: usize,
+
+    /// The number of disputes generated for the payments
+    pub disputes: usize,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TestClockAdvanceRequest {
+    /// The duration in seconds by which the test clock of the merchant is moved forward
+    #[schema(example = 86400)]
+    pub advance_by: u32,
+}
+
+#[derive(Debug, serde::Serialize, ToSchema)]
+pub struct TestClockResponse {
+    /// The identifier for the merchant
+    #[schema(value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The duration in seconds by which the test clock is ahead of the actual time
+    pub offset: i64,
+
+    /// The current time as per the test clock
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub current_time: PrimitiveDateTime,
+
+    /// The number of scheduled tasks of the merchant which were brought forward while advancing
+    /// the test clock
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rescheduled_tasks: Option<usize>,
+}
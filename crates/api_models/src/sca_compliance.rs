@@ -0,0 +1,133 @@
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type,
+};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// How the strong customer authentication (SCA) of PSD2 was treated for a payment
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumIter,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ScaTreatment {
+    /// The customer was authenticated with a challenge of the issuer
+    Challenge,
+    /// The customer was authenticated by the issuer without a challenge
+    Frictionless,
+    /// The customer was authenticated with 3DS by the connector, which does not report whether the
+    /// issuer challenged the customer
+    ConnectorAuthenticated,
+    /// The authentication of the customer was attempted but did not succeed
+    AuthenticationFailed,
+    /// The payment was exempted from SCA, with the exemption requested for it
+    Exempted,
+    /// The payment was initiated by the merchant, which is out of the scope of SCA
+    MerchantInitiated,
+    /// The customer was not authenticated and no exemption was requested. Mail and telephone
+    /// orders, which are out of the scope of SCA, are reported with this treatment as they are not
+    /// flagged as such.
+    NotApplied,
+}
+
+/// The status of the generation of an SCA compliance report
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ScaComplianceReportStatus {
+    /// The report is yet to be generated
+    Queued,
+    /// The report is generated and can be downloaded
+    Completed,
+    /// The generation of the report failed
+    Failed,
+}
+
+/// Request to generate the SCA compliance report of a month
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ScaComplianceReportRequest {
+    /// The year of the month of the report
+    #[schema(example = 2025)]
+    pub year: i32,
+    /// The month of the report, from 1 to 12. The report can only be generated once the month has
+    /// ended.
+    #[schema(example = 3)]
+    pub month: u8,
+}
+
+/// The number of the payments of the report with an SCA treatment
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct ScaTreatmentCount {
+    /// The SCA treatment of the payments
+    pub treatment: ScaTreatment,
+    /// The number of the payments with the SCA treatment
+    pub count: usize,
+}
+
+/// An SCA compliance report, which classifies by SCA treatment the card payments created in a month
+/// with the cards issued in the European Economic Area and the United Kingdom
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ScaComplianceReportResponse {
+    /// The identifier of the report
+    #[schema(example = "scr_9qXMb2KHOb3QV6c3")]
+    pub report_id: String,
+    /// The profile whose payments are reported, all the profiles of the merchant if not given
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+    /// The year of the month of the report
+    #[schema(example = 2025)]
+    pub year: i32,
+    /// The month of the report, from 1 to 12
+    #[schema(example = 3)]
+    pub month: u8,
+    /// The status of the generation of the report
+    pub status: ScaComplianceReportStatus,
+    /// The number of the reported payments
+    pub total_count: usize,
+    /// The number of the reported payments with each SCA treatment
+    pub treatments: Vec<ScaTreatmentCount>,
+    /// The reason due to which the generation of the report failed
+    pub error_message: Option<String>,
+    /// The time at which the report was requested
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The time at which the report was last updated
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl ApiEventMetric for ScaComplianceReportRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for ScaComplianceReportResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
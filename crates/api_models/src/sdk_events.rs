@@ -0,0 +1,83 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// A batch of the events generated by the SDK
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SdkEventsBatchRequest {
+    /// The events of the batch, in the order in which they were generated
+    pub events: Vec<SdkEventRequest>,
+}
+
+/// An event generated by the SDK
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct SdkEventRequest {
+    /// The identifier of the payment for which the event was generated
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: Option<String>,
+    /// The type of the log, such as `INFO`, `WARNING` or `ERROR`
+    #[schema(example = "INFO")]
+    pub log_type: Option<String>,
+    /// The name of the event
+    #[schema(example = "PAYMENT_ATTEMPT")]
+    pub event_name: String,
+    /// Whether this is the first occurrence of the event in the session
+    pub first_event: Option<String>,
+    /// The latency of the event, in milliseconds
+    pub latency: Option<u32>,
+    /// The time at which the event was generated, as milliseconds since the epoch
+    #[schema(example = 1743075000000)]
+    pub timestamp: i64,
+    /// The name of the browser
+    pub browser_name: Option<String>,
+    /// The version of the browser
+    pub browser_version: Option<String>,
+    /// The platform on which the SDK is running
+    pub platform: Option<String>,
+    /// The source of the event
+    pub source: Option<String>,
+    /// The category of the event
+    pub category: Option<String>,
+    /// The version of the SDK
+    pub version: Option<String>,
+    /// The value of the event
+    pub value: Option<String>,
+    /// The component of the SDK which generated the event
+    pub component: Option<String>,
+    /// The payment method used in the SDK
+    pub payment_method: Option<String>,
+    /// The payment experience used in the SDK
+    pub payment_experience: Option<String>,
+    /// The locale in which the SDK is rendered
+    #[schema(example = "en-US")]
+    pub locale: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct SdkEventsBatchResponse {
+    /// The number of events of the batch that were accepted
+    pub accepted: usize,
+    /// The events of the batch that were rejected
+    pub rejected: Vec<RejectedSdkEvent>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RejectedSdkEvent {
+    /// The position of the event in the batch
+    pub index: usize,
+    /// The reason for which the event was rejected
+    pub reason: String,
+}
+
+impl ApiEventMetric for SdkEventsBatchRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for SdkEventsBatchResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -35,6 +35,9 @@ impl EuclidDirFilter for SurchargeDecisionConfigs {
         DirKeyKind::PaymentCurrency,
         DirKeyKind::BillingCountry,
         DirKeyKind::CardNetwork,
+        DirKeyKind::CardType,
+        DirKeyKind::CardBin,
+        DirKeyKind::CardProductType,
         DirKeyKind::PayLaterType,
         DirKeyKind::WalletType,
         DirKeyKind::BankTransferType,
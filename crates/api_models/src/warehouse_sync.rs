@@ -0,0 +1,248 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use masking::Secret;
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The tables of the merchant which can be synced to its data warehouse
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WarehouseSyncTable {
+    Payments,
+    Refunds,
+    Disputes,
+}
+
+/// The data warehouse to which the records of the merchant are synced
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum WarehouseDestination {
+    /// A BigQuery dataset. The records are streamed to the tables of the dataset, which must
+    /// already exist, authenticated as the given service account.
+    BigQuery {
+        /// The Google Cloud project of the dataset
+        #[schema(example = "acme-analytics")]
+        project_id: String,
+        /// The dataset to which the records are synced
+        #[schema(example = "hyperswitch")]
+        dataset_id: String,
+        /// The email address of the service account, which must be allowed to insert rows in the
+        /// tables of the dataset
+        #[schema(example = "warehouse-sync@acme-analytics.iam.gserviceaccount.com")]
+        client_email: String,
+        /// The identifier of the private key of the service account
+        private_key_id: Option<String>,
+        /// The PEM encoded private key of the service account
+        #[schema(value_type = String)]
+        private_key: Secret<String>,
+    },
+    /// A Snowflake schema. The records are inserted in the tables of the schema, which must
+    /// already exist, with the SQL API.
+    Snowflake {
+        /// The identifier of the Snowflake account, as in its URL
+        #[schema(example = "acme-analytics")]
+        account_identifier: String,
+        /// The database to which the records are synced
+        #[schema(example = "ANALYTICS")]
+        database: String,
+        /// The schema of the database to which the records are synced
+        #[schema(example = "HYPERSWITCH")]
+        schema: String,
+        /// The warehouse which runs the inserts, the default warehouse of the user if not given
+        warehouse: Option<String>,
+        /// The role with which the inserts are run, the default role of the user if not given
+        role: Option<String>,
+        /// A programmatic access token of a user which is allowed to insert rows in the tables of
+        /// the schema
+        #[schema(value_type = String)]
+        access_token: Secret<String>,
+    },
+}
+
+/// The data warehouse to which the records of the merchant are synced, without its credentials
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WarehouseDestinationResponse {
+    BigQuery {
+        project_id: String,
+        dataset_id: String,
+        client_email: String,
+    },
+    Snowflake {
+        account_identifier: String,
+        database: String,
+        schema: String,
+        warehouse: Option<String>,
+        role: Option<String>,
+    },
+}
+
+/// How a field of the synced records is redacted
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WarehouseRedactionAction {
+    /// The field is not synced
+    Drop,
+    /// The field is replaced with a keyed hash of its value, which can still be used to join the
+    /// records with the same value
+    Hash,
+    /// All but the last four characters of the field are masked
+    Mask,
+}
+
+/// A rule for the redaction of a field of the synced records
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WarehouseRedactionRule {
+    /// The table whose records are redacted, all the tables with the field if not given
+    pub table: Option<WarehouseSyncTable>,
+    /// The field which is redacted
+    #[schema(example = "customer_id")]
+    pub field: String,
+    /// How the field is redacted
+    pub action: WarehouseRedactionAction,
+}
+
+/// Request to configure the sync of the records of the merchant to its data warehouse
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WarehouseSyncConfigRequest {
+    /// The data warehouse to which the records are synced
+    pub destination: WarehouseDestination,
+    /// The tables which are synced, all the tables if not given
+    pub tables: Option<Vec<WarehouseSyncTable>>,
+    /// The prefix of the names of the tables of the data warehouse, such as `hyperswitch_` for the
+    /// `hyperswitch_payments` table
+    #[schema(example = "hyperswitch_")]
+    pub table_prefix: Option<String>,
+    /// The rules for the redaction of the personal data of the synced records. The customer
+    /// identifiers are hashed, and the descriptions, reasons and metadata are dropped, if not
+    /// given.
+    pub redaction_rules: Option<Vec<WarehouseRedactionRule>>,
+    /// The interval between the syncs, in minutes
+    #[schema(example = 60)]
+    pub sync_interval_minutes: Option<u32>,
+    /// Whether the records are synced
+    pub enabled: Option<bool>,
+}
+
+/// The sync of the records of the merchant to its data warehouse
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct WarehouseSyncConfigResponse {
+    /// The data warehouse to which the records are synced
+    pub destination: WarehouseDestinationResponse,
+    /// The tables which are synced
+    pub tables: Vec<WarehouseSyncTable>,
+    /// The prefix of the names of the tables of the data warehouse
+    pub table_prefix: Option<String>,
+    /// The rules for the redaction of the personal data of the synced records
+    pub redaction_rules: Vec<WarehouseRedactionRule>,
+    /// The interval between the syncs, in minutes
+    pub sync_interval_minutes: u32,
+    /// Whether the records are synced
+    pub enabled: bool,
+    /// The time at which the sync was configured
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The time at which the sync was last reconfigured
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+/// The progress of the sync of a table to the data warehouse
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct WarehouseSyncTableStatus {
+    /// The synced table
+    pub table: WarehouseSyncTable,
+    /// The last modification time of the last record synced. The records modified after it are
+    /// synced in the next sync.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub high_water_mark: Option<PrimitiveDateTime>,
+    /// The number of records synced since the table was last synced from the start
+    pub records_synced: u64,
+    /// The time at which records of the table were last synced
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_synced_at: Option<PrimitiveDateTime>,
+    /// The error of the last sync of the table, if it failed
+    pub last_error: Option<String>,
+    /// The time at which the last sync of the table failed
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_error_at: Option<PrimitiveDateTime>,
+}
+
+/// The status of the sync of the records of the merchant to its data warehouse
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct WarehouseSyncStatusResponse {
+    /// Whether the records are synced
+    pub enabled: bool,
+    /// The time at which the records were last synced
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_run_at: Option<PrimitiveDateTime>,
+    /// The time at which the records are next synced
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub next_run_at: Option<PrimitiveDateTime>,
+    /// The progress of the sync of each of the synced tables
+    pub tables: Vec<WarehouseSyncTableStatus>,
+}
+
+/// Request to sync the records of the merchant to its data warehouse immediately
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WarehouseSyncTriggerRequest {
+    /// The tables which are synced again from their first record, for instance after the tables of
+    /// the data warehouse were recreated
+    pub resync_tables: Option<Vec<WarehouseSyncTable>>,
+}
+
+impl ApiEventMetric for WarehouseSyncConfigRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for WarehouseSyncConfigResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for WarehouseSyncStatusResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for WarehouseSyncTriggerRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
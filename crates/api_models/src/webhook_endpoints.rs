@@ -0,0 +1,124 @@
+use common_utils::{
+    events::{ApiEventMetric, ApiEventsType},
+    id_type,
+};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The maximum window over which the health of a webhook endpoint can be retrieved, in hours
+pub const WEBHOOK_ENDPOINT_HEALTH_MAX_WINDOW_IN_HOURS: u32 = 7 * 24;
+/// The default window over which the health of a webhook endpoint is retrieved, in hours
+pub const WEBHOOK_ENDPOINT_HEALTH_DEFAULT_WINDOW_IN_HOURS: u32 = 24;
+
+/// The status of the delivery of the outgoing webhooks to an endpoint
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEndpointStatus {
+    /// The last webhook sent to the endpoint was delivered, or no webhook has been sent to it yet
+    Healthy,
+    /// The last webhooks sent to the endpoint failed to be delivered, and are being retried
+    Failing,
+    /// The delivery of the webhooks to the endpoint is paused, as it has been failing persistently.
+    /// The webhooks are not sent to the endpoint, nor retried, until the delivery is resumed.
+    Paused,
+}
+
+/// Query parameters to retrieve the health of a webhook endpoint
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookEndpointHealthRequest {
+    /// The number of hours, up to the current hour, over which the deliveries are aggregated.
+    /// Defaults to 24 hours, and cannot exceed 168 hours.
+    #[schema(example = 24, maximum = 168)]
+    pub window_in_hours: Option<u32>,
+}
+
+impl WebhookEndpointHealthRequest {
+    pub fn get_window_in_hours(&self) -> Option<u32> {
+        match self.window_in_hours {
+            None => Some(WEBHOOK_ENDPOINT_HEALTH_DEFAULT_WINDOW_IN_HOURS),
+            Some(window_in_hours)
+                if (1..=WEBHOOK_ENDPOINT_HEALTH_MAX_WINDOW_IN_HOURS).contains(&window_in_hours) =>
+            {
+                Some(window_in_hours)
+            }
+            Some(_) => None,
+        }
+    }
+}
+
+/// The deliveries of the outgoing webhooks to an endpoint during an hour
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct WebhookEndpointHourlyHealth {
+    /// The start of the hour
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:00:00Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub hour_start: PrimitiveDateTime,
+    /// The number of attempts to deliver a webhook to the endpoint
+    pub total_deliveries: u64,
+    /// The number of attempts which failed, either with a non 2xx response or without a response
+    pub failed_deliveries: u64,
+    /// The average time taken by the endpoint to respond, in milliseconds
+    pub average_latency_in_ms: Option<u64>,
+}
+
+/// The health of the delivery of the outgoing webhooks to an endpoint. The endpoint of a profile is
+/// the webhook URL configured in the profile, and is identified by the ID of the profile.
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct WebhookEndpointHealthResponse {
+    /// The identifier of the endpoint, which is the ID of its profile
+    #[schema(value_type = String, example = "pro_abcdefghijklmnopqrst")]
+    pub endpoint_id: id_type::ProfileId,
+    /// The URL of the endpoint, if configured in the profile
+    #[schema(example = "https://merchant.example.com/webhooks")]
+    pub url: Option<String>,
+    /// The status of the delivery of the webhooks to the endpoint
+    pub status: WebhookEndpointStatus,
+    /// The number of hours over which the deliveries are aggregated
+    pub window_in_hours: u32,
+    /// The number of attempts to deliver a webhook to the endpoint within the window
+    pub total_deliveries: u64,
+    /// The number of attempts within the window which were delivered
+    pub successful_deliveries: u64,
+    /// The number of attempts within the window which failed
+    pub failed_deliveries: u64,
+    /// The percentage of the attempts within the window which were delivered
+    #[schema(example = 99.5)]
+    pub success_rate: Option<f64>,
+    /// The average time taken by the endpoint to respond within the window, in milliseconds
+    pub average_latency_in_ms: Option<u64>,
+    /// The number of consecutive attempts which failed since the last delivered webhook
+    pub consecutive_failures: u64,
+    /// The time of the first of the consecutive failed attempts
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub failing_since: Option<PrimitiveDateTime>,
+    /// The time of the last attempt which was delivered
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_success_at: Option<PrimitiveDateTime>,
+    /// The time of the last attempt which failed
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_failure_at: Option<PrimitiveDateTime>,
+    /// The time at which the delivery of the webhooks to the endpoint was paused
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub paused_at: Option<PrimitiveDateTime>,
+    /// The deliveries within the window, per hour, from the oldest hour
+    pub hourly: Vec<WebhookEndpointHourlyHealth>,
+}
+
+impl ApiEventMetric for WebhookEndpointHealthRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for WebhookEndpointHealthResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::BusinessProfile {
+            profile_id: self.endpoint_id.clone(),
+        })
+    }
+}
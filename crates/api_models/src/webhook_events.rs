@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use common_enums::{EventClass, EventType, WebhookDeliveryAttempt};
 use masking::Secret;
 use serde::{Deserialize, Serialize};
@@ -108,6 +110,145 @@ impl common_utils::events::ApiEventMetric for TotalEventsResponse {
     }
 }
 
+/// An event type of the outgoing webhooks, along with the schema of the object sent in its webhooks
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventTypeCatalogItem {
+    /// The type of the event
+    pub event_type: EventType,
+
+    /// The class of the event (the type of object: Payment, Refund, etc.)
+    pub event_class: EventClass,
+
+    /// The type of the object sent in the webhooks of the event type, as `content.type`
+    #[schema(example = "payment_details")]
+    pub content_type: String,
+
+    /// The name of the schema of the object sent in the webhooks of the event type, as
+    /// `content.object`, in `schemas`
+    #[schema(example = "PaymentsResponse")]
+    pub content_schema: String,
+}
+
+/// The catalog of the event types of the outgoing webhooks, with the JSON schemas of their payloads
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventTypeCatalogResponse {
+    /// The event types of the outgoing webhooks
+    pub event_types: Vec<EventTypeCatalogItem>,
+
+    /// The JSON schemas of the payloads of the outgoing webhooks, keyed by their names. The
+    /// `OutgoingWebhook` schema is the envelope of the payload of every webhook. The schemas refer
+    /// to the schemas of their nested objects as `#/components/schemas/{name}`, as published in
+    /// the OpenAPI specification of the API.
+    #[schema(value_type = Object)]
+    pub schemas: BTreeMap<String, serde_json::Value>,
+}
+
+impl common_utils::events::ApiEventMetric for EventTypeCatalogResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Miscellaneous)
+    }
+}
+
+/// The constraints to apply when listing the lifecycle events of a merchant.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct LifecycleEventListConstraints {
+    /// Only include events created after the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_after: Option<PrimitiveDateTime>,
+
+    /// Only include events created before the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_before: Option<PrimitiveDateTime>,
+
+    /// Only include events of the specified class (the type of object: Payment, Refund, etc.)
+    pub event_class: Option<EventClass>,
+
+    /// Only include events of the specified type.
+    pub event_type: Option<EventType>,
+
+    /// Only include events associated with the specified business profile ID.
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+
+    /// The cursor to continue listing from, which is the `next_cursor` returned in the previous
+    /// page of events.
+    #[schema(example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    pub starting_after: Option<String>,
+
+    /// Include at most the specified number of events.
+    pub limit: Option<u16>,
+}
+
+impl common_utils::events::ApiEventMetric for LifecycleEventListConstraints {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::ResourceListAPI)
+    }
+}
+
+/// The response body for each lifecycle event when listing the lifecycle events of a merchant.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LifecycleEventResponse {
+    /// The identifier for the Event.
+    #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    pub event_id: String,
+
+    /// The identifier for the Merchant Account.
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44", value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, value_type = Option<String>, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+
+    /// The identifier for the object (Payment Intent ID, Refund ID, etc.)
+    #[schema(max_length = 64, example = "QHrfd5LUDdZaKtAjdJmMu0dMa1")]
+    pub object_id: String,
+
+    /// Specifies the type of event, which includes the object and its status.
+    pub event_type: EventType,
+
+    /// Specifies the class of event (the type of object: Payment, Refund, etc.)
+    pub event_class: EventClass,
+
+    /// The identifier for the initial webhook delivery attempt of the event. This is absent when
+    /// no webhook was sent for the event.
+    #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    pub initial_attempt_id: Option<String>,
+
+    /// Indicates whether the webhook of the event was delivered successfully.
+    pub is_webhook_delivered: bool,
+
+    /// Time at which the object of the event was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub object_created: Option<PrimitiveDateTime>,
+
+    /// Time at which the event was created.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+/// The response body of the list lifecycle events api call.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LifecycleEventListResponse {
+    /// The lifecycle events, newest first
+    pub data: Vec<LifecycleEventResponse>,
+
+    /// Indicates whether there are more events matching the constraints after this page
+    pub has_more: bool,
+
+    /// The cursor to pass as `starting_after` to list the next page of events
+    #[schema(example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    pub next_cursor: Option<String>,
+}
+
+impl common_utils::events::ApiEventMetric for LifecycleEventListResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::ResourceListAPI)
+    }
+}
+
 /// The response body for retrieving an event.
 #[derive(Debug, Serialize, ToSchema)]
 pub struct EventRetrieveResponse {
@@ -0,0 +1,152 @@
+use common_enums::EventType;
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// Request to set the payload template of the outgoing webhooks of an event type
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookPayloadTemplateRequest {
+    /// The payload template. The strings of the template can reference the fields of the default
+    /// payload of the webhook with placeholders such as `{{ content.object.payment_id }}`.
+    #[schema(value_type = Object, example = json!({
+        "id": "{{ event_id }}",
+        "type": "{{ event_type }}",
+        "payment": {
+            "reference": "{{ content.object.payment_id }}",
+            "amount": "{{ content.object.amount }}",
+            "description": "Payment {{ content.object.payment_id }} is {{ content.object.status }}"
+        }
+    }))]
+    pub template: serde_json::Value,
+}
+
+/// The payload template of the outgoing webhooks of an event type
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct WebhookPayloadTemplateResponse {
+    /// The event type of the webhooks rendered with the template
+    #[schema(value_type = EventType)]
+    pub event_type: EventType,
+    /// The payload template
+    #[schema(value_type = Object)]
+    pub template: serde_json::Value,
+    /// The fields of the default payload which are referenced by the template
+    #[schema(example = json!(["event_id", "content.object.payment_id"]))]
+    pub referenced_paths: Vec<String>,
+    /// The time at which the template was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    /// The time at which the template was last updated
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+/// The payload templates of the outgoing webhooks of the merchant
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct WebhookPayloadTemplateListResponse {
+    pub templates: Vec<WebhookPayloadTemplateResponse>,
+}
+
+/// Request to validate a payload template, without storing it
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookPayloadTemplateValidateRequest {
+    /// The payload template
+    #[schema(value_type = Object)]
+    pub template: serde_json::Value,
+}
+
+/// An error in a payload template
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, ToSchema)]
+pub struct WebhookPayloadTemplateError {
+    /// The location of the error in the template, as the keys and the indices leading to it
+    #[schema(example = "payment.reference")]
+    pub location: String,
+    /// The description of the error
+    #[schema(example = "Placeholder is not closed")]
+    pub message: String,
+}
+
+/// The result of the validation of a payload template
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct WebhookPayloadTemplateValidateResponse {
+    /// Whether the template can be used
+    pub valid: bool,
+    /// The errors in the template
+    pub errors: Vec<WebhookPayloadTemplateError>,
+    /// The fields of the default payload which are referenced by the template
+    pub referenced_paths: Vec<String>,
+}
+
+/// Request to preview the payload rendered with a template. The payload is rendered from the
+/// given default payload, or from the payload of a past event.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookPayloadTemplatePreviewRequest {
+    /// The event type whose stored template is used, if no template is given
+    #[schema(value_type = Option<EventType>)]
+    pub event_type: Option<EventType>,
+    /// The payload template to preview, instead of the stored template of the event type
+    #[schema(value_type = Option<Object>)]
+    pub template: Option<serde_json::Value>,
+    /// A default payload of a webhook to render the template from
+    #[schema(value_type = Option<Object>)]
+    pub payload: Option<serde_json::Value>,
+    /// The identifier for a past event, whose delivered payload is rendered from
+    #[schema(example = "evt_018e31720d1b7a2b82677d3032cab959")]
+    pub event_id: Option<String>,
+}
+
+/// The payload rendered with a template
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct WebhookPayloadTemplatePreviewResponse {
+    /// The rendered payload, as it would be sent in the webhook
+    #[schema(value_type = Object)]
+    pub rendered_payload: serde_json::Value,
+    /// The referenced fields which are not present in the default payload, rendered as null
+    pub unresolved_paths: Vec<String>,
+}
+
+impl ApiEventMetric for WebhookPayloadTemplateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for WebhookPayloadTemplateResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for WebhookPayloadTemplateListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for WebhookPayloadTemplateValidateRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for WebhookPayloadTemplateValidateResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for WebhookPayloadTemplatePreviewRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for WebhookPayloadTemplatePreviewResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
@@ -5,7 +5,7 @@ use utoipa::ToSchema;
 
 #[cfg(feature = "payouts")]
 use crate::payouts;
-use crate::{disputes, enums as api_enums, mandates, payments, refunds};
+use crate::{admin, disputes, enums as api_enums, mandates, payments, refunds};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy)]
 #[serde(rename_all = "snake_case")]
@@ -302,6 +302,10 @@ pub enum OutgoingWebhookContent {
     #[cfg(feature = "payouts")]
     #[schema(value_type = PayoutCreateResponse, title = "PayoutCreateResponse")]
     PayoutDetails(Box<payouts::PayoutCreateResponse>),
+    #[schema(value_type = MerchantAccountStatusResponse, title = "MerchantAccountStatusResponse")]
+    MerchantAccountDetails(Box<admin::MerchantAccountStatusResponse>),
+    #[schema(value_type = ConnectorCredentialsResponse, title = "ConnectorCredentialsResponse")]
+    ConnectorCredentialsDetails(Box<admin::ConnectorCredentialsResponse>),
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -319,6 +323,10 @@ pub enum OutgoingWebhookContent {
     #[cfg(feature = "payouts")]
     #[schema(value_type = PayoutCreateResponse, title = "PayoutCreateResponse")]
     PayoutDetails(Box<payouts::PayoutCreateResponse>),
+    #[schema(value_type = MerchantAccountStatusResponse, title = "MerchantAccountStatusResponse")]
+    MerchantAccountDetails(Box<admin::MerchantAccountStatusResponse>),
+    #[schema(value_type = ConnectorCredentialsResponse, title = "ConnectorCredentialsResponse")]
+    ConnectorCredentialsDetails(Box<admin::ConnectorCredentialsResponse>),
 }
 
 #[derive(Debug, Clone, Serialize)]
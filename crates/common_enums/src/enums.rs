@@ -3,7 +3,7 @@ mod payments;
 mod ui;
 use std::num::{ParseFloatError, TryFromIntError};
 
-pub use accounts::MerchantProductType;
+pub use accounts::{MerchantAccountStatus, MerchantProductType};
 pub use payments::ProductType;
 use serde::{Deserialize, Serialize};
 pub use ui::*;
@@ -180,6 +180,74 @@ impl AttemptStatus {
             | Self::DeviceDataCollectionPending => false,
         }
     }
+
+    /// Indicates whether the attempt can be moved from its current status to the given status by
+    /// an asynchronous update from the connector, such as a webhook. An attempt only moves forward
+    /// in its lifecycle, and a terminal status is never regressed.
+    pub fn is_valid_transition_to(self, next: Self) -> bool {
+        if self == next {
+            return true;
+        }
+
+        match self {
+            Self::Started
+            | Self::AuthenticationPending
+            | Self::AuthenticationSuccessful
+            | Self::Authorizing
+            | Self::CodInitiated
+            | Self::Unresolved
+            | Self::Pending
+            | Self::PaymentMethodAwaited
+            | Self::ConfirmationAwaited
+            | Self::DeviceDataCollectionPending => next != Self::Started,
+            Self::Authorized => matches!(
+                next,
+                Self::CaptureInitiated
+                    | Self::Charged
+                    | Self::PartialCharged
+                    | Self::PartialChargedAndChargeable
+                    | Self::CaptureFailed
+                    | Self::VoidInitiated
+                    | Self::Voided
+                    | Self::VoidFailed
+                    | Self::AutoRefunded
+                    | Self::Failure
+            ),
+            Self::PartialChargedAndChargeable => matches!(
+                next,
+                Self::CaptureInitiated
+                    | Self::Charged
+                    | Self::PartialCharged
+                    | Self::CaptureFailed
+                    | Self::VoidInitiated
+                    | Self::Voided
+                    | Self::VoidFailed
+            ),
+            Self::CaptureInitiated => matches!(
+                next,
+                Self::Charged
+                    | Self::PartialCharged
+                    | Self::PartialChargedAndChargeable
+                    | Self::CaptureFailed
+            ),
+            Self::VoidInitiated => matches!(next, Self::Voided | Self::VoidFailed),
+            Self::AuthenticationFailed | Self::AuthorizationFailed => {
+                matches!(next, Self::Failure)
+            }
+            // The capture or the void of the attempt can be retried after it has failed
+            Self::CaptureFailed => matches!(
+                next,
+                Self::CaptureInitiated | Self::Charged | Self::PartialCharged
+            ),
+            Self::VoidFailed => matches!(next, Self::VoidInitiated | Self::Voided),
+            Self::RouterDeclined
+            | Self::Charged
+            | Self::AutoRefunded
+            | Self::Voided
+            | Self::Failure
+            | Self::PartialCharged => false,
+        }
+    }
 }
 
 /// Indicates the method by which a card is discovered during a payment
@@ -1375,6 +1443,8 @@ pub enum EventClass {
     Mandates,
     #[cfg(feature = "payouts")]
     Payouts,
+    MerchantAccount,
+    MerchantConnectorAccount,
 }
 
 #[derive(
@@ -1382,10 +1452,12 @@ pub enum EventClass {
     Copy,
     Debug,
     Eq,
+    Hash,
     PartialEq,
     serde::Deserialize,
     serde::Serialize,
     strum::Display,
+    strum::EnumIter,
     strum::EnumString,
     ToSchema,
 )]
@@ -1402,6 +1474,18 @@ pub enum EventType {
     PaymentAuthorized,
     PaymentCaptured,
     ActionRequired,
+    /// The payer abandoned the 3DS challenge of the payment without completing it
+    PaymentAuthenticationAbandoned,
+    /// The uncaptured remainder of the authorization of a partially captured payment was released
+    PaymentAuthorizationRemainderReleased,
+    /// A retry of the failed recurring payment was scheduled as per the dunning configuration of
+    /// the profile, so that the customer can be notified before the payment is retried
+    PaymentDunningRetryScheduled,
+    /// The failed recurring payment could not be recovered by its dunning and is not retried anymore
+    PaymentUncollectible,
+    /// The connector of the payment reached one of its daily volume caps with the payment, and is
+    /// excluded from routing for the rest of the day
+    ConnectorVolumeCapReached,
     RefundSucceeded,
     RefundFailed,
     DisputeOpened,
@@ -1420,6 +1504,14 @@ pub enum EventType {
     PayoutCancelled,
     PayoutExpired,
     PayoutReversed,
+    /// The lifecycle status of the merchant account was updated
+    MerchantAccountStatusUpdated,
+    /// The credentials of a merchant connector account expire within the reminder period and have
+    /// to be rotated
+    ConnectorCredentialsExpiring,
+    /// The credentials of a merchant connector account have expired, and the connector is excluded
+    /// from routing
+    ConnectorCredentialsExpired,
 }
 
 #[derive(
@@ -1961,6 +2053,19 @@ pub enum RefundStatus {
     TransactionFailure,
 }
 
+impl RefundStatus {
+    /// Indicates whether the refund can be moved from its current status to the given status by
+    /// an asynchronous update from the connector, such as a webhook
+    pub fn is_valid_transition_to(self, next: Self) -> bool {
+        match self {
+            Self::Pending | Self::ManualReview => true,
+            // Connectors can report the reversal of a refund after it has succeeded
+            Self::Success => matches!(next, Self::Success | Self::Failure),
+            Self::Failure | Self::TransactionFailure => self == next,
+        }
+    }
+}
+
 #[derive(
     Clone,
     Copy,
@@ -2154,6 +2259,90 @@ pub enum DisputeStatus {
     DisputeLost,
 }
 
+/// Type of an entry in the ledger of the funds of a merchant
+#[derive(
+    Clone,
+    Debug,
+    Copy,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum LedgerEntryType {
+    /// The disputed amount, held from the balance of the merchant while the dispute is open
+    DisputeHold,
+    /// The held amount, released back to the balance of the merchant once the dispute is won or
+    /// cancelled
+    DisputeRelease,
+    /// The held amount, charged to the merchant once the dispute is lost, accepted or expired
+    DisputeCharge,
+}
+
+/// Type of an entry in the store credit ledger of a customer
+#[derive(
+    Clone,
+    Debug,
+    Copy,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum StoreCreditEntryType {
+    /// The amount of a refund, credited to the balance of the customer
+    RefundCredit,
+    /// The store credit applied to a payment, debited from the balance of the customer when the
+    /// payment is confirmed
+    PaymentDebit,
+    /// The store credit applied to a payment, credited back to the balance of the customer when the
+    /// payment fails or is cancelled
+    PaymentDebitReversal,
+}
+
+/// The destination of the funds of a refund
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RefundDestination {
+    /// The payment method with which the payment was paid, through the connector of the payment
+    #[default]
+    OriginalPaymentMethod,
+    /// The store credit balance of the customer of the payment, which their future payments can
+    /// draw down
+    StoreCredit,
+}
+
 #[derive(
     Clone,
     Debug,
@@ -6451,6 +6640,7 @@ pub enum PayoutStatus {
     RequiresPayoutMethodData,
     RequiresFulfillment,
     RequiresVendorAccountCreation,
+    OnHold,
 }
 
 /// The payout_type of the payout request is a mandatory field for confirming the payouts. It should be specified in the Create request. If not provided, it must be updated in the Payout Update request before it can be confirmed.
@@ -7822,3 +8012,255 @@ pub enum CryptoPadding {
     PKCS7,
     ZeroPadding,
 }
+
+/// The flows which can be enabled or disabled for a merchant, or a profile of the merchant,
+/// without a redeploy
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum MerchantFeatureFlag {
+    /// Retry the failed payments with the rules of the gateway status map
+    SmartRetries,
+    /// Use the network tokens of the saved cards for the payments
+    NetworkTokenization,
+    /// Capture more than the amount capturable of the payments, within the tolerance of the card
+    /// network, with the connectors which support it
+    Overcapture,
+    /// Send the results of the checks of the connector, such as the AVS and CVV results, in the
+    /// payment responses and webhooks
+    ConnectorResponseDetails,
+    /// Validate and normalize the billing and shipping addresses of the customers and payments
+    /// which are created
+    AddressValidation,
+    /// Screen the names of the beneficiaries of the payouts and of the customers of the
+    /// high-value payments against the watchlists
+    SanctionsScreening,
+}
+
+impl MerchantFeatureFlag {
+    /// Whether the flow is enabled when the flag is neither set for the merchant, nor in the
+    /// configuration of the application
+    pub fn default_value(&self) -> bool {
+        match self {
+            Self::SmartRetries | Self::NetworkTokenization => true,
+            Self::Overcapture
+            | Self::ConnectorResponseDetails
+            | Self::AddressValidation
+            | Self::SanctionsScreening => false,
+        }
+    }
+}
+
+/// The version of the contract of the payment responses, to which an api key is pinned. The
+/// responses of the api keys which are not pinned follow the latest version.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    Ord,
+    PartialEq,
+    PartialOrd,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+pub enum ApiResponseVersion {
+    #[serde(rename = "2024-12-01")]
+    #[strum(serialize = "2024-12-01")]
+    V2024_12_01,
+    #[serde(rename = "2025-02-01")]
+    #[strum(serialize = "2025-02-01")]
+    V2025_02_01,
+    #[serde(rename = "2025-04-01")]
+    #[strum(serialize = "2025-04-01")]
+    V2025_04_01,
+    #[serde(rename = "2025-06-01")]
+    #[strum(serialize = "2025-06-01")]
+    V2025_06_01,
+}
+
+impl ApiResponseVersion {
+    /// The version followed by the responses of the api keys which are not pinned
+    pub const LATEST: Self = Self::V2025_06_01;
+}
+
+/// The rounding of the amounts derived by the application, such as the surcharges and the
+/// converted amounts, to the exponent of their currency
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RoundingMode {
+    /// Rounds half to the nearest even digit, also known as banker's rounding
+    HalfEven,
+    /// Rounds half away from zero
+    HalfUp,
+    /// Rounds towards negative infinity
+    Floor,
+    /// Rounds towards positive infinity
+    #[default]
+    Ceil,
+}
+
+/// The result of the address verification (AVS) of a card payment, normalized from the response
+/// codes of the connectors
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AvsResult {
+    /// Both the street address and the postal code matched
+    FullMatch,
+    /// The street address matched, but the postal code did not
+    AddressMatch,
+    /// The postal code matched, but the street address did not
+    PostalCodeMatch,
+    /// Neither the street address nor the postal code matched
+    NoMatch,
+    /// The address could not be verified by the issuer
+    Unavailable,
+}
+
+/// The result of the verification of the card verification value (CVV) of a card payment,
+/// normalized from the response codes of the connectors
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CvvResult {
+    /// The card verification value matched
+    Match,
+    /// The card verification value did not match
+    NoMatch,
+    /// The card verification value was not checked by the issuer
+    NotProcessed,
+    /// The card verification value could not be verified by the issuer
+    Unavailable,
+}
+
+/// The action taken on an authorized payment when a post authorization rule matches it
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PostAuthorizationAction {
+    /// Void the authorization of the payment
+    Void,
+}
+
+/// The credential of a saved card with which a payment is authorized
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CardCredentialType {
+    /// The network token of the card, along with a cryptogram fetched for the payment
+    #[default]
+    NetworkToken,
+    /// The card number held in the vault
+    Pan,
+}
+
+/// The type of the values of a custom field of the payments of a profile
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CustomFieldType {
+    /// A string, of at most the maximum length of the field
+    String,
+    /// A number, within the minimum and maximum values of the field
+    Number,
+    /// A string, among the values allowed for the field
+    Enum,
+}
@@ -25,3 +25,55 @@ pub enum MerchantProductType {
     CostObservability,
     DynamicRouting,
 }
+
+/// The lifecycle status of a merchant account
+#[derive(
+    Default,
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+    Hash,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum MerchantAccountStatus {
+    /// The merchant account can use all the operations
+    #[default]
+    Active,
+    /// The merchant account cannot create new charges or payouts, but can refund and manage its
+    /// existing payments
+    Restricted,
+    /// The merchant account cannot be accessed with its API keys
+    Suspended,
+    /// The merchant account is being offboarded, its pending payouts are drained and its data is
+    /// exported. This status is final.
+    Closing,
+}
+
+impl MerchantAccountStatus {
+    /// Whether new payments and payouts can be created by the merchant account
+    pub fn can_charge(self) -> bool {
+        matches!(self, Self::Active)
+    }
+
+    /// Whether the merchant account can be accessed with its API keys and publishable key
+    pub fn can_authenticate(self) -> bool {
+        !matches!(self, Self::Suspended)
+    }
+
+    /// Whether the merchant account can be moved from this status to the given status
+    pub fn can_transition_to(self, status: Self) -> bool {
+        match self {
+            Self::Closing => false,
+            Self::Active | Self::Restricted | Self::Suspended => self != status,
+        }
+    }
+}
@@ -4,6 +4,7 @@
 
 pub mod customers;
 pub mod domain;
+pub mod payment_method_display;
 pub mod payment_methods;
 pub mod payments;
 /// types that are wrappers around primitive types
@@ -0,0 +1,339 @@
+//! Registry of the display names and the icons of the payment methods and the payment method
+//! types, shared by the API and the WASM bindings so that the SDKs and the dashboard show the same
+//! labels. The names of the brands are not translated, while the generic names are translated into
+//! the languages of `PAYMENT_METHOD_DISPLAY_LANGUAGES`.
+
+use common_enums::{PaymentMethod, PaymentMethodType};
+
+/// The locale of the display names of the registry which are not translated
+pub const DEFAULT_PAYMENT_METHOD_DISPLAY_LOCALE: &str = "en";
+
+/// The languages into which the generic display names of the registry are translated
+pub const PAYMENT_METHOD_DISPLAY_LANGUAGES: [&str; 7] = ["en", "de", "es", "fr", "it", "nl", "pt"];
+
+/// The display name and the icon of a payment method or of a payment method type
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PaymentMethodDisplayInfo {
+    /// The name shown to the payers, in English
+    pub display_name: &'static str,
+    /// The identifier of the icon in the icon sets of the SDKs and the dashboard
+    pub icon: &'static str,
+}
+
+/// The generic names of the registry which are translated
+#[derive(Clone, Copy, Debug)]
+enum GenericName {
+    Card,
+    CreditCard,
+    DebitCard,
+    PayLater,
+    Wallet,
+    OnlineBanking,
+    BankTransfer,
+    BankDebit,
+    GiftCard,
+    Voucher,
+    Crypto,
+    MobilePayment,
+    CarrierBilling,
+}
+
+impl GenericName {
+    fn translate(self, language: &str) -> Option<&'static str> {
+        let translations = match self {
+            Self::Card => ["Karte", "Tarjeta", "Carte", "Carta", "Kaart", "Cartão"],
+            Self::CreditCard => [
+                "Kreditkarte",
+                "Tarjeta de crédito",
+                "Carte de crédit",
+                "Carta di credito",
+                "Creditcard",
+                "Cartão de crédito",
+            ],
+            Self::DebitCard => [
+                "Debitkarte",
+                "Tarjeta de débito",
+                "Carte de débit",
+                "Carta di debito",
+                "Debetkaart",
+                "Cartão de débito",
+            ],
+            Self::PayLater => [
+                "Später bezahlen",
+                "Paga después",
+                "Payer plus tard",
+                "Paga dopo",
+                "Later betalen",
+                "Pague depois",
+            ],
+            Self::Wallet => [
+                "Wallet",
+                "Monedero electrónico",
+                "Portefeuille électronique",
+                "Portafoglio digitale",
+                "Wallet",
+                "Carteira digital",
+            ],
+            Self::OnlineBanking => [
+                "Online-Banking",
+                "Banca en línea",
+                "Virement en ligne",
+                "Bonifico online",
+                "Online bankieren",
+                "Banco online",
+            ],
+            Self::BankTransfer => [
+                "Banküberweisung",
+                "Transferencia bancaria",
+                "Virement bancaire",
+                "Bonifico bancario",
+                "Bankoverschrijving",
+                "Transferência bancária",
+            ],
+            Self::BankDebit => [
+                "Lastschrift",
+                "Débito bancario",
+                "Prélèvement bancaire",
+                "Addebito bancario",
+                "Automatische incasso",
+                "Débito bancário",
+            ],
+            Self::GiftCard => [
+                "Geschenkkarte",
+                "Tarjeta regalo",
+                "Carte cadeau",
+                "Carta regalo",
+                "Cadeaukaart",
+                "Cartão-presente",
+            ],
+            Self::Voucher => ["Gutschein", "Cupón", "Bon", "Buono", "Voucher", "Voucher"],
+            Self::Crypto => [
+                "Kryptowährung",
+                "Criptomoneda",
+                "Cryptomonnaie",
+                "Criptovaluta",
+                "Cryptovaluta",
+                "Criptomoeda",
+            ],
+            Self::MobilePayment => [
+                "Mobile Zahlung",
+                "Pago móvil",
+                "Paiement mobile",
+                "Pagamento mobile",
+                "Mobiel betalen",
+                "Pagamento móvel",
+            ],
+            Self::CarrierBilling => [
+                "Zahlung über Mobilfunkrechnung",
+                "Pago con operador móvil",
+                "Paiement sur facture opérateur",
+                "Addebito su credito telefonico",
+                "Betalen via telefoonrekening",
+                "Pagamento na fatura da operadora",
+            ],
+        };
+        let [de, es, fr, it, nl, pt] = translations;
+        match language {
+            "de" => Some(de),
+            "es" => Some(es),
+            "fr" => Some(fr),
+            "it" => Some(it),
+            "nl" => Some(nl),
+            "pt" => Some(pt),
+            _ => None,
+        }
+    }
+}
+
+/// The language of a locale, such as `fr` for `fr-CA`
+fn get_language(locale: &str) -> String {
+    locale
+        .split(['-', '_'])
+        .next()
+        .unwrap_or(locale)
+        .to_lowercase()
+}
+
+/// The display name and the icon of the payment method
+pub fn get_payment_method_display_info(payment_method: PaymentMethod) -> PaymentMethodDisplayInfo {
+    let (display_name, icon) = match payment_method {
+        PaymentMethod::Card => ("Card", "card"),
+        PaymentMethod::CardRedirect => ("Card", "card"),
+        PaymentMethod::PayLater => ("Pay Later", "pay_later"),
+        PaymentMethod::Wallet => ("Wallet", "wallet"),
+        PaymentMethod::BankRedirect => ("Online Banking", "bank_redirect"),
+        PaymentMethod::BankTransfer => ("Bank Transfer", "bank_transfer"),
+        PaymentMethod::Crypto => ("Cryptocurrency", "crypto"),
+        PaymentMethod::BankDebit => ("Bank Debit", "bank_debit"),
+        PaymentMethod::Reward => ("Reward", "reward"),
+        PaymentMethod::RealTimePayment => ("Real Time Payment", "real_time_payment"),
+        PaymentMethod::Upi => ("UPI", "upi"),
+        PaymentMethod::Voucher => ("Voucher", "voucher"),
+        PaymentMethod::GiftCard => ("Gift Card", "gift_card"),
+        PaymentMethod::OpenBanking => ("Open Banking", "open_banking"),
+        PaymentMethod::MobilePayment => ("Mobile Payment", "mobile_payment"),
+    };
+    PaymentMethodDisplayInfo { display_name, icon }
+}
+
+/// The display name of the payment method translated into the language of the locale, if the
+/// name is translated into it
+pub fn get_localized_payment_method_display_name(
+    payment_method: PaymentMethod,
+    locale: &str,
+) -> Option<&'static str> {
+    let generic_name = match payment_method {
+        PaymentMethod::Card | PaymentMethod::CardRedirect => GenericName::Card,
+        PaymentMethod::PayLater => GenericName::PayLater,
+        PaymentMethod::Wallet => GenericName::Wallet,
+        PaymentMethod::BankRedirect => GenericName::OnlineBanking,
+        PaymentMethod::BankTransfer => GenericName::BankTransfer,
+        PaymentMethod::Crypto => GenericName::Crypto,
+        PaymentMethod::BankDebit => GenericName::BankDebit,
+        PaymentMethod::Voucher => GenericName::Voucher,
+        PaymentMethod::GiftCard => GenericName::GiftCard,
+        PaymentMethod::MobilePayment => GenericName::MobilePayment,
+        PaymentMethod::Reward
+        | PaymentMethod::RealTimePayment
+        | PaymentMethod::Upi
+        | PaymentMethod::OpenBanking => return None,
+    };
+    generic_name.translate(&get_language(locale))
+}
+
+/// The display name and the icon of the payment method type
+pub fn get_payment_method_type_display_info(
+    payment_method_type: PaymentMethodType,
+) -> PaymentMethodDisplayInfo {
+    let (display_name, icon) = match payment_method_type {
+        PaymentMethodType::Ach => ("ACH", "ach"),
+        PaymentMethodType::Affirm => ("Affirm", "affirm"),
+        PaymentMethodType::AfterpayClearpay => ("Afterpay", "afterpay_clearpay"),
+        PaymentMethodType::Alfamart => ("Alfamart", "alfamart"),
+        PaymentMethodType::AliPay => ("Alipay", "ali_pay"),
+        PaymentMethodType::AliPayHk => ("AlipayHK", "ali_pay_hk"),
+        PaymentMethodType::Alma => ("Alma", "alma"),
+        PaymentMethodType::AmazonPay => ("Amazon Pay", "amazon_pay"),
+        PaymentMethodType::ApplePay => ("Apple Pay", "apple_pay"),
+        PaymentMethodType::Atome => ("Atome", "atome"),
+        PaymentMethodType::Bacs => ("Bacs", "bacs"),
+        PaymentMethodType::BancontactCard => ("Bancontact", "bancontact_card"),
+        PaymentMethodType::Becs => ("BECS", "becs"),
+        PaymentMethodType::Benefit => ("BENEFIT", "benefit"),
+        PaymentMethodType::Bizum => ("Bizum", "bizum"),
+        PaymentMethodType::Blik => ("BLIK", "blik"),
+        PaymentMethodType::Boleto => ("Boleto", "boleto"),
+        PaymentMethodType::BcaBankTransfer => ("BCA Virtual Account", "bca_bank_transfer"),
+        PaymentMethodType::BniVa => ("BNI Virtual Account", "bni_va"),
+        PaymentMethodType::BriVa => ("BRI Virtual Account", "bri_va"),
+        PaymentMethodType::CardRedirect => ("Card", "card"),
+        PaymentMethodType::CimbVa => ("CIMB Virtual Account", "cimb_va"),
+        PaymentMethodType::ClassicReward => ("Classic Reward", "classic"),
+        PaymentMethodType::Credit => ("Credit Card", "credit"),
+        PaymentMethodType::CryptoCurrency => ("Cryptocurrency", "crypto_currency"),
+        PaymentMethodType::Cashapp => ("Cash App", "cashapp"),
+        PaymentMethodType::Dana => ("DANA", "dana"),
+        PaymentMethodType::DanamonVa => ("Danamon Virtual Account", "danamon_va"),
+        PaymentMethodType::Debit => ("Debit Card", "debit"),
+        PaymentMethodType::DuitNow => ("DuitNow", "duit_now"),
+        PaymentMethodType::Efecty => ("Efecty", "efecty"),
+        PaymentMethodType::Eft => ("EFT", "eft"),
+        PaymentMethodType::Eps => ("EPS", "eps"),
+        PaymentMethodType::Fps => ("FPS", "fps"),
+        PaymentMethodType::Evoucher => ("E-Voucher", "evoucher"),
+        PaymentMethodType::Giropay => ("giropay", "giropay"),
+        PaymentMethodType::Givex => ("Givex", "givex"),
+        PaymentMethodType::GooglePay => ("Google Pay", "google_pay"),
+        PaymentMethodType::GoPay => ("GoPay", "go_pay"),
+        PaymentMethodType::Gcash => ("GCash", "gcash"),
+        PaymentMethodType::Ideal => ("iDEAL", "ideal"),
+        PaymentMethodType::Interac => ("Interac", "interac"),
+        PaymentMethodType::Indomaret => ("Indomaret", "indomaret"),
+        PaymentMethodType::Klarna => ("Klarna", "klarna"),
+        PaymentMethodType::KakaoPay => ("Kakao Pay", "kakao_pay"),
+        PaymentMethodType::LocalBankRedirect => ("Online Banking", "local_bank_redirect"),
+        PaymentMethodType::MandiriVa => ("Mandiri Virtual Account", "mandiri_va"),
+        PaymentMethodType::Knet => ("KNET", "knet"),
+        PaymentMethodType::MbWay => ("MB WAY", "mb_way"),
+        PaymentMethodType::MobilePay => ("MobilePay", "mobile_pay"),
+        PaymentMethodType::Momo => ("MoMo", "momo"),
+        PaymentMethodType::MomoAtm => ("MoMo ATM", "momo_atm"),
+        PaymentMethodType::Multibanco => ("Multibanco", "multibanco"),
+        PaymentMethodType::OnlineBankingThailand => {
+            ("Thai Online Banking", "online_banking_thailand")
+        }
+        PaymentMethodType::OnlineBankingCzechRepublic => {
+            ("Czech Online Banking", "online_banking_czech_republic")
+        }
+        PaymentMethodType::OnlineBankingFinland => {
+            ("Finnish Online Banking", "online_banking_finland")
+        }
+        PaymentMethodType::OnlineBankingFpx => ("FPX", "online_banking_fpx"),
+        PaymentMethodType::OnlineBankingPoland => {
+            ("Polish Online Banking", "online_banking_poland")
+        }
+        PaymentMethodType::OnlineBankingSlovakia => {
+            ("Slovak Online Banking", "online_banking_slovakia")
+        }
+        PaymentMethodType::Oxxo => ("OXXO", "oxxo"),
+        PaymentMethodType::PagoEfectivo => ("PagoEfectivo", "pago_efectivo"),
+        PaymentMethodType::PermataBankTransfer => {
+            ("Permata Virtual Account", "permata_bank_transfer")
+        }
+        PaymentMethodType::OpenBankingUk => ("Pay by Bank", "open_banking_uk"),
+        PaymentMethodType::PayBright => ("PayBright", "pay_bright"),
+        PaymentMethodType::Paypal => ("PayPal", "paypal"),
+        PaymentMethodType::Paze => ("Paze", "paze"),
+        PaymentMethodType::Pix => ("Pix", "pix"),
+        PaymentMethodType::PaySafeCard => ("paysafecard", "pay_safe_card"),
+        PaymentMethodType::Przelewy24 => ("Przelewy24", "przelewy24"),
+        PaymentMethodType::PromptPay => ("PromptPay", "prompt_pay"),
+        PaymentMethodType::Pse => ("PSE", "pse"),
+        PaymentMethodType::RedCompra => ("RedCompra", "red_compra"),
+        PaymentMethodType::RedPagos => ("RedPagos", "red_pagos"),
+        PaymentMethodType::SamsungPay => ("Samsung Pay", "samsung_pay"),
+        PaymentMethodType::Sepa => ("SEPA", "sepa"),
+        PaymentMethodType::Sofort => ("Sofort", "sofort"),
+        PaymentMethodType::Swish => ("Swish", "swish"),
+        PaymentMethodType::TouchNGo => ("Touch 'n Go", "touch_n_go"),
+        PaymentMethodType::Trustly => ("Trustly", "trustly"),
+        PaymentMethodType::Twint => ("TWINT", "twint"),
+        PaymentMethodType::UpiCollect => ("UPI ID", "upi_collect"),
+        PaymentMethodType::UpiIntent => ("UPI Apps", "upi_intent"),
+        PaymentMethodType::Vipps => ("Vipps", "vipps"),
+        PaymentMethodType::VietQr => ("VietQR", "viet_qr"),
+        PaymentMethodType::Venmo => ("Venmo", "venmo"),
+        PaymentMethodType::Walley => ("Walley", "walley"),
+        PaymentMethodType::WeChatPay => ("WeChat Pay", "we_chat_pay"),
+        PaymentMethodType::SevenEleven => ("7-Eleven", "seven_eleven"),
+        PaymentMethodType::Lawson => ("Lawson", "lawson"),
+        PaymentMethodType::MiniStop => ("Ministop", "mini_stop"),
+        PaymentMethodType::FamilyMart => ("FamilyMart", "family_mart"),
+        PaymentMethodType::Seicomart => ("Seicomart", "seicomart"),
+        PaymentMethodType::PayEasy => ("Pay-easy", "pay_easy"),
+        PaymentMethodType::LocalBankTransfer => ("Bank Transfer", "local_bank_transfer"),
+        PaymentMethodType::Mifinity => ("MiFinity", "mifinity"),
+        PaymentMethodType::OpenBankingPIS => ("Open Banking", "open_banking_pis"),
+        PaymentMethodType::DirectCarrierBilling => ("Carrier Billing", "direct_carrier_billing"),
+    };
+    PaymentMethodDisplayInfo { display_name, icon }
+}
+
+/// The display name of the payment method type translated into the language of the locale, if the
+/// name is translated into it. The names of the brands are not translated.
+pub fn get_localized_payment_method_type_display_name(
+    payment_method_type: PaymentMethodType,
+    locale: &str,
+) -> Option<&'static str> {
+    let generic_name = match payment_method_type {
+        PaymentMethodType::CardRedirect => GenericName::Card,
+        PaymentMethodType::Credit => GenericName::CreditCard,
+        PaymentMethodType::Debit => GenericName::DebitCard,
+        PaymentMethodType::CryptoCurrency => GenericName::Crypto,
+        PaymentMethodType::LocalBankRedirect => GenericName::OnlineBanking,
+        PaymentMethodType::LocalBankTransfer => GenericName::BankTransfer,
+        PaymentMethodType::DirectCarrierBilling => GenericName::CarrierBilling,
+        _ => return None,
+    };
+    generic_name.translate(&get_language(locale))
+}
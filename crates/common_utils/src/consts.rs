@@ -91,6 +91,9 @@ pub const MIN_REQUIRED_MERCHANT_REFERENCE_ID_LENGTH: u8 = 1;
 /// Length of a cell identifier in a distributed system
 pub const CELL_IDENTIFIER_LENGTH: u8 = 5;
 
+/// Length of the identifier of a region, embedded in the ids generated by the region
+pub const REGION_IDENTIFIER_LENGTH: u8 = 4;
+
 /// General purpose base64 engine
 pub const BASE64_ENGINE: base64::engine::GeneralPurpose = base64::engine::general_purpose::STANDARD;
 
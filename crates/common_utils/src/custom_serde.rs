@@ -277,6 +277,34 @@ pub mod iso8601custom {
     }
 }
 
+/// Distinguish a field which is absent from one which is explicitly `null` when deserializing an
+/// `Option<Option<T>>`. An absent field is deserialized as `None` and requires the field to be
+/// annotated with `#[serde(default)]`, while a `null` field is deserialized as `Some(None)`.
+pub mod double_option {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serialize both an absent and a `null` field as `null`
+    pub fn serialize<T, S>(value: &Option<Option<T>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Serialize,
+        S: Serializer,
+    {
+        value
+            .as_ref()
+            .and_then(Option::as_ref)
+            .serialize(serializer)
+    }
+
+    /// Deserialize a field which is present, possibly as `null`, into `Some`
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        Option::<T>::deserialize(deserializer).map(Some)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use serde::{Deserialize, Serialize};
@@ -294,4 +322,21 @@ mod tests {
 
         assert!(deser.is_ok())
     }
+
+    #[test]
+    fn test_double_option_parse() {
+        #[derive(Serialize, Deserialize)]
+        struct Try {
+            #[serde(default, with = "crate::custom_serde::double_option")]
+            f: Option<Option<u8>>,
+        }
+
+        let absent = serde_json::from_value::<Try>(json!({})).map(|t| t.f);
+        let null = serde_json::from_value::<Try>(json!({"f": null})).map(|t| t.f);
+        let value = serde_json::from_value::<Try>(json!({"f": 1})).map(|t| t.f);
+
+        assert!(matches!(absent, Ok(None)));
+        assert!(matches!(null, Ok(Some(None))));
+        assert!(matches!(value, Ok(Some(Some(1)))));
+    }
 }
@@ -12,6 +12,7 @@ mod organization;
 mod payment;
 mod profile;
 mod refunds;
+mod region;
 mod relay;
 mod routing;
 mod tenant;
@@ -46,11 +47,12 @@ pub use self::{
     payment::{PaymentId, PaymentReferenceId},
     profile::ProfileId,
     refunds::RefundReferenceId,
+    region::{get_id_generation_region, set_id_generation_region, RegionId},
     relay::RelayId,
     routing::RoutingId,
     tenant::TenantId,
 };
-use crate::{fp_utils::when, generate_id_with_default_len};
+use crate::{fp_utils::when, generate_resource_id_with_default_len};
 
 #[inline]
 fn is_valid_id_character(input_char: char) -> bool {
@@ -107,7 +109,7 @@ impl AlphaNumericId {
 
     /// Generate a new alphanumeric id of default length
     pub(crate) fn new(prefix: &str) -> Self {
-        Self(generate_id_with_default_len(prefix))
+        Self(generate_resource_id_with_default_len(prefix))
     }
 }
 
@@ -8,8 +8,8 @@ use std::fmt::Display;
 use crate::{
     date_time,
     errors::{CustomResult, ValidationError},
-    generate_id_with_default_len,
-    id_type::{AlphaNumericId, LengthId},
+    generate_resource_id_with_default_len,
+    id_type::{AlphaNumericId, CustomerId, LengthId},
     new_type::MerchantName,
     types::keymanager,
 };
@@ -45,8 +45,9 @@ impl MerchantId {
 
         let merchant_id_prefix = merchant_name_string.trim().to_lowercase().replace(' ', "");
 
-        let alphanumeric_id =
-            AlphaNumericId::new_unchecked(generate_id_with_default_len(&merchant_id_prefix));
+        let alphanumeric_id = AlphaNumericId::new_unchecked(generate_resource_id_with_default_len(
+            &merchant_id_prefix,
+        ));
         let length_id = LengthId::new_unchecked(alphanumeric_id);
 
         Self(length_id)
@@ -219,4 +220,103 @@ impl MerchantId {
             ),
         }
     }
+
+    /// Get the key of the sandbox test clock of the merchant
+    pub fn get_test_clock_key(&self) -> String {
+        format!("test_clock_{}", self.get_string_repr())
+    }
+
+    /// Get the key of the feature flags set for the merchant
+    pub fn get_feature_flags_key(&self) -> String {
+        format!("merchant_feature_flags_{}", self.get_string_repr())
+    }
+
+    /// get_customer_payment_method_preferences_key
+    pub fn get_customer_payment_method_preferences_key(&self, customer_id: &CustomerId) -> String {
+        format!(
+            "customer_pm_preferences_{}_{}",
+            self.get_string_repr(),
+            customer_id.get_string_repr()
+        )
+    }
+
+    /// Get the key of the refund batch created by the merchant
+    pub fn get_refund_batch_key(&self, batch_id: &str) -> String {
+        format!("refund_batch_{}_{batch_id}", self.get_string_repr())
+    }
+
+    /// Get the key of a chunk of the rows of the refund batch created by the merchant
+    pub fn get_refund_batch_chunk_key(&self, batch_id: &str, chunk_number: usize) -> String {
+        format!(
+            "refund_batch_chunk_{}_{batch_id}_{chunk_number}",
+            self.get_string_repr()
+        )
+    }
+
+    /// Get the key of the SCA compliance report requested by the merchant
+    pub fn get_sca_compliance_report_key(&self, report_id: &str) -> String {
+        format!(
+            "sca_compliance_report_{}_{report_id}",
+            self.get_string_repr()
+        )
+    }
+
+    /// Get the key of the faults injected into the connector calls of the merchant
+    pub fn get_fault_injection_config_key(&self) -> String {
+        format!("fault_injection_config_{}", self.get_string_repr())
+    }
+
+    /// Get the key of the dunning of the failed recurring payment of the merchant
+    pub fn get_dunning_key(&self, payment_id: &str) -> String {
+        format!("dunning_{}_{payment_id}", self.get_string_repr())
+    }
+
+    /// Get the key of the offboarding of the merchant
+    pub fn get_offboarding_key(&self) -> String {
+        format!("merchant_offboarding_{}", self.get_string_repr())
+    }
+
+    /// Get the key of a sanctions screening case of the merchant
+    pub fn get_sanctions_screening_case_key(&self, case_id: &str) -> String {
+        format!(
+            "sanctions_screening_case_{}_{case_id}",
+            self.get_string_repr()
+        )
+    }
+
+    /// Get the key of the sanctions screening case opened for a payment or a payout of the merchant
+    pub fn get_sanctions_screening_object_key(&self, object_type: &str, object_id: &str) -> String {
+        format!(
+            "sanctions_screening_{object_type}_{}_{object_id}",
+            self.get_string_repr()
+        )
+    }
+
+    /// Get the key of the payload templates of the outgoing webhooks of the merchant
+    pub fn get_webhook_payload_templates_key(&self) -> String {
+        format!("webhook_payload_templates_{}", self.get_string_repr())
+    }
+
+    /// Get the key of the configuration of the data warehouse sync of the merchant
+    pub fn get_warehouse_sync_config_key(&self) -> String {
+        format!("warehouse_sync_config_{}", self.get_string_repr())
+    }
+
+    /// Get the key of the progress of the data warehouse sync of the merchant
+    pub fn get_warehouse_sync_state_key(&self) -> String {
+        format!("warehouse_sync_state_{}", self.get_string_repr())
+    }
+
+    /// Get the key of a backfill of the connector transaction IDs of the payments of the merchant
+    pub fn get_connector_transaction_id_backfill_key(&self, backfill_id: &str) -> String {
+        format!(
+            "connector_transaction_id_backfill_{}_{backfill_id}",
+            self.get_string_repr()
+        )
+    }
+
+    /// Get the key of the data residency of the merchant
+    pub fn get_data_residency_key(&self) -> String {
+        format!("data_residency_{}", self.get_string_repr())
+    }
 }
@@ -20,4 +20,19 @@ impl MerchantConnectorAccountId {
     pub fn wrap(merchant_connector_account_id: String) -> CustomResult<Self, ValidationError> {
         Self::try_from(std::borrow::Cow::from(merchant_connector_account_id))
     }
+
+    /// Get the key for storing the maintenance windows of the merchant connector account
+    pub fn get_maintenance_windows_config_key(&self) -> String {
+        format!("maintenance_windows_{}", self.get_string_repr())
+    }
+
+    /// Get the key for storing the metadata of the credentials of the merchant connector account
+    pub fn get_credentials_metadata_config_key(&self) -> String {
+        format!("credentials_metadata_{}", self.get_string_repr())
+    }
+
+    /// Get the key for storing the soft deletion of the merchant connector account
+    pub fn get_soft_deletion_config_key(&self) -> String {
+        format!("soft_deletion_{}", self.get_string_repr())
+    }
 }
@@ -23,6 +23,99 @@ impl crate::events::ApiEventMetric for ProfileId {
     }
 }
 
+/// All the keys that can be formed from profile id
+impl ProfileId {
+    /// get the key for the payment method display config of the profile
+    pub fn get_payment_method_display_config_key(&self) -> String {
+        format!("pm_display_config_{}", self.get_string_repr())
+    }
+
+    /// get the key for the external vault config of the profile
+    pub fn get_external_vault_config_key(&self) -> String {
+        format!("external_vault_config_{}", self.get_string_repr())
+    }
+
+    /// get the key for the cvv re-collection config of the profile
+    pub fn get_cvv_recollection_config_key(&self) -> String {
+        format!("cvv_recollection_config_{}", self.get_string_repr())
+    }
+
+    /// get the key for the feature flags set for the profile
+    pub fn get_feature_flags_key(&self) -> String {
+        format!("profile_feature_flags_{}", self.get_string_repr())
+    }
+
+    /// get the key for the payment facilitator details of the profile
+    pub fn get_payment_facilitator_details_key(&self) -> String {
+        format!("payment_facilitator_details_{}", self.get_string_repr())
+    }
+
+    /// get the key for the custom payment status mapping of the profile
+    pub fn get_custom_payment_status_mapping_key(&self) -> String {
+        format!("custom_payment_status_mapping_{}", self.get_string_repr())
+    }
+
+    /// get the key for the overrides of the unified error messages of the profile
+    pub fn get_unified_error_message_overrides_key(&self) -> String {
+        format!("unified_error_message_overrides_{}", self.get_string_repr())
+    }
+
+    /// get the key for the post authorization rules of the profile
+    pub fn get_post_authorization_rules_key(&self) -> String {
+        format!("post_authorization_rules_{}", self.get_string_repr())
+    }
+
+    /// get the key for the versions of the theme of the hosted checkout page of the profile
+    pub fn get_checkout_theme_key(&self) -> String {
+        format!("checkout_theme_{}", self.get_string_repr())
+    }
+
+    /// get the key for the dunning configuration of the profile
+    pub fn get_dunning_config_key(&self) -> String {
+        format!("dunning_config_{}", self.get_string_repr())
+    }
+
+    /// get the key for the routing experiments of the profile
+    pub fn get_routing_experiments_key(&self) -> String {
+        format!("routing_experiments_{}", self.get_string_repr())
+    }
+
+    /// get the key for the daily volume caps of the connectors of the profile
+    pub fn get_connector_volume_caps_key(&self) -> String {
+        format!("connector_volume_caps_{}", self.get_string_repr())
+    }
+
+    /// get the key for the preference between the network token and the card number of saved cards
+    pub fn get_network_token_preference_config_key(&self) -> String {
+        format!("network_token_preference_config_{}", self.get_string_repr())
+    }
+
+    /// get the key for the schema of the custom fields of the payments of the profile
+    pub fn get_custom_fields_schema_key(&self) -> String {
+        format!("custom_fields_schema_{}", self.get_string_repr())
+    }
+
+    /// get the key for the delay of the capture of the payments with automatic capture
+    pub fn get_auto_capture_delay_config_key(&self) -> String {
+        format!("auto_capture_delay_config_{}", self.get_string_repr())
+    }
+
+    /// get the key for the rules which force a 3DS challenge on the risky card payments
+    pub fn get_step_up_authentication_config_key(&self) -> String {
+        format!("step_up_authentication_config_{}", self.get_string_repr())
+    }
+
+    /// get the key for the pause of the delivery of the outgoing webhooks to the profile's endpoint
+    pub fn get_webhook_endpoint_pause_key(&self) -> String {
+        format!("webhook_endpoint_pause_{}", self.get_string_repr())
+    }
+
+    /// get the key for the overrides of the display names and icons of the payment methods
+    pub fn get_payment_method_display_names_key(&self) -> String {
+        format!("payment_method_display_names_{}", self.get_string_repr())
+    }
+}
+
 impl FromStr for ProfileId {
     type Err = error_stack::Report<crate::errors::ValidationError>;
 
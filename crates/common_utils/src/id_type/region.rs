@@ -0,0 +1,74 @@
+//! Region identifiers of the deployments of the application. In an active-active deployment across
+//! multiple regions, the tag of the region is prefixed to the resource ids generated by each
+//! region, so that the ids generated concurrently by different regions never collide.
+
+use error_stack::report;
+use once_cell::sync::OnceCell;
+
+use crate::{consts::REGION_IDENTIFIER_LENGTH, errors};
+
+static ID_GENERATION_REGION: OnceCell<RegionId> = OnceCell::new();
+
+/// Region identifier of a deployment of the application
+#[derive(Debug, Clone, Hash, PartialEq, Eq, serde::Serialize)]
+pub struct RegionId(String);
+
+impl RegionId {
+    /// Create a new region id from a string
+    pub fn from_string(
+        input_string: impl AsRef<str>,
+    ) -> error_stack::Result<Self, errors::ValidationError> {
+        let region_id = input_string.as_ref().trim();
+        let is_valid_length = region_id.len() == usize::from(REGION_IDENTIFIER_LENGTH);
+
+        // The tag is embedded in the random part of the ids, which is strictly alphanumeric
+        if !is_valid_length || !region_id.chars().all(|char| char.is_ascii_alphanumeric()) {
+            return Err(report!(errors::ValidationError::IncorrectValueProvided {
+                field_name: "region_id",
+            }));
+        }
+
+        Ok(Self(region_id.to_string()))
+    }
+
+    /// Get the string representation of the region id
+    pub fn get_string_repr(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for RegionId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let deserialized_string = String::deserialize(deserializer)?;
+        Self::from_string(deserialized_string).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Set the region whose tag is embedded in the ids generated by this process. The region can be
+/// set only once, and `false` is returned if a different region was already set.
+pub fn set_id_generation_region(region_id: RegionId) -> bool {
+    let current_region_id = ID_GENERATION_REGION.get_or_init(|| region_id.clone());
+    *current_region_id == region_id
+}
+
+/// Get the region whose tag is embedded in the ids generated by this process, if any
+pub fn get_id_generation_region() -> Option<&'static RegionId> {
+    ID_GENERATION_REGION.get()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_region_id_validation() {
+        assert!(RegionId::from_string("use1").is_ok());
+        assert!(RegionId::from_string(" euw1 ").is_ok());
+        assert!(RegionId::from_string("us").is_err());
+        assert!(RegionId::from_string("us_1").is_err());
+        assert!(RegionId::from_string("useast1").is_err());
+    }
+}
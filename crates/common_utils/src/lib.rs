@@ -205,6 +205,32 @@ pub mod date_time {
     }
 }
 
+/// The tag of the region set for id generation, if any
+fn get_region_tag() -> &'static str {
+    id_type::get_id_generation_region().map_or("", id_type::RegionId::get_string_repr)
+}
+
+/// Generate the id of a public resource with the given prefix and length of its random part. The
+/// tag of the region set for id generation, if any, is added ahead of the random part, so that the
+/// ids generated by different regions never collide. Secrets must never be generated with this,
+/// since the tag is predictable.
+#[inline]
+pub fn generate_resource_id(length: usize, prefix: &str) -> String {
+    format!(
+        "{}_{}{}",
+        prefix,
+        get_region_tag(),
+        nanoid::nanoid!(length, &consts::ALPHABETS)
+    )
+}
+
+/// Generate the id of a public resource with the given prefix and a default length of its random
+/// part, tagged with the region set for id generation, if any
+#[inline]
+pub fn generate_resource_id_with_default_len(prefix: &str) -> String {
+    generate_resource_id(consts::ID_LENGTH, prefix)
+}
+
 /// Generate a nanoid with the given prefix and length
 #[inline]
 pub fn generate_id(length: usize, prefix: &str) -> String {
@@ -332,4 +358,14 @@ mod nanoid_tests {
 
         assert!(ref_id.is_ok())
     }
+
+    #[test]
+    fn test_generate_resource_id_keeps_the_length_of_the_random_part() {
+        let resource_id = generate_resource_id(10, "def");
+        let (prefix, random_part) = resource_id.split_once('_').unwrap();
+
+        assert_eq!(prefix, "def");
+        assert_eq!(random_part.len(), get_region_tag().len() + 10);
+        assert!(random_part.starts_with(get_region_tag()));
+    }
 }
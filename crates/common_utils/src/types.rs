@@ -86,27 +86,41 @@ impl<const PRECISION: u8> Percentage<PRECISION> {
     }
 
     /// apply the percentage to amount and ceil the result
-    #[allow(clippy::as_conversions)]
     pub fn apply_and_ceil_result(
         &self,
         amount: MinorUnit,
+    ) -> CustomResult<MinorUnit, PercentageError> {
+        self.apply_and_round_result(amount, enums::RoundingMode::Ceil)
+    }
+
+    /// apply the percentage to amount and round the result to whole minor units as per the
+    /// rounding mode
+    pub fn apply_and_round_result(
+        &self,
+        amount: MinorUnit,
+        rounding_mode: enums::RoundingMode,
     ) -> CustomResult<MinorUnit, PercentageError> {
         let max_amount = i64::MAX / 10000;
         let amount = amount.0;
+        let unable_to_apply_percentage = || PercentageError::UnableToApplyPercentage {
+            percentage: self.percentage,
+            amount: MinorUnit::new(amount),
+        };
         if amount > max_amount {
             // value gets rounded off after i64::MAX/10000
-            Err(report!(PercentageError::UnableToApplyPercentage {
-                percentage: self.percentage,
-                amount: MinorUnit::new(amount),
-            }))
-            .attach_printable(format!(
+            Err(report!(unable_to_apply_percentage())).attach_printable(format!(
                 "Cannot calculate percentage for amount greater than {}",
                 max_amount
             ))
         } else {
-            let percentage_f64 = f64::from(self.percentage);
-            let result = (amount as f64 * (percentage_f64 / 100.0)).ceil() as i64;
-            Ok(MinorUnit::new(result))
+            let percentage = Decimal::from_f32(self.percentage)
+                .ok_or_else(|| report!(unable_to_apply_percentage()))
+                .attach_printable("Cannot convert percentage to decimal")?;
+            MinorUnit::from_decimal_minor_unit(
+                Decimal::from(amount) * percentage / Decimal::ONE_HUNDRED,
+                rounding_mode,
+            )
+            .change_context_lazy(unable_to_apply_percentage)
         }
     }
 
@@ -236,6 +250,76 @@ impl FromStr for SemanticVersion {
 
 crate::impl_to_sql_from_sql_json!(SemanticVersion);
 
+/// A range of IP addresses in the CIDR notation, such as `192.0.2.0/24` or `2001:db8::/32`. An
+/// address without a prefix length is a range of that address alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IpRange {
+    network: std::net::IpAddr,
+    prefix_length: u8,
+}
+
+impl IpRange {
+    /// Checks whether the address falls within the range. The IPv4 addresses mapped to IPv6
+    /// addresses are matched against the IPv4 ranges.
+    pub fn contains(&self, address: std::net::IpAddr) -> bool {
+        match (self.network, address.to_canonical()) {
+            (std::net::IpAddr::V4(network), std::net::IpAddr::V4(address)) => {
+                let mask = u32::MAX
+                    .checked_shl(u32::from(32 - self.prefix_length))
+                    .unwrap_or(0);
+                u32::from(network) & mask == u32::from(address) & mask
+            }
+            (std::net::IpAddr::V6(network), std::net::IpAddr::V6(address)) => {
+                let mask = u128::MAX
+                    .checked_shl(u32::from(128 - self.prefix_length))
+                    .unwrap_or(0);
+                u128::from(network) & mask == u128::from(address) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for IpRange {
+    type Err = error_stack::Report<ParsingError>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (network, prefix_length) = s
+            .split_once('/')
+            .map_or((s, None), |(network, prefix_length)| {
+                (network, Some(prefix_length))
+            });
+        let network = std::net::IpAddr::from_str(network.trim())
+            .change_context(ParsingError::StructParseFailure("IpRange"))?;
+        let max_prefix_length = if network.is_ipv4() { 32 } else { 128 };
+        let prefix_length = prefix_length
+            .map(|prefix_length| u8::from_str(prefix_length.trim()))
+            .transpose()
+            .change_context(ParsingError::StructParseFailure("IpRange"))?
+            .unwrap_or(max_prefix_length);
+
+        when(prefix_length > max_prefix_length, || {
+            Err(report!(ParsingError::StructParseFailure("IpRange")))
+                .attach_printable("Prefix length exceeds the length of the address")
+        })?;
+
+        Ok(Self {
+            network,
+            prefix_length,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for IpRange {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let ip_range = String::deserialize(deserializer)?;
+        Self::from_str(&ip_range).map_err(serde::de::Error::custom)
+    }
+}
+
 /// Amount convertor trait for connector
 pub trait AmountConvertor: Send {
     /// Output type for the connector
@@ -385,6 +469,15 @@ impl AmountConvertor for MinorUnitForConnector {
 #[diesel(sql_type = sql_types::BigInt)]
 pub struct MinorUnit(i64);
 
+fn get_rounding_strategy(rounding_mode: enums::RoundingMode) -> rust_decimal::RoundingStrategy {
+    match rounding_mode {
+        enums::RoundingMode::HalfEven => rust_decimal::RoundingStrategy::MidpointNearestEven,
+        enums::RoundingMode::HalfUp => rust_decimal::RoundingStrategy::MidpointAwayFromZero,
+        enums::RoundingMode::Floor => rust_decimal::RoundingStrategy::ToNegativeInfinity,
+        enums::RoundingMode::Ceil => rust_decimal::RoundingStrategy::ToPositiveInfinity,
+    }
+}
+
 impl MinorUnit {
     /// gets amount as i64 value will be removed in future
     pub fn get_amount_as_i64(self) -> i64 {
@@ -401,6 +494,55 @@ impl MinorUnit {
         Self(value)
     }
 
+    /// Rounds an amount in minor units, which may have a fractional part, to whole minor units as
+    /// per the rounding mode
+    pub fn from_decimal_minor_unit(
+        amount: Decimal,
+        rounding_mode: enums::RoundingMode,
+    ) -> Result<Self, error_stack::Report<ParsingError>> {
+        amount
+            .round_dp_with_strategy(0, get_rounding_strategy(rounding_mode))
+            .to_i64()
+            .map(Self)
+            .ok_or_else(|| report!(ParsingError::DecimalToI64ConversionFailure))
+    }
+
+    /// Applies a percentage to the amount, such as a share of a split payment, rounding the result
+    /// to whole minor units as per the rounding mode
+    pub fn apply_percentage(
+        self,
+        percentage: Decimal,
+        rounding_mode: enums::RoundingMode,
+    ) -> Result<Self, error_stack::Report<ParsingError>> {
+        let amount = Decimal::from(self.0)
+            .checked_mul(percentage)
+            .ok_or_else(|| report!(ParsingError::DecimalToI64ConversionFailure))?;
+        Self::from_decimal_minor_unit(amount / Decimal::ONE_HUNDRED, rounding_mode)
+    }
+
+    /// Converts an amount in the major denomination of the currency to minor units, rounding off
+    /// the digits beyond the exponent of the currency as per the rounding mode
+    pub fn from_major_unit_as_decimal(
+        amount: Decimal,
+        currency: enums::Currency,
+        rounding_mode: enums::RoundingMode,
+    ) -> Result<Self, error_stack::Report<ParsingError>> {
+        let exponent = u32::from(currency.number_of_digits_after_decimal_point());
+        let minor_unit_amount = amount
+            .checked_mul(Decimal::from(10_i64.pow(exponent)))
+            .ok_or_else(|| report!(ParsingError::DecimalToI64ConversionFailure))?;
+        Self::from_decimal_minor_unit(minor_unit_amount, rounding_mode)
+    }
+
+    /// Convert the amount to its major denomination based on the exponent of the currency, without
+    /// any loss of precision
+    pub fn to_major_unit_as_decimal(self, currency: enums::Currency) -> Decimal {
+        Decimal::new(
+            self.0,
+            u32::from(currency.number_of_digits_after_decimal_point()),
+        )
+    }
+
     /// Convert the amount to its major denomination based on Currency and return String
     /// Paypal Connector accepts Zero and Two decimal currency but not three decimal and it should be updated as required for 3 decimal currencies.
     /// Paypal Ref - https://developer.paypal.com/docs/reports/reference/paypal-supported-currencies/
@@ -1603,3 +1745,32 @@ where
         self.0.to_sql(out)
     }
 }
+
+#[cfg(test)]
+mod ip_range_tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_ip_range_contains() {
+        let ipv4_range = IpRange::from_str("192.0.2.0/24").unwrap();
+        assert!(ipv4_range.contains("192.0.2.17".parse().unwrap()));
+        assert!(ipv4_range.contains("::ffff:192.0.2.17".parse().unwrap()));
+        assert!(!ipv4_range.contains("192.0.3.17".parse().unwrap()));
+
+        let ipv6_range = IpRange::from_str("2001:db8::/32").unwrap();
+        assert!(ipv6_range.contains("2001:db8:1::1".parse().unwrap()));
+        assert!(!ipv6_range.contains("2001:db9::1".parse().unwrap()));
+
+        let address = IpRange::from_str("198.51.100.7").unwrap();
+        assert!(address.contains("198.51.100.7".parse().unwrap()));
+        assert!(!address.contains("198.51.100.8".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_invalid_ip_range() {
+        assert!(IpRange::from_str("192.0.2.0/33").is_err());
+        assert!(IpRange::from_str("192.0.2/24").is_err());
+        assert!(IpRange::from_str("2001:db8::/129").is_err());
+    }
+}
@@ -81,6 +81,92 @@ pub fn validate_domain_against_allowed_domains(
     })
 }
 
+/// Validates an IBAN by checking its structure and the mod-97 check digits as per ISO 13616
+pub fn validate_iban(iban: &str) -> Result<(), ValidationError> {
+    let iban = iban
+        .chars()
+        .filter(|character| !character.is_whitespace())
+        .map(|character| character.to_ascii_uppercase())
+        .collect::<String>();
+
+    let is_valid_structure = (15..=34).contains(&iban.len())
+        && iban
+            .chars()
+            .all(|character| character.is_ascii_alphanumeric())
+        && iban
+            .chars()
+            .take(2)
+            .all(|character| character.is_ascii_alphabetic())
+        && iban
+            .chars()
+            .skip(2)
+            .take(2)
+            .all(|character| character.is_ascii_digit());
+
+    let remainder = iban
+        .chars()
+        .skip(4)
+        .chain(iban.chars().take(4))
+        .filter_map(|character| character.to_digit(36))
+        .fold(0, |remainder, digit| {
+            if digit < 10 {
+                (remainder * 10 + digit) % 97
+            } else {
+                (remainder * 100 + digit) % 97
+            }
+        });
+
+    if is_valid_structure && remainder == 1 {
+        Ok(())
+    } else {
+        Err(ValidationError::InvalidValue {
+            message: "Invalid IBAN".to_string(),
+        })
+    }
+}
+
+/// Validates an ABA routing number by checking its length and checksum digit
+pub fn validate_aba_routing_number(routing_number: &str) -> Result<(), ValidationError> {
+    let digits = routing_number
+        .chars()
+        .map(|character| character.to_digit(10))
+        .collect::<Option<Vec<_>>>()
+        .filter(|digits| digits.len() == 9);
+
+    let is_valid = digits.is_some_and(|digits| {
+        digits
+            .iter()
+            .zip([3, 7, 1].iter().cycle())
+            .map(|(digit, weight)| digit * weight)
+            .sum::<u32>()
+            % 10
+            == 0
+    });
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(ValidationError::InvalidValue {
+            message: "Invalid routing number".to_string(),
+        })
+    }
+}
+
+/// Validates that a bank account number consists of 4 to 17 digits, as required for ACH
+pub fn validate_ach_account_number(account_number: &str) -> Result<(), ValidationError> {
+    if (4..=17).contains(&account_number.len())
+        && account_number
+            .chars()
+            .all(|character| character.is_ascii_digit())
+    {
+        Ok(())
+    } else {
+        Err(ValidationError::InvalidValue {
+            message: "Invalid bank account number".to_string(),
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use fake::{faker::internet::en::SafeEmail, Fake};
@@ -131,6 +217,32 @@ mod tests {
         assert!(res.is_err());
     }
 
+    #[test_case("DE89370400440532013000" ; "German IBAN")]
+    #[test_case("GB82 WEST 1234 5698 7654 32" ; "British IBAN with spaces")]
+    #[test_case("fr1420041010050500013m02606" ; "French IBAN in lowercase")]
+    fn test_valid_iban(iban: &str) {
+        assert!(validate_iban(iban).is_ok());
+    }
+
+    #[test_case("DE89370400440532013001" ; "IBAN with invalid check digits")]
+    #[test_case("DE8937040044" ; "IBAN that is too short")]
+    #[test_case("1289370400440532013000" ; "IBAN without country code")]
+    fn test_invalid_iban(iban: &str) {
+        assert!(validate_iban(iban).is_err());
+    }
+
+    #[test_case("110000000", true ; "valid routing number")]
+    #[test_case("021000021", true ; "another valid routing number")]
+    #[test_case("110000001", false ; "routing number with invalid checksum")]
+    #[test_case("11000000", false ; "routing number that is too short")]
+    #[test_case("11000000a", false ; "routing number with letters")]
+    fn test_validate_aba_routing_number(routing_number: &str, is_valid: bool) {
+        assert_eq!(
+            validate_aba_routing_number(routing_number).is_ok(),
+            is_valid
+        );
+    }
+
     proptest::proptest! {
         /// Example of unit test
         #[test]
@@ -1,5 +1,10 @@
 #![allow(clippy::panic_in_result_fn)]
-use common_utils::{errors::PercentageError, types::Percentage};
+use common_enums::RoundingMode;
+use common_utils::{
+    errors::PercentageError,
+    types::{MinorUnit, Percentage},
+};
+use rust_decimal::Decimal;
 const PRECISION_2: u8 = 2;
 const PRECISION_0: u8 = 0;
 
@@ -198,3 +203,67 @@ fn deserialization_test_err() -> Result<(), Box<dyn std::error::Error + Send + S
     }
     Ok(())
 }
+
+#[test]
+fn apply_and_round_result_test() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let percentage = Percentage::<PRECISION_2>::from_string("2.5".to_string())?;
+    // 2.5% of 1010 is 25.25
+    let amount = MinorUnit::new(1010);
+    assert_eq!(
+        percentage.apply_and_round_result(amount, RoundingMode::Ceil)?,
+        MinorUnit::new(26)
+    );
+    assert_eq!(
+        percentage.apply_and_round_result(amount, RoundingMode::Floor)?,
+        MinorUnit::new(25)
+    );
+
+    // 2.5% of 1020 is 25.5
+    let amount = MinorUnit::new(1020);
+    assert_eq!(
+        percentage.apply_and_round_result(amount, RoundingMode::HalfUp)?,
+        MinorUnit::new(26)
+    );
+    assert_eq!(
+        percentage.apply_and_round_result(amount, RoundingMode::HalfEven)?,
+        MinorUnit::new(26)
+    );
+
+    // 2.5% of 1060 is 26.5
+    let amount = MinorUnit::new(1060);
+    assert_eq!(
+        percentage.apply_and_round_result(amount, RoundingMode::HalfUp)?,
+        MinorUnit::new(27)
+    );
+    assert_eq!(
+        percentage.apply_and_round_result(amount, RoundingMode::HalfEven)?,
+        MinorUnit::new(26)
+    );
+
+    // 7% of 1000 is exactly 70, which is not ceiled to 71
+    let percentage = Percentage::<PRECISION_2>::from_string("7".to_string())?;
+    assert_eq!(
+        percentage.apply_and_ceil_result(MinorUnit::new(1000))?,
+        MinorUnit::new(70)
+    );
+    Ok(())
+}
+
+#[test]
+fn apply_percentage_to_minor_unit_test() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    // 15% of 1003 is 150.45
+    let amount = MinorUnit::new(1003);
+    assert_eq!(
+        amount.apply_percentage(Decimal::from(15), RoundingMode::Ceil)?,
+        MinorUnit::new(151)
+    );
+    assert_eq!(
+        amount.apply_percentage(Decimal::from(15), RoundingMode::Floor)?,
+        MinorUnit::new(150)
+    );
+    assert_eq!(
+        amount.apply_percentage(Decimal::from(15), RoundingMode::HalfUp)?,
+        MinorUnit::new(150)
+    );
+    Ok(())
+}
@@ -27,9 +27,10 @@ pub struct AddressNew {
     pub modified_at: PrimitiveDateTime,
     pub updated_by: String,
     pub email: Option<Encryption>,
+    pub normalized_address: Option<Encryption>,
 }
 
-#[derive(Clone, Debug, Queryable, Identifiable, Selectable, Serialize, Deserialize)]
+#[derive(Clone, Debug, Queryable, Identifiable, Insertable, Selectable, Serialize, Deserialize)]
 #[diesel(table_name = address, primary_key(address_id), check_for_backend(diesel::pg::Pg))]
 pub struct Address {
     pub address_id: String,
@@ -51,6 +52,7 @@ pub struct Address {
     pub payment_id: Option<common_utils::id_type::PaymentId>,
     pub updated_by: String,
     pub email: Option<Encryption>,
+    pub normalized_address: Option<Encryption>,
 }
 
 #[derive(Clone)]
@@ -84,6 +86,7 @@ pub struct AddressUpdateInternal {
     pub modified_at: PrimitiveDateTime,
     pub updated_by: String,
     pub email: Option<Encryption>,
+    pub normalized_address: Option<Encryption>,
 }
 
 impl AddressUpdateInternal {
@@ -102,6 +105,7 @@ impl AddressUpdateInternal {
             country_code: self.country_code,
             modified_at: self.modified_at,
             updated_by: self.updated_by,
+            normalized_address: self.normalized_address.or(source.normalized_address),
             ..source
         }
     }
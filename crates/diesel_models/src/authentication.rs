@@ -49,6 +49,8 @@ pub struct Authentication {
     pub acquirer_country_code: Option<String>,
     pub service_details: Option<serde_json::Value>,
     pub organization_id: common_utils::id_type::OrganizationId,
+    /// Whether the payer abandoned the challenge of the authentication without completing it
+    pub challenge_abandoned: Option<bool>,
 }
 
 impl Authentication {
@@ -160,6 +162,9 @@ pub enum AuthenticationUpdate {
         trans_status: common_enums::TransactionStatus,
         authentication_status: common_enums::AuthenticationStatus,
     },
+    ChallengeAbandonmentUpdate {
+        challenge_abandoned: bool,
+    },
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, AsChangeset, Serialize, Deserialize)]
@@ -195,6 +200,7 @@ pub struct AuthenticationUpdateInternal {
     pub directory_server_id: Option<String>,
     pub acquirer_country_code: Option<String>,
     pub service_details: Option<serde_json::Value>,
+    pub challenge_abandoned: Option<bool>,
 }
 
 impl Default for AuthenticationUpdateInternal {
@@ -229,6 +235,7 @@ impl Default for AuthenticationUpdateInternal {
             directory_server_id: Default::default(),
             acquirer_country_code: Default::default(),
             service_details: Default::default(),
+            challenge_abandoned: Default::default(),
         }
     }
 }
@@ -265,6 +272,7 @@ impl AuthenticationUpdateInternal {
             directory_server_id,
             acquirer_country_code,
             service_details,
+            challenge_abandoned,
         } = self;
         Authentication {
             connector_authentication_id: connector_authentication_id
@@ -300,6 +308,7 @@ impl AuthenticationUpdateInternal {
             directory_server_id: directory_server_id.or(source.directory_server_id),
             acquirer_country_code: acquirer_country_code.or(source.acquirer_country_code),
             service_details: service_details.or(source.service_details),
+            challenge_abandoned: challenge_abandoned.or(source.challenge_abandoned),
             ..source
         }
     }
@@ -438,6 +447,12 @@ impl From<AuthenticationUpdate> for AuthenticationUpdateInternal {
                 authentication_status: Some(authentication_status),
                 ..Default::default()
             },
+            AuthenticationUpdate::ChallengeAbandonmentUpdate {
+                challenge_abandoned,
+            } => Self {
+                challenge_abandoned: Some(challenge_abandoned),
+                ..Default::default()
+            },
         }
     }
 }
@@ -66,6 +66,7 @@ pub struct Profile {
     pub card_testing_secret_key: Option<Encryption>,
     pub is_clear_pan_retries_enabled: bool,
     pub force_3ds_challenge: Option<bool>,
+    pub is_test_mode: Option<bool>,
 }
 
 #[cfg(feature = "v1")]
@@ -116,6 +117,7 @@ pub struct ProfileNew {
     pub card_testing_secret_key: Option<Encryption>,
     pub is_clear_pan_retries_enabled: bool,
     pub force_3ds_challenge: Option<bool>,
+    pub is_test_mode: Option<bool>,
 }
 
 #[cfg(feature = "v1")]
@@ -164,6 +166,7 @@ pub struct ProfileUpdateInternal {
     pub card_testing_secret_key: Option<Encryption>,
     pub is_clear_pan_retries_enabled: Option<bool>,
     pub force_3ds_challenge: Option<bool>,
+    pub is_test_mode: Option<bool>,
 }
 
 #[cfg(feature = "v1")]
@@ -210,6 +213,7 @@ impl ProfileUpdateInternal {
             card_testing_secret_key,
             is_clear_pan_retries_enabled,
             force_3ds_challenge,
+            is_test_mode,
         } = self;
         Profile {
             profile_id: source.profile_id,
@@ -281,6 +285,7 @@ impl ProfileUpdateInternal {
             is_clear_pan_retries_enabled: is_clear_pan_retries_enabled
                 .unwrap_or(source.is_clear_pan_retries_enabled),
             force_3ds_challenge,
+            is_test_mode: is_test_mode.or(source.is_test_mode),
         }
     }
 }
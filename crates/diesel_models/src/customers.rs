@@ -123,7 +123,14 @@ impl From<CustomerNew> for Customer {
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 #[derive(
-    Clone, Debug, Identifiable, Queryable, Selectable, serde::Deserialize, serde::Serialize,
+    Clone,
+    Debug,
+    Identifiable,
+    Insertable,
+    Queryable,
+    Selectable,
+    serde::Deserialize,
+    serde::Serialize,
 )]
 #[diesel(table_name = customers, primary_key(customer_id, merchant_id), check_for_backend(diesel::pg::Pg))]
 pub struct Customer {
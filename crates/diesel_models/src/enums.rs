@@ -76,6 +76,8 @@ pub enum EventObjectType {
     DisputeDetails,
     MandateDetails,
     PayoutDetails,
+    MerchantAccountDetails,
+    ConnectorCredentialsDetails,
 }
 
 #[derive(
@@ -81,6 +81,12 @@ pub enum EventMetadata {
         payment_method_id: String,
         mandate_id: String,
     },
+    MerchantAccount {
+        merchant_id: common_utils::id_type::MerchantId,
+    },
+    MerchantConnectorAccount {
+        merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    },
 }
 
 common_utils::impl_to_sql_from_sql_json!(EventMetadata);
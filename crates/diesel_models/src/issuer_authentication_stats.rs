@@ -0,0 +1,25 @@
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+
+use crate::schema::issuer_authentication_stats;
+
+#[derive(Clone, Debug, Eq, Insertable, PartialEq)]
+#[diesel(table_name = issuer_authentication_stats)]
+pub struct IssuerAuthenticationStatsNew {
+    pub card_bin: String,
+    pub card_issuer: Option<String>,
+    pub frictionless_count: i64,
+    pub challenge_count: i64,
+    pub created_at: time::PrimitiveDateTime,
+    pub last_modified_at: time::PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = issuer_authentication_stats, primary_key(card_bin), check_for_backend(diesel::pg::Pg))]
+pub struct IssuerAuthenticationStats {
+    pub card_bin: String,
+    pub card_issuer: Option<String>,
+    pub frictionless_count: i64,
+    pub challenge_count: i64,
+    pub created_at: time::PrimitiveDateTime,
+    pub last_modified_at: time::PrimitiveDateTime,
+}
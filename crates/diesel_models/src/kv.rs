@@ -1,25 +1,25 @@
 use error_stack::ResultExt;
 use serde::{Deserialize, Serialize};
 
-#[cfg(feature = "v2")]
-use crate::payment_attempt::PaymentAttemptUpdateInternal;
-#[cfg(feature = "v1")]
-use crate::payment_intent::PaymentIntentUpdate;
-#[cfg(feature = "v2")]
-use crate::payment_intent::PaymentIntentUpdateInternal;
 use crate::{
     address::{Address, AddressNew, AddressUpdateInternal},
     customers::{Customer, CustomerNew, CustomerUpdateInternal},
     errors,
-    payment_attempt::{PaymentAttempt, PaymentAttemptNew, PaymentAttemptUpdate},
-    payment_intent::PaymentIntentNew,
-    payout_attempt::{PayoutAttempt, PayoutAttemptNew, PayoutAttemptUpdate},
-    payouts::{Payouts, PayoutsNew, PayoutsUpdate},
+    payment_attempt::{
+        PaymentAttempt, PaymentAttemptNew, PaymentAttemptUpdate, PaymentAttemptUpdateInternal,
+    },
+    payment_intent::{PaymentIntentNew, PaymentIntentUpdateInternal},
+    payout_attempt::{
+        PayoutAttempt, PayoutAttemptNew, PayoutAttemptUpdate, PayoutAttemptUpdateInternal,
+    },
+    payouts::{Payouts, PayoutsNew, PayoutsUpdate, PayoutsUpdateInternal},
     refund::{Refund, RefundNew, RefundUpdate},
     reverse_lookup::{ReverseLookup, ReverseLookupNew},
     Mandate, MandateNew, MandateUpdateInternal, PaymentIntent, PaymentMethod, PaymentMethodNew,
     PaymentMethodUpdateInternal, PgPooledConn,
 };
+#[cfg(feature = "v1")]
+use PaymentIntentUpdate;
 
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case", tag = "db_op", content = "data")]
@@ -62,6 +62,46 @@ impl DBOperation {
             },
         }
     }
+
+    /// Returns the lane of the drainer stream through which the operation is drained. The inserts
+    /// and the updates which change the status of a payment, refund or payout are drained with
+    /// high priority, so that the statuses in the database do not lag behind while the other
+    /// updates, such as those of the metadata or of the last synced time, pile up in the low
+    /// priority lane. Inserts are always drained with high priority, so that a row is not updated
+    /// through the low priority lane before it is inserted.
+    pub fn lane_priority(&self) -> DrainerLanePriority {
+        match self {
+            Self::Insert { .. } => DrainerLanePriority::High,
+            Self::Update { updatable } if updatable.is_status_update() => DrainerLanePriority::High,
+            Self::Update { .. } => DrainerLanePriority::Low,
+        }
+    }
+}
+
+/// Priority of a lane of the drainer stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrainerLanePriority {
+    High,
+    Low,
+}
+
+impl DrainerLanePriority {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::High => "high",
+            Self::Low => "low",
+        }
+    }
+
+    /// Returns the name of the lane of the drainer stream, given the name of the stream. The high
+    /// priority lane is the stream itself, so that the entries pushed before the lanes were
+    /// enabled are drained as before.
+    pub fn get_lane_stream_name(&self, stream_name: &str) -> String {
+        match self {
+            Self::High => stream_name.to_string(),
+            Self::Low => format!("{stream_name}_low_priority"),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -84,6 +124,12 @@ pub struct TypedSql {
     pub op: DBOperation,
 }
 
+impl TypedSql {
+    pub fn lane_priority(&self) -> DrainerLanePriority {
+        self.op.lane_priority()
+    }
+}
+
 impl DBOperation {
     pub async fn execute(self, conn: &PgPooledConn) -> crate::StorageResult<DBResult> {
         Ok(match self {
@@ -241,6 +287,40 @@ pub enum Updateable {
     MandateUpdate(MandateUpdateMems),
 }
 
+impl Updateable {
+    /// Returns whether the update changes the status of a payment, refund or payout
+    pub fn is_status_update(&self) -> bool {
+        match self {
+            #[cfg(feature = "v1")]
+            Self::PaymentIntentUpdate(a) => {
+                PaymentIntentUpdateInternal::from(a.update_data.clone())
+                    .status
+                    .is_some()
+            }
+            #[cfg(feature = "v2")]
+            Self::PaymentIntentUpdate(a) => a.update_data.status.is_some(),
+            Self::PaymentAttemptUpdate(a) => {
+                PaymentAttemptUpdateInternal::from(a.update_data.clone())
+                    .status
+                    .is_some()
+            }
+            Self::RefundUpdate(a) => a.update_data.is_status_update(),
+            Self::PayoutsUpdate(a) => PayoutsUpdateInternal::from(a.update_data.clone())
+                .status
+                .is_some(),
+            Self::PayoutAttemptUpdate(a) => {
+                PayoutAttemptUpdateInternal::from(a.update_data.clone())
+                    .status
+                    .is_some()
+            }
+            Self::CustomerUpdate(_)
+            | Self::AddressUpdate(_)
+            | Self::PaymentMethodUpdate(_)
+            | Self::MandateUpdate(_) => false,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CustomerUpdateMems {
     pub orig: Customer,
@@ -301,3 +381,96 @@ pub struct MandateUpdateMems {
     pub orig: Mandate,
     pub update_data: MandateUpdateInternal,
 }
+
+/// Redis list holding the entries drained from the KV store, which are to be compared with their
+/// rows in Postgres by the consistency checker of the drainer
+pub const DRAINED_ENTRY_SAMPLES_KEY: &str = "drainer_consistency_samples";
+
+/// Redis list holding the divergences between the KV store and Postgres found by the consistency
+/// checker of the drainer
+pub const KV_PG_DIVERGENCES_KEY: &str = "drainer_kv_pg_divergences";
+
+/// An entry drained from the KV store, identified by its partition key and field in Redis
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "table")]
+pub enum DrainedEntry {
+    PaymentAttempt {
+        merchant_id: common_utils::id_type::MerchantId,
+        payment_id: common_utils::id_type::PaymentId,
+        attempt_id: String,
+    },
+    Refund {
+        merchant_id: common_utils::id_type::MerchantId,
+        payment_id: common_utils::id_type::PaymentId,
+        attempt_id: String,
+        refund_id: String,
+    },
+}
+
+impl DrainedEntry {
+    pub fn table(&self) -> &'static str {
+        match self {
+            Self::PaymentAttempt { .. } => "payment_attempt",
+            Self::Refund { .. } => "refund",
+        }
+    }
+
+    pub fn merchant_id(&self) -> &common_utils::id_type::MerchantId {
+        match self {
+            Self::PaymentAttempt { merchant_id, .. } | Self::Refund { merchant_id, .. } => {
+                merchant_id
+            }
+        }
+    }
+
+    pub fn object_id(&self) -> &str {
+        match self {
+            Self::PaymentAttempt { attempt_id, .. } => attempt_id,
+            Self::Refund { refund_id, .. } => refund_id,
+        }
+    }
+
+    /// Returns the partition key and the field under which the entry is stored in the KV store
+    pub fn get_redis_key_and_field(&self) -> (String, String) {
+        match self {
+            Self::PaymentAttempt {
+                merchant_id,
+                payment_id,
+                attempt_id,
+            } => (
+                format!(
+                    "mid_{}_pid_{}",
+                    merchant_id.get_string_repr(),
+                    payment_id.get_string_repr()
+                ),
+                format!("pa_{attempt_id}"),
+            ),
+            Self::Refund {
+                merchant_id,
+                payment_id,
+                attempt_id,
+                refund_id,
+            } => (
+                format!(
+                    "mid_{}_pid_{}",
+                    merchant_id.get_string_repr(),
+                    payment_id.get_string_repr()
+                ),
+                format!("pa_{attempt_id}_ref_{refund_id}"),
+            ),
+        }
+    }
+}
+
+/// A field of a drained entry whose value in the KV store differs from its value in Postgres
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KvPgDivergence {
+    pub table: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub object_id: String,
+    pub field: String,
+    pub kv_value: String,
+    pub pg_value: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub detected_at: time::PrimitiveDateTime,
+}
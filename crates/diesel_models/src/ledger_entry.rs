@@ -0,0 +1,39 @@
+use common_utils::{custom_serde, id_type, types::MinorUnit};
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::Serialize;
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::ledger_entry};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = ledger_entry)]
+pub struct LedgerEntryNew {
+    pub entry_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub entry_type: storage_enums::LedgerEntryType,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub payment_id: id_type::PaymentId,
+    pub dispute_id: String,
+    pub dispute_stage: storage_enums::DisputeStage,
+    pub connector: String,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = ledger_entry, primary_key(entry_id), check_for_backend(diesel::pg::Pg))]
+pub struct LedgerEntry {
+    pub entry_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub entry_type: storage_enums::LedgerEntryType,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub payment_id: id_type::PaymentId,
+    pub dispute_id: String,
+    pub dispute_stage: storage_enums::DisputeStage,
+    pub connector: String,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
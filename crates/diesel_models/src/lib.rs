@@ -23,8 +23,10 @@ pub mod file;
 pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
+pub mod issuer_authentication_stats;
 #[cfg(feature = "kv_store")]
 pub mod kv;
+pub mod ledger_entry;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -32,9 +34,12 @@ pub mod merchant_connector_account;
 pub mod merchant_key_store;
 pub mod organization;
 pub mod payment_attempt;
+pub mod payment_custom_field;
 pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_method_consent_event;
+pub mod payment_note;
 pub mod payout_attempt;
 pub mod payouts;
 pub mod process_tracker;
@@ -44,6 +49,7 @@ pub mod relay;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod store_credit_entry;
 pub mod types;
 pub mod unified_translations;
 
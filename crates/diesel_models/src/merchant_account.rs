@@ -54,6 +54,7 @@ pub struct MerchantAccount {
     pub is_platform_account: bool,
     pub id: Option<common_utils::id_type::MerchantId>,
     pub product_type: Option<common_enums::MerchantProductType>,
+    pub status: common_enums::MerchantAccountStatus,
 }
 
 #[cfg(feature = "v1")]
@@ -88,6 +89,7 @@ pub struct MerchantAccountSetter {
     pub version: common_enums::ApiVersion,
     pub is_platform_account: bool,
     pub product_type: Option<common_enums::MerchantProductType>,
+    pub status: common_enums::MerchantAccountStatus,
 }
 
 #[cfg(feature = "v1")]
@@ -125,6 +127,7 @@ impl From<MerchantAccountSetter> for MerchantAccount {
             version: item.version,
             is_platform_account: item.is_platform_account,
             product_type: item.product_type,
+            status: item.status,
         }
     }
 }
@@ -158,6 +161,7 @@ pub struct MerchantAccount {
     pub is_platform_account: bool,
     pub id: common_utils::id_type::MerchantId,
     pub product_type: Option<common_enums::MerchantProductType>,
+    pub status: common_enums::MerchantAccountStatus,
 }
 
 #[cfg(feature = "v2")]
@@ -177,6 +181,7 @@ impl From<MerchantAccountSetter> for MerchantAccount {
             version: item.version,
             is_platform_account: item.is_platform_account,
             product_type: item.product_type,
+            status: item.status,
         }
     }
 }
@@ -196,6 +201,7 @@ pub struct MerchantAccountSetter {
     pub version: common_enums::ApiVersion,
     pub is_platform_account: bool,
     pub product_type: Option<common_enums::MerchantProductType>,
+    pub status: common_enums::MerchantAccountStatus,
 }
 
 impl MerchantAccount {
@@ -245,6 +251,7 @@ pub struct MerchantAccountNew {
     pub is_platform_account: bool,
     pub id: Option<common_utils::id_type::MerchantId>,
     pub product_type: Option<common_enums::MerchantProductType>,
+    pub status: common_enums::MerchantAccountStatus,
 }
 
 #[cfg(feature = "v2")]
@@ -263,6 +270,7 @@ pub struct MerchantAccountNew {
     pub version: common_enums::ApiVersion,
     pub is_platform_account: bool,
     pub product_type: Option<common_enums::MerchantProductType>,
+    pub status: common_enums::MerchantAccountStatus,
 }
 
 #[cfg(feature = "v2")]
@@ -279,6 +287,7 @@ pub struct MerchantAccountUpdateInternal {
     pub recon_status: Option<storage_enums::ReconStatus>,
     pub is_platform_account: Option<bool>,
     pub product_type: Option<common_enums::MerchantProductType>,
+    pub status: Option<common_enums::MerchantAccountStatus>,
 }
 
 #[cfg(feature = "v2")]
@@ -295,6 +304,7 @@ impl MerchantAccountUpdateInternal {
             recon_status,
             is_platform_account,
             product_type,
+            status,
         } = self;
 
         MerchantAccount {
@@ -311,6 +321,7 @@ impl MerchantAccountUpdateInternal {
             id: source.id,
             is_platform_account: is_platform_account.unwrap_or(source.is_platform_account),
             product_type: product_type.or(source.product_type),
+            status: status.unwrap_or(source.status),
         }
     }
 }
@@ -346,6 +357,7 @@ pub struct MerchantAccountUpdateInternal {
     pub pm_collect_link_config: Option<serde_json::Value>,
     pub is_platform_account: Option<bool>,
     pub product_type: Option<common_enums::MerchantProductType>,
+    pub status: Option<common_enums::MerchantAccountStatus>,
 }
 
 #[cfg(feature = "v1")]
@@ -379,6 +391,7 @@ impl MerchantAccountUpdateInternal {
             pm_collect_link_config,
             is_platform_account,
             product_type,
+            status,
         } = self;
 
         MerchantAccount {
@@ -417,6 +430,7 @@ impl MerchantAccountUpdateInternal {
             is_platform_account: is_platform_account.unwrap_or(source.is_platform_account),
             id: source.id,
             product_type: product_type.or(source.product_type),
+            status: status.unwrap_or(source.status),
         }
     }
 }
@@ -189,6 +189,19 @@ pub struct PaymentAttempt {
     pub charges: Option<common_types::payments::ConnectorChargeResponseData>,
     pub issuer_error_code: Option<String>,
     pub issuer_error_message: Option<String>,
+    /// The amount captured above the amount capturable of the attempt
+    pub overcaptured_amount: Option<MinorUnit>,
+    /// The idempotency key sent to the connector with the requests of the attempt
+    pub connector_idempotency_key: Option<String>,
+    /// The result of the address verification, normalized from the checks of the connector
+    pub avs_result: Option<storage_enums::AvsResult>,
+    /// The result of the verification of the card verification value, normalized from the checks
+    /// of the connector
+    pub cvv_result: Option<storage_enums::CvvResult>,
+    /// The reference number assigned by the acquirer to the capture of the attempt
+    pub acquirer_reference_number: Option<String>,
+    /// The credential of the saved card with which the attempt was authorized
+    pub card_credential_type: Option<storage_enums::CardCredentialType>,
 }
 
 #[cfg(feature = "v1")]
@@ -399,6 +412,7 @@ pub struct PaymentAttemptNew {
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub capture_before: Option<PrimitiveDateTime>,
     pub card_discovery: Option<storage_enums::CardDiscovery>,
+    pub connector_idempotency_key: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -432,6 +446,7 @@ pub enum PaymentAttemptUpdate {
         tax_amount: Option<MinorUnit>,
         updated_by: String,
         merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+        capture_method: Option<storage_enums::CaptureMethod>,
     },
     AuthenticationTypeUpdate {
         authentication_type: storage_enums::AuthenticationType,
@@ -472,6 +487,7 @@ pub enum PaymentAttemptUpdate {
         order_tax_amount: Option<MinorUnit>,
         connector_mandate_detail: Option<ConnectorMandateReferenceId>,
         card_discovery: Option<storage_enums::CardDiscovery>,
+        card_credential_type: Option<storage_enums::CardCredentialType>,
     },
     VoidUpdate {
         status: storage_enums::AttemptStatus,
@@ -558,6 +574,16 @@ pub enum PaymentAttemptUpdate {
         amount_to_capture: Option<MinorUnit>,
         multiple_capture_count: Option<i16>,
         updated_by: String,
+        overcaptured_amount: Option<MinorUnit>,
+    },
+    PaymentChecksUpdate {
+        avs_result: Option<storage_enums::AvsResult>,
+        cvv_result: Option<storage_enums::CvvResult>,
+        updated_by: String,
+    },
+    AcquirerReferenceNumberUpdate {
+        acquirer_reference_number: String,
+        updated_by: String,
     },
     AmountToCaptureUpdate {
         status: storage_enums::AttemptStatus,
@@ -908,6 +934,11 @@ pub struct PaymentAttemptUpdateInternal {
     pub charges: Option<common_types::payments::ConnectorChargeResponseData>,
     pub issuer_error_code: Option<String>,
     pub issuer_error_message: Option<String>,
+    pub overcaptured_amount: Option<MinorUnit>,
+    pub avs_result: Option<storage_enums::AvsResult>,
+    pub cvv_result: Option<storage_enums::CvvResult>,
+    pub acquirer_reference_number: Option<String>,
+    pub card_credential_type: Option<storage_enums::CardCredentialType>,
 }
 
 #[cfg(feature = "v1")]
@@ -1096,6 +1127,11 @@ impl PaymentAttemptUpdate {
             charges,
             issuer_error_code,
             issuer_error_message,
+            overcaptured_amount,
+            avs_result,
+            cvv_result,
+            acquirer_reference_number,
+            card_credential_type,
         } = PaymentAttemptUpdateInternal::from(self).populate_derived_fields(&source);
         PaymentAttempt {
             amount: amount.unwrap_or(source.amount),
@@ -1160,6 +1196,12 @@ impl PaymentAttemptUpdate {
             charges: charges.or(source.charges),
             issuer_error_code: issuer_error_code.or(source.issuer_error_code),
             issuer_error_message: issuer_error_message.or(source.issuer_error_message),
+            overcaptured_amount: overcaptured_amount.or(source.overcaptured_amount),
+            avs_result: avs_result.or(source.avs_result),
+            cvv_result: cvv_result.or(source.cvv_result),
+            acquirer_reference_number: acquirer_reference_number
+                .or(source.acquirer_reference_number),
+            card_credential_type: card_credential_type.or(source.card_credential_type),
             ..source
         }
     }
@@ -2217,6 +2259,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 charges: None,
                 issuer_error_code: None,
                 issuer_error_message: None,
+                overcaptured_amount: None,
+                avs_result: None,
+                cvv_result: None,
+                acquirer_reference_number: None,
+                card_credential_type: None,
             },
             PaymentAttemptUpdate::AuthenticationTypeUpdate {
                 authentication_type,
@@ -2278,6 +2325,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 charges: None,
                 issuer_error_code: None,
                 issuer_error_message: None,
+                overcaptured_amount: None,
+                avs_result: None,
+                cvv_result: None,
+                acquirer_reference_number: None,
+                card_credential_type: None,
             },
             PaymentAttemptUpdate::ConfirmUpdate {
                 amount,
@@ -2314,6 +2366,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 order_tax_amount,
                 connector_mandate_detail,
                 card_discovery,
+                card_credential_type,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -2371,6 +2424,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 charges: None,
                 issuer_error_code: None,
                 issuer_error_message: None,
+                overcaptured_amount: None,
+                avs_result: None,
+                cvv_result: None,
+                acquirer_reference_number: None,
+                card_credential_type,
             },
             PaymentAttemptUpdate::VoidUpdate {
                 status,
@@ -2433,6 +2491,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 charges: None,
                 issuer_error_code: None,
                 issuer_error_message: None,
+                overcaptured_amount: None,
+                avs_result: None,
+                cvv_result: None,
+                acquirer_reference_number: None,
+                card_credential_type: None,
             },
             PaymentAttemptUpdate::RejectUpdate {
                 status,
@@ -2496,6 +2559,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 charges: None,
                 issuer_error_code: None,
                 issuer_error_message: None,
+                overcaptured_amount: None,
+                avs_result: None,
+                cvv_result: None,
+                acquirer_reference_number: None,
+                card_credential_type: None,
             },
             PaymentAttemptUpdate::BlocklistUpdate {
                 status,
@@ -2559,6 +2627,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 charges: None,
                 issuer_error_code: None,
                 issuer_error_message: None,
+                overcaptured_amount: None,
+                avs_result: None,
+                cvv_result: None,
+                acquirer_reference_number: None,
+                card_credential_type: None,
             },
             PaymentAttemptUpdate::ConnectorMandateDetailUpdate {
                 connector_mandate_detail,
@@ -2620,6 +2693,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 charges: None,
                 issuer_error_code: None,
                 issuer_error_message: None,
+                overcaptured_amount: None,
+                avs_result: None,
+                cvv_result: None,
+                acquirer_reference_number: None,
+                card_credential_type: None,
             },
             PaymentAttemptUpdate::PaymentMethodDetailsUpdate {
                 payment_method_id,
@@ -2681,6 +2759,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 charges: None,
                 issuer_error_code: None,
                 issuer_error_message: None,
+                overcaptured_amount: None,
+                avs_result: None,
+                cvv_result: None,
+                acquirer_reference_number: None,
+                card_credential_type: None,
             },
             PaymentAttemptUpdate::ResponseUpdate {
                 status,
@@ -2769,6 +2852,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     card_discovery: None,
                     issuer_error_code: None,
                     issuer_error_message: None,
+                    overcaptured_amount: None,
+                    avs_result: None,
+                    cvv_result: None,
+                    acquirer_reference_number: None,
+                    card_credential_type: None,
                 }
             }
             PaymentAttemptUpdate::ErrorUpdate {
@@ -2809,6 +2897,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     processor_transaction_data,
                     issuer_error_code,
                     issuer_error_message,
+                    overcaptured_amount: None,
+                    avs_result: None,
+                    cvv_result: None,
+                    acquirer_reference_number: None,
+                    card_credential_type: None,
                     amount: None,
                     net_amount: None,
                     currency: None,
@@ -2908,6 +3001,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 charges: None,
                 issuer_error_code: None,
                 issuer_error_message: None,
+                overcaptured_amount: None,
+                avs_result: None,
+                cvv_result: None,
+                acquirer_reference_number: None,
+                card_credential_type: None,
             },
             PaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
@@ -2918,6 +3016,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 tax_amount,
                 updated_by,
                 merchant_connector_id,
+                capture_method,
             } => Self {
                 payment_token,
                 modified_at: common_utils::date_time::now(),
@@ -2949,7 +3048,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 business_sub_label: None,
                 preprocessing_step_id: None,
                 error_reason: None,
-                capture_method: None,
+                capture_method,
                 connector_response_reference_id: None,
                 multiple_capture_count: None,
                 authentication_data: None,
@@ -2975,6 +3074,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 charges: None,
                 issuer_error_code: None,
                 issuer_error_message: None,
+                overcaptured_amount: None,
+                avs_result: None,
+                cvv_result: None,
+                acquirer_reference_number: None,
+                card_credential_type: None,
             },
             PaymentAttemptUpdate::UnresolvedResponseUpdate {
                 status,
@@ -3049,6 +3153,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     charges: None,
                     issuer_error_code: None,
                     issuer_error_message: None,
+                    overcaptured_amount: None,
+                    avs_result: None,
+                    cvv_result: None,
+                    acquirer_reference_number: None,
+                    card_credential_type: None,
                 }
             }
             PaymentAttemptUpdate::PreprocessingUpdate {
@@ -3122,17 +3231,160 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     charges: None,
                     issuer_error_code: None,
                     issuer_error_message: None,
+                    overcaptured_amount: None,
+                    avs_result: None,
+                    cvv_result: None,
+                    acquirer_reference_number: None,
+                    card_credential_type: None,
                 }
             }
             PaymentAttemptUpdate::CaptureUpdate {
                 multiple_capture_count,
                 updated_by,
                 amount_to_capture,
+                overcaptured_amount,
             } => Self {
                 multiple_capture_count,
                 modified_at: common_utils::date_time::now(),
                 updated_by,
                 amount_to_capture,
+                overcaptured_amount,
+                avs_result: None,
+                cvv_result: None,
+                acquirer_reference_number: None,
+                card_credential_type: None,
+                amount: None,
+                net_amount: None,
+                currency: None,
+                status: None,
+                connector_transaction_id: None,
+                connector: None,
+                authentication_type: None,
+                payment_method: None,
+                error_message: None,
+                payment_method_id: None,
+                cancellation_reason: None,
+                mandate_id: None,
+                browser_info: None,
+                payment_token: None,
+                error_code: None,
+                connector_metadata: None,
+                payment_method_data: None,
+                payment_method_type: None,
+                payment_experience: None,
+                business_sub_label: None,
+                straight_through_algorithm: None,
+                preprocessing_step_id: None,
+                error_reason: None,
+                capture_method: None,
+                connector_response_reference_id: None,
+                surcharge_amount: None,
+                tax_amount: None,
+                amount_capturable: None,
+                merchant_connector_id: None,
+                authentication_data: None,
+                encoded_data: None,
+                unified_code: None,
+                unified_message: None,
+                external_three_ds_authentication_attempted: None,
+                authentication_connector: None,
+                authentication_id: None,
+                fingerprint_id: None,
+                payment_method_billing_address_id: None,
+                client_source: None,
+                client_version: None,
+                customer_acceptance: None,
+                card_network: None,
+                shipping_cost: None,
+                order_tax_amount: None,
+                capture_before: None,
+                extended_authorization_applied: None,
+                processor_transaction_data: None,
+                connector_mandate_detail: None,
+                card_discovery: None,
+                charges: None,
+                issuer_error_code: None,
+                issuer_error_message: None,
+            },
+            PaymentAttemptUpdate::PaymentChecksUpdate {
+                avs_result,
+                cvv_result,
+                updated_by,
+            } => Self {
+                avs_result,
+                cvv_result,
+                acquirer_reference_number: None,
+                card_credential_type: None,
+                modified_at: common_utils::date_time::now(),
+                updated_by,
+                multiple_capture_count: None,
+                amount_to_capture: None,
+                overcaptured_amount: None,
+                amount: None,
+                net_amount: None,
+                currency: None,
+                status: None,
+                connector_transaction_id: None,
+                connector: None,
+                authentication_type: None,
+                payment_method: None,
+                error_message: None,
+                payment_method_id: None,
+                cancellation_reason: None,
+                mandate_id: None,
+                browser_info: None,
+                payment_token: None,
+                error_code: None,
+                connector_metadata: None,
+                payment_method_data: None,
+                payment_method_type: None,
+                payment_experience: None,
+                business_sub_label: None,
+                straight_through_algorithm: None,
+                preprocessing_step_id: None,
+                error_reason: None,
+                capture_method: None,
+                connector_response_reference_id: None,
+                surcharge_amount: None,
+                tax_amount: None,
+                amount_capturable: None,
+                merchant_connector_id: None,
+                authentication_data: None,
+                encoded_data: None,
+                unified_code: None,
+                unified_message: None,
+                external_three_ds_authentication_attempted: None,
+                authentication_connector: None,
+                authentication_id: None,
+                fingerprint_id: None,
+                payment_method_billing_address_id: None,
+                client_source: None,
+                client_version: None,
+                customer_acceptance: None,
+                card_network: None,
+                shipping_cost: None,
+                order_tax_amount: None,
+                capture_before: None,
+                extended_authorization_applied: None,
+                processor_transaction_data: None,
+                connector_mandate_detail: None,
+                card_discovery: None,
+                charges: None,
+                issuer_error_code: None,
+                issuer_error_message: None,
+            },
+            PaymentAttemptUpdate::AcquirerReferenceNumberUpdate {
+                acquirer_reference_number,
+                updated_by,
+            } => Self {
+                acquirer_reference_number: Some(acquirer_reference_number),
+                modified_at: common_utils::date_time::now(),
+                updated_by,
+                avs_result: None,
+                cvv_result: None,
+                multiple_capture_count: None,
+                amount_to_capture: None,
+                overcaptured_amount: None,
                 amount: None,
                 net_amount: None,
                 currency: None,
@@ -3185,6 +3437,7 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 charges: None,
                 issuer_error_code: None,
                 issuer_error_message: None,
+                card_credential_type: None,
             },
             PaymentAttemptUpdate::AmountToCaptureUpdate {
                 status,
@@ -3247,6 +3500,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 charges: None,
                 issuer_error_code: None,
                 issuer_error_message: None,
+                overcaptured_amount: None,
+                avs_result: None,
+                cvv_result: None,
+                acquirer_reference_number: None,
+                card_credential_type: None,
             },
             PaymentAttemptUpdate::ConnectorResponse {
                 authentication_data,
@@ -3318,6 +3576,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     card_discovery: None,
                     issuer_error_code: None,
                     issuer_error_message: None,
+                    overcaptured_amount: None,
+                    avs_result: None,
+                    cvv_result: None,
+                    acquirer_reference_number: None,
+                    card_credential_type: None,
                 }
             }
             PaymentAttemptUpdate::IncrementalAuthorizationAmountUpdate {
@@ -3380,6 +3643,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 charges: None,
                 issuer_error_code: None,
                 issuer_error_message: None,
+                overcaptured_amount: None,
+                avs_result: None,
+                cvv_result: None,
+                acquirer_reference_number: None,
+                card_credential_type: None,
             },
             PaymentAttemptUpdate::AuthenticationUpdate {
                 status,
@@ -3444,6 +3712,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 charges: None,
                 issuer_error_code: None,
                 issuer_error_message: None,
+                overcaptured_amount: None,
+                avs_result: None,
+                cvv_result: None,
+                acquirer_reference_number: None,
+                card_credential_type: None,
             },
             PaymentAttemptUpdate::ManualUpdate {
                 status,
@@ -3517,6 +3790,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                     charges: None,
                     issuer_error_code: None,
                     issuer_error_message: None,
+                    overcaptured_amount: None,
+                    avs_result: None,
+                    cvv_result: None,
+                    acquirer_reference_number: None,
+                    card_credential_type: None,
                 }
             }
             PaymentAttemptUpdate::PostSessionTokensUpdate {
@@ -3579,6 +3857,11 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 charges: None,
                 issuer_error_code: None,
                 issuer_error_message: None,
+                overcaptured_amount: None,
+                avs_result: None,
+                cvv_result: None,
+                acquirer_reference_number: None,
+                card_credential_type: None,
             },
         }
     }
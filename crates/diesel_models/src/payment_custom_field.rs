@@ -0,0 +1,35 @@
+use common_utils::{custom_serde, id_type};
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::Serialize;
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::payment_custom_field};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_custom_field)]
+pub struct PaymentCustomFieldNew {
+    pub merchant_id: id_type::MerchantId,
+    pub payment_id: id_type::PaymentId,
+    pub profile_id: id_type::ProfileId,
+    pub field_key: String,
+    pub field_type: storage_enums::CustomFieldType,
+    pub field_value: String,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Identifiable, Queryable, Selectable)]
+#[diesel(
+    table_name = payment_custom_field,
+    primary_key(merchant_id, payment_id, field_key),
+    check_for_backend(diesel::pg::Pg)
+)]
+pub struct PaymentCustomField {
+    pub merchant_id: id_type::MerchantId,
+    pub payment_id: id_type::PaymentId,
+    pub profile_id: id_type::ProfileId,
+    pub field_key: String,
+    pub field_type: storage_enums::CustomFieldType,
+    pub field_value: String,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
@@ -145,6 +145,13 @@ pub struct PaymentIntent {
     pub psd2_sca_exemption_type: Option<storage_enums::ScaExemptionType>,
     pub split_payments: Option<common_types::payments::SplitPaymentsRequest>,
     pub platform_merchant_id: Option<common_utils::id_type::MerchantId>,
+    pub customer_email_hash: Option<String>,
+    pub customer_phone_hash: Option<String>,
+    pub change_sequence: Option<i64>,
+    pub is_amount_estimated: Option<bool>,
+    pub enable_partial_payments: Option<bool>,
+    pub store_credit_amount: Option<MinorUnit>,
+    pub is_test_payment: Option<bool>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression, PartialEq)]
@@ -396,6 +403,12 @@ pub struct PaymentIntentNew {
     pub psd2_sca_exemption_type: Option<storage_enums::ScaExemptionType>,
     pub split_payments: Option<common_types::payments::SplitPaymentsRequest>,
     pub platform_merchant_id: Option<common_utils::id_type::MerchantId>,
+    pub customer_email_hash: Option<String>,
+    pub customer_phone_hash: Option<String>,
+    pub is_amount_estimated: Option<bool>,
+    pub enable_partial_payments: Option<bool>,
+    pub store_credit_amount: Option<MinorUnit>,
+    pub is_test_payment: Option<bool>,
 }
 
 #[cfg(feature = "v2")]
@@ -551,6 +564,8 @@ pub struct PaymentIntentUpdateFields {
     pub shipping_details: Option<Encryption>,
     pub is_payment_processor_token_flow: Option<bool>,
     pub tax_details: Option<TaxDetails>,
+    pub customer_email_hash: Option<String>,
+    pub customer_phone_hash: Option<String>,
 }
 
 // TODO: uncomment fields as necessary
@@ -635,6 +650,8 @@ pub struct PaymentIntentUpdateInternal {
     pub shipping_details: Option<Encryption>,
     pub is_payment_processor_token_flow: Option<bool>,
     pub tax_details: Option<TaxDetails>,
+    pub customer_email_hash: Option<String>,
+    pub customer_phone_hash: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -677,6 +694,8 @@ impl PaymentIntentUpdate {
             shipping_details,
             is_payment_processor_token_flow,
             tax_details,
+            customer_email_hash,
+            customer_phone_hash,
         } = self.into();
         PaymentIntent {
             amount: amount.unwrap_or(source.amount),
@@ -722,6 +741,8 @@ impl PaymentIntentUpdate {
             is_payment_processor_token_flow: is_payment_processor_token_flow
                 .or(source.is_payment_processor_token_flow),
             tax_details: tax_details.or(source.tax_details),
+            customer_email_hash: customer_email_hash.or(source.customer_email_hash),
+            customer_phone_hash: customer_phone_hash.or(source.customer_phone_hash),
             ..source
         }
     }
@@ -771,6 +792,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::Update(value) => Self {
                 amount: Some(value.amount),
@@ -810,6 +833,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 authorization_count: None,
                 is_payment_processor_token_flow: value.is_payment_processor_token_flow,
                 tax_details: None,
+                customer_email_hash: value.customer_email_hash,
+                customer_phone_hash: value.customer_phone_hash,
             },
             PaymentIntentUpdate::PaymentCreateUpdate {
                 return_url,
@@ -856,6 +881,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::PGStatusUpdate {
                 status,
@@ -898,6 +925,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::MerchantStatusUpdate {
                 status,
@@ -941,6 +970,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::ResponseUpdate {
                 // amount,
@@ -991,6 +1022,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::PaymentAttemptAndAttemptCountUpdate {
                 active_attempt_id,
@@ -1033,6 +1066,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::StatusAndAttemptUpdate {
                 status,
@@ -1076,6 +1111,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::ApproveUpdate {
                 status,
@@ -1118,6 +1155,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::RejectUpdate {
                 status,
@@ -1160,6 +1199,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::SurchargeApplicableUpdate {
                 surcharge_applicable,
@@ -1201,6 +1242,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::IncrementalAuthorizationAmountUpdate { amount } => Self {
                 amount: Some(amount),
@@ -1239,6 +1282,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::AuthorizationCountUpdate {
                 authorization_count,
@@ -1279,6 +1324,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::CompleteAuthorizeUpdate {
                 shipping_address_id,
@@ -1319,6 +1366,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::ManualUpdate { status, updated_by } => Self {
                 status,
@@ -1357,6 +1406,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
                 tax_details: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
             PaymentIntentUpdate::SessionResponseUpdate {
                 tax_details,
@@ -1400,6 +1451,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details,
                 is_payment_processor_token_flow: None,
+                customer_email_hash: None,
+                customer_phone_hash: None,
             },
         }
     }
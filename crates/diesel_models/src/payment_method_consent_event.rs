@@ -0,0 +1,27 @@
+use common_utils::{custom_serde, id_type, pii};
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::Serialize;
+use time::PrimitiveDateTime;
+
+use crate::schema::payment_method_consent_event;
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_method_consent_event)]
+pub struct PaymentMethodConsentEventNew {
+    pub event_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub payment_method_id: String,
+    pub event_data: pii::SecretSerdeValue,
+    pub recorded_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = payment_method_consent_event, primary_key(event_id), check_for_backend(diesel::pg::Pg))]
+pub struct PaymentMethodConsentEvent {
+    pub event_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub payment_method_id: String,
+    pub event_data: pii::SecretSerdeValue,
+    #[serde(with = "custom_serde::iso8601")]
+    pub recorded_at: PrimitiveDateTime,
+}
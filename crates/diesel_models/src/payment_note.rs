@@ -0,0 +1,29 @@
+use common_utils::{custom_serde, id_type};
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::Serialize;
+use time::PrimitiveDateTime;
+
+use crate::schema::payment_note;
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_note)]
+pub struct PaymentNoteNew {
+    pub note_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub payment_id: id_type::PaymentId,
+    pub author: String,
+    pub note: String,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Serialize, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = payment_note, primary_key(note_id), check_for_backend(diesel::pg::Pg))]
+pub struct PaymentNote {
+    pub note_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub payment_id: id_type::PaymentId,
+    pub author: String,
+    pub note: String,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
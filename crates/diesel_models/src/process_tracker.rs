@@ -218,6 +218,21 @@ pub enum ProcessTrackerRunner {
     AttachPayoutAccountWorkflow,
     PaymentMethodStatusUpdateWorkflow,
     PassiveRecoveryWorkflow,
+    DeferredVaultingWorkflow,
+    AuthenticationAbandonmentWorkflow,
+    RefundBatchWorkflow,
+    DunningWorkflow,
+    RoutingExperimentWorkflow,
+    MerchantOffboardingWorkflow,
+    WarehouseSyncWorkflow,
+    ConnectorOAuthTokenRefreshWorkflow,
+    ConnectorCredentialsExpiryWorkflow,
+    ScheduledCaptureWorkflow,
+    AttemptRedactionWorkflow,
+    RefundReconciliationWorkflow,
+    ScaComplianceReportWorkflow,
+    ConnectorTransactionIdBackfillWorkflow,
+    DataResidencyMigrationWorkflow,
 }
 
 #[cfg(test)]
@@ -274,6 +289,21 @@ pub mod business_status {
     /// Business status set for newly created tasks.
     pub const PENDING: &str = "Pending";
 
+    /// The task was abandoned by an operator through the process tracker API.
+    /// A task that reaches this status should not be retried (rescheduled for execution) later.
+    pub const ABANDONED_BY_OPERATOR: &str = "ABANDONED_BY_OPERATOR";
+
+    /// The task was canceled by the merchant through the API.
+    /// A task that reaches this status should not be retried (rescheduled for execution) later,
+    /// unless it is rescheduled by the merchant.
+    pub const CANCELED_BY_MERCHANT: &str = "CANCELED_BY_MERCHANT";
+
+    /// The delivery of the outgoing webhooks to the endpoint of the merchant was paused, as the
+    /// endpoint has been failing persistently.
+    /// A task that reaches this status is kept pending, and is rescheduled for execution once the
+    /// merchant resumes the delivery.
+    pub const WEBHOOK_ENDPOINT_PAUSED: &str = "WEBHOOK_ENDPOINT_PAUSED";
+
     /// For the PCR Workflow
     ///
     /// This status indicates the completion of a execute task
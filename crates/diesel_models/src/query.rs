@@ -13,6 +13,10 @@ pub mod blocklist_fingerprint;
 pub mod callback_mapper;
 pub mod customers;
 pub mod dashboard_metadata;
+#[cfg(feature = "v1")]
+pub mod data_residency;
+#[cfg(feature = "v1")]
+pub mod data_retention;
 pub mod dispute;
 pub mod dynamic_routing_stats;
 pub mod events;
@@ -21,6 +25,8 @@ pub mod fraud_check;
 pub mod generic_link;
 pub mod generics;
 pub mod gsm;
+pub mod issuer_authentication_stats;
+pub mod ledger_entry;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -28,9 +34,12 @@ pub mod merchant_connector_account;
 pub mod merchant_key_store;
 pub mod organization;
 pub mod payment_attempt;
+pub mod payment_custom_field;
 pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_method_consent_event;
+pub mod payment_note;
 pub mod payout_attempt;
 pub mod payouts;
 pub mod process_tracker;
@@ -39,8 +48,13 @@ pub mod relay;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod store_credit_entry;
+#[cfg(feature = "v1")]
+pub mod test_data;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+#[cfg(feature = "v1")]
+pub mod warehouse_sync;
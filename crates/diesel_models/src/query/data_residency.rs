@@ -0,0 +1,173 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+use router_env::logger;
+use time::PrimitiveDateTime;
+
+use super::generics;
+use crate::{
+    address::Address,
+    customers::Customer,
+    errors,
+    payment_method::PaymentMethod,
+    schema::{
+        address::dsl as address_dsl, customers::dsl as customer_dsl,
+        payment_methods::dsl as payment_method_dsl,
+    },
+    PgPooledConn, StorageResult,
+};
+
+/// Position of a migration pass in the rows of a merchant, which are migrated in the order of
+/// their last update
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ResidencyMigrationCursor {
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+    pub id: String,
+}
+
+/// Finds the customers of the merchant which were last updated at or after the given time, after
+/// the cursor
+pub async fn find_customers_for_residency_migration(
+    conn: &PgPooledConn,
+    merchant_id: &common_utils::id_type::MerchantId,
+    modified_since: Option<PrimitiveDateTime>,
+    cursor: Option<ResidencyMigrationCursor>,
+    limit: i64,
+) -> StorageResult<Vec<Customer>> {
+    let mut query = <Customer as HasTable>::table()
+        .filter(customer_dsl::merchant_id.eq(merchant_id.to_owned()))
+        .into_boxed();
+
+    if let Some(modified_since) = modified_since {
+        query = query.filter(customer_dsl::modified_at.ge(modified_since));
+    }
+
+    if let Some(cursor) = cursor {
+        query = query.filter(
+            customer_dsl::modified_at
+                .gt(cursor.modified_at)
+                .or(customer_dsl::modified_at
+                    .eq(cursor.modified_at)
+                    .and(customer_dsl::customer_id.gt(cursor.id))),
+        );
+    }
+
+    let query = query
+        .order((
+            customer_dsl::modified_at.asc(),
+            customer_dsl::customer_id.asc(),
+        ))
+        .limit(limit);
+
+    logger::debug!(query = %debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+    query
+        .get_results_async(conn)
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while finding customers for data residency migration")
+}
+
+/// Finds the addresses of the merchant which were last updated at or after the given time, after
+/// the cursor
+pub async fn find_addresses_for_residency_migration(
+    conn: &PgPooledConn,
+    merchant_id: &common_utils::id_type::MerchantId,
+    modified_since: Option<PrimitiveDateTime>,
+    cursor: Option<ResidencyMigrationCursor>,
+    limit: i64,
+) -> StorageResult<Vec<Address>> {
+    let mut query = <Address as HasTable>::table()
+        .filter(address_dsl::merchant_id.eq(merchant_id.to_owned()))
+        .into_boxed();
+
+    if let Some(modified_since) = modified_since {
+        query = query.filter(address_dsl::modified_at.ge(modified_since));
+    }
+
+    if let Some(cursor) = cursor {
+        query = query.filter(
+            address_dsl::modified_at
+                .gt(cursor.modified_at)
+                .or(address_dsl::modified_at
+                    .eq(cursor.modified_at)
+                    .and(address_dsl::address_id.gt(cursor.id))),
+        );
+    }
+
+    let query = query
+        .order((
+            address_dsl::modified_at.asc(),
+            address_dsl::address_id.asc(),
+        ))
+        .limit(limit);
+
+    logger::debug!(query = %debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+    query
+        .get_results_async(conn)
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while finding addresses for data residency migration")
+}
+
+/// Finds the payment methods of the merchant holding a card or a network token in the locker which
+/// were last updated at or after the given time, after the cursor
+pub async fn find_payment_methods_for_residency_migration(
+    conn: &PgPooledConn,
+    merchant_id: &common_utils::id_type::MerchantId,
+    modified_since: Option<PrimitiveDateTime>,
+    cursor: Option<ResidencyMigrationCursor>,
+    limit: i64,
+) -> StorageResult<Vec<PaymentMethod>> {
+    let mut query = <PaymentMethod as HasTable>::table()
+        .filter(payment_method_dsl::merchant_id.eq(merchant_id.to_owned()))
+        .filter(
+            payment_method_dsl::locker_id
+                .is_not_null()
+                .or(payment_method_dsl::network_token_locker_id.is_not_null()),
+        )
+        .into_boxed();
+
+    if let Some(modified_since) = modified_since {
+        query = query.filter(payment_method_dsl::last_modified.ge(modified_since));
+    }
+
+    if let Some(cursor) = cursor {
+        query = query.filter(
+            payment_method_dsl::last_modified.gt(cursor.modified_at).or(
+                payment_method_dsl::last_modified
+                    .eq(cursor.modified_at)
+                    .and(payment_method_dsl::payment_method_id.gt(cursor.id)),
+            ),
+        );
+    }
+
+    let query = query
+        .order((
+            payment_method_dsl::last_modified.asc(),
+            payment_method_dsl::payment_method_id.asc(),
+        ))
+        .limit(limit);
+
+    logger::debug!(query = %debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+    query
+        .get_results_async(conn)
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while finding payment methods for data residency migration")
+}
+
+/// Inserts a customer as is, including the time of its creation and last update
+pub async fn insert_customer(conn: &PgPooledConn, customer: Customer) -> StorageResult<Customer> {
+    generics::generic_insert(conn, customer).await
+}
+
+/// Inserts an address as is, including the time of its creation and last update
+pub async fn insert_address(conn: &PgPooledConn, address: Address) -> StorageResult<Address> {
+    generics::generic_insert(conn, address).await
+}
@@ -0,0 +1,97 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+use router_env::logger;
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums, errors, schema::payment_attempt::dsl as payment_attempt_dsl,
+    PaymentAttempt, PgPooledConn, StorageResult,
+};
+
+/// Position of the redaction pass in the payment attempts, which are redacted in the order of
+/// their last update
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct AttemptRedactionCursor {
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub attempt_id: String,
+}
+
+/// Finds the attempts in the given statuses which were last updated before the given time and
+/// hold payment method data or encoded data, after the cursor
+pub async fn find_payment_attempts_for_redaction(
+    conn: &PgPooledConn,
+    statuses: Vec<storage_enums::AttemptStatus>,
+    modified_before: PrimitiveDateTime,
+    cursor: Option<AttemptRedactionCursor>,
+    limit: i64,
+) -> StorageResult<Vec<PaymentAttempt>> {
+    let mut query = <PaymentAttempt as HasTable>::table()
+        .filter(payment_attempt_dsl::status.eq_any(statuses))
+        .filter(payment_attempt_dsl::modified_at.lt(modified_before))
+        .filter(
+            payment_attempt_dsl::payment_method_data
+                .is_not_null()
+                .or(payment_attempt_dsl::encoded_data.is_not_null()),
+        )
+        .into_boxed();
+
+    if let Some(cursor) = cursor {
+        query = query.filter(
+            payment_attempt_dsl::modified_at.gt(cursor.modified_at).or(
+                payment_attempt_dsl::modified_at.eq(cursor.modified_at).and(
+                    payment_attempt_dsl::merchant_id
+                        .gt(cursor.merchant_id.clone())
+                        .or(payment_attempt_dsl::merchant_id
+                            .eq(cursor.merchant_id)
+                            .and(payment_attempt_dsl::attempt_id.gt(cursor.attempt_id))),
+                ),
+            ),
+        );
+    }
+
+    let query = query
+        .order((
+            payment_attempt_dsl::modified_at.asc(),
+            payment_attempt_dsl::merchant_id.asc(),
+            payment_attempt_dsl::attempt_id.asc(),
+        ))
+        .limit(limit);
+
+    logger::debug!(query = %debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+    query
+        .get_results_async(conn)
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while finding payment attempts for redaction")
+}
+
+/// Replaces the payment method data of the attempt and clears its encoded data, without changing
+/// the time of its last update
+pub async fn redact_payment_attempt(
+    conn: &PgPooledConn,
+    merchant_id: &common_utils::id_type::MerchantId,
+    attempt_id: &str,
+    payment_method_data: Option<serde_json::Value>,
+) -> StorageResult<PaymentAttempt> {
+    let query = diesel::update(<PaymentAttempt>::table())
+        .filter(payment_attempt_dsl::merchant_id.eq(merchant_id.to_owned()))
+        .filter(payment_attempt_dsl::attempt_id.eq(attempt_id.to_owned()))
+        .set((
+            payment_attempt_dsl::payment_method_data.eq(payment_method_data),
+            payment_attempt_dsl::encoded_data.eq(None::<String>),
+        ));
+
+    logger::debug!(query = %debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+    query
+        .get_result_async(conn)
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while redacting the payment attempt")
+}
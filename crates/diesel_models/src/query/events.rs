@@ -91,6 +91,77 @@ impl Event {
             .attach_printable("Error filtering events by constraints")
     }
 
+    /// Lists the lifecycle events of the merchant, which are the initial delivery attempts and
+    /// the events recorded without a webhook being sent, newest first. The `starting_after`
+    /// cursor is the `(created_at, event_id)` of the last event of the previous page.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn list_lifecycle_events_by_merchant_id_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        event_class: Option<common_enums::EventClass>,
+        event_type: Option<common_enums::EventType>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        starting_after: Option<(time::PrimitiveDateTime, String)>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+        use crate::errors::DatabaseError;
+
+        let mut query = Self::table()
+            .filter(
+                dsl::merchant_id.eq(merchant_id.to_owned()).and(
+                    dsl::initial_attempt_id
+                        .is_null()
+                        .or(dsl::event_id.nullable().eq(dsl::initial_attempt_id)),
+                ),
+            )
+            .order((dsl::created_at.desc(), dsl::event_id.desc()))
+            .limit(limit)
+            .into_boxed();
+
+        if let Some(created_after) = created_after {
+            query = query.filter(dsl::created_at.ge(created_after));
+        }
+
+        if let Some(created_before) = created_before {
+            query = query.filter(dsl::created_at.le(created_before));
+        }
+
+        if let Some(profile_id) = profile_id {
+            query = query.filter(dsl::business_profile_id.eq(profile_id));
+        }
+
+        if let Some(event_class) = event_class {
+            query = query.filter(dsl::event_class.eq(event_class));
+        }
+
+        if let Some(event_type) = event_type {
+            query = query.filter(dsl::event_type.eq(event_type));
+        }
+
+        if let Some((cursor_created_at, cursor_event_id)) = starting_after {
+            query = query.filter(
+                dsl::created_at.lt(cursor_created_at).or(dsl::created_at
+                    .eq(cursor_created_at)
+                    .and(dsl::event_id.lt(cursor_event_id))),
+            );
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error filtering lifecycle events by constraints")
+    }
+
     pub async fn list_by_merchant_id_initial_attempt_id(
         conn: &PgPooledConn,
         merchant_id: &common_utils::id_type::MerchantId,
@@ -0,0 +1,49 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{associations::HasTable, debug_query, upsert::excluded, ExpressionMethods};
+use error_stack::ResultExt;
+use router_env::logger;
+
+use super::generics;
+use crate::{
+    errors,
+    issuer_authentication_stats::{IssuerAuthenticationStats, IssuerAuthenticationStatsNew},
+    schema::issuer_authentication_stats::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl IssuerAuthenticationStatsNew {
+    /// Inserts the stats of the card BIN, or adds the counts to its existing stats
+    pub async fn upsert(self, conn: &PgPooledConn) -> StorageResult<IssuerAuthenticationStats> {
+        let query = diesel::insert_into(<IssuerAuthenticationStats>::table())
+            .values(self)
+            .on_conflict(dsl::card_bin)
+            .do_update()
+            .set((
+                dsl::frictionless_count
+                    .eq(dsl::frictionless_count + excluded(dsl::frictionless_count)),
+                dsl::challenge_count.eq(dsl::challenge_count + excluded(dsl::challenge_count)),
+                dsl::last_modified_at.eq(excluded(dsl::last_modified_at)),
+            ));
+
+        logger::debug!(query = %debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+        query
+            .get_result_async(conn)
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error while upserting issuer authentication stats")
+    }
+}
+
+impl IssuerAuthenticationStats {
+    pub async fn find_optional_by_card_bin(
+        conn: &PgPooledConn,
+        card_bin: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::card_bin.eq(card_bin.to_owned()),
+        )
+        .await
+    }
+}
@@ -0,0 +1,87 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+use router_env::logger;
+
+use super::generics;
+use crate::{
+    enums as storage_enums, errors,
+    ledger_entry::{LedgerEntry, LedgerEntryNew},
+    schema::ledger_entry::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl LedgerEntryNew {
+    /// Inserts the entry, unless an entry of its type has already been recorded for the stage of
+    /// the dispute, in which case `None` is returned
+    pub async fn insert_if_absent(self, conn: &PgPooledConn) -> StorageResult<Option<LedgerEntry>> {
+        let query = diesel::insert_into(<LedgerEntry>::table())
+            .values(self)
+            .on_conflict((
+                dsl::merchant_id,
+                dsl::dispute_id,
+                dsl::dispute_stage,
+                dsl::entry_type,
+            ))
+            .do_nothing();
+
+        logger::debug!(query = %debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+        query
+            .get_results_async(conn)
+            .await
+            .map(|ledger_entries: Vec<LedgerEntry>| ledger_entries.into_iter().next())
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error while inserting ledger entry")
+    }
+}
+
+impl LedgerEntry {
+    pub async fn find_by_merchant_id_dispute_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        dispute_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::dispute_id.eq(dispute_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    /// The total amount of the entries of the merchant, for each currency and type of entry
+    pub async fn get_total_amount_by_currency_and_entry_type(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+    ) -> StorageResult<Vec<(storage_enums::Currency, storage_enums::LedgerEntryType, i64)>> {
+        let mut query = <Self as HasTable>::table()
+            .group_by((dsl::currency, dsl::entry_type))
+            .select((
+                dsl::currency,
+                dsl::entry_type,
+                diesel::dsl::sql::<diesel::sql_types::BigInt>("CAST(SUM(amount) AS BIGINT)"),
+            ))
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .into_boxed();
+
+        if let Some(profile_id_list) = profile_id_list {
+            query = query.filter(dsl::profile_id.eq_any(profile_id_list));
+        }
+
+        logger::debug!(query = %debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+        query
+            .get_results_async(conn)
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error while aggregating ledger entries")
+    }
+}
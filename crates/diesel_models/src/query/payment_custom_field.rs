@@ -0,0 +1,37 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    payment_custom_field::{PaymentCustomField, PaymentCustomFieldNew},
+    schema::payment_custom_field::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl PaymentCustomFieldNew {
+    pub async fn batch_insert(
+        payment_custom_fields: Vec<Self>,
+        conn: &PgPooledConn,
+    ) -> StorageResult<()> {
+        generics::generic_insert::<_, _, PaymentCustomField>(conn, payment_custom_fields).await?;
+        Ok(())
+    }
+}
+
+impl PaymentCustomField {
+    pub async fn find_by_merchant_id_payment_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_ids: Vec<common_utils::id_type::PaymentId>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_id.eq_any(payment_ids)),
+            None,
+            None,
+            Some(dsl::field_key.asc()),
+        )
+        .await
+    }
+}
@@ -148,4 +148,45 @@ impl PaymentIntent {
         )
         .await
     }
+
+    /// Finds the payment intents of the merchant whose status changed after the given change
+    /// sequence, and which were last modified at or before the given time, in the order of their
+    /// changes
+    #[cfg(feature = "v1")]
+    pub async fn find_by_merchant_id_changed_after(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<&common_utils::id_type::ProfileId>,
+        change_sequence: i64,
+        modified_before: time::PrimitiveDateTime,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        let predicate = dsl::merchant_id
+            .eq(merchant_id.to_owned())
+            .and(dsl::change_sequence.gt(change_sequence))
+            .and(dsl::modified_at.le(modified_before));
+
+        match profile_id {
+            Some(profile_id) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate.and(dsl::profile_id.eq(profile_id.to_owned())),
+                    Some(limit),
+                    None,
+                    Some(dsl::change_sequence.asc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    predicate,
+                    Some(limit),
+                    None,
+                    Some(dsl::change_sequence.asc()),
+                )
+                .await
+            }
+        }
+    }
 }
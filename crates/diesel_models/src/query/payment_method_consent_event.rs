@@ -0,0 +1,34 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    payment_method_consent_event::{PaymentMethodConsentEvent, PaymentMethodConsentEventNew},
+    schema::payment_method_consent_event::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl PaymentMethodConsentEventNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentMethodConsentEvent> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PaymentMethodConsentEvent {
+    /// The consent events of the payment methods, in the order in which they were recorded
+    pub async fn find_by_merchant_id_payment_method_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_ids: Vec<String>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_method_id.eq_any(payment_method_ids)),
+            None,
+            None,
+            Some(dsl::recorded_at.asc()),
+        )
+        .await
+    }
+}
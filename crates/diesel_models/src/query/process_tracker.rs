@@ -1,8 +1,17 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
-use router_env::{instrument, tracing};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use common_utils::id_type;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods,
+    QueryDsl, Table,
+};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
 use time::PrimitiveDateTime;
 
-use super::generics;
+use super::generics::{
+    self,
+    db_metrics::{track_database_call, DatabaseOperation},
+};
 use crate::{
     enums, errors,
     process_tracker::{
@@ -41,6 +50,27 @@ impl ProcessTracker {
         }
     }
 
+    /// Updates the process only if its status has not changed since it was fetched, so that a
+    /// process picked by the scheduler in the meantime is not modified
+    #[instrument(skip(conn))]
+    pub async fn update_if_status_unchanged(
+        self,
+        conn: &PgPooledConn,
+        process: ProcessTrackerUpdate,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::id.eq(self.id).and(dsl::status.eq(self.status)),
+            ProcessTrackerUpdateInternal::from(process),
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn update_process_status_by_ids(
         conn: &PgPooledConn,
@@ -91,6 +121,41 @@ impl ProcessTracker {
         .await
     }
 
+    /// Filters the processes of a merchant, identified by the `merchant_id` in their tracking data
+    #[instrument(skip(conn))]
+    pub async fn find_merchant_processes_by_time_status(
+        conn: &PgPooledConn,
+        merchant_id: &id_type::MerchantId,
+        time_lower_limit: PrimitiveDateTime,
+        time_upper_limit: PrimitiveDateTime,
+        status: enums::ProcessTrackerStatus,
+        limit: Option<i64>,
+        version: enums::ApiVersion,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<
+            <Self as HasTable>::Table,
+            _,
+            <<Self as HasTable>::Table as Table>::PrimaryKey,
+            _,
+        >(
+            conn,
+            dsl::schedule_time
+                .between(time_lower_limit, time_upper_limit)
+                .and(dsl::status.eq(status))
+                .and(dsl::version.eq(version))
+                .and(
+                    diesel::dsl::sql::<diesel::sql_types::Bool>(
+                        "process_tracker.tracking_data ->> 'merchant_id' = ",
+                    )
+                    .bind::<diesel::sql_types::Text, _>(merchant_id.get_string_repr().to_owned()),
+                ),
+            limit,
+            None,
+            None,
+        )
+        .await
+    }
+
     #[instrument(skip(conn))]
     pub async fn find_processes_to_clean(
         conn: &PgPooledConn,
@@ -138,4 +203,63 @@ impl ProcessTracker {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn find_processes_by_runner_status(
+        conn: &PgPooledConn,
+        runner: &str,
+        statuses: Vec<enums::ProcessTrackerStatus>,
+        business_statuses: Option<Vec<String>>,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        let mut query = <Self as HasTable>::table()
+            .filter(dsl::runner.eq(runner.to_owned()))
+            .filter(dsl::status.eq_any(statuses))
+            .order(dsl::created_at.asc())
+            .limit(limit)
+            .offset(offset)
+            .into_boxed();
+
+        if let Some(business_statuses) = business_statuses {
+            query = query.filter(dsl::business_status.eq_any(business_statuses));
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(errors::DatabaseError::NotFound)
+            .attach_printable("Error filtering processes by runner and status")
+    }
+
+    /// Returns the number of processes of the runner with the given statuses, along with the
+    /// creation time of the oldest of them
+    #[instrument(skip(conn))]
+    pub async fn get_processes_count_by_runner_status(
+        conn: &PgPooledConn,
+        runner: &str,
+        statuses: Vec<enums::ProcessTrackerStatus>,
+        business_statuses: Option<Vec<String>>,
+    ) -> StorageResult<(i64, Option<PrimitiveDateTime>)> {
+        let mut query = <Self as HasTable>::table()
+            .select((diesel::dsl::count_star(), diesel::dsl::min(dsl::created_at)))
+            .filter(dsl::runner.eq(runner.to_owned()))
+            .filter(dsl::status.eq_any(statuses))
+            .into_boxed();
+
+        if let Some(business_statuses) = business_statuses {
+            query = query.filter(dsl::business_status.eq_any(business_statuses));
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(
+            query.get_result_async::<(i64, Option<PrimitiveDateTime>)>(conn),
+            DatabaseOperation::Count,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error counting processes by runner and status")
+    }
 }
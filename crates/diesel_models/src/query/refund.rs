@@ -1,16 +1,25 @@
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "refunds_v2")))]
+use async_bb8_diesel::AsyncRunQueryDsl;
 use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods, Table};
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "refunds_v2")))]
+use diesel::{debug_query, QueryDsl};
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "refunds_v2")))]
+use error_stack::ResultExt;
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "refunds_v2")))]
+use router_env::logger;
 
 use super::generics;
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "refunds_v2")))]
+use crate::{
+    enums as storage_enums,
+    refund::{Refund, RefundCreationCursor, RefundNew},
+    schema::refund::dsl,
+};
 use crate::{
     errors,
     refund::{RefundUpdate, RefundUpdateInternal},
     PgPooledConn, StorageResult,
 };
-#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "refunds_v2")))]
-use crate::{
-    refund::{Refund, RefundNew},
-    schema::refund::dsl,
-};
 #[cfg(all(feature = "v2", feature = "refunds_v2"))]
 use crate::{
     refund::{Refund, RefundNew},
@@ -136,6 +145,51 @@ impl Refund {
         )
         .await
     }
+
+    /// Finds the refunds in the given status which were created before the given time, in the
+    /// order of their creation, after the cursor
+    pub async fn find_by_status_created_before(
+        conn: &PgPooledConn,
+        refund_status: storage_enums::RefundStatus,
+        created_before: time::PrimitiveDateTime,
+        cursor: Option<RefundCreationCursor>,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        let mut query = <Self as HasTable>::table()
+            .filter(dsl::refund_status.eq(refund_status))
+            .filter(dsl::created_at.lt(created_before))
+            .into_boxed();
+
+        if let Some(cursor) = cursor {
+            query = query.filter(
+                dsl::created_at.gt(cursor.created_at).or(dsl::created_at
+                    .eq(cursor.created_at)
+                    .and(
+                        dsl::merchant_id
+                            .gt(cursor.merchant_id.clone())
+                            .or(dsl::merchant_id
+                                .eq(cursor.merchant_id)
+                                .and(dsl::refund_id.gt(cursor.refund_id))),
+                    )),
+            );
+        }
+
+        let query = query
+            .order((
+                dsl::created_at.asc(),
+                dsl::merchant_id.asc(),
+                dsl::refund_id.asc(),
+            ))
+            .limit(limit);
+
+        logger::debug!(query = %debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+        query
+            .get_results_async(conn)
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error while finding refunds by status created before")
+    }
 }
 
 #[cfg(all(feature = "v2", feature = "refunds_v2"))]
@@ -0,0 +1,108 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+use router_env::logger;
+
+use super::generics;
+use crate::{
+    enums as storage_enums, errors,
+    schema::store_credit_entry::dsl,
+    store_credit_entry::{StoreCreditEntry, StoreCreditEntryNew},
+    PgPooledConn, StorageResult,
+};
+
+impl StoreCreditEntryNew {
+    /// Inserts the entry, unless an entry of its type has already been recorded for the payment, or
+    /// for the refund of a refund credit, in which case `None` is returned
+    pub async fn insert_if_absent(
+        self,
+        conn: &PgPooledConn,
+    ) -> StorageResult<Option<StoreCreditEntry>> {
+        let query = diesel::insert_into(<StoreCreditEntry>::table())
+            .values(self)
+            .on_conflict_do_nothing();
+
+        logger::debug!(query = %debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+        query
+            .get_results_async(conn)
+            .await
+            .map(|entries: Vec<StoreCreditEntry>| entries.into_iter().next())
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error while inserting store credit entry")
+    }
+}
+
+impl StoreCreditEntry {
+    pub async fn find_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned())),
+            limit,
+            offset,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn find_by_merchant_id_payment_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_id.eq(payment_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    /// The total amount of the entries of the customer, for each currency and type of entry
+    pub async fn get_total_amount_by_currency_and_entry_type(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> StorageResult<
+        Vec<(
+            storage_enums::Currency,
+            storage_enums::StoreCreditEntryType,
+            i64,
+        )>,
+    > {
+        let query = <Self as HasTable>::table()
+            .group_by((dsl::currency, dsl::entry_type))
+            .select((
+                dsl::currency,
+                dsl::entry_type,
+                diesel::dsl::sql::<diesel::sql_types::BigInt>("CAST(SUM(amount) AS BIGINT)"),
+            ))
+            .filter(
+                dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(dsl::customer_id.eq(customer_id.to_owned())),
+            );
+
+        logger::debug!(query = %debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+        query
+            .get_results_async(conn)
+            .await
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Error while aggregating store credit entries")
+    }
+}
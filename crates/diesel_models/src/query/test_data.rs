@@ -0,0 +1,96 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{associations::HasTable, debug_query, ExpressionMethods, QueryDsl};
+use error_stack::ResultExt;
+use router_env::logger;
+use time::PrimitiveDateTime;
+
+use crate::{
+    errors,
+    schema::{
+        payment_attempt::dsl as payment_attempt_dsl, payment_intent::dsl as payment_intent_dsl,
+        refund::dsl as refund_dsl,
+    },
+    PaymentAttempt, PaymentIntent, PgPooledConn, Refund, StorageResult,
+};
+
+/// Finds the ids of the test payments of the profile which were created before the given time,
+/// oldest first
+pub async fn find_test_payment_ids(
+    conn: &PgPooledConn,
+    merchant_id: &common_utils::id_type::MerchantId,
+    profile_id: &common_utils::id_type::ProfileId,
+    created_before: PrimitiveDateTime,
+    limit: i64,
+) -> StorageResult<Vec<common_utils::id_type::PaymentId>> {
+    let query = <PaymentIntent>::table()
+        .select(payment_intent_dsl::payment_id)
+        .filter(payment_intent_dsl::merchant_id.eq(merchant_id.to_owned()))
+        .filter(payment_intent_dsl::profile_id.eq(profile_id.to_owned()))
+        .filter(payment_intent_dsl::is_test_payment.eq(true))
+        .filter(payment_intent_dsl::created_at.lt(created_before))
+        .order(payment_intent_dsl::created_at.asc())
+        .limit(limit);
+
+    logger::debug!(query = %debug_query::<diesel::pg::Pg,_>(&query).to_string());
+
+    query
+        .get_results_async(conn)
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while finding test payments")
+}
+
+pub async fn delete_payment_intents(
+    conn: &PgPooledConn,
+    merchant_id: &common_utils::id_type::MerchantId,
+    payment_ids: Vec<common_utils::id_type::PaymentId>,
+) -> StorageResult<Vec<PaymentIntent>> {
+    let query = diesel::delete(<PaymentIntent>::table())
+        .filter(payment_intent_dsl::merchant_id.eq(merchant_id.to_owned()))
+        .filter(payment_intent_dsl::payment_id.eq_any(payment_ids))
+        .filter(payment_intent_dsl::is_test_payment.eq(true));
+
+    logger::debug!(query = %debug_query::<diesel::pg::Pg,_>(&query).to_string());
+
+    query
+        .get_results_async(conn)
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while deleting payment intents")
+}
+
+pub async fn delete_payment_attempts(
+    conn: &PgPooledConn,
+    merchant_id: &common_utils::id_type::MerchantId,
+    payment_ids: Vec<common_utils::id_type::PaymentId>,
+) -> StorageResult<Vec<PaymentAttempt>> {
+    let query = diesel::delete(<PaymentAttempt>::table())
+        .filter(payment_attempt_dsl::merchant_id.eq(merchant_id.to_owned()))
+        .filter(payment_attempt_dsl::payment_id.eq_any(payment_ids));
+
+    logger::debug!(query = %debug_query::<diesel::pg::Pg,_>(&query).to_string());
+
+    query
+        .get_results_async(conn)
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while deleting payment attempts")
+}
+
+pub async fn delete_refunds(
+    conn: &PgPooledConn,
+    merchant_id: &common_utils::id_type::MerchantId,
+    payment_ids: Vec<common_utils::id_type::PaymentId>,
+) -> StorageResult<Vec<Refund>> {
+    let query = diesel::delete(<Refund>::table())
+        .filter(refund_dsl::merchant_id.eq(merchant_id.to_owned()))
+        .filter(refund_dsl::payment_id.eq_any(payment_ids));
+
+    logger::debug!(query = %debug_query::<diesel::pg::Pg,_>(&query).to_string());
+
+    query
+        .get_results_async(conn)
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while deleting refunds")
+}
@@ -32,6 +32,31 @@ impl UnifiedTranslations {
         .await
     }
 
+    /// Finds the latest translation of the unified code in the locale, regardless of the unified
+    /// message it was added for
+    pub async fn find_by_unified_code_locale(
+        conn: &PgPooledConn,
+        unified_code: String,
+        locale: String,
+    ) -> StorageResult<Self> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::unified_code
+                .eq(unified_code)
+                .and(dsl::locale.eq(locale)),
+            Some(1),
+            None,
+            Some(dsl::last_modified_at.desc()),
+        )
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| {
+            report!(errors::DatabaseError::NotFound)
+                .attach_printable("Translation not found for the unified code and locale")
+        })
+    }
+
     pub async fn update_by_unified_code_unified_message_locale(
         conn: &PgPooledConn,
         unified_code: String,
@@ -0,0 +1,133 @@
+//! Incremental reads of the records of a merchant, in the order in which they were last modified.
+//! The records are paginated with the last modification time and the identifier of the last record
+//! read, so that records modified within the same instant are neither skipped nor read twice. Only
+//! the records last modified before the given time are read, so that the caller can hold back the
+//! latest modifications, which may still be followed by ones with an earlier modification time.
+
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, BoolExpressionMethods, ExpressionMethods, QueryDsl,
+};
+use error_stack::ResultExt;
+use router_env::logger;
+use time::PrimitiveDateTime;
+
+use crate::{
+    errors,
+    schema::{
+        dispute::dsl as dispute_dsl, payment_intent::dsl as payment_intent_dsl,
+        refund::dsl as refund_dsl,
+    },
+    Dispute, PaymentIntent, PgPooledConn, Refund, StorageResult,
+};
+
+pub async fn find_payment_intents_modified_after(
+    conn: &PgPooledConn,
+    merchant_id: &common_utils::id_type::MerchantId,
+    modified_after: Option<(PrimitiveDateTime, common_utils::id_type::PaymentId)>,
+    modified_before: PrimitiveDateTime,
+    limit: i64,
+) -> StorageResult<Vec<PaymentIntent>> {
+    let mut query = <PaymentIntent>::table()
+        .filter(payment_intent_dsl::merchant_id.eq(merchant_id.to_owned()))
+        .filter(payment_intent_dsl::modified_at.le(modified_before))
+        .into_boxed();
+
+    if let Some((modified_at, payment_id)) = modified_after {
+        query = query.filter(
+            payment_intent_dsl::modified_at
+                .gt(modified_at)
+                .or(payment_intent_dsl::modified_at
+                    .eq(modified_at)
+                    .and(payment_intent_dsl::payment_id.gt(payment_id))),
+        );
+    }
+
+    let query = query
+        .order((
+            payment_intent_dsl::modified_at.asc(),
+            payment_intent_dsl::payment_id.asc(),
+        ))
+        .limit(limit);
+
+    logger::debug!(query = %debug_query::<diesel::pg::Pg,_>(&query).to_string());
+
+    query
+        .get_results_async(conn)
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while fetching modified payment intents")
+}
+
+pub async fn find_refunds_modified_after(
+    conn: &PgPooledConn,
+    merchant_id: &common_utils::id_type::MerchantId,
+    modified_after: Option<(PrimitiveDateTime, String)>,
+    modified_before: PrimitiveDateTime,
+    limit: i64,
+) -> StorageResult<Vec<Refund>> {
+    let mut query = <Refund>::table()
+        .filter(refund_dsl::merchant_id.eq(merchant_id.to_owned()))
+        .filter(refund_dsl::modified_at.le(modified_before))
+        .into_boxed();
+
+    if let Some((modified_at, refund_id)) = modified_after {
+        query = query.filter(
+            refund_dsl::modified_at
+                .gt(modified_at)
+                .or(refund_dsl::modified_at
+                    .eq(modified_at)
+                    .and(refund_dsl::refund_id.gt(refund_id))),
+        );
+    }
+
+    let query = query
+        .order((refund_dsl::modified_at.asc(), refund_dsl::refund_id.asc()))
+        .limit(limit);
+
+    logger::debug!(query = %debug_query::<diesel::pg::Pg,_>(&query).to_string());
+
+    query
+        .get_results_async(conn)
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while fetching modified refunds")
+}
+
+pub async fn find_disputes_modified_after(
+    conn: &PgPooledConn,
+    merchant_id: &common_utils::id_type::MerchantId,
+    modified_after: Option<(PrimitiveDateTime, String)>,
+    modified_before: PrimitiveDateTime,
+    limit: i64,
+) -> StorageResult<Vec<Dispute>> {
+    let mut query = <Dispute>::table()
+        .filter(dispute_dsl::merchant_id.eq(merchant_id.to_owned()))
+        .filter(dispute_dsl::modified_at.le(modified_before))
+        .into_boxed();
+
+    if let Some((modified_at, dispute_id)) = modified_after {
+        query = query.filter(
+            dispute_dsl::modified_at
+                .gt(modified_at)
+                .or(dispute_dsl::modified_at
+                    .eq(modified_at)
+                    .and(dispute_dsl::dispute_id.gt(dispute_id))),
+        );
+    }
+
+    let query = query
+        .order((
+            dispute_dsl::modified_at.asc(),
+            dispute_dsl::dispute_id.asc(),
+        ))
+        .limit(limit);
+
+    logger::debug!(query = %debug_query::<diesel::pg::Pg,_>(&query).to_string());
+
+    query
+        .get_results_async(conn)
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while fetching modified disputes")
+}
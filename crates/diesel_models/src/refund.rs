@@ -67,6 +67,8 @@ pub struct Refund {
     pub processor_transaction_data: Option<String>,
     pub issuer_error_code: Option<String>,
     pub issuer_error_message: Option<String>,
+    pub end_to_end_reference: Option<String>,
+    pub refund_destination: Option<storage_enums::RefundDestination>,
 }
 
 #[cfg(all(feature = "v2", feature = "refunds_v2"))]
@@ -165,6 +167,8 @@ pub struct RefundNew {
     pub split_refunds: Option<common_types::refunds::SplitRefund>,
     pub processor_refund_data: Option<String>,
     pub processor_transaction_data: Option<String>,
+    pub end_to_end_reference: Option<String>,
+    pub refund_destination: Option<storage_enums::RefundDestination>,
 }
 
 #[cfg(all(feature = "v2", feature = "refunds_v2"))]
@@ -214,6 +218,14 @@ pub struct RefundNew {
     pub processor_transaction_data: Option<String>,
 }
 
+/// Position in the refunds listed in the order of their creation
+#[derive(Clone, Debug)]
+pub struct RefundCreationCursor {
+    pub created_at: PrimitiveDateTime,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub refund_id: String,
+}
+
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "refunds_v2")))]
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub enum RefundUpdate {
@@ -222,7 +234,7 @@ pub enum RefundUpdate {
         refund_status: storage_enums::RefundStatus,
         sent_to_gateway: bool,
         refund_error_message: Option<String>,
-        refund_arn: String,
+        refund_arn: Option<String>,
         updated_by: String,
         processor_refund_data: Option<String>,
     },
@@ -256,6 +268,10 @@ pub enum RefundUpdate {
         refund_error_code: Option<String>,
         updated_by: String,
     },
+    AcquirerReferenceNumberUpdate {
+        refund_arn: String,
+        updated_by: String,
+    },
 }
 
 #[cfg(all(feature = "v2", feature = "refunds_v2"))]
@@ -266,7 +282,7 @@ pub enum RefundUpdate {
         refund_status: storage_enums::RefundStatus,
         sent_to_gateway: bool,
         refund_error_message: Option<String>,
-        refund_arn: String,
+        refund_arn: Option<String>,
         updated_by: String,
         processor_refund_data: Option<String>,
     },
@@ -401,7 +417,7 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 refund_status: Some(refund_status),
                 sent_to_gateway: Some(sent_to_gateway),
                 refund_error_message,
-                refund_arn: Some(refund_arn),
+                refund_arn,
                 updated_by,
                 processor_refund_data,
                 metadata: None,
@@ -507,6 +523,26 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 issuer_error_code: None,
                 issuer_error_message: None,
             },
+            RefundUpdate::AcquirerReferenceNumberUpdate {
+                refund_arn,
+                updated_by,
+            } => Self {
+                refund_arn: Some(refund_arn),
+                updated_by,
+                connector_refund_id: None,
+                refund_status: None,
+                sent_to_gateway: None,
+                refund_error_message: None,
+                metadata: None,
+                refund_reason: None,
+                refund_error_code: None,
+                modified_at: common_utils::date_time::now(),
+                processor_refund_data: None,
+                unified_code: None,
+                unified_message: None,
+                issuer_error_code: None,
+                issuer_error_message: None,
+            },
         }
     }
 }
@@ -528,7 +564,7 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 refund_status: Some(refund_status),
                 sent_to_gateway: Some(sent_to_gateway),
                 refund_error_message,
-                refund_arn: Some(refund_arn),
+                refund_arn,
                 updated_by,
                 processor_refund_data,
                 metadata: None,
@@ -704,6 +740,15 @@ impl RefundUpdate {
     }
 }
 
+impl RefundUpdate {
+    /// Returns whether the update changes the status of the refund
+    pub fn is_status_update(&self) -> bool {
+        RefundUpdateInternal::from(self.clone())
+            .refund_status
+            .is_some()
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Deserialize, Serialize)]
 pub struct RefundCoreWorkflow {
     pub refund_internal_reference_id: String,
@@ -31,6 +31,7 @@ diesel::table! {
         #[max_length = 32]
         updated_by -> Varchar,
         email -> Nullable<Bytea>,
+        normalized_address -> Nullable<Bytea>,
     }
 }
 
@@ -54,6 +55,11 @@ diesel::table! {
         created_at -> Timestamp,
         expires_at -> Nullable<Timestamp>,
         last_used -> Nullable<Timestamp>,
+        #[max_length = 16]
+        response_version -> Nullable<Varchar>,
+        test_mode -> Nullable<Bool>,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
     }
 }
 
@@ -123,6 +129,7 @@ diesel::table! {
         service_details -> Nullable<Jsonb>,
         #[max_length = 32]
         organization_id -> Varchar,
+        challenge_abandoned -> Nullable<Bool>,
     }
 }
 
@@ -221,6 +228,7 @@ diesel::table! {
         card_testing_secret_key -> Nullable<Bytea>,
         is_clear_pan_retries_enabled -> Bool,
         force_3ds_challenge -> Nullable<Bool>,
+        is_test_mode -> Nullable<Bool>,
     }
 }
 
@@ -618,6 +626,48 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    issuer_authentication_stats (card_bin) {
+        #[max_length = 8]
+        card_bin -> Varchar,
+        #[max_length = 255]
+        card_issuer -> Nullable<Varchar>,
+        frictionless_count -> Int8,
+        challenge_count -> Int8,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    ledger_entry (entry_id) {
+        #[max_length = 64]
+        entry_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        entry_type -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        dispute_id -> Varchar,
+        dispute_stage -> DisputeStage,
+        #[max_length = 255]
+        connector -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -745,6 +795,8 @@ diesel::table! {
         id -> Nullable<Varchar>,
         #[max_length = 64]
         product_type -> Nullable<Varchar>,
+        #[max_length = 64]
+        status -> Varchar,
     }
 }
 
@@ -925,6 +977,38 @@ diesel::table! {
         #[max_length = 64]
         issuer_error_code -> Nullable<Varchar>,
         issuer_error_message -> Nullable<Text>,
+        overcaptured_amount -> Nullable<Int8>,
+        #[max_length = 64]
+        connector_idempotency_key -> Nullable<Varchar>,
+        #[max_length = 32]
+        avs_result -> Nullable<Varchar>,
+        #[max_length = 32]
+        cvv_result -> Nullable<Varchar>,
+        #[max_length = 64]
+        acquirer_reference_number -> Nullable<Varchar>,
+        #[max_length = 32]
+        card_credential_type -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_custom_field (merchant_id, payment_id, field_key) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        field_key -> Varchar,
+        #[max_length = 64]
+        field_type -> Varchar,
+        #[max_length = 255]
+        field_value -> Varchar,
+        created_at -> Timestamp,
     }
 }
 
@@ -1010,6 +1094,15 @@ diesel::table! {
         split_payments -> Nullable<Jsonb>,
         #[max_length = 64]
         platform_merchant_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        customer_email_hash -> Nullable<Varchar>,
+        #[max_length = 64]
+        customer_phone_hash -> Nullable<Varchar>,
+        change_sequence -> Nullable<Int8>,
+        is_amount_estimated -> Nullable<Bool>,
+        enable_partial_payments -> Nullable<Bool>,
+        store_credit_amount -> Nullable<Int8>,
+        is_test_payment -> Nullable<Bool>,
     }
 }
 
@@ -1043,6 +1136,22 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_method_consent_event (event_id) {
+        #[max_length = 64]
+        event_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_method_id -> Varchar,
+        event_data -> Jsonb,
+        recorded_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1104,6 +1213,24 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_note (note_id) {
+        #[max_length = 64]
+        note_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        author -> Varchar,
+        note -> Text,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1282,6 +1409,10 @@ diesel::table! {
         #[max_length = 64]
         issuer_error_code -> Nullable<Varchar>,
         issuer_error_message -> Nullable<Text>,
+        #[max_length = 140]
+        end_to_end_reference -> Nullable<Varchar>,
+        #[max_length = 32]
+        refund_destination -> Nullable<Varchar>,
     }
 }
 
@@ -1385,6 +1516,30 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    store_credit_entry (entry_id) {
+        #[max_length = 64]
+        entry_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 64]
+        entry_type -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        refund_id -> Nullable<Varchar>,
+        debit_sequence -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1551,6 +1706,8 @@ diesel::allow_tables_to_appear_in_same_query!(
     gateway_status_map,
     generic_link,
     incremental_authorization,
+    issuer_authentication_stats,
+    ledger_entry,
     locker_mock_up,
     mandate,
     merchant_account,
@@ -1558,9 +1715,12 @@ diesel::allow_tables_to_appear_in_same_query!(
     merchant_key_store,
     organization,
     payment_attempt,
+    payment_custom_field,
     payment_intent,
     payment_link,
+    payment_method_consent_event,
     payment_methods,
+    payment_note,
     payout_attempt,
     payouts,
     process_tracker,
@@ -1569,6 +1729,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     reverse_lookup,
     roles,
     routing_algorithm,
+    store_credit_entry,
     themes,
     unified_translations,
     user_authentication_methods,
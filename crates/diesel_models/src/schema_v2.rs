@@ -31,6 +31,7 @@ diesel::table! {
         #[max_length = 32]
         updated_by -> Varchar,
         email -> Nullable<Bytea>,
+        normalized_address -> Nullable<Bytea>,
     }
 }
 
@@ -54,6 +55,11 @@ diesel::table! {
         created_at -> Timestamp,
         expires_at -> Nullable<Timestamp>,
         last_used -> Nullable<Timestamp>,
+        #[max_length = 16]
+        response_version -> Nullable<Varchar>,
+        test_mode -> Nullable<Bool>,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
     }
 }
 
@@ -123,6 +129,7 @@ diesel::table! {
         service_details -> Nullable<Jsonb>,
         #[max_length = 32]
         organization_id -> Varchar,
+        challenge_abandoned -> Nullable<Bool>,
     }
 }
 
@@ -629,6 +636,48 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    issuer_authentication_stats (card_bin) {
+        #[max_length = 8]
+        card_bin -> Varchar,
+        #[max_length = 255]
+        card_issuer -> Nullable<Varchar>,
+        frictionless_count -> Int8,
+        challenge_count -> Int8,
+        created_at -> Timestamp,
+        last_modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    ledger_entry (entry_id) {
+        #[max_length = 64]
+        entry_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        entry_type -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        dispute_id -> Varchar,
+        dispute_stage -> DisputeStage,
+        #[max_length = 255]
+        connector -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -733,6 +782,8 @@ diesel::table! {
         id -> Varchar,
         #[max_length = 64]
         product_type -> Nullable<Varchar>,
+        #[max_length = 64]
+        status -> Varchar,
     }
 }
 
@@ -890,6 +941,27 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_custom_field (merchant_id, payment_id, field_key) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        field_key -> Varchar,
+        #[max_length = 64]
+        field_type -> Varchar,
+        #[max_length = 255]
+        field_value -> Varchar,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -998,6 +1070,22 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_method_consent_event (event_id) {
+        #[max_length = 64]
+        event_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_method_id -> Varchar,
+        event_data -> Jsonb,
+        recorded_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1041,6 +1129,24 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_note (note_id) {
+        #[max_length = 64]
+        note_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        author -> Varchar,
+        note -> Text,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1315,6 +1421,30 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    store_credit_entry (entry_id) {
+        #[max_length = 64]
+        entry_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 64]
+        entry_type -> Varchar,
+        amount -> Int8,
+        currency -> Currency,
+        #[max_length = 64]
+        payment_id -> Varchar,
+        #[max_length = 64]
+        refund_id -> Nullable<Varchar>,
+        debit_sequence -> Int4,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1481,6 +1611,8 @@ diesel::allow_tables_to_appear_in_same_query!(
     gateway_status_map,
     generic_link,
     incremental_authorization,
+    issuer_authentication_stats,
+    ledger_entry,
     locker_mock_up,
     mandate,
     merchant_account,
@@ -1488,9 +1620,12 @@ diesel::allow_tables_to_appear_in_same_query!(
     merchant_key_store,
     organization,
     payment_attempt,
+    payment_custom_field,
     payment_intent,
     payment_link,
+    payment_method_consent_event,
     payment_methods,
+    payment_note,
     payout_attempt,
     payouts,
     process_tracker,
@@ -1499,6 +1634,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     reverse_lookup,
     roles,
     routing_algorithm,
+    store_credit_entry,
     themes,
     unified_translations,
     user_authentication_methods,
@@ -0,0 +1,37 @@
+use common_utils::{custom_serde, id_type, types::MinorUnit};
+use diesel::{Identifiable, Insertable, Queryable, Selectable};
+use serde::Serialize;
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::store_credit_entry};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = store_credit_entry)]
+pub struct StoreCreditEntryNew {
+    pub entry_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub customer_id: id_type::CustomerId,
+    pub entry_type: storage_enums::StoreCreditEntryType,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub payment_id: id_type::PaymentId,
+    pub refund_id: Option<String>,
+    pub debit_sequence: i32,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = store_credit_entry, primary_key(entry_id), check_for_backend(diesel::pg::Pg))]
+pub struct StoreCreditEntry {
+    pub entry_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub customer_id: id_type::CustomerId,
+    pub entry_type: storage_enums::StoreCreditEntryType,
+    pub amount: MinorUnit,
+    pub currency: storage_enums::Currency,
+    pub payment_id: id_type::PaymentId,
+    pub refund_id: Option<String>,
+    pub debit_sequence: i32,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
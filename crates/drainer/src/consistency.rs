@@ -0,0 +1,338 @@
+//! Consistency checker for the entries drained from the KV store. A sample of the drained entries
+//! is recorded in Redis, and is periodically compared with the rows in Postgres. The fields which
+//! differ are reported to the metrics and recorded in Redis, from where they can be retrieved
+//! through the admin API of the router.
+
+use diesel_models::{
+    kv::{self, DrainedEntry, KvPgDivergence, DRAINED_ENTRY_SAMPLES_KEY, KV_PG_DIVERGENCES_KEY},
+    PaymentAttempt, Refund,
+};
+use router_env::{instrument, logger, tracing};
+use serde::Serialize;
+
+use crate::{metrics, pg_connection, Store};
+
+/// Time for which the divergences found by the consistency checker are retained in Redis
+const KV_PG_DIVERGENCES_EXPIRY_SECONDS: i64 = 7 * 24 * 60 * 60; // 7 days
+
+fn get_drained_entry(result: &kv::DBResult) -> Option<DrainedEntry> {
+    match result {
+        kv::DBResult::PaymentAttempt(payment_attempt) => Some(DrainedEntry::PaymentAttempt {
+            merchant_id: payment_attempt.merchant_id.clone(),
+            payment_id: payment_attempt.payment_id.clone(),
+            attempt_id: payment_attempt.attempt_id.clone(),
+        }),
+        kv::DBResult::Refund(refund) => Some(DrainedEntry::Refund {
+            merchant_id: refund.merchant_id.clone(),
+            payment_id: refund.payment_id.clone(),
+            attempt_id: refund.attempt_id.clone(),
+            refund_id: refund.refund_id.clone(),
+        }),
+        _ => None,
+    }
+}
+
+/// Records the drained entry for the consistency check, if it is picked in the sample. Failures
+/// are only logged, as the consistency check should never affect the draining of the stream.
+#[instrument(skip_all)]
+pub async fn sample_drained_entry(store: &Store, result: &kv::DBResult) {
+    let consistency_check = &store.config.consistency_check;
+    if !consistency_check.enabled {
+        return;
+    }
+
+    let drained_entries = store
+        .drained_entries
+        .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    if drained_entries % consistency_check.sample_interval != 0 {
+        return;
+    }
+
+    let Some(entry) = get_drained_entry(result) else {
+        return;
+    };
+
+    let key = DRAINED_ENTRY_SAMPLES_KEY.into();
+    match store.redis_conn.get_list_length(&key).await {
+        Ok(length) if length >= consistency_check.max_samples => {
+            logger::warn!("Maximum number of drained entries are already sampled");
+            return;
+        }
+        Ok(_) => (),
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch the sampled drained entries");
+            return;
+        }
+    }
+
+    let serialized_entry = match serde_json::to_string(&entry) {
+        Ok(serialized_entry) => serialized_entry,
+        Err(error) => {
+            logger::error!(?error, "Failed to serialize the drained entry");
+            return;
+        }
+    };
+
+    match store
+        .redis_conn
+        .append_elements_to_list(&key, serialized_entry)
+        .await
+    {
+        Ok(()) => metrics::DRAINED_ENTRIES_SAMPLED
+            .add(1, router_env::metric_attributes!(("table", entry.table()))),
+        Err(error) => logger::error!(?error, "Failed to sample the drained entry"),
+    }
+}
+
+/// Compares the sampled drained entries with their rows in Postgres, and records the
+/// divergences found
+#[instrument(skip_all)]
+pub async fn verify_drained_entry_samples(store: &Store) {
+    let serialized_entries = match store
+        .redis_conn
+        .lpop_list_elements(
+            &DRAINED_ENTRY_SAMPLES_KEY.into(),
+            Some(store.config.consistency_check.batch_size),
+        )
+        .await
+    {
+        Ok(serialized_entries) => serialized_entries,
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch the sampled drained entries");
+            return;
+        }
+    };
+
+    for serialized_entry in serialized_entries {
+        let entry = match serde_json::from_str::<DrainedEntry>(&serialized_entry) {
+            Ok(entry) => entry,
+            Err(error) => {
+                logger::error!(?error, "Failed to deserialize the sampled drained entry");
+                continue;
+            }
+        };
+
+        let divergences = verify_drained_entry(store, &entry).await;
+        metrics::DRAINED_ENTRIES_VERIFIED
+            .add(1, router_env::metric_attributes!(("table", entry.table())));
+
+        for divergence in divergences {
+            logger::error!(
+                table = %divergence.table,
+                object_id = %divergence.object_id,
+                field = %divergence.field,
+                kv_value = %divergence.kv_value,
+                pg_value = %divergence.pg_value,
+                "KV store and Postgres are inconsistent"
+            );
+            metrics::KV_PG_DIVERGENCES.add(
+                1,
+                router_env::metric_attributes!(
+                    ("table", entry.table()),
+                    ("field", divergence.field.clone())
+                ),
+            );
+            record_divergence(store, divergence).await;
+        }
+    }
+}
+
+/// Serializes the value of a field, so that the fields of different types can be compared
+fn get_field_value<T: Serialize>(value: &T) -> String {
+    serde_json::to_string(value).unwrap_or_default()
+}
+
+fn get_divergences(
+    entry: &DrainedEntry,
+    fields: Vec<(&'static str, String, String)>,
+) -> Vec<KvPgDivergence> {
+    let detected_at = common_utils::date_time::now();
+    fields
+        .into_iter()
+        .filter(|(_, kv_value, pg_value)| kv_value != pg_value)
+        .map(|(field, kv_value, pg_value)| KvPgDivergence {
+            table: entry.table().to_string(),
+            merchant_id: entry.merchant_id().clone(),
+            object_id: entry.object_id().to_string(),
+            field: field.to_string(),
+            kv_value,
+            pg_value,
+            detected_at,
+        })
+        .collect()
+}
+
+async fn verify_drained_entry(store: &Store, entry: &DrainedEntry) -> Vec<KvPgDivergence> {
+    let (key, field) = entry.get_redis_key_and_field();
+    let conn = pg_connection(&store.master_pool).await;
+
+    match entry {
+        DrainedEntry::PaymentAttempt {
+            merchant_id,
+            attempt_id,
+            ..
+        } => {
+            let kv_attempt = match store
+                .redis_conn
+                .get_hash_field_and_deserialize::<PaymentAttempt>(
+                    &key.as_str().into(),
+                    &field,
+                    "PaymentAttempt",
+                )
+                .await
+            {
+                Ok(kv_attempt) => kv_attempt,
+                // The entry is no longer held in the KV store, and there is nothing to compare
+                Err(error) => {
+                    logger::debug!(?error, "Failed to fetch the payment attempt from redis");
+                    return Vec::new();
+                }
+            };
+            let pg_attempt = match PaymentAttempt::find_by_merchant_id_attempt_id(
+                &conn,
+                merchant_id,
+                attempt_id,
+            )
+            .await
+            {
+                Ok(pg_attempt) => pg_attempt,
+                Err(error) => {
+                    logger::error!(?error, "Failed to fetch the payment attempt from postgres");
+                    return Vec::new();
+                }
+            };
+
+            // A newer update of the entry is yet to be drained
+            if kv_attempt.modified_at > pg_attempt.modified_at {
+                return Vec::new();
+            }
+
+            get_divergences(
+                entry,
+                vec![
+                    (
+                        "status",
+                        get_field_value(&kv_attempt.status),
+                        get_field_value(&pg_attempt.status),
+                    ),
+                    (
+                        "amount",
+                        get_field_value(&kv_attempt.amount),
+                        get_field_value(&pg_attempt.amount),
+                    ),
+                    (
+                        "amount_capturable",
+                        get_field_value(&kv_attempt.amount_capturable),
+                        get_field_value(&pg_attempt.amount_capturable),
+                    ),
+                    (
+                        "amount_to_capture",
+                        get_field_value(&kv_attempt.amount_to_capture),
+                        get_field_value(&pg_attempt.amount_to_capture),
+                    ),
+                    (
+                        "net_amount",
+                        get_field_value(&kv_attempt.net_amount),
+                        get_field_value(&pg_attempt.net_amount),
+                    ),
+                ],
+            )
+        }
+        DrainedEntry::Refund {
+            merchant_id,
+            refund_id,
+            ..
+        } => {
+            let kv_refund = match store
+                .redis_conn
+                .get_hash_field_and_deserialize::<Refund>(&key.as_str().into(), &field, "Refund")
+                .await
+            {
+                Ok(kv_refund) => kv_refund,
+                // The entry is no longer held in the KV store, and there is nothing to compare
+                Err(error) => {
+                    logger::debug!(?error, "Failed to fetch the refund from redis");
+                    return Vec::new();
+                }
+            };
+            let pg_refund =
+                match Refund::find_by_merchant_id_refund_id(&conn, merchant_id, refund_id).await {
+                    Ok(pg_refund) => pg_refund,
+                    Err(error) => {
+                        logger::error!(?error, "Failed to fetch the refund from postgres");
+                        return Vec::new();
+                    }
+                };
+
+            // A newer update of the entry is yet to be drained
+            if kv_refund.modified_at > pg_refund.modified_at {
+                return Vec::new();
+            }
+
+            get_divergences(
+                entry,
+                vec![
+                    (
+                        "refund_status",
+                        get_field_value(&kv_refund.refund_status),
+                        get_field_value(&pg_refund.refund_status),
+                    ),
+                    (
+                        "refund_amount",
+                        get_field_value(&kv_refund.refund_amount),
+                        get_field_value(&pg_refund.refund_amount),
+                    ),
+                    (
+                        "total_amount",
+                        get_field_value(&kv_refund.total_amount),
+                        get_field_value(&pg_refund.total_amount),
+                    ),
+                ],
+            )
+        }
+    }
+}
+
+/// Records the divergence in Redis, dropping the oldest divergences beyond the configured limit
+async fn record_divergence(store: &Store, divergence: KvPgDivergence) {
+    let serialized_divergence = match serde_json::to_string(&divergence) {
+        Ok(serialized_divergence) => serialized_divergence,
+        Err(error) => {
+            logger::error!(?error, "Failed to serialize the divergence");
+            return;
+        }
+    };
+
+    let key = KV_PG_DIVERGENCES_KEY.into();
+    if let Err(error) = store
+        .redis_conn
+        .append_elements_to_list(&key, serialized_divergence)
+        .await
+    {
+        logger::error!(?error, "Failed to record the divergence");
+        return;
+    }
+
+    if let Err(error) = store
+        .redis_conn
+        .set_expiry(&key, KV_PG_DIVERGENCES_EXPIRY_SECONDS)
+        .await
+    {
+        logger::error!(?error, "Failed to set expiry for the divergences");
+    }
+
+    match store.redis_conn.get_list_length(&key).await {
+        Ok(length) if length > store.config.consistency_check.max_divergences => {
+            let excess = length - store.config.consistency_check.max_divergences;
+            if let Err(error) = store
+                .redis_conn
+                .lpop_list_elements(&key, Some(excess))
+                .await
+            {
+                logger::error!(?error, "Failed to drop the oldest divergences");
+            }
+        }
+        Ok(_) => (),
+        Err(error) => logger::error!(?error, "Failed to fetch the number of divergences"),
+    }
+}
@@ -4,12 +4,15 @@ use std::{
 };
 
 use common_utils::id_type;
+use diesel_models::kv::DrainerLanePriority;
 use router_env::tracing::Instrument;
 use tokio::{
     sync::{mpsc, oneshot},
     time::{self, Duration},
 };
 
+#[cfg(feature = "v1")]
+use crate::consistency;
 use crate::{
     errors, instrument, logger, metrics, query::ExecuteQuery, tracing, utils, DrainerSettings,
     Store, StreamData,
@@ -34,6 +37,8 @@ pub struct HandlerInner {
     shutdown_interval: Duration,
     loop_interval: Duration,
     active_tasks: Arc<atomic::AtomicU64>,
+    high_priority_tasks: Arc<atomic::AtomicU64>,
+    low_priority_tasks: Arc<atomic::AtomicU64>,
     conf: DrainerSettings,
     stores: HashMap<id_type::TenantId, Arc<Store>>,
     running: Arc<atomic::AtomicBool>,
@@ -48,6 +53,8 @@ impl Handler {
         let loop_interval = Duration::from_millis(conf.loop_interval.into());
 
         let active_tasks = Arc::new(atomic::AtomicU64::new(0));
+        let high_priority_tasks = Arc::new(atomic::AtomicU64::new(0));
+        let low_priority_tasks = Arc::new(atomic::AtomicU64::new(0));
 
         let running = Arc::new(atomic::AtomicBool::new(true));
 
@@ -55,6 +62,8 @@ impl Handler {
             shutdown_interval,
             loop_interval,
             active_tasks,
+            high_priority_tasks,
+            low_priority_tasks,
             conf,
             stores,
             running,
@@ -69,6 +78,36 @@ impl Handler {
         self.running.store(false, atomic::Ordering::SeqCst);
     }
 
+    /// Returns the lanes to be drained, the high priority lane being the stream itself
+    fn get_lanes(&self) -> &'static [DrainerLanePriority] {
+        if self.conf.priority_lanes.enabled {
+            &[DrainerLanePriority::High, DrainerLanePriority::Low]
+        } else {
+            &[DrainerLanePriority::High]
+        }
+    }
+
+    fn get_lane_tasks(&self, lane: DrainerLanePriority) -> &Arc<atomic::AtomicU64> {
+        match lane {
+            DrainerLanePriority::High => &self.high_priority_tasks,
+            DrainerLanePriority::Low => &self.low_priority_tasks,
+        }
+    }
+
+    /// Indicates whether the lane has reached its limit of active tasks, in which case it is not
+    /// picked until some of its tasks complete
+    fn is_lane_concurrency_limit_reached(&self, lane: DrainerLanePriority) -> bool {
+        if !self.conf.priority_lanes.enabled {
+            return false;
+        }
+
+        let concurrency = match lane {
+            DrainerLanePriority::High => self.conf.priority_lanes.high_priority_concurrency,
+            DrainerLanePriority::Low => self.conf.priority_lanes.low_priority_concurrency,
+        };
+        self.get_lane_tasks(lane).load(atomic::Ordering::SeqCst) >= concurrency
+    }
+
     pub async fn spawn(&self) -> errors::DrainerResult<()> {
         let mut stream_index: u8 = 0;
         let jobs_picked = Arc::new(atomic::AtomicU8::new(0));
@@ -76,17 +115,26 @@ impl Handler {
         while self.running.load(atomic::Ordering::SeqCst) {
             metrics::DRAINER_HEALTH.add(1, &[]);
             for store in self.stores.values() {
-                if store.is_stream_available(stream_index).await {
-                    let _task_handle = tokio::spawn(
-                        drainer_handler(
-                            store.clone(),
-                            stream_index,
-                            self.conf.max_read_count,
-                            self.active_tasks.clone(),
-                            jobs_picked.clone(),
-                        )
-                        .in_current_span(),
-                    );
+                for &lane in self.get_lanes() {
+                    if self.is_lane_concurrency_limit_reached(lane) {
+                        metrics::LANE_CONCURRENCY_LIMIT_REACHED
+                            .add(1, router_env::metric_attributes!(("lane", lane.as_str())));
+                        continue;
+                    }
+
+                    if store.is_stream_available(stream_index, lane).await {
+                        let _task_handle = tokio::spawn(
+                            drainer_handler(
+                                store.clone(),
+                                stream_index,
+                                lane,
+                                self.conf.max_read_count,
+                                (self.active_tasks.clone(), self.get_lane_tasks(lane).clone()),
+                                jobs_picked.clone(),
+                            )
+                            .in_current_span(),
+                        );
+                    }
                 }
             }
             stream_index = utils::increment_stream_index(
@@ -100,6 +148,30 @@ impl Handler {
         Ok(())
     }
 
+    /// Spawns the consistency checker of each store, which runs until the drainer is shut down
+    #[cfg(feature = "v1")]
+    pub fn spawn_consistency_checkers(&self) {
+        if !self.conf.consistency_check.enabled {
+            return;
+        }
+
+        let check_interval =
+            Duration::from_millis(self.conf.consistency_check.check_interval.into());
+        for store in self.stores.values() {
+            let store = store.clone();
+            let running = self.running.clone();
+            let _task_handle = tokio::spawn(
+                async move {
+                    while running.load(atomic::Ordering::SeqCst) {
+                        time::sleep(check_interval).await;
+                        consistency::verify_drained_entry_samples(&store).await;
+                    }
+                }
+                .in_current_span(),
+            );
+        }
+    }
+
     pub(crate) async fn shutdown_listener(&self, mut rx: mpsc::Receiver<()>) {
         while let Some(_c) = rx.recv().await {
             logger::info!("Awaiting shutdown!");
@@ -173,13 +245,15 @@ pub async fn redis_error_receiver(rx: oneshot::Receiver<()>, shutdown_channel: m
 async fn drainer_handler(
     store: Arc<Store>,
     stream_index: u8,
+    lane: DrainerLanePriority,
     max_read_count: u64,
-    active_tasks: Arc<atomic::AtomicU64>,
+    (active_tasks, lane_tasks): (Arc<atomic::AtomicU64>, Arc<atomic::AtomicU64>),
     jobs_picked: Arc<atomic::AtomicU8>,
 ) -> errors::DrainerResult<()> {
     active_tasks.fetch_add(1, atomic::Ordering::Release);
+    lane_tasks.fetch_add(1, atomic::Ordering::Release);
 
-    let stream_name = store.get_drainer_stream_name(stream_index);
+    let stream_name = store.get_drainer_stream_name(stream_index, lane);
 
     let drainer_result = Box::pin(drainer(
         store.clone(),
@@ -193,9 +267,14 @@ async fn drainer_handler(
         logger::error!(?error)
     }
 
-    let flag_stream_name = store.get_stream_key_flag(stream_index);
+    if store.config.priority_lanes_enabled {
+        store.record_stream_length(stream_name.as_str(), lane).await;
+    }
+
+    let flag_stream_name = store.get_stream_key_flag(stream_index, lane);
 
     let output = store.make_stream_available(flag_stream_name.as_str()).await;
+    lane_tasks.fetch_sub(1, atomic::Ordering::Release);
     active_tasks.fetch_sub(1, atomic::Ordering::Release);
     output.inspect_err(|err| logger::error!(operation = "unlock_stream", err=?err))
 }
@@ -264,7 +343,9 @@ async fn drainer(
         tracing::Span::current().record("session_id", &session_id);
 
         match data.typed_sql.execute_query(&store, data.pushed_at).await {
-            Ok(_) => {
+            Ok(_result) => {
+                #[cfg(feature = "v1")]
+                consistency::sample_drained_entry(&store, &_result).await;
                 last_processed_id = entry_id;
             }
             Err(err) => match err.current_context() {
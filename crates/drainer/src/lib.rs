@@ -1,4 +1,6 @@
 mod connection;
+#[cfg(feature = "v1")]
+mod consistency;
 pub mod errors;
 mod handler;
 mod health_check;
@@ -50,6 +52,8 @@ pub async fn start_drainer(
     tokio::task::spawn(async move { handler_clone.shutdown_listener(rx).await });
 
     drainer_handler.spawn_error_handlers(tx)?;
+    #[cfg(feature = "v1")]
+    drainer_handler.spawn_consistency_checkers();
     drainer_handler.spawn().await?;
 
     handle.close();
@@ -1,4 +1,6 @@
-use router_env::{counter_metric, global_meter, histogram_metric_f64, histogram_metric_u64};
+use router_env::{
+    counter_metric, gauge_metric, global_meter, histogram_metric_f64, histogram_metric_u64,
+};
 
 global_meter!(DRAINER_METER, "DRAINER");
 
@@ -12,6 +14,12 @@ counter_metric!(SUCCESSFUL_SHUTDOWN, DRAINER_METER);
 counter_metric!(STREAM_EMPTY, DRAINER_METER);
 counter_metric!(STREAM_PARSE_FAIL, DRAINER_METER);
 counter_metric!(DRAINER_HEALTH, DRAINER_METER);
+counter_metric!(DRAINED_ENTRIES_SAMPLED, DRAINER_METER);
+counter_metric!(DRAINED_ENTRIES_VERIFIED, DRAINER_METER);
+counter_metric!(KV_PG_DIVERGENCES, DRAINER_METER);
+counter_metric!(LANE_CONCURRENCY_LIMIT_REACHED, DRAINER_METER);
+
+gauge_metric!(LANE_STREAM_LENGTH, DRAINER_METER); // Number of entries pending in the lane
 
 histogram_metric_f64!(QUERY_EXECUTION_TIME, DRAINER_METER); // Time in (ms) milliseconds
 histogram_metric_f64!(REDIS_STREAM_READ_TIME, DRAINER_METER); // Time in (ms) milliseconds
@@ -11,7 +11,7 @@ pub trait ExecuteQuery {
         self,
         store: &Arc<Store>,
         pushed_at: i64,
-    ) -> CustomResult<(), DatabaseError>;
+    ) -> CustomResult<kv::DBResult, DatabaseError>;
 }
 
 #[async_trait::async_trait]
@@ -20,7 +20,7 @@ impl ExecuteQuery for kv::DBOperation {
         self,
         store: &Arc<Store>,
         pushed_at: i64,
-    ) -> CustomResult<(), DatabaseError> {
+    ) -> CustomResult<kv::DBResult, DatabaseError> {
         let conn = pg_connection(&store.master_pool).await;
         let operation = self.operation();
         let table = self.table();
@@ -37,7 +37,7 @@ impl ExecuteQuery for kv::DBOperation {
             Ok(result) => {
                 logger::info!(operation = operation, table = table, ?result);
                 metrics::SUCCESSFUL_QUERY_EXECUTION.add(1, tags);
-                Ok(())
+                Ok(result)
             }
             Err(err) => {
                 logger::error!(operation = operation, table = table, ?err);
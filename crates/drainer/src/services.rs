@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::sync::{atomic::AtomicU64, Arc};
 
 use actix_web::{body, HttpResponse, ResponseError};
 use error_stack::Report;
@@ -7,7 +7,7 @@ use redis_interface::RedisConnectionPool;
 use crate::{
     connection::{diesel_make_pg_pool, PgPool},
     logger,
-    settings::Tenant,
+    settings::{ConsistencyCheckSettings, Tenant},
 };
 
 #[derive(Clone)]
@@ -16,12 +16,16 @@ pub struct Store {
     pub redis_conn: Arc<RedisConnectionPool>,
     pub config: StoreConfig,
     pub request_id: Option<String>,
+    /// Number of entries drained by the store, used to sample the entries for the consistency check
+    pub drained_entries: Arc<AtomicU64>,
 }
 
 #[derive(Clone)]
 pub struct StoreConfig {
     pub drainer_stream_name: String,
     pub drainer_num_partitions: u8,
+    pub consistency_check: ConsistencyCheckSettings,
+    pub priority_lanes_enabled: bool,
 }
 
 impl Store {
@@ -45,8 +49,11 @@ impl Store {
             config: StoreConfig {
                 drainer_stream_name: config.drainer.stream_name.clone(),
                 drainer_num_partitions: config.drainer.num_partitions,
+                consistency_check: config.drainer.consistency_check.clone(),
+                priority_lanes_enabled: config.drainer.priority_lanes.enabled,
             },
             request_id: None,
+            drained_entries: Arc::new(AtomicU64::new(0)),
         }
     }
 }
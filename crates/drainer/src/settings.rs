@@ -114,6 +114,33 @@ pub struct DrainerSettings {
     pub max_read_count: u64,
     pub shutdown_interval: u32, // in milliseconds
     pub loop_interval: u32,     // in milliseconds
+    pub consistency_check: ConsistencyCheckSettings,
+    pub priority_lanes: PriorityLanesSettings,
+}
+
+/// Settings of the priority lanes of the drainer stream. When enabled, the inserts and the updates
+/// which change the status of a payment, refund or payout are drained from the high priority lane,
+/// while the other updates are drained from the low priority lane. The low priority lane is not picked while the
+/// configured number of its tasks are active, so that it can not hold back the high priority lane.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct PriorityLanesSettings {
+    pub enabled: bool,
+    pub high_priority_concurrency: u64, // maximum number of active tasks of the high priority lane
+    pub low_priority_concurrency: u64,  // maximum number of active tasks of the low priority lane
+}
+
+/// Settings of the consistency checker, which compares a sample of the entries drained from the
+/// KV store with their rows in Postgres
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ConsistencyCheckSettings {
+    pub enabled: bool,
+    pub sample_interval: u64, // one in every `sample_interval` drained entries is verified
+    pub check_interval: u32,  // in milliseconds
+    pub batch_size: usize,
+    pub max_samples: usize,
+    pub max_divergences: usize,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -224,6 +251,31 @@ impl Default for DrainerSettings {
             max_read_count: 100,
             shutdown_interval: 1000, // in milliseconds
             loop_interval: 100,      // in milliseconds
+            consistency_check: ConsistencyCheckSettings::default(),
+            priority_lanes: PriorityLanesSettings::default(),
+        }
+    }
+}
+
+impl Default for PriorityLanesSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            high_priority_concurrency: 64,
+            low_priority_concurrency: 16,
+        }
+    }
+}
+
+impl Default for ConsistencyCheckSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            sample_interval: 100,
+            check_interval: 60_000, // in milliseconds
+            batch_size: 100,
+            max_samples: 10_000,
+            max_divergences: 1000,
         }
     }
 }
@@ -274,7 +326,27 @@ impl DrainerSettings {
             Err(errors::DrainerError::ConfigParsingError(
                 "drainer stream name must not be empty".into(),
             ))
-        })
+        })?;
+
+        common_utils::fp_utils::when(
+            self.consistency_check.enabled && self.consistency_check.sample_interval == 0,
+            || {
+                Err(errors::DrainerError::ConfigParsingError(
+                    "consistency check sample interval must be greater than zero".into(),
+                ))
+            },
+        )?;
+
+        common_utils::fp_utils::when(
+            self.priority_lanes.enabled
+                && (self.priority_lanes.high_priority_concurrency == 0
+                    || self.priority_lanes.low_priority_concurrency == 0),
+            || {
+                Err(errors::DrainerError::ConfigParsingError(
+                    "priority lane concurrency must be greater than zero".into(),
+                ))
+            },
+        )
     }
 }
 
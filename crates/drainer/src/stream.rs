@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use diesel_models::kv::DrainerLanePriority;
 use redis_interface as redis;
 use router_env::{logger, tracing};
 
@@ -16,18 +17,30 @@ impl Store {
     }
 
     #[inline(always)]
-    pub(crate) fn get_stream_key_flag(&self, stream_index: u8) -> String {
-        format!("{}_in_use", self.get_drainer_stream_name(stream_index))
+    pub(crate) fn get_stream_key_flag(
+        &self,
+        stream_index: u8,
+        lane: DrainerLanePriority,
+    ) -> String {
+        format!(
+            "{}_in_use",
+            self.get_drainer_stream_name(stream_index, lane)
+        )
     }
 
     #[inline(always)]
-    pub(crate) fn get_drainer_stream_name(&self, stream_index: u8) -> String {
-        self.drainer_stream(format!("shard_{stream_index}").as_str())
+    pub(crate) fn get_drainer_stream_name(
+        &self,
+        stream_index: u8,
+        lane: DrainerLanePriority,
+    ) -> String {
+        // Example: {shard_5}_drainer_stream_low_priority
+        lane.get_lane_stream_name(&self.drainer_stream(format!("shard_{stream_index}").as_str()))
     }
 
     #[router_env::instrument(skip_all)]
-    pub async fn is_stream_available(&self, stream_index: u8) -> bool {
-        let stream_key_flag = self.get_stream_key_flag(stream_index);
+    pub async fn is_stream_available(&self, stream_index: u8, lane: DrainerLanePriority) -> bool {
+        let stream_key_flag = self.get_stream_key_flag(stream_index, lane);
 
         match self
             .redis_conn
@@ -53,6 +66,21 @@ impl Store {
         }
     }
 
+    /// Records the number of entries pending in the lane of the stream, which indicates how far
+    /// the lane lags behind
+    pub async fn record_stream_length(&self, stream_name: &str, lane: DrainerLanePriority) {
+        match self.redis_conn.stream_get_length(&stream_name.into()).await {
+            Ok(length) => metrics::LANE_STREAM_LENGTH.record(
+                u64::try_from(length).unwrap_or(u64::MAX),
+                router_env::metric_attributes!(
+                    ("stream", stream_name.to_owned()),
+                    ("lane", lane.as_str())
+                ),
+            ),
+            Err(error) => logger::error!(operation = "stream_length", err=?error),
+        }
+    }
+
     pub async fn read_from_stream(
         &self,
         stream_name: &str,
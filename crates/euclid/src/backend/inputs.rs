@@ -15,6 +15,8 @@ pub struct PaymentMethodInput {
     pub payment_method: Option<enums::PaymentMethod>,
     pub payment_method_type: Option<enums::PaymentMethodType>,
     pub card_network: Option<enums::CardNetwork>,
+    /// Product type of the card, derived from its BIN
+    pub card_product_type: Option<enums::CardProductType>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -28,6 +30,8 @@ pub struct PaymentInput {
     pub billing_country: Option<enums::Country>,
     pub business_label: Option<String>,
     pub setup_future_usage: Option<enums::SetupFutureUsage>,
+    /// Percentage of the past 3DS authentications of the card BIN completed without a challenge
+    pub issuer_frictionless_rate: Option<common_utils::types::MinorUnit>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -74,6 +74,13 @@ impl From<inputs::BackendInput> for Context {
                 EuclidKey::PaymentCurrency.to_string(),
                 Some(ValueType::EnumVariant(input.payment.currency.to_string())),
             ),
+            (
+                EuclidKey::IssuerFrictionlessRate.to_string(),
+                input
+                    .payment
+                    .issuer_frictionless_rate
+                    .map(ValueType::Number),
+            ),
         ]);
 
         Self(ctx)
@@ -11,6 +11,7 @@ use crate::{
         dir::{self, EuclidDirFilter},
         vir,
     },
+    types::EuclidKey,
 };
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -74,6 +75,27 @@ where
                 },
             )
     }
+
+    fn statement_references_key(stmt: &vir::ValuedIfStatement, key: &EuclidKey) -> bool {
+        stmt.condition
+            .iter()
+            .any(|comp| comp.values.iter().any(|value| value.get_key() == *key))
+            || stmt.nested.as_ref().is_some_and(|nested_stmts| {
+                nested_stmts
+                    .iter()
+                    .any(|s| Self::statement_references_key(s, key))
+            })
+    }
+
+    /// Checks whether any rule of the program has a condition on the given key, so that inputs
+    /// which are costly to compute can be skipped for programs which never use them
+    pub fn references_key(&self, key: &EuclidKey) -> bool {
+        self.program.rules.iter().any(|rule| {
+            rule.statements
+                .iter()
+                .any(|stmt| Self::statement_references_key(stmt, key))
+        })
+    }
 }
 
 impl<O> EuclidBackend<O> for VirInterpreterBackend<O>
@@ -140,11 +162,13 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                issuer_frictionless_rate: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_product_type: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -180,11 +204,13 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                issuer_frictionless_rate: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_product_type: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -221,11 +247,13 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                issuer_frictionless_rate: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_product_type: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -262,11 +290,13 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                issuer_frictionless_rate: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_product_type: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -303,11 +333,13 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                issuer_frictionless_rate: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_product_type: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: Some(enums::MandateAcceptanceType::Online),
@@ -344,11 +376,13 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                issuer_frictionless_rate: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_product_type: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -385,11 +419,13 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                issuer_frictionless_rate: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_product_type: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -426,11 +462,13 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                issuer_frictionless_rate: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_product_type: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -467,11 +505,13 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: Some(enums::SetupFutureUsage::OffSession),
+                issuer_frictionless_rate: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_product_type: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -510,11 +550,13 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                issuer_frictionless_rate: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_product_type: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -551,11 +593,13 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                issuer_frictionless_rate: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_product_type: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -601,11 +645,13 @@ mod test {
                 billing_country: Some(enums::Country::France),
                 business_label: None,
                 setup_future_usage: None,
+                issuer_frictionless_rate: None,
             },
             payment_method: inputs::PaymentMethodInput {
                 payment_method: Some(enums::PaymentMethod::PayLater),
                 payment_method_type: Some(enums::PaymentMethodType::Affirm),
                 card_network: None,
+                card_product_type: None,
             },
             mandate: inputs::MandateData {
                 mandate_acceptance_type: None,
@@ -627,4 +673,77 @@ mod test {
             "rule_1"
         );
     }
+
+    #[test]
+    fn test_card_product_type_execution() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        rule_1: ["stripe"]
+        {
+           card_product_type = commercial
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let inp_commercial = inputs::BackendInput {
+            metadata: None,
+            payment: inputs::PaymentInput {
+                amount: MinorUnit::new(32),
+                card_bin: Some("424242".to_string()),
+                currency: enums::Currency::USD,
+                authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+                capture_method: Some(enums::CaptureMethod::Automatic),
+                business_country: Some(enums::Country::UnitedStatesOfAmerica),
+                billing_country: Some(enums::Country::France),
+                business_label: None,
+                setup_future_usage: None,
+                issuer_frictionless_rate: None,
+            },
+            payment_method: inputs::PaymentMethodInput {
+                payment_method: Some(enums::PaymentMethod::Card),
+                payment_method_type: Some(enums::PaymentMethodType::Credit),
+                card_network: Some(enums::CardNetwork::Visa),
+                card_product_type: Some(enums::CardProductType::Commercial),
+            },
+            mandate: inputs::MandateData {
+                mandate_acceptance_type: None,
+                mandate_type: None,
+                payment_type: None,
+            },
+        };
+        let mut inp_consumer = inp_commercial.clone();
+        inp_consumer.payment_method.card_product_type = Some(enums::CardProductType::Consumer);
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        let result_commercial = backend.execute(inp_commercial).expect("Execution");
+        let result_consumer = backend.execute(inp_consumer).expect("Execution");
+        assert_eq!(
+            result_commercial.rule_name.expect("Rule Name").as_str(),
+            "rule_1"
+        );
+        assert!(result_consumer.rule_name.is_none());
+    }
+
+    #[test]
+    fn test_references_key() {
+        let program_str = r#"
+        default: ["stripe", "adyen"]
+
+        rule_1: ["stripe"]
+        {
+           payment_method = card {
+               issuer_frictionless_rate >= 80
+           }
+        }
+
+        rule_2: ["adyen"]
+        {
+           amount >= 123
+        }
+        "#;
+        let (_, program) = ast::parser::program::<DummyOutput>(program_str).expect("Program");
+        let backend = VirInterpreterBackend::<DummyOutput>::with_program(program).expect("Program");
+        assert!(backend.references_key(&EuclidKey::IssuerFrictionlessRate));
+        assert!(backend.references_key(&EuclidKey::PaymentAmount));
+        assert!(!backend.references_key(&EuclidKey::CardNetwork));
+    }
 }
@@ -77,6 +77,10 @@ impl Context {
             enum_values.insert(EuclidValue::CardNetwork(card_network));
         }
 
+        if let Some(card_product_type) = payment_method.card_product_type {
+            enum_values.insert(EuclidValue::CardProductType(card_product_type));
+        }
+
         if let Some(at) = payment.authentication_type {
             enum_values.insert(EuclidValue::AuthenticationType(at));
         }
@@ -113,13 +117,22 @@ impl Context {
             enum_values.insert(EuclidValue::MandateAcceptanceType(mandate_acceptance_type));
         }
 
-        let numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
+        let mut numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
             EuclidKey::PaymentAmount,
             EuclidValue::PaymentAmount(types::NumValue {
                 number: payment.amount,
                 refinement: None,
             }),
         )]);
+        if let Some(issuer_frictionless_rate) = payment.issuer_frictionless_rate {
+            numeric_values.insert(
+                EuclidKey::IssuerFrictionlessRate,
+                EuclidValue::IssuerFrictionlessRate(types::NumValue {
+                    number: issuer_frictionless_rate,
+                    refinement: None,
+                }),
+            );
+        }
 
         Self {
             atomic_values: enum_values,
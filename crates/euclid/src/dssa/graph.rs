@@ -44,6 +44,7 @@ impl cgraph::NodeViz for dir::DirValue {
             Self::CardBin(bin) => bin.value.clone(),
             Self::CardType(ct) => ct.to_string(),
             Self::CardNetwork(cn) => cn.to_string(),
+            Self::CardProductType(cpt) => cpt.to_string(),
             Self::PayLaterType(plt) => plt.to_string(),
             Self::WalletType(wt) => wt.to_string(),
             Self::UpiType(ut) => ut.to_string(),
@@ -53,6 +54,7 @@ impl cgraph::NodeViz for dir::DirValue {
             Self::CryptoType(ct) => ct.to_string(),
             Self::RewardType(rt) => rt.to_string(),
             Self::PaymentAmount(amt) => amt.number.to_string(),
+            Self::IssuerFrictionlessRate(rate) => rate.number.to_string(),
             Self::PaymentCurrency(curr) => curr.to_string(),
             Self::AuthenticationType(at) => at.to_string(),
             Self::CaptureMethod(cm) => cm.to_string(),
@@ -27,6 +27,7 @@ collect_variants!(RoutableConnectors);
 collect_variants!(PaymentType);
 collect_variants!(MandateType);
 collect_variants!(MandateAcceptanceType);
+collect_variants!(CardProductType);
 collect_variants!(PaymentMethodType);
 collect_variants!(CardNetwork);
 collect_variants!(AuthenticationType);
@@ -61,6 +62,26 @@ pub enum MandateAcceptanceType {
     Offline,
 }
 
+#[derive(
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    strum::Display,
+    strum::VariantNames,
+    strum::EnumIter,
+    strum::EnumString,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CardProductType {
+    Consumer,
+    Commercial,
+}
+
 #[derive(
     Clone,
     Debug,
@@ -228,6 +228,8 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
 
         dir::DirKeyKind::CardNetwork => lower_enum!(CardNetwork, value),
 
+        dir::DirKeyKind::CardProductType => lower_enum!(CardProductType, value),
+
         dir::DirKeyKind::PayLaterType => lower_enum!(PayLaterType, value),
 
         dir::DirKeyKind::WalletType => lower_enum!(WalletType, value),
@@ -295,6 +297,10 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
 
         dir::DirKeyKind::PaymentAmount => lower_number!(PaymentAmount, value, comparison),
 
+        dir::DirKeyKind::IssuerFrictionlessRate => {
+            lower_number!(IssuerFrictionlessRate, value, comparison)
+        }
+
         dir::DirKeyKind::Connector => Err(AnalysisErrorType::InvalidKey(
             dir::DirKeyKind::Connector.to_string(),
         )),
@@ -100,6 +100,14 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "card_network")]
     CardNetwork,
+    #[strum(
+        serialize = "card_product_type",
+        detailed_message = "Product type of the payment card derived from its BIN - eg. consumer, \
+            commercial",
+        props(Category = "Payment Methods")
+    )]
+    #[serde(rename = "card_product_type")]
+    CardProductType,
     #[strum(
         serialize = "pay_later",
         detailed_message = "Supported types of Pay Later payment method",
@@ -257,6 +265,14 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "setup_future_usage")]
     SetupFutureUsage,
+    #[strum(
+        serialize = "issuer_frictionless_rate",
+        detailed_message = "Percentage of the past 3DS authentications of the card BIN completed \
+            without a challenge by the issuer",
+        props(Category = "Payment Methods")
+    )]
+    #[serde(rename = "issuer_frictionless_rate")]
+    IssuerFrictionlessRate,
     #[strum(
         serialize = "card_redirect",
         detailed_message = "Supported types of Card Redirect payment method",
@@ -308,6 +324,7 @@ impl DirKeyKind {
             Self::CardBin => types::DataType::StrValue,
             Self::CardType => types::DataType::EnumVariant,
             Self::CardNetwork => types::DataType::EnumVariant,
+            Self::CardProductType => types::DataType::EnumVariant,
             Self::MetaData => types::DataType::MetadataValue,
             Self::MandateType => types::DataType::EnumVariant,
             Self::PaymentType => types::DataType::EnumVariant,
@@ -331,6 +348,7 @@ impl DirKeyKind {
             Self::BankDebitType => types::DataType::EnumVariant,
             Self::BusinessLabel => types::DataType::StrValue,
             Self::SetupFutureUsage => types::DataType::EnumVariant,
+            Self::IssuerFrictionlessRate => types::DataType::Number,
             Self::CardRedirectType => types::DataType::EnumVariant,
             Self::RealTimePaymentType => types::DataType::EnumVariant,
             Self::OpenBankingType => types::DataType::EnumVariant,
@@ -366,6 +384,11 @@ impl DirKeyKind {
                     .map(DirValue::CardNetwork)
                     .collect(),
             ),
+            Self::CardProductType => Some(
+                enums::CardProductType::iter()
+                    .map(DirValue::CardProductType)
+                    .collect(),
+            ),
             Self::PayLaterType => Some(
                 enums::PayLaterType::iter()
                     .map(DirValue::PayLaterType)
@@ -409,6 +432,7 @@ impl DirKeyKind {
                     .collect(),
             ),
             Self::PaymentAmount => None,
+            Self::IssuerFrictionlessRate => None,
             Self::PaymentCurrency => Some(
                 enums::PaymentCurrency::iter()
                     .map(DirValue::PaymentCurrency)
@@ -489,6 +513,8 @@ pub enum DirValue {
     CardType(enums::CardType),
     #[serde(rename = "card_network")]
     CardNetwork(enums::CardNetwork),
+    #[serde(rename = "card_product_type")]
+    CardProductType(enums::CardProductType),
     #[serde(rename = "metadata")]
     MetaData(types::MetadataValue),
     #[serde(rename = "pay_later")]
@@ -535,6 +561,8 @@ pub enum DirValue {
     BusinessLabel(types::StrValue),
     #[serde(rename = "setup_future_usage")]
     SetupFutureUsage(enums::SetupFutureUsage),
+    #[serde(rename = "issuer_frictionless_rate")]
+    IssuerFrictionlessRate(types::NumValue),
     #[serde(rename = "card_redirect")]
     CardRedirectType(enums::CardRedirectType),
     #[serde(rename = "real_time_payment")]
@@ -557,6 +585,7 @@ impl DirValue {
             Self::UpiType(_) => (DirKeyKind::UpiType, None),
             Self::CardType(_) => (DirKeyKind::CardType, None),
             Self::CardNetwork(_) => (DirKeyKind::CardNetwork, None),
+            Self::CardProductType(_) => (DirKeyKind::CardProductType, None),
             Self::MetaData(met) => (DirKeyKind::MetaData, Some(met.key.clone())),
             Self::PayLaterType(_) => (DirKeyKind::PayLaterType, None),
             Self::WalletType(_) => (DirKeyKind::WalletType, None),
@@ -573,6 +602,7 @@ impl DirValue {
             Self::PaymentType(_) => (DirKeyKind::PaymentType, None),
             Self::BusinessLabel(_) => (DirKeyKind::BusinessLabel, None),
             Self::SetupFutureUsage(_) => (DirKeyKind::SetupFutureUsage, None),
+            Self::IssuerFrictionlessRate(_) => (DirKeyKind::IssuerFrictionlessRate, None),
             Self::CardRedirectType(_) => (DirKeyKind::CardRedirectType, None),
             Self::VoucherType(_) => (DirKeyKind::VoucherType, None),
             Self::GiftCardType(_) => (DirKeyKind::GiftCardType, None),
@@ -590,6 +620,7 @@ impl DirValue {
             Self::CardBin(_) => None,
             Self::CardType(_) => None,
             Self::CardNetwork(_) => None,
+            Self::CardProductType(_) => None,
             Self::PayLaterType(_) => None,
             Self::WalletType(_) => None,
             Self::BankRedirectType(_) => None,
@@ -612,6 +643,7 @@ impl DirValue {
             Self::PaymentType(_) => None,
             Self::BusinessLabel(_) => None,
             Self::SetupFutureUsage(_) => None,
+            Self::IssuerFrictionlessRate(_) => None,
             Self::CardRedirectType(_) => None,
             Self::RealTimePaymentType(_) => None,
             Self::OpenBankingType(_) => None,
@@ -628,7 +660,7 @@ impl DirValue {
 
     pub fn get_num_value(&self) -> Option<types::NumValue> {
         match self {
-            Self::PaymentAmount(val) => Some(val.clone()),
+            Self::PaymentAmount(val) | Self::IssuerFrictionlessRate(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -638,6 +670,7 @@ impl DirValue {
             (Self::PaymentMethod(pm1), Self::PaymentMethod(pm2)) => pm1 == pm2,
             (Self::CardType(ct1), Self::CardType(ct2)) => ct1 == ct2,
             (Self::CardNetwork(cn1), Self::CardNetwork(cn2)) => cn1 == cn2,
+            (Self::CardProductType(cpt1), Self::CardProductType(cpt2)) => cpt1 == cpt2,
             (Self::MetaData(md1), Self::MetaData(md2)) => md1 == md2,
             (Self::PayLaterType(plt1), Self::PayLaterType(plt2)) => plt1 == plt2,
             (Self::WalletType(wt1), Self::WalletType(wt2)) => wt1 == wt2,
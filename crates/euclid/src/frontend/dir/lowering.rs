@@ -230,6 +230,7 @@ fn lower_value(dir_value: dir::DirValue) -> Result<EuclidValue, AnalysisErrorTyp
         dir::DirValue::CardBin(ci) => EuclidValue::CardBin(ci),
         dir::DirValue::CardType(ct) => EuclidValue::PaymentMethodType(ct.into()),
         dir::DirValue::CardNetwork(cn) => EuclidValue::CardNetwork(cn),
+        dir::DirValue::CardProductType(cpt) => EuclidValue::CardProductType(cpt),
         dir::DirValue::MetaData(md) => EuclidValue::Metadata(md),
         dir::DirValue::PayLaterType(plt) => EuclidValue::PaymentMethodType(plt.into()),
         dir::DirValue::WalletType(wt) => EuclidValue::PaymentMethodType(wt.into()),
@@ -244,6 +245,7 @@ fn lower_value(dir_value: dir::DirValue) -> Result<EuclidValue, AnalysisErrorTyp
         dir::DirValue::AuthenticationType(at) => EuclidValue::AuthenticationType(at),
         dir::DirValue::CaptureMethod(cm) => EuclidValue::CaptureMethod(cm),
         dir::DirValue::PaymentAmount(pa) => EuclidValue::PaymentAmount(pa),
+        dir::DirValue::IssuerFrictionlessRate(ifr) => EuclidValue::IssuerFrictionlessRate(ifr),
         dir::DirValue::PaymentCurrency(pc) => EuclidValue::PaymentCurrency(pc),
         dir::DirValue::BusinessCountry(buc) => EuclidValue::BusinessCountry(buc),
         dir::DirValue::BillingCountry(bic) => EuclidValue::BillingCountry(bic),
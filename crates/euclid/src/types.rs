@@ -44,6 +44,8 @@ pub enum EuclidKey {
     PaymentMethodType,
     #[strum(serialize = "card_network")]
     CardNetwork,
+    #[strum(serialize = "card_product_type")]
+    CardProductType,
     #[strum(serialize = "authentication_type")]
     AuthenticationType,
     #[strum(serialize = "capture_method")]
@@ -60,6 +62,8 @@ pub enum EuclidKey {
     BusinessLabel,
     #[strum(serialize = "setup_future_usage")]
     SetupFutureUsage,
+    #[strum(serialize = "issuer_frictionless_rate")]
+    IssuerFrictionlessRate,
 }
 impl EuclidDirFilter for DummyOutput {
     const ALLOWED: &'static [DirKeyKind] = &[
@@ -70,6 +74,7 @@ impl EuclidDirFilter for DummyOutput {
         DirKeyKind::CaptureMethod,
         DirKeyKind::AuthenticationType,
         DirKeyKind::CardBin,
+        DirKeyKind::CardProductType,
         DirKeyKind::PayLaterType,
         DirKeyKind::PaymentAmount,
         DirKeyKind::MetaData,
@@ -127,6 +132,7 @@ impl EuclidKey {
             Self::Metadata => DataType::MetadataValue,
             Self::PaymentMethodType => DataType::EnumVariant,
             Self::CardNetwork => DataType::EnumVariant,
+            Self::CardProductType => DataType::EnumVariant,
             Self::AuthenticationType => DataType::EnumVariant,
             Self::CaptureMethod => DataType::EnumVariant,
             Self::PaymentAmount => DataType::Number,
@@ -138,6 +144,7 @@ impl EuclidKey {
             Self::PaymentType => DataType::EnumVariant,
             Self::BusinessLabel => DataType::StrValue,
             Self::SetupFutureUsage => DataType::EnumVariant,
+            Self::IssuerFrictionlessRate => DataType::Number,
         }
     }
 }
@@ -242,6 +249,7 @@ pub enum EuclidValue {
     Metadata(MetadataValue),
     PaymentMethodType(enums::PaymentMethodType),
     CardNetwork(enums::CardNetwork),
+    CardProductType(enums::CardProductType),
     AuthenticationType(enums::AuthenticationType),
     CaptureMethod(enums::CaptureMethod),
     PaymentType(enums::PaymentType),
@@ -253,12 +261,13 @@ pub enum EuclidValue {
     BillingCountry(enums::Country),
     BusinessLabel(StrValue),
     SetupFutureUsage(enums::SetupFutureUsage),
+    IssuerFrictionlessRate(NumValue),
 }
 
 impl EuclidValue {
     pub fn get_num_value(&self) -> Option<NumValue> {
         match self {
-            Self::PaymentAmount(val) => Some(val.clone()),
+            Self::PaymentAmount(val) | Self::IssuerFrictionlessRate(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -273,6 +282,7 @@ impl EuclidValue {
             Self::PaymentType(_) => EuclidKey::PaymentType,
             Self::MandateAcceptanceType(_) => EuclidKey::MandateAcceptanceType,
             Self::CardNetwork(_) => EuclidKey::CardNetwork,
+            Self::CardProductType(_) => EuclidKey::CardProductType,
             Self::AuthenticationType(_) => EuclidKey::AuthenticationType,
             Self::CaptureMethod(_) => EuclidKey::CaptureMethod,
             Self::PaymentAmount(_) => EuclidKey::PaymentAmount,
@@ -281,6 +291,7 @@ impl EuclidValue {
             Self::BillingCountry(_) => EuclidKey::BillingCountry,
             Self::BusinessLabel(_) => EuclidKey::BusinessLabel,
             Self::SetupFutureUsage(_) => EuclidKey::SetupFutureUsage,
+            Self::IssuerFrictionlessRate(_) => EuclidKey::IssuerFrictionlessRate,
         }
     }
 }
@@ -7,8 +7,8 @@ use std::{
 };
 
 use api_models::{
-    enums as api_model_enums, routing::ConnectorSelection,
-    surcharge_decision_configs::SurchargeDecisionConfigs,
+    enums as api_model_enums, payment_methods::PaymentMethodDisplayNamesResponse,
+    routing::ConnectorSelection, surcharge_decision_configs::SurchargeDecisionConfigs,
 };
 use common_enums::RoutableConnectors;
 use connector_configs::{
@@ -202,6 +202,15 @@ pub fn get_all_connectors() -> JsResult {
     Ok(serde_wasm_bindgen::to_value(RoutableConnectors::VARIANTS)?)
 }
 
+/// Display names and icons of all the payment methods and payment method types from the registry,
+/// in the given locale or else in the default locale of the registry
+#[wasm_bindgen(js_name = getPaymentMethodDisplayNames)]
+pub fn get_payment_method_display_names(locale: Option<String>) -> JsResult {
+    let locales: Vec<String> = locale.into_iter().collect();
+    let display_names = PaymentMethodDisplayNamesResponse::from_registry(&locales, None);
+    Ok(serde_wasm_bindgen::to_value(&display_names)?)
+}
+
 #[wasm_bindgen(js_name = getAllKeys)]
 pub fn get_all_keys() -> JsResult {
     let keys: Vec<&'static str> = dir::DirKeyKind::VARIANTS
@@ -244,6 +253,7 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         dir::DirKeyKind::PaymentMethod => dir_enums::PaymentMethod::VARIANTS,
         dir::DirKeyKind::CardType => dir_enums::CardType::VARIANTS,
         dir::DirKeyKind::CardNetwork => dir_enums::CardNetwork::VARIANTS,
+        dir::DirKeyKind::CardProductType => dir_enums::CardProductType::VARIANTS,
         dir::DirKeyKind::PayLaterType => dir_enums::PayLaterType::VARIANTS,
         dir::DirKeyKind::WalletType => dir_enums::WalletType::VARIANTS,
         dir::DirKeyKind::BankRedirectType => dir_enums::BankRedirectType::VARIANTS,
@@ -269,6 +279,7 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         dir::DirKeyKind::MobilePaymentType => dir_enums::MobilePaymentType::VARIANTS,
 
         dir::DirKeyKind::PaymentAmount
+        | dir::DirKeyKind::IssuerFrictionlessRate
         | dir::DirKeyKind::Connector
         | dir::DirKeyKind::CardBin
         | dir::DirKeyKind::BusinessLabel
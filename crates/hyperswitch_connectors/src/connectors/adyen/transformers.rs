@@ -25,14 +25,18 @@ use hyperswitch_domain_models::{
     router_data::{
         ConnectorAuthType, ErrorResponse, PaymentMethodBalance, PaymentMethodToken, RouterData,
     },
-    router_request_types::{PaymentsPreProcessingData, ResponseId, SubmitEvidenceRequestData},
+    router_request_types::{
+        GiftCardBalanceCheckRequestData, PaymentsPreProcessingData, ResponseId,
+        SubmitEvidenceRequestData,
+    },
     router_response_types::{
-        AcceptDisputeResponse, DefendDisputeResponse, MandateReference, PaymentsResponseData,
-        RedirectForm, RefundsResponseData, SubmitEvidenceResponse,
+        AcceptDisputeResponse, DefendDisputeResponse, GiftCardBalanceCheckResponseData,
+        MandateReference, PaymentsResponseData, RedirectForm, RefundsResponseData,
+        SubmitEvidenceResponse,
     },
     types::{
-        PaymentsAuthorizeRouterData, PaymentsCancelRouterData, PaymentsCaptureRouterData,
-        PaymentsPreProcessingRouterData, RefundsRouterData,
+        PaymentsAuthorizeRouterData, PaymentsBalanceRouterData, PaymentsCancelRouterData,
+        PaymentsCaptureRouterData, PaymentsPreProcessingRouterData, RefundsRouterData,
     },
 };
 #[cfg(feature = "payouts")]
@@ -1650,30 +1654,48 @@ impl TryFrom<&AdyenRouterData<&PaymentsAuthorizeRouterData>> for AdyenPaymentReq
     }
 }
 
-impl TryFrom<&PaymentsPreProcessingRouterData> for AdyenBalanceRequest<'_> {
-    type Error = Error;
-    fn try_from(item: &PaymentsPreProcessingRouterData) -> Result<Self, Self::Error> {
-        let payment_method = match &item.request.payment_method_data {
-            Some(PaymentMethodData::GiftCard(gift_card_data)) => match gift_card_data.as_ref() {
-                GiftCardData::Givex(gift_card_data) => {
-                    let balance_pm = BalancePmData {
-                        number: gift_card_data.number.clone(),
-                        cvc: gift_card_data.cvc.clone(),
-                    };
-                    Ok(AdyenPaymentMethod::PaymentMethodBalance(Box::new(
-                        balance_pm,
-                    )))
-                }
-                GiftCardData::PaySafeCard {} => Err(errors::ConnectorError::FlowNotSupported {
-                    flow: "Balance".to_string(),
-                    connector: "adyen".to_string(),
-                }),
-            },
-            _ => Err(errors::ConnectorError::FlowNotSupported {
+fn get_balance_payment_method<'a>(
+    payment_method_data: Option<&PaymentMethodData>,
+) -> Result<AdyenPaymentMethod<'a>, errors::ConnectorError> {
+    match payment_method_data {
+        Some(PaymentMethodData::GiftCard(gift_card_data)) => match gift_card_data.as_ref() {
+            GiftCardData::Givex(gift_card_data) => {
+                let balance_pm = BalancePmData {
+                    number: gift_card_data.number.clone(),
+                    cvc: gift_card_data.cvc.clone(),
+                };
+                Ok(AdyenPaymentMethod::PaymentMethodBalance(Box::new(
+                    balance_pm,
+                )))
+            }
+            GiftCardData::PaySafeCard {} => Err(errors::ConnectorError::FlowNotSupported {
                 flow: "Balance".to_string(),
                 connector: "adyen".to_string(),
             }),
-        }?;
+        },
+        _ => Err(errors::ConnectorError::FlowNotSupported {
+            flow: "Balance".to_string(),
+            connector: "adyen".to_string(),
+        }),
+    }
+}
+
+impl TryFrom<&PaymentsPreProcessingRouterData> for AdyenBalanceRequest<'_> {
+    type Error = Error;
+    fn try_from(item: &PaymentsPreProcessingRouterData) -> Result<Self, Self::Error> {
+        let payment_method = get_balance_payment_method(item.request.payment_method_data.as_ref())?;
+        let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
+        Ok(Self {
+            payment_method,
+            merchant_account: auth_type.merchant_account,
+        })
+    }
+}
+
+impl TryFrom<&PaymentsBalanceRouterData> for AdyenBalanceRequest<'_> {
+    type Error = Error;
+    fn try_from(item: &PaymentsBalanceRouterData) -> Result<Self, Self::Error> {
+        let payment_method = get_balance_payment_method(Some(&item.request.payment_method_data))?;
         let auth_type = AdyenAuthType::try_from(&item.connector_auth_type)?;
         Ok(Self {
             payment_method,
@@ -3435,6 +3457,39 @@ impl<F>
     }
 }
 
+impl<F>
+    TryFrom<
+        ResponseRouterData<
+            F,
+            AdyenBalanceResponse,
+            GiftCardBalanceCheckRequestData,
+            GiftCardBalanceCheckResponseData,
+        >,
+    > for RouterData<F, GiftCardBalanceCheckRequestData, GiftCardBalanceCheckResponseData>
+{
+    type Error = Error;
+    fn try_from(
+        item: ResponseRouterData<
+            F,
+            AdyenBalanceResponse,
+            GiftCardBalanceCheckRequestData,
+            GiftCardBalanceCheckResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(GiftCardBalanceCheckResponseData {
+                balance: item.response.balance.value,
+                currency: item.response.balance.currency,
+            }),
+            payment_method_balance: Some(PaymentMethodBalance {
+                currency: item.response.balance.currency,
+                amount: item.response.balance.value,
+            }),
+            ..item.data
+        })
+    }
+}
+
 pub fn get_adyen_response(
     response: AdyenResponse,
     is_capture_manual: bool,
@@ -273,7 +273,12 @@ impl TryFrom<&AirwallexRouterData<&types::PaymentsAuthorizeRouterData>>
         let device_data = get_device_data(item.router_data)?;
 
         Ok(Self {
-            request_id: Uuid::new_v4().to_string(),
+            // Airwallex deduplicates the requests by their id, so the retries of the attempt are
+            // sent with the same id
+            request_id: request
+                .connector_idempotency_key
+                .clone()
+                .unwrap_or_else(|| Uuid::new_v4().to_string()),
             payment_method,
             payment_method_options,
             return_url: request.complete_authorize_url.clone(),
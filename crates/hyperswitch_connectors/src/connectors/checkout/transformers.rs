@@ -8,7 +8,9 @@ use common_utils::{
 use error_stack::ResultExt;
 use hyperswitch_domain_models::{
     payment_method_data::{PaymentMethodData, WalletData},
-    router_data::{ConnectorAuthType, ErrorResponse, PaymentMethodToken, RouterData},
+    router_data::{
+        ConnectorAuthType, ConnectorResponseData, ErrorResponse, PaymentMethodToken, RouterData,
+    },
     router_flow_types::{Execute, RSync},
     router_request_types::ResponseId,
     router_response_types::{PaymentsResponseData, RedirectForm, RefundsResponseData},
@@ -1060,6 +1062,21 @@ pub struct ActionResponse {
     pub action_type: ActionType,
     pub approved: Option<bool>,
     pub reference: Option<String>,
+    pub processing: Option<ActionProcessingDetails>,
+}
+
+#[derive(Deserialize, Debug, Serialize)]
+pub struct ActionProcessingDetails {
+    pub acquirer_reference_number: Option<String>,
+}
+
+impl ActionResponse {
+    fn get_connector_response(&self) -> Option<ConnectorResponseData> {
+        self.processing
+            .as_ref()
+            .and_then(|processing| processing.acquirer_reference_number.clone())
+            .map(ConnectorResponseData::with_acquirer_reference_number)
+    }
 }
 
 impl From<&ActionResponse> for enums::RefundStatus {
@@ -1139,11 +1156,16 @@ impl TryFrom<RefundsResponseRouterData<Execute, &ActionResponse>> for RefundsRou
         item: RefundsResponseRouterData<Execute, &ActionResponse>,
     ) -> Result<Self, Self::Error> {
         let refund_status = enums::RefundStatus::from(item.response);
+        let connector_response = item
+            .response
+            .get_connector_response()
+            .or(item.data.connector_response.clone());
         Ok(Self {
             response: Ok(RefundsResponseData {
                 connector_refund_id: item.response.action_id.clone(),
                 refund_status,
             }),
+            connector_response,
             ..item.data
         })
     }
@@ -1155,11 +1177,16 @@ impl TryFrom<RefundsResponseRouterData<RSync, &ActionResponse>> for RefundsRoute
         item: RefundsResponseRouterData<RSync, &ActionResponse>,
     ) -> Result<Self, Self::Error> {
         let refund_status = enums::RefundStatus::from(item.response);
+        let connector_response = item
+            .response
+            .get_connector_response()
+            .or(item.data.connector_response.clone());
         Ok(Self {
             response: Ok(RefundsResponseData {
                 connector_refund_id: item.response.action_id.clone(),
                 refund_status,
             }),
+            connector_response,
             ..item.data
         })
     }
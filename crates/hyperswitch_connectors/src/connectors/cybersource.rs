@@ -1731,4 +1731,9 @@ impl webhooks::IncomingWebhook for Cybersource {
     }
 }
 
-impl ConnectorSpecifications for Cybersource {}
+impl ConnectorSpecifications for Cybersource {
+    fn is_authorization_remainder_release_supported(&self) -> bool {
+        // Reversals of partially captured authorizations release the amount sent in the request
+        true
+    }
+}
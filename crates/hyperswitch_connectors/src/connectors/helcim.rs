@@ -294,7 +294,16 @@ impl ConnectorIntegration<Authorize, PaymentsAuthorizeData, PaymentsResponseData
         connectors: &Connectors,
     ) -> CustomResult<Vec<(String, masking::maskable::Maskable<String>)>, errors::ConnectorError>
     {
-        self.build_headers(req, connectors)
+        let mut header = self.build_headers(req, connectors)?;
+        // The idempotency key of the attempt is reused when the authorization is resent
+        if let Some(idempotency_key) = req.request.connector_idempotency_key.clone() {
+            header.retain(|(name, _)| name != headers::IDEMPOTENCY_KEY);
+            header.push((
+                headers::IDEMPOTENCY_KEY.to_string(),
+                idempotency_key.into_masked(),
+            ));
+        }
+        Ok(header)
     }
 
     fn get_content_type(&self) -> &'static str {
@@ -376,6 +376,8 @@ impl<F, T> TryFrom<ResponseRouterData<F, ItaubankPaymentsSyncResponse, T, Paymen
 #[derive(Default, Debug, Serialize)]
 pub struct ItaubankRefundRequest {
     pub valor: StringMajorUnit, // refund_amount
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub descricao: Option<String>, // remittance information shown to the payer
 }
 
 impl<F> TryFrom<&ItaubankRouterData<&types::RefundsRouterData<F>>> for ItaubankRefundRequest {
@@ -385,6 +387,7 @@ impl<F> TryFrom<&ItaubankRouterData<&types::RefundsRouterData<F>>> for ItaubankR
     ) -> Result<Self, Self::Error> {
         Ok(Self {
             valor: item.amount.to_owned(),
+            descricao: item.router_data.request.end_to_end_reference.clone(),
         })
     }
 }
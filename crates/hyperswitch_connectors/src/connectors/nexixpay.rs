@@ -498,7 +498,14 @@ impl ConnectorIntegration<Authorize, PaymentsAuthorizeData, PaymentsResponseData
         req: &PaymentsAuthorizeRouterData,
         connectors: &Connectors,
     ) -> CustomResult<Vec<(String, masking::Maskable<String>)>, errors::ConnectorError> {
-        self.build_headers(req, connectors)
+        let mut header = self.build_headers(req, connectors)?;
+        if let Some(idempotency_key) = req.request.connector_idempotency_key.clone() {
+            header.push((
+                headers::IDEMPOTENCY_KEY.to_string(),
+                idempotency_key.into_masked(),
+            ));
+        }
+        Ok(header)
     }
 
     fn get_content_type(&self) -> &'static str {
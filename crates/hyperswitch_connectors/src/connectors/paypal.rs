@@ -260,6 +260,16 @@ fn construct_auth_assertion_header(
     format!("{algorithm}.{encoded_credentials}.")
 }
 
+/// Replaces the `PayPal-Request-Id` header, which PayPal uses to detect retried requests, so that
+/// the retries of a request are sent with the same id
+fn set_paypal_request_id(headers: &mut Vec<(String, Maskable<String>)>, request_id: String) {
+    headers.retain(|(name, _)| name != auth_headers::PAYPAL_REQUEST_ID);
+    headers.push((
+        auth_headers::PAYPAL_REQUEST_ID.to_string(),
+        request_id.into_masked(),
+    ));
+}
+
 impl ConnectorCommon for Paypal {
     fn id(&self) -> &'static str {
         "paypal"
@@ -970,7 +980,11 @@ impl ConnectorIntegration<Authorize, PaymentsAuthorizeData, PaymentsResponseData
         req: &PaymentsAuthorizeRouterData,
         connectors: &Connectors,
     ) -> CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
-        self.build_headers(req, connectors)
+        let mut headers = self.build_headers(req, connectors)?;
+        if let Some(idempotency_key) = req.request.connector_idempotency_key.clone() {
+            set_paypal_request_id(&mut headers, idempotency_key);
+        }
+        Ok(headers)
     }
 
     fn get_content_type(&self) -> &'static str {
@@ -2171,3 +2185,31 @@ impl ConnectorErrorTypeMapping for Paypal {
 }
 
 impl ConnectorSpecifications for Paypal {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_paypal_request_id() {
+        let mut headers = vec![
+            (
+                headers::CONTENT_TYPE.to_string(),
+                "application/json".to_string().into(),
+            ),
+            (
+                auth_headers::PAYPAL_REQUEST_ID.to_string(),
+                "connector_request_reference_id".to_string().into_masked(),
+            ),
+        ];
+        set_paypal_request_id(&mut headers, "idempotency_key".to_string());
+
+        let request_ids = headers
+            .iter()
+            .filter(|(name, _)| name == auth_headers::PAYPAL_REQUEST_ID)
+            .map(|(_, value)| value.clone().into_inner())
+            .collect::<Vec<_>>();
+        assert_eq!(request_ids, vec!["idempotency_key".to_string()]);
+        assert_eq!(headers.len(), 2);
+    }
+}
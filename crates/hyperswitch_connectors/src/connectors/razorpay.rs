@@ -727,4 +727,9 @@ impl ConnectorSpecifications for Razorpay {
     fn get_supported_webhook_flows(&self) -> Option<&'static [enums::EventClass]> {
         Some(&*RAZORPAY_SUPPORTED_WEBHOOK_FLOWS)
     }
+
+    fn get_refund_validity_days(&self) -> Option<i64> {
+        // Razorpay accepts refunds of the payments made within the last six months
+        Some(180)
+    }
 }
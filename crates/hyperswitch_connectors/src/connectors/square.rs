@@ -997,4 +997,9 @@ impl ConnectorSpecifications for Square {
     fn get_supported_webhook_flows(&self) -> Option<&'static [enums::EventClass]> {
         Some(&*SQUARE_SUPPORTED_WEBHOOK_FLOWS)
     }
+
+    fn get_refund_validity_days(&self) -> Option<i64> {
+        // Square accepts refunds of the payments taken within the last year
+        Some(365)
+    }
 }
@@ -263,7 +263,12 @@ impl TryFrom<&types::PaymentsAuthorizeRouterData> for SquarePaymentsRequest {
             PaymentMethodData::Card(_) => {
                 let pm_token = item.get_payment_method_token()?;
                 Ok(Self {
-                    idempotency_key: Secret::new(item.attempt_id.clone()),
+                    idempotency_key: Secret::new(
+                        item.request
+                            .connector_idempotency_key
+                            .clone()
+                            .unwrap_or_else(|| item.attempt_id.clone()),
+                    ),
                     source_id: match pm_token {
                         PaymentMethodToken::Token(token) => token,
                         PaymentMethodToken::ApplePayDecrypt(_) => Err(
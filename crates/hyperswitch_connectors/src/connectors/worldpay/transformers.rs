@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use api_models::payments::{MandateIds, MandateReferenceId};
+use api_models::payments::{MandateIds, MandateReferenceId, PaymentFacilitatorDetails};
 use base64::Engine;
 use common_enums::enums;
 use common_utils::{
@@ -258,6 +258,9 @@ trait WorldpayPaymentsRequestData {
     fn get_settlement_info(&self, _amount: i64) -> Option<AutoSettlement> {
         None
     }
+    fn get_payment_facilitator_details(&self) -> Option<&PaymentFacilitatorDetails> {
+        None
+    }
 }
 
 impl WorldpayPaymentsRequestData
@@ -390,6 +393,10 @@ impl WorldpayPaymentsRequestData
             _ => None,
         }
     }
+
+    fn get_payment_facilitator_details(&self) -> Option<&PaymentFacilitatorDetails> {
+        self.request.payment_facilitator_details.as_ref()
+    }
 }
 
 // Dangling helper function to create ThreeDS request
@@ -507,6 +514,65 @@ fn get_token_and_agreement(
     }
 }
 
+// Dangling helper function to create the payment facilitator details, which are sent only if the
+// payment facilitator id is present. Worldpay requires the address of the sub-merchant with it
+fn get_payment_facilitator(
+    payment_facilitator_details: &PaymentFacilitatorDetails,
+) -> Result<Option<PaymentFacilitator>, error_stack::Report<errors::ConnectorError>> {
+    let Some(pf_id) = payment_facilitator_details.payment_facilitator_id.clone() else {
+        return Ok(None);
+    };
+
+    let sub_merchant = payment_facilitator_details.sub_merchant.as_ref().ok_or(
+        errors::ConnectorError::MissingRequiredField {
+            field_name: "payment_facilitator_details.sub_merchant",
+        },
+    )?;
+    let address =
+        sub_merchant
+            .address
+            .as_ref()
+            .ok_or(errors::ConnectorError::MissingRequiredField {
+                field_name: "payment_facilitator_details.sub_merchant.address",
+            })?;
+
+    Ok(Some(PaymentFacilitator {
+        pf_id,
+        iso_id: payment_facilitator_details
+            .independent_sales_organization_id
+            .clone(),
+        sub_merchant: SubMerchant {
+            city: address
+                .city
+                .clone()
+                .ok_or(errors::ConnectorError::MissingRequiredField {
+                    field_name: "payment_facilitator_details.sub_merchant.address.city",
+                })?,
+            name: sub_merchant.name.clone(),
+            state: address.state.clone().map(ExposeInterface::expose),
+            postal_code: address.zip.clone().ok_or(
+                errors::ConnectorError::MissingRequiredField {
+                    field_name: "payment_facilitator_details.sub_merchant.address.zip",
+                },
+            )?,
+            merchant_id: sub_merchant.id.clone(),
+            country_code: address
+                .country
+                .ok_or(errors::ConnectorError::MissingRequiredField {
+                    field_name: "payment_facilitator_details.sub_merchant.address.country",
+                })?
+                .to_string(),
+            street: address
+                .line1
+                .clone()
+                .ok_or(errors::ConnectorError::MissingRequiredField {
+                    field_name: "payment_facilitator_details.sub_merchant.address.line1",
+                })?,
+            tax_id: sub_merchant.tax_id.clone().map(ExposeInterface::expose),
+        },
+    }))
+}
+
 // Implementation for WorldpayPaymentsRequest using abstracted request
 impl<T: WorldpayPaymentsRequestData> TryFrom<(&WorldpayRouterData<&T>, &Secret<String>)>
     for WorldpayPaymentsRequest
@@ -524,6 +590,12 @@ impl<T: WorldpayPaymentsRequestData> TryFrom<(&WorldpayRouterData<&T>, &Secret<S
             },
         )?;
 
+        let payment_facilitator_details = item.router_data.get_payment_facilitator_details();
+        let payment_facilitator = payment_facilitator_details
+            .map(get_payment_facilitator)
+            .transpose()?
+            .flatten();
+
         let is_mandate_payment = item.router_data.get_is_mandate_payment();
         let three_ds = create_three_ds_request(item.router_data, is_mandate_payment)?;
 
@@ -547,7 +619,9 @@ impl<T: WorldpayPaymentsRequestData> TryFrom<(&WorldpayRouterData<&T>, &Secret<S
                     item.router_data.get_mandate_id(),
                 )?,
                 narrative: InstructionNarrative {
-                    line1: merchant_name.expose(),
+                    line1: payment_facilitator_details
+                        .and_then(|details| details.soft_descriptor.clone())
+                        .unwrap_or_else(|| merchant_name.expose()),
                 },
                 value: PaymentValue {
                     amount: item.amount,
@@ -560,7 +634,9 @@ impl<T: WorldpayPaymentsRequestData> TryFrom<(&WorldpayRouterData<&T>, &Secret<S
             },
             merchant: Merchant {
                 entity: entity_id.clone(),
-                ..Default::default()
+                mcc: payment_facilitator_details
+                    .and_then(|details| details.merchant_category_code.clone()),
+                payment_facilitator,
             },
             transaction_reference: item.router_data.get_connector_request_reference_id(),
             customer: None,
@@ -5,6 +5,7 @@ pub(crate) mod headers {
     pub(crate) const APIKEY: &str = "apikey";
     pub(crate) const API_TOKEN: &str = "Api-Token";
     pub(crate) const AUTHORIZATION: &str = "Authorization";
+    pub(crate) const CKO_IDEMPOTENCY_KEY: &str = "Cko-Idempotency-Key";
     pub(crate) const CONTENT_TYPE: &str = "Content-Type";
     pub(crate) const DATE: &str = "Date";
     pub(crate) const IDEMPOTENCY_KEY: &str = "Idempotency-Key";
@@ -67,6 +67,7 @@ pub struct Profile {
     pub card_testing_secret_key: OptionalEncryptableName,
     pub is_clear_pan_retries_enabled: bool,
     pub force_3ds_challenge: bool,
+    pub is_test_mode: bool,
 }
 
 #[cfg(feature = "v1")]
@@ -115,6 +116,7 @@ pub struct ProfileSetter {
     pub card_testing_secret_key: OptionalEncryptableName,
     pub is_clear_pan_retries_enabled: bool,
     pub force_3ds_challenge: bool,
+    pub is_test_mode: bool,
 }
 
 #[cfg(feature = "v1")]
@@ -169,6 +171,7 @@ impl From<ProfileSetter> for Profile {
             card_testing_secret_key: value.card_testing_secret_key,
             is_clear_pan_retries_enabled: value.is_clear_pan_retries_enabled,
             force_3ds_challenge: value.force_3ds_challenge,
+            is_test_mode: value.is_test_mode,
         }
     }
 }
@@ -250,6 +253,9 @@ pub enum ProfileUpdate {
     CardTestingSecretKeyUpdate {
         card_testing_secret_key: OptionalEncryptableName,
     },
+    TestModeUpdate {
+        is_test_mode: bool,
+    },
 }
 
 #[cfg(feature = "v1")]
@@ -340,6 +346,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                     card_testing_secret_key: card_testing_secret_key.map(Encryption::from),
                     is_clear_pan_retries_enabled,
                     force_3ds_challenge,
+                    is_test_mode: None,
                 }
             }
             ProfileUpdate::RoutingAlgorithmUpdate {
@@ -386,6 +393,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 card_testing_secret_key: None,
                 is_clear_pan_retries_enabled: None,
                 force_3ds_challenge: None,
+                is_test_mode: None,
             },
             ProfileUpdate::DynamicRoutingAlgorithmUpdate {
                 dynamic_routing_algorithm,
@@ -430,6 +438,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 card_testing_secret_key: None,
                 is_clear_pan_retries_enabled: None,
                 force_3ds_challenge: None,
+                is_test_mode: None,
             },
             ProfileUpdate::ExtendedCardInfoUpdate {
                 is_extended_card_info_enabled,
@@ -474,6 +483,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 card_testing_secret_key: None,
                 is_clear_pan_retries_enabled: None,
                 force_3ds_challenge: None,
+                is_test_mode: None,
             },
             ProfileUpdate::ConnectorAgnosticMitUpdate {
                 is_connector_agnostic_mit_enabled,
@@ -518,6 +528,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 card_testing_secret_key: None,
                 is_clear_pan_retries_enabled: None,
                 force_3ds_challenge: None,
+                is_test_mode: None,
             },
             ProfileUpdate::NetworkTokenizationUpdate {
                 is_network_tokenization_enabled,
@@ -562,6 +573,7 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 card_testing_secret_key: None,
                 is_clear_pan_retries_enabled: None,
                 force_3ds_challenge: None,
+                is_test_mode: None,
             },
             ProfileUpdate::CardTestingSecretKeyUpdate {
                 card_testing_secret_key,
@@ -606,6 +618,50 @@ impl From<ProfileUpdate> for ProfileUpdateInternal {
                 card_testing_secret_key: card_testing_secret_key.map(Encryption::from),
                 is_clear_pan_retries_enabled: None,
                 force_3ds_challenge: None,
+                is_test_mode: None,
+            },
+            ProfileUpdate::TestModeUpdate { is_test_mode } => Self {
+                profile_name: None,
+                modified_at: now,
+                return_url: None,
+                enable_payment_response_hash: None,
+                payment_response_hash_key: None,
+                redirect_to_merchant_with_http_post: None,
+                webhook_details: None,
+                metadata: None,
+                routing_algorithm: None,
+                intent_fulfillment_time: None,
+                frm_routing_algorithm: None,
+                payout_routing_algorithm: None,
+                is_recon_enabled: None,
+                applepay_verified_domains: None,
+                payment_link_config: None,
+                session_expiry: None,
+                authentication_connector_details: None,
+                payout_link_config: None,
+                is_extended_card_info_enabled: None,
+                extended_card_info_config: None,
+                is_connector_agnostic_mit_enabled: None,
+                use_billing_as_payment_method_billing: None,
+                collect_shipping_details_from_wallet_connector: None,
+                collect_billing_details_from_wallet_connector: None,
+                outgoing_webhook_custom_http_headers: None,
+                always_collect_billing_details_from_wallet_connector: None,
+                always_collect_shipping_details_from_wallet_connector: None,
+                tax_connector_id: None,
+                is_tax_connector_enabled: None,
+                dynamic_routing_algorithm: None,
+                is_network_tokenization_enabled: None,
+                is_auto_retries_enabled: None,
+                max_auto_retries_enabled: None,
+                always_request_extended_authorization: None,
+                is_click_to_pay_enabled: None,
+                authentication_product_ids: None,
+                card_testing_guard_config: None,
+                card_testing_secret_key: None,
+                is_clear_pan_retries_enabled: None,
+                force_3ds_challenge: None,
+                is_test_mode: Some(is_test_mode),
             },
         }
     }
@@ -669,6 +725,7 @@ impl super::behaviour::Conversion for Profile {
             card_testing_secret_key: self.card_testing_secret_key.map(|name| name.into()),
             is_clear_pan_retries_enabled: self.is_clear_pan_retries_enabled,
             force_3ds_challenge: Some(self.force_3ds_challenge),
+            is_test_mode: Some(self.is_test_mode),
         })
     }
 
@@ -757,6 +814,7 @@ impl super::behaviour::Conversion for Profile {
                     .await?,
                 is_clear_pan_retries_enabled: item.is_clear_pan_retries_enabled,
                 force_3ds_challenge: item.force_3ds_challenge.unwrap_or_default(),
+                is_test_mode: item.is_test_mode.unwrap_or_default(),
             })
         }
         .await
@@ -815,6 +873,7 @@ impl super::behaviour::Conversion for Profile {
             card_testing_secret_key: self.card_testing_secret_key.map(Encryption::from),
             is_clear_pan_retries_enabled: self.is_clear_pan_retries_enabled,
             force_3ds_challenge: Some(self.force_3ds_challenge),
+            is_test_mode: Some(self.is_test_mode),
         })
     }
 }
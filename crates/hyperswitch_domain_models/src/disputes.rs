@@ -41,6 +41,7 @@ impl
             merchant_connector_id,
             currency,
             time_range,
+            payment_context: _,
         } = value;
         let profile_id_from_request_body = profile_id;
         // Match both the profile ID from the request body and the list of authenticated profile IDs coming from auth layer
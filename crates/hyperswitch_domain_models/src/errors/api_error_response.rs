@@ -160,6 +160,10 @@ pub enum ApiErrorResponse {
     InvalidTenant { tenant_id: String },
     #[error(error_type = ErrorType::ValidationError, code = "HE_06", message = "Failed to convert amount to {amount_type} type")]
     AmountConversionFailed { amount_type: &'static str },
+    #[error(error_type = ErrorType::ServerNotAvailable, code = "HE_07", message = "All the eligible connectors are under scheduled maintenance, retry the payment after {retry_after}")]
+    ConnectorsUnderMaintenance { retry_after: String },
+    #[error(error_type = ErrorType::ServerNotAvailable, code = "HE_08", message = "Calls to the connector {connector} are throttled as per its rate limit, retry the request")]
+    ConnectorThrottled { connector: String },
     #[error(error_type = ErrorType::ServerNotAvailable, code = "IR_00", message = "{message:?}")]
     NotImplemented { message: NotImplementedMessage },
     #[error(
@@ -283,6 +287,17 @@ pub enum ApiErrorResponse {
     PlatformAccountAuthNotSupported,
     #[error(error_type = ErrorType::InvalidRequestError, code = "IR_44", message = "Invalid platform account operation")]
     InvalidPlatformOperation,
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_45", message = "Refunds of this payment are accepted only until {latest_refund_date} as per the rules of {imposed_by}")]
+    RefundWindowExpired {
+        imposed_by: String,
+        latest_refund_date: String,
+    },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_46", message = "Too many requests, retry after {retry_after} seconds")]
+    TooManyRequests { retry_after: i64 },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_47", message = "This operation is not allowed for a merchant account in the {status} status")]
+    MerchantAccountStatusNotAllowed { status: String },
+    #[error(error_type = ErrorType::InvalidRequestError, code = "IR_48", message = "This operation is not allowed until the sanctions screening case {case_id} is cleared")]
+    SanctionsScreeningNotCleared { case_id: String },
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_01", message = "Failed to authenticate the webhook")]
     WebhookAuthenticationFailed,
     #[error(error_type = ErrorType::InvalidRequestError, code = "WE_02", message = "Bad request received in webhook")]
@@ -495,6 +510,33 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::AmountConversionFailed { amount_type }  => {
                 AER::InternalServerError(ApiError::new("HE", 6, format!("Failed to convert amount to {amount_type} type"), None))
             }
+            Self::ConnectorsUnderMaintenance { retry_after } => AER::ConnectorError(
+                ApiError::new(
+                    "HE",
+                    7,
+                    format!("All the eligible connectors are under scheduled maintenance, retry the payment after {retry_after}"),
+                    Some(Extra {
+                        data: Some(serde_json::json!({
+                            "retryable": true,
+                            "retry_after": retry_after,
+                        })),
+                        ..Default::default()
+                    }),
+                ),
+                StatusCode::SERVICE_UNAVAILABLE,
+            ),
+            Self::ConnectorThrottled { connector } => AER::ConnectorError(
+                ApiError::new(
+                    "HE",
+                    8,
+                    format!("Calls to the connector {connector} are throttled as per its rate limit, retry the request"),
+                    Some(Extra {
+                        data: Some(serde_json::json!({ "retryable": true })),
+                        ..Default::default()
+                    }),
+                ),
+                StatusCode::TOO_MANY_REQUESTS,
+            ),
 
             Self::NotImplemented { message } => {
                 AER::NotImplemented(ApiError::new("IR", 0, format!("{message:?}"), None))
@@ -676,6 +718,27 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::InvalidPlatformOperation => {
                 AER::Unauthorized(ApiError::new("IR", 44, "Invalid platform account operation", None))
             }
+            Self::RefundWindowExpired { imposed_by, latest_refund_date } => {
+                AER::BadRequest(ApiError::new("IR", 45, format!("Refunds of this payment are accepted only until {latest_refund_date} as per the rules of {imposed_by}"), None))
+            }
+            Self::TooManyRequests { retry_after } => AER::TooManyRequests(ApiError::new(
+                "IR",
+                46,
+                format!("Too many requests, retry after {retry_after} seconds"),
+                Some(Extra {
+                    data: Some(serde_json::json!({
+                        "retryable": true,
+                        "retry_after": retry_after,
+                    })),
+                    ..Default::default()
+                }),
+            )),
+            Self::MerchantAccountStatusNotAllowed { status } => {
+                AER::ForbiddenCommonResource(ApiError::new("IR", 47, format!("This operation is not allowed for a merchant account in the {status} status"), None))
+            }
+            Self::SanctionsScreeningNotCleared { case_id } => {
+                AER::ForbiddenCommonResource(ApiError::new("IR", 48, format!("This operation is not allowed until the sanctions screening case {case_id} is cleared"), None))
+            }
         }
     }
 }
@@ -75,6 +75,8 @@ pub struct CustomerAcceptance {
     pub accepted_at: Option<PrimitiveDateTime>,
     /// Information required for online mandate generation
     pub online: Option<OnlineMandate>,
+    /// The version of the consent text that was presented to the customer
+    pub consent_text_version: Option<String>,
 }
 
 #[derive(Default, Debug, PartialEq, Eq, Clone, serde::Deserialize, serde::Serialize)]
@@ -181,6 +183,7 @@ impl From<ApiCustomerAcceptance> for CustomerAcceptance {
             acceptance_type: value.acceptance_type.into(),
             accepted_at: value.accepted_at,
             online: value.online.map(|d| d.into()),
+            consent_text_version: value.consent_text_version,
         }
     }
 }
@@ -191,6 +194,7 @@ impl From<CustomerAcceptance> for ApiCustomerAcceptance {
             acceptance_type: value.acceptance_type.into(),
             accepted_at: value.accepted_at,
             online: value.online.map(|d| d.into()),
+            consent_text_version: value.consent_text_version,
         }
     }
 }
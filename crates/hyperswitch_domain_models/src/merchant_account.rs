@@ -49,6 +49,7 @@ pub struct MerchantAccount {
     pub version: common_enums::ApiVersion,
     pub is_platform_account: bool,
     pub product_type: Option<common_enums::MerchantProductType>,
+    pub status: common_enums::MerchantAccountStatus,
 }
 
 #[cfg(feature = "v1")]
@@ -85,6 +86,7 @@ pub struct MerchantAccountSetter {
     pub version: common_enums::ApiVersion,
     pub is_platform_account: bool,
     pub product_type: Option<common_enums::MerchantProductType>,
+    pub status: common_enums::MerchantAccountStatus,
 }
 
 #[cfg(feature = "v1")]
@@ -121,6 +123,7 @@ impl From<MerchantAccountSetter> for MerchantAccount {
             version: item.version,
             is_platform_account: item.is_platform_account,
             product_type: item.product_type,
+            status: item.status,
         }
     }
 }
@@ -142,6 +145,7 @@ pub struct MerchantAccountSetter {
     pub is_platform_account: bool,
     pub version: common_enums::ApiVersion,
     pub product_type: Option<common_enums::MerchantProductType>,
+    pub status: common_enums::MerchantAccountStatus,
 }
 
 #[cfg(feature = "v2")]
@@ -161,6 +165,7 @@ impl From<MerchantAccountSetter> for MerchantAccount {
             is_platform_account,
             version,
             product_type,
+            status,
         } = item;
         Self {
             id,
@@ -176,6 +181,7 @@ impl From<MerchantAccountSetter> for MerchantAccount {
             is_platform_account,
             version,
             product_type,
+            status,
         }
     }
 }
@@ -196,6 +202,7 @@ pub struct MerchantAccount {
     pub is_platform_account: bool,
     pub version: common_enums::ApiVersion,
     pub product_type: Option<common_enums::MerchantProductType>,
+    pub status: common_enums::MerchantAccountStatus,
 }
 
 impl MerchantAccount {
@@ -252,6 +259,9 @@ pub enum MerchantAccountUpdate {
     UnsetDefaultProfile,
     ModifiedAtUpdate,
     ToPlatformAccount,
+    StatusUpdate {
+        status: common_enums::MerchantAccountStatus,
+    },
 }
 
 #[cfg(feature = "v2")]
@@ -271,6 +281,9 @@ pub enum MerchantAccountUpdate {
     },
     ModifiedAtUpdate,
     ToPlatformAccount,
+    StatusUpdate {
+        status: common_enums::MerchantAccountStatus,
+    },
 }
 
 #[cfg(feature = "v1")]
@@ -328,6 +341,7 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 recon_status: None,
                 is_platform_account: None,
                 product_type: None,
+                status: None,
             },
             MerchantAccountUpdate::StorageSchemeUpdate { storage_scheme } => Self {
                 storage_scheme: Some(storage_scheme),
@@ -357,6 +371,7 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 pm_collect_link_config: None,
                 is_platform_account: None,
                 product_type: None,
+                status: None,
             },
             MerchantAccountUpdate::ReconUpdate { recon_status } => Self {
                 recon_status: Some(recon_status),
@@ -386,6 +401,7 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 pm_collect_link_config: None,
                 is_platform_account: None,
                 product_type: None,
+                status: None,
             },
             MerchantAccountUpdate::UnsetDefaultProfile => Self {
                 default_profile: Some(None),
@@ -415,6 +431,7 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 pm_collect_link_config: None,
                 is_platform_account: None,
                 product_type: None,
+                status: None,
             },
             MerchantAccountUpdate::ModifiedAtUpdate => Self {
                 modified_at: now,
@@ -444,6 +461,7 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 pm_collect_link_config: None,
                 is_platform_account: None,
                 product_type: None,
+                status: None,
             },
             MerchantAccountUpdate::ToPlatformAccount => Self {
                 modified_at: now,
@@ -473,6 +491,37 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 pm_collect_link_config: None,
                 is_platform_account: Some(true),
                 product_type: None,
+                status: None,
+            },
+            MerchantAccountUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                modified_at: now,
+                merchant_name: None,
+                merchant_details: None,
+                return_url: None,
+                webhook_details: None,
+                sub_merchants_enabled: None,
+                parent_merchant_id: None,
+                enable_payment_response_hash: None,
+                payment_response_hash_key: None,
+                redirect_to_merchant_with_http_post: None,
+                publishable_key: None,
+                storage_scheme: None,
+                locker_id: None,
+                metadata: None,
+                routing_algorithm: None,
+                primary_business_details: None,
+                intent_fulfillment_time: None,
+                frm_routing_algorithm: None,
+                payout_routing_algorithm: None,
+                organization_id: None,
+                is_recon_enabled: None,
+                default_profile: None,
+                recon_status: None,
+                payment_link_config: None,
+                pm_collect_link_config: None,
+                is_platform_account: None,
+                product_type: None,
             },
         }
     }
@@ -500,6 +549,7 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 recon_status: None,
                 is_platform_account: None,
                 product_type: None,
+                status: None,
             },
             MerchantAccountUpdate::StorageSchemeUpdate { storage_scheme } => Self {
                 storage_scheme: Some(storage_scheme),
@@ -512,6 +562,7 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 recon_status: None,
                 is_platform_account: None,
                 product_type: None,
+                status: None,
             },
             MerchantAccountUpdate::ReconUpdate { recon_status } => Self {
                 recon_status: Some(recon_status),
@@ -524,6 +575,7 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 organization_id: None,
                 is_platform_account: None,
                 product_type: None,
+                status: None,
             },
             MerchantAccountUpdate::ModifiedAtUpdate => Self {
                 modified_at: now,
@@ -536,6 +588,7 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 recon_status: None,
                 is_platform_account: None,
                 product_type: None,
+                status: None,
             },
             MerchantAccountUpdate::ToPlatformAccount => Self {
                 modified_at: now,
@@ -548,6 +601,20 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 recon_status: None,
                 is_platform_account: Some(true),
                 product_type: None,
+                status: None,
+            },
+            MerchantAccountUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                modified_at: now,
+                merchant_name: None,
+                merchant_details: None,
+                publishable_key: None,
+                storage_scheme: None,
+                metadata: None,
+                organization_id: None,
+                recon_status: None,
+                is_platform_account: None,
+                product_type: None,
             },
         }
     }
@@ -575,6 +642,7 @@ impl super::behaviour::Conversion for MerchantAccount {
             version: crate::consts::API_VERSION,
             is_platform_account: self.is_platform_account,
             product_type: self.product_type,
+            status: self.status,
         };
 
         Ok(diesel_models::MerchantAccount::from(setter))
@@ -637,6 +705,7 @@ impl super::behaviour::Conversion for MerchantAccount {
                 is_platform_account: item.is_platform_account,
                 version: item.version,
                 product_type: item.product_type,
+                status: item.status,
             })
         }
         .await
@@ -662,6 +731,7 @@ impl super::behaviour::Conversion for MerchantAccount {
             product_type: self
                 .product_type
                 .or(Some(common_enums::MerchantProductType::Orchestration)),
+            status: self.status,
         })
     }
 }
@@ -703,6 +773,7 @@ impl super::behaviour::Conversion for MerchantAccount {
             version: self.version,
             is_platform_account: self.is_platform_account,
             product_type: self.product_type,
+            status: self.status,
         };
 
         Ok(diesel_models::MerchantAccount::from(setter))
@@ -782,6 +853,7 @@ impl super::behaviour::Conversion for MerchantAccount {
                 version: item.version,
                 is_platform_account: item.is_platform_account,
                 product_type: item.product_type,
+                status: item.status,
             })
         }
         .await
@@ -825,6 +897,7 @@ impl super::behaviour::Conversion for MerchantAccount {
             product_type: self
                 .product_type
                 .or(Some(common_enums::MerchantProductType::Orchestration)),
+            status: self.status,
         })
     }
 }
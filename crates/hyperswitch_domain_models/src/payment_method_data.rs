@@ -1268,6 +1268,39 @@ impl From<CardToken> for payment_additional_types::CardTokenAdditionalData {
     }
 }
 
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+impl From<api_models::payment_methods::BankDebitDetail> for BankDebitData {
+    fn from(value: api_models::payment_methods::BankDebitDetail) -> Self {
+        match value {
+            api_models::payment_methods::BankDebitDetail::Ach {
+                account_number,
+                routing_number,
+                bank_account_holder_name,
+                bank_type,
+                bank_holder_type,
+            } => Self::AchBankDebit {
+                account_number,
+                routing_number,
+                card_holder_name: None,
+                bank_account_holder_name,
+                bank_name: None,
+                bank_type,
+                bank_holder_type,
+            },
+            api_models::payment_methods::BankDebitDetail::Sepa {
+                iban,
+                bank_account_holder_name,
+            } => Self::SepaBankDebit {
+                iban,
+                bank_account_holder_name,
+            },
+        }
+    }
+}
+
 impl From<api_models::payments::BankDebitData> for BankDebitData {
     fn from(value: api_models::payments::BankDebitData) -> Self {
         match value {
@@ -115,6 +115,25 @@ pub struct PaymentIntent {
     pub request_extended_authorization: Option<RequestExtendedAuthorizationBool>,
     pub psd2_sca_exemption_type: Option<storage_enums::ScaExemptionType>,
     pub platform_merchant_id: Option<id_type::MerchantId>,
+    /// Keyed hash of the email of the customer, used to search the payments by the email
+    pub customer_email_hash: Option<String>,
+    /// Keyed hash of the phone number of the customer, used to search the payments by the phone
+    pub customer_phone_hash: Option<String>,
+    /// Position of the last status change of the payment among the changes of all the payments,
+    /// assigned by the database, used to poll the payments whose status changed
+    pub change_sequence: Option<i64>,
+    /// Whether the amount of the payment is an estimate of the final amount, which is authorized
+    /// and adjusted to the final amount when the payment is captured
+    pub is_amount_estimated: Option<bool>,
+    /// Whether the payment can be paid by multiple attempts, each paying a part of its amount, as
+    /// for a gift card and a card
+    pub enable_partial_payments: Option<bool>,
+    /// The part of the amount of the payment paid with the store credit balance of the customer,
+    /// which the attempts of the payment do not charge
+    pub store_credit_amount: Option<MinorUnit>,
+    /// Whether the payment was created on a profile in test mode, which allows it to be purged
+    /// along with the other test data of the profile
+    pub is_test_payment: Option<bool>,
 }
 
 impl PaymentIntent {
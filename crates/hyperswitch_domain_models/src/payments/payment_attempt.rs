@@ -850,6 +850,19 @@ pub struct PaymentAttempt {
     pub charges: Option<common_types::payments::ConnectorChargeResponseData>,
     pub issuer_error_code: Option<String>,
     pub issuer_error_message: Option<String>,
+    /// The amount captured above the amount capturable of the attempt
+    pub overcaptured_amount: Option<MinorUnit>,
+    /// The idempotency key sent to the connector with the requests of the attempt
+    pub connector_idempotency_key: Option<String>,
+    /// The result of the address verification, normalized from the checks of the connector
+    pub avs_result: Option<storage_enums::AvsResult>,
+    /// The result of the verification of the card verification value, normalized from the checks
+    /// of the connector
+    pub cvv_result: Option<storage_enums::CvvResult>,
+    /// The reference number assigned by the acquirer to the capture of the attempt
+    pub acquirer_reference_number: Option<String>,
+    /// The credential of the saved card with which the attempt was authorized
+    pub card_credential_type: Option<storage_enums::CardCredentialType>,
 }
 
 #[cfg(feature = "v1")]
@@ -1099,6 +1112,7 @@ pub struct PaymentAttemptNew {
     pub extended_authorization_applied: Option<ExtendedAuthorizationAppliedBool>,
     pub capture_before: Option<PrimitiveDateTime>,
     pub card_discovery: Option<common_enums::CardDiscovery>,
+    pub connector_idempotency_key: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -1130,6 +1144,7 @@ pub enum PaymentAttemptUpdate {
         tax_amount: Option<MinorUnit>,
         updated_by: String,
         merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+        capture_method: Option<storage_enums::CaptureMethod>,
     },
     AuthenticationTypeUpdate {
         authentication_type: storage_enums::AuthenticationType,
@@ -1166,6 +1181,7 @@ pub enum PaymentAttemptUpdate {
         customer_acceptance: Option<pii::SecretSerdeValue>,
         connector_mandate_detail: Option<ConnectorMandateReferenceId>,
         card_discovery: Option<common_enums::CardDiscovery>,
+        card_credential_type: Option<storage_enums::CardCredentialType>,
     },
     RejectUpdate {
         status: storage_enums::AttemptStatus,
@@ -1251,6 +1267,16 @@ pub enum PaymentAttemptUpdate {
     CaptureUpdate {
         amount_to_capture: Option<MinorUnit>,
         multiple_capture_count: Option<i16>,
+        overcaptured_amount: Option<MinorUnit>,
+        updated_by: String,
+    },
+    PaymentChecksUpdate {
+        avs_result: Option<storage_enums::AvsResult>,
+        cvv_result: Option<storage_enums::CvvResult>,
+        updated_by: String,
+    },
+    AcquirerReferenceNumberUpdate {
+        acquirer_reference_number: String,
         updated_by: String,
     },
     AmountToCaptureUpdate {
@@ -1350,6 +1376,7 @@ impl PaymentAttemptUpdate {
                 surcharge_amount,
                 tax_amount,
                 merchant_connector_id,
+                capture_method,
             } => DieselPaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
                 connector,
@@ -1359,6 +1386,7 @@ impl PaymentAttemptUpdate {
                 tax_amount,
                 updated_by,
                 merchant_connector_id,
+                capture_method,
             },
             Self::AuthenticationTypeUpdate {
                 authentication_type,
@@ -1423,6 +1451,7 @@ impl PaymentAttemptUpdate {
                 customer_acceptance,
                 connector_mandate_detail,
                 card_discovery,
+                card_credential_type,
             } => DieselPaymentAttemptUpdate::ConfirmUpdate {
                 amount: net_amount.get_order_amount(),
                 currency,
@@ -1458,6 +1487,7 @@ impl PaymentAttemptUpdate {
                 order_tax_amount: net_amount.get_order_tax_amount(),
                 connector_mandate_detail,
                 card_discovery,
+                card_credential_type,
             },
             Self::VoidUpdate {
                 status,
@@ -1576,10 +1606,28 @@ impl PaymentAttemptUpdate {
                 multiple_capture_count,
                 updated_by,
                 amount_to_capture,
+                overcaptured_amount,
             } => DieselPaymentAttemptUpdate::CaptureUpdate {
                 multiple_capture_count,
                 updated_by,
                 amount_to_capture,
+                overcaptured_amount,
+            },
+            Self::PaymentChecksUpdate {
+                avs_result,
+                cvv_result,
+                updated_by,
+            } => DieselPaymentAttemptUpdate::PaymentChecksUpdate {
+                avs_result,
+                cvv_result,
+                updated_by,
+            },
+            Self::AcquirerReferenceNumberUpdate {
+                acquirer_reference_number,
+                updated_by,
+            } => DieselPaymentAttemptUpdate::AcquirerReferenceNumberUpdate {
+                acquirer_reference_number,
+                updated_by,
             },
             Self::PreprocessingUpdate {
                 status,
@@ -1842,6 +1890,12 @@ impl behaviour::Conversion for PaymentAttempt {
             charges: self.charges,
             issuer_error_code: self.issuer_error_code,
             issuer_error_message: self.issuer_error_message,
+            overcaptured_amount: self.overcaptured_amount,
+            connector_idempotency_key: self.connector_idempotency_key,
+            avs_result: self.avs_result,
+            cvv_result: self.cvv_result,
+            acquirer_reference_number: self.acquirer_reference_number,
+            card_credential_type: self.card_credential_type,
             // Below fields are deprecated. Please add any new fields above this line.
             connector_transaction_data: None,
         })
@@ -1932,6 +1986,12 @@ impl behaviour::Conversion for PaymentAttempt {
                 charges: storage_model.charges,
                 issuer_error_code: storage_model.issuer_error_code,
                 issuer_error_message: storage_model.issuer_error_message,
+                overcaptured_amount: storage_model.overcaptured_amount,
+                connector_idempotency_key: storage_model.connector_idempotency_key,
+                avs_result: storage_model.avs_result,
+                cvv_result: storage_model.cvv_result,
+                acquirer_reference_number: storage_model.acquirer_reference_number,
+                card_credential_type: storage_model.card_credential_type,
             })
         }
         .await
@@ -2017,6 +2077,7 @@ impl behaviour::Conversion for PaymentAttempt {
             extended_authorization_applied: self.extended_authorization_applied,
             capture_before: self.capture_before,
             card_discovery: self.card_discovery,
+            connector_idempotency_key: self.connector_idempotency_key,
         })
     }
 }
@@ -64,6 +64,18 @@ pub trait PaymentIntentInterface {
         merchant_key_store: &MerchantKeyStore,
         storage_scheme: common_enums::MerchantStorageScheme,
     ) -> error_stack::Result<PaymentIntent, Self::Error>;
+
+    /// Finds the payment intent without reading from a replica of the database, which may lag
+    /// behind the writes
+    #[cfg(feature = "v1")]
+    async fn find_payment_intent_by_payment_id_merchant_id_from_master(
+        &self,
+        state: &KeyManagerState,
+        payment_id: &id_type::PaymentId,
+        merchant_id: &id_type::MerchantId,
+        merchant_key_store: &MerchantKeyStore,
+        storage_scheme: common_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<PaymentIntent, Self::Error>;
     #[cfg(feature = "v2")]
     async fn find_payment_intent_by_merchant_reference_id_profile_id(
         &self,
@@ -230,6 +242,8 @@ pub struct PaymentIntentUpdateFields {
     pub shipping_details: Option<Encryptable<Secret<serde_json::Value>>>,
     pub is_payment_processor_token_flow: Option<bool>,
     pub tax_details: Option<diesel_models::TaxDetails>,
+    pub customer_email_hash: Option<String>,
+    pub customer_phone_hash: Option<String>,
 }
 
 #[cfg(feature = "v1")]
@@ -392,6 +406,8 @@ pub struct PaymentIntentUpdateInternal {
     pub shipping_details: Option<Encryptable<Secret<serde_json::Value>>>,
     pub is_payment_processor_token_flow: Option<bool>,
     pub tax_details: Option<diesel_models::TaxDetails>,
+    pub customer_email_hash: Option<String>,
+    pub customer_phone_hash: Option<String>,
 }
 
 // This conversion is used in the `update_payment_intent` function
@@ -725,6 +741,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: value.merchant_order_reference_id,
                 shipping_details: value.shipping_details,
                 is_payment_processor_token_flow: value.is_payment_processor_token_flow,
+                customer_email_hash: value.customer_email_hash,
+                customer_phone_hash: value.customer_phone_hash,
                 ..Default::default()
             },
             PaymentIntentUpdate::PaymentCreateUpdate {
@@ -954,6 +972,8 @@ impl From<PaymentIntentUpdate> for DieselPaymentIntentUpdate {
                     shipping_details: value.shipping_details.map(Encryption::from),
                     is_payment_processor_token_flow: value.is_payment_processor_token_flow,
                     tax_details: value.tax_details,
+                    customer_email_hash: value.customer_email_hash,
+                    customer_phone_hash: value.customer_phone_hash,
                 }))
             }
             PaymentIntentUpdate::PaymentCreateUpdate {
@@ -1110,6 +1130,8 @@ impl From<PaymentIntentUpdateInternal> for diesel_models::PaymentIntentUpdateInt
             shipping_details,
             is_payment_processor_token_flow,
             tax_details,
+            customer_email_hash,
+            customer_phone_hash,
         } = value;
         Self {
             amount,
@@ -1148,6 +1170,8 @@ impl From<PaymentIntentUpdateInternal> for diesel_models::PaymentIntentUpdateInt
             shipping_details: shipping_details.map(Encryption::from),
             is_payment_processor_token_flow,
             tax_details,
+            customer_email_hash,
+            customer_phone_hash,
         }
     }
 }
@@ -1212,6 +1236,10 @@ pub struct PaymentIntentListParams {
     pub card_network: Option<Vec<common_enums::CardNetwork>>,
     pub card_discovery: Option<Vec<common_enums::CardDiscovery>>,
     pub merchant_order_reference_id: Option<String>,
+    pub customer_email_hash: Option<String>,
+    pub customer_phone_hash: Option<String>,
+    /// The keys and the stored values of the custom fields which the payments must have
+    pub custom_fields: Option<Vec<(String, String)>>,
 }
 
 #[cfg(feature = "v2")]
@@ -1272,6 +1300,9 @@ impl From<api_models::payments::PaymentListConstraints> for PaymentIntentFetchCo
             card_network: None,
             card_discovery: None,
             merchant_order_reference_id: None,
+            customer_email_hash: None,
+            customer_phone_hash: None,
+            custom_fields: None,
         }))
     }
 }
@@ -1366,6 +1397,9 @@ impl From<common_utils::types::TimeRange> for PaymentIntentFetchConstraints {
             card_network: None,
             card_discovery: None,
             merchant_order_reference_id: None,
+            customer_email_hash: None,
+            customer_phone_hash: None,
+            custom_fields: None,
         }))
     }
 }
@@ -1392,6 +1426,8 @@ impl From<api_models::payments::PaymentListFilterConstraints> for PaymentIntentF
             card_network,
             card_discovery,
             merchant_order_reference_id,
+            // The filters by the custom fields are validated against their types by the router
+            custom_fields: _,
         } = value;
         if let Some(payment_intent_id) = payment_id {
             Self::Single { payment_intent_id }
@@ -1417,6 +1453,9 @@ impl From<api_models::payments::PaymentListFilterConstraints> for PaymentIntentF
                 card_network,
                 card_discovery,
                 merchant_order_reference_id,
+                customer_email_hash: None,
+                customer_phone_hash: None,
+                custom_fields: None,
             }))
         }
     }
@@ -1868,6 +1907,13 @@ impl behaviour::Conversion for PaymentIntent {
             request_extended_authorization: self.request_extended_authorization,
             psd2_sca_exemption_type: self.psd2_sca_exemption_type,
             platform_merchant_id: self.platform_merchant_id,
+            customer_email_hash: self.customer_email_hash,
+            customer_phone_hash: self.customer_phone_hash,
+            change_sequence: self.change_sequence,
+            is_amount_estimated: self.is_amount_estimated,
+            enable_partial_payments: self.enable_partial_payments,
+            store_credit_amount: self.store_credit_amount,
+            is_test_payment: self.is_test_payment,
         })
     }
 
@@ -1958,6 +2004,13 @@ impl behaviour::Conversion for PaymentIntent {
                 request_extended_authorization: storage_model.request_extended_authorization,
                 psd2_sca_exemption_type: storage_model.psd2_sca_exemption_type,
                 platform_merchant_id: storage_model.platform_merchant_id,
+                customer_email_hash: storage_model.customer_email_hash,
+                customer_phone_hash: storage_model.customer_phone_hash,
+                change_sequence: storage_model.change_sequence,
+                is_amount_estimated: storage_model.is_amount_estimated,
+                enable_partial_payments: storage_model.enable_partial_payments,
+                store_credit_amount: storage_model.store_credit_amount,
+                is_test_payment: storage_model.is_test_payment,
             })
         }
         .await
@@ -2024,6 +2077,12 @@ impl behaviour::Conversion for PaymentIntent {
             request_extended_authorization: self.request_extended_authorization,
             psd2_sca_exemption_type: self.psd2_sca_exemption_type,
             platform_merchant_id: self.platform_merchant_id,
+            customer_email_hash: self.customer_email_hash,
+            customer_phone_hash: self.customer_phone_hash,
+            is_amount_estimated: self.is_amount_estimated,
+            enable_partial_payments: self.enable_partial_payments,
+            store_credit_amount: self.store_credit_amount,
+            is_test_payment: self.is_test_payment,
         })
     }
 }
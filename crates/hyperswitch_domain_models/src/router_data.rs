@@ -102,6 +102,9 @@ pub struct RouterData<Flow, Request, Response> {
     pub authentication_id: Option<String>,
     /// Contains the type of sca exemption required for the transaction
     pub psd2_sca_exemption_type: Option<common_enums::ScaExemptionType>,
+
+    /// Contains the profile under which the call to the connector is made
+    pub profile_id: Option<id_type::ProfileId>,
 }
 
 // Different patterns of authentication.
@@ -363,6 +366,9 @@ pub struct PaymentMethodBalance {
 pub struct ConnectorResponseData {
     pub additional_payment_method_data: Option<AdditionalPaymentMethodConnectorResponse>,
     extended_authorization_response_data: Option<ExtendedAuthorizationResponseData>,
+    /// The reference number assigned by the acquirer to the capture or the refund, once it is
+    /// submitted to the card network
+    acquirer_reference_number: Option<String>,
 }
 
 impl ConnectorResponseData {
@@ -372,6 +378,14 @@ impl ConnectorResponseData {
         Self {
             additional_payment_method_data: Some(additional_payment_method_data),
             extended_authorization_response_data: None,
+            acquirer_reference_number: None,
+        }
+    }
+    pub fn with_acquirer_reference_number(acquirer_reference_number: String) -> Self {
+        Self {
+            additional_payment_method_data: None,
+            extended_authorization_response_data: None,
+            acquirer_reference_number: Some(acquirer_reference_number),
         }
     }
     pub fn get_extended_authorization_response_data(
@@ -379,6 +393,9 @@ impl ConnectorResponseData {
     ) -> Option<&ExtendedAuthorizationResponseData> {
         self.extended_authorization_response_data.as_ref()
     }
+    pub fn get_acquirer_reference_number(&self) -> Option<&String> {
+        self.acquirer_reference_number.as_ref()
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
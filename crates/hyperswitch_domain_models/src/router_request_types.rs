@@ -76,6 +76,10 @@ pub struct PaymentsAuthorizeData {
     pub additional_payment_method_data: Option<AdditionalPaymentData>,
     pub merchant_account_id: Option<Secret<String>>,
     pub merchant_config_currency: Option<storage_enums::Currency>,
+    /// Details of the sub-merchant, sent to the connectors which accept payment facilitator fields
+    pub payment_facilitator_details: Option<api_models::payments::PaymentFacilitatorDetails>,
+    /// Idempotency key of the payment attempt, sent to the connectors which support idempotency
+    pub connector_idempotency_key: Option<String>,
 }
 #[derive(Debug, Clone)]
 pub struct PaymentsPostSessionTokensData {
@@ -94,6 +98,14 @@ pub struct PaymentsPostSessionTokensData {
     pub router_return_url: Option<String>,
 }
 
+/// Request to inquire the balance of a gift card with the connector
+#[derive(Debug, Clone)]
+pub struct GiftCardBalanceCheckRequestData {
+    pub payment_method_data: PaymentMethodData,
+    /// The currency of the payment which the gift card is to be redeemed for
+    pub currency: storage_enums::Currency,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct AuthoriseIntegrityObject {
     /// Authorise amount
@@ -652,6 +664,9 @@ pub struct RefundsData {
     pub merchant_account_id: Option<Secret<String>>,
     pub merchant_config_currency: Option<storage_enums::Currency>,
     pub capture_method: Option<storage_enums::CaptureMethod>,
+    /// End-to-end reference of the refund of a bank transfer payment, for the connectors which
+    /// pass it as the remittance information of the refund
+    pub end_to_end_reference: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -315,6 +315,18 @@ pub trait ConnectorSpecifications {
     fn get_connector_about(&self) -> Option<&'static ConnectorInfo> {
         None
     }
+
+    /// Number of days after the creation of a payment within which the connector accepts its
+    /// refunds
+    fn get_refund_validity_days(&self) -> Option<i64> {
+        None
+    }
+
+    /// Whether the connector can release the uncaptured remainder of a partially captured
+    /// authorization
+    fn is_authorization_remainder_release_supported(&self) -> bool {
+        false
+    }
 }
 
 /// Extended trait for connector common to allow functions with generic type
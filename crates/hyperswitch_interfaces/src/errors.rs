@@ -119,6 +119,8 @@ pub enum ConnectorError {
     InvalidConnectorConfig { config: &'static str },
     #[error("Failed to convert amount to required type")]
     AmountConversionFailed,
+    #[error("Calls to the connector {connector} are throttled as per its rate limit")]
+    ConnectorThrottled { connector: String },
     #[error("Generic Error")]
     GenericError {
         error_message: String,
@@ -152,6 +154,9 @@ impl ErrorSwitch<ApiErrorResponse> for ConnectorError {
             Self::WebhookVerificationSecretInvalid => {
                 ApiErrorResponse::WebhookInvalidMerchantSecret
             }
+            Self::ConnectorThrottled { connector } => ApiErrorResponse::ConnectorThrottled {
+                connector: connector.to_owned(),
+            },
             _ => ApiErrorResponse::InternalServerError,
         }
     }
@@ -276,6 +276,17 @@ pub trait IncomingWebhook: ConnectorCommon + Sync {
         Ok(None)
     }
 
+    /// fn get_webhook_event_timestamp
+    ///
+    /// Returns the time at which the event occurred at the connector, which is used to discard
+    /// the webhooks which are delivered after a more recent webhook of the same object
+    fn get_webhook_event_timestamp(
+        &self,
+        _request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<time::PrimitiveDateTime>, errors::ConnectorError> {
+        Ok(None)
+    }
+
     #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
     /// get revenue recovery invoice details
     fn get_revenue_recovery_attempt_details(
@@ -0,0 +1,246 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use api_models::{admin as admin_api, enums as api_enums, refunds::MinorUnit};
+use euclid::{
+    dssa::graph::{AnalysisContext, CgraphExt},
+    frontend::{ast, dir},
+    types::NumValue,
+};
+use hyperswitch_constraint_graph as cgraph;
+use strum::IntoEnumIterator;
+
+use crate::{error::KgraphError, transformers::IntoDirValue, types as kgraph_types};
+
+/// Checks whether the connector can accept the payment method type in the currency, from the
+/// billing country if one is given. The amount of the payment is left out of the check by
+/// checking a zero amount payment, which is allowed irrespective of the amount limits.
+fn is_covered(
+    graph: &cgraph::ConstraintGraph<dir::DirValue>,
+    connector: api_enums::RoutableConnectors,
+    payment_method: api_enums::PaymentMethod,
+    payment_method_type: api_enums::PaymentMethodType,
+    currency: api_enums::Currency,
+    country: Option<api_enums::CountryAlpha2>,
+) -> Result<bool, KgraphError> {
+    let mut context = vec![
+        payment_method.into_dir_value()?,
+        (payment_method_type, payment_method).into_dir_value()?,
+        currency.into_dir_value()?,
+        dir::DirValue::PaymentAmount(NumValue {
+            number: MinorUnit::zero(),
+            refinement: None,
+        }),
+    ];
+    if let Some(country) = country {
+        context.push(dir::DirValue::BillingCountry(
+            api_enums::Country::from_alpha2(country),
+        ));
+    }
+
+    graph
+        .check_value_validity(
+            ast::ConnectorChoice { connector }.into_dir_value()?,
+            &AnalysisContext::from_dir_values(context),
+            &mut cgraph::Memoization::new(),
+            &mut cgraph::CycleCheck::new(),
+            None,
+        )
+        .map_err(KgraphError::GraphAnalysisError)
+}
+
+/// Returns the countries configured for the payment method type of the connector, or `None` if
+/// the payment method type is not restricted to any countries
+fn get_candidate_countries(
+    config: &kgraph_types::CountryCurrencyFilter,
+    connector: api_enums::RoutableConnectors,
+    payment_method_type: api_enums::PaymentMethodType,
+) -> Option<Vec<api_enums::CountryAlpha2>> {
+    let filters = config
+        .connector_configs
+        .get(&connector)
+        .or(config.default_configs.as_ref())?;
+
+    let is_restricted = filters
+        .0
+        .get(&kgraph_types::PaymentMethodFilterKey::PaymentMethodType(
+            payment_method_type,
+        ))
+        .is_some_and(|filter| filter.country.is_some());
+    if !is_restricted {
+        return None;
+    }
+
+    // The filters of the other payment method types of the connector can also allow the payment
+    // method type, as in the case of the credit and the debit cards
+    let mut countries = filters
+        .0
+        .values()
+        .filter_map(|filter| filter.country.clone())
+        .flatten()
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+    countries.sort_by_key(ToString::to_string);
+
+    Some(countries)
+}
+
+/// Returns the currencies the connector can accept the payment method type in, along with the
+/// countries it can be accepted from. The currency and the country constraints of the graph are
+/// independent of each other, so the countries are checked only with the first of the currencies.
+fn get_connector_coverage(
+    graph: &cgraph::ConstraintGraph<dir::DirValue>,
+    config: &kgraph_types::CountryCurrencyFilter,
+    connector: api_enums::RoutableConnectors,
+    payment_method: api_enums::PaymentMethod,
+    payment_method_type: api_enums::PaymentMethodType,
+) -> Result<Vec<admin_api::CurrencyCoverage>, KgraphError> {
+    let mut currencies = Vec::new();
+    for currency in api_enums::Currency::iter() {
+        if is_covered(
+            graph,
+            connector,
+            payment_method,
+            payment_method_type,
+            currency,
+            None,
+        )? {
+            currencies.push(currency);
+        }
+    }
+
+    let countries = match (
+        currencies.first(),
+        get_candidate_countries(config, connector, payment_method_type),
+    ) {
+        (Some(currency), Some(candidate_countries)) => {
+            let mut countries = Vec::new();
+            for country in candidate_countries {
+                if is_covered(
+                    graph,
+                    connector,
+                    payment_method,
+                    payment_method_type,
+                    *currency,
+                    Some(country),
+                )? {
+                    countries.push(country);
+                }
+            }
+
+            if countries.is_empty() {
+                return Ok(Vec::new());
+            }
+            Some(countries)
+        }
+        (_, None) | (None, _) => None,
+    };
+
+    Ok(currencies
+        .into_iter()
+        .map(|currency| admin_api::CurrencyCoverage {
+            currency,
+            countries: countries.clone(),
+        })
+        .collect())
+}
+
+/// Builds the coverage of the merchant connector accounts from their constraint graph, that is the
+/// payment method types they can accept, along with the currencies and the billing countries
+/// they can be accepted in
+pub fn get_coverage(
+    graph: &cgraph::ConstraintGraph<dir::DirValue>,
+    accts: &[admin_api::MerchantConnectorResponse],
+    config: &kgraph_types::CountryCurrencyFilter,
+) -> Result<Vec<admin_api::PaymentMethodCoverage>, KgraphError> {
+    let mut coverage: Vec<admin_api::PaymentMethodCoverage> = Vec::new();
+    let mut checked = HashSet::new();
+
+    for acct in accts {
+        let connector = api_enums::RoutableConnectors::from_str(&acct.connector_name)
+            .map_err(|_| KgraphError::InvalidConnectorName(acct.connector_name.clone()))?;
+
+        for pm_enabled in acct.payment_methods_enabled.iter().flatten() {
+            for pm_types in pm_enabled.payment_method_types.iter().flatten() {
+                let payment_method = pm_enabled.payment_method;
+                let payment_method_type = pm_types.payment_method_type;
+                if !checked.insert((connector, payment_method, payment_method_type)) {
+                    continue;
+                }
+
+                let connector_coverage = get_connector_coverage(
+                    graph,
+                    config,
+                    connector,
+                    payment_method,
+                    payment_method_type,
+                )?;
+                if connector_coverage.is_empty() {
+                    continue;
+                }
+
+                match coverage.iter_mut().find(|pm_coverage| {
+                    pm_coverage.payment_method == payment_method
+                        && pm_coverage.payment_method_type == payment_method_type
+                }) {
+                    Some(pm_coverage) => {
+                        merge_currency_coverage(&mut pm_coverage.currencies, connector_coverage)
+                    }
+                    None => coverage.push(admin_api::PaymentMethodCoverage {
+                        payment_method,
+                        payment_method_type,
+                        currencies: connector_coverage,
+                    }),
+                }
+            }
+        }
+    }
+
+    for pm_coverage in coverage.iter_mut() {
+        pm_coverage
+            .currencies
+            .sort_by_key(|currency_coverage| currency_coverage.currency.to_string());
+    }
+
+    Ok(coverage)
+}
+
+/// Merges the currencies of a connector into the currencies of the other connectors. A payment
+/// method type accepted from any country by one of the connectors is accepted from any country.
+fn merge_currency_coverage(
+    currencies: &mut Vec<admin_api::CurrencyCoverage>,
+    connector_currencies: Vec<admin_api::CurrencyCoverage>,
+) {
+    let mut indices = currencies
+        .iter()
+        .enumerate()
+        .map(|(index, currency_coverage)| (currency_coverage.currency, index))
+        .collect::<HashMap<_, _>>();
+
+    for connector_currency in connector_currencies {
+        let Some(existing) = indices
+            .get(&connector_currency.currency)
+            .and_then(|index| currencies.get_mut(*index))
+        else {
+            indices.insert(connector_currency.currency, currencies.len());
+            currencies.push(connector_currency);
+            continue;
+        };
+
+        existing.countries = match (existing.countries.take(), connector_currency.countries) {
+            (Some(mut countries), Some(connector_countries)) => {
+                for country in connector_countries {
+                    if !countries.contains(&country) {
+                        countries.push(country);
+                    }
+                }
+                countries.sort_by_key(ToString::to_string);
+                Some(countries)
+            }
+            (None, _) | (_, None) => None,
+        };
+    }
+}
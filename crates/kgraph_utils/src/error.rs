@@ -9,6 +9,8 @@ pub enum KgraphError {
     DomainCreationError,
     #[error("There was an error constructing the graph: {0}")]
     GraphConstructionError(hyperswitch_constraint_graph::GraphError<dir::DirValue>),
+    #[error("There was an error analysing the graph: {0}")]
+    GraphAnalysisError(hyperswitch_constraint_graph::GraphError<dir::DirValue>),
     #[error("There was an error constructing the context")]
     ContextConstructionError(AnalysisErrorType),
     #[error("there was an unprecedented indexing error")]
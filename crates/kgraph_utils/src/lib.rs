@@ -1,3 +1,5 @@
+#[cfg(feature = "v1")]
+pub mod coverage;
 pub mod error;
 pub mod mca;
 pub mod transformers;
@@ -58,9 +58,18 @@ impl IntoContext for BackendInput {
         if let Some(card_network) = self.payment_method.card_network {
             ctx.push(dir::DirValue::CardNetwork(card_network));
         }
+        if let Some(card_product_type) = self.payment_method.card_product_type {
+            ctx.push(dir::DirValue::CardProductType(card_product_type));
+        }
         if let Some(setup_future_usage) = self.payment.setup_future_usage {
             ctx.push(dir::DirValue::SetupFutureUsage(setup_future_usage));
         }
+        if let Some(issuer_frictionless_rate) = self.payment.issuer_frictionless_rate {
+            ctx.push(dir::DirValue::IssuerFrictionlessRate(NumValue {
+                number: issuer_frictionless_rate,
+                refinement: None,
+            }));
+        }
         if let Some(mandate_acceptance_type) = self.mandate.mandate_acceptance_type {
             ctx.push(dir::DirValue::MandateAcceptanceType(
                 mandate_acceptance_type,
@@ -56,6 +56,7 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "Profile", description = "Create and manage profiles"),
         (name = "Merchant Connector Account", description = "Create and manage merchant connector accounts"),
         (name = "Payments", description = "Create and manage one-time payments, recurring payments and mandates"),
+        (name = "Checkout Sessions", description = "Hold the cart before a payment is created and convert it to a payment"),
         (name = "Refunds", description = "Create and manage refunds for successful payments"),
         (name = "Mandates", description = "Manage mandates"),
         (name = "Customers", description = "Create and manage customers"),
@@ -66,6 +67,14 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "payment link", description = "Create payment link"),
         (name = "Routing", description = "Create and manage routing configurations"),
         (name = "Event", description = "Manage events"),
+        (name = "Webhook Payload Templates", description = "Customize the payloads of the outgoing webhooks"),
+        (name = "Webhook Endpoints", description = "Monitor the health of the endpoints receiving the outgoing webhooks"),
+        (name = "Warehouse Sync", description = "Sync the payments, refunds and disputes to a data warehouse"),
+        (name = "Sanctions Screening", description = "Review the sanctions screening cases of payments and payouts"),
+        (name = "SCA Compliance", description = "Generate the PSD2 strong customer authentication compliance reports"),
+        (name = "Sandbox", description = "Generate test data and control the test clock in the sandbox"),
+        (name = "Process Tracker", description = "Inspect and manage the scheduler tasks"),
+        (name = "SDK Events", description = "Ingest the events generated by the SDK"),
     ),
     // The paths will be displayed in the same order as they are registered here
     paths(
@@ -77,22 +86,60 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payments::payments_capture,
         routes::payments::payments_connector_session,
         routes::payments::payments_cancel,
+        routes::payments::payments_cancel_remaining,
         routes::payments::payments_list,
         routes::payments::payments_incremental_authorization,
         routes::payment_link::payment_link_retrieve,
         routes::payments::payments_external_authentication,
+        routes::payments::payments_resume_authentication,
         routes::payments::payments_complete_authorize,
         routes::payments::payments_post_session_tokens,
+        routes::payments::payments_timeline,
+        routes::payments::payments_dunning_retrieve,
+        routes::payments::payments_metadata_update,
+        routes::payments::payments_notes_add,
+        routes::payments::payments_notes_list,
+        routes::payments::payments_capture_schedule,
+        routes::payments::payments_capture_schedule_cancel,
+        routes::payments::payments_capture_schedule_release,
+        routes::payments::payments_finalize_amount,
+        routes::payments::payments_gift_card_balance,
+        routes::payments::payments_search_by_contact,
+        routes::payments::payments_lookup,
+        routes::payments::payments_changes,
+        routes::payments::payments_import_acquirer_reference_numbers,
+        routes::payments::payments_send_receipt_email,
+
+        // Routes for checkout sessions
+        routes::checkout_sessions::checkout_session_create,
+        routes::checkout_sessions::checkout_session_retrieve,
+        routes::checkout_sessions::checkout_session_update,
+        routes::checkout_sessions::checkout_session_confirm,
+
+        // Routes for sanctions screening
+        routes::sanctions_screening::screening_case_retrieve,
+        routes::sanctions_screening::screening_case_resolve,
 
         // Routes for relay
         routes::relay::relay,
         routes::relay::relay_retrieve,
 
+        // Routes for sandbox
+        routes::sandbox::seed_sandbox_data,
+        routes::sandbox::delete_sandbox_data,
+        routes::sandbox::retrieve_test_clock,
+        routes::sandbox::advance_test_clock,
+        routes::sandbox::reset_test_clock,
+
         // Routes for refunds
         routes::refunds::refunds_create,
         routes::refunds::refunds_retrieve,
         routes::refunds::refunds_update,
         routes::refunds::refunds_list,
+        routes::refunds::refunds_batch_create,
+        routes::refunds::refunds_batch_retrieve,
+        routes::refunds::refunds_batch_report,
+        routes::refunds::refunds_resolve,
 
         // Routes for Organization
         routes::organization::organization_create,
@@ -105,6 +152,19 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::merchant_account::update_merchant_account,
         routes::merchant_account::delete_merchant_account,
         routes::merchant_account::merchant_account_kv_status,
+        routes::merchant_account::kv_pg_divergences_list,
+        routes::merchant_account::merchant_feature_flags_retrieve,
+        routes::merchant_account::merchant_feature_flags_update,
+        routes::merchant_account::merchant_fault_injection_upsert,
+        routes::merchant_account::merchant_fault_injection_retrieve,
+        routes::merchant_account::merchant_fault_injection_delete,
+        routes::merchant_account::merchant_account_status_update,
+        routes::merchant_account::merchant_account_offboard,
+        routes::merchant_account::merchant_account_offboarding_retrieve,
+        routes::merchant_account::connector_transaction_id_backfill_create,
+        routes::merchant_account::connector_transaction_id_backfill_retrieve,
+        routes::merchant_account::data_residency_create,
+        routes::merchant_account::data_residency_retrieve,
 
         // Routes for merchant connector account
         routes::merchant_connector_account::connector_create,
@@ -112,6 +172,14 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::merchant_connector_account::connector_list,
         routes::merchant_connector_account::connector_update,
         routes::merchant_connector_account::connector_delete,
+        routes::merchant_connector_account::connector_restore,
+        routes::merchant_connector_account::connector_maintenance_windows_upsert,
+        routes::merchant_connector_account::connector_maintenance_windows_retrieve,
+        routes::merchant_connector_account::connector_credentials_retrieve,
+        routes::merchant_connector_account::connector_credentials_update,
+        routes::merchant_connector_account::connector_credentials_verify,
+        routes::merchant_connector_account::connector_credentials_expiring_list,
+        routes::merchant_connector_account::connector_bulk_config,
 
         //Routes for gsm
         routes::gsm::create_gsm_rule,
@@ -119,6 +187,12 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::gsm::update_gsm_rule,
         routes::gsm::delete_gsm_rule,
 
+        // Routes for process tracker
+        routes::process_tracker::retrieve_scheduler_tasks_summary,
+        routes::process_tracker::list_scheduler_tasks,
+        routes::process_tracker::requeue_scheduler_task,
+        routes::process_tracker::abandon_scheduler_task,
+
         // Routes for mandates
         routes::mandates::get_mandate,
         routes::mandates::revoke_mandate,
@@ -130,6 +204,9 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::customers::customers_list,
         routes::customers::customers_update,
         routes::customers::customers_delete,
+        routes::customers::customers_payments_list,
+        routes::customers::customers_store_credit_retrieve,
+        routes::customers::customers_store_credit_entries_list,
 
         //Routes for payment methods
         routes::payment_method::create_payment_method_api,
@@ -137,9 +214,14 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payment_method::list_customer_payment_method_api,
         routes::payment_method::list_customer_payment_method_api_client,
         routes::payment_method::default_payment_method_set_api,
+        routes::payment_method::customer_payment_method_update_api,
         routes::payment_method::payment_method_retrieve_api,
         routes::payment_method::payment_method_update_api,
+        routes::payment_method::payment_method_consent_recollect_api,
+        routes::payment_method::payment_method_consent_revoke_api,
         routes::payment_method::payment_method_delete_api,
+        routes::payment_method::tokenize_cvv_api,
+        routes::payment_method::list_payment_method_display_names_api,
 
         // Routes for Profile
         routes::profile::profile_create,
@@ -147,15 +229,73 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::profile::profile_retrieve,
         routes::profile::profile_update,
         routes::profile::profile_delete,
+        routes::profile::payment_method_display_config_upsert,
+        routes::profile::payment_method_display_config_retrieve,
+        routes::profile::payment_method_display_config_delete,
+        routes::profile::payment_method_display_names_upsert,
+        routes::profile::payment_method_display_names_retrieve,
+        routes::profile::payment_method_display_names_delete,
+        routes::profile::external_vault_config_upsert,
+        routes::profile::external_vault_config_retrieve,
+        routes::profile::external_vault_config_delete,
+        routes::profile::cvv_recollection_config_upsert,
+        routes::profile::cvv_recollection_config_retrieve,
+        routes::profile::network_token_preference_config_upsert,
+        routes::profile::network_token_preference_config_retrieve,
+        routes::profile::profile_feature_flags_update,
+        routes::profile::profile_feature_flags_retrieve,
+        routes::profile::payment_facilitator_details_upsert,
+        routes::profile::payment_facilitator_details_retrieve,
+        routes::profile::payment_facilitator_details_delete,
+        routes::profile::custom_payment_status_mapping_upsert,
+        routes::profile::custom_payment_status_mapping_retrieve,
+        routes::profile::custom_payment_status_mapping_delete,
+        routes::profile::custom_fields_schema_upsert,
+        routes::profile::custom_fields_schema_retrieve,
+        routes::profile::custom_fields_schema_delete,
+        routes::profile::unified_error_message_overrides_upsert,
+        routes::profile::unified_error_message_overrides_retrieve,
+        routes::profile::unified_error_message_overrides_delete,
+        routes::profile::post_authorization_rules_upsert,
+        routes::profile::post_authorization_rules_retrieve,
+        routes::profile::post_authorization_rules_delete,
+        routes::profile::checkout_theme_upsert,
+        routes::profile::checkout_theme_retrieve,
+        routes::profile::checkout_theme_delete,
+        routes::profile::checkout_theme_versions_list,
+        routes::profile::checkout_theme_activate,
+        routes::profile::dunning_config_upsert,
+        routes::profile::dunning_config_retrieve,
+        routes::profile::dunning_config_delete,
+        routes::profile::auto_capture_delay_config_upsert,
+        routes::profile::auto_capture_delay_config_retrieve,
+        routes::profile::auto_capture_delay_config_delete,
+        routes::profile::step_up_authentication_config_upsert,
+        routes::profile::step_up_authentication_config_retrieve,
+        routes::profile::step_up_authentication_config_delete,
+        routes::profile::profile_test_mode_update,
+        routes::profile::profile_test_mode_retrieve,
+        routes::profile::test_data_purge,
+        routes::profile::profile_coverage_retrieve,
 
         // Routes for disputes
         routes::disputes::retrieve_dispute,
         routes::disputes::retrieve_disputes_list,
+        routes::disputes::get_disputes_balance,
 
         // Routes for routing
         routes::routing::routing_create_config,
         routes::routing::routing_link_config,
         routes::routing::routing_retrieve_config,
+        routes::routing::routing_export_config,
+        routes::routing::routing_import_config,
+        routes::routing::routing_experiment_create,
+        routes::routing::routing_experiment_list,
+        routes::routing::routing_experiment_retrieve,
+        routes::routing::routing_experiment_conclude,
+        routes::routing::routing_volume_caps_upsert,
+        routes::routing::routing_volume_caps_retrieve,
+        routes::routing::routing_simulate,
         routes::routing::list_routing_configs,
         routes::routing::routing_unlink_config,
         routes::routing::routing_update_default_config,
@@ -198,9 +338,36 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::webhook_events::list_initial_webhook_delivery_attempts_with_jwtauth,
         routes::webhook_events::list_webhook_delivery_attempts,
         routes::webhook_events::retry_webhook_delivery_attempt,
+        routes::webhook_events::list_event_types,
+        routes::webhook_events::list_lifecycle_events,
+
+        // Routes for webhook payload templates
+        routes::webhook_payload_templates::set_webhook_payload_template,
+        routes::webhook_payload_templates::retrieve_webhook_payload_template,
+        routes::webhook_payload_templates::list_webhook_payload_templates,
+        routes::webhook_payload_templates::delete_webhook_payload_template,
+        routes::webhook_payload_templates::validate_webhook_payload_template,
+        routes::webhook_payload_templates::preview_webhook_payload_template,
+        routes::webhook_endpoints::retrieve_webhook_endpoint_health,
+        routes::webhook_endpoints::resume_webhook_endpoint,
+
+        // Routes for warehouse sync
+        routes::warehouse_sync::set_warehouse_sync_config,
+        routes::warehouse_sync::retrieve_warehouse_sync_config,
+        routes::warehouse_sync::delete_warehouse_sync_config,
+        routes::warehouse_sync::retrieve_warehouse_sync_status,
+        routes::warehouse_sync::trigger_warehouse_sync,
+
+        // Routes for SCA compliance reports
+        routes::sca_compliance::create_sca_compliance_report,
+        routes::sca_compliance::retrieve_sca_compliance_report,
+        routes::sca_compliance::download_sca_compliance_report,
 
         // Routes for poll apis
         routes::poll::retrieve_poll_status,
+
+        // Routes for SDK events
+        routes::sdk_events::ingest_sdk_events,
     ),
     components(schemas(
         common_utils::types::MinorUnit,
@@ -235,16 +402,47 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::refunds::RefundRequest,
         api_models::refunds::RefundType,
         api_models::refunds::RefundResponse,
+        api_models::refunds::RefundEstimatedArrival,
         api_models::refunds::RefundStatus,
         api_models::refunds::RefundUpdateRequest,
+        api_models::refunds::RefundResolveRequest,
+        api_models::refunds::RefundBatchRecord,
+        api_models::refunds::RefundBatchStatus,
+        api_models::refunds::RefundBatchRowStatus,
+        api_models::refunds::RefundBatchRowResult,
+        api_models::refunds::RefundBatchResponse,
         api_models::organization::OrganizationCreateRequest,
         api_models::organization::OrganizationUpdateRequest,
         api_models::organization::OrganizationResponse,
         api_models::admin::MerchantAccountCreate,
         api_models::admin::MerchantAccountUpdate,
         api_models::admin::MerchantAccountDeleteResponse,
+        api_models::admin::MerchantAccountStatusUpdateRequest,
+        api_models::admin::MerchantAccountStatusResponse,
+        api_models::admin::MerchantOffboardingRequest,
+        api_models::admin::MerchantOffboardingResponse,
+        api_models::admin::MerchantOffboardingStatus,
+        api_models::admin::ConnectorTransactionIdBackfillRequest,
+        api_models::admin::ConnectorTransactionIdBackfillResponse,
+        api_models::admin::ConnectorTransactionIdBackfillStatus,
+        api_models::admin::ConnectorTransactionIdBackfillFailure,
+        api_models::admin::DataResidencyRequest,
+        api_models::admin::DataResidencyResponse,
+        api_models::admin::DataResidencyStatus,
         api_models::admin::MerchantConnectorDeleteResponse,
         api_models::admin::MerchantConnectorResponse,
+        api_models::admin::ConnectorMaintenanceWindow,
+        api_models::admin::ConnectorMaintenanceWindowsRequest,
+        api_models::admin::ConnectorMaintenanceWindowsResponse,
+        api_models::admin::MerchantConnectorProfileOverride,
+        api_models::admin::MerchantConnectorBulkConfigRequest,
+        api_models::admin::MerchantConnectorBulkConfigAction,
+        api_models::admin::MerchantConnectorBulkConfigStatus,
+        api_models::admin::MerchantConnectorBulkConfigResult,
+        api_models::admin::MerchantConnectorBulkConfigResponse,
+        api_models::admin::ConnectorCredentialsStatus,
+        api_models::admin::ConnectorCredentialsUpdateRequest,
+        api_models::admin::ConnectorCredentialsResponse,
         api_models::admin::MerchantConnectorListResponse,
         api_models::admin::AuthenticationConnectorDetails,
         api_models::admin::ExtendedCardInfoConfig,
@@ -256,6 +454,14 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::customers::CustomerRequest,
         api_models::customers::CustomerUpdateRequest,
         api_models::customers::CustomerDeleteResponse,
+        api_models::customers::CustomerPaymentsListResponse,
+        api_models::customers::CustomerPaymentStats,
+        api_models::customers::CustomerLifetimeValue,
+        api_models::customers::CustomerPreferredPaymentMethod,
+        api_models::customers::CustomerStoreCreditResponse,
+        api_models::customers::StoreCreditBalance,
+        api_models::customers::StoreCreditEntryResponse,
+        api_models::customers::StoreCreditEntriesListResponse,
         api_models::payment_methods::PaymentMethodCreate,
         api_models::payment_methods::PaymentMethodResponse,
         api_models::payment_methods::CustomerPaymentMethod,
@@ -266,17 +472,49 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payment_methods::CardNetworkTypes,
         api_models::payment_methods::BankDebitTypes,
         api_models::payment_methods::BankTransferTypes,
+        api_models::payment_methods::PaymentMethodDisplayConfig,
+        api_models::payment_methods::PaymentMethodDisplayOrder,
+        api_models::payment_methods::PaymentMethodVisibilityRule,
+        api_models::payment_methods::PaymentMethodDisplayOverride,
+        api_models::payment_methods::PaymentMethodDisplayNameOverrides,
+        api_models::payment_methods::PaymentMethodDisplayNamesRequest,
+        api_models::payment_methods::PaymentMethodDisplayName,
+        api_models::payment_methods::PaymentMethodTypeDisplayName,
+        api_models::payment_methods::PaymentMethodDisplayNamesResponse,
+        api_models::payment_methods::ExternalVaultConfigRequest,
+        api_models::payment_methods::ExternalVaultConfigResponse,
+        api_models::payment_methods::CvvRecollectionConfig,
+        api_models::payment_methods::NetworkTokenPreferenceConfig,
+        api_models::payment_methods::CvvTokenizeRequest,
+        api_models::payment_methods::CvvTokenizeResponse,
         api_models::payment_methods::CustomerPaymentMethodsListResponse,
         api_models::payment_methods::PaymentMethodDeleteResponse,
         api_models::payment_methods::PaymentMethodUpdate,
         api_models::payment_methods::CustomerDefaultPaymentMethodResponse,
+        api_models::payment_methods::CustomerPaymentMethodUpdateRequest,
+        api_models::payment_methods::CustomerPaymentMethodUpdateResponse,
+        api_models::payment_methods::DefaultPaymentMethodScope,
+        api_models::payment_methods::DefaultPaymentMethodChange,
+        api_models::payment_methods::PaymentMethodPreferenceUpdatedBy,
+        api_models::payment_methods::PaymentMethodConsent,
+        api_models::payment_methods::PaymentMethodConsentStatus,
+        api_models::payment_methods::PaymentMethodConsentAction,
+        api_models::payment_methods::PaymentMethodConsentEvent,
+        api_models::payment_methods::PaymentMethodConsentRecollectRequest,
+        api_models::payment_methods::PaymentMethodConsentRevokeRequest,
+        api_models::payment_methods::PaymentMethodConsentResponse,
         api_models::payment_methods::CardDetailFromLocker,
         api_models::payment_methods::PaymentMethodCreateData,
         api_models::payment_methods::CardDetail,
+        api_models::payment_methods::BankDebitDetail,
         api_models::payment_methods::CardDetailUpdate,
         api_models::payment_methods::RequestPaymentMethodTypes,
         api_models::poll::PollResponse,
         api_models::poll::PollStatus,
+        api_models::sdk_events::SdkEventsBatchRequest,
+        api_models::sdk_events::SdkEventRequest,
+        api_models::sdk_events::SdkEventsBatchResponse,
+        api_models::sdk_events::RejectedSdkEvent,
         api_models::customers::CustomerResponse,
         api_models::admin::AcceptedCountries,
         api_models::admin::AcceptedCurrencies,
@@ -285,6 +523,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::ScaExemptionType,
         api_models::enums::PaymentMethod,
         api_models::enums::TriggeredBy,
+        api_models::enums::RefundDestination,
+        api_models::enums::StoreCreditEntryType,
         api_models::enums::PaymentMethodType,
         api_models::enums::ConnectorType,
         api_models::enums::PayoutConnectors,
@@ -322,6 +562,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::PaymentMethodStatus,
         api_models::enums::UIWidgetFormLayout,
         api_models::enums::MerchantProductType,
+        api_models::enums::MerchantAccountStatus,
         api_models::enums::PaymentConnectorCategory,
         api_models::enums::CardDiscovery,
         api_models::enums::FeatureStatus,
@@ -348,7 +589,11 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::PaymentLinkTransactionDetails,
         api_models::admin::TransactionDetailsUiConfiguration,
         api_models::disputes::DisputeResponse,
+        api_models::disputes::DisputePaymentContext,
+        api_models::disputes::DisputePaymentContextField,
         api_models::disputes::DisputeResponsePaymentsRetrieve,
+        api_models::disputes::DisputesBalanceResponse,
+        api_models::disputes::DisputesCurrencyBalance,
         api_models::gsm::GsmCreateRequest,
         api_models::gsm::GsmRetrieveRequest,
         api_models::gsm::GsmUpdateRequest,
@@ -412,6 +657,11 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PayLaterData,
         api_models::payments::MandateData,
         api_models::payments::PhoneDetails,
+        api_models::payments::AddressValidationStatus,
+        api_models::payments::AddressValidationHintCode,
+        api_models::payments::AddressValidationHint,
+        api_models::payments::AddressValidationDetails,
+        api_models::payments::PaymentAddressValidation,
         api_models::payments::PaymentMethodData,
         api_models::payments::PaymentMethodDataRequest,
         api_models::payments::MandateType,
@@ -559,6 +809,27 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::relay::RelayRefundRequestData,
         api_models::enums::RelayStatus,
         api_models::relay::RelayError,
+        api_models::checkout_sessions::CheckoutSessionCreateRequest,
+        api_models::checkout_sessions::CheckoutSessionUpdateRequest,
+        api_models::checkout_sessions::CheckoutSessionResponse,
+        api_models::checkout_sessions::CheckoutSessionCustomer,
+        api_models::checkout_sessions::CheckoutSessionStatus,
+        api_models::sanctions_screening::ScreeningObjectType,
+        api_models::sanctions_screening::ScreeningCaseStatus,
+        api_models::sanctions_screening::ScreeningDecision,
+        api_models::sanctions_screening::ScreeningMatch,
+        api_models::sanctions_screening::ScreeningCaseResolveRequest,
+        api_models::sanctions_screening::ScreeningCaseResponse,
+        api_models::sandbox::SandboxDataSeedRequest,
+        api_models::sandbox::SandboxDataSeedResponse,
+        api_models::sandbox::TestClockAdvanceRequest,
+        api_models::sandbox::TestClockResponse,
+        api_models::process_tracker::SchedulerTaskType,
+        api_models::process_tracker::SchedulerTaskState,
+        api_models::process_tracker::SchedulerTask,
+        api_models::process_tracker::SchedulerTasksSummary,
+        api_models::process_tracker::SchedulerTasksSummaryResponse,
+        api_models::process_tracker::SchedulerTasksListResponse,
         api_models::payments::AmountFilter,
         api_models::mandates::MandateRevokedResponse,
         api_models::mandates::MandateResponse,
@@ -617,7 +888,25 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantDetails,
         api_models::admin::ToggleKVRequest,
         api_models::admin::ToggleKVResponse,
+        api_models::admin::KvPgDivergence,
+        api_models::admin::KvPgDivergencesListResponse,
+        api_models::admin::ProfileTestModeRequest,
+        api_models::admin::ProfileTestModeResponse,
+        api_models::admin::TestDataPurgeRequest,
+        api_models::admin::TestDataPurgeResponse,
+        api_models::admin::ProfileCoverageResponse,
+        api_models::admin::PaymentMethodCoverage,
+        api_models::admin::CurrencyCoverage,
+        api_models::enums::MerchantFeatureFlag,
+        api_models::feature_flags::FeatureFlagsUpdateRequest,
+        api_models::feature_flags::FeatureFlagSource,
+        api_models::feature_flags::FeatureFlag,
+        api_models::feature_flags::FeatureFlagsResponse,
+        api_models::fault_injection::FaultInjectionConfig,
+        api_models::fault_injection::ConnectorFaults,
+        api_models::fault_injection::ConnectorCallFault,
         api_models::admin::WebhookDetails,
+        api_models::enums::ApiResponseVersion,
         api_models::api_keys::ApiKeyExpiration,
         api_models::api_keys::CreateApiKeyRequest,
         api_models::api_keys::CreateApiKeyResponse,
@@ -648,6 +937,29 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::routing::RoutingRetrieveResponse,
         api_models::routing::ProfileDefaultRoutingConfig,
         api_models::routing::MerchantRoutingAlgorithm,
+        api_models::routing::RoutingConfigExport,
+        api_models::routing::RoutingConfigImportRequest,
+        api_models::routing::RoutingExperimentMetric,
+        api_models::routing::RoutingExperimentConfidenceLevel,
+        api_models::routing::RoutingExperimentStatus,
+        api_models::routing::RoutingExperimentArm,
+        api_models::routing::RoutingExperimentEvent,
+        api_models::routing::RoutingExperimentCreateRequest,
+        api_models::routing::RoutingExperimentAuditEntry,
+        api_models::routing::RoutingExperiment,
+        api_models::routing::RoutingExperimentArmResult,
+        api_models::routing::RoutingExperimentResponse,
+        api_models::routing::RoutingExperimentConcludeRequest,
+        api_models::routing::ConnectorVolumeCap,
+        api_models::routing::ConnectorVolumeCapsRequest,
+        api_models::routing::ConnectorVolumeCapUsage,
+        api_models::routing::ConnectorVolumeCapsResponse,
+        api_models::routing::RoutingSimulationRequest,
+        api_models::routing::RoutingSimulationSource,
+        api_models::routing::RoutingEliminationReason,
+        api_models::routing::RoutingSimulationCandidate,
+        api_models::routing::RoutingSimulationElimination,
+        api_models::routing::RoutingSimulationResponse,
         api_models::routing::RoutingAlgorithmKind,
         api_models::routing::RoutingDictionary,
         api_models::routing::RoutingAlgorithm,
@@ -680,6 +992,37 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::webhook_events::OutgoingWebhookRequestContent,
         api_models::webhook_events::OutgoingWebhookResponseContent,
         api_models::webhook_events::TotalEventsResponse,
+        api_models::webhook_events::EventTypeCatalogItem,
+        api_models::webhook_events::EventTypeCatalogResponse,
+        api_models::webhook_events::LifecycleEventResponse,
+        api_models::webhook_events::LifecycleEventListResponse,
+        api_models::webhook_payload_templates::WebhookPayloadTemplateRequest,
+        api_models::webhook_payload_templates::WebhookPayloadTemplateResponse,
+        api_models::webhook_payload_templates::WebhookPayloadTemplateListResponse,
+        api_models::webhook_payload_templates::WebhookPayloadTemplateValidateRequest,
+        api_models::webhook_payload_templates::WebhookPayloadTemplateValidateResponse,
+        api_models::webhook_payload_templates::WebhookPayloadTemplateError,
+        api_models::webhook_payload_templates::WebhookPayloadTemplatePreviewRequest,
+        api_models::webhook_payload_templates::WebhookPayloadTemplatePreviewResponse,
+        api_models::webhook_endpoints::WebhookEndpointStatus,
+        api_models::webhook_endpoints::WebhookEndpointHealthRequest,
+        api_models::webhook_endpoints::WebhookEndpointHourlyHealth,
+        api_models::webhook_endpoints::WebhookEndpointHealthResponse,
+        api_models::warehouse_sync::WarehouseSyncTable,
+        api_models::warehouse_sync::WarehouseDestination,
+        api_models::warehouse_sync::WarehouseDestinationResponse,
+        api_models::warehouse_sync::WarehouseRedactionAction,
+        api_models::warehouse_sync::WarehouseRedactionRule,
+        api_models::warehouse_sync::WarehouseSyncConfigRequest,
+        api_models::warehouse_sync::WarehouseSyncConfigResponse,
+        api_models::warehouse_sync::WarehouseSyncTableStatus,
+        api_models::warehouse_sync::WarehouseSyncStatusResponse,
+        api_models::warehouse_sync::WarehouseSyncTriggerRequest,
+        api_models::sca_compliance::ScaTreatment,
+        api_models::sca_compliance::ScaComplianceReportStatus,
+        api_models::sca_compliance::ScaComplianceReportRequest,
+        api_models::sca_compliance::ScaTreatmentCount,
+        api_models::sca_compliance::ScaComplianceReportResponse,
         api_models::enums::WebhookDeliveryAttempt,
         api_models::enums::PaymentChargeType,
         api_models::enums::StripeChargeType,
@@ -723,7 +1066,62 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::DisplayAmountOnSdk,
         api_models::payments::PaymentsPostSessionTokensRequest,
         api_models::payments::PaymentsPostSessionTokensResponse,
+        api_models::payments::PaymentTimelineResponse,
+        api_models::payments::PaymentTimelineEvent,
+        api_models::payments::PaymentTimelineEventType,
+        api_models::payments::PaymentsMetadataUpdateRequest,
+        api_models::payments::PaymentsMetadataUpdateResponse,
+        api_models::payments::PaymentNoteRequest,
+        api_models::payments::PaymentNote,
+        api_models::payments::PaymentNotesResponse,
+        api_models::payments::PaymentsCaptureScheduleRequest,
+        api_models::payments::PaymentsCaptureScheduleResponse,
+        api_models::payments::AutoCaptureDelayConfig,
+        api_models::payments::StepUpAuthenticationConfig,
+        api_models::payments::StepUpVelocityLimit,
+        api_models::payments::StepUpAmountThreshold,
+        api_models::payments::PaymentsFinalizeAmountRequest,
+        api_models::payments::PaymentsGiftCardBalanceRequest,
+        api_models::payments::PaymentsGiftCardBalanceResponse,
+        api_models::payments::PaymentsContactSearchRequest,
+        api_models::payments::PaymentContactSearchResult,
+        api_models::payments::PaymentsContactSearchResponse,
+        api_models::payments::PaymentLookupResponse,
+        api_models::payments::PaymentChange,
+        api_models::payments::PaymentsChangesResponse,
+        api_models::payments::AcquirerReferenceNumberObjectType,
+        api_models::payments::AcquirerReferenceNumberRecord,
+        api_models::payments::AcquirerReferenceNumberRecordError,
+        api_models::payments::AcquirerReferenceNumberImportResponse,
+        api_models::payments::ReceiptFormat,
+        api_models::payments::PaymentReceiptEmailRequest,
+        api_models::payments::PaymentReceiptEmailResponse,
         api_models::payments::CtpServiceDetails,
+        api_models::payments::ExternalThreeDsData,
+        api_models::payments::PaymentFacilitatorDetails,
+        api_models::payments::SubMerchantDetails,
+        api_models::payments::CustomPaymentStatusMapping,
+        api_models::payments::CustomFieldsSchema,
+        api_models::payments::CustomFieldDefinition,
+        api_models::payments::ConnectorResponseDetails,
+        api_models::payments::UnifiedErrorMessageOverrides,
+        api_models::payments::PostAuthorizationRules,
+        api_models::payments::CheckoutTheme,
+        api_models::payments::CheckoutThemeColors,
+        api_models::payments::CheckoutThemeLayout,
+        api_models::payments::CheckoutSection,
+        api_models::payments::CheckoutThemeResponse,
+        api_models::payments::CheckoutThemeVersionsResponse,
+        api_models::payments::CheckoutThemeActivateRequest,
+        api_models::payments::PostAuthorizationRule,
+        api_models::enums::AvsResult,
+        api_models::enums::CvvResult,
+        api_models::enums::CardCredentialType,
+        api_models::enums::PostAuthorizationAction,
+        api_models::enums::CustomFieldType,
+        api_models::dunning::DunningConfig,
+        api_models::dunning::DunningStatus,
+        api_models::dunning::DunningResponse,
         api_models::feature_matrix::FeatureMatrixListResponse,
         api_models::feature_matrix::FeatureMatrixRequest,
         api_models::feature_matrix::ConnectorFeatureMatrixResponse,
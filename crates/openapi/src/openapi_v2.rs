@@ -195,6 +195,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantAccountCreateWithoutOrgId,
         api_models::admin::MerchantAccountUpdate,
         api_models::admin::MerchantAccountDeleteResponse,
+        api_models::admin::MerchantAccountStatusResponse,
         api_models::admin::MerchantConnectorDeleteResponse,
         api_models::admin::MerchantConnectorResponse,
         api_models::admin::MerchantConnectorListResponse,
@@ -260,6 +261,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::PaymentMethodIssuerCode,
         api_models::enums::MandateStatus,
         api_models::enums::MerchantProductType,
+        api_models::enums::MerchantAccountStatus,
         api_models::enums::PaymentExperience,
         api_models::enums::BankNames,
         api_models::enums::BankType,
@@ -598,6 +600,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::ToggleKVRequest,
         api_models::admin::ToggleKVResponse,
         api_models::admin::WebhookDetails,
+        api_models::enums::ApiResponseVersion,
         api_models::api_keys::ApiKeyExpiration,
         api_models::api_keys::CreateApiKeyRequest,
         api_models::api_keys::CreateApiKeyResponse,
@@ -2,6 +2,7 @@
 
 pub mod api_keys;
 pub mod blocklist;
+pub mod checkout_sessions;
 pub mod customers;
 pub mod disputes;
 pub mod gsm;
@@ -14,8 +15,16 @@ pub mod payment_method;
 pub mod payments;
 pub mod payouts;
 pub mod poll;
+pub mod process_tracker;
 pub mod profile;
 pub mod refunds;
 pub mod relay;
 pub mod routing;
+pub mod sanctions_screening;
+pub mod sandbox;
+pub mod sca_compliance;
+pub mod sdk_events;
+pub mod warehouse_sync;
+pub mod webhook_endpoints;
 pub mod webhook_events;
+pub mod webhook_payload_templates;
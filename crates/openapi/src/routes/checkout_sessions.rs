@@ -0,0 +1,142 @@
+/// Checkout Sessions - Create
+///
+/// Creates a checkout session, which holds the cart and the details of the customer until the
+/// checkout is confirmed. No payment is created until the session is confirmed.
+#[utoipa::path(
+    post,
+    path = "/checkout_sessions",
+    request_body(
+        content = CheckoutSessionCreateRequest,
+        examples((
+            "Create a checkout session with two items" = (
+                value = json!({
+                    "currency": "USD",
+                    "line_items": [
+                        {
+                            "product_name": "T-shirt",
+                            "quantity": 2,
+                            "amount": 1500
+                        },
+                        {
+                            "product_name": "Cap",
+                            "quantity": 1,
+                            "amount": 1000
+                        }
+                    ],
+                    "shipping_cost": 500,
+                    "customer": {
+                        "email": "guest@example.com"
+                    }
+                })
+            )
+        ))
+    ),
+    responses(
+        (status = 200, description = "Checkout session created", body = CheckoutSessionResponse),
+        (status = 400, description = "Invalid cart")
+    ),
+    tag = "Checkout Sessions",
+    operation_id = "Create a Checkout Session",
+    security(("api_key" = []))
+)]
+pub async fn checkout_session_create() {}
+
+/// Checkout Sessions - Retrieve
+///
+/// Retrieves a checkout session, along with the payment to which it was converted
+#[utoipa::path(
+    get,
+    path = "/checkout_sessions/{session_id}",
+    params(
+        ("session_id" = String, Path, description = "The identifier for the checkout session")
+    ),
+    responses(
+        (status = 200, description = "Checkout session retrieved", body = CheckoutSessionResponse),
+        (status = 404, description = "Checkout session not found or expired")
+    ),
+    tag = "Checkout Sessions",
+    operation_id = "Retrieve a Checkout Session",
+    security(("api_key" = []))
+)]
+pub async fn checkout_session_retrieve() {}
+
+/// Checkout Sessions - Update
+///
+/// Updates the cart or the details of the customer of an open checkout session. The fields which
+/// are passed replace those of the session.
+#[utoipa::path(
+    post,
+    path = "/checkout_sessions/{session_id}",
+    params(
+        ("session_id" = String, Path, description = "The identifier for the checkout session")
+    ),
+    request_body(
+        content = CheckoutSessionUpdateRequest,
+        examples((
+            "Replace the items of the cart" = (
+                value = json!({
+                    "line_items": [
+                        {
+                            "product_name": "T-shirt",
+                            "quantity": 3,
+                            "amount": 1500
+                        }
+                    ]
+                })
+            )
+        ))
+    ),
+    responses(
+        (status = 200, description = "Checkout session updated", body = CheckoutSessionResponse),
+        (status = 400, description = "Invalid cart"),
+        (status = 404, description = "Checkout session not found or expired"),
+        (status = 412, description = "Checkout session already converted to a payment")
+    ),
+    tag = "Checkout Sessions",
+    operation_id = "Update a Checkout Session",
+    security(("api_key" = []))
+)]
+pub async fn checkout_session_update() {}
+
+/// Checkout Sessions - Confirm
+///
+/// Converts an open checkout session to a payment. The amount, the currency, the items and the
+/// details of the customer of the payment are taken from the session, and the other fields of the
+/// payment, such as the payment method details, are taken from the request.
+#[utoipa::path(
+    post,
+    path = "/checkout_sessions/{session_id}/confirm",
+    params(
+        ("session_id" = String, Path, description = "The identifier for the checkout session")
+    ),
+    request_body(
+        content = PaymentsCreateRequest,
+        examples((
+            "Confirm the checkout session with a card" = (
+                value = json!({
+                    "confirm": true,
+                    "payment_method": "card",
+                    "payment_method_data": {
+                        "card": {
+                            "card_number": "4242424242424242",
+                            "card_exp_month": "10",
+                            "card_exp_year": "25",
+                            "card_holder_name": "joseph Doe",
+                            "card_cvc": "123"
+                        }
+                    }
+                })
+            )
+        ))
+    ),
+    responses(
+        (status = 200, description = "Payment created", body = PaymentsCreateResponseOpenApi),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Checkout session not found or expired"),
+        (status = 412, description = "Checkout session already converted to a payment")
+    ),
+    tag = "Checkout Sessions",
+    operation_id = "Confirm a Checkout Session",
+    security(("api_key" = []))
+)]
+pub async fn checkout_session_confirm() {}
@@ -106,6 +106,76 @@ pub async fn customers_delete() {}
 #[cfg(feature = "v1")]
 pub async fn customers_list() {}
 
+/// Customers - Payments List
+///
+/// Lists the payments of a customer, from the most recent payment, along with the statistics of
+/// their payments, such as their lifetime value, the number of disputes raised on their payments
+/// and the payment method they use the most.
+#[utoipa::path(
+    get,
+    path = "/customers/{customer_id}/payments",
+    params(
+        ("customer_id" = String, Path, description = "The unique identifier for the Customer"),
+        ("offset" = Option<u32>, Query, description = "The number of payments to skip, from the most recent payment"),
+        ("limit" = Option<u32>, Query, description = "The number of payments to return, 10 by default and up to 100"),
+    ),
+    responses(
+        (status = 200, description = "Customer payments retrieved", body = CustomerPaymentsListResponse),
+        (status = 400, description = "Invalid Data"),
+        (status = 404, description = "Customer was not found")
+    ),
+    tag = "Customers",
+    operation_id = "List all Payments for a Customer",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn customers_payments_list() {}
+
+/// Customers - Store Credit Retrieve
+///
+/// Retrieves the store credit balances of a customer in each currency. The balances are credited
+/// by the refunds to store credit, and debited by the payments to which the store credit is
+/// applied.
+#[utoipa::path(
+    get,
+    path = "/customers/{customer_id}/store_credit",
+    params(
+        ("customer_id" = String, Path, description = "The unique identifier for the Customer"),
+    ),
+    responses(
+        (status = 200, description = "Customer store credit retrieved", body = CustomerStoreCreditResponse),
+        (status = 404, description = "Customer was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Retrieve the Store Credit of a Customer",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn customers_store_credit_retrieve() {}
+
+/// Customers - Store Credit Entries List
+///
+/// Lists the entries of the store credit ledger of a customer, from the most recent entry.
+#[utoipa::path(
+    get,
+    path = "/customers/{customer_id}/store_credit/entries",
+    params(
+        ("customer_id" = String, Path, description = "The unique identifier for the Customer"),
+        ("offset" = Option<u32>, Query, description = "The number of entries to skip, from the most recent entry"),
+        ("limit" = Option<u32>, Query, description = "The number of entries to return, 10 by default and up to 100"),
+    ),
+    responses(
+        (status = 200, description = "Customer store credit entries retrieved", body = StoreCreditEntriesListResponse),
+        (status = 400, description = "Invalid Data"),
+        (status = 404, description = "Customer was not found")
+    ),
+    tag = "Customers",
+    operation_id = "List the Store Credit Entries of a Customer",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn customers_store_credit_entries_list() {}
+
 /// Customers - Create
 ///
 /// Creates a customer object and stores the customer details to be reused for future payments.
@@ -32,6 +32,7 @@ pub async fn retrieve_dispute() {}
         ("received_time.gt" = Option<PrimitiveDateTime>, Query, description = "Time greater than the dispute received time"),
         ("received_time.lte" = Option<PrimitiveDateTime>, Query, description = "Time less than or equals to the dispute received time"),
         ("received_time.gte" = Option<PrimitiveDateTime>, Query, description = "Time greater than or equals to the dispute received time"),
+        ("payment_context" = Option<String>, Query, description = "The comma separated list of the fields of the disputed payment to embed in the disputes, among amount, payment_method, customer_email_hash and connector"),
     ),
     responses(
         (status = 200, description = "The dispute list was retrieved successfully", body = Vec<DisputeResponse>),
@@ -59,6 +60,7 @@ pub async fn retrieve_disputes_list() {}
         ("received_time.gt" = Option<PrimitiveDateTime>, Query, description = "Time greater than the dispute received time"),
         ("received_time.lte" = Option<PrimitiveDateTime>, Query, description = "Time less than or equals to the dispute received time"),
         ("received_time.gte" = Option<PrimitiveDateTime>, Query, description = "Time greater than or equals to the dispute received time"),
+        ("payment_context" = Option<String>, Query, description = "The comma separated list of the fields of the disputed payment to embed in the disputes, among amount, payment_method, customer_email_hash and connector"),
     ),
     responses(
         (status = 200, description = "The dispute list was retrieved successfully", body = Vec<DisputeResponse>),
@@ -69,3 +71,18 @@ pub async fn retrieve_disputes_list() {}
     security(("api_key" = []))
 )]
 pub async fn retrieve_disputes_list_profile() {}
+
+/// Disputes - Retrieve Disputes Balance
+/// Retrieves the funds of the merchant at risk due to the disputes, in each currency
+#[utoipa::path(
+    get,
+    path = "/disputes/balance",
+    responses(
+        (status = 200, description = "The disputes balance was retrieved successfully", body = DisputesBalanceResponse),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Disputes",
+    operation_id = "Retrieve Disputes Balance",
+    security(("api_key" = []))
+)]
+pub async fn get_disputes_balance() {}
@@ -274,6 +274,340 @@ pub async fn delete_merchant_account() {}
 )]
 pub async fn merchant_account_kv_status() {}
 
+#[cfg(feature = "v1")]
+/// Merchant Account - KV and Postgres Divergences
+///
+/// List the divergences between the KV store and Postgres found by the consistency checker of the
+/// drainer, latest first
+#[utoipa::path(
+    get,
+    path = "/accounts/kv/divergences",
+    params (("merchant_id" = Option<String>, Query, description = "List only the divergences of the entries of this merchant")),
+    responses(
+        (status = 200, description = "Divergences listed", body = KvPgDivergencesListResponse)
+    ),
+    tag = "Merchant Account",
+    operation_id = "List KV and Postgres Divergences",
+    security(("admin_api_key" = []))
+)]
+pub async fn kv_pg_divergences_list() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Account - Retrieve Feature Flags
+///
+/// Retrieve the feature flags of a merchant account, along with the level from which the value of
+/// each flag is picked
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/feature_flags",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Feature Flags Retrieved", body = FeatureFlagsResponse),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Retrieve the Feature Flags of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_feature_flags_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Account - Update Feature Flags
+///
+/// Set or clear the feature flags of a merchant account. A cleared flag takes the value in the
+/// configuration of the application
+#[utoipa::path(
+    put,
+    path = "/accounts/{account_id}/feature_flags",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    request_body(
+        content = FeatureFlagsUpdateRequest,
+        examples(
+            (
+                "Disable smart retries" = (
+                    value = json!({
+                        "flags": {
+                            "smart_retries": false
+                        }
+                    })
+                )
+            )
+    )),
+    responses(
+        (status = 200, description = "Feature Flags Updated", body = FeatureFlagsResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Update the Feature Flags of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_feature_flags_update() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Account - Upsert Fault Injection Config
+///
+/// Create or replace the faults injected into the connector calls and the incoming webhooks of a
+/// merchant account. Faults are injected only outside production
+#[utoipa::path(
+    put,
+    path = "/accounts/{account_id}/fault_injection",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    request_body(
+        content = FaultInjectionConfig,
+        examples(
+            (
+                "Time out half of the calls to Stripe" = (
+                    value = json!({
+                        "connectors": {
+                            "stripe": {
+                                "call_fault": {
+                                    "type": "timeout"
+                                },
+                                "percentage": 50
+                            }
+                        }
+                    })
+                )
+            ),
+            (
+                "Delay the webhooks of Adyen" = (
+                    value = json!({
+                        "connectors": {
+                            "adyen": {
+                                "webhook_delay_seconds": 30
+                            }
+                        }
+                    })
+                )
+            )
+    )),
+    responses(
+        (status = 200, description = "Fault Injection Config Upserted", body = FaultInjectionConfig),
+        (status = 400, description = "Invalid data or fault injection is not available")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Upsert the Fault Injection Config of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_fault_injection_upsert() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Account - Retrieve Fault Injection Config
+///
+/// Retrieve the faults injected into the connector calls and the incoming webhooks of a merchant
+/// account
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/fault_injection",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Fault Injection Config Retrieved", body = FaultInjectionConfig),
+        (status = 404, description = "Fault injection config not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Retrieve the Fault Injection Config of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_fault_injection_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Account - Delete Fault Injection Config
+///
+/// Stop injecting faults into the connector calls and the incoming webhooks of a merchant account
+#[utoipa::path(
+    delete,
+    path = "/accounts/{account_id}/fault_injection",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Fault Injection Config Deleted"),
+        (status = 404, description = "Fault injection config not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Delete the Fault Injection Config of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_fault_injection_delete() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Account - Update Status
+///
+/// Move a merchant account to the active, restricted or suspended status. A restricted merchant
+/// account can refund but cannot charge, and a suspended merchant account cannot be accessed with
+/// its API keys. A `merchant_account_status_updated` webhook is sent to each profile of the
+/// merchant.
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/status",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    request_body(
+        content = MerchantAccountStatusUpdateRequest,
+        examples(
+            (
+                "Restrict a merchant account" = (
+                    value = json!({
+                        "status": "restricted",
+                        "reason": "Pending review of the chargeback ratio"
+                    })
+                )
+            )
+    )),
+    responses(
+        (status = 200, description = "Merchant Account Status Updated", body = MerchantAccountStatusResponse),
+        (status = 400, description = "Invalid status transition"),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Update the Status of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_account_status_update() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Account - Offboard
+///
+/// Move a merchant account to the closing status. The payouts which were not sent to the
+/// connectors are cancelled, those in flight are awaited, and the payments and refunds of the
+/// merchant are then exported to the file storage.
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/offboard",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    request_body = MerchantOffboardingRequest,
+    responses(
+        (status = 200, description = "Merchant Account Offboarding Started", body = MerchantOffboardingResponse),
+        (status = 404, description = "Merchant account not found"),
+        (status = 412, description = "Merchant account is already being offboarded")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Offboard a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_account_offboard() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Account - Retrieve Offboarding
+///
+/// Retrieve the progress of the offboarding of a merchant account
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/offboard",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Merchant Account Offboarding Retrieved", body = MerchantOffboardingResponse),
+        (status = 404, description = "Merchant offboarding not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Retrieve the Offboarding of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn merchant_account_offboarding_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Account - Backfill Connector Transaction IDs
+///
+/// Start a backfill of the connector transaction IDs of the payments of a merchant account created
+/// within a time range. The payments whose attempt was sent to a connector without a connector
+/// transaction ID being stored are synced with their connectors by the scheduler, at most
+/// `requests_per_minute` per minute.
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/connector_transaction_id_backfill",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    request_body(
+        content = ConnectorTransactionIdBackfillRequest,
+        examples(
+            (
+                "Backfill the Stripe payments of a day" = (
+                    value = json!({
+                        "start_time": "2022-09-10T00:00:00Z",
+                        "end_time": "2022-09-11T00:00:00Z",
+                        "connector": "stripe",
+                        "requests_per_minute": 120
+                    })
+                )
+            )
+    )),
+    responses(
+        (status = 200, description = "Connector Transaction ID Backfill Created", body = ConnectorTransactionIdBackfillResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Backfill the Connector Transaction IDs of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn connector_transaction_id_backfill_create() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Account - Retrieve Connector Transaction ID Backfill
+///
+/// Retrieve the progress of a backfill of the connector transaction IDs of a merchant account
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/connector_transaction_id_backfill/{backfill_id}",
+    params (
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("backfill_id" = String, Path, description = "The unique identifier for the backfill")
+    ),
+    responses(
+        (status = 200, description = "Connector Transaction ID Backfill Retrieved", body = ConnectorTransactionIdBackfillResponse),
+        (status = 404, description = "Connector transaction ID backfill not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Retrieve a Connector Transaction ID Backfill of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn connector_transaction_id_backfill_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Account - Data Residency
+///
+/// Pin the customers, addresses and locker entries of a merchant account to a data residency
+/// region. The existing ones are migrated to the region by the scheduler, and are read from and
+/// written to the region once the migration is being finalized. A failed migration is resumed by
+/// repeating the request.
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/data_residency",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    request_body(
+        content = DataResidencyRequest,
+        examples(
+            (
+                "Pin the personal data of a merchant account to the EU" = (
+                    value = json!({
+                        "region": "eu"
+                    })
+                )
+            )
+    )),
+    responses(
+        (status = 200, description = "Data Residency Created", body = DataResidencyResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Create the Data Residency of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn data_residency_create() {}
+
+#[cfg(feature = "v1")]
+/// Merchant Account - Retrieve Data Residency
+///
+/// Retrieve the data residency of a merchant account and the progress of its migration
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/data_residency",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Data Residency Retrieved", body = DataResidencyResponse),
+        (status = 404, description = "Data residency not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Retrieve the Data Residency of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn data_residency_retrieve() {}
+
 /// Merchant Connector - List
 ///
 /// List Merchant Connector Details for the merchant
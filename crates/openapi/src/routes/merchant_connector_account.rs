@@ -284,7 +284,7 @@ pub async fn connector_update() {}
 
 /// Merchant Connector - Delete
 ///
-/// Delete or Detach a Merchant Connector from Merchant Account
+/// Delete or Detach a Merchant Connector from Merchant Account. The Merchant Connector is disabled and kept for the payments made through it, and can be restored within 30 days of its deletion.
 #[cfg(feature = "v1")]
 #[utoipa::path(
     delete,
@@ -304,6 +304,181 @@ pub async fn connector_update() {}
 )]
 pub async fn connector_delete() {}
 
+/// Merchant Connector - Restore
+///
+/// Restore a deleted Merchant Connector to its state before the deletion, within 30 days of its deletion
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/connectors/{connector_id}/restore",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("connector_id" = String, Path, description = "The unique identifier for the Merchant Connector")
+    ),
+    responses(
+        (status = 200, description = "Merchant Connector Restored", body = MerchantConnectorResponse),
+        (status = 404, description = "Merchant Connector does not exist in records"),
+        (status = 412, description = "Merchant Connector is not deleted, or can no longer be restored")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Restore a Merchant Connector",
+    security(("admin_api_key" = []))
+)]
+pub async fn connector_restore() {}
+
+/// Merchant Connector - Upsert Maintenance Windows
+///
+/// Set the scheduled maintenance windows of a Merchant Connector. The connector is excluded from routing for the affected payment methods while a window is active.
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    put,
+    path = "/accounts/{account_id}/connectors/{connector_id}/maintenance_windows",
+    request_body = ConnectorMaintenanceWindowsRequest,
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("connector_id" = String, Path, description = "The unique identifier for the Merchant Connector")
+    ),
+    responses(
+        (status = 200, description = "Maintenance windows updated", body = ConnectorMaintenanceWindowsResponse),
+        (status = 400, description = "Invalid maintenance windows"),
+        (status = 404, description = "Merchant Connector does not exist in records")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Upsert Maintenance Windows of a Merchant Connector",
+    security(("admin_api_key" = []))
+)]
+pub async fn connector_maintenance_windows_upsert() {}
+
+/// Merchant Connector - Retrieve Maintenance Windows
+///
+/// Retrieve the scheduled maintenance windows of a Merchant Connector which have not ended yet
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/connectors/{connector_id}/maintenance_windows",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("connector_id" = String, Path, description = "The unique identifier for the Merchant Connector")
+    ),
+    responses(
+        (status = 200, description = "Maintenance windows retrieved", body = ConnectorMaintenanceWindowsResponse),
+        (status = 404, description = "Merchant Connector does not exist in records")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Retrieve Maintenance Windows of a Merchant Connector",
+    security(("admin_api_key" = []))
+)]
+pub async fn connector_maintenance_windows_retrieve() {}
+
+/// Merchant Connector - Retrieve Credentials
+///
+/// Retrieve the metadata of the credentials of a Merchant Connector, such as the time at which they expire and the time at which they were last verified
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/connectors/{connector_id}/credentials",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("connector_id" = String, Path, description = "The unique identifier for the Merchant Connector")
+    ),
+    responses(
+        (status = 200, description = "Credentials metadata retrieved", body = ConnectorCredentialsResponse),
+        (status = 404, description = "Merchant Connector does not exist in records")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Retrieve Credentials of a Merchant Connector",
+    security(("admin_api_key" = []))
+)]
+pub async fn connector_credentials_retrieve() {}
+
+/// Merchant Connector - Update Credentials
+///
+/// Set the time at which the current credentials of a Merchant Connector expire. Reminder webhooks are sent ahead of the expiry, and the connector is excluded from routing once the credentials have expired.
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    put,
+    path = "/accounts/{account_id}/connectors/{connector_id}/credentials",
+    request_body = ConnectorCredentialsUpdateRequest,
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("connector_id" = String, Path, description = "The unique identifier for the Merchant Connector")
+    ),
+    responses(
+        (status = 200, description = "Credentials metadata updated", body = ConnectorCredentialsResponse),
+        (status = 400, description = "Invalid expiry of the credentials"),
+        (status = 404, description = "Merchant Connector does not exist in records")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Update Credentials of a Merchant Connector",
+    security(("admin_api_key" = []))
+)]
+pub async fn connector_credentials_update() {}
+
+/// Merchant Connector - Verify Credentials
+///
+/// Verify the current credentials of a Merchant Connector with the connector, and record the time of the verification
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/connectors/{connector_id}/credentials/verify",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("connector_id" = String, Path, description = "The unique identifier for the Merchant Connector")
+    ),
+    responses(
+        (status = 200, description = "Credentials verified", body = ConnectorCredentialsResponse),
+        (status = 400, description = "Verification of the credentials is not supported for the connector"),
+        (status = 404, description = "Merchant Connector does not exist in records")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Verify Credentials of a Merchant Connector",
+    security(("admin_api_key" = []))
+)]
+pub async fn connector_credentials_verify() {}
+
+/// Merchant Connector - List Expiring Credentials
+///
+/// List the Merchant Connectors of the merchant account whose credentials expire within the given number of days, including the ones whose credentials have already expired
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/connectors/credentials/expiring",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        api_models::admin::ExpiringConnectorCredentialsRequest
+    ),
+    responses(
+        (status = 200, description = "Expiring credentials listed", body = Vec<ConnectorCredentialsResponse>),
+        (status = 400, description = "Invalid number of days")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "List Expiring Credentials of the Merchant Connectors",
+    security(("admin_api_key" = []))
+)]
+pub async fn connector_credentials_expiring_list() {}
+
+/// Merchant Connector - Bulk Config
+///
+/// Create or update the Merchant Connector of a connector in many business profiles of the merchant account at once. The template is applied to every profile along with the overrides of the profile, creating the Merchant Connector in the profiles which do not have one and updating it in the others. A dry run only validates the configuration of every profile.
+#[cfg(feature = "v1")]
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/connectors/bulk",
+    request_body = MerchantConnectorBulkConfigRequest,
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account")
+    ),
+    responses(
+        (status = 200, description = "Bulk configuration applied, with the outcome for every profile", body = MerchantConnectorBulkConfigResponse),
+        (status = 400, description = "Invalid list of profiles"),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Bulk Configure a Merchant Connector across Profiles",
+    security(("admin_api_key" = []))
+)]
+pub async fn connector_bulk_config() {}
+
 /// Merchant Connector - Delete
 ///
 /// Delete or Detach a Merchant Connector from Merchant Account
@@ -157,6 +157,49 @@ pub async fn payment_method_retrieve_api() {}
 #[cfg(feature = "v1")]
 pub async fn payment_method_update_api() {}
 
+/// Payment Method - Recollect Consent
+///
+/// Record the consent collected again from the customer to store the payment method and use it for future payments.
+#[utoipa::path(
+    post,
+    path = "/payment_methods/{method_id}/consent/recollect",
+    params (
+        ("method_id" = String, Path, description = "The unique identifier for the Payment Method"),
+    ),
+    request_body = PaymentMethodConsentRecollectRequest,
+    responses(
+        (status = 200, description = "Payment Method consent recorded", body = PaymentMethodConsentResponse),
+        (status = 404, description = "Payment Method does not exist in records")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Recollect the consent for a Payment method",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn payment_method_consent_recollect_api() {}
+
+/// Payment Method - Revoke Consent
+///
+/// Record the revocation of the consent given by the customer to store the payment method.
+#[utoipa::path(
+    post,
+    path = "/payment_methods/{method_id}/consent/revoke",
+    params (
+        ("method_id" = String, Path, description = "The unique identifier for the Payment Method"),
+    ),
+    request_body = PaymentMethodConsentRevokeRequest,
+    responses(
+        (status = 200, description = "Payment Method consent revoked", body = PaymentMethodConsentResponse),
+        (status = 400, description = "Payment Method has no active consent"),
+        (status = 404, description = "Payment Method does not exist in records")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Revoke the consent for a Payment method",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn payment_method_consent_revoke_api() {}
+
 /// Payment Method - Delete
 ///
 /// Deletes a payment method of a customer.
@@ -198,6 +241,73 @@ pub async fn payment_method_delete_api() {}
 )]
 pub async fn default_payment_method_set_api() {}
 
+/// Payment Method - Update Saved Payment Method for Customer
+///
+/// Update the nickname of a saved payment method, set it as the default for a currency or profile, or disable it.
+/// A disabled payment method is not listed during checkout, but can still be used for merchant initiated payments.
+#[utoipa::path(
+    patch,
+    path = "/customers/{customer_id}/payment_methods/{payment_method_id}",
+    params (
+        ("customer_id" = String,Path, description ="The unique identifier for the Customer"),
+        ("payment_method_id" = String,Path, description = "The unique identifier for the Payment Method"),
+    ),
+    request_body = CustomerPaymentMethodUpdateRequest,
+    responses(
+        (status = 200, description = "Payment Method has been updated", body = CustomerPaymentMethodUpdateResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Payment Method not found for the customer")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Update a saved Payment Method of the Customer",
+    security(("api_key" = []), ("ephemeral_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn customer_payment_method_update_api() {}
+
+/// Payment Method - Tokenize CVV
+///
+/// Tokenize the CVV re-collected by the SDK for a saved card payment. The returned token is valid
+/// for a single payment confirmation and expires in 5 minutes. The CVV is never persisted.
+#[utoipa::path(
+    post,
+    path = "/payment_methods/tokenize-cvv",
+    request_body = CvvTokenizeRequest,
+    responses(
+        (status = 200, description = "CVV has been tokenized", body = CvvTokenizeResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 422, description = "Payment token is invalid or expired")
+    ),
+    tag = "Payment Methods",
+    operation_id = "Tokenize the CVV of a saved card",
+    security(("publishable_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn tokenize_cvv_api() {}
+
+/// Payment Method - List Display Names
+///
+/// List the display names and the icons of all the payment methods and payment method types, from
+/// the central registry with the overrides of the profile applied. The names are localized in the
+/// requested locale, or else in the locales of the `Accept-Language` header.
+#[utoipa::path(
+    get,
+    path = "/payment_methods/display_names",
+    params(
+        ("profile_id" = Option<String>, Query, description = "The profile whose overrides are applied"),
+        ("locale" = Option<String>, Query, description = "The locale of the display names"),
+    ),
+    responses(
+        (status = 200, description = "Payment Method Display Names retrieved", body = PaymentMethodDisplayNamesResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Payment Methods",
+    operation_id = "List the Display Names of the Payment Methods",
+    security(("publishable_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn list_payment_method_display_names_api() {}
+
 /// Payment Method - Create Intent
 ///
 /// Creates a payment method for customer with billing information and other metadata.
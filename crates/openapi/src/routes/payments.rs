@@ -70,6 +70,15 @@
                   })
                 )
             ),
+            (
+                "Create a payment to be paid by a gift card and a card" = (
+                    value = json!({
+                    "amount": 6540,
+                    "currency": "USD",
+                    "enable_partial_payments": true
+                  })
+                )
+            ),
             (
                 "Create a setup mandate payment" = (
                     value = json!({
@@ -300,6 +309,8 @@ pub fn payments_update() {}
 /// 2. transition to a `requires_customer_action` status with a `next_action` block or
 ///
 /// 3. succeed with either `succeeded` in case of automatic capture or `requires_capture` in case of manual capture
+///
+/// A payment with partial payments enabled returns to `requires_payment_method` while a part of its amount remains to be paid, and is confirmed again with another payment method, paying `partial_amount` or the remaining amount. Without `partial_amount`, a gift card whose balance does not cover the remaining amount pays its balance
 #[utoipa::path(
     post,
     path = "/payments/{payment_id}/confirm",
@@ -334,6 +345,24 @@ pub fn payments_update() {}
             }
           )
         )
+      ),
+      (
+        "Pay a part of a payment with partial payments enabled with a gift card" = (
+          value = json!({
+              "payment_method": "gift_card",
+              "payment_method_type": "givex",
+              "payment_method_data": {
+                "gift_card": {
+                  "givex": {
+                    "number": "6036280000000000000",
+                    "cvc": "123"
+                  }
+                }
+              },
+              "partial_amount": 2500
+            }
+          )
+        )
       )
      )
     ),
@@ -454,6 +483,40 @@ pub fn payments_connector_session() {}
 )]
 pub fn payments_cancel() {}
 
+/// Payments - Cancel Remaining
+///
+/// Releases the uncaptured remainder of the authorization of a payment in the `partially_captured_and_capturable` status, where the connector supports it. The payment moves to the `partially_captured` status and the `payment_authorization_remainder_released` webhook is sent.
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/cancel_remaining",
+    request_body (
+        content = PaymentsCancelRequest,
+        examples(
+            (
+                "Release the remaining amount with minimal fields" = (
+                    value = json!({})
+                )
+            ),
+            (
+                "Release the remaining amount with cancellation reason" = (
+                    value = json!({"cancellation_reason": "order_partially_fulfilled"})
+                )
+            ),
+        )
+    ),
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Remaining amount of the payment released", body = PaymentsResponse),
+        (status = 400, description = "Missing mandatory fields")
+    ),
+    tag = "Payments",
+    operation_id = "Cancel the remaining amount of a Payment",
+    security(("api_key" = []))
+)]
+pub fn payments_cancel_remaining() {}
+
 /// Payments - List
 ///
 /// To list the *payments*
@@ -549,6 +612,26 @@ pub fn payments_incremental_authorization() {}
 )]
 pub fn payments_external_authentication() {}
 
+/// Payments - Resume External 3DS Authentication
+///
+/// Regenerates the challenge of an external 3DS authentication abandoned by the payer, where the ACS supports it
+#[utoipa::path(
+  post,
+  path = "/payments/{payment_id}/resume_authentication",
+  request_body=PaymentsExternalAuthenticationRequest,
+  params(
+      ("payment_id" = String, Path, description = "The identifier for payment")
+  ),
+  responses(
+      (status = 200, description = "Authentication challenge regenerated", body = PaymentsExternalAuthenticationResponse),
+      (status = 400, description = "Challenge of the authentication has not been abandoned")
+  ),
+  tag = "Payments",
+  operation_id = "Resume external authentication for a Payment",
+  security(("publishable_key" = []))
+)]
+pub fn payments_resume_authentication() {}
+
 /// Payments - Complete Authorize
 #[utoipa::path(
   post,
@@ -599,6 +682,415 @@ pub fn payments_dynamic_tax_calculation() {}
 
 pub fn payments_post_session_tokens() {}
 
+#[cfg(feature = "v1")]
+/// Payments - Timeline
+///
+/// Retrieves the ordered timeline of events recorded for a payment, such as the routing decision, connector calls, webhooks received and status transitions
+#[utoipa::path(
+    get,
+    path = "/payments/{payment_id}/timeline",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Timeline of the payment retrieved", body = PaymentTimelineResponse),
+        (status = 404, description = "No payment found")
+    ),
+    tag = "Payments",
+    operation_id = "Retrieve the Timeline of a Payment",
+    security(("api_key" = []))
+)]
+pub fn payments_timeline() {}
+
+#[cfg(feature = "v1")]
+/// Payments - Dunning
+///
+/// Retrieves the dunning of a failed recurring payment, with the retries performed so far and the time of the next retry
+#[utoipa::path(
+    get,
+    path = "/payments/{payment_id}/dunning",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Dunning of the payment retrieved", body = DunningResponse),
+        (status = 404, description = "No dunning found for the payment")
+    ),
+    tag = "Payments",
+    operation_id = "Retrieve the Dunning of a Payment",
+    security(("api_key" = []))
+)]
+pub fn payments_dunning_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Payments - Update Metadata
+///
+/// Updates the metadata of a payment in any of its statuses, including after it is completed. The keys of the request are set in the metadata of the payment, and the keys whose value is `null` are removed from it
+#[utoipa::path(
+    patch,
+    path = "/payments/{payment_id}/metadata",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    request_body(
+        content = PaymentsMetadataUpdateRequest,
+        examples(
+            (
+                "Tag a payment under investigation" = (
+                    value = json!({"metadata": {"investigation": "chargeback_review"}})
+                )
+            )
+        )
+    ),
+    responses(
+        (status = 200, description = "Metadata of the payment updated", body = PaymentsMetadataUpdateResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "No payment found")
+    ),
+    tag = "Payments",
+    operation_id = "Update the Metadata of a Payment",
+    security(("api_key" = []))
+)]
+pub fn payments_metadata_update() {}
+
+#[cfg(feature = "v1")]
+/// Payments - Add Note
+///
+/// Adds an internal note to a payment, such as the findings of an investigation of a dispute. The notes are visible only to the merchant, and are stored separately from the payment. The author of the note is the dashboard user adding it
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/notes",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    request_body(
+        content = PaymentNoteRequest,
+        examples(
+            (
+                "Add a note on a disputed payment" = (
+                    value = json!({"note": "Customer confirmed the purchase over a call"})
+                )
+            )
+        )
+    ),
+    responses(
+        (status = 200, description = "Note added to the payment", body = PaymentNotesResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "No payment found")
+    ),
+    tag = "Payments",
+    operation_id = "Add a Note to a Payment",
+    security(("jwt_key" = []))
+)]
+pub fn payments_notes_add() {}
+
+#[cfg(feature = "v1")]
+/// Payments - List Notes
+///
+/// Lists the internal notes of a payment, in the order in which they were added
+#[utoipa::path(
+    get,
+    path = "/payments/{payment_id}/notes",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Notes of the payment retrieved", body = PaymentNotesResponse),
+        (status = 404, description = "No payment found")
+    ),
+    tag = "Payments",
+    operation_id = "List the Notes of a Payment",
+    security(("api_key" = []))
+)]
+pub fn payments_notes_list() {}
+
+#[cfg(feature = "v1")]
+/// Payments - Reschedule Capture
+///
+/// Reschedules the capture of an authorized payment to the given time, or schedules it if the payment was not authorized with a `capture_on`. The full authorized amount of the payment is captured at that time, unless the payment has been captured or voided by then
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/capture_schedule",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    request_body(
+        content = PaymentsCaptureScheduleRequest,
+        examples(
+            (
+                "Reschedule the capture of a payment" = (
+                    value = json!({"capture_on": "2022-09-10T10:11:12Z"})
+                )
+            )
+        )
+    ),
+    responses(
+        (status = 200, description = "Capture of the payment scheduled", body = PaymentsCaptureScheduleResponse),
+        (status = 400, description = "Invalid capture time"),
+        (status = 404, description = "No payment found"),
+        (status = 412, description = "Payment not awaiting a capture")
+    ),
+    tag = "Payments",
+    operation_id = "Reschedule the Capture of a Payment",
+    security(("api_key" = []))
+)]
+pub fn payments_capture_schedule() {}
+
+#[cfg(feature = "v1")]
+/// Payments - Cancel Scheduled Capture
+///
+/// Cancels the pending scheduled capture of a payment. The payment can then be captured only through the capture API
+#[utoipa::path(
+    delete,
+    path = "/payments/{payment_id}/capture_schedule",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Scheduled capture of the payment canceled", body = PaymentsCaptureScheduleResponse),
+        (status = 404, description = "No payment found"),
+        (status = 412, description = "No pending scheduled capture")
+    ),
+    tag = "Payments",
+    operation_id = "Cancel the Scheduled Capture of a Payment",
+    security(("api_key" = []))
+)]
+pub fn payments_capture_schedule_cancel() {}
+
+#[cfg(feature = "v1")]
+/// Payments - Release Scheduled Capture
+///
+/// Releases the scheduled capture of an authorized payment, such as a payment whose capture is held by the auto capture delay of its profile once it has been reviewed. The full authorized amount of the payment is captured right away instead of at the scheduled time. To void the payment instead, cancel the scheduled capture and then cancel the payment
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/capture_schedule/release",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    responses(
+        (status = 200, description = "Scheduled capture of the payment released", body = PaymentsCaptureScheduleResponse),
+        (status = 404, description = "No payment found"),
+        (status = 412, description = "Payment not awaiting a capture or without a scheduled capture")
+    ),
+    tag = "Payments",
+    operation_id = "Release the Scheduled Capture of a Payment",
+    security(("api_key" = []))
+)]
+pub fn payments_capture_schedule_release() {}
+
+#[cfg(feature = "v1")]
+/// Payments - Finalize Amount
+///
+/// Captures the final amount of a payment which was authorized with an estimated amount, as for hotel stays, fuel or rides. A final amount above the authorized amount is captured within the overcapture tolerance of the connector and the card network, or else after incrementing the authorization of the payment to the final amount. A final amount below the authorized amount is partially captured
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/finalize_amount",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    request_body(
+        content = PaymentsFinalizeAmountRequest,
+        examples(
+            (
+                "Capture a final amount above the estimated amount" = (
+                    value = json!({"final_amount": 7250})
+                )
+            )
+        )
+    ),
+    responses(
+        (status = 200, description = "Final amount of the payment captured", body = PaymentsResponse),
+        (status = 400, description = "Invalid final amount"),
+        (status = 404, description = "No payment found"),
+        (status = 412, description = "Payment not authorized with an estimated amount, or final amount not capturable")
+    ),
+    tag = "Payments",
+    operation_id = "Finalize the Amount of a Payment",
+    security(("api_key" = []))
+)]
+pub fn payments_finalize_amount() {}
+
+#[cfg(feature = "v1")]
+/// Payments - Gift Card Balance
+///
+/// Checks the balance of a gift card with the connector, and returns the part of the amount remaining to be paid for the payment which the gift card can pay. With partial payments enabled for the payment, a gift card whose balance does not cover the amount pays its balance when the payment is confirmed with it, and the rest of the amount is paid with another payment method. Otherwise the gift card can only pay the whole amount of the payment
+#[utoipa::path(
+    post,
+    path = "/payments/{payment_id}/gift_card_balance",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    request_body(
+        content = PaymentsGiftCardBalanceRequest,
+        examples(
+            (
+                "Check the balance of a Givex gift card" = (
+                    value = json!({
+                        "gift_card": {
+                            "givex": {
+                                "number": "6036280000000000000",
+                                "cvc": "123"
+                            }
+                        }
+                    })
+                )
+            )
+        )
+    ),
+    responses(
+        (status = 200, description = "Balance of the gift card retrieved", body = PaymentsGiftCardBalanceResponse),
+        (status = 404, description = "No payment found"),
+        (status = 412, description = "No connector account with the gift card enabled")
+    ),
+    tag = "Payments",
+    operation_id = "Check the Balance of a Gift Card for a Payment",
+    security(("api_key" = []))
+)]
+pub fn payments_gift_card_balance() {}
+
+#[cfg(feature = "v1")]
+/// Payments - Search by Contact
+///
+/// Searches the payments of a customer by their email or phone number, the most recent payments first. The contact details are matched against the details provided when the payments were created, ignoring the case of the email and the formatting of the phone number. The searches of a merchant are rate limited
+#[utoipa::path(
+    post,
+    path = "/payments/search/contact",
+    request_body(
+        content = PaymentsContactSearchRequest,
+        examples(
+            (
+                "Search the payments by email" = (
+                    value = json!({"email": "johntest@test.com"})
+                )
+            ),
+            (
+                "Search the payments by phone number" = (
+                    value = json!({"phone": "9123456789", "phone_country_code": "+1", "limit": 10})
+                )
+            )
+        )
+    ),
+    responses(
+        (status = 200, description = "Payments matching the contact details retrieved", body = PaymentsContactSearchResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 429, description = "Too many searches")
+    ),
+    tag = "Payments",
+    operation_id = "Search Payments by Contact",
+    security(("api_key" = []))
+)]
+pub fn payments_search_by_contact() {}
+
+#[cfg(feature = "v1")]
+/// Payments - Lookup by Connector Transaction ID
+///
+/// Looks up the payment whose attempt was processed with the given reference at the connector, across all the connectors of the merchant. This can be used to find the payment of a reference on a bank statement or a dispute notice
+#[utoipa::path(
+    get,
+    path = "/payments/lookup",
+    params(api_models::payments::PaymentLookupRequest),
+    responses(
+        (status = 200, description = "Payment of the connector transaction id retrieved", body = PaymentLookupResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "No payment found")
+    ),
+    tag = "Payments",
+    operation_id = "Lookup a Payment by Connector Transaction ID",
+    security(("api_key" = []))
+)]
+pub fn payments_lookup() {}
+
+#[cfg(feature = "v1")]
+/// Payments - Changes
+///
+/// Lists the payments whose status changed after the cursor returned by the previous poll, in the order of their changes. This can be polled by the merchants which cannot receive webhooks, instead of listing all the payments. A poll without a cursor returns the changes from the start, and the returned cursor is sent in the next poll. The changes of the last few seconds are returned in the next polls
+#[utoipa::path(
+    get,
+    path = "/payments/changes",
+    params(api_models::payments::PaymentsChangesRequest),
+    responses(
+        (status = 200, description = "Changed payments retrieved", body = PaymentsChangesResponse),
+        (status = 400, description = "Invalid cursor or limit")
+    ),
+    tag = "Payments",
+    operation_id = "Poll the Changed Payments",
+    security(("api_key" = []))
+)]
+pub fn payments_changes() {}
+
+#[cfg(feature = "v1")]
+/// Payments - Import Acquirer Reference Numbers
+///
+/// Imports the acquirer reference numbers of captured payments and refunds, as read from the settlement files of the connectors. The records can be provided either as a JSON array, or as a CSV file with `connector`, `object_type`, `connector_reference_id` and `acquirer_reference_number` columns uploaded in the `file` field of a multipart form. The acquirer reference numbers are then returned with the payments and the refunds, and in their webhooks
+#[utoipa::path(
+    post,
+    path = "/payments/acquirer_reference_numbers",
+    request_body(
+        content = Vec<AcquirerReferenceNumberRecord>,
+        examples(
+            (
+                "Import the acquirer reference number of a refund" = (
+                    value = json!([
+                        {
+                            "connector": "stripe",
+                            "object_type": "refund",
+                            "connector_reference_id": "re_3MtwBwLkdIwHu7ix0s8cXEiD",
+                            "acquirer_reference_number": "74537604221431003881865"
+                        }
+                    ])
+                )
+            ),
+        )
+    ),
+    responses(
+        (status = 200, description = "Acquirer reference numbers imported", body = AcquirerReferenceNumberImportResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Payments",
+    operation_id = "Import Acquirer Reference Numbers",
+    security(("api_key" = []))
+)]
+pub fn payments_import_acquirer_reference_numbers() {}
+
+#[cfg(feature = "v1")]
+/// Payments - Email Receipt
+///
+/// Emails the link to the hosted receipt of a completed payment to the given email, or else to the email of the customer of the payment. The receipt shows the amount, the payment method and the statement descriptor of the payment, with the branding of the payment link configuration of the profile, and can be downloaded as a PDF. The same link is returned in the `receipt_url` of the payment responses once the payment is completed
+#[utoipa::path(
+    post,
+    path = "/receipts/{payment_id}/email",
+    params(
+        ("payment_id" = String, Path, description = "The identifier for payment")
+    ),
+    request_body(
+        content = PaymentReceiptEmailRequest,
+        examples(
+            (
+                "Email the receipt to the customer of the payment" = (
+                    value = json!({})
+                )
+            ),
+            (
+                "Email the receipt to the given email" = (
+                    value = json!({
+                        "email": "guest@example.com"
+                    })
+                )
+            ),
+        )
+    ),
+    responses(
+        (status = 200, description = "Receipt emailed", body = PaymentReceiptEmailResponse),
+        (status = 400, description = "No email to send the receipt to"),
+        (status = 404, description = "No payment found"),
+        (status = 412, description = "Payment not completed")
+    ),
+    tag = "Payments",
+    operation_id = "Email the Receipt of a Payment",
+    security(("api_key" = []))
+)]
+pub fn payments_send_receipt_email() {}
+
 /// Payments - Create Intent
 ///
 /// **Creates a payment intent object when amount_details are passed.**
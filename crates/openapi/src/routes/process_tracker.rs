@@ -0,0 +1,73 @@
+/// Process Tracker - Scheduler Tasks Summary
+///
+/// Retrieves the number of scheduler tasks of each type in each state, along with the age of the
+/// oldest of them.
+#[utoipa::path(
+    get,
+    path = "/process_tracker/tasks/summary",
+    responses(
+        (status = 200, description = "Scheduler tasks summarized", body = SchedulerTasksSummaryResponse)
+    ),
+    tag = "Process Tracker",
+    operation_id = "Summarize Scheduler Tasks",
+    security(("admin_api_key" = []))
+)]
+pub async fn retrieve_scheduler_tasks_summary() {}
+
+/// Process Tracker - List Scheduler Tasks
+///
+/// Lists the scheduler tasks of a type in a state, starting from the oldest task.
+#[utoipa::path(
+    get,
+    path = "/process_tracker/tasks",
+    params(
+        ("task_type" = SchedulerTaskType, Query, description = "The type of the tasks to be listed"),
+        ("state" = SchedulerTaskState, Query, description = "The state of the tasks to be listed"),
+        ("limit" = Option<u32>, Query, description = "The maximum number of tasks to be listed"),
+        ("offset" = Option<u32>, Query, description = "The number of tasks to be skipped")
+    ),
+    responses(
+        (status = 200, description = "Scheduler tasks listed", body = SchedulerTasksListResponse)
+    ),
+    tag = "Process Tracker",
+    operation_id = "List Scheduler Tasks",
+    security(("admin_api_key" = []))
+)]
+pub async fn list_scheduler_tasks() {}
+
+/// Process Tracker - Requeue Scheduler Task
+///
+/// Schedules a pending, failed or held task to be run right away. The retries of a failed task
+/// are reset.
+#[utoipa::path(
+    post,
+    path = "/process_tracker/tasks/{task_id}/requeue",
+    params(("task_id" = String, Path, description = "The identifier for the task")),
+    responses(
+        (status = 200, description = "Scheduler task requeued", body = SchedulerTask),
+        (status = 404, description = "Scheduler task does not exist"),
+        (status = 412, description = "Scheduler task is being run or has finished")
+    ),
+    tag = "Process Tracker",
+    operation_id = "Requeue Scheduler Task",
+    security(("admin_api_key" = []))
+)]
+pub async fn requeue_scheduler_task() {}
+
+/// Process Tracker - Abandon Scheduler Task
+///
+/// Finishes a pending or held task without running it any further.
+#[utoipa::path(
+    post,
+    path = "/process_tracker/tasks/{task_id}/abandon",
+    params(("task_id" = String, Path, description = "The identifier for the task")),
+    responses(
+        (status = 200, description = "Scheduler task abandoned", body = SchedulerTask),
+        (status = 404, description = "Scheduler task does not exist"),
+        (status = 412, description = "Scheduler task is being run or has finished")
+    ),
+    tag = "Process Tracker",
+    operation_id = "Abandon Scheduler Task",
+    security(("admin_api_key" = []))
+)]
+pub async fn abandon_scheduler_task() {}
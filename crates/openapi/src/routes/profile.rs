@@ -90,286 +90,1624 @@ pub async fn profile_update() {}
 )]
 pub async fn profile_retrieve() {}
 
-// ******************************************** Common profile routes ******************************************** //
-
-/// Profile - Delete
+#[cfg(feature = "v1")]
+/// Profile - Upsert Payment Method Display Config
 ///
-/// Delete the *profile*
+/// Create or update the rules which control the order and the visibility of the payment methods
+/// returned by the payment methods list endpoint for the *profile*
 #[utoipa::path(
-    delete,
-    path = "/account/{account_id}/business_profile/{profile_id}",
+    put,
+    path = "/account/{account_id}/business_profile/{profile_id}/payment_method_display_config",
     params(
         ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
         ("profile_id" = String, Path, description = "The unique identifier for the profile")
     ),
+    request_body(
+        content = PaymentMethodDisplayConfig,
+        examples(
+            (
+                "Show wallets first and hide pay later above an amount" = (
+                    value = json!({
+                        "display_order": [
+                            { "payment_method": "wallet" },
+                            { "payment_method": "card", "payment_method_types": ["debit", "credit"] }
+                        ],
+                        "visibility_rules": [
+                            {
+                                "payment_method": "pay_later",
+                                "max_amount": 50000,
+                                "allowed_countries": ["US", "DE"]
+                            }
+                        ]
+                    })
+                )
+            )
+    )),
     responses(
-        (status = 200, description = "Profiles Deleted", body = bool),
+        (status = 200, description = "Payment Method Display Config Updated", body = PaymentMethodDisplayConfig),
         (status = 400, description = "Invalid data")
     ),
     tag = "Profile",
-    operation_id = "Delete the Profile",
-    security(("api_key" = []))
+    operation_id = "Upsert the Payment Method Display Config of a Profile",
+    security(("admin_api_key" = []))
 )]
-pub async fn profile_delete() {}
+pub async fn payment_method_display_config_upsert() {}
 
-/// Profile - List
+#[cfg(feature = "v1")]
+/// Profile - Retrieve Payment Method Display Config
 ///
-/// Lists all the *profiles* under a merchant
+/// Retrieve the payment method display config of the *profile*
 #[utoipa::path(
     get,
-    path = "/account/{account_id}/business_profile",
-    params (
-        ("account_id" = String, Path, description = "Merchant Identifier"),
+    path = "/account/{account_id}/business_profile/{profile_id}/payment_method_display_config",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
     ),
     responses(
-        (status = 200, description = "Profiles Retrieved", body = Vec<ProfileResponse>)
+        (status = 200, description = "Payment Method Display Config Retrieved", body = PaymentMethodDisplayConfig),
+        (status = 404, description = "Payment Method Display Config not found")
     ),
     tag = "Profile",
-    operation_id = "List Profiles",
-    security(("api_key" = []))
+    operation_id = "Retrieve the Payment Method Display Config of a Profile",
+    security(("admin_api_key" = []))
 )]
-pub async fn profile_list() {}
-
-// ******************************************** V2 profile routes ******************************************** //
+pub async fn payment_method_display_config_retrieve() {}
 
-#[cfg(feature = "v2")]
-/// Profile - Create
+#[cfg(feature = "v1")]
+/// Profile - Delete Payment Method Display Config
 ///
-/// Creates a new *profile* for a merchant
+/// Delete the payment method display config of the *profile*
 #[utoipa::path(
-    post,
-    path = "/v2/profiles",
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}/payment_method_display_config",
     params(
-        (
-            "X-Merchant-Id" = String, Header,
-            description = "Merchant ID of the profile.",
-            example = json!({"X-Merchant-Id": "abc_iG5VNjsN9xuCg7Xx0uWh"})
-        ),
-    ),
-    request_body(
-        content = ProfileCreate,
-        examples(
-            (
-                "Create a profile with profile name" = (
-                    value = json!({
-                        "profile_name": "shoe_business"
-                    })
-                )
-            )
-        )
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
     ),
     responses(
-        (status = 200, description = "Account Created", body = ProfileResponse),
-        (status = 400, description = "Invalid data")
+        (status = 200, description = "Payment Method Display Config Deleted"),
+        (status = 404, description = "Payment Method Display Config not found")
     ),
     tag = "Profile",
-    operation_id = "Create A Profile",
+    operation_id = "Delete the Payment Method Display Config of a Profile",
     security(("admin_api_key" = []))
 )]
-pub async fn profile_create() {}
+pub async fn payment_method_display_config_delete() {}
 
-#[cfg(feature = "v2")]
-/// Profile - Update
+#[cfg(feature = "v1")]
+/// Profile - Upsert Payment Method Display Names
 ///
-/// Update the *profile*
+/// Create or update the overrides of the display names and the icons of the registry of the
+/// payment methods for the *profile*
 #[utoipa::path(
     put,
-    path = "/v2/profiles/{id}",
+    path = "/account/{account_id}/business_profile/{profile_id}/payment_method_display_names",
     params(
-        ("id" = String, Path, description = "The unique identifier for the profile"),
-        (
-            "X-Merchant-Id" = String, Header,
-            description = "Merchant ID of the profile.",
-            example = json!({"X-Merchant-Id": "abc_iG5VNjsN9xuCg7Xx0uWh"})
-        ),
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
     ),
     request_body(
-        content = ProfileCreate,
+        content = PaymentMethodDisplayNameOverrides,
         examples(
             (
-                "Update profile with profile name fields" = (
+                "Rename iDEAL and pay later" = (
                     value = json!({
-                        "profile_name" : "shoe_business"
+                        "payment_methods": {
+                            "pay_later": { "display_names": { "en": "Buy now, pay later" } }
+                        },
+                        "payment_method_types": {
+                            "ideal": {
+                                "display_names": { "en": "Pay with iDEAL", "nl": "Betaal met iDEAL" },
+                                "icon": "ideal_dark"
+                            }
+                        }
                     })
                 )
             )
     )),
     responses(
-        (status = 200, description = "Profile Updated", body = ProfileResponse),
+        (status = 200, description = "Payment Method Display Names Updated", body = PaymentMethodDisplayNameOverrides),
         (status = 400, description = "Invalid data")
     ),
     tag = "Profile",
-    operation_id = "Update a Profile",
+    operation_id = "Upsert the Payment Method Display Names of a Profile",
     security(("admin_api_key" = []))
 )]
-pub async fn profile_update() {}
+pub async fn payment_method_display_names_upsert() {}
 
-#[cfg(feature = "v2")]
-/// Profile - Activate routing algorithm
+#[cfg(feature = "v1")]
+/// Profile - Retrieve Payment Method Display Names
 ///
-/// Activates a routing algorithm under a profile
+/// Retrieve the overrides of the payment method display names of the *profile*
 #[utoipa::path(
-    patch,
-    path = "/v2/profiles/{id}/activate-routing-algorithm",
-    request_body ( content = RoutingAlgorithmId,
-      examples(  (
-            "Activate a routing algorithm" = (
-                value = json!({
-                    "routing_algorithm_id": "routing_algorithm_123"
-                })
-            )
-            ))),
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/payment_method_display_names",
     params(
-        ("id" = String, Path, description = "The unique identifier for the profile"),
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
     ),
     responses(
-        (status = 200, description = "Routing Algorithm is activated", body = RoutingDictionaryRecord),
-        (status = 500, description = "Internal server error"),
-        (status = 404, description = "Resource missing"),
-        (status = 400, description = "Bad request")
+        (status = 200, description = "Payment Method Display Names Retrieved", body = PaymentMethodDisplayNameOverrides),
+        (status = 404, description = "Payment Method Display Names not found")
     ),
-   tag = "Profile",
-   operation_id = "Activates a routing algorithm under a profile",
-   security(("api_key" = []), ("jwt_key" = []))
+    tag = "Profile",
+    operation_id = "Retrieve the Payment Method Display Names of a Profile",
+    security(("admin_api_key" = []))
 )]
-pub async fn routing_link_config() {}
+pub async fn payment_method_display_names_retrieve() {}
 
-#[cfg(feature = "v2")]
-/// Profile - Deactivate routing algorithm
+#[cfg(feature = "v1")]
+/// Profile - Delete Payment Method Display Names
 ///
-/// Deactivates a routing algorithm under a profile
+/// Delete the overrides of the payment method display names of the *profile*
 #[utoipa::path(
-    patch,
-    path = "/v2/profiles/{id}/deactivate-routing-algorithm",
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}/payment_method_display_names",
     params(
-        ("id" = String, Path, description = "The unique identifier for the profile"),
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
     ),
     responses(
-        (status = 200, description = "Successfully deactivated routing config", body = RoutingDictionaryRecord),
-        (status = 500, description = "Internal server error"),
-        (status = 400, description = "Malformed request"),
-        (status = 403, description = "Malformed request"),
-        (status = 422, description = "Unprocessable request")
+        (status = 200, description = "Payment Method Display Names Deleted"),
+        (status = 404, description = "Payment Method Display Names not found")
     ),
-   tag = "Profile",
-   operation_id = " Deactivates a routing algorithm under a profile",
-   security(("api_key" = []), ("jwt_key" = []))
+    tag = "Profile",
+    operation_id = "Delete the Payment Method Display Names of a Profile",
+    security(("admin_api_key" = []))
 )]
-pub async fn routing_unlink_config() {}
+pub async fn payment_method_display_names_delete() {}
 
-#[cfg(feature = "v2")]
-/// Profile - Update Default Fallback Routing Algorithm
+#[cfg(feature = "v1")]
+/// Profile - Upsert External Vault Config
 ///
-/// Update the default fallback routing algorithm for the profile
+/// Configure a vault hosted by the merchant for the *profile*. Card details saved during the
+/// payments of the profile are tokenized with this vault instead of the Hyperswitch locker
 #[utoipa::path(
-    patch,
-    path = "/v2/profiles/{id}/fallback-routing",
-    request_body = Vec<RoutableConnectorChoice>,
+    put,
+    path = "/account/{account_id}/business_profile/{profile_id}/external_vault_config",
     params(
-        ("id" = String, Path, description = "The unique identifier for the profile"),
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
     ),
+    request_body(
+        content = ExternalVaultConfigRequest,
+        examples(
+            (
+                "Configure an external vault" = (
+                    value = json!({
+                        "base_url": "https://vault.example.com/v1",
+                        "api_key_header": "x-api-key",
+                        "api_key": "vault_api_key"
+                    })
+                )
+            )
+    )),
     responses(
-        (status = 200, description = "Successfully updated the default fallback routing algorithm", body = Vec<RoutableConnectorChoice>),
-        (status = 500, description = "Internal server error"),
-        (status = 400, description = "Malformed request"),
-        (status = 422, description = "Unprocessable request")
+        (status = 200, description = "External Vault Config Updated", body = ExternalVaultConfigResponse),
+        (status = 400, description = "Invalid data")
     ),
-   tag = "Profile",
-   operation_id = "Update the default fallback routing algorithm for the profile",
-   security(("api_key" = []), ("jwt_key" = []))
+    tag = "Profile",
+    operation_id = "Upsert the External Vault Config of a Profile",
+    security(("admin_api_key" = []))
 )]
-pub async fn routing_update_default_config() {}
+pub async fn external_vault_config_upsert() {}
 
-#[cfg(feature = "v2")]
-/// Profile - Retrieve
+#[cfg(feature = "v1")]
+/// Profile - Retrieve External Vault Config
 ///
-/// Retrieve existing *profile*
+/// Retrieve the external vault config of the *profile*. The api key of the vault is not returned
 #[utoipa::path(
     get,
-    path = "/v2/profiles/{id}",
+    path = "/account/{account_id}/business_profile/{profile_id}/external_vault_config",
     params(
-        ("id" = String, Path, description = "The unique identifier for the profile"),
-        (
-            "X-Merchant-Id" = String, Header,
-            description = "Merchant ID of the profile.",
-            example = json!({"X-Merchant-Id": "abc_iG5VNjsN9xuCg7Xx0uWh"})
-        ),
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
     ),
     responses(
-        (status = 200, description = "Profile Updated", body = ProfileResponse),
-        (status = 400, description = "Invalid data")
+        (status = 200, description = "External Vault Config Retrieved", body = ExternalVaultConfigResponse),
+        (status = 404, description = "External Vault Config not found")
     ),
     tag = "Profile",
-    operation_id = "Retrieve a Profile",
+    operation_id = "Retrieve the External Vault Config of a Profile",
     security(("admin_api_key" = []))
 )]
-pub async fn profile_retrieve() {}
+pub async fn external_vault_config_retrieve() {}
 
-#[cfg(feature = "v2")]
-/// Profile - Retrieve Active Routing Algorithm
-///_
-/// Retrieve active routing algorithm under the profile
+#[cfg(feature = "v1")]
+/// Profile - Delete External Vault Config
+///
+/// Delete the external vault config of the *profile*. Cards which were already saved in the
+/// external vault can no longer be used after the config is deleted
 #[utoipa::path(
-    get,
-    path = "/v2/profiles/{id}/routing-algorithm",
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}/external_vault_config",
     params(
-        ("id" = String, Path, description = "The unique identifier for the profile"),
-        ("limit" = Option<u16>, Query, description = "The number of records of the algorithms to be returned"),
-        ("offset" = Option<u8>, Query, description = "The record offset of the algorithm from which to start gathering the results")),
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
     responses(
-        (status = 200, description = "Successfully retrieved active config", body = LinkedRoutingConfigRetrieveResponse),
-        (status = 500, description = "Internal server error"),
-        (status = 404, description = "Resource missing"),
-        (status = 403, description = "Forbidden")
+        (status = 200, description = "External Vault Config Deleted"),
+        (status = 404, description = "External Vault Config not found")
     ),
-   tag = "Profile",
-   operation_id = "Retrieve the active routing algorithm under the profile",
-   security(("api_key" = []), ("jwt_key" = []))
+    tag = "Profile",
+    operation_id = "Delete the External Vault Config of a Profile",
+    security(("admin_api_key" = []))
 )]
-pub async fn routing_retrieve_linked_config() {}
+pub async fn external_vault_config_delete() {}
 
-#[cfg(feature = "v2")]
-/// Profile - Retrieve Default Fallback Routing Algorithm
+#[cfg(feature = "v1")]
+/// Profile - Upsert CVV Re-collection Config
 ///
-/// Retrieve the default fallback routing algorithm for the profile
+/// Configure when the CVV of a saved card has to be re-collected for the on-session payments of
+/// the *profile*. Payments made without the CVV are rejected when re-collection is enforced
 #[utoipa::path(
-    get,
-    path = "/v2/profiles/{id}/fallback-routing",
+    put,
+    path = "/account/{account_id}/business_profile/{profile_id}/cvv_recollection_config",
     params(
-        ("id" = String, Path, description = "The unique identifier for the profile"),
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
     ),
+    request_body(
+        content = CvvRecollectionConfig,
+        examples(
+            (
+                "Re-collect the CVV for specific connectors" = (
+                    value = json!({
+                        "enforce_for_all_connectors": false,
+                        "connectors": ["stripe", "adyen"]
+                    })
+                )
+            )
+    )),
     responses(
-        (status = 200, description = "Successfully retrieved default fallback routing algorithm", body = Vec<RoutableConnectorChoice>),
-        (status = 500, description = "Internal server error")
+        (status = 200, description = "CVV Re-collection Config Updated", body = CvvRecollectionConfig),
+        (status = 400, description = "Invalid data")
     ),
-   tag = "Profile",
-   operation_id = "Retrieve the default fallback routing algorithm for the profile",
-   security(("api_key" = []), ("jwt_key" = []))
+    tag = "Profile",
+    operation_id = "Upsert the CVV Re-collection Config of a Profile",
+    security(("admin_api_key" = []))
 )]
-pub async fn routing_retrieve_default_config() {}
+pub async fn cvv_recollection_config_upsert() {}
 
-/// Profile - Connector Accounts List
+#[cfg(feature = "v1")]
+/// Profile - Retrieve CVV Re-collection Config
 ///
-/// List Connector Accounts for the profile
+/// Retrieve the CVV re-collection config of the *profile*
 #[utoipa::path(
     get,
-    path = "/v2/profiles/{id}/connector-accounts",
+    path = "/account/{account_id}/business_profile/{profile_id}/cvv_recollection_config",
     params(
-        ("id" = String, Path, description = "The unique identifier for the business profile"),
-        (
-            "X-Merchant-Id" = String, Header,
-            description = "Merchant ID of the profile.",
-            example = json!({"X-Merchant-Id": "abc_iG5VNjsN9xuCg7Xx0uWh"})
-        ),
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
     ),
     responses(
-        (status = 200, description = "Merchant Connector list retrieved successfully", body = Vec<MerchantConnectorResponse>),
-        (status = 404, description = "Merchant Connector does not exist in records"),
-        (status = 401, description = "Unauthorized request")
+        (status = 200, description = "CVV Re-collection Config Retrieved", body = CvvRecollectionConfig),
+        (status = 404, description = "Profile not found")
     ),
-    tag = "Business Profile",
-    operation_id = "List all Merchant Connectors",
+    tag = "Profile",
+    operation_id = "Retrieve the CVV Re-collection Config of a Profile",
     security(("admin_api_key" = []))
 )]
-#[cfg(feature = "v2")]
-pub async fn connector_list() {}
+pub async fn cvv_recollection_config_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Upsert Network Token Preference Config
+///
+/// Configure whether the saved cards of the *profile* which have a network token are authorized
+/// with the network token and a cryptogram, or with the card number held in the vault. The
+/// preference can be overridden for each connector. Payments fall back to the card number while
+/// the provisioning of the network token of the card is suspended
+#[utoipa::path(
+    put,
+    path = "/account/{account_id}/business_profile/{profile_id}/network_token_preference_config",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body(
+        content = NetworkTokenPreferenceConfig,
+        examples(
+            (
+                "Authorize with the card number for a connector" = (
+                    value = json!({
+                        "default_preference": "network_token",
+                        "connectors": {
+                            "adyen": "pan"
+                        }
+                    })
+                )
+            )
+    )),
+    responses(
+        (status = 200, description = "Network Token Preference Config Updated", body = NetworkTokenPreferenceConfig),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Profile",
+    operation_id = "Upsert the Network Token Preference Config of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn network_token_preference_config_upsert() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Retrieve Network Token Preference Config
+///
+/// Retrieve the network token preference config of the *profile*
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/network_token_preference_config",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Network Token Preference Config Retrieved", body = NetworkTokenPreferenceConfig),
+        (status = 404, description = "Profile not found")
+    ),
+    tag = "Profile",
+    operation_id = "Retrieve the Network Token Preference Config of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn network_token_preference_config_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Update Feature Flags
+///
+/// Set or clear the feature flags of the *profile*, which take precedence over the feature flags
+/// of the merchant account
+#[utoipa::path(
+    put,
+    path = "/account/{account_id}/business_profile/{profile_id}/feature_flags",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body(
+        content = FeatureFlagsUpdateRequest,
+        examples(
+            (
+                "Clear the network tokenization flag" = (
+                    value = json!({
+                        "flags": {
+                            "network_tokenization": null
+                        }
+                    })
+                )
+            )
+    )),
+    responses(
+        (status = 200, description = "Feature Flags Updated", body = FeatureFlagsResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Profile",
+    operation_id = "Update the Feature Flags of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn profile_feature_flags_update() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Retrieve Feature Flags
+///
+/// Retrieve the feature flags of the *profile*, along with the level from which the value of each
+/// flag is picked
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/feature_flags",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Feature Flags Retrieved", body = FeatureFlagsResponse),
+        (status = 404, description = "Profile not found")
+    ),
+    tag = "Profile",
+    operation_id = "Retrieve the Feature Flags of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn profile_feature_flags_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Upsert Payment Facilitator Details
+///
+/// Configure the merchant category code, the soft descriptor and the sub-merchant details sent to
+/// the connectors for the payments of the *profile*. These can be overridden per payment
+#[utoipa::path(
+    put,
+    path = "/account/{account_id}/business_profile/{profile_id}/payment_facilitator_details",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body(
+        content = PaymentFacilitatorDetails,
+        examples(
+            (
+                "Configure the details of a sub-merchant" = (
+                    value = json!({
+                        "merchant_category_code": "5812",
+                        "soft_descriptor": "PLATFORM*JOES PIZZA",
+                        "payment_facilitator_id": "pf_12345",
+                        "sub_merchant": {
+                            "id": "sub_merchant_123",
+                            "name": "Joe's Pizza",
+                            "address": {
+                                "line1": "1467 Harrison Street",
+                                "city": "San Francisco",
+                                "state": "California",
+                                "zip": "94122",
+                                "country": "US"
+                            }
+                        }
+                    })
+                )
+            )
+    )),
+    responses(
+        (status = 200, description = "Payment Facilitator Details Updated", body = PaymentFacilitatorDetails),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Profile",
+    operation_id = "Upsert the Payment Facilitator Details of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn payment_facilitator_details_upsert() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Retrieve Payment Facilitator Details
+///
+/// Retrieve the payment facilitator details configured for the *profile*
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/payment_facilitator_details",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Payment Facilitator Details Retrieved", body = PaymentFacilitatorDetails),
+        (status = 404, description = "Payment Facilitator Details not found")
+    ),
+    tag = "Profile",
+    operation_id = "Retrieve the Payment Facilitator Details of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn payment_facilitator_details_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Delete Payment Facilitator Details
+///
+/// Delete the payment facilitator details configured for the *profile*. Only the details passed
+/// in the payments are sent to the connectors after they are deleted
+#[utoipa::path(
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}/payment_facilitator_details",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Payment Facilitator Details Deleted"),
+        (status = 404, description = "Payment Facilitator Details not found")
+    ),
+    tag = "Profile",
+    operation_id = "Delete the Payment Facilitator Details of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn payment_facilitator_details_delete() {}
+
+// ******************************************** Common profile routes ******************************************** //
+
+/// Profile - Delete
+///
+/// Delete the *profile*
+#[utoipa::path(
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Profiles Deleted", body = bool),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Profile",
+    operation_id = "Delete the Profile",
+    security(("api_key" = []))
+)]
+pub async fn profile_delete() {}
+
+/// Profile - List
+///
+/// Lists all the *profiles* under a merchant
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile",
+    params (
+        ("account_id" = String, Path, description = "Merchant Identifier"),
+    ),
+    responses(
+        (status = 200, description = "Profiles Retrieved", body = Vec<ProfileResponse>)
+    ),
+    tag = "Profile",
+    operation_id = "List Profiles",
+    security(("api_key" = []))
+)]
+pub async fn profile_list() {}
+
+// ******************************************** V2 profile routes ******************************************** //
+
+#[cfg(feature = "v2")]
+/// Profile - Create
+///
+/// Creates a new *profile* for a merchant
+#[utoipa::path(
+    post,
+    path = "/v2/profiles",
+    params(
+        (
+            "X-Merchant-Id" = String, Header,
+            description = "Merchant ID of the profile.",
+            example = json!({"X-Merchant-Id": "abc_iG5VNjsN9xuCg7Xx0uWh"})
+        ),
+    ),
+    request_body(
+        content = ProfileCreate,
+        examples(
+            (
+                "Create a profile with profile name" = (
+                    value = json!({
+                        "profile_name": "shoe_business"
+                    })
+                )
+            )
+        )
+    ),
+    responses(
+        (status = 200, description = "Account Created", body = ProfileResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Profile",
+    operation_id = "Create A Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn profile_create() {}
+
+#[cfg(feature = "v2")]
+/// Profile - Update
+///
+/// Update the *profile*
+#[utoipa::path(
+    put,
+    path = "/v2/profiles/{id}",
+    params(
+        ("id" = String, Path, description = "The unique identifier for the profile"),
+        (
+            "X-Merchant-Id" = String, Header,
+            description = "Merchant ID of the profile.",
+            example = json!({"X-Merchant-Id": "abc_iG5VNjsN9xuCg7Xx0uWh"})
+        ),
+    ),
+    request_body(
+        content = ProfileCreate,
+        examples(
+            (
+                "Update profile with profile name fields" = (
+                    value = json!({
+                        "profile_name" : "shoe_business"
+                    })
+                )
+            )
+    )),
+    responses(
+        (status = 200, description = "Profile Updated", body = ProfileResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Profile",
+    operation_id = "Update a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn profile_update() {}
+
+#[cfg(feature = "v2")]
+/// Profile - Activate routing algorithm
+///
+/// Activates a routing algorithm under a profile
+#[utoipa::path(
+    patch,
+    path = "/v2/profiles/{id}/activate-routing-algorithm",
+    request_body ( content = RoutingAlgorithmId,
+      examples(  (
+            "Activate a routing algorithm" = (
+                value = json!({
+                    "routing_algorithm_id": "routing_algorithm_123"
+                })
+            )
+            ))),
+    params(
+        ("id" = String, Path, description = "The unique identifier for the profile"),
+    ),
+    responses(
+        (status = 200, description = "Routing Algorithm is activated", body = RoutingDictionaryRecord),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 400, description = "Bad request")
+    ),
+   tag = "Profile",
+   operation_id = "Activates a routing algorithm under a profile",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_link_config() {}
+
+#[cfg(feature = "v2")]
+/// Profile - Deactivate routing algorithm
+///
+/// Deactivates a routing algorithm under a profile
+#[utoipa::path(
+    patch,
+    path = "/v2/profiles/{id}/deactivate-routing-algorithm",
+    params(
+        ("id" = String, Path, description = "The unique identifier for the profile"),
+    ),
+    responses(
+        (status = 200, description = "Successfully deactivated routing config", body = RoutingDictionaryRecord),
+        (status = 500, description = "Internal server error"),
+        (status = 400, description = "Malformed request"),
+        (status = 403, description = "Malformed request"),
+        (status = 422, description = "Unprocessable request")
+    ),
+   tag = "Profile",
+   operation_id = " Deactivates a routing algorithm under a profile",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_unlink_config() {}
+
+#[cfg(feature = "v2")]
+/// Profile - Update Default Fallback Routing Algorithm
+///
+/// Update the default fallback routing algorithm for the profile
+#[utoipa::path(
+    patch,
+    path = "/v2/profiles/{id}/fallback-routing",
+    request_body = Vec<RoutableConnectorChoice>,
+    params(
+        ("id" = String, Path, description = "The unique identifier for the profile"),
+    ),
+    responses(
+        (status = 200, description = "Successfully updated the default fallback routing algorithm", body = Vec<RoutableConnectorChoice>),
+        (status = 500, description = "Internal server error"),
+        (status = 400, description = "Malformed request"),
+        (status = 422, description = "Unprocessable request")
+    ),
+   tag = "Profile",
+   operation_id = "Update the default fallback routing algorithm for the profile",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_update_default_config() {}
+
+#[cfg(feature = "v2")]
+/// Profile - Retrieve
+///
+/// Retrieve existing *profile*
+#[utoipa::path(
+    get,
+    path = "/v2/profiles/{id}",
+    params(
+        ("id" = String, Path, description = "The unique identifier for the profile"),
+        (
+            "X-Merchant-Id" = String, Header,
+            description = "Merchant ID of the profile.",
+            example = json!({"X-Merchant-Id": "abc_iG5VNjsN9xuCg7Xx0uWh"})
+        ),
+    ),
+    responses(
+        (status = 200, description = "Profile Updated", body = ProfileResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Profile",
+    operation_id = "Retrieve a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn profile_retrieve() {}
+
+#[cfg(feature = "v2")]
+/// Profile - Retrieve Active Routing Algorithm
+///_
+/// Retrieve active routing algorithm under the profile
+#[utoipa::path(
+    get,
+    path = "/v2/profiles/{id}/routing-algorithm",
+    params(
+        ("id" = String, Path, description = "The unique identifier for the profile"),
+        ("limit" = Option<u16>, Query, description = "The number of records of the algorithms to be returned"),
+        ("offset" = Option<u8>, Query, description = "The record offset of the algorithm from which to start gathering the results")),
+    responses(
+        (status = 200, description = "Successfully retrieved active config", body = LinkedRoutingConfigRetrieveResponse),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 403, description = "Forbidden")
+    ),
+   tag = "Profile",
+   operation_id = "Retrieve the active routing algorithm under the profile",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_retrieve_linked_config() {}
+
+#[cfg(feature = "v2")]
+/// Profile - Retrieve Default Fallback Routing Algorithm
+///
+/// Retrieve the default fallback routing algorithm for the profile
+#[utoipa::path(
+    get,
+    path = "/v2/profiles/{id}/fallback-routing",
+    params(
+        ("id" = String, Path, description = "The unique identifier for the profile"),
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved default fallback routing algorithm", body = Vec<RoutableConnectorChoice>),
+        (status = 500, description = "Internal server error")
+    ),
+   tag = "Profile",
+   operation_id = "Retrieve the default fallback routing algorithm for the profile",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_retrieve_default_config() {}
+
+/// Profile - Connector Accounts List
+///
+/// List Connector Accounts for the profile
+#[utoipa::path(
+    get,
+    path = "/v2/profiles/{id}/connector-accounts",
+    params(
+        ("id" = String, Path, description = "The unique identifier for the business profile"),
+        (
+            "X-Merchant-Id" = String, Header,
+            description = "Merchant ID of the profile.",
+            example = json!({"X-Merchant-Id": "abc_iG5VNjsN9xuCg7Xx0uWh"})
+        ),
+    ),
+    responses(
+        (status = 200, description = "Merchant Connector list retrieved successfully", body = Vec<MerchantConnectorResponse>),
+        (status = 404, description = "Merchant Connector does not exist in records"),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Business Profile",
+    operation_id = "List all Merchant Connectors",
+    security(("admin_api_key" = []))
+)]
+#[cfg(feature = "v2")]
+pub async fn connector_list() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Upsert Custom Payment Status Mapping
+///
+/// Map the statuses of the payments of the *profile*, and of their attempts, to custom statuses.
+/// The custom status is sent as the `custom_status` of the payments in the API responses and the
+/// webhooks
+#[utoipa::path(
+    put,
+    path = "/account/{account_id}/business_profile/{profile_id}/custom_payment_status_mapping",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body(
+        content = CustomPaymentStatusMapping,
+        examples(
+            (
+                "Map the statuses of the payments awaiting bank transfers" = (
+                    value = json!({
+                        "attempt_statuses": {
+                            "authentication_pending": "awaiting_bank_confirmation"
+                        },
+                        "intent_statuses": {
+                            "processing": "awaiting_bank_confirmation",
+                            "succeeded": "paid"
+                        }
+                    })
+                )
+            )
+    )),
+    responses(
+        (status = 200, description = "Custom Payment Status Mapping Updated", body = CustomPaymentStatusMapping),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Profile",
+    operation_id = "Upsert the Custom Payment Status Mapping of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn custom_payment_status_mapping_upsert() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Retrieve Custom Payment Status Mapping
+///
+/// Retrieve the custom payment status mapping configured for the *profile*
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/custom_payment_status_mapping",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Custom Payment Status Mapping Retrieved", body = CustomPaymentStatusMapping),
+        (status = 404, description = "Custom Payment Status Mapping not found")
+    ),
+    tag = "Profile",
+    operation_id = "Retrieve the Custom Payment Status Mapping of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn custom_payment_status_mapping_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Delete Custom Payment Status Mapping
+///
+/// Delete the custom payment status mapping configured for the *profile*. The payments are sent
+/// without a `custom_status` after it is deleted
+#[utoipa::path(
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}/custom_payment_status_mapping",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Custom Payment Status Mapping Deleted"),
+        (status = 404, description = "Custom Payment Status Mapping not found")
+    ),
+    tag = "Profile",
+    operation_id = "Delete the Custom Payment Status Mapping of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn custom_payment_status_mapping_delete() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Upsert Custom Fields Schema
+///
+/// Define the custom fields accepted on the payments of the *profile*. The values passed under the
+/// `custom_fields` of the payments on their creation are validated against the schema, returned
+/// in the responses and the webhooks of the payments, and can be used to filter the payments list
+#[utoipa::path(
+    put,
+    path = "/account/{account_id}/business_profile/{profile_id}/custom_fields_schema",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body(
+        content = CustomFieldsSchema,
+        examples(
+            (
+                "Define the store and the channel of the payments" = (
+                    value = json!({
+                        "fields": [
+                            {
+                                "key": "store_id",
+                                "field_type": "string",
+                                "required": true,
+                                "max_length": 32
+                            },
+                            {
+                                "key": "channel",
+                                "field_type": "enum",
+                                "allowed_values": ["online", "in_store"]
+                            }
+                        ]
+                    })
+                )
+            )
+    )),
+    responses(
+        (status = 200, description = "Custom Fields Schema Updated", body = CustomFieldsSchema),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Profile",
+    operation_id = "Upsert the Custom Fields Schema of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn custom_fields_schema_upsert() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Retrieve Custom Fields Schema
+///
+/// Retrieve the custom fields schema configured for the *profile*
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/custom_fields_schema",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Custom Fields Schema Retrieved", body = CustomFieldsSchema),
+        (status = 404, description = "Custom Fields Schema not found")
+    ),
+    tag = "Profile",
+    operation_id = "Retrieve the Custom Fields Schema of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn custom_fields_schema_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Delete Custom Fields Schema
+///
+/// Delete the custom fields schema configured for the *profile*. The payments no longer accept
+/// `custom_fields` after it is deleted
+#[utoipa::path(
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}/custom_fields_schema",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Custom Fields Schema Deleted"),
+        (status = 404, description = "Custom Fields Schema not found")
+    ),
+    tag = "Profile",
+    operation_id = "Delete the Custom Fields Schema of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn custom_fields_schema_delete() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Upsert Unified Error Message Overrides
+///
+/// Override the payer facing messages of the unified errors for the payments of the *profile*, by
+/// unified code and locale. The overrides take precedence over the catalog of translations when the
+/// `localized_unified_message` of the payments is looked up
+#[utoipa::path(
+    put,
+    path = "/account/{account_id}/business_profile/{profile_id}/unified_error_message_overrides",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body(
+        content = UnifiedErrorMessageOverrides,
+        examples(
+            (
+                "Override the message of declined cards" = (
+                    value = json!({
+                        "messages": {
+                            "UE_9000": {
+                                "en": "Your card was declined, please try another card",
+                                "fr": "Votre carte a été refusée, veuillez essayer une autre carte"
+                            }
+                        }
+                    })
+                )
+            )
+    )),
+    responses(
+        (status = 200, description = "Unified Error Message Overrides Updated", body = UnifiedErrorMessageOverrides),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Profile",
+    operation_id = "Upsert the Unified Error Message Overrides of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn unified_error_message_overrides_upsert() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Retrieve Unified Error Message Overrides
+///
+/// Retrieve the unified error message overrides configured for the *profile*
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/unified_error_message_overrides",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Unified Error Message Overrides Retrieved", body = UnifiedErrorMessageOverrides),
+        (status = 404, description = "Unified Error Message Overrides not found")
+    ),
+    tag = "Profile",
+    operation_id = "Retrieve the Unified Error Message Overrides of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn unified_error_message_overrides_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Delete Unified Error Message Overrides
+///
+/// Delete the unified error message overrides configured for the *profile*. The payer facing
+/// messages are looked up only in the catalog of translations after they are deleted
+#[utoipa::path(
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}/unified_error_message_overrides",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Unified Error Message Overrides Deleted"),
+        (status = 404, description = "Unified Error Message Overrides not found")
+    ),
+    tag = "Profile",
+    operation_id = "Delete the Unified Error Message Overrides of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn unified_error_message_overrides_delete() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Upsert Post Authorization Rules
+///
+/// Create or update the post authorization rules of the *profile*. The rules are evaluated in
+/// order on the payments right after they are authorized, on the normalized results of the address
+/// and the card verification value checks of the connector, and the action of the first rule which
+/// matches is taken. Only the payments which are authorized and not yet captured can be voided.
+#[utoipa::path(
+    put,
+    path = "/account/{account_id}/business_profile/{profile_id}/post_authorization_rules",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body(
+        content = PostAuthorizationRules,
+        examples(
+            (
+                "Void the payments above 100 USD with a full address mismatch" = (
+                    value = json!({
+                        "rules": [
+                            {
+                                "name": "void_full_avs_mismatch",
+                                "avs_results": ["no_match"],
+                                "min_amount": 10000,
+                                "currency": "USD",
+                                "action": "void"
+                            }
+                        ]
+                    })
+                )
+            )
+    )),
+    responses(
+        (status = 200, description = "Post Authorization Rules Updated", body = PostAuthorizationRules),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Profile",
+    operation_id = "Upsert the Post Authorization Rules of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn post_authorization_rules_upsert() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Retrieve Post Authorization Rules
+///
+/// Retrieve the post authorization rules configured for the *profile*
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/post_authorization_rules",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Post Authorization Rules Retrieved", body = PostAuthorizationRules),
+        (status = 404, description = "Post Authorization Rules not found")
+    ),
+    tag = "Profile",
+    operation_id = "Retrieve the Post Authorization Rules of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn post_authorization_rules_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Delete Post Authorization Rules
+///
+/// Delete the post authorization rules configured for the *profile*. The authorized payments of the
+/// profile are no longer voided on the results of the checks after they are deleted
+#[utoipa::path(
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}/post_authorization_rules",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Post Authorization Rules Deleted"),
+        (status = 404, description = "Post Authorization Rules not found")
+    ),
+    tag = "Profile",
+    operation_id = "Delete the Post Authorization Rules of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn post_authorization_rules_delete() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Upsert Checkout Theme
+///
+/// Create a new version of the theme of the hosted checkout page of the *profile*, and apply it to
+/// the payment links of the profile. The theme is applied when the payment links are rendered, over
+/// the payment link configuration with which they were created
+#[utoipa::path(
+    put,
+    path = "/account/{account_id}/business_profile/{profile_id}/checkout_theme",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body(
+        content = CheckoutTheme,
+        examples(
+            (
+                "Brand the checkout page and hide the order details" = (
+                    value = json!({
+                        "logo": "https://hyperswitch.io/favicon.ico",
+                        "colors": {
+                            "primary": "#4E6ADD",
+                            "button": "#1A1A1A",
+                            "button_text": "#FFFFFF"
+                        },
+                        "layout": {
+                            "sdk_layout": "accordion",
+                            "visible_sections": ["merchant_description", "transaction_details"]
+                        },
+                        "default_locale": "fr"
+                    })
+                )
+            )
+    )),
+    responses(
+        (status = 200, description = "Checkout Theme Updated", body = CheckoutThemeResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Profile",
+    operation_id = "Upsert the Checkout Theme of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn checkout_theme_upsert() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Retrieve Checkout Theme
+///
+/// Retrieve the version of the theme of the hosted checkout page applied to the payment links of
+/// the *profile*, or the requested version
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/checkout_theme",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile"),
+        api_models::payments::CheckoutThemeRetrieveQuery
+    ),
+    responses(
+        (status = 200, description = "Checkout Theme Retrieved", body = CheckoutThemeResponse),
+        (status = 404, description = "Checkout Theme not found")
+    ),
+    tag = "Profile",
+    operation_id = "Retrieve the Checkout Theme of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn checkout_theme_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Delete Checkout Theme
+///
+/// Delete every version of the theme of the hosted checkout page of the *profile*. The payment
+/// links are rendered only with the payment link configuration with which they were created after
+/// it is deleted
+#[utoipa::path(
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}/checkout_theme",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Checkout Theme Deleted"),
+        (status = 404, description = "Checkout Theme not found")
+    ),
+    tag = "Profile",
+    operation_id = "Delete the Checkout Theme of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn checkout_theme_delete() {}
+
+#[cfg(feature = "v1")]
+/// Profile - List Checkout Theme Versions
+///
+/// List the versions of the theme of the hosted checkout page of the *profile*, from the latest
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/checkout_theme/versions",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Checkout Theme Versions Retrieved", body = CheckoutThemeVersionsResponse),
+        (status = 404, description = "Checkout Theme not found")
+    ),
+    tag = "Profile",
+    operation_id = "List the Checkout Theme Versions of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn checkout_theme_versions_list() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Activate Checkout Theme Version
+///
+/// Apply a previous version of the theme of the hosted checkout page to the payment links of the
+/// *profile*
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/checkout_theme/activate",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body = CheckoutThemeActivateRequest,
+    responses(
+        (status = 200, description = "Checkout Theme Version Activated", body = CheckoutThemeResponse),
+        (status = 404, description = "Checkout Theme Version not found")
+    ),
+    tag = "Profile",
+    operation_id = "Activate a Checkout Theme Version of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn checkout_theme_activate() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Upsert Dunning Config
+///
+/// Create or update the dunning configuration of the *profile*. The failed off session payments of
+/// the profile made with a mandate or a saved payment method are retried on the days of the
+/// schedule, and are marked as uncollectible once all the retries fail
+#[utoipa::path(
+    put,
+    path = "/account/{account_id}/business_profile/{profile_id}/dunning_config",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body(
+        content = DunningConfig,
+        examples(
+            (
+                "Retry on the first, third and seventh day after the failure" = (
+                    value = json!({
+                        "retry_schedule_days": [1, 3, 7],
+                        "hard_decline_codes": ["41", "43", "lost_card", "stolen_card"]
+                    })
+                )
+            )
+    )),
+    responses(
+        (status = 200, description = "Dunning Config Updated", body = DunningConfig),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Profile",
+    operation_id = "Upsert the Dunning Config of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn dunning_config_upsert() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Retrieve Dunning Config
+///
+/// Retrieve the dunning configuration of the *profile*
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/dunning_config",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Dunning Config Retrieved", body = DunningConfig),
+        (status = 404, description = "Dunning Config not found")
+    ),
+    tag = "Profile",
+    operation_id = "Retrieve the Dunning Config of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn dunning_config_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Delete Dunning Config
+///
+/// Delete the dunning configuration of the *profile*. The failed payments of the profile are not
+/// retried after it is deleted, while the payments already being retried continue on their schedule
+#[utoipa::path(
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}/dunning_config",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Dunning Config Deleted"),
+        (status = 404, description = "Dunning Config not found")
+    ),
+    tag = "Profile",
+    operation_id = "Delete the Dunning Config of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn dunning_config_delete() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Upsert Auto Capture Delay Config
+///
+/// Create or update the auto capture delay of the *profile*. The payments of the profile with
+/// automatic capture are authorized with manual capture, and are captured once the delay has
+/// elapsed after they are confirmed, which gives a window to review them for fraud. The payments
+/// flagged for review by the FRM connector are not captured by then, and the capture of a payment
+/// can be released or canceled early through its scheduled capture
+#[utoipa::path(
+    put,
+    path = "/account/{account_id}/business_profile/{profile_id}/auto_capture_delay_config",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body(
+        content = AutoCaptureDelayConfig,
+        examples(
+            (
+                "Capture the payments a day after they are confirmed" = (
+                    value = json!({
+                        "delay_in_hours": 24
+                    })
+                )
+            )
+    )),
+    responses(
+        (status = 200, description = "Auto Capture Delay Config Updated", body = AutoCaptureDelayConfig),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Profile",
+    operation_id = "Upsert the Auto Capture Delay Config of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn auto_capture_delay_config_upsert() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Retrieve Auto Capture Delay Config
+///
+/// Retrieve the auto capture delay of the *profile*
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/auto_capture_delay_config",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Auto Capture Delay Config Retrieved", body = AutoCaptureDelayConfig),
+        (status = 404, description = "Auto Capture Delay Config not found")
+    ),
+    tag = "Profile",
+    operation_id = "Retrieve the Auto Capture Delay Config of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn auto_capture_delay_config_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Delete Auto Capture Delay Config
+///
+/// Delete the auto capture delay of the *profile*. The payments of the profile confirmed after it
+/// is deleted are captured automatically again, while the captures already held remain scheduled
+#[utoipa::path(
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}/auto_capture_delay_config",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Auto Capture Delay Config Deleted"),
+        (status = 404, description = "Auto Capture Delay Config not found")
+    ),
+    tag = "Profile",
+    operation_id = "Delete the Auto Capture Delay Config of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn auto_capture_delay_config_delete() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Upsert Step Up Authentication Config
+///
+/// Create or update the step-up authentication rules of the *profile*. A 3DS challenge is forced on
+/// the card payments of the profile when the customer or the card exceeds their number of payments
+/// within a window, or when the amount of the payment exceeds the threshold for its currency. The
+/// other card payments go through the 3DS decision of the merchant.
+#[utoipa::path(
+    put,
+    path = "/account/{account_id}/business_profile/{profile_id}/step_up_authentication_config",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body(
+        content = StepUpAuthenticationConfig,
+        examples(
+            (
+                "Step up the fourth payment of a card within an hour, and the payments above 500 USD" = (
+                    value = json!({
+                        "card_velocity": {
+                            "max_payments": 3,
+                            "window_in_minutes": 60
+                        },
+                        "amount_thresholds": [
+                            {
+                                "currency": "USD",
+                                "amount": 50000
+                            }
+                        ]
+                    })
+                )
+            )
+    )),
+    responses(
+        (status = 200, description = "Step Up Authentication Config Updated", body = StepUpAuthenticationConfig),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Profile",
+    operation_id = "Upsert the Step Up Authentication Config of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn step_up_authentication_config_upsert() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Retrieve Step Up Authentication Config
+///
+/// Retrieve the step-up authentication rules of the *profile*
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/step_up_authentication_config",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Step Up Authentication Config Retrieved", body = StepUpAuthenticationConfig),
+        (status = 404, description = "Step Up Authentication Config not found")
+    ),
+    tag = "Profile",
+    operation_id = "Retrieve the Step Up Authentication Config of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn step_up_authentication_config_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Delete Step Up Authentication Config
+///
+/// Delete the step-up authentication rules of the *profile*. The card payments of the profile then
+/// only go through the 3DS decision of the merchant.
+#[utoipa::path(
+    delete,
+    path = "/account/{account_id}/business_profile/{profile_id}/step_up_authentication_config",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Step Up Authentication Config Deleted"),
+        (status = 404, description = "Step Up Authentication Config not found")
+    ),
+    tag = "Profile",
+    operation_id = "Delete the Step Up Authentication Config of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn step_up_authentication_config_delete() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Update Test Mode
+///
+/// Enable or disable the test mode of the *profile*. The payments of a profile in test mode are
+/// routed only to its test merchant connector accounts. The test mode cannot be disabled while
+/// test API Keys are bound to the profile
+#[utoipa::path(
+    put,
+    path = "/account/{account_id}/business_profile/{profile_id}/test_mode",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body(
+        content = ProfileTestModeRequest,
+        examples(
+            (
+                "Enable the test mode" = (
+                    value = json!({
+                        "test_mode": true
+                    })
+                )
+            )
+    )),
+    responses(
+        (status = 200, description = "Test Mode Updated", body = ProfileTestModeResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 412, description = "Test API Keys are bound to the profile")
+    ),
+    tag = "Profile",
+    operation_id = "Update the Test Mode of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn profile_test_mode_update() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Retrieve Test Mode
+///
+/// Retrieve whether the *profile* is in test mode
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/test_mode",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Test Mode Retrieved", body = ProfileTestModeResponse),
+        (status = 404, description = "Profile not found")
+    ),
+    tag = "Profile",
+    operation_id = "Retrieve the Test Mode of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn profile_test_mode_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Purge Test Data
+///
+/// Delete the payments of the *profile* created before the given number of days, along with their
+/// attempts and refunds, and the customers left without payments or saved payment methods. Only
+/// the data of a profile in test mode can be purged
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/test_data/purge",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    request_body(
+        content = TestDataPurgeRequest,
+        examples(
+            (
+                "Purge the test data older than thirty days" = (
+                    value = json!({
+                        "older_than_days": 30
+                    })
+                )
+            )
+    )),
+    responses(
+        (status = 200, description = "Test Data Purged", body = TestDataPurgeResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 412, description = "The profile is not in test mode")
+    ),
+    tag = "Profile",
+    operation_id = "Purge the Test Data of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn test_data_purge() {}
+
+#[cfg(feature = "v1")]
+/// Profile - Retrieve Coverage
+///
+/// Retrieve the payment methods that the *profile* can accept, along with the currencies and the
+/// billing countries they can be accepted in, based on its merchant connector accounts and the
+/// payment method filters of their connectors
+#[utoipa::path(
+    get,
+    path = "/account/{account_id}/business_profile/{profile_id}/coverage",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the profile")
+    ),
+    responses(
+        (status = 200, description = "Coverage Retrieved", body = ProfileCoverageResponse),
+        (status = 404, description = "Profile not found")
+    ),
+    tag = "Profile",
+    operation_id = "Retrieve the Coverage of a Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn profile_coverage_retrieve() {}
@@ -161,6 +161,124 @@ pub fn refunds_list_profile() {}
 )]
 pub async fn refunds_filter_list() {}
 
+/// Refunds - Create a Batch
+///
+/// Creates a batch of refunds, for instance to refund the payments of a recalled product. The refunds can be provided either as a JSON array, or as a CSV file with `payment_id`, `amount` and `reason` columns uploaded in the `file` field of a multipart form. Each row is validated when the batch is created, and the refunds of the valid rows are initiated asynchronously
+#[utoipa::path(
+    post,
+    path = "/refunds/batch",
+    request_body(
+        content = Vec<RefundBatchRecord>,
+        examples(
+            (
+                "Create a refund batch" = (
+                    value = json!([
+                        {
+                            "payment_id": "{{payment_id}}",
+                            "amount": 6540,
+                            "reason": "Product recall"
+                        },
+                        {
+                            "payment_id": "{{payment_id}}"
+                        }
+                    ])
+                )
+            ),
+        )
+    ),
+    responses(
+        (status = 200, description = "Refund batch created", body = RefundBatchResponse),
+        (status = 400, description = "Invalid refund batch")
+    ),
+    tag = "Refunds",
+    operation_id = "Create a Refund Batch",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn refunds_batch_create() {}
+
+/// Refunds - Retrieve a Batch
+///
+/// Retrieves the progress of a refund batch
+#[utoipa::path(
+    get,
+    path = "/refunds/batch/{batch_id}",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for the refund batch")
+    ),
+    responses(
+        (status = 200, description = "Refund batch retrieved", body = RefundBatchResponse),
+        (status = 404, description = "Refund batch does not exist in our records")
+    ),
+    tag = "Refunds",
+    operation_id = "Retrieve a Refund Batch",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn refunds_batch_retrieve() {}
+
+/// Refunds - Download the Report of a Batch
+///
+/// Downloads the outcome of each row of a refund batch as a CSV file, along with the identifier and the status of the refund initiated for the row
+#[utoipa::path(
+    get,
+    path = "/refunds/batch/{batch_id}/report",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for the refund batch")
+    ),
+    responses(
+        (status = 200, description = "Refund batch report", content_type = "text/csv", body = String),
+        (status = 404, description = "Refund batch does not exist in our records")
+    ),
+    tag = "Refunds",
+    operation_id = "Download a Refund Batch Report",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn refunds_batch_report() {}
+
+/// Refunds - Resolve
+///
+/// Resolves a refund held for manual review, which is the case for the refunds stuck in pending beyond the settlement time of the connector which the connector could not find. The outcome of the refund is to be confirmed with the connector before the refund is resolved as either succeeded or failed. The refunds held for manual review can be listed by filtering the refunds with the `review` status
+#[utoipa::path(
+    post,
+    path = "/refunds/{refund_id}/resolve",
+    params(
+        ("refund_id" = String, Path, description = "The identifier for refund")
+    ),
+    request_body(
+        content = RefundResolveRequest,
+        examples(
+            (
+                "Resolve a refund as succeeded" = (
+                    value = json!({
+                        "status": "succeeded"
+                    })
+                )
+            ),
+            (
+                "Resolve a refund as failed" = (
+                    value = json!({
+                        "status": "failed",
+                        "error_code": "refund_not_found",
+                        "error_message": "Refund was not processed by the connector"
+                    })
+                )
+            ),
+        )
+    ),
+    responses(
+        (status = 200, description = "Refund resolved", body = RefundResponse),
+        (status = 400, description = "Refund is not held for manual review"),
+        (status = 404, description = "Refund does not exist in our records")
+    ),
+    tag = "Refunds",
+    operation_id = "Resolve a Refund",
+    security(("api_key" = []))
+)]
+#[cfg(feature = "v1")]
+pub async fn refunds_resolve() {}
+
 /// Refunds - Create
 ///
 /// Creates a refund against an already processed payment. In case of some processors, you can even opt to refund only a partial amount multiple times until the original charge amount has been refunded
@@ -86,6 +86,207 @@ pub async fn routing_link_config() {}
 )]
 pub async fn routing_retrieve_config() {}
 
+#[cfg(feature = "v1")]
+/// Routing - Export
+///
+/// Export a routing algorithm as a portable document, which can be imported into another profile
+#[utoipa::path(
+    get,
+    path = "/routing/{routing_algorithm_id}/export",
+    params(
+        ("routing_algorithm_id" = String, Path, description = "The unique identifier for a config"),
+    ),
+    responses(
+        (status = 200, description = "Successfully exported routing config", body = RoutingConfigExport),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 412, description = "Dynamic routing configs cannot be exported")
+    ),
+   tag = "Routing",
+   operation_id = "Export a routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_export_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Import
+///
+/// Import a routing algorithm exported from another profile. The connectors of the algorithm are
+/// bound to the merchant connector accounts of the profile, and its rules are validated against
+/// the payment methods, currencies and countries supported by them
+#[utoipa::path(
+    post,
+    path = "/routing/import",
+    request_body = RoutingConfigImportRequest,
+    responses(
+        (status = 200, description = "Routing config imported", body = RoutingDictionaryRecord),
+        (status = 400, description = "Request body is malformed"),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 422, description = "Unprocessable request"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Import a routing config",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_import_config() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Create Experiment
+///
+/// Start an experiment on a rule of an advanced routing algorithm. The traffic of the rule is split
+/// between its connector selection and the variant, and the experiment is concluded once the
+/// difference between their success rates is statistically significant
+#[utoipa::path(
+    post,
+    path = "/routing/experiments",
+    request_body = RoutingExperimentCreateRequest,
+    responses(
+        (status = 200, description = "Routing experiment started", body = RoutingExperiment),
+        (status = 400, description = "Request body is malformed"),
+        (status = 404, description = "Resource missing"),
+        (status = 412, description = "An experiment is already running on the rule"),
+        (status = 500, description = "Internal server error"),
+    ),
+   tag = "Routing",
+   operation_id = "Create a routing experiment",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_experiment_create() {}
+
+#[cfg(feature = "v1")]
+/// Routing - List Experiments
+///
+/// List the routing experiments of a profile
+#[utoipa::path(
+    get,
+    path = "/routing/experiments",
+    params(
+        ("profile_id" = String, Query, description = "The unique identifier for the profile"),
+    ),
+    responses(
+        (status = 200, description = "Routing experiments retrieved", body = Vec<RoutingExperiment>),
+        (status = 404, description = "Resource missing"),
+        (status = 500, description = "Internal server error"),
+    ),
+   tag = "Routing",
+   operation_id = "List routing experiments",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_experiment_list() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Retrieve Experiment
+///
+/// Retrieve a routing experiment along with the results of its arms
+#[utoipa::path(
+    get,
+    path = "/routing/experiments/{experiment_id}",
+    params(
+        ("experiment_id" = String, Path, description = "The unique identifier for the experiment"),
+        ("profile_id" = String, Query, description = "The unique identifier for the profile"),
+    ),
+    responses(
+        (status = 200, description = "Routing experiment retrieved", body = RoutingExperimentResponse),
+        (status = 404, description = "Resource missing"),
+        (status = 500, description = "Internal server error"),
+    ),
+   tag = "Routing",
+   operation_id = "Retrieve a routing experiment",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_experiment_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Conclude Experiment
+///
+/// Conclude a running routing experiment with the given arm, or cancel it if no arm is given
+#[utoipa::path(
+    post,
+    path = "/routing/experiments/{experiment_id}/conclude",
+    params(
+        ("experiment_id" = String, Path, description = "The unique identifier for the experiment"),
+    ),
+    request_body = RoutingExperimentConcludeRequest,
+    responses(
+        (status = 200, description = "Routing experiment concluded", body = RoutingExperiment),
+        (status = 400, description = "Request body is malformed"),
+        (status = 404, description = "Resource missing"),
+        (status = 412, description = "The experiment is not running"),
+        (status = 500, description = "Internal server error"),
+    ),
+   tag = "Routing",
+   operation_id = "Conclude a routing experiment",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_experiment_conclude() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Update Volume Caps
+///
+/// Set the daily volume caps of the connectors of a profile, replacing its existing caps. A
+/// connector is excluded from routing for the rest of the day once it reaches any of its caps,
+/// unless none of the other eligible connectors is available
+#[utoipa::path(
+    put,
+    path = "/routing/volume_caps",
+    request_body = ConnectorVolumeCapsRequest,
+    responses(
+        (status = 200, description = "Volume caps updated", body = ConnectorVolumeCapsResponse),
+        (status = 400, description = "Request body is malformed"),
+        (status = 404, description = "Resource missing"),
+        (status = 500, description = "Internal server error"),
+    ),
+   tag = "Routing",
+   operation_id = "Update the connector volume caps",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_volume_caps_upsert() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Retrieve Volume Caps
+///
+/// Retrieve the daily volume caps of the connectors of a profile along with their usage for the day
+#[utoipa::path(
+    get,
+    path = "/routing/volume_caps",
+    params(
+        ("profile_id" = String, Query, description = "The unique identifier for the profile"),
+    ),
+    responses(
+        (status = 200, description = "Volume caps retrieved", body = ConnectorVolumeCapsResponse),
+        (status = 404, description = "Resource missing"),
+        (status = 500, description = "Internal server error"),
+    ),
+   tag = "Routing",
+   operation_id = "Retrieve the connector volume caps",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_volume_caps_retrieve() {}
+
+#[cfg(feature = "v1")]
+/// Routing - Simulate
+///
+/// Simulate the routing of a hypothetical payment through the active or the given routing
+/// algorithm of a profile, returning the connectors the payment would be tried with in order, and
+/// the connectors which were eliminated along with the reason
+#[utoipa::path(
+    post,
+    path = "/routing/simulate",
+    request_body = RoutingSimulationRequest,
+    responses(
+        (status = 200, description = "Routing simulated", body = RoutingSimulationResponse),
+        (status = 400, description = "Request body is malformed"),
+        (status = 404, description = "Resource missing"),
+        (status = 500, description = "Internal server error"),
+    ),
+   tag = "Routing",
+   operation_id = "Simulate the routing of a payment",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_simulate() {}
+
 #[cfg(feature = "v2")]
 /// Routing - Retrieve
 ///
@@ -0,0 +1,52 @@
+/// Sanctions Screening - Retrieve Case
+///
+/// Retrieves a sanctions screening case, opened when a screened name of a payment or a payout
+/// matched a watchlist
+#[utoipa::path(
+    get,
+    path = "/screening/cases/{case_id}",
+    params(
+        ("case_id" = String, Path, description = "The identifier for the sanctions screening case")
+    ),
+    responses(
+        (status = 200, description = "Sanctions screening case retrieved", body = ScreeningCaseResponse),
+        (status = 404, description = "Sanctions screening case not found")
+    ),
+    tag = "Sanctions Screening",
+    operation_id = "Retrieve a Sanctions Screening Case",
+    security(("api_key" = []))
+)]
+pub async fn screening_case_retrieve() {}
+
+/// Sanctions Screening - Resolve Case
+///
+/// Resolves a sanctions screening case which is pending review. A cleared payout is moved back to
+/// `requires_confirmation` and a cleared payment can be confirmed, while a blocked payout is
+/// failed and a blocked payment cannot be confirmed.
+#[utoipa::path(
+    post,
+    path = "/screening/cases/{case_id}/resolve",
+    params(
+        ("case_id" = String, Path, description = "The identifier for the sanctions screening case")
+    ),
+    request_body(
+        content = ScreeningCaseResolveRequest,
+        examples((
+            "Clear a false positive" = (
+                value = json!({
+                    "decision": "clear",
+                    "notes": "Different date of birth"
+                })
+            )
+        ))
+    ),
+    responses(
+        (status = 200, description = "Sanctions screening case resolved", body = ScreeningCaseResponse),
+        (status = 404, description = "Sanctions screening case not found"),
+        (status = 412, description = "Sanctions screening case has already been resolved")
+    ),
+    tag = "Sanctions Screening",
+    operation_id = "Resolve a Sanctions Screening Case",
+    security(("api_key" = []))
+)]
+pub async fn screening_case_resolve() {}
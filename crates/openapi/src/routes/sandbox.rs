@@ -0,0 +1,102 @@
+/// Sandbox - Generate Data
+///
+/// Generates test payments along with their refunds and disputes for the merchant. Available only
+/// outside production.
+#[utoipa::path(
+    post,
+    path = "/sandbox/data",
+    request_body(
+        content = SandboxDataSeedRequest,
+        examples((
+            "Generate 50 payments in USD" = (
+                value = json!({
+                    "record": 50,
+                    "currency": ["USD"]
+                })
+            )
+        ))
+    ),
+    responses(
+        (status = 200, description = "Test data generated", body = SandboxDataSeedResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Sandbox",
+    operation_id = "Generate Sandbox Data",
+    security(("api_key" = []))
+)]
+pub async fn seed_sandbox_data() {}
+
+/// Sandbox - Delete Data
+///
+/// Deletes the test payments, refunds and disputes generated for the merchant. Available only
+/// outside production.
+#[utoipa::path(
+    delete,
+    path = "/sandbox/data",
+    responses(
+        (status = 200, description = "Test data deleted"),
+        (status = 404, description = "Test data does not exist for the merchant")
+    ),
+    tag = "Sandbox",
+    operation_id = "Delete Sandbox Data",
+    security(("api_key" = []))
+)]
+pub async fn delete_sandbox_data() {}
+
+/// Sandbox - Retrieve Test Clock
+///
+/// Retrieves the test clock of the merchant. Available only outside production.
+#[utoipa::path(
+    get,
+    path = "/sandbox/test_clock",
+    responses(
+        (status = 200, description = "Test clock retrieved", body = TestClockResponse)
+    ),
+    tag = "Sandbox",
+    operation_id = "Retrieve Test Clock",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_test_clock() {}
+
+/// Sandbox - Advance Test Clock
+///
+/// Moves the test clock of the merchant forward. The payment links and mandates of the merchant
+/// expire as per the test clock, and the scheduled tasks of the merchant which would have become
+/// due are brought forward. Available only outside production.
+#[utoipa::path(
+    post,
+    path = "/sandbox/test_clock/advance",
+    request_body(
+        content = TestClockAdvanceRequest,
+        examples((
+            "Advance the test clock by a day" = (
+                value = json!({
+                    "advance_by": 86400
+                })
+            )
+        ))
+    ),
+    responses(
+        (status = 200, description = "Test clock advanced", body = TestClockResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Sandbox",
+    operation_id = "Advance Test Clock",
+    security(("api_key" = []))
+)]
+pub async fn advance_test_clock() {}
+
+/// Sandbox - Reset Test Clock
+///
+/// Resets the test clock of the merchant to the actual time. Available only outside production.
+#[utoipa::path(
+    delete,
+    path = "/sandbox/test_clock",
+    responses(
+        (status = 200, description = "Test clock reset", body = TestClockResponse)
+    ),
+    tag = "Sandbox",
+    operation_id = "Reset Test Clock",
+    security(("api_key" = []))
+)]
+pub async fn reset_test_clock() {}
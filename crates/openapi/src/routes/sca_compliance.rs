@@ -0,0 +1,70 @@
+/// SCA Compliance - Request a Report
+///
+/// Requests the SCA compliance report of a month that has ended. The card payments created in the
+/// month with the cards issued in the European Economic Area and the United Kingdom are classified
+/// by their strong customer authentication treatment by the scheduler, after which the report can
+/// be downloaded.
+#[utoipa::path(
+    post,
+    path = "/compliance/sca_reports",
+    request_body(
+        content = ScaComplianceReportRequest,
+        examples((
+            "Report of March 2025" = (
+                value = json!({
+                    "year": 2025,
+                    "month": 3
+                })
+            )
+        ))
+    ),
+    responses(
+        (status = 200, description = "SCA compliance report requested", body = ScaComplianceReportResponse),
+        (status = 400, description = "Invalid month, or the month has not ended")
+    ),
+    tag = "SCA Compliance",
+    operation_id = "Request an SCA Compliance Report",
+    security(("api_key" = []))
+)]
+pub async fn create_sca_compliance_report() {}
+
+/// SCA Compliance - Retrieve a Report
+///
+/// Retrieves the status of an SCA compliance report, along with the number of the payments with
+/// each SCA treatment once it is completed
+#[utoipa::path(
+    get,
+    path = "/compliance/sca_reports/{report_id}",
+    params(
+        ("report_id" = String, Path, description = "The identifier for the SCA compliance report")
+    ),
+    responses(
+        (status = 200, description = "SCA compliance report retrieved", body = ScaComplianceReportResponse),
+        (status = 404, description = "SCA compliance report does not exist in our records")
+    ),
+    tag = "SCA Compliance",
+    operation_id = "Retrieve an SCA Compliance Report",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_sca_compliance_report() {}
+
+/// SCA Compliance - Download a Report
+///
+/// Downloads the classified payments of a completed SCA compliance report as a CSV file, with the
+/// SCA treatment, the requested exemption and the outcome of the authentication of each payment
+#[utoipa::path(
+    get,
+    path = "/compliance/sca_reports/{report_id}/download",
+    params(
+        ("report_id" = String, Path, description = "The identifier for the SCA compliance report")
+    ),
+    responses(
+        (status = 200, description = "SCA compliance report file", content_type = "text/csv", body = String),
+        (status = 404, description = "SCA compliance report does not exist in our records"),
+        (status = 412, description = "SCA compliance report is not completed")
+    ),
+    tag = "SCA Compliance",
+    operation_id = "Download an SCA Compliance Report",
+    security(("api_key" = []))
+)]
+pub async fn download_sca_compliance_report() {}
@@ -0,0 +1,38 @@
+/// SDK Events - Ingest Batch
+///
+/// Ingests a batch of the events generated by the SDK. The events are validated individually and
+/// counted against the quota of the merchant, and the events which are rejected are returned
+/// along with the reason. The request body may be compressed with gzip.
+#[utoipa::path(
+    post,
+    path = "/sdk_events/batch",
+    request_body(
+        content = SdkEventsBatchRequest,
+        examples((
+            "Ingest a batch of SDK events" = (
+                value = json!({
+                    "events": [
+                        {
+                            "payment_id": "pay_mbabizu24mvu3mela5njyhpit4",
+                            "log_type": "INFO",
+                            "event_name": "PAYMENT_ATTEMPT",
+                            "timestamp": 1743075000000,
+                            "source": "WEB",
+                            "category": "USER_EVENT",
+                            "payment_method": "card",
+                            "locale": "en-US"
+                        }
+                    ]
+                })
+            )
+        ))
+    ),
+    responses(
+        (status = 200, description = "SDK events ingested", body = SdkEventsBatchResponse),
+        (status = 400, description = "Invalid batch of SDK events")
+    ),
+    tag = "SDK Events",
+    operation_id = "Ingest SDK Events Batch",
+    security(("publishable_key" = []))
+)]
+pub async fn ingest_sdk_events() {}
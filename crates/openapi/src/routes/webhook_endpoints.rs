@@ -0,0 +1,45 @@
+/// Webhook Endpoints - Health
+///
+/// Retrieves the health of the delivery of the outgoing webhooks to the endpoint of a profile,
+/// which is the webhook URL configured in the profile. The deliveries are aggregated per hour over
+/// the requested window, with their success rate and the latency of the endpoint. The delivery to
+/// an endpoint which has been failing persistently is paused, and the merchant is notified.
+#[utoipa::path(
+    get,
+    path = "/webhooks/endpoints/{endpoint_id}/health",
+    params(
+        ("endpoint_id" = String, Path, description = "The identifier of the endpoint, which is the ID of its profile"),
+        ("window_in_hours" = Option<u32>, Query, description = "The number of hours over which the deliveries are aggregated, 24 by default and up to 168")
+    ),
+    responses(
+        (status = 200, description = "Webhook endpoint health retrieved", body = WebhookEndpointHealthResponse),
+        (status = 400, description = "Invalid window"),
+        (status = 404, description = "Profile not found")
+    ),
+    tag = "Webhook Endpoints",
+    operation_id = "Retrieve the Health of a Webhook Endpoint",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_webhook_endpoint_health() {}
+
+/// Webhook Endpoints - Resume
+///
+/// Resumes the delivery of the outgoing webhooks to an endpoint which was paused for failing
+/// persistently, once the endpoint has been fixed. The webhooks which were not delivered while the
+/// delivery was paused are not sent again, and can be retried manually through the events API.
+#[utoipa::path(
+    post,
+    path = "/webhooks/endpoints/{endpoint_id}/resume",
+    params(
+        ("endpoint_id" = String, Path, description = "The identifier of the endpoint, which is the ID of its profile")
+    ),
+    responses(
+        (status = 200, description = "Webhook endpoint resumed", body = WebhookEndpointHealthResponse),
+        (status = 400, description = "The delivery to the webhook endpoint is not paused"),
+        (status = 404, description = "Profile not found")
+    ),
+    tag = "Webhook Endpoints",
+    operation_id = "Resume a Webhook Endpoint",
+    security(("api_key" = []))
+)]
+pub async fn resume_webhook_endpoint() {}
@@ -144,3 +144,73 @@ pub fn list_webhook_delivery_attempts() {}
     security(("admin_api_key" = []))
 )]
 pub fn retry_webhook_delivery_attempt() {}
+
+/// Events - List Types
+///
+/// List the catalog of the event types of the outgoing webhooks, along with the JSON schemas of
+/// their payloads, to generate the consumers of the webhooks and validate their payloads.
+#[utoipa::path(
+    get,
+    path = "/events/types",
+    responses(
+        (status = 200, description = "Catalog of the event types retrieved successfully", body = EventTypeCatalogResponse),
+    ),
+    tag = "Event",
+    operation_id = "List the event types of the outgoing webhooks",
+    security(("api_key" = []))
+)]
+pub fn list_event_types() {}
+
+/// Events - List Lifecycle Events
+///
+/// List every lifecycle event (payment, refund, dispute, mandate and payout events) of the
+/// Merchant Account, newest first, regardless of whether webhooks were delivered for them, to
+/// reconcile against an authoritative event stream.
+#[utoipa::path(
+    get,
+    path = "/events",
+    params(
+        (
+            "created_after" = Option<PrimitiveDateTime>,
+            Query,
+            description = "Only include Events created after the specified time."
+        ),
+        (
+            "created_before" = Option<PrimitiveDateTime>,
+            Query,
+            description = "Only include Events created before the specified time."
+        ),
+        (
+            "event_class" = Option<EventClass>,
+            Query,
+            description = "Only include Events of the specified class (Payment, Refund, etc.)."
+        ),
+        (
+            "event_type" = Option<EventType>,
+            Query,
+            description = "Only include Events of the specified type."
+        ),
+        (
+            "profile_id" = Option<String>,
+            Query,
+            description = "Only include Events associated with the Profile identified by the specified Profile ID."
+        ),
+        (
+            "starting_after" = Option<String>,
+            Query,
+            description = "The `next_cursor` returned in the previous page, to list the Events after it."
+        ),
+        (
+            "limit" = Option<u16>,
+            Query,
+            description = "The maximum number of Events to include in the response, at most 100. Defaults to 20."
+        ),
+    ),
+    responses(
+        (status = 200, description = "List of lifecycle Events retrieved successfully", body = LifecycleEventListResponse),
+    ),
+    tag = "Event",
+    operation_id = "List all lifecycle Events of a Merchant Account",
+    security(("api_key" = []))
+)]
+pub fn list_lifecycle_events() {}
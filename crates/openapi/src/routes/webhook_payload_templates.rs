@@ -0,0 +1,138 @@
+/// Webhook Payload Templates - Set
+///
+/// Sets the payload template of the outgoing webhooks of an event type. The webhooks of the event
+/// type are sent with the payload rendered from the template instead of the default payload, and
+/// are signed over the rendered payload.
+#[utoipa::path(
+    post,
+    path = "/webhook_payload_templates/{event_type}",
+    params(
+        ("event_type" = EventType, Path, description = "The event type of the webhooks rendered with the template")
+    ),
+    request_body(
+        content = WebhookPayloadTemplateRequest,
+        examples((
+            "Rename and select the fields of a payment webhook" = (
+                value = json!({
+                    "template": {
+                        "id": "{{ event_id }}",
+                        "type": "{{ event_type }}",
+                        "payment": {
+                            "reference": "{{ content.object.payment_id }}",
+                            "amount": "{{ content.object.amount }}",
+                            "order_id": "{{ content.object.metadata.order_id }}"
+                        }
+                    }
+                })
+            )
+        ))
+    ),
+    responses(
+        (status = 200, description = "Webhook payload template set", body = WebhookPayloadTemplateResponse),
+        (status = 400, description = "Invalid webhook payload template")
+    ),
+    tag = "Webhook Payload Templates",
+    operation_id = "Set a Webhook Payload Template",
+    security(("api_key" = []))
+)]
+pub async fn set_webhook_payload_template() {}
+
+/// Webhook Payload Templates - Retrieve
+///
+/// Retrieves the payload template of the outgoing webhooks of an event type
+#[utoipa::path(
+    get,
+    path = "/webhook_payload_templates/{event_type}",
+    params(
+        ("event_type" = EventType, Path, description = "The event type of the webhooks rendered with the template")
+    ),
+    responses(
+        (status = 200, description = "Webhook payload template retrieved", body = WebhookPayloadTemplateResponse),
+        (status = 404, description = "Webhook payload template not found")
+    ),
+    tag = "Webhook Payload Templates",
+    operation_id = "Retrieve a Webhook Payload Template",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_webhook_payload_template() {}
+
+/// Webhook Payload Templates - List
+///
+/// Lists the payload templates of the outgoing webhooks of the merchant
+#[utoipa::path(
+    get,
+    path = "/webhook_payload_templates",
+    responses(
+        (status = 200, description = "Webhook payload templates listed", body = WebhookPayloadTemplateListResponse)
+    ),
+    tag = "Webhook Payload Templates",
+    operation_id = "List Webhook Payload Templates",
+    security(("api_key" = []))
+)]
+pub async fn list_webhook_payload_templates() {}
+
+/// Webhook Payload Templates - Delete
+///
+/// Deletes the payload template of the outgoing webhooks of an event type, the webhooks of the
+/// event type being sent with the default payload again
+#[utoipa::path(
+    delete,
+    path = "/webhook_payload_templates/{event_type}",
+    params(
+        ("event_type" = EventType, Path, description = "The event type of the webhooks rendered with the template")
+    ),
+    responses(
+        (status = 200, description = "Webhook payload template deleted", body = WebhookPayloadTemplateResponse),
+        (status = 404, description = "Webhook payload template not found")
+    ),
+    tag = "Webhook Payload Templates",
+    operation_id = "Delete a Webhook Payload Template",
+    security(("api_key" = []))
+)]
+pub async fn delete_webhook_payload_template() {}
+
+/// Webhook Payload Templates - Validate
+///
+/// Validates a payload template without storing it, listing its errors and the fields of the
+/// default payload which it references
+#[utoipa::path(
+    post,
+    path = "/webhook_payload_templates/validate",
+    request_body = WebhookPayloadTemplateValidateRequest,
+    responses(
+        (status = 200, description = "Webhook payload template validated", body = WebhookPayloadTemplateValidateResponse)
+    ),
+    tag = "Webhook Payload Templates",
+    operation_id = "Validate a Webhook Payload Template",
+    security(("api_key" = []))
+)]
+pub async fn validate_webhook_payload_template() {}
+
+/// Webhook Payload Templates - Preview
+///
+/// Renders a payload template without sending any webhook, from a given default payload or from
+/// the payload delivered for a past event
+#[utoipa::path(
+    post,
+    path = "/webhook_payload_templates/preview",
+    request_body(
+        content = WebhookPayloadTemplatePreviewRequest,
+        examples((
+            "Preview the stored template with a past event" = (
+                value = json!({
+                    "event_type": "payment_succeeded",
+                    "event_id": "evt_018e31720d1b7a2b82677d3032cab959"
+                })
+            )
+        ))
+    ),
+    responses(
+        (status = 200, description = "Webhook payload template rendered", body = WebhookPayloadTemplatePreviewResponse),
+        (status = 400, description = "Invalid webhook payload template"),
+        (status = 404, description = "Webhook payload template or event not found")
+    ),
+    tag = "Webhook Payload Templates",
+    operation_id = "Preview a Webhook Payload Template",
+    security(("api_key" = []))
+)]
+pub async fn preview_webhook_payload_template() {}
@@ -1,5 +1,7 @@
 pub use analytics::*;
 
+pub mod payment_summary;
+
 pub mod routes {
     use std::{
         collections::{HashMap, HashSet},
@@ -22,8 +24,9 @@ pub mod routes {
         GetApiEventFiltersRequest, GetApiEventMetricRequest, GetAuthEventFilterRequest,
         GetAuthEventMetricRequest, GetDisputeMetricRequest, GetFrmFilterRequest,
         GetFrmMetricRequest, GetPaymentFiltersRequest, GetPaymentIntentFiltersRequest,
-        GetPaymentIntentMetricRequest, GetPaymentMetricRequest, GetRefundFilterRequest,
-        GetRefundMetricRequest, GetSdkEventFiltersRequest, GetSdkEventMetricRequest, ReportRequest,
+        GetPaymentIntentMetricRequest, GetPaymentMetricRequest, GetPayoutMetricRequest,
+        GetRefundFilterRequest, GetRefundMetricRequest, GetSdkEventFiltersRequest,
+        GetSdkEventMetricRequest, ReportRequest,
     };
     use common_enums::EntityType;
     use common_utils::types::TimeRange;
@@ -161,6 +164,10 @@ pub mod routes {
                             web::resource("metrics/sankey")
                                 .route(web::post().to(get_merchant_sankey)),
                         )
+                        .service(
+                            web::resource("summary")
+                                .route(web::get().to(get_merchant_payment_summary)),
+                        )
                         .service(
                             web::scope("/merchant")
                                 .service(
@@ -215,6 +222,22 @@ pub mod routes {
                                     web::resource("filters/disputes")
                                         .route(web::post().to(get_merchant_dispute_filters)),
                                 )
+                                .service(
+                                    web::resource("metrics/payouts")
+                                        .route(web::post().to(get_merchant_payout_metrics)),
+                                )
+                                .service(
+                                    web::resource("filters/payouts")
+                                        .route(web::post().to(get_merchant_payout_filters)),
+                                )
+                                .service(
+                                    web::resource("metrics/connector_fees")
+                                        .route(web::post().to(get_merchant_connector_fee_metrics)),
+                                )
+                                .service(
+                                    web::resource("filters/connector_fees")
+                                        .route(web::post().to(get_merchant_connector_fee_filters)),
+                                )
                                 .service(
                                     web::resource("metrics/sankey")
                                         .route(web::post().to(get_merchant_sankey)),
@@ -299,6 +322,14 @@ pub mod routes {
                                     web::resource("filters/disputes")
                                         .route(web::post().to(get_profile_dispute_filters)),
                                 )
+                                .service(
+                                    web::resource("metrics/payouts")
+                                        .route(web::post().to(get_profile_payout_metrics)),
+                                )
+                                .service(
+                                    web::resource("filters/payouts")
+                                        .route(web::post().to(get_profile_payout_filters)),
+                                )
                                 .service(
                                     web::resource("connector_event_logs")
                                         .route(web::get().to(get_profile_connector_events)),
@@ -917,6 +948,7 @@ pub mod routes {
             |state, auth: AuthenticationData, req, _| async move {
                 analytics::sdk_events::get_metrics(
                     &state.pool,
+                    auth.merchant_account.get_id(),
                     &auth.merchant_account.publishable_key,
                     req,
                 )
@@ -2622,6 +2654,228 @@ pub mod routes {
         .await
     }
 
+    pub async fn get_merchant_payout_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<api_models::analytics::GetPayoutFilterRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetPayoutFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let auth: AuthInfo = AuthInfo::MerchantLevel {
+                    org_id: org_id.clone(),
+                    merchant_ids: vec![merchant_id.clone()],
+                };
+                analytics::payouts::get_filters(&state.pool, req, &auth)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    pub async fn get_profile_payout_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<api_models::analytics::GetPayoutFilterRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetPayoutFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+                let auth: AuthInfo = AuthInfo::ProfileLevel {
+                    org_id: org_id.clone(),
+                    merchant_id: merchant_id.clone(),
+                    profile_ids: vec![profile_id.clone()],
+                };
+                analytics::payouts::get_filters(&state.pool, req, &auth)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetPayoutMetricRequest` element.
+    pub async fn get_merchant_payout_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetPayoutMetricRequest; 1]>,
+    ) -> impl Responder {
+        // safety: This shouldn't panic owing to the data type
+        #[allow(clippy::expect_used)]
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetPayoutMetricRequest");
+        let flow = AnalyticsFlow::GetPayoutMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let auth: AuthInfo = AuthInfo::MerchantLevel {
+                    org_id: org_id.clone(),
+                    merchant_ids: vec![merchant_id.clone()],
+                };
+                analytics::payouts::get_metrics(&state.pool, &auth, req)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    #[cfg(feature = "v1")]
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetPayoutMetricRequest` element.
+    pub async fn get_profile_payout_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetPayoutMetricRequest; 1]>,
+    ) -> impl Responder {
+        // safety: This shouldn't panic owing to the data type
+        #[allow(clippy::expect_used)]
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetPayoutMetricRequest");
+        let flow = AnalyticsFlow::GetPayoutMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let profile_id = auth
+                    .profile_id
+                    .ok_or(report!(UserErrors::JwtProfileIdMissing))
+                    .change_context(AnalyticsError::AccessForbiddenError)?;
+                let auth: AuthInfo = AuthInfo::ProfileLevel {
+                    org_id: org_id.clone(),
+                    merchant_id: merchant_id.clone(),
+                    profile_ids: vec![profile_id.clone()],
+                };
+                analytics::payouts::get_metrics(&state.pool, &auth, req)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::ProfileAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn get_merchant_connector_fee_filters(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<api_models::analytics::GetConnectorFeeFilterRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetConnectorFeeFilters;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let auth: AuthInfo = AuthInfo::MerchantLevel {
+                    org_id: org_id.clone(),
+                    merchant_ids: vec![merchant_id.clone()],
+                };
+                analytics::connector_fees::get_filters(&state.pool, req, &auth)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantReconAndSettlementAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetConnectorFeeMetricRequest` element.
+    pub async fn get_merchant_connector_fee_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<[GetConnectorFeeMetricRequest; 1]>,
+    ) -> impl Responder {
+        // safety: This shouldn't panic owing to the data type
+        #[allow(clippy::expect_used)]
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetConnectorFeeMetricRequest");
+        let flow = AnalyticsFlow::GetConnectorFeeMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req, _| async move {
+                let org_id = auth.merchant_account.get_org_id();
+                let merchant_id = auth.merchant_account.get_id();
+                let auth: AuthInfo = AuthInfo::MerchantLevel {
+                    org_id: org_id.clone(),
+                    merchant_ids: vec![merchant_id.clone()],
+                };
+                analytics::connector_fees::get_metrics(&state.pool, &auth, req)
+                    .await
+                    .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantReconAndSettlementAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
     pub async fn get_merchant_sankey(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
@@ -2653,6 +2907,33 @@ pub mod routes {
         .await
     }
 
+    pub async fn get_merchant_payment_summary(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetPaymentSummary;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            (),
+            |state, auth: AuthenticationData, _, _| async move {
+                super::payment_summary::get_payment_summary(
+                    &state,
+                    auth.merchant_account.get_org_id(),
+                    auth.merchant_account.get_id(),
+                )
+                .await
+                .map(ApplicationResponse::Json)
+            },
+            &auth::JWTAuth {
+                permission: Permission::MerchantAnalyticsRead,
+            },
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
     pub async fn get_org_sankey(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
@@ -0,0 +1,159 @@
+//! Caching of the payment summaries shown on the home page of the dashboard. The summary of a
+//! merchant is computed on the first request and cached in redis. Later requests are served from
+//! the cache, and a stale summary is refreshed in the background while it is still being served,
+//! so that the analytics queries are not issued on every load of the dashboard.
+
+use analytics::{enums::AuthInfo, errors::AnalyticsError};
+use api_models::analytics::PaymentSummaryResponse;
+use common_utils::{date_time, errors::CustomResult, id_type};
+use router_env::{instrument, logger, tracing};
+use tracing_futures::Instrument;
+
+use crate::{consts, core::currency::get_forex_exchange_rates, routes::SessionState};
+
+fn get_payment_summary_key(merchant_id: &id_type::MerchantId) -> String {
+    format!(
+        "{}_analytics_payment_summary",
+        merchant_id.get_string_repr()
+    )
+}
+
+fn get_payment_summary_refresh_lock_key(merchant_id: &id_type::MerchantId) -> String {
+    format!(
+        "{}_analytics_payment_summary_refresh_lock",
+        merchant_id.get_string_repr()
+    )
+}
+
+#[instrument(skip_all)]
+pub async fn get_payment_summary(
+    state: &SessionState,
+    org_id: &id_type::OrganizationId,
+    merchant_id: &id_type::MerchantId,
+) -> CustomResult<PaymentSummaryResponse, AnalyticsError> {
+    match get_cached_payment_summary(state, merchant_id).await {
+        Some(summary) => {
+            let age = date_time::now() - summary.computed_at;
+            if age.whole_seconds() > consts::ANALYTICS_PAYMENT_SUMMARY_REFRESH_INTERVAL_SECONDS {
+                let state = state.clone();
+                let org_id = org_id.clone();
+                let merchant_id = merchant_id.clone();
+                tokio::spawn(
+                    async move {
+                        refresh_payment_summary(&state, &org_id, &merchant_id).await;
+                    }
+                    .in_current_span(),
+                );
+            }
+            Ok(summary)
+        }
+        None => compute_and_cache_payment_summary(state, org_id, merchant_id).await,
+    }
+}
+
+async fn get_cached_payment_summary(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> Option<PaymentSummaryResponse> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .inspect_err(|error| logger::error!(?error, "Failed to get redis connection"))
+        .ok()?;
+
+    redis_conn
+        .get_and_deserialize_key(
+            &get_payment_summary_key(merchant_id).as_str().into(),
+            "PaymentSummaryResponse",
+        )
+        .await
+        .inspect_err(|error| {
+            if !matches!(
+                error.current_context(),
+                redis_interface::errors::RedisError::NotFound
+            ) {
+                logger::error!(?error, "Failed to fetch the cached payment summary");
+            }
+        })
+        .ok()
+}
+
+/// Refreshes the cached payment summary, unless it is already being refreshed by another request
+async fn refresh_payment_summary(
+    state: &SessionState,
+    org_id: &id_type::OrganizationId,
+    merchant_id: &id_type::MerchantId,
+) {
+    let Ok(redis_conn) = state.store.get_redis_conn() else {
+        return;
+    };
+
+    let lock_key = get_payment_summary_refresh_lock_key(merchant_id);
+    match redis_conn
+        .set_key_if_not_exists_with_expiry(
+            &lock_key.as_str().into(),
+            "1",
+            Some(consts::ANALYTICS_PAYMENT_SUMMARY_REFRESH_LOCK_TTL_SECONDS),
+        )
+        .await
+    {
+        Ok(redis_interface::SetnxReply::KeySet) => {}
+        Ok(redis_interface::SetnxReply::KeyNotSet) => {
+            logger::debug!("The payment summary is already being refreshed");
+            return;
+        }
+        Err(error) => {
+            logger::error!(?error, "Failed to acquire the payment summary refresh lock");
+            return;
+        }
+    }
+
+    if let Err(error) = compute_and_cache_payment_summary(state, org_id, merchant_id).await {
+        logger::error!(?error, "Failed to refresh the payment summary");
+    }
+
+    if let Err(error) = redis_conn.delete_key(&lock_key.as_str().into()).await {
+        logger::error!(?error, "Failed to release the payment summary refresh lock");
+    }
+}
+
+async fn compute_and_cache_payment_summary(
+    state: &SessionState,
+    org_id: &id_type::OrganizationId,
+    merchant_id: &id_type::MerchantId,
+) -> CustomResult<PaymentSummaryResponse, AnalyticsError> {
+    let auth = AuthInfo::MerchantLevel {
+        org_id: org_id.clone(),
+        merchant_ids: vec![merchant_id.clone()],
+    };
+    // The summary includes the processed amount in USD, for which the exchange rates are needed
+    let ex_rates = if state.conf.analytics.get_inner().get_forex_enabled() {
+        Some(get_forex_exchange_rates(state.clone()).await?)
+    } else {
+        None
+    };
+
+    let summary =
+        analytics::payments::get_payment_summary(&state.pool, &ex_rates, &auth, date_time::now())
+            .await?;
+
+    // The summary is still returned if it cannot be cached, and is computed again on the next
+    // request
+    match state.store.get_redis_conn() {
+        Ok(redis_conn) => {
+            if let Err(error) = redis_conn
+                .serialize_and_set_key_with_expiry(
+                    &get_payment_summary_key(merchant_id).as_str().into(),
+                    &summary,
+                    consts::ANALYTICS_PAYMENT_SUMMARY_CACHE_TTL_SECONDS,
+                )
+                .await
+            {
+                logger::error!(?error, "Failed to cache the payment summary");
+            }
+        }
+        Err(error) => logger::error!(?error, "Failed to get redis connection"),
+    }
+
+    Ok(summary)
+}
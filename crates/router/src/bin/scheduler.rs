@@ -34,9 +34,10 @@ async fn main() -> CustomResult<(), ProcessTrackerError> {
     #[allow(clippy::expect_used)]
     let conf = Settings::with_config_path(cmd_line.config_path)
         .expect("Unable to construct application configuration");
-    let api_client = Box::new(
+    let api_client = services::get_api_client(
         services::ProxyClient::new(&conf.proxy)
             .change_context(ProcessTrackerError::ConfigurationError)?,
+        &conf.connector_cassettes,
     );
     // channel for listening to redis disconnect events
     let (redis_shutdown_signal_tx, redis_shutdown_signal_rx) = oneshot::channel();
@@ -324,6 +325,53 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::PassiveRecoveryWorkflow => Ok(Box::new(
                     workflows::passive_churn_recovery_workflow::ExecutePcrWorkflow,
                 )),
+                storage::ProcessTrackerRunner::DeferredVaultingWorkflow => Ok(Box::new(
+                    workflows::deferred_vaulting::DeferredVaultingWorkflow,
+                )),
+                storage::ProcessTrackerRunner::AuthenticationAbandonmentWorkflow => Ok(Box::new(
+                    workflows::authentication_abandonment::AuthenticationAbandonmentWorkflow,
+                )),
+                storage::ProcessTrackerRunner::RefundBatchWorkflow => {
+                    Ok(Box::new(workflows::refund_batch::RefundBatchWorkflow))
+                }
+                storage::ProcessTrackerRunner::DunningWorkflow => {
+                    Ok(Box::new(workflows::dunning::DunningWorkflow))
+                }
+                storage::ProcessTrackerRunner::RoutingExperimentWorkflow => Ok(Box::new(
+                    workflows::routing_experiment::RoutingExperimentWorkflow,
+                )),
+                storage::ProcessTrackerRunner::MerchantOffboardingWorkflow => Ok(Box::new(
+                    workflows::merchant_offboarding::MerchantOffboardingWorkflow,
+                )),
+                storage::ProcessTrackerRunner::WarehouseSyncWorkflow => {
+                    Ok(Box::new(workflows::warehouse_sync::WarehouseSyncWorkflow))
+                }
+                storage::ProcessTrackerRunner::ConnectorOAuthTokenRefreshWorkflow => Ok(Box::new(
+                    workflows::connector_oauth_token_refresh::ConnectorOAuthTokenRefreshWorkflow,
+                )),
+                storage::ProcessTrackerRunner::ConnectorCredentialsExpiryWorkflow => Ok(Box::new(
+                    workflows::connector_credentials_expiry::ConnectorCredentialsExpiryWorkflow,
+                )),
+                storage::ProcessTrackerRunner::ScheduledCaptureWorkflow => Ok(Box::new(
+                    workflows::scheduled_capture::ScheduledCaptureWorkflow,
+                )),
+                storage::ProcessTrackerRunner::AttemptRedactionWorkflow => Ok(Box::new(
+                    workflows::attempt_redaction::AttemptRedactionWorkflow,
+                )),
+                storage::ProcessTrackerRunner::RefundReconciliationWorkflow => Ok(Box::new(
+                    workflows::refund_reconciliation::RefundReconciliationWorkflow,
+                )),
+                storage::ProcessTrackerRunner::ScaComplianceReportWorkflow => Ok(Box::new(
+                    workflows::sca_compliance_report::ScaComplianceReportWorkflow,
+                )),
+                storage::ProcessTrackerRunner::ConnectorTransactionIdBackfillWorkflow => {
+                    Ok(Box::new(
+                        workflows::connector_transaction_id_backfill::ConnectorTransactionIdBackfillWorkflow,
+                    ))
+                }
+                storage::ProcessTrackerRunner::DataResidencyMigrationWorkflow => Ok(Box::new(
+                    workflows::data_residency_migration::DataResidencyMigrationWorkflow,
+                )),
             }
         };
 
@@ -372,6 +420,12 @@ async fn start_scheduler(
         .scheduler
         .clone()
         .ok_or(ProcessTrackerError::ConfigurationError)?;
+
+    #[cfg(feature = "v1")]
+    if matches!(scheduler_flow, scheduler::SchedulerFlow::Producer) {
+        schedule_recurring_tasks(state).await;
+    }
+
     scheduler::start_process_tracker(
         state,
         scheduler_flow,
@@ -385,3 +439,40 @@ async fn start_scheduler(
     )
     .await
 }
+
+/// Schedules the recurring tasks of every tenant, such as the redaction of the payment attempts
+/// and the reconciliation of the pending refunds, as they are not triggered by any request
+#[cfg(feature = "v1")]
+async fn schedule_recurring_tasks(state: &routes::AppState) {
+    let app_state = Arc::new(state.clone());
+    for tenant in state.stores.keys() {
+        let session_state = match app_state.clone().get_session_state(tenant, None, || {
+            error_stack::report!(ProcessTrackerError::TenantNotFound)
+        }) {
+            Ok(session_state) => session_state,
+            Err(error) => {
+                logger::error!(?error, "Failed to get the session state of the tenant");
+                continue;
+            }
+        };
+
+        if let Err(error) =
+            router::core::data_retention::schedule_attempt_redaction(&session_state).await
+        {
+            logger::error!(
+                ?error,
+                "Failed to schedule the redaction of the payment attempts"
+            );
+        }
+
+        if let Err(error) =
+            router::core::refunds::reconciliation::schedule_refund_reconciliation(&session_state)
+                .await
+        {
+            logger::error!(
+                ?error,
+                "Failed to schedule the reconciliation of the pending refunds"
+            );
+        }
+    }
+}
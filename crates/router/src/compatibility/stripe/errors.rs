@@ -278,10 +278,16 @@ pub enum StripeErrorCode {
     InvalidTenant,
     #[error(error_type = StripeErrorType::HyperswitchError, code = "HE_01", message = "Failed to convert amount to {amount_type} type")]
     AmountConversionFailed { amount_type: &'static str },
+    #[error(error_type = StripeErrorType::HyperswitchError, code = "", message = "All the eligible connectors are under scheduled maintenance, retry the payment after {retry_after}")]
+    ConnectorsUnderMaintenance { retry_after: String },
+    #[error(error_type = StripeErrorType::HyperswitchError, code = "", message = "Calls to the connector {connector} are throttled as per its rate limit, retry the request")]
+    ConnectorThrottled { connector: String },
     #[error(error_type = StripeErrorType::HyperswitchError, code = "", message = "Platform Bad Request")]
     PlatformBadRequest,
     #[error(error_type = StripeErrorType::HyperswitchError, code = "", message = "Platform Unauthorized Request")]
     PlatformUnauthorizedRequest,
+    #[error(error_type = StripeErrorType::InvalidRequestError, code = "rate_limit", message = "Too many requests, retry after {retry_after} seconds")]
+    TooManyRequests { retry_after: i64 },
     // [#216]: https://github.com/juspay/hyperswitch/issues/216
     // Implement the remaining stripe error codes
 
@@ -682,8 +688,39 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             errors::ApiErrorResponse::AmountConversionFailed { amount_type } => {
                 Self::AmountConversionFailed { amount_type }
             }
+            errors::ApiErrorResponse::ConnectorsUnderMaintenance { retry_after } => {
+                Self::ConnectorsUnderMaintenance { retry_after }
+            }
+            errors::ApiErrorResponse::ConnectorThrottled { connector } => {
+                Self::ConnectorThrottled { connector }
+            }
             errors::ApiErrorResponse::PlatformAccountAuthNotSupported => Self::PlatformBadRequest,
             errors::ApiErrorResponse::InvalidPlatformOperation => Self::PlatformUnauthorizedRequest,
+            errors::ApiErrorResponse::RefundWindowExpired {
+                imposed_by,
+                latest_refund_date,
+            } => Self::PreconditionFailed {
+                message: format!(
+                    "Refunds of this payment are accepted only until {latest_refund_date} as per the rules of {imposed_by}"
+                ),
+            },
+            errors::ApiErrorResponse::MerchantAccountStatusNotAllowed { status } => {
+                Self::PreconditionFailed {
+                    message: format!(
+                        "This operation is not allowed for a merchant account in the {status} status"
+                    ),
+                }
+            }
+            errors::ApiErrorResponse::SanctionsScreeningNotCleared { case_id } => {
+                Self::PreconditionFailed {
+                    message: format!(
+                        "This operation is not allowed until the sanctions screening case {case_id} is cleared"
+                    ),
+                }
+            }
+            errors::ApiErrorResponse::TooManyRequests { retry_after } => {
+                Self::TooManyRequests { retry_after }
+            }
         }
     }
 }
@@ -768,7 +805,9 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::WebhookProcessingError
             | Self::InvalidTenant
             | Self::AmountConversionFailed { .. } => StatusCode::INTERNAL_SERVER_ERROR,
-            Self::ReturnUrlUnavailable => StatusCode::SERVICE_UNAVAILABLE,
+            Self::ReturnUrlUnavailable | Self::ConnectorsUnderMaintenance { .. } => {
+                StatusCode::SERVICE_UNAVAILABLE
+            }
             Self::ExternalConnectorError { status_code, .. } => {
                 StatusCode::from_u16(*status_code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
             }
@@ -777,6 +816,9 @@ impl actix_web::ResponseError for StripeErrorCode {
                 StatusCode::from_u16(*code).unwrap_or(StatusCode::OK)
             }
             Self::LockTimeout => StatusCode::LOCKED,
+            Self::TooManyRequests { .. } | Self::ConnectorThrottled { .. } => {
+                StatusCode::TOO_MANY_REQUESTS
+            }
         }
     }
 
@@ -117,8 +117,9 @@ pub async fn payment_intents_retrieve(
         param: None,
         merchant_connector_details: None,
         client_secret: query_payload.client_secret.clone(),
-        expand_attempts: None,
+        expand_attempts: Some(true),
         expand_captures: None,
+        consistency_token: None,
     };
 
     let (auth_type, auth_flow) =
@@ -193,6 +194,7 @@ pub async fn payment_intents_retrieve_with_gateway_creds(
         merchant_id: json_payload.merchant_id.clone(),
         force_sync: json_payload.force_sync.unwrap_or(false),
         merchant_connector_details: json_payload.merchant_connector_details.clone(),
+        expand_attempts: Some(true),
         ..Default::default()
     };
     let (auth_type, _auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
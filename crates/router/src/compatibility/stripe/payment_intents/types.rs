@@ -512,6 +512,9 @@ pub struct LastPaymentError {
 
 impl From<payments::PaymentsResponse> for StripePaymentIntentResponse {
     fn from(resp: payments::PaymentsResponse) -> Self {
+        let charges = Charges::from_attempts(&resp.payment_id, resp.attempts);
+        let latest_charge_id = charges.data.first().map(|charge| charge.id.clone());
+
         Self {
             object: "payment_intent",
             id: resp.payment_id,
@@ -550,9 +553,9 @@ impl From<payments::PaymentsResponse> for StripePaymentIntentResponse {
             next_action: into_stripe_next_action(resp.next_action, resp.return_url),
             cancellation_reason: resp.cancellation_reason,
             metadata: resp.metadata,
-            charges: Charges::new(),
+            charges,
             last_payment_error: resp.error_code.map(|code| LastPaymentError {
-                charge: None,
+                charge: latest_charge_id,
                 code: Some(code.to_owned()),
                 decline_code: None,
                 message: resp
@@ -590,7 +593,7 @@ pub struct StripePaymentMethod {
 #[derive(Default, Eq, PartialEq, Serialize, Debug)]
 pub struct Charges {
     object: &'static str,
-    data: Vec<String>,
+    data: Vec<StripeCharge>,
     has_more: bool,
     total_count: i32,
     url: String,
@@ -606,6 +609,145 @@ impl Charges {
             url: "http://placeholder".to_string(),
         }
     }
+
+    /// Each attempt of the payment is a charge of the payment intent, listed with the latest
+    /// attempt first as in the charges of Stripe
+    fn from_attempts(
+        payment_id: &id_type::PaymentId,
+        attempts: Option<Vec<payments::PaymentAttemptResponse>>,
+    ) -> Self {
+        let mut data = attempts
+            .unwrap_or_default()
+            .into_iter()
+            .map(|attempt| StripeCharge::from_attempt(payment_id, attempt))
+            .collect::<Vec<_>>();
+        data.sort_by(|charge_a, charge_b| charge_b.created.cmp(&charge_a.created));
+
+        Self {
+            object: "list",
+            total_count: i32::try_from(data.len()).unwrap_or(i32::MAX),
+            data,
+            has_more: false,
+            url: format!(
+                "/v1/charges?payment_intent={}",
+                payment_id.get_string_repr()
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeChargeStatus {
+    Succeeded,
+    Pending,
+    Failed,
+}
+
+impl From<api_enums::AttemptStatus> for StripeChargeStatus {
+    fn from(item: api_enums::AttemptStatus) -> Self {
+        match item {
+            api_enums::AttemptStatus::Authorized
+            | api_enums::AttemptStatus::Charged
+            | api_enums::AttemptStatus::PartialCharged
+            | api_enums::AttemptStatus::PartialChargedAndChargeable
+            | api_enums::AttemptStatus::CaptureInitiated
+            | api_enums::AttemptStatus::CaptureFailed
+            | api_enums::AttemptStatus::VoidInitiated
+            | api_enums::AttemptStatus::Voided
+            | api_enums::AttemptStatus::VoidFailed
+            | api_enums::AttemptStatus::AutoRefunded => Self::Succeeded,
+            api_enums::AttemptStatus::AuthenticationFailed
+            | api_enums::AttemptStatus::RouterDeclined
+            | api_enums::AttemptStatus::AuthorizationFailed
+            | api_enums::AttemptStatus::Failure => Self::Failed,
+            api_enums::AttemptStatus::Started
+            | api_enums::AttemptStatus::AuthenticationPending
+            | api_enums::AttemptStatus::AuthenticationSuccessful
+            | api_enums::AttemptStatus::Authorizing
+            | api_enums::AttemptStatus::CodInitiated
+            | api_enums::AttemptStatus::Unresolved
+            | api_enums::AttemptStatus::Pending
+            | api_enums::AttemptStatus::PaymentMethodAwaited
+            | api_enums::AttemptStatus::ConfirmationAwaited
+            | api_enums::AttemptStatus::DeviceDataCollectionPending => Self::Pending,
+        }
+    }
+}
+
+/// An attempt of the payment, in the shape of a charge of Stripe
+#[derive(Eq, PartialEq, Serialize, Debug)]
+pub struct StripeCharge {
+    id: String,
+    object: &'static str,
+    amount: i64,
+    currency: Option<String>,
+    status: StripeChargeStatus,
+    paid: bool,
+    captured: bool,
+    created: i64,
+    payment_intent: id_type::PaymentId,
+    payment_method: Option<api_models::enums::PaymentMethod>,
+    connector: Option<String>,
+    connector_transaction_id: Option<String>,
+    failure_code: Option<String>,
+    failure_message: Option<String>,
+}
+
+impl StripeCharge {
+    fn from_attempt(
+        payment_id: &id_type::PaymentId,
+        attempt: payments::PaymentAttemptResponse,
+    ) -> Self {
+        let status = StripeChargeStatus::from(attempt.status);
+        let (failure_code, failure_message) = match status {
+            StripeChargeStatus::Failed => (
+                attempt
+                    .unified_code
+                    .as_deref()
+                    .map(get_failure_code_of_unified_code)
+                    .map(ToString::to_string)
+                    .or(attempt.error_code),
+                attempt.unified_message.or(attempt.error_message),
+            ),
+            StripeChargeStatus::Succeeded | StripeChargeStatus::Pending => (None, None),
+        };
+
+        Self {
+            id: attempt.attempt_id,
+            object: "charge",
+            amount: attempt.amount.get_amount_as_i64(),
+            currency: attempt
+                .currency
+                .map(|currency| currency.to_string().to_lowercase()),
+            paid: status == StripeChargeStatus::Succeeded,
+            captured: matches!(
+                attempt.status,
+                api_enums::AttemptStatus::Charged
+                    | api_enums::AttemptStatus::PartialCharged
+                    | api_enums::AttemptStatus::PartialChargedAndChargeable
+                    | api_enums::AttemptStatus::AutoRefunded
+            ),
+            status,
+            created: attempt.created_at.assume_utc().unix_timestamp(),
+            payment_intent: payment_id.clone(),
+            payment_method: attempt.payment_method,
+            connector: attempt.connector,
+            connector_transaction_id: attempt.connector_transaction_id,
+            failure_code,
+            failure_message,
+        }
+    }
+}
+
+/// Maps the category of the unified error of an attempt to the failure code of a Stripe charge
+fn get_failure_code_of_unified_code(unified_code: &str) -> &'static str {
+    match unified_code {
+        // Issue with the payment method details, or a decline by the connector
+        "UE_1000" | "UE_2000" => "card_declined",
+        // Technical issue with the connector, an integration issue or an unknown error
+        _ => "processing_error",
+    }
 }
 
 #[derive(Clone, Debug, serde::Deserialize)]
@@ -774,6 +916,7 @@ impl ForeignTryFrom<(Option<MandateData>, Option<String>)> for Option<payments::
                         ip_address: Some(online.ip_address),
                         user_agent: online.user_agent,
                     }),
+                consent_text_version: None,
             }),
             update_mandate_id: None,
         });
@@ -1,12 +1,15 @@
 #[cfg(feature = "payouts")]
 use api_models::payouts as payout_models;
 use api_models::{
-    enums::{Currency, DisputeStatus, MandateStatus},
+    admin::{
+        ConnectorCredentialsResponse, ConnectorCredentialsStatus, MerchantAccountStatusResponse,
+    },
+    enums::{Currency, DisputeStatus, MandateStatus, MerchantAccountStatus},
     webhooks::{self as api},
 };
 #[cfg(feature = "payouts")]
 use common_utils::pii::{self, Email};
-use common_utils::{crypto::SignMessage, date_time, ext_traits::Encode};
+use common_utils::{crypto::SignMessage, date_time};
 use error_stack::ResultExt;
 use router_env::logger;
 use serde::Serialize;
@@ -38,6 +41,7 @@ impl OutgoingWebhookType for StripeOutgoingWebhook {
     fn get_outgoing_webhooks_signature(
         &self,
         payment_response_hash_key: Option<impl AsRef<[u8]>>,
+        payload_template: Option<&serde_json::Value>,
     ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError> {
         let timestamp = self.created;
 
@@ -45,10 +49,7 @@ impl OutgoingWebhookType for StripeOutgoingWebhook {
             .ok_or(errors::WebhooksFlowError::MerchantConfigNotFound)
             .attach_printable("For stripe compatibility payment_response_hash_key is mandatory")?;
 
-        let webhook_signature_payload = self
-            .encode_to_string_of_json()
-            .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
-            .attach_printable("failed encoding outgoing webhook payload")?;
+        let webhook_signature_payload = self.get_outgoing_webhook_payload(payload_template)?;
 
         let new_signature_payload = format!("{timestamp}.{webhook_signature_payload}");
         let v1 = hex::encode(
@@ -87,6 +88,8 @@ pub enum StripeWebhookObject {
     Mandate(StripeMandateResponse),
     #[cfg(feature = "payouts")]
     Payout(StripePayoutResponse),
+    Account(StripeAccountResponse),
+    ConnectorCredentials(StripeConnectorCredentialsResponse),
 }
 
 #[derive(Serialize, Debug)]
@@ -107,6 +110,46 @@ pub struct StripeMandateResponse {
     pub payment_method: String,
 }
 
+#[derive(Serialize, Debug)]
+pub struct StripeAccountResponse {
+    pub id: common_utils::id_type::MerchantId,
+    pub charges_enabled: bool,
+    pub payouts_enabled: bool,
+    pub status: MerchantAccountStatus,
+}
+
+impl From<MerchantAccountStatusResponse> for StripeAccountResponse {
+    fn from(res: MerchantAccountStatusResponse) -> Self {
+        Self {
+            id: res.merchant_id,
+            charges_enabled: res.status.can_charge(),
+            payouts_enabled: res.status.can_charge(),
+            status: res.status,
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+pub struct StripeConnectorCredentialsResponse {
+    pub id: common_utils::id_type::MerchantConnectorAccountId,
+    pub connector: String,
+    pub status: ConnectorCredentialsStatus,
+    pub expires_at: Option<i64>,
+}
+
+impl From<ConnectorCredentialsResponse> for StripeConnectorCredentialsResponse {
+    fn from(res: ConnectorCredentialsResponse) -> Self {
+        Self {
+            id: res.merchant_connector_id,
+            connector: res.connector_name,
+            status: res.status,
+            expires_at: res
+                .expires_at
+                .map(|expires_at| expires_at.assume_utc().unix_timestamp()),
+        }
+    }
+}
+
 #[cfg(feature = "payouts")]
 #[derive(Clone, Serialize, Debug)]
 pub struct StripePayoutResponse {
@@ -156,7 +199,8 @@ impl From<common_enums::PayoutStatus> for StripePayoutStatus {
             | common_enums::PayoutStatus::RequiresFulfillment
             | common_enums::PayoutStatus::RequiresPayoutMethodData
             | common_enums::PayoutStatus::RequiresVendorAccountCreation
-            | common_enums::PayoutStatus::RequiresConfirmation => Self::PayoutProcessing,
+            | common_enums::PayoutStatus::RequiresConfirmation
+            | common_enums::PayoutStatus::OnHold => Self::PayoutProcessing,
         }
     }
 }
@@ -271,6 +315,19 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
 
         // the below are not really stripe compatible because stripe doesn't provide this
         api_models::enums::EventType::ActionRequired => "action.required",
+        api_models::enums::EventType::PaymentAuthenticationAbandoned => {
+            "payment_intent.authentication_abandoned"
+        }
+        api_models::enums::EventType::PaymentAuthorizationRemainderReleased => {
+            "payment_intent.authorization_remainder_released"
+        }
+        api_models::enums::EventType::PaymentDunningRetryScheduled => {
+            "payment_intent.dunning_retry_scheduled"
+        }
+        api_models::enums::EventType::PaymentUncollectible => "payment_intent.uncollectible",
+        api_models::enums::EventType::ConnectorVolumeCapReached => {
+            "payment_intent.connector_volume_cap_reached"
+        }
         api_models::enums::EventType::RefundSucceeded => "refund.succeeded",
         api_models::enums::EventType::RefundFailed => "refund.failed",
         api_models::enums::EventType::DisputeOpened => "dispute.failed",
@@ -296,6 +353,13 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PayoutProcessing => "payout.created",
         api_models::enums::EventType::PayoutExpired => "payout.failed",
         api_models::enums::EventType::PayoutReversed => "payout.reconciliation_completed",
+        api_models::enums::EventType::MerchantAccountStatusUpdated => "account.updated",
+        api_models::enums::EventType::ConnectorCredentialsExpiring => {
+            "connector_credentials.expiring"
+        }
+        api_models::enums::EventType::ConnectorCredentialsExpired => {
+            "connector_credentials.expired"
+        }
     }
 }
 
@@ -338,6 +402,12 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             }
             #[cfg(feature = "payouts")]
             api::OutgoingWebhookContent::PayoutDetails(payout) => Self::Payout((*payout).into()),
+            api::OutgoingWebhookContent::MerchantAccountDetails(merchant_account) => {
+                Self::Account((*merchant_account).into())
+            }
+            api::OutgoingWebhookContent::ConnectorCredentialsDetails(connector_credentials) => {
+                Self::ConnectorCredentials((*connector_credentials).into())
+            }
         }
     }
 }
@@ -77,6 +77,8 @@ impl Default for super::settings::Locker {
             //Time to live for storage entries in locker
             ttl_for_storage_in_secs: 60 * 60 * 24 * 365 * 7,
             decryption_scheme: Default::default(),
+            deferred_vaulting_enabled: false,
+            external_vault_allowed_hosts: HashSet::new(),
         }
     }
 }
@@ -106,6 +108,117 @@ impl Default for super::settings::Refund {
         Self {
             max_attempts: 10,
             max_age: 365,
+            card_network_validity_days: HashMap::from([
+                (enums::CardNetwork::Visa, 540),
+                (enums::CardNetwork::Mastercard, 540),
+                (enums::CardNetwork::AmericanExpress, 365),
+                (enums::CardNetwork::Discover, 365),
+                (enums::CardNetwork::DinersClub, 365),
+                (enums::CardNetwork::JCB, 365),
+            ]),
+        }
+    }
+}
+
+impl Default for super::settings::SdkEventsConfig {
+    fn default() -> Self {
+        Self {
+            max_batch_size: 100,
+            merchant_events_per_minute: 10000,
+            // 1 day
+            max_event_age: 86400,
+            max_value_length: 4096,
+        }
+    }
+}
+
+impl Default for super::settings::AttemptDataRetentionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            redact_after_days: 180,
+            batch_size: 500,
+            // 1 day
+            interval: 86400,
+        }
+    }
+}
+
+impl Default for super::settings::SessionResponseCacheConfig {
+    fn default() -> Self {
+        Self {
+            connectors: HashSet::new(),
+            // 5 minutes
+            ttl_in_seconds: 300,
+        }
+    }
+}
+
+impl Default for super::settings::GooglePayGatewayParametersConfig {
+    fn default() -> Self {
+        Self {
+            gateways: HashMap::new(),
+            // 1 hour
+            ttl_in_seconds: 3600,
+        }
+    }
+}
+
+impl Default for super::settings::ConnectorRateLimits {
+    fn default() -> Self {
+        Self {
+            max_wait_in_millis: 2000,
+            connectors: HashMap::new(),
+        }
+    }
+}
+
+impl Default for super::settings::RefundReconciliationConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            default_sla_in_hours: 72,
+            connector_sla_in_hours: HashMap::new(),
+            batch_size: 100,
+            // 1 hour
+            interval: 3600,
+        }
+    }
+}
+
+impl Default for super::settings::ConnectorCassettes {
+    fn default() -> Self {
+        Self {
+            mode: super::settings::CassetteMode::Off,
+            directory: "cassettes".into(),
+            sanitized_fields: [
+                "authorization",
+                "proxy-authorization",
+                "cookie",
+                "set-cookie",
+                "x-api-key",
+                "api_key",
+                "secret",
+                "api_secret",
+                "secret_key",
+                "private_key",
+                "client_secret",
+                "access_token",
+                "refresh_token",
+                "id_token",
+                "password",
+                "number",
+                "card_number",
+                "account_number",
+                "iban",
+                "cvc",
+                "cvv",
+                "cvv2",
+                "security_code",
+            ]
+            .into_iter()
+            .map(String::from)
+            .collect(),
         }
     }
 }
@@ -125,6 +238,7 @@ impl Default for super::settings::DrainerSettings {
             max_read_count: 100,
             shutdown_interval: 1000,
             loop_interval: 100,
+            priority_lanes: super::settings::DrainerPriorityLanes::default(),
         }
     }
 }
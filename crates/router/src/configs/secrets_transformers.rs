@@ -67,6 +67,22 @@ impl SecretsHandler for settings::ConnectorOnboarding {
             secret_management_client.get_secret(onboarding_config.partner_id.clone())
         )?;
 
+        let mut oauth = std::collections::HashMap::new();
+        for (connector, oauth_config) in value.get_inner().oauth.iter() {
+            let (client_id, client_secret) = tokio::try_join!(
+                secret_management_client.get_secret(oauth_config.client_id.clone()),
+                secret_management_client.get_secret(oauth_config.client_secret.clone())
+            )?;
+            oauth.insert(
+                *connector,
+                settings::OAuthOnboarding {
+                    client_id,
+                    client_secret,
+                    ..oauth_config.clone()
+                },
+            );
+        }
+
         Ok(value.transition_state(|onboarding_config| Self {
             paypal: settings::PayPalOnboarding {
                 client_id,
@@ -74,6 +90,7 @@ impl SecretsHandler for settings::ConnectorOnboarding {
                 partner_id,
                 ..onboarding_config.paypal
             },
+            oauth,
         }))
     }
 }
@@ -99,6 +116,63 @@ impl SecretsHandler for settings::ForexApi {
     }
 }
 
+#[async_trait::async_trait]
+impl SecretsHandler for settings::SanctionsScreeningConfig {
+    async fn convert_to_raw_secret(
+        value: SecretStateContainer<Self, SecuredSecret>,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
+        let provider = match &value.get_inner().provider {
+            settings::SanctionsScreeningProvider::Watchlist { entries } => {
+                settings::SanctionsScreeningProvider::Watchlist {
+                    entries: entries.clone(),
+                }
+            }
+            settings::SanctionsScreeningProvider::External {
+                name,
+                base_url,
+                api_key,
+                timeout,
+            } => settings::SanctionsScreeningProvider::External {
+                name: name.clone(),
+                base_url: base_url.clone(),
+                api_key: secret_management_client.get_secret(api_key.clone()).await?,
+                timeout: *timeout,
+            },
+        };
+
+        Ok(value.transition_state(|sanctions_screening| Self {
+            provider,
+            ..sanctions_screening
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretsHandler for settings::AddressValidationConfig {
+    async fn convert_to_raw_secret(
+        value: SecretStateContainer<Self, SecuredSecret>,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
+        let address_validation = match value.get_inner() {
+            Self::Offline => Self::Offline,
+            Self::External {
+                name,
+                base_url,
+                api_key,
+                timeout,
+            } => Self::External {
+                name: name.clone(),
+                base_url: base_url.clone(),
+                api_key: secret_management_client.get_secret(api_key.clone()).await?,
+                timeout: *timeout,
+            },
+        };
+
+        Ok(value.transition_state(|_| address_validation))
+    }
+}
+
 #[async_trait::async_trait]
 impl SecretsHandler for settings::ApiKeys {
     async fn convert_to_raw_secret(
@@ -465,6 +539,53 @@ pub(crate) async fn fetch_raw_secrets(
         })
         .await;
 
+    #[allow(clippy::expect_used)]
+    let sanctions_screening = settings::SanctionsScreeningConfig::convert_to_raw_secret(
+        conf.sanctions_screening,
+        secret_management_client,
+    )
+    .await
+    .expect("Failed to decrypt sanctions screening configs");
+
+    #[allow(clippy::expect_used)]
+    let address_validation = settings::AddressValidationConfig::convert_to_raw_secret(
+        conf.address_validation,
+        secret_management_client,
+    )
+    .await
+    .expect("Failed to decrypt address validation configs");
+
+    let mut data_residency_regions = std::collections::HashMap::new();
+    for (region, region_config) in conf.data_residency.regions {
+        #[allow(clippy::expect_used)]
+        let master_database = settings::Database::convert_to_raw_secret(
+            region_config.master_database,
+            secret_management_client,
+        )
+        .await
+        .expect("Failed to decrypt data residency master database configuration");
+
+        #[cfg(feature = "olap")]
+        #[allow(clippy::expect_used)]
+        let replica_database = settings::Database::convert_to_raw_secret(
+            region_config.replica_database,
+            secret_management_client,
+        )
+        .await
+        .expect("Failed to decrypt data residency replica database configuration");
+
+        data_residency_regions.insert(
+            region,
+            settings::DataResidencyRegion {
+                master_database,
+                #[cfg(feature = "olap")]
+                replica_database,
+                locker_host: region_config.locker_host,
+                key_manager_url: region_config.key_manager_url,
+            },
+        );
+    }
+
     Settings {
         server: conf.server,
         master_database,
@@ -543,11 +664,31 @@ pub(crate) async fn fetch_raw_secrets(
         grpc_client: conf.grpc_client,
         #[cfg(feature = "v2")]
         cell_information: conf.cell_information,
+        region: conf.region,
+        data_residency: settings::DataResidencySettings {
+            regions: data_residency_regions,
+        },
         network_tokenization_supported_card_networks: conf
             .network_tokenization_supported_card_networks,
         network_tokenization_service,
         network_tokenization_supported_connectors: conf.network_tokenization_supported_connectors,
         theme: conf.theme,
         platform: conf.platform,
+        token_migration_adapters: conf.token_migration_adapters,
+        merchant_feature_flags: conf.merchant_feature_flags,
+        sdk_events: conf.sdk_events,
+        connector_cassettes: conf.connector_cassettes,
+        rounding_policy: conf.rounding_policy,
+        overcapture: conf.overcapture,
+        address_validation,
+        sanctions_screening,
+        refund_settlement_times: conf.refund_settlement_times,
+        attempt_data_retention: conf.attempt_data_retention,
+        session_response_cache: conf.session_response_cache,
+        google_pay_gateway_parameters: conf.google_pay_gateway_parameters,
+        connector_rate_limits: conf.connector_rate_limits,
+        required_fields_validation: conf.required_fields_validation,
+        webhook_source_ip_allowlist: conf.webhook_source_ip_allowlist,
+        refund_reconciliation: conf.refund_reconciliation,
     }
 }
@@ -44,6 +44,7 @@ use crate::{
 };
 
 pub const REQUIRED_FIELDS_CONFIG_FILE: &str = "payment_required_fields_v2.toml";
+pub const REGION_CONFIG_DIRECTORY: &str = "regions";
 
 #[derive(clap::Parser, Default)]
 #[cfg_attr(feature = "vergen", command(version = router_env::version!()))]
@@ -133,11 +134,29 @@ pub struct Settings<S: SecretState> {
     pub grpc_client: GrpcClientSettings,
     #[cfg(feature = "v2")]
     pub cell_information: CellInformation,
+    pub region: RegionInformation,
+    pub data_residency: DataResidencySettings<S>,
     pub network_tokenization_supported_card_networks: NetworkTokenizationSupportedCardNetworks,
     pub network_tokenization_service: Option<SecretStateContainer<NetworkTokenizationService, S>>,
     pub network_tokenization_supported_connectors: NetworkTokenizationSupportedConnectors,
     pub theme: ThemeSettings,
     pub platform: Platform,
+    pub token_migration_adapters: TokenMigrationAdapters,
+    pub merchant_feature_flags: MerchantFeatureFlagsConfig,
+    pub sdk_events: SdkEventsConfig,
+    pub connector_cassettes: ConnectorCassettes,
+    pub rounding_policy: RoundingPolicy,
+    pub overcapture: OvercaptureConfig,
+    pub address_validation: SecretStateContainer<AddressValidationConfig, S>,
+    pub sanctions_screening: SecretStateContainer<SanctionsScreeningConfig, S>,
+    pub refund_settlement_times: RefundSettlementTimes,
+    pub attempt_data_retention: AttemptDataRetentionConfig,
+    pub session_response_cache: SessionResponseCacheConfig,
+    pub google_pay_gateway_parameters: GooglePayGatewayParametersConfig,
+    pub connector_rate_limits: ConnectorRateLimits,
+    pub required_fields_validation: RequiredFieldsValidation,
+    pub webhook_source_ip_allowlist: WebhookSourceIpAllowlist,
+    pub refund_reconciliation: RefundReconciliationConfig,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -213,6 +232,55 @@ impl TenantConfig {
     /// # Panics
     ///
     /// Panics if Failed to create event handler
+    pub async fn get_regional_store_interface_map(
+        &self,
+        storage_impl: &app::StorageImpl,
+        conf: &configs::Settings,
+        cache_store: Arc<storage_impl::redis::RedisStore>,
+        testable: bool,
+    ) -> HashMap<id_type::TenantId, HashMap<String, Box<dyn app::StorageInterface>>> {
+        #[allow(clippy::expect_used)]
+        let event_handler = conf
+            .events
+            .get_event_handler()
+            .await
+            .expect("Failed to create event handler");
+        futures::future::join_all(self.0.iter().map(|(tenant_name, tenant)| async {
+            let stores = futures::future::join_all(conf.data_residency.regions.iter().map(
+                |(region, region_config)| async {
+                    // The stores of a region differ from the store of the tenant only in their
+                    // databases
+                    let mut regional_conf = conf.clone();
+                    regional_conf.master_database = region_config.master_database.clone();
+                    #[cfg(feature = "olap")]
+                    {
+                        regional_conf.replica_database = region_config.replica_database.clone();
+                    }
+                    let store = AppState::get_store_interface(
+                        storage_impl,
+                        &event_handler,
+                        &regional_conf,
+                        tenant,
+                        cache_store.clone(),
+                        testable,
+                    )
+                    .await
+                    .get_storage_interface();
+                    (region.clone(), store)
+                },
+            ))
+            .await
+            .into_iter()
+            .collect();
+            (tenant_name.clone(), stores)
+        }))
+        .await
+        .into_iter()
+        .collect()
+    }
+    /// # Panics
+    ///
+    /// Panics if Failed to create event handler
     pub async fn get_accounts_store_interface_map(
         &self,
         storage_impl: &app::StorageImpl,
@@ -491,6 +559,7 @@ pub struct DummyConnector {
     pub refund_retrieve_duration: u64,
     pub refund_retrieve_tolerance: u64,
     pub authorize_ttl: i64,
+    pub mandate_ttl: i64,
     pub assets_base_url: String,
     pub default_return_url: String,
     pub slack_invite_url: String,
@@ -697,6 +766,13 @@ pub struct Locker {
     pub locker_enabled: bool,
     pub ttl_for_storage_in_secs: i64,
     pub decryption_scheme: DecryptionScheme,
+    /// Complete the payment and vault the card later when the locker is unreachable while saving
+    /// a payment method
+    pub deferred_vaulting_enabled: bool,
+    /// Hosts of the vaults which the profiles can bring to store their cards. The cards are sent
+    /// only to the vaults on these hosts, over https
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub external_vault_allowed_hosts: HashSet<String>,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -713,6 +789,266 @@ pub enum DecryptionScheme {
 pub struct Refund {
     pub max_attempts: usize,
     pub max_age: i64,
+    /// Number of days after the creation of a card payment within which each card network allows
+    /// refunding it against the original transaction. The payments of the networks which are not
+    /// listed are not subject to a refund window of their network.
+    pub card_network_validity_days: HashMap<enums::CardNetwork, i64>,
+}
+
+/// Limits on the events ingested from the SDK
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct SdkEventsConfig {
+    /// Maximum number of events accepted in a batch
+    pub max_batch_size: usize,
+    /// Maximum number of events accepted per minute for a merchant
+    pub merchant_events_per_minute: u64,
+    /// Maximum age of the events accepted, in seconds
+    pub max_event_age: i64,
+    /// Maximum length of the value of the events accepted
+    pub max_value_length: usize,
+}
+
+/// Recording of the HTTP exchanges with the connectors into cassettes, and their replay in place
+/// of the connectors, for the integration tests
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorCassettes {
+    pub mode: CassetteMode,
+    /// Directory in which a cassette is kept for each host called
+    pub directory: PathBuf,
+    /// Names of the headers, of the query parameters and of the body fields whose values are
+    /// redacted in the cassettes
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub sanitized_fields: HashSet<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CassetteMode {
+    #[default]
+    Off,
+    /// The requests are sent to the connectors and the exchanges are appended to the cassettes
+    Record,
+    /// The responses are served from the cassettes, and the connectors are not called
+    Replay,
+}
+
+/// Rounding of the amounts derived by the application, such as the surcharges, the taxes on the
+/// surcharges, the percentage shares of the split payments and the converted amounts, to the
+/// exponent of their currency
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct RoundingPolicy {
+    pub default_mode: enums::RoundingMode,
+    /// Rounding modes of the currencies which are not rounded as per the default mode
+    pub currency_modes: HashMap<enums::Currency, enums::RoundingMode>,
+}
+
+impl RoundingPolicy {
+    pub fn get_rounding_mode(&self, currency: Option<enums::Currency>) -> enums::RoundingMode {
+        currency
+            .and_then(|currency| self.currency_modes.get(&currency).copied())
+            .unwrap_or(self.default_mode)
+    }
+}
+
+/// Connectors and card networks with which more than the amount capturable of a payment may be
+/// captured, for the merchants which have enabled overcapture
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct OvercaptureConfig {
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub supported_connectors: HashSet<enums::Connector>,
+    /// The amount which may be captured above the amount capturable for each card network, as a
+    /// percentage of the amount capturable. Overcapture is not allowed for the other networks.
+    pub network_tolerances: HashMap<enums::CardNetwork, u8>,
+}
+
+/// The provider with which the billing and shipping addresses are validated, for the merchants
+/// which have enabled address validation
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(tag = "provider", rename_all = "snake_case")]
+pub enum AddressValidationConfig {
+    /// Normalize the addresses offline, without verifying that they exist
+    #[default]
+    Offline,
+    /// Validate the addresses with an external provider, falling back to the offline
+    /// normalization when the provider cannot be reached
+    External {
+        /// Name of the provider, recorded with the results of the validation
+        name: String,
+        base_url: String,
+        api_key: Secret<String>,
+        /// Time after which the request to the provider is abandoned, in seconds
+        timeout: u64,
+    },
+}
+
+/// Screening of the names of the beneficiaries of the payouts and of the customers of the
+/// high-value payments against the watchlists, for the merchants which have enabled sanctions
+/// screening
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct SanctionsScreeningConfig {
+    #[serde(flatten)]
+    pub provider: SanctionsScreeningProvider,
+    /// The amount from which the payments are screened, in the minor unit of each currency. The
+    /// payments in the currencies which are not listed are not screened
+    #[serde(default)]
+    pub payment_thresholds: HashMap<enums::Currency, i64>,
+}
+
+/// The provider with which the names are screened
+#[derive(Debug, Deserialize, Clone)]
+#[serde(tag = "provider", rename_all = "snake_case")]
+pub enum SanctionsScreeningProvider {
+    /// Match the names against the entries of a watchlist in the configuration
+    Watchlist {
+        #[serde(default)]
+        entries: Vec<String>,
+    },
+    /// Screen the names with an external provider. The objects are held for review when the
+    /// provider cannot be reached
+    External {
+        /// Name of the provider, recorded with the screening cases
+        name: String,
+        base_url: String,
+        api_key: Secret<String>,
+        /// Time after which the request to the provider is abandoned, in seconds
+        timeout: u64,
+    },
+}
+
+impl Default for SanctionsScreeningProvider {
+    fn default() -> Self {
+        Self::Watchlist {
+            entries: Vec::new(),
+        }
+    }
+}
+
+/// Number of days after the creation of a refund within which the refunded amount arrives in the
+/// account of the customer
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub struct SettlementTimeRange {
+    pub min_days: u16,
+    pub max_days: u16,
+}
+
+/// Times within which the refunds settle with the customers, from which the estimated arrival of
+/// the refunds is derived until enough refunds have settled to refine them
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RefundSettlementTimes {
+    /// Settlement time of the refunds of the connectors which are not listed
+    pub default: SettlementTimeRange,
+    pub connectors: HashMap<enums::Connector, ConnectorRefundSettlementTimes>,
+}
+
+impl Default for RefundSettlementTimes {
+    fn default() -> Self {
+        Self {
+            default: SettlementTimeRange {
+                min_days: 5,
+                max_days: 10,
+            },
+            connectors: HashMap::new(),
+        }
+    }
+}
+
+/// Settlement times of the refunds of a connector
+#[derive(Debug, Deserialize, Clone)]
+pub struct ConnectorRefundSettlementTimes {
+    /// Settlement time of the refunds of the payment methods which are not listed
+    pub default: Option<SettlementTimeRange>,
+    #[serde(default)]
+    pub payment_methods: HashMap<enums::PaymentMethod, SettlementTimeRange>,
+}
+
+/// Redaction of the payment method data and the encoded data of the payment attempts, some days
+/// after they have reached a terminal status. The fields of the attempts which are used in the
+/// analytics, such as the card network and the card BIN, are preserved.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct AttemptDataRetentionConfig {
+    pub enabled: bool,
+    /// Number of days after the last update of a terminal attempt after which its data is redacted
+    pub redact_after_days: u16,
+    /// Number of attempts which are redacted together
+    pub batch_size: i64,
+    /// Interval between the redaction passes, in seconds
+    pub interval: i64,
+}
+
+/// Reconciliation of the refunds stuck in pending beyond the time within which the connector is
+/// expected to process them. The refunds are synced with the connector, and the refunds which the
+/// connector fails to find are held for manual review.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RefundReconciliationConfig {
+    pub enabled: bool,
+    /// Number of hours after their creation after which the refunds of the connectors which are not
+    /// listed are reconciled
+    pub default_sla_in_hours: u32,
+    pub connector_sla_in_hours: HashMap<enums::Connector, u32>,
+    /// Number of refunds which are fetched together
+    pub batch_size: i64,
+    /// Interval between the reconciliation passes, in seconds
+    pub interval: i64,
+}
+
+impl RefundReconciliationConfig {
+    /// Number of hours after their creation after which the refunds of the connector are reconciled
+    pub fn get_sla_in_hours(&self, connector: Option<enums::Connector>) -> u32 {
+        connector
+            .and_then(|connector| self.connector_sla_in_hours.get(&connector))
+            .copied()
+            .unwrap_or(self.default_sla_in_hours)
+    }
+
+    /// The shortest of the SLAs, within which none of the refunds are reconciled
+    pub fn get_min_sla_in_hours(&self) -> u32 {
+        self.connector_sla_in_hours
+            .values()
+            .copied()
+            .fold(self.default_sla_in_hours, u32::min)
+    }
+}
+
+impl RefundSettlementTimes {
+    pub fn get_settlement_time(
+        &self,
+        connector: enums::Connector,
+        payment_method: Option<enums::PaymentMethod>,
+    ) -> SettlementTimeRange {
+        self.connectors
+            .get(&connector)
+            .and_then(|connector_settlement_times| {
+                payment_method
+                    .and_then(|payment_method| {
+                        connector_settlement_times
+                            .payment_methods
+                            .get(&payment_method)
+                    })
+                    .or(connector_settlement_times.default.as_ref())
+            })
+            .copied()
+            .unwrap_or(self.default)
+    }
+}
+
+impl OvercaptureConfig {
+    pub fn get_tolerance_percentage(
+        &self,
+        connector: enums::Connector,
+        card_network: &enums::CardNetwork,
+    ) -> Option<u8> {
+        self.supported_connectors
+            .contains(&connector)
+            .then(|| self.network_tolerances.get(card_network).copied())
+            .flatten()
+    }
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -798,6 +1134,18 @@ pub struct DrainerSettings {
     pub max_read_count: u64,
     pub shutdown_interval: u32, // in milliseconds
     pub loop_interval: u32,     // in milliseconds
+    pub priority_lanes: DrainerPriorityLanes,
+}
+
+/// Priority lanes of the drainer stream, through which the inserts and the status updates of the
+/// payments, refunds and payouts are drained ahead of the other updates. The priority lanes must be
+/// enabled in the drainer before they are enabled here, as the drainer does not read the low
+/// priority lanes otherwise.
+#[cfg(feature = "kv_store")]
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct DrainerPriorityLanes {
+    pub enabled: bool,
 }
 
 #[derive(Debug, Clone, Default, Deserialize)]
@@ -841,6 +1189,74 @@ pub struct DelayedSessionConfig {
     pub connectors_with_delayed_session_response: HashSet<enums::Connector>,
 }
 
+/// Caching of the session responses of the connectors, so that the session calls made for the same
+/// profile and parameters within the time to live of the cache do not reach the connector
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct SessionResponseCacheConfig {
+    /// Connectors whose session responses are cached
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub connectors: HashSet<enums::Connector>,
+    /// Time to live of the cached session responses, in seconds
+    pub ttl_in_seconds: i64,
+}
+
+/// Google Pay gateway parameters generated for the connector chosen by the session routing
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct GooglePayGatewayParametersConfig {
+    /// Identifiers of the connectors as gateways in the Google Pay API, used when the connector
+    /// account does not set the gateway of its Google Pay tokenization
+    pub gateways: HashMap<enums::Connector, String>,
+    /// Time for which the connector which the Google Pay token of a payment is created for is
+    /// recorded, in seconds
+    pub ttl_in_seconds: i64,
+}
+
+/// Limits on the rate of the calls made under each profile to the connectors, which protect the
+/// merchants from the rate limits imposed by the connectors during spikes in traffic
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorRateLimits {
+    /// Maximum time for which a call waits for the rate limit of the connector, in milliseconds,
+    /// beyond which the call is throttled
+    pub max_wait_in_millis: u64,
+    pub connectors: HashMap<enums::Connector, ConnectorRateLimit>,
+}
+
+/// Token bucket from which the calls of a profile to a connector draw
+#[derive(Debug, Deserialize, Clone)]
+pub struct ConnectorRateLimit {
+    /// Number of calls which can be made at once, after a period without calls
+    pub burst: u32,
+    /// Number of calls which can be made per second
+    pub calls_per_second: u32,
+}
+
+/// Validation of the fields required by the connectors on the confirmation of the payments, which
+/// rejects the payments missing any of the fields before the connector is called
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct RequiredFieldsValidation {
+    pub enabled: bool,
+}
+
+/// IP ranges published by the connectors, from which their webhooks are sent
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct WebhookSourceIpAllowlist {
+    pub connectors: HashMap<enums::Connector, WebhookSourceIpRanges>,
+    /// The number of trusted proxies in front of the application, each of which appends the
+    /// address of its peer to the `X-Forwarded-For` header
+    pub trusted_proxy_hops: usize,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct WebhookSourceIpRanges {
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub ip_ranges: HashSet<common_utils::types::IpRange>,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct WebhookSourceVerificationCall {
     #[serde(deserialize_with = "deserialize_hashset")]
@@ -872,6 +1288,36 @@ pub struct GooglePayDecryptConfig {
     pub google_pay_root_signing_keys: Secret<String>,
 }
 
+/// Adapters through which the tokens of an external vault are detokenized while migrating the
+/// payment methods, keyed by the migration agreement id
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(transparent)]
+pub struct TokenMigrationAdapters(pub HashMap<String, TokenMigrationAdapter>);
+
+/// The values of the merchant feature flags which are used when a flag is not set for the
+/// merchant or its profile
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct MerchantFeatureFlagsConfig {
+    pub defaults: HashMap<enums::MerchantFeatureFlag, bool>,
+}
+
+impl MerchantFeatureFlagsConfig {
+    pub fn get_default(&self, flag: enums::MerchantFeatureFlag) -> bool {
+        self.defaults
+            .get(&flag)
+            .copied()
+            .unwrap_or_else(|| flag.default_value())
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct TokenMigrationAdapter {
+    /// The merchant which is allowed to use the migration agreement
+    pub merchant_id: id_type::MerchantId,
+    pub detokenize_url: String,
+    pub api_key: Secret<String>,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 #[serde(default)]
 pub struct LockerBasedRecipientConnectorList {
@@ -928,19 +1374,40 @@ impl Settings<SecuredSecret> {
             config.add_source(File::from(required_fields_config_file).required(false))
         };
 
-        let config = config
-            .add_source(
-                Environment::with_prefix("ROUTER")
-                    .try_parsing(true)
-                    .separator("__")
-                    .list_separator(",")
-                    .with_list_parse_key("log.telemetry.route_to_trace")
-                    .with_list_parse_key("redis.cluster_urls")
-                    .with_list_parse_key("events.kafka.brokers")
-                    .with_list_parse_key("connectors.supported.wallets")
-                    .with_list_parse_key("connector_request_reference_id_config.merchant_ids_send_payment_id_as_connector_request_id"),
+        let environment_source = || {
+            Environment::with_prefix("ROUTER")
+                .try_parsing(true)
+                .separator("__")
+                .list_separator(",")
+                .with_list_parse_key("log.telemetry.route_to_trace")
+                .with_list_parse_key("redis.cluster_urls")
+                .with_list_parse_key("events.kafka.brokers")
+                .with_list_parse_key("connectors.supported.wallets")
+                .with_list_parse_key("connector_request_reference_id_config.merchant_ids_send_payment_id_as_connector_request_id")
+        };
 
-            )
+        // The configuration specific to the region of the deployment, such as its database and
+        // redis, overrides the config file. The region itself may be set in either the config file
+        // or the environment variables.
+        let region_id = config
+            .clone()
+            .add_source(environment_source())
+            .build()
+            .change_context(ApplicationError::ConfigurationError)?
+            .get_string("region.id")
+            .ok();
+        let config = match region_id {
+            Some(region_id) => {
+                let region_config_file = router_env::Config::get_config_directory()
+                    .join(REGION_CONFIG_DIRECTORY)
+                    .join(format!("{}.toml", region_id.trim()));
+                config.add_source(File::from(region_config_file).required(false))
+            }
+            None => config,
+        };
+
+        let config = config
+            .add_source(environment_source())
             .build()
             .change_context(ApplicationError::ConfigurationError)?;
 
@@ -981,6 +1448,7 @@ impl Settings<SecuredSecret> {
         }
         self.secrets.get_inner().validate()?;
         self.locker.validate()?;
+        self.data_residency.validate(&self.locker)?;
         self.connectors.validate("connectors")?;
 
         self.cors.validate()?;
@@ -1030,7 +1498,22 @@ impl Settings<SecuredSecret> {
             .map(|x| x.validate())
             .transpose()?;
 
+        self.token_migration_adapters
+            .0
+            .values()
+            .try_for_each(|adapter| adapter.validate())?;
+
         self.key_manager.get_inner().validate()?;
+        self.connector_cassettes.validate()?;
+        self.overcapture.validate()?;
+        self.address_validation.get_inner().validate()?;
+        self.sanctions_screening.get_inner().validate()?;
+        self.refund_settlement_times.validate()?;
+        self.attempt_data_retention.validate()?;
+        self.session_response_cache.validate()?;
+        self.google_pay_gateway_parameters.validate()?;
+        self.connector_rate_limits.validate()?;
+        self.refund_reconciliation.validate()?;
         #[cfg(feature = "email")]
         self.email
             .validate()
@@ -1098,6 +1581,8 @@ impl<'de> Deserialize<'de> for LockSettings {
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct ConnectorOnboarding {
     pub paypal: PayPalOnboarding,
+    #[serde(default)]
+    pub oauth: HashMap<enums::Connector, OAuthOnboarding>,
 }
 
 #[cfg(feature = "olap")]
@@ -1109,6 +1594,27 @@ pub struct PayPalOnboarding {
     pub enabled: bool,
 }
 
+/// The OAuth application of the platform at a connector, with which merchants connect their
+/// accounts at the connector by giving their consent. The access token obtained is used as the API
+/// key of the connector account of the merchant.
+#[cfg(feature = "olap")]
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct OAuthOnboarding {
+    pub client_id: Secret<String>,
+    pub client_secret: Secret<String>,
+    /// The url of the consent page of the connector
+    pub authorization_url: String,
+    /// The url at which the authorization codes and the refresh tokens are exchanged for tokens
+    pub token_url: String,
+    /// The scopes which can be requested, all of which are requested by default
+    #[serde(default)]
+    pub scopes: Vec<String>,
+    /// The scopes without which the connector account cannot be used
+    #[serde(default)]
+    pub required_scopes: Vec<String>,
+    pub enabled: bool,
+}
+
 #[cfg(feature = "tls")]
 #[derive(Debug, Deserialize, Clone)]
 pub struct ServerTls {
@@ -1142,6 +1648,38 @@ impl Default for CellInformation {
     }
 }
 
+/// The region of the deployment, in an active-active deployment across multiple regions. The tag
+/// of the region is embedded in the ids generated by the deployment and is returned in the
+/// `x-hyperswitch-region` response header, and the configuration specific to the region is read
+/// from `config/regions/<region>.toml`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct RegionInformation {
+    pub id: Option<id_type::RegionId>,
+}
+
+/// The regions in which the personal data of a merchant can be required to reside, keyed by the
+/// name of the region. The customers and addresses of a merchant residing in a region are stored
+/// in the database of the region, and its cards and network tokens in the locker of the region,
+/// while the rest of its data is stored in the database of the deployment.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct DataResidencySettings<S: SecretState> {
+    pub regions: HashMap<String, DataResidencyRegion<S>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DataResidencyRegion<S: SecretState> {
+    pub master_database: SecretStateContainer<Database, S>,
+    #[cfg(feature = "olap")]
+    pub replica_database: SecretStateContainer<Database, S>,
+    pub locker_host: String,
+    /// The key manager of the region, which is given the data key of the merchants residing in the
+    /// region and encrypts and decrypts their personal data in place of the key manager of the
+    /// deployment
+    pub key_manager_url: Option<String>,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct ThemeSettings {
     pub storage: FileStorageConfig,
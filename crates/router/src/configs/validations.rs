@@ -272,6 +272,24 @@ impl super::settings::GooglePayDecryptConfig {
     }
 }
 
+impl super::settings::TokenMigrationAdapter {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.detokenize_url.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "token migration adapter detokenize_url must not be empty".into(),
+            ))
+        })?;
+
+        when(self.api_key.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "token migration adapter api_key must not be empty".into(),
+            ))
+        })
+    }
+}
+
 impl super::settings::KeyManagerConfig {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -293,3 +311,270 @@ impl super::settings::KeyManagerConfig {
         })
     }
 }
+
+impl super::settings::ConnectorCassettes {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        if self.mode == super::settings::CassetteMode::Off {
+            return Ok(());
+        }
+
+        when(
+            router_env::env::which() == router_env::env::Env::Production,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "connector cassettes must not be enabled in production".into(),
+                ))
+            },
+        )?;
+
+        when(self.directory.as_os_str().is_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector cassettes directory must not be empty".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::OvercaptureConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        self.network_tolerances
+            .iter()
+            .try_for_each(|(card_network, tolerance)| {
+                common_utils::fp_utils::when(*tolerance > 100, || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "overcapture tolerance of {card_network} must not exceed 100 percent"
+                    )))
+                })
+            })
+    }
+}
+
+impl super::settings::AddressValidationConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        match self {
+            Self::Offline => Ok(()),
+            Self::External {
+                name,
+                base_url,
+                timeout,
+                ..
+            } => {
+                when(
+                    name.is_default_or_empty() || base_url.is_default_or_empty(),
+                    || {
+                        Err(ApplicationError::InvalidConfigurationValueError(
+                        "name and base_url of the address validation provider must not be empty"
+                            .into(),
+                    ))
+                    },
+                )?;
+
+                when(*timeout == 0, || {
+                    Err(ApplicationError::InvalidConfigurationValueError(
+                        "timeout of the address validation provider must be greater than 0".into(),
+                    ))
+                })
+            }
+        }
+    }
+}
+
+impl super::settings::SanctionsScreeningConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        self.payment_thresholds
+            .iter()
+            .try_for_each(|(currency, threshold)| {
+                when(*threshold <= 0, || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "sanctions screening threshold of {currency} must be greater than 0"
+                    )))
+                })
+            })?;
+
+        match &self.provider {
+            super::settings::SanctionsScreeningProvider::Watchlist { .. } => Ok(()),
+            super::settings::SanctionsScreeningProvider::External {
+                name,
+                base_url,
+                timeout,
+                ..
+            } => {
+                when(
+                    name.is_default_or_empty() || base_url.is_default_or_empty(),
+                    || {
+                        Err(ApplicationError::InvalidConfigurationValueError(
+                            "name and base_url of the sanctions screening provider must not be empty"
+                                .into(),
+                        ))
+                    },
+                )?;
+
+                when(*timeout == 0, || {
+                    Err(ApplicationError::InvalidConfigurationValueError(
+                        "timeout of the sanctions screening provider must be greater than 0".into(),
+                    ))
+                })
+            }
+        }
+    }
+}
+
+impl super::settings::RefundSettlementTimes {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        let validate_range = |name: String, range: &super::settings::SettlementTimeRange| {
+            common_utils::fp_utils::when(range.min_days > range.max_days, || {
+                Err(ApplicationError::InvalidConfigurationValueError(format!(
+                    "min_days of the refund settlement time of {name} must not exceed max_days"
+                )))
+            })
+        };
+
+        validate_range("default".to_string(), &self.default)?;
+        self.connectors
+            .iter()
+            .try_for_each(|(connector, connector_settlement_times)| {
+                connector_settlement_times
+                    .default
+                    .as_ref()
+                    .map_or(Ok(()), |range| validate_range(connector.to_string(), range))?;
+                connector_settlement_times
+                    .payment_methods
+                    .iter()
+                    .try_for_each(|(payment_method, range)| {
+                        validate_range(format!("{connector} {payment_method}"), range)
+                    })
+            })
+    }
+}
+
+impl super::settings::AttemptDataRetentionConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.redact_after_days == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "redact_after_days of the attempt data retention must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.batch_size <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "batch_size of the attempt data retention must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.interval <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "interval of the attempt data retention must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::SessionResponseCacheConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.ttl_in_seconds <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "ttl_in_seconds of the session response cache must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::GooglePayGatewayParametersConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        self.gateways.iter().try_for_each(|(connector, gateway)| {
+            when(gateway.is_empty(), || {
+                Err(ApplicationError::InvalidConfigurationValueError(format!(
+                    "Google Pay gateway of {connector} must not be empty"
+                )))
+            })
+        })?;
+
+        when(self.ttl_in_seconds <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "ttl_in_seconds of the Google Pay gateway parameters must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::ConnectorRateLimits {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        self.connectors
+            .iter()
+            .try_for_each(|(connector, rate_limit)| {
+                common_utils::fp_utils::when(
+                    rate_limit.burst == 0 || rate_limit.calls_per_second == 0,
+                    || {
+                        Err(ApplicationError::InvalidConfigurationValueError(format!(
+                            "burst and calls_per_second of {connector} must be greater than 0"
+                        )))
+                    },
+                )
+            })
+    }
+}
+
+impl super::settings::RefundReconciliationConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.get_min_sla_in_hours() == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "SLAs of the refund reconciliation must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.batch_size <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "batch_size of the refund reconciliation must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.interval <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "interval of the refund reconciliation must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl<S: hyperswitch_interfaces::secrets_interface::secret_state::SecretState>
+    super::settings::DataResidencySettings<S>
+{
+    pub fn validate(&self, locker: &super::settings::Locker) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        self.regions.iter().try_for_each(|(region, region_config)| {
+            when(region.trim().is_empty(), || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "data residency region must not be empty".into(),
+                ))
+            })?;
+
+            region_config.master_database.get_inner().validate()?;
+            #[cfg(feature = "olap")]
+            region_config.replica_database.get_inner().validate()?;
+
+            when(
+                !locker.mock_locker && region_config.locker_host.is_default_or_empty(),
+                || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "locker host of the data residency region {region} must not be empty when mock locker is disabled"
+                    )))
+                },
+            )
+        })
+    }
+}
@@ -1,10 +1,15 @@
+use common_utils::pii;
 use diesel_models::enums::Currency;
-use masking::Secret;
+use error_stack::ResultExt;
+use hyperswitch_domain_models::network_tokenization::NetworkTokenNumber;
+use masking::{PeekInterface, Secret};
 use serde::{Deserialize, Serialize};
 use url::Url;
 
 use crate::{
-    connector::utils::RouterData,
+    connector::utils::{
+        self as connector_utils, NetworkTokenData, PaymentsAuthorizeRequestData, RouterData,
+    },
     core::errors,
     services,
     types::{self, api, domain, storage::enums},
@@ -58,13 +63,60 @@ impl From<u8> for DummyConnectors {
     }
 }
 
+/// The simulation configured in the metadata of the merchant connector account, for simulating
+/// the latency and the failures of a real processor
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, Eq, PartialEq)]
+pub struct DummyConnectorSimulation {
+    latency_in_ms: Option<u64>,
+    failure_percentage: Option<u8>,
+}
+
+impl TryFrom<&Option<pii::SecretSerdeValue>> for DummyConnectorSimulation {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(meta_data: &Option<pii::SecretSerdeValue>) -> Result<Self, Self::Error> {
+        let Some(meta_data) = meta_data
+            .clone()
+            .filter(|meta_data| !meta_data.peek().is_null())
+        else {
+            return Ok(Self::default());
+        };
+        let simulation: Self = connector_utils::to_connector_meta_from_secret(Some(meta_data))
+            .change_context(errors::ConnectorError::InvalidConnectorConfig {
+                config: "metadata",
+            })?;
+        if simulation
+            .failure_percentage
+            .is_some_and(|failure_percentage| failure_percentage > 100)
+        {
+            return Err(errors::ConnectorError::InvalidConnectorConfig {
+                config: "metadata.failure_percentage",
+            })
+            .attach_printable("failure_percentage must not be greater than 100");
+        }
+        Ok(simulation)
+    }
+}
+
 #[derive(Debug, Serialize, Eq, PartialEq)]
 pub struct DummyConnectorPaymentsRequest<const T: u8> {
     amount: i64,
     currency: Currency,
     payment_method_data: PaymentMethodData,
     return_url: Option<String>,
+    webhook_url: Option<String>,
     connector: DummyConnectors,
+    setup_mandate: bool,
+    simulation: DummyConnectorSimulation,
+}
+
+/// A payment charging the payment method of a mandate, without the customer
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct DummyConnectorMandatePaymentRequest {
+    amount: i64,
+    currency: Currency,
+    return_url: Option<String>,
+    webhook_url: Option<String>,
+    simulation: DummyConnectorSimulation,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -73,6 +125,26 @@ pub enum PaymentMethodData {
     Card(DummyConnectorCard),
     Wallet(DummyConnectorWallet),
     PayLater(DummyConnectorPayLater),
+    NetworkToken(DummyConnectorNetworkToken),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct DummyConnectorNetworkToken {
+    token_number: NetworkTokenNumber,
+    expiry_month: Secret<String>,
+    expiry_year: Secret<String>,
+    cryptogram: Option<Secret<String>>,
+}
+
+impl From<&domain::NetworkTokenData> for DummyConnectorNetworkToken {
+    fn from(value: &domain::NetworkTokenData) -> Self {
+        Self {
+            token_number: value.get_network_token(),
+            expiry_month: value.get_network_token_expiry_month(),
+            expiry_year: value.get_network_token_expiry_year(),
+            cryptogram: value.get_cryptogram(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -145,40 +217,91 @@ impl TryFrom<domain::payments::PayLaterData> for DummyConnectorPayLater {
     }
 }
 
+fn get_payment_method_data(
+    payment_method_data: &domain::PaymentMethodData,
+    card_holder_name: Option<Secret<String>>,
+) -> Result<PaymentMethodData, error_stack::Report<errors::ConnectorError>> {
+    match payment_method_data {
+        domain::PaymentMethodData::Card(req_card) => Ok(PaymentMethodData::Card(
+            DummyConnectorCard::try_from((req_card.clone(), card_holder_name))?,
+        )),
+        domain::PaymentMethodData::Wallet(wallet_data) => {
+            Ok(PaymentMethodData::Wallet(wallet_data.clone().try_into()?))
+        }
+        domain::PaymentMethodData::PayLater(pay_later_data) => Ok(PaymentMethodData::PayLater(
+            pay_later_data.clone().try_into()?,
+        )),
+        domain::PaymentMethodData::NetworkToken(network_token_data) => Ok(
+            PaymentMethodData::NetworkToken(DummyConnectorNetworkToken::from(network_token_data)),
+        ),
+        _ => Err(errors::ConnectorError::NotImplemented("Payment methods".to_string()).into()),
+    }
+}
+
 impl<const T: u8> TryFrom<&types::PaymentsAuthorizeRouterData>
     for DummyConnectorPaymentsRequest<T>
 {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
-        let payment_method_data: Result<PaymentMethodData, Self::Error> = match item
-            .request
-            .payment_method_data
-        {
-            domain::PaymentMethodData::Card(ref req_card) => {
-                let card_holder_name = item.get_optional_billing_full_name();
-                Ok(PaymentMethodData::Card(DummyConnectorCard::try_from((
-                    req_card.clone(),
-                    card_holder_name,
-                ))?))
-            }
-            domain::PaymentMethodData::Wallet(ref wallet_data) => {
-                Ok(PaymentMethodData::Wallet(wallet_data.clone().try_into()?))
-            }
-            domain::PaymentMethodData::PayLater(ref pay_later_data) => Ok(
-                PaymentMethodData::PayLater(pay_later_data.clone().try_into()?),
-            ),
-            _ => Err(errors::ConnectorError::NotImplemented("Payment methods".to_string()).into()),
-        };
+        let payment_method_data = get_payment_method_data(
+            &item.request.payment_method_data,
+            item.get_optional_billing_full_name(),
+        )?;
         Ok(Self {
             amount: item.request.amount,
             currency: item.request.currency,
-            payment_method_data: payment_method_data?,
+            payment_method_data,
+            return_url: item.request.router_return_url.clone(),
+            webhook_url: item.request.webhook_url.clone(),
+            connector: Into::<DummyConnectors>::into(T),
+            setup_mandate: item.request.is_customer_initiated_mandate_payment(),
+            simulation: DummyConnectorSimulation::try_from(&item.connector_meta_data)?,
+        })
+    }
+}
+
+impl<const T: u8> TryFrom<&types::SetupMandateRouterData> for DummyConnectorPaymentsRequest<T> {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::SetupMandateRouterData) -> Result<Self, Self::Error> {
+        let payment_method_data = get_payment_method_data(
+            &item.request.payment_method_data,
+            item.get_optional_billing_full_name(),
+        )?;
+        Ok(Self {
+            amount: item.request.amount.unwrap_or_default(),
+            currency: item.request.currency,
+            payment_method_data,
             return_url: item.request.router_return_url.clone(),
+            webhook_url: item.request.webhook_url.clone(),
             connector: Into::<DummyConnectors>::into(T),
+            setup_mandate: true,
+            simulation: DummyConnectorSimulation::try_from(&item.connector_meta_data)?,
         })
     }
 }
 
+impl TryFrom<&types::PaymentsAuthorizeRouterData> for DummyConnectorMandatePaymentRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentsAuthorizeRouterData) -> Result<Self, Self::Error> {
+        Ok(Self {
+            amount: item.request.amount,
+            currency: item.request.currency,
+            return_url: item.request.router_return_url.clone(),
+            webhook_url: item.request.webhook_url.clone(),
+            simulation: DummyConnectorSimulation::try_from(&item.connector_meta_data)?,
+        })
+    }
+}
+
+/// Returns the identifier of the mandate at the connector, if the payment charges the payment
+/// method of a mandate
+pub fn get_connector_mandate_id(item: &types::PaymentsAuthorizeRouterData) -> Option<String> {
+    match item.request.payment_method_data {
+        domain::PaymentMethodData::MandatePayment => item.request.connector_mandate_id(),
+        _ => None,
+    }
+}
+
 // Auth Struct
 pub struct DummyConnectorAuthType {
     pub(super) api_key: Secret<String>,
@@ -219,12 +342,14 @@ impl From<DummyConnectorPaymentStatus> for enums::AttemptStatus {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct PaymentsResponse {
     status: DummyConnectorPaymentStatus,
-    id: String,
+    pub(super) id: String,
     amount: i64,
     currency: Currency,
     created: String,
     payment_method_type: PaymentMethodType,
     next_action: Option<DummyConnectorNextAction>,
+    #[serde(default)]
+    mandate_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
@@ -249,12 +374,28 @@ impl<F, T> TryFrom<types::ResponseRouterData<F, PaymentsResponse, T, types::Paym
             .map(|redirection_url| {
                 services::RedirectForm::from((redirection_url, services::Method::Get))
             });
+        // A payment processing without any next action awaits the webhook of its outcome
+        let status = match item.response.status {
+            DummyConnectorPaymentStatus::Processing if redirection_data.is_none() => {
+                enums::AttemptStatus::Pending
+            }
+            status => enums::AttemptStatus::from(status),
+        };
+        let mandate_reference =
+            item.response
+                .mandate_id
+                .map(|mandate_id| types::MandateReference {
+                    connector_mandate_id: Some(mandate_id),
+                    payment_method_id: None,
+                    mandate_metadata: None,
+                    connector_mandate_request_reference_id: None,
+                });
         Ok(Self {
-            status: enums::AttemptStatus::from(item.response.status),
+            status,
             response: Ok(types::PaymentsResponseData::TransactionResponse {
                 resource_id: types::ResponseId::ConnectorTransactionId(item.response.id),
                 redirection_data: Box::new(redirection_data),
-                mandate_reference: Box::new(None),
+                mandate_reference: Box::new(mandate_reference),
                 connector_metadata: None,
                 network_txn_id: None,
                 connector_response_reference_id: None,
@@ -280,11 +421,289 @@ impl DummyConnectorNextAction {
     }
 }
 
+// WEBHOOKS :
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorWebhookEventType {
+    PaymentSucceeded,
+    DisputeOpened,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DummyConnectorDispute {
+    pub id: String,
+    pub amount: i64,
+    pub currency: Currency,
+    pub reason: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: time::PrimitiveDateTime,
+}
+
+// MANDATES :
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorMandateStatus {
+    Pending,
+    Active,
+    Revoked,
+}
+
+impl From<DummyConnectorMandateStatus> for common_enums::MandateStatus {
+    fn from(item: DummyConnectorMandateStatus) -> Self {
+        match item {
+            DummyConnectorMandateStatus::Pending => Self::Pending,
+            DummyConnectorMandateStatus::Active => Self::Active,
+            DummyConnectorMandateStatus::Revoked => Self::Revoked,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DummyConnectorMandateResponse {
+    mandate_id: String,
+    status: DummyConnectorMandateStatus,
+}
+
+impl
+    TryFrom<
+        types::ResponseRouterData<
+            api::MandateRevoke,
+            DummyConnectorMandateResponse,
+            types::MandateRevokeRequestData,
+            types::MandateRevokeResponseData,
+        >,
+    > for types::MandateRevokeRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            api::MandateRevoke,
+            DummyConnectorMandateResponse,
+            types::MandateRevokeRequestData,
+            types::MandateRevokeResponseData,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::MandateRevokeResponseData {
+                mandate_status: common_enums::MandateStatus::from(item.response.status),
+            }),
+            ..item.data
+        })
+    }
+}
+
+// DISPUTES :
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DummyConnectorDisputeStatus {
+    Opened,
+    Accepted,
+    Challenged,
+}
+
+impl From<DummyConnectorDisputeStatus> for enums::DisputeStatus {
+    fn from(item: DummyConnectorDisputeStatus) -> Self {
+        match item {
+            DummyConnectorDisputeStatus::Opened => Self::DisputeOpened,
+            DummyConnectorDisputeStatus::Accepted => Self::DisputeAccepted,
+            DummyConnectorDisputeStatus::Challenged => Self::DisputeChallenged,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DummyConnectorDisputeResponse {
+    id: String,
+    status: DummyConnectorDisputeStatus,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DummyConnectorDisputeEvidenceRequest {
+    product_description: Option<String>,
+    shipping_tracking_number: Option<String>,
+    uncategorized_text: Option<String>,
+}
+
+impl From<&types::SubmitEvidenceRouterData> for DummyConnectorDisputeEvidenceRequest {
+    fn from(item: &types::SubmitEvidenceRouterData) -> Self {
+        Self {
+            product_description: item.request.product_description.clone(),
+            shipping_tracking_number: item.request.shipping_tracking_number.clone(),
+            uncategorized_text: item.request.uncategorized_text.clone(),
+        }
+    }
+}
+
+impl
+    TryFrom<
+        types::ResponseRouterData<
+            api::Accept,
+            DummyConnectorDisputeResponse,
+            types::AcceptDisputeRequestData,
+            types::AcceptDisputeResponse,
+        >,
+    > for types::AcceptDisputeRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            api::Accept,
+            DummyConnectorDisputeResponse,
+            types::AcceptDisputeRequestData,
+            types::AcceptDisputeResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::AcceptDisputeResponse {
+                connector_status: Some(item.response.status.to_string()),
+                dispute_status: enums::DisputeStatus::from(item.response.status),
+            }),
+            ..item.data
+        })
+    }
+}
+
+impl
+    TryFrom<
+        types::ResponseRouterData<
+            api::Evidence,
+            DummyConnectorDisputeResponse,
+            types::SubmitEvidenceRequestData,
+            types::SubmitEvidenceResponse,
+        >,
+    > for types::SubmitEvidenceRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            api::Evidence,
+            DummyConnectorDisputeResponse,
+            types::SubmitEvidenceRequestData,
+            types::SubmitEvidenceResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::SubmitEvidenceResponse {
+                connector_status: Some(item.response.status.to_string()),
+                dispute_status: enums::DisputeStatus::from(item.response.status),
+            }),
+            ..item.data
+        })
+    }
+}
+
+// PAYOUTS :
+#[cfg(feature = "payouts")]
+#[derive(Debug, Serialize)]
+pub struct DummyConnectorPayoutCard {
+    number: cards::CardNumber,
+    expiry_month: Secret<String>,
+    expiry_year: Secret<String>,
+    name: Option<Secret<String>>,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DummyConnectorPayoutMethodData {
+    Card(DummyConnectorPayoutCard),
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Serialize)]
+pub struct DummyConnectorPayoutRequest<const T: u8> {
+    amount: i64,
+    currency: Currency,
+    payout_method_data: DummyConnectorPayoutMethodData,
+    connector: DummyConnectors,
+    simulation: DummyConnectorSimulation,
+}
+
+#[cfg(feature = "payouts")]
+impl<F, const T: u8> TryFrom<&types::PayoutsRouterData<F>> for DummyConnectorPayoutRequest<T> {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PayoutsRouterData<F>) -> Result<Self, Self::Error> {
+        let payout_method_data = match item.get_payout_method_data()? {
+            api::PayoutMethodData::Card(card) => {
+                DummyConnectorPayoutMethodData::Card(DummyConnectorPayoutCard {
+                    number: card.card_number,
+                    expiry_month: card.expiry_month,
+                    expiry_year: card.expiry_year,
+                    name: card.card_holder_name,
+                })
+            }
+            api::PayoutMethodData::Bank(_) | api::PayoutMethodData::Wallet(_) => {
+                Err(errors::ConnectorError::NotSupported {
+                    message: "Bank and wallet payouts".to_string(),
+                    connector: Into::<DummyConnectors>::into(T).get_dummy_connector_id(),
+                })?
+            }
+        };
+        Ok(Self {
+            amount: item.request.amount,
+            currency: item.request.destination_currency,
+            payout_method_data,
+            connector: Into::<DummyConnectors>::into(T),
+            simulation: DummyConnectorSimulation::try_from(&item.connector_meta_data)?,
+        })
+    }
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DummyConnectorPayoutResponse {
+    id: String,
+    status: DummyConnectorPaymentStatus,
+    error_message: Option<String>,
+}
+
+#[cfg(feature = "payouts")]
+impl From<DummyConnectorPaymentStatus> for enums::PayoutStatus {
+    fn from(item: DummyConnectorPaymentStatus) -> Self {
+        match item {
+            DummyConnectorPaymentStatus::Succeeded => Self::Success,
+            DummyConnectorPaymentStatus::Failed => Self::Failed,
+            DummyConnectorPaymentStatus::Processing => Self::Pending,
+        }
+    }
+}
+
+#[cfg(feature = "payouts")]
+impl<F> TryFrom<types::PayoutsResponseRouterData<F, DummyConnectorPayoutResponse>>
+    for types::PayoutsRouterData<F>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::PayoutsResponseRouterData<F, DummyConnectorPayoutResponse>,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            response: Ok(types::PayoutsResponseData {
+                status: Some(enums::PayoutStatus::from(item.response.status)),
+                connector_payout_id: Some(item.response.id),
+                payout_eligible: None,
+                should_add_next_step_to_process_tracker: false,
+                error_code: None,
+                error_message: item.response.error_message,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DummyConnectorWebhookBody {
+    pub event_type: DummyConnectorWebhookEventType,
+    pub payment: PaymentsResponse,
+    pub dispute: Option<DummyConnectorDispute>,
+}
+
 // REFUND :
 // Type definition for RefundRequest
 #[derive(Default, Debug, Serialize)]
 pub struct DummyConnectorRefundRequest {
     pub amount: i64,
+    pub simulation: DummyConnectorSimulation,
 }
 
 impl<F> TryFrom<&types::RefundsRouterData<F>> for DummyConnectorRefundRequest {
@@ -292,6 +711,7 @@ impl<F> TryFrom<&types::RefundsRouterData<F>> for DummyConnectorRefundRequest {
     fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
         Ok(Self {
             amount: item.request.refund_amount,
+            simulation: DummyConnectorSimulation::try_from(&item.connector_meta_data)?,
         })
     }
 }
@@ -952,6 +952,13 @@ impl
         let mut api_key = self.get_auth_header(&req.connector_auth_type)?;
         header.append(&mut api_key);
 
+        if let Some(idempotency_key) = req.request.connector_idempotency_key.clone() {
+            header.push((
+                headers::IDEMPOTENCY_KEY.to_string(),
+                idempotency_key.into_masked(),
+            ));
+        }
+
         if let Some(common_types::payments::SplitPaymentsRequest::StripeSplitPayment(
             stripe_split_payment,
         )) = &req.request.split_payments
@@ -2460,6 +2467,26 @@ impl api::IncomingWebhook for Stripe {
 
         Ok(Box::new(details.event_data.event_object))
     }
+
+    fn get_webhook_event_timestamp(
+        &self,
+        request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<time::PrimitiveDateTime>, errors::ConnectorError> {
+        let details: stripe::WebhookEventTimestampBody = request
+            .body
+            .parse_struct("WebhookEventTimestampBody")
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)?;
+
+        let created_at = time::OffsetDateTime::from_unix_timestamp(details.created)
+            .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)
+            .attach_printable("Invalid timestamp of the webhook event")?;
+
+        Ok(Some(time::PrimitiveDateTime::new(
+            created_at.date(),
+            created_at.time(),
+        )))
+    }
+
     fn get_dispute_details(
         &self,
         request: &api::IncomingWebhookRequestDetails<'_>,
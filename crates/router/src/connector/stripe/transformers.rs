@@ -3136,6 +3136,31 @@ pub struct RefundResponse {
     pub payment_intent: String,
     pub status: RefundStatus,
     pub failure_reason: Option<String>,
+    pub destination_details: Option<StripeRefundDestinationDetails>,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct StripeRefundDestinationDetails {
+    pub card: Option<StripeRefundCardDestinationDetails>,
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct StripeRefundCardDestinationDetails {
+    pub reference: Option<String>,
+    pub reference_type: Option<String>,
+}
+
+impl RefundResponse {
+    /// Stripe sends the reference number of the acquirer of card refunds once the refund is
+    /// submitted to the card network
+    fn get_connector_response(&self) -> Option<types::ConnectorResponseData> {
+        self.destination_details
+            .as_ref()
+            .and_then(|destination_details| destination_details.card.as_ref())
+            .filter(|card| card.reference_type.as_deref() == Some("acquirer_reference_number"))
+            .and_then(|card| card.reference.clone())
+            .map(types::ConnectorResponseData::with_acquirer_reference_number)
+    }
 }
 
 impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
@@ -3146,6 +3171,10 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
         item: types::RefundsResponseRouterData<api::Execute, RefundResponse>,
     ) -> Result<Self, Self::Error> {
         let refund_status = enums::RefundStatus::from(item.response.status);
+        let connector_response = item
+            .response
+            .get_connector_response()
+            .or(item.data.connector_response.clone());
         let response = if connector_util::is_refund_failure(refund_status) {
             Err(types::ErrorResponse {
                 code: consts::NO_ERROR_CODE.to_string(),
@@ -3170,6 +3199,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::Execute, RefundResponse>>
 
         Ok(Self {
             response,
+            connector_response,
             ..item.data
         })
     }
@@ -3183,6 +3213,10 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
         item: types::RefundsResponseRouterData<api::RSync, RefundResponse>,
     ) -> Result<Self, Self::Error> {
         let refund_status = enums::RefundStatus::from(item.response.status);
+        let connector_response = item
+            .response
+            .get_connector_response()
+            .or(item.data.connector_response.clone());
         let response = if connector_util::is_refund_failure(refund_status) {
             Err(types::ErrorResponse {
                 code: consts::NO_ERROR_CODE.to_string(),
@@ -3207,6 +3241,7 @@ impl TryFrom<types::RefundsResponseRouterData<api::RSync, RefundResponse>>
 
         Ok(Self {
             response,
+            connector_response,
             ..item.data
         })
     }
@@ -3666,6 +3701,12 @@ pub struct WebhookEvent {
     pub event_data: WebhookEventData,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct WebhookEventTimestampBody {
+    /// Time at which the event was created, measured in seconds since the Unix epoch
+    pub created: i64,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct WebhookEventTypeBody {
     #[serde(rename = "type")]
@@ -167,13 +167,10 @@ where
         F: Clone,
     {
         match self.status {
-            enums::AttemptStatus::Voided => {
-                if payment_data.payment_intent.amount_captured > Some(MinorUnit::new(0)) {
-                    enums::AttemptStatus::PartialCharged
-                } else {
-                    self.status
-                }
-            }
+            enums::AttemptStatus::Voided => get_voided_attempt_status(
+                payment_data.payment_intent.status,
+                payment_data.payment_intent.amount_captured,
+            ),
             enums::AttemptStatus::Charged => {
                 let captured_amount =
                     types::Capturable::get_captured_amount(&self.request, payment_data);
@@ -191,6 +188,21 @@ where
     }
 }
 
+/// The status of a voided attempt. Voiding a partially captured payment, as when the uncaptured
+/// remainder of its authorization is released, leaves it partially captured rather than cancelled.
+fn get_voided_attempt_status(
+    intent_status: enums::IntentStatus,
+    amount_captured: Option<MinorUnit>,
+) -> enums::AttemptStatus {
+    if intent_status == enums::IntentStatus::PartiallyCapturedAndCapturable
+        || amount_captured > Some(MinorUnit::new(0))
+    {
+        enums::AttemptStatus::PartialCharged
+    } else {
+        enums::AttemptStatus::Voided
+    }
+}
+
 pub const SELECTED_PAYMENT_METHOD: &str = "Selected payment method";
 
 pub fn get_unimplemented_payment_method_error_message(connector: &str) -> String {
@@ -2640,6 +2652,34 @@ pub fn get_card_details(
     }
 }
 
+#[cfg(test)]
+mod voided_attempt_status_tests {
+    use super::*;
+
+    #[test]
+    fn test_void_of_partially_captured_payment_stays_partially_captured() {
+        let attempt_status = get_voided_attempt_status(
+            enums::IntentStatus::PartiallyCapturedAndCapturable,
+            Some(MinorUnit::new(600)),
+        );
+        assert_eq!(attempt_status, enums::AttemptStatus::PartialCharged);
+        assert_eq!(
+            enums::IntentStatus::foreign_from(attempt_status),
+            enums::IntentStatus::PartiallyCaptured
+        );
+    }
+
+    #[test]
+    fn test_void_of_uncaptured_payment_is_cancelled() {
+        let attempt_status = get_voided_attempt_status(enums::IntentStatus::RequiresCapture, None);
+        assert_eq!(attempt_status, enums::AttemptStatus::Voided);
+        assert_eq!(
+            enums::IntentStatus::foreign_from(attempt_status),
+            enums::IntentStatus::Cancelled
+        );
+    }
+}
+
 #[cfg(test)]
 mod error_code_error_message_tests {
     #![allow(clippy::unwrap_used)]
@@ -54,6 +54,47 @@ pub(crate) const MERCHANT_ID_FIELD_EXTENSION_ID: &str = "1.2.840.113635.100.6.32
 pub(crate) const METRICS_HOST_TAG_NAME: &str = "host";
 pub const MAX_ROUTING_CONFIGS_PER_MERCHANT: usize = 100;
 pub const ROUTING_CONFIG_ID_LENGTH: usize = 10;
+/// Version of the format of the exported routing algorithms
+pub const ROUTING_CONFIG_EXPORT_VERSION: u16 = 1;
+/// Default number of resolved attempts each arm of a routing experiment should have before it is
+/// evaluated
+pub const DEFAULT_ROUTING_EXPERIMENT_MIN_SAMPLE_SIZE: u32 = 100;
+/// Interval between the evaluations of a running routing experiment
+pub const ROUTING_EXPERIMENT_EVALUATION_INTERVAL_IN_SECS: i64 = 60 * 60; // 1 hour
+/// Time for which the arm of a routing experiment an attempt was routed to is remembered, for the
+/// outcome of the attempt to be counted against the arm
+pub const ROUTING_EXPERIMENT_ASSIGNMENT_TTL_IN_SECS: i64 = 7 * 24 * 60 * 60; // 7 days
+/// Time for which the outcomes counted for a routing experiment are retained after the last one,
+/// which outlives the evaluation of the experiment once no attempt is routed to it anymore
+pub const ROUTING_EXPERIMENT_RESULTS_TTL_IN_SECS: i64 = 90 * 24 * 60 * 60; // 90 days
+
+/// Time for which the daily volumes of the connectors are retained, which is longer than a day for
+/// the volumes to be retained till the end of the day they are counted for
+pub const CONNECTOR_VOLUME_TTL_IN_SECS: i64 = 2 * 24 * 60 * 60; // 2 days
+
+/// Time for which the hourly deliveries of the outgoing webhooks to an endpoint are retained, which
+/// covers the longest window over which the health of an endpoint can be retrieved
+pub const WEBHOOK_ENDPOINT_HEALTH_TTL_IN_SECS: i64 = 8 * 24 * 60 * 60; // 8 days
+
+/// Time for which the failures and the last deliveries of the outgoing webhooks to an endpoint are
+/// retained after the last delivery attempt
+pub const WEBHOOK_ENDPOINT_STATE_TTL_IN_SECS: i64 = 30 * 24 * 60 * 60; // 30 days
+
+/// Number of consecutive failed deliveries of the outgoing webhooks to an endpoint after which the
+/// delivery to the endpoint is paused, provided it has been failing for long enough
+pub const WEBHOOK_ENDPOINT_PAUSE_CONSECUTIVE_FAILURES: u64 = 50;
+
+/// Time for which the deliveries of the outgoing webhooks to an endpoint must have been failing for
+/// the delivery to the endpoint to be paused
+pub const WEBHOOK_ENDPOINT_PAUSE_FAILING_DURATION_IN_SECS: i64 = 24 * 60 * 60; // 1 day
+
+/// Time after which the held delivery of an outgoing webhook to a paused endpoint is checked again,
+/// for the webhook to be delivered even if it is missed when the delivery is resumed
+pub const WEBHOOK_ENDPOINT_PAUSED_TASK_RECHECK_IN_SECS: i64 = 24 * 60 * 60; // 1 day
+
+/// Number of the held deliveries of the outgoing webhooks fetched at a time when the delivery to
+/// their endpoint is resumed
+pub const WEBHOOK_ENDPOINT_PAUSED_TASKS_BATCH_SIZE: i64 = 100;
 
 pub const LOCKER_REDIS_PREFIX: &str = "LOCKER_PM_TOKEN";
 pub const LOCKER_REDIS_EXPIRY_SECONDS: u32 = 60 * 15; // 15 minutes
@@ -80,6 +121,7 @@ pub const EMAIL_SUBJECT_API_KEY_EXPIRY: &str = "API Key Expiry Notice";
 pub const EMAIL_SUBJECT_DASHBOARD_FEATURE_REQUEST: &str = "Dashboard Pro Feature Request by";
 pub const EMAIL_SUBJECT_APPROVAL_RECON_REQUEST: &str =
     "Approval of Recon Request - Access Granted to Recon Dashboard";
+pub const EMAIL_SUBJECT_WEBHOOK_ENDPOINT_PAUSED: &str = "Webhook Delivery Paused";
 
 pub const ROLE_INFO_CACHE_PREFIX: &str = "CR_INFO_";
 
@@ -97,6 +139,42 @@ pub const VERIFY_CONNECTOR_MERCHANT_ID: &str = "test_merchant";
 #[cfg(feature = "olap")]
 pub const CONNECTOR_ONBOARDING_CONFIG_PREFIX: &str = "onboarding";
 
+/// Prefix of the redis keys of the OAuth authorizations waiting for the consent of the merchant
+#[cfg(feature = "olap")]
+pub const CONNECTOR_OAUTH_STATE_PREFIX: &str = "connector_oauth_state";
+
+#[cfg(feature = "olap")]
+pub const CONNECTOR_OAUTH_STATE_LENGTH: usize = 32;
+
+/// Time within which the merchant has to give the consent on the OAuth consent page
+#[cfg(feature = "olap")]
+pub const CONNECTOR_OAUTH_STATE_TTL_IN_SECONDS: i64 = 10 * 60; // 10 minutes
+
+#[cfg(feature = "olap")]
+pub const CONNECTOR_OAUTH_TOKEN_CONFIG_PREFIX: &str = "connector_oauth_token";
+
+/// Time before the expiry of the access token of a connector account at which it is refreshed
+#[cfg(feature = "olap")]
+pub const CONNECTOR_OAUTH_TOKEN_REFRESH_BUFFER_IN_SECONDS: i64 = 5 * 60; // 5 minutes
+
+/// Time after which a failed refresh of the access token of a connector account is retried
+pub const CONNECTOR_OAUTH_TOKEN_REFRESH_RETRY_IN_SECONDS: i64 = 60;
+
+/// Number of days before the expiry of the credentials of a connector account within which they
+/// are reported as expiring, and a reminder webhook is sent to rotate them
+pub const CONNECTOR_CREDENTIALS_EXPIRY_REMINDER_DAYS: i64 = 14;
+
+/// Maximum number of days within which the expiring credentials of the connector accounts can be
+/// listed
+pub const MAX_CONNECTOR_CREDENTIALS_EXPIRING_WITHIN_DAYS: u32 = 365;
+
+/// Time after which a failed check of the expiry of the credentials of a connector account is
+/// retried
+pub const CONNECTOR_CREDENTIALS_EXPIRY_RETRY_IN_SECONDS: i64 = 60 * 60; // 1 hour
+
+/// Number of days after the deletion of a connector account within which it can be restored
+pub const MERCHANT_CONNECTOR_ACCOUNT_RESTORE_WINDOW_IN_DAYS: i64 = 30;
+
 /// Max payment session expiry
 pub const MAX_SESSION_EXPIRY: u32 = 7890000;
 
@@ -232,3 +310,324 @@ pub(crate) const SENDER_ID: &[u8] = b"Google";
 
 /// Default value for the number of attempts to retry fetching forex rates
 pub const DEFAULT_ANALYTICS_FOREX_RETRY_ATTEMPTS: u64 = 3;
+
+/// Maximum length of the nickname of a saved payment method
+pub const MAX_PAYMENT_METHOD_NICKNAME_LENGTH: usize = 64;
+
+/// Maximum number of default payment method changes retained per customer
+pub const MAX_DEFAULT_PAYMENT_METHOD_CHANGE_HISTORY: usize = 50;
+
+/// Maximum number of records accepted in a single external token migration request
+pub const MAX_EXTERNAL_TOKEN_MIGRATION_RECORDS: usize = 100;
+
+/// Time for which the CVV re-collected for a saved card payment is held in redis
+pub const CVV_TOKEN_EXPIRY_SECONDS: i64 = 5 * 60; // 5 minutes
+
+/// Time for which the network token of a saved card is not used for authorizations, after the
+/// tokenization service failed to provision a cryptogram for it
+pub const NETWORK_TOKEN_SUSPENSION_SECONDS: i64 = 60 * 60; // 1 hour
+
+/// Delay after which the vaulting of a card deferred due to the locker being unreachable is
+/// retried. The card details are held in the temporary locker for
+/// `LOCKER_REDIS_EXPIRY_SECONDS`, so the retries have to be completed within that time.
+pub const DEFERRED_VAULTING_RETRY_INTERVAL_SECONDS: i64 = 2 * 60; // 2 minutes
+
+/// Maximum number of attempts made to vault a card whose vaulting was deferred
+pub const DEFERRED_VAULTING_MAX_RETRIES: i32 = 6;
+
+/// Time for which the timeline of a payment is retained in redis after its latest event
+pub const PAYMENT_TIMELINE_EXPIRY_SECONDS: i64 = 30 * 24 * 60 * 60; // 30 days
+
+/// Maximum number of events returned in the timeline of a payment
+pub const MAX_PAYMENT_TIMELINE_EVENTS: i64 = 500;
+
+/// Maximum duration by which the sandbox test clock of a merchant can be ahead of the actual time
+pub const MAX_TEST_CLOCK_OFFSET_SECONDS: i64 = 5 * 365 * 24 * 60 * 60; // 5 years
+
+/// Maximum number of scheduled tasks looked up for rescheduling while advancing a test clock
+pub const MAX_TEST_CLOCK_TASKS_LOOKUP: i64 = 10_000;
+
+/// Time for which the timestamp of the latest connector webhook applied to a payment or a refund
+/// is retained, to discard the webhooks which are delivered after a newer one
+pub const WEBHOOK_LAST_EVENT_TIME_EXPIRY_SECONDS: i64 = 7 * 24 * 60 * 60; // 7 days
+
+/// Time for which the refund webhooks received before the capture of their payment are buffered
+pub const BUFFERED_REFUND_WEBHOOKS_EXPIRY_SECONDS: i64 = 24 * 60 * 60; // 1 day
+
+/// Maximum number of refund webhooks buffered for a payment
+pub const MAX_BUFFERED_REFUND_WEBHOOKS: usize = 50;
+
+/// Default number of scheduler tasks listed by the process tracker API
+pub const DEFAULT_SCHEDULER_TASKS_LIST_LIMIT: u32 = 100;
+
+/// Maximum number of scheduler tasks listed by the process tracker API at a time
+pub const MAX_SCHEDULER_TASKS_LIST_LIMIT: u32 = 1000;
+
+/// Time after which a 3DS challenge which has not been completed by the payer is considered to be
+/// abandoned
+pub const AUTHENTICATION_CHALLENGE_ABANDONMENT_TIMEOUT_SECONDS: i64 = 10 * 60; // 10 minutes
+
+/// Maximum number of refunds which can be initiated with a single refund batch
+pub const MAX_REFUND_BATCH_SIZE: usize = 1000;
+
+/// Number of rows of a refund batch stored together, and processed by the scheduler in a single run
+/// of the task
+pub const REFUND_BATCH_CHUNK_SIZE: usize = 50;
+
+/// Time after which a failed run of the task of a refund batch is retried
+pub const REFUND_BATCH_RETRY_IN_SECONDS: i64 = 60;
+
+/// Maximum number of times a failed run of the task of a refund batch is retried
+pub const REFUND_BATCH_MAX_RETRIES: i32 = 5;
+
+/// Maximum time by which the timestamp of an SDK event may be ahead of the server, to allow for
+/// the clock skew of the client
+pub const SDK_EVENTS_MAX_CLOCK_SKEW_SECONDS: i64 = 5 * 60; // 5 minutes
+
+/// Length of the window over which the SDK events of a merchant are counted against its quota
+pub const SDK_EVENTS_QUOTA_WINDOW_SECONDS: i64 = 60;
+
+/// Age after which the cached payment summary of a merchant is refreshed in the background
+pub const ANALYTICS_PAYMENT_SUMMARY_REFRESH_INTERVAL_SECONDS: i64 = 5 * 60; // 5 minutes
+
+/// Time for which the payment summary of a merchant is cached, after which it is computed again
+/// on the next request
+pub const ANALYTICS_PAYMENT_SUMMARY_CACHE_TTL_SECONDS: i64 = 24 * 60 * 60; // 1 day
+
+/// Time for which the refresh of the payment summary of a merchant is locked, so that only one
+/// refresh is run at a time
+pub const ANALYTICS_PAYMENT_SUMMARY_REFRESH_LOCK_TTL_SECONDS: i64 = 60;
+
+/// Maximum number of searches of payments by customer contact details a merchant can make in a
+/// window
+pub const PAYMENTS_CONTACT_SEARCH_QUOTA: i64 = 30;
+
+/// Length of the window over which the searches of payments by customer contact details of a
+/// merchant are counted against its quota
+pub const PAYMENTS_CONTACT_SEARCH_QUOTA_WINDOW_SECONDS: i64 = 60;
+
+/// Default number of payments returned by the search of payments by customer contact details
+pub const DEFAULT_PAYMENTS_CONTACT_SEARCH_LIMIT: u32 = 20;
+
+/// Maximum number of payments returned by the search of payments by customer contact details
+pub const MAX_PAYMENTS_CONTACT_SEARCH_LIMIT: u32 = 100;
+
+/// Default number of payments returned by a poll of the payments whose status changed
+pub const DEFAULT_PAYMENTS_CHANGES_LIMIT: u32 = 100;
+
+/// Maximum number of payments returned by a poll of the payments whose status changed
+pub const MAX_PAYMENTS_CHANGES_LIMIT: u32 = 1000;
+
+/// Time for which a status change of a payment is held back from the polls, so that the changes
+/// assigned an earlier sequence by transactions that commit later are not skipped by the cursor
+pub const PAYMENTS_CHANGES_SETTLE_TIME_IN_SECONDS: i64 = 5;
+
+/// Length of the idempotency keys sent to the connectors, excluding the prefix. The keys are kept
+/// at 25 characters with the prefix, as required by Helcim.
+pub const CONNECTOR_IDEMPOTENCY_KEY_LENGTH: usize = 22;
+
+/// Minimum number of 3DS authentications of a card BIN to be seen before the rate of its
+/// frictionless authentications is made available to the 3DS decision rules
+pub const MIN_ISSUER_AUTHENTICATION_SAMPLE_SIZE: i64 = 20;
+
+/// Maximum number of acquirer reference numbers which can be imported with a single request
+pub const MAX_ACQUIRER_REFERENCE_NUMBER_IMPORT_SIZE: usize = 1000;
+
+/// Time for which the signed links to the receipts of the payments are valid
+pub const RECEIPT_URL_VALIDITY_IN_SECONDS: i64 = 30 * 24 * 60 * 60; // 30 days
+
+/// Label of the key, derived from the key of the merchant, with which the links to the receipts
+/// are signed
+pub const RECEIPT_KEY_LABEL: &[u8] = b"receipt";
+
+/// Label of the key, derived from the key of the merchant, with which the contact details of the
+/// customers are hashed for the search of the payments
+pub const CONTACT_SEARCH_KEY_LABEL: &[u8] = b"contact_search";
+
+/// Maximum number of versions of the theme of the hosted checkout page kept for a profile, the
+/// oldest ones are dropped beyond it
+pub const MAX_CHECKOUT_THEME_VERSIONS: usize = 20;
+
+/// Interval after which the offboarding of a merchant checks again for the payouts in flight with
+/// the connectors
+pub const MERCHANT_OFFBOARDING_PAYOUTS_POLL_INTERVAL_IN_SECONDS: i64 = 60 * 60; // 1 hour
+
+/// Number of payouts cancelled by the offboarding of a merchant in a single run of the task
+pub const MERCHANT_OFFBOARDING_PAYOUTS_CHUNK_SIZE: u32 = 100;
+
+/// Number of records exported to a single file while exporting the data of an offboarded merchant
+pub const MERCHANT_OFFBOARDING_EXPORT_PAGE_SIZE: i64 = 1000;
+
+/// Time after which a failed run of the offboarding of a merchant is retried
+pub const MERCHANT_OFFBOARDING_RETRY_IN_SECONDS: i64 = 5 * 60; // 5 minutes
+
+/// Maximum number of times a failed run of the offboarding of a merchant is retried
+pub const MERCHANT_OFFBOARDING_MAX_RETRIES: i32 = 5;
+
+/// Number of records of a table sent to the data warehouse of a merchant in a single request
+pub const WAREHOUSE_SYNC_BATCH_SIZE: i64 = 500;
+
+/// Maximum number of batches of records of a table synced to the data warehouse of a merchant in a
+/// single run of the task, the remaining records are synced in the next run
+pub const WAREHOUSE_SYNC_MAX_BATCHES_PER_RUN: usize = 20;
+
+/// Time for which a modification of a record is held back from the sync to the data warehouse of a
+/// merchant, so that the records modified earlier by transactions that commit later are not skipped
+/// by the high-water mark
+pub const WAREHOUSE_SYNC_SETTLE_TIME_IN_SECONDS: i64 = 60;
+
+/// Default interval between the syncs of the records of a merchant to its data warehouse
+pub const DEFAULT_WAREHOUSE_SYNC_INTERVAL_IN_MINUTES: u32 = 60;
+
+/// Minimum interval between the syncs of the records of a merchant to its data warehouse
+pub const MIN_WAREHOUSE_SYNC_INTERVAL_IN_MINUTES: u32 = 15;
+
+/// Maximum interval between the syncs of the records of a merchant to its data warehouse
+pub const MAX_WAREHOUSE_SYNC_INTERVAL_IN_MINUTES: u32 = 24 * 60; // 1 day
+
+/// Maximum time for which a retrieve of a payment with a consistency token waits for the version
+/// of the payment in the token to be visible
+pub const PAYMENT_CONSISTENCY_MAX_WAIT_IN_MILLISECONDS: u64 = 2000;
+
+/// Interval at which a retrieve of a payment with a consistency token reads the payment again
+/// while waiting for the version of the payment in the token
+pub const PAYMENT_CONSISTENCY_POLL_INTERVAL_IN_MILLISECONDS: u64 = 100;
+
+/// Time after which a failed scheduled capture of a payment is retried
+pub const SCHEDULED_CAPTURE_RETRY_IN_SECONDS: i64 = 15 * 60; // 15 minutes
+
+/// Maximum number of times a failed scheduled capture of a payment is retried
+pub const SCHEDULED_CAPTURE_MAX_RETRIES: i32 = 3;
+
+/// Time after which a failed redaction pass of the payment attempts is retried
+pub const ATTEMPT_REDACTION_RETRY_IN_SECONDS: i64 = 60 * 60; // 1 hour
+
+/// Maximum number of batches of payment attempts redacted in a single run of the redaction task,
+/// the task being scheduled again right away to continue the pass
+pub const ATTEMPT_REDACTION_MAX_BATCHES_PER_RUN: u32 = 20;
+
+/// Time after which a failed reconciliation pass of the pending refunds is retried
+pub const REFUND_RECONCILIATION_RETRY_IN_SECONDS: i64 = 15 * 60; // 15 minutes
+
+/// Maximum number of business profiles to which a bulk configuration of merchant connector
+/// accounts can be applied at once
+pub const MAX_MERCHANT_CONNECTOR_BULK_CONFIG_PROFILES: usize = 100;
+
+/// Number of payments fetched at a time while generating an SCA compliance report
+pub const SCA_COMPLIANCE_REPORT_PAGE_SIZE: u32 = 1000;
+
+/// Time after which the failed generation of an SCA compliance report is retried
+pub const SCA_COMPLIANCE_REPORT_RETRY_IN_SECONDS: i64 = 10 * 60; // 10 minutes
+
+/// Maximum number of times the failed generation of an SCA compliance report is retried
+pub const SCA_COMPLIANCE_REPORT_MAX_RETRIES: i32 = 3;
+
+/// Countries of the European Economic Area and the United Kingdom, as named in the issuing country
+/// of the cards, whose card payments are subject to the strong customer authentication of PSD2
+pub const SCA_CARD_ISSUING_COUNTRIES: [&str; 32] = [
+    "AUSTRIA",
+    "BELGIUM",
+    "BULGARIA",
+    "CROATIA",
+    "CYPRUS",
+    "CZECHIA",
+    "CZECHREPUBLIC",
+    "DENMARK",
+    "ESTONIA",
+    "FINLAND",
+    "FRANCE",
+    "GERMANY",
+    "GREECE",
+    "HUNGARY",
+    "ICELAND",
+    "IRELAND",
+    "ITALY",
+    "LATVIA",
+    "LIECHTENSTEIN",
+    "LITHUANIA",
+    "LUXEMBOURG",
+    "MALTA",
+    "NETHERLANDS",
+    "NORWAY",
+    "POLAND",
+    "PORTUGAL",
+    "ROMANIA",
+    "SLOVAKIA",
+    "SLOVENIA",
+    "SPAIN",
+    "SWEDEN",
+    "UNITEDKINGDOM",
+];
+
+/// Maximum number of the most recent payments of a customer over which the statistics of their
+/// payment history are computed
+pub const CUSTOMER_PAYMENT_STATS_MAX_PAYMENTS: u32 = 1000;
+
+/// Time for which a payment stepped up to 3DS is remembered, for its external 3DS authentication to
+/// force a challenge
+pub const STEP_UP_AUTHENTICATION_TTL_IN_SECS: i64 = 24 * 60 * 60; // 1 day
+
+/// Maximum duration of the time range of a backfill of connector transaction IDs
+pub const CONNECTOR_TRANSACTION_ID_BACKFILL_MAX_RANGE_IN_DAYS: i64 = 90;
+
+/// Default number of payments synced with the connectors per minute by a backfill of connector
+/// transaction IDs
+pub const CONNECTOR_TRANSACTION_ID_BACKFILL_DEFAULT_REQUESTS_PER_MINUTE: u32 = 60;
+
+/// Maximum number of payments synced with the connectors per minute by a backfill of connector
+/// transaction IDs
+pub const CONNECTOR_TRANSACTION_ID_BACKFILL_MAX_REQUESTS_PER_MINUTE: u32 = 600;
+
+/// Number of payments fetched at once while scanning the time range of a backfill of connector
+/// transaction IDs
+pub const CONNECTOR_TRANSACTION_ID_BACKFILL_PAGE_SIZE: u32 = 100;
+
+/// Maximum number of pages of payments scanned by the scheduler in a single run of a backfill of
+/// connector transaction IDs
+pub const CONNECTOR_TRANSACTION_ID_BACKFILL_MAX_PAGES_PER_RUN: u32 = 20;
+
+/// Maximum number of the failed syncs of a backfill of connector transaction IDs which are kept
+pub const CONNECTOR_TRANSACTION_ID_BACKFILL_MAX_FAILURES: usize = 100;
+
+/// Time after which a failed run of a backfill of connector transaction IDs is retried
+pub const CONNECTOR_TRANSACTION_ID_BACKFILL_RETRY_IN_SECONDS: i64 = 5 * 60; // 5 minutes
+
+/// Maximum number of times a failed run of a backfill of connector transaction IDs is retried
+pub const CONNECTOR_TRANSACTION_ID_BACKFILL_MAX_RETRIES: i32 = 3;
+
+/// Number of rows fetched at once by a migration of the personal data of a merchant to its data
+/// residency region
+pub const DATA_RESIDENCY_MIGRATION_BATCH_SIZE: i64 = 100;
+
+/// Maximum number of batches of rows migrated by the scheduler in a single run of a migration of
+/// the personal data of a merchant to its data residency region
+pub const DATA_RESIDENCY_MIGRATION_MAX_BATCHES_PER_RUN: u32 = 20;
+
+/// Number of rows and locker entries copied by a pass of a migration to a data residency region
+/// under which the personal data of the merchant is switched over to the region, the final pass
+/// copying what was updated in the meantime
+pub const DATA_RESIDENCY_SWITCHOVER_COPIED_COUNT: u64 = 100;
+
+/// Number of passes of a migration to a data residency region after which the personal data of the
+/// merchant is switched over to the region, however much the last pass copied
+pub const DATA_RESIDENCY_MIGRATION_MAX_PASSES: u32 = 10;
+
+/// Time for which the final pass of a migration to a data residency region is delayed, for all the
+/// instances of the application to read and write the personal data of the merchant in the region
+pub const DATA_RESIDENCY_FINALIZATION_DELAY_IN_SECONDS: i64 = 5 * 60; // 5 minutes
+
+/// Time after which a failed run of a migration to a data residency region is retried
+pub const DATA_RESIDENCY_MIGRATION_RETRY_IN_SECONDS: i64 = 5 * 60; // 5 minutes
+
+/// Maximum number of times a failed run of a migration to a data residency region is retried
+pub const DATA_RESIDENCY_MIGRATION_MAX_RETRIES: i32 = 3;
+
+/// Time after which a failed run of the dunning task of a payment is retried
+pub const DUNNING_TASK_RETRY_IN_SECONDS: i64 = 60 * 60; // 1 hour
+
+/// Maximum number of times a failed run of the dunning task of a payment is retried
+pub const DUNNING_TASK_MAX_RETRIES: i32 = 3;
+
+/// Number of the test payments of a profile deleted in a single transaction when its test data is
+/// purged
+pub const TEST_DATA_PURGE_BATCH_SIZE: i64 = 100;
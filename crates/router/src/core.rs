@@ -1,3 +1,4 @@
+pub mod address_validation;
 pub mod admin;
 pub mod api_keys;
 pub mod api_locking;
@@ -9,17 +10,38 @@ pub mod blocklist;
 pub mod cache;
 pub mod card_testing_guard;
 pub mod cards_info;
+#[cfg(feature = "v1")]
+pub mod checkout_sessions;
 pub mod conditional_config;
 pub mod configs;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod connector_bulk_config;
+#[cfg(feature = "v1")]
+pub mod connector_credentials;
+#[cfg(feature = "v1")]
+pub mod connector_deletion;
+#[cfg(feature = "v1")]
+pub mod connector_maintenance;
 #[cfg(feature = "olap")]
 pub mod connector_onboarding;
+pub mod connector_rate_limit;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod connector_transaction_id_backfill;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod coverage;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 pub mod currency;
 pub mod customers;
+#[cfg(feature = "v1")]
+pub mod data_residency;
+#[cfg(feature = "v1")]
+pub mod data_retention;
 pub mod disputes;
 pub mod encryption;
 pub mod errors;
 pub mod external_service_auth;
+pub mod fault_injection;
+pub mod feature_flags;
 pub mod files;
 #[cfg(feature = "frm")]
 pub mod fraud_check;
@@ -28,6 +50,8 @@ pub mod health_check;
 #[cfg(feature = "v1")]
 pub mod locker_migration;
 pub mod mandate;
+#[cfg(feature = "v1")]
+pub mod merchant_lifecycle;
 pub mod metrics;
 pub mod payment_link;
 pub mod payment_methods;
@@ -38,12 +62,22 @@ pub mod payout_link;
 pub mod payouts;
 pub mod pm_auth;
 pub mod poll;
+#[cfg(feature = "olap")]
+pub mod process_tracker;
 #[cfg(feature = "recon")]
 pub mod recon;
 #[cfg(feature = "v1")]
 pub mod refunds;
 pub mod routing;
+#[cfg(feature = "v1")]
+pub mod sanctions_screening;
+pub mod sandbox;
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub mod sca_compliance;
+pub mod sdk_events;
 pub mod surcharge_decision_config;
+#[cfg(feature = "v1")]
+pub mod test_mode;
 #[cfg(feature = "olap")]
 pub mod user;
 #[cfg(feature = "olap")]
@@ -53,6 +87,8 @@ pub mod utils;
 pub mod verification;
 #[cfg(feature = "olap")]
 pub mod verify_connector;
+#[cfg(all(feature = "v1", feature = "olap"))]
+pub mod warehouse_sync;
 pub mod webhooks;
 
 pub mod unified_authentication_service;
@@ -0,0 +1,243 @@
+//! Validation and normalization of the billing and shipping addresses of the customers and the
+//! payments, for the merchants which have enabled the `address_validation` feature flag. The
+//! addresses are validated with the provider in the configuration, either the offline normalizer
+//! or an external address validation service. The address is stored as it was given along with
+//! its normalized form, and the normalized form is used with the connectors.
+
+pub mod normalizer;
+
+use api_models::payments::{
+    AddressDetails, AddressValidationDetails, AddressValidationHint, AddressValidationHintCode,
+    AddressValidationStatus, PaymentAddressValidation,
+};
+use common_utils::{
+    crypto::Encryptable,
+    errors::{CustomResult, ParsingError},
+    ext_traits::{BytesExt, Encode, StringExt},
+    id_type,
+    request::RequestContent,
+};
+use error_stack::{report, ResultExt};
+use masking::{Mask, PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    configs::settings::AddressValidationConfig,
+    core::{errors, feature_flags},
+    headers,
+    routes::SessionState,
+    services,
+    types::{api::enums, domain},
+};
+
+/// The name of the offline address validation provider
+const OFFLINE_PROVIDER: &str = "offline";
+
+/// The result of the validation of an address, stored encrypted along with the address
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct AddressValidationRecord {
+    /// The provider which validated the address
+    pub provider: String,
+    /// The address as it was given, to identify the records of the addresses which were updated
+    /// after their validation
+    pub raw_address: AddressDetails,
+    pub validation: AddressValidationDetails,
+}
+
+impl AddressValidationRecord {
+    /// The record serialized as JSON, to be encrypted along with the address
+    pub fn to_encryptable_value(&self) -> CustomResult<Secret<String>, ParsingError> {
+        self.encode_to_string_of_json().map(Secret::new)
+    }
+
+    /// The normalized form of the address, unless the address could not be validated
+    pub fn get_normalized_address_details(&self) -> Option<AddressDetails> {
+        match self.validation.status {
+            AddressValidationStatus::Invalid => None,
+            AddressValidationStatus::Valid
+            | AddressValidationStatus::Corrected
+            | AddressValidationStatus::Unverified => self.validation.normalized_address.clone(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ExternalAddressValidationRequest {
+    address: AddressDetails,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct ExternalAddressValidationResponse {
+    status: AddressValidationStatus,
+    normalized_address: Option<AddressDetails>,
+    #[serde(default)]
+    hints: Vec<AddressValidationHint>,
+}
+
+async fn validate_address_with_external_provider(
+    state: &SessionState,
+    base_url: &str,
+    api_key: &Secret<String>,
+    timeout: u64,
+    address: &AddressDetails,
+) -> errors::RouterResult<AddressValidationDetails> {
+    let mut request = services::Request::new(services::Method::Post, base_url);
+    request.add_header(headers::CONTENT_TYPE, "application/json".into());
+    request.add_header(headers::AUTHORIZATION, api_key.peek().clone().into_masked());
+    request.add_default_headers();
+    request.set_body(RequestContent::Json(Box::new(
+        ExternalAddressValidationRequest {
+            address: address.clone(),
+        },
+    )));
+
+    let response: ExternalAddressValidationResponse = tokio::time::timeout(
+        std::time::Duration::from_secs(timeout),
+        services::call_connector_api(state, request, "validate_address_with_external_provider"),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Timed out waiting for the address validation provider")?
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to call the address validation provider")?
+    .map_err(|error_response| {
+        report!(errors::ApiErrorResponse::InternalServerError).attach_printable(format!(
+            "Address validation provider responded with status code {}",
+            error_response.status_code
+        ))
+    })?
+    .response
+    .parse_struct("ExternalAddressValidationResponse")
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to parse the response of the address validation provider")?;
+
+    Ok(AddressValidationDetails {
+        status: response.status,
+        normalized_address: response.normalized_address,
+        hints: response.hints,
+    })
+}
+
+/// The offline validation of the address, used when the external provider could not be reached
+fn get_fallback_validation(provider: &str, address: &AddressDetails) -> AddressValidationDetails {
+    let mut validation = normalizer::normalize_address(address);
+    if validation.status != AddressValidationStatus::Invalid {
+        validation.status = AddressValidationStatus::Unverified;
+    }
+    validation.hints.push(AddressValidationHint {
+        field: None,
+        code: AddressValidationHintCode::ProviderUnavailable,
+        message: format!("address validation provider {provider} could not be reached"),
+    });
+    validation
+}
+
+/// Validates and normalizes the address if the merchant has enabled address validation. The
+/// validation never fails the flow, the address is only normalized offline if the external
+/// provider could not be reached.
+#[instrument(skip_all)]
+pub async fn validate_address(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    profile_id: Option<&id_type::ProfileId>,
+    address: Option<&AddressDetails>,
+) -> Option<AddressValidationRecord> {
+    let address = address.filter(|address| **address != AddressDetails::default())?;
+
+    if !feature_flags::is_feature_enabled(
+        state,
+        merchant_id,
+        profile_id,
+        enums::MerchantFeatureFlag::AddressValidation,
+    )
+    .await
+    {
+        return None;
+    }
+
+    let (provider, validation) = match state.conf.address_validation.get_inner() {
+        AddressValidationConfig::Offline => (
+            OFFLINE_PROVIDER.to_string(),
+            normalizer::normalize_address(address),
+        ),
+        AddressValidationConfig::External {
+            name,
+            base_url,
+            api_key,
+            timeout,
+        } => {
+            let validation = validate_address_with_external_provider(
+                state, base_url, api_key, *timeout, address,
+            )
+            .await
+            .unwrap_or_else(|error| {
+                logger::error!(?error, provider = %name, "Failed to validate the address");
+                get_fallback_validation(name, address)
+            });
+            (name.clone(), validation)
+        }
+    };
+
+    Some(AddressValidationRecord {
+        provider,
+        raw_address: address.clone(),
+        validation,
+    })
+}
+
+/// Validates the shipping and the billing addresses of a payment concurrently, so that the payment
+/// waits at most for the timeout of the provider when both addresses are given
+#[instrument(skip_all)]
+pub async fn validate_payment_addresses(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    profile_id: Option<&id_type::ProfileId>,
+    shipping_address: Option<&AddressDetails>,
+    billing_address: Option<&AddressDetails>,
+) -> (
+    Option<AddressValidationRecord>,
+    Option<AddressValidationRecord>,
+) {
+    tokio::join!(
+        validate_address(state, merchant_id, profile_id, shipping_address),
+        validate_address(state, merchant_id, profile_id, billing_address),
+    )
+}
+
+/// The record of the validation of the stored address, unless the address was updated after it
+/// was validated
+pub fn get_address_validation_record(address: &domain::Address) -> Option<AddressValidationRecord> {
+    let record: AddressValidationRecord = address
+        .normalized_address
+        .clone()
+        .map(Encryptable::into_inner)?
+        .peek()
+        .clone()
+        .parse_struct("AddressValidationRecord")
+        .map_err(|error| {
+            logger::error!(?error, "Failed to parse the address validation record");
+        })
+        .ok()?;
+
+    (record.raw_address == AddressDetails::from(address.clone())).then_some(record)
+}
+
+/// The result of the validation of the stored address, returned in the responses
+pub fn get_address_validation_details(
+    address: &domain::Address,
+) -> Option<AddressValidationDetails> {
+    get_address_validation_record(address).map(|record| record.validation)
+}
+
+/// The results of the validation of the stored billing and shipping addresses of a payment
+pub fn get_payment_address_validation(
+    shipping_address: Option<&domain::Address>,
+    billing_address: Option<&domain::Address>,
+) -> Option<PaymentAddressValidation> {
+    let address_validation = PaymentAddressValidation {
+        billing: billing_address.and_then(get_address_validation_details),
+        shipping: shipping_address.and_then(get_address_validation_details),
+    };
+
+    (address_validation != PaymentAddressValidation::default()).then_some(address_validation)
+}
@@ -0,0 +1,469 @@
+//! Offline normalization of the addresses, in the manner of libpostal: the whitespace of the
+//! fields is collapsed, the postal codes are formatted as per their country, the states of the
+//! countries which have state codes are replaced by their codes, and the street suffixes and unit
+//! designators of the US addresses are abbreviated as per the USPS standards. The addresses are not
+//! verified to exist.
+
+use api_models::{
+    enums::{CanadaStatesAbbreviation, CountryAlpha2, UsStatesAbbreviation},
+    payments::{
+        AddressDetails, AddressValidationDetails, AddressValidationHint, AddressValidationHintCode,
+        AddressValidationStatus,
+    },
+};
+use masking::{PeekInterface, Secret};
+
+use crate::types::transformers::ForeignTryFrom;
+
+/// USPS abbreviations of the common street suffixes and unit designators
+const US_STREET_ABBREVIATIONS: &[(&str, &str)] = &[
+    ("apartment", "Apt"),
+    ("avenue", "Ave"),
+    ("boulevard", "Blvd"),
+    ("building", "Bldg"),
+    ("circle", "Cir"),
+    ("court", "Ct"),
+    ("drive", "Dr"),
+    ("expressway", "Expy"),
+    ("floor", "Fl"),
+    ("freeway", "Fwy"),
+    ("highway", "Hwy"),
+    ("lane", "Ln"),
+    ("parkway", "Pkwy"),
+    ("place", "Pl"),
+    ("road", "Rd"),
+    ("square", "Sq"),
+    ("street", "St"),
+    ("suite", "Ste"),
+    ("terrace", "Ter"),
+];
+
+/// Collapses the whitespace of the value, and removes the separators around it
+fn normalize_text(value: &str) -> String {
+    value
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .trim_matches(|character: char| character == ',' || character == ';')
+        .trim()
+        .to_string()
+}
+
+fn abbreviate_us_street_line(line: &str) -> String {
+    line.split(' ')
+        .map(|word| {
+            let (word, suffix) = match word.strip_suffix(',') {
+                Some(word) => (word, ","),
+                None => (word, ""),
+            };
+            let lowercase_word = word.to_lowercase();
+            US_STREET_ABBREVIATIONS
+                .iter()
+                .find(|(name, _)| *name == lowercase_word)
+                .map(|(_, abbreviation)| format!("{abbreviation}{suffix}"))
+                .unwrap_or_else(|| format!("{word}{suffix}"))
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The digits and letters of a postal code, in upper case
+fn get_postal_code_characters(zip: &str) -> String {
+    zip.chars()
+        .filter(char::is_ascii_alphanumeric)
+        .map(|character| character.to_ascii_uppercase())
+        .collect()
+}
+
+fn is_digits(value: &str, length: usize) -> bool {
+    value.len() == length && value.chars().all(|character| character.is_ascii_digit())
+}
+
+/// Whether the format of the postal codes of the country is known, the postal code is required
+/// for the addresses of these countries
+fn has_postal_code_format(country: Option<CountryAlpha2>) -> bool {
+    matches!(
+        country,
+        Some(
+            CountryAlpha2::US
+                | CountryAlpha2::CA
+                | CountryAlpha2::GB
+                | CountryAlpha2::NL
+                | CountryAlpha2::JP
+                | CountryAlpha2::BR
+                | CountryAlpha2::IN
+                | CountryAlpha2::AU
+                | CountryAlpha2::DE
+                | CountryAlpha2::FR
+                | CountryAlpha2::ES
+                | CountryAlpha2::IT
+        )
+    )
+}
+
+/// Formats the postal code as per its country. Returns `None` if the postal code does not match
+/// the format of the country, and the postal code with its whitespace collapsed if the format of
+/// the country is not known.
+fn normalize_postal_code(zip: &str, country: Option<CountryAlpha2>) -> Option<String> {
+    let characters = get_postal_code_characters(zip);
+    // The inward code of the postal codes of CA and GB is formed by their last 3 characters
+    let (first, last) = characters.split_at(characters.len().saturating_sub(3));
+
+    match country {
+        Some(CountryAlpha2::US) => match characters.len() {
+            5 if is_digits(&characters, 5) => Some(characters),
+            9 if is_digits(&characters, 9) => {
+                Some(format!("{}-{}", &characters[..5], &characters[5..]))
+            }
+            _ => None,
+        },
+        Some(CountryAlpha2::CA) => {
+            let is_valid = characters.len() == 6
+                && characters.chars().enumerate().all(|(index, character)| {
+                    if index % 2 == 0 {
+                        character.is_ascii_alphabetic()
+                    } else {
+                        character.is_ascii_digit()
+                    }
+                });
+            is_valid.then(|| format!("{first} {last}"))
+        }
+        Some(CountryAlpha2::GB) => {
+            let is_valid = (5..=7).contains(&characters.len())
+                && last.chars().next().is_some_and(|c| c.is_ascii_digit())
+                && last.chars().skip(1).all(|c| c.is_ascii_alphabetic());
+            is_valid.then(|| format!("{first} {last}"))
+        }
+        Some(CountryAlpha2::NL) => {
+            let is_valid = characters.len() == 6
+                && is_digits(&characters[..4], 4)
+                && characters[4..].chars().all(|c| c.is_ascii_alphabetic());
+            is_valid.then(|| format!("{} {}", &characters[..4], &characters[4..]))
+        }
+        Some(CountryAlpha2::JP) => {
+            is_digits(&characters, 7).then(|| format!("{}-{}", &characters[..3], &characters[3..]))
+        }
+        Some(CountryAlpha2::BR) => {
+            is_digits(&characters, 8).then(|| format!("{}-{}", &characters[..5], &characters[5..]))
+        }
+        Some(CountryAlpha2::IN) => is_digits(&characters, 6).then_some(characters),
+        Some(CountryAlpha2::AU) => is_digits(&characters, 4).then_some(characters),
+        Some(CountryAlpha2::DE | CountryAlpha2::FR | CountryAlpha2::ES | CountryAlpha2::IT) => {
+            is_digits(&characters, 5).then_some(characters)
+        }
+        _ => Some(normalize_text(zip).to_uppercase()),
+    }
+}
+
+/// Replaces the state by its code, for the countries which have state codes. Returns `None` if
+/// the state is not a known state of the country.
+fn normalize_state(state: &str, country: Option<CountryAlpha2>) -> Option<String> {
+    let state = normalize_text(state);
+    match country {
+        Some(CountryAlpha2::US) => UsStatesAbbreviation::foreign_try_from(state)
+            .ok()
+            .map(|state| state.to_string()),
+        Some(CountryAlpha2::CA) => CanadaStatesAbbreviation::foreign_try_from(state)
+            .ok()
+            .map(|state| state.to_string()),
+        _ => Some(state),
+    }
+}
+
+fn get_hint(
+    field: &str,
+    code: AddressValidationHintCode,
+    message: String,
+) -> AddressValidationHint {
+    AddressValidationHint {
+        field: Some(field.to_string()),
+        code,
+        message,
+    }
+}
+
+/// Normalizes a field of the address, recording a hint if its value was changed
+fn normalize_field(
+    field: &str,
+    value: Option<&Secret<String>>,
+    normalize: impl Fn(&str) -> String,
+    hints: &mut Vec<AddressValidationHint>,
+) -> Option<Secret<String>> {
+    let value = value?;
+    let normalized_value = normalize(value.peek());
+    if &normalized_value != value.peek() {
+        hints.push(get_hint(
+            field,
+            AddressValidationHintCode::ValueNormalized,
+            format!("{field} was normalized"),
+        ));
+    }
+    (!normalized_value.is_empty()).then(|| Secret::new(normalized_value))
+}
+
+/// Normalizes the address, and validates the presence and the format of its fields
+pub fn normalize_address(address: &AddressDetails) -> AddressValidationDetails {
+    let mut hints = Vec::new();
+    let country = address.country;
+    let is_us_address = country == Some(CountryAlpha2::US);
+
+    let normalize_line = |line: &str| {
+        let line = normalize_text(line);
+        if is_us_address {
+            abbreviate_us_street_line(&line)
+        } else {
+            line
+        }
+    };
+    let line1 = normalize_field("line1", address.line1.as_ref(), normalize_line, &mut hints);
+    let line2 = normalize_field("line2", address.line2.as_ref(), normalize_line, &mut hints);
+    let line3 = normalize_field("line3", address.line3.as_ref(), normalize_line, &mut hints);
+    let first_name = normalize_field(
+        "first_name",
+        address.first_name.as_ref(),
+        normalize_text,
+        &mut hints,
+    );
+    let last_name = normalize_field(
+        "last_name",
+        address.last_name.as_ref(),
+        normalize_text,
+        &mut hints,
+    );
+
+    let city = address.city.as_deref().map(normalize_text);
+    if city.is_some() && city.as_deref() != address.city.as_deref() {
+        hints.push(get_hint(
+            "city",
+            AddressValidationHintCode::ValueNormalized,
+            "city was normalized".to_string(),
+        ));
+    }
+    let city = city.filter(|city| !city.is_empty());
+
+    let state = match address.state.as_ref() {
+        Some(state) => match normalize_state(state.peek(), country) {
+            Some(normalized_state) => {
+                if &normalized_state != state.peek() {
+                    hints.push(get_hint(
+                        "state",
+                        AddressValidationHintCode::ValueNormalized,
+                        "state was normalized".to_string(),
+                    ));
+                }
+                Some(Secret::new(normalized_state))
+            }
+            None => {
+                hints.push(get_hint(
+                    "state",
+                    AddressValidationHintCode::UnknownState,
+                    format!(
+                        "state is not a known state of {}",
+                        country
+                            .map(|country| country.to_string())
+                            .unwrap_or_default()
+                    ),
+                ));
+                Some(state.clone())
+            }
+        },
+        None => None,
+    };
+
+    let zip = match address.zip.as_ref() {
+        Some(zip) => match normalize_postal_code(zip.peek(), country) {
+            Some(normalized_zip) => {
+                if &normalized_zip != zip.peek() {
+                    hints.push(get_hint(
+                        "zip",
+                        AddressValidationHintCode::ValueNormalized,
+                        "zip was normalized".to_string(),
+                    ));
+                }
+                Some(Secret::new(normalized_zip))
+            }
+            None => {
+                hints.push(get_hint(
+                    "zip",
+                    AddressValidationHintCode::InvalidPostalCode,
+                    format!(
+                        "zip does not match the postal code format of {}",
+                        country
+                            .map(|country| country.to_string())
+                            .unwrap_or_default()
+                    ),
+                ));
+                Some(zip.clone())
+            }
+        },
+        None => None,
+    };
+
+    let normalized_address = AddressDetails {
+        city,
+        country,
+        line1,
+        line2,
+        line3,
+        zip,
+        state,
+        first_name,
+        last_name,
+    };
+
+    [
+        ("line1", normalized_address.line1.is_none()),
+        ("city", normalized_address.city.is_none()),
+        (
+            "zip",
+            normalized_address.zip.is_none() && has_postal_code_format(country),
+        ),
+        ("country", normalized_address.country.is_none()),
+    ]
+    .into_iter()
+    .filter(|(_, is_missing)| *is_missing)
+    .for_each(|(field, _)| {
+        hints.push(get_hint(
+            field,
+            AddressValidationHintCode::MissingField,
+            format!("{field} is missing"),
+        ))
+    });
+
+    let status = if hints
+        .iter()
+        .any(|hint| hint.code != AddressValidationHintCode::ValueNormalized)
+    {
+        AddressValidationStatus::Invalid
+    } else if hints.is_empty() {
+        AddressValidationStatus::Valid
+    } else {
+        AddressValidationStatus::Corrected
+    };
+
+    AddressValidationDetails {
+        status,
+        normalized_address: Some(normalized_address),
+        hints,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use masking::ExposeInterface;
+
+    use super::*;
+
+    fn get_address(
+        line1: &str,
+        city: &str,
+        state: &str,
+        zip: &str,
+        country: CountryAlpha2,
+    ) -> AddressDetails {
+        AddressDetails {
+            line1: Some(Secret::new(line1.to_string())),
+            city: Some(city.to_string()),
+            state: Some(Secret::new(state.to_string())),
+            zip: Some(Secret::new(zip.to_string())),
+            country: Some(country),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_normalize_us_address() {
+        let address = get_address(
+            " 1600  Amphitheatre Parkway, ",
+            "Mountain  View",
+            "california",
+            "94043 1351",
+            CountryAlpha2::US,
+        );
+
+        let result = normalize_address(&address);
+        let normalized_address = result.normalized_address.unwrap();
+        assert_eq!(result.status, AddressValidationStatus::Corrected);
+        assert_eq!(
+            normalized_address.line1.unwrap().expose(),
+            "1600 Amphitheatre Pkwy"
+        );
+        assert_eq!(normalized_address.city.unwrap(), "Mountain View");
+        assert_eq!(normalized_address.state.unwrap().expose(), "CA");
+        assert_eq!(normalized_address.zip.unwrap().expose(), "94043-1351");
+    }
+
+    #[test]
+    fn test_normalize_valid_address() {
+        let address = get_address(
+            "10 Downing St",
+            "London",
+            "London",
+            "SW1A 2AA",
+            CountryAlpha2::GB,
+        );
+
+        let result = normalize_address(&address);
+        assert_eq!(result.status, AddressValidationStatus::Valid);
+        assert!(result.hints.is_empty());
+        assert_eq!(result.normalized_address, Some(address));
+    }
+
+    #[test]
+    fn test_normalize_postal_codes() {
+        assert_eq!(
+            normalize_postal_code("k1a0b1", Some(CountryAlpha2::CA)).as_deref(),
+            Some("K1A 0B1")
+        );
+        assert_eq!(
+            normalize_postal_code("sw1a2aa", Some(CountryAlpha2::GB)).as_deref(),
+            Some("SW1A 2AA")
+        );
+        assert_eq!(
+            normalize_postal_code("1012js", Some(CountryAlpha2::NL)).as_deref(),
+            Some("1012 JS")
+        );
+        assert_eq!(
+            normalize_postal_code("1000001", Some(CountryAlpha2::JP)).as_deref(),
+            Some("100-0001")
+        );
+        assert_eq!(normalize_postal_code("9404", Some(CountryAlpha2::US)), None);
+        assert_eq!(
+            normalize_postal_code(" 00100 ", Some(CountryAlpha2::KE)).as_deref(),
+            Some("00100")
+        );
+    }
+
+    #[test]
+    fn test_normalize_invalid_address() {
+        let mut address = get_address(
+            "1 Main Street",
+            "Springfield",
+            "Atlantis",
+            "123",
+            CountryAlpha2::US,
+        );
+        address.city = None;
+
+        let result = normalize_address(&address);
+        let hint_codes = result
+            .hints
+            .iter()
+            .map(|hint| (hint.field.clone().unwrap(), hint.code))
+            .collect::<Vec<_>>();
+        assert_eq!(result.status, AddressValidationStatus::Invalid);
+        assert!(
+            hint_codes.contains(&("state".to_string(), AddressValidationHintCode::UnknownState))
+        );
+        assert!(hint_codes.contains(&(
+            "zip".to_string(),
+            AddressValidationHintCode::InvalidPostalCode
+        )));
+        assert!(hint_codes.contains(&("city".to_string(), AddressValidationHintCode::MissingField)));
+        assert_eq!(
+            result.normalized_address.unwrap().line1.unwrap().expose(),
+            "1 Main St"
+        );
+    }
+}
@@ -6,7 +6,7 @@ use api_models::{
 };
 use common_utils::{
     date_time,
-    ext_traits::{AsyncExt, Encode, OptionExt, ValueExt},
+    ext_traits::{AsyncExt, Encode, OptionExt, StringExt, ValueExt},
     fp_utils, id_type, pii, type_name,
     types::keymanager::{self as km_types, KeyManagerState, ToEncryptable},
 };
@@ -23,6 +23,8 @@ use pm_auth::{connector::plaid::transformers::PlaidAuthType, types as pm_auth_ty
 use regex::Regex;
 use uuid::Uuid;
 
+#[cfg(feature = "v1")]
+use crate::core::{connector_credentials, connector_deletion, data_residency};
 #[cfg(any(feature = "v1", feature = "v2"))]
 use crate::types::transformers::ForeignFrom;
 use crate::{
@@ -405,6 +407,7 @@ impl MerchantAccountCreateBridge for api::MerchantAccountCreate {
                     version: hyperswitch_domain_models::consts::API_VERSION,
                     is_platform_account: false,
                     product_type: self.product_type,
+                    status: api_enums::MerchantAccountStatus::Active,
                 },
             )
         }
@@ -675,6 +678,7 @@ impl MerchantAccountCreateBridge for api::MerchantAccountCreate {
                     is_platform_account: false,
                     version: hyperswitch_domain_models::consts::API_VERSION,
                     product_type: self.product_type,
+                    status: api_enums::MerchantAccountStatus::Active,
                 }),
             )
         }
@@ -1198,6 +1202,27 @@ async fn validate_merchant_id(
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
 }
 
+/// Validates the credentials and the metadata of a connector account, without storing them
+#[cfg(feature = "v1")]
+pub(crate) fn validate_connector_account_details(
+    connector_name: &api_enums::Connector,
+    connector_account_details: Option<pii::SecretSerdeValue>,
+    connector_meta_data: &Option<pii::SecretSerdeValue>,
+) -> RouterResult<()> {
+    let auth = types::ConnectorAuthType::from_option_secret_value(connector_account_details)
+        .change_context(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "connector_account_details".to_string(),
+            expected_format: "auth_type and api_key".to_string(),
+        })?;
+
+    ConnectorAuthTypeAndMetadataValidation {
+        connector_name,
+        auth_type: &auth,
+        connector_meta_data,
+    }
+    .validate_auth_and_metadata_type()
+}
+
 struct ConnectorAuthTypeAndMetadataValidation<'a> {
     connector_name: &'a api_models::enums::Connector,
     auth_type: &'a types::ConnectorAuthType,
@@ -1254,6 +1279,9 @@ impl ConnectorAuthTypeAndMetadataValidation<'_> {
             | api_enums::Connector::DummyConnector6
             | api_enums::Connector::DummyConnector7 => {
                 dummyconnector::transformers::DummyConnectorAuthType::try_from(self.auth_type)?;
+                dummyconnector::transformers::DummyConnectorSimulation::try_from(
+                    self.connector_meta_data,
+                )?;
                 Ok(())
             }
             api_enums::Connector::Aci => {
@@ -2897,6 +2925,9 @@ pub async fn create_connector(
 
     connector_metadata.validate_apple_pay_certificates_in_mca_metadata()?;
 
+    #[cfg(feature = "v1")]
+    connector_credentials::validate_expires_at(req.credentials_expires_at)?;
+
     #[cfg(feature = "v1")]
     helpers::validate_business_details(
         req.business_country,
@@ -2995,6 +3026,16 @@ pub async fn create_connector(
         .retrieve_and_update_default_fallback_routing_algorithm_if_routable_connector_exists()
         .await?;
 
+    #[cfg(feature = "v1")]
+    connector_credentials::record_credentials_update(
+        &state,
+        merchant_id,
+        &mca.get_id(),
+        true,
+        req.credentials_expires_at,
+    )
+    .await?;
+
     metrics::MCA_CREATE.add(
         1,
         router_env::metric_attributes!(
@@ -3091,6 +3132,11 @@ pub async fn retrieve_connector(
             id: merchant_connector_id.get_string_repr().to_string(),
         })?;
     core_utils::validate_profile_id_from_auth_layer(profile_id, &mca)?;
+    connector_deletion::validate_merchant_connector_account_not_deleted(
+        store,
+        &merchant_connector_id,
+    )
+    .await?;
 
     Ok(service_api::ApplicationResponse::Json(
         mca.foreign_try_into()?,
@@ -3190,6 +3236,13 @@ pub async fn list_payment_connectors(
         profile_id_list,
         merchant_connector_accounts,
     );
+    #[cfg(feature = "v1")]
+    let merchant_connector_accounts =
+        connector_deletion::filter_out_deleted_merchant_connector_accounts(
+            store,
+            merchant_connector_accounts,
+        )
+        .await?;
     let mut response = vec![];
 
     // The can be eliminated once [#79711](https://github.com/rust-lang/rust/issues/79711) is stabilized
@@ -3234,6 +3287,16 @@ pub async fn update_connector(
         )
         .await?;
     core_utils::validate_profile_id_from_auth_layer(profile_id, &mca)?;
+    #[cfg(feature = "v1")]
+    connector_deletion::validate_merchant_connector_account_not_deleted(db, merchant_connector_id)
+        .await?;
+
+    #[cfg(feature = "v1")]
+    connector_credentials::validate_expires_at(req.credentials_expires_at)?;
+    #[cfg(feature = "v1")]
+    let is_credentials_rotated = req.connector_account_details.is_some();
+    #[cfg(feature = "v1")]
+    let credentials_expires_at = req.credentials_expires_at;
 
     let payment_connector = req
         .clone()
@@ -3272,6 +3335,16 @@ pub async fn update_connector(
             )
         })?;
 
+    #[cfg(feature = "v1")]
+    connector_credentials::record_credentials_update(
+        &state,
+        merchant_id,
+        merchant_connector_id,
+        is_credentials_rotated,
+        credentials_expires_at,
+    )
+    .await?;
+
     let response = updated_mca.foreign_try_into()?;
 
     Ok(service_api::ApplicationResponse::Json(response))
@@ -3311,30 +3384,30 @@ pub async fn delete_connector(
             id: merchant_connector_id.get_string_repr().to_string(),
         })?;
 
-    let is_deleted = db
-        .delete_merchant_connector_account_by_merchant_id_merchant_connector_id(
-            &merchant_id,
-            &merchant_connector_id,
-        )
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
-            id: merchant_connector_id.get_string_repr().to_string(),
+    let routable_connector = common_enums::RoutableConnectors::from_str(&mca.connector_name)
+        .map_err(|_| errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "connector_name",
         })?;
+    let profile_id = mca.profile_id.clone();
+    let transaction_type: api_enums::TransactionType = mca.connector_type.into();
+
+    // The mca is only disabled, so that the payments made through it can still refer to it
+    let soft_deletion = connector_deletion::soft_delete_merchant_connector_account(
+        db,
+        key_manager_state,
+        &key_store,
+        mca,
+    )
+    .await?;
 
     // delete the mca from the config as well
     let merchant_default_config_delete = MerchantDefaultConfigUpdate {
-        routable_connector: &Some(
-            common_enums::RoutableConnectors::from_str(&mca.connector_name).map_err(|_| {
-                errors::ApiErrorResponse::InvalidDataValue {
-                    field_name: "connector_name",
-                }
-            })?,
-        ),
-        merchant_connector_id: &mca.get_id(),
+        routable_connector: &Some(routable_connector),
+        merchant_connector_id: &merchant_connector_id,
         store: db,
         merchant_id: &merchant_id,
-        profile_id: &mca.profile_id,
-        transaction_type: &mca.connector_type.into(),
+        profile_id: &profile_id,
+        transaction_type: &transaction_type,
     };
 
     merchant_default_config_delete
@@ -3344,11 +3417,79 @@ pub async fn delete_connector(
     let response = api::MerchantConnectorDeleteResponse {
         merchant_id,
         merchant_connector_id,
-        deleted: is_deleted,
+        deleted: true,
+        restorable_until: Some(soft_deletion.get_restorable_until()),
     };
     Ok(service_api::ApplicationResponse::Json(response))
 }
 
+#[cfg(feature = "v1")]
+pub async fn restore_connector(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    merchant_connector_id: id_type::MerchantConnectorAccountId,
+) -> RouterResponse<api_models::admin::MerchantConnectorResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let _merchant_account = db
+        .find_merchant_account_by_merchant_id(key_manager_state, &merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let mca = db
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            key_manager_state,
+            &merchant_id,
+            &merchant_connector_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.get_string_repr().to_string(),
+        })?;
+
+    let restored_mca = connector_deletion::restore_merchant_connector_account(
+        db,
+        key_manager_state,
+        &key_store,
+        mca,
+    )
+    .await?;
+
+    // add the mca back to the end of the config, from which it was removed on its deletion
+    let merchant_default_config_update = MerchantDefaultConfigUpdate {
+        routable_connector: &Some(
+            common_enums::RoutableConnectors::from_str(&restored_mca.connector_name).map_err(
+                |_| errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "connector_name",
+                },
+            )?,
+        ),
+        merchant_connector_id: &merchant_connector_id,
+        store: db,
+        merchant_id: &merchant_id,
+        profile_id: &restored_mca.profile_id,
+        transaction_type: &restored_mca.connector_type.into(),
+    };
+
+    merchant_default_config_update
+        .retrieve_and_update_default_fallback_routing_algorithm_if_routable_connector_exists()
+        .await?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        restored_mca.foreign_try_into()?,
+    ))
+}
+
 #[cfg(feature = "v2")]
 pub async fn delete_connector(
     state: SessionState,
@@ -3455,6 +3596,18 @@ pub async fn kv_for_merchant(
                 })?
             }
 
+            // The rows written to redis are drained to the database of the deployment, which
+            // does not hold the customers and addresses of a merchant with a data residency
+            #[cfg(feature = "v1")]
+            if data_residency::get_data_residency(db, &merchant_id)
+                .await?
+                .is_some()
+            {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Kv cannot be enabled for a merchant with a data residency".to_owned(),
+                })?
+            }
+
             db.update_merchant(
                 key_manager_state,
                 merchant_account,
@@ -3559,6 +3712,63 @@ pub async fn check_merchant_account_kv_status(
     ))
 }
 
+/// Lists the divergences between the KV store and Postgres found by the consistency checker of
+/// the drainer
+pub async fn list_kv_pg_divergences(
+    state: SessionState,
+    request: admin_types::KvPgDivergencesListRequest,
+) -> RouterResponse<admin_types::KvPgDivergencesListResponse> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let serialized_divergences = redis_conn
+        .get_list_elements(&diesel_models::kv::KV_PG_DIVERGENCES_KEY.into(), 0, -1)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the KV and Postgres divergences from redis")?;
+
+    let divergences = serialized_divergences
+        .into_iter()
+        .rev()
+        .filter_map(|serialized_divergence| {
+            serialized_divergence
+                .parse_struct::<diesel_models::kv::KvPgDivergence>("KvPgDivergence")
+                .map_err(|error| {
+                    crate::logger::error!(
+                        ?error,
+                        "Failed to deserialize KV and Postgres divergence"
+                    );
+                })
+                .ok()
+        })
+        .filter(|divergence| {
+            request
+                .merchant_id
+                .as_ref()
+                .map_or(true, |merchant_id| &divergence.merchant_id == merchant_id)
+        })
+        .map(|divergence| admin_types::KvPgDivergence {
+            table: divergence.table,
+            merchant_id: divergence.merchant_id,
+            object_id: divergence.object_id,
+            field: divergence.field,
+            kv_value: divergence.kv_value,
+            pg_value: divergence.pg_value,
+            detected_at: divergence.detected_at,
+        })
+        .collect::<Vec<_>>();
+
+    Ok(service_api::ApplicationResponse::Json(
+        admin_types::KvPgDivergencesListResponse {
+            total_count: divergences.len(),
+            divergences,
+        },
+    ))
+}
+
 pub fn get_frm_config_as_secret(
     frm_configs: Option<Vec<api_models::admin::FrmConfigs>>,
 ) -> Option<Vec<Secret<serde_json::Value>>> {
@@ -3810,6 +4020,7 @@ impl ProfileCreateBridge for api::ProfileCreate {
                 .attach_printable("error while generating card testing secret key")?,
             is_clear_pan_retries_enabled: self.is_clear_pan_retries_enabled.unwrap_or_default(),
             force_3ds_challenge: self.force_3ds_challenge.unwrap_or_default(),
+            is_test_mode: false,
         }))
     }
 
@@ -4953,6 +5164,7 @@ async fn locker_recipient_create_call(
     let payload = transformers::StoreLockerReq::LockerGeneric(transformers::StoreGenericReq {
         merchant_id: merchant_id.to_owned(),
         merchant_customer_id: cust_id.clone(),
+        requestor_card_reference: None,
         enc_data,
         ttl: state.conf.locker.ttl_for_storage_in_secs,
     });
@@ -5,6 +5,8 @@ use error_stack::{report, ResultExt};
 use masking::{PeekInterface, StrongSecret};
 use router_env::{instrument, tracing};
 
+#[cfg(feature = "v1")]
+use crate::core::test_mode;
 use crate::{
     configs::settings,
     consts,
@@ -119,6 +121,23 @@ pub async fn create_api_key(
 
     let merchant_id = key_store.merchant_id.clone();
 
+    #[cfg(feature = "v1")]
+    test_mode::validate_api_key_test_mode(
+        &state,
+        &key_store,
+        api_key.test_mode.unwrap_or(false),
+        api_key.profile_id.as_ref(),
+    )
+    .await?;
+
+    #[cfg(feature = "v2")]
+    if api_key.test_mode.unwrap_or(false) || api_key.profile_id.is_some() {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Test API keys are not supported".to_string(),
+        }
+        .into());
+    }
+
     let hash_key = api_key_config.get_hash_key()?;
     let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
     let api_key = storage::ApiKeyNew {
@@ -131,6 +150,9 @@ pub async fn create_api_key(
         created_at: date_time::now(),
         expires_at: api_key.expiration.into(),
         last_used: None,
+        response_version: api_key.response_version,
+        test_mode: api_key.test_mode,
+        profile_id: api_key.profile_id,
     };
 
     let api_key = store
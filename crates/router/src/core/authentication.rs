@@ -1,5 +1,7 @@
 pub(crate) mod utils;
 
+pub mod abandonment;
+pub mod issuer_stats;
 pub mod transformers;
 pub mod types;
 
@@ -43,6 +45,10 @@ pub async fn perform_authentication(
     payment_id: common_utils::id_type::PaymentId,
     force_3ds_challenge: bool,
 ) -> CustomResult<api::authentication::AuthenticationResponse, ApiErrorResponse> {
+    let card = match &payment_method_data {
+        domain::PaymentMethodData::Card(card) => Some(card.clone()),
+        _ => None,
+    };
     let router_data = transformers::construct_authentication_router_data(
         state,
         merchant_id,
@@ -76,6 +82,9 @@ pub async fn perform_authentication(
     .await?;
     let authentication =
         utils::update_trackers(state, response.clone(), authentication_data, None).await?;
+    if let Some(card) = &card {
+        issuer_stats::record_authentication_outcome(state, card, &authentication).await;
+    }
     response
         .response
         .map_err(|err| ApiErrorResponse::ExternalConnectorError {
@@ -95,14 +104,6 @@ pub async fn perform_post_authentication(
     authentication_id: String,
     payment_id: &common_utils::id_type::PaymentId,
 ) -> CustomResult<storage::Authentication, ApiErrorResponse> {
-    let (authentication_connector, three_ds_connector_account) =
-        utils::get_authentication_connector_data(state, key_store, &business_profile).await?;
-    let is_pull_mechanism_enabled =
-        check_if_pull_mechanism_for_external_3ds_enabled_from_connector_metadata(
-            three_ds_connector_account
-                .get_metadata()
-                .map(|metadata| metadata.expose()),
-        );
     let authentication = state
         .store
         .find_authentication_by_merchant_id_authentication_id(
@@ -112,6 +113,20 @@ pub async fn perform_post_authentication(
         .await
         .to_not_found_response(ApiErrorResponse::InternalServerError)
         .attach_printable_lazy(|| format!("Error while fetching authentication record with authentication_id {authentication_id}"))?;
+    let (authentication_connector, three_ds_connector_account) =
+        utils::get_authentication_connector_data_for_authentication(
+            state,
+            key_store,
+            &business_profile,
+            &authentication,
+        )
+        .await?;
+    let is_pull_mechanism_enabled =
+        check_if_pull_mechanism_for_external_3ds_enabled_from_connector_metadata(
+            three_ds_connector_account
+                .get_metadata()
+                .map(|metadata| metadata.expose()),
+        );
     if !authentication.authentication_status.is_terminal_status() && is_pull_mechanism_enabled {
         let router_data = transformers::construct_post_authentication_router_data(
             state,
@@ -130,6 +145,8 @@ pub async fn perform_post_authentication(
     }
 }
 
+/// Performs the pre-authentication with the preferred authentication connector of the profile,
+/// falling back to the other authentication connectors of the profile on failures
 #[allow(clippy::too_many_arguments)]
 pub async fn perform_pre_authentication(
     state: &SessionState,
@@ -141,8 +158,42 @@ pub async fn perform_pre_authentication(
     payment_id: common_utils::id_type::PaymentId,
     organization_id: common_utils::id_type::OrganizationId,
 ) -> CustomResult<storage::Authentication, ApiErrorResponse> {
-    let (authentication_connector, three_ds_connector_account) =
-        utils::get_authentication_connector_data(state, key_store, business_profile).await?;
+    utils::perform_pre_authentication_with_fallback(business_profile, |authentication_connector| {
+        Box::pin(pre_authenticate_with_connector(
+            state,
+            key_store,
+            card.clone(),
+            token.clone(),
+            business_profile,
+            acquirer_details.clone(),
+            payment_id.clone(),
+            organization_id.clone(),
+            authentication_connector,
+        ))
+    })
+    .await
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn pre_authenticate_with_connector(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    card: hyperswitch_domain_models::payment_method_data::Card,
+    token: String,
+    business_profile: &domain::Profile,
+    acquirer_details: Option<types::AcquirerDetails>,
+    payment_id: common_utils::id_type::PaymentId,
+    organization_id: common_utils::id_type::OrganizationId,
+    authentication_connector: common_enums::AuthenticationConnectors,
+) -> CustomResult<storage::Authentication, ApiErrorResponse> {
+    let three_ds_connector_account = utils::get_authentication_connector_account(
+        state,
+        key_store,
+        business_profile,
+        authentication_connector,
+        None,
+    )
+    .await?;
     let authentication_connector_name = authentication_connector.to_string();
     let authentication = utils::create_new_authentication(
         state,
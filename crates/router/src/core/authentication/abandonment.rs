@@ -0,0 +1,276 @@
+//! Tracking of the 3DS challenges abandoned by the payers. A check is scheduled whenever a
+//! challenge is presented to the payer, and if the challenge is still not completed when the check
+//! runs, the authentication is marked as abandoned and the merchant is notified with an outgoing
+//! webhook, so that they can reach out to the payer. The challenge of an abandoned authentication
+//! can be regenerated with the resume authentication endpoint, where the ACS supports it.
+
+use api_models::{payments, webhooks};
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+use time::Duration;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments as payments_core, webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+    utils,
+};
+
+const AUTHENTICATION_ABANDONMENT_TASK: &str = "AUTHENTICATION_ABANDONMENT";
+const AUTHENTICATION_ABANDONMENT_TAG: &str = "AUTHENTICATION";
+
+/// Whether the challenge of the authentication has been presented to the payer, and is neither
+/// completed nor abandoned yet
+fn is_challenge_pending(authentication: &storage::Authentication) -> bool {
+    authentication.trans_status == Some(enums::TransactionStatus::ChallengeRequired)
+        && authentication.authentication_status == enums::AuthenticationStatus::Pending
+        && authentication.challenge_abandoned != Some(true)
+}
+
+/// Schedules the check for the abandonment of the challenge presented to the payer. The check of
+/// an authentication whose challenge is regenerated is rescheduled.
+#[instrument(skip_all)]
+pub async fn add_challenge_abandonment_task(
+    db: &dyn StorageInterface,
+    tracking_data: storage::AuthenticationAbandonmentTrackingData,
+) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::AuthenticationAbandonmentWorkflow;
+    let process_tracker_id = format!(
+        "{runner}_{AUTHENTICATION_ABANDONMENT_TASK}_{}",
+        tracking_data.authentication_id
+    );
+    let schedule_time = common_utils::date_time::now().saturating_add(Duration::seconds(
+        consts::AUTHENTICATION_CHALLENGE_ABANDONMENT_TIMEOUT_SECONDS,
+    ));
+
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching AUTHENTICATION_ABANDONMENT task")?;
+
+    match existing_process {
+        Some(process) => db
+            .as_scheduler()
+            .reset_process(process, schedule_time)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while resetting AUTHENTICATION_ABANDONMENT task")?,
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                AUTHENTICATION_ABANDONMENT_TASK,
+                runner,
+                [AUTHENTICATION_ABANDONMENT_TAG],
+                tracking_data,
+                None,
+                schedule_time,
+                hyperswitch_domain_models::consts::API_VERSION,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "Failed to construct AUTHENTICATION_ABANDONMENT process tracker task",
+            )?;
+
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed while inserting AUTHENTICATION_ABANDONMENT task to process_tracker",
+                )?;
+        }
+    };
+
+    Ok(())
+}
+
+/// Marks the authentication as abandoned if its challenge is still not completed, and triggers the
+/// `payment_authentication_abandoned` outgoing webhook. Returns `false` if the challenge was
+/// completed in the meantime.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn mark_challenge_abandoned(
+    state: &SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    tracking_data: &storage::AuthenticationAbandonmentTrackingData,
+) -> RouterResult<bool> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+
+    let authentication = db
+        .find_authentication_by_merchant_id_authentication_id(
+            &tracking_data.merchant_id,
+            tracking_data.authentication_id.clone(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::AuthenticationNotFound {
+            id: tracking_data.authentication_id.clone(),
+        })?;
+
+    if !is_challenge_pending(&authentication) {
+        return Ok(false);
+    }
+
+    db.update_authentication_by_merchant_id_authentication_id(
+        authentication,
+        storage::AuthenticationUpdate::ChallengeAbandonmentUpdate {
+            challenge_abandoned: true,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Error while updating authentication")?;
+
+    let business_profile = db
+        .find_business_profile_by_profile_id(
+            key_manager_state,
+            &key_store,
+            &tracking_data.profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: tracking_data.profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let request = payments::PaymentsRetrieveRequest {
+        resource_id: payments::PaymentIdType::PaymentIntentId(tracking_data.payment_id.clone()),
+        merchant_id: Some(tracking_data.merchant_id.clone()),
+        force_sync: false,
+        ..Default::default()
+    };
+    let payments_response = match Box::pin(payments_core::payments_core::<
+        api::PSync,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments_core::PaymentData<api::PSync>,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account.clone(),
+        None,
+        key_store.clone(),
+        payments_core::PaymentStatus,
+        request,
+        services::AuthFlow::Merchant,
+        payments_core::CallConnectorAction::Avoid,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+        None, // Platform merchant account
+    ))
+    .await?
+    {
+        services::ApplicationResponse::Json(payments_response)
+        | services::ApplicationResponse::JsonWithHeaders((payments_response, _)) => {
+            payments_response
+        }
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response received while retrieving the payment")?,
+    };
+
+    let primary_object_created_at = payments_response.created;
+    Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account,
+        business_profile,
+        &key_store,
+        enums::EventType::PaymentAuthenticationAbandoned,
+        enums::EventClass::Payments,
+        tracking_data.payment_id.get_string_repr().to_owned(),
+        enums::EventObjectType::PaymentDetails,
+        webhooks::OutgoingWebhookContent::PaymentDetails(Box::new(payments_response)),
+        primary_object_created_at,
+    ))
+    .await?;
+
+    Ok(true)
+}
+
+/// Regenerates the challenge of an authentication abandoned by the payer. The authentication is
+/// performed afresh with the authentication connector, which fails if the ACS does not support
+/// regenerating the challenge.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn resume_authentication<F: Clone + Sync>(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payments::PaymentsExternalAuthenticationRequest,
+) -> RouterResponse<payments::PaymentsExternalAuthenticationResponse> {
+    let db = &*state.store;
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_account.get_id();
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            key_manager_state,
+            &req.payment_id,
+            merchant_id,
+            &key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            merchant_id,
+            payment_intent.active_attempt.get_id(),
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let authentication_id = payment_attempt.authentication_id.ok_or(
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: "You cannot resume the authentication of this payment because it has not been authenticated".to_owned(),
+        },
+    )?;
+    let authentication = db
+        .find_authentication_by_merchant_id_authentication_id(
+            merchant_id,
+            authentication_id.clone(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::AuthenticationNotFound {
+            id: authentication_id,
+        })?;
+
+    utils::when(authentication.challenge_abandoned != Some(true), || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "You cannot resume the authentication of this payment because its challenge has not been abandoned".to_owned(),
+        })
+    })?;
+
+    let response = Box::pin(payments_core::payment_external_authentication::<F>(
+        state.clone(),
+        merchant_account,
+        key_store,
+        req,
+    ))
+    .await?;
+
+    db.update_authentication_by_merchant_id_authentication_id(
+        authentication,
+        storage::AuthenticationUpdate::ChallengeAbandonmentUpdate {
+            challenge_abandoned: false,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Error while updating authentication")?;
+
+    Ok(response)
+}
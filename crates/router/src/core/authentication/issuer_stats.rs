@@ -0,0 +1,226 @@
+//! Tracking of the 3DS behaviour of the card issuers. The outcome of every 3DS authentication, done
+//! either by an external authentication connector or by the payment connector itself, is counted
+//! against the BIN of the card, as either frictionless or challenged, and the rate of the
+//! frictionless authentications of the BIN is made available to the 3DS decision rules with the
+//! `issuer_frictionless_rate` key, so that the merchants can skip 3DS for the issuers that
+//! challenge most of the payers.
+
+use common_utils::types::MinorUnit;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    routes::SessionState,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+};
+
+/// Whether the authentication was completed without a challenge, or `None` if the authentication
+/// did not reach a frictionless or a challenged outcome
+fn is_frictionless(trans_status: &enums::TransactionStatus) -> Option<bool> {
+    match trans_status {
+        enums::TransactionStatus::Success | enums::TransactionStatus::NotVerified => Some(true),
+        enums::TransactionStatus::ChallengeRequired
+        | enums::TransactionStatus::ChallengeRequiredDecoupledAuthentication => Some(false),
+        enums::TransactionStatus::Failure
+        | enums::TransactionStatus::VerificationNotPerformed
+        | enums::TransactionStatus::Rejected
+        | enums::TransactionStatus::InformationOnly => None,
+    }
+}
+
+/// Whether the 3DS authentication done by the payment connector was completed without a
+/// challenge, or `None` if the response does not tell the outcome of a 3DS authentication.
+///
+/// A redirection of the payer is taken as a challenge, unless the payer was already redirected in
+/// an earlier step of the payment. A payment authorized without any redirection is taken as
+/// frictionless, unless it was already authorized before.
+fn get_connector_authentication_outcome(
+    auth_type: enums::AuthenticationType,
+    previous_status: enums::AttemptStatus,
+    status: enums::AttemptStatus,
+    is_redirection: bool,
+    was_redirected: bool,
+) -> Option<bool> {
+    if auth_type != enums::AuthenticationType::ThreeDs || was_redirected {
+        return None;
+    }
+    if is_redirection {
+        return (previous_status != enums::AttemptStatus::AuthenticationPending).then_some(false);
+    }
+    let is_authorized = |status: enums::AttemptStatus| {
+        matches!(
+            status,
+            enums::AttemptStatus::Charged | enums::AttemptStatus::Authorized
+        )
+    };
+    (is_authorized(status) && !is_authorized(previous_status)).then_some(true)
+}
+
+/// Counts the outcome of the authentication against the BIN of the card. Failures are only
+/// logged, as they must not affect the payment.
+async fn record_outcome(state: &SessionState, card: &domain::Card, is_frictionless: bool) {
+    let now = common_utils::date_time::now();
+    let issuer_authentication_stats = storage::IssuerAuthenticationStatsNew {
+        card_bin: card.card_number.get_card_isin(),
+        card_issuer: card.card_issuer.clone(),
+        frictionless_count: i64::from(is_frictionless),
+        challenge_count: i64::from(!is_frictionless),
+        created_at: now,
+        last_modified_at: now,
+    };
+
+    state
+        .store
+        .upsert_issuer_authentication_stats(issuer_authentication_stats)
+        .await
+        .map_err(|error| {
+            logger::error!(?error, "Failed to record the outcome of the authentication");
+        })
+        .ok();
+}
+
+/// Counts the outcome of the authentication done by an external authentication connector
+#[instrument(skip_all)]
+pub async fn record_authentication_outcome(
+    state: &SessionState,
+    card: &domain::Card,
+    authentication: &storage::Authentication,
+) {
+    if let Some(is_frictionless) = authentication
+        .trans_status
+        .as_ref()
+        .and_then(is_frictionless)
+    {
+        record_outcome(state, card, is_frictionless).await;
+    }
+}
+
+/// Counts the outcome of the 3DS authentication done by the payment connector, from the response
+/// of the connector to a payment with the previous status `previous_status`
+#[instrument(skip_all)]
+pub async fn record_connector_authentication_outcome(
+    state: &SessionState,
+    card: &domain::Card,
+    auth_type: enums::AuthenticationType,
+    previous_status: enums::AttemptStatus,
+    status: enums::AttemptStatus,
+    is_redirection: bool,
+    was_redirected: bool,
+) {
+    if let Some(is_frictionless) = get_connector_authentication_outcome(
+        auth_type,
+        previous_status,
+        status,
+        is_redirection,
+        was_redirected,
+    ) {
+        record_outcome(state, card, is_frictionless).await;
+    }
+}
+
+/// Returns the percentage of the past authentications of the BIN completed without a challenge,
+/// or `None` if too few authentications of the BIN have been seen for the rate to be reliable
+#[instrument(skip_all)]
+pub async fn get_issuer_frictionless_rate(
+    state: &SessionState,
+    card_bin: &str,
+) -> Option<MinorUnit> {
+    let stats = state
+        .store
+        .find_issuer_authentication_stats_optional_by_card_bin(card_bin)
+        .await
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to fetch the authentication stats of the issuer"
+            );
+        })
+        .ok()
+        .flatten()?;
+
+    let total_count = stats.frictionless_count + stats.challenge_count;
+    (total_count >= consts::MIN_ISSUER_AUTHENTICATION_SAMPLE_SIZE)
+        .then(|| MinorUnit::new(stats.frictionless_count * 100 / total_count))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_frictionless() {
+        assert_eq!(
+            is_frictionless(&enums::TransactionStatus::Success),
+            Some(true)
+        );
+        assert_eq!(
+            is_frictionless(&enums::TransactionStatus::ChallengeRequired),
+            Some(false)
+        );
+        assert_eq!(is_frictionless(&enums::TransactionStatus::Failure), None);
+    }
+
+    #[test]
+    fn test_connector_authentication_outcome() {
+        use enums::{AttemptStatus, AuthenticationType};
+
+        // Redirection of the payer for a challenge
+        assert_eq!(
+            get_connector_authentication_outcome(
+                AuthenticationType::ThreeDs,
+                AttemptStatus::Pending,
+                AttemptStatus::AuthenticationPending,
+                true,
+                false,
+            ),
+            Some(false)
+        );
+        // Authorized without any redirection
+        assert_eq!(
+            get_connector_authentication_outcome(
+                AuthenticationType::ThreeDs,
+                AttemptStatus::Pending,
+                AttemptStatus::Charged,
+                false,
+                false,
+            ),
+            Some(true)
+        );
+        // Authorized after the payer came back from a challenge
+        assert_eq!(
+            get_connector_authentication_outcome(
+                AuthenticationType::ThreeDs,
+                AttemptStatus::AuthenticationPending,
+                AttemptStatus::Charged,
+                false,
+                true,
+            ),
+            None
+        );
+        // Synced after being authorized
+        assert_eq!(
+            get_connector_authentication_outcome(
+                AuthenticationType::ThreeDs,
+                AttemptStatus::Authorized,
+                AttemptStatus::Charged,
+                false,
+                false,
+            ),
+            None
+        );
+        // Payments without 3DS
+        assert_eq!(
+            get_connector_authentication_outcome(
+                AuthenticationType::NoThreeDs,
+                AttemptStatus::Pending,
+                AttemptStatus::Charged,
+                false,
+                false,
+            ),
+            None
+        );
+    }
+}
@@ -206,6 +206,7 @@ pub fn construct_router_data<F: Clone, Req, Res>(
         connector_mandate_request_reference_id: None,
         authentication_id: None,
         psd2_sca_exemption_type,
+        profile_id: None,
     })
 }
 
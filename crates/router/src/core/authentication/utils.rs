@@ -1,5 +1,7 @@
-use error_stack::ResultExt;
+use common_utils::ext_traits::StringExt;
+use error_stack::{report, ResultExt};
 use hyperswitch_domain_models::router_data_v2::ExternalAuthenticationFlowData;
+use router_env::logger;
 
 use crate::{
     consts,
@@ -186,7 +188,7 @@ pub async fn create_new_authentication(
     organization_id: common_utils::id_type::OrganizationId,
 ) -> RouterResult<storage::Authentication> {
     let authentication_id =
-        common_utils::generate_id_with_default_len(consts::AUTHENTICATION_ID_PREFIX);
+        common_utils::generate_resource_id_with_default_len(consts::AUTHENTICATION_ID_PREFIX);
     let new_authorization = storage::AuthenticationNew {
         authentication_id: authentication_id.clone(),
         merchant_id,
@@ -268,14 +270,10 @@ where
     Ok(router_data)
 }
 
-pub async fn get_authentication_connector_data(
-    state: &SessionState,
-    key_store: &domain::MerchantKeyStore,
+/// Returns the authentication connectors configured on the profile, in their order of preference
+pub fn get_authentication_connectors(
     business_profile: &domain::Profile,
-) -> RouterResult<(
-    common_enums::AuthenticationConnectors,
-    payments::helpers::MerchantConnectorAccountType,
-)> {
+) -> RouterResult<Vec<common_enums::AuthenticationConnectors>> {
     let authentication_details = business_profile
         .authentication_connector_details
         .clone()
@@ -284,10 +282,8 @@ pub async fn get_authentication_connector_data(
             message: "authentication_connector_details is not available in business profile".into(),
         })
         .attach_printable("authentication_connector_details not configured by the merchant")?;
-    let authentication_connector = authentication_details
-        .authentication_connectors
-        .first()
-        .ok_or(errors::ApiErrorResponse::UnprocessableEntity {
+    if authentication_details.authentication_connectors.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::UnprocessableEntity {
             message: format!(
                 "No authentication_connector found for profile_id {:?}",
                 business_profile.get_id()
@@ -295,18 +291,96 @@ pub async fn get_authentication_connector_data(
         })
         .attach_printable(
             "No authentication_connector found from merchant_account.authentication_details",
-        )?
-        .to_owned();
-    let profile_id = business_profile.get_id();
-    let authentication_connector_mca = payments::helpers::get_merchant_connector_account(
+        ));
+    }
+    Ok(authentication_details.authentication_connectors)
+}
+
+pub async fn get_authentication_connector_account(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
+    authentication_connector: common_enums::AuthenticationConnectors,
+    merchant_connector_id: Option<&common_utils::id_type::MerchantConnectorAccountId>,
+) -> RouterResult<payments::helpers::MerchantConnectorAccountType> {
+    payments::helpers::get_merchant_connector_account(
         state,
         &business_profile.merchant_id,
         None,
         key_store,
-        profile_id,
+        business_profile.get_id(),
         authentication_connector.to_string().as_str(),
-        None,
+        merchant_connector_id,
+    )
+    .await
+}
+
+/// Returns the connector which performed the authentication, which need not be the preferred
+/// authentication connector of the profile if the authentication had fallen back to another one
+pub async fn get_authentication_connector_data_for_authentication(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
+    authentication: &storage::Authentication,
+) -> RouterResult<(
+    common_enums::AuthenticationConnectors,
+    payments::helpers::MerchantConnectorAccountType,
+)> {
+    let authentication_connector = authentication
+        .authentication_connector
+        .parse_enum("AuthenticationConnectors")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid authentication_connector in authentication")?;
+    let authentication_connector_mca = get_authentication_connector_account(
+        state,
+        key_store,
+        business_profile,
+        authentication_connector,
+        Some(&authentication.merchant_connector_id),
     )
     .await?;
     Ok((authentication_connector, authentication_connector_mca))
 }
+
+/// Performs the pre-authentication with the authentication connectors of the profile in their
+/// order of preference. When a connector errors or times out, the pre-authentication falls back
+/// to the next connector, and the authentication of the connector which ultimately performed the
+/// pre-authentication is returned.
+pub async fn perform_pre_authentication_with_fallback<F, Fut>(
+    business_profile: &domain::Profile,
+    mut pre_authenticate: F,
+) -> RouterResult<storage::Authentication>
+where
+    F: FnMut(common_enums::AuthenticationConnectors) -> Fut,
+    Fut: std::future::Future<Output = RouterResult<storage::Authentication>>,
+{
+    let authentication_connectors = get_authentication_connectors(business_profile)?;
+    let mut authentication_connectors = authentication_connectors.into_iter().peekable();
+
+    while let Some(authentication_connector) = authentication_connectors.next() {
+        let is_fallback_available = authentication_connectors.peek().is_some();
+        match pre_authenticate(authentication_connector).await {
+            Ok(authentication)
+                if authentication.authentication_status.is_failed() && is_fallback_available =>
+            {
+                logger::warn!(
+                    %authentication_connector,
+                    authentication_id = %authentication.authentication_id,
+                    error_code = ?authentication.error_code,
+                    "Pre-authentication failed, falling back to the next authentication connector"
+                );
+            }
+            Err(error) if is_fallback_available => {
+                logger::warn!(
+                    %authentication_connector,
+                    ?error,
+                    "Pre-authentication errored, falling back to the next authentication connector"
+                );
+            }
+            result => return result,
+        }
+    }
+
+    Err(report!(errors::ApiErrorResponse::InternalServerError))
+        .attach_printable("No authentication connector performed the pre-authentication")
+}
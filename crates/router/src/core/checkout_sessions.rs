@@ -0,0 +1,572 @@
+//! Checkout sessions, which hold the cart and the details of the customer while the customer is
+//! browsing, before any payment exists. The cart of an open session can be updated as it changes,
+//! and the session is converted to a payment only when the checkout is confirmed, so that the
+//! payment is not updated again and again during the browsing. The sessions are held in redis
+//! until they expire, and the details of the customer are encrypted with the key of the merchant.
+
+use api_models::{
+    checkout_sessions::{
+        self as checkout_session_types, CheckoutSessionCreateRequest, CheckoutSessionCustomer,
+        CheckoutSessionResponse, CheckoutSessionStatus, CheckoutSessionUpdateRequest,
+    },
+    enums as api_enums,
+    payments::{self as payment_types, OrderDetailsWithAmount},
+};
+use common_utils::{
+    encryption::Encryption,
+    ext_traits::{Encode, StringExt},
+    id_type, type_name,
+    types::{keymanager::Identifier, MinorUnit},
+};
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::payments::HeaderPayload;
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use url::Url;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        payments,
+        utils::{self as core_utils, GetProfileId},
+    },
+    routes::{app::ReqState, SessionState},
+    services,
+    types::{api, domain, storage},
+};
+
+/// The checkout session held in redis until it expires
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredCheckoutSession {
+    id: String,
+    merchant_id: id_type::MerchantId,
+    profile_id: id_type::ProfileId,
+    status: CheckoutSessionStatus,
+    currency: api_enums::Currency,
+    line_items: Vec<OrderDetailsWithAmount>,
+    shipping_cost: Option<MinorUnit>,
+    customer_id: Option<id_type::CustomerId>,
+    customer: Option<Encryption>,
+    description: Option<String>,
+    return_url: Option<Url>,
+    metadata: Option<serde_json::Value>,
+    payment_id: Option<id_type::PaymentId>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    updated_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    expires_at: PrimitiveDateTime,
+}
+
+impl GetProfileId for StoredCheckoutSession {
+    fn get_profile_id(&self) -> Option<&id_type::ProfileId> {
+        Some(&self.profile_id)
+    }
+}
+
+fn get_checkout_session_redis_key(
+    merchant_id: &id_type::MerchantId,
+    checkout_session_id: &str,
+) -> String {
+    format!(
+        "checkout_session_{}_{checkout_session_id}",
+        merchant_id.get_string_repr()
+    )
+}
+
+fn get_checkout_session_not_found_error() -> errors::ApiErrorResponse {
+    errors::ApiErrorResponse::GenericNotFoundError {
+        message: "Checkout session not found or expired".to_string(),
+    }
+}
+
+/// Validates the line items of the cart and returns the sum of their prices
+fn get_subtotal(line_items: &[OrderDetailsWithAmount]) -> RouterResult<MinorUnit> {
+    if line_items.is_empty()
+        || line_items.len() > checkout_session_types::MAX_CHECKOUT_SESSION_LINE_ITEMS
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "line_items must contain between 1 and {} items",
+                checkout_session_types::MAX_CHECKOUT_SESSION_LINE_ITEMS
+            ),
+        }));
+    }
+
+    line_items
+        .iter()
+        .try_fold(0i64, |subtotal, line_item| {
+            if line_item.product_name.trim().is_empty() {
+                return Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "product_name of the line items must not be empty".to_string(),
+                });
+            }
+            if line_item.quantity == 0 || line_item.amount < MinorUnit::zero() {
+                return Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "line item `{}` must have a positive quantity and a non negative amount",
+                        line_item.product_name
+                    ),
+                });
+            }
+            line_item
+                .amount
+                .get_amount_as_i64()
+                .checked_mul(i64::from(line_item.quantity))
+                .and_then(|line_total| subtotal.checked_add(line_total))
+                .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "total amount of the line items is too large".to_string(),
+                })
+        })
+        .map(MinorUnit::new)
+        .map_err(|error| report!(error))
+}
+
+/// Validates the cart and returns its subtotal and total amount
+fn get_cart_amounts(
+    line_items: &[OrderDetailsWithAmount],
+    shipping_cost: Option<MinorUnit>,
+) -> RouterResult<(MinorUnit, MinorUnit)> {
+    let subtotal = get_subtotal(line_items)?;
+    let shipping_cost = shipping_cost.unwrap_or_default();
+    if shipping_cost < MinorUnit::zero() {
+        return Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "shipping_cost",
+        }));
+    }
+
+    let total_amount = subtotal
+        .get_amount_as_i64()
+        .checked_add(shipping_cost.get_amount_as_i64())
+        .map(MinorUnit::new)
+        .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+            message: "total amount of the cart is too large".to_string(),
+        })?;
+    if total_amount == MinorUnit::zero() {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "total amount of the cart must be greater than zero".to_string(),
+        }));
+    }
+
+    Ok((subtotal, total_amount))
+}
+
+async fn encrypt_customer(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    customer: CheckoutSessionCustomer,
+) -> RouterResult<Encryption> {
+    let customer = customer
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the customer of the checkout session")?;
+
+    domain::types::crypto_operation(
+        &state.into(),
+        type_name!(storage::PaymentIntent),
+        domain::types::CryptoOperation::Encrypt(Secret::<String>::new(customer)),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt the customer of the checkout session")
+    .map(Encryption::from)
+}
+
+async fn decrypt_customer(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    customer: Encryption,
+) -> RouterResult<CheckoutSessionCustomer> {
+    domain::types::crypto_operation::<String, masking::WithType>(
+        &state.into(),
+        type_name!(storage::PaymentIntent),
+        domain::types::CryptoOperation::Decrypt(customer),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to decrypt the customer of the checkout session")?
+    .into_inner()
+    .peek()
+    .parse_struct("CheckoutSessionCustomer")
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to deserialize the customer of the checkout session")
+}
+
+async fn get_checkout_session(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    checkout_session_id: &str,
+) -> RouterResult<StoredCheckoutSession> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    redis_conn
+        .get_key::<Option<String>>(
+            &get_checkout_session_redis_key(merchant_id, checkout_session_id)
+                .as_str()
+                .into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the checkout session from redis")?
+        .ok_or_else(|| report!(get_checkout_session_not_found_error()))?
+        .parse_struct("StoredCheckoutSession")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize the checkout session")
+}
+
+/// Writes the updated session to redis, keeping the expiry with which it was created
+async fn update_checkout_session(
+    state: &SessionState,
+    checkout_session: &StoredCheckoutSession,
+) -> RouterResult<()> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    redis_conn
+        .serialize_and_set_key_without_modifying_ttl(
+            &get_checkout_session_redis_key(&checkout_session.merchant_id, &checkout_session.id)
+                .as_str()
+                .into(),
+            checkout_session,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the checkout session in redis")
+}
+
+fn validate_checkout_session_is_open(checkout_session: &StoredCheckoutSession) -> RouterResult<()> {
+    match checkout_session.status {
+        CheckoutSessionStatus::Open => Ok(()),
+        CheckoutSessionStatus::Converted => {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The checkout session has already been converted to a payment".to_string(),
+            }))
+        }
+    }
+}
+
+async fn get_checkout_session_response(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    checkout_session: StoredCheckoutSession,
+) -> RouterResult<CheckoutSessionResponse> {
+    let (subtotal, total_amount) =
+        get_cart_amounts(&checkout_session.line_items, checkout_session.shipping_cost)?;
+    let customer = match checkout_session.customer {
+        Some(customer) => Some(decrypt_customer(state, key_store, customer).await?),
+        None => None,
+    };
+
+    Ok(CheckoutSessionResponse {
+        id: checkout_session.id,
+        status: checkout_session.status,
+        profile_id: checkout_session.profile_id,
+        currency: checkout_session.currency,
+        line_items: checkout_session.line_items,
+        subtotal,
+        shipping_cost: checkout_session.shipping_cost,
+        total_amount,
+        customer_id: checkout_session.customer_id,
+        customer,
+        description: checkout_session.description,
+        return_url: checkout_session.return_url,
+        metadata: checkout_session.metadata,
+        payment_id: checkout_session.payment_id,
+        created_at: checkout_session.created_at,
+        updated_at: checkout_session.updated_at,
+        expires_at: checkout_session.expires_at,
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn create_checkout_session(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id_from_auth: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    request: CheckoutSessionCreateRequest,
+) -> RouterResponse<CheckoutSessionResponse> {
+    get_cart_amounts(&request.line_items, request.shipping_cost)?;
+
+    let session_expiry = request
+        .session_expiry
+        .unwrap_or(checkout_session_types::DEFAULT_CHECKOUT_SESSION_EXPIRY);
+    if !(60..=checkout_session_types::MAX_CHECKOUT_SESSION_EXPIRY).contains(&session_expiry) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "session_expiry must be between 60 and {} seconds",
+                checkout_session_types::MAX_CHECKOUT_SESSION_EXPIRY
+            ),
+        }));
+    }
+
+    let profile_id = request
+        .profile_id
+        .clone()
+        .or(profile_id_from_auth.clone())
+        .or(merchant_account.default_profile.clone())
+        .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "profile_id",
+        })?;
+    core_utils::validate_and_get_business_profile(
+        state.store.as_ref(),
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    let customer = match request.customer {
+        Some(customer) => Some(encrypt_customer(&state, &key_store, customer).await?),
+        None => None,
+    };
+
+    let now = common_utils::date_time::now();
+    let checkout_session = StoredCheckoutSession {
+        id: common_utils::generate_id(consts::ID_LENGTH, "cs"),
+        merchant_id: merchant_account.get_id().clone(),
+        profile_id,
+        status: CheckoutSessionStatus::Open,
+        currency: request.currency,
+        line_items: request.line_items,
+        shipping_cost: request.shipping_cost,
+        customer_id: request.customer_id,
+        customer,
+        description: request.description,
+        return_url: request.return_url,
+        metadata: request.metadata,
+        payment_id: None,
+        created_at: now,
+        updated_at: now,
+        expires_at: now.saturating_add(time::Duration::seconds(i64::from(session_expiry))),
+    };
+    core_utils::validate_profile_id_from_auth_layer(profile_id_from_auth, &checkout_session)?;
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    redis_conn
+        .serialize_and_set_key_with_expiry(
+            &get_checkout_session_redis_key(&checkout_session.merchant_id, &checkout_session.id)
+                .as_str()
+                .into(),
+            &checkout_session,
+            i64::from(session_expiry),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add the checkout session in redis")?;
+
+    let response = get_checkout_session_response(&state, &key_store, checkout_session).await?;
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_checkout_session(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    checkout_session_id: String,
+) -> RouterResponse<CheckoutSessionResponse> {
+    let checkout_session =
+        get_checkout_session(&state, merchant_account.get_id(), &checkout_session_id).await?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &checkout_session)?;
+
+    let response = get_checkout_session_response(&state, &key_store, checkout_session).await?;
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+#[instrument(skip_all)]
+pub async fn update_checkout_session_cart(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    checkout_session_id: String,
+    request: CheckoutSessionUpdateRequest,
+) -> RouterResponse<CheckoutSessionResponse> {
+    let mut checkout_session =
+        get_checkout_session(&state, merchant_account.get_id(), &checkout_session_id).await?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &checkout_session)?;
+    validate_checkout_session_is_open(&checkout_session)?;
+
+    if let Some(line_items) = request.line_items {
+        checkout_session.line_items = line_items;
+    }
+    if request.shipping_cost.is_some() {
+        checkout_session.shipping_cost = request.shipping_cost;
+    }
+    get_cart_amounts(&checkout_session.line_items, checkout_session.shipping_cost)?;
+
+    if request.customer_id.is_some() {
+        checkout_session.customer_id = request.customer_id;
+    }
+    if let Some(customer) = request.customer {
+        checkout_session.customer = Some(encrypt_customer(&state, &key_store, customer).await?);
+    }
+    if request.description.is_some() {
+        checkout_session.description = request.description;
+    }
+    if request.return_url.is_some() {
+        checkout_session.return_url = request.return_url;
+    }
+    if request.metadata.is_some() {
+        checkout_session.metadata = request.metadata;
+    }
+    checkout_session.updated_at = common_utils::date_time::now();
+
+    update_checkout_session(&state, &checkout_session).await?;
+
+    let response = get_checkout_session_response(&state, &key_store, checkout_session).await?;
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+/// Fills the payment request with the cart and the details of the customer of the session. The
+/// amount, the currency and the items of the payment can only come from the session.
+fn get_payment_request(
+    checkout_session: &StoredCheckoutSession,
+    customer: Option<CheckoutSessionCustomer>,
+    mut request: payment_types::PaymentsRequest,
+) -> RouterResult<payment_types::PaymentsRequest> {
+    if request.amount.is_some()
+        || request.currency.is_some()
+        || request.order_details.is_some()
+        || request.shipping_cost.is_some()
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount, currency, order_details and shipping_cost of the payment are taken from the checkout session".to_string(),
+        }));
+    }
+    if request
+        .profile_id
+        .as_ref()
+        .is_some_and(|profile_id| profile_id != &checkout_session.profile_id)
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "profile_id of the payment must be that of the checkout session".to_string(),
+        }));
+    }
+
+    let subtotal = get_subtotal(&checkout_session.line_items)?;
+    request.payment_id = Some(payment_types::PaymentIdType::PaymentIntentId(
+        id_type::PaymentId::default(),
+    ));
+    request.amount = Some(subtotal.into());
+    request.currency = Some(checkout_session.currency);
+    request.shipping_cost = checkout_session.shipping_cost;
+    request.order_details = Some(checkout_session.line_items.clone());
+    request.profile_id = Some(checkout_session.profile_id.clone());
+    request.customer_id = request
+        .customer_id
+        .or_else(|| checkout_session.customer_id.clone());
+    request.description = request
+        .description
+        .or_else(|| checkout_session.description.clone());
+    request.return_url = request
+        .return_url
+        .or_else(|| checkout_session.return_url.clone());
+    request.metadata = request
+        .metadata
+        .or_else(|| checkout_session.metadata.clone());
+
+    if let Some(customer) = customer {
+        request.name = request.name.or(customer.name);
+        request.email = request.email.or(customer.email);
+        request.phone = request.phone.or(customer.phone);
+        request.phone_country_code = request.phone_country_code.or(customer.phone_country_code);
+    }
+
+    Ok(request)
+}
+
+/// Converts the session to a payment. The payment is created with the cart and the details of the
+/// customer of the session, along with the payment method details of the request, and is
+/// confirmed if the request asks for it. The session cannot be updated or confirmed again once
+/// the payment is created.
+#[allow(clippy::too_many_arguments)]
+#[instrument(skip_all)]
+pub async fn confirm_checkout_session(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    checkout_session_id: String,
+    request: payment_types::PaymentsRequest,
+    header_payload: HeaderPayload,
+    platform_merchant_account: Option<domain::MerchantAccount>,
+) -> RouterResponse<payment_types::PaymentsResponse> {
+    let mut checkout_session =
+        get_checkout_session(&state, merchant_account.get_id(), &checkout_session_id).await?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id.clone(), &checkout_session)?;
+    validate_checkout_session_is_open(&checkout_session)?;
+
+    let customer = match checkout_session.customer.clone() {
+        Some(customer) => Some(decrypt_customer(&state, &key_store, customer).await?),
+        None => None,
+    };
+    let payment_request = get_payment_request(&checkout_session, customer, request)?;
+
+    let payments_response = Box::pin(payments::payments_core::<
+        api::Authorize,
+        payment_types::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments::PaymentData<api::Authorize>,
+    >(
+        state.clone(),
+        req_state,
+        merchant_account,
+        profile_id,
+        key_store,
+        payments::PaymentCreate,
+        payment_request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        header_payload,
+        platform_merchant_account,
+    ))
+    .await?;
+
+    let payment_id = match &payments_response {
+        services::ApplicationResponse::Json(response)
+        | services::ApplicationResponse::JsonWithHeaders((response, _)) => {
+            response.payment_id.clone()
+        }
+        _ => {
+            return Err(report!(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable("Unexpected response received while creating the payment");
+        }
+    };
+
+    checkout_session.status = CheckoutSessionStatus::Converted;
+    checkout_session.payment_id = Some(payment_id);
+    checkout_session.updated_at = common_utils::date_time::now();
+    update_checkout_session(&state, &checkout_session).await?;
+
+    Ok(payments_response)
+}
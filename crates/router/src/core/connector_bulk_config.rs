@@ -0,0 +1,335 @@
+//! Bulk configuration of the merchant connector accounts of a connector across many business
+//! profiles of a merchant account. A template of the connector account is applied to every profile,
+//! along with the overrides of the profile such as its credentials, creating the connector account
+//! in the profiles which do not have one and updating it in the others. A dry run only validates
+//! the configuration of every profile. Every profile is configured independently, so that a failure
+//! for a profile is reported without affecting the others.
+
+use std::collections::HashSet;
+
+use api_models::admin::{
+    MerchantConnectorBulkConfigAction, MerchantConnectorBulkConfigRequest,
+    MerchantConnectorBulkConfigResponse, MerchantConnectorBulkConfigResult,
+    MerchantConnectorBulkConfigStatus, MerchantConnectorCreate, MerchantConnectorProfileOverride,
+    MerchantConnectorUpdate,
+};
+use common_utils::id_type;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::{
+        admin, connector_deletion,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    },
+    routes::SessionState,
+    services,
+    types::domain,
+    utils,
+};
+
+fn validate_bulk_config_request(request: &MerchantConnectorBulkConfigRequest) -> RouterResult<()> {
+    utils::when(request.profiles.is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "profiles must not be empty".to_string(),
+        }))
+    })?;
+
+    utils::when(
+        request.profiles.len() > consts::MAX_MERCHANT_CONNECTOR_BULK_CONFIG_PROFILES,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "At most {} profiles can be configured at once",
+                    consts::MAX_MERCHANT_CONNECTOR_BULK_CONFIG_PROFILES
+                ),
+            }))
+        },
+    )?;
+
+    let mut profile_ids = HashSet::new();
+    request.profiles.iter().try_for_each(|profile| {
+        utils::when(!profile_ids.insert(&profile.profile_id), || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "profile {} is present more than once in profiles",
+                    profile.profile_id.get_string_repr()
+                ),
+            }))
+        })
+    })
+}
+
+fn get_create_request(
+    template: &MerchantConnectorCreate,
+    profile: &MerchantConnectorProfileOverride,
+) -> MerchantConnectorCreate {
+    MerchantConnectorCreate {
+        profile_id: Some(profile.profile_id.clone()),
+        merchant_connector_id: None,
+        connector_account_details: profile
+            .connector_account_details
+            .clone()
+            .or(template.connector_account_details.clone()),
+        connector_label: profile
+            .connector_label
+            .clone()
+            .or(template.connector_label.clone()),
+        metadata: profile.metadata.clone().or(template.metadata.clone()),
+        ..template.clone()
+    }
+}
+
+fn get_update_request(
+    template: &MerchantConnectorCreate,
+    profile: &MerchantConnectorProfileOverride,
+) -> MerchantConnectorUpdate {
+    let create_request = get_create_request(template, profile);
+    MerchantConnectorUpdate {
+        connector_type: create_request.connector_type,
+        connector_label: create_request.connector_label,
+        connector_account_details: create_request.connector_account_details,
+        payment_methods_enabled: create_request.payment_methods_enabled,
+        connector_webhook_details: create_request.connector_webhook_details,
+        metadata: create_request.metadata,
+        test_mode: create_request.test_mode,
+        disabled: create_request.disabled,
+        frm_configs: create_request.frm_configs,
+        pm_auth_config: create_request.pm_auth_config,
+        status: create_request.status,
+        additional_merchant_data: create_request.additional_merchant_data,
+        connector_wallets_details: create_request.connector_wallets_details,
+        credentials_expires_at: create_request.credentials_expires_at,
+    }
+}
+
+/// Finds the merchant connector account of the connector in the profile, if it has one which is
+/// not deleted
+async fn find_profile_connector_account(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    template: &MerchantConnectorCreate,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Option<domain::MerchantConnectorAccount>> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+
+    db.find_business_profile_by_merchant_id_profile_id(
+        key_manager_state,
+        key_store,
+        merchant_account.get_id(),
+        profile_id,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    })?;
+
+    match db
+        .find_merchant_connector_account_by_profile_id_connector_name(
+            key_manager_state,
+            profile_id,
+            &template.connector_name.to_string(),
+            key_store,
+        )
+        .await
+    {
+        Ok(merchant_connector_account) => Ok(connector_deletion::get_soft_deletion(
+            db,
+            &merchant_connector_account.get_id(),
+        )
+        .await?
+        .is_none()
+        .then_some(merchant_connector_account)),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the merchant connector account of the profile"),
+    }
+}
+
+fn get_merchant_connector_id(
+    response: services::ApplicationResponse<api_models::admin::MerchantConnectorResponse>,
+) -> RouterResult<id_type::MerchantConnectorAccountId> {
+    match response {
+        services::ApplicationResponse::Json(merchant_connector_account) => {
+            Ok(merchant_connector_account.merchant_connector_id)
+        }
+        _ => Err(errors::ApiErrorResponse::InternalServerError).attach_printable(
+            "Unexpected response received while configuring the merchant connector account",
+        ),
+    }
+}
+
+/// Applies the template to the profile, or only validates it in case of a dry run, returning the
+/// change made and the identifier for the merchant connector account of the profile
+async fn apply_to_profile(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    template: &MerchantConnectorCreate,
+    profile: &MerchantConnectorProfileOverride,
+    dry_run: bool,
+) -> RouterResult<(
+    MerchantConnectorBulkConfigAction,
+    Option<id_type::MerchantConnectorAccountId>,
+)> {
+    let existing_connector_account = find_profile_connector_account(
+        state,
+        merchant_account,
+        key_store,
+        template,
+        &profile.profile_id,
+    )
+    .await?;
+
+    match existing_connector_account {
+        None => {
+            let request = get_create_request(template, profile);
+            if dry_run {
+                admin::validate_connector_account_details(
+                    &request.connector_name,
+                    request.connector_account_details.clone(),
+                    &request.metadata,
+                )?;
+                return Ok((MerchantConnectorBulkConfigAction::Create, None));
+            }
+
+            let response = Box::pin(admin::create_connector(
+                state.clone(),
+                request,
+                merchant_account.clone(),
+                None,
+                key_store.clone(),
+            ))
+            .await?;
+            Ok((
+                MerchantConnectorBulkConfigAction::Create,
+                Some(get_merchant_connector_id(response)?),
+            ))
+        }
+        Some(connector_account) => {
+            let merchant_connector_id = connector_account.get_id();
+            let request = get_update_request(template, profile);
+            if dry_run {
+                if request.connector_account_details.is_some() {
+                    admin::validate_connector_account_details(
+                        &template.connector_name,
+                        request.connector_account_details.clone(),
+                        &request.metadata.clone().or(connector_account.metadata),
+                    )?;
+                }
+                return Ok((
+                    MerchantConnectorBulkConfigAction::Update,
+                    Some(merchant_connector_id),
+                ));
+            }
+
+            Box::pin(admin::update_connector(
+                state.clone(),
+                merchant_account.get_id(),
+                None,
+                &merchant_connector_id,
+                request,
+            ))
+            .await?;
+            Ok((
+                MerchantConnectorBulkConfigAction::Update,
+                Some(merchant_connector_id),
+            ))
+        }
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn apply_bulk_connector_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: MerchantConnectorBulkConfigRequest,
+) -> RouterResponse<MerchantConnectorBulkConfigResponse> {
+    validate_bulk_config_request(&request)?;
+
+    let mut results = Vec::with_capacity(request.profiles.len());
+    for profile in &request.profiles {
+        let result = match apply_to_profile(
+            &state,
+            &merchant_account,
+            &key_store,
+            &request.template,
+            profile,
+            request.dry_run,
+        )
+        .await
+        {
+            Ok((action, merchant_connector_id)) => MerchantConnectorBulkConfigResult {
+                profile_id: profile.profile_id.clone(),
+                action: Some(action),
+                status: if request.dry_run {
+                    MerchantConnectorBulkConfigStatus::Validated
+                } else {
+                    MerchantConnectorBulkConfigStatus::Succeeded
+                },
+                merchant_connector_id,
+                error_message: None,
+            },
+            Err(error) => {
+                logger::warn!(
+                    ?error,
+                    profile_id = ?profile.profile_id,
+                    "Failed to apply the bulk connector configuration to the profile"
+                );
+                MerchantConnectorBulkConfigResult {
+                    profile_id: profile.profile_id.clone(),
+                    action: None,
+                    status: MerchantConnectorBulkConfigStatus::Failed,
+                    merchant_connector_id: None,
+                    error_message: Some(error.current_context().to_string()),
+                }
+            }
+        };
+        results.push(result);
+    }
+
+    let failed_count = results
+        .iter()
+        .filter(|result| result.status == MerchantConnectorBulkConfigStatus::Failed)
+        .count();
+
+    Ok(services::ApplicationResponse::Json(
+        MerchantConnectorBulkConfigResponse {
+            connector_name: request.template.connector_name,
+            dry_run: request.dry_run,
+            failed_count,
+            results,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_request(profile_ids: &[&str]) -> MerchantConnectorBulkConfigRequest {
+        serde_json::from_value(serde_json::json!({
+            "template": {
+                "connector_type": "payment_processor",
+                "connector_name": "stripe",
+            },
+            "profiles": profile_ids
+                .iter()
+                .map(|profile_id| serde_json::json!({ "profile_id": profile_id }))
+                .collect::<Vec<_>>(),
+        }))
+        .expect("Failed to deserialize the bulk config request")
+    }
+
+    #[test]
+    fn test_validate_bulk_config_request() {
+        assert!(validate_bulk_config_request(&get_request(&["pro_1", "pro_2"])).is_ok());
+        assert!(validate_bulk_config_request(&get_request(&[])).is_err());
+        assert!(validate_bulk_config_request(&get_request(&["pro_1", "pro_1"])).is_err());
+    }
+}
@@ -0,0 +1,715 @@
+//! Tracking of the credentials of the merchant connector accounts. The time at which the
+//! credentials were set, the time at which they expire where it is known, and the time at which
+//! they were last verified with the connector are stored for each merchant connector account. A
+//! reminder webhook is sent ahead of the expiry of the credentials, another one is sent once they
+//! have expired, and the connector is excluded from routing from then on until the credentials
+//! are rotated.
+
+#[cfg(feature = "olap")]
+use std::str::FromStr;
+
+use api_models::{
+    admin::{
+        ConnectorCredentialsResponse, ConnectorCredentialsStatus,
+        ConnectorCredentialsUpdateRequest, ExpiringConnectorCredentialsRequest,
+    },
+    routing::RoutableConnectorChoice,
+    webhooks,
+};
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+#[cfg(feature = "olap")]
+use crate::core::verify_connector;
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils, webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{api::enums as api_enums, domain, storage, transformers::ForeignFrom},
+};
+
+const CONNECTOR_CREDENTIALS_EXPIRY_TASK: &str = "CONNECTOR_CREDENTIALS_EXPIRY";
+const CONNECTOR_CREDENTIALS_EXPIRY_TAG: &str = "MERCHANT_CONNECTOR_ACCOUNT";
+
+/// The metadata of the credentials of a merchant connector account, as stored
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CredentialsMetadata {
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_at: Option<PrimitiveDateTime>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub expires_at: Option<PrimitiveDateTime>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub last_verified_at: Option<PrimitiveDateTime>,
+}
+
+fn get_credentials_status(
+    expires_at: Option<PrimitiveDateTime>,
+    now: PrimitiveDateTime,
+) -> ConnectorCredentialsStatus {
+    match expires_at {
+        Some(expires_at) if expires_at <= now => ConnectorCredentialsStatus::Expired,
+        Some(expires_at)
+            if expires_at
+                <= now.saturating_add(time::Duration::days(
+                    consts::CONNECTOR_CREDENTIALS_EXPIRY_REMINDER_DAYS,
+                )) =>
+        {
+            ConnectorCredentialsStatus::ExpiringSoon
+        }
+        Some(_) | None => ConnectorCredentialsStatus::Active,
+    }
+}
+
+fn build_credentials_response(
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    metadata: &CredentialsMetadata,
+    now: PrimitiveDateTime,
+) -> ConnectorCredentialsResponse {
+    ConnectorCredentialsResponse {
+        merchant_connector_id: merchant_connector_account.get_id(),
+        connector_name: merchant_connector_account.connector_name.clone(),
+        connector_label: merchant_connector_account.connector_label.clone(),
+        profile_id: merchant_connector_account.profile_id.clone(),
+        status: get_credentials_status(metadata.expires_at, now),
+        created_at: metadata.created_at,
+        expires_at: metadata.expires_at,
+        last_verified_at: metadata.last_verified_at,
+    }
+}
+
+pub fn validate_expires_at(expires_at: Option<PrimitiveDateTime>) -> RouterResult<()> {
+    if expires_at.is_some_and(|expires_at| expires_at <= common_utils::date_time::now()) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The expiry of the credentials must be in the future".to_string(),
+        }));
+    }
+    Ok(())
+}
+
+pub async fn get_credentials_metadata(
+    db: &dyn StorageInterface,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<Option<CredentialsMetadata>> {
+    match db
+        .find_config_by_key(&merchant_connector_id.get_credentials_metadata_config_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("CredentialsMetadata")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse credentials metadata")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch credentials metadata"),
+    }
+}
+
+async fn store_credentials_metadata(
+    db: &dyn StorageInterface,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    metadata: &CredentialsMetadata,
+    is_existing: bool,
+) -> RouterResult<()> {
+    let key = merchant_connector_id.get_credentials_metadata_config_key();
+    let serialized_metadata = metadata
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize credentials metadata")?;
+
+    if is_existing {
+        db.update_config_by_key(
+            &key,
+            configs::ConfigUpdate::Update {
+                config: Some(serialized_metadata),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update credentials metadata")?;
+    } else {
+        db.insert_config(configs::ConfigNew {
+            key,
+            config: serialized_metadata,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert credentials metadata")?;
+    }
+
+    Ok(())
+}
+
+fn get_process_tracker_id(merchant_connector_id: &id_type::MerchantConnectorAccountId) -> String {
+    let runner = storage::ProcessTrackerRunner::ConnectorCredentialsExpiryWorkflow;
+    format!(
+        "{runner}_{CONNECTOR_CREDENTIALS_EXPIRY_TASK}_{}",
+        merchant_connector_id.get_string_repr()
+    )
+}
+
+/// Schedules the check of the expiry of the credentials of the merchant connector account at the
+/// start of their reminder period, replacing the check already scheduled
+async fn schedule_credentials_expiry_check(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    expires_at: PrimitiveDateTime,
+) -> RouterResult<()> {
+    let process_tracker_id = get_process_tracker_id(merchant_connector_id);
+    let schedule_time = (expires_at
+        - time::Duration::days(consts::CONNECTOR_CREDENTIALS_EXPIRY_REMINDER_DAYS))
+    .max(common_utils::date_time::now());
+
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching CONNECTOR_CREDENTIALS_EXPIRY task")?;
+
+    match existing_process {
+        Some(process) => db
+            .as_scheduler()
+            .reset_process(process, schedule_time)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while resetting CONNECTOR_CREDENTIALS_EXPIRY task")?,
+        None => {
+            let tracking_data = storage::ConnectorCredentialsExpiryTrackingData {
+                merchant_id: merchant_id.clone(),
+                merchant_connector_id: merchant_connector_id.clone(),
+            };
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                CONNECTOR_CREDENTIALS_EXPIRY_TASK,
+                storage::ProcessTrackerRunner::ConnectorCredentialsExpiryWorkflow,
+                [CONNECTOR_CREDENTIALS_EXPIRY_TAG],
+                tracking_data,
+                None,
+                schedule_time,
+                hyperswitch_domain_models::consts::API_VERSION,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct CONNECTOR_CREDENTIALS_EXPIRY task")?;
+
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed while inserting CONNECTOR_CREDENTIALS_EXPIRY task to process_tracker",
+                )?;
+        }
+    };
+
+    Ok(())
+}
+
+async fn update_and_schedule_credentials_metadata(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    metadata: &CredentialsMetadata,
+    is_existing: bool,
+) -> RouterResult<()> {
+    store_credentials_metadata(db, merchant_connector_id, metadata, is_existing).await?;
+
+    // A check which is already scheduled for credentials without an expiry finishes on its own
+    match metadata.expires_at {
+        Some(expires_at) => {
+            schedule_credentials_expiry_check(db, merchant_id, merchant_connector_id, expires_at)
+                .await
+        }
+        None => Ok(()),
+    }
+}
+
+/// Records the metadata of the credentials of a merchant connector account when they are set or
+/// rotated, or when only their expiry is updated
+pub async fn record_credentials_update(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    is_rotated: bool,
+    expires_at: Option<PrimitiveDateTime>,
+) -> RouterResult<()> {
+    validate_expires_at(expires_at)?;
+
+    let db = state.store.as_ref();
+    let existing_metadata = get_credentials_metadata(db, merchant_connector_id).await?;
+    let is_existing = existing_metadata.is_some();
+
+    let metadata = match (is_rotated, expires_at) {
+        (true, _) => CredentialsMetadata {
+            created_at: Some(common_utils::date_time::now()),
+            expires_at,
+            last_verified_at: None,
+        },
+        (false, Some(expires_at)) => CredentialsMetadata {
+            expires_at: Some(expires_at),
+            ..existing_metadata.unwrap_or_default()
+        },
+        (false, None) => return Ok(()),
+    };
+
+    update_and_schedule_credentials_metadata(
+        db,
+        merchant_id,
+        merchant_connector_id,
+        &metadata,
+        is_existing,
+    )
+    .await
+}
+
+async fn get_merchant_connector_account(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    profile_id: Option<id_type::ProfileId>,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<domain::MerchantConnectorAccount> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_connector_account = db
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            key_manager_state,
+            merchant_id,
+            merchant_connector_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.get_string_repr().to_string(),
+        })?;
+
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &merchant_connector_account)?;
+
+    Ok(merchant_connector_account)
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_credentials_metadata(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id: Option<id_type::ProfileId>,
+    merchant_connector_id: id_type::MerchantConnectorAccountId,
+) -> RouterResponse<ConnectorCredentialsResponse> {
+    let merchant_connector_account =
+        get_merchant_connector_account(&state, &merchant_id, profile_id, &merchant_connector_id)
+            .await?;
+
+    let metadata = get_credentials_metadata(state.store.as_ref(), &merchant_connector_id)
+        .await?
+        .unwrap_or_default();
+
+    Ok(services::ApplicationResponse::Json(
+        build_credentials_response(
+            &merchant_connector_account,
+            &metadata,
+            common_utils::date_time::now(),
+        ),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn update_credentials_metadata(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id: Option<id_type::ProfileId>,
+    merchant_connector_id: id_type::MerchantConnectorAccountId,
+    request: ConnectorCredentialsUpdateRequest,
+) -> RouterResponse<ConnectorCredentialsResponse> {
+    let merchant_connector_account =
+        get_merchant_connector_account(&state, &merchant_id, profile_id, &merchant_connector_id)
+            .await?;
+    validate_expires_at(request.expires_at)?;
+
+    let db = state.store.as_ref();
+    let existing_metadata = get_credentials_metadata(db, &merchant_connector_id).await?;
+    let is_existing = existing_metadata.is_some();
+    let metadata = CredentialsMetadata {
+        expires_at: request.expires_at,
+        ..existing_metadata.unwrap_or_default()
+    };
+
+    update_and_schedule_credentials_metadata(
+        db,
+        &merchant_id,
+        &merchant_connector_id,
+        &metadata,
+        is_existing,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        build_credentials_response(
+            &merchant_connector_account,
+            &metadata,
+            common_utils::date_time::now(),
+        ),
+    ))
+}
+
+/// Verifies the credentials of the merchant connector account with the connector, and records the
+/// time of the verification if they are valid
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn verify_credentials(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id: Option<id_type::ProfileId>,
+    merchant_connector_id: id_type::MerchantConnectorAccountId,
+) -> RouterResponse<ConnectorCredentialsResponse> {
+    let merchant_connector_account =
+        get_merchant_connector_account(&state, &merchant_id, profile_id, &merchant_connector_id)
+            .await?;
+
+    let connector_name = api_enums::Connector::from_str(&merchant_connector_account.connector_name)
+        .change_context(errors::ApiErrorResponse::IncorrectConnectorNameGiven)
+        .attach_printable_lazy(|| {
+            format!(
+                "Invalid connector name: {}",
+                merchant_connector_account.connector_name
+            )
+        })?;
+    let connector_auth = merchant_connector_account
+        .get_connector_account_details()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the connector account details")?;
+
+    verify_connector::verify_connector_auth(&state, connector_name, connector_auth).await?;
+
+    let db = state.store.as_ref();
+    let existing_metadata = get_credentials_metadata(db, &merchant_connector_id).await?;
+    let is_existing = existing_metadata.is_some();
+    let now = common_utils::date_time::now();
+    let metadata = CredentialsMetadata {
+        last_verified_at: Some(now),
+        ..existing_metadata.unwrap_or_default()
+    };
+    store_credentials_metadata(db, &merchant_connector_id, &metadata, is_existing).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        build_credentials_response(&merchant_connector_account, &metadata, now),
+    ))
+}
+
+/// Lists the merchant connector accounts of the merchant whose credentials expire within the
+/// given number of days, including the ones whose credentials have already expired
+#[instrument(skip_all)]
+pub async fn list_expiring_credentials(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id: Option<id_type::ProfileId>,
+    request: ExpiringConnectorCredentialsRequest,
+) -> RouterResponse<Vec<ConnectorCredentialsResponse>> {
+    let within_days = request.within_days.unwrap_or(
+        u32::try_from(consts::CONNECTOR_CREDENTIALS_EXPIRY_REMINDER_DAYS).unwrap_or_default(),
+    );
+    if within_days > consts::MAX_CONNECTOR_CREDENTIALS_EXPIRING_WITHIN_DAYS {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "within_days must not be greater than {}",
+                consts::MAX_CONNECTOR_CREDENTIALS_EXPIRING_WITHIN_DAYS
+            ),
+        }));
+    }
+
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_connector_accounts = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            key_manager_state,
+            &merchant_id,
+            true,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::InternalServerError)?;
+
+    let now = common_utils::date_time::now();
+    let expiring_before = now.saturating_add(time::Duration::days(i64::from(within_days)));
+    let mut expiring_credentials = Vec::new();
+
+    for merchant_connector_account in merchant_connector_accounts {
+        if profile_id
+            .as_ref()
+            .is_some_and(|profile_id| *profile_id != merchant_connector_account.profile_id)
+        {
+            continue;
+        }
+
+        let Some(metadata) =
+            get_credentials_metadata(db, &merchant_connector_account.get_id()).await?
+        else {
+            continue;
+        };
+
+        if metadata
+            .expires_at
+            .is_some_and(|expires_at| expires_at <= expiring_before)
+        {
+            expiring_credentials.push(build_credentials_response(
+                &merchant_connector_account,
+                &metadata,
+                now,
+            ));
+        }
+    }
+
+    expiring_credentials.sort_by_key(|credentials| credentials.expires_at);
+
+    Ok(services::ApplicationResponse::Json(expiring_credentials))
+}
+
+/// Removes the connectors whose credentials have expired from the routed connectors. Fails if the
+/// credentials of all the routed connectors have expired.
+#[instrument(skip_all)]
+pub async fn filter_connectors_with_expired_credentials(
+    db: &dyn StorageInterface,
+    connectors: Vec<RoutableConnectorChoice>,
+) -> RouterResult<Vec<RoutableConnectorChoice>> {
+    if connectors.is_empty() {
+        return Ok(connectors);
+    }
+
+    let now = common_utils::date_time::now();
+    let mut available_connectors = Vec::with_capacity(connectors.len());
+
+    for connector in connectors {
+        let Some(merchant_connector_id) = connector.merchant_connector_id.as_ref() else {
+            available_connectors.push(connector);
+            continue;
+        };
+
+        // The credentials are considered valid if their metadata could not be fetched, as only
+        // the credentials which have provably expired are excluded
+        let expires_at = get_credentials_metadata(db, merchant_connector_id)
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to fetch credentials metadata"))
+            .ok()
+            .flatten()
+            .and_then(|metadata| metadata.expires_at);
+
+        if get_credentials_status(expires_at, now) == ConnectorCredentialsStatus::Expired {
+            logger::info!(
+                merchant_connector_id = merchant_connector_id.get_string_repr(),
+                "Excluding connector from routing due to expired credentials"
+            );
+        } else {
+            available_connectors.push(connector);
+        }
+    }
+
+    if available_connectors.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The credentials of all the eligible connectors have expired".to_string(),
+        }));
+    }
+
+    Ok(available_connectors)
+}
+
+fn get_event_object_id(
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+    expires_at: PrimitiveDateTime,
+) -> String {
+    // The expiry is a part of the identifier so that the webhooks are sent again for the
+    // credentials which are rotated
+    format!(
+        "{}_{}",
+        merchant_connector_id.get_string_repr(),
+        expires_at.assume_utc().unix_timestamp()
+    )
+}
+
+/// Fetches the current state of the credentials referred to by the primary object of a webhook,
+/// along with the event type of the state. The event type is not returned if the credentials have
+/// been rotated or their expiry changed since the webhook was created.
+pub async fn get_credentials_webhook_content(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    primary_object_id: &str,
+) -> RouterResult<(ConnectorCredentialsResponse, Option<api_enums::EventType>)> {
+    let (merchant_connector_id, _) = primary_object_id
+        .rsplit_once('_')
+        .ok_or(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+    let merchant_connector_id =
+        id_type::MerchantConnectorAccountId::wrap(merchant_connector_id.to_string())
+            .change_context(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+
+    let db = state.store.as_ref();
+    let merchant_connector_account = db
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &state.into(),
+            &key_store.merchant_id,
+            &merchant_connector_id,
+            key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+    let metadata = get_credentials_metadata(db, &merchant_connector_id)
+        .await?
+        .unwrap_or_default();
+
+    let response = build_credentials_response(
+        &merchant_connector_account,
+        &metadata,
+        common_utils::date_time::now(),
+    );
+    let event_type = metadata
+        .expires_at
+        .filter(|expires_at| {
+            get_event_object_id(&merchant_connector_id, *expires_at) == primary_object_id
+        })
+        .and_then(|_| Option::<api_enums::EventType>::foreign_from(response.status));
+
+    Ok((response, event_type))
+}
+
+/// Sends the reminder webhook for the credentials of the merchant connector account which are
+/// about to expire, or the webhook for the credentials which have expired. Returns the time at
+/// which the expiry of the credentials has to be checked again, if any.
+pub async fn process_credentials_expiry(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<Option<PrimitiveDateTime>> {
+    let db = state.store.as_ref();
+    let Some(metadata) = get_credentials_metadata(db, merchant_connector_id).await? else {
+        return Ok(None);
+    };
+    let Some(expires_at) = metadata.expires_at else {
+        return Ok(None);
+    };
+
+    let now = common_utils::date_time::now();
+    let reminder_at =
+        expires_at - time::Duration::days(consts::CONNECTOR_CREDENTIALS_EXPIRY_REMINDER_DAYS);
+    if now < reminder_at {
+        return Ok(Some(reminder_at));
+    }
+
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(key_manager_state, merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_connector_account = db
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            key_manager_state,
+            merchant_id,
+            merchant_connector_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.get_string_repr().to_string(),
+        })?;
+    let business_profile = db
+        .find_business_profile_by_profile_id(
+            key_manager_state,
+            &key_store,
+            &merchant_connector_account.profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: merchant_connector_account
+                .profile_id
+                .get_string_repr()
+                .to_owned(),
+        })?;
+
+    let response = build_credentials_response(&merchant_connector_account, &metadata, now);
+    let (event_type, next_check_at) = match response.status {
+        ConnectorCredentialsStatus::ExpiringSoon => (
+            api_enums::EventType::ConnectorCredentialsExpiring,
+            Some(expires_at),
+        ),
+        ConnectorCredentialsStatus::Expired | ConnectorCredentialsStatus::Active => {
+            (api_enums::EventType::ConnectorCredentialsExpired, None)
+        }
+    };
+
+    Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account,
+        business_profile,
+        &key_store,
+        event_type,
+        api_enums::EventClass::MerchantConnectorAccount,
+        get_event_object_id(merchant_connector_id, expires_at),
+        storage::enums::EventObjectType::ConnectorCredentialsDetails,
+        webhooks::OutgoingWebhookContent::ConnectorCredentialsDetails(Box::new(response)),
+        metadata.created_at,
+    ))
+    .await?;
+
+    Ok(next_check_at)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_credentials_status() {
+        let now = common_utils::date_time::now();
+
+        assert_eq!(
+            get_credentials_status(None, now),
+            ConnectorCredentialsStatus::Active
+        );
+        assert_eq!(
+            get_credentials_status(Some(now + time::Duration::days(30)), now),
+            ConnectorCredentialsStatus::Active
+        );
+        assert_eq!(
+            get_credentials_status(Some(now + time::Duration::days(3)), now),
+            ConnectorCredentialsStatus::ExpiringSoon
+        );
+        assert_eq!(
+            get_credentials_status(Some(now), now),
+            ConnectorCredentialsStatus::Expired
+        );
+    }
+}
@@ -0,0 +1,226 @@
+//! Soft deletion of the merchant connector accounts. A deleted connector account is disabled and
+//! hidden from the connector APIs, but kept in the database so that the payments, refunds and
+//! disputes made through it can still be synced and traced back to it. The deletion is stored
+//! along with the state of the connector account before it, to which the connector account is
+//! returned if it is restored within the grace window after its deletion.
+
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResult},
+    db::StorageInterface,
+    types::{api::enums as api_enums, domain, storage},
+};
+
+/// The soft deletion of a merchant connector account, as stored
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SoftDeletion {
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub deleted_at: PrimitiveDateTime,
+    /// Whether the connector account was disabled before its deletion
+    pub disabled: Option<bool>,
+    /// The status of the connector account before its deletion
+    pub status: api_enums::ConnectorStatus,
+}
+
+impl SoftDeletion {
+    /// The time until which the connector account can be restored
+    pub fn get_restorable_until(&self) -> PrimitiveDateTime {
+        self.deleted_at.saturating_add(time::Duration::days(
+            consts::MERCHANT_CONNECTOR_ACCOUNT_RESTORE_WINDOW_IN_DAYS,
+        ))
+    }
+}
+
+fn get_state_update(
+    disabled: Option<bool>,
+    status: api_enums::ConnectorStatus,
+) -> storage::MerchantConnectorAccountUpdate {
+    storage::MerchantConnectorAccountUpdate::Update {
+        connector_type: None,
+        connector_name: None,
+        connector_account_details: Box::new(None),
+        test_mode: None,
+        disabled,
+        merchant_connector_id: None,
+        payment_methods_enabled: None,
+        metadata: None,
+        frm_configs: None,
+        connector_webhook_details: Box::new(None),
+        applepay_verified_domains: None,
+        pm_auth_config: Box::new(None),
+        connector_label: None,
+        status: Some(status),
+        connector_wallets_details: Box::new(None),
+        additional_merchant_data: Box::new(None),
+    }
+}
+
+pub async fn get_soft_deletion(
+    db: &dyn StorageInterface,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<Option<SoftDeletion>> {
+    match db
+        .find_config_by_key(&merchant_connector_id.get_soft_deletion_config_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("SoftDeletion")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse soft deletion of merchant connector account")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch soft deletion of merchant connector account"),
+    }
+}
+
+/// Fails with not found for the deleted connector accounts, which are hidden from the connector
+/// APIs other than the restore
+pub async fn validate_merchant_connector_account_not_deleted(
+    db: &dyn StorageInterface,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<()> {
+    if get_soft_deletion(db, merchant_connector_id)
+        .await?
+        .is_some()
+    {
+        return Err(report!(
+            errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+                id: merchant_connector_id.get_string_repr().to_string(),
+            }
+        ));
+    }
+    Ok(())
+}
+
+pub async fn filter_out_deleted_merchant_connector_accounts(
+    db: &dyn StorageInterface,
+    merchant_connector_accounts: Vec<domain::MerchantConnectorAccount>,
+) -> RouterResult<Vec<domain::MerchantConnectorAccount>> {
+    let mut available_merchant_connector_accounts = vec![];
+    for merchant_connector_account in merchant_connector_accounts {
+        if get_soft_deletion(db, &merchant_connector_account.get_id())
+            .await?
+            .is_none()
+        {
+            available_merchant_connector_accounts.push(merchant_connector_account);
+        }
+    }
+    Ok(available_merchant_connector_accounts)
+}
+
+/// Disables the connector account and records its deletion. The connector account is updated
+/// first, so that it is never left routable while recorded as deleted, and its update invalidates
+/// the cached connector accounts and constraint graphs of the profile.
+#[instrument(skip_all)]
+pub async fn soft_delete_merchant_connector_account(
+    db: &dyn StorageInterface,
+    key_manager_state: &common_utils::types::keymanager::KeyManagerState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_connector_account: domain::MerchantConnectorAccount,
+) -> RouterResult<SoftDeletion> {
+    let merchant_connector_id = merchant_connector_account.get_id();
+    validate_merchant_connector_account_not_deleted(db, &merchant_connector_id).await?;
+
+    let soft_deletion = SoftDeletion {
+        deleted_at: common_utils::date_time::now(),
+        disabled: merchant_connector_account.disabled,
+        status: merchant_connector_account.status,
+    };
+    let serialized_soft_deletion = soft_deletion
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize soft deletion of merchant connector account")?;
+
+    db.update_merchant_connector_account(
+        key_manager_state,
+        merchant_connector_account,
+        get_state_update(Some(true), api_enums::ConnectorStatus::Inactive).into(),
+        key_store,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to disable the deleted merchant connector account")?;
+
+    db.insert_config(configs::ConfigNew {
+        key: merchant_connector_id.get_soft_deletion_config_key(),
+        config: serialized_soft_deletion,
+    })
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to insert soft deletion of merchant connector account")?;
+
+    Ok(soft_deletion)
+}
+
+/// Returns a deleted connector account to its state before the deletion. The deletion is removed
+/// first, so that a failure leaves the connector account disabled but no longer deleted.
+#[instrument(skip_all)]
+pub async fn restore_merchant_connector_account(
+    db: &dyn StorageInterface,
+    key_manager_state: &common_utils::types::keymanager::KeyManagerState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_connector_account: domain::MerchantConnectorAccount,
+) -> RouterResult<domain::MerchantConnectorAccount> {
+    let merchant_connector_id = merchant_connector_account.get_id();
+    let soft_deletion = get_soft_deletion(db, &merchant_connector_id).await?.ok_or(
+        errors::ApiErrorResponse::PreconditionFailed {
+            message: "The merchant connector account is not deleted".to_string(),
+        },
+    )?;
+
+    if soft_deletion.get_restorable_until() < common_utils::date_time::now() {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "The merchant connector account can be restored only within {} days of its deletion",
+                consts::MERCHANT_CONNECTOR_ACCOUNT_RESTORE_WINDOW_IN_DAYS
+            ),
+        }));
+    }
+
+    db.delete_config_by_key(&merchant_connector_id.get_soft_deletion_config_key())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to delete soft deletion of merchant connector account")?;
+
+    db.update_merchant_connector_account(
+        key_manager_state,
+        merchant_connector_account,
+        get_state_update(soft_deletion.disabled, soft_deletion.status).into(),
+        key_store,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to restore the merchant connector account")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_restorable_until() {
+        let soft_deletion = SoftDeletion {
+            deleted_at: time::macros::datetime!(2025-04-01 10:00),
+            disabled: Some(false),
+            status: api_enums::ConnectorStatus::Active,
+        };
+
+        assert_eq!(
+            soft_deletion.get_restorable_until(),
+            time::macros::datetime!(2025-05-01 10:00)
+        );
+    }
+}
@@ -0,0 +1,256 @@
+use api_models::{
+    admin::{
+        ConnectorMaintenanceWindow, ConnectorMaintenanceWindowsRequest,
+        ConnectorMaintenanceWindowsResponse,
+    },
+    routing::RoutableConnectorChoice,
+};
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::api::enums as api_enums,
+};
+
+async fn validate_and_get_merchant_connector_account(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    profile_id: Option<id_type::ProfileId>,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_connector_account = db
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            key_manager_state,
+            merchant_id,
+            merchant_connector_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.get_string_repr().to_string(),
+        })?;
+
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &merchant_connector_account)
+}
+
+fn validate_maintenance_windows(windows: &[ConnectorMaintenanceWindow]) -> RouterResult<()> {
+    windows.iter().try_for_each(|window| {
+        if window.end_time <= window.start_time {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "end_time of a maintenance window must be after its start_time"
+                    .to_string(),
+            }));
+        }
+        if window
+            .payment_methods
+            .as_ref()
+            .is_some_and(|payment_methods| payment_methods.is_empty())
+        {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "payment_methods of a maintenance window must not be empty".to_string(),
+            }));
+        }
+        Ok(())
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn upsert_maintenance_windows(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id: Option<id_type::ProfileId>,
+    merchant_connector_id: id_type::MerchantConnectorAccountId,
+    request: ConnectorMaintenanceWindowsRequest,
+) -> RouterResponse<ConnectorMaintenanceWindowsResponse> {
+    validate_and_get_merchant_connector_account(
+        &state,
+        &merchant_id,
+        profile_id,
+        &merchant_connector_id,
+    )
+    .await?;
+    validate_maintenance_windows(&request.windows)?;
+
+    // Windows which have already ended have no effect on routing, and are not stored
+    let now = common_utils::date_time::now();
+    let windows = request
+        .windows
+        .into_iter()
+        .filter(|window| window.end_time > now)
+        .collect::<Vec<_>>();
+
+    let db = state.store.as_ref();
+    let key = merchant_connector_id.get_maintenance_windows_config_key();
+    let serialized_windows = windows
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize maintenance windows")?;
+
+    // The cache holds the windows of the connectors without any, so the existence of the windows
+    // is checked in the database
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => {
+            db.update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(serialized_windows),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update maintenance windows")?;
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            db.insert_config(configs::ConfigNew {
+                key,
+                config: serialized_windows,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert maintenance windows")?;
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch maintenance windows");
+        }
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        ConnectorMaintenanceWindowsResponse {
+            merchant_connector_id,
+            windows,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_maintenance_windows(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    profile_id: Option<id_type::ProfileId>,
+    merchant_connector_id: id_type::MerchantConnectorAccountId,
+) -> RouterResponse<ConnectorMaintenanceWindowsResponse> {
+    validate_and_get_merchant_connector_account(
+        &state,
+        &merchant_id,
+        profile_id,
+        &merchant_connector_id,
+    )
+    .await?;
+
+    let now = common_utils::date_time::now();
+    let windows = get_maintenance_windows(state.store.as_ref(), &merchant_connector_id)
+        .await?
+        .into_iter()
+        .filter(|window| window.end_time > now)
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        ConnectorMaintenanceWindowsResponse {
+            merchant_connector_id,
+            windows,
+        },
+    ))
+}
+
+/// Fetches the maintenance windows of the merchant connector account. These are looked up for
+/// every connector routed to, so the absence of the windows is cached as well.
+pub async fn get_maintenance_windows(
+    db: &dyn StorageInterface,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<Vec<ConnectorMaintenanceWindow>> {
+    db.find_config_by_key_unwrap_or(
+        &merchant_connector_id.get_maintenance_windows_config_key(),
+        Some("[]".to_string()),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch maintenance windows")?
+    .config
+    .parse_struct("Vec<ConnectorMaintenanceWindow>")
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to parse maintenance windows")
+}
+
+/// Removes the connectors which are under an active maintenance window for the payment method
+/// from the routed connectors. Fails with a retryable error if all the routed connectors are
+/// under maintenance.
+#[instrument(skip_all)]
+pub async fn filter_connectors_under_maintenance(
+    db: &dyn StorageInterface,
+    connectors: Vec<RoutableConnectorChoice>,
+    payment_method: Option<api_enums::PaymentMethod>,
+) -> RouterResult<Vec<RoutableConnectorChoice>> {
+    let now = common_utils::date_time::now();
+    let mut available_connectors = Vec::with_capacity(connectors.len());
+    let mut earliest_window_end = None;
+
+    for connector in connectors {
+        let Some(merchant_connector_id) = connector.merchant_connector_id.as_ref() else {
+            available_connectors.push(connector);
+            continue;
+        };
+
+        // The maintenance windows should not fail the payment if they could not be fetched
+        let windows = get_maintenance_windows(db, merchant_connector_id)
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to fetch maintenance windows"))
+            .unwrap_or_default();
+
+        match windows
+            .iter()
+            .filter(|window| window.is_active_for(now, payment_method))
+            .map(|window| window.end_time)
+            .max()
+        {
+            Some(window_end) => {
+                logger::info!(
+                    merchant_connector_id = merchant_connector_id.get_string_repr(),
+                    "Excluding connector from routing due to an active maintenance window"
+                );
+                earliest_window_end = Some(
+                    earliest_window_end.map_or(window_end, |end: time::PrimitiveDateTime| {
+                        end.min(window_end)
+                    }),
+                );
+            }
+            None => available_connectors.push(connector),
+        }
+    }
+
+    match earliest_window_end {
+        Some(window_end) if available_connectors.is_empty() => Err(report!(
+            errors::ApiErrorResponse::ConnectorsUnderMaintenance {
+                retry_after: window_end
+                    .assume_utc()
+                    .format(&time::format_description::well_known::Rfc3339)
+                    .unwrap_or_else(|_| window_end.to_string()),
+            }
+        )),
+        _ => Ok(available_connectors),
+    }
+}
@@ -10,6 +10,8 @@ use crate::{
     SessionState,
 };
 
+#[cfg(feature = "v1")]
+pub mod oauth;
 pub mod paypal;
 
 #[async_trait::async_trait]
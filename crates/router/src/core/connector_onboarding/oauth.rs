@@ -0,0 +1,955 @@
+//! Onboarding of connector accounts through the OAuth authorization code flow of the connector.
+//! The merchant is redirected to the consent page of the connector, which redirects them back to
+//! the callback with an authorization code. The code is exchanged for an access token, which is
+//! used as the API key of the connector account, and a refresh token, with which the access token
+//! is refreshed by the scheduler before it expires.
+
+use api_models::{
+    admin::MerchantConnectorUpdate, connector_onboarding as api, enums,
+    payments::RedirectionResponse,
+};
+use common_utils::{
+    encryption::Encryption,
+    ext_traits::{Encode, StringExt},
+    id_type,
+    request::{Method, RequestBuilder, RequestContent},
+    type_name,
+    types::keymanager::Identifier,
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+use time::{Duration, PrimitiveDateTime};
+
+use crate::{
+    configs::settings,
+    consts,
+    core::{
+        admin,
+        errors::{ApiErrorResponse, RouterResponse, RouterResult, StorageErrorExt},
+    },
+    db::StorageInterface,
+    headers,
+    routes::app::ReqState,
+    services::{self, authentication as auth, ApplicationResponse},
+    types::{self as oss_types, domain, storage},
+    SessionState,
+};
+
+const OAUTH_TOKEN_REFRESH_TASK: &str = "CONNECTOR_OAUTH_TOKEN_REFRESH";
+const OAUTH_TOKEN_REFRESH_TAG: &str = "CONNECTOR_ONBOARDING";
+
+const OAUTH_REQUEST_TIMEOUT_IN_SECONDS: u64 = 30;
+/// The error with which the connectors reject revoked or expired refresh tokens
+const OAUTH_INVALID_GRANT_ERROR: &str = "invalid_grant";
+
+/// An authorization which is waiting for the consent of the merchant, keyed by the `state`
+/// parameter sent to the connector
+#[derive(Debug, Serialize, Deserialize)]
+struct PendingOAuthAuthorization {
+    merchant_id: id_type::MerchantId,
+    connector: enums::Connector,
+    connector_id: id_type::MerchantConnectorAccountId,
+    return_url: String,
+    scopes: Vec<String>,
+}
+
+/// The tokens of a connector account connected with OAuth
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredOAuthToken {
+    connector: enums::Connector,
+    /// The refresh token, encrypted with the key of the merchant
+    refresh_token: Option<Encryption>,
+    scopes: Vec<String>,
+    access_token_expires_at: Option<PrimitiveDateTime>,
+    connected_at: PrimitiveDateTime,
+    last_refreshed_at: Option<PrimitiveDateTime>,
+    last_refresh_error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct AuthorizationCodeGrantRequest {
+    grant_type: &'static str,
+    code: Secret<String>,
+    redirect_uri: String,
+    client_id: Secret<String>,
+    client_secret: Secret<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct RefreshTokenGrantRequest {
+    grant_type: &'static str,
+    refresh_token: Secret<String>,
+    client_id: Secret<String>,
+    client_secret: Secret<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OAuthTokenResponse {
+    access_token: Secret<String>,
+    refresh_token: Option<Secret<String>>,
+    expires_in: Option<i64>,
+    scope: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OAuthErrorResponse {
+    error: String,
+    error_description: Option<String>,
+}
+
+/// The reason for which the connector did not issue a token
+enum OAuthTokenError {
+    /// The grant was rejected by the connector, with the OAuth error code
+    Rejected { error: String, message: String },
+    /// The connector could not be reached or responded unexpectedly
+    Failed(String),
+}
+
+impl OAuthTokenError {
+    fn message(&self) -> &str {
+        match self {
+            Self::Rejected { message, .. } | Self::Failed(message) => message,
+        }
+    }
+}
+
+fn get_oauth_config(
+    state: &SessionState,
+    connector: enums::Connector,
+) -> RouterResult<settings::OAuthOnboarding> {
+    state
+        .conf
+        .connector_onboarding
+        .get_inner()
+        .oauth
+        .get(&connector)
+        .filter(|oauth_config| oauth_config.enabled)
+        .cloned()
+        .ok_or(
+            ApiErrorResponse::FlowNotSupported {
+                flow: "OAuth connector onboarding".to_string(),
+                connector: connector.to_string(),
+            }
+            .into(),
+        )
+}
+
+fn get_redirect_uri(state: &SessionState) -> String {
+    format!("{}/connector_onboarding/oauth/callback", state.base_url)
+}
+
+fn get_pending_authorization_key(oauth_state: &str) -> String {
+    format!("{}_{oauth_state}", consts::CONNECTOR_OAUTH_STATE_PREFIX)
+}
+
+fn get_oauth_token_key(
+    merchant_id: &id_type::MerchantId,
+    connector_id: &id_type::MerchantConnectorAccountId,
+) -> String {
+    format!(
+        "{}_{}_{}",
+        consts::CONNECTOR_OAUTH_TOKEN_CONFIG_PREFIX,
+        merchant_id.get_string_repr(),
+        connector_id.get_string_repr()
+    )
+}
+
+/// Validates the requested scopes against the scopes of the OAuth application, and returns the
+/// scopes to be requested
+fn get_requested_scopes(
+    requested_scopes: Option<Vec<String>>,
+    oauth_config: &settings::OAuthOnboarding,
+) -> Result<Vec<String>, ApiErrorResponse> {
+    let scopes = match requested_scopes {
+        Some(scopes) if !scopes.is_empty() => scopes,
+        _ => return Ok(oauth_config.scopes.clone()),
+    };
+
+    if let Some(scope) = scopes
+        .iter()
+        .find(|scope| !oauth_config.scopes.is_empty() && !oauth_config.scopes.contains(scope))
+    {
+        return Err(ApiErrorResponse::InvalidRequestData {
+            message: format!("The scope {scope} cannot be requested from the connector"),
+        });
+    }
+
+    let missing_scopes = get_missing_scopes(&scopes, &oauth_config.required_scopes);
+    if !missing_scopes.is_empty() {
+        return Err(ApiErrorResponse::InvalidRequestData {
+            message: format!("The scopes {} must be requested", missing_scopes.join(", ")),
+        });
+    }
+
+    Ok(scopes)
+}
+
+/// The scopes granted by the merchant, as returned by the connector. The connectors which do not
+/// return the granted scopes grant all the requested scopes.
+fn get_granted_scopes(granted_scopes: Option<&str>, requested_scopes: &[String]) -> Vec<String> {
+    match granted_scopes {
+        Some(granted_scopes) => granted_scopes
+            .split(|character: char| character == ' ' || character == ',')
+            .filter(|scope| !scope.is_empty())
+            .map(str::to_owned)
+            .collect(),
+        None => requested_scopes.to_vec(),
+    }
+}
+
+fn get_missing_scopes(scopes: &[String], required_scopes: &[String]) -> Vec<String> {
+    required_scopes
+        .iter()
+        .filter(|required_scope| !scopes.contains(required_scope))
+        .cloned()
+        .collect()
+}
+
+fn build_authorization_url(
+    oauth_config: &settings::OAuthOnboarding,
+    redirect_uri: &str,
+    scopes: &[String],
+    oauth_state: &str,
+) -> RouterResult<String> {
+    let client_id = oauth_config.client_id.peek();
+    let scope = scopes.join(" ");
+    let mut params = vec![
+        ("response_type", "code"),
+        ("client_id", client_id.as_str()),
+        ("redirect_uri", redirect_uri),
+        ("state", oauth_state),
+    ];
+    if !scope.is_empty() {
+        params.push(("scope", scope.as_str()));
+    }
+
+    url::Url::parse_with_params(&oauth_config.authorization_url, params)
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid authorization url of the connector")
+        .map(String::from)
+}
+
+async fn find_connector_account(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    connector_id: &id_type::MerchantConnectorAccountId,
+    connector: enums::Connector,
+) -> RouterResult<(domain::MerchantConnectorAccount, domain::MerchantKeyStore)> {
+    let key_manager_state = &state.into();
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let connector_account = state
+        .store
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            key_manager_state,
+            merchant_id,
+            connector_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: connector_id.get_string_repr().to_string(),
+        })?;
+
+    if connector_account.connector_name != connector.to_string() {
+        return Err(ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "The connector account {} is not a {connector} account",
+                connector_id.get_string_repr()
+            ),
+        }
+        .into());
+    }
+
+    Ok((connector_account, key_store))
+}
+
+/// Sets the access token as the API key of the connector account, leaving the rest of the account
+/// as is
+async fn update_connector_account_auth(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    connector_account: &domain::MerchantConnectorAccount,
+    access_token: Secret<String>,
+) -> RouterResult<()> {
+    let connector_auth_json = oss_types::ConnectorAuthType::HeaderKey {
+        api_key: access_token,
+    }
+    .encode_to_value()
+    .change_context(ApiErrorResponse::InternalServerError)
+    .attach_printable("Error while serializing connector_account_details")?;
+
+    let request = MerchantConnectorUpdate {
+        connector_type: connector_account.connector_type,
+        connector_account_details: Some(Secret::new(connector_auth_json)),
+        disabled: None,
+        status: None,
+        connector_label: None,
+        payment_methods_enabled: None,
+        metadata: None,
+        frm_configs: None,
+        connector_webhook_details: None,
+        pm_auth_config: None,
+        test_mode: None,
+        additional_merchant_data: None,
+        connector_wallets_details: None,
+        credentials_expires_at: None,
+    };
+
+    admin::update_connector(
+        state.clone(),
+        merchant_id,
+        None,
+        &connector_account.merchant_connector_id,
+        request,
+    )
+    .await
+    .map(|_| ())
+}
+
+async fn request_token<T>(
+    state: &SessionState,
+    oauth_config: &settings::OAuthOnboarding,
+    grant: T,
+) -> Result<OAuthTokenResponse, OAuthTokenError>
+where
+    T: Serialize + Send + 'static,
+{
+    let request = RequestBuilder::new()
+        .method(Method::Post)
+        .url(&oauth_config.token_url)
+        .attach_default_headers()
+        .header(headers::CONTENT_TYPE, "application/x-www-form-urlencoded")
+        .header(headers::ACCEPT, "application/json")
+        .set_body(RequestContent::FormUrlEncoded(Box::new(grant)))
+        .build();
+
+    let response = services::send_request(state, request, Some(OAUTH_REQUEST_TIMEOUT_IN_SECONDS))
+        .await
+        .map_err(|error| {
+            logger::error!(?error, "Failed to call the token endpoint of the connector");
+            OAuthTokenError::Failed("Failed to connect to the connector".to_string())
+        })?;
+
+    let status_code = response.status();
+    let body = response.bytes().await.map_err(|error| {
+        logger::error!(?error, "Failed to read the token response of the connector");
+        OAuthTokenError::Failed("Failed to read the response of the connector".to_string())
+    })?;
+
+    if status_code.is_success() {
+        return serde_json::from_slice::<OAuthTokenResponse>(&body).map_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to parse the token response of the connector"
+            );
+            OAuthTokenError::Failed("The connector did not return an access token".to_string())
+        });
+    }
+
+    match serde_json::from_slice::<OAuthErrorResponse>(&body) {
+        Ok(error_response) => Err(OAuthTokenError::Rejected {
+            message: error_response
+                .error_description
+                .unwrap_or_else(|| error_response.error.clone()),
+            error: error_response.error,
+        }),
+        Err(_) => Err(OAuthTokenError::Failed(format!(
+            "The connector responded with status code {}",
+            status_code.as_u16()
+        ))),
+    }
+}
+
+async fn encrypt_refresh_token(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    refresh_token: Secret<String>,
+) -> RouterResult<Encryption> {
+    domain::types::crypto_operation(
+        &state.into(),
+        type_name!(domain::MerchantConnectorAccount),
+        domain::types::CryptoOperation::Encrypt(refresh_token),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt the refresh token")
+    .map(Encryption::from)
+}
+
+async fn decrypt_refresh_token(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    refresh_token: Encryption,
+) -> RouterResult<Secret<String>> {
+    domain::types::crypto_operation::<String, masking::WithType>(
+        &state.into(),
+        type_name!(domain::MerchantConnectorAccount),
+        domain::types::CryptoOperation::Decrypt(refresh_token),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to decrypt the refresh token")
+    .map(|refresh_token| refresh_token.into_inner())
+}
+
+/// The tokens of the connector account, if it was connected with OAuth
+async fn find_oauth_token(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<Option<StoredOAuthToken>> {
+    match db
+        .find_config_by_key(&get_oauth_token_key(merchant_id, connector_id))
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("StoredOAuthToken")
+            .change_context(ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the OAuth token of the connector account")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error
+            .change_context(ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the OAuth token of the connector account")),
+    }
+}
+
+async fn store_oauth_token(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    connector_id: &id_type::MerchantConnectorAccountId,
+    oauth_token: &StoredOAuthToken,
+    is_existing: bool,
+) -> RouterResult<()> {
+    let key = get_oauth_token_key(merchant_id, connector_id);
+    let serialized_token = oauth_token
+        .encode_to_string_of_json()
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize the OAuth token of the connector account")?;
+
+    if is_existing {
+        db.update_config_by_key(
+            &key,
+            configs::ConfigUpdate::Update {
+                config: Some(serialized_token),
+            },
+        )
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the OAuth token of the connector account")?;
+    } else {
+        db.insert_config(configs::ConfigNew {
+            key,
+            config: serialized_token,
+        })
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the OAuth token of the connector account")?;
+    }
+
+    Ok(())
+}
+
+/// The time at which the access token is refreshed, some time before it expires
+fn get_refresh_time(access_token_expires_at: PrimitiveDateTime) -> PrimitiveDateTime {
+    let refresh_time = access_token_expires_at.saturating_sub(Duration::seconds(
+        consts::CONNECTOR_OAUTH_TOKEN_REFRESH_BUFFER_IN_SECONDS,
+    ));
+    std::cmp::max(refresh_time, common_utils::date_time::now())
+}
+
+fn get_process_tracker_id(connector_id: &id_type::MerchantConnectorAccountId) -> String {
+    let runner = storage::ProcessTrackerRunner::ConnectorOAuthTokenRefreshWorkflow;
+    format!(
+        "{runner}_{OAUTH_TOKEN_REFRESH_TASK}_{}",
+        connector_id.get_string_repr()
+    )
+}
+
+/// Schedules the refresh of the access token of the connector account, replacing the refresh
+/// already scheduled
+async fn schedule_oauth_token_refresh(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    connector_id: &id_type::MerchantConnectorAccountId,
+    schedule_time: PrimitiveDateTime,
+) -> RouterResult<()> {
+    let process_tracker_id = get_process_tracker_id(connector_id);
+
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching CONNECTOR_OAUTH_TOKEN_REFRESH task")?;
+
+    match existing_process {
+        Some(process) => db
+            .as_scheduler()
+            .reset_process(process, schedule_time)
+            .await
+            .change_context(ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while resetting CONNECTOR_OAUTH_TOKEN_REFRESH task")?,
+        None => {
+            let tracking_data = storage::ConnectorOAuthTokenRefreshTrackingData {
+                merchant_id: merchant_id.clone(),
+                merchant_connector_id: connector_id.clone(),
+            };
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                OAUTH_TOKEN_REFRESH_TASK,
+                storage::ProcessTrackerRunner::ConnectorOAuthTokenRefreshWorkflow,
+                [OAUTH_TOKEN_REFRESH_TAG],
+                tracking_data,
+                None,
+                schedule_time,
+                hyperswitch_domain_models::consts::API_VERSION,
+            )
+            .change_context(ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct CONNECTOR_OAUTH_TOKEN_REFRESH task")?;
+
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed while inserting CONNECTOR_OAUTH_TOKEN_REFRESH task to process_tracker",
+                )?;
+        }
+    };
+
+    Ok(())
+}
+
+fn build_redirection_response(
+    return_url: &str,
+    params: Vec<(String, String)>,
+) -> RouterResult<RedirectionResponse> {
+    let return_url_with_query_params = url::Url::parse_with_params(return_url, &params)
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid return url of the OAuth authorization")?;
+
+    Ok(RedirectionResponse {
+        return_url: return_url.to_owned(),
+        params,
+        return_url_with_query_params: return_url_with_query_params.to_string(),
+        http_method: "GET".to_string(),
+        headers: vec![],
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn authorize(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+    request: api::OAuthAuthorizeRequest,
+    _req_state: ReqState,
+) -> RouterResponse<api::OAuthAuthorizeResponse> {
+    let oauth_config = get_oauth_config(&state, request.connector)?;
+    find_connector_account(
+        &state,
+        &user_from_token.merchant_id,
+        &request.connector_id,
+        request.connector,
+    )
+    .await?;
+
+    url::Url::parse(&request.return_url).change_context(ApiErrorResponse::InvalidRequestData {
+        message: "return_url is not a valid url".to_string(),
+    })?;
+    let scopes = get_requested_scopes(request.scopes, &oauth_config)?;
+
+    let oauth_state = common_utils::generate_id_with_len(consts::CONNECTOR_OAUTH_STATE_LENGTH);
+    let pending_authorization = PendingOAuthAuthorization {
+        merchant_id: user_from_token.merchant_id,
+        connector: request.connector,
+        connector_id: request.connector_id,
+        return_url: request.return_url,
+        scopes,
+    };
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    redis_conn
+        .serialize_and_set_key_with_expiry(
+            &get_pending_authorization_key(&oauth_state).as_str().into(),
+            &pending_authorization,
+            consts::CONNECTOR_OAUTH_STATE_TTL_IN_SECONDS,
+        )
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add the pending OAuth authorization in redis")?;
+
+    let authorization_url = build_authorization_url(
+        &oauth_config,
+        &get_redirect_uri(&state),
+        &pending_authorization.scopes,
+        &oauth_state,
+    )?;
+
+    Ok(ApplicationResponse::Json(api::OAuthAuthorizeResponse {
+        authorization_url,
+        expires_in: consts::CONNECTOR_OAUTH_STATE_TTL_IN_SECONDS,
+    }))
+}
+
+/// Completes the authorization once the merchant is redirected back by the connector, and
+/// redirects the merchant to the return url with the outcome
+#[instrument(skip_all)]
+pub async fn handle_callback(
+    state: SessionState,
+    _: (),
+    request: api::OAuthCallbackRequest,
+    _req_state: ReqState,
+) -> RouterResponse<()> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let pending_authorization_key = get_pending_authorization_key(&request.state);
+
+    let pending_authorization = redis_conn
+        .get_key::<Option<String>>(&pending_authorization_key.as_str().into())
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the pending OAuth authorization from redis")?
+        .ok_or_else(|| {
+            report!(ApiErrorResponse::InvalidRequestData {
+                message: "The authorization has expired or is invalid".to_string(),
+            })
+        })?
+        .parse_struct::<PendingOAuthAuthorization>("PendingOAuthAuthorization")
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize the pending OAuth authorization")?;
+
+    // The state can be used only once
+    redis_conn
+        .delete_key(&pending_authorization_key.as_str().into())
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to delete the pending OAuth authorization from redis")?;
+
+    let outcome = complete_authorization(&state, &pending_authorization, request).await;
+
+    let mut params = vec![(
+        "connector_id".to_string(),
+        pending_authorization
+            .connector_id
+            .get_string_repr()
+            .to_owned(),
+    )];
+    match outcome {
+        Ok(()) => params.push(("status".to_string(), "succeeded".to_string())),
+        Err(error) => {
+            logger::error!(?error, "Failed to complete the OAuth authorization");
+            params.push(("status".to_string(), "failed".to_string()));
+            params.push(("error".to_string(), error.current_context().error_message()));
+        }
+    }
+
+    Ok(ApplicationResponse::JsonForRedirection(
+        build_redirection_response(&pending_authorization.return_url, params)?,
+    ))
+}
+
+async fn complete_authorization(
+    state: &SessionState,
+    pending_authorization: &PendingOAuthAuthorization,
+    request: api::OAuthCallbackRequest,
+) -> RouterResult<()> {
+    if let Some(error) = request.error {
+        return Err(ApiErrorResponse::InvalidRequestData {
+            message: request.error_description.unwrap_or(error),
+        }
+        .into());
+    }
+    let code = request
+        .code
+        .ok_or(ApiErrorResponse::MissingRequiredField { field_name: "code" })?;
+
+    let oauth_config = get_oauth_config(state, pending_authorization.connector)?;
+    let (connector_account, key_store) = find_connector_account(
+        state,
+        &pending_authorization.merchant_id,
+        &pending_authorization.connector_id,
+        pending_authorization.connector,
+    )
+    .await?;
+
+    let token_response = request_token(
+        state,
+        &oauth_config,
+        AuthorizationCodeGrantRequest {
+            grant_type: "authorization_code",
+            code: Secret::new(code),
+            redirect_uri: get_redirect_uri(state),
+            client_id: oauth_config.client_id.clone(),
+            client_secret: oauth_config.client_secret.clone(),
+        },
+    )
+    .await
+    .map_err(|error| ApiErrorResponse::InvalidRequestData {
+        message: format!("The connector did not issue a token: {}", error.message()),
+    })?;
+
+    let scopes = get_granted_scopes(
+        token_response.scope.as_deref().or(request.scope.as_deref()),
+        &pending_authorization.scopes,
+    );
+    let missing_scopes = get_missing_scopes(&scopes, &oauth_config.required_scopes);
+    if !missing_scopes.is_empty() {
+        return Err(ApiErrorResponse::InvalidRequestData {
+            message: format!("The scopes {} were not granted", missing_scopes.join(", ")),
+        }
+        .into());
+    }
+
+    update_connector_account_auth(
+        state,
+        &pending_authorization.merchant_id,
+        &connector_account,
+        token_response.access_token,
+    )
+    .await?;
+
+    let refresh_token = match token_response.refresh_token {
+        Some(refresh_token) => Some(encrypt_refresh_token(state, &key_store, refresh_token).await?),
+        None => None,
+    };
+    let now = common_utils::date_time::now();
+    let access_token_expires_at = token_response
+        .expires_in
+        .map(|expires_in| now.saturating_add(Duration::seconds(expires_in)));
+
+    let db = state.store.as_ref();
+    let is_existing = find_oauth_token(
+        db,
+        &pending_authorization.merchant_id,
+        &pending_authorization.connector_id,
+    )
+    .await?
+    .is_some();
+    let oauth_token = StoredOAuthToken {
+        connector: pending_authorization.connector,
+        refresh_token,
+        scopes,
+        access_token_expires_at,
+        connected_at: now,
+        last_refreshed_at: None,
+        last_refresh_error: None,
+    };
+    store_oauth_token(
+        db,
+        &pending_authorization.merchant_id,
+        &pending_authorization.connector_id,
+        &oauth_token,
+        is_existing,
+    )
+    .await?;
+
+    if let Some(expires_at) = oauth_token
+        .access_token_expires_at
+        .filter(|_| oauth_token.refresh_token.is_some())
+    {
+        schedule_oauth_token_refresh(
+            db,
+            &pending_authorization.merchant_id,
+            &pending_authorization.connector_id,
+            get_refresh_time(expires_at),
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_status(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+    request: api::OAuthStatusRequest,
+    _req_state: ReqState,
+) -> RouterResponse<api::OAuthStatusResponse> {
+    find_connector_account(
+        &state,
+        &user_from_token.merchant_id,
+        &request.connector_id,
+        request.connector,
+    )
+    .await?;
+
+    let oauth_token = find_oauth_token(
+        state.store.as_ref(),
+        &user_from_token.merchant_id,
+        &request.connector_id,
+    )
+    .await?
+    .ok_or(ApiErrorResponse::GenericNotFoundError {
+        message: "The connector account is not connected with OAuth".to_string(),
+    })?;
+
+    Ok(ApplicationResponse::Json(api::OAuthStatusResponse {
+        connector: oauth_token.connector,
+        connector_id: request.connector_id,
+        scopes: oauth_token.scopes,
+        access_token_expires_at: oauth_token.access_token_expires_at,
+        connected_at: oauth_token.connected_at,
+        last_refreshed_at: oauth_token.last_refreshed_at,
+        last_refresh_error: oauth_token.last_refresh_error,
+    }))
+}
+
+/// Refreshes the access token of the connector account with its refresh token, and returns the time
+/// at which it is to be refreshed next. No time is returned once the access token can no longer be
+/// refreshed, such as when the merchant revoked the consent.
+#[instrument(skip_all)]
+pub async fn refresh_oauth_token(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    connector_id: &id_type::MerchantConnectorAccountId,
+) -> RouterResult<Option<PrimitiveDateTime>> {
+    let db = state.store.as_ref();
+    let Some(mut oauth_token) = find_oauth_token(db, merchant_id, connector_id).await? else {
+        return Ok(None);
+    };
+    let Some(encrypted_refresh_token) = oauth_token.refresh_token.clone() else {
+        return Ok(None);
+    };
+
+    let oauth_config = get_oauth_config(state, oauth_token.connector)?;
+    let (connector_account, key_store) =
+        find_connector_account(state, merchant_id, connector_id, oauth_token.connector).await?;
+    let refresh_token = decrypt_refresh_token(state, &key_store, encrypted_refresh_token).await?;
+
+    let token_response = request_token(
+        state,
+        &oauth_config,
+        RefreshTokenGrantRequest {
+            grant_type: "refresh_token",
+            refresh_token,
+            client_id: oauth_config.client_id.clone(),
+            client_secret: oauth_config.client_secret.clone(),
+        },
+    )
+    .await;
+
+    let now = common_utils::date_time::now();
+    let next_refresh_time = match token_response {
+        Ok(token_response) => {
+            update_connector_account_auth(
+                state,
+                merchant_id,
+                &connector_account,
+                token_response.access_token,
+            )
+            .await?;
+
+            // Some connectors rotate the refresh token on every refresh
+            if let Some(refresh_token) = token_response.refresh_token {
+                oauth_token.refresh_token =
+                    Some(encrypt_refresh_token(state, &key_store, refresh_token).await?);
+            }
+            if let Some(scope) = token_response.scope.as_deref() {
+                oauth_token.scopes = get_granted_scopes(Some(scope), &oauth_token.scopes);
+            }
+            oauth_token.access_token_expires_at = token_response
+                .expires_in
+                .map(|expires_in| now.saturating_add(Duration::seconds(expires_in)));
+            oauth_token.last_refreshed_at = Some(now);
+            oauth_token.last_refresh_error = None;
+
+            oauth_token.access_token_expires_at.map(get_refresh_time)
+        }
+        Err(OAuthTokenError::Rejected { error, message }) if error == OAUTH_INVALID_GRANT_ERROR => {
+            logger::warn!(%message, "The refresh token of the connector account was rejected");
+            oauth_token.refresh_token = None;
+            oauth_token.last_refresh_error = Some(format!(
+                "The consent was revoked or has expired, the account has to be connected again: {message}"
+            ));
+            None
+        }
+        Err(error) => {
+            logger::error!(
+                message = error.message(),
+                "Failed to refresh the access token of the connector account"
+            );
+            oauth_token.last_refresh_error = Some(error.message().to_owned());
+            Some(now.saturating_add(Duration::seconds(
+                consts::CONNECTOR_OAUTH_TOKEN_REFRESH_RETRY_IN_SECONDS,
+            )))
+        }
+    };
+
+    store_oauth_token(db, merchant_id, connector_id, &oauth_token, true).await?;
+
+    Ok(next_refresh_time)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_oauth_config() -> settings::OAuthOnboarding {
+        settings::OAuthOnboarding {
+            scopes: vec!["read_write".to_string(), "read_only".to_string()],
+            required_scopes: vec!["read_write".to_string()],
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_get_requested_scopes() {
+        let oauth_config = get_oauth_config();
+
+        assert_eq!(
+            get_requested_scopes(None, &oauth_config).ok(),
+            Some(oauth_config.scopes.clone())
+        );
+        assert_eq!(
+            get_requested_scopes(Some(vec!["read_write".to_string()]), &oauth_config).ok(),
+            Some(vec!["read_write".to_string()])
+        );
+        assert!(get_requested_scopes(Some(vec!["read_only".to_string()]), &oauth_config).is_err());
+        assert!(get_requested_scopes(Some(vec!["admin".to_string()]), &oauth_config).is_err());
+    }
+
+    #[test]
+    fn test_get_granted_scopes() {
+        let requested_scopes = vec!["read_write".to_string()];
+
+        assert_eq!(
+            get_granted_scopes(Some("read_write read_only"), &requested_scopes),
+            vec!["read_write".to_string(), "read_only".to_string()]
+        );
+        assert_eq!(
+            get_granted_scopes(Some("read_only,"), &requested_scopes),
+            vec!["read_only".to_string()]
+        );
+        assert_eq!(
+            get_granted_scopes(None, &requested_scopes),
+            requested_scopes
+        );
+    }
+}
@@ -163,6 +163,7 @@ pub async fn update_mca(
         test_mode: None,
         additional_merchant_data: None,
         connector_wallets_details: None,
+        credentials_expires_at: None,
     };
     #[cfg(feature = "v2")]
     let request = MerchantConnectorUpdate {
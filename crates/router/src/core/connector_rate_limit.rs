@@ -0,0 +1,145 @@
+//! Throttling of the calls made to the connectors, which protects the merchants from the rate
+//! limits imposed by the connectors during spikes in traffic. The calls of a profile to a
+//! connector draw from a token bucket in redis, refilled at the rate configured for the connector.
+//! A call which finds the bucket empty waits for its token, unless the wait exceeds the configured
+//! maximum, in which case the call is throttled without reaching the connector. The calls which
+//! are not made under a profile draw from the bucket of their merchant.
+
+use std::str::FromStr;
+
+use api_models::enums;
+use common_utils::id_type;
+use router_env::{instrument, logger, tracing};
+
+use crate::routes::{metrics, SessionState};
+
+/// Takes a token from the bucket, refilled since its last update. The token is reserved ahead of
+/// the refill when the wait for it does not exceed the maximum wait, so that the waiting calls are
+/// let through in the order in which they arrived. Returns the wait for the token in milliseconds,
+/// or -1 if the call is throttled.
+const TOKEN_BUCKET_SCRIPT: &str = r#"
+local burst = tonumber(ARGV[1])
+local calls_per_second = tonumber(ARGV[2])
+local now = tonumber(ARGV[3])
+local max_wait = tonumber(ARGV[4])
+
+local bucket = redis.call('HMGET', KEYS[1], 'tokens', 'updated_at')
+local tokens = tonumber(bucket[1]) or burst
+local updated_at = tonumber(bucket[2]) or now
+local elapsed = math.max(0, now - updated_at)
+tokens = math.min(burst, tokens + elapsed * calls_per_second / 1000)
+
+local wait = 0
+if tokens < 1 then
+    wait = math.ceil((1 - tokens) * 1000 / calls_per_second)
+    if wait > max_wait then
+        return -1
+    end
+end
+
+redis.call('HSET', KEYS[1],
+    'tokens', tostring(tokens - 1),
+    'updated_at', tostring(math.max(now, updated_at)))
+redis.call('PEXPIRE', KEYS[1], math.ceil(burst * 1000 / calls_per_second) + max_wait + 1000)
+return wait
+"#;
+
+fn get_token_bucket_key(
+    tenant_id: &id_type::TenantId,
+    merchant_id: &id_type::MerchantId,
+    profile_id: Option<&id_type::ProfileId>,
+    connector: enums::Connector,
+) -> String {
+    match profile_id {
+        Some(profile_id) => format!(
+            "{}_{}_{}_{connector}_connector_rate_limit",
+            tenant_id.get_string_repr(),
+            merchant_id.get_string_repr(),
+            profile_id.get_string_repr(),
+        ),
+        None => format!(
+            "{}_{}_{connector}_connector_rate_limit",
+            tenant_id.get_string_repr(),
+            merchant_id.get_string_repr(),
+        ),
+    }
+}
+
+/// Waits for the rate limit of the connector before a call is made to it, returning whether the
+/// call can be made. Failures to reach redis are only logged and let the call through, as the rate
+/// limits should never fail the calls on their own.
+#[instrument(skip_all)]
+pub async fn acquire_connector_call_permit(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    profile_id: Option<&id_type::ProfileId>,
+    connector: &str,
+) -> bool {
+    let Ok(connector_name) = enums::Connector::from_str(connector) else {
+        return true;
+    };
+    let config = &state.conf.connector_rate_limits;
+    let Some(rate_limit) = config.connectors.get(&connector_name) else {
+        return true;
+    };
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection");
+            return true;
+        }
+    };
+
+    let now_in_millis = i64::try_from(
+        common_utils::date_time::now()
+            .assume_utc()
+            .unix_timestamp_nanos()
+            / 1_000_000,
+    )
+    .unwrap_or(i64::MAX);
+    let key = get_token_bucket_key(
+        &state.tenant.tenant_id,
+        merchant_id,
+        profile_id,
+        connector_name,
+    );
+    let wait_in_millis = redis_conn
+        .evaluate_redis_script::<_, i64>(
+            TOKEN_BUCKET_SCRIPT,
+            vec![key],
+            vec![
+                i64::from(rate_limit.burst),
+                i64::from(rate_limit.calls_per_second),
+                now_in_millis,
+                i64::try_from(config.max_wait_in_millis).unwrap_or(i64::MAX),
+            ],
+        )
+        .await;
+
+    match wait_in_millis {
+        Ok(wait_in_millis) if wait_in_millis < 0 => {
+            logger::warn!(connector, "Connector call throttled as per its rate limit");
+            metrics::CONNECTOR_CALL_THROTTLED_COUNT.add(
+                1,
+                router_env::metric_attributes!(("connector", connector.to_owned())),
+            );
+            false
+        }
+        Ok(wait_in_millis) => {
+            if let Ok(wait_in_millis @ 1..) = u64::try_from(wait_in_millis) {
+                logger::info!(
+                    connector,
+                    wait_in_millis,
+                    "Connector call waiting for its rate limit"
+                );
+                tokio::time::sleep(std::time::Duration::from_millis(wait_in_millis)).await;
+            }
+            true
+        }
+        Err(error) => {
+            logger::error!(?error, "Failed to evaluate the rate limit of the connector");
+            true
+        }
+    }
+}
@@ -0,0 +1,547 @@
+//! Backfill of the connector transaction IDs of the payment attempts of a merchant, for instance
+//! after an incident during which the responses of a connector were lost. The payments created in
+//! the time range of the backfill are scanned page by page by the scheduler, and those whose
+//! attempt was sent to a connector without a connector transaction ID being stored are synced with
+//! their connector. Storing the connector transaction ID returned by the sync also repairs the
+//! lookups of the attempt by its connector transaction ID, which the incoming webhooks and the
+//! disputes rely on. The syncs are rate limited per minute, and the progress of the backfill can be
+//! polled.
+
+use api_models::{
+    admin::{
+        ConnectorTransactionIdBackfillFailure, ConnectorTransactionIdBackfillRequest,
+        ConnectorTransactionIdBackfillResponse, ConnectorTransactionIdBackfillStatus,
+    },
+    payments::{Order, SortBy, SortOn},
+};
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::payments::{
+    payment_intent::{PaymentIntentFetchConstraints, PaymentIntentListParams},
+    HeaderPayload,
+};
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::{self, operations},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{
+        api::{self, enums},
+        domain, storage,
+    },
+};
+
+const CONNECTOR_TRANSACTION_ID_BACKFILL_TASK: &str = "CONNECTOR_TRANSACTION_ID_BACKFILL";
+const CONNECTOR_TRANSACTION_ID_BACKFILL_TAG: &str = "PAYMENT";
+
+/// A backfill of connector transaction IDs, as stored along with its progress
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectorTransactionIdBackfill {
+    pub backfill_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub status: ConnectorTransactionIdBackfillStatus,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub start_time: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub end_time: PrimitiveDateTime,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub connector: Option<enums::Connector>,
+    pub requests_per_minute: u32,
+    /// The number of payments of the time range scanned so far, which is also the offset of the
+    /// next page of payments to scan
+    pub scanned_count: u64,
+    pub missing_count: u64,
+    pub repaired_count: u64,
+    pub unresolved_count: u64,
+    pub skipped_count: u64,
+    pub failed_count: u64,
+    pub failures: Vec<ConnectorTransactionIdBackfillFailure>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<&ConnectorTransactionIdBackfill> for ConnectorTransactionIdBackfillResponse {
+    fn from(backfill: &ConnectorTransactionIdBackfill) -> Self {
+        Self {
+            backfill_id: backfill.backfill_id.clone(),
+            merchant_id: backfill.merchant_id.clone(),
+            status: backfill.status,
+            start_time: backfill.start_time,
+            end_time: backfill.end_time,
+            profile_id: backfill.profile_id.clone(),
+            connector: backfill.connector,
+            requests_per_minute: backfill.requests_per_minute,
+            scanned_count: backfill.scanned_count,
+            missing_count: backfill.missing_count,
+            repaired_count: backfill.repaired_count,
+            unresolved_count: backfill.unresolved_count,
+            skipped_count: backfill.skipped_count,
+            failed_count: backfill.failed_count,
+            failures: backfill.failures.clone(),
+            created_at: backfill.created_at,
+            modified_at: backfill.modified_at,
+        }
+    }
+}
+
+/// Whether the attempt was sent to a connector, but has no connector transaction ID. The attempts
+/// which did not reach a connector legitimately have none.
+fn is_connector_transaction_id_missing(payment_attempt: &storage::PaymentAttempt) -> bool {
+    payment_attempt.connector.is_some()
+        && payment_attempt.get_connector_payment_id().is_none()
+        && !matches!(
+            payment_attempt.status,
+            enums::AttemptStatus::Started
+                | enums::AttemptStatus::RouterDeclined
+                | enums::AttemptStatus::PaymentMethodAwaited
+                | enums::AttemptStatus::ConfirmationAwaited
+                | enums::AttemptStatus::AuthenticationPending
+                | enums::AttemptStatus::AuthenticationFailed
+        )
+}
+
+/// Whether the status of the payment is synced with its connector. The payments in a final status
+/// are not, so their connector transaction ID cannot be backfilled with a sync.
+fn is_syncable(intent_status: enums::IntentStatus) -> bool {
+    matches!(
+        intent_status,
+        enums::IntentStatus::Processing
+            | enums::IntentStatus::RequiresCustomerAction
+            | enums::IntentStatus::RequiresMerchantAction
+            | enums::IntentStatus::RequiresCapture
+            | enums::IntentStatus::PartiallyCapturedAndCapturable
+    )
+}
+
+async fn get_connector_transaction_id_backfill(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    backfill_id: &str,
+) -> RouterResult<ConnectorTransactionIdBackfill> {
+    db.find_config_by_key(&merchant_id.get_connector_transaction_id_backfill_key(backfill_id))
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::ApiErrorResponse::GenericNotFoundError {
+                    message: "Connector transaction ID backfill not found".to_string(),
+                })
+            } else {
+                error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch connector transaction ID backfill")
+            }
+        })?
+        .config
+        .parse_struct("ConnectorTransactionIdBackfill")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse connector transaction ID backfill")
+}
+
+async fn update_connector_transaction_id_backfill(
+    db: &dyn StorageInterface,
+    backfill: &ConnectorTransactionIdBackfill,
+) -> RouterResult<()> {
+    let serialized_backfill = backfill
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize connector transaction ID backfill")?;
+
+    db.update_config_by_key(
+        &backfill
+            .merchant_id
+            .get_connector_transaction_id_backfill_key(&backfill.backfill_id),
+        configs::ConfigUpdate::Update {
+            config: Some(serialized_backfill),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update connector transaction ID backfill")?;
+
+    Ok(())
+}
+
+async fn add_connector_transaction_id_backfill_task(
+    db: &dyn StorageInterface,
+    tracking_data: storage::payment_attempt::ConnectorTransactionIdBackfillTrackingData,
+    schedule_time: PrimitiveDateTime,
+) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::ConnectorTransactionIdBackfillWorkflow;
+    let process_tracker_id = format!(
+        "{runner}_{CONNECTOR_TRANSACTION_ID_BACKFILL_TASK}_{}",
+        tracking_data.backfill_id
+    );
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        CONNECTOR_TRANSACTION_ID_BACKFILL_TASK,
+        runner,
+        [CONNECTOR_TRANSACTION_ID_BACKFILL_TAG],
+        tracking_data,
+        None,
+        schedule_time,
+        hyperswitch_domain_models::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable(
+        "Failed to construct CONNECTOR_TRANSACTION_ID_BACKFILL process tracker task",
+    )?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable(
+            "Failed while inserting CONNECTOR_TRANSACTION_ID_BACKFILL task to process_tracker",
+        )?;
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn create_connector_transaction_id_backfill(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    req: ConnectorTransactionIdBackfillRequest,
+) -> RouterResponse<ConnectorTransactionIdBackfillResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let now = common_utils::date_time::now();
+    if req.start_time >= req.end_time {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "start_time must be before end_time".to_string(),
+        }));
+    }
+    if req.end_time > now {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "end_time cannot be in the future".to_string(),
+        }));
+    }
+    if req.end_time - req.start_time
+        > time::Duration::days(consts::CONNECTOR_TRANSACTION_ID_BACKFILL_MAX_RANGE_IN_DAYS)
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "The time range cannot exceed {} days",
+                consts::CONNECTOR_TRANSACTION_ID_BACKFILL_MAX_RANGE_IN_DAYS
+            ),
+        }));
+    }
+
+    let requests_per_minute = req
+        .requests_per_minute
+        .unwrap_or(consts::CONNECTOR_TRANSACTION_ID_BACKFILL_DEFAULT_REQUESTS_PER_MINUTE);
+    if !(1..=consts::CONNECTOR_TRANSACTION_ID_BACKFILL_MAX_REQUESTS_PER_MINUTE)
+        .contains(&requests_per_minute)
+    {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "requests_per_minute must be between 1 and {}",
+                consts::CONNECTOR_TRANSACTION_ID_BACKFILL_MAX_REQUESTS_PER_MINUTE
+            ),
+        }));
+    }
+
+    if let Some(profile_id) = &req.profile_id {
+        core_utils::validate_and_get_business_profile(
+            db,
+            key_manager_state,
+            &key_store,
+            Some(profile_id),
+            &merchant_id,
+        )
+        .await?;
+    }
+
+    let backfill = ConnectorTransactionIdBackfill {
+        backfill_id: common_utils::generate_id(consts::ID_LENGTH, "ctb"),
+        merchant_id: merchant_id.clone(),
+        status: ConnectorTransactionIdBackfillStatus::Queued,
+        start_time: req.start_time,
+        end_time: req.end_time,
+        profile_id: req.profile_id,
+        connector: req.connector,
+        requests_per_minute,
+        scanned_count: 0,
+        missing_count: 0,
+        repaired_count: 0,
+        unresolved_count: 0,
+        skipped_count: 0,
+        failed_count: 0,
+        failures: Vec::new(),
+        created_at: now,
+        modified_at: now,
+    };
+    let serialized_backfill = backfill
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize connector transaction ID backfill")?;
+
+    db.insert_config(configs::ConfigNew {
+        key: merchant_id.get_connector_transaction_id_backfill_key(&backfill.backfill_id),
+        config: serialized_backfill,
+    })
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to insert connector transaction ID backfill")?;
+
+    add_connector_transaction_id_backfill_task(
+        db,
+        storage::payment_attempt::ConnectorTransactionIdBackfillTrackingData {
+            backfill_id: backfill.backfill_id.clone(),
+            merchant_id,
+        },
+        now,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        ConnectorTransactionIdBackfillResponse::from(&backfill),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_connector_transaction_id_backfill(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    backfill_id: String,
+) -> RouterResponse<ConnectorTransactionIdBackfillResponse> {
+    let backfill =
+        get_connector_transaction_id_backfill(state.store.as_ref(), &merchant_id, &backfill_id)
+            .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        ConnectorTransactionIdBackfillResponse::from(&backfill),
+    ))
+}
+
+/// Syncs the payment with its connector, and returns whether its attempt got a connector
+/// transaction ID from the sync
+async fn sync_payment(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: id_type::PaymentId,
+) -> RouterResult<bool> {
+    let request = api::PaymentsRetrieveRequest {
+        resource_id: api::PaymentIdType::PaymentIntentId(payment_id),
+        merchant_id: Some(merchant_account.get_id().clone()),
+        force_sync: true,
+        ..Default::default()
+    };
+
+    let (payment_data, _, _, _, _) = Box::pin(payments::payments_operation_core::<
+        api::PSync,
+        _,
+        _,
+        _,
+        payments::PaymentData<api::PSync>,
+    >(
+        state,
+        state.get_req_state(),
+        merchant_account.clone(),
+        None,
+        key_store.clone(),
+        operations::PaymentStatus,
+        request,
+        payments::CallConnectorAction::Trigger,
+        services::AuthFlow::Merchant,
+        None,
+        HeaderPayload::default(),
+        None, //Platform merchant account
+    ))
+    .await?;
+
+    Ok(payment_data
+        .payment_attempt
+        .get_connector_payment_id()
+        .is_some())
+}
+
+/// Scans the next pages of payments of the backfill, syncing at most `requests_per_minute` of
+/// them with their connectors. Returns `true` once all the payments of the time range have been
+/// scanned.
+#[instrument(skip_all)]
+pub async fn process_connector_transaction_id_backfill(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    backfill_id: &str,
+) -> RouterResult<bool> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let mut backfill =
+        get_connector_transaction_id_backfill(db, merchant_account.get_id(), backfill_id).await?;
+    if matches!(
+        backfill.status,
+        ConnectorTransactionIdBackfillStatus::Completed
+            | ConnectorTransactionIdBackfillStatus::Failed
+    ) {
+        return Ok(true);
+    }
+    backfill.status = ConnectorTransactionIdBackfillStatus::Processing;
+
+    let page_size = consts::CONNECTOR_TRANSACTION_ID_BACKFILL_PAGE_SIZE;
+    let mut remaining_requests = backfill.requests_per_minute;
+    let mut is_completed = false;
+
+    for _ in 0..consts::CONNECTOR_TRANSACTION_ID_BACKFILL_MAX_PAGES_PER_RUN {
+        let constraints = PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+            offset: u32::try_from(backfill.scanned_count)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Connector transaction ID backfill offset out of range")?,
+            starting_at: Some(backfill.start_time),
+            ending_at: Some(backfill.end_time),
+            amount_filter: None,
+            connector: backfill.connector.map(|connector| vec![connector]),
+            currency: None,
+            status: None,
+            payment_method: None,
+            payment_method_type: None,
+            authentication_type: None,
+            merchant_connector_id: None,
+            profile_id: backfill
+                .profile_id
+                .clone()
+                .map(|profile_id| vec![profile_id]),
+            customer_id: None,
+            starting_after_id: None,
+            ending_before_id: None,
+            limit: Some(page_size),
+            order: Order {
+                on: SortOn::Created,
+                by: SortBy::Asc,
+            },
+            card_network: None,
+            card_discovery: None,
+            merchant_order_reference_id: None,
+            customer_email_hash: None,
+            customer_phone_hash: None,
+            custom_fields: None,
+        }));
+        let payments = db
+            .get_filtered_payment_intents_attempt(
+                key_manager_state,
+                merchant_account.get_id(),
+                &constraints,
+                key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "Failed to fetch the payments of the connector transaction ID backfill",
+            )?;
+
+        let fetched_count = payments.len();
+        let mut consumed_count = 0;
+        for (payment_intent, payment_attempt) in payments {
+            if !is_connector_transaction_id_missing(&payment_attempt) {
+                consumed_count += 1;
+                continue;
+            }
+            if !is_syncable(payment_intent.status) {
+                backfill.missing_count += 1;
+                backfill.skipped_count += 1;
+                consumed_count += 1;
+                continue;
+            }
+            // The payment is left for the next run once the requests of this run are used up
+            if remaining_requests == 0 {
+                break;
+            }
+            remaining_requests -= 1;
+            backfill.missing_count += 1;
+            consumed_count += 1;
+
+            match sync_payment(
+                state,
+                merchant_account,
+                key_store,
+                payment_intent.payment_id.clone(),
+            )
+            .await
+            {
+                Ok(true) => backfill.repaired_count += 1,
+                Ok(false) => backfill.unresolved_count += 1,
+                Err(error) => {
+                    logger::warn!(
+                        ?error,
+                        payment_id = ?payment_intent.payment_id,
+                        "Failed to sync the payment of the connector transaction ID backfill"
+                    );
+                    backfill.failed_count += 1;
+                    if backfill.failures.len()
+                        >= consts::CONNECTOR_TRANSACTION_ID_BACKFILL_MAX_FAILURES
+                    {
+                        backfill.failures.remove(0);
+                    }
+                    backfill
+                        .failures
+                        .push(ConnectorTransactionIdBackfillFailure {
+                            payment_id: payment_intent.payment_id,
+                            attempt_id: payment_attempt.attempt_id,
+                            error_message: error.current_context().to_string(),
+                        });
+                }
+            }
+        }
+        backfill.scanned_count += u64::try_from(consumed_count).unwrap_or(u64::MAX);
+
+        if consumed_count < fetched_count {
+            break;
+        }
+        if fetched_count < usize::try_from(page_size).unwrap_or(usize::MAX) {
+            is_completed = true;
+            break;
+        }
+    }
+
+    if is_completed {
+        backfill.status = ConnectorTransactionIdBackfillStatus::Completed;
+    }
+    backfill.modified_at = common_utils::date_time::now();
+    update_connector_transaction_id_backfill(db, &backfill).await?;
+
+    Ok(is_completed)
+}
+
+/// Marks the backfill as failed once the retries of its task are exhausted
+#[instrument(skip_all)]
+pub async fn fail_connector_transaction_id_backfill(
+    db: &dyn StorageInterface,
+    tracking_data: &storage::payment_attempt::ConnectorTransactionIdBackfillTrackingData,
+) -> RouterResult<()> {
+    let mut backfill = get_connector_transaction_id_backfill(
+        db,
+        &tracking_data.merchant_id,
+        &tracking_data.backfill_id,
+    )
+    .await?;
+    backfill.status = ConnectorTransactionIdBackfillStatus::Failed;
+    backfill.modified_at = common_utils::date_time::now();
+    update_connector_transaction_id_backfill(db, &backfill).await
+}
@@ -0,0 +1,66 @@
+//! Discovery of the payment methods, the currencies and the countries that a profile can accept
+//! payments in, derived from the same constraint graph of its merchant connector accounts that
+//! the eligibility of the connectors is checked against while routing the payments.
+
+use api_models::{admin::ProfileCoverageResponse, enums as api_enums};
+use common_utils::id_type;
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse},
+        payments::routing,
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services,
+    types::domain,
+};
+
+#[instrument(skip_all)]
+pub async fn retrieve_profile_coverage(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<ProfileCoverageResponse> {
+    core_utils::validate_and_get_business_profile(
+        state.store.as_ref(),
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    let transaction_type = api_enums::TransactionType::Payment;
+    let cgraph = routing::get_merchant_cgraph(&state, &key_store, &profile_id, &transaction_type)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the constraint graph of the profile")?;
+    let merchant_connector_accounts = routing::get_cgraph_merchant_connector_accounts(
+        &state,
+        &key_store,
+        &profile_id,
+        &transaction_type,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch the merchant connector accounts of the profile")?;
+    let config = routing::get_country_currency_filter(&state)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to build the payment method filters")?;
+
+    let coverage =
+        kgraph_utils::coverage::get_coverage(&cgraph, &merchant_connector_accounts, &config)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to build the coverage of the profile")?;
+
+    Ok(services::ApplicationResponse::Json(
+        ProfileCoverageResponse {
+            profile_id,
+            coverage,
+        },
+    ))
+}
@@ -1,3 +1,8 @@
+#[cfg(all(feature = "olap", feature = "v1", not(feature = "customer_v2")))]
+pub mod payment_history;
+#[cfg(feature = "v1")]
+pub mod store_credit;
+
 use common_utils::{
     crypto::Encryptable,
     errors::ReportSwitchExt,
@@ -15,9 +20,13 @@ use router_env::{instrument, tracing};
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
 use crate::core::payment_methods::cards::create_encrypted_data;
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
-use crate::utils::CustomerAddress;
+use crate::{
+    core::{address_validation, data_residency},
+    utils::CustomerAddress,
+};
 use crate::{
     core::{
+        address_validation::AddressValidationRecord,
         errors::{self, StorageErrorExt},
         payment_methods::{cards, network_tokenization},
     },
@@ -42,7 +51,18 @@ pub async fn create_customer(
     key_store: domain::MerchantKeyStore,
     customer_data: customers::CustomerRequest,
 ) -> errors::CustomerResponse<customers::CustomerResponse> {
+    #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+    let db = data_residency::get_pii_store(&state, merchant_account.get_id())
+        .await
+        .switch()?;
+    #[cfg(all(feature = "v2", feature = "customer_v2"))]
     let db: &dyn StorageInterface = state.store.as_ref();
+    #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+    let key_manager_state =
+        &data_residency::get_key_manager_state(&state, merchant_account.get_id())
+            .await
+            .switch()?;
+    #[cfg(all(feature = "v2", feature = "customer_v2"))]
     let key_manager_state = &(&state).into();
 
     let merchant_reference_id = customer_data.get_merchant_reference_id();
@@ -67,6 +87,17 @@ pub async fn create_customer(
         .verify_if_merchant_reference_not_present_by_optional_merchant_reference_id(db)
         .await?;
 
+    #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+    let address_validation = address_validation::validate_address(
+        &state,
+        merchant_id,
+        None,
+        customer_data.get_address().as_ref(),
+    )
+    .await;
+    #[cfg(all(feature = "v2", feature = "customer_v2"))]
+    let address_validation = None;
+
     let domain_customer = customer_data
         .create_domain_model_from_request(
             db,
@@ -75,6 +106,7 @@ pub async fn create_customer(
             &merchant_account,
             key_manager_state,
             &state,
+            address_validation.as_ref(),
         )
         .await?;
 
@@ -88,11 +120,12 @@ pub async fn create_customer(
         .await
         .to_duplicate_response(errors::CustomersErrorResponse::CustomerAlreadyExists)?;
 
-    customer_data.generate_response(&customer)
+    customer_data.generate_response(&customer, address_validation.as_ref())
 }
 
 #[async_trait::async_trait]
 trait CustomerCreateBridge {
+    #[allow(clippy::too_many_arguments)]
     async fn create_domain_model_from_request<'a>(
         &'a self,
         db: &'a dyn StorageInterface,
@@ -101,11 +134,13 @@ trait CustomerCreateBridge {
         merchant_account: &'a domain::MerchantAccount,
         key_manager_state: &'a KeyManagerState,
         state: &'a SessionState,
+        address_validation: Option<&'a AddressValidationRecord>,
     ) -> errors::CustomResult<domain::Customer, errors::CustomersErrorResponse>;
 
     fn generate_response<'a>(
         &'a self,
         customer: &'a domain::Customer,
+        address_validation: Option<&'a AddressValidationRecord>,
     ) -> errors::CustomerResponse<customers::CustomerResponse>;
 }
 
@@ -120,6 +155,7 @@ impl CustomerCreateBridge for customers::CustomerRequest {
         merchant_account: &'a domain::MerchantAccount,
         key_manager_state: &'a KeyManagerState,
         state: &'a SessionState,
+        address_validation: Option<&'a AddressValidationRecord>,
     ) -> errors::CustomResult<domain::Customer, errors::CustomersErrorResponse> {
         // Setting default billing address to Db
         let address = self.get_address();
@@ -135,6 +171,7 @@ impl CustomerCreateBridge for customers::CustomerRequest {
             key_store,
             key_manager_state,
             state,
+            address_validation,
         };
 
         let address_from_db = customer_billing_address_struct
@@ -195,10 +232,15 @@ impl CustomerCreateBridge for customers::CustomerRequest {
     fn generate_response<'a>(
         &'a self,
         customer: &'a domain::Customer,
+        address_validation: Option<&'a AddressValidationRecord>,
     ) -> errors::CustomerResponse<customers::CustomerResponse> {
         let address = self.get_address();
         Ok(services::ApplicationResponse::Json(
-            customers::CustomerResponse::foreign_from((customer.clone(), address)),
+            customers::CustomerResponse::foreign_from((
+                customer.clone(),
+                address,
+                address_validation.map(|record| record.validation.clone()),
+            )),
         ))
     }
 }
@@ -214,6 +256,7 @@ impl CustomerCreateBridge for customers::CustomerRequest {
         merchant_account: &'a domain::MerchantAccount,
         key_state: &'a KeyManagerState,
         state: &'a SessionState,
+        _address_validation: Option<&'a AddressValidationRecord>,
     ) -> errors::CustomResult<domain::Customer, errors::CustomersErrorResponse> {
         let default_customer_billing_address = self.get_default_customer_billing_address();
         let encrypted_customer_billing_address = default_customer_billing_address
@@ -292,6 +335,7 @@ impl CustomerCreateBridge for customers::CustomerRequest {
     fn generate_response<'a>(
         &'a self,
         customer: &'a domain::Customer,
+        _address_validation: Option<&'a AddressValidationRecord>,
     ) -> errors::CustomerResponse<customers::CustomerResponse> {
         Ok(services::ApplicationResponse::Json(
             customers::CustomerResponse::foreign_from(customer.clone()),
@@ -309,6 +353,7 @@ struct AddressStructForDbEntry<'a> {
     key_store: &'a domain::MerchantKeyStore,
     key_manager_state: &'a KeyManagerState,
     state: &'a SessionState,
+    address_validation: Option<&'a AddressValidationRecord>,
 }
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
@@ -329,6 +374,7 @@ impl AddressStructForDbEntry<'_> {
                             .ok_or(errors::CustomersErrorResponse::InternalServerError)?, // should we raise error since in v1 appilcation is supposed to have this id or generate it at this point.
                         self.key_store.key.get_inner().peek(),
                         self.storage_scheme,
+                        self.address_validation,
                     )
                     .await
                     .switch()
@@ -457,8 +503,13 @@ pub async fn retrieve_customer(
     key_store: domain::MerchantKeyStore,
     customer_id: id_type::CustomerId,
 ) -> errors::CustomerResponse<customers::CustomerResponse> {
-    let db = state.store.as_ref();
-    let key_manager_state = &(&state).into();
+    let db = data_residency::get_pii_store(&state, merchant_account.get_id())
+        .await
+        .switch()?;
+    let key_manager_state =
+        &data_residency::get_key_manager_state(&state, merchant_account.get_id())
+            .await
+            .switch()?;
 
     let response = db
         .find_customer_optional_with_redacted_customer_details_by_customer_id_merchant_id(
@@ -473,15 +524,22 @@ pub async fn retrieve_customer(
         .ok_or(errors::CustomersErrorResponse::CustomerNotFound)?;
 
     let address = match &response.address_id {
-        Some(address_id) => Some(api_models::payments::AddressDetails::from(
+        Some(address_id) => Some(
             db.find_address_by_address_id(key_manager_state, address_id, &key_store)
                 .await
                 .switch()?,
-        )),
+        ),
         None => None,
     };
+    let address_validation = address
+        .as_ref()
+        .and_then(address_validation::get_address_validation_details);
     Ok(services::ApplicationResponse::Json(
-        customers::CustomerResponse::foreign_from((response, address)),
+        customers::CustomerResponse::foreign_from((
+            response,
+            address.map(api_models::payments::AddressDetails::from),
+            address_validation,
+        )),
     ))
 }
 
@@ -520,7 +578,18 @@ pub async fn list_customers(
     key_store: domain::MerchantKeyStore,
     request: customers::CustomerListRequest,
 ) -> errors::CustomerResponse<Vec<customers::CustomerResponse>> {
+    #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+    let db = data_residency::get_pii_store(&state, &merchant_id)
+        .await
+        .switch()?;
+    #[cfg(all(feature = "v2", feature = "customer_v2"))]
     let db = state.store.as_ref();
+    #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+    let key_manager_state = data_residency::get_key_manager_state(&state, &merchant_id)
+        .await
+        .switch()?;
+    #[cfg(all(feature = "v2", feature = "customer_v2"))]
+    let key_manager_state = KeyManagerState::from(&state);
 
     let customer_list_constraints = crate::db::customers::CustomerListConstraints {
         limit: request
@@ -531,7 +600,7 @@ pub async fn list_customers(
 
     let domain_customers = db
         .list_customers_by_merchant_id(
-            &(&state).into(),
+            &key_manager_state,
             &merchant_id,
             &key_store,
             customer_list_constraints,
@@ -542,7 +611,9 @@ pub async fn list_customers(
     #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
     let customers = domain_customers
         .into_iter()
-        .map(|domain_customer| customers::CustomerResponse::foreign_from((domain_customer, None)))
+        .map(|domain_customer| {
+            customers::CustomerResponse::foreign_from((domain_customer, None, None))
+        })
         .collect();
 
     #[cfg(all(feature = "v2", feature = "customer_v2"))]
@@ -741,7 +812,10 @@ pub async fn delete_customer(
     key_store: domain::MerchantKeyStore,
 ) -> errors::CustomerResponse<customers::CustomerDeleteResponse> {
     let db = &*state.store;
-    let key_manager_state = &(&state).into();
+    let key_manager_state =
+        &data_residency::get_key_manager_state(&state, merchant_account.get_id())
+            .await
+            .switch()?;
     customer_id
         .redact_customer_details_and_generate_response(
             db,
@@ -768,7 +842,10 @@ impl CustomerDeleteBridge for id_type::CustomerId {
         key_manager_state: &'a KeyManagerState,
         state: &'a SessionState,
     ) -> errors::CustomerResponse<customers::CustomerDeleteResponse> {
-        let customer_orig = db
+        let pii_db = data_residency::get_pii_store(state, merchant_account.get_id())
+            .await
+            .switch()?;
+        let customer_orig = pii_db
             .find_customer_by_customer_id_merchant_id(
                 key_manager_state,
                 self,
@@ -889,9 +966,10 @@ impl CustomerDeleteBridge for id_type::CustomerId {
             country_code: Some(REDACTED.to_string()),
             updated_by: merchant_account.storage_scheme.to_string(),
             email: Some(redacted_encrypted_email),
+            normalized_address: Some(redacted_encrypted_value.clone()),
         };
 
-        match db
+        match pii_db
             .update_address_by_merchant_id_customer_id(
                 key_manager_state,
                 self,
@@ -935,17 +1013,18 @@ impl CustomerDeleteBridge for id_type::CustomerId {
             address_id: None,
         };
 
-        db.update_customer_by_customer_id_merchant_id(
-            key_manager_state,
-            self.clone(),
-            merchant_account.get_id().to_owned(),
-            customer_orig,
-            updated_customer,
-            key_store,
-            merchant_account.storage_scheme,
-        )
-        .await
-        .switch()?;
+        pii_db
+            .update_customer_by_customer_id_merchant_id(
+                key_manager_state,
+                self.clone(),
+                merchant_account.get_id().to_owned(),
+                customer_orig,
+                updated_customer,
+                key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .switch()?;
 
         let response = customers::CustomerDeleteResponse {
             customer_id: self.clone(),
@@ -965,7 +1044,18 @@ pub async fn update_customer(
     update_customer: customers::CustomerUpdateRequestInternal,
     key_store: domain::MerchantKeyStore,
 ) -> errors::CustomerResponse<customers::CustomerResponse> {
+    #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+    let db = data_residency::get_pii_store(&state, merchant_account.get_id())
+        .await
+        .switch()?;
+    #[cfg(all(feature = "v2", feature = "customer_v2"))]
     let db = state.store.as_ref();
+    #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+    let key_manager_state =
+        &data_residency::get_key_manager_state(&state, merchant_account.get_id())
+            .await
+            .switch()?;
+    #[cfg(all(feature = "v2", feature = "customer_v2"))]
     let key_manager_state = &(&state).into();
     //Add this in update call if customer can be updated anywhere else
 
@@ -1082,6 +1172,7 @@ impl AddressStructForDbUpdate<'_> {
                             &self.domain_customer.customer_id,
                             self.key_store.key.get_inner().peek(),
                             self.merchant_account.storage_scheme,
+                            None,
                         )
                         .await
                         .switch()
@@ -1267,7 +1358,7 @@ impl CustomerUpdateBridge for customers::CustomerUpdateRequest {
     ) -> errors::CustomerResponse<customers::CustomerResponse> {
         let address = self.get_address();
         Ok(services::ApplicationResponse::Json(
-            customers::CustomerResponse::foreign_from((customer.clone(), address)),
+            customers::CustomerResponse::foreign_from((customer.clone(), address, None)),
         ))
     }
 }
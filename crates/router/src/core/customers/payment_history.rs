@@ -0,0 +1,232 @@
+//! Payment history of a customer, with the statistics of their payments computed server-side for
+//! the support and the risk review tooling of the merchant: the lifetime value of the customer per
+//! currency, the disputes raised on their payments and the payment method they use the most. The
+//! statistics are computed over the most recent payments of the customer, up to a maximum number
+//! of payments, and the payments are paginated from the most recent payment.
+
+use api_models::customers::{
+    CustomerLifetimeValue, CustomerPaymentStats, CustomerPaymentsListRequest,
+    CustomerPaymentsListResponse, CustomerPreferredPaymentMethod,
+};
+use common_utils::{id_type, types::MinorUnit};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::payments::payment_intent::{
+    PaymentIntentFetchConstraints, PaymentIntentListParams,
+};
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::{
+        data_residency,
+        errors::{self, RouterResponse, StorageErrorExt},
+        payments::{custom_fields, custom_status},
+    },
+    routes::SessionState,
+    services,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+};
+
+const DEFAULT_LIMIT: u32 = 10;
+
+fn is_successful(status: enums::IntentStatus) -> bool {
+    matches!(
+        status,
+        enums::IntentStatus::Succeeded
+            | enums::IntentStatus::PartiallyCaptured
+            | enums::IntentStatus::PartiallyCapturedAndCapturable
+    )
+}
+
+/// Computes the statistics of the payments of a customer, most recent payment first
+fn get_payment_stats(
+    payments: &[(storage::PaymentIntent, storage::PaymentAttempt)],
+    dispute_count: u64,
+    is_partial: bool,
+) -> CustomerPaymentStats {
+    let mut successful_payments = 0;
+    let mut failed_payments = 0;
+    let mut lifetime_value: Vec<CustomerLifetimeValue> = Vec::new();
+    // The payment methods in the order of their most recent use, with their number of payments
+    let mut payment_methods: Vec<(
+        (enums::PaymentMethod, Option<enums::PaymentMethodType>),
+        u64,
+    )> = Vec::new();
+
+    for (payment_intent, payment_attempt) in payments {
+        if payment_intent.status == enums::IntentStatus::Failed {
+            failed_payments += 1;
+        }
+        if !is_successful(payment_intent.status) {
+            continue;
+        }
+        successful_payments += 1;
+
+        if let Some((currency, amount_captured)) =
+            payment_intent.currency.zip(payment_intent.amount_captured)
+        {
+            match lifetime_value
+                .iter_mut()
+                .find(|value| value.currency == currency)
+            {
+                Some(value) => value.amount = value.amount + amount_captured,
+                None => lifetime_value.push(CustomerLifetimeValue {
+                    currency,
+                    amount: amount_captured,
+                }),
+            }
+        }
+
+        if let Some(payment_method) = payment_attempt.payment_method {
+            let key = (payment_method, payment_attempt.payment_method_type);
+            match payment_methods.iter_mut().find(|(used, _)| *used == key) {
+                Some((_, payments_count)) => *payments_count += 1,
+                None => payment_methods.push((key, 1)),
+            }
+        }
+    }
+
+    // The most recently used payment method wins a tie, as it comes first
+    let preferred_payment_method = payment_methods
+        .into_iter()
+        .rev()
+        .max_by_key(|(_, payments_count)| *payments_count)
+        .map(|((payment_method, payment_method_type), payments_count)| {
+            CustomerPreferredPaymentMethod {
+                payment_method,
+                payment_method_type,
+                payments_count,
+            }
+        });
+    lifetime_value.retain(|value| value.amount > MinorUnit::zero());
+
+    CustomerPaymentStats {
+        total_payments: u64::try_from(payments.len()).unwrap_or(u64::MAX),
+        successful_payments,
+        failed_payments,
+        lifetime_value,
+        dispute_count,
+        preferred_payment_method,
+        first_payment_at: payments
+            .last()
+            .map(|(payment_intent, _)| payment_intent.created_at),
+        last_payment_at: payments
+            .first()
+            .map(|(payment_intent, _)| payment_intent.created_at),
+        is_partial,
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn list_customer_payments(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    customer_id: id_type::CustomerId,
+    request: CustomerPaymentsListRequest,
+) -> RouterResponse<CustomerPaymentsListResponse> {
+    let db = state.store.as_ref();
+    let limit = request.limit.unwrap_or(DEFAULT_LIMIT);
+    if !(1..=common_utils::consts::PAYMENTS_LIST_MAX_LIMIT_V1).contains(&limit) {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "limit should be between 1 and {}",
+                common_utils::consts::PAYMENTS_LIST_MAX_LIMIT_V1
+            ),
+        }
+        .into());
+    }
+
+    data_residency::get_pii_store(&state, merchant_account.get_id())
+        .await?
+        .find_customer_optional_by_customer_id_merchant_id(
+            &(&state).into(),
+            &customer_id,
+            merchant_account.get_id(),
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the customer")?
+        .ok_or(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    // One more payment than can be aggregated is fetched, to know whether the statistics are
+    // partial
+    let max_payments = consts::CUSTOMER_PAYMENT_STATS_MAX_PAYMENTS;
+    let constraints = PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+        offset: 0,
+        starting_at: None,
+        ending_at: None,
+        amount_filter: None,
+        connector: None,
+        currency: None,
+        status: None,
+        payment_method: None,
+        payment_method_type: None,
+        authentication_type: None,
+        merchant_connector_id: None,
+        profile_id: profile_id.map(|profile_id| vec![profile_id]),
+        customer_id: Some(customer_id.clone()),
+        starting_after_id: None,
+        ending_before_id: None,
+        limit: Some(max_payments + 1),
+        order: Default::default(),
+        card_network: None,
+        card_discovery: None,
+        merchant_order_reference_id: None,
+        customer_email_hash: None,
+        customer_phone_hash: None,
+        custom_fields: None,
+    }));
+    let mut payments = db
+        .get_filtered_payment_intents_attempt(
+            &(&state).into(),
+            merchant_account.get_id(),
+            &constraints,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let is_partial = payments.len() > usize::try_from(max_payments).unwrap_or(usize::MAX);
+    payments.truncate(usize::try_from(max_payments).unwrap_or(usize::MAX));
+
+    let dispute_count = if payments.is_empty() {
+        0
+    } else {
+        let payment_ids = payments
+            .iter()
+            .map(|(payment_intent, _)| payment_intent.payment_id.clone())
+            .collect();
+        let disputes = db
+            .find_disputes_by_merchant_id_payment_ids(merchant_account.get_id(), payment_ids)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the disputes of the customer")?;
+        u64::try_from(disputes.len()).unwrap_or(u64::MAX)
+    };
+    let stats = get_payment_stats(&payments, dispute_count, is_partial);
+
+    let page = payments
+        .into_iter()
+        .skip(usize::try_from(request.offset.unwrap_or_default()).unwrap_or(usize::MAX))
+        .take(usize::try_from(limit).unwrap_or(usize::MAX))
+        .collect();
+    let mut data = custom_status::get_payments_response_with_custom_statuses(db, page).await?;
+    custom_fields::populate_custom_fields_of_payments(db, merchant_account.get_id(), &mut data)
+        .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        CustomerPaymentsListResponse {
+            customer_id,
+            stats,
+            count: data.len(),
+            data,
+        },
+    ))
+}
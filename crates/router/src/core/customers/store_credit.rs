@@ -0,0 +1,598 @@
+//! Store credit balances of the customers of the merchants, kept in a ledger. A refund with the
+//! store credit destination credits the balance of the customer of the payment instead of being
+//! refunded to the original payment method, and the future payments of the customer can apply a
+//! part of the balance to their amount, which their attempts then do not charge. The store credit
+//! applied to a payment is debited when the payment is confirmed, and credited back if the payment
+//! fails or is cancelled. Each debit of a payment is recorded and credited back at most once, and
+//! each refund credited at most once, so that the same request received several times does not
+//! move the balance again. A payment confirmed again once its debit has been credited back is
+//! debited again.
+
+use std::collections::HashMap;
+
+#[cfg(all(feature = "olap", not(feature = "customer_v2")))]
+use api_models::customers::{
+    CustomerStoreCreditResponse, StoreCreditEntriesListRequest, StoreCreditEntriesListResponse,
+    StoreCreditEntryResponse,
+};
+use api_models::{customers::StoreCreditBalance, payments::PaymentsRequest};
+use common_utils::{id_type, types::MinorUnit};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::{
+        api_locking,
+        errors::{self, RouterResult},
+    },
+    db::StorageInterface,
+    routes::{lock_utils, SessionState},
+    types::storage::{self, enums},
+    utils::OptionExt,
+};
+#[cfg(all(feature = "olap", not(feature = "customer_v2")))]
+use crate::{
+    core::{data_residency, errors::RouterResponse},
+    services,
+    types::{domain, transformers::ForeignFrom},
+};
+
+#[cfg(all(feature = "olap", not(feature = "customer_v2")))]
+const DEFAULT_LIMIT: u32 = 10;
+
+/// Validates the store credit requested on the creation of a payment
+pub fn validate_store_credit_request(request: &PaymentsRequest) -> RouterResult<()> {
+    let Some(store_credit_amount) = request.store_credit_amount else {
+        return Ok(());
+    };
+
+    if request.enable_partial_payments == Some(true) {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "store_credit_amount is not supported when enable_partial_payments is true"
+                .to_string(),
+        }));
+    }
+    if store_credit_amount <= MinorUnit::zero() {
+        return Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "store_credit_amount".to_string(),
+            expected_format: "positive integer".to_string(),
+        }));
+    }
+    match request.amount {
+        Some(amount) if store_credit_amount >= MinorUnit::from(amount) => {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "store_credit_amount must be less than the amount".to_string(),
+            }))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// The part of the amount of a payment charged by its attempts, which is the amount the store
+/// credit applied to the payment does not pay
+pub fn get_amount_to_charge(
+    amount: MinorUnit,
+    store_credit_amount: Option<MinorUnit>,
+) -> MinorUnit {
+    amount - store_credit_amount.unwrap_or_default()
+}
+
+/// The balances of the customer per currency, computed from the totals of the ledger entries of
+/// each type
+fn get_currency_balances(
+    total_amounts: Vec<(enums::Currency, enums::StoreCreditEntryType, i64)>,
+) -> Vec<StoreCreditBalance> {
+    let mut total_amounts_by_currency = HashMap::<_, HashMap<_, i64>>::new();
+    for (currency, entry_type, total_amount) in total_amounts {
+        *total_amounts_by_currency
+            .entry(currency)
+            .or_default()
+            .entry(entry_type)
+            .or_default() += total_amount;
+    }
+
+    let mut balances = total_amounts_by_currency
+        .into_iter()
+        .map(|(currency, total_amounts)| {
+            let get_total_amount =
+                |entry_type| total_amounts.get(&entry_type).copied().unwrap_or(0);
+            let credited_amount = get_total_amount(enums::StoreCreditEntryType::RefundCredit);
+            let debited_amount = get_total_amount(enums::StoreCreditEntryType::PaymentDebit)
+                - get_total_amount(enums::StoreCreditEntryType::PaymentDebitReversal);
+
+            StoreCreditBalance {
+                currency,
+                balance: MinorUnit::new(credited_amount - debited_amount),
+                credited_amount: MinorUnit::new(credited_amount),
+                debited_amount: MinorUnit::new(debited_amount),
+            }
+        })
+        .collect::<Vec<_>>();
+    balances.sort_by_key(|balance| balance.currency.to_string());
+    balances
+}
+
+async fn get_balance(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+    currency: enums::Currency,
+) -> RouterResult<MinorUnit> {
+    let total_amounts = db
+        .get_store_credit_total_amount_by_currency_and_entry_type(merchant_id, customer_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve the store credit totals of the customer")?;
+
+    Ok(get_currency_balances(total_amounts)
+        .into_iter()
+        .find(|balance| balance.currency == currency)
+        .map(|balance| balance.balance)
+        .unwrap_or_default())
+}
+
+/// Debits the store credit applied to the payment from the balance of its customer, once the
+/// payment is confirmed. The payment is rejected if the balance does not cover the store credit.
+/// The balance is checked and debited under a lock on the customer, so that the payments of the
+/// customer confirmed concurrently can not debit the same balance more than once.
+#[instrument(skip_all)]
+pub async fn debit_payment_store_credit(
+    state: &SessionState,
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<()> {
+    let Some(store_credit_amount) = payment_intent.store_credit_amount else {
+        return Ok(());
+    };
+    let customer_id = payment_intent.customer_id.clone().ok_or_else(|| {
+        report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "A customer is required to apply store credit to the payment".to_string(),
+        })
+    })?;
+    let currency = payment_intent.currency.get_required_value("currency")?;
+
+    let lock_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: format!("store_credit_{}", customer_id.get_string_repr()),
+            api_identifier: lock_utils::ApiIdentifier::Customers,
+            override_lock_retries: None,
+        },
+    };
+    lock_action
+        .clone()
+        .perform_locking_action(state, payment_intent.merchant_id.clone())
+        .await?;
+
+    let debit_result = debit_store_credit_balance(
+        &*state.store,
+        payment_intent,
+        customer_id,
+        currency,
+        store_credit_amount,
+    )
+    .await;
+
+    // The lock is released before any error of the debit is propagated
+    lock_action
+        .free_lock_action(state, payment_intent.merchant_id.clone())
+        .await?;
+    debit_result
+}
+
+/// The debit of the store credit of the payment which has not been credited back yet, if any
+fn find_outstanding_debit(
+    entries: &[storage::StoreCreditEntry],
+) -> Option<&storage::StoreCreditEntry> {
+    entries
+        .iter()
+        .filter(|entry| entry.entry_type == enums::StoreCreditEntryType::PaymentDebit)
+        .find(|debit| {
+            !entries.iter().any(|entry| {
+                entry.entry_type == enums::StoreCreditEntryType::PaymentDebitReversal
+                    && entry.debit_sequence == debit.debit_sequence
+            })
+        })
+}
+
+/// The sequence number of the next debit of the store credit of the payment, which tells the
+/// debits of a payment confirmed again after its previous debit was credited back apart
+fn get_next_debit_sequence(entries: &[storage::StoreCreditEntry]) -> i32 {
+    entries
+        .iter()
+        .filter(|entry| entry.entry_type == enums::StoreCreditEntryType::PaymentDebit)
+        .map(|debit| debit.debit_sequence + 1)
+        .max()
+        .unwrap_or(0)
+}
+
+async fn debit_store_credit_balance(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+    customer_id: id_type::CustomerId,
+    currency: enums::Currency,
+    store_credit_amount: MinorUnit,
+) -> RouterResult<()> {
+    // The store credit of a payment confirmed again, after a failure to call the connector, has
+    // already been debited, unless the payment failed and its debit was credited back
+    let entries = db
+        .find_store_credit_entries_by_merchant_id_payment_id(
+            &payment_intent.merchant_id,
+            &payment_intent.payment_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve the store credit entries of the payment")?;
+    if find_outstanding_debit(&entries).is_some() {
+        return Ok(());
+    }
+
+    let balance = get_balance(db, &payment_intent.merchant_id, &customer_id, currency).await?;
+    if balance < store_credit_amount {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "The store credit balance of the customer, {balance} {currency}, does not cover the store_credit_amount of the payment"
+            ),
+        }));
+    }
+
+    db.insert_store_credit_entry_if_absent(storage::StoreCreditEntryNew {
+        entry_id: common_utils::generate_id(consts::ID_LENGTH, "sce"),
+        merchant_id: payment_intent.merchant_id.clone(),
+        customer_id,
+        entry_type: enums::StoreCreditEntryType::PaymentDebit,
+        amount: store_credit_amount,
+        currency,
+        payment_id: payment_intent.payment_id.clone(),
+        refund_id: None,
+        debit_sequence: get_next_debit_sequence(&entries),
+        created_at: common_utils::date_time::now(),
+    })
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Unable to debit the store credit of the payment")?;
+
+    Ok(())
+}
+
+/// Credits the store credit debited for the payment back to the balance of its customer, once
+/// the payment has failed or been cancelled. Failures are only logged, as the ledger should never
+/// fail the processing of the payments on its own.
+#[instrument(skip_all, fields(payment_id = ?payment_intent.payment_id))]
+pub async fn reverse_payment_store_credit_if_required(
+    state: &SessionState,
+    payment_intent: &storage::PaymentIntent,
+    initial_intent_status: enums::IntentStatus,
+) {
+    if payment_intent.store_credit_amount.is_none()
+        || payment_intent.status == initial_intent_status
+        || !matches!(
+            payment_intent.status,
+            enums::IntentStatus::Failed | enums::IntentStatus::Cancelled
+        )
+    {
+        return;
+    }
+
+    let entries = match state
+        .store
+        .find_store_credit_entries_by_merchant_id_payment_id(
+            &payment_intent.merchant_id,
+            &payment_intent.payment_id,
+        )
+        .await
+    {
+        Ok(entries) => entries,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to fetch the store credit entries of the payment"
+            );
+            return;
+        }
+    };
+    let Some(debit) = find_outstanding_debit(&entries).cloned() else {
+        return;
+    };
+
+    let reversal = storage::StoreCreditEntryNew {
+        entry_id: common_utils::generate_id(consts::ID_LENGTH, "sce"),
+        merchant_id: debit.merchant_id,
+        customer_id: debit.customer_id,
+        entry_type: enums::StoreCreditEntryType::PaymentDebitReversal,
+        amount: debit.amount,
+        currency: debit.currency,
+        payment_id: debit.payment_id,
+        refund_id: None,
+        debit_sequence: debit.debit_sequence,
+        created_at: common_utils::date_time::now(),
+    };
+    match state
+        .store
+        .insert_store_credit_entry_if_absent(reversal)
+        .await
+    {
+        Ok(Some(_)) => logger::info!("Credited back the store credit of the payment"),
+        Ok(None) => {}
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to credit back the store credit of the payment"
+            );
+        }
+    }
+}
+
+/// Credits the amount of the refund to the store credit balance of the customer of the payment,
+/// and marks the refund as succeeded, as no connector is involved
+#[instrument(skip_all, fields(refund_id = %refund.refund_id))]
+pub async fn credit_refund_to_store_credit(
+    state: &SessionState,
+    refund: storage::Refund,
+    payment_intent: &storage::PaymentIntent,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<storage::Refund> {
+    let db = &*state.store;
+    let customer_id = payment_intent
+        .customer_id
+        .clone()
+        .get_required_value("customer_id")?;
+
+    db.insert_store_credit_entry_if_absent(storage::StoreCreditEntryNew {
+        entry_id: common_utils::generate_id(consts::ID_LENGTH, "sce"),
+        merchant_id: refund.merchant_id.clone(),
+        customer_id,
+        entry_type: enums::StoreCreditEntryType::RefundCredit,
+        amount: refund.refund_amount,
+        currency: refund.currency,
+        payment_id: refund.payment_id.clone(),
+        refund_id: Some(refund.refund_id.clone()),
+        debit_sequence: 0,
+        created_at: common_utils::date_time::now(),
+    })
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Unable to credit the refund to store credit")?;
+
+    let refund_id = refund.refund_id.clone();
+    db.update_refund(
+        refund,
+        storage::RefundUpdate::StatusUpdate {
+            connector_refund_id: None,
+            sent_to_gateway: false,
+            refund_status: enums::RefundStatus::Success,
+            updated_by: storage_scheme.to_string(),
+            processor_refund_data: None,
+        },
+        storage_scheme,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable_lazy(|| format!("Unable to update refund with refund_id: {refund_id}"))
+}
+
+#[cfg(all(feature = "olap", not(feature = "customer_v2")))]
+async fn validate_customer(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: &id_type::CustomerId,
+) -> RouterResult<()> {
+    data_residency::get_pii_store(state, merchant_account.get_id())
+        .await?
+        .find_customer_optional_by_customer_id_merchant_id(
+            &state.into(),
+            customer_id,
+            merchant_account.get_id(),
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the customer")?
+        .ok_or(errors::ApiErrorResponse::CustomerNotFound)?;
+    Ok(())
+}
+
+#[cfg(all(feature = "olap", not(feature = "customer_v2")))]
+#[instrument(skip_all)]
+pub async fn get_customer_store_credit(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    customer_id: id_type::CustomerId,
+) -> RouterResponse<CustomerStoreCreditResponse> {
+    validate_customer(&state, &merchant_account, &key_store, &customer_id).await?;
+
+    let total_amounts = state
+        .store
+        .get_store_credit_total_amount_by_currency_and_entry_type(
+            merchant_account.get_id(),
+            &customer_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve the store credit totals of the customer")?;
+
+    Ok(services::ApplicationResponse::Json(
+        CustomerStoreCreditResponse {
+            customer_id,
+            balances: get_currency_balances(total_amounts),
+        },
+    ))
+}
+
+#[cfg(all(feature = "olap", not(feature = "customer_v2")))]
+#[instrument(skip_all)]
+pub async fn list_customer_store_credit_entries(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    customer_id: id_type::CustomerId,
+    request: StoreCreditEntriesListRequest,
+) -> RouterResponse<StoreCreditEntriesListResponse> {
+    let limit = request.limit.unwrap_or(DEFAULT_LIMIT);
+    if !(1..=common_utils::consts::PAYMENTS_LIST_MAX_LIMIT_V1).contains(&limit) {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "limit should be between 1 and {}",
+                common_utils::consts::PAYMENTS_LIST_MAX_LIMIT_V1
+            ),
+        }
+        .into());
+    }
+    validate_customer(&state, &merchant_account, &key_store, &customer_id).await?;
+
+    let data = state
+        .store
+        .find_store_credit_entries_by_merchant_id_customer_id(
+            merchant_account.get_id(),
+            &customer_id,
+            Some(i64::from(limit)),
+            request.offset.map(i64::from),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve the store credit entries of the customer")?
+        .into_iter()
+        .map(StoreCreditEntryResponse::foreign_from)
+        .collect::<Vec<_>>();
+
+    Ok(services::ApplicationResponse::Json(
+        StoreCreditEntriesListResponse {
+            customer_id,
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+#[cfg(all(feature = "olap", not(feature = "customer_v2")))]
+impl ForeignFrom<storage::StoreCreditEntry> for StoreCreditEntryResponse {
+    fn foreign_from(entry: storage::StoreCreditEntry) -> Self {
+        Self {
+            entry_id: entry.entry_id,
+            entry_type: entry.entry_type,
+            amount: entry.amount,
+            currency: entry.currency,
+            payment_id: entry.payment_id,
+            refund_id: entry.refund_id,
+            created_at: entry.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use std::borrow::Cow;
+
+    use super::*;
+
+    fn ledger_entry(
+        entry_type: enums::StoreCreditEntryType,
+        debit_sequence: i32,
+    ) -> storage::StoreCreditEntry {
+        storage::StoreCreditEntry {
+            entry_id: format!("sce_{entry_type}_{debit_sequence}"),
+            merchant_id: id_type::MerchantId::try_from(Cow::from("test_merchant"))
+                .expect("valid merchant id"),
+            customer_id: id_type::CustomerId::try_from(Cow::from("test_customer"))
+                .expect("valid customer id"),
+            entry_type,
+            amount: MinorUnit::new(1500),
+            currency: enums::Currency::USD,
+            payment_id: id_type::PaymentId::try_from(Cow::from("test_payment"))
+                .expect("valid payment id"),
+            refund_id: None,
+            debit_sequence,
+            created_at: common_utils::date_time::now(),
+        }
+    }
+
+    #[test]
+    fn test_outstanding_debit_after_failure_reversal_and_retry() {
+        // The payment has not been confirmed yet
+        let mut entries = Vec::new();
+        assert!(find_outstanding_debit(&entries).is_none());
+        assert_eq!(get_next_debit_sequence(&entries), 0);
+
+        // The payment is confirmed, and confirming it again does not debit the balance again
+        entries.push(ledger_entry(enums::StoreCreditEntryType::PaymentDebit, 0));
+        assert_eq!(
+            find_outstanding_debit(&entries).map(|debit| debit.debit_sequence),
+            Some(0)
+        );
+
+        // The payment fails, and its debit is credited back
+        entries.push(ledger_entry(
+            enums::StoreCreditEntryType::PaymentDebitReversal,
+            0,
+        ));
+        assert!(find_outstanding_debit(&entries).is_none());
+        assert_eq!(get_next_debit_sequence(&entries), 1);
+
+        // The payment is retried, which debits the balance again
+        entries.push(ledger_entry(enums::StoreCreditEntryType::PaymentDebit, 1));
+        assert_eq!(
+            find_outstanding_debit(&entries).map(|debit| debit.debit_sequence),
+            Some(1)
+        );
+        assert_eq!(get_next_debit_sequence(&entries), 2);
+    }
+
+    #[test]
+    fn test_get_currency_balances() {
+        let balances = get_currency_balances(vec![
+            (
+                enums::Currency::USD,
+                enums::StoreCreditEntryType::RefundCredit,
+                5000,
+            ),
+            (
+                enums::Currency::USD,
+                enums::StoreCreditEntryType::PaymentDebit,
+                3000,
+            ),
+            (
+                enums::Currency::USD,
+                enums::StoreCreditEntryType::PaymentDebitReversal,
+                1000,
+            ),
+            (
+                enums::Currency::EUR,
+                enums::StoreCreditEntryType::RefundCredit,
+                700,
+            ),
+        ]);
+
+        assert_eq!(
+            balances,
+            vec![
+                StoreCreditBalance {
+                    currency: enums::Currency::EUR,
+                    balance: MinorUnit::new(700),
+                    credited_amount: MinorUnit::new(700),
+                    debited_amount: MinorUnit::new(0),
+                },
+                StoreCreditBalance {
+                    currency: enums::Currency::USD,
+                    balance: MinorUnit::new(3000),
+                    credited_amount: MinorUnit::new(5000),
+                    debited_amount: MinorUnit::new(2000),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_amount_to_charge() {
+        assert_eq!(
+            get_amount_to_charge(MinorUnit::new(6540), Some(MinorUnit::new(1500))),
+            MinorUnit::new(5040)
+        );
+        assert_eq!(
+            get_amount_to_charge(MinorUnit::new(6540), None),
+            MinorUnit::new(6540)
+        );
+    }
+}
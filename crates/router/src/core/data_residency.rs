@@ -0,0 +1,972 @@
+//! Data residency of the personal data of merchants. The customers and addresses of a merchant
+//! residing in a region are stored in the database of the region, and its cards and network tokens
+//! in the locker of the region, while the rest of its data, such as its payments, stays in the
+//! database of the deployment.
+//!
+//! The personal data of an existing merchant is migrated to its region by the scheduler, in passes
+//! which copy the rows and the locker entries updated since the start of the previous pass. Once a
+//! pass copies few enough rows, or after a bounded number of passes, the personal data of the
+//! merchant is switched over to the region: it is read from and written to the region, and a final
+//! pass copies what was updated in the deployment in the meantime and deletes it from there. The
+//! rows are compared by their modification time, so that the final pass never overwrites the rows
+//! already written to the region.
+//!
+//! The rows are copied as they are stored, so the personal data keeps being encrypted with the
+//! data key of the merchant, under the identifier of the merchant. When the region has a key
+//! manager of its own, the data key of the merchant is given to it before anything is copied, and
+//! the personal data of the merchant, including the encrypted personal data held in its payments
+//! and payment methods which stay in the deployment, is encrypted and decrypted by the key manager
+//! of the region once switched over. KV cannot be enabled for a merchant with a data residency, as
+//! its rows would be drained to the database of the deployment.
+
+use std::borrow::Cow;
+
+use api_models::admin::{DataResidencyRequest, DataResidencyResponse, DataResidencyStatus};
+use base64::Engine;
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    id_type, keymanager,
+    types::keymanager::{EncryptionTransferRequest, Identifier, KeyManagerState},
+};
+use diesel_models::{configs, query::data_residency::ResidencyMigrationCursor};
+use error_stack::{report, ResultExt};
+use masking::{ExposeInterface, PeekInterface};
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+use crate::core::payment_methods::external_vault;
+use crate::{
+    configs::settings,
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::{cards, transformers as payment_methods},
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{api::enums, storage},
+    utils::OptionExt,
+};
+
+const DATA_RESIDENCY_MIGRATION_TASK: &str = "DATA_RESIDENCY_MIGRATION";
+const DATA_RESIDENCY_MIGRATION_TAG: &str = "MERCHANT";
+
+/// The kind of personal data copied by a pass of a migration
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DataResidencyMigrationPhase {
+    Customers,
+    Addresses,
+    LockerEntries,
+}
+
+/// The data residency of a merchant, as stored along with the progress of its migration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataResidency {
+    pub merchant_id: id_type::MerchantId,
+    pub region: String,
+    pub status: DataResidencyStatus,
+    pub is_migration_failed: bool,
+    /// Whether the data key of the merchant was given to the key manager of the region
+    #[serde(default)]
+    pub is_key_transferred: bool,
+    pub pass_count: u32,
+    pub phase: DataResidencyMigrationPhase,
+    /// The position of the current pass in the rows of its phase
+    pub cursor: Option<ResidencyMigrationCursor>,
+    /// The rows updated at or after this time are copied by the current pass, which copies all the
+    /// rows when it is not set
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub modified_since: Option<PrimitiveDateTime>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub pass_started_at: Option<PrimitiveDateTime>,
+    /// The number of rows and locker entries copied by the current pass
+    pub pass_copied_count: u64,
+    pub copied_customers_count: u64,
+    pub copied_addresses_count: u64,
+    pub copied_locker_entries_count: u64,
+    pub failed_locker_entries_count: u64,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<&DataResidency> for DataResidencyResponse {
+    fn from(residency: &DataResidency) -> Self {
+        Self {
+            merchant_id: residency.merchant_id.clone(),
+            region: residency.region.clone(),
+            status: residency.status,
+            is_migration_failed: residency.is_migration_failed,
+            pass_count: residency.pass_count,
+            copied_customers_count: residency.copied_customers_count,
+            copied_addresses_count: residency.copied_addresses_count,
+            copied_locker_entries_count: residency.copied_locker_entries_count,
+            failed_locker_entries_count: residency.failed_locker_entries_count,
+            created_at: residency.created_at,
+            modified_at: residency.modified_at,
+        }
+    }
+}
+
+/// Returns the data residency of the merchant, if any
+pub async fn get_data_residency(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Option<DataResidency>> {
+    // The data residency is looked up on every access to the personal data of a merchant, so its
+    // absence is cached as well
+    db.find_config_by_key_unwrap_or(
+        &merchant_id.get_data_residency_key(),
+        Some("null".to_string()),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch data residency")?
+    .config
+    .parse_struct::<Option<DataResidency>>("DataResidency")
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to parse data residency")
+}
+
+async fn update_data_residency(
+    db: &dyn StorageInterface,
+    residency: &DataResidency,
+) -> RouterResult<()> {
+    let serialized_residency = residency
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize data residency")?;
+
+    db.update_config_by_key(
+        &residency.merchant_id.get_data_residency_key(),
+        configs::ConfigUpdate::Update {
+            config: Some(serialized_residency),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update data residency")?;
+
+    Ok(())
+}
+
+/// Returns the region holding the personal data of the merchant. The personal data is held by the
+/// deployment until the migration to the region of the merchant is being finalized.
+async fn get_pii_region(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Option<String>> {
+    Ok(get_data_residency(state.store.as_ref(), merchant_id)
+        .await?
+        .filter(|residency| {
+            matches!(
+                residency.status,
+                DataResidencyStatus::Finalizing | DataResidencyStatus::Active
+            )
+        })
+        .map(|residency| residency.region))
+}
+
+fn get_regional_store<'a>(
+    state: &'a SessionState,
+    region: &str,
+) -> RouterResult<&'a dyn StorageInterface> {
+    state
+        .regional_stores
+        .get(region)
+        .map(|store| store.as_ref())
+        .ok_or(report!(errors::ApiErrorResponse::InternalServerError))
+        .attach_printable_lazy(|| format!("Data residency region {region} is not configured"))
+}
+
+fn get_regional_locker(state: &SessionState, region: &str) -> RouterResult<settings::Locker> {
+    let region_config = state
+        .conf
+        .data_residency
+        .regions
+        .get(region)
+        .ok_or(report!(errors::ApiErrorResponse::InternalServerError))
+        .attach_printable_lazy(|| format!("Data residency region {region} is not configured"))?;
+
+    Ok(settings::Locker {
+        host: region_config.locker_host.clone(),
+        ..state.conf.locker.clone()
+    })
+}
+
+fn get_regional_key_manager_state(
+    state: &SessionState,
+    region: &str,
+) -> RouterResult<Option<KeyManagerState>> {
+    let region_config = state
+        .conf
+        .data_residency
+        .regions
+        .get(region)
+        .ok_or(report!(errors::ApiErrorResponse::InternalServerError))
+        .attach_printable_lazy(|| format!("Data residency region {region} is not configured"))?;
+
+    Ok(region_config
+        .key_manager_url
+        .clone()
+        .map(|url| KeyManagerState {
+            url,
+            ..KeyManagerState::from(state)
+        }))
+}
+
+/// Returns the key manager encrypting and decrypting the personal data of the merchant, which is
+/// the key manager of its region, if it has one, once the personal data is switched over to the
+/// region
+pub async fn get_key_manager_state(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<KeyManagerState> {
+    let regional_key_manager_state = match get_pii_region(state, merchant_id).await? {
+        Some(region) => get_regional_key_manager_state(state, &region)?,
+        None => None,
+    };
+
+    Ok(regional_key_manager_state.unwrap_or_else(|| KeyManagerState::from(state)))
+}
+
+/// Gives the data key of the merchant to the key manager of the region, so that the personal data
+/// of the merchant can be decrypted in the region once switched over
+async fn transfer_key_to_region(
+    state: &SessionState,
+    residency: &DataResidency,
+) -> RouterResult<()> {
+    let Some(regional_key_manager_state) =
+        get_regional_key_manager_state(state, &residency.region)?
+    else {
+        return Ok(());
+    };
+    if !regional_key_manager_state.enabled {
+        return Ok(());
+    }
+
+    let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            &state.into(),
+            &residency.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the key store of the merchant")?;
+
+    keymanager::transfer_key_to_key_manager(
+        &regional_key_manager_state,
+        EncryptionTransferRequest {
+            identifier: Identifier::Merchant(residency.merchant_id.clone()),
+            key: consts::BASE64_ENGINE.encode(key_store.key.into_inner().expose()),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to transfer the data key to the key manager of the region")?;
+
+    Ok(())
+}
+
+/// Returns the store holding the customers and addresses of the merchant
+pub async fn get_pii_store<'a>(
+    state: &'a SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<&'a dyn StorageInterface> {
+    match get_pii_region(state, merchant_id).await? {
+        Some(region) => get_regional_store(state, &region),
+        None => Ok(state.store.as_ref()),
+    }
+}
+
+/// Returns the configuration of the locker holding the cards and network tokens of the merchant
+pub async fn get_locker<'a>(
+    state: &'a SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Cow<'a, settings::Locker>> {
+    match get_pii_region(state, merchant_id).await? {
+        Some(region) => get_regional_locker(state, &region).map(Cow::Owned),
+        None => Ok(Cow::Borrowed(&state.conf.locker)),
+    }
+}
+
+/// Schedules the migration of the personal data of the merchant, rescheduling the task of a
+/// migration which was given up
+async fn add_data_residency_migration_task(
+    db: &dyn StorageInterface,
+    tracking_data: storage::merchant_account::DataResidencyMigrationTrackingData,
+    schedule_time: PrimitiveDateTime,
+) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::DataResidencyMigrationWorkflow;
+    let process_tracker_id = format!(
+        "{runner}_{DATA_RESIDENCY_MIGRATION_TASK}_{}",
+        tracking_data.merchant_id.get_string_repr()
+    );
+
+    if let Some(process) = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch DATA_RESIDENCY_MIGRATION task")?
+    {
+        return db
+            .as_scheduler()
+            .reset_process(process, schedule_time)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to reschedule DATA_RESIDENCY_MIGRATION task");
+    }
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        DATA_RESIDENCY_MIGRATION_TASK,
+        runner,
+        [DATA_RESIDENCY_MIGRATION_TAG],
+        tracking_data,
+        None,
+        schedule_time,
+        hyperswitch_domain_models::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct DATA_RESIDENCY_MIGRATION process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable(
+            "Failed while inserting DATA_RESIDENCY_MIGRATION task to process_tracker",
+        )?;
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn create_data_residency(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    req: DataResidencyRequest,
+) -> RouterResponse<DataResidencyResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(key_manager_state, &merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    if !state.conf.data_residency.regions.contains_key(&req.region) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Data residency region {} is not configured", req.region),
+        }));
+    }
+    if merchant_account.storage_scheme != enums::MerchantStorageScheme::PostgresOnly {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Data residency cannot be enabled for a merchant with KV enabled".to_string(),
+        }));
+    }
+
+    let now = common_utils::date_time::now();
+    let tracking_data = storage::merchant_account::DataResidencyMigrationTrackingData {
+        merchant_id: merchant_id.clone(),
+    };
+    let residency = match get_data_residency(db, &merchant_id).await? {
+        None => {
+            let residency = DataResidency {
+                merchant_id: merchant_id.clone(),
+                region: req.region,
+                status: DataResidencyStatus::Migrating,
+                is_migration_failed: false,
+                is_key_transferred: false,
+                pass_count: 0,
+                phase: DataResidencyMigrationPhase::Customers,
+                cursor: None,
+                modified_since: None,
+                pass_started_at: None,
+                pass_copied_count: 0,
+                copied_customers_count: 0,
+                copied_addresses_count: 0,
+                copied_locker_entries_count: 0,
+                failed_locker_entries_count: 0,
+                created_at: now,
+                modified_at: now,
+            };
+            let serialized_residency = residency
+                .encode_to_string_of_json()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to serialize data residency")?;
+
+            db.insert_config(configs::ConfigNew {
+                key: merchant_id.get_data_residency_key(),
+                config: serialized_residency,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert data residency")?;
+
+            add_data_residency_migration_task(db, tracking_data, now).await?;
+            residency
+        }
+        Some(residency) if residency.region != req.region => {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The personal data of the merchant already resides in the region {}",
+                    residency.region
+                ),
+            }));
+        }
+        Some(mut residency) if residency.is_migration_failed => {
+            // The migration resumes from where it was given up
+            residency.is_migration_failed = false;
+            residency.modified_at = now;
+            update_data_residency(db, &residency).await?;
+            add_data_residency_migration_task(db, tracking_data, now).await?;
+            residency
+        }
+        Some(residency) => residency,
+    };
+
+    Ok(services::ApplicationResponse::Json(
+        DataResidencyResponse::from(&residency),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_data_residency(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<DataResidencyResponse> {
+    let residency = get_data_residency(state.store.as_ref(), &merchant_id)
+        .await?
+        .ok_or(report!(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Data residency not found".to_string(),
+        }))?;
+
+    Ok(services::ApplicationResponse::Json(
+        DataResidencyResponse::from(&residency),
+    ))
+}
+
+/// Copies the customer to the region, unless its copy in the region is as recent. Returns whether
+/// the customer was copied.
+async fn copy_customer(
+    regional_store: &dyn StorageInterface,
+    customer: diesel_models::customers::Customer,
+) -> RouterResult<bool> {
+    match regional_store
+        .find_customer_row_optional(&customer.customer_id, &customer.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the customer from the data residency region")?
+    {
+        Some(regional_customer) if regional_customer.modified_at >= customer.modified_at => {
+            return Ok(false)
+        }
+        Some(_) => {
+            regional_store
+                .delete_customer_row(&customer.customer_id, &customer.merchant_id)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to replace the customer in the data residency region")?;
+        }
+        None => {}
+    }
+
+    regional_store
+        .insert_customer_row(customer)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to copy the customer to the data residency region")?;
+
+    Ok(true)
+}
+
+/// Copies the address to the region, unless its copy in the region is as recent. Returns whether
+/// the address was copied.
+async fn copy_address(
+    regional_store: &dyn StorageInterface,
+    address: diesel_models::address::Address,
+) -> RouterResult<bool> {
+    match regional_store
+        .find_address_row_optional(&address.address_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the address from the data residency region")?
+    {
+        Some(regional_address) if regional_address.modified_at >= address.modified_at => {
+            return Ok(false)
+        }
+        Some(_) => {
+            regional_store
+                .delete_address_row(&address.address_id)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to replace the address in the data residency region")?;
+        }
+        None => {}
+    }
+
+    regional_store
+        .insert_address_row(address)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to copy the address to the data residency region")?;
+
+    Ok(true)
+}
+
+async fn retrieve_locker_entry(
+    state: &SessionState,
+    locker: &settings::Locker,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+    card_reference: &str,
+) -> RouterResult<payment_methods::RetrieveCardRespPayload> {
+    let request = payment_methods::mk_get_card_request_hs(
+        state.conf.jwekey.get_inner(),
+        locker,
+        customer_id,
+        merchant_id,
+        card_reference,
+        Some(enums::LockerChoice::HyperswitchCardVault),
+        state.tenant.tenant_id.clone(),
+        state.request_id,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Making get card request failed")?;
+
+    cards::call_locker_api::<payment_methods::RetrieveCardResp>(
+        state,
+        request,
+        "get_card_from_locker",
+        Some(enums::LockerChoice::HyperswitchCardVault),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to retrieve the locker entry")?
+    .payload
+    .get_required_value("RetrieveCardRespPayload")
+}
+
+/// Copies the locker entry to the locker of the region under the same reference, unless the locker
+/// of the region already holds it, and deletes it from the locker of the deployment when requested.
+/// Returns whether the entry was copied.
+async fn copy_locker_entry(
+    state: &SessionState,
+    regional_locker: &settings::Locker,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+    card_reference: &str,
+    delete_from_deployment: bool,
+) -> RouterResult<bool> {
+    let is_copied = if retrieve_locker_entry(
+        state,
+        regional_locker,
+        merchant_id,
+        customer_id,
+        card_reference,
+    )
+    .await
+    .is_ok()
+    {
+        false
+    } else {
+        let entry = retrieve_locker_entry(
+            state,
+            &state.conf.locker,
+            merchant_id,
+            customer_id,
+            card_reference,
+        )
+        .await?;
+        let ttl = state.conf.locker.ttl_for_storage_in_secs;
+        let payload = match (entry.card, entry.enc_card_data) {
+            (Some(card), _) => {
+                payment_methods::StoreLockerReq::LockerCard(payment_methods::StoreCardReq {
+                    merchant_id: merchant_id.clone(),
+                    merchant_customer_id: customer_id.clone(),
+                    requestor_card_reference: Some(card_reference.to_string()),
+                    card,
+                    ttl,
+                })
+            }
+            (None, Some(enc_card_data)) => {
+                payment_methods::StoreLockerReq::LockerGeneric(payment_methods::StoreGenericReq {
+                    merchant_id: merchant_id.clone(),
+                    merchant_customer_id: customer_id.clone(),
+                    requestor_card_reference: Some(card_reference.to_string()),
+                    enc_data: enc_card_data.peek().to_string(),
+                    ttl,
+                })
+            }
+            (None, None) => {
+                return Err(report!(errors::ApiErrorResponse::InternalServerError))
+                    .attach_printable("The locker entry holds neither a card nor data")
+            }
+        };
+
+        let request = payment_methods::mk_add_locker_request_hs(
+            state.conf.jwekey.get_inner(),
+            regional_locker,
+            &payload,
+            enums::LockerChoice::HyperswitchCardVault,
+            state.tenant.tenant_id.clone(),
+            state.request_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Making add card request failed")?;
+        let stored_entry = cards::call_locker_api::<payment_methods::StoreCardResp>(
+            state,
+            request,
+            "add_card_to_regional_locker",
+            Some(enums::LockerChoice::HyperswitchCardVault),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to copy the locker entry to the data residency region")?
+        .payload
+        .get_required_value("StoreCardRespPayload")?;
+
+        if stored_entry.card_reference != card_reference {
+            return Err(report!(errors::ApiErrorResponse::InternalServerError)).attach_printable(
+                "The locker of the data residency region stored the entry under another reference",
+            );
+        }
+        true
+    };
+
+    if delete_from_deployment {
+        let request = payment_methods::mk_delete_card_request_hs(
+            state.conf.jwekey.get_inner(),
+            &state.conf.locker,
+            customer_id,
+            merchant_id,
+            card_reference,
+            state.tenant.tenant_id.clone(),
+            state.request_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Making delete card request failed")?;
+
+        // The entries stored in the region during the finalization are not held by the locker of
+        // the deployment
+        if let Err(error) = cards::call_locker_api::<payment_methods::DeleteCardResp>(
+            state,
+            request,
+            "delete_card_from_locker",
+            Some(enums::LockerChoice::HyperswitchCardVault),
+        )
+        .await
+        {
+            logger::warn!(
+                ?error,
+                "Failed to delete the locker entry from the locker of the deployment"
+            );
+        }
+    }
+
+    Ok(is_copied)
+}
+
+/// Copies the next batch of the rows or locker entries of the current phase of the migration to
+/// the region. Returns the number of rows fetched.
+async fn migrate_batch(
+    state: &SessionState,
+    residency: &mut DataResidency,
+    regional_store: &dyn StorageInterface,
+    regional_locker: &settings::Locker,
+) -> RouterResult<usize> {
+    let db = state.store.as_ref();
+    let merchant_id = residency.merchant_id.clone();
+    let is_final_pass = residency.status == DataResidencyStatus::Finalizing;
+    // The final pass goes through all the rows left in the deployment, and deletes them once copied
+    let modified_since = if is_final_pass {
+        None
+    } else {
+        residency.modified_since
+    };
+
+    match residency.phase {
+        DataResidencyMigrationPhase::Customers => {
+            let customers = db
+                .find_customers_for_residency_migration(
+                    &merchant_id,
+                    modified_since,
+                    residency.cursor.clone(),
+                    consts::DATA_RESIDENCY_MIGRATION_BATCH_SIZE,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the customers to migrate")?;
+            let fetched_count = customers.len();
+
+            for customer in customers {
+                residency.cursor = Some(ResidencyMigrationCursor {
+                    modified_at: customer.modified_at,
+                    id: customer.customer_id.get_string_repr().to_owned(),
+                });
+                let customer_id = customer.customer_id.clone();
+                if copy_customer(regional_store, customer).await? {
+                    residency.copied_customers_count += 1;
+                    residency.pass_copied_count += 1;
+                }
+                if is_final_pass {
+                    db.delete_customer_row(&customer_id, &merchant_id)
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to delete the customer from the deployment")?;
+                }
+            }
+
+            Ok(fetched_count)
+        }
+        DataResidencyMigrationPhase::Addresses => {
+            let addresses = db
+                .find_addresses_for_residency_migration(
+                    &merchant_id,
+                    modified_since,
+                    residency.cursor.clone(),
+                    consts::DATA_RESIDENCY_MIGRATION_BATCH_SIZE,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the addresses to migrate")?;
+            let fetched_count = addresses.len();
+
+            for address in addresses {
+                residency.cursor = Some(ResidencyMigrationCursor {
+                    modified_at: address.modified_at,
+                    id: address.address_id.clone(),
+                });
+                let address_id = address.address_id.clone();
+                if copy_address(regional_store, address).await? {
+                    residency.copied_addresses_count += 1;
+                    residency.pass_copied_count += 1;
+                }
+                if is_final_pass {
+                    db.delete_address_row(&address_id)
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to delete the address from the deployment")?;
+                }
+            }
+
+            Ok(fetched_count)
+        }
+        // The entries of the mock locker are stored in the database of the deployment
+        DataResidencyMigrationPhase::LockerEntries if state.conf.locker.mock_locker => Ok(0),
+        DataResidencyMigrationPhase::LockerEntries => {
+            let payment_methods = db
+                .find_payment_methods_for_residency_migration(
+                    &merchant_id,
+                    modified_since,
+                    residency.cursor.clone(),
+                    consts::DATA_RESIDENCY_MIGRATION_BATCH_SIZE,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the payment methods to migrate")?;
+            let fetched_count = payment_methods.len();
+
+            for payment_method in payment_methods {
+                residency.cursor = Some(ResidencyMigrationCursor {
+                    modified_at: payment_method.last_modified,
+                    id: payment_method.payment_method_id.clone(),
+                });
+                let card_references = [
+                    payment_method.locker_id.as_deref(),
+                    payment_method.network_token_locker_id.as_deref(),
+                ];
+                for card_reference in card_references.into_iter().flatten() {
+                    // The cards stored in the external vault of a profile are not held by the
+                    // locker
+                    #[cfg(all(
+                        any(feature = "v1", feature = "v2"),
+                        not(feature = "payment_methods_v2")
+                    ))]
+                    if external_vault::ExternalVaultReference::from_locker_id(card_reference)
+                        .is_some()
+                    {
+                        continue;
+                    }
+
+                    match copy_locker_entry(
+                        state,
+                        regional_locker,
+                        &merchant_id,
+                        &payment_method.customer_id,
+                        card_reference,
+                        is_final_pass,
+                    )
+                    .await
+                    {
+                        Ok(true) => {
+                            residency.copied_locker_entries_count += 1;
+                            residency.pass_copied_count += 1;
+                        }
+                        Ok(false) => {}
+                        Err(error) => {
+                            logger::warn!(
+                                ?error,
+                                payment_method_id = %payment_method.payment_method_id,
+                                "Failed to copy the locker entry to the data residency region"
+                            );
+                            residency.failed_locker_entries_count += 1;
+                        }
+                    }
+                }
+            }
+
+            Ok(fetched_count)
+        }
+    }
+}
+
+/// Whether the personal data of the merchant can be switched over to its region once the pass has
+/// completed: the passes copy fewer rows as they go, but the personal data of a merchant which is
+/// updated continuously would never be left alone for a whole pass
+fn is_switchover_due(pass_count: u32, pass_copied_count: u64) -> bool {
+    pass_copied_count <= consts::DATA_RESIDENCY_SWITCHOVER_COPIED_COUNT
+        || pass_count >= consts::DATA_RESIDENCY_MIGRATION_MAX_PASSES
+}
+
+/// Copies the next batches of the personal data of the merchant to its region. Returns the time at
+/// which the migration is to be continued, or `None` once the personal data of the merchant is
+/// only stored in the region.
+#[instrument(skip_all)]
+pub async fn process_data_residency_migration(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Option<PrimitiveDateTime>> {
+    let db = state.store.as_ref();
+    let mut residency = get_data_residency(db, merchant_id)
+        .await?
+        .ok_or(report!(errors::ApiErrorResponse::InternalServerError))
+        .attach_printable("Data residency of the merchant not found")?;
+    if residency.status == DataResidencyStatus::Active {
+        return Ok(None);
+    }
+
+    let regional_store = get_regional_store(state, &residency.region)?;
+    let regional_locker = get_regional_locker(state, &residency.region)?;
+    if !residency.is_key_transferred {
+        transfer_key_to_region(state, &residency).await?;
+        residency.is_key_transferred = true;
+        residency.modified_at = common_utils::date_time::now();
+        update_data_residency(db, &residency).await?;
+    }
+    let batch_size = usize::try_from(consts::DATA_RESIDENCY_MIGRATION_BATCH_SIZE)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Data residency migration batch size out of range")?;
+    residency
+        .pass_started_at
+        .get_or_insert_with(common_utils::date_time::now);
+
+    let mut is_pass_completed = false;
+    for _ in 0..consts::DATA_RESIDENCY_MIGRATION_MAX_BATCHES_PER_RUN {
+        let fetched_count =
+            migrate_batch(state, &mut residency, regional_store, &regional_locker).await?;
+        if fetched_count < batch_size {
+            residency.cursor = None;
+            match residency.phase {
+                DataResidencyMigrationPhase::Customers => {
+                    residency.phase = DataResidencyMigrationPhase::Addresses;
+                }
+                DataResidencyMigrationPhase::Addresses => {
+                    residency.phase = DataResidencyMigrationPhase::LockerEntries;
+                }
+                DataResidencyMigrationPhase::LockerEntries => {
+                    is_pass_completed = true;
+                    break;
+                }
+            }
+        }
+    }
+
+    let now = common_utils::date_time::now();
+    let mut schedule_time = Some(now);
+    if is_pass_completed {
+        residency.pass_count += 1;
+        if residency.status == DataResidencyStatus::Finalizing {
+            residency.status = DataResidencyStatus::Active;
+            schedule_time = None;
+        } else if is_switchover_due(residency.pass_count, residency.pass_copied_count) {
+            // Little was updated since the start of the previous pass, so the personal data of the
+            // merchant is read from and written to the region from now on. The final pass waits for
+            // all the instances of the application to do so, and copies what was updated in the
+            // meantime.
+            residency.status = DataResidencyStatus::Finalizing;
+            schedule_time = Some(now.saturating_add(time::Duration::seconds(
+                consts::DATA_RESIDENCY_FINALIZATION_DELAY_IN_SECONDS,
+            )));
+        } else {
+            residency.modified_since = residency.pass_started_at;
+        }
+        residency.phase = DataResidencyMigrationPhase::Customers;
+        residency.pass_started_at = None;
+        residency.pass_copied_count = 0;
+    }
+    residency.modified_at = now;
+    update_data_residency(db, &residency).await?;
+
+    Ok(schedule_time)
+}
+
+/// Marks the migration as failed once the retries of its task are exhausted
+#[instrument(skip_all)]
+pub async fn fail_data_residency_migration(
+    db: &dyn StorageInterface,
+    tracking_data: &storage::merchant_account::DataResidencyMigrationTrackingData,
+) -> RouterResult<()> {
+    let mut residency = get_data_residency(db, &tracking_data.merchant_id)
+        .await?
+        .ok_or(report!(errors::ApiErrorResponse::InternalServerError))
+        .attach_printable("Data residency of the merchant not found")?;
+    residency.is_migration_failed = true;
+    residency.modified_at = common_utils::date_time::now();
+    update_data_residency(db, &residency).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_switchover_due() {
+        // The pass copied few enough rows
+        assert!(is_switchover_due(1, 0));
+        assert!(is_switchover_due(
+            1,
+            consts::DATA_RESIDENCY_SWITCHOVER_COPIED_COUNT
+        ));
+        // The personal data of the merchant is still updated faster than it is copied
+        assert!(!is_switchover_due(
+            1,
+            consts::DATA_RESIDENCY_SWITCHOVER_COPIED_COUNT + 1
+        ));
+        // The migration is switched over after a bounded number of passes nonetheless
+        assert!(is_switchover_due(
+            consts::DATA_RESIDENCY_MIGRATION_MAX_PASSES,
+            consts::DATA_RESIDENCY_SWITCHOVER_COPIED_COUNT + 1
+        ));
+    }
+}
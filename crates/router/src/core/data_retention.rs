@@ -0,0 +1,365 @@
+//! Redaction of the sensitive data of the payment attempts, reducing the PCI scope of the data held
+//! in the database. A recurring process tracker task redacts the payment method data and the
+//! encoded data of the attempts which have been in a terminal status for the configured number of
+//! days, in batches and in the order of their last update. The card network, issuer, type, issuing
+//! country and BIN are preserved in the payment method data of the card attempts, as the analytics
+//! are derived from them, and only the personal fields are removed from the payment method data of
+//! the other attempts.
+
+use api_models::payments::{AdditionalCardInfo, AdditionalPaymentData};
+use diesel_models::query::data_retention::AttemptRedactionCursor;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::errors::{self, RouterResult},
+    routes::SessionState,
+    types::storage::{self, enums, payment_attempt::AttemptRedactionTrackingData},
+};
+
+const ATTEMPT_REDACTION_TASK: &str = "ATTEMPT_REDACTION";
+const ATTEMPT_REDACTION_TAG: &str = "PAYMENT_ATTEMPT";
+
+/// The statuses from which an attempt does not move anymore
+const TERMINAL_ATTEMPT_STATUSES: [enums::AttemptStatus; 8] = [
+    enums::AttemptStatus::RouterDeclined,
+    enums::AttemptStatus::Charged,
+    enums::AttemptStatus::AutoRefunded,
+    enums::AttemptStatus::Voided,
+    enums::AttemptStatus::VoidFailed,
+    enums::AttemptStatus::CaptureFailed,
+    enums::AttemptStatus::Failure,
+    enums::AttemptStatus::PartialCharged,
+];
+
+fn get_process_tracker_id() -> String {
+    let runner = storage::ProcessTrackerRunner::AttemptRedactionWorkflow;
+    format!("{runner}_{ATTEMPT_REDACTION_TASK}")
+}
+
+/// Schedules the redaction of the attempts right away, unless it is already scheduled. The
+/// redaction is not scheduled when it is disabled.
+#[instrument(skip_all)]
+pub async fn schedule_attempt_redaction(state: &SessionState) -> RouterResult<()> {
+    if !state.conf.attempt_data_retention.enabled {
+        return Ok(());
+    }
+
+    let db = &*state.store;
+    let process_tracker_id = get_process_tracker_id();
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching ATTEMPT_REDACTION task")?;
+    let schedule_time = common_utils::date_time::now();
+
+    match existing_process {
+        Some(process) if process.status == enums::ProcessTrackerStatus::Finish => db
+            .as_scheduler()
+            .reset_process(process, schedule_time)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while resetting ATTEMPT_REDACTION task"),
+        Some(_) => Ok(()),
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                ATTEMPT_REDACTION_TASK,
+                storage::ProcessTrackerRunner::AttemptRedactionWorkflow,
+                [ATTEMPT_REDACTION_TAG],
+                AttemptRedactionTrackingData::default(),
+                None,
+                schedule_time,
+                hyperswitch_domain_models::consts::API_VERSION,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct ATTEMPT_REDACTION task")?;
+
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed while inserting ATTEMPT_REDACTION task to process_tracker",
+                )?;
+            Ok(())
+        }
+    }
+}
+
+/// The fields of the payment method data of the attempts which identify the customer or their
+/// account, whatever the payment method
+const PERSONAL_PAYMENT_METHOD_DATA_FIELDS: [&str; 28] = [
+    "account_number",
+    "authentication_data",
+    "bank_account_holder_name",
+    "billing_email",
+    "blik_code",
+    "bsb_number",
+    "card_exp_month",
+    "card_exp_year",
+    "card_extended_bin",
+    "card_holder_name",
+    "card_number",
+    "cnpj",
+    "cpf",
+    "display_name",
+    "email",
+    "first_name",
+    "iban",
+    "last4",
+    "last_name",
+    "msisdn",
+    "name",
+    "payment_checks",
+    "phone_number",
+    "pix_key",
+    "routing_number",
+    "social_security_number",
+    "sort_code",
+    "vpa_id",
+];
+
+/// Clears the personal fields of the payment method data, wherever they are nested
+fn redact_personal_fields(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(fields) => {
+            for (field, field_value) in fields.iter_mut() {
+                if PERSONAL_PAYMENT_METHOD_DATA_FIELDS.contains(&field.as_str()) {
+                    *field_value = serde_json::Value::Null;
+                } else {
+                    redact_personal_fields(field_value);
+                }
+            }
+        }
+        serde_json::Value::Array(values) => values.iter_mut().for_each(redact_personal_fields),
+        serde_json::Value::Null
+        | serde_json::Value::Bool(_)
+        | serde_json::Value::Number(_)
+        | serde_json::Value::String(_) => {}
+    }
+}
+
+/// The payment method data of an attempt without its sensitive fields. Only the fields of the card
+/// payment methods which are used in the analytics are preserved, while the other payment methods
+/// only lose their personal fields.
+fn redact_payment_method_data(payment_method_data: serde_json::Value) -> Option<serde_json::Value> {
+    match serde_json::from_value::<AdditionalPaymentData>(payment_method_data.clone()) {
+        Ok(AdditionalPaymentData::Card(card)) => {
+            let card = AdditionalCardInfo {
+                card_issuer: card.card_issuer,
+                card_network: card.card_network,
+                card_type: card.card_type,
+                card_issuing_country: card.card_issuing_country,
+                bank_code: card.bank_code,
+                card_isin: card.card_isin,
+                last4: None,
+                card_extended_bin: None,
+                card_exp_month: None,
+                card_exp_year: None,
+                card_holder_name: None,
+                payment_checks: None,
+                authentication_data: None,
+            };
+            serde_json::to_value(AdditionalPaymentData::Card(Box::new(card)))
+                .inspect_err(|error| {
+                    logger::error!(?error, "Failed to serialize the redacted card details");
+                })
+                .ok()
+        }
+        Ok(_) | Err(_) => {
+            let mut payment_method_data = payment_method_data;
+            redact_personal_fields(&mut payment_method_data);
+            Some(payment_method_data)
+        }
+    }
+}
+
+/// A batch of the attempts redacted by a redaction pass
+pub struct AttemptRedactionBatch {
+    /// The progress of the pass after the last attempt of the batch
+    pub tracking_data: AttemptRedactionTrackingData,
+    /// Whether the batch was the last one of the pass
+    pub is_last_batch: bool,
+}
+
+/// Redacts the next batch of the attempts which have been in a terminal status for longer than the
+/// retention period, from the cursor of the redaction pass
+#[instrument(skip_all)]
+pub async fn redact_attempts_batch(
+    state: &SessionState,
+    tracking_data: AttemptRedactionTrackingData,
+) -> RouterResult<AttemptRedactionBatch> {
+    let db = &*state.store;
+    let config = &state.conf.attempt_data_retention;
+    let modified_before = common_utils::date_time::now()
+        .saturating_sub(time::Duration::days(i64::from(config.redact_after_days)));
+
+    let payment_attempts = db
+        .find_payment_attempts_for_redaction(
+            TERMINAL_ATTEMPT_STATUSES.to_vec(),
+            modified_before,
+            tracking_data.cursor.clone(),
+            config.batch_size,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payment attempts for redaction")?;
+
+    let mut redacted_count = 0;
+    for payment_attempt in payment_attempts.iter() {
+        let payment_method_data = payment_attempt
+            .payment_method_data
+            .clone()
+            .and_then(redact_payment_method_data);
+        // The attempts which were redacted before and have not been updated since are skipped
+        if payment_attempt.encoded_data.is_none()
+            && payment_method_data == payment_attempt.payment_method_data
+        {
+            continue;
+        }
+
+        db.redact_payment_attempt(payment_attempt, payment_method_data)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to redact the payment attempt")?;
+        redacted_count += 1;
+    }
+
+    logger::info!(
+        redacted_count,
+        "Redacted the data of a batch of payment attempts"
+    );
+
+    let is_last_batch = i64::try_from(payment_attempts.len())
+        .map_or(true, |batch_length| batch_length < config.batch_size);
+    let cursor = payment_attempts
+        .last()
+        .map(|payment_attempt| AttemptRedactionCursor {
+            modified_at: payment_attempt.modified_at,
+            merchant_id: payment_attempt.merchant_id.clone(),
+            attempt_id: payment_attempt.attempt_id.clone(),
+        })
+        .or(tracking_data.cursor);
+
+    Ok(AttemptRedactionBatch {
+        tracking_data: AttemptRedactionTrackingData { cursor },
+        is_last_batch,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_terminal_attempt_statuses() {
+        assert!(TERMINAL_ATTEMPT_STATUSES
+            .iter()
+            .all(|status| status.is_terminal_status()));
+    }
+
+    #[test]
+    fn test_redact_card_payment_method_data() {
+        let payment_method_data = serde_json::json!({
+            "card": {
+                "card_issuer": "JP Morgan",
+                "card_network": "Visa",
+                "card_type": "CREDIT",
+                "card_issuing_country": "UNITEDSTATES",
+                "bank_code": "JP_JPMORGAN",
+                "last4": "4242",
+                "card_isin": "424242",
+                "card_extended_bin": "42424242",
+                "card_exp_month": "03",
+                "card_exp_year": "2030",
+                "card_holder_name": "John Doe",
+                "payment_checks": null,
+                "authentication_data": null,
+            }
+        });
+
+        let redacted = redact_payment_method_data(payment_method_data).unwrap();
+        let AdditionalPaymentData::Card(card) =
+            serde_json::from_value::<AdditionalPaymentData>(redacted).unwrap()
+        else {
+            panic!("Expected the redacted card details");
+        };
+
+        assert_eq!(card.card_network, Some(common_enums::CardNetwork::Visa));
+        assert_eq!(card.card_isin.as_deref(), Some("424242"));
+        assert_eq!(card.card_issuer.as_deref(), Some("JP Morgan"));
+        assert!(card.last4.is_none());
+        assert!(card.card_extended_bin.is_none());
+        assert!(card.card_exp_month.is_none());
+        assert!(card.card_exp_year.is_none());
+        assert!(card.card_holder_name.is_none());
+    }
+
+    #[test]
+    fn test_redact_non_card_payment_method_data() {
+        let payment_method_data = serde_json::json!({
+            "pay_later": { "klarna_sdk": { "payment_type": "pay_later" } }
+        });
+
+        assert_eq!(
+            redact_payment_method_data(payment_method_data.clone()),
+            Some(payment_method_data)
+        );
+    }
+
+    #[test]
+    fn test_redact_personal_fields_of_non_card_payment_method_data() {
+        let payment_method_data = serde_json::json!({
+            "bank_debit": {
+                "sepa": {
+                    "iban": "DE8937****3000",
+                    "bank_account_holder_name": "John Doe"
+                }
+            }
+        });
+
+        assert_eq!(
+            redact_payment_method_data(payment_method_data).unwrap(),
+            serde_json::json!({
+                "bank_debit": {
+                    "sepa": {
+                        "iban": null,
+                        "bank_account_holder_name": null
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn test_redact_personal_fields_of_wallet_payment_method_data() {
+        let payment_method_data = serde_json::json!({
+            "wallet": {
+                "apple_pay": {
+                    "display_name": "Visa 0326",
+                    "network": "Visa",
+                    "type": "debit"
+                },
+                "google_pay": null,
+                "samsung_pay": null
+            }
+        });
+
+        assert_eq!(
+            redact_payment_method_data(payment_method_data).unwrap(),
+            serde_json::json!({
+                "wallet": {
+                    "apple_pay": {
+                        "display_name": null,
+                        "network": "Visa",
+                        "type": "debit"
+                    },
+                    "google_pay": null,
+                    "samsung_pay": null
+                }
+            })
+        );
+    }
+}
@@ -3,10 +3,17 @@ use std::collections::HashMap;
 use api_models::{
     admin::MerchantConnectorInfo, disputes as dispute_models, files as files_api_models,
 };
+#[cfg(feature = "v1")]
+use common_utils::crypto::{self, GenerateDigest};
 use common_utils::ext_traits::{Encode, ValueExt};
 use error_stack::ResultExt;
+#[cfg(feature = "v1")]
+use hyperswitch_domain_models::payments::payment_intent::CustomerData;
+#[cfg(feature = "v1")]
+use masking::PeekInterface;
 use router_env::{instrument, tracing};
 use strum::IntoEnumIterator;
+pub mod ledger;
 pub mod transformers;
 
 use super::{
@@ -14,7 +21,7 @@ use super::{
     metrics,
 };
 use crate::{
-    core::{files, payments, utils as core_utils},
+    core::{files, payment_methods, payments, utils as core_utils},
     routes::SessionState,
     services,
     types::{
@@ -46,10 +53,12 @@ pub async fn retrieve_dispute(
     Ok(services::ApplicationResponse::Json(dispute_response))
 }
 
+#[cfg(feature = "v1")]
 #[instrument(skip(state))]
 pub async fn retrieve_disputes_list(
     state: SessionState,
     merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
     profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
     constraints: api_models::disputes::DisputeListGetConstraints,
 ) -> RouterResponse<Vec<api_models::disputes::DisputeResponse>> {
@@ -60,13 +69,121 @@ pub async fn retrieve_disputes_list(
         .await
         .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Unable to retrieve disputes")?;
-    let disputes_list = disputes
+    let mut disputes_list = disputes
         .into_iter()
         .map(api_models::disputes::DisputeResponse::foreign_from)
-        .collect();
+        .collect::<Vec<_>>();
+
+    if let Some(fields) = constraints
+        .payment_context
+        .filter(|fields| !fields.is_empty())
+    {
+        embed_payment_context(
+            &state,
+            merchant_account.get_id(),
+            &key_store,
+            &fields,
+            &mut disputes_list,
+        )
+        .await?;
+    }
+
     Ok(services::ApplicationResponse::Json(disputes_list))
 }
 
+/// Embeds the requested fields of the disputed payments in the disputes. The payments of all the
+/// disputes are fetched in a single query, and the customer details are decrypted only when the
+/// hash of the email of the customer is requested.
+#[cfg(feature = "v1")]
+async fn embed_payment_context(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    key_store: &domain::MerchantKeyStore,
+    fields: &[dispute_models::DisputePaymentContextField],
+    disputes: &mut [dispute_models::DisputeResponse],
+) -> errors::RouterResult<()> {
+    if disputes.is_empty() {
+        return Ok(());
+    }
+
+    let attempt_ids = disputes
+        .iter()
+        .map(|dispute| dispute.attempt_id.clone())
+        .collect::<Vec<_>>();
+    let payments_context = state
+        .store
+        .get_payment_context_by_attempt_ids(merchant_id, attempt_ids)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payments of the disputes")?
+        .into_iter()
+        .map(|payment_context| (payment_context.attempt_id.clone(), payment_context))
+        .collect::<HashMap<_, _>>();
+
+    let is_requested = |field| fields.contains(&field);
+
+    for dispute in disputes.iter_mut() {
+        let Some(payment_context) = payments_context.get(&dispute.attempt_id) else {
+            continue;
+        };
+
+        let customer_email_hash =
+            if is_requested(dispute_models::DisputePaymentContextField::CustomerEmailHash) {
+                payment_methods::cards::decrypt_generic_data::<CustomerData>(
+                    state,
+                    payment_context.customer_details.clone(),
+                    key_store,
+                )
+                .await?
+                .and_then(|customer_data| customer_data.email)
+                .map(|email| hash_customer_email(email.peek()))
+                .transpose()?
+            } else {
+                None
+            };
+
+        let (amount, currency) = if is_requested(dispute_models::DisputePaymentContextField::Amount)
+        {
+            (Some(payment_context.amount), payment_context.currency)
+        } else {
+            (None, None)
+        };
+        let (payment_method, payment_method_type) =
+            if is_requested(dispute_models::DisputePaymentContextField::PaymentMethod) {
+                (
+                    payment_context.payment_method,
+                    payment_context.payment_method_type,
+                )
+            } else {
+                (None, None)
+            };
+
+        dispute.payment_context = Some(dispute_models::DisputePaymentContext {
+            amount,
+            currency,
+            payment_method,
+            payment_method_type,
+            customer_email_hash,
+            connector: is_requested(dispute_models::DisputePaymentContextField::Connector)
+                .then(|| payment_context.connector.clone())
+                .flatten(),
+        });
+    }
+
+    Ok(())
+}
+
+/// The email is normalized before hashing, so that the hash matches the hash of the same email
+/// computed by the merchant regardless of its case
+#[cfg(feature = "v1")]
+fn hash_customer_email(email: &str) -> errors::RouterResult<String> {
+    crypto::Sha256
+        .generate_digest(email.trim().to_lowercase().as_bytes())
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to hash the email of the customer")
+}
+
 #[cfg(feature = "v2")]
 #[instrument(skip(state))]
 pub async fn accept_dispute(
@@ -236,6 +353,7 @@ pub async fn accept_dispute(
         .attach_printable_lazy(|| {
             format!("Unable to update dispute with dispute_id: {dispute_id}")
         })?;
+    ledger::record_dispute_ledger_entries(&state, &updated_dispute).await;
     let dispute_response = api_models::disputes::DisputeResponse::foreign_from(updated_dispute);
     Ok(services::ApplicationResponse::Json(dispute_response))
 }
@@ -415,6 +533,7 @@ pub async fn submit_evidence(
         .attach_printable_lazy(|| {
             format!("Unable to update dispute with dispute_id: {dispute_id}")
         })?;
+    ledger::record_dispute_ledger_entries(&state, &updated_dispute).await;
     let dispute_response = api_models::disputes::DisputeResponse::foreign_from(updated_dispute);
     Ok(services::ApplicationResponse::Json(dispute_response))
 }
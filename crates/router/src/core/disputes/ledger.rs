@@ -0,0 +1,214 @@
+//! Ledger of the funds of the merchants held for their disputes. The disputed amount is held as
+//! soon as a dispute is known, and the hold is either released when the dispute is won or
+//! cancelled, or charged when the dispute is lost, accepted or expired. The entries are recorded at
+//! most once per stage of a dispute, so that the same status received several times, by webhooks or
+//! by the actions of the merchant, does not move the funds again.
+
+use std::{collections::HashMap, str::FromStr};
+
+use api_models::disputes as dispute_models;
+use common_utils::types::MinorUnit;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse},
+    routes::SessionState,
+    services,
+    types::{domain, storage, storage::enums as storage_enums},
+};
+
+/// The types of the entries to be recorded for a stage of a dispute in the given status
+fn get_ledger_entry_types(
+    dispute_status: storage_enums::DisputeStatus,
+) -> Vec<storage_enums::LedgerEntryType> {
+    let resolution_entry_type = match dispute_status {
+        storage_enums::DisputeStatus::DisputeOpened
+        | storage_enums::DisputeStatus::DisputeChallenged => None,
+        storage_enums::DisputeStatus::DisputeWon
+        | storage_enums::DisputeStatus::DisputeCancelled => {
+            Some(storage_enums::LedgerEntryType::DisputeRelease)
+        }
+        storage_enums::DisputeStatus::DisputeLost
+        | storage_enums::DisputeStatus::DisputeAccepted
+        | storage_enums::DisputeStatus::DisputeExpired => {
+            Some(storage_enums::LedgerEntryType::DisputeCharge)
+        }
+    };
+
+    std::iter::once(storage_enums::LedgerEntryType::DisputeHold)
+        .chain(resolution_entry_type)
+        .collect()
+}
+
+/// Records the entries of the dispute which are missing in the ledger. Failures are only logged, as
+/// the ledger should never fail the processing of the disputes on its own.
+#[instrument(skip_all, fields(dispute_id = %dispute.dispute_id))]
+pub async fn record_dispute_ledger_entries(state: &SessionState, dispute: &storage::Dispute) {
+    let Some(currency) = dispute
+        .dispute_currency
+        .or_else(|| storage_enums::Currency::from_str(&dispute.currency).ok())
+    else {
+        logger::error!(currency = %dispute.currency, "Failed to parse the currency of the dispute");
+        return;
+    };
+
+    for entry_type in get_ledger_entry_types(dispute.dispute_status) {
+        let ledger_entry = storage::LedgerEntryNew {
+            entry_id: common_utils::generate_id(consts::ID_LENGTH, "le"),
+            merchant_id: dispute.merchant_id.clone(),
+            profile_id: dispute.profile_id.clone(),
+            entry_type,
+            amount: MinorUnit::new(dispute.dispute_amount),
+            currency,
+            payment_id: dispute.payment_id.clone(),
+            dispute_id: dispute.dispute_id.clone(),
+            dispute_stage: dispute.dispute_stage,
+            connector: dispute.connector.clone(),
+            created_at: common_utils::date_time::now(),
+        };
+
+        match state
+            .store
+            .insert_ledger_entry_if_absent(ledger_entry)
+            .await
+        {
+            Ok(Some(_)) => logger::info!(%entry_type, "Recorded the ledger entry of the dispute"),
+            Ok(None) => {}
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    %entry_type,
+                    "Failed to record the ledger entry of the dispute"
+                );
+            }
+        }
+    }
+}
+
+/// The balances of the disputes per currency, computed from the totals of the ledger entries of
+/// each type. The held amount is the amount neither released nor charged yet.
+fn get_currency_balances(
+    total_amounts: Vec<(storage_enums::Currency, storage_enums::LedgerEntryType, i64)>,
+) -> Vec<dispute_models::DisputesCurrencyBalance> {
+    let mut total_amounts_by_currency = HashMap::<_, HashMap<_, i64>>::new();
+    for (currency, entry_type, total_amount) in total_amounts {
+        *total_amounts_by_currency
+            .entry(currency)
+            .or_default()
+            .entry(entry_type)
+            .or_default() += total_amount;
+    }
+
+    let mut balances = total_amounts_by_currency
+        .into_iter()
+        .map(|(currency, total_amounts)| {
+            let get_total_amount =
+                |entry_type| total_amounts.get(&entry_type).copied().unwrap_or(0);
+            let hold_amount = get_total_amount(storage_enums::LedgerEntryType::DisputeHold);
+            let released_amount = get_total_amount(storage_enums::LedgerEntryType::DisputeRelease);
+            let charged_amount = get_total_amount(storage_enums::LedgerEntryType::DisputeCharge);
+
+            dispute_models::DisputesCurrencyBalance {
+                currency,
+                held_amount: MinorUnit::new(hold_amount - released_amount - charged_amount),
+                released_amount: MinorUnit::new(released_amount),
+                charged_amount: MinorUnit::new(charged_amount),
+            }
+        })
+        .collect::<Vec<_>>();
+    balances.sort_by_key(|balance| balance.currency.to_string());
+    balances
+}
+
+#[instrument(skip(state))]
+pub async fn get_disputes_balance(
+    state: SessionState,
+    merchant: domain::MerchantAccount,
+    profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+) -> RouterResponse<dispute_models::DisputesBalanceResponse> {
+    let total_amounts = state
+        .store
+        .get_ledger_total_amount_by_currency_and_entry_type(merchant.get_id(), profile_id_list)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve the ledger totals of the disputes")?;
+
+    Ok(services::ApplicationResponse::Json(
+        dispute_models::DisputesBalanceResponse {
+            balances: get_currency_balances(total_amounts),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_ledger_entry_types() {
+        assert_eq!(
+            get_ledger_entry_types(storage_enums::DisputeStatus::DisputeChallenged),
+            vec![storage_enums::LedgerEntryType::DisputeHold]
+        );
+        assert_eq!(
+            get_ledger_entry_types(storage_enums::DisputeStatus::DisputeWon),
+            vec![
+                storage_enums::LedgerEntryType::DisputeHold,
+                storage_enums::LedgerEntryType::DisputeRelease,
+            ]
+        );
+        assert_eq!(
+            get_ledger_entry_types(storage_enums::DisputeStatus::DisputeExpired),
+            vec![
+                storage_enums::LedgerEntryType::DisputeHold,
+                storage_enums::LedgerEntryType::DisputeCharge,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_currency_balances() {
+        let balances = get_currency_balances(vec![
+            (
+                storage_enums::Currency::USD,
+                storage_enums::LedgerEntryType::DisputeHold,
+                5000,
+            ),
+            (
+                storage_enums::Currency::USD,
+                storage_enums::LedgerEntryType::DisputeRelease,
+                1000,
+            ),
+            (
+                storage_enums::Currency::USD,
+                storage_enums::LedgerEntryType::DisputeCharge,
+                1500,
+            ),
+            (
+                storage_enums::Currency::EUR,
+                storage_enums::LedgerEntryType::DisputeHold,
+                700,
+            ),
+        ]);
+
+        assert_eq!(
+            balances,
+            vec![
+                dispute_models::DisputesCurrencyBalance {
+                    currency: storage_enums::Currency::EUR,
+                    held_amount: MinorUnit::new(700),
+                    released_amount: MinorUnit::new(0),
+                    charged_amount: MinorUnit::new(0),
+                },
+                dispute_models::DisputesCurrencyBalance {
+                    currency: storage_enums::Currency::USD,
+                    held_amount: MinorUnit::new(2500),
+                    released_amount: MinorUnit::new(1000),
+                    charged_amount: MinorUnit::new(1500),
+                },
+            ]
+        );
+    }
+}
@@ -195,6 +195,8 @@ pub enum WebhooksFlowError {
     OutgoingWebhookRetrySchedulingFailed,
     #[error("Outgoing webhook response encoding failed")]
     OutgoingWebhookResponseEncodingFailed,
+    #[error("Delivery of webhooks to the merchant webhook endpoint is paused")]
+    WebhookEndpointPaused,
 }
 
 impl WebhooksFlowError {
@@ -203,7 +205,8 @@ impl WebhooksFlowError {
             Self::MerchantConfigNotFound
             | Self::MerchantWebhookDetailsNotFound
             | Self::MerchantWebhookUrlNotConfigured
-            | Self::OutgoingWebhookResponseEncodingFailed => false,
+            | Self::OutgoingWebhookResponseEncodingFailed
+            | Self::WebhookEndpointPaused => false,
 
             Self::WebhookEventUpdationFailed
             | Self::OutgoingWebhookSigningFailed
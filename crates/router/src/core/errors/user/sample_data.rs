@@ -47,6 +47,24 @@ impl ErrorSwitch<ApiErrorResponse> for SampleDataError {
     }
 }
 
+impl ErrorSwitch<crate::core::errors::ApiErrorResponse> for SampleDataError {
+    fn switch(&self) -> crate::core::errors::ApiErrorResponse {
+        use crate::core::errors::ApiErrorResponse as AER;
+        match self {
+            Self::InternalServerError => AER::InternalServerError,
+            Self::DataDoesNotExist => AER::GenericNotFoundError {
+                message: "Sample Data not present for given request".to_string(),
+            },
+            Self::InvalidParameters => AER::InvalidRequestData {
+                message: "Invalid parameters to generate Sample Data".to_string(),
+            },
+            Self::InvalidRange => AER::InvalidRequestData {
+                message: "Records to be generated should be between range 10 and 100".to_string(),
+            },
+        }
+    }
+}
+
 impl ErrorSwitchFrom<StorageError> for SampleDataError {
     fn switch_from(error: &StorageError) -> Self {
         match matches!(error, StorageError::ValueNotFound(_)) {
@@ -128,6 +128,11 @@ impl<T> ConnectorErrorExt<T> for error_stack::Result<T, errors::ConnectorError>
                 }
                 .into()
             }
+            errors::ConnectorError::ConnectorThrottled { connector } => {
+                err.change_context(errors::ApiErrorResponse::ConnectorThrottled {
+                    connector: connector.to_owned(),
+                })
+            }
             errors::ConnectorError::FailedToObtainIntegrationUrl
             | errors::ConnectorError::RequestEncodingFailed
             | errors::ConnectorError::RequestEncodingFailedWithReason(_)
@@ -235,6 +240,7 @@ impl<T> ConnectorErrorExt<T> for error_stack::Result<T, errors::ConnectorError>
                 errors::ConnectorError::CurrencyNotSupported { message, connector} => errors::ApiErrorResponse::CurrencyNotSupported { message: format!("Credentials for the currency {message} are not configured with the connector {connector}/hyperswitch") },
                 errors::ConnectorError::FailedToObtainAuthType =>  errors::ApiErrorResponse::InvalidConnectorConfiguration {config: "connector_account_details".to_string()},
                 errors::ConnectorError::InvalidConnectorConfig { config }  => errors::ApiErrorResponse::InvalidConnectorConfiguration { config: config.to_string() },
+                errors::ConnectorError::ConnectorThrottled { connector } => errors::ApiErrorResponse::ConnectorThrottled { connector: connector.to_owned() },
                 errors::ConnectorError::FailedToObtainIntegrationUrl |
                 errors::ConnectorError::RequestEncodingFailed |
                 errors::ConnectorError::RequestEncodingFailedWithReason(_) |
@@ -321,6 +327,11 @@ impl<T> ConnectorErrorExt<T> for error_stack::Result<T, errors::ConnectorError>
                         wallet_name: wallet_name.to_string(),
                     }
                 }
+                errors::ConnectorError::ConnectorThrottled { connector } => {
+                    errors::ApiErrorResponse::ConnectorThrottled {
+                        connector: connector.to_owned(),
+                    }
+                }
                 errors::ConnectorError::RequestEncodingFailed
                 | errors::ConnectorError::RequestEncodingFailedWithReason(_)
                 | errors::ConnectorError::ParsingFailed
@@ -405,6 +416,11 @@ impl<T> ConnectorErrorExt<T> for error_stack::Result<T, errors::ConnectorError>
                         field_names: field_names.to_vec(),
                     }
                 }
+                errors::ConnectorError::ConnectorThrottled { connector } => {
+                    errors::ApiErrorResponse::ConnectorThrottled {
+                        connector: connector.to_owned(),
+                    }
+                }
                 _ => errors::ApiErrorResponse::InternalServerError,
             };
             err.change_context(error)
@@ -453,6 +469,11 @@ impl<T> ConnectorErrorExt<T> for error_stack::Result<T, errors::ConnectorError>
                         config: config.to_string(),
                     }
                 }
+                errors::ConnectorError::ConnectorThrottled { connector } => {
+                    errors::ApiErrorResponse::ConnectorThrottled {
+                        connector: connector.to_owned(),
+                    }
+                }
                 _ => errors::ApiErrorResponse::InternalServerError,
             };
             err.change_context(error)
@@ -0,0 +1,239 @@
+//! Fault injection for the connectors of the merchants, available only outside production. The
+//! faults configured for a connector are returned instead of its responses, and delay the
+//! processing of its incoming webhooks, so that the merchants can verify the handling of the
+//! retries and the webhooks without an actual outage of the connector.
+
+use std::str::FromStr;
+
+use api_models::{
+    enums,
+    fault_injection::{ConnectorCallFault, ConnectorFaults, FaultInjectionConfig},
+};
+use common_utils::{
+    errors::CustomResult,
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use rand::Rng;
+use router_env::{env, instrument, logger, tracing};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult},
+    routes::SessionState,
+    services,
+    types::{self, domain},
+};
+
+const INJECTED_FAULT_RESPONSE: &str = r#"{"error":"fault injected by hyperswitch"}"#;
+const INJECTED_MALFORMED_RESPONSE: &str = "<injected malformed response";
+
+fn validate_fault_injection_environment() -> RouterResult<()> {
+    if env::which() == env::Env::Production {
+        return Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: "Fault injection is not available in production".to_string(),
+        }));
+    }
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn upsert_fault_injection_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    request: FaultInjectionConfig,
+) -> RouterResponse<FaultInjectionConfig> {
+    validate_fault_injection_environment()?;
+    request.validate().map_err(|error| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: error.current_context().to_string(),
+        })
+    })?;
+
+    let db = state.store.as_ref();
+    let key = merchant_account.get_id().get_fault_injection_config_key();
+    let serialized_config = request
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize fault injection config")?;
+
+    // The cache holds an empty config for the merchants without one, so the existence of the
+    // config is checked in the database
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => {
+            db.update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(serialized_config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update fault injection config")?;
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            db.insert_config(configs::ConfigNew {
+                key,
+                config: serialized_config,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert fault injection config")?;
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch fault injection config");
+        }
+    }
+
+    Ok(services::ApplicationResponse::Json(request))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_fault_injection_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<FaultInjectionConfig> {
+    validate_fault_injection_environment()?;
+
+    let config = get_fault_injection_config(&state, merchant_account.get_id())
+        .await?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Fault injection config not found for the merchant".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(config))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_fault_injection_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<()> {
+    validate_fault_injection_environment()?;
+
+    state
+        .store
+        .delete_config_by_key(&merchant_account.get_id().get_fault_injection_config_key())
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::ApiErrorResponse::GenericNotFoundError {
+                    message: "Fault injection config not found for the merchant".to_string(),
+                })
+            } else {
+                error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to delete fault injection config")
+            }
+        })?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+/// Fetches the fault injection config of the merchant. This is looked up for every connector call
+/// outside production, so the absence of the config is cached as well.
+async fn get_fault_injection_config(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Option<FaultInjectionConfig>> {
+    let config: FaultInjectionConfig = state
+        .store
+        .find_config_by_key_unwrap_or(
+            &merchant_id.get_fault_injection_config_key(),
+            Some("{}".to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch fault injection config")?
+        .config
+        .parse_struct("FaultInjectionConfig")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse fault injection config")?;
+
+    Ok((!config.is_empty()).then_some(config))
+}
+
+/// Faults configured for the connector, which are never injected in production
+async fn get_connector_faults(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    connector: &str,
+) -> Option<ConnectorFaults> {
+    if env::which() == env::Env::Production {
+        return None;
+    }
+
+    let connector = enums::Connector::from_str(connector).ok()?;
+    get_fault_injection_config(state, merchant_id)
+        .await
+        .inspect_err(|error| logger::error!(?error, "Failed to fetch fault injection config"))
+        .ok()
+        .flatten()?
+        .connectors
+        .remove(&connector)
+}
+
+/// Fault to be returned instead of the response of the connector for this call, picked as per the
+/// percentage of the calls into which the fault is injected
+pub async fn get_connector_call_fault(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    connector: &str,
+) -> Option<ConnectorCallFault> {
+    let faults = get_connector_faults(state, merchant_id, connector).await?;
+    let call_fault = faults.call_fault?;
+
+    (rand::thread_rng().gen_range(1..=100) <= faults.percentage).then(|| {
+        logger::info!(
+            ?call_fault,
+            connector,
+            "Injecting fault into connector call"
+        );
+        call_fault
+    })
+}
+
+/// Response of the connector call into which the fault is injected, as it would have been received
+/// from the connector
+pub fn get_injected_fault_response(
+    call_fault: ConnectorCallFault,
+) -> CustomResult<Result<types::Response, types::Response>, errors::ApiClientError> {
+    match call_fault {
+        ConnectorCallFault::Timeout => Err(report!(errors::ApiClientError::RequestTimeoutReceived)
+            .attach_printable("Timeout injected into connector call")),
+        ConnectorCallFault::ServerError { status_code } => Ok(Err(types::Response {
+            headers: None,
+            response: INJECTED_FAULT_RESPONSE.into(),
+            status_code,
+        })),
+        ConnectorCallFault::MalformedResponse => Ok(Ok(types::Response {
+            headers: None,
+            response: INJECTED_MALFORMED_RESPONSE.into(),
+            status_code: 200,
+        })),
+    }
+}
+
+/// Delays the processing of the incoming webhook by the delay configured for the connector
+pub async fn delay_incoming_webhook(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    connector: &str,
+) {
+    let Some(delay_seconds) = get_connector_faults(state, merchant_id, connector)
+        .await
+        .and_then(|faults| faults.webhook_delay_seconds)
+    else {
+        return;
+    };
+
+    logger::info!(
+        delay_seconds,
+        connector,
+        "Delaying incoming webhook as per fault injection config"
+    );
+    tokio::time::sleep(std::time::Duration::from_secs(u64::from(delay_seconds))).await;
+}
@@ -0,0 +1,338 @@
+//! Feature flags of the merchants, which enable or disable the flows gated by them without a
+//! redeploy. A flag set for a profile takes precedence over the flag set for its merchant, which
+//! takes precedence over the value in the configuration of the application.
+
+use std::collections::HashMap;
+
+use api_models::feature_flags as api;
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use diesel_models::configs;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use strum::IntoEnumIterator;
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{api::enums, domain},
+};
+
+/// The flags set for a merchant or a profile
+type FeatureFlagOverrides = HashMap<enums::MerchantFeatureFlag, bool>;
+
+async fn get_feature_flag_overrides(
+    db: &dyn StorageInterface,
+    key: &str,
+) -> RouterResult<FeatureFlagOverrides> {
+    // The flags are looked up on the flows gated by them, so their absence is cached as well
+    db.find_config_by_key_unwrap_or(key, Some("null".to_string()))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the feature flags")?
+        .config
+        .parse_struct::<Option<FeatureFlagOverrides>>("FeatureFlagOverrides")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the feature flags")
+        .map(Option::unwrap_or_default)
+}
+
+async fn upsert_feature_flag_overrides(
+    db: &dyn StorageInterface,
+    key: String,
+    overrides: &FeatureFlagOverrides,
+) -> RouterResult<()> {
+    let serialized_overrides = overrides
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize the feature flags")?;
+
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => {
+            db.update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(serialized_overrides),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the feature flags")?;
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            db.insert_config(configs::ConfigNew {
+                key,
+                config: serialized_overrides,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the feature flags")?;
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the feature flags");
+        }
+    }
+    Ok(())
+}
+
+fn apply_feature_flags_update(
+    overrides: &mut FeatureFlagOverrides,
+    request: api::FeatureFlagsUpdateRequest,
+) {
+    for (flag, enabled) in request.flags {
+        match enabled {
+            Some(enabled) => overrides.insert(flag, enabled),
+            None => overrides.remove(&flag),
+        };
+    }
+}
+
+fn resolve_feature_flag(
+    flag: enums::MerchantFeatureFlag,
+    default: bool,
+    merchant_overrides: &FeatureFlagOverrides,
+    profile_overrides: Option<&FeatureFlagOverrides>,
+) -> api::FeatureFlag {
+    let (enabled, source) = profile_overrides
+        .and_then(|overrides| overrides.get(&flag))
+        .map(|enabled| (*enabled, api::FeatureFlagSource::Profile))
+        .or_else(|| {
+            merchant_overrides
+                .get(&flag)
+                .map(|enabled| (*enabled, api::FeatureFlagSource::Merchant))
+        })
+        .unwrap_or((default, api::FeatureFlagSource::Default));
+
+    api::FeatureFlag {
+        flag,
+        enabled,
+        source,
+    }
+}
+
+fn resolve_feature_flags(
+    state: &SessionState,
+    merchant_overrides: &FeatureFlagOverrides,
+    profile_overrides: Option<&FeatureFlagOverrides>,
+) -> Vec<api::FeatureFlag> {
+    enums::MerchantFeatureFlag::iter()
+        .map(|flag| {
+            resolve_feature_flag(
+                flag,
+                state.conf.merchant_feature_flags.get_default(flag),
+                merchant_overrides,
+                profile_overrides,
+            )
+        })
+        .collect()
+}
+
+/// Checks if the flow gated by the flag is enabled for the merchant, or the profile if it is
+/// given. The value in the configuration is used if the flags could not be fetched, so that a
+/// failure to fetch them never fails the flow.
+#[instrument(skip_all)]
+pub async fn is_feature_enabled(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    profile_id: Option<&id_type::ProfileId>,
+    flag: enums::MerchantFeatureFlag,
+) -> bool {
+    let db = state.store.as_ref();
+    let default = state.conf.merchant_feature_flags.get_default(flag);
+
+    let profile_overrides = match profile_id {
+        Some(profile_id) => {
+            match get_feature_flag_overrides(db, &profile_id.get_feature_flags_key()).await {
+                Ok(overrides) => Some(overrides),
+                Err(error) => {
+                    logger::error!(?error, %flag, "Failed to fetch the feature flags of the profile");
+                    return default;
+                }
+            }
+        }
+        None => None,
+    };
+    let merchant_overrides =
+        match get_feature_flag_overrides(db, &merchant_id.get_feature_flags_key()).await {
+            Ok(overrides) => overrides,
+            Err(error) => {
+                logger::error!(?error, %flag, "Failed to fetch the feature flags of the merchant");
+                return default;
+            }
+        };
+
+    resolve_feature_flag(
+        flag,
+        default,
+        &merchant_overrides,
+        profile_overrides.as_ref(),
+    )
+    .enabled
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_merchant_feature_flags(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<api::FeatureFlagsResponse> {
+    let merchant_id = merchant_account.get_id();
+    let merchant_overrides =
+        get_feature_flag_overrides(state.store.as_ref(), &merchant_id.get_feature_flags_key())
+            .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        api::FeatureFlagsResponse {
+            merchant_id: merchant_id.clone(),
+            profile_id: None,
+            flags: resolve_feature_flags(&state, &merchant_overrides, None),
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn update_merchant_feature_flags(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    request: api::FeatureFlagsUpdateRequest,
+) -> RouterResponse<api::FeatureFlagsResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+    let key = merchant_id.get_feature_flags_key();
+
+    let mut merchant_overrides = get_feature_flag_overrides(db, &key).await?;
+    apply_feature_flags_update(&mut merchant_overrides, request);
+    upsert_feature_flag_overrides(db, key, &merchant_overrides).await?;
+    logger::info!(?merchant_overrides, "Feature flags of the merchant updated");
+
+    Ok(services::ApplicationResponse::Json(
+        api::FeatureFlagsResponse {
+            merchant_id: merchant_id.clone(),
+            profile_id: None,
+            flags: resolve_feature_flags(&state, &merchant_overrides, None),
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_profile_feature_flags(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<api::FeatureFlagsResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_id,
+    )
+    .await?;
+
+    let merchant_overrides =
+        get_feature_flag_overrides(db, &merchant_id.get_feature_flags_key()).await?;
+    let profile_overrides =
+        get_feature_flag_overrides(db, &profile_id.get_feature_flags_key()).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        api::FeatureFlagsResponse {
+            merchant_id: merchant_id.clone(),
+            flags: resolve_feature_flags(&state, &merchant_overrides, Some(&profile_overrides)),
+            profile_id: Some(profile_id),
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn update_profile_feature_flags(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: api::FeatureFlagsUpdateRequest,
+) -> RouterResponse<api::FeatureFlagsResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_id,
+    )
+    .await?;
+
+    let key = profile_id.get_feature_flags_key();
+    let mut profile_overrides = get_feature_flag_overrides(db, &key).await?;
+    apply_feature_flags_update(&mut profile_overrides, request);
+    upsert_feature_flag_overrides(db, key, &profile_overrides).await?;
+    logger::info!(?profile_overrides, "Feature flags of the profile updated");
+
+    let merchant_overrides =
+        get_feature_flag_overrides(db, &merchant_id.get_feature_flags_key()).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        api::FeatureFlagsResponse {
+            merchant_id: merchant_id.clone(),
+            flags: resolve_feature_flags(&state, &merchant_overrides, Some(&profile_overrides)),
+            profile_id: Some(profile_id),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_flag_takes_precedence_over_merchant_flag() {
+        let flag = enums::MerchantFeatureFlag::SmartRetries;
+        let merchant_overrides = HashMap::from([(flag, false)]);
+        let profile_overrides = HashMap::from([(flag, true)]);
+
+        let resolved = resolve_feature_flag(flag, true, &merchant_overrides, None);
+        assert!(!resolved.enabled);
+        assert_eq!(resolved.source, api::FeatureFlagSource::Merchant);
+
+        let resolved =
+            resolve_feature_flag(flag, false, &merchant_overrides, Some(&profile_overrides));
+        assert!(resolved.enabled);
+        assert_eq!(resolved.source, api::FeatureFlagSource::Profile);
+
+        let resolved = resolve_feature_flag(flag, false, &HashMap::new(), Some(&HashMap::new()));
+        assert!(!resolved.enabled);
+        assert_eq!(resolved.source, api::FeatureFlagSource::Default);
+    }
+
+    #[test]
+    fn test_cleared_flag_is_removed_from_overrides() {
+        let mut overrides = HashMap::from([
+            (enums::MerchantFeatureFlag::SmartRetries, false),
+            (enums::MerchantFeatureFlag::NetworkTokenization, false),
+        ]);
+        apply_feature_flags_update(
+            &mut overrides,
+            api::FeatureFlagsUpdateRequest {
+                flags: HashMap::from([
+                    (enums::MerchantFeatureFlag::SmartRetries, None),
+                    (enums::MerchantFeatureFlag::NetworkTokenization, Some(true)),
+                ]),
+            },
+        );
+        assert_eq!(
+            overrides,
+            HashMap::from([(enums::MerchantFeatureFlag::NetworkTokenization, true)])
+        );
+    }
+}
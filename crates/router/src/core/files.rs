@@ -23,7 +23,7 @@ pub async fn files_create_core(
         create_file_request.clone(),
     )
     .await?;
-    let file_id = common_utils::generate_id(consts::ID_LENGTH, "file");
+    let file_id = common_utils::generate_resource_id(consts::ID_LENGTH, "file");
     let file_key = format!(
         "{}/{}",
         merchant_account.get_id().get_string_repr(),
@@ -130,6 +130,7 @@ pub async fn construct_fulfillment_router_data<'a>(
         connector_mandate_request_reference_id: None,
         authentication_id: None,
         psd2_sca_exemption_type: None,
+        profile_id: Some(profile_id),
     };
     Ok(router_data)
 }
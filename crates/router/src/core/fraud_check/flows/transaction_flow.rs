@@ -148,6 +148,7 @@ impl
             connector_mandate_request_reference_id: None,
             authentication_id: None,
             psd2_sca_exemption_type: None,
+            profile_id: self.payment_intent.profile_id.clone(),
         };
 
         Ok(router_data)
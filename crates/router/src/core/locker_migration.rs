@@ -82,7 +82,8 @@ pub async fn rust_locker_migration(
         offset: None,
     };
 
-    let domain_customers = db
+    let domain_customers = super::data_residency::get_pii_store(&state, merchant_id)
+        .await?
         .list_customers_by_merchant_id(key_manager_state, merchant_id, &key_store, constraints)
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)?;
@@ -180,6 +181,7 @@ pub async fn call_to_locker(
             card: Some(card_details.clone()),
             #[cfg(feature = "payouts")]
             wallet: None,
+            bank_debit: None,
             #[cfg(feature = "payouts")]
             bank_transfer: None,
             metadata: pm.metadata,
@@ -85,6 +85,7 @@ pub async fn construct_mandate_revoke_router_data(
         connector_mandate_request_reference_id: None,
         authentication_id: None,
         psd2_sca_exemption_type: None,
+        profile_id: None,
     };
 
     Ok(router_data)
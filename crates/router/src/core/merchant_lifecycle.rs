@@ -0,0 +1,883 @@
+//! Lifecycle of the merchant accounts, for the compliance operations of the platform. A merchant
+//! account is either active, restricted (it can refund and manage its existing payments, but cannot
+//! charge), suspended (it cannot be accessed with its API keys) or closing. A merchant account is
+//! moved to the closing status by offboarding it, after which its pending payouts are drained and
+//! its data is exported to the file storage by the scheduler. A webhook is sent to each profile of
+//! the merchant on each change of status.
+
+use api_models::{
+    admin::{
+        MerchantAccountStatusResponse, MerchantAccountStatusUpdateRequest,
+        MerchantOffboardingRequest, MerchantOffboardingResponse, MerchantOffboardingStatus,
+    },
+    webhooks,
+};
+#[cfg(all(feature = "v1", feature = "olap"))]
+use common_utils::types::MinorUnit;
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+#[cfg(all(feature = "v1", feature = "olap"))]
+use csv::Writer;
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+#[cfg(all(feature = "v1", feature = "olap", feature = "payouts"))]
+use hyperswitch_domain_models::payouts::{PayoutFetchConstraints, PayoutListParams};
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+#[cfg(all(feature = "v1", feature = "olap", feature = "payouts"))]
+use crate::core::payouts;
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{api::enums, domain, storage},
+};
+
+const MERCHANT_OFFBOARDING_TASK: &str = "MERCHANT_OFFBOARDING";
+const MERCHANT_OFFBOARDING_TAG: &str = "MERCHANT";
+
+/// The tables of the data of a merchant exported by its offboarding, in the order of the export
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OffboardingExportTable {
+    #[default]
+    Payments,
+    Refunds,
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+impl OffboardingExportTable {
+    fn get_file_name_prefix(&self) -> &'static str {
+        match self {
+            Self::Payments => "payments",
+            Self::Refunds => "refunds",
+        }
+    }
+}
+
+/// Position of the export of the data of a merchant, which is exported a page of records at a time
+/// in the order of their last update, each page to its own file
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OffboardingExportCursor {
+    pub table: OffboardingExportTable,
+    pub exported_parts_count: usize,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub last_modified_at: Option<PrimitiveDateTime>,
+    pub last_id: Option<String>,
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+impl OffboardingExportCursor {
+    /// The position of the export after a page of the given number of records was exported, or
+    /// `None` once all the tables are exported. A table is exported once a page is not full.
+    fn get_next_cursor(
+        &self,
+        fetched_count: usize,
+        last_record: Option<(PrimitiveDateTime, String)>,
+        page_size: usize,
+    ) -> Option<Self> {
+        match (fetched_count < page_size, self.table) {
+            (true, OffboardingExportTable::Payments) => Some(Self {
+                table: OffboardingExportTable::Refunds,
+                ..Default::default()
+            }),
+            (true, OffboardingExportTable::Refunds) => None,
+            (false, table) => {
+                let (last_modified_at, last_id) = last_record.unzip();
+                Some(Self {
+                    table,
+                    exported_parts_count: self.exported_parts_count + 1,
+                    last_modified_at,
+                    last_id,
+                })
+            }
+        }
+    }
+}
+
+/// The offboarding of a merchant account, as stored along with its progress
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MerchantOffboarding {
+    pub status: MerchantOffboardingStatus,
+    pub reason: Option<String>,
+    pub cancelled_payouts_count: usize,
+    pub pending_payouts_count: usize,
+    pub exported_files: Vec<String>,
+    #[serde(default)]
+    pub export_cursor: Option<OffboardingExportCursor>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl MerchantOffboarding {
+    fn to_response(&self, merchant_id: &id_type::MerchantId) -> MerchantOffboardingResponse {
+        MerchantOffboardingResponse {
+            merchant_id: merchant_id.clone(),
+            status: self.status,
+            reason: self.reason.clone(),
+            cancelled_payouts_count: self.cancelled_payouts_count,
+            pending_payouts_count: self.pending_payouts_count,
+            exported_files: self.exported_files.clone(),
+            created_at: self.created_at,
+            modified_at: self.modified_at,
+        }
+    }
+}
+
+/// Validates that new payments and payouts can be created by the merchant account
+pub fn validate_merchant_can_charge(
+    merchant_account: &domain::MerchantAccount,
+) -> RouterResult<()> {
+    if merchant_account.status.can_charge() {
+        Ok(())
+    } else {
+        Err(report!(
+            errors::ApiErrorResponse::MerchantAccountStatusNotAllowed {
+                status: merchant_account.status.to_string(),
+            }
+        ))
+    }
+}
+
+async fn get_merchant_account_and_key_store(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<(domain::MerchantAccount, domain::MerchantKeyStore)> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(key_manager_state, merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    Ok((merchant_account, key_store))
+}
+
+/// Moves the merchant account to the given status and notifies each of its profiles
+async fn set_merchant_account_status(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    status: enums::MerchantAccountStatus,
+    reason: Option<String>,
+) -> RouterResult<MerchantAccountStatusResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let updated_merchant_account = db
+        .update_specific_fields_in_merchant(
+            key_manager_state,
+            merchant_account.get_id(),
+            storage::MerchantAccountUpdate::StatusUpdate { status },
+            key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let response = MerchantAccountStatusResponse {
+        merchant_id: updated_merchant_account.get_id().clone(),
+        status: updated_merchant_account.status,
+        previous_status: Some(merchant_account.status),
+        reason,
+        updated_at: updated_merchant_account.modified_at,
+    };
+
+    trigger_merchant_account_status_webhooks(
+        state,
+        &updated_merchant_account,
+        key_store,
+        &response,
+    )
+    .await;
+
+    Ok(response)
+}
+
+async fn trigger_merchant_account_status_webhooks(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    response: &MerchantAccountStatusResponse,
+) {
+    let business_profiles = match state
+        .store
+        .list_profile_by_merchant_id(&state.into(), key_store, merchant_account.get_id())
+        .await
+    {
+        Ok(business_profiles) => business_profiles,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to list the profiles for the merchant account status webhook"
+            );
+            return;
+        }
+    };
+
+    for business_profile in business_profiles {
+        // The events are deduplicated by their primary object, which is unique for each change of
+        // status and each profile as the identifier of the merchant is reused
+        let primary_object_id = common_utils::generate_id(consts::ID_LENGTH, "mas");
+        if let Err(error) = Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+            state.clone(),
+            merchant_account.clone(),
+            business_profile,
+            key_store,
+            enums::EventType::MerchantAccountStatusUpdated,
+            enums::EventClass::MerchantAccount,
+            primary_object_id,
+            storage::enums::EventObjectType::MerchantAccountDetails,
+            webhooks::OutgoingWebhookContent::MerchantAccountDetails(Box::new(response.clone())),
+            Some(response.updated_at),
+        ))
+        .await
+        {
+            logger::error!(
+                ?error,
+                "Failed to trigger the merchant account status webhook"
+            );
+        }
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn update_merchant_account_status(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    req: MerchantAccountStatusUpdateRequest,
+) -> RouterResponse<MerchantAccountStatusResponse> {
+    if req.status == enums::MerchantAccountStatus::Closing {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "A merchant account can be moved to the closing status only by offboarding it"
+                .to_string(),
+        }));
+    }
+
+    let (merchant_account, key_store) =
+        get_merchant_account_and_key_store(&state, &merchant_id).await?;
+
+    if !merchant_account.status.can_transition_to(req.status) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "Merchant account cannot be moved from the {} status to the {} status",
+                merchant_account.status, req.status
+            ),
+        }));
+    }
+
+    let response = set_merchant_account_status(
+        &state,
+        &merchant_account,
+        &key_store,
+        req.status,
+        req.reason,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+async fn get_merchant_offboarding(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<MerchantOffboarding> {
+    db.find_config_by_key(&merchant_id.get_offboarding_key())
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::ApiErrorResponse::GenericNotFoundError {
+                    message: "Merchant offboarding not found".to_string(),
+                })
+            } else {
+                error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch merchant offboarding")
+            }
+        })?
+        .config
+        .parse_struct("MerchantOffboarding")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse merchant offboarding")
+}
+
+async fn update_merchant_offboarding(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    offboarding: &MerchantOffboarding,
+) -> RouterResult<()> {
+    let serialized_offboarding = offboarding
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize merchant offboarding")?;
+
+    db.update_config_by_key(
+        &merchant_id.get_offboarding_key(),
+        configs::ConfigUpdate::Update {
+            config: Some(serialized_offboarding),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update merchant offboarding")?;
+
+    Ok(())
+}
+
+fn get_merchant_offboarding_process_tracker_id(merchant_id: &id_type::MerchantId) -> String {
+    format!(
+        "{}_{MERCHANT_OFFBOARDING_TASK}_{}",
+        storage::ProcessTrackerRunner::MerchantOffboardingWorkflow,
+        merchant_id.get_string_repr()
+    )
+}
+
+async fn add_merchant_offboarding_task(
+    db: &dyn StorageInterface,
+    tracking_data: storage::MerchantOffboardingTrackingData,
+    schedule_time: PrimitiveDateTime,
+) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::MerchantOffboardingWorkflow;
+    let process_tracker_id =
+        get_merchant_offboarding_process_tracker_id(&tracking_data.merchant_id);
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        MERCHANT_OFFBOARDING_TASK,
+        runner,
+        [MERCHANT_OFFBOARDING_TAG],
+        tracking_data,
+        None,
+        schedule_time,
+        hyperswitch_domain_models::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct MERCHANT_OFFBOARDING process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while inserting MERCHANT_OFFBOARDING task to process_tracker")?;
+
+    Ok(())
+}
+
+/// Offboards the merchant account. The offboarding is stored, the merchant account is moved to the
+/// closing status and the task of the offboarding is added in turn, and the request can be retried
+/// to complete an offboarding which failed midway: the stored offboarding is replaced while the
+/// merchant account is not closing, and the task is added if the merchant account is closing
+/// without it.
+#[instrument(skip_all)]
+pub async fn offboard_merchant_account(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    req: MerchantOffboardingRequest,
+) -> RouterResponse<MerchantOffboardingResponse> {
+    let db = state.store.as_ref();
+    let (merchant_account, key_store) =
+        get_merchant_account_and_key_store(&state, &merchant_id).await?;
+    let tracking_data = storage::MerchantOffboardingTrackingData {
+        merchant_id: merchant_id.clone(),
+    };
+
+    if merchant_account.status == enums::MerchantAccountStatus::Closing {
+        let process = db
+            .find_process_by_id(&get_merchant_offboarding_process_tracker_id(&merchant_id))
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the merchant offboarding task")?;
+        if process.is_some() {
+            return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Merchant account is already being offboarded".to_string(),
+            }));
+        }
+
+        let offboarding = get_merchant_offboarding(db, &merchant_id).await?;
+        add_merchant_offboarding_task(db, tracking_data, common_utils::date_time::now()).await?;
+        return Ok(services::ApplicationResponse::Json(
+            offboarding.to_response(&merchant_id),
+        ));
+    }
+
+    let now = common_utils::date_time::now();
+    let offboarding = MerchantOffboarding {
+        status: MerchantOffboardingStatus::DrainingPayouts,
+        reason: req.reason.clone(),
+        cancelled_payouts_count: 0,
+        pending_payouts_count: 0,
+        exported_files: Vec::new(),
+        export_cursor: None,
+        created_at: now,
+        modified_at: now,
+    };
+    let key = merchant_id.get_offboarding_key();
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => update_merchant_offboarding(db, &merchant_id, &offboarding).await?,
+        Err(error) if error.current_context().is_db_not_found() => {
+            let serialized_offboarding = offboarding
+                .encode_to_string_of_json()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Unable to serialize merchant offboarding")?;
+            db.insert_config(configs::ConfigNew {
+                key,
+                config: serialized_offboarding,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert merchant offboarding")?;
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch merchant offboarding");
+        }
+    }
+
+    set_merchant_account_status(
+        &state,
+        &merchant_account,
+        &key_store,
+        enums::MerchantAccountStatus::Closing,
+        req.reason,
+    )
+    .await?;
+
+    add_merchant_offboarding_task(db, tracking_data, now).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        offboarding.to_response(&merchant_id),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_merchant_offboarding(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<MerchantOffboardingResponse> {
+    let offboarding = get_merchant_offboarding(state.store.as_ref(), &merchant_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        offboarding.to_response(&merchant_id),
+    ))
+}
+
+#[cfg(all(feature = "v1", feature = "olap", feature = "payouts"))]
+async fn get_payouts_by_status(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    status: Vec<enums::PayoutStatus>,
+    limit: Option<u32>,
+) -> RouterResult<Vec<storage::Payouts>> {
+    let constraints = PayoutFetchConstraints::List(Box::new(PayoutListParams {
+        offset: 0,
+        starting_at: None,
+        ending_at: None,
+        connector: None,
+        currency: None,
+        status: Some(status),
+        payout_method: None,
+        profile_id: None,
+        customer_id: None,
+        starting_after_id: None,
+        ending_before_id: None,
+        entity_type: None,
+        limit,
+    }));
+
+    state
+        .store
+        .filter_payouts_by_constraints(
+            merchant_account.get_id(),
+            &constraints,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payouts of the merchant")
+}
+
+/// Cancels a chunk of the payouts of the merchant which were not sent to the connectors, and
+/// returns the number of payouts which were cancelled and the number of payouts which are still in
+/// flight with the connectors
+#[cfg(all(feature = "v1", feature = "olap", feature = "payouts"))]
+async fn drain_payouts(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<(usize, usize)> {
+    let payouts_to_cancel = get_payouts_by_status(
+        state,
+        merchant_account,
+        vec![
+            enums::PayoutStatus::RequiresCreation,
+            enums::PayoutStatus::RequiresConfirmation,
+            enums::PayoutStatus::RequiresPayoutMethodData,
+            enums::PayoutStatus::RequiresVendorAccountCreation,
+            enums::PayoutStatus::RequiresFulfillment,
+            enums::PayoutStatus::OnHold,
+        ],
+        Some(consts::MERCHANT_OFFBOARDING_PAYOUTS_CHUNK_SIZE),
+    )
+    .await?;
+
+    let mut cancelled_payouts_count = 0;
+    for payout in payouts_to_cancel {
+        match Box::pin(payouts::payouts_cancel_core(
+            state.clone(),
+            merchant_account.clone(),
+            key_store.clone(),
+            api_models::payouts::PayoutActionRequest {
+                payout_id: payout.payout_id.clone(),
+            },
+        ))
+        .await
+        {
+            Ok(_) => cancelled_payouts_count += 1,
+            Err(error) => {
+                logger::error!(?error, payout_id = %payout.payout_id, "Failed to cancel the payout");
+            }
+        }
+    }
+
+    let pending_payouts_count = get_payouts_by_status(
+        state,
+        merchant_account,
+        vec![
+            enums::PayoutStatus::RequiresCreation,
+            enums::PayoutStatus::RequiresConfirmation,
+            enums::PayoutStatus::RequiresPayoutMethodData,
+            enums::PayoutStatus::RequiresVendorAccountCreation,
+            enums::PayoutStatus::RequiresFulfillment,
+            enums::PayoutStatus::OnHold,
+            enums::PayoutStatus::Pending,
+            enums::PayoutStatus::Initiated,
+        ],
+        None,
+    )
+    .await?
+    .len();
+
+    Ok((cancelled_payouts_count, pending_payouts_count))
+}
+
+#[cfg(all(feature = "v1", feature = "olap", not(feature = "payouts")))]
+async fn drain_payouts(
+    _state: &SessionState,
+    _merchant_account: &domain::MerchantAccount,
+    _key_store: &domain::MerchantKeyStore,
+) -> RouterResult<(usize, usize)> {
+    Ok((0, 0))
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[derive(Debug, Serialize)]
+struct OffboardingPaymentRecord {
+    payment_id: id_type::PaymentId,
+    status: enums::IntentStatus,
+    amount: MinorUnit,
+    currency: Option<enums::Currency>,
+    customer_id: Option<id_type::CustomerId>,
+    profile_id: Option<id_type::ProfileId>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    created_at: PrimitiveDateTime,
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[derive(Debug, Serialize)]
+struct OffboardingRefundRecord {
+    refund_id: String,
+    payment_id: id_type::PaymentId,
+    status: enums::RefundStatus,
+    amount: MinorUnit,
+    currency: enums::Currency,
+    connector: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    created_at: PrimitiveDateTime,
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+fn get_export_file_key(merchant_id: &id_type::MerchantId, file_name: &str) -> String {
+    format!("offboarding/{}/{file_name}", merchant_id.get_string_repr())
+}
+
+#[cfg(all(feature = "v1", feature = "olap"))]
+fn serialize_export_record<T: Serialize>(
+    csv_writer: &mut Writer<Vec<u8>>,
+    record: T,
+) -> RouterResult<()> {
+    csv_writer
+        .serialize(record)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to write the exported records")
+}
+
+/// Exports the next page of the records of the merchant to its own CSV file. Returns the key of
+/// the file, unless there was no record left in the table, along with the position of the export
+/// after the page. The records are read up to the current time for each page, so that a record
+/// updated during the export is exported again in its latest version rather than missed.
+#[cfg(all(feature = "v1", feature = "olap"))]
+async fn export_next_page(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    cursor: &OffboardingExportCursor,
+) -> RouterResult<(Option<String>, Option<OffboardingExportCursor>)> {
+    let db = state.store.as_ref();
+    let page_size = consts::MERCHANT_OFFBOARDING_EXPORT_PAGE_SIZE;
+    let modified_before = common_utils::date_time::now();
+    let last_record = cursor.last_modified_at.zip(cursor.last_id.clone());
+    let mut csv_writer = Writer::from_writer(Vec::new());
+
+    let (fetched_count, last_record) = match cursor.table {
+        OffboardingExportTable::Payments => {
+            let last_record = last_record
+                .map(|(modified_at, payment_id)| {
+                    id_type::PaymentId::wrap(payment_id).map(|payment_id| (modified_at, payment_id))
+                })
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Invalid payment id in the offboarding export cursor")?;
+            let payment_intents = db
+                .find_payment_intents_modified_after(
+                    merchant_id,
+                    last_record,
+                    modified_before,
+                    page_size,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the payments to be exported")?;
+
+            let fetched_count = payment_intents.len();
+            let last_record = payment_intents.last().map(|payment_intent| {
+                (
+                    payment_intent.modified_at,
+                    payment_intent.payment_id.get_string_repr().to_owned(),
+                )
+            });
+            for payment_intent in payment_intents {
+                serialize_export_record(
+                    &mut csv_writer,
+                    OffboardingPaymentRecord {
+                        payment_id: payment_intent.payment_id,
+                        status: payment_intent.status,
+                        amount: payment_intent.amount,
+                        currency: payment_intent.currency,
+                        customer_id: payment_intent.customer_id,
+                        profile_id: payment_intent.profile_id,
+                        created_at: payment_intent.created_at,
+                    },
+                )?;
+            }
+            (fetched_count, last_record)
+        }
+        OffboardingExportTable::Refunds => {
+            let refunds = db
+                .find_refunds_modified_after(merchant_id, last_record, modified_before, page_size)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the refunds to be exported")?;
+
+            let fetched_count = refunds.len();
+            let last_record = refunds
+                .last()
+                .map(|refund| (refund.modified_at, refund.refund_id.clone()));
+            for refund in refunds {
+                serialize_export_record(
+                    &mut csv_writer,
+                    OffboardingRefundRecord {
+                        refund_id: refund.refund_id,
+                        payment_id: refund.payment_id,
+                        status: refund.refund_status,
+                        amount: refund.refund_amount,
+                        currency: refund.currency,
+                        connector: refund.connector,
+                        created_at: refund.created_at,
+                    },
+                )?;
+            }
+            (fetched_count, last_record)
+        }
+    };
+
+    let file_key = if fetched_count > 0 {
+        let file_key = get_export_file_key(
+            merchant_id,
+            &format!(
+                "{}_{:05}.csv",
+                cursor.table.get_file_name_prefix(),
+                cursor.exported_parts_count + 1
+            ),
+        );
+        let data = csv_writer
+            .into_inner()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to generate the export file")?;
+        state
+            .file_storage_client
+            .upload_file(&file_key, data)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to upload the export file")?;
+        Some(file_key)
+    } else {
+        None
+    };
+
+    let next_cursor = cursor.get_next_cursor(
+        fetched_count,
+        last_record,
+        usize::try_from(page_size).unwrap_or(usize::MAX),
+    );
+
+    Ok((file_key, next_cursor))
+}
+
+/// Runs a step of the offboarding of the merchant, and returns the time at which the next step is
+/// to be run, unless the offboarding is completed. The data of the merchant is exported a page at
+/// a time, and the progress is stored after each step, so that a failed run resumes where it
+/// stopped.
+#[cfg(all(feature = "v1", feature = "olap"))]
+#[instrument(skip_all)]
+pub async fn process_merchant_offboarding(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<Option<PrimitiveDateTime>> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+    let mut offboarding = get_merchant_offboarding(db, merchant_id).await?;
+
+    let next_schedule_time = match offboarding.status {
+        MerchantOffboardingStatus::DrainingPayouts => {
+            let (cancelled_payouts_count, pending_payouts_count) =
+                drain_payouts(state, merchant_account, key_store).await?;
+            offboarding.cancelled_payouts_count += cancelled_payouts_count;
+            offboarding.pending_payouts_count = pending_payouts_count;
+
+            if pending_payouts_count == 0 {
+                offboarding.status = MerchantOffboardingStatus::ExportingData;
+                Some(common_utils::date_time::now())
+            } else if cancelled_payouts_count > 0 {
+                // Pick up the next chunk of the payouts in the next run of the scheduler
+                Some(common_utils::date_time::now())
+            } else {
+                // Only the payouts in flight with the connectors are left, which are awaited
+                Some(
+                    common_utils::date_time::now()
+                        + time::Duration::seconds(
+                            consts::MERCHANT_OFFBOARDING_PAYOUTS_POLL_INTERVAL_IN_SECONDS,
+                        ),
+                )
+            }
+        }
+        MerchantOffboardingStatus::ExportingData => {
+            let cursor = offboarding.export_cursor.take().unwrap_or_default();
+            let (file_key, next_cursor) = export_next_page(state, merchant_id, &cursor).await?;
+            offboarding.exported_files.extend(file_key);
+            offboarding.export_cursor = next_cursor;
+
+            if offboarding.export_cursor.is_some() {
+                // Export the next page in the next run of the scheduler
+                Some(common_utils::date_time::now())
+            } else {
+                offboarding.status = MerchantOffboardingStatus::Completed;
+                None
+            }
+        }
+        MerchantOffboardingStatus::Completed => None,
+    };
+
+    offboarding.modified_at = common_utils::date_time::now();
+    update_merchant_offboarding(db, merchant_id, &offboarding).await?;
+
+    Ok(next_schedule_time)
+}
+
+#[cfg(all(test, feature = "v1", feature = "olap"))]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_export_cursor_moves_through_the_tables() {
+        let modified_at = common_utils::date_time::now();
+        let cursor = OffboardingExportCursor::default();
+        assert_eq!(cursor.table, OffboardingExportTable::Payments);
+
+        // A full page of payments is followed by the next page of the payments
+        let cursor = cursor
+            .get_next_cursor(2, Some((modified_at, "pay_2".to_string())), 2)
+            .expect("payments export ended early");
+        assert_eq!(
+            cursor,
+            OffboardingExportCursor {
+                table: OffboardingExportTable::Payments,
+                exported_parts_count: 1,
+                last_modified_at: Some(modified_at),
+                last_id: Some("pay_2".to_string()),
+            }
+        );
+
+        // A page which is not full ends the export of the payments
+        let cursor = cursor
+            .get_next_cursor(1, Some((modified_at, "pay_3".to_string())), 2)
+            .expect("refunds were not exported");
+        assert_eq!(
+            cursor,
+            OffboardingExportCursor {
+                table: OffboardingExportTable::Refunds,
+                ..Default::default()
+            }
+        );
+
+        // An empty page ends the export of the refunds, and so the export
+        assert_eq!(cursor.get_next_cursor(0, None, 2), None);
+    }
+
+    #[test]
+    fn test_offboarding_stored_without_export_cursor() {
+        let offboarding: MerchantOffboarding = serde_json::from_value(serde_json::json!({
+            "status": "exporting_data",
+            "reason": null,
+            "cancelled_payouts_count": 1,
+            "pending_payouts_count": 0,
+            "exported_files": [],
+            "created_at": "2025-01-01T00:00:00.000Z",
+            "modified_at": "2025-01-01T00:00:00.000Z"
+        }))
+        .expect("failed to deserialize offboarding");
+        assert!(offboarding.export_cursor.is_none());
+
+        let cursor = OffboardingExportCursor {
+            table: OffboardingExportTable::Refunds,
+            exported_parts_count: 3,
+            last_modified_at: Some(offboarding.modified_at),
+            last_id: Some("ref_1".to_string()),
+        };
+        let serialized_cursor = serde_json::to_value(&cursor).expect("failed to serialize cursor");
+        assert_eq!(
+            serde_json::from_value::<OffboardingExportCursor>(serialized_cursor)
+                .expect("failed to deserialize cursor"),
+            cursor
+        );
+    }
+}
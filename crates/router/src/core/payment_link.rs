@@ -1,8 +1,10 @@
+#[cfg(feature = "v1")]
+pub mod checkout_theme;
 pub mod validator;
 use actix_web::http::header;
 use api_models::{
     admin::PaymentLinkConfig,
-    payments::{PaymentLinkData, PaymentLinkStatusWrap},
+    payments::{CheckoutSection, PaymentLinkData, PaymentLinkStatusWrap},
 };
 use common_utils::{
     consts::{DEFAULT_LOCALE, DEFAULT_SESSION_EXPIRY},
@@ -19,6 +21,7 @@ use time::PrimitiveDateTime;
 use super::{
     errors::{self, RouterResult, StorageErrorExt},
     payments::helpers,
+    sandbox,
 };
 use crate::{
     consts::{
@@ -54,7 +57,9 @@ pub async fn retrieve_payment_link(
             .saturating_add(time::Duration::seconds(DEFAULT_SESSION_EXPIRY))
     });
 
-    let status = check_payment_link_status(session_expiry);
+    let current_time =
+        sandbox::get_merchant_current_time(db, &payment_link_config.merchant_id).await;
+    let status = check_payment_link_status(session_expiry, current_time);
 
     let response = api_models::payments::RetrievePaymentLinkResponse::foreign_from((
         payment_link_config,
@@ -112,7 +117,7 @@ pub async fn form_payment_link_data(
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentLinkNotFound)?;
 
-    let payment_link_config =
+    let mut payment_link_config =
         if let Some(pl_config_value) = payment_link.payment_link_config.clone() {
             extract_payment_link_config(pl_config_value)?
         } else {
@@ -156,6 +161,12 @@ pub async fn form_payment_link_data(
             id: profile_id.get_string_repr().to_owned(),
         })?;
 
+    let checkout_theme = checkout_theme::get_active_checkout_theme(db, &profile_id).await?;
+    if let Some(checkout_theme) = &checkout_theme {
+        checkout_theme::apply_checkout_theme(&mut payment_link_config, checkout_theme);
+    }
+    let locale = checkout_theme::get_checkout_locale(&state.locale, checkout_theme.as_ref());
+
     let return_url = if let Some(payment_create_return_url) = payment_intent.return_url.clone() {
         payment_create_return_url
     } else {
@@ -179,7 +190,13 @@ pub async fn form_payment_link_data(
             amount_type: "StringMajorUnit",
         })?;
 
-    let order_details = validate_order_details(payment_intent.order_details.clone(), currency)?;
+    let is_section_visible = |section| {
+        checkout_theme.as_ref().map_or(true, |checkout_theme| {
+            checkout_theme.is_section_visible(section)
+        })
+    };
+    let order_details = validate_order_details(payment_intent.order_details.clone(), currency)?
+        .filter(|_| is_section_visible(CheckoutSection::OrderDetails));
 
     let session_expiry = payment_link.fulfilment_time.unwrap_or_else(|| {
         payment_intent
@@ -189,7 +206,8 @@ pub async fn form_payment_link_data(
 
     // converting first letter of merchant name to upperCase
     let merchant_name = capitalize_first_char(&payment_link_config.seller_name);
-    let payment_link_status = check_payment_link_status(session_expiry);
+    let current_time = sandbox::get_merchant_current_time(db, &merchant_id).await;
+    let payment_link_status = check_payment_link_status(session_expiry, current_time);
 
     let is_payment_link_terminal_state = check_payment_link_invalid_conditions(
         payment_intent.status,
@@ -250,7 +268,7 @@ pub async fn form_payment_link_data(
             redirect: false,
             theme: payment_link_config.theme.clone(),
             return_url: return_url.clone(),
-            locale: Some(state.clone().locale),
+            locale: Some(locale),
             transaction_details: payment_link_config.transaction_details.clone(),
             unified_code: payment_attempt.unified_code,
             unified_message: payment_attempt.unified_message,
@@ -276,12 +294,14 @@ pub async fn form_payment_link_data(
         merchant_logo: payment_link_config.logo.clone(),
         max_items_visible_after_collapse: 3,
         theme: payment_link_config.theme.clone(),
-        merchant_description: payment_intent.description,
+        merchant_description: payment_intent
+            .description
+            .filter(|_| is_section_visible(CheckoutSection::MerchantDescription)),
         sdk_layout: payment_link_config.sdk_layout.clone(),
         display_sdk_only: payment_link_config.display_sdk_only,
         hide_card_nickname_field: payment_link_config.hide_card_nickname_field,
         show_card_form_by_default: payment_link_config.show_card_form_by_default,
-        locale: Some(state.clone().locale),
+        locale: Some(locale),
         transaction_details: payment_link_config.transaction_details.clone(),
         background_image: payment_link_config.background_image.clone(),
         details_layout: payment_link_config.details_layout,
@@ -524,10 +544,9 @@ pub async fn list_payment_link(
 
 pub fn check_payment_link_status(
     payment_link_expiry: PrimitiveDateTime,
+    current_time: PrimitiveDateTime,
 ) -> api_models::payments::PaymentLinkStatus {
-    let curr_time = common_utils::date_time::now();
-
-    if curr_time > payment_link_expiry {
+    if current_time > payment_link_expiry {
         api_models::payments::PaymentLinkStatus::Expired
     } else {
         api_models::payments::PaymentLinkStatus::Active
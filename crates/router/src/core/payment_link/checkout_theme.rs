@@ -0,0 +1,514 @@
+//! Theme of the hosted checkout page of the profiles. The theme is stored server side and applied
+//! when the payment links of the profile are rendered, over the payment link configuration with
+//! which they were created, so that the appearance of the page can be changed without changing the
+//! integration of the merchant. Every update of the theme creates a new version, and a previous
+//! version can be applied again.
+
+use api_models::{
+    admin::PaymentLinkConfig,
+    payments::{
+        CheckoutSection, CheckoutTheme, CheckoutThemeActivateRequest, CheckoutThemeResponse,
+        CheckoutThemeRetrieveQuery, CheckoutThemeVersionsResponse,
+    },
+};
+use common_utils::{
+    consts::DEFAULT_LOCALE,
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::domain,
+};
+
+/// A version of the theme, as stored in the configs
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+struct CheckoutThemeVersion {
+    version: u32,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    created_at: PrimitiveDateTime,
+    theme: CheckoutTheme,
+}
+
+/// The versions of the theme of a profile, as stored in the configs
+#[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
+struct CheckoutThemeVersions {
+    #[serde(default)]
+    active_version: u32,
+    #[serde(default)]
+    versions: Vec<CheckoutThemeVersion>,
+}
+
+impl CheckoutThemeVersions {
+    fn get_version(&self, version: u32) -> Option<&CheckoutThemeVersion> {
+        self.versions
+            .iter()
+            .find(|theme_version| theme_version.version == version)
+    }
+
+    fn get_active_version(&self) -> Option<&CheckoutThemeVersion> {
+        self.get_version(self.active_version)
+    }
+
+    /// Adds the theme as a new version, which becomes the active one. The versions are kept in the
+    /// order of their creation, and the new version is the active one, so the oldest versions are
+    /// dropped first beyond the maximum number of versions.
+    fn add_version(&mut self, theme: CheckoutTheme, created_at: PrimitiveDateTime) {
+        let version = self
+            .versions
+            .iter()
+            .map(|theme_version| theme_version.version)
+            .max()
+            .unwrap_or_default()
+            + 1;
+        self.versions.push(CheckoutThemeVersion {
+            version,
+            created_at,
+            theme,
+        });
+        self.active_version = version;
+
+        let excess_versions = self
+            .versions
+            .len()
+            .saturating_sub(consts::MAX_CHECKOUT_THEME_VERSIONS);
+        self.versions.drain(..excess_versions);
+    }
+
+    fn get_response(
+        &self,
+        profile_id: &id_type::ProfileId,
+        theme_version: &CheckoutThemeVersion,
+    ) -> CheckoutThemeResponse {
+        CheckoutThemeResponse {
+            profile_id: profile_id.clone(),
+            version: theme_version.version,
+            is_active: theme_version.version == self.active_version,
+            created_at: theme_version.created_at,
+            theme: theme_version.theme.clone(),
+        }
+    }
+}
+
+fn get_theme_not_found_error() -> errors::ApiErrorResponse {
+    errors::ApiErrorResponse::GenericNotFoundError {
+        message: "Checkout theme not found for the profile".to_string(),
+    }
+}
+
+/// Fetches the versions of the theme of the profile. This is looked up for every payment link
+/// which is rendered, so the absence of the theme is cached as well.
+async fn get_checkout_theme_versions(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Option<CheckoutThemeVersions>> {
+    let versions: CheckoutThemeVersions = db
+        .find_config_by_key_unwrap_or(&profile_id.get_checkout_theme_key(), Some("{}".to_string()))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch checkout theme")?
+        .config
+        .parse_struct("CheckoutThemeVersions")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse checkout theme")?;
+
+    Ok((!versions.versions.is_empty()).then_some(versions))
+}
+
+/// Fetches the versions of the theme of the profile from the database, as the cache holds empty
+/// versions for the profiles without a theme
+async fn get_checkout_theme_versions_from_db(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Option<CheckoutThemeVersions>> {
+    match db
+        .find_config_by_key_from_db(&profile_id.get_checkout_theme_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("CheckoutThemeVersions")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse checkout theme")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch checkout theme"),
+    }
+}
+
+async fn save_checkout_theme_versions(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+    versions: &CheckoutThemeVersions,
+    is_existing: bool,
+) -> RouterResult<()> {
+    let key = profile_id.get_checkout_theme_key();
+    let serialized_versions = versions
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize checkout theme")?;
+
+    if is_existing {
+        db.update_config_by_key(
+            &key,
+            configs::ConfigUpdate::Update {
+                config: Some(serialized_versions),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update checkout theme")?;
+    } else {
+        db.insert_config(configs::ConfigNew {
+            key,
+            config: serialized_versions,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert checkout theme")?;
+    }
+
+    Ok(())
+}
+
+/// Creates a new version of the theme and applies it to the payment links of the profile. The
+/// updates of the theme of a profile hold a lock on it, as they read and write all its versions.
+#[instrument(skip_all)]
+pub async fn upsert_checkout_theme(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: CheckoutTheme,
+) -> RouterResponse<CheckoutThemeResponse> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    request.validate().map_err(|error| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: error.current_context().to_string(),
+        })
+    })?;
+
+    let existing_versions = get_checkout_theme_versions_from_db(db, &profile_id).await?;
+    let is_existing = existing_versions.is_some();
+    let mut versions = existing_versions.unwrap_or_default();
+
+    versions.add_version(request, common_utils::date_time::now());
+    save_checkout_theme_versions(db, &profile_id, &versions, is_existing).await?;
+
+    let response = versions
+        .get_active_version()
+        .map(|theme_version| versions.get_response(&profile_id, theme_version))
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Active version missing in the checkout theme")?;
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+/// Retrieves the active version of the theme of the profile, or the requested version
+#[instrument(skip_all)]
+pub async fn retrieve_checkout_theme(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    query: CheckoutThemeRetrieveQuery,
+) -> RouterResponse<CheckoutThemeResponse> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    let versions = get_checkout_theme_versions(db, &profile_id)
+        .await?
+        .ok_or_else(get_theme_not_found_error)?;
+
+    let theme_version = match query.version {
+        Some(version) => versions.get_version(version),
+        None => versions.get_active_version(),
+    }
+    .ok_or_else(get_theme_not_found_error)?;
+
+    Ok(services::ApplicationResponse::Json(
+        versions.get_response(&profile_id, theme_version),
+    ))
+}
+
+/// Lists the versions of the theme of the profile, from the latest
+#[instrument(skip_all)]
+pub async fn list_checkout_theme_versions(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<CheckoutThemeVersionsResponse> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    let versions = get_checkout_theme_versions(db, &profile_id)
+        .await?
+        .ok_or_else(get_theme_not_found_error)?;
+
+    Ok(services::ApplicationResponse::Json(
+        CheckoutThemeVersionsResponse {
+            active_version: versions.active_version,
+            versions: versions
+                .versions
+                .iter()
+                .rev()
+                .map(|theme_version| versions.get_response(&profile_id, theme_version))
+                .collect(),
+        },
+    ))
+}
+
+/// Applies a previous version of the theme to the payment links of the profile
+#[instrument(skip_all)]
+pub async fn activate_checkout_theme(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: CheckoutThemeActivateRequest,
+) -> RouterResponse<CheckoutThemeResponse> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    let mut versions = get_checkout_theme_versions_from_db(db, &profile_id)
+        .await?
+        .ok_or_else(get_theme_not_found_error)?;
+
+    if versions.get_version(request.version).is_none() {
+        return Err(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!(
+                "Version {} of the checkout theme not found for the profile",
+                request.version
+            ),
+        }
+        .into());
+    }
+
+    versions.active_version = request.version;
+    save_checkout_theme_versions(db, &profile_id, &versions, true).await?;
+
+    let response = versions
+        .get_active_version()
+        .map(|theme_version| versions.get_response(&profile_id, theme_version))
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Active version missing in the checkout theme")?;
+
+    Ok(services::ApplicationResponse::Json(response))
+}
+
+/// Deletes every version of the theme of the profile. The payment links of the profile are then
+/// rendered only with the payment link configuration with which they were created.
+#[instrument(skip_all)]
+pub async fn delete_checkout_theme(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<()> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    db.delete_config_by_key(&profile_id.get_checkout_theme_key())
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(get_theme_not_found_error())
+            } else {
+                error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to delete checkout theme")
+            }
+        })?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+/// Fetches the version of the theme applied to the payment links of the profile
+pub async fn get_active_checkout_theme(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Option<CheckoutTheme>> {
+    Ok(get_checkout_theme_versions(db, profile_id)
+        .await?
+        .and_then(|versions| {
+            versions
+                .get_active_version()
+                .map(|theme_version| theme_version.theme.clone())
+        }))
+}
+
+/// Applies the theme over the payment link configuration with which the payment link was created
+pub fn apply_checkout_theme(payment_link_config: &mut PaymentLinkConfig, theme: &CheckoutTheme) {
+    if let Some(logo) = &theme.logo {
+        payment_link_config.logo.clone_from(logo);
+    }
+
+    if let Some(colors) = &theme.colors {
+        if let Some(primary) = &colors.primary {
+            payment_link_config.theme.clone_from(primary);
+        }
+        if colors.background.is_some() {
+            payment_link_config
+                .background_colour
+                .clone_from(&colors.background);
+        }
+        if colors.button.is_some() {
+            payment_link_config
+                .payment_button_colour
+                .clone_from(&colors.button);
+        }
+        if colors.button_text.is_some() {
+            payment_link_config
+                .payment_button_text_colour
+                .clone_from(&colors.button_text);
+        }
+    }
+
+    if let Some(layout) = &theme.layout {
+        if let Some(sdk_layout) = &layout.sdk_layout {
+            payment_link_config.sdk_layout.clone_from(sdk_layout);
+        }
+        if layout.details_layout.is_some() {
+            payment_link_config.details_layout = layout.details_layout;
+        }
+        if layout
+            .visible_sections
+            .as_ref()
+            .is_some_and(|visible_sections| visible_sections.is_empty())
+        {
+            payment_link_config.display_sdk_only = true;
+        }
+    }
+
+    if !theme.is_section_visible(CheckoutSection::TransactionDetails) {
+        payment_link_config.transaction_details = None;
+    }
+}
+
+/// Locale in which the payment link is rendered. The default locale of the theme is used when the
+/// payer did not request a locale other than the default one.
+pub fn get_checkout_locale(requested_locale: &str, theme: Option<&CheckoutTheme>) -> String {
+    theme
+        .and_then(|theme| theme.default_locale.clone())
+        .filter(|_| requested_locale == DEFAULT_LOCALE)
+        .unwrap_or_else(|| requested_locale.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use api_models::payments::CheckoutThemeColors;
+
+    use super::*;
+
+    fn get_theme(primary: &str) -> CheckoutTheme {
+        CheckoutTheme {
+            colors: Some(CheckoutThemeColors {
+                primary: Some(primary.to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_add_version_activates_the_new_version() {
+        let now = common_utils::date_time::now();
+        let mut versions = CheckoutThemeVersions::default();
+        versions.add_version(get_theme("#000000"), now);
+        versions.add_version(get_theme("#111111"), now);
+
+        assert_eq!(versions.active_version, 2);
+        assert_eq!(
+            versions
+                .get_active_version()
+                .map(|theme_version| theme_version.theme.clone()),
+            Some(get_theme("#111111"))
+        );
+
+        // A previous version activated again stays the base of the numbering of the new versions
+        versions.active_version = 1;
+        versions.add_version(get_theme("#222222"), now);
+        assert_eq!(versions.active_version, 3);
+    }
+
+    #[test]
+    fn test_add_version_drops_the_oldest_versions() {
+        let now = common_utils::date_time::now();
+        let mut versions = CheckoutThemeVersions::default();
+        for _ in 0..consts::MAX_CHECKOUT_THEME_VERSIONS + 2 {
+            versions.add_version(get_theme("#000000"), now);
+        }
+
+        assert_eq!(versions.versions.len(), consts::MAX_CHECKOUT_THEME_VERSIONS);
+        assert!(versions.get_version(1).is_none());
+        assert!(versions.get_version(2).is_none());
+        assert!(versions.get_version(3).is_some());
+        assert_eq!(
+            versions.active_version,
+            u32::try_from(consts::MAX_CHECKOUT_THEME_VERSIONS + 2).unwrap_or_default()
+        );
+    }
+
+    #[test]
+    fn test_get_checkout_locale() {
+        let theme = CheckoutTheme {
+            default_locale: Some("fr".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(get_checkout_locale(DEFAULT_LOCALE, Some(&theme)), "fr");
+        assert_eq!(get_checkout_locale("de", Some(&theme)), "de");
+        assert_eq!(get_checkout_locale(DEFAULT_LOCALE, None), DEFAULT_LOCALE);
+    }
+}
@@ -3,8 +3,45 @@ pub mod cards;
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
 ))]
+pub mod consent;
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+pub mod cvv_recollection;
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+pub mod deferred_vaulting;
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "customer_v2"),
+    not(feature = "payment_methods_v2")
+))]
+pub mod display_config;
+#[cfg(feature = "v1")]
+pub mod display_names;
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+pub mod external_vault;
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
 pub mod migration;
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+pub mod network_token_preference;
 pub mod network_tokenization;
+#[cfg(feature = "v1")]
+pub mod preferences;
+#[cfg(feature = "v1")]
+pub mod required_fields;
 pub mod surcharge_decision_configs;
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
@@ -64,6 +101,8 @@ use super::{
     pm_auth,
 };
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+use crate::core::data_residency;
 use crate::{
     configs::settings,
     core::{payment_methods::transformers as pm_transforms, payments as payments_core},
@@ -345,7 +384,8 @@ pub async fn render_pm_collect_link(
                 })?;
                 // Fetch customer
 
-                let customer = db
+                let customer = data_residency::get_pii_store(&state, &req.merchant_id)
+                    .await?
                     .find_customer_by_customer_id_merchant_id(
                         &(&state).into(),
                         &customer_id,
@@ -698,6 +738,28 @@ pub async fn retrieve_payment_method_with_token(
             payment_method_data: None,
             payment_method_id: None,
         },
+
+        storage::PaymentTokenData::PermanentBankDebit(bank_debit_token) => {
+            let payment_method_info = payment_method_info
+                .get_required_value("PaymentMethod")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("PaymentMethod not found")?;
+
+            let bank_debit = cards::get_bank_debit_from_hs_locker(
+                state,
+                merchant_key_store,
+                &payment_method_info.customer_id,
+                &payment_method_info.merchant_id,
+                &bank_debit_token.locker_id,
+            )
+            .await?;
+
+            storage::PaymentMethodDataWithId {
+                payment_method_data: Some(domain::PaymentMethodData::BankDebit(bank_debit.into())),
+                payment_method: Some(enums::PaymentMethod::BankDebit),
+                payment_method_id: Some(bank_debit_token.payment_method_id.clone()),
+            }
+        }
     };
     Ok(token)
 }
@@ -796,6 +858,7 @@ pub(crate) async fn get_payment_method_create_request(
                         bank_transfer: None,
                         #[cfg(feature = "payouts")]
                         wallet: None,
+                        bank_debit: None,
                         card: Some(card_detail),
                         metadata: None,
                         customer_id: customer_id.clone(),
@@ -822,6 +885,7 @@ pub(crate) async fn get_payment_method_create_request(
                         bank_transfer: None,
                         #[cfg(feature = "payouts")]
                         wallet: None,
+                        bank_debit: None,
                         card: None,
                         metadata: None,
                         customer_id: customer_id.clone(),
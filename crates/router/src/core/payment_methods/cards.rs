@@ -73,20 +73,16 @@ use super::surcharge_decision_configs::{
     not(feature = "payment_methods_v2")
 ))]
 use super::tokenize::NetworkTokenizationProcess;
+#[cfg(feature = "v1")]
+use crate::core::data_residency;
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
 ))]
 use crate::core::payment_methods::{
-    add_payment_method_status_update_task, tokenize,
+    add_payment_method_status_update_task, consent, tokenize,
     utils::{get_merchant_pm_filter_graph, make_pm_graph, refresh_pm_filters_cache},
 };
-#[cfg(all(
-    any(feature = "v2", feature = "v1"),
-    not(feature = "payment_methods_v2"),
-    not(feature = "customer_v2")
-))]
-use crate::routes::app::SessionStateInfo;
 #[cfg(feature = "payouts")]
 use crate::types::domain::types::AsyncLift;
 use crate::{
@@ -121,6 +117,15 @@ use crate::{
     consts as router_consts, core::payment_methods as pm_core, headers,
     types::payment_methods as pm_types, utils::ConnectorResponseExt,
 };
+#[cfg(all(
+    any(feature = "v2", feature = "v1"),
+    not(feature = "payment_methods_v2"),
+    not(feature = "customer_v2")
+))]
+use crate::{
+    core::payment_methods::{display_config, preferences, required_fields},
+    routes::app::SessionStateInfo,
+};
 
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
@@ -151,7 +156,8 @@ pub async fn create_payment_method(
     network_token_payment_method_data: crypto::OptionalEncryptableValue,
 ) -> errors::CustomResult<domain::PaymentMethod, errors::ApiErrorResponse> {
     let db = &*state.store;
-    let customer = db
+    let customer = data_residency::get_pii_store(state, merchant_id)
+        .await?
         .find_customer_by_customer_id_merchant_id(
             &state.into(),
             customer_id,
@@ -242,7 +248,7 @@ pub fn store_default_payment_method(
     api::PaymentMethodResponse,
     Option<payment_methods::DataDuplicationCheck>,
 ) {
-    let pm_id = generate_id(consts::ID_LENGTH, "pm");
+    let pm_id = common_utils::generate_resource_id(consts::ID_LENGTH, "pm");
     let payment_method_response = api::PaymentMethodResponse {
         merchant_id: merchant_id.to_owned(),
         customer_id: Some(customer_id.to_owned()),
@@ -259,6 +265,7 @@ pub fn store_default_payment_method(
         payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]),
         last_used_at: Some(common_utils::date_time::now()),
         client_secret: None,
+        consent: None,
     };
 
     (payment_method_response, None)
@@ -316,7 +323,10 @@ pub async fn get_or_insert_payment_method(
 
                 match &existing_pm_by_locker_id {
                     Ok(pm) => payment_method_id.clone_from(pm.get_id()),
-                    Err(_) => payment_method_id = generate_id(consts::ID_LENGTH, "pm"),
+                    Err(_) => {
+                        payment_method_id =
+                            common_utils::generate_resource_id(consts::ID_LENGTH, "pm")
+                    }
                 };
                 existing_pm_by_locker_id
             } else {
@@ -762,7 +772,8 @@ pub async fn skip_locker_call_and_migrate_payment_method(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Unable to encrypt Payment method billing address")?;
 
-    let customer = db
+    let customer = data_residency::get_pii_store(state, &merchant_id)
+        .await?
         .find_customer_by_customer_id_merchant_id(
             &state.into(),
             &customer_id,
@@ -788,7 +799,7 @@ pub async fn skip_locker_call_and_migrate_payment_method(
 
     let network_transaction_id = req.network_transaction_id.clone();
 
-    let payment_method_id = generate_id(consts::ID_LENGTH, "pm");
+    let payment_method_id = common_utils::generate_resource_id(consts::ID_LENGTH, "pm");
 
     let current_time = common_utils::date_time::now();
 
@@ -1059,7 +1070,7 @@ pub async fn get_client_secret_or_add_payment_method(
     if condition {
         Box::pin(add_payment_method(state, req, merchant_account, key_store)).await
     } else {
-        let payment_method_id = generate_id(consts::ID_LENGTH, "pm");
+        let payment_method_id = common_utils::generate_resource_id(consts::ID_LENGTH, "pm");
 
         let res = create_payment_method(
             state,
@@ -1152,7 +1163,7 @@ pub async fn get_client_secret_or_add_payment_method_for_migration(
         ))
         .await
     } else {
-        let payment_method_id = generate_id(consts::ID_LENGTH, "pm");
+        let payment_method_id = common_utils::generate_resource_id(consts::ID_LENGTH, "pm");
 
         let res = create_payment_method(
             state,
@@ -1279,7 +1290,8 @@ pub async fn add_payment_method_data(
 
     let customer_id = payment_method.customer_id.clone();
 
-    let customer = db
+    let customer = data_residency::get_pii_store(&state, merchant_account.get_id())
+        .await?
         .find_customer_by_customer_id_merchant_id(
             &(&state).into(),
             &customer_id,
@@ -1528,6 +1540,24 @@ pub async fn add_payment_method(
                 merchant_id,
             )),
         },
+        api_enums::PaymentMethod::BankDebit => match req.bank_debit.clone() {
+            Some(bank_debit) => add_bank_debit_to_locker(
+                state,
+                req.clone(),
+                merchant_account,
+                key_store,
+                &bank_debit,
+                &customer_id,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Add Bank Debit Failed"),
+            _ => Ok(store_default_payment_method(
+                &req,
+                &customer_id,
+                merchant_id,
+            )),
+        },
         _ => Ok(store_default_payment_method(
             &req,
             &customer_id,
@@ -1670,12 +1700,14 @@ pub async fn add_payment_method(
 
             let locker_id = if resp.payment_method == Some(api_enums::PaymentMethod::Card)
                 || resp.payment_method == Some(api_enums::PaymentMethod::BankTransfer)
+                || (resp.payment_method == Some(api_enums::PaymentMethod::BankDebit)
+                    && req.bank_debit.is_some())
             {
                 Some(resp.payment_method_id)
             } else {
                 None
             };
-            resp.payment_method_id = generate_id(consts::ID_LENGTH, "pm");
+            resp.payment_method_id = common_utils::generate_resource_id(consts::ID_LENGTH, "pm");
             let pm = insert_payment_method(
                 state,
                 &resp,
@@ -1937,7 +1969,7 @@ pub async fn save_migration_payment_method(
             } else {
                 None
             };
-            resp.payment_method_id = generate_id(consts::ID_LENGTH, "pm");
+            resp.payment_method_id = common_utils::generate_resource_id(consts::ID_LENGTH, "pm");
             let pm = insert_payment_method(
                 state,
                 &resp,
@@ -2006,10 +2038,15 @@ pub async fn insert_payment_method(
         .card
         .clone()
         .map(|card| PaymentMethodsData::Card(CardDetailsPaymentMethod::from(card.clone())));
+    let pm_data = match (pm_card_details, req.bank_debit.as_ref()) {
+        (None, Some(bank_debit)) => {
+            Some(get_bank_debit_payment_methods_data(bank_debit, key_store)?)
+        }
+        (pm_card_details, _) => pm_card_details,
+    };
     let key_manager_state = state.into();
-    let pm_data_encrypted: crypto::OptionalEncryptableValue = pm_card_details
-        .clone()
-        .async_map(|pm_card| create_encrypted_data(&key_manager_state, key_store, pm_card))
+    let pm_data_encrypted: crypto::OptionalEncryptableValue = pm_data
+        .async_map(|pm_data| create_encrypted_data(&key_manager_state, key_store, pm_data))
         .await
         .transpose()
         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -2173,6 +2210,7 @@ pub async fn update_customer_payment_method(
                 card: Some(updated_card_details.clone()),
                 #[cfg(feature = "payouts")]
                 wallet: None,
+                bank_debit: None,
                 metadata: None,
                 customer_id: Some(pm.customer_id.clone()),
                 client_secret: pm.client_secret.clone(),
@@ -2280,6 +2318,7 @@ pub async fn update_customer_payment_method(
                 payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]),
                 last_used_at: Some(common_utils::date_time::now()),
                 client_secret: pm.client_secret.clone(),
+                consent: None,
             }
         };
 
@@ -2410,6 +2449,7 @@ pub async fn add_bank_to_locker(
         payment_methods::StoreLockerReq::LockerGeneric(payment_methods::StoreGenericReq {
             merchant_id: merchant_account.get_id().to_owned(),
             merchant_customer_id: customer_id.to_owned(),
+            requestor_card_reference: None,
             enc_data,
             ttl: state.conf.locker.ttl_for_storage_in_secs,
         });
@@ -2429,6 +2469,135 @@ pub async fn add_bank_to_locker(
     Ok((payment_method_resp, store_resp.duplication_check))
 }
 
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+pub async fn add_bank_debit_to_locker(
+    state: &routes::SessionState,
+    req: api::PaymentMethodCreate,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    bank_debit: &api::BankDebitDetail,
+    customer_id: &id_type::CustomerId,
+) -> errors::CustomResult<
+    (
+        api::PaymentMethodResponse,
+        Option<payment_methods::DataDuplicationCheck>,
+    ),
+    errors::VaultError,
+> {
+    let bank_debit_data = bank_debit
+        .encode_to_string_of_json()
+        .change_context(errors::VaultError::SavePaymentMethodFailed)
+        .attach_printable("Unable to encode bank debit details")?;
+
+    let encrypted_data = domain::types::crypto_operation(
+        &state.into(),
+        type_name!(payment_method::PaymentMethod),
+        domain::types::CryptoOperation::Encrypt(Secret::new(bank_debit_data)),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::VaultError::SavePaymentMethodFailed)
+    .attach_printable("Failed to encrypt bank debit details")?;
+    let enc_data = hex::encode(Encryption::from(encrypted_data).into_inner().peek());
+
+    let payload =
+        payment_methods::StoreLockerReq::LockerGeneric(payment_methods::StoreGenericReq {
+            merchant_id: merchant_account.get_id().to_owned(),
+            merchant_customer_id: customer_id.to_owned(),
+            requestor_card_reference: None,
+            enc_data,
+            ttl: state.conf.locker.ttl_for_storage_in_secs,
+        });
+    let store_resp = add_card_to_hs_locker(
+        state,
+        &payload,
+        customer_id,
+        api_enums::LockerChoice::HyperswitchCardVault,
+    )
+    .await?;
+    let payment_method_resp = payment_methods::mk_add_bank_debit_response_hs(
+        bank_debit,
+        store_resp.card_reference,
+        req,
+        merchant_account.get_id(),
+    );
+    Ok((payment_method_resp, store_resp.duplication_check))
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+pub async fn get_bank_debit_from_hs_locker(
+    state: &routes::SessionState,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: &id_type::CustomerId,
+    merchant_id: &id_type::MerchantId,
+    locker_id: &str,
+) -> errors::RouterResult<api::BankDebitDetail> {
+    let bank_debit_data = get_payment_method_from_hs_locker(
+        state,
+        key_store,
+        customer_id,
+        merchant_id,
+        locker_id,
+        None,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Error getting bank debit details from locker")?;
+
+    bank_debit_data
+        .peek()
+        .to_string()
+        .parse_struct("BankDebitDetail")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse bank debit details from locker")
+}
+
+/// Masked details of a vaulted bank account, stored along with the payment method
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+pub fn get_bank_debit_payment_methods_data(
+    bank_debit: &api::BankDebitDetail,
+    key_store: &domain::MerchantKeyStore,
+) -> errors::RouterResult<PaymentMethodsData> {
+    let hash = hex::encode(
+        crypto::SignMessage::sign_message(
+            &crypto::HmacSha256,
+            key_store.key.get_inner().peek(),
+            bank_debit.get_fingerprint_source().as_bytes(),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate the bank account fingerprint")?,
+    );
+
+    Ok(PaymentMethodsData::BankDetails(
+        api_models::payment_methods::PaymentMethodDataBankCreds {
+            mask: bank_debit.get_mask(),
+            hash,
+            account_type: match bank_debit {
+                api::BankDebitDetail::Ach { bank_type, .. } => {
+                    bank_type.map(|bank_type| bank_type.to_string())
+                }
+                api::BankDebitDetail::Sepa { .. } => None,
+            },
+            account_name: bank_debit
+                .get_bank_account_holder_name()
+                .map(|name| name.peek().to_owned()),
+            payment_method_type: bank_debit.get_payment_method_type(),
+            connector_details: Vec::new(),
+        },
+    ))
+}
+
 /// The response will be the tuple of PaymentMethodResponse and the duplication check of payment_method
 pub async fn add_card_to_locker(
     state: &routes::SessionState,
@@ -2481,6 +2650,21 @@ pub async fn get_card_from_locker(
 ) -> errors::RouterResult<Card> {
     metrics::GET_FROM_LOCKER.add(1, &[]);
 
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    if let Some(vault_reference) =
+        super::external_vault::ExternalVaultReference::from_locker_id(card_reference)
+    {
+        return super::external_vault::get_card_from_external_vault(
+            state,
+            merchant_id,
+            &vault_reference,
+        )
+        .await;
+    }
+
     let get_card_from_rs_locker_resp = common_utils::metrics::utils::record_operation_time(
         async {
             get_card_from_hs_locker(
@@ -2517,6 +2701,21 @@ pub async fn delete_card_from_locker(
 ) -> errors::RouterResult<payment_methods::DeleteCardResp> {
     metrics::DELETE_FROM_LOCKER.add(1, &[]);
 
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    if let Some(vault_reference) =
+        super::external_vault::ExternalVaultReference::from_locker_id(card_reference)
+    {
+        return super::external_vault::delete_card_from_external_vault(
+            state,
+            merchant_id,
+            &vault_reference,
+        )
+        .await;
+    }
+
     common_utils::metrics::utils::record_operation_time(
         async move {
             delete_card_from_hs_locker(state, customer_id, merchant_id, card_reference)
@@ -2561,6 +2760,26 @@ pub async fn add_card_hs(
     ),
     errors::VaultError,
 > {
+    // The card of a payment method stored in the external vault of a profile is replaced in that
+    // vault
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    if let Some(vault_reference) =
+        card_reference.and_then(super::external_vault::ExternalVaultReference::from_locker_id)
+    {
+        return super::external_vault::add_card_to_external_vault(
+            state,
+            &vault_reference,
+            req,
+            card,
+            customer_id,
+            merchant_account.get_id(),
+        )
+        .await;
+    }
+
     let payload = payment_methods::StoreLockerReq::LockerCard(payment_methods::StoreCardReq {
         merchant_id: merchant_account.get_id().to_owned(),
         merchant_customer_id: customer_id.to_owned(),
@@ -2629,13 +2848,18 @@ pub async fn get_payment_method_from_hs_locker<'a>(
     payment_method_reference: &'a str,
     locker_choice: Option<api_enums::LockerChoice>,
 ) -> errors::CustomResult<Secret<String>, errors::VaultError> {
-    let locker = &state.conf.locker;
+    #[cfg(feature = "v1")]
+    let locker = data_residency::get_locker(state, merchant_id)
+        .await
+        .change_context(errors::VaultError::FetchPaymentMethodFailed)?;
+    #[cfg(feature = "v2")]
+    let locker = std::borrow::Cow::Borrowed(&state.conf.locker);
     let jwekey = state.conf.jwekey.get_inner();
 
     let payment_method_data = if !locker.mock_locker {
         let request = payment_methods::mk_get_card_request_hs(
             jwekey,
-            locker,
+            &locker,
             customer_id,
             merchant_id,
             payment_method_reference,
@@ -2685,13 +2909,18 @@ pub async fn add_card_to_hs_locker(
     customer_id: &id_type::CustomerId,
     locker_choice: api_enums::LockerChoice,
 ) -> errors::CustomResult<payment_methods::StoreCardRespPayload, errors::VaultError> {
-    let locker = &state.conf.locker;
+    #[cfg(feature = "v1")]
+    let locker = data_residency::get_locker(state, payload.get_merchant_id())
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)?;
+    #[cfg(feature = "v2")]
+    let locker = std::borrow::Cow::Borrowed(&state.conf.locker);
     let jwekey = state.conf.jwekey.get_inner();
     let db = &*state.store;
     let stored_card_response = if !locker.mock_locker {
         let request = payment_methods::mk_add_locker_request_hs(
             jwekey,
-            locker,
+            &locker,
             payload,
             locker_choice,
             state.tenant.tenant_id.clone(),
@@ -2872,13 +3101,18 @@ pub async fn get_card_from_hs_locker<'a>(
     card_reference: &'a str,
     locker_choice: api_enums::LockerChoice,
 ) -> errors::CustomResult<Card, errors::VaultError> {
-    let locker = &state.conf.locker;
+    #[cfg(feature = "v1")]
+    let locker = data_residency::get_locker(state, merchant_id)
+        .await
+        .change_context(errors::VaultError::FetchCardFailed)?;
+    #[cfg(feature = "v2")]
+    let locker = std::borrow::Cow::Borrowed(&state.conf.locker);
     let jwekey = &state.conf.jwekey.get_inner();
 
     if !locker.mock_locker {
         let request = payment_methods::mk_get_card_request_hs(
             jwekey,
-            locker,
+            &locker,
             customer_id,
             merchant_id,
             card_reference,
@@ -2920,12 +3154,17 @@ pub async fn delete_card_from_hs_locker<'a>(
     merchant_id: &id_type::MerchantId,
     card_reference: &'a str,
 ) -> errors::CustomResult<payment_methods::DeleteCardResp, errors::VaultError> {
-    let locker = &state.conf.locker;
+    #[cfg(feature = "v1")]
+    let locker = data_residency::get_locker(state, merchant_id)
+        .await
+        .change_context(errors::VaultError::DeleteCardFailed)?;
+    #[cfg(feature = "v2")]
+    let locker = std::borrow::Cow::Borrowed(&state.conf.locker);
     let jwekey = &state.conf.jwekey.get_inner();
 
     let request = payment_methods::mk_delete_card_request_hs(
         jwekey,
-        locker,
+        &locker,
         customer_id,
         merchant_id,
         card_reference,
@@ -3199,13 +3438,6 @@ pub fn get_banks(
     }
 }
 
-fn get_val(str: String, val: &serde_json::Value) -> Option<String> {
-    str.split('.')
-        .try_fold(val, |acc, x| acc.get(x))
-        .and_then(|v| v.as_str())
-        .map(|s| s.to_string())
-}
-
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
     not(feature = "customer_v2"),
@@ -3278,22 +3510,24 @@ pub async fn list_payment_methods(
         .transpose()?
         .flatten();
 
+    let pii_db = data_residency::get_pii_store(&state, merchant_account.get_id()).await?;
     let customer = payment_intent
         .as_ref()
         .async_and_then(|pi| async {
             pi.customer_id
                 .as_ref()
                 .async_and_then(|cust| async {
-                    db.find_customer_by_customer_id_merchant_id(
-                        key_manager_state,
-                        cust,
-                        &pi.merchant_id,
-                        &key_store,
-                        merchant_account.storage_scheme,
-                    )
-                    .await
-                    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)
-                    .ok()
+                    pii_db
+                        .find_customer_by_customer_id_merchant_id(
+                            key_manager_state,
+                            cust,
+                            &pi.merchant_id,
+                            &key_store,
+                            merchant_account.storage_scheme,
+                        )
+                        .await
+                        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)
+                        .ok()
                 })
                 .await
         })
@@ -3754,6 +3988,7 @@ pub async fn list_payment_methods(
             merchant_connector_id: None,
             surcharge_amount: None,
             tax_amount: None,
+            capture_method: None,
         };
 
         state
@@ -3823,60 +4058,33 @@ pub async fn list_payment_methods(
                 field_name: "connector",
             })
             .attach_printable_lazy(|| format!("unable to parse connector name {connector:?}"))?;
-        state.conf.required_fields.0.get(&payment_method).map(
-            |required_fields_hm_for_each_payment_method_type| {
-                required_fields_hm_for_each_payment_method_type
-                    .0
-                    .get(&payment_method_type)
-                    .map(|required_fields_hm_for_each_connector| {
-                        required_fields_hm.entry(payment_method).or_default();
-                        required_fields_hm_for_each_connector
-                            .fields
-                            .get(&connector_variant)
-                            .map(|required_fields_final| {
-                                let mut required_fields_hs = required_fields_final.common.clone();
-                                    if is_cit_transaction {
-                                        required_fields_hs
-                                            .extend(required_fields_final.mandate.clone());
-                                    } else {
-                                        required_fields_hs
-                                            .extend(required_fields_final.non_mandate.clone());
-                                    }
-                                 required_fields_hs = should_collect_shipping_or_billing_details_from_wallet_connector(
-                                    payment_method,
-                                    element.payment_experience.as_ref(),
-                                    business_profile.as_ref(),
-                                    required_fields_hs.clone(),
-                                );
+        if let Some(required_fields_hs) = required_fields::get_required_fields(
+            &state.conf.required_fields,
+            payment_method,
+            payment_method_type,
+            connector_variant,
+            is_cit_transaction,
+        ) {
+            let mut required_fields_hs =
+                should_collect_shipping_or_billing_details_from_wallet_connector(
+                    payment_method,
+                    element.payment_experience.as_ref(),
+                    business_profile.as_ref(),
+                    required_fields_hs,
+                );
 
-                                // get the config, check the enums while adding
-                                {
-                                    for (key, val) in &mut required_fields_hs {
-                                        let temp = req_val
-                                            .as_ref()
-                                            .and_then(|r| get_val(key.to_owned(), r));
-                                        if let Some(s) = temp {
-                                            val.value = Some(s.into())
-                                        };
-                                    }
-                                }
-
-                                let existing_req_fields_hs = required_fields_hm
-                                    .get_mut(&payment_method)
-                                    .and_then(|inner_hm| inner_hm.get_mut(&payment_method_type));
-
-                                // If payment_method_type already exist in required_fields_hm, extend the required_fields hs to existing hs.
-                                if let Some(inner_hs) = existing_req_fields_hs {
-                                    inner_hs.extend(required_fields_hs);
-                                } else {
-                                    required_fields_hm.get_mut(&payment_method).map(|inner_hm| {
-                                        inner_hm.insert(payment_method_type, required_fields_hs)
-                                    });
-                                }
-                            })
-                    })
-            },
-        );
+            if let Some(req_val) = req_val.as_ref() {
+                required_fields::populate_required_field_values(&mut required_fields_hs, req_val);
+            }
+
+            // The required fields of the connectors supporting the same payment method type are merged
+            required_fields_hm
+                .entry(payment_method)
+                .or_default()
+                .entry(payment_method_type)
+                .or_default()
+                .extend(required_fields_hs);
+        }
 
         if let Some(payment_experience) = element.payment_experience {
             if let Some(payment_method_hm) =
@@ -4170,6 +4378,19 @@ pub async fn list_payment_methods(
         });
     }
     let currency = payment_intent.as_ref().and_then(|pi| pi.currency);
+
+    // Apply the ordering and visibility rules configured for the profile, so that every SDK
+    // platform receives the payment methods in the same order
+    display_config::perform_payment_method_display_filtering(
+        db,
+        &profile_id,
+        &mut payment_method_responses,
+        payment_intent.as_ref().map(|pi| pi.amount),
+        billing_address.as_ref().and_then(|address| address.country),
+        currency,
+    )
+    .await;
+
     let skip_external_tax_calculation = payment_intent
         .as_ref()
         .and_then(|intent| intent.skip_external_tax_calculation)
@@ -4845,7 +5066,8 @@ pub async fn list_customer_payment_method(
         })
         .unwrap_or(false);
 
-    let customer = db
+    let customer = data_residency::get_pii_store(state, merchant_account.get_id())
+        .await?
         .find_customer_by_customer_id_merchant_id(
             &state.into(),
             customer_id,
@@ -4907,7 +5129,50 @@ pub async fn list_customer_payment_method(
         .and_then(|business_profile| business_profile.is_connector_agnostic_mit_enabled)
         .unwrap_or(false);
 
+    let payment_method_preferences = preferences::get_customer_payment_method_preferences(
+        db,
+        merchant_account.get_id(),
+        customer_id,
+    )
+    .await
+    .unwrap_or_else(|error| {
+        logger::error!(
+            ?error,
+            "Failed to fetch customer payment method preferences"
+        );
+        None
+    })
+    .unwrap_or_default();
+
+    let default_payment_method_id = payment_method_preferences
+        .get_default_payment_method_id(
+            payment_intent
+                .as_ref()
+                .and_then(|payment_intent| payment_intent.currency),
+            profile_id.as_ref(),
+        )
+        .map(ToOwned::to_owned)
+        .or(customer.default_payment_method_id.clone());
+
+    let revoked_payment_method_ids = consent::get_revoked_payment_method_ids(
+        db,
+        merchant_account.get_id(),
+        resp.iter().map(|pm| pm.payment_method_id.clone()).collect(),
+    )
+    .await?;
+
     for pm in resp.into_iter() {
+        // Disabled payment methods are only hidden from the list, they can still be used for
+        // merchant initiated payments
+        if payment_method_preferences.is_disabled(&pm.payment_method_id) {
+            continue;
+        }
+
+        // Payment methods whose consent was revoked by the customer cannot be used anymore
+        if revoked_payment_method_ids.contains(&pm.payment_method_id) {
+            continue;
+        }
+
         let parent_payment_method_token = generate_id(consts::ID_LENGTH, "token");
 
         let payment_method = pm
@@ -4992,8 +5257,9 @@ pub async fn list_customer_payment_method(
             surcharge_details: None,
             requires_cvv,
             last_used_at: Some(pm.last_used_at),
-            default_payment_method_set: customer.default_payment_method_id.is_some()
-                && customer.default_payment_method_id == Some(pm.payment_method_id),
+            nickname: payment_method_preferences.get_nickname(&pm.payment_method_id),
+            default_payment_method_set: default_payment_method_id.is_some()
+                && default_payment_method_id == Some(pm.payment_method_id),
             billing: payment_method_billing,
         };
         if requires_cvv || mca_enabled {
@@ -5101,23 +5367,29 @@ pub async fn get_pm_list_context(
         }
 
         enums::PaymentMethod::BankDebit => {
-            // Retrieve the pm_auth connector details so that it can be tokenized
-            let bank_account_token_data = get_bank_account_connector_details(pm)
-                .await
-                .unwrap_or_else(|err| {
-                    logger::error!(error=?err);
-                    None
-                });
-
-            bank_account_token_data.map(|data| {
-                let token_data = PaymentTokenData::AuthBankDebit(data);
-
-                PaymentMethodListContext {
-                    card_details: None,
-                    #[cfg(feature = "payouts")]
-                    bank_transfer_details: None,
-                    hyperswitch_token_data: is_payment_associated.then_some(token_data),
+            let token_data = match pm.locker_id.clone() {
+                // Bank accounts that are not linked through a pm_auth connector are stored in locker
+                Some(locker_id) => Some(PaymentTokenData::permanent_bank_debit(
+                    pm.get_id().clone(),
+                    locker_id,
+                )),
+                None => {
+                    // Retrieve the pm_auth connector details so that it can be tokenized
+                    get_bank_account_connector_details(pm)
+                        .await
+                        .unwrap_or_else(|err| {
+                            logger::error!(error=?err);
+                            None
+                        })
+                        .map(PaymentTokenData::AuthBankDebit)
                 }
+            };
+
+            token_data.map(|token_data| PaymentMethodListContext {
+                card_details: None,
+                #[cfg(feature = "payouts")]
+                bank_transfer_details: None,
+                hyperswitch_token_data: is_payment_associated.then_some(token_data),
             })
         }
 
@@ -5527,7 +5799,8 @@ pub async fn set_default_payment_method(
     let key_manager_state = &state.into();
     // check for the customer
     // TODO: customer need not be checked again here, this function can take an optional customer and check for existence of customer based on the optional value
-    let customer = db
+    let pii_db = data_residency::get_pii_store(state, merchant_id).await?;
+    let customer = pii_db
         .find_customer_by_customer_id_merchant_id(
             key_manager_state,
             customer_id,
@@ -5576,7 +5849,7 @@ pub async fn set_default_payment_method(
     };
     // update the db with the default payment method id
 
-    let updated_customer_details = db
+    let updated_customer_details = pii_db
         .update_customer_by_customer_id_merchant_id(
             key_manager_state,
             customer_id.to_owned(),
@@ -5812,6 +6085,8 @@ pub async fn retrieve_payment_method(
     } else {
         None
     };
+    let consent =
+        consent::get_payment_method_consent(db, &pm.merchant_id, &pm.payment_method_id).await?;
     Ok(services::ApplicationResponse::Json(
         api::PaymentMethodResponse {
             merchant_id: pm.merchant_id.clone(),
@@ -5829,6 +6104,7 @@ pub async fn retrieve_payment_method(
             payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]),
             last_used_at: Some(pm.last_used_at),
             client_secret: pm.client_secret,
+            consent,
         },
     ))
 }
@@ -5853,7 +6129,8 @@ pub async fn delete_payment_method(
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
 
-    let customer = db
+    let pii_db = data_residency::get_pii_store(&state, merchant_account.get_id()).await?;
+    let customer = pii_db
         .find_customer_by_customer_id_merchant_id(
             key_manager_state,
             &key.customer_id,
@@ -5913,18 +6190,19 @@ pub async fn delete_payment_method(
         let customer_update = CustomerUpdate::UpdateDefaultPaymentMethod {
             default_payment_method_id: Some(None),
         };
-        db.update_customer_by_customer_id_merchant_id(
-            key_manager_state,
-            key.customer_id,
-            key.merchant_id,
-            customer,
-            customer_update,
-            &key_store,
-            merchant_account.storage_scheme,
-        )
-        .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to update the default payment method id for the customer")?;
+        pii_db
+            .update_customer_by_customer_id_merchant_id(
+                key_manager_state,
+                key.customer_id,
+                key.merchant_id,
+                customer,
+                customer_update,
+                &key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the default payment method id for the customer")?;
     };
 
     Ok(services::ApplicationResponse::Json(
@@ -0,0 +1,412 @@
+//! Records of the consent given by the customers to store their payment methods, retained for the
+//! card-on-file compliance audits. The consent captured while saving a payment method is recorded
+//! along with its later collections and revocations, as events in their own table, of which the
+//! full history is retained. The payment methods whose consent was revoked are neither charged by
+//! the merchant initiated payments nor listed as saved payment methods of the customer.
+
+use std::collections::{HashMap, HashSet};
+
+use api_models::{
+    payment_methods::{
+        PaymentMethodConsent, PaymentMethodConsentAction, PaymentMethodConsentEvent,
+        PaymentMethodConsentRecollectRequest, PaymentMethodConsentResponse,
+        PaymentMethodConsentRevokeRequest, PaymentMethodConsentStatus,
+    },
+    payments::CustomerAcceptance,
+};
+use common_utils::{
+    ext_traits::{Encode, ValueExt},
+    id_type,
+};
+use error_stack::ResultExt;
+use masking::ExposeInterface;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{domain, storage},
+    utils,
+};
+
+/// Consent events of a payment method, in the order in which they were recorded
+#[derive(Debug, Default, Clone)]
+pub struct PaymentMethodConsentRecord {
+    pub history: Vec<PaymentMethodConsentEvent>,
+}
+
+impl PaymentMethodConsentRecord {
+    fn record_event(&mut self, event: PaymentMethodConsentEvent) {
+        self.history.push(event);
+    }
+
+    /// The consent is active unless it was revoked after it was last collected
+    fn get_status(&self) -> PaymentMethodConsentStatus {
+        match self.history.last().map(|event| event.action) {
+            Some(PaymentMethodConsentAction::Revoked) => PaymentMethodConsentStatus::Revoked,
+            Some(
+                PaymentMethodConsentAction::Collected | PaymentMethodConsentAction::Recollected,
+            )
+            | None => PaymentMethodConsentStatus::Active,
+        }
+    }
+
+    fn get_latest_consent(&self) -> Option<PaymentMethodConsentEvent> {
+        self.history
+            .iter()
+            .rev()
+            .find(|event| event.action != PaymentMethodConsentAction::Revoked)
+            .cloned()
+    }
+
+    fn get_consent(&self) -> PaymentMethodConsent {
+        PaymentMethodConsent {
+            status: self.get_status(),
+            latest_consent: self.get_latest_consent(),
+            history: self.history.clone(),
+        }
+    }
+}
+
+fn get_collected_event(
+    action: PaymentMethodConsentAction,
+    customer_acceptance: CustomerAcceptance,
+) -> PaymentMethodConsentEvent {
+    let (ip_address, user_agent) = customer_acceptance
+        .online
+        .map(|online| (online.ip_address, Some(online.user_agent)))
+        .unwrap_or_default();
+    let recorded_at = common_utils::date_time::now();
+
+    PaymentMethodConsentEvent {
+        action,
+        acceptance_type: Some(customer_acceptance.acceptance_type),
+        accepted_at: Some(customer_acceptance.accepted_at.unwrap_or(recorded_at)),
+        ip_address,
+        user_agent,
+        consent_text_version: customer_acceptance.consent_text_version,
+        reason: None,
+        recorded_at,
+    }
+}
+
+/// The consent records of the payment methods, by the ID of the payment method. The payment
+/// methods with no consent event recorded have no record.
+async fn get_payment_method_consent_records(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    payment_method_ids: Vec<String>,
+) -> RouterResult<HashMap<String, PaymentMethodConsentRecord>> {
+    let events = db
+        .find_payment_method_consent_events_by_merchant_id_payment_method_ids(
+            merchant_id,
+            payment_method_ids,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payment method consent events")?;
+
+    let mut records = HashMap::<_, PaymentMethodConsentRecord>::new();
+    for event in events {
+        let consent_event = event
+            .event_data
+            .expose()
+            .parse_value("PaymentMethodConsentEvent")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse payment method consent event")?;
+        records
+            .entry(event.payment_method_id)
+            .or_default()
+            .record_event(consent_event);
+    }
+    Ok(records)
+}
+
+async fn get_payment_method_consent_record(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    payment_method_id: &str,
+) -> RouterResult<Option<PaymentMethodConsentRecord>> {
+    Ok(
+        get_payment_method_consent_records(db, merchant_id, vec![payment_method_id.to_owned()])
+            .await?
+            .remove(payment_method_id),
+    )
+}
+
+async fn add_payment_method_consent_event(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    payment_method_id: &str,
+    event: PaymentMethodConsentEvent,
+) -> RouterResult<PaymentMethodConsentRecord> {
+    let mut record = get_payment_method_consent_record(db, merchant_id, payment_method_id)
+        .await?
+        .unwrap_or_default();
+
+    let event_data = event
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize payment method consent event")?;
+    db.insert_payment_method_consent_event(storage::PaymentMethodConsentEventNew {
+        event_id: common_utils::generate_id(consts::ID_LENGTH, "pmce"),
+        merchant_id: merchant_id.clone(),
+        payment_method_id: payment_method_id.to_owned(),
+        event_data: masking::Secret::new(event_data),
+        recorded_at: event.recorded_at,
+    })
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to insert payment method consent event")?;
+
+    record.record_event(event);
+    Ok(record)
+}
+
+/// Rejects the use of the payment method without the customer, for a merchant initiated payment,
+/// once its consent has been revoked
+pub async fn validate_payment_method_consent_not_revoked(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    payment_method_id: &str,
+) -> RouterResult<()> {
+    let record = get_payment_method_consent_record(db, merchant_id, payment_method_id).await?;
+    utils::when(
+        record.is_some_and(|record| record.get_status() == PaymentMethodConsentStatus::Revoked),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The consent of the customer to store the payment method has been revoked"
+                    .to_string(),
+            })
+        },
+    )?;
+    Ok(())
+}
+
+/// The IDs of the payment methods, among the given ones, whose consent has been revoked
+pub async fn get_revoked_payment_method_ids(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    payment_method_ids: Vec<String>,
+) -> RouterResult<HashSet<String>> {
+    Ok(
+        get_payment_method_consent_records(db, merchant_id, payment_method_ids)
+            .await?
+            .into_iter()
+            .filter(|(_, record)| record.get_status() == PaymentMethodConsentStatus::Revoked)
+            .map(|(payment_method_id, _)| payment_method_id)
+            .collect(),
+    )
+}
+
+/// Returns the consent recorded for the payment method, if any
+pub async fn get_payment_method_consent(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    payment_method_id: &str,
+) -> RouterResult<Option<PaymentMethodConsent>> {
+    Ok(
+        get_payment_method_consent_record(db, merchant_id, payment_method_id)
+            .await?
+            .map(|record| record.get_consent()),
+    )
+}
+
+/// Records the consent captured while saving the payment method during a payment. Failures are
+/// only logged, as the payment method has already been saved.
+#[instrument(skip_all)]
+pub async fn record_payment_method_consent(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_method_id: &str,
+    customer_acceptance: CustomerAcceptance,
+) {
+    let event = get_collected_event(PaymentMethodConsentAction::Collected, customer_acceptance);
+    if let Err(error) =
+        add_payment_method_consent_event(&*state.store, merchant_id, payment_method_id, event).await
+    {
+        logger::error!(?error, "Failed to record the payment method consent");
+    }
+}
+
+async fn find_merchant_payment_method(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_method_id: &str,
+) -> RouterResult<domain::PaymentMethod> {
+    let payment_method = state
+        .store
+        .find_payment_method(
+            &state.into(),
+            key_store,
+            payment_method_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    utils::when(
+        payment_method.merchant_id != *merchant_account.get_id(),
+        || Err(errors::ApiErrorResponse::PaymentMethodNotFound),
+    )?;
+
+    Ok(payment_method)
+}
+
+#[instrument(skip_all)]
+pub async fn recollect_payment_method_consent(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_method_id: String,
+    request: PaymentMethodConsentRecollectRequest,
+) -> RouterResponse<PaymentMethodConsentResponse> {
+    let payment_method =
+        find_merchant_payment_method(&state, &merchant_account, &key_store, &payment_method_id)
+            .await?;
+
+    let event = get_collected_event(
+        PaymentMethodConsentAction::Recollected,
+        request.customer_acceptance,
+    );
+    let record = add_payment_method_consent_event(
+        &*state.store,
+        &payment_method.merchant_id,
+        &payment_method_id,
+        event,
+    )
+    .await?;
+    logger::info!("Payment method consent recollected");
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentMethodConsentResponse {
+            payment_method_id,
+            consent: record.get_consent(),
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn revoke_payment_method_consent(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_method_id: String,
+    request: PaymentMethodConsentRevokeRequest,
+) -> RouterResponse<PaymentMethodConsentResponse> {
+    let payment_method =
+        find_merchant_payment_method(&state, &merchant_account, &key_store, &payment_method_id)
+            .await?;
+
+    let record = get_payment_method_consent_record(
+        &*state.store,
+        &payment_method.merchant_id,
+        &payment_method_id,
+    )
+    .await?;
+    utils::when(
+        record.as_ref().map_or(true, |record| {
+            record.get_status() == PaymentMethodConsentStatus::Revoked
+        }),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The payment method has no active consent to be revoked".to_string(),
+            })
+        },
+    )?;
+
+    let event = PaymentMethodConsentEvent {
+        action: PaymentMethodConsentAction::Revoked,
+        acceptance_type: None,
+        accepted_at: None,
+        ip_address: None,
+        user_agent: None,
+        consent_text_version: None,
+        reason: request.reason,
+        recorded_at: common_utils::date_time::now(),
+    };
+    let record = add_payment_method_consent_event(
+        &*state.store,
+        &payment_method.merchant_id,
+        &payment_method_id,
+        event,
+    )
+    .await?;
+    logger::info!("Payment method consent revoked");
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentMethodConsentResponse {
+            payment_method_id,
+            consent: record.get_consent(),
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use api_models::payments::{AcceptanceType, OnlineMandate};
+
+    use super::*;
+
+    #[test]
+    fn test_consent_is_revoked_until_recollected() {
+        let customer_acceptance = CustomerAcceptance {
+            acceptance_type: AcceptanceType::Online,
+            accepted_at: None,
+            online: Some(OnlineMandate {
+                ip_address: None,
+                user_agent: "Mozilla/5.0".to_string(),
+            }),
+            consent_text_version: Some("v1".to_string()),
+        };
+        let mut record = PaymentMethodConsentRecord::default();
+        record.record_event(get_collected_event(
+            PaymentMethodConsentAction::Collected,
+            customer_acceptance.clone(),
+        ));
+        assert_eq!(record.get_status(), PaymentMethodConsentStatus::Active);
+
+        record.record_event(PaymentMethodConsentEvent {
+            action: PaymentMethodConsentAction::Revoked,
+            acceptance_type: None,
+            accepted_at: None,
+            ip_address: None,
+            user_agent: None,
+            consent_text_version: None,
+            reason: None,
+            recorded_at: common_utils::date_time::now(),
+        });
+        let consent = record.get_consent();
+        assert_eq!(consent.status, PaymentMethodConsentStatus::Revoked);
+        assert_eq!(
+            consent
+                .latest_consent
+                .and_then(|event| event.consent_text_version),
+            Some("v1".to_string())
+        );
+
+        record.record_event(get_collected_event(
+            PaymentMethodConsentAction::Recollected,
+            customer_acceptance.clone(),
+        ));
+        assert_eq!(record.get_status(), PaymentMethodConsentStatus::Active);
+        assert_eq!(record.history.len(), 3);
+
+        // The full history is retained, however long it grows
+        for _ in 0..100 {
+            record.record_event(get_collected_event(
+                PaymentMethodConsentAction::Recollected,
+                customer_acceptance.clone(),
+            ));
+        }
+        assert_eq!(record.history.len(), 103);
+        assert_eq!(
+            record.history.first().map(|event| event.action),
+            Some(PaymentMethodConsentAction::Collected)
+        );
+    }
+}
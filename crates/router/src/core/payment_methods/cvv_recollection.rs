@@ -0,0 +1,315 @@
+use api_models::payment_methods::{CvvRecollectionConfig, CvvTokenizeRequest, CvvTokenizeResponse};
+use common_utils::{
+    encryption::Encryption,
+    ext_traits::{Encode, StringExt},
+    id_type, type_name,
+    types::keymanager::Identifier,
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        payments::helpers as payment_helpers,
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{api::enums as api_enums, domain, storage},
+};
+
+/// The CVV held in redis until the payment is confirmed. The CVV is encrypted with the key of
+/// the merchant and is never written to the database.
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredCvvToken {
+    merchant_id: id_type::MerchantId,
+    payment_id: id_type::PaymentId,
+    payment_token: String,
+    card_cvc: Encryption,
+}
+
+fn get_cvv_token_redis_key(cvv_token: &str) -> String {
+    format!("cvv_token_{cvv_token}")
+}
+
+fn validate_card_cvc(card_cvc: &Secret<String>) -> RouterResult<()> {
+    let card_cvc = card_cvc.peek();
+    if !(3..=4).contains(&card_cvc.len()) || !card_cvc.chars().all(|c| c.is_ascii_digit()) {
+        return Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "card_cvc",
+        }));
+    }
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn upsert_cvv_recollection_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: CvvRecollectionConfig,
+) -> RouterResponse<CvvRecollectionConfig> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    let key = profile_id.get_cvv_recollection_config_key();
+    let serialized_config = request
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize cvv re-collection config")?;
+
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => {
+            db.update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(serialized_config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update cvv re-collection config")?;
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            db.insert_config(configs::ConfigNew {
+                key,
+                config: serialized_config,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert cvv re-collection config")?;
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch cvv re-collection config");
+        }
+    }
+
+    Ok(services::ApplicationResponse::Json(request))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_cvv_recollection_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<CvvRecollectionConfig> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    let config = get_cvv_recollection_config(db, &profile_id)
+        .await?
+        .unwrap_or_default();
+
+    Ok(services::ApplicationResponse::Json(config))
+}
+
+/// The cvv re-collection config of the profile, whose absence is cached as well, since it is looked
+/// up on the confirmation of the payments with saved cards
+pub async fn get_cvv_recollection_config(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Option<CvvRecollectionConfig>> {
+    db.find_config_by_key_unwrap_or(
+        &profile_id.get_cvv_recollection_config_key(),
+        Some("null".to_string()),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch cvv re-collection config")?
+    .config
+    .parse_struct::<Option<CvvRecollectionConfig>>("CvvRecollectionConfig")
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to parse cvv re-collection config")
+}
+
+/// Fails the payment if the CVV of the saved card was not re-collected while the config of the
+/// profile requires it for the connector to which the payment is routed
+pub async fn validate_cvv_recollection(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+    connector: Option<&str>,
+) -> RouterResult<()> {
+    let is_cvv_required = get_cvv_recollection_config(db, profile_id)
+        .await?
+        .is_some_and(|config| config.is_required_for_connector(connector));
+
+    if is_cvv_required {
+        return Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "cvv_token"
+        }))
+        .attach_printable("CVV has to be re-collected for saved card payments of the profile");
+    }
+
+    Ok(())
+}
+
+/// Holds the CVV collected by the SDK for a saved card in redis, and returns a single use token
+/// which can be passed while confirming the payment
+#[instrument(skip_all)]
+pub async fn tokenize_cvv(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: CvvTokenizeRequest,
+) -> RouterResponse<CvvTokenizeResponse> {
+    let payment_intent = payment_helpers::verify_payment_intent_time_and_client_secret(
+        &state,
+        &merchant_account,
+        &key_store,
+        Some(request.client_secret.clone()),
+    )
+    .await?
+    .ok_or(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    validate_card_cvc(&request.card_cvc)?;
+
+    let token_data = payment_helpers::retrieve_payment_token_data(
+        &state,
+        request.payment_token.clone(),
+        Some(api_enums::PaymentMethod::Card),
+    )
+    .await?;
+
+    if !matches!(token_data, storage::PaymentTokenData::PermanentCard(_)) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "payment_token does not refer to a saved card".to_string(),
+        }));
+    }
+
+    let encrypted_card_cvc = domain::types::crypto_operation(
+        &(&state).into(),
+        type_name!(storage::PaymentAttempt),
+        domain::types::CryptoOperation::Encrypt(request.card_cvc),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt the card cvc")?;
+
+    let stored_cvv_token = StoredCvvToken {
+        merchant_id: merchant_account.get_id().clone(),
+        payment_id: payment_intent.payment_id,
+        payment_token: request.payment_token,
+        card_cvc: Encryption::from(encrypted_card_cvc),
+    };
+
+    let cvv_token = common_utils::generate_id(consts::ID_LENGTH, "cvv");
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    redis_conn
+        .serialize_and_set_key_with_expiry(
+            &get_cvv_token_redis_key(&cvv_token).as_str().into(),
+            stored_cvv_token,
+            consts::CVV_TOKEN_EXPIRY_SECONDS,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add cvv token in redis")?;
+
+    Ok(services::ApplicationResponse::Json(CvvTokenizeResponse {
+        cvv_token,
+        expires_at: common_utils::date_time::now()
+            .saturating_add(time::Duration::seconds(consts::CVV_TOKEN_EXPIRY_SECONDS)),
+    }))
+}
+
+/// Returns the CVV held against the token and removes it from redis, so that the token cannot be
+/// used again. The token has to be created for the same payment and payment token.
+#[instrument(skip_all)]
+pub async fn get_cvv_from_token(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &id_type::PaymentId,
+    payment_token: Option<&str>,
+    cvv_token: &str,
+) -> RouterResult<Secret<String>> {
+    let invalid_token_error = || errors::ApiErrorResponse::UnprocessableEntity {
+        message: "cvv_token is invalid or expired".to_owned(),
+    };
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let redis_key = get_cvv_token_redis_key(cvv_token);
+    let stored_cvv_token: StoredCvvToken = redis_conn
+        .get_key::<Option<String>>(&redis_key.as_str().into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the cvv token from redis")?
+        .ok_or_else(|| report!(invalid_token_error()))?
+        .parse_struct("StoredCvvToken")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize the cvv token")?;
+
+    if let Err(error) = redis_conn.delete_key(&redis_key.as_str().into()).await {
+        logger::error!(?error, "Failed to delete the cvv token from redis");
+    }
+
+    if stored_cvv_token.merchant_id != key_store.merchant_id
+        || &stored_cvv_token.payment_id != payment_id
+        || payment_token != Some(stored_cvv_token.payment_token.as_str())
+    {
+        return Err(report!(invalid_token_error()))
+            .attach_printable("cvv token was created for a different payment or payment token");
+    }
+
+    domain::types::crypto_operation::<String, masking::WithType>(
+        &state.into(),
+        type_name!(storage::PaymentAttempt),
+        domain::types::CryptoOperation::Decrypt(stored_cvv_token.card_cvc),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to decrypt the card cvc")
+    .map(|card_cvc| card_cvc.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_card_cvc() {
+        assert!(validate_card_cvc(&Secret::new("123".to_string())).is_ok());
+        assert!(validate_card_cvc(&Secret::new("1234".to_string())).is_ok());
+        assert!(validate_card_cvc(&Secret::new("12".to_string())).is_err());
+        assert!(validate_card_cvc(&Secret::new("12a".to_string())).is_err());
+        assert!(validate_card_cvc(&Secret::new("12345".to_string())).is_err());
+    }
+}
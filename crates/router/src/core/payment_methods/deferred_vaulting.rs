@@ -0,0 +1,306 @@
+//! Deferred vaulting of the cards saved during payments. When the locker is unreachable while a
+//! card is being saved, the payment is completed with a payment method awaiting its card data,
+//! and the card is vaulted later by the scheduler from the temporary locker.
+
+use api_models::payment_methods::{CardDetailsPaymentMethod, PaymentMethodsData};
+use common_utils::{ext_traits::AsyncExt, generate_id_with_default_len, id_type};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use time::Duration;
+
+use super::{
+    cards::create_encrypted_data,
+    vault::{self, Vaultable},
+};
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payments::tokenization,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+};
+
+const DEFERRED_VAULTING_TASK: &str = "DEFERRED_VAULTING";
+const DEFERRED_VAULTING_TAG: &str = "PAYMENT_METHOD";
+
+/// Whether the vaulting of the payment method can be deferred when the locker is unreachable, which
+/// is the case only for the cards
+pub fn is_vaulting_deferrable(
+    deferred_vaulting_enabled: bool,
+    payment_method_data: &domain::PaymentMethodData,
+) -> bool {
+    deferred_vaulting_enabled && matches!(payment_method_data, domain::PaymentMethodData::Card(_))
+}
+
+/// Holds the card details in the temporary locker until they can be vaulted, and returns the
+/// lookup key of the card details
+#[instrument(skip_all)]
+pub async fn store_card_for_deferred_vaulting(
+    state: &SessionState,
+    payment_method_data: &domain::PaymentMethodData,
+    customer_id: &id_type::CustomerId,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<String> {
+    let value1 = payment_method_data
+        .get_value1(Some(customer_id.clone()))
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error getting Value1 for locker")?;
+
+    let value2 = payment_method_data
+        .get_value2(Some(customer_id.clone()))
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error getting Value2 for locker")?;
+
+    vault::create_tokenize(
+        state,
+        value1,
+        Some(value2),
+        generate_id_with_default_len("deferred_vault"),
+        key_store.key.get_inner(),
+    )
+    .await
+}
+
+#[instrument(skip_all)]
+pub async fn add_deferred_vaulting_task(
+    db: &dyn StorageInterface,
+    tracking_data: storage::DeferredVaultingTrackingData,
+) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::DeferredVaultingWorkflow;
+    let process_tracker_id = format!(
+        "{runner}_{DEFERRED_VAULTING_TASK}_{}",
+        tracking_data.payment_method_id
+    );
+    let schedule_time = common_utils::date_time::now().saturating_add(Duration::seconds(
+        consts::DEFERRED_VAULTING_RETRY_INTERVAL_SECONDS,
+    ));
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        DEFERRED_VAULTING_TASK,
+        runner,
+        [DEFERRED_VAULTING_TAG],
+        tracking_data,
+        None,
+        schedule_time,
+        hyperswitch_domain_models::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct DEFERRED_VAULTING process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while inserting DEFERRED_VAULTING task to process_tracker")?;
+
+    Ok(())
+}
+
+/// Schedules the vaulting of the card of the payment method. The card is vaulted right away if the
+/// task cannot be added, and the payment method is deactivated if that fails as well, so that it is
+/// never left awaiting its card data without anything to vault it.
+#[instrument(skip_all)]
+pub async fn schedule_deferred_vaulting(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    tracking_data: storage::DeferredVaultingTrackingData,
+) -> RouterResult<()> {
+    let error = match add_deferred_vaulting_task(&*state.store, tracking_data.clone()).await {
+        Ok(()) => return Ok(()),
+        Err(error) => error,
+    };
+    logger::error!(
+        ?error,
+        "Failed to add deferred vaulting task, vaulting the card right away"
+    );
+
+    let vaulting_result = Box::pin(vault_deferred_payment_method(
+        state,
+        merchant_account,
+        key_store,
+        &tracking_data,
+    ))
+    .await;
+    if let Err(error) = &vaulting_result {
+        logger::error!(?error, "Failed to vault the deferred payment method");
+        deactivate_deferred_payment_method(state, merchant_account, key_store, &tracking_data)
+            .await?;
+    }
+
+    vaulting_result.map(|_| ())
+}
+
+/// Vaults the card held in the temporary locker and activates the payment method with the
+/// locker reference. Returns `false` if the payment method is no longer awaiting its card data.
+#[instrument(skip_all)]
+pub async fn vault_deferred_payment_method(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    tracking_data: &storage::DeferredVaultingTrackingData,
+) -> RouterResult<bool> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+
+    let payment_method = db
+        .find_payment_method(
+            key_manager_state,
+            key_store,
+            &tracking_data.payment_method_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    if payment_method.status != enums::PaymentMethodStatus::AwaitingData {
+        return Ok(false);
+    }
+
+    let business_profile = db
+        .find_business_profile_by_profile_id(
+            key_manager_state,
+            key_store,
+            &tracking_data.profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: tracking_data.profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let (payment_method_data, _) = vault::Vault::get_payment_method_data_from_locker(
+        state,
+        &tracking_data.lookup_key,
+        key_store,
+    )
+    .await?;
+
+    let payment_method_create_request = super::get_payment_method_create_request(
+        payment_method_data.as_ref(),
+        payment_method.get_payment_method_type(),
+        payment_method.get_payment_method_subtype(),
+        &Some(tracking_data.customer_id.clone()),
+        None,
+        None,
+    )
+    .await?;
+
+    let (response, _) = Box::pin(tokenization::save_in_locker(
+        state,
+        merchant_account,
+        key_store,
+        &business_profile,
+        payment_method_create_request,
+    ))
+    .await?;
+
+    let payment_method_data_encrypted = response
+        .card
+        .clone()
+        .map(|card| PaymentMethodsData::Card(CardDetailsPaymentMethod::from(card)))
+        .async_map(|pm_data| create_encrypted_data(key_manager_state, key_store, pm_data))
+        .await
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to encrypt payment method data")?;
+
+    let payment_method_update = storage::PaymentMethodUpdate::AdditionalDataUpdate {
+        payment_method_data: payment_method_data_encrypted.map(Into::into),
+        status: Some(enums::PaymentMethodStatus::Active),
+        locker_id: Some(response.payment_method_id),
+        payment_method: None,
+        payment_method_type: None,
+        payment_method_issuer: None,
+        network_token_requestor_reference_id: None,
+        network_token_locker_id: None,
+        network_token_payment_method_data: None,
+    };
+
+    db.update_payment_method(
+        key_manager_state,
+        key_store,
+        payment_method,
+        payment_method_update,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update the payment method after deferred vaulting")?;
+
+    vault::Vault::delete_locker_payment_method_by_lookup_key(
+        state,
+        &Some(tracking_data.lookup_key.clone()),
+    )
+    .await;
+
+    Ok(true)
+}
+
+/// Marks the payment method as inactive once the card could not be vaulted within the lifetime
+/// of the temporary locker entry, so that it is not listed for future payments
+#[instrument(skip_all)]
+pub async fn deactivate_deferred_payment_method(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    tracking_data: &storage::DeferredVaultingTrackingData,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+
+    let payment_method = db
+        .find_payment_method(
+            key_manager_state,
+            key_store,
+            &tracking_data.payment_method_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    if payment_method.status != enums::PaymentMethodStatus::AwaitingData {
+        return Ok(());
+    }
+
+    logger::error!(
+        payment_method_id = %tracking_data.payment_method_id,
+        "Deactivating the payment method as its card could not be vaulted"
+    );
+
+    db.update_payment_method(
+        key_manager_state,
+        key_store,
+        payment_method,
+        storage::PaymentMethodUpdate::StatusUpdate {
+            status: Some(enums::PaymentMethodStatus::Inactive),
+        },
+        merchant_account.storage_scheme,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to deactivate the payment method")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_the_vaulting_of_cards_is_deferrable() {
+        let card = domain::PaymentMethodData::Card(domain::Card::default());
+        assert!(is_vaulting_deferrable(true, &card));
+        assert!(!is_vaulting_deferrable(false, &card));
+        assert!(!is_vaulting_deferrable(
+            true,
+            &domain::PaymentMethodData::MandatePayment
+        ));
+    }
+}
@@ -0,0 +1,314 @@
+use api_models::payment_methods::{PaymentMethodDisplayConfig, ResponsePaymentMethodsEnabled};
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    id_type,
+    types::MinorUnit,
+};
+use diesel_models::configs;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{api::enums as api_enums, domain},
+};
+
+#[instrument(skip_all)]
+pub async fn upsert_payment_method_display_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: PaymentMethodDisplayConfig,
+) -> RouterResponse<PaymentMethodDisplayConfig> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    request
+        .validate()
+        .change_context(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Invalid payment method display config".to_string(),
+        })?;
+
+    let key = profile_id.get_payment_method_display_config_key();
+    let serialized_config = request
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize payment method display config")?;
+
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => {
+            db.update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(serialized_config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update payment method display config")?;
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            db.insert_config(configs::ConfigNew {
+                key,
+                config: serialized_config,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert payment method display config")?;
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch payment method display config");
+        }
+    }
+
+    Ok(services::ApplicationResponse::Json(request))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_payment_method_display_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<PaymentMethodDisplayConfig> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    let config = get_payment_method_display_config(db, &profile_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Payment method display config not found for the profile".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(config))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_payment_method_display_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<()> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    db.delete_config_by_key(&profile_id.get_payment_method_display_config_key())
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::ApiErrorResponse::GenericNotFoundError {
+                    message: "Payment method display config not found for the profile".to_string(),
+                })
+            } else {
+                error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to delete payment method display config")
+            }
+        })?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+/// The payment method display config of the profile, whose absence is cached as well, since it is
+/// looked up for every payment method list
+pub async fn get_payment_method_display_config(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Option<PaymentMethodDisplayConfig>> {
+    db.find_config_by_key_unwrap_or(
+        &profile_id.get_payment_method_display_config_key(),
+        Some("null".to_string()),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch payment method display config")?
+    .config
+    .parse_struct::<Option<PaymentMethodDisplayConfig>>("PaymentMethodDisplayConfig")
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to parse payment method display config")
+}
+
+/// Orders and filters the payment methods in the list response as per the display config of the
+/// profile. The list is left untouched if the config could not be fetched.
+pub async fn perform_payment_method_display_filtering(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+    payment_methods: &mut Vec<ResponsePaymentMethodsEnabled>,
+    amount: Option<MinorUnit>,
+    country: Option<api_enums::CountryAlpha2>,
+    currency: Option<api_enums::Currency>,
+) {
+    match get_payment_method_display_config(db, profile_id).await {
+        Ok(Some(display_config)) => {
+            display_config.apply(payment_methods, amount, country, currency);
+        }
+        Ok(None) => {}
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Skipping payment method display filtering as the config could not be fetched"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::indexing_slicing)]
+    use api_models::payment_methods::{
+        PaymentMethodDisplayOrder, PaymentMethodVisibilityRule, ResponsePaymentMethodTypes,
+    };
+
+    use super::*;
+
+    fn get_payment_method_type(
+        payment_method_type: api_enums::PaymentMethodType,
+    ) -> ResponsePaymentMethodTypes {
+        ResponsePaymentMethodTypes {
+            payment_method_type,
+            payment_experience: None,
+            card_networks: None,
+            bank_names: None,
+            bank_debits: None,
+            bank_transfers: None,
+            required_fields: None,
+            surcharge_details: None,
+            pm_auth_connector: None,
+        }
+    }
+
+    fn get_payment_methods() -> Vec<ResponsePaymentMethodsEnabled> {
+        vec![
+            ResponsePaymentMethodsEnabled {
+                payment_method: api_enums::PaymentMethod::Card,
+                payment_method_types: vec![
+                    get_payment_method_type(api_enums::PaymentMethodType::Credit),
+                    get_payment_method_type(api_enums::PaymentMethodType::Debit),
+                ],
+            },
+            ResponsePaymentMethodsEnabled {
+                payment_method: api_enums::PaymentMethod::PayLater,
+                payment_method_types: vec![get_payment_method_type(
+                    api_enums::PaymentMethodType::Klarna,
+                )],
+            },
+            ResponsePaymentMethodsEnabled {
+                payment_method: api_enums::PaymentMethod::Wallet,
+                payment_method_types: vec![get_payment_method_type(
+                    api_enums::PaymentMethodType::GooglePay,
+                )],
+            },
+        ]
+    }
+
+    #[test]
+    fn test_display_order_is_applied() {
+        let config = PaymentMethodDisplayConfig {
+            display_order: vec![
+                PaymentMethodDisplayOrder {
+                    payment_method: api_enums::PaymentMethod::Wallet,
+                    payment_method_types: None,
+                },
+                PaymentMethodDisplayOrder {
+                    payment_method: api_enums::PaymentMethod::Card,
+                    payment_method_types: Some(vec![api_enums::PaymentMethodType::Debit]),
+                },
+            ],
+            visibility_rules: vec![],
+        };
+        let mut payment_methods = get_payment_methods();
+        config.apply(&mut payment_methods, None, None, None);
+
+        let order = payment_methods
+            .iter()
+            .map(|pm| pm.payment_method)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            order,
+            vec![
+                api_enums::PaymentMethod::Wallet,
+                api_enums::PaymentMethod::Card,
+                api_enums::PaymentMethod::PayLater,
+            ]
+        );
+        assert_eq!(
+            payment_methods[1].payment_method_types[0].payment_method_type,
+            api_enums::PaymentMethodType::Debit
+        );
+    }
+
+    #[test]
+    fn test_visibility_rules_are_applied() {
+        let config = PaymentMethodDisplayConfig {
+            display_order: vec![],
+            visibility_rules: vec![PaymentMethodVisibilityRule {
+                payment_method: api_enums::PaymentMethod::PayLater,
+                payment_method_type: None,
+                min_amount: None,
+                max_amount: Some(MinorUnit::new(1000)),
+                allowed_countries: Some([api_enums::CountryAlpha2::US].into_iter().collect()),
+                allowed_currencies: None,
+            }],
+        };
+
+        let mut payment_methods = get_payment_methods();
+        config.apply(
+            &mut payment_methods,
+            Some(MinorUnit::new(500)),
+            Some(api_enums::CountryAlpha2::US),
+            None,
+        );
+        assert_eq!(payment_methods.len(), 3);
+
+        let mut payment_methods = get_payment_methods();
+        config.apply(
+            &mut payment_methods,
+            Some(MinorUnit::new(5000)),
+            Some(api_enums::CountryAlpha2::US),
+            None,
+        );
+        assert!(payment_methods
+            .iter()
+            .all(|pm| pm.payment_method != api_enums::PaymentMethod::PayLater));
+
+        let mut payment_methods = get_payment_methods();
+        config.apply(
+            &mut payment_methods,
+            Some(MinorUnit::new(500)),
+            Some(api_enums::CountryAlpha2::DE),
+            None,
+        );
+        assert_eq!(payment_methods.len(), 2);
+    }
+}
@@ -0,0 +1,207 @@
+//! Display names and icons of the payment methods. The names are looked up in the locales requested
+//! in the query or in the `Accept-Language` header, first in the overrides of the profile and then
+//! in the registry, so that the SDKs and the dashboard show the same label for a payment method.
+
+use api_models::payment_methods::{
+    PaymentMethodDisplayNameOverrides, PaymentMethodDisplayNamesRequest,
+    PaymentMethodDisplayNamesResponse,
+};
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        payments::unified_messages,
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::domain,
+};
+
+#[instrument(skip_all)]
+pub async fn upsert_payment_method_display_names(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: PaymentMethodDisplayNameOverrides,
+) -> RouterResponse<PaymentMethodDisplayNameOverrides> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    request.validate().map_err(|error| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: error.current_context().to_string(),
+        })
+    })?;
+
+    let key = profile_id.get_payment_method_display_names_key();
+    let serialized_overrides = request
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize payment method display name overrides")?;
+
+    // The cache holds empty overrides for the profiles without any, so the existence of the
+    // overrides is checked in the database
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => {
+            db.update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(serialized_overrides),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update payment method display name overrides")?;
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            db.insert_config(configs::ConfigNew {
+                key,
+                config: serialized_overrides,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert payment method display name overrides")?;
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch payment method display name overrides");
+        }
+    }
+
+    Ok(services::ApplicationResponse::Json(request))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_payment_method_display_names(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<PaymentMethodDisplayNameOverrides> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    let overrides = get_payment_method_display_name_overrides(db, &profile_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Payment method display name overrides not found for the profile".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(overrides))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_payment_method_display_names(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<()> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    db.delete_config_by_key(&profile_id.get_payment_method_display_names_key())
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::ApiErrorResponse::GenericNotFoundError {
+                    message: "Payment method display name overrides not found for the profile"
+                        .to_string(),
+                })
+            } else {
+                error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to delete payment method display name overrides")
+            }
+        })?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+/// Lists the display names and the icons of all the payment methods in the requested locale, or
+/// else in the locales of the `Accept-Language` header, with the overrides of the profile applied
+#[instrument(skip_all)]
+pub async fn list_payment_method_display_names(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: Option<id_type::ProfileId>,
+    request: PaymentMethodDisplayNamesRequest,
+) -> RouterResponse<PaymentMethodDisplayNamesResponse> {
+    let db = state.store.as_ref();
+    let overrides = match profile_id {
+        Some(profile_id) => {
+            core_utils::validate_and_get_business_profile(
+                db,
+                &(&state).into(),
+                &key_store,
+                Some(&profile_id),
+                merchant_account.get_id(),
+            )
+            .await?;
+            get_payment_method_display_name_overrides(db, &profile_id).await?
+        }
+        None => None,
+    };
+
+    let locales =
+        unified_messages::get_locale_candidates(request.locale.as_deref().unwrap_or(&state.locale));
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentMethodDisplayNamesResponse::from_registry(&locales, overrides.as_ref()),
+    ))
+}
+
+/// Fetches the payment method display name overrides of the profile. This is looked up for every
+/// listing of the display names, so the absence of the overrides is cached as well.
+pub async fn get_payment_method_display_name_overrides(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Option<PaymentMethodDisplayNameOverrides>> {
+    let overrides: PaymentMethodDisplayNameOverrides = db
+        .find_config_by_key_unwrap_or(
+            &profile_id.get_payment_method_display_names_key(),
+            Some("{}".to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payment method display name overrides")?
+        .config
+        .parse_struct("PaymentMethodDisplayNameOverrides")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse payment method display name overrides")?;
+
+    Ok((!overrides.is_empty()).then_some(overrides))
+}
@@ -0,0 +1,637 @@
+use api_models::payment_methods::{Card, ExternalVaultConfigRequest, ExternalVaultConfigResponse};
+use common_utils::{
+    encryption::Encryption,
+    ext_traits::{BytesExt, Encode, StringExt},
+    id_type,
+    request::RequestContent,
+    type_name,
+    types::keymanager::Identifier,
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use masking::{Mask, PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+
+use super::transformers as payment_methods;
+use crate::{
+    configs::settings,
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    headers,
+    routes::SessionState,
+    services,
+    types::{self, api, domain},
+};
+
+/// Prefix of the locker id of the payment methods whose card details are stored in an external
+/// vault. The locker id is of the format `{prefix}{profile_id}:{vault_token}`
+const EXTERNAL_VAULT_LOCKER_ID_PREFIX: &str = "ext_vault:";
+
+/// The external vault config as persisted in the configs table. The api key is encrypted with
+/// the key of the merchant.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredExternalVaultConfig {
+    base_url: url::Url,
+    api_key_header: String,
+    api_key: Encryption,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    modified_at: time::PrimitiveDateTime,
+}
+
+impl StoredExternalVaultConfig {
+    fn get_response(&self, profile_id: id_type::ProfileId) -> ExternalVaultConfigResponse {
+        ExternalVaultConfigResponse {
+            profile_id,
+            base_url: self.base_url.clone(),
+            api_key_header: self.api_key_header.clone(),
+            modified_at: self.modified_at,
+        }
+    }
+}
+
+/// Reference to the card details stored in the external vault of a profile
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalVaultReference {
+    pub profile_id: id_type::ProfileId,
+    pub vault_token: String,
+}
+
+impl ExternalVaultReference {
+    pub fn to_locker_id(&self) -> String {
+        format!(
+            "{EXTERNAL_VAULT_LOCKER_ID_PREFIX}{}:{}",
+            self.profile_id.get_string_repr(),
+            self.vault_token
+        )
+    }
+
+    /// Returns `None` if the locker id does not refer to the external vault
+    pub fn from_locker_id(locker_id: &str) -> Option<Self> {
+        let (profile_id, vault_token) = locker_id
+            .strip_prefix(EXTERNAL_VAULT_LOCKER_ID_PREFIX)?
+            .split_once(':')?;
+
+        Some(Self {
+            profile_id: profile_id.parse().ok()?,
+            vault_token: vault_token.to_owned(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ExternalVaultCard {
+    card_number: cards::CardNumber,
+    card_exp_month: Secret<String>,
+    card_exp_year: Secret<String>,
+    card_holder_name: Option<Secret<String>>,
+}
+
+#[derive(Debug, Serialize)]
+struct ExternalVaultTokenizeRequest {
+    merchant_id: id_type::MerchantId,
+    customer_id: id_type::CustomerId,
+    card: ExternalVaultCard,
+    /// The token under which the card is to be stored, when the card of an existing payment
+    /// method is replaced
+    #[serde(skip_serializing_if = "Option::is_none")]
+    token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExternalVaultTokenizeResponse {
+    token: String,
+    #[serde(default)]
+    is_duplicate: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct ExternalVaultTokenRequest {
+    token: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExternalVaultDetokenizeResponse {
+    card: ExternalVaultCard,
+}
+
+/// Client of the vault hosted by the merchant. The card details are sent to the vault, and only
+/// the token issued by the vault is persisted in the payment method.
+#[derive(Debug, Clone)]
+pub struct ExternalVaultConnector {
+    profile_id: id_type::ProfileId,
+    base_url: url::Url,
+    api_key_header: String,
+    api_key: Secret<String>,
+}
+
+/// Whether the cards can be sent to the vault at the url, which is the case for the vaults served
+/// over https from one of the hosts allowed in the config of the locker
+fn is_external_vault_url_allowed(locker: &settings::Locker, url: &url::Url) -> bool {
+    url.scheme() == "https"
+        && url
+            .host_str()
+            .is_some_and(|host| locker.external_vault_allowed_hosts.contains(host))
+}
+
+impl ExternalVaultConnector {
+    fn get_url(&self, path: &str) -> errors::CustomResult<url::Url, errors::VaultError> {
+        let mut url = self.base_url.clone();
+        url.path_segments_mut()
+            .map_err(|_| report!(errors::VaultError::ApiError))
+            .attach_printable("External vault base url cannot be a base")?
+            .pop_if_empty()
+            .push(path);
+        Ok(url)
+    }
+
+    async fn call_vault_api<T: Serialize + Send + 'static>(
+        &self,
+        state: &SessionState,
+        path: &str,
+        payload: T,
+        flow_name: &str,
+    ) -> errors::CustomResult<types::Response, errors::VaultError> {
+        // The allowed hosts are checked on every call, so that the vaults of the hosts which are no
+        // longer allowed are not reached
+        if !is_external_vault_url_allowed(&state.conf.locker, &self.base_url) {
+            return Err(report!(errors::VaultError::ApiError)).attach_printable_lazy(|| {
+                format!(
+                    "External vault host of profile {} is not allowed",
+                    self.profile_id.get_string_repr()
+                )
+            });
+        }
+
+        let url = self.get_url(path)?;
+        let mut request = services::Request::new(services::Method::Post, url.as_str());
+        request.add_header(headers::CONTENT_TYPE, "application/json".into());
+        request.add_header(
+            &self.api_key_header,
+            self.api_key.peek().clone().into_masked(),
+        );
+        request.add_default_headers();
+        request.set_body(RequestContent::Json(Box::new(payload)));
+
+        services::call_connector_api(state, request, flow_name)
+            .await
+            .change_context(errors::VaultError::ApiError)?
+            .map_err(|error_response| {
+                logger::error!(
+                    status_code = error_response.status_code,
+                    "External vault call failed"
+                );
+                report!(errors::VaultError::ApiError)
+            })
+            .attach_printable_lazy(|| format!("External vault returned an error for {flow_name}"))
+    }
+
+    #[instrument(skip_all)]
+    pub async fn add_card(
+        &self,
+        state: &SessionState,
+        req: api::PaymentMethodCreate,
+        card: &api::CardDetail,
+        customer_id: &id_type::CustomerId,
+        merchant_id: &id_type::MerchantId,
+        vault_token: Option<&str>,
+    ) -> errors::CustomResult<
+        (
+            api::PaymentMethodResponse,
+            Option<payment_methods::DataDuplicationCheck>,
+        ),
+        errors::VaultError,
+    > {
+        let payload = ExternalVaultTokenizeRequest {
+            merchant_id: merchant_id.to_owned(),
+            customer_id: customer_id.to_owned(),
+            card: ExternalVaultCard {
+                card_number: card.card_number.clone(),
+                card_exp_month: card.card_exp_month.clone(),
+                card_exp_year: card.card_exp_year.clone(),
+                card_holder_name: card.card_holder_name.clone(),
+            },
+            token: vault_token.map(str::to_string),
+        };
+        let response: ExternalVaultTokenizeResponse = self
+            .call_vault_api(
+                state,
+                "tokenize",
+                payload,
+                "tokenize_card_in_external_vault",
+            )
+            .await
+            .change_context(errors::VaultError::SaveCardFailed)?
+            .response
+            .parse_struct("ExternalVaultTokenizeResponse")
+            .change_context(errors::VaultError::ResponseDeserializationFailed)?;
+
+        if vault_token.is_some_and(|vault_token| vault_token != response.token) {
+            return Err(report!(errors::VaultError::SaveCardFailed))
+                .attach_printable("External vault stored the card under another token");
+        }
+
+        let vault_reference = ExternalVaultReference {
+            profile_id: self.profile_id.clone(),
+            vault_token: response.token,
+        };
+        let payment_method_response = payment_methods::mk_add_card_response_hs(
+            card.clone(),
+            vault_reference.to_locker_id(),
+            req,
+            merchant_id,
+        );
+        let duplication_check = response
+            .is_duplicate
+            .then_some(payment_methods::DataDuplicationCheck::Duplicated);
+
+        Ok((payment_method_response, duplication_check))
+    }
+
+    #[instrument(skip_all)]
+    pub async fn get_card(
+        &self,
+        state: &SessionState,
+        vault_token: &str,
+    ) -> errors::CustomResult<Card, errors::VaultError> {
+        let response: ExternalVaultDetokenizeResponse = self
+            .call_vault_api(
+                state,
+                "detokenize",
+                ExternalVaultTokenRequest {
+                    token: vault_token.to_owned(),
+                },
+                "detokenize_card_from_external_vault",
+            )
+            .await
+            .change_context(errors::VaultError::FetchCardFailed)?
+            .response
+            .parse_struct("ExternalVaultDetokenizeResponse")
+            .change_context(errors::VaultError::ResponseDeserializationFailed)?;
+
+        Ok(Card {
+            card_number: response.card.card_number,
+            name_on_card: response.card.card_holder_name,
+            card_exp_month: response.card.card_exp_month,
+            card_exp_year: response.card.card_exp_year,
+            card_brand: None,
+            card_isin: None,
+            nick_name: None,
+        })
+    }
+
+    #[instrument(skip_all)]
+    pub async fn delete_card(
+        &self,
+        state: &SessionState,
+        vault_token: &str,
+    ) -> errors::CustomResult<payment_methods::DeleteCardResp, errors::VaultError> {
+        self.call_vault_api(
+            state,
+            "delete",
+            ExternalVaultTokenRequest {
+                token: vault_token.to_owned(),
+            },
+            "delete_card_from_external_vault",
+        )
+        .await
+        .change_context(errors::VaultError::DeleteCardFailed)?;
+
+        Ok(payment_methods::DeleteCardResp {
+            status: "Ok".to_string(),
+            error_message: None,
+            error_code: None,
+        })
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn upsert_external_vault_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: ExternalVaultConfigRequest,
+) -> RouterResponse<ExternalVaultConfigResponse> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    if !is_external_vault_url_allowed(&state.conf.locker, &request.base_url) {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "base_url of the external vault must use https and be on an allowed host"
+                .to_string(),
+        }));
+    }
+
+    let encrypted_api_key = domain::types::crypto_operation(
+        &(&state).into(),
+        type_name!(domain::Profile),
+        domain::types::CryptoOperation::Encrypt(request.api_key),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt the api key of the external vault")?;
+
+    let stored_config = StoredExternalVaultConfig {
+        base_url: request.base_url,
+        api_key_header: request
+            .api_key_header
+            .unwrap_or_else(|| headers::AUTHORIZATION.to_string()),
+        api_key: Encryption::from(encrypted_api_key),
+        modified_at: common_utils::date_time::now(),
+    };
+
+    let key = profile_id.get_external_vault_config_key();
+    let serialized_config = stored_config
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize external vault config")?;
+
+    match db.find_config_by_key(&key).await {
+        Ok(_) => {
+            db.update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(serialized_config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update external vault config")?;
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            db.insert_config(configs::ConfigNew {
+                key,
+                config: serialized_config,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert external vault config")?;
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch external vault config");
+        }
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        stored_config.get_response(profile_id),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_external_vault_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<ExternalVaultConfigResponse> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    let stored_config = get_stored_external_vault_config(db, &profile_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "External vault config not found for the profile".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        stored_config.get_response(profile_id),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_external_vault_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<()> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    // The cards which were already saved in the external vault can no longer be retrieved, as
+    // the config is required to reach the vault
+    db.delete_config_by_key(&profile_id.get_external_vault_config_key())
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::ApiErrorResponse::GenericNotFoundError {
+                    message: "External vault config not found for the profile".to_string(),
+                })
+            } else {
+                error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to delete external vault config")
+            }
+        })?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+async fn get_stored_external_vault_config(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Option<StoredExternalVaultConfig>> {
+    match db
+        .find_config_by_key(&profile_id.get_external_vault_config_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("StoredExternalVaultConfig")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse external vault config")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch external vault config"),
+    }
+}
+
+/// Returns the external vault connector of the profile, if the profile has configured one
+pub async fn get_external_vault_connector(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Option<ExternalVaultConnector>> {
+    let Some(stored_config) =
+        get_stored_external_vault_config(state.store.as_ref(), profile_id).await?
+    else {
+        return Ok(None);
+    };
+
+    let api_key = domain::types::crypto_operation::<String, masking::WithType>(
+        &state.into(),
+        type_name!(domain::Profile),
+        domain::types::CryptoOperation::Decrypt(stored_config.api_key),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to decrypt the api key of the external vault")?
+    .into_inner();
+
+    Ok(Some(ExternalVaultConnector {
+        profile_id: profile_id.clone(),
+        base_url: stored_config.base_url,
+        api_key_header: stored_config.api_key_header,
+        api_key,
+    }))
+}
+
+/// Returns the external vault connector of the profile referred by the vault reference. Fails if
+/// the config of the profile has been removed after the card was stored.
+async fn get_external_vault_connector_for_reference(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    vault_reference: &ExternalVaultReference,
+) -> RouterResult<ExternalVaultConnector> {
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            &state.into(),
+            merchant_id,
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the merchant key store")?;
+
+    get_external_vault_connector(state, &key_store, &vault_reference.profile_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "External vault config not found for profile {}",
+                vault_reference.profile_id.get_string_repr()
+            )
+        })
+}
+
+pub async fn get_card_from_external_vault(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    vault_reference: &ExternalVaultReference,
+) -> RouterResult<Card> {
+    get_external_vault_connector_for_reference(state, merchant_id, vault_reference)
+        .await?
+        .get_card(state, &vault_reference.vault_token)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while getting card from external vault")
+}
+
+/// Stores the card of an existing payment method in the external vault holding it, under the same
+/// token, as its payment method keeps referring to the token
+pub async fn add_card_to_external_vault(
+    state: &SessionState,
+    vault_reference: &ExternalVaultReference,
+    req: api::PaymentMethodCreate,
+    card: &api::CardDetail,
+    customer_id: &id_type::CustomerId,
+    merchant_id: &id_type::MerchantId,
+) -> errors::CustomResult<
+    (
+        api::PaymentMethodResponse,
+        Option<payment_methods::DataDuplicationCheck>,
+    ),
+    errors::VaultError,
+> {
+    get_external_vault_connector_for_reference(state, merchant_id, vault_reference)
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)?
+        .add_card(
+            state,
+            req,
+            card,
+            customer_id,
+            merchant_id,
+            Some(&vault_reference.vault_token),
+        )
+        .await
+}
+
+pub async fn delete_card_from_external_vault(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    vault_reference: &ExternalVaultReference,
+) -> RouterResult<payment_methods::DeleteCardResp> {
+    get_external_vault_connector_for_reference(state, merchant_id, vault_reference)
+        .await?
+        .delete_card(state, &vault_reference.vault_token)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while deleting card from external vault")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_external_vault_reference_round_trip() {
+        let vault_reference = ExternalVaultReference {
+            profile_id: "pro_abcdefghijklmnop".parse().unwrap(),
+            vault_token: "tok_123:456".to_string(),
+        };
+        let locker_id = vault_reference.to_locker_id();
+
+        assert_eq!(
+            ExternalVaultReference::from_locker_id(&locker_id),
+            Some(vault_reference)
+        );
+        assert_eq!(
+            ExternalVaultReference::from_locker_id("card_abcdefghijklmnop"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_external_vault_url_allowed() {
+        let locker = settings::Locker {
+            external_vault_allowed_hosts: ["vault.example.com".to_string()].into(),
+            ..Default::default()
+        };
+        let is_allowed =
+            |url: &str| is_external_vault_url_allowed(&locker, &url::Url::parse(url).unwrap());
+
+        assert!(is_allowed("https://vault.example.com/v1"));
+        assert!(is_allowed("https://VAULT.example.com:8443"));
+        assert!(!is_allowed("http://vault.example.com"));
+        assert!(!is_allowed("https://vault.example.com.attacker.com"));
+        assert!(!is_allowed("https://169.254.169.254/latest/meta-data"));
+    }
+}
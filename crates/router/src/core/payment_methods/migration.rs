@@ -1,14 +1,23 @@
 use actix_multipart::form::{bytes::Bytes, text::Text, MultipartForm};
-use api_models::payment_methods::{PaymentMethodMigrationResponse, PaymentMethodRecord};
+use api_models::payment_methods::{
+    ExternalTokenMigrationRecord, ExternalTokenMigrationRequest, ExternalTokenMigrationResponse,
+    MigrateCardDetail, MigrationStatus, PaymentMethodMigrationResponse, PaymentMethodRecord,
+};
+use common_utils::{ext_traits::BytesExt, request::RequestContent};
 use csv::Reader;
-use error_stack::ResultExt;
-use masking::PeekInterface;
+use error_stack::{report, ResultExt};
+use masking::{Mask, PeekInterface};
 use rdkafka::message::ToBytes;
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
 
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+use crate::core::data_residency;
 use crate::{
+    configs::settings,
+    consts,
     core::{errors, payment_methods::cards::migrate_payment_method},
-    routes, services,
+    headers, routes, services,
     types::{api, domain},
 };
 
@@ -210,3 +219,253 @@ impl Default for RecordMigrationStatusBuilder {
         Self::new()
     }
 }
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Serialize)]
+struct TokenMigrationAdapterRequest {
+    migration_agreement_id: String,
+    token: masking::Secret<String>,
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Deserialize)]
+struct TokenMigrationAdapterResponse {
+    card_number: masking::Secret<String>,
+    card_exp_month: masking::Secret<String>,
+    card_exp_year: masking::Secret<String>,
+    card_holder_name: Option<masking::Secret<String>>,
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[instrument(skip_all)]
+async fn detokenize_with_migration_adapter(
+    state: &routes::SessionState,
+    adapter: &settings::TokenMigrationAdapter,
+    migration_agreement_id: &str,
+    external_token: masking::Secret<String>,
+) -> errors::RouterResult<TokenMigrationAdapterResponse> {
+    let mut request = services::Request::new(services::Method::Post, &adapter.detokenize_url);
+    request.add_header(headers::CONTENT_TYPE, "application/json".into());
+    request.add_header(
+        headers::AUTHORIZATION,
+        adapter.api_key.peek().clone().into_masked(),
+    );
+    request.add_default_headers();
+    request.set_body(RequestContent::Json(Box::new(
+        TokenMigrationAdapterRequest {
+            migration_agreement_id: migration_agreement_id.to_owned(),
+            token: external_token,
+        },
+    )));
+
+    services::call_connector_api(state, request, "detokenize_with_migration_adapter")
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to call the token migration adapter")?
+        .map_err(|error_response| {
+            logger::error!(
+                status_code = error_response.status_code,
+                "Token migration adapter failed to detokenize"
+            );
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "external_token could not be detokenized".to_string(),
+            })
+        })?
+        .response
+        .parse_struct("TokenMigrationAdapterResponse")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the response of the token migration adapter")
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+/// Returns the card details of the record, detokenizing the external token if it is provided
+async fn get_card_details_for_external_token_record(
+    state: &routes::SessionState,
+    adapter: &settings::TokenMigrationAdapter,
+    migration_agreement_id: &str,
+    record: &ExternalTokenMigrationRecord,
+) -> errors::RouterResult<MigrateCardDetail> {
+    let Some(external_token) = record.external_token.clone() else {
+        return record
+            .card
+            .clone()
+            .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "external_token or card",
+            })
+            .map_err(|error| report!(error));
+    };
+
+    let detokenized_card =
+        detokenize_with_migration_adapter(state, adapter, migration_agreement_id, external_token)
+            .await?;
+
+    Ok(match record.card.clone() {
+        Some(card) => MigrateCardDetail {
+            card_number: detokenized_card.card_number,
+            card_exp_month: detokenized_card.card_exp_month,
+            card_exp_year: detokenized_card.card_exp_year,
+            card_holder_name: detokenized_card.card_holder_name.or(card.card_holder_name),
+            ..card
+        },
+        None => MigrateCardDetail {
+            card_number: detokenized_card.card_number,
+            card_exp_month: detokenized_card.card_exp_month,
+            card_exp_year: detokenized_card.card_exp_year,
+            card_holder_name: detokenized_card.card_holder_name,
+            nick_name: None,
+            card_issuing_country: None,
+            card_network: None,
+            card_issuer: None,
+            card_type: None,
+        },
+    })
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+async fn migrate_external_token_record(
+    state: &routes::SessionState,
+    adapter: &settings::TokenMigrationAdapter,
+    migration_agreement_id: &str,
+    record: ExternalTokenMigrationRecord,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+) -> errors::RouterResult<api::PaymentMethodMigrateResponse> {
+    let merchant_id = merchant_account.get_id();
+
+    // Payment methods are only mapped to the customers which are already present
+    data_residency::get_pii_store(state, merchant_id)
+        .await?
+        .find_customer_optional_by_customer_id_merchant_id(
+            &state.into(),
+            &record.customer_id,
+            merchant_id,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the customer")?
+        .ok_or(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let card =
+        get_card_details_for_external_token_record(state, adapter, migration_agreement_id, &record)
+            .await?;
+
+    let migrate_request = api::PaymentMethodMigrate {
+        merchant_id: merchant_id.clone(),
+        payment_method: Some(common_enums::PaymentMethod::Card),
+        payment_method_type: None,
+        payment_method_issuer: None,
+        payment_method_issuer_code: None,
+        card_network: card.card_network.as_ref().map(ToString::to_string),
+        card: Some(card),
+        network_token: record.network_token,
+        metadata: record.metadata,
+        customer_id: Some(record.customer_id),
+        #[cfg(feature = "payouts")]
+        bank_transfer: None,
+        #[cfg(feature = "payouts")]
+        wallet: None,
+        payment_method_data: None,
+        billing: record.billing,
+        connector_mandate_details: None,
+        network_transaction_id: record.network_transaction_id,
+    };
+
+    match Box::pin(migrate_payment_method(
+        state.clone(),
+        migrate_request,
+        merchant_id,
+        merchant_account,
+        key_store,
+    ))
+    .await?
+    {
+        services::ApplicationResponse::Json(response) => Ok(response),
+        _ => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Failed to fetch the payment method migration response"),
+    }
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+/// Imports the payment methods of a migration agreement. The status of every record is returned
+/// individually, so that the failed records can be retried
+#[instrument(skip_all)]
+pub async fn migrate_external_tokens(
+    state: routes::SessionState,
+    request: ExternalTokenMigrationRequest,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+) -> errors::RouterResponse<ExternalTokenMigrationResponse> {
+    let adapter = state
+        .conf
+        .token_migration_adapters
+        .0
+        .get(&request.migration_agreement_id)
+        .filter(|adapter| &adapter.merchant_id == merchant_account.get_id())
+        .cloned()
+        .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+            message: "migration_agreement_id is not configured for the merchant".to_string(),
+        })?;
+
+    if request.records.len() > consts::MAX_EXTERNAL_TOKEN_MIGRATION_RECORDS {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "records cannot contain more than {} entries",
+                consts::MAX_EXTERNAL_TOKEN_MIGRATION_RECORDS
+            ),
+        }));
+    }
+
+    let mut records = Vec::with_capacity(request.records.len());
+    for (index, record) in request.records.into_iter().enumerate() {
+        let line_number = i64::try_from(index).ok().map(|index| index + 1);
+        let customer_id = record.customer_id.clone();
+
+        let result = migrate_external_token_record(
+            &state,
+            &adapter,
+            &request.migration_agreement_id,
+            record,
+            &merchant_account,
+            &key_store,
+        )
+        .await;
+
+        records.push(match result {
+            Ok(response) => PaymentMethodMigrationResponse {
+                line_number,
+                card_number_masked: response
+                    .payment_method_response
+                    .card
+                    .as_ref()
+                    .and_then(|card| card.last4_digits.clone())
+                    .map(masking::Secret::new),
+                payment_method_id: Some(response.payment_method_response.payment_method_id),
+                payment_method: response.payment_method_response.payment_method,
+                payment_method_type: response.payment_method_response.payment_method_type,
+                customer_id: Some(customer_id),
+                migration_status: MigrationStatus::Success,
+                migration_error: None,
+                card_migrated: response.card_migrated,
+                network_token_migrated: response.network_token_migrated,
+                connector_mandate_details_migrated: response.connector_mandate_details_migrated,
+                network_transaction_id_migrated: response.network_transaction_id_migrated,
+            },
+            Err(error) => PaymentMethodMigrationResponse {
+                line_number,
+                customer_id: Some(customer_id),
+                migration_status: MigrationStatus::Failed,
+                migration_error: Some(error.to_string()),
+                ..PaymentMethodMigrationResponse::default()
+            },
+        });
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        ExternalTokenMigrationResponse {
+            migration_agreement_id: request.migration_agreement_id,
+            records,
+        },
+    ))
+}
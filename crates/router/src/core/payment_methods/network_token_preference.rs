@@ -0,0 +1,202 @@
+use api_models::payment_methods::NetworkTokenPreferenceConfig;
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use diesel_models::configs;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{api::enums as api_enums, domain},
+};
+
+fn get_network_token_suspension_redis_key(payment_method_id: &str) -> String {
+    format!("network_token_suspended_{payment_method_id}")
+}
+
+#[instrument(skip_all)]
+pub async fn upsert_network_token_preference_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: NetworkTokenPreferenceConfig,
+) -> RouterResponse<NetworkTokenPreferenceConfig> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    let key = profile_id.get_network_token_preference_config_key();
+    let serialized_config = request
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize network token preference config")?;
+
+    match db.find_config_by_key(&key).await {
+        Ok(_) => {
+            db.update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(serialized_config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update network token preference config")?;
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            db.insert_config(configs::ConfigNew {
+                key,
+                config: serialized_config,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert network token preference config")?;
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch network token preference config");
+        }
+    }
+
+    Ok(services::ApplicationResponse::Json(request))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_network_token_preference_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<NetworkTokenPreferenceConfig> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    let config = get_network_token_preference_config(db, &profile_id)
+        .await?
+        .unwrap_or_default();
+
+    Ok(services::ApplicationResponse::Json(config))
+}
+
+pub async fn get_network_token_preference_config(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Option<NetworkTokenPreferenceConfig>> {
+    match db
+        .find_config_by_key(&profile_id.get_network_token_preference_config_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("NetworkTokenPreferenceConfig")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse network token preference config")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch network token preference config"),
+    }
+}
+
+/// Suspends the use of the network token of the saved card after the tokenization service failed
+/// to provision a cryptogram for it, so that the payments of the card are authorized with the card
+/// number until the suspension lapses
+#[instrument(skip_all)]
+pub async fn suspend_network_token(state: &SessionState, payment_method_id: &str) {
+    let result = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn
+            .set_key_with_expiry(
+                &get_network_token_suspension_redis_key(payment_method_id)
+                    .as_str()
+                    .into(),
+                true,
+                consts::NETWORK_TOKEN_SUSPENSION_SECONDS,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError),
+        Err(error) => Err(error).change_context(errors::ApiErrorResponse::InternalServerError),
+    };
+
+    if let Err(error) = result {
+        logger::error!(
+            ?error,
+            "Failed to suspend the network token of the payment method"
+        );
+    }
+}
+
+async fn is_network_token_suspended(state: &SessionState, payment_method_id: &str) -> bool {
+    let result = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn
+            .exists::<()>(
+                &get_network_token_suspension_redis_key(payment_method_id)
+                    .as_str()
+                    .into(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError),
+        Err(error) => Err(error).change_context(errors::ApiErrorResponse::InternalServerError),
+    };
+
+    result
+        .inspect_err(|error| {
+            logger::error!(
+                ?error,
+                "Failed to check the suspension of the network token"
+            )
+        })
+        .unwrap_or(false)
+}
+
+/// Checks if the saved card has to be authorized with the card number instead of its network
+/// token, either because the profile prefers the card number for the connector to which the
+/// payment is routed, or because the provisioning of the network token is suspended
+#[instrument(skip_all)]
+pub async fn should_authorize_with_pan(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+    connector: Option<api_enums::Connector>,
+    payment_method_id: &str,
+) -> bool {
+    let preference = get_network_token_preference_config(&*state.store, profile_id)
+        .await
+        .inspect_err(|error| {
+            logger::error!(?error, "Failed to get the network token preference config")
+        })
+        .ok()
+        .flatten()
+        .unwrap_or_default()
+        .get_preference_for_connector(connector);
+
+    match preference {
+        api_enums::CardCredentialType::Pan => true,
+        api_enums::CardCredentialType::NetworkToken => {
+            is_network_token_suspended(state, payment_method_id).await
+        }
+    }
+}
@@ -0,0 +1,445 @@
+use std::collections::{HashMap, HashSet};
+
+use api_models::payment_methods::{
+    CustomerPaymentMethodUpdateRequest, CustomerPaymentMethodUpdateResponse,
+    DefaultPaymentMethodChange, DefaultPaymentMethodScope, PaymentMethodPreferenceUpdatedBy,
+};
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use diesel_models::configs;
+use error_stack::ResultExt;
+use hyperswitch_domain_models::customer::CustomerUpdate;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::{
+        data_residency,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{api::enums as api_enums, domain, storage::enums as storage_enums},
+    utils,
+};
+
+/// Customer level preferences for the saved payment methods, stored in the configs table
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct CustomerPaymentMethodPreferences {
+    pub nicknames: HashMap<String, String>,
+    pub disabled_payment_method_ids: HashSet<String>,
+    pub scoped_defaults: Vec<ScopedDefaultPaymentMethod>,
+    pub default_change_history: Vec<DefaultPaymentMethodChange>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ScopedDefaultPaymentMethod {
+    pub scope: DefaultPaymentMethodScope,
+    pub payment_method_id: String,
+}
+
+impl CustomerPaymentMethodPreferences {
+    pub fn get_nickname(&self, payment_method_id: &str) -> Option<String> {
+        self.nicknames.get(payment_method_id).cloned()
+    }
+
+    pub fn is_disabled(&self, payment_method_id: &str) -> bool {
+        self.disabled_payment_method_ids.contains(payment_method_id)
+    }
+
+    /// Returns the most specific scoped default applicable for the given currency and profile
+    pub fn get_default_payment_method_id(
+        &self,
+        currency: Option<api_enums::Currency>,
+        profile_id: Option<&id_type::ProfileId>,
+    ) -> Option<&str> {
+        self.scoped_defaults
+            .iter()
+            .filter(|scoped_default| scoped_default.scope.is_applicable(currency, profile_id))
+            .max_by_key(|scoped_default| scoped_default.scope.get_specificity())
+            .map(|scoped_default| scoped_default.payment_method_id.as_str())
+    }
+
+    fn get_default_scopes(&self, payment_method_id: &str) -> Vec<DefaultPaymentMethodScope> {
+        self.scoped_defaults
+            .iter()
+            .filter(|scoped_default| scoped_default.payment_method_id == payment_method_id)
+            .map(|scoped_default| scoped_default.scope.clone())
+            .collect()
+    }
+
+    /// Sets the payment method as the default for the scope, and returns the previous default
+    fn set_scoped_default(
+        &mut self,
+        scope: DefaultPaymentMethodScope,
+        payment_method_id: String,
+    ) -> Option<String> {
+        match self
+            .scoped_defaults
+            .iter_mut()
+            .find(|scoped_default| scoped_default.scope == scope)
+        {
+            Some(scoped_default) => Some(std::mem::replace(
+                &mut scoped_default.payment_method_id,
+                payment_method_id,
+            )),
+            None => {
+                self.scoped_defaults.push(ScopedDefaultPaymentMethod {
+                    scope,
+                    payment_method_id,
+                });
+                None
+            }
+        }
+    }
+
+    fn get_default_changes(&self, payment_method_id: &str) -> Vec<DefaultPaymentMethodChange> {
+        self.default_change_history
+            .iter()
+            .filter(|change| {
+                change.payment_method_id == payment_method_id
+                    || change.previous_payment_method_id.as_deref() == Some(payment_method_id)
+            })
+            .cloned()
+            .collect()
+    }
+
+    fn record_default_change(&mut self, change: DefaultPaymentMethodChange) {
+        self.default_change_history.push(change);
+        let excess_entries = self
+            .default_change_history
+            .len()
+            .saturating_sub(consts::MAX_DEFAULT_PAYMENT_METHOD_CHANGE_HISTORY);
+        self.default_change_history.drain(..excess_entries);
+    }
+}
+
+pub async fn get_customer_payment_method_preferences(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+) -> RouterResult<Option<CustomerPaymentMethodPreferences>> {
+    match db
+        .find_config_by_key(&merchant_id.get_customer_payment_method_preferences_key(customer_id))
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("CustomerPaymentMethodPreferences")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse customer payment method preferences")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch customer payment method preferences"),
+    }
+}
+
+async fn upsert_customer_payment_method_preferences(
+    db: &dyn StorageInterface,
+    key: String,
+    preferences: &CustomerPaymentMethodPreferences,
+    is_existing: bool,
+) -> RouterResult<()> {
+    let serialized_preferences = preferences
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize customer payment method preferences")?;
+
+    if is_existing {
+        db.update_config_by_key(
+            &key,
+            configs::ConfigUpdate::Update {
+                config: Some(serialized_preferences),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update customer payment method preferences")?;
+    } else {
+        db.insert_config(configs::ConfigNew {
+            key,
+            config: serialized_preferences,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert customer payment method preferences")?;
+    }
+
+    Ok(())
+}
+
+fn validate_update_request(request: &CustomerPaymentMethodUpdateRequest) -> RouterResult<()> {
+    if let Some(nickname) = &request.nickname {
+        utils::when(
+            nickname.trim().is_empty()
+                || nickname.chars().count() > consts::MAX_PAYMENT_METHOD_NICKNAME_LENGTH,
+            || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "nickname must be between 1 and {} characters long",
+                        consts::MAX_PAYMENT_METHOD_NICKNAME_LENGTH
+                    ),
+                })
+            },
+        )?;
+    }
+
+    utils::when(
+        request.set_default_for.is_some() && request.disabled == Some(true),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "A disabled payment method cannot be set as default".to_string(),
+            })
+        },
+    )
+}
+
+#[instrument(skip_all)]
+pub async fn update_customer_payment_method(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    customer_id: id_type::CustomerId,
+    payment_method_id: String,
+    request: CustomerPaymentMethodUpdateRequest,
+    ephemeral_api_key: Option<&str>,
+) -> RouterResponse<CustomerPaymentMethodUpdateResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_account.get_id();
+    let storage_scheme = merchant_account.storage_scheme;
+
+    // An ephemeral key can only update the payment methods of the customer it was created for
+    let updated_by = match ephemeral_api_key {
+        Some(key) => {
+            let ephemeral_key = db
+                .get_ephemeral_key(key)
+                .await
+                .change_context(errors::ApiErrorResponse::Unauthorized)?;
+            utils::when(ephemeral_key.customer_id != customer_id, || {
+                Err(errors::ApiErrorResponse::InvalidEphemeralKey)
+            })?;
+            PaymentMethodPreferenceUpdatedBy::Customer
+        }
+        None => PaymentMethodPreferenceUpdatedBy::Merchant,
+    };
+
+    validate_update_request(&request)?;
+
+    let pii_db = data_residency::get_pii_store(&state, merchant_id).await?;
+    let customer = pii_db
+        .find_customer_by_customer_id_merchant_id(
+            key_manager_state,
+            &customer_id,
+            merchant_id,
+            &key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let payment_method = db
+        .find_payment_method(
+            key_manager_state,
+            &key_store,
+            &payment_method_id,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    utils::when(
+        payment_method.customer_id != customer_id || payment_method.merchant_id != *merchant_id,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The payment_method_id is not valid".to_string(),
+            })
+        },
+    )?;
+
+    utils::when(
+        payment_method.status == storage_enums::PaymentMethodStatus::Inactive,
+        || Err(errors::ApiErrorResponse::PaymentMethodNotFound),
+    )?;
+
+    if let Some(profile_id) = request
+        .set_default_for
+        .as_ref()
+        .and_then(|scope| scope.profile_id.as_ref())
+    {
+        core_utils::validate_and_get_business_profile(
+            db,
+            key_manager_state,
+            &key_store,
+            Some(profile_id),
+            merchant_id,
+        )
+        .await?;
+    }
+
+    // The preferences are read and written back as a whole, which is safe as the updates of a
+    // customer hold the api lock of the customer
+    let preferences_key = merchant_id.get_customer_payment_method_preferences_key(&customer_id);
+    let existing_preferences =
+        get_customer_payment_method_preferences(db, merchant_id, &customer_id).await?;
+    let is_existing = existing_preferences.is_some();
+    let mut preferences = existing_preferences.unwrap_or_default();
+
+    let mut is_customer_default =
+        customer.default_payment_method_id.as_deref() == Some(payment_method_id.as_str());
+
+    if request.disabled == Some(true) {
+        utils::when(
+            is_customer_default
+                || !preferences
+                    .get_default_scopes(&payment_method_id)
+                    .is_empty(),
+            || {
+                Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "A default payment method cannot be disabled".to_string(),
+                })
+            },
+        )?;
+    }
+
+    if let Some(nickname) = request.nickname {
+        preferences
+            .nicknames
+            .insert(payment_method_id.clone(), nickname.trim().to_string());
+    }
+
+    match request.disabled {
+        Some(true) => {
+            preferences
+                .disabled_payment_method_ids
+                .insert(payment_method_id.clone());
+        }
+        Some(false) => {
+            preferences
+                .disabled_payment_method_ids
+                .remove(&payment_method_id);
+        }
+        None => {}
+    }
+
+    if let Some(scope) = request.set_default_for {
+        utils::when(preferences.is_disabled(&payment_method_id), || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "A disabled payment method cannot be set as default".to_string(),
+            })
+        })?;
+
+        let previous_payment_method_id = if scope.get_specificity() == 0 {
+            // An unscoped default is the default payment method of the customer
+            let previous_payment_method_id = customer.default_payment_method_id.clone();
+            if !is_customer_default {
+                pii_db
+                    .update_customer_by_customer_id_merchant_id(
+                        key_manager_state,
+                        customer_id.clone(),
+                        merchant_id.to_owned(),
+                        customer,
+                        CustomerUpdate::UpdateDefaultPaymentMethod {
+                            default_payment_method_id: Some(Some(payment_method_id.clone())),
+                        },
+                        &key_store,
+                        storage_scheme,
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable(
+                        "Failed to update the default payment method id for the customer",
+                    )?;
+                is_customer_default = true;
+            }
+            previous_payment_method_id
+        } else {
+            preferences.set_scoped_default(scope.clone(), payment_method_id.clone())
+        };
+
+        if previous_payment_method_id.as_deref() != Some(payment_method_id.as_str()) {
+            logger::info!(
+                ?updated_by,
+                ?previous_payment_method_id,
+                "Default payment method of the customer changed"
+            );
+            preferences.record_default_change(DefaultPaymentMethodChange {
+                scope,
+                previous_payment_method_id,
+                payment_method_id: payment_method_id.clone(),
+                updated_by,
+                changed_at: common_utils::date_time::now(),
+            });
+        }
+    }
+
+    upsert_customer_payment_method_preferences(db, preferences_key, &preferences, is_existing)
+        .await?;
+
+    let mut default_for = preferences.get_default_scopes(&payment_method_id);
+    if is_customer_default {
+        default_for.push(DefaultPaymentMethodScope {
+            currency: None,
+            profile_id: None,
+        });
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        CustomerPaymentMethodUpdateResponse {
+            nickname: preferences.get_nickname(&payment_method_id),
+            disabled: preferences.is_disabled(&payment_method_id),
+            default_changes: preferences.get_default_changes(&payment_method_id),
+            payment_method_id,
+            customer_id,
+            default_for,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use super::*;
+
+    #[test]
+    fn test_most_specific_default_is_picked() {
+        let profile_id = id_type::ProfileId::try_from(std::borrow::Cow::from("pro_test"))
+            .expect("valid profile id");
+        let mut preferences = CustomerPaymentMethodPreferences::default();
+        preferences.set_scoped_default(
+            DefaultPaymentMethodScope {
+                currency: Some(api_enums::Currency::USD),
+                profile_id: None,
+            },
+            "pm_usd".to_string(),
+        );
+        preferences.set_scoped_default(
+            DefaultPaymentMethodScope {
+                currency: Some(api_enums::Currency::USD),
+                profile_id: Some(profile_id.clone()),
+            },
+            "pm_usd_profile".to_string(),
+        );
+
+        assert_eq!(
+            preferences
+                .get_default_payment_method_id(Some(api_enums::Currency::USD), Some(&profile_id)),
+            Some("pm_usd_profile")
+        );
+        assert_eq!(
+            preferences.get_default_payment_method_id(Some(api_enums::Currency::USD), None),
+            Some("pm_usd")
+        );
+        assert_eq!(
+            preferences.get_default_payment_method_id(Some(api_enums::Currency::EUR), None),
+            None
+        );
+    }
+}
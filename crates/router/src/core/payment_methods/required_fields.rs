@@ -0,0 +1,272 @@
+//! Registry of the fields required by the connectors for each payment method type, declared in the
+//! `required_fields` configuration. The registry is consumed by the payment methods list, which
+//! returns the fields to be collected by the SDKs, and by the confirmation of the payments, which
+//! rejects the payments missing any of the fields before the connector is called, so that both
+//! always agree on the fields required by a connector.
+
+use std::collections::HashMap;
+
+use api_models::{
+    enums as api_enums, payment_methods::RequiredFieldInfo, payments as api_payments,
+};
+use error_stack::report;
+use hyperswitch_domain_models::payment_address::PaymentAddress;
+use masking::Secret;
+use router_env::logger;
+
+use crate::{
+    configs::settings,
+    core::errors::{self, RouterResult},
+    routes::SessionState,
+    types::storage,
+};
+
+/// Prefix of the fields of the billing details of the payment method, which fall back to the
+/// billing details of the payment when not passed
+const PAYMENT_METHOD_BILLING_PREFIX: &str = "payment_method_data.billing.";
+const PAYMENT_BILLING_PREFIX: &str = "billing.";
+
+/// Prefixes of the fields which are validated on the confirmation of the payments. The fields of
+/// the payment method data other than its billing details are validated by the payments request
+/// itself.
+const VALIDATED_FIELD_PREFIXES: [&str; 2] = [PAYMENT_METHOD_BILLING_PREFIX, "shipping."];
+
+/// The fields required by the connector for the payment method type, including the fields which
+/// are required only for the mandates or only for the payments without mandates
+pub fn get_required_fields(
+    required_fields: &settings::RequiredFields,
+    payment_method: api_enums::PaymentMethod,
+    payment_method_type: api_enums::PaymentMethodType,
+    connector: api_enums::Connector,
+    is_cit_transaction: bool,
+) -> Option<HashMap<String, RequiredFieldInfo>> {
+    required_fields
+        .0
+        .get(&payment_method)?
+        .0
+        .get(&payment_method_type)?
+        .fields
+        .get(&connector)
+        .map(|required_field_final| {
+            let mut fields = required_field_final.common.clone();
+            if is_cit_transaction {
+                fields.extend(required_field_final.mandate.clone());
+            } else {
+                fields.extend(required_field_final.non_mandate.clone());
+            }
+            fields
+        })
+}
+
+fn get_value_at_path<'a>(
+    path: &str,
+    values: &'a serde_json::Value,
+) -> Option<&'a serde_json::Value> {
+    path.split('.')
+        .try_fold(values, |value, key| match value {
+            serde_json::Value::Array(array) => array.get(key.parse::<usize>().ok()?),
+            _ => value.get(key),
+        })
+        .filter(|value| match value {
+            serde_json::Value::Null => false,
+            serde_json::Value::String(value) => !value.is_empty(),
+            _ => true,
+        })
+}
+
+/// The value of the required field in the values of the payment, looked up by the path of the
+/// field. The billing details of the payment are considered when the billing details of the
+/// payment method are not passed.
+pub fn get_required_field_value<'a>(
+    required_field: &str,
+    values: &'a serde_json::Value,
+) -> Option<&'a serde_json::Value> {
+    get_value_at_path(required_field, values).or_else(|| {
+        required_field
+            .strip_prefix(PAYMENT_METHOD_BILLING_PREFIX)
+            .and_then(|field| {
+                get_value_at_path(&format!("{PAYMENT_BILLING_PREFIX}{field}"), values)
+            })
+    })
+}
+
+/// Fills the values of the required fields which are already present in the values of the payment
+pub fn populate_required_field_values(
+    required_fields: &mut HashMap<String, RequiredFieldInfo>,
+    values: &serde_json::Value,
+) {
+    for (required_field, required_field_info) in required_fields.iter_mut() {
+        if let Some(value) =
+            get_required_field_value(required_field, values).and_then(serde_json::Value::as_str)
+        {
+            required_field_info.value = Some(Secret::new(value.to_owned()));
+        }
+    }
+}
+
+/// The values of the payment which the required fields validated on the confirmation of the
+/// payments are looked up in
+#[derive(Debug, Default, serde::Serialize)]
+pub struct RequiredFieldValues {
+    pub payment_method_data: Option<api_payments::PaymentMethodDataRequest>,
+    pub shipping: Option<api_payments::Address>,
+}
+
+/// The required fields which are validated on the confirmation of the payments and are missing in
+/// the values of the payment, sorted by their path
+pub fn get_missing_required_fields(
+    required_fields: &HashMap<String, RequiredFieldInfo>,
+    values: &RequiredFieldValues,
+) -> Vec<String> {
+    let values = serde_json::to_value(values).unwrap_or_default();
+    let mut missing_fields = required_fields
+        .keys()
+        .filter(|required_field| {
+            VALIDATED_FIELD_PREFIXES
+                .iter()
+                .any(|prefix| required_field.starts_with(prefix))
+        })
+        .filter(|required_field| get_required_field_value(required_field, &values).is_none())
+        .cloned()
+        .collect::<Vec<_>>();
+    missing_fields.sort();
+    missing_fields
+}
+
+/// Rejects the payment being confirmed with the connector when any of the billing or shipping
+/// fields required by the connector is missing. The payments whose details are collected by the
+/// wallet or pay later SDKs, and the recurring payments made with the details stored with the
+/// mandates, are not validated.
+pub fn validate_required_fields(
+    state: &SessionState,
+    connector: api_enums::Connector,
+    payment_attempt: &storage::PaymentAttempt,
+    payment_intent: &storage::PaymentIntent,
+    payment_address: &PaymentAddress,
+    is_recurring_payment: bool,
+) -> RouterResult<()> {
+    let (Some(payment_method), Some(payment_method_type)) = (
+        payment_attempt.payment_method,
+        payment_attempt.payment_method_type,
+    ) else {
+        return Ok(());
+    };
+    if !state.conf.required_fields_validation.enabled
+        || is_recurring_payment
+        || payment_attempt.payment_experience == Some(api_enums::PaymentExperience::InvokeSdkClient)
+    {
+        return Ok(());
+    }
+
+    let is_cit_transaction = payment_attempt.mandate_details.is_some()
+        || payment_intent.setup_future_usage == Some(api_enums::FutureUsage::OffSession);
+    let Some(required_fields) = get_required_fields(
+        &state.conf.required_fields,
+        payment_method,
+        payment_method_type,
+        connector,
+        is_cit_transaction,
+    ) else {
+        return Ok(());
+    };
+
+    let values = RequiredFieldValues {
+        payment_method_data: Some(api_payments::PaymentMethodDataRequest {
+            payment_method_data: None,
+            billing: payment_address
+                .get_payment_method_billing()
+                .cloned()
+                .map(api_payments::Address::from),
+        }),
+        shipping: payment_address
+            .get_shipping()
+            .cloned()
+            .map(api_payments::Address::from),
+    };
+    let missing_fields = get_missing_required_fields(&required_fields, &values);
+    if missing_fields.is_empty() {
+        return Ok(());
+    }
+
+    logger::info!(
+        ?missing_fields,
+        %connector,
+        "Payment missing the fields required by the connector"
+    );
+    Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+        message: format!(
+            "Missing required fields for {connector}: {}",
+            missing_fields.join(", ")
+        ),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_required_field_info(required_field: &str) -> (String, RequiredFieldInfo) {
+        (
+            required_field.to_owned(),
+            RequiredFieldInfo {
+                required_field: required_field.to_owned(),
+                display_name: required_field.to_owned(),
+                field_type: api_enums::FieldType::Text,
+                value: None,
+            },
+        )
+    }
+
+    #[test]
+    fn test_required_field_value_falls_back_to_payment_billing() {
+        let values = serde_json::json!({
+            "billing": { "address": { "first_name": "John" } },
+            "order_details": [{ "product_name": "Shirt" }],
+        });
+
+        assert_eq!(
+            get_required_field_value("payment_method_data.billing.address.first_name", &values),
+            Some(&serde_json::json!("John"))
+        );
+        assert_eq!(
+            get_required_field_value("order_details.0.product_name", &values),
+            Some(&serde_json::json!("Shirt"))
+        );
+        assert!(get_required_field_value("billing.address.last_name", &values).is_none());
+    }
+
+    #[test]
+    fn test_missing_required_fields() {
+        let required_fields = HashMap::from([
+            get_required_field_info("payment_method_data.billing.address.first_name"),
+            get_required_field_info("payment_method_data.billing.address.last_name"),
+            get_required_field_info("payment_method_data.card.card_number"),
+            get_required_field_info("shipping.address.zip"),
+            get_required_field_info("email"),
+        ]);
+        let values = RequiredFieldValues {
+            payment_method_data: Some(api_payments::PaymentMethodDataRequest {
+                payment_method_data: None,
+                billing: Some(api_payments::Address {
+                    address: Some(api_payments::AddressDetails {
+                        first_name: Some(Secret::new("John".to_owned())),
+                        last_name: Some(Secret::new(String::new())),
+                        ..Default::default()
+                    }),
+                    phone: None,
+                    email: None,
+                }),
+            }),
+            shipping: None,
+        };
+
+        assert_eq!(
+            get_missing_required_fields(&required_fields, &values),
+            vec![
+                "payment_method_data.billing.address.last_name".to_owned(),
+                "shipping.address.zip".to_owned(),
+            ]
+        );
+    }
+}
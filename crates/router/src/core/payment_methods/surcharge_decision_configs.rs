@@ -17,12 +17,14 @@ use error_stack::{self, ResultExt};
 use euclid::{
     backend,
     backend::{inputs as dsl_inputs, EuclidBackend},
+    enums as euclid_enums,
 };
 use router_env::{instrument, logger, tracing};
 use serde::{Deserialize, Serialize};
 use storage_impl::redis::cache::{self, SURCHARGE_CACHE};
 
 use crate::{
+    configs::settings,
     core::{
         errors::{self, ConditionalConfigError as ConfigError},
         payments::{
@@ -32,6 +34,7 @@ use crate::{
     },
     db::StorageInterface,
     types::{
+        domain,
         storage::{self, payment_attempt::PaymentAttemptExt},
         transformers::ForeignTryFrom,
     },
@@ -59,6 +62,90 @@ impl TryFrom<SurchargeDecisionManagerRecord> for VirInterpreterBackendCacheWrapp
     }
 }
 
+/// Card subtypes in the BIN data which identify the cards issued to businesses
+const COMMERCIAL_CARD_SUBTYPES: [&str; 5] =
+    ["commercial", "business", "corporate", "purchasing", "fleet"];
+
+/// Details of a card derived from its BIN, on which the surcharge rules can be defined
+#[derive(Debug, Clone, Default)]
+pub struct CardBinDetails {
+    pub card_bin: Option<String>,
+    /// Credit or debit, as per the BIN
+    pub payment_method_type: Option<common_enums::PaymentMethodType>,
+    pub card_network: Option<common_enums::CardNetwork>,
+    pub card_product_type: Option<euclid_enums::CardProductType>,
+}
+
+impl CardBinDetails {
+    fn from_card_info(card_info: storage::CardInfo) -> Self {
+        Self {
+            payment_method_type: card_info
+                .card_type
+                .as_deref()
+                .and_then(get_payment_method_type_from_card_type),
+            card_product_type: card_info
+                .card_subtype
+                .as_deref()
+                .map(get_card_product_type_from_card_subtype),
+            card_network: card_info.card_network,
+            card_bin: Some(card_info.card_iin),
+        }
+    }
+
+    fn populate_backend_input(self, backend_input: &mut dsl_inputs::BackendInput) {
+        backend_input.payment.card_bin = self.card_bin;
+        if let Some(payment_method_type) = self.payment_method_type {
+            backend_input.payment_method.payment_method_type = Some(payment_method_type);
+        }
+        // The network chosen for a co-branded card takes precedence over the network of its BIN
+        if backend_input.payment_method.card_network.is_none() {
+            backend_input.payment_method.card_network = self.card_network;
+        }
+        backend_input.payment_method.card_product_type = self.card_product_type;
+    }
+}
+
+fn get_payment_method_type_from_card_type(
+    card_type: &str,
+) -> Option<common_enums::PaymentMethodType> {
+    if card_type.eq_ignore_ascii_case("credit") {
+        Some(common_enums::PaymentMethodType::Credit)
+    } else if card_type.eq_ignore_ascii_case("debit") {
+        Some(common_enums::PaymentMethodType::Debit)
+    } else {
+        None
+    }
+}
+
+fn get_card_product_type_from_card_subtype(card_subtype: &str) -> euclid_enums::CardProductType {
+    let card_subtype = card_subtype.to_lowercase();
+    if COMMERCIAL_CARD_SUBTYPES
+        .iter()
+        .any(|commercial_subtype| card_subtype.contains(commercial_subtype))
+    {
+        euclid_enums::CardProductType::Commercial
+    } else {
+        euclid_enums::CardProductType::Consumer
+    }
+}
+
+/// Looks up the details of the card BIN, only the BIN is used if it is not known
+#[instrument(skip_all)]
+pub async fn get_card_bin_details(state: &SessionState, card_isin: &str) -> CardBinDetails {
+    state
+        .store
+        .get_card_info(card_isin)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to fetch the card info of the BIN"))
+        .ok()
+        .flatten()
+        .map(CardBinDetails::from_card_info)
+        .unwrap_or_else(|| CardBinDetails {
+            card_bin: Some(card_isin.to_string()),
+            ..Default::default()
+        })
+}
+
 enum SurchargeSource {
     /// Surcharge will be generated through the surcharge rules
     Generate(VirInterpreterBackendCacheWrapper),
@@ -72,6 +159,7 @@ impl SurchargeSource {
         backend_input: &backend::BackendInput,
         payment_attempt: &storage::PaymentAttempt,
         surcharge_metadata_and_key: (&mut types::SurchargeMetadata, types::SurchargeKey),
+        rounding_policy: &settings::RoundingPolicy,
     ) -> ConditionalConfigResult<Option<types::SurchargeDetails>> {
         match self {
             Self::Generate(interpreter) => {
@@ -85,6 +173,7 @@ impl SurchargeSource {
                         get_surcharge_details_from_surcharge_output(
                             surcharge_details,
                             payment_attempt,
+                            rounding_policy,
                         )
                     })
                     .transpose()?
@@ -194,6 +283,7 @@ pub async fn perform_surcharge_decision_management_for_payment_method_list(
                                     Some(card_network_type.card_network.clone()),
                                 ),
                             ),
+                            &state.conf.rounding_policy,
                         )?;
                     card_network_type.surcharge_details = surcharge_details
                         .map(|surcharge_details| {
@@ -219,6 +309,7 @@ pub async fn perform_surcharge_decision_management_for_payment_method_list(
                                 None,
                             ),
                         ),
+                        &state.conf.rounding_policy,
                     )?;
                 payment_method_type_response.surcharge_details = surcharge_details
                     .map(|surcharge_details| {
@@ -283,6 +374,7 @@ pub async fn perform_surcharge_decision_management_for_session_flow(
                     None,
                 ),
             ),
+            &state.conf.rounding_policy,
         )?;
     }
     Ok(surcharge_metadata)
@@ -348,6 +440,16 @@ pub async fn perform_surcharge_decision_management_for_saved_cards(
 
         backend_input.payment_method.card_network = card_network;
 
+        let card_bin_details = match customer_payment_method
+            .card
+            .as_ref()
+            .and_then(|card| card.card_isin.as_deref())
+        {
+            Some(card_isin) => get_card_bin_details(state, card_isin).await,
+            None => CardBinDetails::default(),
+        };
+        card_bin_details.populate_backend_input(&mut backend_input);
+
         let surcharge_details = surcharge_source
             .generate_surcharge_details_and_populate_surcharge_metadata(
                 &backend_input,
@@ -356,6 +458,7 @@ pub async fn perform_surcharge_decision_management_for_saved_cards(
                     &mut surcharge_metadata,
                     types::SurchargeKey::Token(payment_token),
                 ),
+                &state.conf.rounding_policy,
             )?;
         customer_payment_method.surcharge_details = surcharge_details
             .map(|surcharge_details| {
@@ -367,6 +470,45 @@ pub async fn perform_surcharge_decision_management_for_saved_cards(
     Ok(surcharge_metadata)
 }
 
+/// Calculates the surcharge of a card through the surcharge rules, with the details derived from
+/// the card BIN which are only known once the card number is received
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn perform_surcharge_decision_management_for_card(
+    state: &SessionState,
+    algorithm_id: &str,
+    payment_attempt: &storage::PaymentAttempt,
+    payment_intent: &storage::PaymentIntent,
+    billing_address: Option<hyperswitch_domain_models::address::Address>,
+    card: &domain::Card,
+) -> ConditionalConfigResult<Option<types::SurchargeDetails>> {
+    let cached_algo =
+        ensure_algorithm_cached(&*state.store, &payment_attempt.merchant_id, algorithm_id).await?;
+
+    let mut backend_input =
+        make_dsl_input_for_surcharge(payment_attempt, payment_intent, billing_address)
+            .change_context(ConfigError::InputConstructionError)?;
+    backend_input.payment_method.payment_method = Some(common_enums::PaymentMethod::Card);
+    backend_input.payment_method.payment_method_type = payment_attempt.payment_method_type;
+    backend_input.payment_method.card_network = card.card_network.clone();
+    get_card_bin_details(state, &card.card_number.get_card_isin())
+        .await
+        .populate_backend_input(&mut backend_input);
+
+    let surcharge_output =
+        execute_dsl_and_get_conditional_config(backend_input, &cached_algo.cached_algorithm)?;
+    surcharge_output
+        .surcharge_details
+        .map(|surcharge_details| {
+            get_surcharge_details_from_surcharge_output(
+                surcharge_details,
+                payment_attempt,
+                &state.conf.rounding_policy,
+            )
+        })
+        .transpose()
+}
+
 // TODO: uncomment and resolve compiler error when required
 // #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
 // pub async fn perform_surcharge_decision_management_for_saved_cards(
@@ -449,6 +591,7 @@ pub async fn perform_surcharge_decision_management_for_saved_cards(
 fn get_surcharge_details_from_surcharge_output(
     _surcharge_details: surcharge_decision_configs::SurchargeDetailsOutput,
     _payment_attempt: &storage::PaymentAttempt,
+    _rounding_policy: &settings::RoundingPolicy,
 ) -> ConditionalConfigResult<types::SurchargeDetails> {
     todo!()
 }
@@ -457,11 +600,13 @@ fn get_surcharge_details_from_surcharge_output(
 fn get_surcharge_details_from_surcharge_output(
     surcharge_details: surcharge_decision_configs::SurchargeDetailsOutput,
     payment_attempt: &storage::PaymentAttempt,
+    rounding_policy: &settings::RoundingPolicy,
 ) -> ConditionalConfigResult<types::SurchargeDetails> {
+    let rounding_mode = rounding_policy.get_rounding_mode(payment_attempt.currency);
     let surcharge_amount = match surcharge_details.surcharge.clone() {
         surcharge_decision_configs::SurchargeOutput::Fixed { amount } => amount,
         surcharge_decision_configs::SurchargeOutput::Rate(percentage) => percentage
-            .apply_and_ceil_result(payment_attempt.net_amount.get_total_amount())
+            .apply_and_round_result(payment_attempt.net_amount.get_total_amount(), rounding_mode)
             .change_context(ConfigError::DslExecutionError)
             .attach_printable("Failed to Calculate surcharge amount by applying percentage")?,
     };
@@ -470,7 +615,7 @@ fn get_surcharge_details_from_surcharge_output(
         .clone()
         .map(|tax_on_surcharge| {
             tax_on_surcharge
-                .apply_and_ceil_result(surcharge_amount)
+                .apply_and_round_result(surcharge_amount, rounding_mode)
                 .change_context(ConfigError::DslExecutionError)
                 .attach_printable("Failed to Calculate tax amount")
         })
@@ -19,9 +19,12 @@ use super::{
     NetworkTokenizationResponse, State, StoreLockerResponse, TransitionTo,
 };
 use crate::{
-    core::payment_methods::{
-        cards::{add_card_to_hs_locker, create_payment_method},
-        transformers as pm_transformers,
+    core::{
+        data_residency,
+        payment_methods::{
+            cards::{add_card_to_hs_locker, create_payment_method},
+            transformers as pm_transformers,
+        },
     },
     errors::{self, RouterResult},
     types::{api, domain},
@@ -274,6 +277,7 @@ impl<'a> NetworkTokenizationBuilder<'a, CardTokenStored> {
             client_secret: payment_method.client_secret.clone(),
             bank_transfer: None,
             payment_experience: None,
+            consent: None,
         };
         NetworkTokenizationBuilder {
             state: std::marker::PhantomData,
@@ -324,7 +328,7 @@ impl CardNetworkTokenizeExecutor<'_, domain::TokenizeCardRequest> {
             })?;
 
         // Fetch customer details if present
-        let db = &*self.state.store;
+        let db = data_residency::get_pii_store(self.state, self.merchant_account.get_id()).await?;
         let key_manager_state: &KeyManagerState = &self.state.into();
         db.find_customer_optional_by_customer_id_merchant_id(
             key_manager_state,
@@ -362,7 +366,7 @@ impl CardNetworkTokenizeExecutor<'_, domain::TokenizeCardRequest> {
     }
 
     pub async fn create_customer(&self) -> RouterResult<api::CustomerDetails> {
-        let db = &*self.state.store;
+        let db = data_residency::get_pii_store(self.state, self.merchant_account.get_id()).await?;
         let customer_id = self
             .customer
             .customer_id
@@ -519,7 +523,7 @@ impl CardNetworkTokenizeExecutor<'_, domain::TokenizeCardRequest> {
         card_details: &domain::CardDetail,
         customer_id: &id_type::CustomerId,
     ) -> RouterResult<domain::PaymentMethod> {
-        let payment_method_id = common_utils::generate_id(consts::ID_LENGTH, "pm");
+        let payment_method_id = common_utils::generate_resource_id(consts::ID_LENGTH, "pm");
 
         // Form encrypted PM data (original card)
         let enc_pm_data = self.encrypt_card(card_details, true).await?;
@@ -557,6 +561,7 @@ impl CardNetworkTokenizeExecutor<'_, domain::TokenizeCardRequest> {
                 .map(|network| network.to_string()),
             bank_transfer: None,
             wallet: None,
+            bank_debit: None,
             client_secret: None,
             payment_method_data: None,
             billing: None,
@@ -11,7 +11,7 @@ use super::{
     NetworkTokenizationResponse, State, TransitionTo,
 };
 use crate::{
-    core::payment_methods::transformers as pm_transformers,
+    core::{data_residency, payment_methods::transformers as pm_transformers},
     errors::{self, RouterResult},
     types::{api, domain},
 };
@@ -82,6 +82,7 @@ impl<'a> NetworkTokenizationBuilder<'a, TokenizeWithPmId> {
             card: None,
             bank_transfer: None,
             payment_experience: None,
+            consent: None,
         };
         NetworkTokenizationBuilder {
             state: std::marker::PhantomData,
@@ -235,6 +236,7 @@ impl<'a> NetworkTokenizationBuilder<'a, PmTokenStored> {
             card: None,
             bank_transfer: None,
             payment_experience: None,
+            consent: None,
         };
         NetworkTokenizationBuilder {
             state: std::marker::PhantomData,
@@ -353,7 +355,7 @@ impl CardNetworkTokenizeExecutor<'_, domain::TokenizePaymentMethodRequest> {
         ))?;
 
         // Fetch customer
-        let db = &*self.state.store;
+        let db = data_residency::get_pii_store(self.state, self.merchant_account.get_id()).await?;
         let key_manager_state: &KeyManagerState = &self.state.into();
         let customer = db
             .find_customer_by_customer_id_merchant_id(
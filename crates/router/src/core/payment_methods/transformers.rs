@@ -36,6 +36,13 @@ impl StoreLockerReq {
             Self::LockerGeneric(_) => (),
         }
     }
+
+    pub fn get_merchant_id(&self) -> &id_type::MerchantId {
+        match self {
+            Self::LockerCard(c) => &c.merchant_id,
+            Self::LockerGeneric(g) => &g.merchant_id,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -52,6 +59,8 @@ pub struct StoreCardReq {
 pub struct StoreGenericReq {
     pub merchant_id: id_type::MerchantId,
     pub merchant_customer_id: id_type::CustomerId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub requestor_card_reference: Option<String>,
     #[serde(rename = "enc_card_data")]
     pub enc_data: String,
     pub ttl: i64,
@@ -444,6 +453,37 @@ pub fn mk_add_bank_response_hs(
         payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]),
         last_used_at: Some(common_utils::date_time::now()),
         client_secret: None,
+        consent: None,
+    }
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+pub fn mk_add_bank_debit_response_hs(
+    bank_debit: &api::BankDebitDetail,
+    bank_debit_reference: String,
+    req: api::PaymentMethodCreate,
+    merchant_id: &id_type::MerchantId,
+) -> api::PaymentMethodResponse {
+    api::PaymentMethodResponse {
+        merchant_id: merchant_id.to_owned(),
+        customer_id: req.customer_id,
+        payment_method_id: bank_debit_reference,
+        payment_method: req.payment_method,
+        payment_method_type: Some(bank_debit.get_payment_method_type()),
+        #[cfg(feature = "payouts")]
+        bank_transfer: None,
+        card: None,
+        metadata: req.metadata,
+        created: Some(common_utils::date_time::now()),
+        recurring_enabled: true,
+        installment_payment_enabled: false,
+        payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]),
+        last_used_at: Some(common_utils::date_time::now()),
+        client_secret: req.client_secret,
+        consent: None,
     }
 }
 
@@ -507,6 +547,7 @@ pub fn mk_add_card_response_hs(
         payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]),
         last_used_at: Some(common_utils::date_time::now()), // [#256]
         client_secret: req.client_secret,
+        consent: None,
     }
 }
 
@@ -4,13 +4,15 @@ use diesel_models::generic_link::PaymentMethodCollectLinkData;
 use error_stack::ResultExt;
 use masking::Secret;
 
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+use crate::core::data_residency;
 use crate::{
     consts,
     core::{
         errors::{self, RouterResult},
         utils as core_utils,
     },
-    routes::{app::StorageInterface, SessionState},
+    routes::SessionState,
     types::domain,
     utils,
 };
@@ -33,11 +35,11 @@ pub async fn validate_request_and_initiate_payment_method_collect_link(
     req: &PaymentMethodCollectLinkRequest,
 ) -> RouterResult<PaymentMethodCollectLinkData> {
     // Validate customer_id
-    let db: &dyn StorageInterface = &*state.store;
     let customer_id = req.customer_id.clone();
     let merchant_id = merchant_account.get_id().clone();
     #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
-    match db
+    match data_residency::get_pii_store(state, &merchant_id)
+        .await?
         .find_customer_by_customer_id_merchant_id(
             &state.into(),
             &customer_id,
@@ -1,23 +1,70 @@
 pub mod access_token;
+#[cfg(feature = "v1")]
+pub mod acquirer_reference_numbers;
+#[cfg(feature = "v1")]
+pub mod auto_capture_delay;
+#[cfg(feature = "v1")]
+pub mod changes;
 pub mod conditional_configs;
 pub mod connector_integration_v2_impls;
+#[cfg(feature = "v1")]
+pub mod connector_response_details;
+#[cfg(feature = "v1")]
+pub mod consistency;
+#[cfg(feature = "v1")]
+pub mod contact_search;
+#[cfg(feature = "v1")]
+pub mod custom_fields;
+#[cfg(feature = "v1")]
+pub mod custom_status;
 pub mod customers;
+#[cfg(feature = "v1")]
+pub mod dunning;
+#[cfg(feature = "v1")]
+pub mod estimated_authorization;
 pub mod flows;
+#[cfg(feature = "v1")]
+pub mod gift_card;
+pub mod google_pay;
 pub mod helpers;
+#[cfg(feature = "v1")]
+pub mod lookup;
+#[cfg(feature = "v1")]
+pub mod notes;
 pub mod operations;
+#[cfg(feature = "v1")]
+pub mod partial_payments;
+#[cfg(feature = "v1")]
+pub mod payment_facilitator;
+#[cfg(feature = "v1")]
+pub mod post_authorization;
+#[cfg(feature = "v1")]
+pub mod receipts;
 
 #[cfg(feature = "retry")]
 pub mod retry;
 pub mod routing;
+#[cfg(feature = "v1")]
+pub mod scheduled_capture;
 #[cfg(feature = "v2")]
 pub mod session_operation;
+pub mod session_response_cache;
+#[cfg(feature = "v1")]
+pub mod step_up_authentication;
+#[cfg(feature = "v1")]
+pub mod timeline;
 pub mod tokenization;
 pub mod transformers;
 pub mod types;
-#[cfg(feature = "olap")]
-use std::collections::HashMap;
+#[cfg(feature = "v1")]
+pub mod unified_messages;
 use std::{
-    collections::HashSet, fmt::Debug, marker::PhantomData, ops::Deref, time::Instant, vec::IntoIter,
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    marker::PhantomData,
+    ops::Deref,
+    time::Instant,
+    vec::IntoIter,
 };
 
 #[cfg(feature = "v2")]
@@ -71,9 +118,9 @@ use time;
 
 #[cfg(feature = "v1")]
 pub use self::operations::{
-    PaymentApprove, PaymentCancel, PaymentCapture, PaymentConfirm, PaymentCreate,
-    PaymentIncrementalAuthorization, PaymentPostSessionTokens, PaymentReject, PaymentSession,
-    PaymentSessionUpdate, PaymentStatus, PaymentUpdate,
+    PaymentApprove, PaymentCancel, PaymentCancelRemaining, PaymentCapture, PaymentConfirm,
+    PaymentCreate, PaymentIncrementalAuthorization, PaymentPostSessionTokens, PaymentReject,
+    PaymentSession, PaymentSessionUpdate, PaymentStatus, PaymentUpdate,
 };
 use self::{
     conditional_configs::perform_decision_management,
@@ -84,6 +131,8 @@ use self::{
 use super::{
     errors::StorageErrorExt, payment_methods::surcharge_decision_configs, routing::TransactionData,
 };
+#[cfg(feature = "v1")]
+use crate::core::customers::store_credit;
 #[cfg(feature = "frm")]
 use crate::core::fraud_check as frm_core;
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
@@ -95,6 +144,7 @@ use crate::{
     connector::utils::missing_field_err,
     core::{
         errors::{self, CustomResult, RouterResponse, RouterResult},
+        feature_flags,
         payment_methods::{cards, network_tokenization},
         payouts,
         routing::{self as core_routing},
@@ -119,7 +169,7 @@ use crate::{
 };
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 use crate::{
-    core::authentication as authentication_core,
+    core::{authentication as authentication_core, data_residency},
     types::{api::authentication, BrowserInformation},
 };
 
@@ -315,6 +365,22 @@ where
         )
         .await?;
 
+    let initial_intent_status = payment_data.get_payment_intent().status;
+
+    payment_data.set_custom_status_mapping(
+        custom_status::get_custom_payment_status_mapping(&*state.store, business_profile.get_id())
+            .await?,
+    );
+
+    payment_data.set_custom_fields(
+        custom_fields::get_custom_fields_of_payment(
+            &*state.store,
+            business_profile.get_id(),
+            payment_data.get_payment_intent(),
+        )
+        .await?,
+    );
+
     operation
         .to_get_tracker()?
         .validate_request_with_state(state, &req, &mut payment_data, &business_profile)
@@ -339,8 +405,18 @@ where
         .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)
         .attach_printable("Failed while fetching/creating customer")?;
 
-    let authentication_type =
+    let mut authentication_type =
         call_decision_manager(state, &merchant_account, &business_profile, &payment_data).await?;
+    if is_operation_confirm(&operation) {
+        authentication_type = step_up_authentication::get_authentication_type_with_step_up(
+            state,
+            &business_profile,
+            &payment_data,
+            authentication_type,
+        )
+        .await?;
+        store_credit::debit_payment_store_credit(state, payment_data.get_payment_intent()).await?;
+    }
 
     payment_data.set_authentication_type_in_attempt(authentication_type);
 
@@ -357,6 +433,14 @@ where
     )
     .await?;
 
+    if let Some(connector_call_type) = connector.as_ref() {
+        timeline::record_routing_decision(
+            state,
+            payment_data.get_payment_attempt(),
+            connector_call_type,
+        );
+    }
+
     let should_add_task_to_process_tracker = should_add_task_to_process_tracker(&payment_data);
 
     let locale = header_payload.locale.clone();
@@ -650,7 +734,7 @@ where
                     {
                         use crate::core::payments::retry::{self, GsmValidation};
                         let config_bool = retry::config_should_call_gsm(
-                            &*state.store,
+                            state,
                             merchant_account.get_id(),
                             &business_profile,
                         )
@@ -780,6 +864,18 @@ where
                 }
             };
 
+            if is_operation_confirm(&operation) || is_operation_complete_authorize(&operation) {
+                Box::pin(post_authorization::execute_post_authorization_rules(
+                    state,
+                    req_state.clone(),
+                    &merchant_account,
+                    &key_store,
+                    &mut payment_data,
+                    platform_merchant_account.as_ref(),
+                ))
+                .await?;
+            }
+
             #[cfg(feature = "frm")]
             if let Some(fraud_info) = &mut frm_info {
                 #[cfg(feature = "v1")]
@@ -855,6 +951,31 @@ where
             .await?;
     }
 
+    timeline::record_status_transition(
+        state,
+        payment_data.get_payment_attempt(),
+        initial_intent_status,
+        payment_data.get_payment_intent().status,
+    );
+
+    dunning::start_dunning_if_applicable(
+        state,
+        &merchant_account,
+        &key_store,
+        &business_profile,
+        payment_data.get_payment_intent(),
+        payment_data.get_payment_attempt(),
+        initial_intent_status,
+    )
+    .await;
+
+    store_credit::reverse_payment_store_credit_if_required(
+        state,
+        payment_data.get_payment_intent(),
+        initial_intent_status,
+    )
+    .await;
+
     let cloned_payment_data = payment_data.clone();
     let cloned_customer = customer.clone();
 
@@ -956,6 +1077,20 @@ where
         )
         .await?;
 
+    payment_data.set_custom_status_mapping(
+        custom_status::get_custom_payment_status_mapping(&*state.store, business_profile.get_id())
+            .await?,
+    );
+
+    payment_data.set_custom_fields(
+        custom_fields::get_custom_fields_of_payment(
+            &*state.store,
+            business_profile.get_id(),
+            payment_data.get_payment_intent(),
+        )
+        .await?,
+    );
+
     core_utils::validate_profile_id_from_auth_layer(
         profile_id_from_auth_layer,
         &payment_data.get_payment_intent().clone(),
@@ -1337,6 +1472,7 @@ where
 #[instrument(skip_all)]
 async fn populate_surcharge_details<F>(
     state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
     payment_data: &mut PaymentData<F>,
 ) -> RouterResult<()>
 where
@@ -1349,6 +1485,7 @@ where
 #[instrument(skip_all)]
 async fn populate_surcharge_details<F>(
     state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
     payment_data: &mut PaymentData<F>,
 ) -> RouterResult<()>
 where
@@ -1367,6 +1504,43 @@ where
             payment_data.surcharge_details = Some(surcharge_details);
             return Ok(());
         }
+        let algorithm_ref: api::routing::RoutingAlgorithmRef = merchant_account
+            .routing_algorithm
+            .clone()
+            .map(|val| val.parse_value("routing algorithm"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Could not decode the routing algorithm")?
+            .unwrap_or_default();
+
+        // The surcharge rules can be defined on the details derived from the card BIN, so the
+        // surcharge of a card is calculated again once the card number is received
+        if let (Some(domain::PaymentMethodData::Card(card)), Some(algorithm_id)) = (
+            payment_data.payment_method_data.as_ref(),
+            algorithm_ref.surcharge_config_algo_id,
+        ) {
+            let calculated_surcharge_details =
+                surcharge_decision_configs::perform_surcharge_decision_management_for_card(
+                    state,
+                    &algorithm_id,
+                    &payment_data.payment_attempt,
+                    &payment_data.payment_intent,
+                    payment_data.address.get_payment_billing().cloned(),
+                    card,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("error performing surcharge decision operation for card")?;
+            logger::debug!(card_surcharge_details_confirm =? calculated_surcharge_details);
+
+            payment_data.surcharge_details = calculated_surcharge_details.clone();
+            payment_data
+                .payment_attempt
+                .net_amount
+                .set_surcharge_details(calculated_surcharge_details);
+            return Ok(());
+        }
+
         let raw_card_key = payment_data
             .payment_method_data
             .as_ref()
@@ -1553,13 +1727,13 @@ where
             .flat_map(|c| c.foreign_try_into())
             .collect()
     });
-    let (payment_data, _req, customer, connector_http_status_code, external_latency) =
+    let (mut payment_data, _req, customer, connector_http_status_code, external_latency) =
         payments_operation_core::<_, _, _, _, _>(
             &state,
             req_state,
             merchant_account,
             profile_id,
-            key_store,
+            key_store.clone(),
             operation.clone(),
             req,
             call_connector_action,
@@ -1570,6 +1744,12 @@ where
         )
         .await?;
 
+    unified_messages::populate_localized_unified_message(&state, &mut payment_data).await?;
+    connector_response_details::populate_connector_response_details(&state, &mut payment_data)
+        .await;
+    receipts::populate_receipt_url(&state, &key_store, &mut payment_data)?;
+    scheduled_capture::populate_scheduled_capture(&state, &mut payment_data).await;
+
     Res::generate_response(
         payment_data,
         customer,
@@ -1616,13 +1796,13 @@ where
     // To perform router related operation for PaymentResponse
     PaymentResponse: Operation<F, FData, Data = D>,
 {
-    let (payment_data, _req, customer, connector_http_status_code, external_latency) =
+    let (mut payment_data, _req, customer, connector_http_status_code, external_latency) =
         proxy_for_payments_operation_core::<_, _, _, _, _>(
             &state,
             req_state,
             merchant_account,
             profile_id,
-            key_store,
+            key_store.clone(),
             operation.clone(),
             req,
             call_connector_action,
@@ -1632,6 +1812,12 @@ where
         )
         .await?;
 
+    unified_messages::populate_localized_unified_message(&state, &mut payment_data).await?;
+    connector_response_details::populate_connector_response_details(&state, &mut payment_data)
+        .await;
+    receipts::populate_receipt_url(&state, &key_store, &mut payment_data)?;
+    scheduled_capture::populate_scheduled_capture(&state, &mut payment_data).await;
+
     Res::generate_response(
         payment_data,
         customer,
@@ -2489,6 +2675,7 @@ impl PaymentRedirectFlow for PaymentRedirectSync {
             client_secret: None,
             expand_attempts: None,
             expand_captures: None,
+            consistency_token: None,
         };
         let response = Box::pin(
             payments_core::<api::PSync, api::PaymentsResponse, _, _, _, _>(
@@ -2852,6 +3039,7 @@ impl PaymentRedirectFlow for PaymentAuthenticateCompleteAuthorize {
                 client_secret: None,
                 expand_attempts: None,
                 expand_captures: None,
+                consistency_token: None,
             };
             Box::pin(
                 payments_core::<api::PSync, api::PaymentsResponse, _, _, _, _>(
@@ -3032,11 +3220,21 @@ where
             state,
             payment_data,
             merchant_account,
+            key_store,
             business_profile,
             &connector,
         )
         .await?;
 
+    // The capture of the payment is held once its connector is known, as it can only be held when
+    // the connector supports the manual capture of the payment method
+    if matches!(call_connector_action, CallConnectorAction::Trigger)
+        && !is_retry_payment
+        && is_operation_confirm_or_create(operation)
+    {
+        auto_capture_delay::hold_capture(&*state.store, payment_data, &connector).await?;
+    }
+
     let (pd, tokenization_action) = get_connector_tokenization_action_when_confirm_true(
         state,
         operation,
@@ -3054,6 +3252,18 @@ where
     // Validating the blocklist guard and generate the fingerprint
     blocklist_guard(state, merchant_account, key_store, operation, payment_data).await?;
 
+    // Rejecting the payment early when the fields required by the connector are missing
+    if is_operation_confirm(operation) {
+        super::payment_methods::required_fields::validate_required_fields(
+            state,
+            connector.connector_name,
+            payment_data.get_payment_attempt(),
+            payment_data.get_payment_intent(),
+            payment_data.get_address(),
+            payment_data.get_mandate_id().is_some(),
+        )?;
+    }
+
     let updated_customer = call_create_connector_customer_if_required(
         state,
         customer,
@@ -3208,7 +3418,13 @@ where
         // This is added because few connector integrations do not update the status,
         // and rely on previous status set in router_data
         router_data.status = payment_data.get_payment_attempt().status;
-        router_data
+        timeline::record_connector_call_started::<F>(
+            state,
+            payment_data.get_payment_attempt(),
+            &connector,
+        );
+        let stime_connector_call = Instant::now();
+        let router_data = router_data
             .decide_flows(
                 state,
                 &connector,
@@ -3217,10 +3433,19 @@ where
                 business_profile,
                 header_payload.clone(),
             )
-            .await
+            .await?;
+        timeline::record_connector_call_ended::<F>(
+            state,
+            payment_data.get_payment_attempt(),
+            &connector,
+            router_data.status,
+            router_data.response.as_ref().err(),
+            stime_connector_call.elapsed().as_millis(),
+        );
+        router_data
     } else {
-        Ok(router_data)
-    }?;
+        router_data
+    };
 
     let etime_connector = Instant::now();
     let duration_connector = etime_connector.saturating_duration_since(stime_connector);
@@ -4746,6 +4971,11 @@ where
     )
     .await?;
 
+    super::test_mode::validate_connector_account_for_test_mode(
+        payment_data.get_payment_intent(),
+        &merchant_connector_account,
+    )?;
+
     Ok(merchant_connector_account)
 }
 
@@ -5369,6 +5599,25 @@ where
         Option<hyperswitch_domain_models::card_testing_guard_data::CardTestingGuardData>,
     pub vault_operation: Option<domain_payments::VaultOperation>,
     pub threeds_method_comp_ind: Option<api_models::payments::ThreeDsCompletionIndicator>,
+    /// Authentication values passed by the merchant from a 3DS authentication performed outside of Hyperswitch
+    pub external_authentication_data: Option<types::AuthenticationData>,
+    /// Payment facilitator details of the profile, overridden by the ones passed in the payment
+    pub payment_facilitator_details: Option<api_models::payments::PaymentFacilitatorDetails>,
+    /// Custom payment status mapping of the profile, applied to the status of the payment in the
+    /// response
+    pub custom_status_mapping: Option<api_models::payments::CustomPaymentStatusMapping>,
+    /// Payer facing message of the unified error of the payment, in the locale of the request
+    pub localized_unified_message: Option<String>,
+    /// Subset of the response of the connector, for the profiles which have opted in
+    pub connector_response_details: Option<api_models::payments::ConnectorResponseDetails>,
+    /// Signed link to the hosted receipt of the payment, once the payment is completed
+    pub receipt_url: Option<String>,
+    /// Results of the validation of the billing and shipping addresses of the payment
+    pub address_validation: Option<api_models::payments::PaymentAddressValidation>,
+    /// Time at which the authorized amount of the payment is scheduled to be captured
+    pub scheduled_capture_at: Option<time::PrimitiveDateTime>,
+    /// Values of the custom fields of the payment, keyed by the key of the field
+    pub custom_fields: Option<HashMap<String, serde_json::Value>>,
 }
 
 #[derive(Clone, serde::Serialize, Debug)]
@@ -5541,6 +5790,10 @@ where
             storage_enums::IntentStatus::RequiresCapture
                 | storage_enums::IntentStatus::PartiallyCapturedAndCapturable
         ),
+        "PaymentCancelRemaining" => matches!(
+            payment_data.get_payment_intent().status,
+            storage_enums::IntentStatus::PartiallyCapturedAndCapturable
+        ),
         "PaymentCapture" => {
             matches!(
                 payment_data.get_payment_intent().status,
@@ -5572,10 +5825,21 @@ pub fn is_operation_confirm<Op: Debug>(operation: &Op) -> bool {
     matches!(format!("{operation:?}").as_str(), "PaymentConfirm")
 }
 
+pub fn is_operation_confirm_or_create<Op: Debug>(operation: &Op) -> bool {
+    matches!(
+        format!("{operation:?}").as_str(),
+        "PaymentConfirm" | "PaymentCreate"
+    )
+}
+
 pub fn is_operation_complete_authorize<Op: Debug>(operation: &Op) -> bool {
     matches!(format!("{operation:?}").as_str(), "CompleteAuthorize")
 }
 
+pub fn is_operation_cancel_remaining<Op: Debug>(operation: &Op) -> bool {
+    matches!(format!("{operation:?}").as_str(), "PaymentCancelRemaining")
+}
+
 #[cfg(all(feature = "olap", feature = "v1"))]
 pub async fn list_payments(
     state: SessionState,
@@ -5638,11 +5902,13 @@ pub async fn list_payments(
     //Will collect responses in same order async, leading to sorted responses
 
     //Converting Intent-Attempt array to Response if no error
-    let data: Vec<api::PaymentsResponse> = pi_pa_tuple_vec
-        .change_context(errors::ApiErrorResponse::InternalServerError)?
-        .into_iter()
-        .map(ForeignFrom::foreign_from)
-        .collect();
+    let mut data: Vec<api::PaymentsResponse> =
+        custom_status::get_payments_response_with_custom_statuses(
+            db,
+            pi_pa_tuple_vec.change_context(errors::ApiErrorResponse::InternalServerError)?,
+        )
+        .await?;
+    custom_fields::populate_custom_fields_of_payments(db, merchant.get_id(), &mut data).await?;
 
     Ok(services::ApplicationResponse::Json(
         api::PaymentListResponse {
@@ -5741,7 +6007,12 @@ pub async fn apply_filters_on_payments(
             let limit = &constraints.limit;
             helpers::validate_payment_list_request_for_joins(*limit)?;
             let db: &dyn StorageInterface = state.store.as_ref();
-            let pi_fetch_constraints = (constraints.clone(), profile_id_list.clone()).try_into()?;
+            let mut pi_fetch_constraints =
+                (constraints.clone(), profile_id_list.clone()).try_into()?;
+            custom_fields::add_custom_field_filters(
+                &mut pi_fetch_constraints,
+                constraints.custom_fields.as_ref(),
+            )?;
             let list: Vec<(storage::PaymentIntent, storage::PaymentAttempt)> = db
                 .get_filtered_payment_intents_attempt(
                     &(&state).into(),
@@ -5752,8 +6023,10 @@ pub async fn apply_filters_on_payments(
                 )
                 .await
                 .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
-            let data: Vec<api::PaymentsResponse> =
-                list.into_iter().map(ForeignFrom::foreign_from).collect();
+            let mut data: Vec<api::PaymentsResponse> =
+                custom_status::get_payments_response_with_custom_statuses(db, list).await?;
+            custom_fields::populate_custom_fields_of_payments(db, merchant.get_id(), &mut data)
+                .await?;
 
             let active_attempt_ids = db
                 .get_filtered_active_attempt_ids_for_total_count(
@@ -6447,12 +6720,9 @@ where
             }
 
             #[cfg(feature = "retry")]
-            let should_do_retry = retry::config_should_call_gsm(
-                &*state.store,
-                merchant_account.get_id(),
-                business_profile,
-            )
-            .await;
+            let should_do_retry =
+                retry::config_should_call_gsm(&state, merchant_account.get_id(), business_profile)
+                    .await;
 
             #[cfg(feature = "retry")]
             if payment_data.get_payment_attempt().payment_method_type
@@ -6712,6 +6982,15 @@ where
                 network_tokenization_supported_connectors,
             );
 
+            let is_network_tokenization_enabled = is_network_tokenization_enabled
+                && feature_flags::is_feature_enabled(
+                    state,
+                    &payment_data.get_payment_intent().merchant_id,
+                    payment_data.get_payment_intent().profile_id.as_ref(),
+                    storage_enums::MerchantFeatureFlag::NetworkTokenization,
+                )
+                .await;
+
             let action_type = decide_action_type(
                 state,
                 is_connector_agnostic_mit_enabled,
@@ -7194,11 +7473,37 @@ where
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("error performing session flow routing")?;
 
+    // The Google Pay session token is created for the connector which the payment was routed to
+    // at confirm, if the payment method has to be tokenized again for it
+    let payment_intent = payment_data.get_payment_intent();
+    let google_pay_retokenization_connector = google_pay::get_retokenization_connector(
+        &state,
+        &payment_intent.merchant_id,
+        payment_intent.get_id().get_string_repr(),
+    )
+    .await
+    .filter(|merchant_connector_id| {
+        connectors.iter().any(|connector_data| {
+            connector_data.payment_method_type == enums::PaymentMethodType::GooglePay
+                && connector_data.connector.merchant_connector_id.as_ref()
+                    == Some(merchant_connector_id)
+        })
+    });
+
     let mut final_list: Vec<api::SessionConnectorData> = Vec::new();
 
     for connector_data in connectors {
         if !routing_enabled_pms.contains(&connector_data.payment_method_type) {
             final_list.push(connector_data);
+        } else if let Some(merchant_connector_id) = google_pay_retokenization_connector
+            .as_ref()
+            .filter(|_| connector_data.payment_method_type == enums::PaymentMethodType::GooglePay)
+        {
+            if connector_data.connector.merchant_connector_id.as_ref()
+                == Some(merchant_connector_id)
+            {
+                final_list.push(connector_data);
+            }
         } else if let Some(choice) = result.get(&connector_data.payment_method_type) {
             let routing_choice = choice
                 .first()
@@ -7344,6 +7649,40 @@ where
         }
     };
 
+    let connectors = super::connector_maintenance::filter_connectors_under_maintenance(
+        state.store.as_ref(),
+        connectors,
+        payment_data.get_payment_attempt().payment_method,
+    )
+    .await?;
+
+    let connectors = super::connector_credentials::filter_connectors_with_expired_credentials(
+        state.store.as_ref(),
+        connectors,
+    )
+    .await?;
+
+    let connectors = super::test_mode::filter_connectors_for_test_mode(
+        state,
+        merchant_account.get_id(),
+        key_store,
+        business_profile,
+        connectors,
+    )
+    .await?;
+
+    let connectors = super::routing::volume_caps::filter_connectors_over_volume_caps(
+        state,
+        merchant_account.get_id(),
+        key_store,
+        business_profile.get_id(),
+        connectors,
+    )
+    .await;
+
+    let connectors =
+        google_pay::route_to_tokenization_connector(state, payment_data, connectors).await?;
+
     let connector_data = connectors
         .into_iter()
         .map(|conn| {
@@ -7520,8 +7859,8 @@ pub async fn payment_external_authentication<F: Clone + Sync>(
 
     let optional_customer = match &payment_intent.customer_id {
         Some(customer_id) => Some(
-            state
-                .store
+            data_residency::get_pii_store(&state, merchant_account.get_id())
+                .await?
                 .find_customer_by_customer_id_merchant_id(
                     key_manager_state,
                     customer_id,
@@ -7555,6 +7894,7 @@ pub async fn payment_external_authentication<F: Clone + Sync>(
         &key_store,
         &payment_intent.payment_id,
         storage_scheme,
+        None,
     )
     .await?;
     let billing_address = helpers::create_or_find_address_for_payment_by_request(
@@ -7569,6 +7909,7 @@ pub async fn payment_external_authentication<F: Clone + Sync>(
         &key_store,
         &payment_intent.payment_id,
         storage_scheme,
+        None,
     )
     .await?;
     let authentication_connector = payment_attempt
@@ -7586,14 +7927,15 @@ pub async fn payment_external_authentication<F: Clone + Sync>(
         None,
     )
     .await?;
+    let authentication_id = payment_attempt
+        .authentication_id
+        .clone()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("missing authentication_id in payment_attempt")?;
     let authentication = db
         .find_authentication_by_merchant_id_authentication_id(
             merchant_id,
-            payment_attempt
-                .authentication_id
-                .clone()
-                .ok_or(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("missing authentication_id in payment_attempt")?,
+            authentication_id.clone(),
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
@@ -7653,10 +7995,19 @@ pub async fn payment_external_authentication<F: Clone + Sync>(
         .get_required_value("authentication_connector_details")
         .attach_printable("authentication_connector_details not configured by the merchant")?;
 
+    // The payments stepped up when they were confirmed are challenged, like all the payments of the
+    // profiles forcing a challenge
+    let force_3ds_challenge = business_profile.force_3ds_challenge
+        || step_up_authentication::is_stepped_up(&state, merchant_id, &payment_intent.payment_id)
+            .await;
     let authentication_response =
         if helpers::is_merchant_eligible_authentication_service(merchant_account.get_id(), &state)
             .await?
         {
+            let card = match &payment_method_details.0 {
+                domain::PaymentMethodData::Card(card) => Some(card.clone()),
+                _ => None,
+            };
             let auth_response =
                 <ExternalAuthentication as UnifiedAuthenticationService<F>>::authentication(
                     &state,
@@ -7693,6 +8044,14 @@ pub async fn payment_external_authentication<F: Clone + Sync>(
                 None,
             )
             .await?;
+            if let Some(card) = &card {
+                authentication_core::issuer_stats::record_authentication_outcome(
+                    &state,
+                    card,
+                    &authentication,
+                )
+                .await;
+            }
             authentication::AuthenticationResponse::try_from(authentication)?
         } else {
             Box::pin(authentication_core::perform_authentication(
@@ -7723,10 +8082,27 @@ pub async fn payment_external_authentication<F: Clone + Sync>(
                 authentication_details.three_ds_requestor_url.clone(),
                 payment_intent.psd2_sca_exemption_type,
                 payment_intent.payment_id,
-                business_profile.force_3ds_challenge,
+                force_3ds_challenge,
             ))
             .await?
         };
+    if authentication_response.trans_status == common_enums::TransactionStatus::ChallengeRequired {
+        let tracking_data = storage::AuthenticationAbandonmentTrackingData {
+            authentication_id,
+            merchant_id: merchant_id.to_owned(),
+            profile_id: profile_id.to_owned(),
+            payment_id,
+        };
+        if let Err(error) =
+            authentication_core::abandonment::add_challenge_abandonment_task(db, tracking_data)
+                .await
+        {
+            logger::error!(
+                ?error,
+                "Failed to schedule the abandonment check of the authentication challenge"
+            );
+        }
+    }
     Ok(services::ApplicationResponse::Json(
         api_models::payments::PaymentsExternalAuthenticationResponse {
             transaction_status: authentication_response.trans_status,
@@ -8032,6 +8408,31 @@ pub trait OperationSessionGetters<F> {
     #[cfg(feature = "v1")]
     fn get_vault_operation(&self) -> Option<&domain_payments::VaultOperation>;
 
+    #[cfg(feature = "v1")]
+    fn get_custom_status_mapping(
+        &self,
+    ) -> Option<&api_models::payments::CustomPaymentStatusMapping>;
+
+    #[cfg(feature = "v1")]
+    fn get_localized_unified_message(&self) -> Option<&String>;
+
+    #[cfg(feature = "v1")]
+    fn get_connector_response_details(
+        &self,
+    ) -> Option<&api_models::payments::ConnectorResponseDetails>;
+
+    #[cfg(feature = "v1")]
+    fn get_receipt_url(&self) -> Option<&String>;
+
+    #[cfg(feature = "v1")]
+    fn get_address_validation(&self) -> Option<&api_models::payments::PaymentAddressValidation>;
+
+    #[cfg(feature = "v1")]
+    fn get_scheduled_capture_at(&self) -> Option<time::PrimitiveDateTime>;
+
+    #[cfg(feature = "v1")]
+    fn get_custom_fields(&self) -> Option<&HashMap<String, serde_json::Value>>;
+
     #[cfg(feature = "v2")]
     fn get_optional_payment_attempt(&self) -> Option<&storage::PaymentAttempt>;
 }
@@ -8044,6 +8445,8 @@ pub trait OperationSessionSetters<F> {
     fn set_email_if_not_present(&mut self, email: pii::Email);
     fn set_payment_method_id_in_attempt(&mut self, payment_method_id: Option<String>);
     fn set_pm_token(&mut self, token: String);
+    #[cfg(feature = "v1")]
+    fn remove_pm_token(&mut self);
     fn set_connector_customer_id(&mut self, customer_id: Option<String>);
     fn push_sessions_token(&mut self, token: api::SessionToken);
     fn set_surcharge_details(&mut self, surcharge_details: Option<types::SurchargeDetails>);
@@ -8079,6 +8482,30 @@ pub trait OperationSessionSetters<F> {
 
     #[cfg(feature = "v1")]
     fn set_vault_operation(&mut self, vault_operation: domain_payments::VaultOperation);
+
+    #[cfg(feature = "v1")]
+    fn set_custom_status_mapping(
+        &mut self,
+        custom_status_mapping: Option<api_models::payments::CustomPaymentStatusMapping>,
+    );
+
+    #[cfg(feature = "v1")]
+    fn set_localized_unified_message(&mut self, localized_unified_message: Option<String>);
+
+    #[cfg(feature = "v1")]
+    fn set_connector_response_details(
+        &mut self,
+        connector_response_details: Option<api_models::payments::ConnectorResponseDetails>,
+    );
+
+    #[cfg(feature = "v1")]
+    fn set_receipt_url(&mut self, receipt_url: Option<String>);
+
+    #[cfg(feature = "v1")]
+    fn set_scheduled_capture_at(&mut self, scheduled_capture_at: Option<time::PrimitiveDateTime>);
+
+    #[cfg(feature = "v1")]
+    fn set_custom_fields(&mut self, custom_fields: Option<HashMap<String, serde_json::Value>>);
 }
 
 #[cfg(feature = "v1")]
@@ -8218,6 +8645,45 @@ impl<F: Clone> OperationSessionGetters<F> for PaymentData<F> {
         self.vault_operation.as_ref()
     }
 
+    #[cfg(feature = "v1")]
+    fn get_custom_status_mapping(
+        &self,
+    ) -> Option<&api_models::payments::CustomPaymentStatusMapping> {
+        self.custom_status_mapping.as_ref()
+    }
+
+    #[cfg(feature = "v1")]
+    fn get_localized_unified_message(&self) -> Option<&String> {
+        self.localized_unified_message.as_ref()
+    }
+
+    #[cfg(feature = "v1")]
+    fn get_connector_response_details(
+        &self,
+    ) -> Option<&api_models::payments::ConnectorResponseDetails> {
+        self.connector_response_details.as_ref()
+    }
+
+    #[cfg(feature = "v1")]
+    fn get_receipt_url(&self) -> Option<&String> {
+        self.receipt_url.as_ref()
+    }
+
+    #[cfg(feature = "v1")]
+    fn get_address_validation(&self) -> Option<&api_models::payments::PaymentAddressValidation> {
+        self.address_validation.as_ref()
+    }
+
+    #[cfg(feature = "v1")]
+    fn get_scheduled_capture_at(&self) -> Option<time::PrimitiveDateTime> {
+        self.scheduled_capture_at
+    }
+
+    #[cfg(feature = "v1")]
+    fn get_custom_fields(&self) -> Option<&HashMap<String, serde_json::Value>> {
+        self.custom_fields.as_ref()
+    }
+
     // #[cfg(feature = "v2")]
     // fn get_capture_method(&self) -> Option<enums::CaptureMethod> {
     //     Some(self.payment_intent.capture_method)
@@ -8256,6 +8722,11 @@ impl<F: Clone> OperationSessionSetters<F> for PaymentData<F> {
         self.pm_token = Some(token);
     }
 
+    #[cfg(feature = "v1")]
+    fn remove_pm_token(&mut self) {
+        self.pm_token = None;
+    }
+
     fn set_connector_customer_id(&mut self, customer_id: Option<String>) {
         self.connector_customer_id = customer_id;
     }
@@ -8338,6 +8809,36 @@ impl<F: Clone> OperationSessionSetters<F> for PaymentData<F> {
     fn set_vault_operation(&mut self, vault_operation: domain_payments::VaultOperation) {
         self.vault_operation = Some(vault_operation);
     }
+
+    fn set_custom_status_mapping(
+        &mut self,
+        custom_status_mapping: Option<api_models::payments::CustomPaymentStatusMapping>,
+    ) {
+        self.custom_status_mapping = custom_status_mapping;
+    }
+
+    fn set_localized_unified_message(&mut self, localized_unified_message: Option<String>) {
+        self.localized_unified_message = localized_unified_message;
+    }
+
+    fn set_connector_response_details(
+        &mut self,
+        connector_response_details: Option<api_models::payments::ConnectorResponseDetails>,
+    ) {
+        self.connector_response_details = connector_response_details;
+    }
+
+    fn set_receipt_url(&mut self, receipt_url: Option<String>) {
+        self.receipt_url = receipt_url;
+    }
+
+    fn set_scheduled_capture_at(&mut self, scheduled_capture_at: Option<time::PrimitiveDateTime>) {
+        self.scheduled_capture_at = scheduled_capture_at;
+    }
+
+    fn set_custom_fields(&mut self, custom_fields: Option<HashMap<String, serde_json::Value>>) {
+        self.custom_fields = custom_fields;
+    }
 }
 
 #[cfg(feature = "v2")]
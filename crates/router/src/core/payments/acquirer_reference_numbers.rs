@@ -0,0 +1,223 @@
+//! Acquirer reference numbers (ARNs) of the captures of the payments and of the refunds, which the
+//! customers need to trace the funds with their bank. The ARNs are stored from the responses of
+//! the connectors which send them, and can be imported from the settlement files of the other
+//! connectors, either as a JSON array or as a CSV file.
+
+use actix_multipart::form::{bytes::Bytes, MultipartForm};
+use api_models::payments::{
+    AcquirerReferenceNumberImportResponse, AcquirerReferenceNumberObjectType,
+    AcquirerReferenceNumberRecord, AcquirerReferenceNumberRecordError,
+};
+use common_utils::id_type;
+use csv::Reader;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        refunds::settlement_times,
+    },
+    routes::SessionState,
+    services,
+    types::{domain, storage, storage::enums},
+};
+
+const MAX_ACQUIRER_REFERENCE_NUMBER_LENGTH: usize = 64;
+
+#[derive(Debug, MultipartForm)]
+pub struct AcquirerReferenceNumberUploadForm {
+    #[multipart(limit = "1MB")]
+    pub file: Bytes,
+}
+
+pub fn get_acquirer_reference_number_records(
+    form: AcquirerReferenceNumberUploadForm,
+) -> Result<Vec<AcquirerReferenceNumberRecord>, errors::ApiErrorResponse> {
+    Reader::from_reader(form.file.data.as_ref())
+        .deserialize()
+        .collect::<csv::Result<Vec<AcquirerReferenceNumberRecord>>>()
+        .map_err(|error| errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Invalid acquirer reference number file: {error}"),
+        })
+}
+
+/// Stores the acquirer reference number of the capture of the attempt
+#[instrument(skip_all)]
+pub async fn update_payment_attempt_acquirer_reference_number(
+    state: &SessionState,
+    payment_attempt: storage::PaymentAttempt,
+    acquirer_reference_number: String,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<storage::PaymentAttempt> {
+    state
+        .store
+        .update_payment_attempt_with_attempt_id(
+            payment_attempt,
+            storage::PaymentAttemptUpdate::AcquirerReferenceNumberUpdate {
+                acquirer_reference_number,
+                updated_by: storage_scheme.to_string(),
+            },
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+}
+
+fn is_accessible_from_profile(
+    profile_id: Option<&id_type::ProfileId>,
+    object_profile_id: Option<&id_type::ProfileId>,
+) -> bool {
+    profile_id.map_or(true, |profile_id| object_profile_id == Some(profile_id))
+}
+
+/// Stores the acquirer reference number of a record on the payment attempt or the refund it refers
+/// to, and returns the reason due to which the record could not be applied otherwise
+async fn apply_acquirer_reference_number_record(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    profile_id: Option<&id_type::ProfileId>,
+    record: AcquirerReferenceNumberRecord,
+) -> Result<(), String> {
+    let acquirer_reference_number = record.acquirer_reference_number.trim().to_owned();
+    if acquirer_reference_number.is_empty() {
+        return Err("acquirer_reference_number must not be empty".to_string());
+    }
+    if acquirer_reference_number.len() > MAX_ACQUIRER_REFERENCE_NUMBER_LENGTH {
+        return Err(format!(
+            "acquirer_reference_number must be at most {MAX_ACQUIRER_REFERENCE_NUMBER_LENGTH} characters long"
+        ));
+    }
+
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+    let storage_scheme = merchant_account.storage_scheme;
+    let connector_reference_id = record.connector_reference_id.trim();
+
+    match record.object_type {
+        AcquirerReferenceNumberObjectType::Payment => {
+            let payment_attempt = db
+                .find_payment_attempt_by_merchant_id_connector_txn_id(
+                    merchant_id,
+                    connector_reference_id,
+                    storage_scheme,
+                )
+                .await
+                .ok()
+                .filter(|payment_attempt| {
+                    payment_attempt.connector.as_deref() == Some(record.connector.as_str())
+                        && is_accessible_from_profile(profile_id, Some(&payment_attempt.profile_id))
+                })
+                .ok_or_else(|| "No payment found for the connector reference".to_string())?;
+
+            if payment_attempt.acquirer_reference_number.as_ref()
+                != Some(&acquirer_reference_number)
+            {
+                update_payment_attempt_acquirer_reference_number(
+                    state,
+                    payment_attempt,
+                    acquirer_reference_number,
+                    storage_scheme,
+                )
+                .await
+                .map_err(|_| "Failed to update the payment".to_string())?;
+            }
+        }
+        AcquirerReferenceNumberObjectType::Refund => {
+            let refund = db
+                .find_refund_by_merchant_id_connector_refund_id_connector(
+                    merchant_id,
+                    connector_reference_id,
+                    &record.connector,
+                    storage_scheme,
+                )
+                .await
+                .ok()
+                .filter(|refund| is_accessible_from_profile(profile_id, refund.profile_id.as_ref()))
+                .ok_or_else(|| "No refund found for the connector reference".to_string())?;
+
+            if refund.refund_arn.as_ref() != Some(&acquirer_reference_number) {
+                let is_first_settlement = refund.refund_arn.is_none();
+                let refund = db
+                    .update_refund(
+                        refund,
+                        storage::RefundUpdate::AcquirerReferenceNumberUpdate {
+                            refund_arn: acquirer_reference_number,
+                            updated_by: storage_scheme.to_string(),
+                        },
+                        storage_scheme,
+                    )
+                    .await
+                    .map_err(|_| "Failed to update the refund".to_string())?;
+
+                // The settlement file reports the settlement of the refund, whose time refines
+                // the estimated arrival of the later refunds
+                if is_first_settlement {
+                    settlement_times::record_settlement_time(state, merchant_account, &refund)
+                        .await
+                        .inspect_err(|error| {
+                            logger::error!(?error, "Failed to record the refund settlement time")
+                        })
+                        .ok();
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Imports the acquirer reference numbers read from the settlement files of the connectors. The
+/// records are applied independently of each other, and the records which could not be applied
+/// are returned.
+#[instrument(skip_all)]
+pub async fn import_acquirer_reference_numbers(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    records: Vec<AcquirerReferenceNumberRecord>,
+) -> RouterResponse<AcquirerReferenceNumberImportResponse> {
+    if records.is_empty() {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "At least one acquirer reference number must be provided".to_string(),
+        }
+        .into());
+    }
+    if records.len() > consts::MAX_ACQUIRER_REFERENCE_NUMBER_IMPORT_SIZE {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "At most {} acquirer reference numbers can be imported at once",
+                consts::MAX_ACQUIRER_REFERENCE_NUMBER_IMPORT_SIZE
+            ),
+        }
+        .into());
+    }
+
+    let total_count = records.len();
+    let mut failed_records = Vec::new();
+    for (index, record) in records.into_iter().enumerate() {
+        let connector_reference_id = record.connector_reference_id.clone();
+        if let Err(error_message) = apply_acquirer_reference_number_record(
+            &state,
+            &merchant_account,
+            profile_id.as_ref(),
+            record,
+        )
+        .await
+        {
+            failed_records.push(AcquirerReferenceNumberRecordError {
+                row_number: index + 1,
+                connector_reference_id,
+                error_message,
+            });
+        }
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        AcquirerReferenceNumberImportResponse {
+            total_count,
+            updated_count: total_count - failed_records.len(),
+            failed_records,
+        },
+    ))
+}
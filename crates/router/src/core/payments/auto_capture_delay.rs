@@ -0,0 +1,249 @@
+//! Delayed capture of the payments with automatic capture of the profiles with an auto capture
+//! delay, which gives the merchant a window to review the payments for fraud without having to
+//! capture them manually. Such payments are authorized with manual capture when their connector
+//! supports it for their payment method, and their capture is scheduled once the delay elapses
+//! after they are confirmed, through the scheduled capture of the payment. The payments flagged
+//! for review by the FRM connector await the decision of the merchant and are not captured by
+//! then, while the capture can be released or canceled early through the scheduled capture API.
+
+use api_models::payments::AutoCaptureDelayConfig;
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    id_type,
+    types::MinorUnit,
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use super::{
+    partial_payments, scheduled_capture, OperationSessionGetters, OperationSessionSetters,
+};
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services::{self, api::ConnectorValidation},
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+};
+
+#[instrument(skip_all)]
+pub async fn upsert_auto_capture_delay_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: AutoCaptureDelayConfig,
+) -> RouterResponse<AutoCaptureDelayConfig> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    request.validate().map_err(|error| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: error.current_context().to_string(),
+        })
+    })?;
+
+    let key = profile_id.get_auto_capture_delay_config_key();
+    let serialized_config = request
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize auto capture delay config")?;
+
+    match db.find_config_by_key(&key).await {
+        Ok(_) => {
+            db.update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(serialized_config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update auto capture delay config")?;
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            db.insert_config(configs::ConfigNew {
+                key,
+                config: serialized_config,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert auto capture delay config")?;
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch auto capture delay config");
+        }
+    }
+
+    Ok(services::ApplicationResponse::Json(request))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_auto_capture_delay_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<AutoCaptureDelayConfig> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    let config = get_auto_capture_delay_config(db, &profile_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Auto capture delay config not found for the profile".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(config))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_auto_capture_delay_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<()> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    db.delete_config_by_key(&profile_id.get_auto_capture_delay_config_key())
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::ApiErrorResponse::GenericNotFoundError {
+                    message: "Auto capture delay config not found for the profile".to_string(),
+                })
+            } else {
+                error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to delete auto capture delay config")
+            }
+        })?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+pub async fn get_auto_capture_delay_config(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Option<AutoCaptureDelayConfig>> {
+    // The auto capture delay is looked up for every payment confirmed, so its absence is cached
+    // as well
+    db.find_config_by_key_unwrap_or(
+        &profile_id.get_auto_capture_delay_config_key(),
+        Some("null".to_string()),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch auto capture delay config")?
+    .config
+    .parse_struct::<Option<AutoCaptureDelayConfig>>("AutoCaptureDelayConfig")
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to parse auto capture delay config")
+}
+
+/// Whether the capture of the payment can be delayed, which is the case for the payments with
+/// automatic capture of an amount, whose connector supports the manual capture of their payment
+/// method. The payments with partial payments enabled are not delayed, as each of their attempts
+/// pays its part once it is captured.
+fn is_capture_delayable(
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    connector: &api::ConnectorData,
+) -> bool {
+    matches!(
+        payment_attempt.capture_method,
+        None | Some(enums::CaptureMethod::Automatic)
+    ) && payment_intent.amount > MinorUnit::zero()
+        && !partial_payments::is_partial_payments_enabled(payment_intent)
+        && payment_attempt
+            .payment_method
+            .is_some_and(|payment_method| {
+                connector
+                    .connector
+                    .validate_connector_against_payment_request(
+                        Some(enums::CaptureMethod::Manual),
+                        payment_method,
+                        payment_attempt.payment_method_type,
+                    )
+                    .is_ok()
+            })
+}
+
+/// Holds the capture of a payment with automatic capture which is being confirmed, if the profile
+/// of the payment has an auto capture delay. This is done once the connector of the payment has
+/// been decided, as the payment is then authorized with manual capture, and its capture is
+/// scheduled once the delay elapses. The payments whose connector does not support the manual
+/// capture of their payment method are captured right away. The scheduled capture is finished
+/// without capturing the payment if the payment has not been authorized by then.
+#[instrument(skip_all)]
+pub async fn hold_capture<F, D>(
+    db: &dyn StorageInterface,
+    payment_data: &mut D,
+    connector: &api::ConnectorData,
+) -> RouterResult<()>
+where
+    F: Clone,
+    D: OperationSessionGetters<F> + OperationSessionSetters<F>,
+{
+    let payment_intent = payment_data.get_payment_intent();
+    if !is_capture_delayable(
+        payment_intent,
+        payment_data.get_payment_attempt(),
+        connector,
+    ) {
+        return Ok(());
+    }
+
+    let Some(profile_id) = payment_intent.profile_id.as_ref() else {
+        return Ok(());
+    };
+    let Some(config) = get_auto_capture_delay_config(db, profile_id).await? else {
+        return Ok(());
+    };
+
+    let existing_process =
+        scheduled_capture::find_scheduled_capture(db, &payment_intent.payment_id).await?;
+    scheduled_capture::schedule_capture(
+        db,
+        &payment_intent.merchant_id,
+        &payment_intent.payment_id,
+        existing_process,
+        common_utils::date_time::now().saturating_add(config.get_delay()),
+    )
+    .await?;
+
+    payment_data.set_capture_method_in_attempt(enums::CaptureMethod::Manual);
+    Ok(())
+}
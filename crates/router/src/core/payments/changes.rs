@@ -0,0 +1,117 @@
+//! Polling of the payments whose status changed, for the merchants which cannot receive webhooks.
+//! Every payment intent is assigned the next value of a database sequence when it is created and
+//! whenever its status changes, and the cursor of a poll is the last sequence returned to the
+//! merchant. The changes made within the last few seconds are held back, so that a change assigned
+//! an earlier sequence by a transaction that commits later is not skipped by the cursor.
+
+use api_models::payments::{PaymentChange, PaymentsChangesRequest, PaymentsChangesResponse};
+use common_utils::id_type;
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse},
+    routes::SessionState,
+    services,
+    types::domain,
+};
+
+fn parse_cursor(cursor: Option<&str>) -> Result<i64, errors::ApiErrorResponse> {
+    cursor
+        .map(|cursor| {
+            cursor
+                .trim()
+                .parse::<i64>()
+                .ok()
+                .filter(|change_sequence| *change_sequence >= 0)
+                .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "since_cursor is not a valid cursor".to_string(),
+                })
+        })
+        .transpose()
+        .map(|change_sequence| change_sequence.unwrap_or_default())
+}
+
+#[instrument(skip_all)]
+pub async fn list_payment_changes(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    request: PaymentsChangesRequest,
+) -> RouterResponse<PaymentsChangesResponse> {
+    let since = parse_cursor(request.since_cursor.as_deref())?;
+    let limit = request
+        .limit
+        .unwrap_or(consts::DEFAULT_PAYMENTS_CHANGES_LIMIT);
+    if limit == 0 || limit > consts::MAX_PAYMENTS_CHANGES_LIMIT {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "limit must be between 1 and {}",
+                consts::MAX_PAYMENTS_CHANGES_LIMIT
+            ),
+        }
+        .into());
+    }
+
+    let modified_before = common_utils::date_time::now()
+        - time::Duration::seconds(consts::PAYMENTS_CHANGES_SETTLE_TIME_IN_SECONDS);
+
+    // One more payment than the limit is fetched to know whether more changes are available
+    let mut payment_intents = state
+        .store
+        .find_payment_intents_changed_after(
+            merchant_account.get_id(),
+            profile_id.as_ref(),
+            since,
+            modified_before,
+            i64::from(limit) + 1,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the changed payment intents")?;
+
+    let has_more = payment_intents.len() > usize::try_from(limit).unwrap_or(usize::MAX);
+    payment_intents.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+
+    let next_cursor = payment_intents
+        .last()
+        .and_then(|payment_intent| payment_intent.change_sequence)
+        .unwrap_or(since);
+
+    let data = payment_intents
+        .into_iter()
+        .map(|payment_intent| PaymentChange {
+            payment_id: payment_intent.payment_id,
+            status: payment_intent.status,
+            amount: payment_intent.amount,
+            amount_captured: payment_intent.amount_captured,
+            currency: payment_intent.currency,
+            profile_id: payment_intent.profile_id,
+            merchant_order_reference_id: payment_intent.merchant_order_reference_id,
+            attempt_id: payment_intent.active_attempt_id,
+            modified_at: payment_intent.modified_at,
+        })
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentsChangesResponse {
+            data,
+            next_cursor: next_cursor.to_string(),
+            has_more,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cursor() {
+        assert_eq!(parse_cursor(None).ok(), Some(0));
+        assert_eq!(parse_cursor(Some("104857")).ok(), Some(104857));
+        assert!(parse_cursor(Some("-1")).is_err());
+        assert!(parse_cursor(Some("pay_123")).is_err());
+    }
+}
@@ -2,15 +2,20 @@ use api_models::{conditional_configs::DecisionManagerRecord, routing};
 use common_utils::ext_traits::StringExt;
 use error_stack::ResultExt;
 use euclid::backend::{self, inputs as dsl_inputs, EuclidBackend};
+#[cfg(feature = "v1")]
+use euclid::types::EuclidKey;
 use router_env::{instrument, tracing};
 use storage_impl::redis::cache::{self, DECISION_MANAGER_CACHE};
 
 use super::routing::make_dsl_input;
+#[cfg(feature = "v1")]
+use crate::core::authentication::issuer_stats;
 #[cfg(feature = "v2")]
-use crate::{core::errors::RouterResult, types::domain};
+use crate::core::errors::RouterResult;
 use crate::{
     core::{errors, errors::ConditionalConfigError as ConfigError, routing as core_routing},
     routes,
+    types::domain,
 };
 pub type ConditionalConfigResult<O> = errors::CustomResult<O, ConfigError>;
 
@@ -54,8 +59,17 @@ pub async fn perform_decision_management(
     .await
     .change_context(ConfigError::DslCachePoisoned)?;
 
-    let backend_input =
+    let mut backend_input =
         make_dsl_input(payment_data).change_context(ConfigError::InputConstructionError)?;
+    // The rate is looked up only for the programs which have a condition on it
+    if let Some(domain::PaymentMethodData::Card(card)) = payment_data
+        .payment_method_data
+        .filter(|_| interpreter.references_key(&EuclidKey::IssuerFrictionlessRate))
+    {
+        backend_input.payment.issuer_frictionless_rate =
+            issuer_stats::get_issuer_frictionless_rate(state, &card.card_number.get_card_isin())
+                .await;
+    }
 
     execute_dsl_and_get_conditional_config(backend_input, &interpreter)
 }
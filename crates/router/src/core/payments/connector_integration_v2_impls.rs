@@ -56,6 +56,8 @@ mod dummy_connector_default_impl {
 
     impl<const T: u8> api::PaymentPostSessionTokensV2 for connector::DummyConnector<T> {}
 
+    impl<const T: u8> api::PaymentGiftCardBalanceCheckV2 for connector::DummyConnector<T> {}
+
     impl<const T: u8>
         services::ConnectorIntegrationV2<
             api::Authorize,
@@ -222,6 +224,15 @@ mod dummy_connector_default_impl {
         > for connector::DummyConnector<T>
     {
     }
+    impl<const T: u8>
+        services::ConnectorIntegrationV2<
+            api::Balance,
+            types::PaymentFlowData,
+            types::GiftCardBalanceCheckRequestData,
+            types::GiftCardBalanceCheckResponseData,
+        > for connector::DummyConnector<T>
+    {
+    }
 
     impl<const T: u8>
         services::ConnectorIntegrationV2<
@@ -601,6 +612,7 @@ macro_rules! default_imp_for_new_connector_integration_payment {
             impl api::TaxCalculationV2 for $path::$connector{}
             impl api::PaymentSessionUpdateV2 for $path::$connector{}
             impl api::PaymentPostSessionTokensV2 for $path::$connector{}
+            impl api::PaymentGiftCardBalanceCheckV2 for $path::$connector{}
             impl
             services::ConnectorIntegrationV2<api::Authorize,types::PaymentFlowData, types::PaymentsAuthorizeData, types::PaymentsResponseData>
             for $path::$connector{}
@@ -693,6 +705,13 @@ macro_rules! default_imp_for_new_connector_integration_payment {
                 types::PaymentsPostSessionTokensData,
                 types::PaymentsResponseData,
                 > for $path::$connector{}
+
+            impl services::ConnectorIntegrationV2<
+            api::Balance,
+            types::PaymentFlowData,
+                types::GiftCardBalanceCheckRequestData,
+                types::GiftCardBalanceCheckResponseData,
+                > for $path::$connector{}
     )*
     };
 }
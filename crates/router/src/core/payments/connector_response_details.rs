@@ -0,0 +1,124 @@
+//! Subset of the response of the connector sent in the payment responses and the webhooks of the
+//! profiles which have opted in with the `connector_response_details` feature flag. The results of
+//! the checks are read from the checks stored with the card of the attempt, whose structure varies
+//! from connector to connector, and only the known keys are picked, so that nothing else of the
+//! raw response of the connector is sent to the merchants.
+
+use api_models::payments::{AdditionalPaymentData, ConnectorResponseDetails};
+use common_utils::ext_traits::ValueExt;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        feature_flags,
+        payments::{OperationSessionGetters, OperationSessionSetters},
+    },
+    routes::SessionState,
+    types::storage::{self, enums},
+};
+
+/// Keys of the result of the address verification in the checks of the connectors
+pub const AVS_RESULT_KEYS: &[&str] = &[
+    "avs_result",
+    "avs_response",
+    "avs_check",
+    "address_postal_code_check",
+];
+
+/// Keys of the result of the verification of the card verification value in the checks of the
+/// connectors
+pub const CVV_RESULT_KEYS: &[&str] = &[
+    "cvv_result",
+    "card_verification",
+    "cvc_check",
+    "card_validation_result",
+];
+
+/// Keys of the reference number of the acquirer in the checks of the connectors
+const ACQUIRER_REFERENCE_NUMBER_KEYS: &[&str] = &["acquirer_reference_number", "arn"];
+
+/// Keys of the advice code of the card network in the checks of the connectors
+const NETWORK_ADVICE_CODE_KEYS: &[&str] = &["network_advice_code", "merchant_advice_code"];
+
+/// Keys of the code in the checks which some connectors send as objects, such as
+/// `{"code": "Y", "codeRaw": "Y"}`
+const NESTED_CODE_KEYS: &[&str] = &["code", "resultCode"];
+
+/// Returns the value of the first of the keys present in the checks, as a string
+pub fn get_check_value(checks: &serde_json::Value, keys: &[&str]) -> Option<String> {
+    keys.iter()
+        .filter_map(|key| checks.get(key))
+        .find_map(|value| match value {
+            serde_json::Value::String(value) => Some(value.clone()),
+            serde_json::Value::Number(value) => Some(value.to_string()),
+            serde_json::Value::Object(_) => get_check_value(value, NESTED_CODE_KEYS),
+            serde_json::Value::Null | serde_json::Value::Bool(_) | serde_json::Value::Array(_) => {
+                None
+            }
+        })
+}
+
+/// Returns the checks of the connector stored with the card of the attempt
+pub fn get_payment_checks(payment_attempt: &storage::PaymentAttempt) -> Option<serde_json::Value> {
+    let additional_payment_data = payment_attempt
+        .payment_method_data
+        .clone()?
+        .parse_value::<AdditionalPaymentData>("AdditionalPaymentData")
+        .map_err(|error| {
+            logger::warn!(
+                ?error,
+                "Failed to parse the payment method data of the attempt"
+            );
+        })
+        .ok()?;
+
+    let AdditionalPaymentData::Card(card) = additional_payment_data else {
+        return None;
+    };
+    card.payment_checks
+}
+
+/// Picks the details of the response of the connector from the checks stored with the card of the
+/// attempt and the reference number of the acquirer of the attempt, or `None` if the connector
+/// did not send any of them
+pub fn get_connector_response_details(
+    payment_attempt: &storage::PaymentAttempt,
+) -> Option<ConnectorResponseDetails> {
+    let checks = get_payment_checks(payment_attempt).unwrap_or_default();
+
+    let details = ConnectorResponseDetails {
+        avs_result: get_check_value(&checks, AVS_RESULT_KEYS),
+        cvv_result: get_check_value(&checks, CVV_RESULT_KEYS),
+        acquirer_reference_number: payment_attempt
+            .acquirer_reference_number
+            .clone()
+            .or_else(|| get_check_value(&checks, ACQUIRER_REFERENCE_NUMBER_KEYS)),
+        network_advice_code: get_check_value(&checks, NETWORK_ADVICE_CODE_KEYS),
+    };
+
+    (details != ConnectorResponseDetails::default()).then_some(details)
+}
+
+/// Sets the details of the response of the connector of the latest attempt of the payment, if the
+/// profile of the payment has opted in to receive them
+#[instrument(skip_all)]
+pub async fn populate_connector_response_details<F, D>(state: &SessionState, payment_data: &mut D)
+where
+    F: Clone,
+    D: OperationSessionGetters<F> + OperationSessionSetters<F>,
+{
+    let payment_attempt = payment_data.get_payment_attempt();
+    let is_enabled = feature_flags::is_feature_enabled(
+        state,
+        &payment_attempt.merchant_id,
+        Some(&payment_attempt.profile_id),
+        enums::MerchantFeatureFlag::ConnectorResponseDetails,
+    )
+    .await;
+    if !is_enabled {
+        return;
+    }
+
+    let connector_response_details = get_connector_response_details(payment_attempt);
+    payment_data.set_connector_response_details(connector_response_details);
+}
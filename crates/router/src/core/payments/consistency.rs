@@ -0,0 +1,161 @@
+//! Read-your-writes consistency of the payments. The payment responses carry a token identifying
+//! the version of the payment in the response, and a retrieve of the payment with the token waits
+//! until the storage layer serves at least that version. Without the token, a retrieve right
+//! after a create or an update may be served by a read replica, or from the database before the
+//! changes in the KV store are drained, and miss the write.
+
+use std::time::Duration;
+
+use base64::Engine;
+use common_utils::id_type;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResult},
+    routes::SessionState,
+    types::{api, domain, storage::enums},
+};
+
+/// Contents of the opaque consistency token of a payment
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct ConsistencyToken {
+    payment_id: id_type::PaymentId,
+    /// The time at which the payment was last modified, in microseconds since the epoch
+    modified_at: i64,
+}
+
+/// The database stores the timestamps with a precision of microseconds, the finer precision of
+/// the timestamps in memory is truncated so that the stored version is never newer than the
+/// version in the token
+fn get_modified_at_micros(modified_at: PrimitiveDateTime) -> i64 {
+    i64::try_from(modified_at.assume_utc().unix_timestamp_nanos() / 1000).unwrap_or(i64::MAX)
+}
+
+/// Returns the consistency token identifying the given version of the payment
+pub fn generate_consistency_token(
+    payment_id: &id_type::PaymentId,
+    modified_at: PrimitiveDateTime,
+) -> Option<String> {
+    let token = ConsistencyToken {
+        payment_id: payment_id.clone(),
+        modified_at: get_modified_at_micros(modified_at),
+    };
+
+    serde_json::to_vec(&token)
+        .map(|token| base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(token))
+        .inspect_err(|error| logger::error!(?error, "Failed to serialize the consistency token"))
+        .ok()
+}
+
+fn parse_consistency_token(token: &str) -> RouterResult<ConsistencyToken> {
+    base64::engine::general_purpose::URL_SAFE_NO_PAD
+        .decode(token)
+        .ok()
+        .and_then(|token| serde_json::from_slice(&token).ok())
+        .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Invalid consistency_token".to_string(),
+        })
+        .map_err(error_stack::Report::from)
+}
+
+/// Waits until the payment read by the storage layer is at least the version identified by the
+/// consistency token. The payment is read from the KV store or the master database, as a replica
+/// may lag behind the write. The wait is bounded, and once the bound elapses the payment is read
+/// as is.
+#[instrument(skip_all)]
+pub async fn wait_for_payment_version(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    key_store: &domain::MerchantKeyStore,
+    storage_scheme: enums::MerchantStorageScheme,
+    payment_id: &api::PaymentIdType,
+    consistency_token: &str,
+) -> RouterResult<()> {
+    let token = parse_consistency_token(consistency_token)?;
+
+    if let api::PaymentIdType::PaymentIntentId(payment_id) = payment_id {
+        if *payment_id != token.payment_id {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "consistency_token does not belong to the payment".to_string(),
+            }
+            .into());
+        }
+    }
+
+    let db = &*state.store;
+    let max_wait = Duration::from_millis(consts::PAYMENT_CONSISTENCY_MAX_WAIT_IN_MILLISECONDS);
+    let poll_interval =
+        Duration::from_millis(consts::PAYMENT_CONSISTENCY_POLL_INTERVAL_IN_MILLISECONDS);
+    let started_at = tokio::time::Instant::now();
+
+    loop {
+        let payment_intent = db
+            .find_payment_intent_by_payment_id_merchant_id_from_master(
+                &state.into(),
+                &token.payment_id,
+                merchant_id,
+                key_store,
+                storage_scheme,
+            )
+            .await;
+
+        match payment_intent {
+            Ok(payment_intent)
+                if get_modified_at_micros(payment_intent.modified_at) >= token.modified_at =>
+            {
+                return Ok(());
+            }
+            // The write has not propagated to the storage serving the read yet
+            Ok(_) => (),
+            Err(error) if error.current_context().is_db_not_found() => (),
+            Err(error) => {
+                return Err(error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed while waiting for the version of the payment"));
+            }
+        }
+
+        if started_at.elapsed() >= max_wait {
+            logger::warn!(
+                payment_id = token.payment_id.get_string_repr(),
+                "Version of the payment in the consistency token not visible within the wait"
+            );
+            return Ok(());
+        }
+
+        tokio::time::sleep(poll_interval).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_consistency_token_round_trip() {
+        let payment_id =
+            id_type::PaymentId::try_from(std::borrow::Cow::Borrowed("pay_123")).unwrap();
+        let modified_at = common_utils::date_time::now();
+
+        let token = generate_consistency_token(&payment_id, modified_at).unwrap();
+        let parsed_token = parse_consistency_token(&token).unwrap();
+
+        assert_eq!(parsed_token.payment_id, payment_id);
+        assert_eq!(
+            parsed_token.modified_at,
+            get_modified_at_micros(modified_at)
+        );
+        assert!(parse_consistency_token("not a token").is_err());
+    }
+
+    #[test]
+    fn test_modified_at_is_truncated_to_micros() {
+        let modified_at = time::macros::datetime!(2025-04-01 10:00:00.123456789);
+
+        assert_eq!(get_modified_at_micros(modified_at), 1_743_501_600_123_456);
+    }
+}
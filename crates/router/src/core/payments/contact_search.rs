@@ -0,0 +1,353 @@
+//! Search of the payments of a merchant by the contact details of the customer. The email and the
+//! phone number of the customer are stored on the payment intent as keyed hashes when the payment
+//! is created, so that the payments can be looked up by them without the contact details being
+//! stored or indexed in plain text. The searches are counted against a quota of the merchant, as
+//! they can otherwise be used to enumerate the customers of the merchant.
+
+#[cfg(feature = "olap")]
+use api_models::payments::{
+    PaymentContactSearchResult, PaymentsContactSearchRequest, PaymentsContactSearchResponse,
+};
+use common_utils::{crypto, pii::Email};
+#[cfg(feature = "olap")]
+use common_utils::{date_time, id_type};
+use error_stack::ResultExt;
+#[cfg(feature = "olap")]
+use hyperswitch_domain_models::payments::payment_intent::{
+    PaymentIntentFetchConstraints, PaymentIntentListParams,
+};
+use masking::{ExposeInterface, PeekInterface, Secret};
+#[cfg(feature = "olap")]
+use router_env::{instrument, logger, tracing};
+
+#[cfg(feature = "olap")]
+use super::helpers;
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResult},
+        utils as core_utils,
+    },
+    types::{api, domain},
+};
+#[cfg(feature = "olap")]
+use crate::{
+    core::errors::{RouterResponse, StorageErrorExt},
+    routes::SessionState,
+    services,
+};
+
+/// The key with which the contact details of the customers of the merchant are hashed
+fn get_contact_search_key(key_store: &domain::MerchantKeyStore) -> RouterResult<Vec<u8>> {
+    core_utils::derive_merchant_key(
+        key_store.key.get_inner().peek(),
+        consts::CONTACT_SEARCH_KEY_LABEL,
+    )
+}
+
+fn hash_contact_detail(key: &[u8], kind: &str, value: &str) -> RouterResult<String> {
+    crypto::SignMessage::sign_message(
+        &crypto::HmacSha256,
+        key,
+        format!("{kind}:{value}").as_bytes(),
+    )
+    .map(hex::encode)
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to hash the contact details of the customer")
+}
+
+fn normalize_email(email: &Email) -> Option<String> {
+    let email = email.clone().expose().expose().trim().to_lowercase();
+    (!email.is_empty()).then_some(email)
+}
+
+fn normalize_phone(phone: &Secret<String>, phone_country_code: Option<&str>) -> Option<String> {
+    let number = phone
+        .peek()
+        .chars()
+        .filter(char::is_ascii_digit)
+        .collect::<String>();
+    if number.is_empty() {
+        return None;
+    }
+
+    let country_code = phone_country_code
+        .unwrap_or_default()
+        .chars()
+        .filter(char::is_ascii_digit)
+        .collect::<String>();
+
+    Some(format!("{country_code}{number}"))
+}
+
+/// Hashes the email of a customer, ignoring the case and the surrounding whitespace
+pub fn hash_customer_email(
+    key_store: &domain::MerchantKeyStore,
+    email: &Email,
+) -> RouterResult<Option<String>> {
+    normalize_email(email)
+        .map(|email| hash_contact_detail(&get_contact_search_key(key_store)?, "email", &email))
+        .transpose()
+}
+
+/// Hashes the phone number of a customer along with its country code, ignoring everything other
+/// than the digits so that the formatting of the number does not affect the hash
+pub fn hash_customer_phone(
+    key_store: &domain::MerchantKeyStore,
+    phone: &Secret<String>,
+    phone_country_code: Option<&str>,
+) -> RouterResult<Option<String>> {
+    normalize_phone(phone, phone_country_code)
+        .map(|phone| hash_contact_detail(&get_contact_search_key(key_store)?, "phone", &phone))
+        .transpose()
+}
+
+/// Returns the hashes of the email and the phone number of the customer of a payment, taken from
+/// the customer details of the request, falling back to the billing details
+pub fn get_customer_contact_hashes(
+    key_store: &domain::MerchantKeyStore,
+    request: &api::PaymentsRequest,
+) -> RouterResult<(Option<String>, Option<String>)> {
+    let customer = request.customer.as_ref();
+    let billing = request.billing.as_ref();
+
+    let email = request
+        .email
+        .as_ref()
+        .or(customer.and_then(|customer| customer.email.as_ref()))
+        .or(billing.and_then(|billing| billing.email.as_ref()));
+
+    let (phone, phone_country_code) = match (
+        request.phone.as_ref(),
+        customer.and_then(|customer| customer.phone.as_ref()),
+        billing
+            .and_then(|billing| billing.phone.as_ref())
+            .and_then(|phone| phone.number.as_ref().map(|number| (number, phone))),
+    ) {
+        (Some(phone), _, _) => (Some(phone), request.phone_country_code.as_deref()),
+        (None, Some(phone), _) => (
+            Some(phone),
+            customer.and_then(|customer| customer.phone_country_code.as_deref()),
+        ),
+        (None, None, Some((number, phone))) => (Some(number), phone.country_code.as_deref()),
+        (None, None, None) => (None, None),
+    };
+
+    let email_hash = email
+        .map(|email| hash_customer_email(key_store, email))
+        .transpose()?
+        .flatten();
+    let phone_hash = phone
+        .map(|phone| hash_customer_phone(key_store, phone, phone_country_code))
+        .transpose()?
+        .flatten();
+
+    Ok((email_hash, phone_hash))
+}
+
+#[cfg(feature = "olap")]
+fn get_contact_search_quota_redis_key(merchant_id: &id_type::MerchantId, window: i64) -> String {
+    format!(
+        "{}_payments_contact_search_quota_{window}",
+        merchant_id.get_string_repr()
+    )
+}
+
+/// Counts the search against the quota of the merchant for the current window, and fails if the
+/// quota is exhausted. The search is allowed if the quota cannot be checked.
+#[cfg(feature = "olap")]
+async fn check_contact_search_quota(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<()> {
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection");
+            return Ok(());
+        }
+    };
+
+    let now = date_time::now_unix_timestamp();
+    let window = now / consts::PAYMENTS_CONTACT_SEARCH_QUOTA_WINDOW_SECONDS;
+    let key = get_contact_search_quota_redis_key(merchant_id, window);
+
+    let searches_count = match redis_conn
+        .increment_fields_in_hash(&key.as_str().into(), &[("searches", 1)])
+        .await
+    {
+        Ok(values) => values.first().copied().unwrap_or_default(),
+        Err(error) => {
+            logger::error!(?error, "Failed to count the search against the quota");
+            return Ok(());
+        }
+    };
+
+    if let Err(error) = redis_conn
+        .set_expiry(
+            &key.as_str().into(),
+            consts::PAYMENTS_CONTACT_SEARCH_QUOTA_WINDOW_SECONDS,
+        )
+        .await
+    {
+        logger::error!(?error, "Failed to set expiry for the search quota");
+    }
+
+    let quota = usize::try_from(consts::PAYMENTS_CONTACT_SEARCH_QUOTA).unwrap_or(usize::MAX);
+    if searches_count > quota {
+        let window_end = (window + 1) * consts::PAYMENTS_CONTACT_SEARCH_QUOTA_WINDOW_SECONDS;
+        return Err(errors::ApiErrorResponse::TooManyRequests {
+            retry_after: window_end - now,
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn search_payments_by_contact(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    request: PaymentsContactSearchRequest,
+) -> RouterResponse<PaymentsContactSearchResponse> {
+    let limit = request
+        .limit
+        .unwrap_or(consts::DEFAULT_PAYMENTS_CONTACT_SEARCH_LIMIT);
+    if limit == 0 || limit > consts::MAX_PAYMENTS_CONTACT_SEARCH_LIMIT {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "limit should be between 1 and {}",
+                consts::MAX_PAYMENTS_CONTACT_SEARCH_LIMIT
+            ),
+        }
+        .into());
+    }
+
+    let customer_email_hash = request
+        .email
+        .as_ref()
+        .map(|email| hash_customer_email(&key_store, email))
+        .transpose()?
+        .flatten();
+    let customer_phone_hash = request
+        .phone
+        .as_ref()
+        .map(|phone| hash_customer_phone(&key_store, phone, request.phone_country_code.as_deref()))
+        .transpose()?
+        .flatten();
+
+    if customer_email_hash.is_none() && customer_phone_hash.is_none() {
+        return Err(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "email or phone",
+        }
+        .into());
+    }
+
+    check_contact_search_quota(&state, merchant_account.get_id()).await?;
+
+    let constraints = PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+        offset: 0,
+        starting_at: None,
+        ending_at: None,
+        amount_filter: None,
+        connector: None,
+        currency: None,
+        status: None,
+        payment_method: None,
+        payment_method_type: None,
+        authentication_type: None,
+        merchant_connector_id: None,
+        profile_id: profile_id.map(|profile_id| vec![profile_id]),
+        customer_id: None,
+        starting_after_id: None,
+        ending_before_id: None,
+        limit: Some(limit),
+        order: Default::default(),
+        card_network: None,
+        card_discovery: None,
+        merchant_order_reference_id: None,
+        customer_email_hash,
+        customer_phone_hash,
+        custom_fields: None,
+    }));
+
+    let payment_intents = helpers::filter_by_constraints(
+        &state,
+        &constraints,
+        merchant_account.get_id(),
+        &key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let data = payment_intents
+        .into_iter()
+        .map(|payment_intent| PaymentContactSearchResult {
+            payment_id: payment_intent.payment_id,
+            status: payment_intent.status,
+            amount: payment_intent.amount,
+            currency: payment_intent.currency,
+            customer_id: payment_intent.customer_id,
+            profile_id: payment_intent.profile_id,
+            created_at: payment_intent.created_at,
+        })
+        .collect::<Vec<_>>();
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentsContactSearchResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use super::*;
+
+    #[test]
+    fn test_normalize_email() {
+        let email = Email::try_from("John.Doe@Example.com".to_string()).expect("Email");
+        assert_eq!(
+            normalize_email(&email),
+            Some("john.doe@example.com".to_string())
+        );
+    }
+
+    #[test]
+    fn test_normalize_phone() {
+        assert_eq!(
+            normalize_phone(&Secret::new("(415) 555-0100".to_string()), Some("+1")),
+            Some("14155550100".to_string())
+        );
+        assert_eq!(
+            normalize_phone(&Secret::new("415.555.0100".to_string()), Some("1")),
+            normalize_phone(&Secret::new("4155550100".to_string()), Some("+1"))
+        );
+        assert_eq!(normalize_phone(&Secret::new("-".to_string()), None), None);
+    }
+
+    #[test]
+    fn test_hash_contact_detail() {
+        let key = [1u8; 32];
+        let email_hash = hash_contact_detail(&key, "email", "a@b.com").expect("Hash");
+        assert_eq!(
+            email_hash,
+            hash_contact_detail(&key, "email", "a@b.com").expect("Hash")
+        );
+        assert_ne!(
+            email_hash,
+            hash_contact_detail(&key, "phone", "a@b.com").expect("Hash")
+        );
+        assert_ne!(
+            email_hash,
+            hash_contact_detail(&[2u8; 32], "email", "a@b.com").expect("Hash")
+        );
+    }
+}
@@ -0,0 +1,546 @@
+//! Custom fields of the payments of the profiles. A profile defines the schema of its custom
+//! fields, and the merchants send the values of the fields under the `custom_fields` of the
+//! payments on their creation. The values are validated against the schema and stored in the
+//! `payment_custom_field` table, one row per field, so that the payments can be filtered by them
+//! in the payments list. The custom fields are returned in the responses and the webhooks of the
+//! payments, and are synced to the data warehouses of the merchants along with the payments.
+
+use std::collections::HashMap;
+
+use api_models::{
+    enums as api_enums,
+    payments::{CustomFieldDefinition, CustomFieldsSchema, CUSTOM_FIELD_VALUE_MAX_LENGTH},
+};
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints;
+use router_env::{instrument, tracing};
+use serde_json::Value;
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{api, domain, storage},
+};
+
+const EMPTY_CUSTOM_FIELDS_SCHEMA: &str = r#"{"fields":[]}"#;
+
+#[instrument(skip_all)]
+pub async fn upsert_custom_fields_schema(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: CustomFieldsSchema,
+) -> RouterResponse<CustomFieldsSchema> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    request.validate().map_err(|error| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: error.current_context().to_string(),
+        })
+    })?;
+
+    let key = profile_id.get_custom_fields_schema_key();
+    let serialized_schema = request
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize custom fields schema")?;
+
+    // The cache holds an empty schema for the profiles without one, so the existence of the schema
+    // is checked in the database
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => {
+            db.update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(serialized_schema),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update custom fields schema")?;
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            db.insert_config(configs::ConfigNew {
+                key,
+                config: serialized_schema,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert custom fields schema")?;
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch custom fields schema");
+        }
+    }
+
+    Ok(services::ApplicationResponse::Json(request))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_custom_fields_schema(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<CustomFieldsSchema> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    let schema = get_custom_fields_schema(db, &profile_id).await?.ok_or(
+        errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Custom fields schema not found for the profile".to_string(),
+        },
+    )?;
+
+    Ok(services::ApplicationResponse::Json(schema))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_custom_fields_schema(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<()> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    db.delete_config_by_key(&profile_id.get_custom_fields_schema_key())
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::ApiErrorResponse::GenericNotFoundError {
+                    message: "Custom fields schema not found for the profile".to_string(),
+                })
+            } else {
+                error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to delete custom fields schema")
+            }
+        })?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+/// Fetches the custom fields schema of the profile. This is looked up for every payment, so the
+/// absence of the schema is cached as well.
+pub async fn get_custom_fields_schema(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Option<CustomFieldsSchema>> {
+    let schema: CustomFieldsSchema = db
+        .find_config_by_key_unwrap_or(
+            &profile_id.get_custom_fields_schema_key(),
+            Some(EMPTY_CUSTOM_FIELDS_SCHEMA.to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch custom fields schema")?
+        .config
+        .parse_struct("CustomFieldsSchema")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse custom fields schema")?;
+
+    Ok((!schema.fields.is_empty()).then_some(schema))
+}
+
+/// The value of a number field as stored, which is also the value matched by the filters of the
+/// payments list, so that `3` and `3.0` are the same value
+fn get_stored_number_value(number: f64) -> String {
+    number.to_string()
+}
+
+/// Validates the value of a custom field against its definition, returning the value as stored
+fn get_stored_value(field: &CustomFieldDefinition, value: &Value) -> Result<String, String> {
+    match field.field_type {
+        api_enums::CustomFieldType::String => {
+            let value = value.as_str().ok_or("must be a string")?;
+            let max_length = field
+                .max_length
+                .map(usize::from)
+                .unwrap_or(CUSTOM_FIELD_VALUE_MAX_LENGTH);
+            if value.is_empty() || value.chars().count() > max_length {
+                return Err(format!(
+                    "must be between 1 and {max_length} characters long"
+                ));
+            }
+            Ok(value.to_owned())
+        }
+        api_enums::CustomFieldType::Number => {
+            let number = value.as_f64().ok_or("must be a number")?;
+            if let Some(min_value) = field.min_value.filter(|min_value| number < *min_value) {
+                return Err(format!("must not be less than {min_value}"));
+            }
+            if let Some(max_value) = field.max_value.filter(|max_value| number > *max_value) {
+                return Err(format!("must not be greater than {max_value}"));
+            }
+            Ok(get_stored_number_value(number))
+        }
+        api_enums::CustomFieldType::Enum => {
+            let value = value.as_str().ok_or("must be a string")?;
+            let allowed_values = field.allowed_values.as_deref().unwrap_or_default();
+            if !allowed_values
+                .iter()
+                .any(|allowed_value| allowed_value == value)
+            {
+                return Err(format!("must be one of {}", allowed_values.join(", ")));
+            }
+            Ok(value.to_owned())
+        }
+    }
+}
+
+/// Validates the custom fields passed on the creation of a payment against the schema of the
+/// profile, returning the key, the type and the stored value of each field, sorted by key
+fn get_custom_field_values(
+    schema: Option<&CustomFieldsSchema>,
+    custom_fields: Option<&HashMap<String, Value>>,
+) -> Result<Vec<(String, api_enums::CustomFieldType, String)>, String> {
+    let custom_fields = custom_fields.filter(|custom_fields| !custom_fields.is_empty());
+    let Some(schema) = schema else {
+        return match custom_fields {
+            Some(_) => Err("no custom fields schema is configured for the profile".to_string()),
+            None => Ok(Vec::new()),
+        };
+    };
+
+    if let Some(field) = schema.fields.iter().find(|field| {
+        field.required
+            && custom_fields.map_or(true, |custom_fields| {
+                !custom_fields.contains_key(&field.key)
+            })
+    }) {
+        return Err(format!("`{}` is required", field.key));
+    }
+
+    let mut values = custom_fields
+        .into_iter()
+        .flatten()
+        .map(|(key, value)| {
+            let field = schema
+                .get_field(key)
+                .ok_or_else(|| format!("`{key}` is not defined in the schema of the profile"))?;
+            let stored_value =
+                get_stored_value(field, value).map_err(|error| format!("`{key}` {error}"))?;
+            Ok((key.clone(), field.field_type, stored_value))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    values.sort_by(|(key, _, _), (other_key, _, _)| key.cmp(other_key));
+
+    Ok(values)
+}
+
+/// Validates the custom fields passed on the creation of a payment against the schema of its
+/// profile, returning the fields to be stored once the payment is created
+pub async fn get_payment_custom_fields_new(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+    profile_id: &id_type::ProfileId,
+    custom_fields: Option<&HashMap<String, Value>>,
+) -> RouterResult<Vec<storage::PaymentCustomFieldNew>> {
+    let schema = get_custom_fields_schema(db, profile_id).await?;
+    let values = get_custom_field_values(schema.as_ref(), custom_fields).map_err(|message| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Invalid custom_fields: {message}"),
+        })
+    })?;
+
+    let now = common_utils::date_time::now();
+    Ok(values
+        .into_iter()
+        .map(
+            |(field_key, field_type, field_value)| storage::PaymentCustomFieldNew {
+                merchant_id: merchant_id.clone(),
+                payment_id: payment_id.clone(),
+                profile_id: profile_id.clone(),
+                field_key,
+                field_type,
+                field_value,
+                created_at: now,
+            },
+        )
+        .collect())
+}
+
+pub async fn insert_payment_custom_fields(
+    db: &dyn StorageInterface,
+    payment_custom_fields: Vec<storage::PaymentCustomFieldNew>,
+) -> RouterResult<()> {
+    if payment_custom_fields.is_empty() {
+        return Ok(());
+    }
+
+    db.insert_payment_custom_fields(payment_custom_fields)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the custom fields of the payment")
+}
+
+/// The value of a stored custom field, as passed on the creation of the payment
+fn get_json_value(payment_custom_field: storage::PaymentCustomField) -> Value {
+    match payment_custom_field.field_type {
+        api_enums::CustomFieldType::Number => payment_custom_field
+            .field_value
+            .parse::<i64>()
+            .map(Value::from)
+            .ok()
+            .or_else(|| {
+                payment_custom_field
+                    .field_value
+                    .parse::<f64>()
+                    .ok()
+                    .and_then(serde_json::Number::from_f64)
+                    .map(Value::Number)
+            })
+            .unwrap_or(Value::String(payment_custom_field.field_value)),
+        api_enums::CustomFieldType::String | api_enums::CustomFieldType::Enum => {
+            Value::String(payment_custom_field.field_value)
+        }
+    }
+}
+
+/// Fetches the custom fields of the given payments of the merchant, keyed by the payment
+pub async fn get_custom_fields_of_payments(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    payment_ids: Vec<id_type::PaymentId>,
+) -> RouterResult<HashMap<id_type::PaymentId, HashMap<String, Value>>> {
+    if payment_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let payment_custom_fields = db
+        .find_payment_custom_fields_by_merchant_id_payment_ids(merchant_id, payment_ids)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the custom fields of the payments")?;
+
+    let mut custom_fields: HashMap<id_type::PaymentId, HashMap<String, Value>> = HashMap::new();
+    for payment_custom_field in payment_custom_fields {
+        custom_fields
+            .entry(payment_custom_field.payment_id.clone())
+            .or_default()
+            .insert(
+                payment_custom_field.field_key.clone(),
+                get_json_value(payment_custom_field),
+            );
+    }
+
+    Ok(custom_fields)
+}
+
+/// Fetches the custom fields of a payment. The custom fields are looked up only for the profiles
+/// with a custom fields schema, as the payments of the other profiles cannot have any.
+pub async fn get_custom_fields_of_payment(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<Option<HashMap<String, Value>>> {
+    if get_custom_fields_schema(db, profile_id).await?.is_none() {
+        return Ok(None);
+    }
+
+    Ok(get_custom_fields_of_payments(
+        db,
+        &payment_intent.merchant_id,
+        vec![payment_intent.payment_id.clone()],
+    )
+    .await?
+    .remove(&payment_intent.payment_id))
+}
+
+/// Sets the custom fields of the payments of a list
+pub async fn populate_custom_fields_of_payments(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    payments: &mut [api::PaymentsResponse],
+) -> RouterResult<()> {
+    let payment_ids = payments
+        .iter()
+        .map(|payment| payment.payment_id.clone())
+        .collect();
+    let mut custom_fields = get_custom_fields_of_payments(db, merchant_id, payment_ids).await?;
+
+    for payment in payments.iter_mut() {
+        payment.custom_fields = custom_fields.remove(&payment.payment_id);
+    }
+
+    Ok(())
+}
+
+/// Adds the filters of the payments list by the values of their custom fields to the constraints
+/// of the list. The values are matched as stored, so only strings and numbers can be filtered by.
+pub fn add_custom_field_filters(
+    pi_fetch_constraints: &mut PaymentIntentFetchConstraints,
+    custom_fields: Option<&HashMap<String, Value>>,
+) -> RouterResult<()> {
+    let PaymentIntentFetchConstraints::List(params) = pi_fetch_constraints else {
+        return Ok(());
+    };
+    let Some(custom_fields) = custom_fields.filter(|custom_fields| !custom_fields.is_empty())
+    else {
+        return Ok(());
+    };
+
+    let custom_field_filters = custom_fields
+        .iter()
+        .map(|(key, value)| {
+            let stored_value = match value {
+                Value::String(value) => Some(value.clone()),
+                Value::Number(number) => number.as_f64().map(get_stored_number_value),
+                _ => None,
+            }
+            .ok_or_else(|| {
+                report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "Custom field `{key}` can be filtered only by a string or a number"
+                    ),
+                })
+            })?;
+            Ok((key.clone(), stored_value))
+        })
+        .collect::<RouterResult<Vec<_>>>()?;
+    params.custom_fields = Some(custom_field_filters);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_schema() -> CustomFieldsSchema {
+        CustomFieldsSchema {
+            fields: vec![
+                CustomFieldDefinition {
+                    key: "store_id".to_string(),
+                    field_type: api_enums::CustomFieldType::String,
+                    required: true,
+                    max_length: Some(8),
+                    min_value: None,
+                    max_value: None,
+                    allowed_values: None,
+                },
+                CustomFieldDefinition {
+                    key: "terminal_count".to_string(),
+                    field_type: api_enums::CustomFieldType::Number,
+                    required: false,
+                    max_length: None,
+                    min_value: Some(1.0),
+                    max_value: Some(10.0),
+                    allowed_values: None,
+                },
+                CustomFieldDefinition {
+                    key: "channel".to_string(),
+                    field_type: api_enums::CustomFieldType::Enum,
+                    required: false,
+                    max_length: None,
+                    min_value: None,
+                    max_value: None,
+                    allowed_values: Some(vec!["online".to_string(), "in_store".to_string()]),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_get_custom_field_values() {
+        let schema = get_schema();
+        let custom_fields = serde_json::from_value::<HashMap<String, Value>>(serde_json::json!({
+            "store_id": "store_42",
+            "terminal_count": 3.0,
+            "channel": "online",
+        }))
+        .unwrap();
+
+        assert_eq!(
+            get_custom_field_values(Some(&schema), Some(&custom_fields)).unwrap(),
+            vec![
+                (
+                    "channel".to_string(),
+                    api_enums::CustomFieldType::Enum,
+                    "online".to_string()
+                ),
+                (
+                    "store_id".to_string(),
+                    api_enums::CustomFieldType::String,
+                    "store_42".to_string()
+                ),
+                (
+                    "terminal_count".to_string(),
+                    api_enums::CustomFieldType::Number,
+                    "3".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_get_custom_field_values_rejects_invalid_fields() {
+        let schema = get_schema();
+        let get_error = |custom_fields: Value| {
+            let custom_fields =
+                serde_json::from_value::<HashMap<String, Value>>(custom_fields).unwrap();
+            get_custom_field_values(Some(&schema), Some(&custom_fields)).unwrap_err()
+        };
+
+        assert_eq!(
+            get_error(serde_json::json!({ "channel": "online" })),
+            "`store_id` is required"
+        );
+        assert_eq!(
+            get_error(serde_json::json!({ "store_id": "store_42_east" })),
+            "`store_id` must be between 1 and 8 characters long"
+        );
+        assert_eq!(
+            get_error(serde_json::json!({ "store_id": "store_42", "terminal_count": 12 })),
+            "`terminal_count` must not be greater than 10"
+        );
+        assert_eq!(
+            get_error(serde_json::json!({ "store_id": "store_42", "channel": "kiosk" })),
+            "`channel` must be one of online, in_store"
+        );
+        assert_eq!(
+            get_error(serde_json::json!({ "store_id": "store_42", "region": "emea" })),
+            "`region` is not defined in the schema of the profile"
+        );
+        assert!(get_custom_field_values(None, None).unwrap().is_empty());
+    }
+}
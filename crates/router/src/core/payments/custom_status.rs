@@ -0,0 +1,206 @@
+//! Custom payment statuses of the profiles. Merchants map the statuses of the payments and of
+//! their attempts to their own labels, which are sent as the `custom_status` of the payments in the
+//! API responses and the webhooks, so that they need not translate the statuses themselves.
+
+use std::collections::HashMap;
+
+use api_models::payments::CustomPaymentStatusMapping;
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{api, domain, storage, transformers::ForeignFrom},
+};
+
+#[instrument(skip_all)]
+pub async fn upsert_custom_payment_status_mapping(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: CustomPaymentStatusMapping,
+) -> RouterResponse<CustomPaymentStatusMapping> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    request.validate().map_err(|error| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: error.current_context().to_string(),
+        })
+    })?;
+
+    let key = profile_id.get_custom_payment_status_mapping_key();
+    let serialized_mapping = request
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize custom payment status mapping")?;
+
+    // The cache holds an empty mapping for the profiles without one, so the existence of the
+    // mapping is checked in the database
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => {
+            db.update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(serialized_mapping),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update custom payment status mapping")?;
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            db.insert_config(configs::ConfigNew {
+                key,
+                config: serialized_mapping,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert custom payment status mapping")?;
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch custom payment status mapping");
+        }
+    }
+
+    Ok(services::ApplicationResponse::Json(request))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_custom_payment_status_mapping(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<CustomPaymentStatusMapping> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    let mapping = get_custom_payment_status_mapping(db, &profile_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Custom payment status mapping not found for the profile".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(mapping))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_custom_payment_status_mapping(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<()> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    db.delete_config_by_key(&profile_id.get_custom_payment_status_mapping_key())
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::ApiErrorResponse::GenericNotFoundError {
+                    message: "Custom payment status mapping not found for the profile".to_string(),
+                })
+            } else {
+                error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to delete custom payment status mapping")
+            }
+        })?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+/// Fetches the custom payment status mapping of the profile. This is looked up for every payment
+/// response, so the absence of the mapping is cached as well.
+pub async fn get_custom_payment_status_mapping(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Option<CustomPaymentStatusMapping>> {
+    let mapping: CustomPaymentStatusMapping = db
+        .find_config_by_key_unwrap_or(
+            &profile_id.get_custom_payment_status_mapping_key(),
+            Some("{}".to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch custom payment status mapping")?
+        .config
+        .parse_struct("CustomPaymentStatusMapping")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse custom payment status mapping")?;
+
+    Ok((!mapping.is_empty()).then_some(mapping))
+}
+
+/// Converts a list of payments, which may belong to different profiles, to their responses along
+/// with their custom statuses
+pub async fn get_payments_response_with_custom_statuses(
+    db: &dyn StorageInterface,
+    payments: Vec<(storage::PaymentIntent, storage::PaymentAttempt)>,
+) -> RouterResult<Vec<api::PaymentsResponse>> {
+    let mut mappings: HashMap<id_type::ProfileId, Option<CustomPaymentStatusMapping>> =
+        HashMap::new();
+    let mut payments_response = Vec::with_capacity(payments.len());
+
+    for (payment_intent, payment_attempt) in payments {
+        let custom_status = match payment_intent.profile_id.as_ref() {
+            Some(profile_id) => {
+                if !mappings.contains_key(profile_id) {
+                    let mapping = get_custom_payment_status_mapping(db, profile_id).await?;
+                    mappings.insert(profile_id.clone(), mapping);
+                }
+                mappings
+                    .get(profile_id)
+                    .and_then(Option::as_ref)
+                    .and_then(|mapping| {
+                        mapping.get_custom_status(payment_intent.status, payment_attempt.status)
+                    })
+            }
+            None => None,
+        };
+
+        let mut payment_response =
+            api::PaymentsResponse::foreign_from((payment_intent, payment_attempt));
+        payment_response.custom_status = custom_status;
+        payments_response.push(payment_response);
+    }
+
+    Ok(payments_response)
+}
@@ -0,0 +1,860 @@
+//! Dunning of the failed recurring payments. When a merchant initiated payment of a profile with a
+//! dunning configuration fails, it is retried on the days of the configured schedule, counted from
+//! its failure. The merchant is notified with an outgoing webhook whenever a retry is scheduled, so
+//! that they can notify the customer before the payment is retried. The payment is retried as a
+//! manual retry with the mandate or the payment method of its failed attempt, and is marked as
+//! uncollectible once all its retries fail, or once its mandate is no longer active. Only the soft
+//! declines are retried: a payment failing with one of the hard decline codes of the configuration,
+//! such as that of a stolen card, is marked as uncollectible at once.
+
+use api_models::{
+    dunning::{DunningConfig, DunningResponse, DunningStatus},
+    mandates::RecurringDetails,
+    payments as payment_types, webhooks,
+};
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+use time::{Duration, PrimitiveDateTime};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments as payments_core, utils as core_utils, webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+};
+
+const DUNNING_TASK: &str = "DUNNING";
+const DUNNING_TAG: &str = "PAYMENT";
+
+#[instrument(skip_all)]
+pub async fn upsert_dunning_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: DunningConfig,
+) -> RouterResponse<DunningConfig> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    request.validate().map_err(|error| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: error.current_context().to_string(),
+        })
+    })?;
+
+    let key = profile_id.get_dunning_config_key();
+    let serialized_config = request
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize dunning config")?;
+
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => {
+            db.update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(serialized_config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update dunning config")?;
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            db.insert_config(configs::ConfigNew {
+                key,
+                config: serialized_config,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert dunning config")?;
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch dunning config");
+        }
+    }
+
+    Ok(services::ApplicationResponse::Json(request))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_dunning_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<DunningConfig> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    let config = get_dunning_config(db, &profile_id).await?.ok_or(
+        errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Dunning config not found for the profile".to_string(),
+        },
+    )?;
+
+    Ok(services::ApplicationResponse::Json(config))
+}
+
+/// Deletes the dunning configuration of the profile. The dunning of the payments which have
+/// already failed continues as per the schedule with which it was started.
+#[instrument(skip_all)]
+pub async fn delete_dunning_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<()> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    db.delete_config_by_key(&profile_id.get_dunning_config_key())
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::ApiErrorResponse::GenericNotFoundError {
+                    message: "Dunning config not found for the profile".to_string(),
+                })
+            } else {
+                error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to delete dunning config")
+            }
+        })?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+async fn get_dunning_config(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Option<DunningConfig>> {
+    // The configuration is looked up for every failed merchant initiated payment, so its absence
+    // is cached as well
+    db.find_config_by_key_unwrap_or(
+        &profile_id.get_dunning_config_key(),
+        Some("null".to_string()),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch dunning config")?
+    .config
+    .parse_struct("Option<DunningConfig>")
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to parse dunning config")
+}
+
+/// The dunning of a failed recurring payment, as stored along with its progress
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Dunning {
+    pub payment_id: id_type::PaymentId,
+    pub status: DunningStatus,
+    pub retry_schedule_days: Vec<u16>,
+    pub retry_count: usize,
+    #[serde(default = "api_models::dunning::default_hard_decline_codes")]
+    pub hard_decline_codes: Vec<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub next_retry_at: Option<PrimitiveDateTime>,
+    pub last_error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl Dunning {
+    /// The time of the retry following the retries performed so far, if the schedule is not
+    /// exhausted
+    fn get_next_retry_time(&self) -> Option<PrimitiveDateTime> {
+        self.retry_schedule_days.get(self.retry_count).map(|day| {
+            self.created_at
+                .saturating_add(Duration::days(i64::from(*day)))
+        })
+    }
+
+    /// Whether the failure with the given error codes of the connector, the issuer and the unified
+    /// error code is a hard decline, which retrying the payment can not recover
+    fn is_hard_decline<'a>(&self, error_codes: impl IntoIterator<Item = Option<&'a str>>) -> bool {
+        error_codes.into_iter().flatten().any(|error_code| {
+            self.hard_decline_codes
+                .iter()
+                .any(|hard_decline_code| hard_decline_code.eq_ignore_ascii_case(error_code))
+        })
+    }
+}
+
+impl From<&Dunning> for DunningResponse {
+    fn from(dunning: &Dunning) -> Self {
+        Self {
+            payment_id: dunning.payment_id.clone(),
+            status: dunning.status,
+            retry_schedule_days: dunning.retry_schedule_days.clone(),
+            retry_count: dunning.retry_count,
+            next_retry_at: dunning.next_retry_at,
+            last_error_message: dunning.last_error_message.clone(),
+            created_at: dunning.created_at,
+            modified_at: dunning.modified_at,
+        }
+    }
+}
+
+async fn find_dunning(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<Option<Dunning>> {
+    match db
+        .find_config_by_key_from_db(&merchant_id.get_dunning_key(payment_id.get_string_repr()))
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("Dunning")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse dunning")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch dunning"),
+    }
+}
+
+async fn update_dunning(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    dunning: &Dunning,
+) -> RouterResult<()> {
+    let serialized_dunning = dunning
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize dunning")?;
+
+    db.update_config_by_key(
+        &merchant_id.get_dunning_key(dunning.payment_id.get_string_repr()),
+        configs::ConfigUpdate::Update {
+            config: Some(serialized_dunning),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update dunning")?;
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_dunning(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    payment_id: id_type::PaymentId,
+) -> RouterResponse<DunningResponse> {
+    let db = state.store.as_ref();
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payment_intent)?;
+
+    let dunning = find_dunning(db, merchant_account.get_id(), &payment_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Dunning not found for the payment".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(DunningResponse::from(
+        &dunning,
+    )))
+}
+
+/// The mandate or the payment method with which a failed recurring payment is retried
+fn get_recurring_details(payment_attempt: &storage::PaymentAttempt) -> Option<RecurringDetails> {
+    payment_attempt
+        .mandate_id
+        .clone()
+        .map(RecurringDetails::MandateId)
+        .or_else(|| {
+            payment_attempt
+                .payment_method_id
+                .clone()
+                .map(RecurringDetails::PaymentMethodId)
+        })
+}
+
+/// Starts the dunning of the payment if it is a merchant initiated payment which has just failed,
+/// and its profile has a dunning configuration. Failures are only logged, as the dunning should
+/// never affect the outcome of the payment.
+#[instrument(skip_all)]
+pub async fn start_dunning_if_applicable(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    initial_intent_status: enums::IntentStatus,
+) {
+    // The payments which were already failed are being retried, either by their dunning or by the
+    // merchant, and the dunning of their first failure is not started again
+    if payment_intent.status != enums::IntentStatus::Failed
+        || initial_intent_status == enums::IntentStatus::Failed
+        || payment_intent.off_session != Some(true)
+        || get_recurring_details(payment_attempt).is_none()
+    {
+        return;
+    }
+
+    if let Err(error) = start_dunning(
+        state,
+        merchant_account,
+        key_store,
+        business_profile,
+        payment_intent,
+        payment_attempt,
+    )
+    .await
+    {
+        logger::error!(?error, "Failed to start the dunning of the payment");
+    }
+}
+
+async fn start_dunning(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+
+    let Some(config) = get_dunning_config(db, business_profile.get_id()).await? else {
+        return Ok(());
+    };
+    if find_dunning(db, merchant_id, &payment_intent.payment_id)
+        .await?
+        .is_some()
+    {
+        return Ok(());
+    }
+
+    let now = common_utils::date_time::now();
+    let mut dunning = Dunning {
+        payment_id: payment_intent.payment_id.clone(),
+        status: DunningStatus::Scheduled,
+        retry_schedule_days: config.retry_schedule_days,
+        retry_count: 0,
+        hard_decline_codes: config.hard_decline_codes,
+        next_retry_at: None,
+        last_error_message: payment_attempt.error_message.clone(),
+        created_at: now,
+        modified_at: now,
+    };
+    // The payments failing with a hard decline are uncollectible at once, and are not retried
+    let next_retry_at = if dunning.is_hard_decline([
+        payment_attempt.error_code.as_deref(),
+        payment_attempt.issuer_error_code.as_deref(),
+        payment_attempt.unified_code.as_deref(),
+    ]) {
+        dunning.status = DunningStatus::Uncollectible;
+        None
+    } else {
+        Some(
+            dunning
+                .get_next_retry_time()
+                .ok_or(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("The dunning schedule of the profile has no retries")?,
+        )
+    };
+    dunning.next_retry_at = next_retry_at;
+
+    let serialized_dunning = dunning
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize dunning")?;
+    db.insert_config(configs::ConfigNew {
+        key: merchant_id.get_dunning_key(dunning.payment_id.get_string_repr()),
+        config: serialized_dunning,
+    })
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to insert dunning")?;
+
+    let event_type = match next_retry_at {
+        Some(next_retry_at) => {
+            let tracking_data = storage::DunningTrackingData {
+                merchant_id: merchant_id.clone(),
+                profile_id: business_profile.get_id().clone(),
+                payment_id: dunning.payment_id.clone(),
+            };
+            add_dunning_task(db, tracking_data, next_retry_at).await?;
+            enums::EventType::PaymentDunningRetryScheduled
+        }
+        None => enums::EventType::PaymentUncollectible,
+    };
+
+    trigger_dunning_webhook(
+        state,
+        merchant_account.clone(),
+        key_store.clone(),
+        business_profile.clone(),
+        &dunning.payment_id,
+        event_type,
+    )
+    .await;
+
+    Ok(())
+}
+
+async fn add_dunning_task(
+    db: &dyn StorageInterface,
+    tracking_data: storage::DunningTrackingData,
+    schedule_time: PrimitiveDateTime,
+) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::DunningWorkflow;
+    let process_tracker_id = format!(
+        "{runner}_{DUNNING_TASK}_{}_{}",
+        tracking_data.merchant_id.get_string_repr(),
+        tracking_data.payment_id.get_string_repr()
+    );
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        DUNNING_TASK,
+        runner,
+        [DUNNING_TAG],
+        tracking_data,
+        None,
+        schedule_time,
+        hyperswitch_domain_models::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct DUNNING process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while inserting DUNNING task to process_tracker")?;
+
+    Ok(())
+}
+
+/// Triggers an outgoing webhook with the details of the payment whose dunning progressed. Failures
+/// are only logged, as the webhooks should never affect the dunning of the payment.
+async fn trigger_dunning_webhook(
+    state: &SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    business_profile: domain::Profile,
+    payment_id: &id_type::PaymentId,
+    event_type: enums::EventType,
+) {
+    let request = payment_types::PaymentsRetrieveRequest {
+        resource_id: payment_types::PaymentIdType::PaymentIntentId(payment_id.clone()),
+        merchant_id: Some(merchant_account.get_id().clone()),
+        force_sync: false,
+        ..Default::default()
+    };
+    let payments_response = match Box::pin(payments_core::payments_core::<
+        api::PSync,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments_core::PaymentData<api::PSync>,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account.clone(),
+        None,
+        key_store.clone(),
+        payments_core::PaymentStatus,
+        request,
+        services::AuthFlow::Merchant,
+        payments_core::CallConnectorAction::Avoid,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+        None, // Platform merchant account
+    ))
+    .await
+    {
+        Ok(services::ApplicationResponse::Json(payments_response))
+        | Ok(services::ApplicationResponse::JsonWithHeaders((payments_response, _))) => {
+            payments_response
+        }
+        Ok(_) => {
+            logger::error!("Unexpected response received while retrieving the payment");
+            return;
+        }
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to retrieve the payment for the dunning webhook"
+            );
+            return;
+        }
+    };
+
+    let primary_object_created_at = payments_response.created;
+    if let Err(error) = Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account,
+        business_profile,
+        &key_store,
+        event_type,
+        enums::EventClass::Payments,
+        payment_id.get_string_repr().to_owned(),
+        enums::EventObjectType::PaymentDetails,
+        webhooks::OutgoingWebhookContent::PaymentDetails(Box::new(payments_response)),
+        primary_object_created_at,
+    ))
+    .await
+    {
+        logger::error!(?error, "Failed to trigger the dunning webhook");
+    }
+}
+
+/// Checks whether the payment can still be retried, returning the reason for which the dunning
+/// is to be completed otherwise
+async fn get_dunning_completion_reason(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<Option<(DunningStatus, Option<String>)>> {
+    match payment_intent.status {
+        enums::IntentStatus::Failed => (),
+        enums::IntentStatus::Cancelled => {
+            return Ok(Some((
+                DunningStatus::Uncollectible,
+                Some("The payment was cancelled".to_string()),
+            )))
+        }
+        _ => return Ok(Some((DunningStatus::Recovered, None))),
+    }
+
+    if let Some(mandate_id) = payment_attempt.mandate_id.as_deref() {
+        let mandate = state
+            .store
+            .find_mandate_by_merchant_id_mandate_id(
+                merchant_account.get_id(),
+                mandate_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+        if mandate.mandate_status != enums::MandateStatus::Active {
+            return Ok(Some((
+                DunningStatus::Uncollectible,
+                Some(format!(
+                    "The mandate of the payment has status {}",
+                    mandate.mandate_status
+                )),
+            )));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Retries the payment as per its dunning. Returns the time of the next retry of the payment, or
+/// `None` once the dunning is completed.
+#[instrument(skip_all)]
+pub async fn retry_dunning_payment(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    tracking_data: &storage::DunningTrackingData,
+) -> RouterResult<Option<PrimitiveDateTime>> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+
+    let Some(mut dunning) = find_dunning(db, merchant_id, &tracking_data.payment_id).await? else {
+        return Ok(None);
+    };
+    if dunning.status != DunningStatus::Scheduled {
+        return Ok(None);
+    }
+
+    let business_profile = db
+        .find_business_profile_by_profile_id(&state.into(), key_store, &tracking_data.profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: tracking_data.profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            &tracking_data.payment_id,
+            merchant_id,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            merchant_id,
+            payment_intent.active_attempt.get_id().as_str(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let completion_reason =
+        get_dunning_completion_reason(state, merchant_account, &payment_intent, &payment_attempt)
+            .await?;
+    let completion_reason = match (completion_reason, get_recurring_details(&payment_attempt)) {
+        (Some(completion_reason), _) => Some(completion_reason),
+        (None, None) => Some((
+            DunningStatus::Uncollectible,
+            Some("The payment has neither a mandate nor a saved payment method".to_string()),
+        )),
+        (None, Some(recurring_details)) => {
+            // The retry is counted before the payment is confirmed, so that the task failing after
+            // the confirmation does not retry the payment beyond its schedule
+            dunning.retry_count += 1;
+            dunning.modified_at = common_utils::date_time::now();
+            update_dunning(db, merchant_id, &dunning).await?;
+
+            retry_payment(
+                state,
+                merchant_account,
+                key_store,
+                &tracking_data.payment_id,
+                recurring_details,
+                &mut dunning,
+            )
+            .await
+        }
+    };
+
+    dunning.next_retry_at = match &completion_reason {
+        Some((status, error_message)) => {
+            dunning.status = *status;
+            if error_message.is_some() {
+                dunning.last_error_message = error_message.clone();
+            }
+            None
+        }
+        None => dunning.get_next_retry_time(),
+    };
+    // The payment is uncollectible once the retries of its schedule are exhausted
+    if dunning.status == DunningStatus::Scheduled && dunning.next_retry_at.is_none() {
+        dunning.status = DunningStatus::Uncollectible;
+    }
+    dunning.modified_at = common_utils::date_time::now();
+    update_dunning(db, merchant_id, &dunning).await?;
+
+    let event_type = match dunning.status {
+        DunningStatus::Scheduled => Some(enums::EventType::PaymentDunningRetryScheduled),
+        DunningStatus::Uncollectible => Some(enums::EventType::PaymentUncollectible),
+        // The merchant is notified of the recovered payment by the webhook of its status
+        DunningStatus::Recovered => None,
+    };
+    if let Some(event_type) = event_type {
+        trigger_dunning_webhook(
+            state,
+            merchant_account.clone(),
+            key_store.clone(),
+            business_profile,
+            &tracking_data.payment_id,
+            event_type,
+        )
+        .await;
+    }
+
+    Ok(dunning.next_retry_at)
+}
+
+/// Marks the dunning of the payment as uncollectible once its task has exhausted its retries, so
+/// that it is not left scheduled without a task to retry the payment
+pub async fn fail_dunning(
+    db: &dyn StorageInterface,
+    tracking_data: &storage::DunningTrackingData,
+) -> RouterResult<()> {
+    let Some(mut dunning) =
+        find_dunning(db, &tracking_data.merchant_id, &tracking_data.payment_id).await?
+    else {
+        return Ok(());
+    };
+    if dunning.status != DunningStatus::Scheduled {
+        return Ok(());
+    }
+
+    dunning.status = DunningStatus::Uncollectible;
+    dunning.next_retry_at = None;
+    dunning.last_error_message = Some("The retry of the payment failed".to_string());
+    dunning.modified_at = common_utils::date_time::now();
+    update_dunning(db, &tracking_data.merchant_id, &dunning).await
+}
+
+/// Confirms the failed payment again with its mandate or payment method. Returns the status to
+/// which the dunning is to be completed if the retry did not fail, or failed with a hard decline.
+async fn retry_payment(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &id_type::PaymentId,
+    recurring_details: RecurringDetails,
+    dunning: &mut Dunning,
+) -> Option<(DunningStatus, Option<String>)> {
+    let request = api::PaymentsRequest {
+        payment_id: Some(payment_types::PaymentIdType::PaymentIntentId(
+            payment_id.clone(),
+        )),
+        merchant_id: Some(merchant_account.get_id().clone()),
+        confirm: Some(true),
+        off_session: Some(true),
+        recurring_details: Some(recurring_details),
+        retry_action: Some(api_models::enums::RetryAction::ManualRetry),
+        ..Default::default()
+    };
+    let retry_result = Box::pin(payments_core::payments_core::<
+        api::Authorize,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments_core::PaymentData<api::Authorize>,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account.clone(),
+        None,
+        key_store.clone(),
+        payments_core::PaymentConfirm,
+        request,
+        services::AuthFlow::Merchant,
+        payments_core::CallConnectorAction::Trigger,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+        None, // Platform merchant account
+    ))
+    .await;
+
+    match retry_result {
+        Ok(services::ApplicationResponse::Json(payments_response))
+        | Ok(services::ApplicationResponse::JsonWithHeaders((payments_response, _))) => {
+            if payments_response.status == enums::IntentStatus::Failed {
+                dunning.last_error_message = payments_response.error_message;
+                dunning
+                    .is_hard_decline([
+                        payments_response.error_code.as_deref(),
+                        payments_response.issuer_error_code.as_deref(),
+                        payments_response.unified_code.as_deref(),
+                    ])
+                    .then_some((DunningStatus::Uncollectible, None))
+            } else {
+                // The payments which did not fail on the retry are followed up with their
+                // regular flows, such as the webhooks of the connectors
+                Some((DunningStatus::Recovered, None))
+            }
+        }
+        Ok(_) => {
+            logger::error!("Unexpected response received while retrying the payment");
+            None
+        }
+        Err(error) => {
+            logger::error!(?error, "Failed to retry the payment of the dunning");
+            dunning.last_error_message = Some(error.current_context().to_string());
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_the_configured_codes_are_hard_declines() {
+        let now = common_utils::date_time::now();
+        let dunning = Dunning {
+            payment_id: id_type::PaymentId::default(),
+            status: DunningStatus::Scheduled,
+            retry_schedule_days: vec![1, 3, 7],
+            retry_count: 0,
+            hard_decline_codes: api_models::dunning::default_hard_decline_codes(),
+            next_retry_at: None,
+            last_error_message: None,
+            created_at: now,
+            modified_at: now,
+        };
+
+        assert!(dunning.is_hard_decline([Some("card_declined"), Some("43"), None]));
+        assert!(dunning.is_hard_decline([Some("STOLEN_CARD"), None, None]));
+        // Insufficient funds and do not honor are soft declines, which are retried
+        assert!(!dunning.is_hard_decline([Some("insufficient_funds"), Some("51"), None]));
+        assert!(!dunning.is_hard_decline([Some("do_not_honor"), Some("05"), Some("UE_9000")]));
+        assert!(!dunning.is_hard_decline([None, None, None]));
+    }
+
+    #[test]
+    fn test_dunning_stored_without_hard_decline_codes_uses_the_defaults() {
+        let dunning: Dunning = serde_json::from_value(serde_json::json!({
+            "payment_id": "pay_123",
+            "status": "scheduled",
+            "retry_schedule_days": [1, 3, 7],
+            "retry_count": 1,
+            "last_error_message": null,
+            "created_at": "2025-04-01T10:00:00.000Z",
+            "modified_at": "2025-04-02T10:00:00.000Z",
+        }))
+        .expect("Failed to deserialize the dunning");
+
+        assert_eq!(
+            dunning.hard_decline_codes,
+            api_models::dunning::default_hard_decline_codes()
+        );
+    }
+}
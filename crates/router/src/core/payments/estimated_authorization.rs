@@ -0,0 +1,245 @@
+//! Payments authorized with an estimated amount, as for hotel stays, fuel or rides, whose final
+//! amount is known only once the service has been delivered. The estimated amount is authorized,
+//! and the payment is finalized by capturing its final amount. A final amount above the authorized
+//! amount is captured within the overcapture tolerance of the connector and the card network, or
+//! else after incrementing the authorization of the payment to the final amount. A final amount
+//! below the authorized amount is partially captured.
+
+use api_models::payments::PaymentsFinalizeAmountRequest;
+use common_utils::{id_type, types::MinorUnit};
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::{self as payments_core, helpers},
+        utils as core_utils,
+    },
+    routes::{app::ReqState, SessionState},
+    services,
+    types::{api, domain, storage::enums},
+};
+
+/// The adjustment of the authorization of a payment which is required to capture its final amount
+#[derive(Debug, PartialEq)]
+enum AuthorizationAdjustment {
+    /// The final amount is captured with the existing authorization, fully or partially
+    NotRequired,
+    /// The authorization is incremented to the final amount before it is captured
+    IncrementalAuthorization,
+}
+
+fn get_authorization_adjustment(
+    final_amount: MinorUnit,
+    amount_capturable: MinorUnit,
+    overcapture_allowance: Option<MinorUnit>,
+    is_incremental_authorization_allowed: bool,
+) -> RouterResult<AuthorizationAdjustment> {
+    if final_amount <= amount_capturable + overcapture_allowance.unwrap_or_default() {
+        Ok(AuthorizationAdjustment::NotRequired)
+    } else if is_incremental_authorization_allowed {
+        Ok(AuthorizationAdjustment::IncrementalAuthorization)
+    } else {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "final_amount exceeds the amount which can be captured for the payment, and the authorization of the payment cannot be incremented".to_string(),
+        }
+        .into())
+    }
+}
+
+fn get_payments_response(
+    response: services::ApplicationResponse<api::PaymentsResponse>,
+) -> RouterResult<api::PaymentsResponse> {
+    match response {
+        services::ApplicationResponse::Json(payments_response)
+        | services::ApplicationResponse::JsonWithHeaders((payments_response, _)) => {
+            Ok(payments_response)
+        }
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response received while incrementing the authorization"),
+    }
+}
+
+/// Captures the final amount of a payment which was authorized with an estimated amount, after
+/// incrementing its authorization if the final amount cannot be captured otherwise
+#[instrument(skip_all)]
+pub async fn finalize_amount(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    request: PaymentsFinalizeAmountRequest,
+) -> RouterResponse<api::PaymentsResponse> {
+    if request.final_amount <= MinorUnit::zero() {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "final_amount must be greater than zero".to_string(),
+        }
+        .into());
+    }
+
+    let db = &*state.store;
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &request.payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(profile_id.clone(), &payment_intent)?;
+
+    if payment_intent.is_amount_estimated != Some(true) {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The payment was not authorized with an estimated amount".to_string(),
+        }
+        .into());
+    }
+
+    helpers::validate_payment_status_against_allowed_statuses(
+        payment_intent.status,
+        &[enums::IntentStatus::RequiresCapture],
+        "finalize the amount of",
+    )?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            &payment_intent.merchant_id,
+            &payment_intent.active_attempt.get_id(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let overcapture_allowance =
+        helpers::get_overcapture_allowance(&state, &payment_intent, &payment_attempt).await?;
+
+    let authorization_adjustment = get_authorization_adjustment(
+        request.final_amount,
+        payment_attempt.amount_capturable,
+        overcapture_allowance,
+        payment_intent.incremental_authorization_allowed == Some(true),
+    )?;
+
+    if authorization_adjustment == AuthorizationAdjustment::IncrementalAuthorization {
+        let incremental_authorization_request = api::PaymentsIncrementalAuthorizationRequest {
+            payment_id: request.payment_id.clone(),
+            amount: request.final_amount,
+            reason: Some("Final amount of the payment exceeds its estimated amount".to_string()),
+        };
+        let payments_response = get_payments_response(
+            Box::pin(payments_core::payments_core::<
+                api::IncrementalAuthorization,
+                api::PaymentsResponse,
+                _,
+                _,
+                _,
+                payments_core::PaymentData<api::IncrementalAuthorization>,
+            >(
+                state.clone(),
+                req_state.clone(),
+                merchant_account.clone(),
+                profile_id.clone(),
+                key_store.clone(),
+                payments_core::PaymentIncrementalAuthorization,
+                incremental_authorization_request,
+                services::AuthFlow::Merchant,
+                payments_core::CallConnectorAction::Trigger,
+                None,
+                hyperswitch_domain_models::payments::HeaderPayload::default(),
+                None, // Platform merchant account
+            ))
+            .await?,
+        )?;
+
+        if payments_response.amount_capturable < request.final_amount {
+            return Err(errors::ApiErrorResponse::PreconditionFailed {
+                message:
+                    "The authorization of the payment could not be incremented to final_amount"
+                        .to_string(),
+            }
+            .into());
+        }
+    }
+
+    let capture_request = api::PaymentsCaptureRequest {
+        payment_id: request.payment_id,
+        merchant_id: Some(merchant_account.get_id().clone()),
+        amount_to_capture: Some(request.final_amount),
+        ..Default::default()
+    };
+    Box::pin(payments_core::payments_core::<
+        api::Capture,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments_core::PaymentData<api::Capture>,
+    >(
+        state,
+        req_state,
+        merchant_account,
+        profile_id,
+        key_store,
+        payments_core::PaymentCapture,
+        capture_request,
+        services::AuthFlow::Merchant,
+        payments_core::CallConnectorAction::Trigger,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+        None, // Platform merchant account
+    ))
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_authorization_adjustment() {
+        let amount_capturable = MinorUnit::new(10000);
+
+        // Partial capture below the estimated amount
+        assert_eq!(
+            get_authorization_adjustment(MinorUnit::new(8000), amount_capturable, None, false).ok(),
+            Some(AuthorizationAdjustment::NotRequired)
+        );
+
+        // Overcapture within the tolerance of the card network
+        assert_eq!(
+            get_authorization_adjustment(
+                MinorUnit::new(11500),
+                amount_capturable,
+                Some(MinorUnit::new(1500)),
+                false
+            )
+            .ok(),
+            Some(AuthorizationAdjustment::NotRequired)
+        );
+
+        // Above the tolerance of the card network
+        assert_eq!(
+            get_authorization_adjustment(
+                MinorUnit::new(11501),
+                amount_capturable,
+                Some(MinorUnit::new(1500)),
+                true
+            )
+            .ok(),
+            Some(AuthorizationAdjustment::IncrementalAuthorization)
+        );
+        assert!(get_authorization_adjustment(
+            MinorUnit::new(11501),
+            amount_capturable,
+            Some(MinorUnit::new(1500)),
+            false
+        )
+        .is_err());
+    }
+}
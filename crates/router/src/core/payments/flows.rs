@@ -484,17 +484,6 @@ macro_rules! default_imp_for_accept_dispute {
 
 #[cfg(feature = "dummy_connector")]
 impl<const T: u8> api::Dispute for connector::DummyConnector<T> {}
-#[cfg(feature = "dummy_connector")]
-impl<const T: u8> api::AcceptDispute for connector::DummyConnector<T> {}
-#[cfg(feature = "dummy_connector")]
-impl<const T: u8>
-    services::ConnectorIntegration<
-        api::Accept,
-        types::AcceptDisputeRequestData,
-        types::AcceptDisputeResponse,
-    > for connector::DummyConnector<T>
-{
-}
 
 default_imp_for_accept_dispute!(
     connector::Adyenplatform,
@@ -591,18 +580,6 @@ macro_rules! default_imp_for_submit_evidence {
     };
 }
 
-#[cfg(feature = "dummy_connector")]
-impl<const T: u8> api::SubmitEvidence for connector::DummyConnector<T> {}
-#[cfg(feature = "dummy_connector")]
-impl<const T: u8>
-    services::ConnectorIntegration<
-        api::Evidence,
-        types::SubmitEvidenceRequestData,
-        types::SubmitEvidenceResponse,
-    > for connector::DummyConnector<T>
-{
-}
-
 default_imp_for_submit_evidence!(
     connector::Adyenplatform,
     connector::Ebanx,
@@ -823,17 +800,6 @@ macro_rules! default_imp_for_payouts_retrieve {
     };
 }
 
-#[cfg(feature = "payouts")]
-#[cfg(feature = "dummy_connector")]
-impl<const T: u8> api::PayoutSync for connector::DummyConnector<T> {}
-#[cfg(feature = "payouts")]
-#[cfg(feature = "dummy_connector")]
-impl<const T: u8>
-    services::ConnectorIntegration<api::PoSync, types::PayoutsData, types::PayoutsResponseData>
-    for connector::DummyConnector<T>
-{
-}
-
 #[cfg(feature = "payouts")]
 default_imp_for_payouts_retrieve!(
     connector::Adyenplatform,
@@ -912,17 +878,6 @@ macro_rules! default_imp_for_payouts_fulfill {
     };
 }
 
-#[cfg(feature = "payouts")]
-#[cfg(feature = "dummy_connector")]
-impl<const T: u8> api::PayoutFulfill for connector::DummyConnector<T> {}
-#[cfg(feature = "payouts")]
-#[cfg(feature = "dummy_connector")]
-impl<const T: u8>
-    services::ConnectorIntegration<api::PoFulfill, types::PayoutsData, types::PayoutsResponseData>
-    for connector::DummyConnector<T>
-{
-}
-
 #[cfg(feature = "payouts")]
 default_imp_for_payouts_fulfill!(
     connector::Gpayments,
@@ -1570,17 +1525,6 @@ macro_rules! default_imp_for_revoking_mandates {
     };
 }
 
-#[cfg(feature = "dummy_connector")]
-impl<const T: u8> api::ConnectorMandateRevoke for connector::DummyConnector<T> {}
-#[cfg(feature = "dummy_connector")]
-impl<const T: u8>
-    services::ConnectorIntegration<
-        api::MandateRevoke,
-        types::MandateRevokeRequestData,
-        types::MandateRevokeResponseData,
-    > for connector::DummyConnector<T>
-{
-}
 default_imp_for_revoking_mandates!(
     connector::Adyenplatform,
     connector::Ebanx,
@@ -1940,6 +1884,47 @@ default_imp_for_post_session_tokens!(
     connector::Wise
 );
 
+macro_rules! default_imp_for_gift_card_balance_check {
+    ($($path:ident::$connector:ident),*) => {
+        $( impl api::PaymentGiftCardBalanceCheck for $path::$connector {}
+            impl
+            services::ConnectorIntegration<
+                api::Balance,
+                types::GiftCardBalanceCheckRequestData,
+                types::GiftCardBalanceCheckResponseData
+        > for $path::$connector
+        {}
+    )*
+    };
+}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8> api::PaymentGiftCardBalanceCheck for connector::DummyConnector<T> {}
+#[cfg(feature = "dummy_connector")]
+impl<const T: u8>
+    services::ConnectorIntegration<
+        api::Balance,
+        types::GiftCardBalanceCheckRequestData,
+        types::GiftCardBalanceCheckResponseData,
+    > for connector::DummyConnector<T>
+{
+}
+
+default_imp_for_gift_card_balance_check!(
+    connector::Adyenplatform,
+    connector::Ebanx,
+    connector::Gpayments,
+    connector::Netcetera,
+    connector::Nmi,
+    connector::Payone,
+    connector::Plaid,
+    connector::Riskified,
+    connector::Signifyd,
+    connector::Stripe,
+    connector::Threedsecureio,
+    connector::Wellsfargopayout,
+    connector::Wise
+);
+
 macro_rules! default_imp_for_uas_pre_authentication {
     ($($path:ident::$connector:ident),*) => {
         $( impl UnifiedAuthenticationService for $path::$connector {}
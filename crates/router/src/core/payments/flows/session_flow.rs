@@ -14,7 +14,7 @@ use super::{ConstructFlowSpecificData, Feature};
 use crate::{
     consts::PROTOCOL,
     core::{
-        errors::{self, ConnectorErrorExt, RouterResult},
+        errors::{self, RouterResult},
         payments::{self, access_token, helpers, transformers, PaymentData},
     },
     headers, logger,
@@ -1033,7 +1033,12 @@ fn create_gpay_session_token(
                                     merchant_name: gpay_info.merchant_info.merchant_name,
                                     merchant_id: gpay_info.merchant_info.merchant_id,
                                 },
-                                allowed_payment_methods: vec![gpay_allowed_payment_methods],
+                                allowed_payment_methods:
+                                    payments::google_pay::get_allowed_payment_methods_for_connector(
+                                        state,
+                                        connector.connector_name,
+                                        vec![gpay_allowed_payment_methods],
+                                    ),
                                 transaction_info,
                                 connector: connector.connector_name.to_string(),
                                 sdk_next_action: payment_types::SdkNextAction {
@@ -1099,7 +1104,12 @@ fn create_gpay_session_token(
                         payment_types::GpaySessionTokenResponse::GooglePaySession(
                             payment_types::GooglePaySessionResponse {
                                 merchant_info: gpay_data.data.merchant_info,
-                                allowed_payment_methods: gpay_allowed_payment_methods,
+                                allowed_payment_methods:
+                                    payments::google_pay::get_allowed_payment_methods_for_connector(
+                                        state,
+                                        connector.connector_name,
+                                        gpay_allowed_payment_methods,
+                                    ),
                                 transaction_info,
                                 connector: connector.connector_name.to_string(),
                                 sdk_next_action: payment_types::SdkNextAction {
@@ -1127,6 +1137,38 @@ fn create_gpay_session_token(
     }
 }
 
+/// Records the connector which the Google Pay session token is created for, if the token carries
+/// the tokenization parameters of the connector
+async fn record_gpay_tokenization_connector(
+    state: &routes::SessionState,
+    router_data: &types::PaymentsSessionRouterData,
+    connector: &api::ConnectorData,
+) {
+    let is_gpay_session = matches!(
+        &router_data.response,
+        Ok(types::PaymentsResponseData::SessionResponse {
+            session_token: payment_types::SessionToken::GooglePay(gpay_session_token_response),
+        }) if matches!(
+            gpay_session_token_response.as_ref(),
+            payment_types::GpaySessionTokenResponse::GooglePaySession(_)
+        )
+    );
+
+    if let Some(merchant_connector_id) = connector
+        .merchant_connector_id
+        .as_ref()
+        .filter(|_| is_gpay_session)
+    {
+        payments::google_pay::record_tokenization_connector(
+            state,
+            &router_data.merchant_id,
+            &router_data.payment_id,
+            merchant_connector_id,
+        )
+        .await;
+    }
+}
+
 /// Card Type for Google Pay Allowerd Payment Methods
 pub(crate) const CARD: &str = "CARD";
 
@@ -1269,7 +1311,10 @@ impl RouterDataSession for types::PaymentsSessionRouterData {
     ) -> RouterResult<Self> {
         match connector.get_token {
             api::GetToken::GpayMetadata => {
-                create_gpay_session_token(state, self, connector, business_profile)
+                let session_router_data =
+                    create_gpay_session_token(state, self, connector, business_profile)?;
+                record_gpay_tokenization_connector(state, &session_router_data, connector).await;
+                Ok(session_router_data)
             }
             api::GetToken::SamsungPayMetadata => create_samsung_pay_session_token(
                 state,
@@ -1298,17 +1343,15 @@ impl RouterDataSession for types::PaymentsSessionRouterData {
                     types::PaymentsSessionData,
                     types::PaymentsResponseData,
                 > = connector.connector.get_connector_integration();
-                let resp = services::execute_connector_processing_step(
+                payments::session_response_cache::execute_session_call(
                     state,
                     connector_integration,
                     self,
+                    connector,
                     call_connector_action,
-                    None,
+                    business_profile.get_id(),
                 )
                 .await
-                .to_payment_failed_response()?;
-
-                Ok(resp)
             }
         }
     }
@@ -0,0 +1,342 @@
+//! Gift cards, whose balance is checked with the connector before they are redeemed. A gift card
+//! pays the amount of a payment up to its balance. With partial payments enabled for the payment,
+//! an attempt with a gift card whose balance does not cover the amount remaining to be paid pays
+//! the balance, and the rest of the amount is paid with another payment method by a further
+//! attempt of the payment. Otherwise the gift card can only pay the whole amount of the payment.
+
+use api_models::{
+    admin::PaymentMethodsEnabled,
+    payments::{PaymentsGiftCardBalanceRequest, PaymentsGiftCardBalanceResponse},
+};
+use common_utils::{ext_traits::ValueExt, id_type, types::MinorUnit};
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::payment_method_data::GetPaymentMethodType;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, utils::ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
+        payments::{self, helpers, partial_payments},
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services,
+    types::{
+        self, api, domain,
+        storage::{self, enums},
+    },
+};
+
+/// The part of the amount remaining to be paid which is paid with a gift card of the given
+/// balance. Without partial payments, the gift card pays either the whole amount or nothing.
+fn get_redeemable_amount(
+    balance: MinorUnit,
+    amount_remaining: MinorUnit,
+    is_partial_payments_enabled: bool,
+) -> MinorUnit {
+    if balance >= amount_remaining {
+        amount_remaining
+    } else if is_partial_payments_enabled {
+        balance.max(MinorUnit::zero())
+    } else {
+        MinorUnit::zero()
+    }
+}
+
+fn is_gift_card_enabled(
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    payment_method_type: enums::PaymentMethodType,
+) -> bool {
+    merchant_connector_account
+        .payment_methods_enabled
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|payment_methods_enabled| {
+            payment_methods_enabled
+                .parse_value::<PaymentMethodsEnabled>("payment_methods_enabled")
+                .inspect_err(|error| logger::error!(?error))
+                .ok()
+        })
+        .filter(|payment_methods_enabled| {
+            payment_methods_enabled.payment_method == enums::PaymentMethod::GiftCard
+        })
+        .flat_map(|payment_methods_enabled| {
+            payment_methods_enabled
+                .payment_method_types
+                .unwrap_or_default()
+        })
+        .any(|payment_method_types| payment_method_types.payment_method_type == payment_method_type)
+}
+
+/// The connector account of the profile of the payment with which the balance of the gift card is
+/// checked, which is either the given one or the first one with the gift card enabled
+async fn find_connector_account(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: &storage::PaymentIntent,
+    payment_method_type: enums::PaymentMethodType,
+    merchant_connector_id: Option<&id_type::MerchantConnectorAccountId>,
+) -> RouterResult<domain::MerchantConnectorAccount> {
+    let profile_id = payment_intent
+        .profile_id
+        .as_ref()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("profile_id is not set in payment_intent")?;
+
+    let merchant_connector_accounts = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &state.into(),
+            &payment_intent.merchant_id,
+            false,
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Database error when querying for merchant connector accounts")?;
+
+    let mut merchant_connector_accounts = helpers::filter_mca_based_on_profile_and_connector_type(
+        merchant_connector_accounts,
+        profile_id,
+        enums::ConnectorType::PaymentProcessor,
+    )
+    .into_iter();
+
+    let merchant_connector_account = match merchant_connector_id {
+        Some(merchant_connector_id) => merchant_connector_accounts
+            .find(|merchant_connector_account| {
+                merchant_connector_account.get_id() == *merchant_connector_id
+            })
+            .ok_or(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+                id: merchant_connector_id.get_string_repr().to_string(),
+            })?,
+        None => merchant_connector_accounts
+            .find(|merchant_connector_account| {
+                is_gift_card_enabled(merchant_connector_account, payment_method_type)
+            })
+            .ok_or_else(|| errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "No connector account of the profile has the {payment_method_type} gift card enabled"
+                ),
+            })?,
+    };
+    Ok(merchant_connector_account)
+}
+
+/// Checks the balance of the gift card with the connector
+#[instrument(skip_all)]
+pub async fn check_balance(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: &storage::PaymentIntent,
+    gift_card_data: domain::GiftCardData,
+    merchant_connector_id: Option<&id_type::MerchantConnectorAccountId>,
+) -> RouterResult<types::GiftCardBalanceCheckResponseData> {
+    let merchant_connector_account = find_connector_account(
+        state,
+        key_store,
+        payment_intent,
+        gift_card_data.get_payment_method_type(),
+        merchant_connector_id,
+    )
+    .await?;
+    let connector_name = merchant_connector_account.connector_name.clone();
+
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &connector_name,
+        api::GetToken::Connector,
+        Some(merchant_connector_account.get_id()),
+    )?;
+    let connector_integration: services::BoxedPaymentConnectorIntegrationInterface<
+        api::Balance,
+        types::GiftCardBalanceCheckRequestData,
+        types::GiftCardBalanceCheckResponseData,
+    > = connector_data.connector.get_connector_integration();
+
+    let router_data = core_utils::construct_gift_card_balance_check_router_data(
+        state,
+        payment_intent,
+        &merchant_connector_account,
+        gift_card_data,
+    )?;
+
+    // Connectors which do not implement the balance check build no request
+    let connector_request = connector_integration
+        .build_request(&router_data, &state.conf.connectors)
+        .to_payment_failed_response()?
+        .ok_or_else(|| errors::ApiErrorResponse::NotSupported {
+            message: format!("Checking the balance of gift cards with {connector_name}"),
+        })?;
+
+    let router_data = services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        payments::CallConnectorAction::Trigger,
+        Some(connector_request),
+    )
+    .await
+    .to_payment_failed_response()?;
+
+    Ok(router_data
+        .response
+        .map_err(|error| errors::ApiErrorResponse::ExternalConnectorError {
+            code: error.code,
+            message: error.message,
+            connector: connector_name,
+            status_code: error.status_code,
+            reason: error.reason,
+        })?)
+}
+
+/// Retrieves the balance of a gift card, and the part of the amount remaining to be paid for the
+/// payment which the gift card can pay
+#[instrument(skip_all)]
+pub async fn get_gift_card_balance(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    request: PaymentsGiftCardBalanceRequest,
+) -> RouterResponse<PaymentsGiftCardBalanceResponse> {
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &request.payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payment_intent)?;
+
+    helpers::validate_payment_status_against_allowed_statuses(
+        payment_intent.status,
+        &[
+            enums::IntentStatus::RequiresPaymentMethod,
+            enums::IntentStatus::RequiresConfirmation,
+        ],
+        "check the balance of a gift card for",
+    )?;
+
+    let balance = check_balance(
+        &state,
+        &key_store,
+        &payment_intent,
+        domain::GiftCardData::from(request.gift_card),
+        request.merchant_connector_id.as_ref(),
+    )
+    .await?;
+
+    let amount_remaining =
+        partial_payments::get_amount_remaining(&payment_intent).unwrap_or(payment_intent.amount);
+    let redeemable_amount = if payment_intent.currency == Some(balance.currency) {
+        get_redeemable_amount(
+            balance.balance,
+            amount_remaining,
+            partial_payments::is_partial_payments_enabled(&payment_intent),
+        )
+    } else {
+        MinorUnit::zero()
+    };
+    let amount_remaining_after_redemption = amount_remaining - redeemable_amount;
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentsGiftCardBalanceResponse {
+            payment_id: request.payment_id,
+            balance: balance.balance,
+            currency: balance.currency,
+            redeemable_amount,
+            amount_remaining_after_redemption,
+            requires_additional_payment_method: amount_remaining_after_redemption
+                > MinorUnit::zero(),
+        },
+    ))
+}
+
+/// The part of the amount of a payment with partial payments enabled to be paid by an attempt with
+/// a gift card, given the amount of the attempt. The balance is checked once the payment is routed,
+/// with the connector account it is routed to. The gift card pays its balance when it does not
+/// cover the amount remaining to be paid, while an attempt for a part of the amount passed in the
+/// request is left as is. Failures to check the balance are only logged, as the connector then
+/// declines the attempt if the balance is insufficient.
+#[instrument(skip_all)]
+pub async fn get_attempt_amount(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: &storage::PaymentIntent,
+    payment_method_data: Option<&domain::PaymentMethodData>,
+    attempt_amount: MinorUnit,
+    merchant_connector_id: Option<&id_type::MerchantConnectorAccountId>,
+) -> RouterResult<MinorUnit> {
+    let Some(domain::PaymentMethodData::GiftCard(gift_card_data)) = payment_method_data else {
+        return Ok(attempt_amount);
+    };
+    let amount_remaining = partial_payments::get_amount_remaining(payment_intent);
+    if amount_remaining != Some(attempt_amount) {
+        return Ok(attempt_amount);
+    }
+
+    let balance = match check_balance(
+        state,
+        key_store,
+        payment_intent,
+        gift_card_data.as_ref().clone(),
+        merchant_connector_id,
+    )
+    .await
+    {
+        Ok(balance) if payment_intent.currency == Some(balance.currency) => balance.balance,
+        Ok(balance) => {
+            logger::warn!(currency = ?balance.currency, "Gift card balance in another currency");
+            return Ok(attempt_amount);
+        }
+        Err(error) => {
+            logger::warn!(?error, "Failed to check the balance of the gift card");
+            return Ok(attempt_amount);
+        }
+    };
+
+    match get_redeemable_amount(balance, attempt_amount, true) {
+        redeemable_amount if redeemable_amount > MinorUnit::zero() => Ok(redeemable_amount),
+        _ => Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The gift card has no balance".to_string(),
+        })),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_redeemable_amount() {
+        let amount_remaining = MinorUnit::new(6540);
+
+        assert_eq!(
+            get_redeemable_amount(MinorUnit::new(2500), amount_remaining, true),
+            MinorUnit::new(2500)
+        );
+        assert_eq!(
+            get_redeemable_amount(MinorUnit::new(10000), amount_remaining, true),
+            amount_remaining
+        );
+        assert_eq!(
+            get_redeemable_amount(MinorUnit::new(2500), amount_remaining, false),
+            MinorUnit::zero()
+        );
+        assert_eq!(
+            get_redeemable_amount(amount_remaining, amount_remaining, false),
+            amount_remaining
+        );
+        assert_eq!(
+            get_redeemable_amount(MinorUnit::zero(), amount_remaining, true),
+            MinorUnit::zero()
+        );
+    }
+}
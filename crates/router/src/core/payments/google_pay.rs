@@ -0,0 +1,344 @@
+//! Google Pay tokenization parameters generated for the connector which the payment is likely to be
+//! routed to. The gateway parameters of the Google Pay session token are taken from the connector
+//! chosen by the session routing, and the connector is recorded for the payment. A payment routed
+//! at confirm to a connector other than the one its Google Pay token was created for is routed to
+//! the recorded connector instead when it is eligible, as the token can only be processed by it.
+//! Otherwise the routed connector is recorded for the re-tokenization, so that the next session
+//! token of the payment is created for it, and the payment method has to be tokenized again.
+
+use std::collections::HashMap;
+
+#[cfg(feature = "v1")]
+use api_models::routing::RoutableConnectorChoice;
+use api_models::{enums, payments as payment_types};
+use common_utils::id_type;
+#[cfg(feature = "v1")]
+use error_stack::report;
+use router_env::{instrument, logger, tracing};
+
+use crate::routes::SessionState;
+#[cfg(feature = "v1")]
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        payments::OperationSessionGetters,
+    },
+    types::domain,
+};
+
+fn get_tokenization_connector_key(merchant_id: &id_type::MerchantId, payment_id: &str) -> String {
+    format!(
+        "gpay_tokenization_connector_{}_{payment_id}",
+        merchant_id.get_string_repr()
+    )
+}
+
+fn get_retokenization_connector_key(merchant_id: &id_type::MerchantId, payment_id: &str) -> String {
+    format!(
+        "gpay_retokenization_connector_{}_{payment_id}",
+        merchant_id.get_string_repr()
+    )
+}
+
+/// Sets the gateway of the connector in the tokenization specification of the payment gateway
+/// type, when the connector account does not set it
+fn fill_gateway(
+    gateways: &HashMap<enums::Connector, String>,
+    connector: enums::Connector,
+    mut tokenization_specification: payment_types::GpayTokenizationSpecification,
+) -> payment_types::GpayTokenizationSpecification {
+    let is_payment_gateway = tokenization_specification.token_specification_type
+        == payment_types::GooglePayTokenizationType::PaymentGateway.to_string();
+    if is_payment_gateway && tokenization_specification.parameters.gateway.is_none() {
+        tokenization_specification.parameters.gateway = gateways.get(&connector).cloned();
+    }
+    tokenization_specification
+}
+
+/// The allowed payment methods of the Google Pay session token with the gateway parameters of the
+/// connector
+pub fn get_allowed_payment_methods_for_connector(
+    state: &SessionState,
+    connector: enums::Connector,
+    allowed_payment_methods: Vec<payment_types::GpayAllowedPaymentMethods>,
+) -> Vec<payment_types::GpayAllowedPaymentMethods> {
+    allowed_payment_methods
+        .into_iter()
+        .map(
+            |allowed_payment_method| payment_types::GpayAllowedPaymentMethods {
+                tokenization_specification: fill_gateway(
+                    &state.conf.google_pay_gateway_parameters.gateways,
+                    connector,
+                    allowed_payment_method.tokenization_specification,
+                ),
+                ..allowed_payment_method
+            },
+        )
+        .collect()
+}
+
+async fn set_connector(
+    state: &SessionState,
+    key: String,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) {
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection");
+            return;
+        }
+    };
+
+    if let Err(error) = redis_conn
+        .serialize_and_set_key_with_expiry(
+            &key.as_str().into(),
+            merchant_connector_id,
+            state.conf.google_pay_gateway_parameters.ttl_in_seconds,
+        )
+        .await
+    {
+        logger::error!(?error, %key, "Failed to record the Google Pay tokenization connector");
+    }
+}
+
+async fn get_connector(
+    state: &SessionState,
+    key: String,
+) -> Option<id_type::MerchantConnectorAccountId> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .inspect_err(|error| logger::error!(?error, "Failed to get redis connection"))
+        .ok()?;
+
+    redis_conn
+        .get_and_deserialize_key::<id_type::MerchantConnectorAccountId>(
+            &key.as_str().into(),
+            "MerchantConnectorAccountId",
+        )
+        .await
+        .inspect_err(|error| {
+            logger::debug!(?error, %key, "Google Pay tokenization connector not found");
+        })
+        .ok()
+}
+
+/// Records the connector which the Google Pay session token of the payment is created for.
+/// Failures are only logged, as the payment is then routed without regard to the token.
+#[instrument(skip_all)]
+pub async fn record_tokenization_connector(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &str,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) {
+    set_connector(
+        state,
+        get_tokenization_connector_key(merchant_id, payment_id),
+        merchant_connector_id,
+    )
+    .await
+}
+
+/// The connector which the Google Pay session token of the payment has to be created for, after
+/// the payment was routed to it at confirm
+pub async fn get_retokenization_connector(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &str,
+) -> Option<id_type::MerchantConnectorAccountId> {
+    get_connector(
+        state,
+        get_retokenization_connector_key(merchant_id, payment_id),
+    )
+    .await
+}
+
+/// The connectors with the given connector moved to the front, if it is one of them
+#[cfg(feature = "v1")]
+fn move_connector_to_front(
+    mut connectors: Vec<RoutableConnectorChoice>,
+    merchant_connector_id: &id_type::MerchantConnectorAccountId,
+) -> Result<Vec<RoutableConnectorChoice>, Vec<RoutableConnectorChoice>> {
+    match connectors.iter().position(|connector| {
+        connector.merchant_connector_id.as_ref() == Some(merchant_connector_id)
+    }) {
+        Some(position) => {
+            let connector = connectors.remove(position);
+            connectors.insert(0, connector);
+            Ok(connectors)
+        }
+        None => Err(connectors),
+    }
+}
+
+/// Routes the Google Pay payment to the connector which its token was created for. When that
+/// connector is not eligible for the payment, the connector which the payment is routed to is
+/// recorded for the re-tokenization, and the payment fails until the payment method is tokenized
+/// again with a new session token of the payment.
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub async fn route_to_tokenization_connector<F, D>(
+    state: &SessionState,
+    payment_data: &D,
+    connectors: Vec<RoutableConnectorChoice>,
+) -> RouterResult<Vec<RoutableConnectorChoice>>
+where
+    F: Clone,
+    D: OperationSessionGetters<F>,
+{
+    if !matches!(
+        payment_data.get_payment_method_data(),
+        Some(domain::PaymentMethodData::Wallet(
+            domain::WalletData::GooglePay(_)
+        ))
+    ) {
+        return Ok(connectors);
+    }
+
+    let payment_intent = payment_data.get_payment_intent();
+    let merchant_id = &payment_intent.merchant_id;
+    let payment_id = payment_intent.get_id().get_string_repr();
+    let Some(tokenization_connector) = get_connector(
+        state,
+        get_tokenization_connector_key(merchant_id, payment_id),
+    )
+    .await
+    else {
+        return Ok(connectors);
+    };
+
+    let connectors = match move_connector_to_front(connectors, &tokenization_connector) {
+        Ok(connectors) => return Ok(connectors),
+        Err(connectors) => connectors,
+    };
+    let Some(routed_connector) = connectors
+        .first()
+        .and_then(|connector| connector.merchant_connector_id.clone())
+    else {
+        return Ok(connectors);
+    };
+
+    logger::info!(
+        ?tokenization_connector,
+        ?routed_connector,
+        "Google Pay token created for a connector which the payment cannot be routed to"
+    );
+    set_connector(
+        state,
+        get_retokenization_connector_key(merchant_id, payment_id),
+        &routed_connector,
+    )
+    .await;
+    crate::routes::metrics::GOOGLE_PAY_RETOKENIZATION_REQUIRED.add(
+        1,
+        router_env::metric_attributes!(("merchant_id", merchant_id.clone())),
+    );
+
+    Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+        message: "The Google Pay payment method was tokenized for a connector which the payment \
+                  cannot be routed to, and has to be tokenized again with a new session token"
+            .to_string(),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_tokenization_specification(
+        token_specification_type: payment_types::GooglePayTokenizationType,
+        gateway: Option<&str>,
+    ) -> payment_types::GpayTokenizationSpecification {
+        payment_types::GpayTokenizationSpecification {
+            token_specification_type: token_specification_type.to_string(),
+            parameters: payment_types::GpayTokenParameters {
+                gateway: gateway.map(str::to_string),
+                gateway_merchant_id: Some("merchant".to_string()),
+                stripe_version: None,
+                stripe_publishable_key: None,
+                protocol_version: None,
+                public_key: None,
+            },
+        }
+    }
+
+    #[test]
+    fn test_fill_gateway() {
+        let gateways = HashMap::from([(enums::Connector::Checkout, "checkoutltd".to_string())]);
+
+        let tokenization_specification = fill_gateway(
+            &gateways,
+            enums::Connector::Checkout,
+            get_tokenization_specification(
+                payment_types::GooglePayTokenizationType::PaymentGateway,
+                None,
+            ),
+        );
+        assert_eq!(
+            tokenization_specification.parameters.gateway.as_deref(),
+            Some("checkoutltd")
+        );
+
+        let tokenization_specification = fill_gateway(
+            &gateways,
+            enums::Connector::Checkout,
+            get_tokenization_specification(
+                payment_types::GooglePayTokenizationType::PaymentGateway,
+                Some("checkout"),
+            ),
+        );
+        assert_eq!(
+            tokenization_specification.parameters.gateway.as_deref(),
+            Some("checkout")
+        );
+
+        let tokenization_specification = fill_gateway(
+            &gateways,
+            enums::Connector::Checkout,
+            get_tokenization_specification(payment_types::GooglePayTokenizationType::Direct, None),
+        );
+        assert_eq!(tokenization_specification.parameters.gateway, None);
+    }
+
+    #[cfg(feature = "v1")]
+    #[test]
+    fn test_move_connector_to_front() {
+        let get_connector = |connector, merchant_connector_id: &str| RoutableConnectorChoice {
+            choice_kind: api_models::routing::RoutableChoiceKind::FullStruct,
+            connector,
+            merchant_connector_id: Some(
+                id_type::MerchantConnectorAccountId::wrap(merchant_connector_id.to_string())
+                    .unwrap(),
+            ),
+        };
+        let connectors = vec![
+            get_connector(enums::RoutableConnectors::Adyen, "mca_adyen"),
+            get_connector(enums::RoutableConnectors::Checkout, "mca_checkout"),
+        ];
+
+        let moved_connectors = move_connector_to_front(
+            connectors.clone(),
+            &id_type::MerchantConnectorAccountId::wrap("mca_checkout".to_string()).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            moved_connectors
+                .iter()
+                .map(|connector| connector.connector)
+                .collect::<Vec<_>>(),
+            vec![
+                enums::RoutableConnectors::Checkout,
+                enums::RoutableConnectors::Adyen
+            ]
+        );
+
+        assert!(move_connector_to_front(
+            connectors,
+            &id_type::MerchantConnectorAccountId::wrap("mca_stripe".to_string()).unwrap(),
+        )
+        .is_err());
+    }
+}
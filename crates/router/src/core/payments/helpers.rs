@@ -56,11 +56,14 @@ use super::{
     operations::{BoxedOperation, Operation, PaymentResponse},
     CustomerDetails, PaymentData,
 };
+#[cfg(feature = "v1")]
+use crate::core::{data_residency, sandbox};
 use crate::{
     configs::settings::{ConnectorRequestReferenceIdConfig, TempLockerEnableConfig},
     connector,
     consts::{self, BASE64_ENGINE},
     core::{
+        address_validation::AddressValidationRecord,
         authentication,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate::helpers::MandateGenericData,
@@ -108,6 +111,14 @@ pub fn filter_mca_based_on_profile_and_connector_type(
         .collect()
 }
 
+/// Generates the idempotency key of a new payment attempt. The key is persisted with the attempt
+/// and sent to the connectors supporting idempotency, so that a request resent for the attempt is
+/// not processed twice by the connector.
+#[cfg(feature = "v1")]
+pub fn generate_connector_idempotency_key() -> String {
+    common_utils::generate_id(consts::CONNECTOR_IDEMPOTENCY_KEY_LENGTH, "HS")
+}
+
 #[instrument(skip_all)]
 #[allow(clippy::too_many_arguments)]
 pub async fn create_or_update_address_for_payment_by_request(
@@ -119,13 +130,26 @@ pub async fn create_or_update_address_for_payment_by_request(
     merchant_key_store: &domain::MerchantKeyStore,
     payment_id: &id_type::PaymentId,
     storage_scheme: storage_enums::MerchantStorageScheme,
+    address_validation: Option<&AddressValidationRecord>,
 ) -> CustomResult<Option<domain::Address>, errors::ApiErrorResponse> {
     let key = merchant_key_store.key.get_inner().peek();
-    let db = &session_state.store;
+    #[cfg(feature = "v1")]
+    let db = data_residency::get_pii_store(session_state, merchant_id).await?;
+    #[cfg(feature = "v1")]
+    let key_manager_state =
+        &data_residency::get_key_manager_state(session_state, merchant_id).await?;
+    #[cfg(feature = "v2")]
+    let db = &*session_state.store;
+    #[cfg(feature = "v2")]
     let key_manager_state = &session_state.into();
     Ok(match address_id {
         Some(id) => match req_address {
             Some(address) => {
+                let normalized_address = address_validation
+                    .map(AddressValidationRecord::to_encryptable_value)
+                    .transpose()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to serialize the address validation record")?;
                 let encrypted_data = types::crypto_operation(
                     &session_state.into(),
                     type_name!(domain::Address),
@@ -153,6 +177,7 @@ pub async fn create_or_update_address_for_payment_by_request(
                                     .email
                                     .as_ref()
                                     .map(|a| a.clone().expose().switch_strategy()),
+                                normalized_address,
                             },
                         ),
                     ),
@@ -194,6 +219,7 @@ pub async fn create_or_update_address_for_payment_by_request(
                             );
                         encryptable
                     }),
+                    normalized_address: encryptable_address.normalized_address,
                 };
                 let address = db
                     .find_address_by_merchant_id_payment_id_address_id(
@@ -238,11 +264,17 @@ pub async fn create_or_update_address_for_payment_by_request(
         },
         None => match req_address {
             Some(address) => {
-                let address =
-                    get_domain_address(session_state, address, merchant_id, key, storage_scheme)
-                        .await
-                        .change_context(errors::ApiErrorResponse::InternalServerError)
-                        .attach_printable("Failed while encrypting address while insert")?;
+                let address = get_domain_address(
+                    session_state,
+                    address,
+                    merchant_id,
+                    key,
+                    storage_scheme,
+                    address_validation,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while encrypting address while insert")?;
 
                 let payment_address = domain::PaymentAddress {
                     address,
@@ -281,9 +313,16 @@ pub async fn create_or_find_address_for_payment_by_request(
     merchant_key_store: &domain::MerchantKeyStore,
     payment_id: &id_type::PaymentId,
     storage_scheme: storage_enums::MerchantStorageScheme,
+    address_validation: Option<&AddressValidationRecord>,
 ) -> CustomResult<Option<domain::Address>, errors::ApiErrorResponse> {
     let key = merchant_key_store.key.get_inner().peek();
-    let db = &state.store;
+    #[cfg(feature = "v1")]
+    let db = data_residency::get_pii_store(state, merchant_id).await?;
+    #[cfg(feature = "v1")]
+    let key_manager_state = &data_residency::get_key_manager_state(state, merchant_id).await?;
+    #[cfg(feature = "v2")]
+    let db = &*state.store;
+    #[cfg(feature = "v2")]
     let key_manager_state = &state.into();
     Ok(match address_id {
         Some(id) => Some(
@@ -303,10 +342,17 @@ pub async fn create_or_find_address_for_payment_by_request(
         None => match req_address {
             Some(address) => {
                 // generate a new address here
-                let address = get_domain_address(state, address, merchant_id, key, storage_scheme)
-                    .await
-                    .change_context(errors::ApiErrorResponse::InternalServerError)
-                    .attach_printable("Failed while encrypting address while insert")?;
+                let address = get_domain_address(
+                    state,
+                    address,
+                    merchant_id,
+                    key,
+                    storage_scheme,
+                    address_validation,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed while encrypting address while insert")?;
 
                 let payment_address = domain::PaymentAddress {
                     address,
@@ -339,9 +385,14 @@ pub async fn get_domain_address(
     merchant_id: &id_type::MerchantId,
     key: &[u8],
     storage_scheme: enums::MerchantStorageScheme,
+    address_validation: Option<&AddressValidationRecord>,
 ) -> CustomResult<domain::Address, common_utils::errors::CryptoError> {
     async {
         let address_details = &address.address.as_ref();
+        let normalized_address = address_validation
+            .map(AddressValidationRecord::to_encryptable_value)
+            .transpose()
+            .change_context(common_utils::errors::CryptoError::EncodingFailed)?;
         let encrypted_data = types::crypto_operation(
             &session_state.into(),
             type_name!(domain::Address),
@@ -363,6 +414,7 @@ pub async fn get_domain_address(
                             .email
                             .as_ref()
                             .map(|a| a.clone().expose().switch_strategy()),
+                        normalized_address,
                     },
                 ),
             ),
@@ -391,6 +443,7 @@ pub async fn get_domain_address(
             modified_at: common_utils::date_time::now(),
             zip: encryptable_address.zip,
             updated_by: storage_scheme.to_string(),
+            normalized_address: encryptable_address.normalized_address,
             email: encryptable_address.email.map(|email| {
                 let encryptable: Encryptable<masking::Secret<String, pii::EmailStrategy>> =
                     Encryptable::new(
@@ -415,10 +468,18 @@ pub async fn get_address_by_id(
     match address_id {
         None => Ok(None),
         Some(address_id) => {
+            #[cfg(feature = "v1")]
+            let db = data_residency::get_pii_store(state, merchant_id).await?;
+            #[cfg(feature = "v1")]
+            let key_manager_state =
+                data_residency::get_key_manager_state(state, merchant_id).await?;
+            #[cfg(feature = "v2")]
             let db = &*state.store;
+            #[cfg(feature = "v2")]
+            let key_manager_state = KeyManagerState::from(state);
             Ok(db
                 .find_address_by_merchant_id_payment_id_address_id(
-                    &state.into(),
+                    &key_manager_state,
                     merchant_id,
                     payment_id,
                     &address_id,
@@ -714,6 +775,20 @@ pub async fn get_token_pm_type_mandate_details(
             )
         }
     };
+
+    // The payment methods whose consent was revoked by the customer are not to be charged by the
+    // merchant initiated payments
+    if let (Some(api::MandateTransactionType::RecurringMandateTransaction), Some(payment_method)) =
+        (&mandate_type, &payment_method_info)
+    {
+        payment_methods::consent::validate_payment_method_consent_not_revoked(
+            &*state.store,
+            &payment_method.merchant_id,
+            &payment_method.payment_method_id,
+        )
+        .await?;
+    }
+
     Ok(MandateGenericData {
         token: payment_token,
         payment_method,
@@ -800,6 +875,18 @@ pub async fn get_token_for_recurring_mandate(
         };
         mandate.payment_method_id.clone()
     };
+
+    // The end date of the mandate is enforced as per the sandbox test clock of the merchant, so
+    // that the expiry of mandates can be tested by advancing the test clock
+    let current_time = sandbox::get_merchant_current_time(db, &mandate.merchant_id).await;
+    if mandate
+        .end_date
+        .is_some_and(|end_date| end_date < current_time)
+    {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "mandate has expired".into()
+        }))?
+    }
     verify_mandate_details(
         req.amount.get_required_value("amount")?.into(),
         req.currency.get_required_value("currency")?,
@@ -1129,6 +1216,91 @@ pub fn validate_recurring_details_and_token(
     Ok(())
 }
 
+/// Validates the authentication values of a 3DS authentication performed by the merchant outside
+/// of Hyperswitch, which are passed through to the connector for the authorization
+#[cfg(feature = "v1")]
+pub fn validate_external_three_ds_data(
+    request: &api_models::payments::PaymentsRequest,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let Some(three_ds_data) = request.three_ds_data.as_ref() else {
+        return Ok(());
+    };
+
+    utils::when(
+        request.request_external_three_ds_authentication == Some(true),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Expected one out of three_ds_data and request_external_three_ds_authentication but got both".into()
+            }))
+        },
+    )?;
+
+    utils::when(request.ctp_service_details.is_some(), || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Expected one out of three_ds_data and ctp_service_details but got both"
+                .into()
+        }))
+    })?;
+
+    utils::when(
+        request
+            .payment_method
+            .is_some_and(|payment_method| payment_method != api_enums::PaymentMethod::Card),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "three_ds_data is supported only for card payments".into()
+            }))
+        },
+    )?;
+
+    utils::when(
+        request.authentication_type == Some(api_enums::AuthenticationType::NoThreeDs),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "three_ds_data can not be passed when authentication_type is no_three_ds"
+                    .into()
+            }))
+        },
+    )?;
+
+    utils::when(three_ds_data.cavv.peek().trim().is_empty(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "three_ds_data.cavv must not be empty".into()
+        }))
+    })?;
+
+    utils::when(
+        three_ds_data.eci.as_ref().is_some_and(|eci| {
+            eci.len() != 2 || !eci.chars().all(|character| character.is_ascii_digit())
+        }),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "three_ds_data.eci".into(),
+                expected_format: "two digit electronic commerce indicator, e.g. 05".into()
+            }))
+        },
+    )?;
+
+    Ok(())
+}
+
+/// Validates that a payment with an estimated amount is captured manually, so that its
+/// authorization can be adjusted to the final amount before it is captured
+#[cfg(feature = "v1")]
+pub fn validate_estimated_amount(
+    request: &api_models::payments::PaymentsRequest,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    utils::when(
+        request.is_amount_estimated == Some(true)
+            && request.capture_method != Some(api_enums::CaptureMethod::Manual),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "capture_method must be manual when is_amount_estimated is true".into()
+            }))
+        },
+    )
+}
+
 fn validate_new_mandate_request(
     req: api::MandateValidationFields,
     is_confirm_operation: bool,
@@ -1707,7 +1879,10 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, D>(
     let customer_id = request_customer_details
         .customer_id
         .or(payment_data.payment_intent.customer_id.clone());
-    let db = &*state.store;
+    let db = data_residency::get_pii_store(state, merchant_id)
+        .await
+        .change_context(errors::StorageError::DatabaseConnectionError)
+        .attach_printable("Failed to get the store holding the customers of the merchant")?;
     let key_manager_state = &state.into();
     let optional_customer = match customer_id {
         Some(customer_id) => {
@@ -2121,12 +2296,34 @@ pub async fn retrieve_payment_method_data_with_permanent_token(
         })
         .transpose()?;
 
+    let is_network_tokenization_enabled = business_profile.is_network_tokenization_enabled
+        && crate::core::feature_flags::is_feature_enabled(
+            state,
+            &payment_intent.merchant_id,
+            Some(business_profile.get_id()),
+            enums::MerchantFeatureFlag::NetworkTokenization,
+        )
+        .await;
+
+    // The preference of the profile applies only to the customer initiated payments, as the
+    // merchant initiated payments are authorized with the credential used to set up the mandate
+    let should_use_pan = should_retry_with_pan
+        || (is_network_tokenization_enabled
+            && mandate_id.is_none()
+            && payment_methods::network_token_preference::should_authorize_with_pan(
+                state,
+                business_profile.get_id(),
+                connector_variant,
+                payment_method_info.get_id(),
+            )
+            .await);
+
     let vault_fetch_action = decide_payment_method_retrieval_action(
-        business_profile.is_network_tokenization_enabled,
+        is_network_tokenization_enabled,
         mandate_id,
         connector_variant,
         network_tokenization_supported_connectors,
-        should_retry_with_pan,
+        should_use_pan,
         payment_method_info
             .network_token_requestor_reference_id
             .clone(),
@@ -2181,6 +2378,11 @@ pub async fn retrieve_payment_method_data_with_permanent_token(
                     logger::info!(
                         "Failed to fetch network token data from tokenization service {err:?}"
                     );
+                    payment_methods::network_token_preference::suspend_network_token(
+                        state,
+                        payment_method_info.get_id(),
+                    )
+                    .await;
                     logger::info!("Falling back to fetch card details from locker");
                     Ok(domain::PaymentMethodData::Card(
                         vault_data
@@ -2474,6 +2676,19 @@ pub async fn retrieve_payment_method_from_db_with_token_data(
             .attach_printable("error retrieveing payment method from DB")
             .map(Some),
 
+        storage::PaymentTokenData::PermanentBankDebit(data) => state
+            .store
+            .find_payment_method(
+                &(state.into()),
+                merchant_key_store,
+                &data.payment_method_id,
+                storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
+            .attach_printable("error retrieving payment method from DB")
+            .map(Some),
+
         storage::PaymentTokenData::Temporary(_)
         | storage::PaymentTokenData::TemporaryGeneric(_)
         | storage::PaymentTokenData::Permanent(_)
@@ -2612,6 +2827,26 @@ pub async fn make_pm_data<'a, F: Clone, R, D>(
         }
     }
 
+    let is_cvv_collected = card_token_data
+        .as_ref()
+        .is_some_and(|token_data| token_data.card_cvc.is_some());
+    let is_saved_card_payment = matches!(
+        payment_data.token_data,
+        Some(storage::PaymentTokenData::PermanentCard(_))
+    );
+    let is_on_session_saved_card_payment = is_saved_card_payment
+        && payment_data.payment_intent.off_session != Some(true)
+        && payment_data.mandate_id.is_none();
+
+    if payment_data.confirm == Some(true) && is_on_session_saved_card_payment && !is_cvv_collected {
+        payment_methods::cvv_recollection::validate_cvv_recollection(
+            &*state.store,
+            business_profile.get_id(),
+            payment_data.payment_attempt.connector.as_deref(),
+        )
+        .await?;
+    }
+
     let mandate_id = payment_data.mandate_id.clone();
 
     // TODO: Handle case where payment method and token both are present in request properly.
@@ -2857,6 +3092,68 @@ pub(crate) fn validate_amount_to_capture(
     )
 }
 
+/// Returns the amount which may be captured above the amount capturable of the attempt, if the
+/// merchant has enabled overcapture or the amount of the payment is an estimate, and both the
+/// connector and the card network of the attempt allow it
+#[cfg(feature = "v1")]
+pub(crate) async fn get_overcapture_allowance(
+    state: &SessionState,
+    payment_intent: &PaymentIntent,
+    payment_attempt: &PaymentAttempt,
+) -> RouterResult<Option<MinorUnit>> {
+    let is_overcapture_enabled = payment_intent.is_amount_estimated == Some(true)
+        || crate::core::feature_flags::is_feature_enabled(
+            state,
+            &payment_intent.merchant_id,
+            payment_intent.profile_id.as_ref(),
+            enums::MerchantFeatureFlag::Overcapture,
+        )
+        .await;
+    if !is_overcapture_enabled {
+        return Ok(None);
+    }
+
+    let Some(connector) = payment_attempt
+        .connector
+        .as_deref()
+        .and_then(|connector| api_enums::Connector::from_str(connector).ok())
+    else {
+        return Ok(None);
+    };
+
+    let card_network = payment_attempt
+        .payment_method_data
+        .clone()
+        .map(|payment_method_data| {
+            payment_method_data
+                .parse_value::<api_models::payments::AdditionalPaymentData>("AdditionalPaymentData")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the payment method data of the payment attempt")?
+        .and_then(|payment_method_data| match payment_method_data {
+            api_models::payments::AdditionalPaymentData::Card(card) => card.card_network,
+            _ => None,
+        });
+
+    Ok(card_network
+        .and_then(|card_network| {
+            state
+                .conf
+                .overcapture
+                .get_tolerance_percentage(connector, &card_network)
+        })
+        .map(|tolerance_percentage| {
+            MinorUnit::new(
+                payment_attempt
+                    .amount_capturable
+                    .get_amount_as_i64()
+                    .saturating_mul(i64::from(tolerance_percentage))
+                    / 100,
+            )
+        }))
+}
+
 #[cfg(feature = "v1")]
 #[instrument(skip_all)]
 pub(crate) fn validate_payment_method_fields_present(
@@ -3501,7 +3798,7 @@ pub fn generate_mandate(
 ) -> CustomResult<Option<storage::MandateNew>, errors::ApiErrorResponse> {
     match (setup_mandate_details, customer_id) {
         (Some(data), Some(cus_id)) => {
-            let mandate_id = utils::generate_id(consts::ID_LENGTH, "man");
+            let mandate_id = common_utils::generate_resource_id(consts::ID_LENGTH, "man");
 
             // The construction of the mandate new must be visible
             let mut new_mandate = storage::MandateNew::default();
@@ -3851,6 +4148,13 @@ mod tests {
             request_extended_authorization: None,
             psd2_sca_exemption_type: None,
             platform_merchant_id: None,
+            customer_email_hash: None,
+            customer_phone_hash: None,
+            change_sequence: None,
+            is_amount_estimated: None,
+            enable_partial_payments: None,
+            store_credit_amount: None,
+            is_test_payment: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_ok());
@@ -3923,6 +4227,13 @@ mod tests {
             request_extended_authorization: None,
             psd2_sca_exemption_type: None,
             platform_merchant_id: None,
+            customer_email_hash: None,
+            customer_phone_hash: None,
+            change_sequence: None,
+            is_amount_estimated: None,
+            enable_partial_payments: None,
+            store_credit_amount: None,
+            is_test_payment: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent,).is_err())
@@ -3993,6 +4304,13 @@ mod tests {
             request_extended_authorization: None,
             psd2_sca_exemption_type: None,
             platform_merchant_id: None,
+            customer_email_hash: None,
+            customer_phone_hash: None,
+            change_sequence: None,
+            is_amount_estimated: None,
+            enable_partial_payments: None,
+            store_credit_amount: None,
+            is_test_payment: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_err())
@@ -4317,6 +4635,7 @@ pub fn router_data_type_conversion<F1, F2, Req1, Req2, Res1, Res2>(
         connector_mandate_request_reference_id: router_data.connector_mandate_request_reference_id,
         authentication_id: router_data.authentication_id,
         psd2_sca_exemption_type: router_data.psd2_sca_exemption_type,
+        profile_id: router_data.profile_id,
     }
 }
 
@@ -4528,6 +4847,7 @@ impl AttemptType {
             extended_authorization_applied: None,
             capture_before: None,
             card_discovery: None,
+            connector_idempotency_key: Some(generate_connector_idempotency_key()),
         }
     }
 
@@ -6285,8 +6605,8 @@ pub fn get_key_params_for_surcharge_details(
 )> {
     match payment_method_data {
         domain::PaymentMethodData::Card(card) => {
-            // surcharge generated will always be same for credit as well as debit
-            // since surcharge conditions cannot be defined on card_type
+            // surcharge of a card is looked up for credit, since the surcharge based on the
+            // card BIN is calculated at confirm when surcharge rules are configured
             Some((
                 common_enums::PaymentMethod::Card,
                 common_enums::PaymentMethodType::Credit,
@@ -6742,6 +7062,35 @@ pub async fn get_payment_method_details_from_payment_token(
             .await
         }
 
+        storage::PaymentTokenData::PermanentBankDebit(bank_debit_token) => {
+            let payment_method = state
+                .store
+                .find_payment_method(
+                    &(state.into()),
+                    key_store,
+                    &bank_debit_token.payment_method_id,
+                    storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)
+                .attach_printable("error retrieving payment method from DB")?;
+
+            cards::get_bank_debit_from_hs_locker(
+                state,
+                key_store,
+                &payment_method.customer_id,
+                &payment_method.merchant_id,
+                &bank_debit_token.locker_id,
+            )
+            .await
+            .map(|bank_debit| {
+                Some((
+                    domain::PaymentMethodData::BankDebit(bank_debit.into()),
+                    enums::PaymentMethod::BankDebit,
+                ))
+            })
+        }
+
         storage::PaymentTokenData::WalletToken(_) => Ok(None),
     }
 }
@@ -7127,6 +7476,7 @@ pub async fn validate_merchant_connector_ids_in_connector_mandate_details(
 pub fn validate_platform_request_for_marketplace(
     amount: api::Amount,
     split_payments: Option<common_types::payments::SplitPaymentsRequest>,
+    rounding_mode: enums::RoundingMode,
 ) -> Result<(), errors::ApiErrorResponse> {
     match split_payments {
         Some(common_types::payments::SplitPaymentsRequest::StripeSplitPayment(
@@ -7270,11 +7620,18 @@ pub fn validate_platform_request_for_marketplace(
                                 message: "Expected either split_payments.xendit_split_payment.routes.flat_amount or split_payments.xendit_split_payment.routes.percent_amount, but not both".to_string(),
                             })
                         } else {
-                            Ok(route
+                            route
                                 .flat_amount
-                                .map(|amount| amount.get_amount_as_i64())
-                                .or(route.percent_amount.map(|percentage| (percentage * total_payment_amount) / 100))
-                                .unwrap_or(0))
+                                .map(Ok)
+                                .or(route.percent_amount.map(|percentage| {
+                                    MinorUnit::new(total_payment_amount)
+                                        .apply_percentage(percentage.into(), rounding_mode)
+                                        .map_err(|_| errors::ApiErrorResponse::InvalidDataValue {
+                                            field_name: "split_payments.xendit_split_payment.routes.percent_amount",
+                                        })
+                                }))
+                                .transpose()
+                                .map(|amount| amount.map_or(0, MinorUnit::get_amount_as_i64))
                             }
                             })
                             .collect::<Result<Vec<i64>, _>>()?
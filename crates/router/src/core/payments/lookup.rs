@@ -0,0 +1,71 @@
+//! Lookup of a payment by the reference of one of its attempts at the connector, such as the one
+//! on a bank statement or a dispute notice. The lookup is made across all the connectors of the
+//! merchant, through the reverse lookup of the connector transaction id that is maintained for the
+//! payment attempts.
+
+use api_models::payments::{PaymentLookupRequest, PaymentLookupResponse};
+use common_utils::id_type;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, StorageErrorExt},
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services,
+    types::domain,
+};
+
+#[instrument(skip_all)]
+pub async fn lookup_payment_by_connector_transaction_id(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    request: PaymentLookupRequest,
+) -> RouterResponse<PaymentLookupResponse> {
+    let connector_transaction_id = request.connector_transaction_id.trim();
+    if connector_transaction_id.is_empty() {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "connector_transaction_id must not be empty".to_string(),
+        }
+        .into());
+    }
+
+    let db = state.store.as_ref();
+    let payment_attempt = db
+        .find_payment_attempt_by_merchant_id_connector_txn_id(
+            merchant_account.get_id(),
+            connector_transaction_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &payment_attempt.payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payment_intent)?;
+
+    Ok(services::ApplicationResponse::Json(PaymentLookupResponse {
+        payment_id: payment_intent.payment_id,
+        attempt_id: payment_attempt.attempt_id,
+        connector: payment_attempt.connector,
+        connector_transaction_id: connector_transaction_id.to_owned(),
+        status: payment_intent.status,
+        attempt_status: payment_attempt.status,
+        amount: payment_intent.amount,
+        currency: payment_intent.currency,
+        profile_id: payment_intent.profile_id,
+        created_at: payment_intent.created_at,
+    }))
+}
@@ -0,0 +1,282 @@
+//! Annotations made by the merchant on a payment after it is created. The metadata of the payment
+//! can be updated in any status of the payment, and internal notes can be added to it, such as the
+//! findings of an investigation of a dispute by a dashboard user. The notes are stored in a table
+//! of their own, so that the payment itself is not modified when a note is added.
+
+use api_models::payments::{
+    PaymentNote, PaymentNoteRequest, PaymentNotesResponse, PaymentsMetadataUpdateRequest,
+    PaymentsMetadataUpdateResponse,
+};
+use common_utils::id_type;
+use error_stack::ResultExt;
+use masking::{ExposeInterface, Secret};
+use router_env::{instrument, tracing};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services,
+    types::{domain, storage},
+};
+
+/// Maximum number of notes that can be added to a payment
+const MAX_NOTES_PER_PAYMENT: usize = 100;
+
+/// Maximum length of the text of a note
+const MAX_NOTE_LENGTH: usize = 2048;
+
+async fn find_payment_intent(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<storage::PaymentIntent> {
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            payment_id,
+            merchant_account.get_id(),
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payment_intent)?;
+
+    Ok(payment_intent)
+}
+
+/// Sets the keys of the update in the metadata, removing the keys whose value is `null`
+fn merge_metadata(
+    metadata: Option<serde_json::Value>,
+    update: serde_json::Map<String, serde_json::Value>,
+) -> serde_json::Value {
+    let mut merged_metadata = match metadata {
+        Some(serde_json::Value::Object(metadata)) => metadata,
+        _ => serde_json::Map::new(),
+    };
+
+    for (key, value) in update {
+        if value.is_null() {
+            merged_metadata.remove(&key);
+        } else {
+            merged_metadata.insert(key, value);
+        }
+    }
+
+    serde_json::Value::Object(merged_metadata)
+}
+
+#[instrument(skip_all)]
+pub async fn update_payment_metadata(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    request: PaymentsMetadataUpdateRequest,
+) -> RouterResponse<PaymentsMetadataUpdateResponse> {
+    let serde_json::Value::Object(update) = request.metadata.expose() else {
+        return Err(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "metadata".to_string(),
+            expected_format: "JSON object".to_string(),
+        }
+        .into());
+    };
+
+    let payment_intent = find_payment_intent(
+        &state,
+        &merchant_account,
+        profile_id,
+        &key_store,
+        &request.payment_id,
+    )
+    .await?;
+
+    let metadata = merge_metadata(payment_intent.metadata.clone(), update);
+
+    let payment_intent = state
+        .store
+        .update_payment_intent(
+            &(&state).into(),
+            payment_intent,
+            storage::PaymentIntentUpdate::MetadataUpdate {
+                metadata,
+                updated_by: merchant_account.storage_scheme.to_string(),
+            },
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentsMetadataUpdateResponse {
+            payment_id: payment_intent.payment_id,
+            metadata: payment_intent.metadata.map(Secret::new),
+        },
+    ))
+}
+
+async fn find_payment_notes(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<Vec<PaymentNote>> {
+    state
+        .store
+        .find_payment_notes_by_merchant_id_payment_id(merchant_id, payment_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payment notes")
+        .map(|notes| notes.into_iter().map(get_payment_note).collect())
+}
+
+fn get_payment_note(note: storage::PaymentNote) -> PaymentNote {
+    PaymentNote {
+        note_id: note.note_id,
+        author: note.author,
+        note: note.note,
+        created_at: note.created_at,
+    }
+}
+
+fn validate_payment_note(request: &PaymentNoteRequest) -> RouterResult<()> {
+    if request.note.trim().is_empty() || request.note.len() > MAX_NOTE_LENGTH {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "note must be non empty and at most {MAX_NOTE_LENGTH} characters long"
+            ),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Adds a note to the payment, authored by the dashboard user adding it
+#[instrument(skip_all)]
+pub async fn add_payment_note(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    user_id: String,
+    request: PaymentNoteRequest,
+) -> RouterResponse<PaymentNotesResponse> {
+    validate_payment_note(&request)?;
+
+    let payment_intent = find_payment_intent(
+        &state,
+        &merchant_account,
+        profile_id,
+        &key_store,
+        &request.payment_id,
+    )
+    .await?;
+
+    let merchant_id = merchant_account.get_id();
+    let mut notes = find_payment_notes(&state, merchant_id, &payment_intent.payment_id).await?;
+
+    if notes.len() >= MAX_NOTES_PER_PAYMENT {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "A payment can have at most {MAX_NOTES_PER_PAYMENT} notes, no more notes can be added"
+            ),
+        }
+        .into());
+    }
+
+    let note = state
+        .store
+        .insert_payment_note(storage::PaymentNoteNew {
+            note_id: common_utils::generate_id(consts::ID_LENGTH, "note"),
+            merchant_id: merchant_id.clone(),
+            payment_id: payment_intent.payment_id.clone(),
+            author: user_id,
+            note: request.note,
+            created_at: common_utils::date_time::now(),
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert payment note")?;
+    notes.push(get_payment_note(note));
+
+    Ok(services::ApplicationResponse::Json(PaymentNotesResponse {
+        payment_id: payment_intent.payment_id,
+        notes,
+    }))
+}
+
+#[instrument(skip_all)]
+pub async fn list_payment_notes(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    payment_id: id_type::PaymentId,
+) -> RouterResponse<PaymentNotesResponse> {
+    let payment_intent = find_payment_intent(
+        &state,
+        &merchant_account,
+        profile_id,
+        &key_store,
+        &payment_id,
+    )
+    .await?;
+
+    let notes = find_payment_notes(
+        &state,
+        merchant_account.get_id(),
+        &payment_intent.payment_id,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(PaymentNotesResponse {
+        payment_id: payment_intent.payment_id,
+        notes,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_merge_metadata() {
+        let metadata = serde_json::json!({ "order_id": "order_1", "channel": "web" });
+        let update = serde_json::json!({ "order_id": "order_2", "channel": null, "tier": "gold" });
+        let serde_json::Value::Object(update) = update else {
+            panic!("update is not an object");
+        };
+
+        assert_eq!(
+            merge_metadata(Some(metadata), update.clone()),
+            serde_json::json!({ "order_id": "order_2", "tier": "gold" })
+        );
+        assert_eq!(
+            merge_metadata(None, update),
+            serde_json::json!({ "order_id": "order_2", "tier": "gold" })
+        );
+    }
+
+    #[test]
+    fn test_validate_payment_note() {
+        let payment_id = id_type::PaymentId::try_from(std::borrow::Cow::from("pay_1")).unwrap();
+        let request = |note: String| PaymentNoteRequest {
+            payment_id: payment_id.clone(),
+            note,
+        };
+
+        assert!(validate_payment_note(&request("Customer confirmed".to_string())).is_ok());
+        assert!(validate_payment_note(&request(" ".to_string())).is_err());
+        assert!(validate_payment_note(&request("a".repeat(MAX_NOTE_LENGTH + 1))).is_err());
+    }
+}
@@ -3,6 +3,8 @@ pub mod payment_approve;
 #[cfg(feature = "v1")]
 pub mod payment_cancel;
 #[cfg(feature = "v1")]
+pub mod payment_cancel_remaining;
+#[cfg(feature = "v1")]
 pub mod payment_capture;
 #[cfg(feature = "v1")]
 pub mod payment_complete_authorize;
@@ -67,10 +69,11 @@ pub use self::payment_update_intent::PaymentUpdateIntent;
 #[cfg(feature = "v1")]
 pub use self::{
     payment_approve::PaymentApprove, payment_cancel::PaymentCancel,
-    payment_capture::PaymentCapture, payment_confirm::PaymentConfirm,
-    payment_create::PaymentCreate, payment_post_session_tokens::PaymentPostSessionTokens,
-    payment_reject::PaymentReject, payment_session::PaymentSession, payment_start::PaymentStart,
-    payment_status::PaymentStatus, payment_update::PaymentUpdate,
+    payment_cancel_remaining::PaymentCancelRemaining, payment_capture::PaymentCapture,
+    payment_confirm::PaymentConfirm, payment_create::PaymentCreate,
+    payment_post_session_tokens::PaymentPostSessionTokens, payment_reject::PaymentReject,
+    payment_session::PaymentSession, payment_start::PaymentStart, payment_status::PaymentStatus,
+    payment_update::PaymentUpdate,
     payments_incremental_authorization::PaymentIncrementalAuthorization,
     tax_calculation::PaymentSessionUpdate,
 };
@@ -80,6 +83,8 @@ pub use self::{
     payment_session_intent::PaymentSessionIntent,
 };
 use super::{helpers, CustomerDetails, OperationSessionGetters, OperationSessionSetters};
+#[cfg(feature = "v1")]
+use crate::core::data_residency;
 use crate::{
     core::errors::{self, CustomResult, RouterResult},
     routes::{app::ReqState, SessionState},
@@ -309,6 +314,7 @@ pub trait Domain<F: Clone, R, D>: Send + Sync {
         _state: &SessionState,
         _payment_data: &mut D,
         _merchant_account: &domain::MerchantAccount,
+        _key_store: &domain::MerchantKeyStore,
         _business_profile: &domain::Profile,
         _connector_data: &api::ConnectorData,
     ) -> CustomResult<(), errors::ApiErrorResponse> {
@@ -610,7 +616,10 @@ where
         ),
         errors::StorageError,
     > {
-        let db = &*state.store;
+        let db = data_residency::get_pii_store(state, &merchant_key_store.merchant_id)
+            .await
+            .change_context(errors::StorageError::DatabaseConnectionError)
+            .attach_printable("Failed to get the store holding the customers of the merchant")?;
 
         let customer = match payment_data.get_payment_intent().customer_id.as_ref() {
             None => None,
@@ -716,7 +725,10 @@ where
         ),
         errors::StorageError,
     > {
-        let db = &*state.store;
+        let db = data_residency::get_pii_store(state, &merchant_key_store.merchant_id)
+            .await
+            .change_context(errors::StorageError::DatabaseConnectionError)
+            .attach_printable("Failed to get the store holding the customers of the merchant")?;
 
         let customer = match payment_data.get_payment_intent().customer_id.as_ref() {
             None => None,
@@ -203,6 +203,15 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsCaptureR
             card_testing_guard_data: None,
             vault_operation: None,
             threeds_method_comp_ind: None,
+            external_authentication_data: None,
+            payment_facilitator_details: None,
+            custom_status_mapping: None,
+            localized_unified_message: None,
+            connector_response_details: None,
+            receipt_url: None,
+            address_validation: None,
+            scheduled_capture_at: None,
+            custom_fields: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -3,7 +3,7 @@ use std::marker::PhantomData;
 use api_models::enums::FrmSuggestion;
 use async_trait::async_trait;
 use common_utils::ext_traits::AsyncExt;
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
 use router_derive;
 use router_env::{instrument, tracing};
 
@@ -11,7 +11,7 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
-        payments::{helpers, operations, PaymentData},
+        payments::{helpers, operations, partial_payments, PaymentData},
         utils::ValidatePlatformMerchant,
     },
     events::audit_events::{AuditEvent, AuditEventType},
@@ -86,6 +86,13 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsCancelRe
             "cancel",
         )?;
 
+        // The attempts which have paid the parts of a partially paid payment are refunded instead
+        if partial_payments::is_partially_paid(&payment_intent) {
+            return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "A partially paid payment cannot be cancelled, the attempts which have paid its parts have to be refunded instead".to_string(),
+            }));
+        }
+
         let mut payment_attempt = db
             .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
                 &payment_intent.payment_id,
@@ -214,6 +221,15 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsCancelRe
             card_testing_guard_data: None,
             vault_operation: None,
             threeds_method_comp_ind: None,
+            external_authentication_data: None,
+            payment_facilitator_details: None,
+            custom_status_mapping: None,
+            localized_unified_message: None,
+            connector_response_details: None,
+            receipt_url: None,
+            address_validation: None,
+            scheduled_capture_at: None,
+            custom_fields: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -100,13 +100,26 @@ impl<F: Send + Clone + Sync> GetTracker<F, payments::PaymentData<F>, api::Paymen
 
         helpers::validate_status_with_capture_method(payment_intent.status, capture_method)?;
 
+        // The captures of the multiple capture payments are bounded by the amount capturable
+        let overcapture_allowance = if capture_method == enums::CaptureMethod::ManualMultiple {
+            None
+        } else {
+            helpers::get_overcapture_allowance(state, &payment_intent, &payment_attempt).await?
+        };
+
         helpers::validate_amount_to_capture(
-            payment_attempt.amount_capturable.get_amount_as_i64(),
+            (payment_attempt.amount_capturable + overcapture_allowance.unwrap_or_default())
+                .get_amount_as_i64(),
             request
                 .amount_to_capture
                 .map(|capture_amount| capture_amount.get_amount_as_i64()),
         )?;
 
+        payment_attempt.overcaptured_amount = request
+            .amount_to_capture
+            .filter(|amount_to_capture| *amount_to_capture > payment_attempt.amount_capturable)
+            .map(|amount_to_capture| amount_to_capture - payment_attempt.amount_capturable);
+
         helpers::validate_capture_method(capture_method)?;
 
         let multiple_capture_data = if capture_method == enums::CaptureMethod::ManualMultiple {
@@ -263,6 +276,15 @@ impl<F: Send + Clone + Sync> GetTracker<F, payments::PaymentData<F>, api::Paymen
             card_testing_guard_data: None,
             vault_operation: None,
             threeds_method_comp_ind: None,
+            external_authentication_data: None,
+            payment_facilitator_details: None,
+            custom_status_mapping: None,
+            localized_unified_message: None,
+            connector_response_details: None,
+            receipt_url: None,
+            address_validation: None,
+            scheduled_capture_at: None,
+            custom_fields: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -306,12 +328,14 @@ impl<F: Clone + Sync> UpdateTracker<F, payments::PaymentData<F>, api::PaymentsCa
                 .map(|multiple_capture_data| multiple_capture_data.get_captures_count())
                 .transpose()?;
             let amount_to_capture = payment_data.payment_attempt.amount_to_capture;
+            let overcaptured_amount = payment_data.payment_attempt.overcaptured_amount;
             db.store
                 .update_payment_attempt_with_attempt_id(
                     payment_data.payment_attempt,
                     storage::PaymentAttemptUpdate::CaptureUpdate {
                         amount_to_capture,
                         multiple_capture_count,
+                        overcaptured_amount,
                         updated_by: storage_scheme.to_string(),
                     },
                     storage_scheme,
@@ -222,6 +222,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             key_store,
             &payment_id,
             storage_scheme,
+            None,
         )
         .await?;
 
@@ -365,6 +366,15 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             card_testing_guard_data: None,
             vault_operation: None,
             threeds_method_comp_ind: request.threeds_method_comp_ind.clone(),
+            external_authentication_data: None,
+            payment_facilitator_details: None,
+            custom_status_mapping: None,
+            localized_unified_message: None,
+            connector_response_details: None,
+            receipt_url: None,
+            address_validation: None,
+            scheduled_capture_at: None,
+            custom_fields: None,
         };
 
         let customer_details = Some(CustomerDetails {
@@ -24,6 +24,8 @@ use tracing_futures::Instrument;
 
 use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+use crate::core::data_residency;
 use crate::{
     consts,
     core::payment_methods::cards::create_encrypted_data,
@@ -31,15 +33,18 @@ use crate::{
 };
 use crate::{
     core::{
-        authentication,
+        address_validation, authentication,
         blocklist::utils as blocklist_utils,
         card_testing_guard::utils as card_testing_guard_utils,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate::helpers as m_helpers,
+        merchant_lifecycle,
+        payment_methods::cvv_recollection,
         payments::{
-            self, helpers, operations, populate_surcharge_details, CustomerDetails, PaymentAddress,
-            PaymentData,
+            self, contact_search, helpers, operations, populate_surcharge_details, CustomerDetails,
+            PaymentAddress, PaymentData,
         },
+        sanctions_screening,
         unified_authentication_service::{
             self as uas_utils,
             types::{ClickToPay, UnifiedAuthenticationService},
@@ -208,6 +213,22 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             .in_current_span(),
         );
 
+        let (shipping_address_validation, billing_address_validation) =
+            address_validation::validate_payment_addresses(
+                state,
+                merchant_id,
+                payment_intent.profile_id.as_ref(),
+                request
+                    .shipping
+                    .as_ref()
+                    .and_then(|shipping| shipping.address.as_ref()),
+                request
+                    .billing
+                    .as_ref()
+                    .and_then(|billing| billing.address.as_ref()),
+            )
+            .await;
+
         let m_merchant_id = merchant_id.clone();
         let m_request_shipping = request.shipping.clone();
         let m_payment_intent_shipping_address_id = payment_intent.shipping_address_id.clone();
@@ -230,6 +251,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
                     &m_key_store,
                     &m_payment_intent_payment_id,
                     storage_scheme,
+                    shipping_address_validation.as_ref(),
                 )
                 .await
             }
@@ -258,6 +280,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
                     &m_key_store,
                     &m_payment_intent_payment_id,
                     storage_scheme,
+                    billing_address_validation.as_ref(),
                 )
                 .await
             }
@@ -294,14 +317,37 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
                 | api_models::enums::IntentStatus::RequiresConfirmation => {
                     // Normal payment
                     // Parallel calls - level 1
-                    let (payment_attempt, shipping_address, billing_address, business_profile, _) =
-                        tokio::try_join!(
-                            utils::flatten_join_error(payment_attempt_fut),
-                            utils::flatten_join_error(shipping_address_fut),
-                            utils::flatten_join_error(billing_address_fut),
-                            utils::flatten_join_error(business_profile_fut),
-                            utils::flatten_join_error(config_update_fut)
-                        )?;
+                    let (
+                        mut payment_attempt,
+                        shipping_address,
+                        billing_address,
+                        business_profile,
+                        _,
+                    ) = tokio::try_join!(
+                        utils::flatten_join_error(payment_attempt_fut),
+                        utils::flatten_join_error(shipping_address_fut),
+                        utils::flatten_join_error(billing_address_fut),
+                        utils::flatten_join_error(business_profile_fut),
+                        utils::flatten_join_error(config_update_fut)
+                    )?;
+
+                    // A payment with partial payments enabled is paid further by a new attempt,
+                    // once its active attempt has paid its part or failed
+                    if payments::partial_payments::is_new_attempt_required(
+                        &payment_intent,
+                        &payment_attempt,
+                    ) {
+                        (payment_intent, payment_attempt) = helpers::AttemptType::New
+                            .modify_payment_intent_and_payment_attempt(
+                                request,
+                                payment_intent,
+                                payment_attempt,
+                                state,
+                                key_store,
+                                storage_scheme,
+                            )
+                            .await?;
+                    }
 
                     (
                         payment_attempt,
@@ -417,6 +463,22 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             .map(masking::Secret::new)
             .or(payment_attempt.customer_acceptance);
 
+        if payments::partial_payments::is_partial_payments_enabled(&payment_intent) {
+            let attempt_amount = payments::partial_payments::get_attempt_amount(
+                &*state.store,
+                &payment_intent,
+                &payment_attempt,
+                request.partial_amount,
+                storage_scheme,
+            )
+            .await?;
+            payment_attempt.net_amount.set_order_amount(attempt_amount);
+        } else if request.partial_amount.is_some() {
+            return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "partial_amount can only be passed for the payments with partial payments enabled".to_string(),
+            }));
+        }
+
         currency = payment_attempt.currency.get_required_value("currency")?;
         amount = payment_attempt.get_total_amount().into();
 
@@ -530,6 +592,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
                     &m_key_store,
                     &m_payment_intent_payment_id,
                     storage_scheme,
+                    None,
                 )
                 .await
             }
@@ -625,6 +688,21 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
         } else {
             (None, payment_method_info)
         };
+
+        let card_cvc = match (request.card_cvc.clone(), request.cvv_token.as_deref()) {
+            (Some(card_cvc), _) => Some(card_cvc),
+            (None, Some(cvv_token)) => Some(
+                cvv_recollection::get_cvv_from_token(
+                    state,
+                    key_store,
+                    &payment_intent.payment_id,
+                    token.as_deref(),
+                    cvv_token,
+                )
+                .await?,
+            ),
+            (None, None) => None,
+        };
         let additional_pm_data_from_locker = if let Some(ref pm) = payment_method_info {
             let card_detail_from_locker: Option<api::CardDetailFromLocker> = pm
                 .payment_method_data
@@ -728,6 +806,15 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
         let unified_address =
             address.unify_with_payment_method_data_billing(payment_method_data_billing);
 
+        // The contact details of the customer may be updated, so that the payment is found by the
+        // new ones
+        let (customer_email_hash, customer_phone_hash) =
+            contact_search::get_customer_contact_hashes(key_store, request)?;
+        payment_intent.customer_email_hash =
+            customer_email_hash.or(payment_intent.customer_email_hash.take());
+        payment_intent.customer_phone_hash =
+            customer_phone_hash.or(payment_intent.customer_phone_hash.take());
+
         // If processor_payment_token is passed in request then populating the same in PaymentData
         let mandate_id = request
             .recurring_details
@@ -785,6 +872,14 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             )),
         );
 
+        let payment_facilitator_details =
+            payments::payment_facilitator::get_payment_facilitator_details(
+                store,
+                business_profile.get_id(),
+                request.payment_facilitator_details.clone(),
+            )
+            .await?;
+
         let payment_data = PaymentData {
             flow: PhantomData,
             payment_intent,
@@ -807,7 +902,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             disputes: vec![],
             attempts: None,
             sessions_token: vec![],
-            card_cvc: request.card_cvc.clone(),
+            card_cvc,
             creds_identifier,
             pm_token: None,
             connector_customer_id: None,
@@ -829,6 +924,21 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             card_testing_guard_data: None,
             vault_operation: None,
             threeds_method_comp_ind: None,
+            external_authentication_data: request
+                .three_ds_data
+                .clone()
+                .map(payments::types::AuthenticationData::foreign_from),
+            payment_facilitator_details,
+            custom_status_mapping: None,
+            localized_unified_message: None,
+            connector_response_details: None,
+            receipt_url: None,
+            address_validation: address_validation::get_payment_address_validation(
+                shipping_address.as_ref(),
+                billing_address.as_ref(),
+            ),
+            scheduled_capture_at: None,
+            custom_fields: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -856,6 +966,8 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
                 request_payment_method_data.payment_method_data.as_ref()
             });
 
+        sanctions_screening::screen_payment(state, request, payment_data).await?;
+
         let customer_id = &payment_data.payment_intent.customer_id;
 
         match payment_method_data {
@@ -984,11 +1096,32 @@ impl<F: Clone + Send + Sync> Domain<F, api::PaymentsRequest, PaymentData<F>> for
         &'a self,
         state: &SessionState,
         payment_data: &mut PaymentData<F>,
-        _merchant_account: &domain::MerchantAccount,
+        merchant_account: &domain::MerchantAccount,
+        key_store: &domain::MerchantKeyStore,
         business_profile: &domain::Profile,
         connector_data: &api::ConnectorData,
     ) -> CustomResult<(), errors::ApiErrorResponse> {
-        populate_surcharge_details(state, payment_data).await?;
+        // The balance of a gift card is checked with the connector the payment is routed to
+        if payments::partial_payments::is_partial_payments_enabled(&payment_data.payment_intent) {
+            let attempt_amount = payments::gift_card::get_attempt_amount(
+                state,
+                key_store,
+                &payment_data.payment_intent,
+                payment_data.payment_method_data.as_ref(),
+                payment_data.payment_attempt.net_amount.get_order_amount(),
+                connector_data
+                    .merchant_connector_id
+                    .as_ref()
+                    .or(payment_data.payment_attempt.merchant_connector_id.as_ref()),
+            )
+            .await?;
+            payment_data
+                .payment_attempt
+                .net_amount
+                .set_order_amount(attempt_amount);
+            payment_data.amount = payment_data.payment_attempt.get_total_amount().into();
+        }
+        populate_surcharge_details(state, merchant_account, payment_data).await?;
         payment_data.payment_attempt.request_extended_authorization = payment_data
             .payment_intent
             .get_request_extended_authorization_bool_if_connector_supports(
@@ -1147,7 +1280,7 @@ impl<F: Clone + Send + Sync> Domain<F, api::PaymentsRequest, PaymentData<F>> for
                             },
                         )?;
                         let authentication_id =
-                            common_utils::generate_id_with_default_len(consts::AUTHENTICATION_ID_PREFIX);
+                            common_utils::generate_resource_id_with_default_len(consts::AUTHENTICATION_ID_PREFIX);
                         let payment_method = payment_data.payment_attempt.payment_method.ok_or(
                             errors::ApiErrorResponse::MissingRequiredField {
                                 field_name: "payment_method",
@@ -1284,42 +1417,59 @@ impl<F: Clone + Send + Sync> Domain<F, api::PaymentsRequest, PaymentData<F>> for
                     token,
                     ..
                 } => {
-                    let (authentication_connector, three_ds_connector_account) =
-                    authentication::utils::get_authentication_connector_data(state, key_store, business_profile).await?;
-                let authentication_connector_name = authentication_connector.to_string();
-                let authentication = authentication::utils::create_new_authentication(
-                    state,
-                    business_profile.merchant_id.clone(),
-                    authentication_connector_name.clone(),
-                    token,
-                    business_profile.get_id().to_owned(),
-                    payment_data.payment_intent.payment_id.clone(),
-                    three_ds_connector_account
-                        .get_mca_id()
-                        .ok_or(errors::ApiErrorResponse::InternalServerError)
-                        .attach_printable("Error while finding mca_id from merchant_connector_account")?,
-                    payment_data.payment_attempt.organization_id.clone(),
-                )
-                .await?;
-
-                let pre_auth_response = uas_utils::types::ExternalAuthentication::pre_authentication(
-                    state,
-                    key_store,
-                    business_profile,
-                    payment_data,
-                    &three_ds_connector_account,
-                    &authentication_connector_name,
-                    &authentication.authentication_id,
-                    payment_data.payment_attempt.payment_method.ok_or(
+                    let payment_method = payment_data.payment_attempt.payment_method.ok_or(
                         errors::ApiErrorResponse::InternalServerError
-                    ).attach_printable("payment_method not found in payment_attempt")?,
-                ).await?;
-                let updated_authentication = uas_utils::utils::external_authentication_update_trackers(
-                    state,
-                    pre_auth_response,
-                    authentication.clone(),
-                    acquirer_details,
-                ).await?;
+                    ).attach_printable("payment_method not found in payment_attempt")?;
+                    let payment_data_ref = &*payment_data;
+                    let updated_authentication = authentication::utils::perform_pre_authentication_with_fallback(
+                        business_profile,
+                        |authentication_connector| {
+                            let token = token.clone();
+                            let acquirer_details = acquirer_details.clone();
+                            Box::pin(async move {
+                                let three_ds_connector_account = authentication::utils::get_authentication_connector_account(
+                                    state,
+                                    key_store,
+                                    business_profile,
+                                    authentication_connector,
+                                    None,
+                                )
+                                .await?;
+                                let authentication_connector_name = authentication_connector.to_string();
+                                let authentication = authentication::utils::create_new_authentication(
+                                    state,
+                                    business_profile.merchant_id.clone(),
+                                    authentication_connector_name.clone(),
+                                    token,
+                                    business_profile.get_id().to_owned(),
+                                    payment_data_ref.payment_intent.payment_id.clone(),
+                                    three_ds_connector_account
+                                        .get_mca_id()
+                                        .ok_or(errors::ApiErrorResponse::InternalServerError)
+                                        .attach_printable("Error while finding mca_id from merchant_connector_account")?,
+                                    payment_data_ref.payment_attempt.organization_id.clone(),
+                                )
+                                .await?;
+
+                                let pre_auth_response = uas_utils::types::ExternalAuthentication::pre_authentication(
+                                    state,
+                                    key_store,
+                                    business_profile,
+                                    payment_data_ref,
+                                    &three_ds_connector_account,
+                                    &authentication_connector_name,
+                                    &authentication.authentication_id,
+                                    payment_method,
+                                ).await?;
+                                uas_utils::utils::external_authentication_update_trackers(
+                                    state,
+                                    pre_auth_response,
+                                    authentication,
+                                    acquirer_details,
+                                ).await
+                            })
+                        },
+                    ).await?;
                 payment_data.authentication = Some(updated_authentication.clone());
 
                 if updated_authentication.is_separate_authn_required()
@@ -1351,14 +1501,6 @@ impl<F: Clone + Send + Sync> Domain<F, api::PaymentsRequest, PaymentData<F>> for
                 }
                 },
                 helpers::UnifiedAuthenticationServiceFlow::ExternalAuthenticationPostAuthenticate {authentication_id} => {
-                    let (authentication_connector, three_ds_connector_account) =
-                    authentication::utils::get_authentication_connector_data(state, key_store, business_profile).await?;
-                let is_pull_mechanism_enabled =
-                    utils::check_if_pull_mechanism_for_external_3ds_enabled_from_connector_metadata(
-                        three_ds_connector_account
-                            .get_metadata()
-                            .map(|metadata| metadata.expose()),
-                    );
                 let authentication = state
                     .store
                     .find_authentication_by_merchant_id_authentication_id(
@@ -1368,6 +1510,14 @@ impl<F: Clone + Send + Sync> Domain<F, api::PaymentsRequest, PaymentData<F>> for
                     .await
                     .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
                     .attach_printable_lazy(|| format!("Error while fetching authentication record with authentication_id {authentication_id}"))?;
+                    let (authentication_connector, three_ds_connector_account) =
+                    authentication::utils::get_authentication_connector_data_for_authentication(state, key_store, business_profile, &authentication).await?;
+                let is_pull_mechanism_enabled =
+                    utils::check_if_pull_mechanism_for_external_3ds_enabled_from_connector_metadata(
+                        three_ds_connector_account
+                            .get_metadata()
+                            .map(|metadata| metadata.expose()),
+                    );
                 let updated_authentication = if !authentication.authentication_status.is_terminal_status() && is_pull_mechanism_enabled {
                     let post_auth_response = uas_utils::types::ExternalAuthentication::post_authentication(
                         state,
@@ -1714,10 +1864,29 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
                 Some(authentication.authentication_connector.clone()),
                 Some(authentication.authentication_id.clone()),
             ),
-            None => (None, None, None),
+            // The authentication was performed by the merchant outside of Hyperswitch
+            None => (
+                payment_data
+                    .external_authentication_data
+                    .as_ref()
+                    .map(|_| true),
+                None,
+                None,
+            ),
         };
 
         let card_discovery = payment_data.get_card_discovery_for_card_payment_method();
+        let card_credential_type = match payment_data.payment_method_data.as_ref() {
+            Some(domain::PaymentMethodData::NetworkToken(_)) => {
+                Some(storage_enums::CardCredentialType::NetworkToken)
+            }
+            Some(domain::PaymentMethodData::Card(_))
+                if card_discovery == Some(common_enums::CardDiscovery::SavedCard) =>
+            {
+                Some(storage_enums::CardCredentialType::Pan)
+            }
+            _ => None,
+        };
 
         let payment_attempt_fut = tokio::spawn(
             async move {
@@ -1767,6 +1936,7 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
                             .payment_attempt
                             .connector_mandate_detail,
                         card_discovery,
+                        card_credential_type,
                     },
                     storage_scheme,
                 )
@@ -1816,6 +1986,8 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
         let key_manager_state = state.into();
         let is_payment_processor_token_flow =
             payment_data.payment_intent.is_payment_processor_token_flow;
+        let customer_email_hash = payment_data.payment_intent.customer_email_hash.clone();
+        let customer_phone_hash = payment_data.payment_intent.customer_phone_hash.clone();
 
         let payment_intent_fut = tokio::spawn(
             async move {
@@ -1850,6 +2022,8 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
                         shipping_details,
                         is_payment_processor_token_flow,
                         tax_details: None,
+                        customer_email_hash,
+                        customer_phone_hash,
                     })),
                     &m_key_store,
                     storage_scheme,
@@ -1866,10 +2040,12 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
                 let m_key_store = key_store.clone();
                 let m_updated_customer = updated_customer.clone();
                 let session_state = state.clone();
-                let m_db = session_state.store.clone();
                 let key_manager_state = state.into();
                 tokio::spawn(
                     async move {
+                        let m_db =
+                            data_residency::get_pii_store(&session_state, &m_customer_merchant_id)
+                                .await?;
                         let m_customer_customer_id = customer.customer_id.to_owned();
                         m_db.update_customer_by_customer_id_merchant_id(
                             &key_manager_state,
@@ -1931,6 +2107,7 @@ impl<F: Send + Clone + Sync> ValidateRequest<F, api::PaymentsRequest, PaymentDat
         request: &api::PaymentsRequest,
         merchant_account: &'a domain::MerchantAccount,
     ) -> RouterResult<(PaymentConfirmOperation<'b, F>, operations::ValidateResult)> {
+        merchant_lifecycle::validate_merchant_can_charge(merchant_account)?;
         helpers::validate_customer_information(request)?;
 
         if let Some(amount) = request.amount {
@@ -1955,6 +2132,14 @@ impl<F: Send + Clone + Sync> ValidateRequest<F, api::PaymentsRequest, PaymentDat
             &request.mandate_id,
         )?;
 
+        helpers::validate_external_three_ds_data(request)?;
+
+        request
+            .payment_facilitator_details
+            .as_ref()
+            .map(payments::payment_facilitator::validate_payment_facilitator_details)
+            .transpose()?;
+
         let payment_id = request
             .payment_id
             .clone()
@@ -34,11 +34,14 @@ use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, Valida
 use crate::{
     consts,
     core::{
+        address_validation, customers,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate::helpers as m_helpers,
-        payment_link,
+        merchant_lifecycle, payment_link,
         payment_methods::cards::create_encrypted_data,
-        payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
+        payments::{
+            self, contact_search, helpers, operations, CustomerDetails, PaymentAddress, PaymentData,
+        },
         utils as core_utils,
     },
     db::StorageInterface,
@@ -89,6 +92,14 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
 
         let money @ (amount, currency) = payments_create_request_validation(request)?;
 
+        if request.split_payments.is_some() {
+            helpers::validate_platform_request_for_marketplace(
+                amount,
+                request.split_payments.clone(),
+                state.conf.rounding_policy.get_rounding_mode(Some(currency)),
+            )?;
+        };
+
         let payment_id = payment_id
             .get_payment_intent_id()
             .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
@@ -192,6 +203,22 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
 
         let customer_details = helpers::get_customer_details_from_request(request);
 
+        let (shipping_address_validation, billing_address_validation) =
+            address_validation::validate_payment_addresses(
+                state,
+                merchant_id,
+                Some(&profile_id),
+                request
+                    .shipping
+                    .as_ref()
+                    .and_then(|shipping| shipping.address.as_ref()),
+                request
+                    .billing
+                    .as_ref()
+                    .and_then(|billing| billing.address.as_ref()),
+            )
+            .await;
+
         let shipping_address = helpers::create_or_find_address_for_payment_by_request(
             state,
             request.shipping.as_ref(),
@@ -201,6 +228,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             merchant_key_store,
             &payment_id,
             merchant_account.storage_scheme,
+            shipping_address_validation.as_ref(),
         )
         .await?;
 
@@ -213,6 +241,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             merchant_key_store,
             &payment_id,
             merchant_account.storage_scheme,
+            billing_address_validation.as_ref(),
         )
         .await?;
 
@@ -229,6 +258,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
                 merchant_key_store,
                 &payment_id,
                 merchant_account.storage_scheme,
+                None,
             )
             .await?;
 
@@ -315,6 +345,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             profile_id.clone(),
             session_expiry,
             platform_merchant_account,
+            business_profile.is_test_mode,
         )
         .await?;
 
@@ -339,6 +370,15 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
         )
         .await?;
 
+        let payment_custom_fields = payments::custom_fields::get_payment_custom_fields_new(
+            db,
+            merchant_id,
+            &payment_id,
+            business_profile.get_id(),
+            request.custom_fields.as_ref(),
+        )
+        .await?;
+
         let payment_intent = db
             .insert_payment_intent(
                 key_manager_state,
@@ -380,6 +420,8 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
                 payment_id: payment_id.clone(),
             })?;
 
+        payments::custom_fields::insert_payment_custom_fields(db, payment_custom_fields).await?;
+
         let mandate_details_present = payment_attempt.mandate_details.is_some();
 
         helpers::validate_mandate_data_and_future_usage(
@@ -585,6 +627,17 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
         let unified_address =
             address.unify_with_payment_method_data_billing(payment_method_data_billing);
 
+        let payment_facilitator_details = if request.confirm == Some(true) {
+            payments::payment_facilitator::get_payment_facilitator_details(
+                db,
+                business_profile.get_id(),
+                request.payment_facilitator_details.clone(),
+            )
+            .await?
+        } else {
+            None
+        };
+
         let payment_data = PaymentData {
             flow: PhantomData,
             payment_intent,
@@ -629,6 +682,21 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             card_testing_guard_data: None,
             vault_operation: None,
             threeds_method_comp_ind: None,
+            external_authentication_data: request
+                .three_ds_data
+                .clone()
+                .map(payments::types::AuthenticationData::foreign_from),
+            payment_facilitator_details,
+            custom_status_mapping: None,
+            localized_unified_message: None,
+            connector_response_details: None,
+            receipt_url: None,
+            address_validation: address_validation::get_payment_address_validation(
+                shipping_address.as_ref(),
+                billing_address.as_ref(),
+            ),
+            scheduled_capture_at: None,
+            custom_fields: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -901,6 +969,7 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
                     tax_amount,
                     updated_by: storage_scheme.to_string(),
                     merchant_connector_id,
+                    capture_method: payment_data.payment_attempt.capture_method,
                 },
                 storage_scheme,
             )
@@ -967,6 +1036,7 @@ impl<F: Send + Clone + Sync> ValidateRequest<F, api::PaymentsRequest, PaymentDat
         request: &api::PaymentsRequest,
         merchant_account: &'a domain::MerchantAccount,
     ) -> RouterResult<(PaymentCreateOperation<'b, F>, operations::ValidateResult)> {
+        merchant_lifecycle::validate_merchant_can_charge(merchant_account)?;
         helpers::validate_customer_information(request)?;
 
         if let Some(amount) = request.amount {
@@ -1019,6 +1089,17 @@ impl<F: Send + Clone + Sync> ValidateRequest<F, api::PaymentsRequest, PaymentDat
             &request.mandate_id,
         )?;
 
+        helpers::validate_external_three_ds_data(request)?;
+        helpers::validate_estimated_amount(request)?;
+        payments::partial_payments::validate_partial_payments_request(request)?;
+        customers::store_credit::validate_store_credit_request(request)?;
+
+        request
+            .payment_facilitator_details
+            .as_ref()
+            .map(payments::payment_facilitator::validate_payment_facilitator_details)
+            .transpose()?;
+
         if request.confirm.unwrap_or(false) {
             helpers::validate_pm_or_token_given(
                 &request.payment_method,
@@ -1042,14 +1123,6 @@ impl<F: Send + Clone + Sync> ValidateRequest<F, api::PaymentsRequest, PaymentDat
             )?;
         }
 
-        if request.split_payments.is_some() {
-            let amount = request.amount.get_required_value("amount")?;
-            helpers::validate_platform_request_for_marketplace(
-                amount,
-                request.split_payments.clone(),
-            )?;
-        };
-
         let _request_straight_through: Option<api::routing::StraightThroughAlgorithm> = request
             .routing
             .clone()
@@ -1279,6 +1352,7 @@ impl PaymentCreate {
                         key_store,
                         payment_id,
                         storage_scheme,
+                        None,
                     )
                     .await
                 })
@@ -1317,12 +1391,17 @@ impl PaymentCreate {
                     .mandate_data
                     .as_ref()
                     .and_then(|inner| inner.mandate_type.clone().map(Into::into)),
-                external_three_ds_authentication_attempted: None,
+                external_three_ds_authentication_attempted: (request.confirm == Some(true)
+                    && request.three_ds_data.is_some())
+                .then_some(true),
                 mandate_data,
                 payment_method_billing_address_id,
                 net_amount: hyperswitch_domain_models::payments::payment_attempt::NetAmount::from_payments_request(
                     request,
-                    MinorUnit::from(amount),
+                    customers::store_credit::get_amount_to_charge(
+                        request.partial_amount.unwrap_or(MinorUnit::from(amount)),
+                        request.store_credit_amount,
+                    ),
                 ),
                 save_to_locker: None,
                 connector: None,
@@ -1365,6 +1444,7 @@ impl PaymentCreate {
                 extended_authorization_applied: None,
                 capture_before: None,
                 card_discovery: None,
+                connector_idempotency_key: Some(helpers::generate_connector_idempotency_key()),
             },
             additional_pm_data,
 
@@ -1387,6 +1467,7 @@ impl PaymentCreate {
         profile_id: common_utils::id_type::ProfileId,
         session_expiry: PrimitiveDateTime,
         platform_merchant_account: Option<&domain::MerchantAccount>,
+        is_test_payment: bool,
     ) -> RouterResult<storage::PaymentIntent> {
         let created_at @ modified_at @ last_synced = common_utils::date_time::now();
 
@@ -1424,9 +1505,13 @@ impl PaymentCreate {
 
         let payment_link_id = payment_link_data.map(|pl_data| pl_data.payment_link_id);
 
+        // The authorization of a payment with an estimated amount is incremented when its final
+        // amount exceeds the tolerance of the card network, unless requested otherwise
         let request_incremental_authorization =
             core_utils::get_request_incremental_authorization_value(
-                request.request_incremental_authorization,
+                request.request_incremental_authorization.or(request
+                    .is_amount_estimated
+                    .filter(|is_amount_estimated| *is_amount_estimated)),
                 request.capture_method,
             )?;
 
@@ -1505,6 +1590,9 @@ impl PaymentCreate {
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Unable to encrypt the payment intent data")?;
 
+        let (customer_email_hash, customer_phone_hash) =
+            contact_search::get_customer_contact_hashes(key_store, request)?;
+
         let skip_external_tax_calculation = request.skip_external_tax_calculation;
 
         let tax_details = request
@@ -1576,6 +1664,13 @@ impl PaymentCreate {
             psd2_sca_exemption_type: request.psd2_sca_exemption_type,
             platform_merchant_id: platform_merchant_account
                 .map(|platform_merchant_account| platform_merchant_account.get_id().to_owned()),
+            customer_email_hash,
+            customer_phone_hash,
+            change_sequence: None,
+            is_amount_estimated: request.is_amount_estimated,
+            enable_partial_payments: request.enable_partial_payments,
+            store_credit_amount: request.store_credit_amount,
+            is_test_payment: Some(is_test_payment),
         })
     }
 
@@ -1629,7 +1724,7 @@ async fn create_payment_link(
     locale: Option<String>,
 ) -> RouterResult<Option<api_models::payments::PaymentLinkResponse>> {
     let created_at @ last_modified_at = Some(common_utils::date_time::now());
-    let payment_link_id = utils::generate_id(consts::ID_LENGTH, "plink");
+    let payment_link_id = common_utils::generate_resource_id(consts::ID_LENGTH, "plink");
     let locale_str = locale.unwrap_or("en".to_owned());
     let open_payment_link = format!(
         "{}/payment_link/{}/{}?locale={}",
@@ -24,8 +24,18 @@ use storage_impl::DataModelExt;
 use tracing_futures::Instrument;
 
 use super::{Operation, OperationSessionSetters, PostUpdateTracker};
+#[cfg(feature = "v1")]
+use crate::core::authentication::issuer_stats;
+#[cfg(feature = "v1")]
+use crate::core::payments::{acquirer_reference_numbers, partial_payments};
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
 use crate::core::routing::helpers as routing_helpers;
+#[cfg(feature = "v1")]
+use crate::core::routing::{
+    experiments as routing_experiments, volume_caps as routing_volume_caps,
+};
+#[cfg(feature = "v1")]
+use crate::core::webhooks;
 #[cfg(feature = "v2")]
 use crate::utils::OptionExt;
 use crate::{
@@ -733,6 +743,7 @@ impl<F: Clone> PostUpdateTracker<F, PaymentData<F>, types::SdkPaymentsSessionUpd
                                 key_store,
                                 &payment_data.payment_intent.payment_id,
                                 storage_scheme,
+                                None,
                             )
                             .await?;
 
@@ -1317,6 +1328,31 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
             .as_mut()
             .map(|info| info.status = status)
     });
+
+    // The outcomes of the external authentications are recorded when the authentication is done
+    if let (
+        Some(domain::PaymentMethodData::Card(card)),
+        None,
+        Ok(types::PaymentsResponseData::TransactionResponse {
+            redirection_data, ..
+        }),
+    ) = (
+        &payment_data.payment_method_data,
+        &payment_data.authentication,
+        &router_data.response,
+    ) {
+        issuer_stats::record_connector_authentication_outcome(
+            state,
+            card,
+            router_data.auth_type,
+            payment_data.payment_attempt.status,
+            router_data.status,
+            redirection_data.is_some(),
+            payment_data.payment_attempt.authentication_data.is_some(),
+        )
+        .await;
+    }
+
     let (capture_update, mut payment_attempt_update) = match router_data.response.clone() {
         Err(err) => {
             let auth_update = if Some(router_data.auth_type)
@@ -1838,6 +1874,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
 
     // Stage 1
 
+    let previous_attempt_status = payment_data.payment_attempt.status;
     let payment_attempt = payment_data.payment_attempt.clone();
 
     let m_db = state.clone().store;
@@ -1902,20 +1939,24 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
         router_data.status,
         &payment_data,
     );
+    let (intent_status, amount_captured) = partial_payments::get_intent_status_and_amount_captured(
+        &*state.store,
+        &payment_data.payment_intent,
+        &payment_data.payment_attempt,
+        amount_captured,
+        storage_scheme,
+    )
+    .await?;
 
     let payment_intent_update = match &router_data.response {
         Err(_) => storage::PaymentIntentUpdate::PGStatusUpdate {
-            status: api_models::enums::IntentStatus::foreign_from(
-                payment_data.payment_attempt.status,
-            ),
+            status: intent_status,
             updated_by: storage_scheme.to_string(),
             // make this false only if initial payment fails, if incremental authorization call fails don't make it false
             incremental_authorization_allowed: Some(false),
         },
         Ok(_) => storage::PaymentIntentUpdate::ResponseUpdate {
-            status: api_models::enums::IntentStatus::foreign_from(
-                payment_data.payment_attempt.status,
-            ),
+            status: intent_status,
             amount_captured,
             updated_by: storage_scheme.to_string(),
             fingerprint_id: payment_data.payment_attempt.fingerprint_id.clone(),
@@ -2062,6 +2103,29 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
 
     payment_data.payment_intent = payment_intent;
     payment_data.payment_attempt = payment_attempt;
+
+    if let Some(acquirer_reference_number) = router_data
+        .connector_response
+        .as_ref()
+        .and_then(|connector_response| connector_response.get_acquirer_reference_number())
+        .filter(|acquirer_reference_number| {
+            payment_data
+                .payment_attempt
+                .acquirer_reference_number
+                .as_ref()
+                != Some(*acquirer_reference_number)
+        })
+    {
+        payment_data.payment_attempt =
+            acquirer_reference_numbers::update_payment_attempt_acquirer_reference_number(
+                state,
+                payment_data.payment_attempt.clone(),
+                acquirer_reference_number.clone(),
+                storage_scheme,
+            )
+            .await?;
+    }
+
     router_data.payment_method_status.and_then(|status| {
         payment_data
             .payment_method_info
@@ -2069,6 +2133,22 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
             .map(|info| info.status = status)
     });
 
+    routing_experiments::record_experiment_outcome(state, &payment_data.payment_attempt).await;
+    routing_volume_caps::record_connector_volume(
+        state,
+        key_store,
+        previous_attempt_status,
+        &payment_data.payment_attempt,
+    )
+    .await;
+    webhooks::apply_buffered_refund_webhooks_on_capture(
+        state,
+        key_store,
+        &payment_data.payment_intent,
+        previous_attempt_status,
+        payment_data.payment_attempt.status,
+    );
+
     if payment_data.payment_attempt.status == enums::AttemptStatus::Failure {
         let _ = card_testing_guard_utils::increment_blocked_count_in_cache(
             state,
@@ -10,10 +10,11 @@ use router_env::{instrument, logger, tracing};
 use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
 use crate::{
     core::{
+        address_validation,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         payments::{
-            helpers, operations, types as payment_types, CustomerDetails, PaymentAddress,
-            PaymentData,
+            consistency, helpers, operations, types as payment_types, CustomerDetails,
+            PaymentAddress, PaymentData,
         },
     },
     events::audit_events::{AuditEvent, AuditEventType},
@@ -272,6 +273,18 @@ async fn get_tracker_for_sync<
 {
     let (payment_intent, mut payment_attempt, currency, amount);
 
+    if let Some(consistency_token) = request.consistency_token.as_deref() {
+        consistency::wait_for_payment_version(
+            state,
+            merchant_account.get_id(),
+            key_store,
+            storage_scheme,
+            payment_id,
+            consistency_token,
+        )
+        .await?;
+    }
+
     (payment_intent, payment_attempt) = get_payment_intent_payment_attempt(
         state,
         payment_id,
@@ -538,6 +551,18 @@ async fn get_tracker_for_sync<
         card_testing_guard_data: None,
         vault_operation: None,
         threeds_method_comp_ind: None,
+        external_authentication_data: None,
+        payment_facilitator_details: None,
+        custom_status_mapping: None,
+        localized_unified_message: None,
+        connector_response_details: None,
+        receipt_url: None,
+        address_validation: address_validation::get_payment_address_validation(
+            shipping_address.as_ref(),
+            billing_address.as_ref(),
+        ),
+        scheduled_capture_at: None,
+        custom_fields: None,
     };
 
     let get_trackers_response = operations::GetTrackerResponse {
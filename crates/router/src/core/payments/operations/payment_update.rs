@@ -19,10 +19,13 @@ use router_env::{instrument, tracing};
 use super::{BoxedOperation, Domain, GetTracker, Operation, UpdateTracker, ValidateRequest};
 use crate::{
     core::{
+        address_validation,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         mandate::helpers as m_helpers,
         payment_methods::cards::create_encrypted_data,
-        payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
+        payments::{
+            self, contact_search, helpers, operations, CustomerDetails, PaymentAddress, PaymentData,
+        },
         utils as core_utils,
     },
     events::audit_events::{AuditEvent, AuditEventType},
@@ -97,6 +100,13 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
 
         helpers::validate_customer_access(&payment_intent, auth_flow, request)?;
 
+        if payment_intent.store_credit_amount.is_some() && request.amount.is_some() {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The amount of a payment with store credit applied cannot be updated"
+                    .to_string(),
+            })?
+        }
+
         helpers::validate_card_data(
             request
                 .payment_method_data
@@ -202,6 +212,22 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             )?;
         }
 
+        let (shipping_address_validation, billing_address_validation) =
+            address_validation::validate_payment_addresses(
+                state,
+                merchant_id,
+                payment_intent.profile_id.as_ref(),
+                request
+                    .shipping
+                    .as_ref()
+                    .and_then(|shipping| shipping.address.as_ref()),
+                request
+                    .billing
+                    .as_ref()
+                    .and_then(|billing| billing.address.as_ref()),
+            )
+            .await;
+
         let shipping_address = helpers::create_or_update_address_for_payment_by_request(
             state,
             request.shipping.as_ref(),
@@ -214,6 +240,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             key_store,
             &payment_intent.payment_id,
             merchant_account.storage_scheme,
+            shipping_address_validation.as_ref(),
         )
         .await?;
         let billing_address = helpers::create_or_update_address_for_payment_by_request(
@@ -228,6 +255,7 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             key_store,
             &payment_intent.payment_id,
             merchant_account.storage_scheme,
+            billing_address_validation.as_ref(),
         )
         .await?;
 
@@ -246,11 +274,21 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             key_store,
             &payment_intent.payment_id,
             merchant_account.storage_scheme,
+            None,
         )
         .await?;
 
         payment_intent.shipping_address_id = shipping_address.clone().map(|x| x.address_id);
         payment_intent.billing_address_id = billing_address.clone().map(|x| x.address_id);
+
+        // The contact details of the customer may be updated, so that the payment is found by the
+        // new ones
+        let (customer_email_hash, customer_phone_hash) =
+            contact_search::get_customer_contact_hashes(key_store, request)?;
+        payment_intent.customer_email_hash =
+            customer_email_hash.or(payment_intent.customer_email_hash.take());
+        payment_intent.customer_phone_hash =
+            customer_phone_hash.or(payment_intent.customer_phone_hash.take());
         payment_attempt.payment_method_billing_address_id = payment_method_billing
             .as_ref()
             .map(|payment_method_billing| payment_method_billing.address_id.clone());
@@ -364,7 +402,11 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
                         .map(Into::into)
                         .unwrap_or(payment_attempt.net_amount.get_order_amount());
                     payment_attempt.net_amount.set_order_amount(amount);
-                    payment_intent.amount = amount;
+                    // The attempts of the payments with store credit applied only charge a part
+                    // of the amount of the payment
+                    if payment_intent.store_credit_amount.is_none() {
+                        payment_intent.amount = amount;
+                    }
                     let surcharge_amount = request
                         .surcharge_details
                         .as_ref()
@@ -498,6 +540,18 @@ impl<F: Send + Clone + Sync> GetTracker<F, PaymentData<F>, api::PaymentsRequest>
             card_testing_guard_data: None,
             vault_operation: None,
             threeds_method_comp_ind: None,
+            external_authentication_data: None,
+            payment_facilitator_details: None,
+            custom_status_mapping: None,
+            localized_unified_message: None,
+            connector_response_details: None,
+            receipt_url: None,
+            address_validation: address_validation::get_payment_address_validation(
+                shipping_address.as_ref(),
+                billing_address.as_ref(),
+            ),
+            scheduled_capture_at: None,
+            custom_fields: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -904,7 +958,12 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
                 &state.into(),
                 payment_data.payment_intent.clone(),
                 storage::PaymentIntentUpdate::Update(Box::new(PaymentIntentUpdateFields {
-                    amount: payment_data.amount.into(),
+                    amount: payment_data
+                        .payment_intent
+                        .store_credit_amount
+                        .map_or(payment_data.amount.into(), |_| {
+                            payment_data.payment_intent.amount
+                        }),
                     currency: payment_data.currency,
                     setup_future_usage,
                     status: intent_status,
@@ -933,6 +992,8 @@ impl<F: Clone + Sync> UpdateTracker<F, PaymentData<F>, api::PaymentsRequest> for
                     shipping_details,
                     is_payment_processor_token_flow: None,
                     tax_details: None,
+                    customer_email_hash: payment_data.payment_intent.customer_email_hash.clone(),
+                    customer_phone_hash: payment_data.payment_intent.customer_phone_hash.clone(),
                 })),
                 key_store,
                 storage_scheme,
@@ -180,6 +180,15 @@ impl<F: Send + Clone + Sync>
             card_testing_guard_data: None,
             vault_operation: None,
             threeds_method_comp_ind: None,
+            external_authentication_data: None,
+            payment_facilitator_details: None,
+            custom_status_mapping: None,
+            localized_unified_message: None,
+            connector_response_details: None,
+            receipt_url: None,
+            address_validation: None,
+            scheduled_capture_at: None,
+            custom_fields: None,
         };
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -227,7 +236,7 @@ impl<F: Clone + Sync>
         let authorization_new = AuthorizationNew {
             authorization_id: format!(
                 "{}_{}",
-                common_utils::generate_id_with_default_len("auth"),
+                common_utils::generate_resource_id_with_default_len("auth"),
                 new_authorization_count
             ),
             merchant_id: payment_data.payment_intent.merchant_id.clone(),
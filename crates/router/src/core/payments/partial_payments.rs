@@ -0,0 +1,320 @@
+//! Payments paid by multiple attempts, each paying a part of the amount of the payment, as for a
+//! gift card and a card. The part paid by an attempt is its order amount, which defaults to the
+//! amount remaining to be paid. The payment succeeds once its successful attempts have paid its
+//! whole amount, and until then it requires another payment method, with which it is confirmed
+//! again for a new attempt. The amount received of the payment is the sum of the parts paid by its
+//! successful attempts, each of which is refunded separately.
+
+use api_models::payments::PaymentsRequest;
+use common_utils::types::MinorUnit;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::errors::{self, RouterResult},
+    db::StorageInterface,
+    types::{
+        storage::{self, enums},
+        transformers::ForeignFrom,
+    },
+};
+
+pub fn is_partial_payments_enabled(payment_intent: &storage::PaymentIntent) -> bool {
+    payment_intent.enable_partial_payments == Some(true)
+}
+
+/// Whether a part of the amount of the payment has been paid, while the rest remains to be paid
+pub fn is_partially_paid(payment_intent: &storage::PaymentIntent) -> bool {
+    is_partial_payments_enabled(payment_intent)
+        && payment_intent
+            .amount_captured
+            .is_some_and(|amount_captured| amount_captured > MinorUnit::zero())
+}
+
+/// The amount of the payment which remains to be paid, for the payments with partial payments
+/// enabled
+pub fn get_amount_remaining(payment_intent: &storage::PaymentIntent) -> Option<MinorUnit> {
+    is_partial_payments_enabled(payment_intent)
+        .then(|| payment_intent.amount - payment_intent.amount_captured.unwrap_or_default())
+}
+
+/// Validates the partial payments requested on the creation of a payment. The attempts of the
+/// payment are captured automatically, so that each of them pays its part once it succeeds.
+pub fn validate_partial_payments_request(request: &PaymentsRequest) -> RouterResult<()> {
+    if request.enable_partial_payments != Some(true) {
+        return match request.partial_amount {
+            Some(_) => Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "partial_amount can only be passed when enable_partial_payments is true"
+                    .to_string(),
+            })),
+            None => Ok(()),
+        };
+    }
+
+    let unsupported_field = if !matches!(
+        request.capture_method,
+        None | Some(enums::CaptureMethod::Automatic)
+    ) {
+        Some("capture_method must be automatic")
+    } else if request.shipping_cost.is_some() {
+        Some("shipping_cost is not supported")
+    } else if request.is_amount_estimated == Some(true) {
+        Some("is_amount_estimated is not supported")
+    } else if request.mandate_data.is_some()
+        || request.setup_future_usage == Some(enums::FutureUsage::OffSession)
+    {
+        Some("mandates are not supported")
+    } else {
+        None
+    };
+    if let Some(message) = unsupported_field {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("{message} when enable_partial_payments is true"),
+        }));
+    }
+
+    match (request.partial_amount, request.amount) {
+        (Some(_), _) if request.confirm != Some(true) => {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "partial_amount can only be passed when the payment is confirmed"
+                    .to_string(),
+            }))
+        }
+        (Some(partial_amount), Some(amount)) => {
+            get_partial_amount(MinorUnit::from(amount), Some(partial_amount)).map(|_| ())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// The part of the amount to be paid by an attempt, which defaults to the amount remaining to be
+/// paid
+fn get_partial_amount(
+    amount_remaining: MinorUnit,
+    partial_amount: Option<MinorUnit>,
+) -> RouterResult<MinorUnit> {
+    if amount_remaining <= MinorUnit::zero() {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The whole amount of the payment has been paid".to_string(),
+        }));
+    }
+
+    match partial_amount {
+        Some(partial_amount) if partial_amount <= MinorUnit::zero() => {
+            Err(report!(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "partial_amount".to_string(),
+                expected_format: "positive integer".to_string(),
+            }))
+        }
+        Some(partial_amount) if partial_amount > amount_remaining => {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "partial_amount cannot exceed the amount remaining to be paid, which is {amount_remaining}"
+                ),
+            }))
+        }
+        Some(partial_amount) => Ok(partial_amount),
+        None => Ok(amount_remaining),
+    }
+}
+
+/// The status of a payment with partial payments enabled, given the status of its latest attempt.
+/// A successful attempt which leaves a part of the amount to be paid, or a failed attempt after a
+/// part of it has been paid, leaves the payment requiring another payment method.
+fn get_intent_status(
+    amount: MinorUnit,
+    amount_paid: MinorUnit,
+    attempt_status: enums::IntentStatus,
+) -> enums::IntentStatus {
+    match attempt_status {
+        enums::IntentStatus::Succeeded if amount_paid < amount => {
+            enums::IntentStatus::RequiresPaymentMethod
+        }
+        enums::IntentStatus::Failed if amount_paid > MinorUnit::zero() => {
+            enums::IntentStatus::RequiresPaymentMethod
+        }
+        status => status,
+    }
+}
+
+/// The sum of the parts of the amount of the payment paid by its successful attempts, other than
+/// the given attempt
+async fn find_amount_paid(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+    excluded_attempt_id: &str,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<MinorUnit> {
+    let payment_attempts = db
+        .find_attempts_by_merchant_id_payment_id(
+            &payment_intent.merchant_id,
+            payment_intent.get_id(),
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the attempts of the payment")?;
+
+    Ok(payment_attempts
+        .iter()
+        .filter(|payment_attempt| {
+            payment_attempt.attempt_id != excluded_attempt_id
+                && payment_attempt.status == enums::AttemptStatus::Charged
+        })
+        .map(|payment_attempt| payment_attempt.net_amount.get_order_amount())
+        .sum())
+}
+
+/// Whether the confirmation of the payment requires a new attempt, as its active attempt has
+/// either paid its part of the amount or failed
+pub fn is_new_attempt_required(
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) -> bool {
+    is_partial_payments_enabled(payment_intent)
+        && matches!(
+            payment_attempt.status,
+            enums::AttemptStatus::Charged
+                | enums::AttemptStatus::Failure
+                | enums::AttemptStatus::AuthorizationFailed
+                | enums::AttemptStatus::AuthenticationFailed
+                | enums::AttemptStatus::RouterDeclined
+        )
+}
+
+/// The part of the amount of the payment to be paid by the attempt being confirmed
+#[instrument(skip_all)]
+pub async fn get_attempt_amount(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    partial_amount: Option<MinorUnit>,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<MinorUnit> {
+    let amount_paid = find_amount_paid(
+        db,
+        payment_intent,
+        &payment_attempt.attempt_id,
+        storage_scheme,
+    )
+    .await?;
+    get_partial_amount(payment_intent.amount - amount_paid, partial_amount)
+}
+
+/// The status and the amount received of the payment after the response of the connector for its
+/// active attempt, with the amount received of the payments with partial payments enabled being
+/// the sum of the parts paid by their successful attempts
+#[instrument(skip_all)]
+pub async fn get_intent_status_and_amount_captured(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    amount_captured: Option<MinorUnit>,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<(enums::IntentStatus, Option<MinorUnit>)> {
+    let attempt_status = enums::IntentStatus::foreign_from(payment_attempt.status);
+    if !is_partial_payments_enabled(payment_intent) {
+        return Ok((attempt_status, amount_captured));
+    }
+
+    let mut amount_paid = find_amount_paid(
+        db,
+        payment_intent,
+        &payment_attempt.attempt_id,
+        storage_scheme,
+    )
+    .await?;
+    if payment_attempt.status == enums::AttemptStatus::Charged {
+        amount_paid = amount_paid + payment_attempt.net_amount.get_order_amount();
+    }
+
+    Ok((
+        get_intent_status(payment_intent.amount, amount_paid, attempt_status),
+        (amount_paid > MinorUnit::zero())
+            .then_some(amount_paid)
+            .or(amount_captured),
+    ))
+}
+
+/// The attempt of a payment with partial payments enabled to be refunded, which has to have paid
+/// its part of the amount
+#[instrument(skip_all)]
+pub async fn find_attempt_to_refund(
+    db: &dyn StorageInterface,
+    payment_intent: &storage::PaymentIntent,
+    attempt_id: &str,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> RouterResult<storage::PaymentAttempt> {
+    let payment_attempt = db
+        .find_payment_attempt_by_attempt_id_merchant_id(
+            attempt_id,
+            &payment_intent.merchant_id,
+            storage_scheme,
+        )
+        .await
+        .ok()
+        .filter(|payment_attempt| payment_attempt.payment_id == *payment_intent.get_id())
+        .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("attempt_id {attempt_id} is not an attempt of the payment"),
+        })?;
+
+    if payment_attempt.status != enums::AttemptStatus::Charged {
+        return Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
+            current_flow: "refund".into(),
+            field_name: "attempt status".into(),
+            current_value: payment_attempt.status.to_string(),
+            states: "charged".to_string(),
+        }));
+    }
+    Ok(payment_attempt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_partial_amount() {
+        assert_eq!(
+            get_partial_amount(MinorUnit::new(4000), None).ok(),
+            Some(MinorUnit::new(4000))
+        );
+        assert_eq!(
+            get_partial_amount(MinorUnit::new(4000), Some(MinorUnit::new(2500))).ok(),
+            Some(MinorUnit::new(2500))
+        );
+        assert!(get_partial_amount(MinorUnit::new(4000), Some(MinorUnit::new(4001))).is_err());
+        assert!(get_partial_amount(MinorUnit::new(4000), Some(MinorUnit::zero())).is_err());
+        assert!(get_partial_amount(MinorUnit::zero(), None).is_err());
+    }
+
+    #[test]
+    fn test_get_intent_status() {
+        let amount = MinorUnit::new(6540);
+
+        assert_eq!(
+            get_intent_status(amount, MinorUnit::new(2500), enums::IntentStatus::Succeeded),
+            enums::IntentStatus::RequiresPaymentMethod
+        );
+        assert_eq!(
+            get_intent_status(amount, amount, enums::IntentStatus::Succeeded),
+            enums::IntentStatus::Succeeded
+        );
+        assert_eq!(
+            get_intent_status(amount, MinorUnit::new(2500), enums::IntentStatus::Failed),
+            enums::IntentStatus::RequiresPaymentMethod
+        );
+        assert_eq!(
+            get_intent_status(amount, MinorUnit::zero(), enums::IntentStatus::Failed),
+            enums::IntentStatus::Failed
+        );
+        assert_eq!(
+            get_intent_status(
+                amount,
+                MinorUnit::new(2500),
+                enums::IntentStatus::RequiresCustomerAction
+            ),
+            enums::IntentStatus::RequiresCustomerAction
+        );
+    }
+}
@@ -0,0 +1,278 @@
+//! Payment facilitator details of the profiles. Platforms and marketplaces processing payments on
+//! behalf of their sub-merchants configure the merchant category code, the soft descriptor and the
+//! details of the sub-merchant for the profile, and can override them per payment. The resolved
+//! details are sent to the connectors which accept payment facilitator fields in the
+//! authorization request.
+
+use api_models::payments::PaymentFacilitatorDetails;
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::domain,
+};
+
+pub fn validate_payment_facilitator_details(
+    payment_facilitator_details: &PaymentFacilitatorDetails,
+) -> RouterResult<()> {
+    payment_facilitator_details.validate().map_err(|error| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: error.current_context().to_string(),
+        })
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn upsert_payment_facilitator_details(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: PaymentFacilitatorDetails,
+) -> RouterResponse<PaymentFacilitatorDetails> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    validate_payment_facilitator_details(&request)?;
+
+    let key = profile_id.get_payment_facilitator_details_key();
+    let serialized_details = request
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize payment facilitator details")?;
+
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => {
+            db.update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(serialized_details),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update payment facilitator details")?;
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            db.insert_config(configs::ConfigNew {
+                key,
+                config: serialized_details,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert payment facilitator details")?;
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch payment facilitator details");
+        }
+    }
+
+    Ok(services::ApplicationResponse::Json(request))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_payment_facilitator_details(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<PaymentFacilitatorDetails> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    let details = get_profile_payment_facilitator_details(db, &profile_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Payment facilitator details not found for the profile".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(details))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_payment_facilitator_details(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<()> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    db.delete_config_by_key(&profile_id.get_payment_facilitator_details_key())
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::ApiErrorResponse::GenericNotFoundError {
+                    message: "Payment facilitator details not found for the profile".to_string(),
+                })
+            } else {
+                error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to delete payment facilitator details")
+            }
+        })?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+/// The payment facilitator details configured for the profile, whose absence is cached as well,
+/// since they are looked up on the confirmation of every payment
+pub async fn get_profile_payment_facilitator_details(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Option<PaymentFacilitatorDetails>> {
+    db.find_config_by_key_unwrap_or(
+        &profile_id.get_payment_facilitator_details_key(),
+        Some("null".to_string()),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch payment facilitator details")?
+    .config
+    .parse_struct::<Option<PaymentFacilitatorDetails>>("PaymentFacilitatorDetails")
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to parse payment facilitator details")
+}
+
+fn resolve_payment_facilitator_details(
+    profile_details: Option<PaymentFacilitatorDetails>,
+    payment_overrides: Option<PaymentFacilitatorDetails>,
+) -> Option<PaymentFacilitatorDetails> {
+    match (profile_details, payment_overrides) {
+        (Some(profile_details), Some(payment_overrides)) => {
+            Some(profile_details.merge(payment_overrides))
+        }
+        (profile_details, payment_overrides) => payment_overrides.or(profile_details),
+    }
+}
+
+/// Resolves the payment facilitator details to be sent to the connector, by overriding the
+/// details configured for the profile with the ones passed in the payment
+pub async fn get_payment_facilitator_details(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+    payment_overrides: Option<PaymentFacilitatorDetails>,
+) -> RouterResult<Option<PaymentFacilitatorDetails>> {
+    let profile_details = get_profile_payment_facilitator_details(db, profile_id).await?;
+
+    Ok(resolve_payment_facilitator_details(
+        profile_details,
+        payment_overrides,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use api_models::payments::SubMerchantDetails;
+    use masking::Secret;
+
+    use super::*;
+
+    fn get_sub_merchant(id: &str) -> SubMerchantDetails {
+        SubMerchantDetails {
+            id: Secret::new(id.to_string()),
+            name: Secret::new("Joe's Pizza".to_string()),
+            address: None,
+            tax_id: None,
+        }
+    }
+
+    #[test]
+    fn test_resolve_payment_facilitator_details() {
+        let profile_details = PaymentFacilitatorDetails {
+            merchant_category_code: Some("5812".to_string()),
+            soft_descriptor: Some("PLATFORM*JOES PIZZA".to_string()),
+            sub_merchant: Some(get_sub_merchant("sub_merchant_1")),
+            ..Default::default()
+        };
+        let payment_overrides = PaymentFacilitatorDetails {
+            soft_descriptor: Some("PLATFORM*JOES ONLINE".to_string()),
+            sub_merchant: Some(get_sub_merchant("sub_merchant_2")),
+            ..Default::default()
+        };
+
+        assert_eq!(resolve_payment_facilitator_details(None, None), None);
+        assert_eq!(
+            resolve_payment_facilitator_details(Some(profile_details.clone()), None),
+            Some(profile_details.clone())
+        );
+        assert_eq!(
+            resolve_payment_facilitator_details(None, Some(payment_overrides.clone())),
+            Some(payment_overrides.clone())
+        );
+        assert_eq!(
+            resolve_payment_facilitator_details(Some(profile_details), Some(payment_overrides)),
+            Some(PaymentFacilitatorDetails {
+                merchant_category_code: Some("5812".to_string()),
+                soft_descriptor: Some("PLATFORM*JOES ONLINE".to_string()),
+                sub_merchant: Some(get_sub_merchant("sub_merchant_2")),
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_payment_facilitator_details() {
+        let valid_details = PaymentFacilitatorDetails {
+            merchant_category_code: Some("5812".to_string()),
+            soft_descriptor: Some("PLATFORM*JOES PIZZA".to_string()),
+            sub_merchant: Some(get_sub_merchant("sub_merchant_1")),
+            ..Default::default()
+        };
+        assert!(validate_payment_facilitator_details(&valid_details).is_ok());
+
+        let invalid_merchant_category_code = PaymentFacilitatorDetails {
+            merchant_category_code: Some("58A2".to_string()),
+            ..Default::default()
+        };
+        assert!(validate_payment_facilitator_details(&invalid_merchant_category_code).is_err());
+
+        let long_soft_descriptor = PaymentFacilitatorDetails {
+            soft_descriptor: Some("PLATFORM*JOES PIZZA AND PASTA".to_string()),
+            ..Default::default()
+        };
+        assert!(validate_payment_facilitator_details(&long_soft_descriptor).is_err());
+
+        let empty_sub_merchant = PaymentFacilitatorDetails {
+            sub_merchant: Some(get_sub_merchant(" ")),
+            ..Default::default()
+        };
+        assert!(validate_payment_facilitator_details(&empty_sub_merchant).is_err());
+    }
+}
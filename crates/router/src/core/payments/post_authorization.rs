@@ -0,0 +1,521 @@
+//! Normalization of the address (AVS) and the card verification value (CVV) checks of the
+//! connectors, and the post authorization rules of the profiles evaluated on them. The response
+//! codes of the checks are normalized into the `AvsResult` and the `CvvResult` of the attempt right
+//! after the payment is authorized, and the first of the rules of the profile which matches the
+//! payment decides the action taken on it, such as voiding the authorization of the payments
+//! above an amount whose address did not match.
+
+use api_models::payments::{PostAuthorizationRule, PostAuthorizationRules};
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use super::connector_response_details;
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        payments::{self, OperationSessionGetters, OperationSessionSetters},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::{app::ReqState, SessionState},
+    services,
+    types::{
+        api::{self, enums as api_enums},
+        domain,
+        storage::{self, enums},
+    },
+};
+
+#[instrument(skip_all)]
+pub async fn upsert_post_authorization_rules(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: PostAuthorizationRules,
+) -> RouterResponse<PostAuthorizationRules> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    request.validate().map_err(|error| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: error.current_context().to_string(),
+        })
+    })?;
+
+    let key = profile_id.get_post_authorization_rules_key();
+    let serialized_rules = request
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize post authorization rules")?;
+
+    // The cache holds empty rules for the profiles without any, so the existence of the rules is
+    // checked in the database
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => {
+            db.update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(serialized_rules),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update post authorization rules")?;
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            db.insert_config(configs::ConfigNew {
+                key,
+                config: serialized_rules,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert post authorization rules")?;
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch post authorization rules");
+        }
+    }
+
+    Ok(services::ApplicationResponse::Json(request))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_post_authorization_rules(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<PostAuthorizationRules> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    let rules = get_post_authorization_rules(db, &profile_id).await?.ok_or(
+        errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Post authorization rules not found for the profile".to_string(),
+        },
+    )?;
+
+    Ok(services::ApplicationResponse::Json(rules))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_post_authorization_rules(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<()> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    db.delete_config_by_key(&profile_id.get_post_authorization_rules_key())
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::ApiErrorResponse::GenericNotFoundError {
+                    message: "Post authorization rules not found for the profile".to_string(),
+                })
+            } else {
+                error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to delete post authorization rules")
+            }
+        })?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+/// Fetches the post authorization rules of the profile. This is looked up for every authorized
+/// payment, so the absence of the rules is cached as well.
+pub async fn get_post_authorization_rules(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Option<PostAuthorizationRules>> {
+    let rules: PostAuthorizationRules = db
+        .find_config_by_key_unwrap_or(
+            &profile_id.get_post_authorization_rules_key(),
+            Some("{}".to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch post authorization rules")?
+        .config
+        .parse_struct("PostAuthorizationRules")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse post authorization rules")?;
+
+    Ok((!rules.is_empty()).then_some(rules))
+}
+
+/// Normalizes the result of a check sent by the connector as `pass` or `fail`, as Stripe does
+fn is_check_passed(checks: &serde_json::Value, key: &str) -> Option<bool> {
+    match checks.get(key)?.as_str()?.to_ascii_lowercase().as_str() {
+        "pass" => Some(true),
+        "fail" => Some(false),
+        _ => None,
+    }
+}
+
+/// Normalizes the address verification of the checks of the connector, either the separate
+/// results of the street address and the postal code, or the AVS response code of the card
+/// networks
+pub fn normalize_avs_result(checks: &serde_json::Value) -> Option<enums::AvsResult> {
+    if checks.get("address_line1_check").is_some()
+        || checks.get("address_postal_code_check").is_some()
+    {
+        return match (
+            is_check_passed(checks, "address_line1_check"),
+            is_check_passed(checks, "address_postal_code_check"),
+        ) {
+            (Some(true), Some(true)) => Some(enums::AvsResult::FullMatch),
+            (Some(true), Some(false)) => Some(enums::AvsResult::AddressMatch),
+            (Some(false), Some(true)) => Some(enums::AvsResult::PostalCodeMatch),
+            (Some(false), Some(false)) => Some(enums::AvsResult::NoMatch),
+            (None, _) | (_, None) => Some(enums::AvsResult::Unavailable),
+        };
+    }
+
+    let code = connector_response_details::get_check_value(
+        checks,
+        connector_response_details::AVS_RESULT_KEYS,
+    )?;
+    match code.trim().to_ascii_uppercase().as_str() {
+        "Y" | "X" | "D" | "F" | "M" => Some(enums::AvsResult::FullMatch),
+        "A" | "B" => Some(enums::AvsResult::AddressMatch),
+        "Z" | "W" | "P" => Some(enums::AvsResult::PostalCodeMatch),
+        "N" => Some(enums::AvsResult::NoMatch),
+        "U" | "R" | "S" | "G" | "E" | "I" | "C" => Some(enums::AvsResult::Unavailable),
+        _ => None,
+    }
+}
+
+/// Normalizes the verification of the card verification value of the checks of the connector,
+/// either sent as `pass` or `fail`, or as the CVV response code of the card networks
+pub fn normalize_cvv_result(checks: &serde_json::Value) -> Option<enums::CvvResult> {
+    let code = connector_response_details::get_check_value(
+        checks,
+        connector_response_details::CVV_RESULT_KEYS,
+    )?;
+    match code.trim().to_ascii_uppercase().as_str() {
+        "M" | "PASS" => Some(enums::CvvResult::Match),
+        "N" | "FAIL" => Some(enums::CvvResult::NoMatch),
+        "P" | "S" | "UNCHECKED" => Some(enums::CvvResult::NotProcessed),
+        "U" | "X" | "UNAVAILABLE" => Some(enums::CvvResult::Unavailable),
+        _ => None,
+    }
+}
+
+/// Whether the payment matches all the conditions of the rule
+fn is_rule_matched(
+    rule: &PostAuthorizationRule,
+    payment_attempt: &storage::PaymentAttempt,
+) -> bool {
+    let is_avs_result_matched = rule.avs_results.as_ref().map_or(true, |avs_results| {
+        payment_attempt
+            .avs_result
+            .is_some_and(|avs_result| avs_results.contains(&avs_result))
+    });
+    let is_cvv_result_matched = rule.cvv_results.as_ref().map_or(true, |cvv_results| {
+        payment_attempt
+            .cvv_result
+            .is_some_and(|cvv_result| cvv_results.contains(&cvv_result))
+    });
+    let is_currency_matched = rule
+        .currency
+        .map_or(true, |currency| payment_attempt.currency == Some(currency));
+    let is_amount_matched = rule.min_amount.map_or(true, |min_amount| {
+        payment_attempt.net_amount.get_total_amount() >= min_amount
+    });
+
+    is_avs_result_matched && is_cvv_result_matched && is_currency_matched && is_amount_matched
+}
+
+/// Stores the normalized results of the checks of the connector on the attempt, and takes the
+/// action of the first post authorization rule of the profile which matches the payment if it is
+/// authorized. The payments which are already captured are not voided, and the failures after the
+/// authorization, such as to store the checks or to void the payment, are only logged, as the
+/// payment is authorized regardless.
+#[instrument(skip_all)]
+pub async fn execute_post_authorization_rules<F, D>(
+    state: &SessionState,
+    req_state: ReqState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_data: &mut D,
+    platform_merchant_account: Option<&domain::MerchantAccount>,
+) -> RouterResult<()>
+where
+    F: Clone,
+    D: OperationSessionGetters<F> + OperationSessionSetters<F>,
+{
+    let payment_attempt = payment_data.get_payment_attempt();
+    let Some(checks) = connector_response_details::get_payment_checks(payment_attempt) else {
+        return Ok(());
+    };
+    let avs_result = normalize_avs_result(&checks);
+    let cvv_result = normalize_cvv_result(&checks);
+
+    if (avs_result.is_some() || cvv_result.is_some())
+        && (avs_result, cvv_result) != (payment_attempt.avs_result, payment_attempt.cvv_result)
+    {
+        match state
+            .store
+            .update_payment_attempt_with_attempt_id(
+                payment_attempt.clone(),
+                storage::PaymentAttemptUpdate::PaymentChecksUpdate {
+                    avs_result,
+                    cvv_result,
+                    updated_by: merchant_account.storage_scheme.to_string(),
+                },
+                merchant_account.storage_scheme,
+            )
+            .await
+        {
+            Ok(payment_attempt) => payment_data.set_payment_attempt(payment_attempt),
+            // The rules are still evaluated on the results of the checks stored on the attempt
+            Err(error) => logger::error!(
+                ?error,
+                "Failed to update the results of the checks of the attempt"
+            ),
+        }
+    }
+
+    let payment_attempt = payment_data.get_payment_attempt();
+    if payment_attempt.status != enums::AttemptStatus::Authorized {
+        return Ok(());
+    }
+
+    let rules =
+        match get_post_authorization_rules(state.store.as_ref(), &payment_attempt.profile_id).await
+        {
+            Ok(Some(rules)) => rules,
+            Ok(None) => return Ok(()),
+            Err(error) => {
+                logger::error!(?error, "Failed to fetch post authorization rules");
+                return Ok(());
+            }
+        };
+    let Some(rule) = rules
+        .rules
+        .into_iter()
+        .find(|rule| is_rule_matched(rule, payment_attempt))
+    else {
+        return Ok(());
+    };
+
+    match rule.action {
+        api_enums::PostAuthorizationAction::Void => {
+            let cancel_request = api::PaymentsCancelRequest {
+                payment_id: payment_attempt.payment_id.clone(),
+                cancellation_reason: Some(format!(
+                    "Voided by the post authorization rule {}",
+                    rule.name
+                )),
+                merchant_connector_details: None,
+            };
+            let cancel_response = Box::pin(payments::payments_core::<
+                api::Void,
+                api::PaymentsResponse,
+                _,
+                _,
+                _,
+                payments::PaymentData<api::Void>,
+            >(
+                state.clone(),
+                req_state,
+                merchant_account.clone(),
+                None,
+                key_store.clone(),
+                payments::PaymentCancel,
+                cancel_request,
+                services::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                None,
+                hyperswitch_domain_models::payments::HeaderPayload::default(),
+                platform_merchant_account.cloned(),
+            ))
+            .await;
+
+            match cancel_response {
+                Ok(services::ApplicationResponse::JsonWithHeaders((payments_response, _))) => {
+                    logger::info!(
+                        rule = %rule.name,
+                        "Payment voided by the post authorization rule"
+                    );
+                    payment_data.set_payment_intent_status(payments_response.status);
+
+                    // The attempt is updated by the void as well, so that the response of the
+                    // payment reflects its status
+                    let payment_attempt = payment_data.get_payment_attempt();
+                    match state
+                        .store
+                        .find_payment_attempt_by_attempt_id_merchant_id(
+                            &payment_attempt.attempt_id,
+                            &payment_attempt.merchant_id,
+                            merchant_account.storage_scheme,
+                        )
+                        .await
+                    {
+                        Ok(payment_attempt) => payment_data.set_payment_attempt(payment_attempt),
+                        Err(error) => logger::error!(
+                            ?error,
+                            "Failed to fetch the attempt voided by the post authorization rule"
+                        ),
+                    }
+                }
+                Ok(_) => {}
+                Err(error) => {
+                    logger::error!(
+                        ?error,
+                        rule = %rule.name,
+                        "Failed to void the payment matched by the post authorization rule"
+                    );
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_avs_result() {
+        // Separate results of the street address and the postal code
+        assert_eq!(
+            normalize_avs_result(&serde_json::json!({
+                "address_line1_check": "pass",
+                "address_postal_code_check": "pass"
+            })),
+            Some(enums::AvsResult::FullMatch)
+        );
+        assert_eq!(
+            normalize_avs_result(&serde_json::json!({
+                "address_line1_check": "pass",
+                "address_postal_code_check": "fail"
+            })),
+            Some(enums::AvsResult::AddressMatch)
+        );
+        assert_eq!(
+            normalize_avs_result(&serde_json::json!({
+                "address_line1_check": "FAIL",
+                "address_postal_code_check": "pass"
+            })),
+            Some(enums::AvsResult::PostalCodeMatch)
+        );
+        assert_eq!(
+            normalize_avs_result(&serde_json::json!({
+                "address_line1_check": "fail",
+                "address_postal_code_check": "fail"
+            })),
+            Some(enums::AvsResult::NoMatch)
+        );
+        assert_eq!(
+            normalize_avs_result(&serde_json::json!({
+                "address_line1_check": "unavailable",
+                "address_postal_code_check": "pass"
+            })),
+            Some(enums::AvsResult::Unavailable)
+        );
+
+        // AVS response codes of the card networks
+        assert_eq!(
+            normalize_avs_result(&serde_json::json!({ "avs_result": "y" })),
+            Some(enums::AvsResult::FullMatch)
+        );
+        assert_eq!(
+            normalize_avs_result(&serde_json::json!({ "avs_response": { "code": "A" } })),
+            Some(enums::AvsResult::AddressMatch)
+        );
+        assert_eq!(
+            normalize_avs_result(&serde_json::json!({ "avs_check": " Z " })),
+            Some(enums::AvsResult::PostalCodeMatch)
+        );
+        assert_eq!(
+            normalize_avs_result(&serde_json::json!({ "avs_result": "N" })),
+            Some(enums::AvsResult::NoMatch)
+        );
+        assert_eq!(
+            normalize_avs_result(&serde_json::json!({ "avs_result": "U" })),
+            Some(enums::AvsResult::Unavailable)
+        );
+
+        // Unknown codes and missing checks
+        assert_eq!(
+            normalize_avs_result(&serde_json::json!({ "avs_result": "Q" })),
+            None
+        );
+        assert_eq!(normalize_avs_result(&serde_json::json!({})), None);
+    }
+
+    #[test]
+    fn test_normalize_cvv_result() {
+        assert_eq!(
+            normalize_cvv_result(&serde_json::json!({ "cvc_check": "pass" })),
+            Some(enums::CvvResult::Match)
+        );
+        assert_eq!(
+            normalize_cvv_result(&serde_json::json!({ "cvv_result": "M" })),
+            Some(enums::CvvResult::Match)
+        );
+        assert_eq!(
+            normalize_cvv_result(&serde_json::json!({ "cvc_check": "fail" })),
+            Some(enums::CvvResult::NoMatch)
+        );
+        assert_eq!(
+            normalize_cvv_result(&serde_json::json!({ "card_verification": "n" })),
+            Some(enums::CvvResult::NoMatch)
+        );
+        assert_eq!(
+            normalize_cvv_result(&serde_json::json!({ "cvc_check": "unchecked" })),
+            Some(enums::CvvResult::NotProcessed)
+        );
+        assert_eq!(
+            normalize_cvv_result(&serde_json::json!({ "card_validation_result": { "code": "P" } })),
+            Some(enums::CvvResult::NotProcessed)
+        );
+        assert_eq!(
+            normalize_cvv_result(&serde_json::json!({ "cvc_check": "unavailable" })),
+            Some(enums::CvvResult::Unavailable)
+        );
+
+        // Unknown codes and missing checks
+        assert_eq!(
+            normalize_cvv_result(&serde_json::json!({ "cvv_result": "Q" })),
+            None
+        );
+        assert_eq!(normalize_cvv_result(&serde_json::json!({})), None);
+    }
+}
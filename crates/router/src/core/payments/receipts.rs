@@ -0,0 +1,447 @@
+//! Receipts of the completed payments, which the merchants can share with their customers. The
+//! receipt is rendered as a hosted web page or as a PDF document, with the branding of the payment
+//! link configuration of the profile, and is accessed through a signed link which is included in
+//! the payment responses. The receipt can also be emailed to the customer.
+
+mod pdf;
+
+use api_models::payments::{AdditionalPaymentData, PaymentReceiptRequest, ReceiptFormat};
+#[cfg(feature = "email")]
+use api_models::payments::{PaymentReceiptEmailRequest, PaymentReceiptEmailResponse};
+use common_utils::{
+    crypto::{self, SignMessage, VerifySignature},
+    date_time,
+    ext_traits::ValueExt,
+    id_type,
+};
+use error_stack::ResultExt;
+use masking::PeekInterface;
+use router_env::{instrument, tracing};
+use serde::Serialize;
+use tera::{Context, Tera};
+
+use super::{OperationSessionGetters, OperationSessionSetters};
+#[cfg(feature = "email")]
+use crate::core::data_residency;
+#[cfg(feature = "email")]
+use crate::services::email::types as email_types;
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+};
+
+const RECEIPT_TEMPLATE: &str = "receipt.html";
+
+/// Details of a payment, as shown on its receipt
+#[derive(Debug, Serialize)]
+pub struct ReceiptDetails {
+    pub merchant_name: String,
+    pub logo: String,
+    pub theme: String,
+    pub payment_id: String,
+    pub amount: String,
+    pub status: String,
+    pub paid_at: String,
+    pub payment_method: Option<String>,
+    pub statement_descriptor: Option<String>,
+    pub description: Option<String>,
+}
+
+/// Receipts are only available for the payments whose funds have been captured
+fn is_receipt_available(status: enums::IntentStatus) -> bool {
+    matches!(
+        status,
+        enums::IntentStatus::Succeeded | enums::IntentStatus::PartiallyCaptured
+    )
+}
+
+fn get_receipt_signature_message(
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+    expires: i64,
+) -> String {
+    format!(
+        "{}:{}:{expires}",
+        merchant_id.get_string_repr(),
+        payment_id.get_string_repr()
+    )
+}
+
+/// The key with which the links to the receipts of the merchant are signed
+fn get_receipt_key(key_store: &domain::MerchantKeyStore) -> RouterResult<Vec<u8>> {
+    core_utils::derive_merchant_key(key_store.key.get_inner().peek(), consts::RECEIPT_KEY_LABEL)
+}
+
+/// Returns the link to the receipt of the payment, signed with a key derived from the key of the
+/// merchant so that it cannot be forged for the other payments
+pub fn get_receipt_url(
+    base_url: &str,
+    key_store: &domain::MerchantKeyStore,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+    expires: i64,
+) -> RouterResult<String> {
+    let signature = crypto::HmacSha256
+        .sign_message(
+            &get_receipt_key(key_store)?,
+            get_receipt_signature_message(merchant_id, payment_id, expires).as_bytes(),
+        )
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to sign the receipt url")?;
+
+    Ok(format!(
+        "{base_url}/receipts/{}/{}?expires={expires}&signature={signature}",
+        merchant_id.get_string_repr(),
+        payment_id.get_string_repr()
+    ))
+}
+
+fn generate_receipt_url(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<String> {
+    let expires = date_time::now_unix_timestamp() + consts::RECEIPT_URL_VALIDITY_IN_SECONDS;
+    get_receipt_url(&state.base_url, key_store, merchant_id, payment_id, expires)
+}
+
+fn verify_receipt_signature(
+    key_store: &domain::MerchantKeyStore,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+    request: &PaymentReceiptRequest,
+) -> RouterResult<()> {
+    let invalid_link_error = || errors::ApiErrorResponse::GenericUnauthorized {
+        message: "The receipt link is invalid or has expired".to_string(),
+    };
+
+    if request.expires < date_time::now_unix_timestamp() {
+        return Err(invalid_link_error().into());
+    }
+
+    let signature = hex::decode(&request.signature).map_err(|_| invalid_link_error())?;
+    let is_valid = crypto::HmacSha256
+        .verify_signature(
+            &get_receipt_key(key_store)?,
+            &signature,
+            get_receipt_signature_message(merchant_id, payment_id, request.expires).as_bytes(),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to verify the signature of the receipt url")?;
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(invalid_link_error().into())
+    }
+}
+
+/// Sets the link to the receipt of the payment, once the payment is completed
+pub fn populate_receipt_url<F, D>(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    payment_data: &mut D,
+) -> RouterResult<()>
+where
+    F: Clone,
+    D: OperationSessionGetters<F> + OperationSessionSetters<F>,
+{
+    let payment_intent = payment_data.get_payment_intent();
+    if !is_receipt_available(payment_intent.status) {
+        return Ok(());
+    }
+
+    let receipt_url = generate_receipt_url(
+        state,
+        key_store,
+        &payment_intent.merchant_id,
+        &payment_intent.payment_id,
+    )?;
+    payment_data.set_receipt_url(Some(receipt_url));
+    Ok(())
+}
+
+fn get_payment_method_label(payment_attempt: &storage::PaymentAttempt) -> Option<String> {
+    let card = payment_attempt
+        .payment_method_data
+        .clone()
+        .and_then(|payment_method_data| {
+            payment_method_data
+                .parse_value::<AdditionalPaymentData>("AdditionalPaymentData")
+                .ok()
+        })
+        .and_then(|additional_payment_data| match additional_payment_data {
+            AdditionalPaymentData::Card(card) => Some(card),
+            _ => None,
+        });
+
+    match card {
+        Some(card) => {
+            let network = card
+                .card_network
+                .map(|card_network| card_network.to_string())
+                .unwrap_or_else(|| "Card".to_string());
+            Some(match card.last4 {
+                Some(last4) => format!("{network} ending in {last4}"),
+                None => network,
+            })
+        }
+        None => payment_attempt
+            .payment_method_type
+            .map(|payment_method_type| payment_method_type.to_string())
+            .or_else(|| {
+                payment_attempt
+                    .payment_method
+                    .map(|payment_method| payment_method.to_string())
+            }),
+    }
+}
+
+async fn get_receipt_details(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<(storage::PaymentIntent, ReceiptDetails)> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            key_manager_state,
+            payment_id,
+            merchant_account.get_id(),
+            key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    if !is_receipt_available(payment_intent.status) {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The receipt is only available for completed payments".to_string(),
+        }
+        .into());
+    }
+
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            payment_id,
+            merchant_account.get_id(),
+            &payment_intent.active_attempt.get_id(),
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_link_config = match &payment_intent.profile_id {
+        Some(profile_id) => db
+            .find_business_profile_by_profile_id(key_manager_state, key_store, profile_id)
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+                id: profile_id.get_string_repr().to_owned(),
+            })?
+            .payment_link_config
+            .and_then(|payment_link_config| payment_link_config.default_config),
+        None => None,
+    };
+
+    let merchant_name = payment_link_config
+        .as_ref()
+        .and_then(|config| config.seller_name.clone())
+        .or_else(|| {
+            merchant_account
+                .merchant_name
+                .clone()
+                .map(|merchant_name| merchant_name.into_inner().peek().to_owned())
+        })
+        .unwrap_or_else(|| merchant_account.get_id().get_string_repr().to_owned());
+
+    let currency = payment_intent
+        .currency
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Currency missing in a completed payment")?;
+    let amount = payment_intent
+        .amount_captured
+        .unwrap_or(payment_intent.amount);
+    let amount = currency
+        .to_currency_base_unit(amount.get_amount_as_i64())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to convert the amount of the payment to its base unit")?;
+
+    let paid_at = payment_attempt
+        .modified_at
+        .format(time::macros::format_description!(
+            "[year]-[month]-[day] [hour]:[minute] UTC"
+        ))
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to format the time of the payment")?;
+
+    let statement_descriptor = match (
+        &payment_intent.statement_descriptor_name,
+        &payment_intent.statement_descriptor_suffix,
+    ) {
+        (Some(name), Some(suffix)) => Some(format!("{name} {suffix}")),
+        (Some(name), None) => Some(name.clone()),
+        (None, suffix) => suffix.clone(),
+    };
+
+    let receipt_details = ReceiptDetails {
+        merchant_name,
+        logo: payment_link_config
+            .as_ref()
+            .and_then(|config| config.logo.clone())
+            .unwrap_or_else(|| consts::DEFAULT_MERCHANT_LOGO.to_string()),
+        theme: payment_link_config
+            .and_then(|config| config.theme)
+            .unwrap_or_else(|| consts::DEFAULT_BACKGROUND_COLOR.to_string()),
+        payment_id: payment_id.get_string_repr().to_owned(),
+        amount: format!("{currency} {amount}"),
+        status: payment_intent.status.to_string(),
+        paid_at,
+        payment_method: get_payment_method_label(&payment_attempt),
+        statement_descriptor,
+        description: payment_intent.description.clone(),
+    };
+
+    Ok((payment_intent, receipt_details))
+}
+
+fn render_receipt_html(receipt_details: &ReceiptDetails, pdf_url: &str) -> RouterResult<String> {
+    let mut tera = Tera::default();
+    tera.add_raw_template(RECEIPT_TEMPLATE, include_str!("receipts/receipt.html"))
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to load the receipt template")?;
+
+    let mut context = Context::from_serialize(receipt_details)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to build the context of the receipt template")?;
+    context.insert("pdf_url", pdf_url);
+
+    tera.render(RECEIPT_TEMPLATE, &context)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to render the receipt")
+}
+
+/// Renders the receipt of the payment, once the signature of the link has been verified
+#[instrument(skip_all)]
+pub async fn retrieve_payment_receipt(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_id: id_type::PaymentId,
+    request: PaymentReceiptRequest,
+) -> RouterResponse<()> {
+    verify_receipt_signature(&key_store, merchant_account.get_id(), &payment_id, &request)?;
+
+    let (_, receipt_details) =
+        get_receipt_details(&state, &merchant_account, &key_store, &payment_id).await?;
+
+    match request.format.unwrap_or_default() {
+        ReceiptFormat::Html => {
+            let pdf_url = format!(
+                "{}&format=pdf",
+                get_receipt_url(
+                    &state.base_url,
+                    &key_store,
+                    merchant_account.get_id(),
+                    &payment_id,
+                    request.expires,
+                )?
+            );
+            let receipt = render_receipt_html(&receipt_details, &pdf_url)?;
+            Ok(services::ApplicationResponse::FileData((
+                receipt.into_bytes(),
+                mime::TEXT_HTML_UTF_8,
+            )))
+        }
+        ReceiptFormat::Pdf => Ok(services::ApplicationResponse::FileData((
+            pdf::render_receipt_pdf(&receipt_details),
+            mime::APPLICATION_PDF,
+        ))),
+    }
+}
+
+/// Emails the link to the receipt of the payment, to the given email or else to the email of the
+/// customer of the payment
+#[cfg(feature = "email")]
+#[instrument(skip_all)]
+pub async fn send_payment_receipt_email(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    payment_id: id_type::PaymentId,
+    request: PaymentReceiptEmailRequest,
+) -> RouterResponse<PaymentReceiptEmailResponse> {
+    let (payment_intent, receipt_details) =
+        get_receipt_details(&state, &merchant_account, &key_store, &payment_id).await?;
+    crate::core::utils::validate_profile_id_from_auth_layer(profile_id, &payment_intent)?;
+
+    let recipient_email = match request.email {
+        Some(email) => Some(email),
+        None => match &payment_intent.customer_id {
+            Some(customer_id) => data_residency::get_pii_store(&state, merchant_account.get_id())
+                .await?
+                .find_customer_optional_by_customer_id_merchant_id(
+                    &(&state).into(),
+                    customer_id,
+                    merchant_account.get_id(),
+                    &key_store,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the customer of the payment")?
+                .and_then(|customer| customer.email)
+                .map(common_utils::pii::Email::from),
+            None => None,
+        },
+    }
+    .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+        field_name: "email",
+    })?;
+
+    let receipt_url =
+        generate_receipt_url(&state, &key_store, merchant_account.get_id(), &payment_id)?;
+
+    let email_contents = email_types::PaymentReceipt {
+        recipient_email,
+        subject: format!("Your receipt from {}", receipt_details.merchant_name),
+        merchant_name: receipt_details.merchant_name,
+        amount: receipt_details.amount,
+        payment_id: receipt_details.payment_id,
+        receipt_url: receipt_url.clone(),
+    };
+
+    state
+        .email_client
+        .clone()
+        .compose_and_send_email(
+            email_types::get_base_url(&state),
+            Box::new(email_contents),
+            state.conf.proxy.https_url.as_ref(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to send the receipt email")?;
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentReceiptEmailResponse {
+            payment_id,
+            receipt_url,
+        },
+    ))
+}
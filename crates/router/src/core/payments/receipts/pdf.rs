@@ -0,0 +1,146 @@
+//! Minimal PDF rendering of the receipts, with the standard Helvetica fonts which every PDF reader
+//! provides, so that no font has to be embedded in the document.
+
+use super::ReceiptDetails;
+
+const PAGE_WIDTH: u32 = 595;
+const PAGE_HEIGHT: u32 = 842;
+const MARGIN: u32 = 56;
+const LINE_HEIGHT: u32 = 22;
+
+/// Escapes the text for a PDF string literal. The standard fonts only cover the ASCII characters,
+/// the others are replaced.
+fn escape_text(text: &str) -> String {
+    text.chars()
+        .map(|character| match character {
+            '\\' | '(' | ')' => format!("\\{character}"),
+            character if character.is_ascii() && !character.is_ascii_control() => {
+                character.to_string()
+            }
+            _ => "?".to_string(),
+        })
+        .collect()
+}
+
+fn get_content_stream(receipt_details: &ReceiptDetails) -> String {
+    let mut lines = vec![
+        ("F2", 20, receipt_details.merchant_name.clone()),
+        ("F1", 12, "Receipt".to_string()),
+        ("F2", 16, receipt_details.amount.clone()),
+        (
+            "F1",
+            11,
+            format!("Payment ID: {}", receipt_details.payment_id),
+        ),
+        ("F1", 11, format!("Date: {}", receipt_details.paid_at)),
+        ("F1", 11, format!("Status: {}", receipt_details.status)),
+    ];
+    lines.extend(
+        [
+            ("Payment method", &receipt_details.payment_method),
+            (
+                "Statement descriptor",
+                &receipt_details.statement_descriptor,
+            ),
+            ("Description", &receipt_details.description),
+        ]
+        .into_iter()
+        .filter_map(|(label, value)| {
+            value
+                .as_ref()
+                .map(|value| ("F1", 11, format!("{label}: {value}")))
+        }),
+    );
+
+    let mut y = PAGE_HEIGHT - MARGIN;
+    lines
+        .into_iter()
+        .map(|(font, size, text)| {
+            let line = format!(
+                "BT /{font} {size} Tf {MARGIN} {y} Td ({}) Tj ET\n",
+                escape_text(&text)
+            );
+            y -= LINE_HEIGHT;
+            line
+        })
+        .collect()
+}
+
+/// Renders the receipt as a single page PDF document
+pub fn render_receipt_pdf(receipt_details: &ReceiptDetails) -> Vec<u8> {
+    let content_stream = get_content_stream(receipt_details);
+    let objects = [
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_string(),
+        format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] \
+             /Resources << /Font << /F1 4 0 R /F2 5 0 R >> >> /Contents 6 0 R >>"
+        ),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica >>".to_string(),
+        "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold >>".to_string(),
+        format!(
+            "<< /Length {} >>\nstream\n{content_stream}endstream",
+            content_stream.len()
+        ),
+    ];
+
+    let mut document = String::from("%PDF-1.4\n");
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (index, object) in objects.iter().enumerate() {
+        offsets.push(document.len());
+        document.push_str(&format!("{} 0 obj\n{object}\nendobj\n", index + 1));
+    }
+
+    let xref_offset = document.len();
+    document.push_str(&format!(
+        "xref\n0 {}\n0000000000 65535 f \n",
+        objects.len() + 1
+    ));
+    for offset in offsets {
+        document.push_str(&format!("{offset:010} 00000 n \n"));
+    }
+    document.push_str(&format!(
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+        objects.len() + 1
+    ));
+
+    document.into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_render_receipt_pdf() {
+        let receipt_details = ReceiptDetails {
+            merchant_name: "Café (Paris)".to_string(),
+            logo: String::new(),
+            theme: String::new(),
+            payment_id: "pay_123".to_string(),
+            amount: "USD 10.00".to_string(),
+            status: "succeeded".to_string(),
+            paid_at: "2025-04-05 10:00 UTC".to_string(),
+            payment_method: Some("Visa ending in 4242".to_string()),
+            statement_descriptor: None,
+            description: None,
+        };
+
+        let document = String::from_utf8(render_receipt_pdf(&receipt_details)).unwrap();
+        assert!(document.starts_with("%PDF-1.4\n"));
+        assert!(document.ends_with("%%EOF\n"));
+        assert!(document.contains("(Caf? \\(Paris\\)) Tj"));
+        assert!(document.contains("(Payment method: Visa ending in 4242) Tj"));
+        assert!(!document.contains("Statement descriptor"));
+
+        let xref_offset: usize = document
+            .lines()
+            .skip_while(|line| *line != "startxref")
+            .nth(1)
+            .unwrap()
+            .parse()
+            .unwrap();
+        assert!(document[xref_offset..].starts_with("xref\n"));
+    }
+}
@@ -11,6 +11,7 @@ use router_env::{
 use crate::{
     core::{
         errors::{self, RouterResult, StorageErrorExt},
+        payment_methods::network_tokenization,
         payments::{
             self,
             flows::{ConstructFlowSpecificData, Feature},
@@ -160,6 +161,8 @@ where
                         super::get_connector_data(&mut connectors)?
                     };
 
+                    reset_connector_specific_credentials(state, payment_data, &connector).await;
+
                     router_data = do_retry(
                         &state.clone(),
                         req_state.clone(),
@@ -310,6 +313,93 @@ fn get_flow_name<F>() -> RouterResult<String> {
         .to_string())
 }
 
+/// Whether the retry goes to another merchant connector account than the previous attempt, whose
+/// connector specific credentials cannot then be used for the retry. The connector names are
+/// compared when either of the merchant connector accounts is not known.
+#[cfg(feature = "v1")]
+fn is_merchant_connector_account_changed(
+    previous_merchant_connector_id: Option<&common_utils::id_type::MerchantConnectorAccountId>,
+    previous_connector: Option<&str>,
+    connector: &api::ConnectorData,
+) -> bool {
+    match (
+        previous_merchant_connector_id,
+        connector.merchant_connector_id.as_ref(),
+    ) {
+        (Some(previous_merchant_connector_id), Some(merchant_connector_id)) => {
+            previous_merchant_connector_id != merchant_connector_id
+        }
+        _ => previous_connector != Some(connector.connector_name.to_string().as_str()),
+    }
+}
+
+/// Replaces the credentials of the payment method which are specific to the merchant connector
+/// account of the previous attempt, when the payment is retried with another one. The token of the
+/// payment method in the previous connector is dropped, so that the token of the payment method in
+/// the connector of the retry is used instead, or else the payment method is tokenized afresh for
+/// it. A network token is given a new cryptogram from the tokenization service, as the cryptogram
+/// of the previous attempt cannot be used again.
+#[cfg(feature = "v1")]
+async fn reset_connector_specific_credentials<F, D>(
+    state: &app::SessionState,
+    payment_data: &mut D,
+    connector: &api::ConnectorData,
+) where
+    D: payments::OperationSessionGetters<F> + payments::OperationSessionSetters<F>,
+{
+    let previous_attempt = payment_data.get_payment_attempt();
+    if !is_merchant_connector_account_changed(
+        previous_attempt.merchant_connector_id.as_ref(),
+        previous_attempt.connector.as_deref(),
+        connector,
+    ) {
+        return;
+    }
+
+    logger::info!(
+        previous_merchant_connector_id = ?previous_attempt.merchant_connector_id,
+        merchant_connector_id = ?connector.merchant_connector_id,
+        "Resetting the connector specific credentials of the payment method for the retry"
+    );
+    payment_data.remove_pm_token();
+
+    let network_token = payment_data
+        .get_payment_method_data()
+        .filter(|payment_method_data| payment_method_data.is_network_token_payment_method_data())
+        .and(payment_data.get_payment_method_info())
+        .cloned()
+        .and_then(|payment_method| {
+            payment_method
+                .network_token_requestor_reference_id
+                .clone()
+                .map(|network_token_requestor_ref_id| {
+                    (network_token_requestor_ref_id, payment_method)
+                })
+        });
+    if let Some((network_token_requestor_ref_id, payment_method)) = network_token {
+        match network_tokenization::get_token_from_tokenization_service(
+            state,
+            network_token_requestor_ref_id,
+            &payment_method,
+        )
+        .await
+        {
+            Ok(network_token_data) => payment_data.set_payment_method_data(Some(
+                domain::PaymentMethodData::NetworkToken(network_token_data),
+            )),
+            Err(error) => logger::error!(
+                ?error,
+                "Failed to fetch a new cryptogram of the network token for the retry"
+            ),
+        }
+    }
+
+    metrics::AUTO_RETRY_CONNECTOR_CREDENTIALS_RESET_COUNT.add(
+        1,
+        router_env::metric_attributes!(("connector", connector.connector_name.to_string())),
+    );
+}
+
 #[cfg(feature = "v1")]
 #[allow(clippy::too_many_arguments)]
 #[instrument(skip_all)]
@@ -682,6 +772,7 @@ pub fn make_new_payment_attempt(
         extended_authorization_applied: Default::default(),
         capture_before: Default::default(),
         card_discovery: old_payment_attempt.card_discovery,
+        connector_idempotency_key: Some(payments::helpers::generate_connector_idempotency_key()),
     }
 }
 
@@ -718,13 +809,20 @@ pub async fn get_merchant_config_for_gsm(
 
 #[cfg(feature = "v1")]
 pub async fn config_should_call_gsm(
-    db: &dyn StorageInterface,
+    state: &app::SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
     profile: &domain::Profile,
 ) -> bool {
-    let merchant_config_gsm = get_merchant_config_for_gsm(db, merchant_id).await;
+    let merchant_config_gsm = get_merchant_config_for_gsm(&*state.store, merchant_id).await;
     let profile_config_gsm = profile.is_auto_retries_enabled;
-    merchant_config_gsm || profile_config_gsm
+    (merchant_config_gsm || profile_config_gsm)
+        && crate::core::feature_flags::is_feature_enabled(
+            state,
+            merchant_id,
+            Some(profile.get_id()),
+            storage_enums::MerchantFeatureFlag::SmartRetries,
+        )
+        .await
 }
 
 pub trait GsmValidation<F: Send + Clone + Sync, FData: Send + Sync, Resp> {
@@ -771,3 +869,54 @@ impl<F: Send + Clone + Sync, FData: Send + Sync>
         }
     }
 }
+
+#[cfg(all(test, feature = "v1"))]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use super::*;
+
+    fn get_merchant_connector_id(
+        merchant_connector_id: &str,
+    ) -> common_utils::id_type::MerchantConnectorAccountId {
+        common_utils::id_type::MerchantConnectorAccountId::wrap(merchant_connector_id.to_string())
+            .expect("valid merchant connector id")
+    }
+
+    fn get_connector_data(merchant_connector_id: Option<&str>) -> api::ConnectorData {
+        api::ConnectorData {
+            connector: api::ConnectorData::convert_connector("adyen")
+                .expect("valid connector name"),
+            connector_name: types::Connector::Adyen,
+            get_token: api::GetToken::Connector,
+            merchant_connector_id: merchant_connector_id.map(get_merchant_connector_id),
+        }
+    }
+
+    #[test]
+    fn test_merchant_connector_account_changed() {
+        let previous_merchant_connector_id = get_merchant_connector_id("mca_1");
+
+        // Another account of the same connector has credentials of its own
+        assert!(is_merchant_connector_account_changed(
+            Some(&previous_merchant_connector_id),
+            Some("adyen"),
+            &get_connector_data(Some("mca_2")),
+        ));
+        assert!(!is_merchant_connector_account_changed(
+            Some(&previous_merchant_connector_id),
+            Some("adyen"),
+            &get_connector_data(Some("mca_1")),
+        ));
+        // The connector names are compared when the accounts are not known
+        assert!(!is_merchant_connector_account_changed(
+            None,
+            Some("adyen"),
+            &get_connector_data(None),
+        ));
+        assert!(is_merchant_connector_account_changed(
+            None,
+            Some("stripe"),
+            &get_connector_data(Some("mca_2")),
+        ));
+    }
+}
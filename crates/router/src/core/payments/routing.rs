@@ -150,6 +150,7 @@ pub fn make_dsl_input_for_payouts(
             .map(api_enums::Country::from_alpha2),
         business_label: payout_data.payout_attempt.business_label.clone(),
         setup_future_usage: None,
+        issuer_frictionless_rate: None,
     };
     let payment_method = dsl_inputs::PaymentMethodInput {
         payment_method: payout_data
@@ -161,6 +162,7 @@ pub fn make_dsl_input_for_payouts(
             .as_ref()
             .map(api_enums::PaymentMethodType::foreign_from),
         card_network: None,
+        card_product_type: None,
     };
     Ok(dsl_inputs::BackendInput {
         mandate,
@@ -237,6 +239,7 @@ pub fn make_dsl_input(
 
                 _ => None,
             }),
+        card_product_type: None,
     };
 
     let payment_input = dsl_inputs::PaymentInput {
@@ -262,6 +265,7 @@ pub fn make_dsl_input(
             .map(api_enums::Country::from_alpha2),
         business_label: None,
         setup_future_usage: Some(payments_dsl_input.payment_intent.setup_future_usage),
+        issuer_frictionless_rate: None,
     };
 
     let metadata = payments_dsl_input
@@ -346,6 +350,7 @@ pub fn make_dsl_input(
 
                 _ => None,
             }),
+        card_product_type: None,
     };
 
     let payment_input = dsl_inputs::PaymentInput {
@@ -376,6 +381,7 @@ pub fn make_dsl_input(
             .map(api_enums::Country::from_alpha2),
         business_label: payments_dsl_input.payment_intent.business_label.clone(),
         setup_future_usage: payments_dsl_input.payment_intent.setup_future_usage,
+        issuer_frictionless_rate: None,
     };
 
     let metadata = payments_dsl_input
@@ -447,7 +453,31 @@ pub async fn perform_static_routing_v1(
                 }
             };
 
-            execute_dsl_and_get_connector_v1(backend_input, interpreter)?
+            let output = interpreter
+                .execute(backend_input)
+                .change_context(errors::RoutingError::DslExecutionError)?;
+
+            // The traffic of a rule with a running experiment is split between the arms of the
+            // experiment, which are only run for the payments
+            #[cfg(feature = "v1")]
+            let connector_selection = match (transaction_data, output.rule_name.as_deref()) {
+                (routing::TransactionData::Payment(payment_data), Some(rule_name)) => {
+                    routing::experiments::get_experiment_connector_selection(
+                        state,
+                        business_profile.get_id(),
+                        algorithm_id,
+                        rule_name,
+                        payment_data.payment_attempt,
+                    )
+                    .await
+                    .unwrap_or(output.connector_selection)
+                }
+                _ => output.connector_selection,
+            };
+            #[cfg(feature = "v2")]
+            let connector_selection = output.connector_selection;
+
+            get_connectors_from_connector_selection(connector_selection)?
         }
     })
 }
@@ -535,11 +565,19 @@ fn execute_dsl_and_get_connector_v1(
     backend_input: dsl_inputs::BackendInput,
     interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
-    let routing_output: routing_types::RoutingAlgorithm = interpreter
+    let connector_selection = interpreter
         .execute(backend_input)
-        .map(|out| out.connector_selection.foreign_into())
+        .map(|out| out.connector_selection)
         .change_context(errors::RoutingError::DslExecutionError)?;
 
+    get_connectors_from_connector_selection(connector_selection)
+}
+
+fn get_connectors_from_connector_selection(
+    connector_selection: ConnectorSelection,
+) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
+    let routing_output: routing_types::RoutingAlgorithm = connector_selection.foreign_into();
+
     Ok(match routing_output {
         routing_types::RoutingAlgorithm::Priority(plist) => plist,
 
@@ -711,14 +749,14 @@ pub async fn get_merchant_cgraph(
     Ok(cgraph)
 }
 
+/// Fetches the merchant connector accounts of the profile from which its constraint graph is built
 #[cfg(feature = "v1")]
-pub async fn refresh_cgraph_cache(
+pub async fn get_cgraph_merchant_connector_accounts(
     state: &SessionState,
     key_store: &domain::MerchantKeyStore,
-    key: String,
     profile_id: &common_utils::id_type::ProfileId,
     transaction_type: &api_enums::TransactionType,
-) -> RoutingResult<Arc<hyperswitch_constraint_graph::ConstraintGraph<euclid_dir::DirValue>>> {
+) -> RoutingResult<Vec<admin_api::MerchantConnectorResponse>> {
     let mut merchant_connector_accounts = state
         .store
         .find_merchant_connector_account_by_merchant_id_and_disabled_list(
@@ -759,11 +797,17 @@ pub async fn refresh_cgraph_cache(
             connector_type,
         );
 
-    let api_mcas = merchant_connector_accounts
+    merchant_connector_accounts
         .into_iter()
         .map(admin_api::MerchantConnectorResponse::foreign_try_from)
         .collect::<Result<Vec<_>, _>>()
-        .change_context(errors::RoutingError::KgraphCacheRefreshFailed)?;
+        .change_context(errors::RoutingError::KgraphCacheRefreshFailed)
+}
+
+/// Builds the country and the currency filters of the payment methods of the connectors from the
+/// configuration of the application
+#[cfg(feature = "v1")]
+pub fn get_country_currency_filter(state: &SessionState) -> RoutingResult<CountryCurrencyFilter> {
     let connector_configs = state
         .conf
         .pm_filters
@@ -785,10 +829,25 @@ pub async fn refresh_cgraph_cache(
         .get("default")
         .cloned()
         .map(ForeignFrom::foreign_from);
-    let config_pm_filters = CountryCurrencyFilter {
+
+    Ok(CountryCurrencyFilter {
         connector_configs,
         default_configs,
-    };
+    })
+}
+
+#[cfg(feature = "v1")]
+pub async fn refresh_cgraph_cache(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    key: String,
+    profile_id: &common_utils::id_type::ProfileId,
+    transaction_type: &api_enums::TransactionType,
+) -> RoutingResult<Arc<hyperswitch_constraint_graph::ConstraintGraph<euclid_dir::DirValue>>> {
+    let api_mcas =
+        get_cgraph_merchant_connector_accounts(state, key_store, profile_id, transaction_type)
+            .await?;
+    let config_pm_filters = get_country_currency_filter(state)?;
     let cgraph = Arc::new(
         mca_graph::make_mca_graph(api_mcas, &config_pm_filters)
             .change_context(errors::RoutingError::KgraphCacheRefreshFailed)
@@ -1048,6 +1107,7 @@ pub async fn perform_session_flow_routing(
         payment_method: None,
         payment_method_type: None,
         card_network: None,
+        card_product_type: None,
     };
 
     #[cfg(feature = "v1")]
@@ -1075,6 +1135,7 @@ pub async fn perform_session_flow_routing(
             .map(storage_enums::Country::from_alpha2),
         business_label: session_input.payment_intent.business_label.clone(),
         setup_future_usage: session_input.payment_intent.setup_future_usage,
+        issuer_frictionless_rate: None,
     };
 
     #[cfg(feature = "v2")]
@@ -1366,6 +1427,7 @@ pub fn make_dsl_input_for_surcharge(
             .map(api_enums::Country::from_alpha2),
         business_label: payment_intent.business_label.clone(),
         setup_future_usage: payment_intent.setup_future_usage,
+        issuer_frictionless_rate: None,
     };
 
     let metadata = payment_intent
@@ -1380,6 +1442,7 @@ pub fn make_dsl_input_for_surcharge(
         payment_method: None,
         payment_method_type: None,
         card_network: None,
+        card_product_type: None,
     };
     let backend_input = dsl_inputs::BackendInput {
         metadata,
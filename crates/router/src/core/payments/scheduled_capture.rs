@@ -0,0 +1,434 @@
+//! Captures of the authorized payments at the time requested in their `capture_on`. The capture is
+//! scheduled as a process tracker task once the payment is authorized, and the task captures the
+//! full authorized amount of the payment, unless the payment has been captured or voided by then.
+//! The scheduled capture can be rescheduled, released or canceled through the API until it is
+//! executed.
+
+use api_models::payments::{PaymentsCaptureScheduleRequest, PaymentsCaptureScheduleResponse};
+use common_utils::id_type;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use super::{OperationSessionGetters, OperationSessionSetters};
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments as payments_core, utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, business_status, enums},
+    },
+};
+
+const SCHEDULED_CAPTURE_TASK: &str = "SCHEDULED_CAPTURE";
+const SCHEDULED_CAPTURE_TAG: &str = "PAYMENT";
+
+fn get_process_tracker_id(payment_id: &id_type::PaymentId) -> String {
+    let runner = storage::ProcessTrackerRunner::ScheduledCaptureWorkflow;
+    format!(
+        "{runner}_{SCHEDULED_CAPTURE_TASK}_{}",
+        payment_id.get_string_repr()
+    )
+}
+
+/// Whether the scheduled capture is yet to be executed
+fn is_capture_pending(process: &storage::ProcessTracker) -> bool {
+    process.status != enums::ProcessTrackerStatus::Finish
+}
+
+pub(super) async fn find_scheduled_capture(
+    db: &dyn StorageInterface,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<Option<storage::ProcessTracker>> {
+    db.find_process_by_id(&get_process_tracker_id(payment_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching SCHEDULED_CAPTURE task")
+}
+
+/// Schedules the capture of the payment at the given time, replacing the capture already
+/// scheduled. A capture in the past is scheduled right away.
+pub(super) async fn schedule_capture(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+    existing_process: Option<storage::ProcessTracker>,
+    capture_on: PrimitiveDateTime,
+) -> RouterResult<()> {
+    let schedule_time = capture_on.max(common_utils::date_time::now());
+
+    match existing_process {
+        Some(process) => db
+            .as_scheduler()
+            .reset_process(process, schedule_time)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while resetting SCHEDULED_CAPTURE task")?,
+        None => {
+            let tracking_data = storage::payment_attempt::ScheduledCaptureTrackingData {
+                merchant_id: merchant_id.clone(),
+                payment_id: payment_id.clone(),
+            };
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                get_process_tracker_id(payment_id),
+                SCHEDULED_CAPTURE_TASK,
+                storage::ProcessTrackerRunner::ScheduledCaptureWorkflow,
+                [SCHEDULED_CAPTURE_TAG],
+                tracking_data,
+                None,
+                schedule_time,
+                hyperswitch_domain_models::consts::API_VERSION,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct SCHEDULED_CAPTURE task")?;
+
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed while inserting SCHEDULED_CAPTURE task to process_tracker",
+                )?;
+        }
+    };
+
+    Ok(())
+}
+
+async fn sync_scheduled_capture<F, D>(
+    state: &SessionState,
+    payment_data: &D,
+) -> RouterResult<Option<PrimitiveDateTime>>
+where
+    F: Clone,
+    D: OperationSessionGetters<F>,
+{
+    let payment_intent = payment_data.get_payment_intent();
+    if payment_intent.status != enums::IntentStatus::RequiresCapture {
+        return Ok(None);
+    }
+
+    let db = &*state.store;
+    let existing_process = find_scheduled_capture(db, &payment_intent.payment_id).await?;
+
+    match (
+        existing_process,
+        payment_data.get_payment_attempt().capture_on,
+    ) {
+        // A capture which has been executed or canceled is not scheduled again
+        (Some(process), _) => Ok(is_capture_pending(&process)
+            .then_some(process.schedule_time)
+            .flatten()),
+        (None, Some(capture_on)) => {
+            schedule_capture(
+                db,
+                &payment_intent.merchant_id,
+                &payment_intent.payment_id,
+                None,
+                capture_on,
+            )
+            .await?;
+            Ok(Some(capture_on.max(common_utils::date_time::now())))
+        }
+        (None, None) => Ok(None),
+    }
+}
+
+/// Schedules the capture of a payment which has been authorized with a `capture_on`, and sets the
+/// time of the pending scheduled capture of the payment in the payment data. A failure is only
+/// logged, as the payment has already been processed by then.
+#[instrument(skip_all)]
+pub async fn populate_scheduled_capture<F, D>(state: &SessionState, payment_data: &mut D)
+where
+    F: Clone,
+    D: OperationSessionGetters<F> + OperationSessionSetters<F>,
+{
+    let scheduled_capture_at = sync_scheduled_capture(state, payment_data)
+        .await
+        .inspect_err(|error| logger::error!(?error, "Failed to schedule the capture"))
+        .ok()
+        .flatten();
+
+    payment_data.set_scheduled_capture_at(scheduled_capture_at);
+}
+
+async fn find_payment_intent(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<storage::PaymentIntent> {
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            payment_id,
+            merchant_account.get_id(),
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payment_intent)?;
+
+    Ok(payment_intent)
+}
+
+fn validate_capture_on(
+    capture_on: PrimitiveDateTime,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<()> {
+    if capture_on <= common_utils::date_time::now() {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "capture_on must be in the future".to_string(),
+        }
+        .into());
+    }
+
+    match payment_attempt.capture_before {
+        Some(capture_before) if capture_on > capture_before => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "capture_on must be before the authorization of the payment expires at {capture_before}"
+                ),
+            }
+            .into())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Reschedules the capture of an authorized payment, or schedules it if the payment was not
+/// authorized with a `capture_on`
+#[instrument(skip_all)]
+pub async fn reschedule_capture(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    request: PaymentsCaptureScheduleRequest,
+) -> RouterResponse<PaymentsCaptureScheduleResponse> {
+    let payment_intent = find_payment_intent(
+        &state,
+        &merchant_account,
+        profile_id,
+        &key_store,
+        &request.payment_id,
+    )
+    .await?;
+
+    if payment_intent.status != enums::IntentStatus::RequiresCapture {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The capture can only be scheduled for payments in requires_capture status"
+                .to_string(),
+        }
+        .into());
+    }
+
+    let db = &*state.store;
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            &payment_intent.merchant_id,
+            &payment_intent.active_attempt.get_id(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    validate_capture_on(request.capture_on, &payment_attempt)?;
+
+    let existing_process = find_scheduled_capture(db, &payment_intent.payment_id).await?;
+    schedule_capture(
+        db,
+        &payment_intent.merchant_id,
+        &payment_intent.payment_id,
+        existing_process,
+        request.capture_on,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentsCaptureScheduleResponse {
+            payment_id: payment_intent.payment_id,
+            status: payment_intent.status,
+            scheduled_capture_at: Some(request.capture_on),
+        },
+    ))
+}
+
+/// Cancels the pending scheduled capture of a payment, after which the payment can only be
+/// captured through the capture API
+#[instrument(skip_all)]
+pub async fn cancel_scheduled_capture(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    payment_id: id_type::PaymentId,
+) -> RouterResponse<PaymentsCaptureScheduleResponse> {
+    let payment_intent = find_payment_intent(
+        &state,
+        &merchant_account,
+        profile_id,
+        &key_store,
+        &payment_id,
+    )
+    .await?;
+
+    let db = &*state.store;
+    let existing_process = find_scheduled_capture(db, &payment_intent.payment_id)
+        .await?
+        .filter(is_capture_pending)
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The payment has no pending scheduled capture".to_string(),
+        })?;
+
+    db.as_scheduler()
+        .finish_process_with_business_status(
+            existing_process,
+            business_status::CANCELED_BY_MERCHANT,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while canceling SCHEDULED_CAPTURE task")?;
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentsCaptureScheduleResponse {
+            payment_id: payment_intent.payment_id,
+            status: payment_intent.status,
+            scheduled_capture_at: None,
+        },
+    ))
+}
+
+/// Releases the scheduled capture of an authorized payment, such as a payment whose capture is
+/// held by the auto capture delay of its profile once it has been reviewed. The payment is then
+/// captured by the scheduler right away. A canceled scheduled capture can be released as well.
+#[instrument(skip_all)]
+pub async fn release_scheduled_capture(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    payment_id: id_type::PaymentId,
+) -> RouterResponse<PaymentsCaptureScheduleResponse> {
+    let payment_intent = find_payment_intent(
+        &state,
+        &merchant_account,
+        profile_id,
+        &key_store,
+        &payment_id,
+    )
+    .await?;
+
+    if payment_intent.status != enums::IntentStatus::RequiresCapture {
+        return Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The capture can only be released for payments in requires_capture status"
+                .to_string(),
+        }
+        .into());
+    }
+
+    let db = &*state.store;
+    let existing_process = find_scheduled_capture(db, &payment_intent.payment_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The payment has no scheduled capture".to_string(),
+        })?;
+
+    let capture_on = common_utils::date_time::now();
+    schedule_capture(
+        db,
+        &payment_intent.merchant_id,
+        &payment_intent.payment_id,
+        Some(existing_process),
+        capture_on,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentsCaptureScheduleResponse {
+            payment_id: payment_intent.payment_id,
+            status: payment_intent.status,
+            scheduled_capture_at: Some(capture_on),
+        },
+    ))
+}
+
+/// Captures the full authorized amount of the payment whose capture is due. Returns `false` if the
+/// payment is no longer awaiting a capture, such as when it has been captured or voided in the
+/// meantime.
+#[instrument(skip_all)]
+pub async fn execute_scheduled_capture(
+    state: &SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    tracking_data: &storage::payment_attempt::ScheduledCaptureTrackingData,
+) -> RouterResult<bool> {
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            &tracking_data.payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    if payment_intent.status != enums::IntentStatus::RequiresCapture {
+        return Ok(false);
+    }
+
+    let request = api::PaymentsCaptureRequest {
+        payment_id: tracking_data.payment_id.clone(),
+        merchant_id: Some(tracking_data.merchant_id.clone()),
+        ..Default::default()
+    };
+    let payments_response = match Box::pin(payments_core::payments_core::<
+        api::Capture,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments_core::PaymentData<api::Capture>,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account,
+        None,
+        key_store,
+        payments_core::PaymentCapture,
+        request,
+        services::AuthFlow::Merchant,
+        payments_core::CallConnectorAction::Trigger,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+        None, // Platform merchant account
+    ))
+    .await?
+    {
+        services::ApplicationResponse::Json(payments_response)
+        | services::ApplicationResponse::JsonWithHeaders((payments_response, _)) => {
+            payments_response
+        }
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response received while capturing the payment")?,
+    };
+
+    // The capture is retried while the authorization of the payment is still open
+    if payments_response.status == enums::IntentStatus::RequiresCapture {
+        return Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Scheduled capture of the payment failed");
+    }
+
+    Ok(true)
+}
@@ -0,0 +1,234 @@
+//! Caching of the session responses of the connectors, such as the wallet session objects, so that
+//! the payment sheets opened repeatedly for the same profile and session parameters do not call the
+//! connector every time. The raw responses of the connectors are cached in redis with a short time
+//! to live, and are handled by the connector integration again when they are served from the cache.
+
+use common_utils::{
+    crypto::{self, GenerateDigest},
+    id_type,
+    types::MinorUnit,
+};
+use error_stack::ResultExt;
+use masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, ConnectorErrorExt, RouterResult},
+        payments,
+    },
+    routes::{metrics, SessionState},
+    services,
+    types::{self, api},
+};
+
+/// The parameters of the session call which the session response of a connector depends on
+#[derive(Debug, serde::Serialize)]
+struct SessionResponseCacheParameters<'a> {
+    connector: &'a str,
+    merchant_connector_id: Option<&'a id_type::MerchantConnectorAccountId>,
+    amount: MinorUnit,
+    currency: common_enums::Currency,
+    country: Option<common_enums::CountryAlpha2>,
+    email: Option<&'a str>,
+    order_details: Option<&'a Vec<diesel_models::types::OrderDetailsWithAmount>>,
+    apple_pay_recurring_details: Option<&'a api_models::payments::ApplePayRecurringPaymentRequest>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct CachedSessionResponse {
+    response: String,
+}
+
+/// The key of the cached session response, if the session responses of the connector are cached
+fn get_session_response_cache_key(
+    state: &SessionState,
+    router_data: &types::PaymentsSessionRouterData,
+    connector: &api::ConnectorData,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Option<String>> {
+    if !state
+        .conf
+        .session_response_cache
+        .connectors
+        .contains(&connector.connector_name)
+    {
+        return Ok(None);
+    }
+
+    let parameters = SessionResponseCacheParameters {
+        connector: &router_data.connector,
+        merchant_connector_id: connector.merchant_connector_id.as_ref(),
+        amount: router_data.request.minor_amount,
+        currency: router_data.request.currency,
+        country: router_data.request.country,
+        email: router_data
+            .request
+            .email
+            .as_ref()
+            .map(|email| email.peek().as_str()),
+        order_details: router_data.request.order_details.as_ref(),
+        apple_pay_recurring_details: router_data.request.apple_pay_recurring_details.as_ref(),
+    };
+    let serialized_parameters = serde_json::to_vec(&parameters)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the parameters of the session call")?;
+    let parameters_digest = crypto::Sha256
+        .generate_digest(&serialized_parameters)
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to hash the parameters of the session call")?;
+
+    Ok(Some(format!(
+        "session_response_{}_{}_{parameters_digest}",
+        profile_id.get_string_repr(),
+        router_data.connector,
+    )))
+}
+
+/// Fetches the cached session response. Failures are only logged, as the connector is called when
+/// the session response is not found in the cache.
+async fn get_cached_session_response(state: &SessionState, key: &str) -> Option<String> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .inspect_err(|error| logger::error!(?error, "Failed to get redis connection"))
+        .ok()?;
+
+    redis_conn
+        .get_and_deserialize_key::<CachedSessionResponse>(&key.into(), "CachedSessionResponse")
+        .await
+        .inspect_err(|error| logger::debug!(?error, "Session response not found in the cache"))
+        .ok()
+        .map(|cached_session_response| cached_session_response.response)
+}
+
+/// Caches the session response. Failures are only logged, as the cache should never affect the
+/// outcome of the session call.
+async fn cache_session_response(state: &SessionState, key: &str, response: String) {
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection");
+            return;
+        }
+    };
+
+    if let Err(error) = redis_conn
+        .serialize_and_set_key_with_expiry(
+            &key.into(),
+            CachedSessionResponse { response },
+            state.conf.session_response_cache.ttl_in_seconds,
+        )
+        .await
+    {
+        logger::error!(?error, "Failed to cache the session response");
+    }
+}
+
+fn is_session_token_received(router_data: &types::PaymentsSessionRouterData) -> bool {
+    matches!(
+        &router_data.response,
+        Ok(types::PaymentsResponseData::SessionResponse { session_token })
+            if !matches!(
+                session_token,
+                api_models::payments::SessionToken::NoSessionTokenReceived
+            )
+    )
+}
+
+/// Calls the connector for the session token, through the cache of the session responses when it
+/// is enabled for the connector
+#[instrument(skip_all)]
+pub async fn execute_session_call(
+    state: &SessionState,
+    connector_integration: services::BoxedPaymentConnectorIntegrationInterface<
+        api::Session,
+        types::PaymentsSessionData,
+        types::PaymentsResponseData,
+    >,
+    router_data: &types::PaymentsSessionRouterData,
+    connector: &api::ConnectorData,
+    call_connector_action: payments::CallConnectorAction,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<types::PaymentsSessionRouterData> {
+    let cache_key = match call_connector_action {
+        payments::CallConnectorAction::Trigger => {
+            get_session_response_cache_key(state, router_data, connector, profile_id)?
+        }
+        _ => None,
+    };
+    let Some(cache_key) = cache_key else {
+        return services::execute_connector_processing_step(
+            state,
+            connector_integration,
+            router_data,
+            call_connector_action,
+            None,
+        )
+        .await
+        .to_payment_failed_response();
+    };
+
+    if let Some(cached_response) = get_cached_session_response(state, &cache_key).await {
+        metrics::SESSION_RESPONSE_CACHE_HIT.add(
+            1,
+            router_env::metric_attributes!(("connector", router_data.connector.clone())),
+        );
+        return services::execute_connector_processing_step(
+            state,
+            connector_integration,
+            router_data,
+            payments::CallConnectorAction::HandleResponse(cached_response.into_bytes()),
+            None,
+        )
+        .await
+        .to_payment_failed_response();
+    }
+
+    let Some(request) = connector_integration
+        .build_request(router_data, &state.conf.connectors)
+        .to_payment_failed_response()?
+    else {
+        return Ok(router_data.clone());
+    };
+
+    let response = services::call_connector_api(state, request, "execute_session_call")
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to call the connector for the session token")?;
+
+    match response {
+        Ok(body) => {
+            let connector_http_status_code = body.status_code;
+            let raw_response = String::from_utf8(body.response.to_vec()).ok();
+            let mut session_router_data = connector_integration
+                .handle_response(router_data, None, body)
+                .to_payment_failed_response()?;
+            session_router_data.connector_http_status_code = Some(connector_http_status_code);
+
+            // Only the responses which are handled into a session token are cached
+            if let Some(raw_response) =
+                raw_response.filter(|_| is_session_token_received(&session_router_data))
+            {
+                cache_session_response(state, &cache_key, raw_response).await;
+            }
+
+            Ok(session_router_data)
+        }
+        Err(body) => {
+            let connector_http_status_code = body.status_code;
+            let error_response = match connector_http_status_code {
+                500..=511 => connector_integration.get_5xx_error_response(body, None),
+                _ => connector_integration.get_error_response(body, None),
+            }
+            .to_payment_failed_response()?;
+
+            Ok(types::PaymentsSessionRouterData {
+                response: Err(error_response),
+                connector_http_status_code: Some(connector_http_status_code),
+                ..router_data.clone()
+            })
+        }
+    }
+}
@@ -0,0 +1,381 @@
+//! Step-up authentication of the risky card payments of the profiles with step-up rules. The card
+//! payments being confirmed are counted against the velocity of their customer and of their card,
+//! and a payment is authenticated with 3DS whatever the 3DS decision of the merchant when its
+//! customer or its card exceeds their number of payments within a window, or when its amount
+//! exceeds the threshold for its currency. The external 3DS authentication of a stepped up payment
+//! forces a challenge, so that the payer is authenticated even if the issuer would have let the
+//! payment through frictionless. The other card payments keep the 3DS decision of the merchant.
+
+use api_models::payments::{StepUpAuthenticationConfig, StepUpVelocityLimit};
+use common_utils::{
+    crypto::{self, GenerateDigest},
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use super::OperationSessionGetters;
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{domain, storage::enums},
+};
+
+#[instrument(skip_all)]
+pub async fn upsert_step_up_authentication_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: StepUpAuthenticationConfig,
+) -> RouterResponse<StepUpAuthenticationConfig> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    request.validate().map_err(|error| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: error.current_context().to_string(),
+        })
+    })?;
+
+    let key = profile_id.get_step_up_authentication_config_key();
+    let serialized_config = request
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize step up authentication config")?;
+
+    match db.find_config_by_key(&key).await {
+        Ok(_) => {
+            db.update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(serialized_config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update step up authentication config")?;
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            db.insert_config(configs::ConfigNew {
+                key,
+                config: serialized_config,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert step up authentication config")?;
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch step up authentication config");
+        }
+    }
+
+    Ok(services::ApplicationResponse::Json(request))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_step_up_authentication_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<StepUpAuthenticationConfig> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    let config = get_step_up_authentication_config(db, &profile_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Step up authentication config not found for the profile".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(config))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_step_up_authentication_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<()> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    db.delete_config_by_key(&profile_id.get_step_up_authentication_config_key())
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::ApiErrorResponse::GenericNotFoundError {
+                    message: "Step up authentication config not found for the profile".to_string(),
+                })
+            } else {
+                error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to delete step up authentication config")
+            }
+        })?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+pub async fn get_step_up_authentication_config(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Option<StepUpAuthenticationConfig>> {
+    match db
+        .find_config_by_key(&profile_id.get_step_up_authentication_config_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("StepUpAuthenticationConfig")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse step up authentication config")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch step up authentication config"),
+    }
+}
+
+fn get_customer_velocity_redis_key(
+    profile_id: &id_type::ProfileId,
+    customer_id: &id_type::CustomerId,
+) -> String {
+    format!(
+        "step_up_velocity_{}_customer_{}",
+        profile_id.get_string_repr(),
+        customer_id.get_string_repr()
+    )
+}
+
+/// The card is identified by the hash of its number, so that the number is not stored
+fn get_card_velocity_redis_key(
+    profile_id: &id_type::ProfileId,
+    card: &domain::Card,
+) -> RouterResult<String> {
+    let card_hash = crypto::Sha256
+        .generate_digest(card.card_number.get_card_no().as_bytes())
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to hash the card number")?;
+    Ok(format!(
+        "step_up_velocity_{}_card_{card_hash}",
+        profile_id.get_string_repr()
+    ))
+}
+
+fn get_step_up_redis_key(
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> String {
+    format!(
+        "step_up_authentication_{}_{}",
+        merchant_id.get_string_repr(),
+        payment_id.get_string_repr()
+    )
+}
+
+/// Counts the payment against the velocity of a customer or of a card, and returns whether their
+/// number of payments within the window exceeds the limit. The window starts with the first payment
+/// counted in it. Failures are only logged, and the payment is then not stepped up.
+async fn exceeds_velocity_limit(
+    state: &SessionState,
+    key: String,
+    limit: &StepUpVelocityLimit,
+) -> bool {
+    let Ok(redis_conn) = state
+        .store
+        .get_redis_conn()
+        .map_err(|error| logger::error!(?error, "Failed to get redis connection"))
+    else {
+        return false;
+    };
+
+    let payments_count = match redis_conn
+        .increment_fields_in_hash(&key.as_str().into(), &[("payments", 1)])
+        .await
+    {
+        Ok(values) => values.first().copied().unwrap_or_default(),
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to count the payment for step up authentication"
+            );
+            return false;
+        }
+    };
+    if payments_count == 1 {
+        if let Err(error) = redis_conn
+            .set_expiry(&key.as_str().into(), limit.get_window_in_seconds())
+            .await
+        {
+            logger::error!(?error, "Failed to set expiry of the payment velocity");
+        }
+    }
+
+    u32::try_from(payments_count).unwrap_or(u32::MAX) > limit.max_payments
+}
+
+/// Whether the card payment being confirmed is stepped up by the step-up rules of its profile. The
+/// payment is counted against the velocity of its customer and of its card. The recurring payments
+/// initiated by the merchant are neither counted nor stepped up.
+#[instrument(skip_all)]
+async fn should_step_up<F, D>(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    payment_data: &D,
+) -> RouterResult<bool>
+where
+    F: Clone,
+    D: OperationSessionGetters<F>,
+{
+    let payment_intent = payment_data.get_payment_intent();
+    let payment_attempt = payment_data.get_payment_attempt();
+    if payment_attempt.payment_method != Some(enums::PaymentMethod::Card)
+        || payment_intent.off_session == Some(true)
+        || payment_data.get_recurring_details().is_some()
+    {
+        return Ok(false);
+    }
+    let Some(config) =
+        get_step_up_authentication_config(state.store.as_ref(), business_profile.get_id()).await?
+    else {
+        return Ok(false);
+    };
+
+    let exceeds_amount_threshold = config.amount_thresholds.iter().any(|threshold| {
+        payment_intent.currency == Some(threshold.currency)
+            && payment_intent.amount > threshold.amount
+    });
+
+    // Both velocities are counted even if the payment is already stepped up, as it is still a
+    // payment of the customer and of the card
+    let exceeds_customer_velocity = match (
+        config.customer_velocity.as_ref(),
+        payment_intent.customer_id.as_ref(),
+    ) {
+        (Some(limit), Some(customer_id)) => {
+            let key = get_customer_velocity_redis_key(business_profile.get_id(), customer_id);
+            exceeds_velocity_limit(state, key, limit).await
+        }
+        _ => false,
+    };
+    let exceeds_card_velocity = match (
+        config.card_velocity.as_ref(),
+        payment_data.get_payment_method_data(),
+    ) {
+        (Some(limit), Some(domain::PaymentMethodData::Card(card))) => {
+            let key = get_card_velocity_redis_key(business_profile.get_id(), card)?;
+            exceeds_velocity_limit(state, key, limit).await
+        }
+        _ => false,
+    };
+
+    if exceeds_amount_threshold || exceeds_customer_velocity || exceeds_card_velocity {
+        logger::info!(
+            exceeds_amount_threshold,
+            exceeds_customer_velocity,
+            exceeds_card_velocity,
+            "Payment stepped up to 3DS"
+        );
+        return Ok(true);
+    }
+    Ok(false)
+}
+
+/// Returns the authentication type of the card payment being confirmed, which is 3DS if the
+/// payment is stepped up by the step-up rules of its profile, or else the authentication type
+/// decided by the merchant. The stepped up payments are marked, so that their external 3DS
+/// authentication forces a challenge.
+#[instrument(skip_all)]
+pub async fn get_authentication_type_with_step_up<F, D>(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    payment_data: &D,
+    authentication_type: Option<enums::AuthenticationType>,
+) -> RouterResult<Option<enums::AuthenticationType>>
+where
+    F: Clone,
+    D: OperationSessionGetters<F>,
+{
+    if !should_step_up(state, business_profile, payment_data).await? {
+        return Ok(authentication_type);
+    }
+
+    let payment_intent = payment_data.get_payment_intent();
+    let key = get_step_up_redis_key(&payment_intent.merchant_id, &payment_intent.payment_id);
+    let result = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn
+            .set_key_with_expiry(
+                &key.as_str().into(),
+                true,
+                consts::STEP_UP_AUTHENTICATION_TTL_IN_SECS,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError),
+        Err(error) => Err(error).change_context(errors::ApiErrorResponse::InternalServerError),
+    };
+    if let Err(error) = result {
+        logger::error!(?error, "Failed to mark the payment as stepped up");
+    }
+
+    Ok(Some(enums::AuthenticationType::ThreeDs))
+}
+
+/// Whether the payment has been stepped up by the step-up rules of its profile when it was
+/// confirmed, in which case its external 3DS authentication forces a challenge
+pub async fn is_stepped_up(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> bool {
+    let result = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn
+            .exists::<()>(
+                &get_step_up_redis_key(merchant_id, payment_id)
+                    .as_str()
+                    .into(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError),
+        Err(error) => Err(error).change_context(errors::ApiErrorResponse::InternalServerError),
+    };
+
+    result
+        .inspect_err(|error| logger::error!(?error, "Failed to check the step up of the payment"))
+        .unwrap_or(false)
+}
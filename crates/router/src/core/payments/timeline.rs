@@ -0,0 +1,286 @@
+//! Timeline of the events that occurred in the lifecycle of a payment. The events are appended to
+//! a list in redis keyed by the payment id, so that the flow of a payment can be traced without
+//! correlating the logs of every request made for it.
+
+use api_models::payments::{
+    PaymentTimelineEvent, PaymentTimelineEventType, PaymentTimelineResponse,
+};
+use common_utils::{ext_traits::StringExt, id_type};
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use tracing_futures::Instrument;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, StorageErrorExt},
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services,
+    types::{self, api, domain, storage, storage::enums as storage_enums},
+};
+
+fn get_payment_timeline_redis_key(
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> String {
+    format!(
+        "{}_{}_payment_timeline",
+        merchant_id.get_string_repr(),
+        payment_id.get_string_repr()
+    )
+}
+
+/// Returns the name of the flow without the module path, e.g. `Authorize`
+fn get_flow_name<F>() -> &'static str {
+    let type_name = std::any::type_name::<F>();
+    type_name.rsplit("::").next().unwrap_or(type_name)
+}
+
+/// Appends an event to the timeline of the payment. The event is written to redis in the
+/// background, so that the payment neither waits for redis nor fails with it, and the failures are
+/// only logged.
+#[instrument(skip_all)]
+pub fn record_event(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+    attempt_id: Option<&str>,
+    event_type: PaymentTimelineEventType,
+    connector: Option<&str>,
+    details: Option<serde_json::Value>,
+) {
+    let event = PaymentTimelineEvent {
+        event_type,
+        created_at: common_utils::date_time::now(),
+        attempt_id: attempt_id.map(ToOwned::to_owned),
+        connector: connector.map(ToOwned::to_owned),
+        details,
+    };
+
+    let serialized_event = match serde_json::to_string(&event) {
+        Ok(serialized_event) => serialized_event,
+        Err(error) => {
+            logger::error!(?error, "Failed to serialize payment timeline event");
+            return;
+        }
+    };
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection");
+            return;
+        }
+    };
+
+    let key = get_payment_timeline_redis_key(merchant_id, payment_id);
+    tokio::spawn(
+        async move {
+            let result = redis_conn
+                .append_elements_to_list(&key.as_str().into(), serialized_event)
+                .await;
+
+            if let Err(error) = result {
+                logger::error!(?error, "Failed to append event to the payment timeline");
+                return;
+            }
+
+            if let Err(error) = redis_conn
+                .set_expiry(
+                    &key.as_str().into(),
+                    consts::PAYMENT_TIMELINE_EXPIRY_SECONDS,
+                )
+                .await
+            {
+                logger::error!(?error, "Failed to set expiry for the payment timeline");
+            }
+        }
+        .in_current_span(),
+    );
+}
+
+pub fn record_routing_decision(
+    state: &SessionState,
+    payment_attempt: &storage::PaymentAttempt,
+    connector_call_type: &api::ConnectorCallType,
+) {
+    let (call_type, connectors) = match connector_call_type {
+        api::ConnectorCallType::PreDetermined(connector_data) => (
+            "pre_determined",
+            vec![connector_data.connector_name.to_string()],
+        ),
+        api::ConnectorCallType::Retryable(connectors) => (
+            "retryable",
+            connectors
+                .iter()
+                .map(|connector_data| connector_data.connector_name.to_string())
+                .collect(),
+        ),
+        api::ConnectorCallType::SessionMultiple(connectors) => (
+            "session_multiple",
+            connectors
+                .iter()
+                .map(|session_connector_data| {
+                    session_connector_data.connector.connector_name.to_string()
+                })
+                .collect(),
+        ),
+    };
+
+    record_event(
+        state,
+        &payment_attempt.merchant_id,
+        &payment_attempt.payment_id,
+        Some(&payment_attempt.attempt_id),
+        PaymentTimelineEventType::RoutingDecision,
+        connectors.first().map(String::as_str),
+        Some(serde_json::json!({
+            "call_type": call_type,
+            "connectors": connectors,
+        })),
+    );
+}
+
+pub fn record_connector_call_started<F>(
+    state: &SessionState,
+    payment_attempt: &storage::PaymentAttempt,
+    connector: &api::ConnectorData,
+) {
+    record_event(
+        state,
+        &payment_attempt.merchant_id,
+        &payment_attempt.payment_id,
+        Some(&payment_attempt.attempt_id),
+        PaymentTimelineEventType::ConnectorCallStarted,
+        Some(&connector.connector_name.to_string()),
+        Some(serde_json::json!({
+            "flow": get_flow_name::<F>(),
+            "merchant_connector_id": connector.merchant_connector_id,
+        })),
+    );
+}
+
+pub fn record_connector_call_ended<F>(
+    state: &SessionState,
+    payment_attempt: &storage::PaymentAttempt,
+    connector: &api::ConnectorData,
+    status: storage_enums::AttemptStatus,
+    error_response: Option<&types::ErrorResponse>,
+    latency_ms: u128,
+) {
+    record_event(
+        state,
+        &payment_attempt.merchant_id,
+        &payment_attempt.payment_id,
+        Some(&payment_attempt.attempt_id),
+        PaymentTimelineEventType::ConnectorCallEnded,
+        Some(&connector.connector_name.to_string()),
+        Some(serde_json::json!({
+            "flow": get_flow_name::<F>(),
+            "status": status,
+            "latency_ms": latency_ms,
+            "error_code": error_response.map(|error| error.code.as_str()),
+            "error_message": error_response.map(|error| error.message.as_str()),
+        })),
+    );
+}
+
+pub fn record_status_transition(
+    state: &SessionState,
+    payment_attempt: &storage::PaymentAttempt,
+    previous_status: storage_enums::IntentStatus,
+    current_status: storage_enums::IntentStatus,
+) {
+    if previous_status == current_status {
+        return;
+    }
+
+    record_event(
+        state,
+        &payment_attempt.merchant_id,
+        &payment_attempt.payment_id,
+        Some(&payment_attempt.attempt_id),
+        PaymentTimelineEventType::StatusTransition,
+        payment_attempt.connector.as_deref(),
+        Some(serde_json::json!({
+            "previous_status": previous_status,
+            "current_status": current_status,
+            "attempt_status": payment_attempt.status,
+        })),
+    );
+}
+
+#[instrument(skip_all)]
+pub async fn get_payment_timeline(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    payment_id: id_type::PaymentId,
+) -> RouterResponse<PaymentTimelineResponse> {
+    let db = state.store.as_ref();
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &payment_intent)?;
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let key = get_payment_timeline_redis_key(merchant_account.get_id(), &payment_id);
+    let serialized_events = redis_conn
+        .get_list_elements(
+            &key.as_str().into(),
+            0,
+            consts::MAX_PAYMENT_TIMELINE_EVENTS - 1,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payment timeline from redis")?;
+
+    let mut events = serialized_events
+        .into_iter()
+        .filter_map(|serialized_event| {
+            serialized_event
+                .parse_struct::<PaymentTimelineEvent>("PaymentTimelineEvent")
+                .map_err(|error| {
+                    logger::error!(?error, "Failed to deserialize payment timeline event");
+                })
+                .ok()
+        })
+        .collect::<Vec<_>>();
+
+    // Events recorded by concurrent requests may be appended out of order
+    events.sort_by_key(|event| event.created_at);
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentTimelineResponse {
+            payment_id: payment_intent.payment_id,
+            events,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_flow_name() {
+        assert_eq!(get_flow_name::<api::Authorize>(), "Authorize");
+        assert_eq!(get_flow_name::<api::PSync>(), "PSync");
+    }
+}
@@ -23,6 +23,11 @@ use masking::{ExposeInterface, Secret};
 use router_env::{instrument, tracing};
 
 use super::helpers;
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+use crate::core::payment_methods::deferred_vaulting;
 use crate::{
     consts,
     core::{
@@ -40,7 +45,7 @@ use crate::{
         domain,
         storage::enums as storage_enums,
     },
-    utils::{generate_id, OptionExt},
+    utils::OptionExt,
 };
 
 pub struct SavePaymentMethodData<Req> {
@@ -158,10 +163,13 @@ where
                 .get_setup_mandate_details()
                 .and_then(|mandate_data| mandate_data.customer_acceptance.clone());
 
-            let customer_acceptance = save_payment_method_data
+            let customer_acceptance_details = save_payment_method_data
                 .request
                 .get_customer_acceptance()
-                .or(mandate_data_customer_acceptance.clone().map(From::from))
+                .or(mandate_data_customer_acceptance.clone().map(From::from));
+
+            let customer_acceptance = customer_acceptance_details
+                .as_ref()
                 .map(|ca| ca.encode_to_value())
                 .transpose()
                 .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -198,8 +206,16 @@ where
                     .await?;
                 let customer_id = customer_id.to_owned().get_required_value("customer_id")?;
                 let merchant_id = merchant_account.get_id();
-                let is_network_tokenization_enabled =
-                    business_profile.is_network_tokenization_enabled;
+                let is_network_tokenization_enabled = business_profile
+                    .is_network_tokenization_enabled
+                    && crate::core::feature_flags::is_feature_enabled(
+                        state,
+                        merchant_id,
+                        Some(business_profile.get_id()),
+                        storage_enums::MerchantFeatureFlag::NetworkTokenization,
+                    )
+                    .await;
+                let mut deferred_vaulting_lookup_key = None;
                 let (
                     (mut resp, duplication_check, network_token_requestor_ref_id),
                     network_token_resp,
@@ -214,15 +230,50 @@ where
                     pm_status = Some(common_enums::PaymentMethodStatus::from(
                         save_payment_method_data.attempt_status,
                     ));
-                    let (res, dc) = Box::pin(save_in_locker(
+                    let pm_data = &save_payment_method_data.request.get_payment_method_data();
+                    let save_in_locker_result = Box::pin(save_in_locker(
                         state,
                         merchant_account,
+                        key_store,
+                        business_profile,
                         payment_method_create_request.to_owned(),
                     ))
-                    .await?;
+                    .await;
+
+                    let (res, dc) = match save_in_locker_result {
+                        Ok(res) => res,
+                        Err(error)
+                            if deferred_vaulting::is_vaulting_deferrable(
+                                state.conf.locker.deferred_vaulting_enabled,
+                                pm_data,
+                            ) =>
+                        {
+                            logger::error!(
+                                ?error,
+                                "Failed to save card in locker, deferring the vaulting of the card"
+                            );
+                            deferred_vaulting_lookup_key = Some(
+                                deferred_vaulting::store_card_for_deferred_vaulting(
+                                    state,
+                                    pm_data,
+                                    &customer_id,
+                                    key_store,
+                                )
+                                .await?,
+                            );
+                            pm_status = Some(common_enums::PaymentMethodStatus::AwaitingData);
+                            skip_saving_card_in_locker(
+                                merchant_account,
+                                payment_method_create_request.to_owned(),
+                            )
+                            .await?
+                        }
+                        Err(error) => return Err(error),
+                    };
 
-                    if is_network_tokenization_enabled {
-                        let pm_data = &save_payment_method_data.request.get_payment_method_data();
+                    if deferred_vaulting_lookup_key.is_some() {
+                        ((res, dc, None), None)
+                    } else if is_network_tokenization_enabled {
                         match pm_data {
                             domain::PaymentMethodData::Card(card) => {
                                 let (
@@ -349,7 +400,10 @@ where
                                             }
                                             Err(_) => {
                                                 payment_method_id =
-                                                    generate_id(consts::ID_LENGTH, "pm")
+                                                    common_utils::generate_resource_id(
+                                                        consts::ID_LENGTH,
+                                                        "pm",
+                                                    )
                                             }
                                         };
                                         existing_pm_by_locker_id
@@ -451,7 +505,10 @@ where
                                                 }
                                                 Err(_) => {
                                                     payment_method_id =
-                                                        generate_id(consts::ID_LENGTH, "pm")
+                                                        common_utils::generate_resource_id(
+                                                            consts::ID_LENGTH,
+                                                            "pm",
+                                                        )
                                                 }
                                             };
                                             existing_pm_by_locker_id
@@ -714,14 +771,17 @@ where
                                 create_payment_method_metadata(None, connector_token)?;
 
                             locker_id = resp.payment_method.and_then(|pm| {
-                                if pm == PaymentMethod::Card {
+                                if pm == PaymentMethod::Card
+                                    && deferred_vaulting_lookup_key.is_none()
+                                {
                                     Some(resp.payment_method_id)
                                 } else {
                                     None
                                 }
                             });
 
-                            resp.payment_method_id = generate_id(consts::ID_LENGTH, "pm");
+                            resp.payment_method_id =
+                                common_utils::generate_resource_id(consts::ID_LENGTH, "pm");
                             payment_methods::cards::create_payment_method(
                                 state,
                                 &payment_method_create_request,
@@ -751,6 +811,33 @@ where
                     }
                 }
 
+                if let Some(lookup_key) = deferred_vaulting_lookup_key {
+                    let tracking_data = types::storage::DeferredVaultingTrackingData {
+                        payment_method_id: resp.payment_method_id.clone(),
+                        merchant_id: merchant_id.to_owned(),
+                        customer_id: customer_id.clone(),
+                        profile_id: business_profile.get_id().clone(),
+                        lookup_key,
+                    };
+                    Box::pin(deferred_vaulting::schedule_deferred_vaulting(
+                        state,
+                        merchant_account,
+                        key_store,
+                        tracking_data,
+                    ))
+                    .await?;
+                }
+
+                if let Some(customer_acceptance) = customer_acceptance_details {
+                    payment_methods::consent::record_payment_method_consent(
+                        state,
+                        merchant_id,
+                        &resp.payment_method_id,
+                        customer_acceptance,
+                    )
+                    .await;
+                }
+
                 Some(resp.payment_method_id)
             } else {
                 None
@@ -833,7 +920,7 @@ async fn skip_saving_card_in_locker(
         .customer_id
         .clone()
         .get_required_value("customer_id")?;
-    let payment_method_id = common_utils::generate_id(consts::ID_LENGTH, "pm");
+    let payment_method_id = common_utils::generate_resource_id(consts::ID_LENGTH, "pm");
 
     let last4_digits = payment_method_request
         .card
@@ -880,12 +967,13 @@ async fn skip_saving_card_in_locker(
                 bank_transfer: None,
                 last_used_at: Some(common_utils::date_time::now()),
                 client_secret: None,
+                consent: None,
             };
 
             Ok((pm_resp, None))
         }
         None => {
-            let pm_id = common_utils::generate_id(consts::ID_LENGTH, "pm");
+            let pm_id = common_utils::generate_resource_id(consts::ID_LENGTH, "pm");
             let payment_method_response = api::PaymentMethodResponse {
                 merchant_id: merchant_id.to_owned(),
                 customer_id: Some(customer_id),
@@ -902,6 +990,7 @@ async fn skip_saving_card_in_locker(
                 bank_transfer: None,
                 last_used_at: Some(common_utils::date_time::now()),
                 client_secret: None,
+                consent: None,
             };
             Ok((payment_method_response, None))
         }
@@ -926,6 +1015,8 @@ async fn skip_saving_card_in_locker(
 pub async fn save_in_locker(
     state: &SessionState,
     merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
     payment_method_request: api::PaymentMethodCreate,
 ) -> RouterResult<(
     api_models::payment_methods::PaymentMethodResponse,
@@ -938,19 +1029,44 @@ pub async fn save_in_locker(
         .clone()
         .get_required_value("customer_id")?;
     match payment_method_request.card.clone() {
-        Some(card) => Box::pin(payment_methods::cards::add_card_to_locker(
-            state,
-            payment_method_request,
-            &card,
-            &customer_id,
-            merchant_account,
-            None,
-        ))
-        .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Add Card Failed"),
+        Some(card) => {
+            // Cards of the profiles which bring their own vault are stored only in that vault
+            let external_vault_connector =
+                payment_methods::external_vault::get_external_vault_connector(
+                    state,
+                    key_store,
+                    business_profile.get_id(),
+                )
+                .await?;
+
+            match external_vault_connector {
+                Some(external_vault_connector) => external_vault_connector
+                    .add_card(
+                        state,
+                        payment_method_request,
+                        &card,
+                        &customer_id,
+                        merchant_id,
+                        None,
+                    )
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Add Card to external vault Failed"),
+                None => Box::pin(payment_methods::cards::add_card_to_locker(
+                    state,
+                    payment_method_request,
+                    &card,
+                    &customer_id,
+                    merchant_account,
+                    None,
+                ))
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Add Card Failed"),
+            }
+        }
         None => {
-            let pm_id = common_utils::generate_id(consts::ID_LENGTH, "pm");
+            let pm_id = common_utils::generate_resource_id(consts::ID_LENGTH, "pm");
             let payment_method_response = api::PaymentMethodResponse {
                 merchant_id: merchant_id.clone(),
                 customer_id: Some(customer_id),
@@ -967,6 +1083,7 @@ pub async fn save_in_locker(
                 payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]), //[#219]
                 last_used_at: Some(common_utils::date_time::now()),
                 client_secret: None,
+                consent: None,
             };
             Ok((payment_method_response, None))
         }
@@ -977,6 +1094,8 @@ pub async fn save_in_locker(
 pub async fn save_in_locker(
     _state: &SessionState,
     _merchant_account: &domain::MerchantAccount,
+    _key_store: &domain::MerchantKeyStore,
+    _business_profile: &domain::Profile,
     _payment_method_request: api::PaymentMethodCreate,
 ) -> RouterResult<(
     api_models::payment_methods::PaymentMethodResponse,
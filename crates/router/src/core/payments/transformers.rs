@@ -172,6 +172,7 @@ where
         connector_mandate_request_reference_id,
         authentication_id: None,
         psd2_sca_exemption_type: None,
+        profile_id: payment_data.payment_intent.profile_id.clone(),
     };
     Ok(router_data)
 }
@@ -308,6 +309,8 @@ pub async fn construct_payment_router_data_for_authorize<'a>(
         additional_payment_method_data: None,
         merchant_account_id: None,
         merchant_config_currency: None,
+        payment_facilitator_details: None,
+        connector_idempotency_key: None,
     };
     let connector_mandate_request_reference_id = payment_data
         .payment_attempt
@@ -385,6 +388,7 @@ pub async fn construct_payment_router_data_for_authorize<'a>(
         connector_mandate_request_reference_id,
         authentication_id: None,
         psd2_sca_exemption_type: None,
+        profile_id: Some(merchant_connector_account.profile_id.clone()),
     };
 
     Ok(router_data)
@@ -547,6 +551,7 @@ pub async fn construct_payment_router_data_for_capture<'a>(
         header_payload,
         connector_mandate_request_reference_id,
         psd2_sca_exemption_type: None,
+        profile_id: Some(merchant_connector_account.profile_id.clone()),
         authentication_id: None,
     };
 
@@ -676,6 +681,7 @@ pub async fn construct_router_data_for_psync<'a>(
         connector_mandate_request_reference_id: None,
         authentication_id: None,
         psd2_sca_exemption_type: None,
+        profile_id: Some(merchant_connector_account.profile_id.clone()),
     };
 
     Ok(router_data)
@@ -836,6 +842,7 @@ pub async fn construct_payment_router_data_for_sdk_session<'a>(
         header_payload,
         connector_mandate_request_reference_id: None,
         psd2_sca_exemption_type: None,
+        profile_id: Some(merchant_connector_account.profile_id.clone()),
         authentication_id: None,
     };
 
@@ -1038,6 +1045,7 @@ pub async fn construct_payment_router_data_for_setup_mandate<'a>(
         connector_mandate_request_reference_id,
         authentication_id: None,
         psd2_sca_exemption_type: None,
+        profile_id: Some(merchant_connector_account.profile_id.clone()),
     };
 
     Ok(router_data)
@@ -1237,6 +1245,7 @@ where
         connector_mandate_request_reference_id,
         authentication_id: None,
         psd2_sca_exemption_type: payment_data.payment_intent.psd2_sca_exemption_type,
+        profile_id: payment_data.payment_intent.profile_id.clone(),
     };
 
     Ok(router_data)
@@ -2044,6 +2053,12 @@ where
     let payment_attempt = payment_data.get_payment_attempt().clone();
     let payment_intent = payment_data.get_payment_intent().clone();
     let payment_link_data = payment_data.get_payment_link_data();
+    let amount_remaining = payments::partial_payments::get_amount_remaining(&payment_intent);
+    let custom_status = payment_data
+        .get_custom_status_mapping()
+        .and_then(|mapping| {
+            mapping.get_custom_status(payment_intent.status, payment_attempt.status)
+        });
 
     let currency = payment_attempt
         .currency
@@ -2415,6 +2430,7 @@ where
                         ip_address: d.ip_address,
                         user_agent: d.user_agent,
                     }),
+                    consent_text_version: d.consent_text_version,
                 }),
             mandate_type: d.mandate_type.clone().map(|d| match d {
                 hyperswitch_domain_models::mandates::MandateDataType::MultiUse(Some(i)) => {
@@ -2473,6 +2489,11 @@ where
             .get_connector_payment_id()
             .map(ToString::to_string);
 
+        let consistency_token = payments::consistency::generate_consistency_token(
+            &payment_intent.payment_id,
+            payment_intent.modified_at,
+        );
+
         let payments_response = api::PaymentsResponse {
             payment_id: payment_intent.payment_id,
             merchant_id: payment_intent.merchant_id,
@@ -2586,6 +2607,22 @@ where
             card_discovery: payment_attempt.card_discovery,
             issuer_error_code: payment_attempt.issuer_error_code,
             issuer_error_message: payment_attempt.issuer_error_message,
+            custom_status,
+            localized_unified_message: payment_data.get_localized_unified_message().cloned(),
+            test_mode: payment_intent.is_test_payment,
+            connector_response_details: payment_data.get_connector_response_details().cloned(),
+            avs_result: payment_attempt.avs_result,
+            cvv_result: payment_attempt.cvv_result,
+            acquirer_reference_number: payment_attempt.acquirer_reference_number,
+            receipt_url: payment_data.get_receipt_url().cloned(),
+            address_validation: payment_data.get_address_validation().cloned(),
+            consistency_token,
+            scheduled_capture_at: payment_data.get_scheduled_capture_at(),
+            is_amount_estimated: payment_intent.is_amount_estimated,
+            custom_fields: payment_data.get_custom_fields().cloned(),
+            enable_partial_payments: payment_intent.enable_partial_payments,
+            amount_remaining,
+            store_credit_amount: payment_intent.store_credit_amount,
         };
 
         services::ApplicationResponse::JsonWithHeaders((payments_response, headers))
@@ -2749,6 +2786,7 @@ pub fn construct_connector_invoke_hidden_frame(
 impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::PaymentsResponse {
     fn foreign_from((pi, pa): (storage::PaymentIntent, storage::PaymentAttempt)) -> Self {
         let connector_transaction_id = pa.get_connector_payment_id().map(ToString::to_string);
+        let amount_remaining = payments::partial_payments::get_amount_remaining(&pi);
         Self {
             payment_id: pi.payment_id,
             merchant_id: pi.merchant_id,
@@ -2876,6 +2914,22 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
             card_discovery: pa.card_discovery,
             issuer_error_code: pa.issuer_error_code,
             issuer_error_message: pa.issuer_error_message,
+            custom_status: None,
+            localized_unified_message: None,
+            test_mode: pi.is_test_payment,
+            connector_response_details: None,
+            avs_result: pa.avs_result,
+            cvv_result: pa.cvv_result,
+            acquirer_reference_number: pa.acquirer_reference_number,
+            receipt_url: None,
+            address_validation: None,
+            consistency_token: None,
+            scheduled_capture_at: None,
+            is_amount_estimated: pi.is_amount_estimated,
+            custom_fields: None,
+            enable_partial_payments: pi.enable_partial_payments,
+            amount_remaining,
+            store_credit_amount: pi.store_credit_amount,
         }
     }
 }
@@ -3270,7 +3324,8 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
                 .authentication
                 .as_ref()
                 .map(AuthenticationData::foreign_try_from)
-                .transpose()?,
+                .transpose()?
+                .or(payment_data.external_authentication_data),
             customer_acceptance: payment_data.customer_acceptance,
             request_extended_authorization: attempt.request_extended_authorization,
             split_payments,
@@ -3280,6 +3335,8 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
             shipping_cost,
             merchant_account_id,
             merchant_config_currency,
+            payment_facilitator_details: payment_data.payment_facilitator_details,
+            connector_idempotency_key: attempt.connector_idempotency_key.clone(),
         })
     }
 }
@@ -3594,7 +3651,15 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsCancelDa
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
                 field_name: "browser_info",
             })?;
-        let amount = payment_data.payment_attempt.get_total_amount();
+        // Only the uncaptured remainder of the authorization is released for the partially captured
+        // payments
+        let amount = if payment_data.payment_intent.status
+            == enums::IntentStatus::PartiallyCapturedAndCapturable
+        {
+            payment_data.payment_attempt.amount_capturable
+        } else {
+            payment_data.payment_attempt.get_total_amount()
+        };
 
         let router_base_url = &additional_data.router_base_url;
         let attempt = &payment_data.payment_attempt;
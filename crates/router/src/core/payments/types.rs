@@ -11,6 +11,7 @@ use hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt;
 pub use hyperswitch_domain_models::router_request_types::{
     AuthenticationData, SplitRefundsRequest, StripeSplitRefund, SurchargeDetails,
 };
+use masking::ExposeInterface;
 use redis_interface::errors::RedisError;
 use router_env::{instrument, logger, tracing};
 
@@ -21,7 +22,7 @@ use crate::{
     types::{
         domain::Profile,
         storage::{self, enums as storage_enums},
-        transformers::ForeignTryFrom,
+        transformers::{ForeignFrom, ForeignTryFrom},
     },
 };
 
@@ -391,3 +392,15 @@ impl ForeignTryFrom<&storage::Authentication> for AuthenticationData {
         }
     }
 }
+
+impl ForeignFrom<api_models::payments::ExternalThreeDsData> for AuthenticationData {
+    fn foreign_from(three_ds_data: api_models::payments::ExternalThreeDsData) -> Self {
+        Self {
+            eci: three_ds_data.eci,
+            cavv: three_ds_data.cavv.expose(),
+            threeds_server_transaction_id: three_ds_data.threeds_server_transaction_id,
+            message_version: three_ds_data.message_version,
+            ds_trans_id: three_ds_data.ds_trans_id,
+        }
+    }
+}
@@ -0,0 +1,268 @@
+//! Payer facing messages of the unified errors of the payments. The messages are looked up by the
+//! unified code of the error in the locales requested in the `Accept-Language` header, first in the
+//! overrides of the profile and then in the catalog of translations, so that the SDKs can show the
+//! same translated message for a decline regardless of the connector which processed the payment.
+
+use api_models::payments::UnifiedErrorMessageOverrides;
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        payments::{OperationSessionGetters, OperationSessionSetters},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::domain,
+};
+
+#[instrument(skip_all)]
+pub async fn upsert_unified_error_message_overrides(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: UnifiedErrorMessageOverrides,
+) -> RouterResponse<UnifiedErrorMessageOverrides> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    request.validate().map_err(|error| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: error.current_context().to_string(),
+        })
+    })?;
+
+    let key = profile_id.get_unified_error_message_overrides_key();
+    let serialized_overrides = request
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize unified error message overrides")?;
+
+    // The cache holds empty overrides for the profiles without any, so the existence of the
+    // overrides is checked in the database
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => {
+            db.update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(serialized_overrides),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update unified error message overrides")?;
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            db.insert_config(configs::ConfigNew {
+                key,
+                config: serialized_overrides,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert unified error message overrides")?;
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch unified error message overrides");
+        }
+    }
+
+    Ok(services::ApplicationResponse::Json(request))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_unified_error_message_overrides(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<UnifiedErrorMessageOverrides> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    let overrides = get_unified_error_message_overrides(db, &profile_id)
+        .await?
+        .ok_or(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Unified error message overrides not found for the profile".to_string(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(overrides))
+}
+
+#[instrument(skip_all)]
+pub async fn delete_unified_error_message_overrides(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<()> {
+    let db = state.store.as_ref();
+    core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?;
+
+    db.delete_config_by_key(&profile_id.get_unified_error_message_overrides_key())
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::ApiErrorResponse::GenericNotFoundError {
+                    message: "Unified error message overrides not found for the profile"
+                        .to_string(),
+                })
+            } else {
+                error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to delete unified error message overrides")
+            }
+        })?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+/// Fetches the unified error message overrides of the profile. This is looked up for every failed
+/// payment, so the absence of the overrides is cached as well.
+pub async fn get_unified_error_message_overrides(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Option<UnifiedErrorMessageOverrides>> {
+    let overrides: UnifiedErrorMessageOverrides = db
+        .find_config_by_key_unwrap_or(
+            &profile_id.get_unified_error_message_overrides_key(),
+            Some("{}".to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch unified error message overrides")?
+        .config
+        .parse_struct("UnifiedErrorMessageOverrides")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse unified error message overrides")?;
+
+    Ok((!overrides.is_empty()).then_some(overrides))
+}
+
+/// Locales of the `Accept-Language` header in the order of preference, each followed by its
+/// language, so that `fr-CA, en;q=0.8` falls back from `fr-CA` to `fr` before `en`
+pub(crate) fn get_locale_candidates(accept_language: &str) -> Vec<String> {
+    let mut locales = Vec::new();
+    for locale in accept_language
+        .split(',')
+        .filter_map(|language_range| language_range.split(';').next())
+        .map(str::trim)
+        .filter(|locale| !locale.is_empty() && *locale != "*")
+    {
+        let language = locale
+            .split(['-', '_'])
+            .next()
+            .unwrap_or(locale)
+            .to_string();
+        for candidate in [locale.to_string(), language] {
+            if !locales.contains(&candidate) {
+                locales.push(candidate);
+            }
+        }
+    }
+    locales
+}
+
+/// Payer facing message of the unified error in the requested locales. The overrides of the profile
+/// take precedence over the catalog, and no message is returned if neither has one, as the unified
+/// message of the attempt is already sent in the response.
+#[instrument(skip_all)]
+pub async fn get_localized_unified_message(
+    state: &SessionState,
+    profile_id: Option<&id_type::ProfileId>,
+    unified_code: &str,
+    accept_language: &str,
+) -> RouterResult<Option<String>> {
+    let locales = get_locale_candidates(accept_language);
+
+    if let Some(profile_id) = profile_id {
+        let message = get_unified_error_message_overrides(state.store.as_ref(), profile_id)
+            .await?
+            .and_then(|overrides| {
+                overrides.get_message(unified_code, locales.iter().map(String::as_str))
+            });
+        if message.is_some() {
+            return Ok(message);
+        }
+    }
+
+    for locale in locales {
+        match state
+            .store
+            .find_translation_by_unified_code_locale(unified_code.to_string(), locale.clone())
+            .await
+        {
+            Ok(translation) => return Ok(Some(translation)),
+            Err(error) if error.current_context().is_db_not_found() => {}
+            Err(error) => {
+                // The message is only a hint for the payer, so the payment is not failed for it
+                logger::warn!(
+                    ?error,
+                    unified_code,
+                    locale,
+                    "Failed to fetch translation of the unified error"
+                );
+                return Ok(None);
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// Sets the payer facing message of the unified error of the payment, if the latest attempt of the
+/// payment failed with one
+pub async fn populate_localized_unified_message<F, D>(
+    state: &SessionState,
+    payment_data: &mut D,
+) -> RouterResult<()>
+where
+    F: Clone,
+    D: OperationSessionGetters<F> + OperationSessionSetters<F>,
+{
+    let Some(unified_code) = payment_data.get_payment_attempt().unified_code.clone() else {
+        return Ok(());
+    };
+
+    let localized_unified_message = get_localized_unified_message(
+        state,
+        payment_data.get_payment_intent().profile_id.as_ref(),
+        &unified_code,
+        &state.locale,
+    )
+    .await?;
+    payment_data.set_localized_unified_message(localized_unified_message);
+
+    Ok(())
+}
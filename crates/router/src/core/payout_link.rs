@@ -15,6 +15,8 @@ use error_stack::ResultExt;
 use hyperswitch_domain_models::api::{GenericLinks, GenericLinksData};
 
 use super::errors::{RouterResponse, StorageErrorExt};
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+use crate::core::data_residency;
 use crate::{
     configs::settings::{PaymentMethodFilterKey, PaymentMethodFilters},
     core::{
@@ -140,7 +142,8 @@ pub async fn initiate_payout_link(
                 .convert(payout.amount, payout.destination_currency)
                 .change_context(errors::ApiErrorResponse::CurrencyConversionFailed)?;
             // Fetch customer
-            let customer = db
+            let pii_db = data_residency::get_pii_store(&state, &req.merchant_id).await?;
+            let customer = pii_db
                 .find_customer_by_customer_id_merchant_id(
                     &(&state).into(),
                     &customer_id,
@@ -162,7 +165,8 @@ pub async fn initiate_payout_link(
                 .address_id
                 .as_ref()
                 .async_map(|address_id| async {
-                    db.find_address_by_address_id(&(&state).into(), address_id, &key_store)
+                    pii_db
+                        .find_address_by_address_id(&(&state).into(), address_id, &key_store)
                         .await
                 })
                 .await
@@ -38,6 +38,8 @@ use scheduler::utils as pt_utils;
 use serde_json;
 use time::Duration;
 
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+use crate::core::data_residency;
 #[cfg(feature = "olap")]
 use crate::types::domain::behaviour::Conversion;
 #[cfg(feature = "olap")]
@@ -324,6 +326,9 @@ pub async fn payouts_create_core(
     key_store: domain::MerchantKeyStore,
     req: payouts::PayoutCreateRequest,
 ) -> RouterResponse<payouts::PayoutCreateResponse> {
+    #[cfg(feature = "v1")]
+    crate::core::merchant_lifecycle::validate_merchant_can_charge(&merchant_account)?;
+
     // Validate create request
     let (payout_id, payout_method_data, profile_id, customer, payment_method) =
         validator::validate_create_request(&state, &merchant_account, &req, &key_store).await?;
@@ -367,7 +372,13 @@ pub async fn payouts_create_core(
         .await?;
     }
 
-    if let Some(true) = payout_data.payouts.confirm {
+    #[cfg(feature = "v1")]
+    crate::core::sanctions_screening::screen_payout(&state, &merchant_account, &mut payout_data)
+        .await?;
+
+    // The payout is held until its sanctions screening case is reviewed
+    let is_on_hold = payout_data.payouts.status == storage_enums::PayoutStatus::OnHold;
+    if payout_data.payouts.confirm == Some(true) && !is_on_hold {
         payouts_core(
             &state,
             &merchant_account,
@@ -411,6 +422,7 @@ pub async fn payouts_confirm_core(
             storage_enums::PayoutStatus::Ineligible,
             storage_enums::PayoutStatus::RequiresFulfillment,
             storage_enums::PayoutStatus::RequiresVendorAccountCreation,
+            storage_enums::PayoutStatus::OnHold,
         ],
         "confirm",
     )?;
@@ -793,6 +805,7 @@ pub async fn payouts_list_core(
     validator::validate_payout_list_request(&constraints)?;
     let merchant_id = merchant_account.get_id();
     let db = state.store.as_ref();
+    let pii_db = data_residency::get_pii_store(&state, merchant_id).await?;
     let payouts = helpers::filter_by_constraints(
         db,
         &constraints,
@@ -818,7 +831,7 @@ pub async fn payouts_list_core(
             Ok(payout_attempt) => {
                 let domain_customer = match payout.customer_id.clone() {
                     #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
-                    Some(customer_id) => db
+                    Some(customer_id) => pii_db
                         .find_customer_by_customer_id_merchant_id(
                             &(&state).into(),
                             &customer_id,
@@ -854,6 +867,7 @@ pub async fn payouts_list_core(
                     &key_store,
                     &payout_id_as_payment_id_type,
                     merchant_account.storage_scheme,
+                    None,
                 )
                 .await
                 .transpose()
@@ -1270,18 +1284,20 @@ pub async fn create_recipient(
                         {
                             let customer_id = customer.customer_id.to_owned();
                             payout_data.customer_details = Some(
-                                db.update_customer_by_customer_id_merchant_id(
-                                    &state.into(),
-                                    customer_id,
-                                    merchant_account.get_id().to_owned(),
-                                    customer,
-                                    updated_customer,
-                                    key_store,
-                                    merchant_account.storage_scheme,
-                                )
-                                .await
-                                .change_context(errors::ApiErrorResponse::InternalServerError)
-                                .attach_printable("Error updating customers in db")?,
+                                data_residency::get_pii_store(state, merchant_account.get_id())
+                                    .await?
+                                    .update_customer_by_customer_id_merchant_id(
+                                        &state.into(),
+                                        customer_id,
+                                        merchant_account.get_id().to_owned(),
+                                        customer,
+                                        updated_customer,
+                                        key_store,
+                                        merchant_account.storage_scheme,
+                                    )
+                                    .await
+                                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                                    .attach_printable("Error updating customers in db")?,
                             );
                         }
 
@@ -2704,6 +2720,7 @@ pub async fn payout_create_db_entries(
         key_store,
         &payout_id_as_payment_id_type,
         merchant_account.storage_scheme,
+        None,
     )
     .await?;
     let address_id = billing_address.to_owned().map(|address| address.address_id);
@@ -2919,28 +2936,31 @@ pub async fn make_payout_data(
         key_store,
         &payout_id_as_payment_id_type,
         merchant_account.storage_scheme,
+        None,
     )
     .await?;
 
     let payout_id = &payouts.payout_id;
 
+    let pii_db = data_residency::get_pii_store(state, merchant_id).await?;
     let customer_details = customer_id
         .async_map(|customer_id| async move {
-            db.find_customer_optional_by_customer_id_merchant_id(
-                &state.into(),
-                customer_id,
-                merchant_id,
-                key_store,
-                merchant_account.storage_scheme,
-            )
-            .await
-            .map_err(|err| err.change_context(errors::ApiErrorResponse::InternalServerError))
-            .attach_printable_lazy(|| {
-                format!(
-                    "Failed while fetching optional customer [id - {:?}] for payout [id - {}]",
-                    customer_id, payout_id
+            pii_db
+                .find_customer_optional_by_customer_id_merchant_id(
+                    &state.into(),
+                    customer_id,
+                    merchant_id,
+                    key_store,
+                    merchant_account.storage_scheme,
                 )
-            })
+                .await
+                .map_err(|err| err.change_context(errors::ApiErrorResponse::InternalServerError))
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Failed while fetching optional customer [id - {:?}] for payout [id - {}]",
+                        customer_id, payout_id
+                    )
+                })
         })
         .await
         .transpose()?
@@ -3051,6 +3071,34 @@ pub async fn make_payout_data(
         );
     }
 
+    // The bank accounts vaulted for bank debits are read from the locker, as they are not stored in
+    // the temporary locker when the payout is created without being confirmed
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    let payout_method_data_req = match (
+        payout_method_data_req,
+        payment_method.as_ref(),
+        customer_details.as_ref(),
+    ) {
+        (None, Some(payment_method), Some(customer))
+            if payment_method.payment_method == Some(storage_enums::PaymentMethod::BankDebit)
+                && !matches!(req, payouts::PayoutRequest::PayoutRetrieveRequest(_)) =>
+        {
+            Some(
+                helpers::get_bank_debit_payout_method_data(
+                    state,
+                    key_store,
+                    &customer.customer_id,
+                    payment_method,
+                )
+                .await?,
+            )
+        }
+        (payout_method_data_req, _, _) => payout_method_data_req,
+    };
+
     Ok(PayoutData {
         billing_address,
         business_profile,
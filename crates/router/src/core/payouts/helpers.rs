@@ -18,6 +18,8 @@ use masking::{ExposeInterface, PeekInterface, Secret, SwitchStrategy};
 use router_env::logger;
 
 use super::PayoutData;
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+use crate::core::data_residency;
 #[cfg(feature = "payouts")]
 use crate::core::payments::route_connector_v1_for_payouts;
 use crate::{
@@ -92,6 +94,34 @@ pub async fn make_payout_method_data(
                 .attach_printable("failed to deserialize hyperswitch token data")?;
 
             let payment_token = match payment_token_data {
+                // The bank accounts vaulted for bank debits are paid out to as they are vaulted
+                #[cfg(all(
+                    any(feature = "v1", feature = "v2"),
+                    not(feature = "payment_methods_v2")
+                ))]
+                storage::PaymentTokenData::PermanentBankDebit(storage::BankDebitTokenData {
+                    payment_method_id,
+                    ..
+                }) if payout_method_data.is_none() => {
+                    let payment_method = db
+                        .find_payment_method(
+                            &state.into(),
+                            merchant_key_store,
+                            &payment_method_id,
+                            storage_scheme,
+                        )
+                        .await
+                        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+                    return get_bank_debit_payout_method_data(
+                        state,
+                        merchant_key_store,
+                        customer_id,
+                        &payment_method,
+                    )
+                    .await
+                    .map(Some);
+                }
                 storage::PaymentTokenData::PermanentCard(storage::CardTokenData {
                     locker_id,
                     token,
@@ -221,6 +251,65 @@ pub fn should_create_connector_transfer_method(
     Ok(connector_transfer_method_id)
 }
 
+/// Payout method data of a bank account of the customer which was vaulted for bank debits, so that
+/// the saved bank accounts can be used as payout destinations
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+pub async fn get_bank_debit_payout_method_data(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: &id_type::CustomerId,
+    payment_method: &domain::PaymentMethod,
+) -> RouterResult<api::PayoutMethodData> {
+    utils::when(&payment_method.customer_id != customer_id, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "customer associated with payout method and customer passed in payout are \
+                      not same"
+                .into(),
+        })
+    })?;
+
+    // The bank accounts linked through a pm_auth connector are not vaulted
+    let locker_id = payment_method.locker_id.as_ref().ok_or(report!(
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: "The bank account of the payment method cannot be paid out to".to_string(),
+        }
+    ))?;
+
+    let bank = match cards::get_bank_debit_from_hs_locker(
+        state,
+        key_store,
+        customer_id,
+        &payment_method.merchant_id,
+        locker_id,
+    )
+    .await?
+    {
+        api::BankDebitDetail::Ach {
+            account_number,
+            routing_number,
+            ..
+        } => payouts::Bank::Ach(payouts::AchBankTransfer {
+            bank_name: None,
+            bank_country_code: None,
+            bank_city: None,
+            bank_account_number: account_number,
+            bank_routing_number: routing_number,
+        }),
+        api::BankDebitDetail::Sepa { iban, .. } => payouts::Bank::Sepa(payouts::SepaBankTransfer {
+            bank_name: None,
+            bank_country_code: None,
+            bank_city: None,
+            iban,
+            bic: None,
+        }),
+    };
+
+    Ok(api::PayoutMethodData::Bank(bank))
+}
+
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
@@ -309,6 +398,7 @@ pub async fn save_payout_data_to_locker(
                 let payload = StoreLockerReq::LockerGeneric(StoreGenericReq {
                     merchant_id: merchant_account.get_id().to_owned(),
                     merchant_customer_id: customer_id.to_owned(),
+                    requestor_card_reference: None,
                     enc_data,
                     ttl: state.conf.locker.ttl_for_storage_in_secs,
                 });
@@ -444,6 +534,7 @@ pub async fn save_payout_data_to_locker(
                 bank_transfer: None,
                 card: card_details.clone(),
                 wallet: None,
+                bank_debit: None,
                 metadata: None,
                 customer_id: Some(customer_id.to_owned()),
                 card_network: None,
@@ -531,6 +622,7 @@ pub async fn save_payout_data_to_locker(
                     bank_transfer: bank_details,
                     card: None,
                     wallet: wallet_details,
+                    bank_debit: None,
                     metadata: None,
                     customer_id: Some(customer_id.to_owned()),
                     card_network: None,
@@ -545,7 +637,7 @@ pub async fn save_payout_data_to_locker(
 
     // Insert new entry in payment_methods table
     if should_insert_in_pm_table {
-        let payment_method_id = common_utils::generate_id(consts::ID_LENGTH, "pm");
+        let payment_method_id = common_utils::generate_resource_id(consts::ID_LENGTH, "pm");
         payout_data.payment_method = Some(
             cards::create_payment_method(
                 state,
@@ -694,7 +786,7 @@ pub(super) async fn get_or_create_customer_details(
     merchant_account: &domain::MerchantAccount,
     key_store: &domain::MerchantKeyStore,
 ) -> RouterResult<Option<domain::Customer>> {
-    let db: &dyn StorageInterface = &*state.store;
+    let db = data_residency::get_pii_store(state, merchant_account.get_id()).await?;
     // Create customer_id if not passed in request
     let customer_id = customer_details
         .customer_id
@@ -1097,6 +1189,7 @@ pub fn is_payout_terminal_state(status: api_enums::PayoutStatus) -> bool {
             | api_enums::PayoutStatus::RequiresConfirmation
             | api_enums::PayoutStatus::RequiresPayoutMethodData
             | api_enums::PayoutStatus::RequiresVendorAccountCreation
+            | api_enums::PayoutStatus::OnHold
             // Initiated by the underlying connector
             | api_enums::PayoutStatus::Pending
             | api_enums::PayoutStatus::Initiated
@@ -1127,6 +1220,7 @@ pub fn is_eligible_for_local_payout_cancellation(status: api_enums::PayoutStatus
             | api_enums::PayoutStatus::RequiresConfirmation
             | api_enums::PayoutStatus::RequiresPayoutMethodData
             | api_enums::PayoutStatus::RequiresVendorAccountCreation
+            | api_enums::PayoutStatus::OnHold
     )
 }
 
@@ -1213,6 +1307,7 @@ pub async fn update_payouts_and_payout_attempt(
         merchant_key_store,
         &payout_id_as_payment_id_type,
         merchant_account.storage_scheme,
+        None,
     )
     .await?;
     let address_id = if billing_address.is_some() {
@@ -229,6 +229,19 @@ pub async fn validate_create_request(
             )
             .await
         }
+        // The bank accounts vaulted for bank debits are paid out to as they are vaulted
+        (_, Some(customer), Some(payment_method))
+            if payment_method.payment_method == Some(storage::enums::PaymentMethod::BankDebit) =>
+        {
+            helpers::get_bank_debit_payout_method_data(
+                state,
+                merchant_key_store,
+                &customer.customer_id,
+                payment_method,
+            )
+            .await
+            .map(Some)
+        }
         (_, Some(_), Some(payment_method)) => {
             match get_pm_list_context(
                 state,
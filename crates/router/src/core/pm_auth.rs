@@ -13,7 +13,6 @@ use common_utils::{
     consts,
     crypto::{HmacSha256, SignMessage},
     ext_traits::{AsyncExt, ValueExt},
-    generate_id,
     types::{self as util_types, AmountConvertor},
 };
 use error_stack::ResultExt;
@@ -507,7 +506,7 @@ async fn store_bank_details_in_payment_methods(
                 any(feature = "v1", feature = "v2"),
                 not(feature = "payment_methods_v2")
             ))]
-            let pm_id = generate_id(consts::ID_LENGTH, "pm");
+            let pm_id = common_utils::generate_resource_id(consts::ID_LENGTH, "pm");
 
             #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
             let pm_id = common_utils::id_type::GlobalPaymentMethodId::generate("random_cell_id")
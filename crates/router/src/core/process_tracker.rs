@@ -0,0 +1,265 @@
+//! Observability of the scheduler tasks, so that the operators can inspect the backlogs of the
+//! scheduler, and requeue or abandon the tasks, without accessing the database directly.
+
+use api_models::process_tracker as api;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use strum::IntoEnumIterator;
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult},
+    routes::SessionState,
+    services,
+    types::{
+        storage::{self, business_status, enums},
+        transformers::{ForeignFrom, ForeignInto},
+    },
+};
+
+/// Business statuses with which the workflows finish the tasks without a successful outcome
+const FAILED_BUSINESS_STATUSES: [&str; 4] = [
+    business_status::GLOBAL_FAILURE,
+    business_status::GLOBAL_ERROR,
+    business_status::FAILURE,
+    business_status::RETRIES_EXCEEDED,
+];
+
+/// Returns the statuses, and optionally the business statuses, of the tasks in the state
+fn get_state_filters(
+    state: api::SchedulerTaskState,
+) -> (Vec<enums::ProcessTrackerStatus>, Option<Vec<String>>) {
+    match state {
+        api::SchedulerTaskState::Pending => (
+            vec![
+                enums::ProcessTrackerStatus::New,
+                enums::ProcessTrackerStatus::Pending,
+                enums::ProcessTrackerStatus::Processing,
+                enums::ProcessTrackerStatus::ProcessStarted,
+            ],
+            None,
+        ),
+        api::SchedulerTaskState::Review => (vec![enums::ProcessTrackerStatus::Review], None),
+        api::SchedulerTaskState::Failed => (
+            vec![enums::ProcessTrackerStatus::Finish],
+            Some(
+                FAILED_BUSINESS_STATUSES
+                    .iter()
+                    .map(|status| status.to_string())
+                    .collect(),
+            ),
+        ),
+    }
+}
+
+fn get_task_state(task: &storage::ProcessTracker) -> Option<api::SchedulerTaskState> {
+    match task.status {
+        enums::ProcessTrackerStatus::New
+        | enums::ProcessTrackerStatus::Pending
+        | enums::ProcessTrackerStatus::Processing
+        | enums::ProcessTrackerStatus::ProcessStarted => Some(api::SchedulerTaskState::Pending),
+        enums::ProcessTrackerStatus::Review => Some(api::SchedulerTaskState::Review),
+        enums::ProcessTrackerStatus::Finish => task
+            .is_valid_business_status(&FAILED_BUSINESS_STATUSES)
+            .then_some(api::SchedulerTaskState::Failed),
+    }
+}
+
+/// Indicates whether the task has been picked by the scheduler, in which case it can not be
+/// modified until the scheduler is done with it
+fn is_task_picked(task: &storage::ProcessTracker) -> bool {
+    matches!(
+        task.status,
+        enums::ProcessTrackerStatus::Processing | enums::ProcessTrackerStatus::ProcessStarted
+    )
+}
+
+async fn get_tasks_summary(
+    state: &SessionState,
+    task_type: api::SchedulerTaskType,
+    task_state: api::SchedulerTaskState,
+) -> RouterResult<api::SchedulerTasksSummary> {
+    let (statuses, business_statuses) = get_state_filters(task_state);
+    let (count, oldest_task_created_at) = state
+        .store
+        .get_processes_count_by_runner_status(task_type.foreign_into(), statuses, business_statuses)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to count the scheduler tasks")?;
+
+    let now = common_utils::date_time::now();
+    Ok(api::SchedulerTasksSummary {
+        task_type,
+        state: task_state,
+        count,
+        oldest_task_age: oldest_task_created_at
+            .map(|created_at| (now - created_at).whole_seconds()),
+    })
+}
+
+async fn find_task(state: &SessionState, task_id: &str) -> RouterResult<storage::ProcessTracker> {
+    state
+        .store
+        .find_process_by_id(task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the scheduler task")?
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::GenericNotFoundError {
+                message: format!("Scheduler task {task_id} does not exist"),
+            })
+        })
+}
+
+/// The task is updated only if its status has not changed since it was fetched, which fails if
+/// the task was picked by the scheduler, or modified by another operator, in the meantime
+fn handle_task_update_error(
+    error: error_stack::Report<errors::StorageError>,
+    action: &str,
+) -> error_stack::Report<errors::ApiErrorResponse> {
+    if error.current_context().is_db_not_found() {
+        error.change_context(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "Scheduler task was modified while being {action}, fetch the task and try again"
+            ),
+        })
+    } else {
+        error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(format!(
+                "Failed to update the scheduler task to be {action}"
+            ))
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_scheduler_tasks_summary(
+    state: SessionState,
+) -> RouterResponse<api::SchedulerTasksSummaryResponse> {
+    let summaries = api::SchedulerTaskType::iter()
+        .flat_map(|task_type| {
+            api::SchedulerTaskState::iter().map(move |task_state| (task_type, task_state))
+        })
+        .map(|(task_type, task_state)| get_tasks_summary(&state, task_type, task_state));
+    let summary = futures::future::try_join_all(summaries).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        api::SchedulerTasksSummaryResponse { summary },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_scheduler_tasks(
+    state: SessionState,
+    request: api::SchedulerTasksListRequest,
+) -> RouterResponse<api::SchedulerTasksListResponse> {
+    let limit = request
+        .limit
+        .unwrap_or(consts::DEFAULT_SCHEDULER_TASKS_LIST_LIMIT)
+        .min(consts::MAX_SCHEDULER_TASKS_LIST_LIMIT);
+    let (statuses, business_statuses) = get_state_filters(request.state);
+
+    let summary = get_tasks_summary(&state, request.task_type, request.state).await?;
+    let tasks = state
+        .store
+        .find_processes_by_runner_status(
+            request.task_type.foreign_into(),
+            statuses,
+            business_statuses,
+            i64::from(limit),
+            i64::from(request.offset.unwrap_or(0)),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the scheduler tasks")?;
+
+    Ok(services::ApplicationResponse::Json(
+        api::SchedulerTasksListResponse {
+            summary,
+            tasks: tasks.into_iter().map(ForeignFrom::foreign_from).collect(),
+        },
+    ))
+}
+
+/// Schedules the task to be run right away. The retries of a failed task are reset, so that the
+/// task is retried by its workflow again.
+#[instrument(skip_all)]
+pub async fn requeue_scheduler_task(
+    state: SessionState,
+    task_id: String,
+) -> RouterResponse<api::SchedulerTask> {
+    let task = find_task(&state, &task_id).await?;
+
+    if is_task_picked(&task) {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Scheduler task is being run, and can not be requeued".to_string(),
+        }));
+    }
+
+    let retry_count = match get_task_state(&task) {
+        Some(api::SchedulerTaskState::Failed) => Some(0),
+        Some(api::SchedulerTaskState::Pending | api::SchedulerTaskState::Review) => None,
+        None => {
+            return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Scheduler task is already finished, and can not be requeued".to_string(),
+            }));
+        }
+    };
+
+    let now = common_utils::date_time::now();
+    let task = state
+        .store
+        .update_process_if_status_unchanged(
+            task,
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count,
+                schedule_time: Some(now),
+                tracking_data: None,
+                business_status: Some(String::from(business_status::PENDING)),
+                status: Some(enums::ProcessTrackerStatus::New),
+                updated_at: Some(now),
+            },
+        )
+        .await
+        .map_err(|error| handle_task_update_error(error, "requeued"))?;
+    logger::info!(task_id = %task.id, "Scheduler task requeued");
+
+    Ok(services::ApplicationResponse::Json(task.foreign_into()))
+}
+
+/// Finishes the task without running it any further
+#[instrument(skip_all)]
+pub async fn abandon_scheduler_task(
+    state: SessionState,
+    task_id: String,
+) -> RouterResponse<api::SchedulerTask> {
+    let task = find_task(&state, &task_id).await?;
+
+    if is_task_picked(&task) {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Scheduler task is being run, and can not be abandoned".to_string(),
+        }));
+    }
+
+    if task.status == enums::ProcessTrackerStatus::Finish {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Scheduler task is already finished, and can not be abandoned".to_string(),
+        }));
+    }
+
+    let task = state
+        .store
+        .update_process_if_status_unchanged(
+            task,
+            storage::ProcessTrackerUpdate::StatusUpdate {
+                status: enums::ProcessTrackerStatus::Finish,
+                business_status: Some(String::from(business_status::ABANDONED_BY_OPERATOR)),
+            },
+        )
+        .await
+        .map_err(|error| handle_task_update_error(error, "abandoned"))?;
+    logger::info!(task_id = %task.id, "Scheduler task abandoned");
+
+    Ok(services::ApplicationResponse::Json(task.foreign_into()))
+}
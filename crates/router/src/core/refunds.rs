@@ -1,3 +1,8 @@
+#[cfg(feature = "v1")]
+pub mod batch;
+#[cfg(feature = "v1")]
+pub mod reconciliation;
+pub mod settlement_times;
 pub mod transformers;
 pub mod validator;
 
@@ -15,7 +20,10 @@ use error_stack::{report, ResultExt};
 use hyperswitch_domain_models::{
     router_data::ErrorResponse, router_request_types::SplitRefundsRequest,
 };
-use hyperswitch_interfaces::integrity::{CheckIntegrity, FlowIntegrity, GetIntegrityObject};
+use hyperswitch_interfaces::{
+    api::ConnectorSpecifications,
+    integrity::{CheckIntegrity, FlowIntegrity, GetIntegrityObject},
+};
 use router_env::{instrument, tracing};
 use scheduler::{consumer::types::process_data, utils as process_tracker_utils};
 #[cfg(feature = "olap")]
@@ -24,6 +32,7 @@ use strum::IntoEnumIterator;
 use crate::{
     consts,
     core::{
+        customers,
         errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
         payments::{self, access_token, helpers},
         refunds::transformers::SplitRefundInput,
@@ -69,9 +78,11 @@ pub async fn refund_create_core(
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
+    // The attempts of a partially paid payment which have paid their parts can be refunded
     utils::when(
         !(payment_intent.status == enums::IntentStatus::Succeeded
-            || payment_intent.status == enums::IntentStatus::PartiallyCaptured),
+            || payment_intent.status == enums::IntentStatus::PartiallyCaptured
+            || payments::partial_payments::is_partially_paid(&payment_intent)),
         || {
             Err(report!(errors::ApiErrorResponse::PaymentUnexpectedState {
                 current_flow: "refund".into(),
@@ -83,10 +94,44 @@ pub async fn refund_create_core(
         },
     )?;
 
-    // Amount is not passed in request refer from payment intent.
+    let is_partial_payments_enabled =
+        payments::partial_payments::is_partial_payments_enabled(&payment_intent);
+    payment_attempt = match req.attempt_id.as_deref() {
+        Some(attempt_id) if is_partial_payments_enabled => {
+            payments::partial_payments::find_attempt_to_refund(
+                db,
+                &payment_intent,
+                attempt_id,
+                merchant_account.storage_scheme,
+            )
+            .await?
+        }
+        Some(_) => {
+            return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message:
+                    "attempt_id can only be passed for the payments with partial payments enabled"
+                        .to_string(),
+            }))
+        }
+        None => db
+            .find_payment_attempt_last_successful_or_partially_captured_attempt_by_payment_id_merchant_id(
+                &req.payment_id,
+                merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::SuccessfulPaymentNotFound)?,
+    };
+
+    // Amount is not passed in request refer from payment intent, or from the refunded attempt of a
+    // payment with partial payments enabled
     amount = req
         .amount
-        .or(payment_intent.amount_captured)
+        .or(if is_partial_payments_enabled {
+            Some(payment_attempt.get_total_amount())
+        } else {
+            payment_intent.amount_captured
+        })
         .ok_or(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("amount captured is none in a successful payment")?;
 
@@ -99,15 +144,6 @@ pub async fn refund_create_core(
         .attach_printable("amount less than or equal to zero"))
     })?;
 
-    payment_attempt = db
-        .find_payment_attempt_last_successful_or_partially_captured_attempt_by_payment_id_merchant_id(
-            &req.payment_id,
-            merchant_id,
-            merchant_account.storage_scheme,
-        )
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::SuccessfulPaymentNotFound)?;
-
     let creds_identifier = req
         .merchant_connector_details
         .as_ref()
@@ -393,7 +429,13 @@ pub async fn trigger_refund_to_gateway(
                         refund_status: response.refund_status,
                         sent_to_gateway: true,
                         refund_error_message: None,
-                        refund_arn: "".to_string(),
+                        refund_arn: router_data_res
+                            .connector_response
+                            .as_ref()
+                            .and_then(|connector_response| {
+                                connector_response.get_acquirer_reference_number()
+                            })
+                            .cloned(),
                         updated_by: storage_scheme.to_string(),
                         processor_refund_data,
                     }
@@ -448,7 +490,7 @@ where
 
 // ********************************************** REFUND SYNC **********************************************
 
-pub async fn refund_response_wrapper<F, Fut, T, Req>(
+pub async fn refund_response_wrapper<F, Fut, Req>(
     state: SessionState,
     merchant_account: domain::MerchantAccount,
     profile_id: Option<common_utils::id_type::ProfileId>,
@@ -464,13 +506,19 @@ where
         domain::MerchantKeyStore,
         Req,
     ) -> Fut,
-    Fut: futures::Future<Output = RouterResult<T>>,
-    T: ForeignInto<refunds::RefundResponse>,
+    Fut: futures::Future<Output = RouterResult<storage::Refund>>,
 {
+    let refund = f(
+        state.clone(),
+        merchant_account.clone(),
+        profile_id,
+        key_store,
+        request,
+    )
+    .await?;
+
     Ok(services::ApplicationResponse::Json(
-        f(state, merchant_account, profile_id, key_store, request)
-            .await?
-            .foreign_into(),
+        settlement_times::get_refund_response(&state, &merchant_account, refund).await,
     ))
 }
 
@@ -732,7 +780,13 @@ pub async fn sync_refund_with_gateway(
                     refund_status: response.refund_status,
                     sent_to_gateway: true,
                     refund_error_message: None,
-                    refund_arn: "".to_string(),
+                    refund_arn: router_data_res
+                        .connector_response
+                        .as_ref()
+                        .and_then(|connector_response| {
+                            connector_response.get_acquirer_reference_number()
+                        })
+                        .cloned(),
                     updated_by: storage_scheme.to_string(),
                     processor_refund_data,
                 }
@@ -801,7 +855,9 @@ pub async fn refund_update_core(
             format!("Unable to update refund with refund_id: {}", req.refund_id)
         })?;
 
-    Ok(services::ApplicationResponse::Json(response.foreign_into()))
+    Ok(services::ApplicationResponse::Json(
+        settlement_times::get_refund_response(&state, &merchant_account, response).await,
+    ))
 }
 
 // ********************************************** VALIDATIONS **********************************************
@@ -872,9 +928,16 @@ pub async fn validate_and_create_refund(
             ),
         })?;
 
-    let total_amount_captured = payment_intent
-        .amount_captured
-        .unwrap_or(payment_attempt.get_total_amount());
+    // The refunds of a payment with partial payments enabled are limited to the amount paid by the
+    // refunded attempt
+    let total_amount_captured =
+        if payments::partial_payments::is_partial_payments_enabled(payment_intent) {
+            payment_attempt.get_total_amount()
+        } else {
+            payment_intent
+                .amount_captured
+                .unwrap_or(payment_attempt.get_total_amount())
+        };
 
     validator::validate_refund_amount(
         total_amount_captured.get_amount_as_i64(),
@@ -894,6 +957,43 @@ pub async fn validate_and_create_refund(
         .clone()
         .ok_or(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("No connector populated in payment attempt")?;
+
+    // The refunds to store credit do not involve the connector, so its refund window does not
+    // apply to them
+    let is_store_credit_refund =
+        req.refund_destination == Some(enums::RefundDestination::StoreCredit);
+    if is_store_credit_refund {
+        validator::validate_store_credit_refund(&req, payment_intent)?;
+    } else {
+        let connector_refund_validity_days =
+            api::ConnectorData::convert_connector(&connector)?.get_refund_validity_days();
+        let refund_windows = validator::get_refund_windows(
+            &state.conf.refund,
+            connector_refund_validity_days,
+            &connector,
+            payment_attempt,
+        );
+        validator::validate_refund_window(&payment_intent.created_at, &refund_windows).map_err(
+            |error| {
+                let api_error = match error.current_context() {
+                    validator::RefundValidationError::RefundWindowExpired {
+                        imposed_by,
+                        latest_refund_date,
+                    } => errors::ApiErrorResponse::RefundWindowExpired {
+                        imposed_by: imposed_by.clone(),
+                        latest_refund_date: latest_refund_date.to_string(),
+                    },
+                    _ => errors::ApiErrorResponse::InternalServerError,
+                };
+                error.change_context(api_error)
+            },
+        )?;
+    }
+
+    if let Some(end_to_end_reference) = req.end_to_end_reference.as_deref() {
+        validator::validate_end_to_end_reference(end_to_end_reference, payment_attempt)?;
+    }
+
     let (connector_transaction_id, processor_transaction_data) =
         ConnectorTransactionId::form_id_and_data(connector_transaction_id);
     let refund_create_req = storage::RefundNew {
@@ -926,12 +1026,23 @@ pub async fn validate_and_create_refund(
         organization_id: merchant_account.organization_id.clone(),
         processor_transaction_data,
         processor_refund_data: None,
+        end_to_end_reference: req.end_to_end_reference,
+        refund_destination: req.refund_destination,
     };
 
     let refund = match db
         .insert_refund(refund_create_req, merchant_account.storage_scheme)
         .await
     {
+        Ok(refund) if is_store_credit_refund => {
+            customers::store_credit::credit_refund_to_store_credit(
+                state,
+                refund,
+                payment_intent,
+                merchant_account.storage_scheme,
+            )
+            .await?
+        }
         Ok(refund) => {
             Box::pin(schedule_refund_execution(
                 state,
@@ -976,7 +1087,14 @@ pub async fn validate_and_create_refund(
         ..refund
     };
 
-    Ok(refund.foreign_into())
+    let estimated_arrival =
+        settlement_times::get_estimated_arrival(state, &refund, payment_attempt.payment_method)
+            .await;
+
+    Ok(refunds::RefundResponse {
+        estimated_arrival,
+        ..refund.foreign_into()
+    })
 }
 
 // ********************************************** Refund list **********************************************
@@ -1279,6 +1397,12 @@ impl ForeignFrom<storage::Refund> for api::RefundResponse {
             unified_message: refund.unified_message,
             issuer_error_code: refund.issuer_error_code,
             issuer_error_message: refund.issuer_error_message,
+            acquirer_reference_number: refund
+                .refund_arn
+                .filter(|refund_arn| !refund_arn.is_empty()),
+            end_to_end_reference: refund.end_to_end_reference,
+            refund_destination: refund.refund_destination,
+            estimated_arrival: None,
         }
     }
 }
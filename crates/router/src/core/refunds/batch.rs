@@ -0,0 +1,676 @@
+//! Refund batches, to initiate a large number of refunds at once, for instance on a product recall.
+//! The refunds of a batch are submitted either as a JSON array or as a CSV file, and are validated
+//! when the batch is created. The valid rows are then refunded asynchronously by the scheduler, in
+//! chunks, and the progress of the batch can be polled. Once the batch is completed, the outcome of
+//! each row can be downloaded as a CSV report. The rows of a batch are stored in chunks apart from
+//! the batch, so that each run of the scheduler reads and writes only the chunk it processes, and
+//! a batch created with a profile can be accessed only with that profile.
+
+use actix_multipart::form::{bytes::Bytes, MultipartForm};
+use api_models::refunds::{
+    RefundBatchRecord, RefundBatchResponse, RefundBatchRowResult, RefundBatchRowStatus,
+    RefundBatchStatus, RefundStatus,
+};
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use csv::{Reader, Writer};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        refunds::refund_create_core,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{api::refunds, domain, storage, transformers::ForeignInto},
+    utils,
+};
+
+const REFUND_BATCH_TASK: &str = "REFUND_BATCH";
+const REFUND_BATCH_TAG: &str = "REFUND";
+
+#[derive(Debug, MultipartForm)]
+pub struct RefundBatchUploadForm {
+    #[multipart(limit = "1MB")]
+    pub file: Bytes,
+}
+
+pub fn get_refund_batch_records(
+    form: RefundBatchUploadForm,
+) -> Result<Vec<RefundBatchRecord>, errors::ApiErrorResponse> {
+    Reader::from_reader(form.file.data.as_ref())
+        .deserialize()
+        .collect::<csv::Result<Vec<RefundBatchRecord>>>()
+        .map_err(|error| errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Invalid refund batch file: {error}"),
+        })
+}
+
+/// A refund batch, as stored along with its progress. The rows of the batch are stored apart, in
+/// chunks of `REFUND_BATCH_CHUNK_SIZE` rows.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefundBatch {
+    pub batch_id: String,
+    pub status: RefundBatchStatus,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub total_count: usize,
+    pub initiated_count: usize,
+    pub failed_count: usize,
+    pub chunks_count: usize,
+    /// Number of the chunks whose rows have all been processed, the chunks being processed in turn
+    pub processed_chunks_count: usize,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl RefundBatch {
+    /// A batch created with a profile is accessible only with that profile, while the
+    /// authentications without a profile access all the batches of the merchant
+    fn is_accessible_by_profile(&self, profile_id: Option<&id_type::ProfileId>) -> bool {
+        profile_id.map_or(true, |profile_id| {
+            self.profile_id.as_ref() == Some(profile_id)
+        })
+    }
+}
+
+impl From<&RefundBatch> for RefundBatchResponse {
+    fn from(batch: &RefundBatch) -> Self {
+        Self {
+            batch_id: batch.batch_id.clone(),
+            status: batch.status,
+            total_count: batch.total_count,
+            processed_count: batch.initiated_count + batch.failed_count,
+            initiated_count: batch.initiated_count,
+            failed_count: batch.failed_count,
+            created_at: batch.created_at,
+            modified_at: batch.modified_at,
+        }
+    }
+}
+
+fn count_rows(rows: &[RefundBatchRowResult], status: RefundBatchRowStatus) -> usize {
+    rows.iter().filter(|row| row.status == status).count()
+}
+
+fn validate_refund_batch_record(record: &RefundBatchRecord) -> Result<(), String> {
+    id_type::PaymentId::wrap(record.payment_id.clone())
+        .map_err(|_| "Invalid payment_id".to_string())?;
+
+    if record
+        .amount
+        .is_some_and(|amount| amount.get_amount_as_i64() <= 0)
+    {
+        return Err("amount must be greater than zero".to_string());
+    }
+
+    if record
+        .reason
+        .as_ref()
+        .is_some_and(|reason| reason.len() > 255)
+    {
+        return Err("reason must be at most 255 characters long".to_string());
+    }
+
+    Ok(())
+}
+
+/// The refunds initiated for the rows are assigned deterministic identifiers, so that a row is not
+/// refunded twice when a chunk is retried after a failure
+fn get_refund_id(batch_id: &str, row_number: usize) -> String {
+    format!("{batch_id}_{row_number}")
+}
+
+fn get_refund_batch_not_found_error() -> errors::ApiErrorResponse {
+    errors::ApiErrorResponse::GenericNotFoundError {
+        message: "Refund batch not found".to_string(),
+    }
+}
+
+async fn get_refund_batch(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    batch_id: &str,
+) -> RouterResult<RefundBatch> {
+    db.find_config_by_key(&merchant_id.get_refund_batch_key(batch_id))
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(get_refund_batch_not_found_error())
+            } else {
+                error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch refund batch")
+            }
+        })?
+        .config
+        .parse_struct("RefundBatch")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse refund batch")
+}
+
+/// Fetches the refund batch for the profile of the authentication, if any. A batch created with a
+/// profile is not found for the other profiles of the merchant.
+async fn get_refund_batch_for_profile(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    profile_id: Option<&id_type::ProfileId>,
+    batch_id: &str,
+) -> RouterResult<RefundBatch> {
+    let batch = get_refund_batch(db, merchant_id, batch_id).await?;
+    utils::when(!batch.is_accessible_by_profile(profile_id), || {
+        Err(report!(get_refund_batch_not_found_error()))
+    })?;
+    Ok(batch)
+}
+
+async fn update_refund_batch(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    batch: &RefundBatch,
+) -> RouterResult<()> {
+    let serialized_batch = batch
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize refund batch")?;
+
+    db.update_config_by_key(
+        &merchant_id.get_refund_batch_key(&batch.batch_id),
+        configs::ConfigUpdate::Update {
+            config: Some(serialized_batch),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update refund batch")?;
+
+    Ok(())
+}
+
+async fn get_refund_batch_chunk(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    batch_id: &str,
+    chunk_number: usize,
+) -> RouterResult<Vec<RefundBatchRowResult>> {
+    db.find_config_by_key_from_db(&merchant_id.get_refund_batch_chunk_key(batch_id, chunk_number))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch a chunk of the refund batch")?
+        .config
+        .parse_struct("Vec<RefundBatchRowResult>")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse a chunk of the refund batch")
+}
+
+async fn insert_refund_batch_chunk(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    batch_id: &str,
+    chunk_number: usize,
+    rows: &[RefundBatchRowResult],
+) -> RouterResult<()> {
+    let serialized_rows = rows
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize a chunk of the refund batch")?;
+
+    db.insert_config(configs::ConfigNew {
+        key: merchant_id.get_refund_batch_chunk_key(batch_id, chunk_number),
+        config: serialized_rows,
+    })
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to insert a chunk of the refund batch")?;
+
+    Ok(())
+}
+
+async fn update_refund_batch_chunk(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    batch_id: &str,
+    chunk_number: usize,
+    rows: &[RefundBatchRowResult],
+) -> RouterResult<()> {
+    let serialized_rows = rows
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize a chunk of the refund batch")?;
+
+    db.update_config_by_key(
+        &merchant_id.get_refund_batch_chunk_key(batch_id, chunk_number),
+        configs::ConfigUpdate::Update {
+            config: Some(serialized_rows),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update a chunk of the refund batch")?;
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn create_refund_batch(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    records: Vec<RefundBatchRecord>,
+) -> RouterResponse<RefundBatchResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+
+    if records.is_empty() {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The refund batch must contain at least one refund".to_string(),
+        }
+        .into());
+    }
+    if records.len() > consts::MAX_REFUND_BATCH_SIZE {
+        return Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "The refund batch can contain at most {} refunds",
+                consts::MAX_REFUND_BATCH_SIZE
+            ),
+        }
+        .into());
+    }
+
+    let rows = records
+        .into_iter()
+        .enumerate()
+        .map(|(index, record)| {
+            let validation_result = validate_refund_batch_record(&record);
+            RefundBatchRowResult {
+                row_number: index + 1,
+                payment_id: record.payment_id,
+                amount: record.amount,
+                reason: record.reason,
+                status: if validation_result.is_ok() {
+                    RefundBatchRowStatus::Pending
+                } else {
+                    RefundBatchRowStatus::Failed
+                },
+                refund_id: None,
+                refund_status: None,
+                error_message: validation_result.err(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let now = common_utils::date_time::now();
+    let failed_count = count_rows(&rows, RefundBatchRowStatus::Failed);
+    let has_pending_rows = failed_count < rows.len();
+    let batch_id = common_utils::generate_id(consts::ID_LENGTH, "rfb");
+    let chunks = rows
+        .chunks(consts::REFUND_BATCH_CHUNK_SIZE)
+        .collect::<Vec<_>>();
+    for (chunk_number, chunk) in chunks.iter().enumerate() {
+        insert_refund_batch_chunk(db, merchant_id, &batch_id, chunk_number, chunk).await?;
+    }
+
+    let batch = RefundBatch {
+        batch_id,
+        status: if has_pending_rows {
+            RefundBatchStatus::Queued
+        } else {
+            RefundBatchStatus::Completed
+        },
+        profile_id: profile_id.clone(),
+        total_count: rows.len(),
+        initiated_count: 0,
+        failed_count,
+        chunks_count: chunks.len(),
+        processed_chunks_count: 0,
+        created_at: now,
+        modified_at: now,
+    };
+
+    // The batch is stored after its chunks, so that it is never found without them
+    let serialized_batch = batch
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize refund batch")?;
+    db.insert_config(configs::ConfigNew {
+        key: merchant_id.get_refund_batch_key(&batch.batch_id),
+        config: serialized_batch,
+    })
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to insert refund batch")?;
+
+    if has_pending_rows {
+        let tracking_data = storage::RefundBatchTrackingData {
+            batch_id: batch.batch_id.clone(),
+            merchant_id: merchant_id.clone(),
+            profile_id,
+        };
+        add_refund_batch_task(db, tracking_data, now).await?;
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        RefundBatchResponse::from(&batch),
+    ))
+}
+
+async fn add_refund_batch_task(
+    db: &dyn StorageInterface,
+    tracking_data: storage::RefundBatchTrackingData,
+    schedule_time: PrimitiveDateTime,
+) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::RefundBatchWorkflow;
+    let process_tracker_id = format!("{runner}_{REFUND_BATCH_TASK}_{}", tracking_data.batch_id);
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        REFUND_BATCH_TASK,
+        runner,
+        [REFUND_BATCH_TAG],
+        tracking_data,
+        None,
+        schedule_time,
+        hyperswitch_domain_models::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct REFUND_BATCH process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while inserting REFUND_BATCH task to process_tracker")?;
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_refund_batch(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    batch_id: String,
+) -> RouterResponse<RefundBatchResponse> {
+    let batch = get_refund_batch_for_profile(
+        state.store.as_ref(),
+        merchant_account.get_id(),
+        profile_id.as_ref(),
+        &batch_id,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        RefundBatchResponse::from(&batch),
+    ))
+}
+
+/// Generates the CSV report of the refund batch, with the outcome of each of its rows
+#[instrument(skip_all)]
+pub async fn get_refund_batch_report(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    batch_id: String,
+) -> RouterResponse<serde_json::Value> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+    let batch =
+        get_refund_batch_for_profile(db, merchant_id, profile_id.as_ref(), &batch_id).await?;
+
+    let mut csv_writer = Writer::from_writer(Vec::new());
+    for chunk_number in 0..batch.chunks_count {
+        for row in get_refund_batch_chunk(db, merchant_id, &batch_id, chunk_number).await? {
+            csv_writer
+                .serialize(row)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to write the refund batch report")?;
+        }
+    }
+    let report = csv_writer
+        .into_inner()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate the refund batch report")?;
+
+    Ok(services::ApplicationResponse::FileData((
+        report,
+        mime::TEXT_CSV,
+    )))
+}
+
+/// Initiates the refund of a row of a refund batch. The refund already initiated for the row is
+/// looked up if the row had been processed before the task was interrupted.
+async fn process_refund_batch_row(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: Option<id_type::ProfileId>,
+    batch_id: &str,
+    row: &mut RefundBatchRowResult,
+) -> RouterResult<()> {
+    let refund_id = get_refund_id(batch_id, row.row_number);
+    let payment_id = id_type::PaymentId::wrap(row.payment_id.clone())
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid payment id in a validated refund batch row")?;
+    let request = refunds::RefundRequest {
+        payment_id,
+        refund_id: Some(refund_id.clone()),
+        merchant_id: Some(merchant_account.get_id().clone()),
+        amount: row.amount,
+        reason: row.reason.clone(),
+        ..Default::default()
+    };
+
+    let refund_result = Box::pin(refund_create_core(
+        state.clone(),
+        merchant_account.clone(),
+        profile_id,
+        key_store.clone(),
+        request,
+    ))
+    .await;
+
+    let refund_status = match refund_result {
+        Ok(services::ApplicationResponse::Json(refund)) => {
+            row.error_message = refund.error_message;
+            refund.status
+        }
+        Ok(_) => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response received while creating the refund")?,
+        Err(error)
+            if matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::DuplicateRefundRequest
+            ) =>
+        {
+            let refund = state
+                .store
+                .find_refund_by_merchant_id_refund_id(
+                    merchant_account.get_id(),
+                    &refund_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the refund initiated for the row")?;
+            row.error_message = refund.refund_error_message;
+            refund.refund_status.foreign_into()
+        }
+        Err(error) => {
+            row.status = RefundBatchRowStatus::Failed;
+            row.error_message = Some(error.to_string());
+            return Ok(());
+        }
+    };
+
+    row.status = if refund_status == RefundStatus::Failed {
+        RefundBatchRowStatus::Failed
+    } else {
+        RefundBatchRowStatus::Initiated
+    };
+    row.refund_id = Some(refund_id);
+    row.refund_status = Some(refund_status);
+
+    Ok(())
+}
+
+/// Initiates the refunds of the pending rows of the next chunk of the refund batch. Returns `true`
+/// once all the rows of the batch have been processed.
+#[instrument(skip_all)]
+pub async fn process_refund_batch_chunk(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    tracking_data: &storage::RefundBatchTrackingData,
+) -> RouterResult<bool> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+    let mut batch = get_refund_batch(db, merchant_id, &tracking_data.batch_id).await?;
+
+    if batch.processed_chunks_count < batch.chunks_count {
+        let chunk_number = batch.processed_chunks_count;
+        let mut rows =
+            get_refund_batch_chunk(db, merchant_id, &batch.batch_id, chunk_number).await?;
+        let initiated_count = count_rows(&rows, RefundBatchRowStatus::Initiated);
+        let failed_count = count_rows(&rows, RefundBatchRowStatus::Failed);
+
+        for row in rows
+            .iter_mut()
+            .filter(|row| row.status == RefundBatchRowStatus::Pending)
+        {
+            process_refund_batch_row(
+                state,
+                merchant_account,
+                key_store,
+                tracking_data.profile_id.clone(),
+                &tracking_data.batch_id,
+                row,
+            )
+            .await?;
+        }
+        update_refund_batch_chunk(db, merchant_id, &batch.batch_id, chunk_number, &rows).await?;
+
+        batch.initiated_count +=
+            count_rows(&rows, RefundBatchRowStatus::Initiated).saturating_sub(initiated_count);
+        batch.failed_count +=
+            count_rows(&rows, RefundBatchRowStatus::Failed).saturating_sub(failed_count);
+        batch.processed_chunks_count += 1;
+    }
+
+    let is_completed = batch.processed_chunks_count >= batch.chunks_count;
+    batch.status = if is_completed {
+        RefundBatchStatus::Completed
+    } else {
+        RefundBatchStatus::Processing
+    };
+    batch.modified_at = common_utils::date_time::now();
+    update_refund_batch(db, merchant_id, &batch).await?;
+
+    Ok(is_completed)
+}
+
+/// Marks the refund batch as failed once its task has exhausted its retries, so that it is not
+/// left processing without a task to initiate the refunds of its pending rows
+pub async fn fail_refund_batch(
+    db: &dyn StorageInterface,
+    tracking_data: &storage::RefundBatchTrackingData,
+) -> RouterResult<()> {
+    let mut batch =
+        get_refund_batch(db, &tracking_data.merchant_id, &tracking_data.batch_id).await?;
+    if batch.status == RefundBatchStatus::Completed {
+        return Ok(());
+    }
+
+    batch.status = RefundBatchStatus::Failed;
+    batch.modified_at = common_utils::date_time::now();
+    update_refund_batch(db, &tracking_data.merchant_id, &batch).await
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use std::borrow::Cow;
+
+    use super::*;
+
+    fn get_test_refund_batch(profile_id: Option<id_type::ProfileId>) -> RefundBatch {
+        let now = common_utils::date_time::now();
+        RefundBatch {
+            batch_id: "rfb_test".to_string(),
+            status: RefundBatchStatus::Processing,
+            profile_id,
+            total_count: 120,
+            initiated_count: 40,
+            failed_count: 10,
+            chunks_count: 3,
+            processed_chunks_count: 1,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    #[test]
+    fn test_refund_batch_is_accessible_only_by_its_profile() {
+        let profile_id =
+            id_type::ProfileId::try_from(Cow::from("pro_test")).expect("invalid profile id");
+        let other_profile_id =
+            id_type::ProfileId::try_from(Cow::from("pro_other")).expect("invalid profile id");
+
+        let batch = get_test_refund_batch(Some(profile_id.clone()));
+        assert!(batch.is_accessible_by_profile(None));
+        assert!(batch.is_accessible_by_profile(Some(&profile_id)));
+        assert!(!batch.is_accessible_by_profile(Some(&other_profile_id)));
+
+        // A batch created without a profile is accessible only to the merchant
+        let batch = get_test_refund_batch(None);
+        assert!(batch.is_accessible_by_profile(None));
+        assert!(!batch.is_accessible_by_profile(Some(&profile_id)));
+    }
+
+    #[test]
+    fn test_refund_batch_response_counts() {
+        let response = RefundBatchResponse::from(&get_test_refund_batch(None));
+        assert_eq!(response.total_count, 120);
+        assert_eq!(response.processed_count, 50);
+        assert_eq!(response.initiated_count, 40);
+        assert_eq!(response.failed_count, 10);
+    }
+
+    #[test]
+    fn test_validate_refund_batch_record() {
+        let record = RefundBatchRecord {
+            payment_id: "pay_test".to_string(),
+            amount: Some(common_utils::types::MinorUnit::new(100)),
+            reason: None,
+        };
+        assert!(validate_refund_batch_record(&record).is_ok());
+
+        let record = RefundBatchRecord {
+            amount: Some(common_utils::types::MinorUnit::new(0)),
+            ..record
+        };
+        assert_eq!(
+            validate_refund_batch_record(&record),
+            Err("amount must be greater than zero".to_string())
+        );
+
+        let record = RefundBatchRecord {
+            amount: None,
+            reason: Some("a".repeat(256)),
+            ..record
+        };
+        assert_eq!(
+            validate_refund_batch_record(&record),
+            Err("reason must be at most 255 characters long".to_string())
+        );
+    }
+}
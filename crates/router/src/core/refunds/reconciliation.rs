@@ -0,0 +1,409 @@
+//! Reconciliation of the refunds stuck in pending beyond the time within which their connector is
+//! expected to process them. A recurring process tracker task syncs these refunds with their
+//! connectors, which moves them to the status reported by the connectors. The refunds which the
+//! connectors fail to find, and the refunds which were never acknowledged by the connectors, are
+//! held for manual review instead. The refunds held for manual review are listed by filtering the
+//! refunds with the `review` status, and are resolved once their outcome has been confirmed with
+//! the connector.
+
+use std::str::FromStr;
+
+use api_models::enums as api_enums;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use super::{refund_retrieve_core, settlement_times};
+use crate::{
+    configs::settings::RefundReconciliationConfig,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
+    routes::{metrics, SessionState},
+    services,
+    types::{
+        api::refunds,
+        domain,
+        storage::{self, enums, RefundReconciliationTrackingData},
+    },
+    utils,
+};
+
+const REFUND_RECONCILIATION_TASK: &str = "REFUND_RECONCILIATION";
+const REFUND_RECONCILIATION_TAG: &str = "REFUND";
+
+/// Error code of the refunds held for manual review which the connector could not find
+const REFUND_NOT_FOUND_ERROR_CODE: &str = "refund_not_found";
+const REFUND_NOT_FOUND_ERROR_MESSAGE: &str =
+    "Refund is pending beyond the SLA of the connector, and was not found with the connector";
+
+fn get_process_tracker_id() -> String {
+    let runner = storage::ProcessTrackerRunner::RefundReconciliationWorkflow;
+    format!("{runner}_{REFUND_RECONCILIATION_TASK}")
+}
+
+/// Schedules the reconciliation of the pending refunds right away, unless it is already scheduled.
+/// The reconciliation is not scheduled when it is disabled.
+#[instrument(skip_all)]
+pub async fn schedule_refund_reconciliation(state: &SessionState) -> RouterResult<()> {
+    if !state.conf.refund_reconciliation.enabled {
+        return Ok(());
+    }
+
+    let db = &*state.store;
+    let process_tracker_id = get_process_tracker_id();
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching REFUND_RECONCILIATION task")?;
+    let schedule_time = common_utils::date_time::now();
+
+    match existing_process {
+        Some(process) if process.status == enums::ProcessTrackerStatus::Finish => db
+            .as_scheduler()
+            .reset_process(process, schedule_time)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while resetting REFUND_RECONCILIATION task"),
+        Some(_) => Ok(()),
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                REFUND_RECONCILIATION_TASK,
+                storage::ProcessTrackerRunner::RefundReconciliationWorkflow,
+                [REFUND_RECONCILIATION_TAG],
+                RefundReconciliationTrackingData::default(),
+                None,
+                schedule_time,
+                hyperswitch_domain_models::consts::API_VERSION,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct REFUND_RECONCILIATION task")?;
+
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed while inserting REFUND_RECONCILIATION task to process_tracker",
+                )?;
+            Ok(())
+        }
+    }
+}
+
+/// Whether the refund has been pending for longer than the SLA of its connector
+fn is_past_sla(
+    config: &RefundReconciliationConfig,
+    refund: &storage::Refund,
+    now: time::PrimitiveDateTime,
+) -> bool {
+    let connector = api_enums::Connector::from_str(&refund.connector).ok();
+    let sla = time::Duration::hours(i64::from(config.get_sla_in_hours(connector)));
+
+    refund.created_at.saturating_add(sla) <= now
+}
+
+/// Reconciles the refunds which have been pending for longer than the SLA of their connector,
+/// returning the time at which the reconciliation pass was completed. The refunds which fail to be
+/// reconciled are left pending, to be reconciled by the next pass.
+#[instrument(skip_all)]
+pub async fn reconcile_pending_refunds(
+    state: &SessionState,
+) -> RouterResult<RefundReconciliationTrackingData> {
+    let db = &*state.store;
+    let config = &state.conf.refund_reconciliation;
+    let now = common_utils::date_time::now();
+    // None of the refunds created after the shortest of the SLAs are past their SLA
+    let created_before = now.saturating_sub(time::Duration::hours(i64::from(
+        config.get_min_sla_in_hours(),
+    )));
+
+    let mut cursor = None;
+    let (mut synced_count, mut manual_review_count) = (0, 0);
+    loop {
+        let refunds = db
+            .find_refunds_by_status_created_before(
+                enums::RefundStatus::Pending,
+                created_before,
+                cursor.clone(),
+                config.batch_size,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the pending refunds for reconciliation")?;
+
+        for refund in refunds
+            .iter()
+            .filter(|refund| is_past_sla(config, refund, now))
+        {
+            match reconcile_refund(state, refund).await {
+                Ok(refund) if refund.refund_status == enums::RefundStatus::ManualReview => {
+                    manual_review_count += 1;
+                }
+                Ok(_) => synced_count += 1,
+                Err(error) => {
+                    logger::error!(
+                        ?error,
+                        refund_id = %refund.refund_id,
+                        "Failed to reconcile the refund"
+                    );
+                }
+            }
+        }
+
+        if let Some(refund) = refunds.last() {
+            cursor = Some(diesel_models::refund::RefundCreationCursor {
+                created_at: refund.created_at,
+                merchant_id: refund.merchant_id.clone(),
+                refund_id: refund.refund_id.clone(),
+            });
+        }
+
+        let is_last_batch = i64::try_from(refunds.len())
+            .map_or(true, |batch_length| batch_length < config.batch_size);
+        if is_last_batch {
+            break;
+        }
+    }
+
+    logger::info!(
+        synced_count,
+        manual_review_count,
+        "Reconciled the pending refunds"
+    );
+
+    Ok(RefundReconciliationTrackingData {
+        last_reconciled_at: Some(common_utils::date_time::now()),
+    })
+}
+
+/// Syncs the refund with its connector, holding it for manual review when the connector fails to
+/// find it
+#[instrument(skip_all, fields(refund_id = %refund.refund_id))]
+async fn reconcile_refund(
+    state: &SessionState,
+    refund: &storage::Refund,
+) -> RouterResult<storage::Refund> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            &refund.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .attach_printable("Error while fetching the key store by merchant_id")?;
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(key_manager_state, &refund.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+        .attach_printable("Error while fetching the merchant_account by merchant_id")?;
+
+    // The refunds which were never acknowledged by the connector cannot be synced
+    if refund.connector_refund_id.is_none() {
+        return hold_for_manual_review(
+            state,
+            &merchant_account,
+            refund.clone(),
+            Some(REFUND_NOT_FOUND_ERROR_CODE.to_owned()),
+            Some(REFUND_NOT_FOUND_ERROR_MESSAGE.to_owned()),
+        )
+        .await;
+    }
+
+    let request = refunds::RefundsRetrieveRequest {
+        refund_id: refund.refund_id.clone(),
+        force_sync: Some(true),
+        merchant_connector_details: None,
+    };
+    let synced_refund = Box::pin(refund_retrieve_core(
+        state.clone(),
+        merchant_account.clone(),
+        None,
+        key_store,
+        request,
+        refund.clone(),
+    ))
+    .await?;
+
+    // The sync leaves the refund pending with the error of the connector when the connector fails
+    // to find the refund
+    if synced_refund.refund_status == enums::RefundStatus::Pending
+        && synced_refund.refund_error_message.is_some()
+    {
+        let refund_error_code = synced_refund
+            .refund_error_code
+            .clone()
+            .or(Some(REFUND_NOT_FOUND_ERROR_CODE.to_owned()));
+        let refund_error_message = synced_refund.refund_error_message.clone();
+        return hold_for_manual_review(
+            state,
+            &merchant_account,
+            synced_refund,
+            refund_error_code,
+            refund_error_message,
+        )
+        .await;
+    }
+
+    Ok(synced_refund)
+}
+
+async fn hold_for_manual_review(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    refund: storage::Refund,
+    refund_error_code: Option<String>,
+    refund_error_message: Option<String>,
+) -> RouterResult<storage::Refund> {
+    let refund_update = storage::RefundUpdate::ManualUpdate {
+        refund_status: Some(enums::RefundStatus::ManualReview),
+        refund_error_message,
+        refund_error_code,
+        updated_by: merchant_account.storage_scheme.to_string(),
+    };
+    let refund_id = refund.refund_id.clone();
+    let connector = refund.connector.clone();
+    let refund = state
+        .store
+        .update_refund(refund, refund_update, merchant_account.storage_scheme)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)
+        .attach_printable_lazy(|| {
+            format!("Unable to hold the refund for manual review: refund_id: {refund_id}")
+        })?;
+
+    logger::warn!(%refund_id, connector, "Refund held for manual review");
+    metrics::REFUND_RECONCILIATION_MANUAL_REVIEW_COUNT.add(
+        1,
+        router_env::metric_attributes!(
+            ("merchant_id", merchant_account.get_id().clone()),
+            ("connector", connector)
+        ),
+    );
+
+    Ok(refund)
+}
+
+/// Resolves a refund held for manual review as either succeeded or failed, once its outcome has
+/// been confirmed with the connector, and notifies the merchant of the outcome
+#[instrument(skip_all)]
+pub async fn resolve_refund(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    req: refunds::RefundResolveRequest,
+) -> RouterResponse<refunds::RefundResponse> {
+    let db = state.store.as_ref();
+    let refund = db
+        .find_refund_by_merchant_id_refund_id(
+            merchant_account.get_id(),
+            &req.refund_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &refund)?;
+
+    utils::when(
+        refund.refund_status != enums::RefundStatus::ManualReview,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Only the refunds held for manual review can be resolved".into(),
+            }))
+        },
+    )?;
+
+    let (refund_status, refund_error_code, refund_error_message) = match req.status {
+        refunds::RefundStatus::Succeeded => (enums::RefundStatus::Success, None, None),
+        refunds::RefundStatus::Failed => (
+            enums::RefundStatus::Failure,
+            req.error_code,
+            req.error_message,
+        ),
+        refunds::RefundStatus::Pending | refunds::RefundStatus::Review => {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "status must be either succeeded or failed".into(),
+            }));
+        }
+    };
+
+    let refund_update = storage::RefundUpdate::ManualUpdate {
+        refund_status: Some(refund_status),
+        refund_error_message,
+        refund_error_code,
+        updated_by: merchant_account.storage_scheme.to_string(),
+    };
+    let response = db
+        .update_refund(refund, refund_update, merchant_account.storage_scheme)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Unable to resolve refund with refund_id: {}", req.refund_id)
+        })?;
+
+    if let Some(profile_id) = response.profile_id.clone() {
+        utils::trigger_refund_outgoing_webhook(
+            &state,
+            &merchant_account,
+            &response,
+            profile_id,
+            &key_store,
+        )
+        .await
+        .map_err(|error| logger::warn!(refunds_outgoing_webhook_error=?error))
+        .ok();
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        settlement_times::get_refund_response(&state, &merchant_account, response).await,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn get_config() -> RefundReconciliationConfig {
+        RefundReconciliationConfig {
+            enabled: true,
+            default_sla_in_hours: 72,
+            connector_sla_in_hours: HashMap::from([(api_enums::Connector::Adyen, 24)]),
+            batch_size: 100,
+            interval: 3600,
+        }
+    }
+
+    #[test]
+    fn test_sla_of_connector() {
+        let config = get_config();
+
+        assert_eq!(
+            config.get_sla_in_hours(Some(api_enums::Connector::Adyen)),
+            24
+        );
+        assert_eq!(
+            config.get_sla_in_hours(Some(api_enums::Connector::Stripe)),
+            72
+        );
+        assert_eq!(config.get_sla_in_hours(None), 72);
+        assert_eq!(config.get_min_sla_in_hours(), 24);
+    }
+
+    #[test]
+    fn test_min_sla_without_connector_slas() {
+        let config = RefundReconciliationConfig {
+            connector_sla_in_hours: HashMap::new(),
+            ..get_config()
+        };
+
+        assert_eq!(config.get_min_sla_in_hours(), 72);
+    }
+}
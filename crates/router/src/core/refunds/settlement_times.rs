@@ -0,0 +1,258 @@
+//! Estimated arrival of the refunds with the customers. The settlement times of the refunds are
+//! configured by connector and payment method, and are refined with the settlement times observed
+//! once the acquirer reference numbers of the refunds are imported from the settlement files of
+//! the connectors. The observed settlement times are stored in the configs table, keyed by the
+//! connector and the payment method, and are used once enough refunds have settled.
+
+use std::{collections::VecDeque, str::FromStr};
+
+use api_models::refunds::{RefundEstimatedArrival, RefundResponse};
+use common_utils::ext_traits::{Encode, StringExt};
+use diesel_models::configs;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    configs::settings::SettlementTimeRange,
+    core::errors::{self, RouterResult},
+    db::StorageInterface,
+    routes::SessionState,
+    types::{api::enums as api_enums, domain, storage, transformers::ForeignInto},
+};
+
+/// Number of the latest settlement times observed for a connector and payment method which are
+/// retained
+const MAX_OBSERVED_SETTLEMENT_TIMES: usize = 100;
+
+/// Number of settlement times which must be observed for a connector and payment method before
+/// they replace the configured settlement time
+const MIN_OBSERVED_SETTLEMENT_TIMES: usize = 20;
+
+/// Percentiles of the observed settlement times which bound the estimated arrival, so that the
+/// outliers do not widen the range
+const LOWER_SETTLEMENT_TIME_PERCENTILE: usize = 10;
+const UPPER_SETTLEMENT_TIME_PERCENTILE: usize = 90;
+
+const HOURS_PER_DAY: i64 = 24;
+
+/// The latest settlement times of the refunds of a connector and payment method, in hours
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ObservedSettlementTimes {
+    settlement_hours: VecDeque<i64>,
+}
+
+impl ObservedSettlementTimes {
+    fn record(&mut self, settlement_hours: i64) {
+        self.settlement_hours.push_back(settlement_hours);
+        while self.settlement_hours.len() > MAX_OBSERVED_SETTLEMENT_TIMES {
+            self.settlement_hours.pop_front();
+        }
+    }
+
+    fn get_settlement_time(&self) -> Option<SettlementTimeRange> {
+        if self.settlement_hours.len() < MIN_OBSERVED_SETTLEMENT_TIMES {
+            return None;
+        }
+
+        let mut settlement_hours = Vec::from(self.settlement_hours.clone());
+        settlement_hours.sort_unstable();
+        let last_index = settlement_hours.len() - 1;
+        let lower_hours = settlement_hours[last_index * LOWER_SETTLEMENT_TIME_PERCENTILE / 100];
+        let upper_hours = settlement_hours[last_index * UPPER_SETTLEMENT_TIME_PERCENTILE / 100];
+
+        Some(SettlementTimeRange {
+            min_days: u16::try_from(lower_hours / HOURS_PER_DAY).unwrap_or(u16::MAX),
+            max_days: u16::try_from((upper_hours + HOURS_PER_DAY - 1) / HOURS_PER_DAY)
+                .unwrap_or(u16::MAX),
+        })
+    }
+}
+
+fn get_observed_settlement_times_key(
+    connector: &str,
+    payment_method: Option<api_enums::PaymentMethod>,
+) -> String {
+    let payment_method = payment_method.map_or_else(|| "default".to_string(), |pm| pm.to_string());
+    format!("refund_settlement_times_{connector}_{payment_method}")
+}
+
+async fn get_observed_settlement_times(
+    db: &dyn StorageInterface,
+    key: &str,
+) -> RouterResult<Option<ObservedSettlementTimes>> {
+    match db.find_config_by_key(key).await {
+        Ok(config) => config
+            .config
+            .parse_struct("ObservedSettlementTimes")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse observed refund settlement times")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch observed refund settlement times"),
+    }
+}
+
+async fn get_payment_method(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    refund: &storage::Refund,
+) -> Option<api_enums::PaymentMethod> {
+    state
+        .store
+        .find_payment_attempt_by_attempt_id_merchant_id(
+            &refund.attempt_id,
+            merchant_account.get_id(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .inspect_err(|error| logger::error!(?error, "Failed to fetch the payment attempt"))
+        .ok()
+        .and_then(|payment_attempt| payment_attempt.payment_method)
+}
+
+/// Returns the settlement time of the refunds of the connector and payment method, as observed
+/// once enough refunds have settled, or else as configured
+async fn get_settlement_time(
+    state: &SessionState,
+    connector: &str,
+    payment_method: Option<api_enums::PaymentMethod>,
+) -> SettlementTimeRange {
+    let key = get_observed_settlement_times_key(connector, payment_method);
+    let observed_settlement_time = get_observed_settlement_times(&*state.store, &key)
+        .await
+        .inspect_err(|error| logger::error!(?error, "Failed to get refund settlement times"))
+        .ok()
+        .flatten()
+        .and_then(|observed_settlement_times| observed_settlement_times.get_settlement_time());
+
+    observed_settlement_time.unwrap_or_else(|| {
+        api_enums::Connector::from_str(connector)
+            .map(|connector| {
+                state
+                    .conf
+                    .refund_settlement_times
+                    .get_settlement_time(connector, payment_method)
+            })
+            .unwrap_or(state.conf.refund_settlement_times.default)
+    })
+}
+
+/// Returns the estimated arrival of the refund with the customer, counted from the creation of the
+/// refund
+pub async fn get_estimated_arrival(
+    state: &SessionState,
+    refund: &storage::Refund,
+    payment_method: Option<api_enums::PaymentMethod>,
+) -> Option<RefundEstimatedArrival> {
+    if matches!(
+        refund.refund_status,
+        storage::enums::RefundStatus::Failure | storage::enums::RefundStatus::TransactionFailure
+    ) {
+        return None;
+    }
+
+    let settlement_time = get_settlement_time(state, &refund.connector, payment_method).await;
+    Some(RefundEstimatedArrival {
+        earliest: refund.created_at + time::Duration::days(i64::from(settlement_time.min_days)),
+        latest: refund.created_at + time::Duration::days(i64::from(settlement_time.max_days)),
+    })
+}
+
+/// Returns the response of the refund, with its estimated arrival
+#[instrument(skip_all)]
+pub async fn get_refund_response(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    refund: storage::Refund,
+) -> RefundResponse {
+    let payment_method = get_payment_method(state, merchant_account, &refund).await;
+    let estimated_arrival = get_estimated_arrival(state, &refund, payment_method).await;
+
+    RefundResponse {
+        estimated_arrival,
+        ..refund.foreign_into()
+    }
+}
+
+/// Records the time within which the refund settled with the customer, once its settlement is
+/// reported in the settlement file of the connector
+#[instrument(skip_all)]
+pub async fn record_settlement_time(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    refund: &storage::Refund,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let payment_method = get_payment_method(state, merchant_account, refund).await;
+    let key = get_observed_settlement_times_key(&refund.connector, payment_method);
+    let settlement_hours = (common_utils::date_time::now() - refund.created_at).whole_hours();
+
+    let existing_settlement_times = get_observed_settlement_times(db, &key).await?;
+    let is_existing = existing_settlement_times.is_some();
+    let mut observed_settlement_times = existing_settlement_times.unwrap_or_default();
+    observed_settlement_times.record(settlement_hours);
+
+    let serialized_settlement_times = observed_settlement_times
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize observed refund settlement times")?;
+
+    if is_existing {
+        db.update_config_by_key(
+            &key,
+            configs::ConfigUpdate::Update {
+                config: Some(serialized_settlement_times),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update observed refund settlement times")?;
+    } else {
+        db.insert_config(configs::ConfigNew {
+            key,
+            config: serialized_settlement_times,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert observed refund settlement times")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_observed_settlement_time() {
+        let mut observed_settlement_times = ObservedSettlementTimes::default();
+        for settlement_hours in 0..MIN_OBSERVED_SETTLEMENT_TIMES - 1 {
+            observed_settlement_times.record(i64::try_from(settlement_hours).unwrap_or_default());
+        }
+        assert_eq!(observed_settlement_times.get_settlement_time(), None);
+
+        // Settlement times from 2 to 6 days, and one outlier of 30 days
+        let mut observed_settlement_times = ObservedSettlementTimes::default();
+        for day in 0..MAX_OBSERVED_SETTLEMENT_TIMES + 10 {
+            let day = i64::try_from(day % 5).unwrap_or_default() + 2;
+            observed_settlement_times.record(day * HOURS_PER_DAY + 1);
+        }
+        observed_settlement_times.record(30 * HOURS_PER_DAY);
+
+        assert_eq!(
+            observed_settlement_times.settlement_hours.len(),
+            MAX_OBSERVED_SETTLEMENT_TIMES
+        );
+        assert_eq!(
+            observed_settlement_times.get_settlement_time(),
+            Some(SettlementTimeRange {
+                min_days: 2,
+                max_days: 7,
+            })
+        );
+    }
+}
@@ -3,6 +3,7 @@ use router_env::{instrument, tracing};
 use time::PrimitiveDateTime;
 
 use crate::{
+    configs::settings,
     core::errors::{self, CustomResult, RouterResult},
     types::{
         self,
@@ -29,6 +30,19 @@ pub enum RefundValidationError {
     MaxRefundCountReached,
     #[error("There is already another refund request for this payment attempt")]
     DuplicateRefund,
+    #[error("Refunds of this payment are accepted only until {latest_refund_date} as per the rules of {imposed_by}")]
+    RefundWindowExpired {
+        imposed_by: String,
+        latest_refund_date: time::Date,
+    },
+}
+
+/// The number of days after the creation of a payment within which its refunds are accepted, as
+/// imposed by the connector or the card network of the payment
+#[derive(Debug, Clone)]
+pub struct RefundWindow {
+    pub imposed_by: String,
+    pub validity_days: i64,
 }
 
 #[instrument(skip_all)]
@@ -84,6 +98,210 @@ pub fn validate_payment_order_age(
     )
 }
 
+/// Number of days after the creation of a card payment within which the card network allows
+/// refunding it against the original transaction, as configured for the network
+pub fn get_card_network_refund_validity_days(
+    refund_config: &settings::Refund,
+    card_network: &api_enums::CardNetwork,
+) -> Option<i64> {
+    refund_config
+        .card_network_validity_days
+        .get(card_network)
+        .copied()
+}
+
+/// Collects the refund windows applicable to the payment attempt, from the connector which
+/// processed it and from the card network of the card used for it
+pub fn get_refund_windows(
+    refund_config: &settings::Refund,
+    connector_refund_validity_days: Option<i64>,
+    connector: &str,
+    payment_attempt: &storage::PaymentAttempt,
+) -> Vec<RefundWindow> {
+    let card_network = payment_attempt
+        .payment_method_data
+        .as_ref()
+        .and_then(|data| data.get("card"))
+        .and_then(|card| card.get("card_network"))
+        .and_then(|network| serde_json::from_value::<api_enums::CardNetwork>(network.clone()).ok());
+
+    let connector_window = connector_refund_validity_days.map(|validity_days| RefundWindow {
+        imposed_by: connector.to_string(),
+        validity_days,
+    });
+    let card_network_window = card_network.and_then(|card_network| {
+        get_card_network_refund_validity_days(refund_config, &card_network).map(|validity_days| {
+            RefundWindow {
+                imposed_by: card_network.to_string(),
+                validity_days,
+            }
+        })
+    });
+
+    connector_window
+        .into_iter()
+        .chain(card_network_window)
+        .collect()
+}
+
+/// Validates that the refund is requested within the strictest of the refund windows, reporting the
+/// latest date until which the payment could have been refunded otherwise
+#[instrument(skip_all)]
+pub fn validate_refund_window(
+    created_at: &PrimitiveDateTime,
+    refund_windows: &[RefundWindow],
+) -> CustomResult<(), RefundValidationError> {
+    let current_time = common_utils::date_time::now();
+    let strictest_window = refund_windows
+        .iter()
+        .map(|window| {
+            (
+                window,
+                created_at.saturating_add(time::Duration::days(window.validity_days)),
+            )
+        })
+        .min_by_key(|(_, latest_refund_time)| *latest_refund_time);
+
+    match strictest_window {
+        Some((window, latest_refund_time)) if current_time > latest_refund_time => {
+            Err(report!(RefundValidationError::RefundWindowExpired {
+                imposed_by: window.imposed_by.clone(),
+                latest_refund_date: latest_refund_time.date(),
+            }))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// The constraints imposed by a bank transfer scheme on the end-to-end reference of a refund, which
+/// is passed to the payer as the remittance information of the refund
+#[derive(Debug)]
+pub struct EndToEndReferenceConstraints {
+    pub scheme: &'static str,
+    pub max_length: usize,
+    pub is_allowed_character: fn(char) -> bool,
+}
+
+fn is_sepa_character(character: char) -> bool {
+    character.is_ascii_alphanumeric() || "/-?:().,'+ ".contains(character)
+}
+
+fn is_faster_payments_character(character: char) -> bool {
+    character.is_ascii_alphanumeric() || "&-./ ".contains(character)
+}
+
+fn is_ach_character(character: char) -> bool {
+    character.is_ascii_graphic() || character == ' '
+}
+
+fn is_pix_character(character: char) -> bool {
+    !character.is_control()
+}
+
+/// Returns the constraints of the bank transfer scheme of the payment method type on the
+/// end-to-end reference, defaulting to those of ISO 20022 as used by SEPA
+pub fn get_end_to_end_reference_constraints(
+    payment_method_type: Option<api_enums::PaymentMethodType>,
+) -> EndToEndReferenceConstraints {
+    match payment_method_type {
+        Some(api_enums::PaymentMethodType::Bacs | api_enums::PaymentMethodType::OpenBankingUk) => {
+            EndToEndReferenceConstraints {
+                scheme: "Faster Payments",
+                max_length: 18,
+                is_allowed_character: is_faster_payments_character,
+            }
+        }
+        Some(api_enums::PaymentMethodType::Ach) => EndToEndReferenceConstraints {
+            scheme: "ACH",
+            max_length: 80,
+            is_allowed_character: is_ach_character,
+        },
+        Some(api_enums::PaymentMethodType::Pix) => EndToEndReferenceConstraints {
+            scheme: "Pix",
+            max_length: 140,
+            is_allowed_character: is_pix_character,
+        },
+        _ => EndToEndReferenceConstraints {
+            scheme: "SEPA",
+            max_length: 35,
+            is_allowed_character: is_sepa_character,
+        },
+    }
+}
+
+pub fn is_valid_end_to_end_reference(
+    end_to_end_reference: &str,
+    constraints: &EndToEndReferenceConstraints,
+) -> bool {
+    !end_to_end_reference.trim().is_empty()
+        && end_to_end_reference.chars().count() <= constraints.max_length
+        && end_to_end_reference
+            .chars()
+            .all(constraints.is_allowed_character)
+}
+
+/// Validates the end-to-end reference of a refund against the constraints of the bank transfer
+/// scheme of the payment being refunded
+#[instrument(skip_all)]
+pub fn validate_end_to_end_reference(
+    end_to_end_reference: &str,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<()> {
+    utils::when(
+        !matches!(
+            payment_attempt.payment_method,
+            Some(
+                enums::PaymentMethod::BankTransfer
+                    | enums::PaymentMethod::BankRedirect
+                    | enums::PaymentMethod::OpenBanking
+            )
+        ),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "end_to_end_reference is supported only for the refunds of bank transfer payments".to_string(),
+            }))
+        },
+    )?;
+
+    let constraints = get_end_to_end_reference_constraints(payment_attempt.payment_method_type);
+    utils::when(
+        !is_valid_end_to_end_reference(end_to_end_reference, &constraints),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "end_to_end_reference must be a non-empty reference of at most {} characters allowed by the {} scheme",
+                    constraints.max_length, constraints.scheme
+                ),
+            }))
+        },
+    )
+}
+
+/// Validates a refund to the store credit of the customer of the payment, which no connector
+/// processes
+#[instrument(skip_all)]
+pub fn validate_store_credit_refund(
+    req: &api_models::refunds::RefundRequest,
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<()> {
+    utils::when(payment_intent.customer_id.is_none(), || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Only the payments of a customer can be refunded to store credit".to_string(),
+        }))
+    })?;
+    utils::when(req.split_refunds.is_some(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "split_refunds is not supported for the refunds to store credit".to_string(),
+        }))
+    })?;
+    utils::when(req.end_to_end_reference.is_some(), || {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "end_to_end_reference is not supported for the refunds to store credit"
+                .to_string(),
+        }))
+    })
+}
+
 #[instrument(skip_all)]
 pub fn validate_maximum_refund_against_payment_attempt(
     all_refunds: &[storage::Refund],
@@ -281,3 +499,94 @@ pub fn validate_xendit_charge_refund(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_end_to_end_reference_against_scheme_constraints() {
+        let sepa = get_end_to_end_reference_constraints(Some(api_enums::PaymentMethodType::Sepa));
+        assert!(is_valid_end_to_end_reference("REFUND ORDER 12345", &sepa));
+        assert!(!is_valid_end_to_end_reference("REFUND_ORDER_12345", &sepa));
+        assert!(!is_valid_end_to_end_reference(&"A".repeat(36), &sepa));
+        assert!(!is_valid_end_to_end_reference("  ", &sepa));
+
+        let faster_payments =
+            get_end_to_end_reference_constraints(Some(api_enums::PaymentMethodType::Bacs));
+        assert!(is_valid_end_to_end_reference("REF 12345", &faster_payments));
+        assert!(!is_valid_end_to_end_reference(
+            "REFUND ORDER 12345 ",
+            &faster_payments
+        ));
+
+        let pix = get_end_to_end_reference_constraints(Some(api_enums::PaymentMethodType::Pix));
+        assert!(is_valid_end_to_end_reference(
+            "Devolução do pedido 12345",
+            &pix
+        ));
+    }
+
+    #[test]
+    fn test_card_network_refund_validity_days_fall_back_to_the_defaults() {
+        let refund_config = serde_json::from_str::<settings::Refund>(r#"{"max_attempts": 5}"#)
+            .expect("Failed to deserialize the refund config");
+        assert_eq!(
+            get_card_network_refund_validity_days(&refund_config, &api_enums::CardNetwork::Visa),
+            Some(540)
+        );
+        assert_eq!(
+            get_card_network_refund_validity_days(&refund_config, &api_enums::CardNetwork::JCB),
+            Some(365)
+        );
+        assert_eq!(
+            get_card_network_refund_validity_days(&refund_config, &api_enums::CardNetwork::Interac),
+            None
+        );
+
+        let refund_config = serde_json::from_str::<settings::Refund>(
+            r#"{"card_network_validity_days": {"Visa": 120}}"#,
+        )
+        .expect("Failed to deserialize the refund config");
+        assert_eq!(
+            get_card_network_refund_validity_days(&refund_config, &api_enums::CardNetwork::Visa),
+            Some(120)
+        );
+        assert_eq!(
+            get_card_network_refund_validity_days(
+                &refund_config,
+                &api_enums::CardNetwork::Mastercard
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_refund_window_boundaries() {
+        let refund_windows = [
+            RefundWindow {
+                imposed_by: "Visa".to_string(),
+                validity_days: 540,
+            },
+            RefundWindow {
+                imposed_by: "stripe".to_string(),
+                validity_days: 180,
+            },
+        ];
+        let now = common_utils::date_time::now();
+
+        let created_at = now - time::Duration::days(180) + time::Duration::minutes(1);
+        assert!(validate_refund_window(&created_at, &refund_windows).is_ok());
+
+        // The strictest of the windows is reported once it has elapsed
+        let created_at = now - time::Duration::days(180) - time::Duration::minutes(1);
+        let error = validate_refund_window(&created_at, &refund_windows)
+            .expect_err("The refund window should have expired");
+        assert!(matches!(
+            error.current_context(),
+            RefundValidationError::RefundWindowExpired { imposed_by, .. } if imposed_by == "stripe"
+        ));
+
+        assert!(validate_refund_window(&created_at, &[]).is_ok());
+    }
+}
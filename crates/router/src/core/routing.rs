@@ -1,5 +1,11 @@
+#[cfg(feature = "v1")]
+pub mod experiments;
 pub mod helpers;
+#[cfg(feature = "v1")]
+pub mod simulation;
 pub mod transformers;
+#[cfg(feature = "v1")]
+pub mod volume_caps;
 use std::collections::HashSet;
 
 #[cfg(all(feature = "v1", feature = "dynamic_routing"))]
@@ -35,6 +41,8 @@ use super::{
     },
 };
 #[cfg(feature = "v1")]
+use crate::consts;
+#[cfg(feature = "v1")]
 use crate::utils::ValueExt;
 #[cfg(feature = "v2")]
 use crate::{core::admin, utils::ValueExt};
@@ -664,6 +672,172 @@ pub async fn retrieve_routing_algorithm_from_algorithm_id(
     Ok(service_api::ApplicationResponse::Json(response))
 }
 
+#[cfg(feature = "v1")]
+pub async fn export_routing_algorithm(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    algorithm_id: common_utils::id_type::RoutingId,
+) -> RouterResponse<routing_types::RoutingConfigExport> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(
+            &algorithm_id,
+            merchant_account.get_id(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        &key_store,
+        Some(&routing_algorithm.profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("Profile")
+    .change_context(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    // Dynamic routing algorithms hold the configuration of the dynamic routing service of the
+    // profile rather than a set of rules, so they cannot be carried over to another profile
+    utils::when(
+        routing_algorithm.kind == diesel_models::enums::RoutingAlgorithmKind::Dynamic,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Dynamic routing algorithms cannot be exported".to_string(),
+            })
+        },
+    )?;
+
+    let algorithm = routing_algorithm
+        .algorithm_data
+        .parse_value::<routing_types::RoutingAlgorithm>("RoutingAlgorithm")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to parse routing algorithm")?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        routing_types::RoutingConfigExport {
+            version: consts::ROUTING_CONFIG_EXPORT_VERSION,
+            name: routing_algorithm.name,
+            description: routing_algorithm.description.unwrap_or_default(),
+            kind: routing_algorithm.kind.foreign_into(),
+            algorithm_for: routing_algorithm.algorithm_for,
+            algorithm,
+            source_profile_id: routing_algorithm.profile_id,
+            exported_at: common_utils::date_time::now_unix_timestamp(),
+        },
+    ))
+}
+
+#[cfg(feature = "v1")]
+pub async fn import_routing_algorithm(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    authentication_profile_id: Option<common_utils::id_type::ProfileId>,
+    request: routing_types::RoutingConfigImportRequest,
+) -> RouterResponse<routing_types::RoutingDictionaryRecord> {
+    metrics::ROUTING_CREATE_REQUEST_RECEIVED.add(1, &[]);
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let routing_types::RoutingConfigImportRequest {
+        profile_id,
+        name,
+        config,
+    } = request;
+
+    utils::when(
+        config.version != consts::ROUTING_CONFIG_EXPORT_VERSION,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "unsupported version '{}' of the routing config, expected version '{}'",
+                    config.version,
+                    consts::ROUTING_CONFIG_EXPORT_VERSION
+                ),
+            })
+        },
+    )?;
+
+    let mut algorithm = config.algorithm;
+    utils::when(algorithm.get_kind() != config.kind, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "kind '{}' of the routing config does not match its algorithm",
+                config.kind
+            ),
+        })
+    })?;
+
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        key_manager_state,
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("Profile")?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    helpers::rebind_connectors_in_routing_config(
+        &state,
+        &key_store,
+        merchant_account.get_id(),
+        &profile_id,
+        &mut algorithm,
+    )
+    .await?;
+
+    helpers::validate_connectors_in_routing_config(
+        &state,
+        &key_store,
+        merchant_account.get_id(),
+        &profile_id,
+        &algorithm,
+    )
+    .await?;
+
+    helpers::validate_routing_config_with_cgraph(
+        &state,
+        &key_store,
+        &profile_id,
+        &config.algorithm_for,
+        &algorithm,
+    )
+    .await?;
+
+    let timestamp = common_utils::date_time::now();
+    let algo = RoutingAlgorithm {
+        algorithm_id: common_utils::generate_routing_id_of_default_length(),
+        profile_id,
+        merchant_id: merchant_account.get_id().to_owned(),
+        name: name.unwrap_or(config.name),
+        description: Some(config.description),
+        kind: algorithm.get_kind().foreign_into(),
+        algorithm_data: serde_json::json!(algorithm),
+        created_at: timestamp,
+        modified_at: timestamp,
+        algorithm_for: config.algorithm_for,
+    };
+    let record = db
+        .insert_routing_algorithm(algo)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    metrics::ROUTING_CREATE_SUCCESS_RESPONSE.add(1, &[]);
+    Ok(service_api::ApplicationResponse::Json(
+        record.foreign_into(),
+    ))
+}
+
 #[cfg(feature = "v2")]
 pub async fn unlink_routing_config_under_profile(
     state: SessionState,
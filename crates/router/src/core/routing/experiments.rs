@@ -0,0 +1,896 @@
+//! Experiments on the rules of the advanced routing algorithms. An experiment splits the traffic
+//! of a rule between the connector selection of the rule, the control, and a variant connector
+//! selection. The arm an attempt is routed to is remembered until the outcome of the attempt is
+//! known, and the outcomes are counted per arm. The experiments are evaluated on a schedule with a
+//! two-proportion z-test, and are concluded once the difference between the arms is significant,
+//! optionally promoting the winning arm to the connector selection of the rule.
+
+use std::collections::HashMap;
+
+use api_models::routing::{
+    self as routing_types, RoutingExperiment, RoutingExperimentArm, RoutingExperimentArmResult,
+    RoutingExperimentAuditEntry, RoutingExperimentConcludeRequest, RoutingExperimentCreateRequest,
+    RoutingExperimentEvent, RoutingExperimentMetric, RoutingExperimentResponse,
+    RoutingExperimentStatus,
+};
+use common_utils::{
+    date_time,
+    ext_traits::{Encode, StringExt, ValueExt},
+    id_type,
+};
+use diesel_models::{configs, routing_algorithm::RoutingAlgorithm};
+use error_stack::{report, ResultExt};
+use rand::Rng;
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+use time::Duration;
+
+use super::helpers;
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{domain, storage},
+    utils::{self, OptionExt},
+};
+
+const ROUTING_EXPERIMENT_TASK: &str = "ROUTING_EXPERIMENT_EVALUATION";
+const ROUTING_EXPERIMENT_TAG: &str = "ROUTING";
+
+/// The arm of a routing experiment an attempt was routed to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RoutingExperimentAssignment {
+    experiment_id: String,
+    arm: RoutingExperimentArm,
+    success_metric: RoutingExperimentMetric,
+}
+
+fn get_assignment_redis_key(merchant_id: &id_type::MerchantId, attempt_id: &str) -> String {
+    format!(
+        "routing_experiment_assignment_{}_{attempt_id}",
+        merchant_id.get_string_repr()
+    )
+}
+
+fn get_results_redis_key(experiment_id: &str) -> String {
+    format!("routing_experiment_results_{experiment_id}")
+}
+
+async fn get_routing_experiments(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Vec<RoutingExperiment>> {
+    // The experiments are looked up for every payment routed and every outcome of an attempt, so
+    // their absence is cached as well
+    db.find_config_by_key_unwrap_or(
+        &profile_id.get_routing_experiments_key(),
+        Some("[]".to_string()),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch routing experiments")?
+    .config
+    .parse_struct("Vec<RoutingExperiment>")
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to parse routing experiments")
+}
+
+async fn save_routing_experiments(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+    experiments: &[RoutingExperiment],
+) -> RouterResult<()> {
+    let key = profile_id.get_routing_experiments_key();
+    let serialized_experiments = experiments
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize routing experiments")?;
+
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => {
+            db.update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(serialized_experiments),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update routing experiments")?;
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            db.insert_config(configs::ConfigNew {
+                key,
+                config: serialized_experiments,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert routing experiments")?;
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch routing experiments");
+        }
+    }
+
+    Ok(())
+}
+
+fn get_routing_experiment_not_found_error(experiment_id: &str) -> errors::ApiErrorResponse {
+    errors::ApiErrorResponse::GenericNotFoundError {
+        message: format!("Routing experiment '{experiment_id}' not found for the profile"),
+    }
+}
+
+async fn get_business_profile(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    authentication_profile_id: Option<id_type::ProfileId>,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<domain::Profile> {
+    let business_profile = core_utils::validate_and_get_business_profile(
+        state.store.as_ref(),
+        &state.into(),
+        key_store,
+        Some(profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("Profile")?;
+
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    Ok(business_profile)
+}
+
+fn get_advanced_program(
+    routing_algorithm: &RoutingAlgorithm,
+) -> RouterResult<euclid::frontend::ast::Program<routing_types::ConnectorSelection>> {
+    match routing_algorithm
+        .algorithm_data
+        .clone()
+        .parse_value::<routing_types::RoutingAlgorithm>("RoutingAlgorithm")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to parse routing algorithm")?
+    {
+        routing_types::RoutingAlgorithm::Advanced(program) => Ok(program),
+        routing_types::RoutingAlgorithm::Single(_)
+        | routing_types::RoutingAlgorithm::Priority(_)
+        | routing_types::RoutingAlgorithm::VolumeSplit(_) => {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "experiments can only be run on the rules of advanced routing algorithms"
+                    .to_string(),
+            }))
+        }
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn create_routing_experiment(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    authentication_profile_id: Option<id_type::ProfileId>,
+    request: RoutingExperimentCreateRequest,
+) -> RouterResponse<RoutingExperiment> {
+    let db = state.store.as_ref();
+    let profile_id = request.profile_id;
+    get_business_profile(
+        &state,
+        &merchant_account,
+        &key_store,
+        authentication_profile_id,
+        &profile_id,
+    )
+    .await?;
+
+    utils::when(
+        !(1..=99).contains(&request.variant_traffic_percentage),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "variant_traffic_percentage should be between 1 and 99".to_string(),
+            })
+        },
+    )?;
+    let min_sample_size = request
+        .min_sample_size
+        .unwrap_or(consts::DEFAULT_ROUTING_EXPERIMENT_MIN_SAMPLE_SIZE);
+    utils::when(min_sample_size == 0, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "min_sample_size should be greater than 0".to_string(),
+        })
+    })?;
+
+    let routing_algorithm = db
+        .find_routing_algorithm_by_profile_id_algorithm_id(&profile_id, &request.algorithm_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+    let program = get_advanced_program(&routing_algorithm)?;
+    let control = program
+        .rules
+        .into_iter()
+        .find(|rule| rule.name == request.rule_name)
+        .map(|rule| rule.connector_selection)
+        .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "rule '{}' not found in the routing algorithm",
+                request.rule_name
+            ),
+        })?;
+
+    let variant_algorithm = match request.variant.clone() {
+        routing_types::ConnectorSelection::Priority(connectors) => {
+            routing_types::RoutingAlgorithm::Priority(connectors)
+        }
+        routing_types::ConnectorSelection::VolumeSplit(splits) => {
+            routing_types::RoutingAlgorithm::VolumeSplit(splits)
+        }
+    };
+    helpers::validate_connectors_in_routing_config(
+        &state,
+        &key_store,
+        merchant_account.get_id(),
+        &profile_id,
+        &variant_algorithm,
+    )
+    .await?;
+
+    let mut experiments = get_routing_experiments(db, &profile_id).await?;
+    utils::when(
+        experiments.iter().any(|experiment| {
+            experiment.status == RoutingExperimentStatus::Running
+                && experiment.algorithm_id == request.algorithm_id
+                && experiment.rule_name == request.rule_name
+        }),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "An experiment is already running on the rule".to_string(),
+            })
+        },
+    )?;
+
+    let created_at = date_time::now_unix_timestamp();
+    let experiment = RoutingExperiment {
+        experiment_id: common_utils::generate_id(consts::ID_LENGTH, "rexp"),
+        profile_id: profile_id.clone(),
+        name: request.name,
+        algorithm_id: request.algorithm_id,
+        rule_name: request.rule_name,
+        control,
+        variant: request.variant,
+        variant_traffic_percentage: request.variant_traffic_percentage,
+        success_metric: request.success_metric,
+        min_sample_size,
+        confidence_level: request.confidence_level.unwrap_or_default(),
+        auto_promote: request.auto_promote.unwrap_or(false),
+        status: RoutingExperimentStatus::Running,
+        winner: None,
+        promoted_algorithm_id: None,
+        created_at,
+        audit_trail: vec![RoutingExperimentAuditEntry {
+            event: RoutingExperimentEvent::Started,
+            arm: None,
+            details: None,
+            created_at,
+        }],
+    };
+    experiments.push(experiment.clone());
+    save_routing_experiments(db, &profile_id, &experiments).await?;
+
+    let tracking_data = storage::RoutingExperimentTrackingData {
+        merchant_id: merchant_account.get_id().clone(),
+        profile_id,
+        experiment_id: experiment.experiment_id.clone(),
+    };
+    add_routing_experiment_task(
+        db,
+        tracking_data,
+        date_time::now().saturating_add(Duration::seconds(
+            consts::ROUTING_EXPERIMENT_EVALUATION_INTERVAL_IN_SECS,
+        )),
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(experiment))
+}
+
+#[instrument(skip_all)]
+pub async fn list_routing_experiments(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    authentication_profile_id: Option<id_type::ProfileId>,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<Vec<RoutingExperiment>> {
+    get_business_profile(
+        &state,
+        &merchant_account,
+        &key_store,
+        authentication_profile_id,
+        &profile_id,
+    )
+    .await?;
+
+    let experiments = get_routing_experiments(state.store.as_ref(), &profile_id).await?;
+
+    Ok(services::ApplicationResponse::Json(experiments))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_routing_experiment(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    authentication_profile_id: Option<id_type::ProfileId>,
+    profile_id: id_type::ProfileId,
+    experiment_id: String,
+) -> RouterResponse<RoutingExperimentResponse> {
+    get_business_profile(
+        &state,
+        &merchant_account,
+        &key_store,
+        authentication_profile_id,
+        &profile_id,
+    )
+    .await?;
+
+    let experiment = get_routing_experiments(state.store.as_ref(), &profile_id)
+        .await?
+        .into_iter()
+        .find(|experiment| experiment.experiment_id == experiment_id)
+        .ok_or_else(|| get_routing_experiment_not_found_error(&experiment_id))?;
+
+    let (control_result, variant_result) =
+        get_experiment_results(&state, &experiment.experiment_id).await?;
+    let z_score = get_z_score(&control_result, &variant_result);
+
+    Ok(services::ApplicationResponse::Json(
+        RoutingExperimentResponse {
+            experiment,
+            control_result,
+            variant_result,
+            z_score,
+        },
+    ))
+}
+
+/// Concludes a running experiment with the given arm, promoting the arm if asked to, or cancels it
+/// if no arm is given
+#[instrument(skip_all)]
+pub async fn conclude_routing_experiment(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    authentication_profile_id: Option<id_type::ProfileId>,
+    experiment_id: String,
+    request: RoutingExperimentConcludeRequest,
+) -> RouterResponse<RoutingExperiment> {
+    let db = state.store.as_ref();
+    let profile_id = request.profile_id;
+    let business_profile = get_business_profile(
+        &state,
+        &merchant_account,
+        &key_store,
+        authentication_profile_id,
+        &profile_id,
+    )
+    .await?;
+
+    let mut experiments = get_routing_experiments(db, &profile_id).await?;
+    let experiment = experiments
+        .iter_mut()
+        .find(|experiment| experiment.experiment_id == experiment_id)
+        .ok_or_else(|| get_routing_experiment_not_found_error(&experiment_id))?;
+
+    utils::when(
+        experiment.status != RoutingExperimentStatus::Running,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!("The experiment is already {}", experiment.status),
+            })
+        },
+    )?;
+
+    match request.winner {
+        Some(winner) => {
+            let promote = request.promote.unwrap_or(experiment.auto_promote);
+            conclude_experiment(
+                &state,
+                &key_store,
+                business_profile,
+                experiment,
+                winner,
+                promote,
+                Some("Concluded manually".to_string()),
+            )
+            .await?;
+        }
+        None => {
+            experiment.status = RoutingExperimentStatus::Cancelled;
+            experiment.audit_trail.push(RoutingExperimentAuditEntry {
+                event: RoutingExperimentEvent::Cancelled,
+                arm: None,
+                details: None,
+                created_at: date_time::now_unix_timestamp(),
+            });
+        }
+    }
+
+    let experiment = experiment.clone();
+    save_routing_experiments(db, &profile_id, &experiments).await?;
+
+    Ok(services::ApplicationResponse::Json(experiment))
+}
+
+/// Marks the experiment as concluded with the winning arm, and promotes the winning arm to the
+/// connector selection of the rule if asked to. The control is the connector selection of the rule
+/// already, so only a winning variant needs to be promoted.
+async fn conclude_experiment(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: domain::Profile,
+    experiment: &mut RoutingExperiment,
+    winner: RoutingExperimentArm,
+    promote: bool,
+    details: Option<String>,
+) -> RouterResult<()> {
+    experiment.status = RoutingExperimentStatus::Concluded;
+    experiment.winner = Some(winner);
+    experiment.audit_trail.push(RoutingExperimentAuditEntry {
+        event: RoutingExperimentEvent::Concluded,
+        arm: Some(winner),
+        details,
+        created_at: date_time::now_unix_timestamp(),
+    });
+
+    if promote && winner == RoutingExperimentArm::Variant {
+        let algorithm_id =
+            promote_experiment_variant(state, key_store, business_profile, experiment).await?;
+        experiment.audit_trail.push(RoutingExperimentAuditEntry {
+            event: RoutingExperimentEvent::Promoted,
+            arm: Some(winner),
+            details: Some(format!(
+                "Created routing algorithm '{}'",
+                algorithm_id.get_string_repr()
+            )),
+            created_at: date_time::now_unix_timestamp(),
+        });
+        experiment.promoted_algorithm_id = Some(algorithm_id);
+    }
+
+    Ok(())
+}
+
+/// Creates a routing algorithm with the variant of the experiment as the connector selection of
+/// the rule, and activates it in place of the algorithm of the experiment if that is active
+async fn promote_experiment_variant(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: domain::Profile,
+    experiment: &RoutingExperiment,
+) -> RouterResult<id_type::RoutingId> {
+    let db = state.store.as_ref();
+    let routing_algorithm = db
+        .find_routing_algorithm_by_profile_id_algorithm_id(
+            &experiment.profile_id,
+            &experiment.algorithm_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the routing algorithm of the experiment")?;
+
+    let mut program = get_advanced_program(&routing_algorithm)?;
+    let rule = program
+        .rules
+        .iter_mut()
+        .find(|rule| rule.name == experiment.rule_name)
+        .ok_or(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "rule '{}' no longer exists in the routing algorithm",
+                experiment.rule_name
+            ),
+        })?;
+    rule.connector_selection = experiment.variant.clone();
+
+    let timestamp = date_time::now();
+    let algorithm_id = common_utils::generate_routing_id_of_default_length();
+    db.insert_routing_algorithm(RoutingAlgorithm {
+        algorithm_id: algorithm_id.clone(),
+        profile_id: routing_algorithm.profile_id,
+        merchant_id: routing_algorithm.merchant_id,
+        name: routing_algorithm.name,
+        description: Some(format!(
+            "Promoted the variant of the routing experiment '{}' on the rule '{}'",
+            experiment.experiment_id, experiment.rule_name
+        )),
+        kind: routing_algorithm.kind,
+        algorithm_data: serde_json::json!(routing_types::RoutingAlgorithm::Advanced(program)),
+        created_at: timestamp,
+        modified_at: timestamp,
+        algorithm_for: routing_algorithm.algorithm_for,
+    })
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to insert the promoted routing algorithm")?;
+
+    let mut routing_ref: routing_types::RoutingAlgorithmRef = business_profile
+        .routing_algorithm
+        .clone()
+        .map(|val| val.parse_value("RoutingAlgorithmRef"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to deserialize routing algorithm ref from business profile")?
+        .unwrap_or_default();
+
+    if routing_ref.algorithm_id.as_ref() == Some(&experiment.algorithm_id) {
+        routing_ref.update_algorithm_id(algorithm_id.clone());
+        helpers::update_profile_active_algorithm_ref(
+            db,
+            &state.into(),
+            key_store,
+            business_profile,
+            routing_ref,
+            &routing_algorithm.algorithm_for,
+        )
+        .await?;
+    }
+
+    Ok(algorithm_id)
+}
+
+#[allow(clippy::as_conversions)]
+async fn get_experiment_results(
+    state: &SessionState,
+    experiment_id: &str,
+) -> RouterResult<(RoutingExperimentArmResult, RoutingExperimentArmResult)> {
+    let results = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .get_hash_fields::<HashMap<String, u64>>(&get_results_redis_key(experiment_id).into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the results of the routing experiment")?;
+
+    let get_arm_result = |arm: RoutingExperimentArm| {
+        let attempts = results
+            .get(&format!("{arm}_attempts"))
+            .copied()
+            .unwrap_or_default();
+        let successes = results
+            .get(&format!("{arm}_successes"))
+            .copied()
+            .unwrap_or_default();
+        RoutingExperimentArmResult {
+            attempts,
+            successes,
+            success_rate: (attempts > 0).then(|| successes as f64 / attempts as f64),
+        }
+    };
+
+    Ok((
+        get_arm_result(RoutingExperimentArm::Control),
+        get_arm_result(RoutingExperimentArm::Variant),
+    ))
+}
+
+/// The z-score of the difference between the success rates of the variant and the control, as per
+/// the two-proportion z-test with the pooled success rate of the arms
+#[allow(clippy::as_conversions)]
+fn get_z_score(
+    control_result: &RoutingExperimentArmResult,
+    variant_result: &RoutingExperimentArmResult,
+) -> Option<f64> {
+    let control_rate = control_result.success_rate?;
+    let variant_rate = variant_result.success_rate?;
+    let control_attempts = control_result.attempts as f64;
+    let variant_attempts = variant_result.attempts as f64;
+
+    let pooled_rate = (control_result.successes + variant_result.successes) as f64
+        / (control_attempts + variant_attempts);
+    let standard_error =
+        (pooled_rate * (1.0 - pooled_rate) * (1.0 / control_attempts + 1.0 / variant_attempts))
+            .sqrt();
+
+    (standard_error > 0.0).then(|| (variant_rate - control_rate) / standard_error)
+}
+
+/// Evaluates a running experiment, concluding it if the difference between its arms is
+/// significant. Returns whether the experiment should be evaluated again.
+#[instrument(skip_all)]
+pub async fn evaluate_routing_experiment(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    tracking_data: &storage::RoutingExperimentTrackingData,
+) -> RouterResult<bool> {
+    let db = state.store.as_ref();
+    let mut experiments = get_routing_experiments(db, &tracking_data.profile_id).await?;
+    let Some(experiment) = experiments
+        .iter_mut()
+        .find(|experiment| experiment.experiment_id == tracking_data.experiment_id)
+    else {
+        logger::info!("Routing experiment no longer exists, skipping evaluation");
+        return Ok(false);
+    };
+
+    if experiment.status != RoutingExperimentStatus::Running {
+        return Ok(false);
+    }
+
+    let (control_result, variant_result) =
+        get_experiment_results(state, &experiment.experiment_id).await?;
+    let min_sample_size = u64::from(experiment.min_sample_size);
+    if control_result.attempts < min_sample_size || variant_result.attempts < min_sample_size {
+        return Ok(true);
+    }
+
+    let Some(z_score) = get_z_score(&control_result, &variant_result) else {
+        return Ok(true);
+    };
+    if z_score.abs() < experiment.confidence_level.get_critical_z_score() {
+        return Ok(true);
+    }
+
+    let winner = if z_score > 0.0 {
+        RoutingExperimentArm::Variant
+    } else {
+        RoutingExperimentArm::Control
+    };
+    let business_profile = db
+        .find_business_profile_by_profile_id(&state.into(), key_store, &tracking_data.profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: tracking_data.profile_id.get_string_repr().to_owned(),
+        })?;
+    let promote = experiment.auto_promote;
+    conclude_experiment(
+        state,
+        key_store,
+        business_profile,
+        experiment,
+        winner,
+        promote,
+        Some(format!(
+            "control: {}/{}, variant: {}/{}, z-score: {z_score:.3}",
+            control_result.successes,
+            control_result.attempts,
+            variant_result.successes,
+            variant_result.attempts
+        )),
+    )
+    .await?;
+    save_routing_experiments(db, &tracking_data.profile_id, &experiments).await?;
+
+    Ok(false)
+}
+
+async fn add_routing_experiment_task(
+    db: &dyn StorageInterface,
+    tracking_data: storage::RoutingExperimentTrackingData,
+    schedule_time: time::PrimitiveDateTime,
+) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::RoutingExperimentWorkflow;
+    let process_tracker_id = format!(
+        "{runner}_{ROUTING_EXPERIMENT_TASK}_{}_{}",
+        tracking_data.merchant_id.get_string_repr(),
+        tracking_data.experiment_id
+    );
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        ROUTING_EXPERIMENT_TASK,
+        runner,
+        [ROUTING_EXPERIMENT_TAG],
+        tracking_data,
+        None,
+        schedule_time,
+        hyperswitch_domain_models::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct ROUTING_EXPERIMENT process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while inserting ROUTING_EXPERIMENT task to process_tracker")?;
+
+    Ok(())
+}
+
+/// Returns the connector selection of the arm of the running experiment on the rule that the
+/// attempt is routed to, if there is one, remembering the arm for the outcome of the attempt to be
+/// counted against it. Failures are only logged, so that the rule is used as is.
+pub async fn get_experiment_connector_selection(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+    algorithm_id: &id_type::RoutingId,
+    rule_name: &str,
+    payment_attempt: &storage::PaymentAttempt,
+) -> Option<routing_types::ConnectorSelection> {
+    let experiment = get_routing_experiments(state.store.as_ref(), profile_id)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to fetch routing experiments"))
+        .ok()?
+        .into_iter()
+        .find(|experiment| {
+            experiment.status == RoutingExperimentStatus::Running
+                && &experiment.algorithm_id == algorithm_id
+                && experiment.rule_name == rule_name
+        })?;
+
+    let (arm, connector_selection) =
+        if rand::thread_rng().gen_range(0..100) < experiment.variant_traffic_percentage {
+            (RoutingExperimentArm::Variant, experiment.variant)
+        } else {
+            (RoutingExperimentArm::Control, experiment.control)
+        };
+
+    let assignment = RoutingExperimentAssignment {
+        experiment_id: experiment.experiment_id,
+        arm,
+        success_metric: experiment.success_metric,
+    };
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .map_err(|error| logger::error!(?error, "Failed to get redis connection"))
+        .ok()?;
+    redis_conn
+        .serialize_and_set_key_with_expiry(
+            &get_assignment_redis_key(&payment_attempt.merchant_id, &payment_attempt.attempt_id)
+                .into(),
+            assignment,
+            consts::ROUTING_EXPERIMENT_ASSIGNMENT_TTL_IN_SECS,
+        )
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to store routing experiment assignment"))
+        .ok()?;
+
+    Some(connector_selection)
+}
+
+fn is_outcome_resolved(
+    metric: RoutingExperimentMetric,
+    status: storage::enums::AttemptStatus,
+) -> bool {
+    match metric {
+        RoutingExperimentMetric::AuthorizationRate => {
+            status.is_terminal_status() || status == storage::enums::AttemptStatus::Authorized
+        }
+        RoutingExperimentMetric::CaptureRate => status.is_terminal_status(),
+    }
+}
+
+fn is_outcome_successful(
+    metric: RoutingExperimentMetric,
+    status: storage::enums::AttemptStatus,
+) -> bool {
+    use storage::enums::AttemptStatus;
+
+    match metric {
+        RoutingExperimentMetric::AuthorizationRate => matches!(
+            status,
+            AttemptStatus::Authorized
+                | AttemptStatus::Charged
+                | AttemptStatus::PartialCharged
+                | AttemptStatus::PartialChargedAndChargeable
+                | AttemptStatus::AutoRefunded
+                | AttemptStatus::Voided
+                | AttemptStatus::VoidFailed
+                | AttemptStatus::CaptureFailed
+        ),
+        RoutingExperimentMetric::CaptureRate => matches!(
+            status,
+            AttemptStatus::Charged | AttemptStatus::PartialCharged
+        ),
+    }
+}
+
+/// Counts the outcome of the attempt against the arm of the routing experiment it was routed to,
+/// once the outcome is known as per the success metric of the experiment. Failures are only
+/// logged, as the experiments should never affect the payments.
+pub async fn record_experiment_outcome(
+    state: &SessionState,
+    payment_attempt: &storage::PaymentAttempt,
+) {
+    // The attempts of the profiles without a running experiment were not assigned an arm, which
+    // spares looking up their assignment
+    let has_running_experiment =
+        get_routing_experiments(state.store.as_ref(), &payment_attempt.profile_id)
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to fetch routing experiments"))
+            .is_ok_and(|experiments| {
+                experiments
+                    .iter()
+                    .any(|experiment| experiment.status == RoutingExperimentStatus::Running)
+            });
+    if !has_running_experiment {
+        return;
+    }
+
+    let Ok(redis_conn) = state
+        .store
+        .get_redis_conn()
+        .map_err(|error| logger::error!(?error, "Failed to get redis connection"))
+    else {
+        return;
+    };
+
+    let key = get_assignment_redis_key(&payment_attempt.merchant_id, &payment_attempt.attempt_id);
+    let assignment = match redis_conn
+        .get_and_deserialize_key::<RoutingExperimentAssignment>(
+            &key.as_str().into(),
+            "RoutingExperimentAssignment",
+        )
+        .await
+    {
+        Ok(assignment) => assignment,
+        Err(error) if matches!(error.current_context(), errors::RedisError::NotFound) => return,
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch routing experiment assignment");
+            return;
+        }
+    };
+
+    if !is_outcome_resolved(assignment.success_metric, payment_attempt.status) {
+        return;
+    }
+
+    // The assignment is deleted before the outcome is counted, so that the outcome is counted
+    // only once even if the attempt is updated again
+    match redis_conn.delete_key(&key.as_str().into()).await {
+        Ok(redis_interface::DelReply::KeyDeleted) => {}
+        Ok(redis_interface::DelReply::KeyNotDeleted) => return,
+        Err(error) => {
+            logger::error!(?error, "Failed to delete routing experiment assignment");
+            return;
+        }
+    }
+
+    let arm = assignment.arm;
+    let successes = i64::from(is_outcome_successful(
+        assignment.success_metric,
+        payment_attempt.status,
+    ));
+    let results_key = get_results_redis_key(&assignment.experiment_id);
+    if let Err(error) = redis_conn
+        .increment_fields_in_hash(
+            &results_key.as_str().into(),
+            &[
+                (format!("{arm}_attempts"), 1),
+                (format!("{arm}_successes"), successes),
+            ],
+        )
+        .await
+    {
+        logger::error!(
+            ?error,
+            experiment_id = %assignment.experiment_id,
+            "Failed to record routing experiment outcome"
+        );
+        return;
+    }
+
+    // The outcomes of the experiments which are no longer routed to expire
+    if let Err(error) = redis_conn
+        .set_expiry(
+            &results_key.as_str().into(),
+            consts::ROUTING_EXPERIMENT_RESULTS_TTL_IN_SECS,
+        )
+        .await
+    {
+        logger::error!(
+            ?error,
+            experiment_id = %assignment.experiment_id,
+            "Failed to set the expiry of routing experiment outcomes"
+        );
+    }
+}
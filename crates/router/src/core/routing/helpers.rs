@@ -34,6 +34,8 @@ use storage_impl::redis::cache;
 #[cfg(all(feature = "dynamic_routing", feature = "v1"))]
 use storage_impl::redis::cache::Cacheable;
 
+#[cfg(feature = "v1")]
+use crate::core::payments::routing as payments_routing;
 #[cfg(all(feature = "dynamic_routing", feature = "v1"))]
 use crate::db::errors::StorageErrorExt;
 #[cfg(feature = "v2")]
@@ -551,6 +553,135 @@ pub async fn validate_connectors_in_routing_config(
     Ok(())
 }
 
+/// Applies the function to every connector choice of the routing algorithm
+#[cfg(feature = "v1")]
+fn for_each_connector_choice(
+    routing_algorithm: &mut routing_types::RoutingAlgorithm,
+    f: &dyn Fn(&mut routing_types::RoutableConnectorChoice) -> RouterResult<()>,
+) -> RouterResult<()> {
+    let for_each_in_selection = |selection: &mut routing_types::ConnectorSelection| match selection
+    {
+        routing_types::ConnectorSelection::Priority(list) => list.iter_mut().try_for_each(f),
+        routing_types::ConnectorSelection::VolumeSplit(splits) => splits
+            .iter_mut()
+            .try_for_each(|split| f(&mut split.connector)),
+    };
+
+    match routing_algorithm {
+        routing_types::RoutingAlgorithm::Single(choice) => f(choice.as_mut()),
+        routing_types::RoutingAlgorithm::Priority(list) => list.iter_mut().try_for_each(f),
+        routing_types::RoutingAlgorithm::VolumeSplit(splits) => splits
+            .iter_mut()
+            .try_for_each(|split| f(&mut split.connector)),
+        routing_types::RoutingAlgorithm::Advanced(program) => {
+            for_each_in_selection(&mut program.default_selection)?;
+            program
+                .rules
+                .iter_mut()
+                .try_for_each(|rule| for_each_in_selection(&mut rule.connector_selection))
+        }
+    }
+}
+
+/// Binds the connectors of a routing algorithm imported from another profile to the merchant
+/// connector accounts of the profile. A connector bound to a merchant connector account which is
+/// not of the profile is bound to the merchant connector account of the connector in the profile,
+/// provided that the profile has exactly one.
+#[cfg(feature = "v1")]
+pub async fn rebind_connectors_in_routing_config(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+    routing_algorithm: &mut routing_types::RoutingAlgorithm,
+) -> RouterResult<()> {
+    let profile_mcas = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &state.into(),
+            merchant_id,
+            true,
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_id.get_string_repr().to_owned(),
+        })?
+        .into_iter()
+        .filter(|mca| mca.profile_id == *profile_id)
+        .collect::<Vec<_>>();
+
+    let rebind_connector_choice =
+        |choice: &mut routing_types::RoutableConnectorChoice| -> RouterResult<()> {
+            let Some(mca_id) = choice.merchant_connector_id.as_ref() else {
+                return Ok(());
+            };
+
+            let connector_name = choice.connector.to_string();
+            let mut connector_mcas = profile_mcas
+                .iter()
+                .filter(|mca| mca.connector_name == connector_name);
+            if connector_mcas.clone().any(|mca| mca.get_id() == *mca_id) {
+                return Ok(());
+            }
+
+            match (connector_mcas.next(), connector_mcas.next()) {
+                (Some(mca), None) => {
+                    choice.merchant_connector_id = Some(mca.get_id());
+                    Ok(())
+                }
+                (Some(_), Some(_)) => Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "multiple merchant connector accounts of connector '{}' found for the given profile, the connector must be bound to one of them",
+                        choice.connector,
+                    ),
+                }
+                .into()),
+                (None, _) => Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "connector with name '{}' not found for the given profile",
+                        choice.connector,
+                    ),
+                }
+                .into()),
+            }
+        };
+
+    for_each_connector_choice(routing_algorithm, &rebind_connector_choice)
+}
+
+/// Validates the rules of an advanced routing algorithm against the constraint graph of the
+/// merchant connector accounts of the profile, so that a rule does not route payments to a
+/// connector which cannot accept them
+#[cfg(feature = "v1")]
+pub async fn validate_routing_config_with_cgraph(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+    transaction_type: &storage::enums::TransactionType,
+    routing_algorithm: &routing_types::RoutingAlgorithm,
+) -> RouterResult<()> {
+    let routing_types::RoutingAlgorithm::Advanced(program) = routing_algorithm else {
+        return Ok(());
+    };
+
+    let cgraph =
+        payments_routing::get_merchant_cgraph(state, key_store, profile_id, transaction_type)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the constraint graph of the profile")?;
+
+    euclid::dssa::analyzer::analyze(program.clone(), Some(cgraph.as_ref())).map_err(|error| {
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "routing rules are not supported by the connectors of the given profile: {error}"
+            ),
+        }
+    })?;
+
+    Ok(())
+}
+
 /// Provides the identifier for the specific merchant's routing_dictionary_key
 #[inline(always)]
 pub fn get_routing_dictionary_key(merchant_id: &str) -> String {
@@ -0,0 +1,464 @@
+//! Simulation of the routing of a hypothetical payment. The payment is routed server-side the way
+//! the payments of the profile are routed, through the routing algorithm, the default fallback
+//! connectors, the eligibility of the connectors as per the constraint graph of the profile and
+//! the availability of the merchant connector accounts, so that the simulation does not drift from
+//! the actual routing the way a client-side preview of the routing algorithm can.
+
+use api_models::routing::{
+    ConnectorSelection, ConnectorVolumeSplit, RoutableConnectorChoice, RoutingAlgorithm,
+    RoutingAlgorithmRef, RoutingEliminationReason, RoutingSimulationCandidate,
+    RoutingSimulationElimination, RoutingSimulationRequest, RoutingSimulationResponse,
+    RoutingSimulationSource,
+};
+use common_utils::{ext_traits::ValueExt, id_type};
+use error_stack::ResultExt;
+use euclid::backend::{self, inputs as dsl_inputs, EuclidBackend};
+use router_env::{instrument, tracing};
+
+use super::helpers;
+use crate::{
+    core::{
+        connector_credentials, connector_maintenance,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::routing as payments_routing,
+        test_mode, utils as core_utils,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{domain, storage::enums, transformers::ForeignInto},
+    utils::{self, OptionExt},
+};
+
+/// A connector chosen by the simulated routing, along with how it was chosen
+struct SimulatedConnector {
+    choice: RoutableConnectorChoice,
+    source: RoutingSimulationSource,
+    volume_split: Option<u8>,
+}
+
+impl SimulatedConnector {
+    fn new(choice: RoutableConnectorChoice, source: RoutingSimulationSource) -> Self {
+        Self {
+            choice,
+            source,
+            volume_split: None,
+        }
+    }
+
+    fn into_candidate(self) -> RoutingSimulationCandidate {
+        RoutingSimulationCandidate {
+            connector: self.choice.connector,
+            merchant_connector_id: self.choice.merchant_connector_id,
+            source: self.source,
+            volume_split: self.volume_split,
+        }
+    }
+}
+
+fn get_choices(connectors: &[SimulatedConnector]) -> Vec<RoutableConnectorChoice> {
+    connectors
+        .iter()
+        .map(|connector| connector.choice.clone())
+        .collect()
+}
+
+/// Retains the connectors accepted by the filter, and records the other connectors as eliminated
+/// for the given reason
+fn eliminate_connectors(
+    connectors: Vec<SimulatedConnector>,
+    is_retained: impl Fn(&SimulatedConnector) -> bool,
+    reason: RoutingEliminationReason,
+    eliminated: &mut Vec<RoutingSimulationElimination>,
+) -> Vec<SimulatedConnector> {
+    let (retained, removed): (Vec<_>, Vec<_>) = connectors.into_iter().partition(is_retained);
+    eliminated.extend(
+        removed
+            .into_iter()
+            .map(|connector| RoutingSimulationElimination {
+                candidate: connector.into_candidate(),
+                reason,
+            }),
+    );
+
+    retained
+}
+
+/// Retains the connectors which are still routable once the filter of the routing is applied
+fn eliminate_filtered_connectors(
+    connectors: Vec<SimulatedConnector>,
+    routable_connectors: &[RoutableConnectorChoice],
+    reason: RoutingEliminationReason,
+    eliminated: &mut Vec<RoutingSimulationElimination>,
+) -> Vec<SimulatedConnector> {
+    eliminate_connectors(
+        connectors,
+        |connector| routable_connectors.contains(&connector.choice),
+        reason,
+        eliminated,
+    )
+}
+
+fn make_dsl_input(request: &RoutingSimulationRequest) -> dsl_inputs::BackendInput {
+    dsl_inputs::BackendInput {
+        metadata: request
+            .metadata
+            .clone()
+            .map(|metadata| metadata.into_iter().collect()),
+        payment: dsl_inputs::PaymentInput {
+            amount: request.amount,
+            currency: request.currency,
+            authentication_type: request.authentication_type,
+            card_bin: request
+                .card_bin
+                .as_ref()
+                .map(|card_bin| card_bin.chars().take(6).collect()),
+            capture_method: request
+                .capture_method
+                .and_then(|capture_method| capture_method.foreign_into()),
+            business_country: request
+                .business_country
+                .map(common_enums::Country::from_alpha2),
+            billing_country: request
+                .billing_country
+                .map(common_enums::Country::from_alpha2),
+            business_label: request.business_label.clone(),
+            setup_future_usage: request.setup_future_usage,
+            issuer_frictionless_rate: None,
+        },
+        payment_method: dsl_inputs::PaymentMethodInput {
+            payment_method: Some(request.payment_method),
+            payment_method_type: request.payment_method_type,
+            card_network: request.card_network.clone(),
+            card_product_type: None,
+        },
+        mandate: dsl_inputs::MandateData {
+            mandate_acceptance_type: None,
+            mandate_type: None,
+            payment_type: Some(euclid::enums::PaymentType::NonMandate),
+        },
+    }
+}
+
+/// The connectors of a volume split, ordered by their share of the traffic
+fn get_volume_split_connectors(mut splits: Vec<ConnectorVolumeSplit>) -> Vec<SimulatedConnector> {
+    splits.sort_by(|split, other_split| other_split.split.cmp(&split.split));
+    splits
+        .into_iter()
+        .map(|split| SimulatedConnector {
+            volume_split: Some(split.split),
+            ..SimulatedConnector::new(split.connector, RoutingSimulationSource::Algorithm)
+        })
+        .collect()
+}
+
+fn get_priority_connectors(connectors: Vec<RoutableConnectorChoice>) -> Vec<SimulatedConnector> {
+    connectors
+        .into_iter()
+        .map(|connector| SimulatedConnector::new(connector, RoutingSimulationSource::Algorithm))
+        .collect()
+}
+
+/// Runs the routing algorithm on the payment, returning the chosen connectors along with the name
+/// of the matched rule of an advanced routing algorithm
+fn perform_static_routing(
+    algorithm: RoutingAlgorithm,
+    backend_input: dsl_inputs::BackendInput,
+) -> RouterResult<(Vec<SimulatedConnector>, Option<String>)> {
+    Ok(match algorithm {
+        RoutingAlgorithm::Single(connector) => (get_priority_connectors(vec![*connector]), None),
+        RoutingAlgorithm::Priority(connectors) => (get_priority_connectors(connectors), None),
+        RoutingAlgorithm::VolumeSplit(splits) => (get_volume_split_connectors(splits), None),
+        RoutingAlgorithm::Advanced(program) => {
+            let interpreter = backend::VirInterpreterBackend::with_program(program)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Error initializing DSL interpreter backend")?;
+            let output = interpreter
+                .execute(backend_input)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to execute the routing algorithm")?;
+
+            let connectors = match output.connector_selection {
+                ConnectorSelection::Priority(connectors) => get_priority_connectors(connectors),
+                ConnectorSelection::VolumeSplit(splits) => get_volume_split_connectors(splits),
+            };
+            (connectors, output.rule_name)
+        }
+    })
+}
+
+async fn get_routing_algorithm(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+    algorithm_id: &id_type::RoutingId,
+) -> RouterResult<RoutingAlgorithm> {
+    let routing_algorithm = db
+        .find_routing_algorithm_by_algorithm_id_merchant_id(algorithm_id, merchant_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
+
+    utils::when(
+        routing_algorithm.profile_id != *profile_id
+            || routing_algorithm.algorithm_for != enums::TransactionType::Payment,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "routing algorithm does not route the payments of the profile".to_string(),
+            })
+        },
+    )?;
+
+    // Dynamic routing algorithms hold the configuration of the dynamic routing service of the
+    // profile, whose routing depends on the live traffic of the profile
+    utils::when(
+        routing_algorithm.kind == diesel_models::enums::RoutingAlgorithmKind::Dynamic,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Dynamic routing algorithms cannot be simulated".to_string(),
+            })
+        },
+    )?;
+
+    routing_algorithm
+        .algorithm_data
+        .parse_value("RoutingAlgorithm")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("unable to parse routing algorithm")
+}
+
+/// Eliminates the connectors the payment is not restricted to, and the connectors which do not
+/// support the payment as per the constraint graph of the profile
+async fn perform_eligibility_analysis(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+    connectors: Vec<SimulatedConnector>,
+    request: &RoutingSimulationRequest,
+    backend_input: &dsl_inputs::BackendInput,
+    eliminated: &mut Vec<RoutingSimulationElimination>,
+) -> RouterResult<Vec<SimulatedConnector>> {
+    let connectors = match request.connector.as_ref() {
+        Some(eligible_connectors) => eliminate_connectors(
+            connectors,
+            |connector| eligible_connectors.contains(&connector.choice.connector),
+            RoutingEliminationReason::NotInEligibleConnectors,
+            eliminated,
+        ),
+        None => connectors,
+    };
+
+    let eligible_connectors = payments_routing::perform_cgraph_filtering(
+        state,
+        key_store,
+        get_choices(&connectors),
+        backend_input.clone(),
+        None,
+        profile_id,
+        &enums::TransactionType::Payment,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to perform the eligibility analysis of the connectors")?;
+
+    Ok(eliminate_filtered_connectors(
+        connectors,
+        &eligible_connectors,
+        RoutingEliminationReason::ConstraintGraph,
+        eliminated,
+    ))
+}
+
+/// Eliminates the connectors whose merchant connector accounts are not available for routing, in
+/// the order in which the routing of the payments filters them
+async fn perform_availability_analysis(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
+    connectors: Vec<SimulatedConnector>,
+    payment_method: enums::PaymentMethod,
+    eliminated: &mut Vec<RoutingSimulationElimination>,
+) -> RouterResult<Vec<SimulatedConnector>> {
+    let db = state.store.as_ref();
+
+    let available_connectors = connector_maintenance::filter_connectors_under_maintenance(
+        db,
+        get_choices(&connectors),
+        Some(payment_method),
+    )
+    .await?;
+    let connectors = eliminate_filtered_connectors(
+        connectors,
+        &available_connectors,
+        RoutingEliminationReason::UnderMaintenance,
+        eliminated,
+    );
+
+    let available_connectors = connector_credentials::filter_connectors_with_expired_credentials(
+        db,
+        get_choices(&connectors),
+    )
+    .await?;
+    let connectors = eliminate_filtered_connectors(
+        connectors,
+        &available_connectors,
+        RoutingEliminationReason::CredentialsExpired,
+        eliminated,
+    );
+
+    // The routing of the payments fails if none of the connectors has a test merchant connector
+    // account, which eliminates all the connectors here
+    let available_connectors = match test_mode::filter_connectors_for_test_mode(
+        state,
+        merchant_id,
+        key_store,
+        business_profile,
+        get_choices(&connectors),
+    )
+    .await
+    {
+        Ok(available_connectors) => available_connectors,
+        Err(error)
+            if matches!(
+                error.current_context(),
+                errors::ApiErrorResponse::PreconditionFailed { .. }
+            ) =>
+        {
+            Vec::new()
+        }
+        Err(error) => return Err(error),
+    };
+    let connectors = eliminate_filtered_connectors(
+        connectors,
+        &available_connectors,
+        RoutingEliminationReason::NotInTestMode,
+        eliminated,
+    );
+
+    let available_connectors = super::volume_caps::filter_connectors_over_volume_caps(
+        state,
+        merchant_id,
+        key_store,
+        business_profile.get_id(),
+        get_choices(&connectors),
+    )
+    .await;
+    Ok(eliminate_filtered_connectors(
+        connectors,
+        &available_connectors,
+        RoutingEliminationReason::VolumeCapReached,
+        eliminated,
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn simulate_routing(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    authentication_profile_id: Option<id_type::ProfileId>,
+    request: RoutingSimulationRequest,
+) -> RouterResponse<RoutingSimulationResponse> {
+    let db = state.store.as_ref();
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&request.profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("Profile")?;
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+    let profile_id = business_profile.get_id().clone();
+
+    let algorithm_id = match request.algorithm_id.clone() {
+        Some(algorithm_id) => Some(algorithm_id),
+        None => business_profile
+            .routing_algorithm
+            .clone()
+            .map(|algorithm| algorithm.parse_value::<RoutingAlgorithmRef>("RoutingAlgorithmRef"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Could not decode merchant routing algorithm ref")?
+            .and_then(|algorithm_ref| algorithm_ref.algorithm_id),
+    };
+
+    let backend_input = make_dsl_input(&request);
+    let (algorithm_connectors, matched_rule) = match algorithm_id.as_ref() {
+        Some(algorithm_id) => {
+            let algorithm =
+                get_routing_algorithm(db, merchant_account.get_id(), &profile_id, algorithm_id)
+                    .await?;
+            perform_static_routing(algorithm, backend_input.clone())?
+        }
+        None => (Vec::new(), None),
+    };
+
+    let mut eliminated = Vec::new();
+    let mut connectors = perform_eligibility_analysis(
+        &state,
+        &key_store,
+        &profile_id,
+        algorithm_connectors,
+        &request,
+        &backend_input,
+        &mut eliminated,
+    )
+    .await?;
+
+    // The fallback connectors are appended to the connectors chosen by the routing algorithm,
+    // except for the connectors which were already chosen
+    let fallback_connectors = helpers::get_merchant_default_config(
+        db,
+        profile_id.get_string_repr(),
+        &enums::TransactionType::Payment,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch the default fallback connectors of the profile")?
+    .into_iter()
+    .filter(|choice| {
+        !connectors
+            .iter()
+            .any(|connector| connector.choice == *choice)
+    })
+    .map(|choice| SimulatedConnector::new(choice, RoutingSimulationSource::Fallback))
+    .collect();
+    connectors.extend(
+        perform_eligibility_analysis(
+            &state,
+            &key_store,
+            &profile_id,
+            fallback_connectors,
+            &request,
+            &backend_input,
+            &mut eliminated,
+        )
+        .await?,
+    );
+
+    let candidates = perform_availability_analysis(
+        &state,
+        merchant_account.get_id(),
+        &key_store,
+        &business_profile,
+        connectors,
+        request.payment_method,
+        &mut eliminated,
+    )
+    .await?
+    .into_iter()
+    .map(SimulatedConnector::into_candidate)
+    .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        RoutingSimulationResponse {
+            profile_id,
+            algorithm_id,
+            matched_rule,
+            candidates,
+            eliminated,
+        },
+    ))
+}
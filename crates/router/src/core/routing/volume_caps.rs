@@ -0,0 +1,635 @@
+//! Daily volume caps of the connectors of a profile, such as the contractual maximums agreed with
+//! the connectors. The payments authorized through the capped connectors are counted per day
+//! (UTC), and the connectors are excluded from routing for the rest of the day once any of their
+//! caps is reached, shifting their traffic to the other eligible connectors.
+
+use std::collections::{HashMap, HashSet};
+
+use api_models::{
+    payments as payment_types,
+    routing::{
+        ConnectorVolumeCap, ConnectorVolumeCapUsage, ConnectorVolumeCapsRequest,
+        ConnectorVolumeCapsResponse, RoutableConnectorChoice,
+    },
+    webhooks,
+};
+use common_utils::{
+    date_time,
+    ext_traits::{Encode, StringExt},
+    id_type,
+    types::MinorUnit,
+};
+use diesel_models::configs;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use tracing_futures::Instrument;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments as payments_core, utils as core_utils, webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+    utils::{self, OptionExt},
+};
+
+fn get_volume_redis_key(profile_id: &id_type::ProfileId, date: time::Date) -> String {
+    format!("connector_volume_{}_{date}", profile_id.get_string_repr())
+}
+
+fn get_payments_field(merchant_connector_id: &id_type::MerchantConnectorAccountId) -> String {
+    format!("{}_payments", merchant_connector_id.get_string_repr())
+}
+
+fn get_amount_field(merchant_connector_id: &id_type::MerchantConnectorAccountId) -> String {
+    format!("{}_amount", merchant_connector_id.get_string_repr())
+}
+
+pub async fn get_connector_volume_caps(
+    db: &dyn StorageInterface,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Vec<ConnectorVolumeCap>> {
+    // The caps are looked up for every payment routed and every attempt authorized, so their
+    // absence is cached as well
+    db.find_config_by_key_unwrap_or(
+        &profile_id.get_connector_volume_caps_key(),
+        Some("[]".to_string()),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch connector volume caps")?
+    .config
+    .parse_struct("Vec<ConnectorVolumeCap>")
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to parse connector volume caps")
+}
+
+/// The number of payments and the amount authorized today through the connectors of the profile,
+/// keyed by the fields of the connectors
+async fn get_connector_volumes(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<HashMap<String, u64>> {
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .get_hash_fields::<HashMap<String, u64>>(
+            &get_volume_redis_key(profile_id, date_time::now().date()).into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch connector volumes")
+}
+
+fn get_cap_usage(
+    cap: ConnectorVolumeCap,
+    volumes: &HashMap<String, u64>,
+) -> ConnectorVolumeCapUsage {
+    let payments_today = volumes
+        .get(&get_payments_field(&cap.merchant_connector_id))
+        .copied()
+        .unwrap_or_default();
+    let amount_today = cap.max_daily_amount.map(|_| {
+        MinorUnit::new(
+            volumes
+                .get(&get_amount_field(&cap.merchant_connector_id))
+                .and_then(|amount| i64::try_from(*amount).ok())
+                .unwrap_or_default(),
+        )
+    });
+    let is_cap_reached = cap
+        .max_daily_payments
+        .is_some_and(|max_daily_payments| payments_today >= max_daily_payments)
+        || cap
+            .max_daily_amount
+            .zip(amount_today)
+            .is_some_and(|(max_daily_amount, amount_today)| amount_today >= max_daily_amount);
+
+    ConnectorVolumeCapUsage {
+        cap,
+        payments_today,
+        amount_today,
+        is_cap_reached,
+    }
+}
+
+async fn validate_connector_volume_caps(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+    caps: &[ConnectorVolumeCap],
+) -> RouterResult<()> {
+    let mut merchant_connector_ids = HashSet::new();
+    caps.iter().try_for_each(|cap| {
+        utils::when(
+            !merchant_connector_ids.insert(cap.merchant_connector_id.clone()),
+            || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "more than one cap provided for merchant connector account '{}'",
+                        cap.merchant_connector_id.get_string_repr()
+                    ),
+                })
+            },
+        )?;
+        utils::when(
+            cap.max_daily_payments.is_none() && cap.max_daily_amount.is_none(),
+            || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "either max_daily_payments or max_daily_amount must be provided for \
+                        a cap"
+                        .to_string(),
+                })
+            },
+        )?;
+        utils::when(cap.max_daily_payments == Some(0), || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "max_daily_payments should be greater than 0".to_string(),
+            })
+        })?;
+        utils::when(
+            cap.max_daily_amount
+                .is_some_and(|max_daily_amount| max_daily_amount <= MinorUnit::zero()),
+            || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "max_daily_amount should be greater than 0".to_string(),
+                })
+            },
+        )?;
+        utils::when(
+            cap.max_daily_amount.is_some() != cap.currency.is_some(),
+            || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "currency must be provided along with max_daily_amount, and only \
+                        with it"
+                        .to_string(),
+                })
+            },
+        )
+    })?;
+
+    let merchant_connector_accounts = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &state.into(),
+            merchant_id,
+            true,
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch merchant connector accounts")?;
+
+    caps.iter().try_for_each(|cap| {
+        utils::when(
+            !merchant_connector_accounts.iter().any(|mca| {
+                &mca.profile_id == profile_id && mca.get_id() == cap.merchant_connector_id
+            }),
+            || {
+                Err(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+                    id: cap.merchant_connector_id.get_string_repr().to_string(),
+                })
+            },
+        )
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn upsert_connector_volume_caps(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    authentication_profile_id: Option<id_type::ProfileId>,
+    request: ConnectorVolumeCapsRequest,
+) -> RouterResponse<ConnectorVolumeCapsResponse> {
+    let db = state.store.as_ref();
+    let profile_id = request.profile_id;
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("Profile")?;
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    validate_connector_volume_caps(
+        &state,
+        merchant_account.get_id(),
+        &key_store,
+        &profile_id,
+        &request.caps,
+    )
+    .await?;
+
+    let key = profile_id.get_connector_volume_caps_key();
+    let serialized_caps = request
+        .caps
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize connector volume caps")?;
+
+    match db.find_config_by_key_from_db(&key).await {
+        Ok(_) => {
+            db.update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(serialized_caps),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update connector volume caps")?;
+        }
+        Err(error) if error.current_context().is_db_not_found() => {
+            db.insert_config(configs::ConfigNew {
+                key,
+                config: serialized_caps,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert connector volume caps")?;
+        }
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch connector volume caps");
+        }
+    }
+
+    let volumes = get_connector_volumes(&state, &profile_id).await?;
+    let caps = request
+        .caps
+        .into_iter()
+        .map(|cap| get_cap_usage(cap, &volumes))
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        ConnectorVolumeCapsResponse { profile_id, caps },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_connector_volume_caps(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    authentication_profile_id: Option<id_type::ProfileId>,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<ConnectorVolumeCapsResponse> {
+    let db = state.store.as_ref();
+    let business_profile = core_utils::validate_and_get_business_profile(
+        db,
+        &(&state).into(),
+        &key_store,
+        Some(&profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .get_required_value("Profile")?;
+    core_utils::validate_profile_id_from_auth_layer(authentication_profile_id, &business_profile)?;
+
+    let volumes = get_connector_volumes(&state, &profile_id).await?;
+    let caps = get_connector_volume_caps(db, &profile_id)
+        .await?
+        .into_iter()
+        .map(|cap| get_cap_usage(cap, &volumes))
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        ConnectorVolumeCapsResponse { profile_id, caps },
+    ))
+}
+
+/// Removes the connectors which have reached their daily volume caps from the routed connectors.
+/// The caps are not enforced if all the routed connectors have reached them, so that the payment
+/// is not failed for want of a connector, and they are not enforced either if they could not be
+/// fetched. The connectors routed without a merchant connector account are capped through the
+/// account of the connector in the profile, which is the one the payment would be made through.
+#[instrument(skip_all)]
+pub async fn filter_connectors_over_volume_caps(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+    connectors: Vec<RoutableConnectorChoice>,
+) -> Vec<RoutableConnectorChoice> {
+    let caps = match get_connector_volume_caps(state.store.as_ref(), profile_id).await {
+        Ok(caps) if caps.is_empty() => return connectors,
+        Ok(caps) => caps,
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch connector volume caps");
+            return connectors;
+        }
+    };
+    let volumes = match get_connector_volumes(state, profile_id).await {
+        Ok(volumes) => volumes,
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch connector volumes");
+            return connectors;
+        }
+    };
+
+    let capped_connectors = caps
+        .into_iter()
+        .map(|cap| get_cap_usage(cap, &volumes))
+        .filter(|usage| usage.is_cap_reached)
+        .map(|usage| usage.cap.merchant_connector_id)
+        .collect::<HashSet<_>>();
+    if capped_connectors.is_empty() {
+        return connectors;
+    }
+
+    let profile_connector_accounts = if connectors
+        .iter()
+        .any(|connector| connector.merchant_connector_id.is_none())
+    {
+        match get_profile_connector_accounts(state, merchant_id, key_store, profile_id).await {
+            Ok(profile_connector_accounts) => profile_connector_accounts,
+            Err(error) => {
+                logger::error!(?error, "Failed to fetch merchant connector accounts");
+                return connectors;
+            }
+        }
+    } else {
+        HashMap::new()
+    };
+
+    let available_connectors = connectors
+        .iter()
+        .filter(|connector| {
+            connector
+                .merchant_connector_id
+                .as_ref()
+                .or_else(|| profile_connector_accounts.get(&connector.connector.to_string()))
+                .map_or(true, |merchant_connector_id| {
+                    !capped_connectors.contains(merchant_connector_id)
+                })
+        })
+        .cloned()
+        .collect::<Vec<_>>();
+
+    if available_connectors.is_empty() {
+        logger::warn!(
+            "All the routed connectors have reached their daily volume caps, ignoring the caps"
+        );
+        return connectors;
+    }
+    if available_connectors.len() < connectors.len() {
+        logger::info!(
+            "Excluding connectors from routing as they have reached their daily volume caps"
+        );
+    }
+
+    available_connectors
+}
+
+/// The merchant connector accounts of the profile, keyed by the names of their connectors
+async fn get_profile_connector_accounts(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<HashMap<String, id_type::MerchantConnectorAccountId>> {
+    Ok(state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &state.into(),
+            merchant_id,
+            false,
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch merchant connector accounts")?
+        .into_iter()
+        .filter(|mca| &mca.profile_id == profile_id)
+        .map(|mca| (mca.connector_name.clone(), mca.get_id()))
+        .collect())
+}
+
+fn is_counted_status(status: enums::AttemptStatus) -> bool {
+    matches!(
+        status,
+        enums::AttemptStatus::Authorized
+            | enums::AttemptStatus::Charged
+            | enums::AttemptStatus::PartialCharged
+            | enums::AttemptStatus::PartialChargedAndChargeable
+    )
+}
+
+/// Counts the attempt against the daily volume caps of its connector when it is authorized, and
+/// emits an event for the payment if it makes the connector reach any of its caps. Failures are
+/// only logged, as the caps should never fail the payments.
+#[instrument(skip_all)]
+pub async fn record_connector_volume(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    previous_status: enums::AttemptStatus,
+    payment_attempt: &storage::PaymentAttempt,
+) {
+    if is_counted_status(previous_status) || !is_counted_status(payment_attempt.status) {
+        return;
+    }
+    let Some(merchant_connector_id) = payment_attempt.merchant_connector_id.as_ref() else {
+        return;
+    };
+
+    let profile_id = &payment_attempt.profile_id;
+    let cap = match get_connector_volume_caps(state.store.as_ref(), profile_id).await {
+        Ok(caps) => caps
+            .into_iter()
+            .find(|cap| &cap.merchant_connector_id == merchant_connector_id),
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch connector volume caps");
+            return;
+        }
+    };
+    let Some(cap) = cap else {
+        return;
+    };
+
+    let Ok(redis_conn) = state
+        .store
+        .get_redis_conn()
+        .map_err(|error| logger::error!(?error, "Failed to get redis connection"))
+    else {
+        return;
+    };
+
+    // Only the payments in the currency of the cap are counted against its maximum amount
+    let amount = cap
+        .max_daily_amount
+        .filter(|_| cap.currency == payment_attempt.currency)
+        .map(|_| payment_attempt.get_total_amount().get_amount_as_i64());
+    let mut fields = vec![(get_payments_field(merchant_connector_id), 1)];
+    if let Some(amount) = amount {
+        fields.push((get_amount_field(merchant_connector_id), amount));
+    }
+
+    let key = get_volume_redis_key(profile_id, date_time::now().date());
+    let values = match redis_conn
+        .increment_fields_in_hash(&key.as_str().into(), &fields)
+        .await
+    {
+        Ok(values) => values,
+        Err(error) => {
+            logger::error!(?error, "Failed to record connector volume");
+            return;
+        }
+    };
+    if let Err(error) = redis_conn
+        .set_expiry(&key.as_str().into(), consts::CONNECTOR_VOLUME_TTL_IN_SECS)
+        .await
+    {
+        logger::error!(?error, "Failed to set expiry of connector volumes");
+    }
+
+    let payments_today = values
+        .first()
+        .and_then(|value| u64::try_from(*value).ok())
+        .unwrap_or_default();
+    let amount_today = values
+        .get(1)
+        .and_then(|value| i64::try_from(*value).ok())
+        .map(MinorUnit::new);
+
+    // The event is emitted only for the payment that makes the connector reach a cap, and not for
+    // the payments counted after it, which were routed before the cap was reached
+    let has_reached_payments_cap = cap.max_daily_payments == Some(payments_today);
+    let has_reached_amount_cap = cap
+        .max_daily_amount
+        .zip(amount_today.zip(amount))
+        .is_some_and(|(max_daily_amount, (amount_today, amount))| {
+            amount_today >= max_daily_amount
+                && amount_today - MinorUnit::new(amount) < max_daily_amount
+        });
+    if !(has_reached_payments_cap || has_reached_amount_cap) {
+        return;
+    }
+
+    logger::info!(
+        merchant_connector_id = merchant_connector_id.get_string_repr(),
+        "Connector has reached its daily volume cap"
+    );
+
+    let state = state.clone();
+    let key_store = key_store.clone();
+    let payment_id = payment_attempt.payment_id.clone();
+    let profile_id = profile_id.clone();
+    tokio::spawn(
+        async move {
+            trigger_volume_cap_reached_webhook(&state, key_store, &profile_id, &payment_id).await
+        }
+        .in_current_span(),
+    );
+}
+
+async fn trigger_volume_cap_reached_webhook(
+    state: &SessionState,
+    key_store: domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+    payment_id: &id_type::PaymentId,
+) {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let merchant_account = match db
+        .find_merchant_account_by_merchant_id(key_manager_state, &key_store.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)
+    {
+        Ok(merchant_account) => merchant_account,
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch the merchant account");
+            return;
+        }
+    };
+    let business_profile = match db
+        .find_business_profile_by_profile_id(key_manager_state, &key_store, profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        }) {
+        Ok(business_profile) => business_profile,
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch the business profile");
+            return;
+        }
+    };
+
+    let request = payment_types::PaymentsRetrieveRequest {
+        resource_id: payment_types::PaymentIdType::PaymentIntentId(payment_id.clone()),
+        merchant_id: Some(merchant_account.get_id().clone()),
+        force_sync: false,
+        ..Default::default()
+    };
+    let payments_response = match Box::pin(payments_core::payments_core::<
+        api::PSync,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        payments_core::PaymentData<api::PSync>,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account.clone(),
+        None,
+        key_store.clone(),
+        payments_core::PaymentStatus,
+        request,
+        services::AuthFlow::Merchant,
+        payments_core::CallConnectorAction::Avoid,
+        None,
+        hyperswitch_domain_models::payments::HeaderPayload::default(),
+        None, // Platform merchant account
+    ))
+    .await
+    {
+        Ok(services::ApplicationResponse::Json(payments_response))
+        | Ok(services::ApplicationResponse::JsonWithHeaders((payments_response, _))) => {
+            payments_response
+        }
+        Ok(_) => {
+            logger::error!("Unexpected response received while retrieving the payment");
+            return;
+        }
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to retrieve the payment for the volume cap webhook"
+            );
+            return;
+        }
+    };
+
+    let primary_object_created_at = payments_response.created;
+    if let Err(error) = Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account,
+        business_profile,
+        &key_store,
+        enums::EventType::ConnectorVolumeCapReached,
+        enums::EventClass::Payments,
+        payment_id.get_string_repr().to_owned(),
+        enums::EventObjectType::PaymentDetails,
+        webhooks::OutgoingWebhookContent::PaymentDetails(Box::new(payments_response)),
+        primary_object_created_at,
+    ))
+    .await
+    {
+        logger::error!(?error, "Failed to trigger the volume cap webhook");
+    }
+}
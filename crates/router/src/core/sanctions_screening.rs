@@ -0,0 +1,862 @@
+//! Sanctions screening of the names of the beneficiaries of the payouts and of the customers of the
+//! high-value payments, for the merchants which have enabled the `sanctions_screening` feature
+//! flag. The names are screened with the provider in the configuration, either the watchlist in
+//! the configuration or an external screening service. A match opens a case which holds the
+//! object until it is reviewed: the payout is put on hold, and the payment cannot be confirmed.
+//! The object is held as well if the external provider could not be reached. A cleared payment is
+//! screened again if it is confirmed with names other than the ones which were cleared.
+
+use api_models::sanctions_screening::{
+    ScreeningCaseResolveRequest, ScreeningCaseResponse, ScreeningCaseStatus, ScreeningDecision,
+    ScreeningMatch, ScreeningObjectType,
+};
+#[cfg(feature = "payouts")]
+use common_utils::crypto::Encryptable;
+use common_utils::{
+    crypto::{self, GenerateDigest},
+    ext_traits::{BytesExt, Encode, StringExt},
+    id_type,
+    request::RequestContent,
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use masking::{Mask, PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+#[cfg(feature = "payouts")]
+use crate::core::payouts::{self, PayoutData};
+#[cfg(feature = "payouts")]
+use crate::types::storage;
+use crate::{
+    configs::settings::SanctionsScreeningProvider,
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        feature_flags,
+        payments::PaymentData,
+    },
+    db::StorageInterface,
+    headers,
+    routes::SessionState,
+    services,
+    types::{
+        api::{self, enums},
+        domain,
+    },
+};
+
+/// The name of the provider which screens the names against the watchlist in the configuration
+const WATCHLIST_PROVIDER: &str = "watchlist";
+
+/// The case id cached for the objects for which no case was opened
+const NO_SCREENING_CASE: &str = "null";
+
+/// The error message of the payouts which were blocked on the review of their case
+#[cfg(feature = "payouts")]
+const BLOCKED_PAYOUT_ERROR_MESSAGE: &str = "Blocked by sanctions screening";
+
+/// A sanctions screening case, as stored along with its resolution
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScreeningCase {
+    pub case_id: String,
+    pub object_type: ScreeningObjectType,
+    pub object_id: String,
+    pub profile_id: id_type::ProfileId,
+    pub status: ScreeningCaseStatus,
+    pub provider: String,
+    pub matches: Vec<ScreeningMatch>,
+    pub provider_unavailable: bool,
+    /// The digest of the names which were screened, which a clearance holds for
+    #[serde(default)]
+    pub screened_names_digest: Option<String>,
+    pub resolution_notes: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub resolved_at: Option<PrimitiveDateTime>,
+}
+
+impl ScreeningCase {
+    fn to_response(&self) -> ScreeningCaseResponse {
+        ScreeningCaseResponse {
+            case_id: self.case_id.clone(),
+            object_type: self.object_type,
+            object_id: self.object_id.clone(),
+            profile_id: self.profile_id.clone(),
+            status: self.status,
+            provider: self.provider.clone(),
+            matches: self.matches.clone(),
+            provider_unavailable: self.provider_unavailable,
+            resolution_notes: self.resolution_notes.clone(),
+            created_at: self.created_at,
+            resolved_at: self.resolved_at,
+        }
+    }
+}
+
+/// A name to be screened, along with the field of the object it was taken from
+#[derive(Debug, Clone, Serialize)]
+struct ScreenedName {
+    field: &'static str,
+    name: Secret<String>,
+}
+
+/// The result of the screening of the names of an object
+struct ScreeningOutcome {
+    provider: String,
+    matches: Vec<ScreeningMatch>,
+    provider_unavailable: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct ExternalScreeningRequest {
+    names: Vec<ScreenedName>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExternalScreeningResponse {
+    #[serde(default)]
+    matches: Vec<ScreeningMatch>,
+}
+
+/// The names which were given for the fields, leaving out the fields without a name
+fn get_screened_names(
+    names: impl IntoIterator<Item = (&'static str, Option<Secret<String>>)>,
+) -> Vec<ScreenedName> {
+    names
+        .into_iter()
+        .filter_map(|(field, name)| {
+            name.filter(|name| !name.peek().trim().is_empty())
+                .map(|name| ScreenedName { field, name })
+        })
+        .collect()
+}
+
+/// The words of a name, lowercased and without punctuation, for the matching of names regardless
+/// of their order and their formatting
+fn get_name_tokens(name: &str) -> Vec<String> {
+    name.split(|character: char| !character.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// The digest of the screened names, which does not depend on the order of the names nor on their
+/// formatting, so that the names a case was cleared for can be told apart from other names
+fn get_screened_names_digest(names: &[ScreenedName]) -> RouterResult<String> {
+    let mut normalized_names = names
+        .iter()
+        .map(|screened_name| {
+            format!(
+                "{}:{}",
+                screened_name.field,
+                get_name_tokens(screened_name.name.peek()).join(" ")
+            )
+        })
+        .collect::<Vec<_>>();
+    normalized_names.sort();
+
+    crypto::Sha256
+        .generate_digest(normalized_names.join("\n").as_bytes())
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to hash the screened names")
+}
+
+/// An entry of the watchlist matches a name if each of its words is one of the words of the name
+fn screen_with_watchlist(entries: &[String], names: &[ScreenedName]) -> Vec<ScreeningMatch> {
+    names
+        .iter()
+        .flat_map(|screened_name| {
+            let name_tokens = get_name_tokens(screened_name.name.peek());
+            entries
+                .iter()
+                .filter(move |entry| {
+                    let entry_tokens = get_name_tokens(entry);
+                    !entry_tokens.is_empty()
+                        && entry_tokens.iter().all(|token| name_tokens.contains(token))
+                })
+                .map(|entry| ScreeningMatch {
+                    field: screened_name.field.to_string(),
+                    entry: entry.clone(),
+                    list: Some(WATCHLIST_PROVIDER.to_string()),
+                    score: Some(100),
+                })
+        })
+        .collect()
+}
+
+async fn screen_with_external_provider(
+    state: &SessionState,
+    base_url: &str,
+    api_key: &Secret<String>,
+    timeout: u64,
+    names: &[ScreenedName],
+) -> errors::RouterResult<Vec<ScreeningMatch>> {
+    let mut request = services::Request::new(services::Method::Post, base_url);
+    request.add_header(headers::CONTENT_TYPE, "application/json".into());
+    request.add_header(headers::AUTHORIZATION, api_key.peek().clone().into_masked());
+    request.add_default_headers();
+    request.set_body(RequestContent::Json(Box::new(ExternalScreeningRequest {
+        names: names.to_vec(),
+    })));
+
+    let response: ExternalScreeningResponse = tokio::time::timeout(
+        std::time::Duration::from_secs(timeout),
+        services::call_connector_api(state, request, "screen_with_external_provider"),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Timed out waiting for the sanctions screening provider")?
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to call the sanctions screening provider")?
+    .map_err(|error_response| {
+        report!(errors::ApiErrorResponse::InternalServerError).attach_printable(format!(
+            "Sanctions screening provider responded with status code {}",
+            error_response.status_code
+        ))
+    })?
+    .response
+    .parse_struct("ExternalScreeningResponse")
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to parse the response of the sanctions screening provider")?;
+
+    Ok(response.matches)
+}
+
+async fn screen_names(state: &SessionState, names: &[ScreenedName]) -> ScreeningOutcome {
+    match &state.conf.sanctions_screening.get_inner().provider {
+        SanctionsScreeningProvider::Watchlist { entries } => ScreeningOutcome {
+            provider: WATCHLIST_PROVIDER.to_string(),
+            matches: screen_with_watchlist(entries, names),
+            provider_unavailable: false,
+        },
+        SanctionsScreeningProvider::External {
+            name,
+            base_url,
+            api_key,
+            timeout,
+        } => match screen_with_external_provider(state, base_url, api_key, *timeout, names).await {
+            Ok(matches) => ScreeningOutcome {
+                provider: name.clone(),
+                matches,
+                provider_unavailable: false,
+            },
+            Err(error) => {
+                logger::error!(?error, provider = %name, "Failed to screen the names");
+                ScreeningOutcome {
+                    provider: name.clone(),
+                    matches: Vec::new(),
+                    provider_unavailable: true,
+                }
+            }
+        },
+    }
+}
+
+async fn get_screening_case(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    case_id: &str,
+) -> RouterResult<ScreeningCase> {
+    db.find_config_by_key(&merchant_id.get_sanctions_screening_case_key(case_id))
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::ApiErrorResponse::GenericNotFoundError {
+                    message: "Sanctions screening case not found".to_string(),
+                })
+            } else {
+                error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch sanctions screening case")
+            }
+        })?
+        .config
+        .parse_struct("ScreeningCase")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse sanctions screening case")
+}
+
+/// The case which was last opened for the payment or the payout, if any
+async fn find_screening_case_by_object(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    object_type: ScreeningObjectType,
+    object_id: &str,
+) -> RouterResult<Option<ScreeningCase>> {
+    // The case of the payment is looked up for every high-value payment confirmed, so its absence
+    // is cached as well
+    let case_id = db
+        .find_config_by_key_unwrap_or(
+            &merchant_id.get_sanctions_screening_object_key(&object_type.to_string(), object_id),
+            Some(NO_SCREENING_CASE.to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch sanctions screening case of the object")?
+        .config;
+    if case_id == NO_SCREENING_CASE {
+        return Ok(None);
+    }
+
+    get_screening_case(db, merchant_id, &case_id)
+        .await
+        .map(Some)
+}
+
+async fn update_screening_case(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    case: &ScreeningCase,
+) -> RouterResult<()> {
+    let serialized_case = case
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize sanctions screening case")?;
+
+    db.update_config_by_key(
+        &merchant_id.get_sanctions_screening_case_key(&case.case_id),
+        configs::ConfigUpdate::Update {
+            config: Some(serialized_case),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update sanctions screening case")?;
+
+    Ok(())
+}
+
+/// Screens the names of the object, and opens a case if any of them matched a watchlist or if the
+/// provider could not be reached. The case replaces the one previously opened for the object, if
+/// any.
+async fn screen_object(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+    object_type: ScreeningObjectType,
+    object_id: &str,
+    names: Vec<ScreenedName>,
+    previous_case: Option<&ScreeningCase>,
+) -> RouterResult<Option<ScreeningCase>> {
+    if names.is_empty() {
+        return Ok(None);
+    }
+
+    let outcome = screen_names(state, &names).await;
+    if outcome.matches.is_empty() && !outcome.provider_unavailable {
+        return Ok(None);
+    }
+
+    let db = state.store.as_ref();
+    let case = ScreeningCase {
+        case_id: common_utils::generate_id(consts::ID_LENGTH, "scr"),
+        object_type,
+        object_id: object_id.to_string(),
+        profile_id: profile_id.clone(),
+        status: ScreeningCaseStatus::PendingReview,
+        provider: outcome.provider,
+        matches: outcome.matches,
+        provider_unavailable: outcome.provider_unavailable,
+        screened_names_digest: Some(get_screened_names_digest(&names)?),
+        resolution_notes: None,
+        created_at: common_utils::date_time::now(),
+        resolved_at: None,
+    };
+    let serialized_case = case
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize sanctions screening case")?;
+
+    db.insert_config(configs::ConfigNew {
+        key: merchant_id.get_sanctions_screening_case_key(&case.case_id),
+        config: serialized_case,
+    })
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to insert sanctions screening case")?;
+
+    let object_key =
+        merchant_id.get_sanctions_screening_object_key(&object_type.to_string(), object_id);
+    if previous_case.is_some() {
+        db.update_config_by_key(
+            &object_key,
+            configs::ConfigUpdate::Update {
+                config: Some(case.case_id.clone()),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update sanctions screening case of the object")?;
+    } else {
+        db.insert_config(configs::ConfigNew {
+            key: object_key,
+            config: case.case_id.clone(),
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert sanctions screening case of the object")?;
+    }
+
+    logger::info!(
+        case_id = %case.case_id,
+        %object_type,
+        provider_unavailable = case.provider_unavailable,
+        "Opened sanctions screening case"
+    );
+
+    Ok(Some(case))
+}
+
+#[cfg(feature = "payouts")]
+async fn update_payout_status(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payout_data: &mut PayoutData,
+    status: enums::PayoutStatus,
+    error_message: Option<String>,
+) -> RouterResult<()> {
+    let payout_attempt = payout_data.payout_attempt.to_owned();
+    let updated_payout_attempt = storage::PayoutAttemptUpdate::StatusUpdate {
+        connector_payout_id: payout_attempt.connector_payout_id.to_owned(),
+        status,
+        error_message,
+        error_code: None,
+        is_eligible: None,
+        unified_code: None,
+        unified_message: None,
+    };
+    payout_data.payout_attempt = state
+        .store
+        .update_payout_attempt(
+            &payout_attempt,
+            updated_payout_attempt,
+            &payout_data.payouts,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating payout_attempt in db")?;
+    payout_data.payouts = state
+        .store
+        .update_payout(
+            &payout_data.payouts,
+            storage::PayoutsUpdate::StatusUpdate { status },
+            &payout_data.payout_attempt,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error updating payouts in db")?;
+
+    Ok(())
+}
+
+/// Screens the names of the beneficiary of the payout, and puts the payout on hold if a case was
+/// opened for it
+#[cfg(feature = "payouts")]
+#[instrument(skip_all)]
+pub async fn screen_payout(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payout_data: &mut PayoutData,
+) -> RouterResult<()> {
+    let merchant_id = merchant_account.get_id();
+    if !feature_flags::is_feature_enabled(
+        state,
+        merchant_id,
+        Some(&payout_data.profile_id),
+        enums::MerchantFeatureFlag::SanctionsScreening,
+    )
+    .await
+    {
+        return Ok(());
+    }
+
+    let beneficiary_name = payout_data
+        .payout_method_data
+        .as_ref()
+        .and_then(|payout_method_data| match payout_method_data {
+            api_models::payouts::PayoutMethodData::Card(card) => card.card_holder_name.clone(),
+            api_models::payouts::PayoutMethodData::Bank(_)
+            | api_models::payouts::PayoutMethodData::Wallet(_) => None,
+        });
+    let billing_name = payout_data.billing_address.clone().and_then(|address| {
+        api_models::payments::AddressDetails::from(address).get_optional_full_name()
+    });
+    let customer_name = payout_data
+        .customer_details
+        .as_ref()
+        .and_then(|customer| customer.name.clone())
+        .map(Encryptable::into_inner);
+    let names = get_screened_names([
+        ("beneficiary_name", beneficiary_name),
+        ("billing_name", billing_name),
+        ("customer_name", customer_name),
+    ]);
+
+    let case = screen_object(
+        state,
+        merchant_id,
+        &payout_data.profile_id,
+        ScreeningObjectType::Payout,
+        &payout_data.payouts.payout_id,
+        names,
+        None,
+    )
+    .await?;
+
+    if case.is_some() {
+        update_payout_status(
+            state,
+            merchant_account,
+            payout_data,
+            enums::PayoutStatus::OnHold,
+            None,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Whether the case clears the names, a clearance only holding for the names which were screened
+fn is_cleared_for_names(case: &ScreeningCase, names: &[ScreenedName]) -> RouterResult<bool> {
+    if case.status != ScreeningCaseStatus::Cleared {
+        return Ok(false);
+    }
+    if names.is_empty() {
+        return Ok(true);
+    }
+
+    let digest = get_screened_names_digest(names)?;
+    Ok(case.screened_names_digest.as_ref() == Some(&digest))
+}
+
+/// Screens the names of the customer of the payment if its amount is over the threshold of its
+/// currency. The payment cannot be confirmed until the case which was opened for it is cleared.
+#[instrument(skip_all)]
+pub async fn screen_payment<F: Clone>(
+    state: &SessionState,
+    request: &api::PaymentsRequest,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<()> {
+    let payment_intent = &payment_data.payment_intent;
+    let Some(threshold) = payment_intent.currency.and_then(|currency| {
+        state
+            .conf
+            .sanctions_screening
+            .get_inner()
+            .payment_thresholds
+            .get(&currency)
+    }) else {
+        return Ok(());
+    };
+    if payment_intent.amount.get_amount_as_i64() < *threshold {
+        return Ok(());
+    }
+
+    let merchant_id = &payment_intent.merchant_id;
+    if !feature_flags::is_feature_enabled(
+        state,
+        merchant_id,
+        payment_intent.profile_id.as_ref(),
+        enums::MerchantFeatureFlag::SanctionsScreening,
+    )
+    .await
+    {
+        return Ok(());
+    }
+
+    let profile_id = payment_intent
+        .profile_id
+        .as_ref()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("profile_id is not set in payment_intent")?;
+    let card_holder_name = request
+        .payment_method_data
+        .as_ref()
+        .and_then(|request_payment_method_data| {
+            request_payment_method_data.payment_method_data.as_ref()
+        })
+        .and_then(|payment_method_data| match payment_method_data {
+            api_models::payments::PaymentMethodData::Card(card) => card.card_holder_name.clone(),
+            _ => None,
+        });
+    let billing_name = payment_data
+        .address
+        .get_payment_billing()
+        .and_then(|billing| billing.address.as_ref())
+        .and_then(|address| address.get_optional_full_name());
+    let customer_name = request
+        .customer
+        .as_ref()
+        .and_then(|customer| customer.name.clone())
+        .or_else(|| request.name.clone());
+    let names = get_screened_names([
+        ("card_holder_name", card_holder_name),
+        ("billing_name", billing_name),
+        ("customer_name", customer_name),
+    ]);
+
+    let payment_id = payment_intent.payment_id.get_string_repr();
+    let previous_case = find_screening_case_by_object(
+        state.store.as_ref(),
+        merchant_id,
+        ScreeningObjectType::Payment,
+        payment_id,
+    )
+    .await?;
+    if let Some(case) = &previous_case {
+        if is_cleared_for_names(case, &names)? {
+            return Ok(());
+        }
+        // A cleared payment confirmed with other names is screened again
+        if case.status != ScreeningCaseStatus::Cleared {
+            return Err(report!(
+                errors::ApiErrorResponse::SanctionsScreeningNotCleared {
+                    case_id: case.case_id.clone()
+                }
+            ));
+        }
+    }
+
+    match screen_object(
+        state,
+        merchant_id,
+        profile_id,
+        ScreeningObjectType::Payment,
+        payment_id,
+        names,
+        previous_case.as_ref(),
+    )
+    .await?
+    {
+        Some(case) => Err(report!(
+            errors::ApiErrorResponse::SanctionsScreeningNotCleared {
+                case_id: case.case_id
+            }
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Releases the payout which was held for the case, or fails it if the case was blocked
+#[cfg(feature = "payouts")]
+async fn resolve_held_payout(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payout_id: &str,
+    decision: ScreeningDecision,
+) -> RouterResult<()> {
+    let mut payout_data = payouts::make_payout_data(
+        state,
+        merchant_account,
+        None,
+        key_store,
+        &api_models::payouts::PayoutRequest::PayoutActionRequest(
+            api_models::payouts::PayoutActionRequest {
+                payout_id: payout_id.to_string(),
+            },
+        ),
+        &state.locale,
+    )
+    .await?;
+
+    // The payout could have been cancelled while it was on hold
+    if payout_data.payout_attempt.status != enums::PayoutStatus::OnHold {
+        logger::info!(
+            %payout_id,
+            status = %payout_data.payout_attempt.status,
+            "Payout of the sanctions screening case is no longer on hold"
+        );
+        return Ok(());
+    }
+
+    let (status, error_message) = match decision {
+        ScreeningDecision::Clear => (enums::PayoutStatus::RequiresConfirmation, None),
+        ScreeningDecision::Block => (
+            enums::PayoutStatus::Failed,
+            Some(BLOCKED_PAYOUT_ERROR_MESSAGE.to_string()),
+        ),
+    };
+
+    update_payout_status(
+        state,
+        merchant_account,
+        &mut payout_data,
+        status,
+        error_message,
+    )
+    .await
+}
+
+#[cfg(not(feature = "payouts"))]
+async fn resolve_held_payout(
+    _state: &SessionState,
+    _merchant_account: &domain::MerchantAccount,
+    _key_store: &domain::MerchantKeyStore,
+    _payout_id: &str,
+    _decision: ScreeningDecision,
+) -> RouterResult<()> {
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_screening_case(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    case_id: String,
+) -> RouterResponse<ScreeningCaseResponse> {
+    let case =
+        get_screening_case(state.store.as_ref(), merchant_account.get_id(), &case_id).await?;
+
+    Ok(services::ApplicationResponse::Json(case.to_response()))
+}
+
+/// Resolves a case which is pending review. A cleared payout requires to be confirmed again, and a
+/// cleared payment can be confirmed.
+#[instrument(skip_all)]
+pub async fn resolve_screening_case(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    case_id: String,
+    req: ScreeningCaseResolveRequest,
+) -> RouterResponse<ScreeningCaseResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+    let mut case = get_screening_case(db, merchant_id, &case_id).await?;
+
+    if case.status != ScreeningCaseStatus::PendingReview {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "Sanctions screening case {case_id} has already been resolved as {}",
+                case.status
+            ),
+        }));
+    }
+
+    if case.object_type == ScreeningObjectType::Payout {
+        resolve_held_payout(
+            &state,
+            &merchant_account,
+            &key_store,
+            &case.object_id,
+            req.decision,
+        )
+        .await?;
+    }
+
+    case.status = match req.decision {
+        ScreeningDecision::Clear => ScreeningCaseStatus::Cleared,
+        ScreeningDecision::Block => ScreeningCaseStatus::Blocked,
+    };
+    case.resolution_notes = req.notes;
+    case.resolved_at = Some(common_utils::date_time::now());
+    update_screening_case(db, merchant_id, &case).await?;
+
+    Ok(services::ApplicationResponse::Json(case.to_response()))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use std::borrow::Cow;
+
+    use super::*;
+
+    fn screened_names(names: &[(&'static str, &str)]) -> Vec<ScreenedName> {
+        get_screened_names(
+            names
+                .iter()
+                .map(|(field, name)| (*field, Some(Secret::new(name.to_string())))),
+        )
+    }
+
+    fn cleared_case(names: &[ScreenedName]) -> ScreeningCase {
+        ScreeningCase {
+            case_id: "scr_test".to_string(),
+            object_type: ScreeningObjectType::Payment,
+            object_id: "pay_test".to_string(),
+            profile_id: id_type::ProfileId::try_from(Cow::from("pro_test"))
+                .expect("valid profile id"),
+            status: ScreeningCaseStatus::Cleared,
+            provider: WATCHLIST_PROVIDER.to_string(),
+            matches: Vec::new(),
+            provider_unavailable: false,
+            screened_names_digest: Some(
+                get_screened_names_digest(names).expect("names are hashed"),
+            ),
+            resolution_notes: None,
+            created_at: common_utils::date_time::now(),
+            resolved_at: None,
+        }
+    }
+
+    #[test]
+    fn test_screen_with_watchlist() {
+        let entries = vec!["John Doe".to_string()];
+        let names = screened_names(&[
+            ("card_holder_name", "DOE, John"),
+            ("customer_name", "Johnny Doe"),
+        ]);
+
+        let matches = screen_with_watchlist(&entries, &names);
+
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].field, "card_holder_name");
+    }
+
+    #[test]
+    fn test_screened_names_digest_ignores_order_and_formatting() {
+        let names = screened_names(&[
+            ("card_holder_name", "John Doe"),
+            ("billing_name", "Jane Roe"),
+        ]);
+        let reformatted_names = screened_names(&[
+            ("billing_name", "jane  roe"),
+            ("card_holder_name", "JOHN DOE"),
+        ]);
+
+        assert_eq!(
+            get_screened_names_digest(&names).expect("names are hashed"),
+            get_screened_names_digest(&reformatted_names).expect("names are hashed")
+        );
+    }
+
+    #[test]
+    fn test_clearance_holds_only_for_the_screened_names() {
+        let names = screened_names(&[("card_holder_name", "John Doe")]);
+        let case = cleared_case(&names);
+
+        assert!(is_cleared_for_names(&case, &names).expect("names are hashed"));
+        assert!(
+            !is_cleared_for_names(&case, &screened_names(&[("card_holder_name", "Jane Roe")]))
+                .expect("names are hashed")
+        );
+        assert!(!is_cleared_for_names(
+            &case,
+            &screened_names(&[
+                ("card_holder_name", "John Doe"),
+                ("billing_name", "Jane Roe"),
+            ])
+        )
+        .expect("names are hashed"));
+    }
+
+    #[test]
+    fn test_pending_case_clears_no_names() {
+        let names = screened_names(&[("card_holder_name", "John Doe")]);
+        let case = ScreeningCase {
+            status: ScreeningCaseStatus::PendingReview,
+            ..cleared_case(&names)
+        };
+
+        assert!(!is_cleared_for_names(&case, &names).expect("names are hashed"));
+    }
+}
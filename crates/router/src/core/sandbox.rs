@@ -0,0 +1,330 @@
+//! APIs which are available only outside production, to make the integration testing of the
+//! merchants deterministic. The test clock of a merchant moves the time forward for the expiry of
+//! the payment links and mandates of the merchant, and brings forward the scheduled tasks of the
+//! merchant when it is advanced.
+
+#[cfg(all(feature = "olap", feature = "dummy_connector", feature = "v1"))]
+use api_models::sandbox::{SandboxDataSeedRequest, SandboxDataSeedResponse};
+use api_models::sandbox::{TestClockAdvanceRequest, TestClockResponse};
+#[cfg(all(feature = "olap", feature = "dummy_connector", feature = "v1"))]
+use common_utils::errors::ReportSwitchExt;
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use router_env::{env, instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+use time::{Duration, PrimitiveDateTime};
+
+#[cfg(all(feature = "olap", feature = "dummy_connector", feature = "v1"))]
+use crate::core::user::sample_data;
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult},
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{domain, storage},
+};
+
+/// The test clock of a merchant, stored as the duration in seconds by which it is ahead of the
+/// actual time
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct TestClock {
+    offset: i64,
+}
+
+fn validate_sandbox_environment() -> RouterResult<()> {
+    if env::which() == env::Env::Production {
+        return Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: "Sandbox APIs are not available in production".to_string(),
+        }));
+    }
+    Ok(())
+}
+
+#[cfg(all(feature = "olap", feature = "dummy_connector", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn seed_sandbox_data(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    request: SandboxDataSeedRequest,
+) -> RouterResponse<SandboxDataSeedResponse> {
+    validate_sandbox_environment()?;
+
+    let (payments, refunds, disputes) = sample_data::insert_sample_data(
+        &state,
+        request.into(),
+        merchant_account.get_id(),
+        &merchant_account.organization_id,
+    )
+    .await
+    .switch()?;
+
+    Ok(services::ApplicationResponse::Json(
+        SandboxDataSeedResponse {
+            payments,
+            refunds,
+            disputes,
+        },
+    ))
+}
+
+#[cfg(all(feature = "olap", feature = "dummy_connector", feature = "v1"))]
+#[instrument(skip_all)]
+pub async fn delete_sandbox_data(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<()> {
+    validate_sandbox_environment()?;
+
+    sample_data::delete_sample_data(&state, merchant_account.get_id())
+        .await
+        .switch()?;
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+/// The test clock of the merchant, whose absence is cached as well, since it is looked up for the
+/// payment links and mandates of all the merchants
+async fn get_test_clock(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Option<TestClock>> {
+    db.find_config_by_key_unwrap_or(&merchant_id.get_test_clock_key(), Some("null".to_string()))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch test clock")?
+        .config
+        .parse_struct::<Option<TestClock>>("TestClock")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse test clock")
+}
+
+async fn get_test_clock_from_db(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Option<TestClock>> {
+    match db
+        .find_config_by_key_from_db(&merchant_id.get_test_clock_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("TestClock")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse test clock")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch test clock"),
+    }
+}
+
+/// Returns the duration by which the test clock of the merchant is ahead of the actual time. Test
+/// clocks are never applied in production, and are ignored if they could not be fetched.
+pub async fn get_test_clock_offset(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> Option<Duration> {
+    if env::which() == env::Env::Production {
+        return None;
+    }
+
+    get_test_clock(db, merchant_id)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to fetch test clock"))
+        .ok()
+        .flatten()
+        .map(|test_clock| Duration::seconds(test_clock.offset))
+}
+
+/// Returns the current time as per the test clock of the merchant
+pub async fn get_merchant_current_time(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> PrimitiveDateTime {
+    let now = common_utils::date_time::now();
+    get_test_clock_offset(db, merchant_id)
+        .await
+        .map_or(now, |offset| now.saturating_add(offset))
+}
+
+/// Brings forward the scheduled tasks of the merchant which would have become due while the test
+/// clock is advanced, so that they are picked by the scheduler as if the time had passed
+async fn reschedule_merchant_tasks(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    advance_by: Duration,
+) -> RouterResult<usize> {
+    let now = common_utils::date_time::now();
+    let mut rescheduled_tasks = 0;
+
+    for status in [
+        storage::enums::ProcessTrackerStatus::New,
+        storage::enums::ProcessTrackerStatus::Pending,
+    ] {
+        let tasks = db
+            .find_merchant_processes_by_time_status(
+                merchant_id,
+                now,
+                now.saturating_add(advance_by),
+                status,
+                Some(consts::MAX_TEST_CLOCK_TASKS_LOOKUP),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the scheduled tasks")?;
+
+        for task in tasks {
+            let schedule_time = task
+                .schedule_time
+                .map_or(now, |schedule_time| {
+                    schedule_time.saturating_sub(advance_by)
+                })
+                .max(now);
+            let retry_count = task.retry_count;
+
+            db.update_process(
+                task,
+                storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                    status,
+                    retry_count,
+                    schedule_time,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to reschedule the task")?;
+            rescheduled_tasks += 1;
+        }
+    }
+
+    Ok(rescheduled_tasks)
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_test_clock(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<TestClockResponse> {
+    validate_sandbox_environment()?;
+
+    let test_clock = get_test_clock(state.store.as_ref(), merchant_account.get_id())
+        .await?
+        .unwrap_or_default();
+
+    Ok(services::ApplicationResponse::Json(TestClockResponse {
+        merchant_id: merchant_account.get_id().clone(),
+        offset: test_clock.offset,
+        current_time: common_utils::date_time::now()
+            .saturating_add(Duration::seconds(test_clock.offset)),
+        rescheduled_tasks: None,
+    }))
+}
+
+#[instrument(skip_all)]
+pub async fn advance_test_clock(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    request: TestClockAdvanceRequest,
+) -> RouterResponse<TestClockResponse> {
+    validate_sandbox_environment()?;
+
+    if request.advance_by == 0 {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "advance_by must be greater than zero".to_string(),
+        }));
+    }
+
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+    let existing_test_clock = get_test_clock_from_db(db, merchant_id).await?;
+    let offset = existing_test_clock
+        .as_ref()
+        .map_or(0, |test_clock| test_clock.offset)
+        .saturating_add(i64::from(request.advance_by));
+
+    if offset > consts::MAX_TEST_CLOCK_OFFSET_SECONDS {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "test clock can not be ahead of the actual time by more than {} seconds",
+                consts::MAX_TEST_CLOCK_OFFSET_SECONDS
+            ),
+        }));
+    }
+
+    let key = merchant_id.get_test_clock_key();
+    let serialized_test_clock = TestClock { offset }
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize test clock")?;
+
+    if existing_test_clock.is_some() {
+        db.update_config_by_key(
+            &key,
+            configs::ConfigUpdate::Update {
+                config: Some(serialized_test_clock),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update test clock")?;
+    } else {
+        db.insert_config(configs::ConfigNew {
+            key,
+            config: serialized_test_clock,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert test clock")?;
+    }
+
+    let rescheduled_tasks = reschedule_merchant_tasks(
+        db,
+        merchant_id,
+        Duration::seconds(i64::from(request.advance_by)),
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(TestClockResponse {
+        merchant_id: merchant_id.clone(),
+        offset,
+        current_time: common_utils::date_time::now().saturating_add(Duration::seconds(offset)),
+        rescheduled_tasks: Some(rescheduled_tasks),
+    }))
+}
+
+#[instrument(skip_all)]
+pub async fn reset_test_clock(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<TestClockResponse> {
+    validate_sandbox_environment()?;
+
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+
+    match db
+        .delete_config_by_key(&merchant_id.get_test_clock_key())
+        .await
+    {
+        Ok(_) => (),
+        Err(error) if error.current_context().is_db_not_found() => (),
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to delete test clock");
+        }
+    }
+
+    Ok(services::ApplicationResponse::Json(TestClockResponse {
+        merchant_id: merchant_id.clone(),
+        offset: 0,
+        current_time: common_utils::date_time::now(),
+        rescheduled_tasks: None,
+    }))
+}
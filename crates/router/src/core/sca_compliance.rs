@@ -0,0 +1,727 @@
+//! Compliance reports of the strong customer authentication (SCA) of PSD2, which merchants hand to
+//! their acquirers and regulators. A report is requested for a month that has ended, and is then
+//! generated by the scheduler: each card payment created in the month with a card issued in the
+//! European Economic Area or the United Kingdom is classified by its SCA treatment, from the
+//! authentication run for it and its attempt. The classified payments can be downloaded as a CSV
+//! file once the report is completed, along with the number of payments with each treatment.
+
+use api_models::{
+    payments::{AdditionalPaymentData, Order, SortBy, SortOn},
+    sca_compliance::{
+        ScaComplianceReportRequest, ScaComplianceReportResponse, ScaComplianceReportStatus,
+        ScaTreatment, ScaTreatmentCount,
+    },
+};
+use common_utils::{
+    ext_traits::{Encode, StringExt, ValueExt},
+    id_type,
+    types::MinorUnit,
+};
+use csv::Writer;
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use hyperswitch_domain_models::payments::payment_intent::{
+    PaymentIntentFetchConstraints, PaymentIntentListParams,
+};
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult},
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{api::enums, domain, storage},
+};
+
+const SCA_COMPLIANCE_REPORT_TASK: &str = "SCA_COMPLIANCE_REPORT";
+const SCA_COMPLIANCE_REPORT_TAG: &str = "COMPLIANCE";
+
+/// An SCA compliance report, as stored along with the outcome of its generation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScaComplianceReport {
+    pub report_id: String,
+    pub profile_id: Option<id_type::ProfileId>,
+    pub year: i32,
+    pub month: u8,
+    pub status: ScaComplianceReportStatus,
+    pub total_count: usize,
+    pub treatments: Vec<ScaTreatmentCount>,
+    /// The key of the generated CSV file in the file storage
+    pub file_key: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<&ScaComplianceReport> for ScaComplianceReportResponse {
+    fn from(report: &ScaComplianceReport) -> Self {
+        Self {
+            report_id: report.report_id.clone(),
+            profile_id: report.profile_id.clone(),
+            year: report.year,
+            month: report.month,
+            status: report.status,
+            total_count: report.total_count,
+            treatments: report.treatments.clone(),
+            error_message: report.error_message.clone(),
+            created_at: report.created_at,
+            modified_at: report.modified_at,
+        }
+    }
+}
+
+/// A classified payment, as written to the CSV file of the report
+#[derive(Debug, Serialize)]
+struct ScaComplianceReportRecord {
+    payment_id: id_type::PaymentId,
+    attempt_id: String,
+    profile_id: Option<id_type::ProfileId>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    created_at: PrimitiveDateTime,
+    status: enums::IntentStatus,
+    amount: MinorUnit,
+    currency: Option<enums::Currency>,
+    connector: Option<String>,
+    card_network: Option<enums::CardNetwork>,
+    card_issuing_country: Option<String>,
+    sca_treatment: ScaTreatment,
+    exemption_type: Option<enums::ScaExemptionType>,
+    authentication_connector: Option<String>,
+    trans_status: Option<enums::TransactionStatus>,
+    eci: Option<String>,
+}
+
+/// The data of a payment which its SCA treatment is derived from
+struct ScaTreatmentInputs {
+    off_session: Option<bool>,
+    exemption_type: Option<enums::ScaExemptionType>,
+    attempt_authentication_type: Option<enums::AuthenticationType>,
+    attempt_status: enums::AttemptStatus,
+    /// The type and the result of the authentication run with the authentication connector
+    authentication: Option<(
+        Option<enums::DecoupledAuthenticationType>,
+        Option<enums::TransactionStatus>,
+    )>,
+}
+
+/// Classifies a payment by its SCA treatment. Merchant initiated payments are out of the scope of
+/// SCA whatever else was done for them. The authentication run with an authentication connector
+/// is preferred over the 3DS of the connector, which does not tell whether the issuer challenged
+/// the customer, and an exemption is only reported when the customer was not authenticated.
+fn get_sca_treatment(inputs: &ScaTreatmentInputs) -> ScaTreatment {
+    if inputs.off_session == Some(true) {
+        return ScaTreatment::MerchantInitiated;
+    }
+
+    if let Some((authentication_type, trans_status)) = &inputs.authentication {
+        return match (authentication_type, trans_status) {
+            (
+                Some(enums::DecoupledAuthenticationType::Challenge),
+                Some(enums::TransactionStatus::Success),
+            ) => ScaTreatment::Challenge,
+            (_, Some(enums::TransactionStatus::Success)) => ScaTreatment::Frictionless,
+            (_, _) => ScaTreatment::AuthenticationFailed,
+        };
+    }
+
+    if inputs.attempt_authentication_type == Some(enums::AuthenticationType::ThreeDs) {
+        return if inputs.attempt_status == enums::AttemptStatus::AuthenticationFailed {
+            ScaTreatment::AuthenticationFailed
+        } else {
+            ScaTreatment::ConnectorAuthenticated
+        };
+    }
+
+    if inputs.exemption_type.is_some() {
+        ScaTreatment::Exempted
+    } else {
+        ScaTreatment::NotApplied
+    }
+}
+
+/// Whether the card was issued in a country where SCA applies. The issuing countries of the cards
+/// are compared without their case, spaces and punctuation, as the BIN lookups name them variously.
+fn is_sca_issuing_country(card_issuing_country: &str) -> bool {
+    let normalized_country = card_issuing_country
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .collect::<String>()
+        .to_ascii_uppercase();
+    consts::SCA_CARD_ISSUING_COUNTRIES.contains(&normalized_country.as_str())
+}
+
+fn get_card_info(
+    payment_attempt: &storage::PaymentAttempt,
+) -> Option<Box<api_models::payments::AdditionalCardInfo>> {
+    let additional_payment_data = payment_attempt
+        .payment_method_data
+        .clone()?
+        .parse_value::<AdditionalPaymentData>("AdditionalPaymentData")
+        .map_err(|error| {
+            logger::warn!(
+                ?error,
+                "Failed to parse the payment method data of the attempt"
+            );
+        })
+        .ok()?;
+
+    match additional_payment_data {
+        AdditionalPaymentData::Card(card) => Some(card),
+        _ => None,
+    }
+}
+
+/// The first and the last instant of the month, or `None` if the month is invalid
+fn get_month_range(year: i32, month: u8) -> Option<(PrimitiveDateTime, PrimitiveDateTime)> {
+    let month = time::Month::try_from(month).ok()?;
+    let (next_year, next_month) = match month {
+        time::Month::December => (year.checked_add(1)?, time::Month::January),
+        _ => (year, month.next()),
+    };
+
+    let start = time::Date::from_calendar_date(year, month, 1).ok()?;
+    let next_start = time::Date::from_calendar_date(next_year, next_month, 1).ok()?;
+    Some((
+        start.midnight(),
+        next_start.midnight() - time::Duration::microseconds(1),
+    ))
+}
+
+fn get_report_file_key(merchant_id: &id_type::MerchantId, report_id: &str) -> String {
+    format!(
+        "sca_compliance_reports/{}/{report_id}.csv",
+        merchant_id.get_string_repr()
+    )
+}
+
+async fn get_sca_compliance_report(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    report_id: &str,
+) -> RouterResult<ScaComplianceReport> {
+    db.find_config_by_key(&merchant_id.get_sca_compliance_report_key(report_id))
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::ApiErrorResponse::GenericNotFoundError {
+                    message: "SCA compliance report not found".to_string(),
+                })
+            } else {
+                error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch SCA compliance report")
+            }
+        })?
+        .config
+        .parse_struct("ScaComplianceReport")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse SCA compliance report")
+}
+
+/// Fetches the report of the profile, reporting the reports of the other profiles as not found
+async fn get_profile_sca_compliance_report(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    profile_id: Option<&id_type::ProfileId>,
+    report_id: &str,
+) -> RouterResult<ScaComplianceReport> {
+    let report = get_sca_compliance_report(db, merchant_id, report_id).await?;
+    if profile_id.is_some_and(|profile_id| report.profile_id.as_ref() != Some(profile_id)) {
+        return Err(report!(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "SCA compliance report not found".to_string(),
+        }));
+    }
+    Ok(report)
+}
+
+async fn update_sca_compliance_report(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    report: &ScaComplianceReport,
+) -> RouterResult<()> {
+    let serialized_report = report
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize SCA compliance report")?;
+
+    db.update_config_by_key(
+        &merchant_id.get_sca_compliance_report_key(&report.report_id),
+        configs::ConfigUpdate::Update {
+            config: Some(serialized_report),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update SCA compliance report")?;
+
+    Ok(())
+}
+
+async fn add_sca_compliance_report_task(
+    db: &dyn StorageInterface,
+    tracking_data: storage::ScaComplianceReportTrackingData,
+    schedule_time: PrimitiveDateTime,
+) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::ScaComplianceReportWorkflow;
+    let process_tracker_id = format!(
+        "{runner}_{SCA_COMPLIANCE_REPORT_TASK}_{}",
+        tracking_data.report_id
+    );
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        SCA_COMPLIANCE_REPORT_TASK,
+        runner,
+        [SCA_COMPLIANCE_REPORT_TAG],
+        tracking_data,
+        None,
+        schedule_time,
+        hyperswitch_domain_models::consts::API_VERSION,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct SCA_COMPLIANCE_REPORT process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while inserting SCA_COMPLIANCE_REPORT task to process_tracker")?;
+
+    Ok(())
+}
+
+#[instrument(skip_all)]
+pub async fn create_sca_compliance_report(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    req: ScaComplianceReportRequest,
+) -> RouterResponse<ScaComplianceReportResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+
+    let (_, month_end) = get_month_range(req.year, req.month).ok_or(
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: "Invalid year or month".to_string(),
+        },
+    )?;
+    let now = common_utils::date_time::now();
+    if month_end >= now {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The report can only be generated once the month has ended".to_string(),
+        }));
+    }
+
+    let report = ScaComplianceReport {
+        report_id: common_utils::generate_id(consts::ID_LENGTH, "scr"),
+        profile_id,
+        year: req.year,
+        month: req.month,
+        status: ScaComplianceReportStatus::Queued,
+        total_count: 0,
+        treatments: Vec::new(),
+        file_key: None,
+        error_message: None,
+        created_at: now,
+        modified_at: now,
+    };
+    let serialized_report = report
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize SCA compliance report")?;
+
+    db.insert_config(configs::ConfigNew {
+        key: merchant_id.get_sca_compliance_report_key(&report.report_id),
+        config: serialized_report,
+    })
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to insert SCA compliance report")?;
+
+    add_sca_compliance_report_task(
+        db,
+        storage::ScaComplianceReportTrackingData {
+            report_id: report.report_id.clone(),
+            merchant_id: merchant_id.clone(),
+        },
+        now,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        ScaComplianceReportResponse::from(&report),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_sca_compliance_report(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    report_id: String,
+) -> RouterResponse<ScaComplianceReportResponse> {
+    let report = get_profile_sca_compliance_report(
+        state.store.as_ref(),
+        merchant_account.get_id(),
+        profile_id.as_ref(),
+        &report_id,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        ScaComplianceReportResponse::from(&report),
+    ))
+}
+
+/// Downloads the CSV file of the classified payments of a completed report
+#[instrument(skip_all)]
+pub async fn download_sca_compliance_report(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<id_type::ProfileId>,
+    report_id: String,
+) -> RouterResponse<serde_json::Value> {
+    let report = get_profile_sca_compliance_report(
+        state.store.as_ref(),
+        merchant_account.get_id(),
+        profile_id.as_ref(),
+        &report_id,
+    )
+    .await?;
+
+    let file_key = match (report.status, report.file_key) {
+        (ScaComplianceReportStatus::Completed, Some(file_key)) => file_key,
+        _ => {
+            return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                "The SCA compliance report can only be downloaded once completed, its status is {}",
+                report.status
+            ),
+            }))
+        }
+    };
+
+    let file_data = state
+        .file_storage_client
+        .retrieve_file(&file_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve the SCA compliance report file")?;
+
+    Ok(services::ApplicationResponse::FileData((
+        file_data,
+        mime::TEXT_CSV,
+    )))
+}
+
+/// Fetches the authentication run for the attempt with an authentication connector, if any. The
+/// attempts authenticated by the connector do not have an authentication.
+async fn get_authentication(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<Option<storage::Authentication>> {
+    let Some(authentication_id) = payment_attempt.authentication_id.clone() else {
+        return Ok(None);
+    };
+
+    match db
+        .find_authentication_by_merchant_id_authentication_id(merchant_id, authentication_id)
+        .await
+    {
+        Ok(authentication) => Ok(Some(authentication)),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the authentication of the attempt"),
+    }
+}
+
+/// Generates the report, classifying the card payments of its month page by page, and uploads the
+/// classified payments to the file storage. The report is generated again from scratch when the
+/// task is retried after a failure.
+#[instrument(skip_all)]
+pub async fn generate_sca_compliance_report(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    report_id: &str,
+) -> RouterResult<()> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let merchant_id = merchant_account.get_id();
+    let mut report = get_sca_compliance_report(db, merchant_id, report_id).await?;
+    if report.status != ScaComplianceReportStatus::Queued {
+        return Ok(());
+    }
+
+    let (starting_at, ending_at) = get_month_range(report.year, report.month)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid month in a validated SCA compliance report")?;
+    let page_size = consts::SCA_COMPLIANCE_REPORT_PAGE_SIZE;
+    let mut csv_writer = Writer::from_writer(Vec::new());
+    let mut treatment_counts = std::collections::HashMap::<ScaTreatment, usize>::new();
+    let mut offset = 0;
+
+    loop {
+        let constraints = PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+            offset,
+            starting_at: Some(starting_at),
+            ending_at: Some(ending_at),
+            amount_filter: None,
+            connector: None,
+            currency: None,
+            status: None,
+            payment_method: Some(vec![enums::PaymentMethod::Card]),
+            payment_method_type: None,
+            authentication_type: None,
+            merchant_connector_id: None,
+            profile_id: report.profile_id.clone().map(|profile_id| vec![profile_id]),
+            customer_id: None,
+            starting_after_id: None,
+            ending_before_id: None,
+            limit: Some(page_size),
+            order: Order {
+                on: SortOn::Created,
+                by: SortBy::Asc,
+            },
+            card_network: None,
+            card_discovery: None,
+            merchant_order_reference_id: None,
+            customer_email_hash: None,
+            customer_phone_hash: None,
+            custom_fields: None,
+        }));
+        let payments = db
+            .get_filtered_payment_intents_attempt(
+                key_manager_state,
+                merchant_id,
+                &constraints,
+                key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the payments of the SCA compliance report")?;
+
+        let fetched_count = payments.len();
+        for (payment_intent, payment_attempt) in payments {
+            let Some(card_info) = get_card_info(&payment_attempt) else {
+                continue;
+            };
+            if !card_info
+                .card_issuing_country
+                .as_deref()
+                .is_some_and(is_sca_issuing_country)
+            {
+                continue;
+            }
+
+            let authentication = get_authentication(db, merchant_id, &payment_attempt).await?;
+            let sca_treatment = get_sca_treatment(&ScaTreatmentInputs {
+                off_session: payment_intent.off_session,
+                exemption_type: payment_intent.psd2_sca_exemption_type,
+                attempt_authentication_type: payment_attempt.authentication_type,
+                attempt_status: payment_attempt.status,
+                authentication: authentication.as_ref().map(|authentication| {
+                    (
+                        authentication.authentication_type,
+                        authentication.trans_status.clone(),
+                    )
+                }),
+            });
+            *treatment_counts.entry(sca_treatment).or_default() += 1;
+
+            csv_writer
+                .serialize(ScaComplianceReportRecord {
+                    payment_id: payment_intent.payment_id,
+                    attempt_id: payment_attempt.attempt_id,
+                    profile_id: payment_intent.profile_id,
+                    created_at: payment_intent.created_at,
+                    status: payment_intent.status,
+                    amount: payment_intent.amount,
+                    currency: payment_intent.currency,
+                    connector: payment_attempt.connector,
+                    card_network: card_info.card_network,
+                    card_issuing_country: card_info.card_issuing_country,
+                    sca_treatment,
+                    exemption_type: payment_intent.psd2_sca_exemption_type,
+                    authentication_connector: authentication
+                        .as_ref()
+                        .map(|authentication| authentication.authentication_connector.clone()),
+                    trans_status: authentication
+                        .as_ref()
+                        .and_then(|authentication| authentication.trans_status.clone()),
+                    eci: authentication.and_then(|authentication| authentication.eci),
+                })
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to write the SCA compliance report")?;
+        }
+
+        if fetched_count < usize::try_from(page_size).unwrap_or(usize::MAX) {
+            break;
+        }
+        offset += page_size;
+    }
+
+    let data = csv_writer
+        .into_inner()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate the SCA compliance report file")?;
+    let file_key = get_report_file_key(merchant_id, report_id);
+    state
+        .file_storage_client
+        .upload_file(&file_key, data)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to upload the SCA compliance report file")?;
+
+    report.treatments = ScaTreatment::iter()
+        .map(|treatment| ScaTreatmentCount {
+            treatment,
+            count: treatment_counts
+                .get(&treatment)
+                .copied()
+                .unwrap_or_default(),
+        })
+        .collect();
+    report.total_count = treatment_counts.values().sum();
+    report.file_key = Some(file_key);
+    report.status = ScaComplianceReportStatus::Completed;
+    report.modified_at = common_utils::date_time::now();
+    update_sca_compliance_report(db, merchant_id, &report).await
+}
+
+/// Marks the report as failed, once its generation is no longer retried
+#[instrument(skip_all)]
+pub async fn fail_sca_compliance_report(
+    db: &dyn StorageInterface,
+    tracking_data: &storage::ScaComplianceReportTrackingData,
+) -> RouterResult<()> {
+    let mut report =
+        get_sca_compliance_report(db, &tracking_data.merchant_id, &tracking_data.report_id).await?;
+    report.status = ScaComplianceReportStatus::Failed;
+    report.error_message = Some("The payments of the month could not be classified".to_string());
+    report.modified_at = common_utils::date_time::now();
+    update_sca_compliance_report(db, &tracking_data.merchant_id, &report).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_inputs() -> ScaTreatmentInputs {
+        ScaTreatmentInputs {
+            off_session: None,
+            exemption_type: None,
+            attempt_authentication_type: Some(enums::AuthenticationType::NoThreeDs),
+            attempt_status: enums::AttemptStatus::Charged,
+            authentication: None,
+        }
+    }
+
+    #[test]
+    fn test_get_sca_treatment() {
+        assert_eq!(get_sca_treatment(&get_inputs()), ScaTreatment::NotApplied);
+
+        let exempted = ScaTreatmentInputs {
+            exemption_type: Some(enums::ScaExemptionType::LowValue),
+            ..get_inputs()
+        };
+        assert_eq!(get_sca_treatment(&exempted), ScaTreatment::Exempted);
+
+        let merchant_initiated = ScaTreatmentInputs {
+            off_session: Some(true),
+            authentication: Some((None, Some(enums::TransactionStatus::Success))),
+            ..get_inputs()
+        };
+        assert_eq!(
+            get_sca_treatment(&merchant_initiated),
+            ScaTreatment::MerchantInitiated
+        );
+
+        let challenged = ScaTreatmentInputs {
+            exemption_type: Some(enums::ScaExemptionType::TransactionRiskAnalysis),
+            authentication: Some((
+                Some(enums::DecoupledAuthenticationType::Challenge),
+                Some(enums::TransactionStatus::Success),
+            )),
+            ..get_inputs()
+        };
+        assert_eq!(get_sca_treatment(&challenged), ScaTreatment::Challenge);
+
+        let frictionless = ScaTreatmentInputs {
+            authentication: Some((
+                Some(enums::DecoupledAuthenticationType::Frictionless),
+                Some(enums::TransactionStatus::Success),
+            )),
+            ..get_inputs()
+        };
+        assert_eq!(get_sca_treatment(&frictionless), ScaTreatment::Frictionless);
+
+        let rejected = ScaTreatmentInputs {
+            authentication: Some((
+                Some(enums::DecoupledAuthenticationType::Challenge),
+                Some(enums::TransactionStatus::Rejected),
+            )),
+            ..get_inputs()
+        };
+        assert_eq!(
+            get_sca_treatment(&rejected),
+            ScaTreatment::AuthenticationFailed
+        );
+
+        let connector_authenticated = ScaTreatmentInputs {
+            attempt_authentication_type: Some(enums::AuthenticationType::ThreeDs),
+            ..get_inputs()
+        };
+        assert_eq!(
+            get_sca_treatment(&connector_authenticated),
+            ScaTreatment::ConnectorAuthenticated
+        );
+
+        let connector_authentication_failed = ScaTreatmentInputs {
+            attempt_authentication_type: Some(enums::AuthenticationType::ThreeDs),
+            attempt_status: enums::AttemptStatus::AuthenticationFailed,
+            ..get_inputs()
+        };
+        assert_eq!(
+            get_sca_treatment(&connector_authentication_failed),
+            ScaTreatment::AuthenticationFailed
+        );
+    }
+
+    #[test]
+    fn test_is_sca_issuing_country() {
+        assert!(is_sca_issuing_country("GERMANY"));
+        assert!(is_sca_issuing_country("United Kingdom"));
+        assert!(is_sca_issuing_country("czech republic"));
+        assert!(!is_sca_issuing_country("UNITEDSTATESOFAMERICA"));
+        assert!(!is_sca_issuing_country("INDIA"));
+    }
+
+    #[test]
+    fn test_get_month_range() {
+        assert_eq!(
+            get_month_range(2025, 2),
+            Some((
+                time::macros::datetime!(2025-02-01 00:00),
+                time::macros::datetime!(2025-02-28 23:59:59.999999),
+            ))
+        );
+        assert_eq!(
+            get_month_range(2024, 12),
+            Some((
+                time::macros::datetime!(2024-12-01 00:00),
+                time::macros::datetime!(2024-12-31 23:59:59.999999),
+            ))
+        );
+        assert_eq!(get_month_range(2025, 13), None);
+        assert_eq!(get_month_range(2025, 0), None);
+    }
+}
@@ -0,0 +1,178 @@
+//! Ingestion of the events generated by the SDK. The SDK sends its events in batches, which are
+//! validated and counted against the quota of the merchant before being streamed to the SDK events
+//! analytics source. The events of a batch are accepted or rejected individually, so that a
+//! malformed event does not cause the rest of the batch to be dropped.
+
+use api_models::sdk_events::{
+    RejectedSdkEvent, SdkEventRequest, SdkEventsBatchRequest, SdkEventsBatchResponse,
+};
+use common_utils::{date_time, id_type};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    configs::settings::SdkEventsConfig,
+    consts,
+    core::errors::{self, RouterResponse},
+    events::sdk_events::SdkEvent,
+    routes::{app::SessionStateInfo, SessionState},
+    services::ApplicationResponse,
+    types::domain,
+    utils,
+};
+
+#[instrument(skip_all)]
+pub async fn ingest_sdk_events(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    remote_ip: Option<String>,
+    request: SdkEventsBatchRequest,
+) -> RouterResponse<SdkEventsBatchResponse> {
+    let config = &state.conf.sdk_events;
+
+    utils::when(request.events.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The batch must contain at least one event".to_string(),
+        })
+    })?;
+    utils::when(request.events.len() > config.max_batch_size, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "The batch must not contain more than {} events",
+                config.max_batch_size
+            ),
+        })
+    })?;
+
+    let now = date_time::now_unix_timestamp();
+    let mut rejected = Vec::new();
+    let mut valid_events = Vec::with_capacity(request.events.len());
+    for (index, event) in request.events.into_iter().enumerate() {
+        match validate_sdk_event(&event, config, now) {
+            Ok(()) => valid_events.push((index, event)),
+            Err(reason) => rejected.push(RejectedSdkEvent { index, reason }),
+        }
+    }
+
+    let allowed_events_count =
+        get_allowed_events_count(&state, merchant_account.get_id(), valid_events.len(), now).await;
+
+    let mut accepted = 0;
+    for (index, event) in valid_events {
+        if accepted < allowed_events_count {
+            state.event_handler().log_event(&SdkEvent::new(
+                merchant_account.publishable_key.clone(),
+                remote_ip.clone(),
+                event,
+            ));
+            accepted += 1;
+        } else {
+            rejected.push(RejectedSdkEvent {
+                index,
+                reason: "The quota of SDK events of the merchant has been exceeded".to_string(),
+            });
+        }
+    }
+    rejected.sort_by_key(|rejected_event| rejected_event.index);
+
+    Ok(ApplicationResponse::Json(SdkEventsBatchResponse {
+        accepted,
+        rejected,
+    }))
+}
+
+fn validate_sdk_event(
+    event: &SdkEventRequest,
+    config: &SdkEventsConfig,
+    now: i64,
+) -> Result<(), String> {
+    if event.event_name.trim().is_empty() {
+        return Err("The event name must not be empty".to_string());
+    }
+
+    let timestamp = event.timestamp / 1000;
+    if timestamp < now - config.max_event_age {
+        return Err(format!(
+            "The event must not be older than {} seconds",
+            config.max_event_age
+        ));
+    }
+    if timestamp > now + consts::SDK_EVENTS_MAX_CLOCK_SKEW_SECONDS {
+        return Err("The timestamp of the event must not be in the future".to_string());
+    }
+
+    if event
+        .value
+        .as_ref()
+        .is_some_and(|value| value.len() > config.max_value_length)
+    {
+        return Err(format!(
+            "The value of the event must not be longer than {} characters",
+            config.max_value_length
+        ));
+    }
+
+    Ok(())
+}
+
+fn get_sdk_events_quota_redis_key(merchant_id: &id_type::MerchantId, window: i64) -> String {
+    format!(
+        "{}_sdk_events_quota_{window}",
+        merchant_id.get_string_repr()
+    )
+}
+
+/// Counts the events against the quota of the merchant for the current window and returns the
+/// number of events which are within the quota. The events are accepted if the quota cannot be
+/// checked, as the loss of the telemetry is worse than exceeding the quota.
+async fn get_allowed_events_count(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    events_count: usize,
+    now: i64,
+) -> usize {
+    if events_count == 0 {
+        return 0;
+    }
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection");
+            return events_count;
+        }
+    };
+
+    let window = now / consts::SDK_EVENTS_QUOTA_WINDOW_SECONDS;
+    let key = get_sdk_events_quota_redis_key(merchant_id, window);
+    let events_count_after_increment = match redis_conn
+        .increment_fields_in_hash(
+            &key.as_str().into(),
+            &[("events", i64::try_from(events_count).unwrap_or(i64::MAX))],
+        )
+        .await
+    {
+        Ok(values) => values.first().copied().unwrap_or(events_count),
+        Err(error) => {
+            logger::error!(?error, "Failed to count the SDK events against the quota");
+            return events_count;
+        }
+    };
+
+    if let Err(error) = redis_conn
+        .set_expiry(
+            &key.as_str().into(),
+            consts::SDK_EVENTS_QUOTA_WINDOW_SECONDS,
+        )
+        .await
+    {
+        logger::error!(?error, "Failed to set expiry for the SDK events quota");
+    }
+
+    let quota =
+        usize::try_from(state.conf.sdk_events.merchant_events_per_minute).unwrap_or(usize::MAX);
+    let previous_events_count = events_count_after_increment.saturating_sub(events_count);
+
+    quota
+        .saturating_sub(previous_events_count)
+        .min(events_count)
+}
@@ -0,0 +1,517 @@
+//! Segregation of the test and the live data of a merchant by profile. A profile in test mode can
+//! only make payments through its test merchant connector accounts, test API keys are bound to a
+//! profile in test mode, and the payments created on a profile in test mode are marked as test
+//! payments, which can be purged along with their customers once they are no longer needed.
+
+#[cfg(feature = "olap")]
+use std::collections::HashSet;
+
+#[cfg(feature = "olap")]
+use api_models::admin::{TestDataPurgeRequest, TestDataPurgeResponse};
+use api_models::{
+    admin::{ProfileTestModeRequest, ProfileTestModeResponse},
+    routing::RoutableConnectorChoice,
+};
+use common_utils::{date_time, id_type};
+use error_stack::{report, ResultExt};
+#[cfg(feature = "olap")]
+use hyperswitch_domain_models::payments::payment_intent::PaymentIntentFetchConstraints;
+#[cfg(feature = "olap")]
+use router_env::logger;
+use router_env::{instrument, tracing};
+
+#[cfg(feature = "olap")]
+use crate::{consts, core::data_residency, types::api};
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::helpers,
+    },
+    routes::SessionState,
+    services,
+    types::{domain, storage},
+};
+
+async fn find_business_profile(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_id: &id_type::MerchantId,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<domain::Profile> {
+    state
+        .store
+        .find_business_profile_by_merchant_id_profile_id(
+            &state.into(),
+            key_store,
+            merchant_id,
+            profile_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })
+}
+
+#[instrument(skip_all)]
+pub async fn update_profile_test_mode(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: ProfileTestModeRequest,
+) -> RouterResponse<ProfileTestModeResponse> {
+    let db = state.store.as_ref();
+    let business_profile =
+        find_business_profile(&state, &key_store, merchant_account.get_id(), &profile_id).await?;
+
+    // The test API keys bound to the profile would otherwise be able to make live payments
+    if !request.test_mode {
+        let now = date_time::now();
+        let has_active_test_api_keys = db
+            .list_api_keys_by_merchant_id(merchant_account.get_id(), None, None)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list API keys of the merchant")?
+            .iter()
+            .any(|api_key| {
+                api_key.test_mode.unwrap_or(false)
+                    && api_key.profile_id.as_ref() == Some(&profile_id)
+                    && api_key
+                        .expires_at
+                        .map_or(true, |expires_at| expires_at > now)
+            });
+
+        if has_active_test_api_keys {
+            return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "The test API keys bound to the profile must be revoked before its test \
+                          mode is disabled"
+                    .to_string(),
+            }));
+        }
+    }
+
+    let business_profile = db
+        .update_profile_by_profile_id(
+            &(&state).into(),
+            &key_store,
+            business_profile,
+            domain::ProfileUpdate::TestModeUpdate {
+                is_test_mode: request.test_mode,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        ProfileTestModeResponse {
+            profile_id,
+            test_mode: business_profile.is_test_mode,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_profile_test_mode(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<ProfileTestModeResponse> {
+    let business_profile =
+        find_business_profile(&state, &key_store, merchant_account.get_id(), &profile_id).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        ProfileTestModeResponse {
+            profile_id,
+            test_mode: business_profile.is_test_mode,
+        },
+    ))
+}
+
+/// Validates that a test API key is bound to a profile of the merchant in test mode, and that a
+/// live API key is not bound to a profile
+pub async fn validate_api_key_test_mode(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    test_mode: bool,
+    profile_id: Option<&id_type::ProfileId>,
+) -> RouterResult<()> {
+    match (test_mode, profile_id) {
+        (true, Some(profile_id)) => {
+            let business_profile =
+                find_business_profile(state, key_store, &key_store.merchant_id, profile_id).await?;
+
+            if !business_profile.is_test_mode {
+                return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Test API keys can only be bound to profiles in test mode".to_string(),
+                }));
+            }
+            Ok(())
+        }
+        (true, None) => Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "profile_id",
+        })),
+        (false, Some(_)) => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "profile_id can only be provided for test API keys".to_string(),
+        })),
+        (false, None) => Ok(()),
+    }
+}
+
+/// Whether the connector has one of the given test merchant connector accounts, which are
+/// identified by their IDs and their connector names
+fn has_test_connector_account(
+    connector: &RoutableConnectorChoice,
+    test_connector_accounts: &[(id_type::MerchantConnectorAccountId, String)],
+) -> bool {
+    test_connector_accounts
+        .iter()
+        .any(|(merchant_connector_id, connector_name)| {
+            match connector.merchant_connector_id.as_ref() {
+                Some(connector_merchant_connector_id) => {
+                    merchant_connector_id == connector_merchant_connector_id
+                }
+                None => *connector_name == connector.connector.to_string(),
+            }
+        })
+}
+
+/// Removes the connectors which do not have a test merchant connector account from the routed
+/// connectors of a profile in test mode. The connectors of the profiles which are not in test mode
+/// are not filtered, as the merchant connector accounts were not segregated before.
+#[instrument(skip_all)]
+pub async fn filter_connectors_for_test_mode(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::Profile,
+    connectors: Vec<RoutableConnectorChoice>,
+) -> RouterResult<Vec<RoutableConnectorChoice>> {
+    if !business_profile.is_test_mode {
+        return Ok(connectors);
+    }
+
+    let test_connector_accounts = state
+        .store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &state.into(),
+            merchant_id,
+            false,
+            key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch merchant connector accounts")?
+        .into_iter()
+        .filter(|mca| &mca.profile_id == business_profile.get_id() && mca.test_mode == Some(true))
+        .map(|mca| (mca.get_id(), mca.connector_name))
+        .collect::<Vec<_>>();
+
+    let available_connectors = connectors
+        .into_iter()
+        .filter(|connector| has_test_connector_account(connector, &test_connector_accounts))
+        .collect::<Vec<_>>();
+
+    if available_connectors.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "None of the eligible connectors has a test merchant connector account \
+                      configured for the profile in test mode"
+                .to_string(),
+        }));
+    }
+
+    Ok(available_connectors)
+}
+
+fn validate_test_mode_of_connector_account(
+    is_test_payment: bool,
+    is_test_connector_account: Option<bool>,
+) -> RouterResult<()> {
+    if is_test_payment && is_test_connector_account != Some(true) {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Profiles in test mode can only make payments through test merchant \
+                      connector accounts"
+                .to_string(),
+        }));
+    }
+    Ok(())
+}
+
+/// Validates that a test payment is made only through a test merchant connector account. The
+/// payment is checked rather than its profile, as the test mode of the profile may have changed
+/// since the payment was created.
+pub fn validate_connector_account_for_test_mode(
+    payment_intent: &storage::PaymentIntent,
+    merchant_connector_account: &helpers::MerchantConnectorAccountType,
+) -> RouterResult<()> {
+    validate_test_mode_of_connector_account(
+        payment_intent.is_test_payment == Some(true),
+        merchant_connector_account.is_test_mode_on(),
+    )
+}
+
+#[cfg(feature = "olap")]
+fn get_purge_cutoff(
+    now: time::PrimitiveDateTime,
+    older_than_days: u16,
+) -> RouterResult<time::PrimitiveDateTime> {
+    if older_than_days == 0 {
+        return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "older_than_days must be greater than 0".to_string(),
+        }));
+    }
+    Ok(now - time::Duration::days(i64::from(older_than_days)))
+}
+
+/// Deletes the test payments of a profile in test mode which were created before the given number
+/// of days, along with their attempts and refunds. The payments are deleted in batches, each in a
+/// single transaction, and only the payments created while the profile was in test mode are
+/// deleted. The customers of the deleted payments are deleted as well, unless they have other
+/// payments or saved payment methods.
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn purge_test_data(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: TestDataPurgeRequest,
+) -> RouterResponse<TestDataPurgeResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_account.get_id();
+
+    let business_profile =
+        find_business_profile(&state, &key_store, merchant_id, &profile_id).await?;
+
+    let created_before = get_purge_cutoff(date_time::now(), request.older_than_days)?;
+
+    if !business_profile.is_test_mode {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Test data can only be purged for profiles in test mode".to_string(),
+        }));
+    }
+
+    let mut payments_deleted = 0;
+    let mut customer_ids = HashSet::new();
+    loop {
+        let deletion = db
+            .delete_test_payments(
+                key_manager_state,
+                merchant_id,
+                &profile_id,
+                created_before,
+                consts::TEST_DATA_PURGE_BATCH_SIZE,
+                &key_store,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to delete test payments")?;
+
+        let deleted_count = deletion.payment_intents.len();
+        payments_deleted += deleted_count;
+        customer_ids.extend(
+            deletion
+                .payment_intents
+                .into_iter()
+                .filter_map(|payment_intent| payment_intent.customer_id),
+        );
+
+        if i64::try_from(deleted_count).map_or(true, |deleted_count| {
+            deleted_count < consts::TEST_DATA_PURGE_BATCH_SIZE
+        }) {
+            break;
+        }
+    }
+
+    let mut customers_deleted = 0;
+    for customer_id in customer_ids {
+        match purge_test_customer(
+            &state,
+            &merchant_account,
+            &key_store,
+            &customer_id,
+            created_before,
+        )
+        .await
+        {
+            Ok(true) => customers_deleted += 1,
+            Ok(false) => {}
+            // The payments are already deleted, so the failure to delete a customer is not
+            // failed upon
+            Err(error) => logger::error!(?error, "Failed to purge test customer"),
+        }
+    }
+
+    Ok(services::ApplicationResponse::Json(TestDataPurgeResponse {
+        profile_id,
+        payments_deleted,
+        customers_deleted,
+    }))
+}
+
+/// Deletes the customer if it was created before the given time, and has neither payments nor
+/// saved payment methods left
+#[cfg(feature = "olap")]
+async fn purge_test_customer(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: &id_type::CustomerId,
+    created_before: time::PrimitiveDateTime,
+) -> RouterResult<bool> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let merchant_id = merchant_account.get_id();
+    let pii_store = data_residency::get_pii_store(state, merchant_id).await?;
+
+    let customer = pii_store
+        .find_customer_optional_by_customer_id_merchant_id(
+            key_manager_state,
+            customer_id,
+            merchant_id,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch customer")?;
+
+    if customer.map_or(true, |customer| customer.created_at >= created_before) {
+        return Ok(false);
+    }
+
+    let constraints: PaymentIntentFetchConstraints = api::PaymentListConstraints {
+        customer_id: Some(customer_id.clone()),
+        starting_after: None,
+        ending_before: None,
+        limit: 1,
+        created: None,
+        created_lt: None,
+        created_gt: None,
+        created_lte: None,
+        created_gte: None,
+    }
+    .into();
+    let has_payments = !db
+        .filter_payment_intent_by_constraints(
+            key_manager_state,
+            merchant_id,
+            &constraints,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payments of the customer")?
+        .is_empty();
+
+    let has_payment_methods = !db
+        .find_payment_method_by_customer_id_merchant_id_list(
+            key_manager_state,
+            key_store,
+            customer_id,
+            merchant_id,
+            Some(1),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payment methods of the customer")?
+        .is_empty();
+
+    if has_payments || has_payment_methods {
+        return Ok(false);
+    }
+
+    pii_store
+        .delete_customer_by_customer_id_merchant_id(customer_id, merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to delete customer")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+
+    use api_models::{enums::RoutableConnectors, routing::RoutableChoiceKind};
+
+    use super::*;
+
+    fn merchant_connector_id(id: &str) -> id_type::MerchantConnectorAccountId {
+        id_type::MerchantConnectorAccountId::wrap(id.to_string())
+            .expect("Failed to create merchant connector account id")
+    }
+
+    fn connector_choice(
+        connector: RoutableConnectors,
+        merchant_connector_id: Option<id_type::MerchantConnectorAccountId>,
+    ) -> RoutableConnectorChoice {
+        RoutableConnectorChoice {
+            choice_kind: RoutableChoiceKind::FullStruct,
+            connector,
+            merchant_connector_id,
+        }
+    }
+
+    #[test]
+    fn test_has_test_connector_account() {
+        let test_connector_accounts =
+            vec![(merchant_connector_id("mca_test"), "stripe".to_string())];
+
+        assert!(has_test_connector_account(
+            &connector_choice(
+                RoutableConnectors::Stripe,
+                Some(merchant_connector_id("mca_test"))
+            ),
+            &test_connector_accounts,
+        ));
+        assert!(!has_test_connector_account(
+            &connector_choice(
+                RoutableConnectors::Stripe,
+                Some(merchant_connector_id("mca_live"))
+            ),
+            &test_connector_accounts,
+        ));
+        assert!(has_test_connector_account(
+            &connector_choice(RoutableConnectors::Stripe, None),
+            &test_connector_accounts,
+        ));
+        assert!(!has_test_connector_account(
+            &connector_choice(RoutableConnectors::Adyen, None),
+            &test_connector_accounts,
+        ));
+        assert!(!has_test_connector_account(
+            &connector_choice(RoutableConnectors::Stripe, None),
+            &[],
+        ));
+    }
+
+    #[test]
+    fn test_validate_test_mode_of_connector_account() {
+        assert!(validate_test_mode_of_connector_account(true, Some(true)).is_ok());
+        assert!(validate_test_mode_of_connector_account(true, Some(false)).is_err());
+        assert!(validate_test_mode_of_connector_account(true, None).is_err());
+        assert!(validate_test_mode_of_connector_account(false, Some(false)).is_ok());
+        assert!(validate_test_mode_of_connector_account(false, None).is_ok());
+        assert!(validate_test_mode_of_connector_account(false, Some(true)).is_ok());
+    }
+
+    #[cfg(feature = "olap")]
+    #[test]
+    fn test_get_purge_cutoff() {
+        let now = time::macros::datetime!(2025-04-24 10:00);
+
+        assert!(get_purge_cutoff(now, 0).is_err());
+        assert_eq!(
+            get_purge_cutoff(now, 1).expect("Failed to get purge cutoff"),
+            time::macros::datetime!(2025-04-23 10:00)
+        );
+        assert_eq!(
+            get_purge_cutoff(now, 30).expect("Failed to get purge cutoff"),
+            time::macros::datetime!(2025-03-25 10:00)
+        );
+    }
+}
@@ -20,7 +20,7 @@ pub async fn generate_sample_data_for_user(
     req: SampleDataRequest,
     _req_state: ReqState,
 ) -> SampleDataApiResponse<()> {
-    let sample_data = utils::user::sample_data::generate_sample_data(
+    insert_sample_data(
         &state,
         req,
         &user_from_token.merchant_id,
@@ -28,11 +28,26 @@ pub async fn generate_sample_data_for_user(
     )
     .await?;
 
+    Ok(ApplicationResponse::StatusOk)
+}
+
+/// Generates the sample payments for the merchant along with their refunds and disputes, and
+/// returns the number of payments, refunds and disputes inserted
+#[cfg(feature = "v1")]
+pub async fn insert_sample_data(
+    state: &SessionState,
+    req: SampleDataRequest,
+    merchant_id: &common_utils::id_type::MerchantId,
+    org_id: &common_utils::id_type::OrganizationId,
+) -> SampleDataResult<(usize, usize, usize)> {
+    let sample_data =
+        utils::user::sample_data::generate_sample_data(state, req, merchant_id, org_id).await?;
+
     let key_store = state
         .store
         .get_merchant_key_store_by_merchant_id(
-            &(&state).into(),
-            &user_from_token.merchant_id,
+            &state.into(),
+            merchant_id,
             &state.store.get_master_key().to_vec().into(),
         )
         .await
@@ -58,10 +73,11 @@ pub async fn generate_sample_data_for_user(
             (pi, pa, rf, dp)
         },
     );
+    let counts = (payment_intents.len(), refunds.len(), disputes.len());
 
     state
         .store
-        .insert_payment_intents_batch_for_sample_data(&(&state).into(), payment_intents, &key_store)
+        .insert_payment_intents_batch_for_sample_data(&state.into(), payment_intents, &key_store)
         .await
         .switch()?;
     state
@@ -80,7 +96,7 @@ pub async fn generate_sample_data_for_user(
         .await
         .switch()?;
 
-    Ok(ApplicationResponse::StatusOk)
+    Ok(counts)
 }
 
 #[cfg(feature = "v1")]
@@ -90,13 +106,22 @@ pub async fn delete_sample_data_for_user(
     _req: SampleDataRequest,
     _req_state: ReqState,
 ) -> SampleDataApiResponse<()> {
-    let merchant_id_del = user_from_token.merchant_id;
-    let key_manager_state = &(&state).into();
+    delete_sample_data(&state, &user_from_token.merchant_id).await?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+#[cfg(feature = "v1")]
+pub async fn delete_sample_data(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> SampleDataResult<()> {
+    let key_manager_state = &state.into();
     let key_store = state
         .store
         .get_merchant_key_store_by_merchant_id(
             key_manager_state,
-            &merchant_id_del,
+            merchant_id,
             &state.store.get_master_key().to_vec().into(),
         )
         .await
@@ -105,24 +130,24 @@ pub async fn delete_sample_data_for_user(
 
     state
         .store
-        .delete_payment_intents_for_sample_data(key_manager_state, &merchant_id_del, &key_store)
+        .delete_payment_intents_for_sample_data(key_manager_state, merchant_id, &key_store)
         .await
         .switch()?;
     state
         .store
-        .delete_payment_attempts_for_sample_data(&merchant_id_del)
+        .delete_payment_attempts_for_sample_data(merchant_id)
         .await
         .switch()?;
     state
         .store
-        .delete_refunds_for_sample_data(&merchant_id_del)
+        .delete_refunds_for_sample_data(merchant_id)
         .await
         .switch()?;
     state
         .store
-        .delete_disputes_for_sample_data(&merchant_id_del)
+        .delete_disputes_for_sample_data(merchant_id)
         .await
         .switch()?;
 
-    Ok(ApplicationResponse::StatusOk)
+    Ok(())
 }
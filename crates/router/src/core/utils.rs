@@ -41,7 +41,7 @@ use crate::{
         storage::{self, enums},
         PollConfig,
     },
-    utils::{generate_id, generate_uuid, OptionExt, ValueExt},
+    utils::{generate_uuid, OptionExt, ValueExt},
 };
 
 pub const IRRELEVANT_CONNECTOR_REQUEST_REFERENCE_ID_IN_DISPUTE_FLOW: &str =
@@ -223,6 +223,7 @@ pub async fn construct_payout_router_data<'a, F>(
         connector_mandate_request_reference_id: None,
         authentication_id: None,
         psd2_sca_exemption_type: None,
+        profile_id: Some(payout_data.profile_id.clone()),
     };
 
     Ok(router_data)
@@ -398,6 +399,7 @@ pub async fn construct_refund_router_data<'a, F>(
             merchant_account_id,
             merchant_config_currency,
             capture_method,
+            end_to_end_reference: refund.end_to_end_reference.clone(),
         },
 
         response: Ok(types::RefundsResponseData {
@@ -432,6 +434,7 @@ pub async fn construct_refund_router_data<'a, F>(
         connector_mandate_request_reference_id: None,
         authentication_id: None,
         psd2_sca_exemption_type: None,
+        profile_id: Some(profile_id.clone()),
     };
 
     Ok(router_data)
@@ -443,9 +446,13 @@ pub fn get_or_generate_id(
     prefix: &str,
 ) -> Result<String, errors::ApiErrorResponse> {
     let validate_id = |id| validate_id(id, key);
-    provided_id
-        .clone()
-        .map_or(Ok(generate_id(consts::ID_LENGTH, prefix)), validate_id)
+    provided_id.clone().map_or(
+        Ok(common_utils::generate_resource_id(
+            consts::ID_LENGTH,
+            prefix,
+        )),
+        validate_id,
+    )
 }
 
 pub fn get_or_generate_uuid(
@@ -615,11 +622,41 @@ pub fn get_split_refunds(
         _ => Ok(None),
     }
 }
+
+/// Derives a key for a single purpose, identified by the label, from the key of the merchant, so
+/// that the key which encrypts the data of the merchant is never used to sign or to hash
+pub fn derive_merchant_key(merchant_key: &[u8], label: &[u8]) -> RouterResult<Vec<u8>> {
+    let hkdf = ::hkdf::Hkdf::<sha2::Sha256>::new(None, merchant_key);
+    let mut derived_key = vec![0u8; 32];
+    hkdf.expand(label, &mut derived_key)
+        .map_err(|_| report!(errors::ApiErrorResponse::InternalServerError))
+        .attach_printable("Failed to derive a key from the key of the merchant")?;
+    Ok(derived_key)
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::expect_used)]
     use super::*;
 
+    #[test]
+    fn test_derive_merchant_key() {
+        let merchant_key = [7u8; 32];
+        let receipt_key =
+            derive_merchant_key(&merchant_key, consts::RECEIPT_KEY_LABEL).expect("Derived key");
+
+        assert_eq!(receipt_key.len(), 32);
+        assert_ne!(receipt_key, merchant_key.to_vec());
+        assert_eq!(
+            receipt_key,
+            derive_merchant_key(&merchant_key, consts::RECEIPT_KEY_LABEL).expect("Derived key")
+        );
+        assert_ne!(
+            receipt_key,
+            derive_merchant_key(&merchant_key, b"other").expect("Derived key")
+        );
+    }
+
     #[test]
     fn validate_id_length_constraint() {
         let payment_id =
@@ -641,7 +678,7 @@ mod tests {
 
     #[test]
     fn test_generate_id() {
-        let generated_id = generate_id(consts::ID_LENGTH, "ref");
+        let generated_id = crate::utils::generate_id(consts::ID_LENGTH, "ref");
         assert_eq!(generated_id.len(), consts::ID_LENGTH + 4)
     }
 
@@ -873,6 +910,7 @@ pub async fn construct_accept_dispute_router_data<'a>(
         connector_mandate_request_reference_id: None,
         authentication_id: None,
         psd2_sca_exemption_type: None,
+        profile_id: Some(profile_id),
     };
     Ok(router_data)
 }
@@ -971,6 +1009,7 @@ pub async fn construct_submit_evidence_router_data<'a>(
         connector_mandate_request_reference_id: None,
         authentication_id: None,
         psd2_sca_exemption_type: None,
+        profile_id: Some(profile_id),
     };
     Ok(router_data)
 }
@@ -1075,6 +1114,7 @@ pub async fn construct_upload_file_router_data<'a>(
         connector_mandate_request_reference_id: None,
         authentication_id: None,
         psd2_sca_exemption_type: None,
+        profile_id: Some(profile_id),
     };
     Ok(router_data)
 }
@@ -1199,6 +1239,7 @@ pub async fn construct_payments_dynamic_tax_calculation_router_data<F: Clone>(
         connector_mandate_request_reference_id: None,
         authentication_id: None,
         psd2_sca_exemption_type: None,
+        profile_id: payment_intent.profile_id.clone(),
     };
     Ok(router_data)
 }
@@ -1300,6 +1341,7 @@ pub async fn construct_defend_dispute_router_data<'a>(
         connector_mandate_request_reference_id: None,
         authentication_id: None,
         psd2_sca_exemption_type: None,
+        profile_id: Some(profile_id),
     };
     Ok(router_data)
 }
@@ -1395,6 +1437,89 @@ pub async fn construct_retrieve_file_router_data<'a>(
         connector_mandate_request_reference_id: None,
         authentication_id: None,
         psd2_sca_exemption_type: None,
+        profile_id: Some(profile_id.clone()),
+    };
+    Ok(router_data)
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all)]
+pub fn construct_gift_card_balance_check_router_data(
+    state: &SessionState,
+    payment_intent: &storage::PaymentIntent,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+    gift_card_data: domain::GiftCardData,
+) -> RouterResult<types::PaymentsBalanceRouterData> {
+    let merchant_connector_account =
+        helpers::MerchantConnectorAccountType::DbVal(Box::new(merchant_connector_account.clone()));
+    let test_mode: Option<bool> = merchant_connector_account.is_test_mode_on();
+    let auth_type: types::ConnectorAuthType = merchant_connector_account
+        .get_connector_account_details()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let connector = merchant_connector_account
+        .get_connector_name()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Missing connector name in merchant connector account")?;
+    let currency = payment_intent
+        .currency
+        .get_required_value("currency")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("currency is not set in payment_intent")?;
+    let payment_id = payment_intent.get_id().get_string_repr().to_owned();
+    let router_data = types::RouterData {
+        flow: PhantomData,
+        merchant_id: payment_intent.merchant_id.clone(),
+        connector,
+        tenant_id: state.tenant.tenant_id.clone(),
+        customer_id: payment_intent.customer_id.clone(),
+        connector_customer: None,
+        payment_id: payment_id.clone(),
+        attempt_id: payment_intent.active_attempt.get_id(),
+        status: enums::AttemptStatus::default(),
+        payment_method: enums::PaymentMethod::GiftCard,
+        connector_auth_type: auth_type,
+        description: None,
+        address: PaymentAddress::default(),
+        auth_type: enums::AuthenticationType::default(),
+        connector_meta_data: merchant_connector_account.get_metadata(),
+        connector_wallets_details: merchant_connector_account.get_connector_wallets_details(),
+        amount_captured: None,
+        minor_amount_captured: None,
+        payment_method_status: None,
+        request: types::GiftCardBalanceCheckRequestData {
+            payment_method_data: domain::PaymentMethodData::GiftCard(Box::new(gift_card_data)),
+            currency,
+        },
+        response: Err(ErrorResponse::default()),
+        access_token: None,
+        session_token: None,
+        reference_id: None,
+        payment_method_token: None,
+        recurring_mandate_payment_data: None,
+        preprocessing_id: None,
+        payment_method_balance: None,
+        connector_request_reference_id: payment_id,
+        #[cfg(feature = "payouts")]
+        payout_method_data: None,
+        #[cfg(feature = "payouts")]
+        quote_id: None,
+        test_mode,
+        connector_api_version: None,
+        connector_http_status_code: None,
+        external_latency: None,
+        apple_pay_flow: None,
+        frm_metadata: None,
+        refund_id: None,
+        dispute_id: None,
+        connector_response: None,
+        integrity_check: Ok(()),
+        additional_merchant_data: None,
+        header_payload: None,
+        connector_mandate_request_reference_id: None,
+        authentication_id: None,
+        psd2_sca_exemption_type: None,
+        profile_id: payment_intent.profile_id.clone(),
     };
     Ok(router_data)
 }
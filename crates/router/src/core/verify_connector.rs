@@ -6,6 +6,7 @@ use crate::{
     core::errors,
     services,
     types::{
+        self as oss_types,
         api::{
             self,
             verify_connector::{self as types, VerifyConnector},
@@ -20,39 +21,53 @@ pub async fn verify_connector_credentials(
     state: SessionState,
     req: VerifyConnectorRequest,
     _profile_id: Option<common_utils::id_type::ProfileId>,
+) -> errors::RouterResponse<()> {
+    verify_connector_auth(
+        &state,
+        req.connector_name,
+        req.connector_account_details.foreign_into(),
+    )
+    .await
+}
+
+/// Verifies the credentials of the connector by making a test request to it
+pub async fn verify_connector_auth(
+    state: &SessionState,
+    connector_name: Connector,
+    connector_auth: oss_types::ConnectorAuthType,
 ) -> errors::RouterResponse<()> {
     let boxed_connector = api::ConnectorData::get_connector_by_name(
         &state.conf.connectors,
-        &req.connector_name.to_string(),
+        &connector_name.to_string(),
         api::GetToken::Connector,
         None,
     )
     .change_context(errors::ApiErrorResponse::IncorrectConnectorNameGiven)?;
 
-    let card_details = utils::get_test_card_details(req.connector_name)?.ok_or(
+    let card_details = utils::get_test_card_details(connector_name)?.ok_or(
         errors::ApiErrorResponse::FlowNotSupported {
             flow: "Verify credentials".to_string(),
-            connector: req.connector_name.to_string(),
+            connector: connector_name.to_string(),
         },
     )?;
 
-    match req.connector_name {
+    match connector_name {
         Connector::Stripe => {
             connector::Stripe::verify(
-                &state,
+                state,
                 types::VerifyConnectorData {
                     connector: boxed_connector.connector,
-                    connector_auth: req.connector_account_details.foreign_into(),
+                    connector_auth,
                     card_details,
                 },
             )
             .await
         }
         Connector::Paypal => connector::Paypal::get_access_token(
-            &state,
+            state,
             types::VerifyConnectorData {
                 connector: boxed_connector.connector,
-                connector_auth: req.connector_account_details.foreign_into(),
+                connector_auth,
                 card_details,
             },
         )
@@ -60,7 +75,7 @@ pub async fn verify_connector_credentials(
         .map(|_| services::ApplicationResponse::StatusOk),
         _ => Err(errors::ApiErrorResponse::FlowNotSupported {
             flow: "Verify credentials".to_string(),
-            connector: req.connector_name.to_string(),
+            connector: connector_name.to_string(),
         }
         .into()),
     }
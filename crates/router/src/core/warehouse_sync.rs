@@ -0,0 +1,1585 @@
+//! Sync of the payments, refunds and disputes of a merchant to its own data warehouse, BigQuery or
+//! Snowflake. The records of each table are synced incrementally by the scheduler, in the order in
+//! which they were last modified, and the last modification time of the last record synced is kept
+//! as the high-water mark of the table, so that each sync only sends the records modified since the
+//! previous one. The modifications made within the last minute are held back until the next sync,
+//! so that a record modified earlier by a transaction that commits later is not skipped by the
+//! high-water mark. A record is sent again whenever it is modified, and the records are identified
+//! in the warehouse by their identifier and last modification time, so that the latest version of
+//! a record can be selected there. The personal data of the records is redacted with the redaction
+//! rules of the merchant before the records leave, and the credentials of the warehouse are
+//! encrypted with the key of the merchant.
+
+use std::collections::HashMap;
+
+use api_models::warehouse_sync::{
+    WarehouseDestination, WarehouseDestinationResponse, WarehouseRedactionAction,
+    WarehouseRedactionRule, WarehouseSyncConfigRequest, WarehouseSyncConfigResponse,
+    WarehouseSyncStatusResponse, WarehouseSyncTable, WarehouseSyncTableStatus,
+    WarehouseSyncTriggerRequest,
+};
+use common_utils::{
+    crypto::{self, SignMessage},
+    encryption::Encryption,
+    ext_traits::{BytesExt, Encode, StringExt},
+    id_type,
+    request::RequestContent,
+    type_name,
+    types::keymanager::Identifier,
+};
+use diesel_models::{configs, enums as storage_enums};
+use error_stack::{report, ResultExt};
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use masking::{Mask, PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use time::{Duration, PrimitiveDateTime};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        payments::custom_fields,
+    },
+    db::StorageInterface,
+    headers,
+    routes::SessionState,
+    services,
+    types::{domain, storage},
+};
+
+const WAREHOUSE_SYNC_TASK: &str = "WAREHOUSE_SYNC";
+const WAREHOUSE_SYNC_TAG: &str = "WAREHOUSE_SYNC";
+
+const BIG_QUERY_BASE_URL: &str = "https://bigquery.googleapis.com/bigquery/v2";
+/// The audience of the self-signed JWTs of the service accounts, which are accepted by BigQuery
+/// in place of OAuth access tokens
+const BIG_QUERY_AUDIENCE: &str = "https://bigquery.googleapis.com/";
+const BIG_QUERY_TOKEN_VALIDITY_IN_SECONDS: i64 = 60 * 60; // 1 hour
+
+const SNOWFLAKE_TOKEN_TYPE_HEADER: &str = "X-Snowflake-Authorization-Token-Type";
+const SNOWFLAKE_PROGRAMMATIC_ACCESS_TOKEN: &str = "PROGRAMMATIC_ACCESS_TOKEN";
+
+const WAREHOUSE_REQUEST_TIMEOUT_IN_SECONDS: u64 = 60;
+const MAX_TABLE_PREFIX_LENGTH: usize = 64;
+const HASH_KEY_LENGTH: usize = 32;
+
+const PAYMENTS_FIELDS: &[&str] = &[
+    "payment_id",
+    "merchant_id",
+    "profile_id",
+    "status",
+    "amount",
+    "currency",
+    "amount_captured",
+    "customer_id",
+    "description",
+    "return_url",
+    "metadata",
+    "setup_future_usage",
+    "off_session",
+    "business_country",
+    "business_label",
+    "statement_descriptor_name",
+    "statement_descriptor_suffix",
+    "merchant_order_reference_id",
+    "attempt_count",
+    "custom_fields",
+    "created_at",
+    "modified_at",
+];
+
+const REFUNDS_FIELDS: &[&str] = &[
+    "refund_id",
+    "merchant_id",
+    "profile_id",
+    "payment_id",
+    "connector",
+    "merchant_connector_id",
+    "refund_type",
+    "refund_status",
+    "total_amount",
+    "refund_amount",
+    "currency",
+    "refund_reason",
+    "description",
+    "metadata",
+    "error_code",
+    "error_message",
+    "created_at",
+    "modified_at",
+];
+
+const DISPUTES_FIELDS: &[&str] = &[
+    "dispute_id",
+    "merchant_id",
+    "profile_id",
+    "payment_id",
+    "attempt_id",
+    "connector",
+    "merchant_connector_id",
+    "connector_dispute_id",
+    "dispute_stage",
+    "dispute_status",
+    "connector_status",
+    "connector_reason",
+    "connector_reason_code",
+    "amount",
+    "currency",
+    "challenge_required_by",
+    "created_at",
+    "modified_at",
+];
+
+/// The fields which identify a version of a record, and cannot be redacted
+const PROTECTED_FIELDS: &[&str] = &[
+    "payment_id",
+    "refund_id",
+    "dispute_id",
+    "merchant_id",
+    "modified_at",
+];
+
+fn get_table_fields(table: WarehouseSyncTable) -> &'static [&'static str] {
+    match table {
+        WarehouseSyncTable::Payments => PAYMENTS_FIELDS,
+        WarehouseSyncTable::Refunds => REFUNDS_FIELDS,
+        WarehouseSyncTable::Disputes => DISPUTES_FIELDS,
+    }
+}
+
+fn get_default_tables() -> Vec<WarehouseSyncTable> {
+    vec![
+        WarehouseSyncTable::Payments,
+        WarehouseSyncTable::Refunds,
+        WarehouseSyncTable::Disputes,
+    ]
+}
+
+fn get_default_redaction_rules() -> Vec<WarehouseRedactionRule> {
+    vec![
+        WarehouseRedactionRule {
+            table: Some(WarehouseSyncTable::Payments),
+            field: "customer_id".to_string(),
+            action: WarehouseRedactionAction::Hash,
+        },
+        WarehouseRedactionRule {
+            table: Some(WarehouseSyncTable::Payments),
+            field: "return_url".to_string(),
+            action: WarehouseRedactionAction::Drop,
+        },
+        WarehouseRedactionRule {
+            table: Some(WarehouseSyncTable::Refunds),
+            field: "refund_reason".to_string(),
+            action: WarehouseRedactionAction::Drop,
+        },
+        WarehouseRedactionRule {
+            table: None,
+            field: "description".to_string(),
+            action: WarehouseRedactionAction::Drop,
+        },
+        WarehouseRedactionRule {
+            table: None,
+            field: "metadata".to_string(),
+            action: WarehouseRedactionAction::Drop,
+        },
+    ]
+}
+
+/// The credentials of the warehouse, and the key with which the fields redacted with
+/// [`WarehouseRedactionAction::Hash`] are hashed. The key is kept when the sync is reconfigured, so
+/// that the hashes of the same value do not change.
+#[derive(Debug, Serialize, Deserialize)]
+struct WarehouseSyncSecrets {
+    destination: WarehouseDestination,
+    hash_key: Secret<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StoredWarehouseSyncConfig {
+    secrets: Encryption,
+    tables: Vec<WarehouseSyncTable>,
+    table_prefix: Option<String>,
+    redaction_rules: Vec<WarehouseRedactionRule>,
+    sync_interval_minutes: u32,
+    enabled: bool,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    modified_at: PrimitiveDateTime,
+}
+
+impl StoredWarehouseSyncConfig {
+    fn to_response(&self, destination: &WarehouseDestination) -> WarehouseSyncConfigResponse {
+        WarehouseSyncConfigResponse {
+            destination: get_destination_response(destination),
+            tables: self.tables.clone(),
+            table_prefix: self.table_prefix.clone(),
+            redaction_rules: self.redaction_rules.clone(),
+            sync_interval_minutes: self.sync_interval_minutes,
+            enabled: self.enabled,
+            created_at: self.created_at,
+            modified_at: self.modified_at,
+        }
+    }
+
+    fn get_table_name(&self, table: WarehouseSyncTable) -> String {
+        format!(
+            "{}{table}",
+            self.table_prefix.as_deref().unwrap_or_default()
+        )
+    }
+}
+
+/// The progress of the sync of a table. The high-water mark and the identifier of the last record
+/// synced together form the cursor from which the next sync reads the records of the table.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct TableSyncState {
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    high_water_mark: Option<PrimitiveDateTime>,
+    last_record_id: Option<String>,
+    records_synced: u64,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    last_synced_at: Option<PrimitiveDateTime>,
+    last_error: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    last_error_at: Option<PrimitiveDateTime>,
+}
+
+impl TableSyncState {
+    fn to_response(&self, table: WarehouseSyncTable) -> WarehouseSyncTableStatus {
+        WarehouseSyncTableStatus {
+            table,
+            high_water_mark: self.high_water_mark,
+            records_synced: self.records_synced,
+            last_synced_at: self.last_synced_at,
+            last_error: self.last_error.clone(),
+            last_error_at: self.last_error_at,
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct WarehouseSyncState {
+    tables: HashMap<WarehouseSyncTable, TableSyncState>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    last_run_at: Option<PrimitiveDateTime>,
+}
+
+/// A version of a record, as sent to the warehouse
+#[derive(Debug)]
+struct WarehouseRecord {
+    id: String,
+    modified_at: PrimitiveDateTime,
+    data: Map<String, Value>,
+}
+
+fn get_destination_response(destination: &WarehouseDestination) -> WarehouseDestinationResponse {
+    match destination {
+        WarehouseDestination::BigQuery {
+            project_id,
+            dataset_id,
+            client_email,
+            ..
+        } => WarehouseDestinationResponse::BigQuery {
+            project_id: project_id.clone(),
+            dataset_id: dataset_id.clone(),
+            client_email: client_email.clone(),
+        },
+        WarehouseDestination::Snowflake {
+            account_identifier,
+            database,
+            schema,
+            warehouse,
+            role,
+            ..
+        } => WarehouseDestinationResponse::Snowflake {
+            account_identifier: account_identifier.clone(),
+            database: database.clone(),
+            schema: schema.clone(),
+            warehouse: warehouse.clone(),
+            role: role.clone(),
+        },
+    }
+}
+
+fn to_iso8601(date_time: PrimitiveDateTime) -> Option<String> {
+    date_time
+        .assume_utc()
+        .format(&time::format_description::well_known::Iso8601::DEFAULT)
+        .ok()
+}
+
+fn into_record(id: String, modified_at: PrimitiveDateTime, data: Value) -> WarehouseRecord {
+    WarehouseRecord {
+        id,
+        modified_at,
+        data: match data {
+            Value::Object(data) => data,
+            _ => Map::new(),
+        },
+    }
+}
+
+fn get_payment_record(
+    payment_intent: diesel_models::PaymentIntent,
+    custom_fields: Option<HashMap<String, Value>>,
+) -> WarehouseRecord {
+    let data = json!({
+        "payment_id": payment_intent.payment_id,
+        "merchant_id": payment_intent.merchant_id,
+        "profile_id": payment_intent.profile_id,
+        "status": payment_intent.status,
+        "amount": payment_intent.amount,
+        "currency": payment_intent.currency,
+        "amount_captured": payment_intent.amount_captured,
+        "customer_id": payment_intent.customer_id,
+        "description": payment_intent.description,
+        "return_url": payment_intent.return_url,
+        "metadata": payment_intent.metadata,
+        "setup_future_usage": payment_intent.setup_future_usage,
+        "off_session": payment_intent.off_session,
+        "business_country": payment_intent.business_country,
+        "business_label": payment_intent.business_label,
+        "statement_descriptor_name": payment_intent.statement_descriptor_name,
+        "statement_descriptor_suffix": payment_intent.statement_descriptor_suffix,
+        "merchant_order_reference_id": payment_intent.merchant_order_reference_id,
+        "attempt_count": payment_intent.attempt_count,
+        "custom_fields": custom_fields,
+        "created_at": to_iso8601(payment_intent.created_at),
+        "modified_at": to_iso8601(payment_intent.modified_at),
+    });
+
+    into_record(
+        payment_intent.payment_id.get_string_repr().to_owned(),
+        payment_intent.modified_at,
+        data,
+    )
+}
+
+fn get_refund_record(refund: diesel_models::Refund) -> WarehouseRecord {
+    let data = json!({
+        "refund_id": refund.refund_id,
+        "merchant_id": refund.merchant_id,
+        "profile_id": refund.profile_id,
+        "payment_id": refund.payment_id,
+        "connector": refund.connector,
+        "merchant_connector_id": refund.merchant_connector_id,
+        "refund_type": refund.refund_type,
+        "refund_status": refund.refund_status,
+        "total_amount": refund.total_amount,
+        "refund_amount": refund.refund_amount,
+        "currency": refund.currency,
+        "refund_reason": refund.refund_reason,
+        "description": refund.description,
+        "metadata": refund.metadata.as_ref().map(|metadata| metadata.peek()),
+        "error_code": refund.refund_error_code,
+        "error_message": refund.refund_error_message,
+        "created_at": to_iso8601(refund.created_at),
+        "modified_at": to_iso8601(refund.modified_at),
+    });
+
+    into_record(refund.refund_id, refund.modified_at, data)
+}
+
+fn get_dispute_record(dispute: diesel_models::Dispute) -> WarehouseRecord {
+    let data = json!({
+        "dispute_id": dispute.dispute_id,
+        "merchant_id": dispute.merchant_id,
+        "profile_id": dispute.profile_id,
+        "payment_id": dispute.payment_id,
+        "attempt_id": dispute.attempt_id,
+        "connector": dispute.connector,
+        "merchant_connector_id": dispute.merchant_connector_id,
+        "connector_dispute_id": dispute.connector_dispute_id,
+        "dispute_stage": dispute.dispute_stage,
+        "dispute_status": dispute.dispute_status,
+        "connector_status": dispute.connector_status,
+        "connector_reason": dispute.connector_reason,
+        "connector_reason_code": dispute.connector_reason_code,
+        "amount": dispute.dispute_amount,
+        "currency": dispute.dispute_currency.map(|currency| currency.to_string()).unwrap_or(dispute.currency),
+        "challenge_required_by": dispute.challenge_required_by.and_then(to_iso8601),
+        "created_at": to_iso8601(dispute.created_at),
+        "modified_at": to_iso8601(dispute.modified_at),
+    });
+
+    into_record(dispute.dispute_id, dispute.modified_at, data)
+}
+
+async fn fetch_records(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    table: WarehouseSyncTable,
+    cursor: Option<(PrimitiveDateTime, String)>,
+) -> RouterResult<Vec<WarehouseRecord>> {
+    let limit = consts::WAREHOUSE_SYNC_BATCH_SIZE;
+    let modified_before = common_utils::date_time::now()
+        - time::Duration::seconds(consts::WAREHOUSE_SYNC_SETTLE_TIME_IN_SECONDS);
+    let records = match table {
+        WarehouseSyncTable::Payments => {
+            let cursor = cursor
+                .map(|(modified_at, payment_id)| {
+                    id_type::PaymentId::wrap(payment_id).map(|payment_id| (modified_at, payment_id))
+                })
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Invalid payment id in the warehouse sync cursor")?;
+            let payment_intents = db
+                .find_payment_intents_modified_after(merchant_id, cursor, modified_before, limit)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the payments to sync")?;
+            let mut custom_fields = custom_fields::get_custom_fields_of_payments(
+                db,
+                merchant_id,
+                payment_intents
+                    .iter()
+                    .map(|payment_intent| payment_intent.payment_id.clone())
+                    .collect(),
+            )
+            .await?;
+
+            payment_intents
+                .into_iter()
+                .map(|payment_intent| {
+                    let payment_custom_fields = custom_fields.remove(&payment_intent.payment_id);
+                    get_payment_record(payment_intent, payment_custom_fields)
+                })
+                .collect()
+        }
+        WarehouseSyncTable::Refunds => db
+            .find_refunds_modified_after(merchant_id, cursor, modified_before, limit)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the refunds to sync")?
+            .into_iter()
+            .map(get_refund_record)
+            .collect(),
+        WarehouseSyncTable::Disputes => db
+            .find_disputes_modified_after(merchant_id, cursor, modified_before, limit)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the disputes to sync")?
+            .into_iter()
+            .map(get_dispute_record)
+            .collect(),
+    };
+
+    Ok(records)
+}
+
+fn hash_value(hash_key: &Secret<String>, value: &Value) -> RouterResult<String> {
+    let message = match value {
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    };
+
+    crypto::HmacSha256
+        .sign_message(hash_key.peek().as_bytes(), message.as_bytes())
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to hash a redacted field")
+}
+
+fn mask_value(value: &Value) -> String {
+    let value = match value {
+        Value::String(value) => value.clone(),
+        value => value.to_string(),
+    };
+    let length = value.chars().count();
+    let masked_length = if length <= 4 { length } else { length - 4 };
+
+    value
+        .chars()
+        .enumerate()
+        .map(|(index, character)| {
+            if index < masked_length {
+                '*'
+            } else {
+                character
+            }
+        })
+        .collect()
+}
+
+fn redact_record(
+    table: WarehouseSyncTable,
+    record: &mut WarehouseRecord,
+    redaction_rules: &[WarehouseRedactionRule],
+    hash_key: &Secret<String>,
+) -> RouterResult<()> {
+    for rule in redaction_rules
+        .iter()
+        .filter(|rule| rule.table.map_or(true, |rule_table| rule_table == table))
+    {
+        match rule.action {
+            WarehouseRedactionAction::Drop => {
+                record.data.remove(&rule.field);
+            }
+            WarehouseRedactionAction::Hash => {
+                if let Some(value) = record
+                    .data
+                    .get_mut(&rule.field)
+                    .filter(|value| !value.is_null())
+                {
+                    *value = Value::String(hash_value(hash_key, value)?);
+                }
+            }
+            WarehouseRedactionAction::Mask => {
+                if let Some(value) = record
+                    .data
+                    .get_mut(&rule.field)
+                    .filter(|value| !value.is_null())
+                {
+                    *value = Value::String(mask_value(value));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn is_valid_identifier(identifier: &str, allowed_characters: &[char]) -> bool {
+    !identifier.is_empty()
+        && identifier.chars().all(|character| {
+            character.is_ascii_alphanumeric() || allowed_characters.contains(&character)
+        })
+}
+
+fn validate_destination(destination: &WarehouseDestination) -> Result<(), String> {
+    match destination {
+        WarehouseDestination::BigQuery {
+            project_id,
+            dataset_id,
+            client_email,
+            private_key,
+            ..
+        } => {
+            if !is_valid_identifier(project_id, &['-', '.', ':']) {
+                return Err("project_id is not a valid BigQuery project".to_string());
+            }
+            if !is_valid_identifier(dataset_id, &['_']) {
+                return Err("dataset_id is not a valid BigQuery dataset".to_string());
+            }
+            if !client_email.contains('@') {
+                return Err(
+                    "client_email is not the email address of a service account".to_string()
+                );
+            }
+            EncodingKey::from_rsa_pem(private_key.peek().as_bytes())
+                .map_err(|_| "private_key is not a PEM encoded RSA private key".to_string())?;
+        }
+        WarehouseDestination::Snowflake {
+            account_identifier,
+            database,
+            schema,
+            warehouse,
+            role,
+            access_token,
+        } => {
+            if !is_valid_identifier(account_identifier, &['-', '_', '.']) {
+                return Err("account_identifier is not a valid Snowflake account".to_string());
+            }
+            for (name, identifier) in [
+                ("database", Some(database)),
+                ("schema", Some(schema)),
+                ("warehouse", warehouse.as_ref()),
+                ("role", role.as_ref()),
+            ] {
+                if identifier
+                    .is_some_and(|identifier| !is_valid_identifier(identifier, &['_', '$']))
+                {
+                    return Err(format!("{name} is not a valid Snowflake identifier"));
+                }
+            }
+            if access_token.peek().is_empty() {
+                return Err("access_token must not be empty".to_string());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_redaction_rules(
+    tables: &[WarehouseSyncTable],
+    redaction_rules: &[WarehouseRedactionRule],
+) -> Result<(), String> {
+    for rule in redaction_rules {
+        if PROTECTED_FIELDS.contains(&rule.field.as_str()) {
+            return Err(format!(
+                "{} identifies the synced records, and cannot be redacted",
+                rule.field
+            ));
+        }
+
+        let has_field = match rule.table {
+            Some(table) => get_table_fields(table).contains(&rule.field.as_str()),
+            None => tables
+                .iter()
+                .any(|table| get_table_fields(*table).contains(&rule.field.as_str())),
+        };
+        if !has_field {
+            return Err(match rule.table {
+                Some(table) => format!("The {table} table has no field {}", rule.field),
+                None => format!("None of the synced tables has a field {}", rule.field),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_warehouse_sync_config_request(
+    request: &WarehouseSyncConfigRequest,
+    tables: &[WarehouseSyncTable],
+    redaction_rules: &[WarehouseRedactionRule],
+    sync_interval_minutes: u32,
+) -> Result<(), String> {
+    validate_destination(&request.destination)?;
+
+    if tables.is_empty() {
+        return Err("At least one table must be synced".to_string());
+    }
+
+    if let Some(table_prefix) = &request.table_prefix {
+        if table_prefix.len() > MAX_TABLE_PREFIX_LENGTH
+            || !is_valid_identifier(table_prefix, &['_'])
+        {
+            return Err(format!(
+                "table_prefix must be at most {MAX_TABLE_PREFIX_LENGTH} letters, digits or underscores"
+            ));
+        }
+    }
+
+    if !(consts::MIN_WAREHOUSE_SYNC_INTERVAL_IN_MINUTES
+        ..=consts::MAX_WAREHOUSE_SYNC_INTERVAL_IN_MINUTES)
+        .contains(&sync_interval_minutes)
+    {
+        return Err(format!(
+            "sync_interval_minutes must be between {} and {}",
+            consts::MIN_WAREHOUSE_SYNC_INTERVAL_IN_MINUTES,
+            consts::MAX_WAREHOUSE_SYNC_INTERVAL_IN_MINUTES
+        ));
+    }
+
+    validate_redaction_rules(tables, redaction_rules)
+}
+
+fn get_error_message(response: &services::Response) -> String {
+    let message = serde_json::from_slice::<Value>(&response.response)
+        .ok()
+        .and_then(|body| {
+            body.pointer("/error/message")
+                .or_else(|| body.pointer("/message"))
+                .and_then(Value::as_str)
+                .map(str::to_owned)
+        });
+
+    match message {
+        Some(message) => format!(
+            "The warehouse responded with status code {}: {message}",
+            response.status_code
+        ),
+        None => format!(
+            "The warehouse responded with status code {}",
+            response.status_code
+        ),
+    }
+}
+
+async fn send_warehouse_request(
+    state: &SessionState,
+    request: services::Request,
+) -> Result<services::Response, String> {
+    tokio::time::timeout(
+        std::time::Duration::from_secs(WAREHOUSE_REQUEST_TIMEOUT_IN_SECONDS),
+        services::call_connector_api(state, request, "sync_records_to_warehouse"),
+    )
+    .await
+    .map_err(|_| "Timed out waiting for the warehouse".to_string())?
+    .map_err(|error| {
+        logger::error!(?error, "Failed to call the warehouse");
+        "Failed to connect to the warehouse".to_string()
+    })?
+    .map_err(|response| get_error_message(&response))
+}
+
+#[derive(Debug, Serialize)]
+struct BigQueryJwtClaims<'a> {
+    iss: &'a str,
+    sub: &'a str,
+    aud: &'a str,
+    iat: i64,
+    exp: i64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BigQueryRow<'a> {
+    insert_id: String,
+    json: &'a Map<String, Value>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BigQueryInsertAllRequest<'a> {
+    rows: Vec<BigQueryRow<'a>>,
+    skip_invalid_rows: bool,
+    ignore_unknown_values: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BigQueryInsertAllResponse {
+    #[serde(default)]
+    insert_errors: Vec<Value>,
+}
+
+/// Signs a JWT with the key of the service account, which BigQuery accepts as an access token
+fn get_big_query_access_token(
+    client_email: &str,
+    private_key_id: Option<&String>,
+    private_key: &Secret<String>,
+) -> Result<String, String> {
+    let issued_at = time::OffsetDateTime::now_utc().unix_timestamp();
+    let claims = BigQueryJwtClaims {
+        iss: client_email,
+        sub: client_email,
+        aud: BIG_QUERY_AUDIENCE,
+        iat: issued_at,
+        exp: issued_at + BIG_QUERY_TOKEN_VALIDITY_IN_SECONDS,
+    };
+    let mut header = Header::new(Algorithm::RS256);
+    header.kid = private_key_id.cloned();
+
+    let key = EncodingKey::from_rsa_pem(private_key.peek().as_bytes())
+        .map_err(|_| "The private key of the service account is invalid".to_string())?;
+    jsonwebtoken::encode(&header, &claims, &key).map_err(|error| {
+        logger::error!(?error, "Failed to sign the BigQuery access token");
+        "Failed to sign the access token of the service account".to_string()
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn write_records_to_big_query(
+    state: &SessionState,
+    project_id: &str,
+    dataset_id: &str,
+    client_email: &str,
+    private_key_id: Option<&String>,
+    private_key: &Secret<String>,
+    table_name: &str,
+    records: &[WarehouseRecord],
+) -> Result<(), String> {
+    let access_token = get_big_query_access_token(client_email, private_key_id, private_key)?;
+    let url = format!(
+        "{BIG_QUERY_BASE_URL}/projects/{project_id}/datasets/{dataset_id}/tables/{table_name}/insertAll"
+    );
+    let body = BigQueryInsertAllRequest {
+        rows: records
+            .iter()
+            .map(|record| BigQueryRow {
+                // BigQuery drops the rows whose insert id it has already seen recently, so that a
+                // batch sent again after a failure is not inserted twice
+                insert_id: format!(
+                    "{}_{}",
+                    record.id,
+                    record.modified_at.assume_utc().unix_timestamp_nanos()
+                ),
+                json: &record.data,
+            })
+            .collect(),
+        skip_invalid_rows: false,
+        ignore_unknown_values: true,
+    };
+    let body = serde_json::to_value(body)
+        .map_err(|_| "Failed to serialize the records for BigQuery".to_string())?;
+
+    let mut request = services::Request::new(services::Method::Post, &url);
+    request.add_header(headers::CONTENT_TYPE, "application/json".into());
+    request.add_header(
+        headers::AUTHORIZATION,
+        format!("Bearer {access_token}").into_masked(),
+    );
+    request.set_body(RequestContent::Json(Box::new(body)));
+
+    let response: BigQueryInsertAllResponse = send_warehouse_request(state, request)
+        .await?
+        .response
+        .parse_struct("BigQueryInsertAllResponse")
+        .map_err(|_| "Failed to parse the response of BigQuery".to_string())?;
+
+    match response.insert_errors.len() {
+        0 => Ok(()),
+        error_count => Err(format!(
+            "BigQuery rejected {error_count} of the {} records",
+            records.len()
+        )),
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct SnowflakeBinding {
+    #[serde(rename = "type")]
+    binding_type: &'static str,
+    value: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct SnowflakeStatementRequest<'a> {
+    statement: String,
+    timeout: u64,
+    database: &'a str,
+    schema: &'a str,
+    warehouse: Option<&'a String>,
+    role: Option<&'a String>,
+    bindings: HashMap<&'static str, SnowflakeBinding>,
+}
+
+/// Inserts the records with a single statement, binding the columns of all the records as arrays.
+/// The records are inserted in the `RECORD_ID`, `MODIFIED_AT` and `DATA` columns of the table,
+/// with the record serialized as JSON in the `DATA` column.
+#[allow(clippy::too_many_arguments)]
+async fn write_records_to_snowflake(
+    state: &SessionState,
+    account_identifier: &str,
+    database: &str,
+    schema: &str,
+    warehouse: Option<&String>,
+    role: Option<&String>,
+    access_token: &Secret<String>,
+    table_name: &str,
+    records: &[WarehouseRecord],
+) -> Result<(), String> {
+    let url = format!("https://{account_identifier}.snowflakecomputing.com/api/v2/statements");
+    let data = records
+        .iter()
+        .map(|record| {
+            record
+                .data
+                .encode_to_string_of_json()
+                .map_err(|_| "Failed to serialize the records for Snowflake".to_string())
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let body = SnowflakeStatementRequest {
+        statement: format!(
+            "INSERT INTO {table_name} (RECORD_ID, MODIFIED_AT, DATA) VALUES (?, ?, ?)"
+        ),
+        timeout: WAREHOUSE_REQUEST_TIMEOUT_IN_SECONDS,
+        database,
+        schema,
+        warehouse,
+        role,
+        bindings: HashMap::from([
+            (
+                "1",
+                SnowflakeBinding {
+                    binding_type: "TEXT",
+                    value: records.iter().map(|record| record.id.clone()).collect(),
+                },
+            ),
+            (
+                "2",
+                SnowflakeBinding {
+                    binding_type: "TEXT",
+                    value: records
+                        .iter()
+                        .map(|record| to_iso8601(record.modified_at).unwrap_or_default())
+                        .collect(),
+                },
+            ),
+            (
+                "3",
+                SnowflakeBinding {
+                    binding_type: "TEXT",
+                    value: data,
+                },
+            ),
+        ]),
+    };
+    let body = serde_json::to_value(body)
+        .map_err(|_| "Failed to serialize the records for Snowflake".to_string())?;
+
+    let mut request = services::Request::new(services::Method::Post, &url);
+    request.add_header(headers::CONTENT_TYPE, "application/json".into());
+    request.add_header(headers::ACCEPT, "application/json".into());
+    request.add_header(
+        headers::AUTHORIZATION,
+        format!("Bearer {}", access_token.peek()).into_masked(),
+    );
+    request.add_header(
+        SNOWFLAKE_TOKEN_TYPE_HEADER,
+        SNOWFLAKE_PROGRAMMATIC_ACCESS_TOKEN.into(),
+    );
+    request.set_body(RequestContent::Json(Box::new(body)));
+
+    // The statement is accepted with a 202 when it is still running once the request times out on
+    // the side of Snowflake, in which case it completes asynchronously
+    send_warehouse_request(state, request).await.map(|_| ())
+}
+
+async fn write_records(
+    state: &SessionState,
+    destination: &WarehouseDestination,
+    table_name: &str,
+    records: &[WarehouseRecord],
+) -> Result<(), String> {
+    match destination {
+        WarehouseDestination::BigQuery {
+            project_id,
+            dataset_id,
+            client_email,
+            private_key_id,
+            private_key,
+        } => {
+            write_records_to_big_query(
+                state,
+                project_id,
+                dataset_id,
+                client_email,
+                private_key_id.as_ref(),
+                private_key,
+                table_name,
+                records,
+            )
+            .await
+        }
+        WarehouseDestination::Snowflake {
+            account_identifier,
+            database,
+            schema,
+            warehouse,
+            role,
+            access_token,
+        } => {
+            write_records_to_snowflake(
+                state,
+                account_identifier,
+                database,
+                schema,
+                warehouse.as_ref(),
+                role.as_ref(),
+                access_token,
+                table_name,
+                records,
+            )
+            .await
+        }
+    }
+}
+
+async fn encrypt_secrets(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    secrets: &WarehouseSyncSecrets,
+) -> RouterResult<Encryption> {
+    let secrets = secrets
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the credentials of the warehouse")?;
+
+    domain::types::crypto_operation(
+        &state.into(),
+        type_name!(storage::MerchantAccount),
+        domain::types::CryptoOperation::Encrypt(Secret::<String>::new(secrets)),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt the credentials of the warehouse")
+    .map(Encryption::from)
+}
+
+async fn decrypt_secrets(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    secrets: Encryption,
+) -> RouterResult<WarehouseSyncSecrets> {
+    domain::types::crypto_operation::<String, masking::WithType>(
+        &state.into(),
+        type_name!(storage::MerchantAccount),
+        domain::types::CryptoOperation::Decrypt(secrets),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to decrypt the credentials of the warehouse")?
+    .into_inner()
+    .peek()
+    .parse_struct("WarehouseSyncSecrets")
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to deserialize the credentials of the warehouse")
+}
+
+fn get_warehouse_sync_not_found_error() -> errors::ApiErrorResponse {
+    errors::ApiErrorResponse::GenericNotFoundError {
+        message: "Warehouse sync is not configured".to_string(),
+    }
+}
+
+async fn find_warehouse_sync_config(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Option<StoredWarehouseSyncConfig>> {
+    match db
+        .find_config_by_key(&merchant_id.get_warehouse_sync_config_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("StoredWarehouseSyncConfig")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse warehouse sync config")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch warehouse sync config")),
+    }
+}
+
+async fn store_warehouse_sync_config(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    config: &StoredWarehouseSyncConfig,
+    is_existing: bool,
+) -> RouterResult<()> {
+    let key = merchant_id.get_warehouse_sync_config_key();
+    let serialized_config = config
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize warehouse sync config")?;
+
+    if is_existing {
+        db.update_config_by_key(
+            &key,
+            configs::ConfigUpdate::Update {
+                config: Some(serialized_config),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update warehouse sync config")?;
+    } else {
+        db.insert_config(configs::ConfigNew {
+            key,
+            config: serialized_config,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert warehouse sync config")?;
+    }
+
+    Ok(())
+}
+
+/// The progress of the sync, along with whether it has been stored already
+async fn find_warehouse_sync_state(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<(WarehouseSyncState, bool)> {
+    match db
+        .find_config_by_key(&merchant_id.get_warehouse_sync_state_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("WarehouseSyncState")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse warehouse sync state")
+            .map(|sync_state| (sync_state, true)),
+        Err(error) if error.current_context().is_db_not_found() => {
+            Ok((WarehouseSyncState::default(), false))
+        }
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch warehouse sync state")),
+    }
+}
+
+async fn store_warehouse_sync_state(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    sync_state: &WarehouseSyncState,
+    is_existing: bool,
+) -> RouterResult<()> {
+    let key = merchant_id.get_warehouse_sync_state_key();
+    let serialized_state = sync_state
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize warehouse sync state")?;
+
+    if is_existing {
+        db.update_config_by_key(
+            &key,
+            configs::ConfigUpdate::Update {
+                config: Some(serialized_state),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update warehouse sync state")?;
+    } else {
+        db.insert_config(configs::ConfigNew {
+            key,
+            config: serialized_state,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert warehouse sync state")?;
+    }
+
+    Ok(())
+}
+
+fn get_process_tracker_id(merchant_id: &id_type::MerchantId) -> String {
+    let runner = storage::ProcessTrackerRunner::WarehouseSyncWorkflow;
+    format!(
+        "{runner}_{WAREHOUSE_SYNC_TASK}_{}",
+        merchant_id.get_string_repr()
+    )
+}
+
+/// Schedules the next sync of the records of the merchant, replacing the sync already scheduled
+async fn schedule_warehouse_sync_task(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    schedule_time: PrimitiveDateTime,
+) -> RouterResult<()> {
+    let process_tracker_id = get_process_tracker_id(merchant_id);
+
+    let existing_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching WAREHOUSE_SYNC task")?;
+
+    match existing_process {
+        Some(process) => db
+            .as_scheduler()
+            .reset_process(process, schedule_time)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed while resetting WAREHOUSE_SYNC task")?,
+        None => {
+            let tracking_data = storage::WarehouseSyncTrackingData {
+                merchant_id: merchant_id.clone(),
+            };
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                WAREHOUSE_SYNC_TASK,
+                storage::ProcessTrackerRunner::WarehouseSyncWorkflow,
+                [WAREHOUSE_SYNC_TAG],
+                tracking_data,
+                None,
+                schedule_time,
+                hyperswitch_domain_models::consts::API_VERSION,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct WAREHOUSE_SYNC process tracker task")?;
+
+            db.insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed while inserting WAREHOUSE_SYNC task to process_tracker",
+                )?;
+        }
+    };
+
+    Ok(())
+}
+
+/// Syncs the records of the table modified since its high-water mark, a batch at a time. The sync
+/// of the table stops at the first batch which the warehouse fails to accept, which is sent again
+/// in the next sync. Returns whether records are left to be synced.
+async fn sync_table(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    config: &StoredWarehouseSyncConfig,
+    secrets: &WarehouseSyncSecrets,
+    table: WarehouseSyncTable,
+    table_state: &mut TableSyncState,
+) -> RouterResult<bool> {
+    let table_name = config.get_table_name(table);
+
+    for _ in 0..consts::WAREHOUSE_SYNC_MAX_BATCHES_PER_RUN {
+        let cursor = table_state
+            .high_water_mark
+            .zip(table_state.last_record_id.clone());
+        let mut records = fetch_records(state.store.as_ref(), merchant_id, table, cursor).await?;
+        let Some(last_record) = records.last() else {
+            return Ok(false);
+        };
+        let cursor = (last_record.modified_at, last_record.id.clone());
+        let is_last_batch = records.len()
+            < usize::try_from(consts::WAREHOUSE_SYNC_BATCH_SIZE).unwrap_or(usize::MAX);
+
+        for record in records.iter_mut() {
+            redact_record(table, record, &config.redaction_rules, &secrets.hash_key)?;
+        }
+
+        let now = common_utils::date_time::now();
+        if let Err(message) =
+            write_records(state, &secrets.destination, &table_name, &records).await
+        {
+            logger::error!(%table, error = %message, "Failed to sync records to the warehouse");
+            table_state.last_error = Some(message);
+            table_state.last_error_at = Some(now);
+            return Ok(false);
+        }
+
+        let (high_water_mark, last_record_id) = cursor;
+        table_state.high_water_mark = Some(high_water_mark);
+        table_state.last_record_id = Some(last_record_id);
+        table_state.records_synced = table_state
+            .records_synced
+            .saturating_add(u64::try_from(records.len()).unwrap_or(u64::MAX));
+        table_state.last_synced_at = Some(now);
+        table_state.last_error = None;
+        table_state.last_error_at = None;
+
+        if is_last_batch {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+/// Syncs the records of the merchant modified since the previous sync. Returns the time of the
+/// next sync, which is immediate while records are left to be synced, such as during the initial
+/// sync of the tables, or `None` if the sync has been disabled or deleted.
+#[instrument(skip_all)]
+pub async fn run_warehouse_sync(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<Option<PrimitiveDateTime>> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+
+    let Some(config) = find_warehouse_sync_config(db, merchant_id)
+        .await?
+        .filter(|config| config.enabled)
+    else {
+        return Ok(None);
+    };
+    let secrets = decrypt_secrets(state, key_store, config.secrets.clone()).await?;
+    let (mut sync_state, is_existing) = find_warehouse_sync_state(db, merchant_id).await?;
+
+    let mut has_remaining_records = false;
+    for table in &config.tables {
+        let mut table_state = sync_state.tables.remove(table).unwrap_or_default();
+        has_remaining_records |= sync_table(
+            state,
+            merchant_id,
+            &config,
+            &secrets,
+            *table,
+            &mut table_state,
+        )
+        .await?;
+        sync_state.tables.insert(*table, table_state);
+    }
+
+    let now = common_utils::date_time::now();
+    sync_state.last_run_at = Some(now);
+    store_warehouse_sync_state(db, merchant_id, &sync_state, is_existing).await?;
+
+    Ok(Some(if has_remaining_records {
+        now
+    } else {
+        now.saturating_add(Duration::minutes(config.sync_interval_minutes.into()))
+    }))
+}
+
+#[instrument(skip_all)]
+pub async fn set_warehouse_sync_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: WarehouseSyncConfigRequest,
+) -> RouterResponse<WarehouseSyncConfigResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+
+    let tables = match &request.tables {
+        Some(requested_tables) => get_default_tables()
+            .into_iter()
+            .filter(|table| requested_tables.contains(table))
+            .collect(),
+        None => get_default_tables(),
+    };
+    let redaction_rules = request
+        .redaction_rules
+        .clone()
+        .unwrap_or_else(get_default_redaction_rules);
+    let sync_interval_minutes = request
+        .sync_interval_minutes
+        .unwrap_or(consts::DEFAULT_WAREHOUSE_SYNC_INTERVAL_IN_MINUTES);
+    validate_warehouse_sync_config_request(
+        &request,
+        &tables,
+        &redaction_rules,
+        sync_interval_minutes,
+    )
+    .map_err(|message| errors::ApiErrorResponse::InvalidRequestData { message })?;
+
+    let existing_config = find_warehouse_sync_config(db, merchant_id).await?;
+    let hash_key = match &existing_config {
+        Some(config) => {
+            decrypt_secrets(&state, &key_store, config.secrets.clone())
+                .await?
+                .hash_key
+        }
+        None => Secret::new(crypto::generate_cryptographically_secure_random_string(
+            HASH_KEY_LENGTH,
+        )),
+    };
+    let secrets = WarehouseSyncSecrets {
+        destination: request.destination,
+        hash_key,
+    };
+
+    let now = common_utils::date_time::now();
+    let config = StoredWarehouseSyncConfig {
+        secrets: encrypt_secrets(&state, &key_store, &secrets).await?,
+        tables,
+        table_prefix: request.table_prefix,
+        redaction_rules,
+        sync_interval_minutes,
+        enabled: request.enabled.unwrap_or(true),
+        created_at: existing_config
+            .as_ref()
+            .map_or(now, |config| config.created_at),
+        modified_at: now,
+    };
+    store_warehouse_sync_config(db, merchant_id, &config, existing_config.is_some()).await?;
+
+    if config.enabled {
+        schedule_warehouse_sync_task(db, merchant_id, now).await?;
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        config.to_response(&secrets.destination),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_warehouse_sync_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+) -> RouterResponse<WarehouseSyncConfigResponse> {
+    let config = find_warehouse_sync_config(state.store.as_ref(), merchant_account.get_id())
+        .await?
+        .ok_or_else(|| report!(get_warehouse_sync_not_found_error()))?;
+    let secrets = decrypt_secrets(&state, &key_store, config.secrets.clone()).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        config.to_response(&secrets.destination),
+    ))
+}
+
+/// Deletes the configuration and the progress of the sync. The scheduled sync completes without
+/// syncing any records when it next runs.
+#[instrument(skip_all)]
+pub async fn delete_warehouse_sync_config(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<()> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+
+    db.delete_config_by_key(&merchant_id.get_warehouse_sync_config_key())
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(get_warehouse_sync_not_found_error())
+            } else {
+                error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to delete warehouse sync config")
+            }
+        })?;
+
+    if let Err(error) = db
+        .delete_config_by_key(&merchant_id.get_warehouse_sync_state_key())
+        .await
+    {
+        if !error.current_context().is_db_not_found() {
+            return Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to delete warehouse sync state"));
+        }
+    }
+
+    Ok(services::ApplicationResponse::StatusOk)
+}
+
+async fn get_warehouse_sync_status_response(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    config: &StoredWarehouseSyncConfig,
+    sync_state: &WarehouseSyncState,
+) -> RouterResult<WarehouseSyncStatusResponse> {
+    let next_run_at = db
+        .find_process_by_id(&get_process_tracker_id(merchant_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while fetching WAREHOUSE_SYNC task")?
+        .filter(|process| process.status != storage_enums::ProcessTrackerStatus::Finish)
+        .and_then(|process| process.schedule_time);
+
+    Ok(WarehouseSyncStatusResponse {
+        enabled: config.enabled,
+        last_run_at: sync_state.last_run_at,
+        next_run_at: next_run_at.filter(|_| config.enabled),
+        tables: config
+            .tables
+            .iter()
+            .map(|table| {
+                sync_state
+                    .tables
+                    .get(table)
+                    .cloned()
+                    .unwrap_or_default()
+                    .to_response(*table)
+            })
+            .collect(),
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_warehouse_sync_status(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<WarehouseSyncStatusResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+
+    let config = find_warehouse_sync_config(db, merchant_id)
+        .await?
+        .ok_or_else(|| report!(get_warehouse_sync_not_found_error()))?;
+    let (sync_state, _) = find_warehouse_sync_state(db, merchant_id).await?;
+
+    get_warehouse_sync_status_response(db, merchant_id, &config, &sync_state)
+        .await
+        .map(services::ApplicationResponse::Json)
+}
+
+/// Schedules the sync of the records of the merchant immediately, after optionally discarding the
+/// high-water marks of the tables which are to be synced again from their first record
+#[instrument(skip_all)]
+pub async fn trigger_warehouse_sync(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    request: WarehouseSyncTriggerRequest,
+) -> RouterResponse<WarehouseSyncStatusResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+
+    let config = find_warehouse_sync_config(db, merchant_id)
+        .await?
+        .ok_or_else(|| report!(get_warehouse_sync_not_found_error()))?;
+    if !config.enabled {
+        return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Warehouse sync is disabled".to_string(),
+        }));
+    }
+
+    let (mut sync_state, is_existing) = find_warehouse_sync_state(db, merchant_id).await?;
+    let resync_tables = request.resync_tables.unwrap_or_default();
+    if !resync_tables.is_empty() {
+        for table in &resync_tables {
+            sync_state.tables.remove(table);
+        }
+        store_warehouse_sync_state(db, merchant_id, &sync_state, is_existing).await?;
+    }
+
+    schedule_warehouse_sync_task(db, merchant_id, common_utils::date_time::now()).await?;
+
+    get_warehouse_sync_status_response(db, merchant_id, &config, &sync_state)
+        .await
+        .map(services::ApplicationResponse::Json)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_record(data: Value) -> WarehouseRecord {
+        into_record("pay_1".to_string(), common_utils::date_time::now(), data)
+    }
+
+    #[test]
+    fn test_redact_record() {
+        let hash_key = Secret::new("key".to_string());
+        let mut record = get_record(json!({
+            "payment_id": "pay_1",
+            "customer_id": "cus_1",
+            "description": "Order of a lamp",
+            "metadata": null,
+            "business_label": "storefront",
+        }));
+
+        redact_record(
+            WarehouseSyncTable::Payments,
+            &mut record,
+            &get_default_redaction_rules(),
+            &hash_key,
+        )
+        .unwrap();
+
+        assert_eq!(
+            record.data.get("customer_id"),
+            Some(&Value::String(
+                hash_value(&hash_key, &Value::String("cus_1".to_string())).unwrap()
+            ))
+        );
+        assert!(!record.data.contains_key("description"));
+        assert!(!record.data.contains_key("metadata"));
+        assert_eq!(
+            record.data.get("business_label"),
+            Some(&json!("storefront"))
+        );
+        assert_eq!(record.data.get("payment_id"), Some(&json!("pay_1")));
+    }
+
+    #[test]
+    fn test_mask_value() {
+        assert_eq!(mask_value(&json!("4242424242424242")), "************4242");
+        assert_eq!(mask_value(&json!("abc")), "***");
+        assert_eq!(mask_value(&json!(12345)), "*2345");
+    }
+
+    #[test]
+    fn test_validate_redaction_rules() {
+        let tables = get_default_tables();
+        assert!(validate_redaction_rules(&tables, &get_default_redaction_rules()).is_ok());
+
+        let protected_field_rule = WarehouseRedactionRule {
+            table: None,
+            field: "payment_id".to_string(),
+            action: WarehouseRedactionAction::Drop,
+        };
+        assert!(validate_redaction_rules(&tables, &[protected_field_rule]).is_err());
+
+        let unknown_field_rule = WarehouseRedactionRule {
+            table: Some(WarehouseSyncTable::Disputes),
+            field: "customer_id".to_string(),
+            action: WarehouseRedactionAction::Hash,
+        };
+        assert!(validate_redaction_rules(&tables, &[unknown_field_rule]).is_err());
+    }
+}
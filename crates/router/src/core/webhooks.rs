@@ -1,11 +1,20 @@
 #[cfg(feature = "v1")]
+pub mod endpoint_health;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod event_catalog;
+#[cfg(feature = "v1")]
 mod incoming;
 #[cfg(feature = "v2")]
 mod incoming_v2;
 #[cfg(feature = "v1")]
+mod ordering;
+#[cfg(feature = "v1")]
 mod outgoing;
+pub mod payload_templates;
 #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
 mod recovery_incoming;
+#[cfg(feature = "v1")]
+mod source_ip_verification;
 pub mod types;
 pub mod utils;
 #[cfg(feature = "olap")]
@@ -15,7 +24,7 @@ pub mod webhook_events;
 pub(crate) use self::incoming_v2::incoming_webhooks_wrapper;
 #[cfg(feature = "v1")]
 pub(crate) use self::{
-    incoming::incoming_webhooks_wrapper,
+    incoming::{apply_buffered_refund_webhooks_on_capture, incoming_webhooks_wrapper},
     outgoing::{
         create_event_and_trigger_outgoing_webhook, get_outgoing_webhook_request,
         trigger_webhook_and_raise_event,
@@ -0,0 +1,661 @@
+//! Health of the endpoints receiving the outgoing webhooks. The endpoint of a profile is the
+//! webhook URL configured in the profile, and is identified by the ID of the profile. Every attempt
+//! to deliver a webhook to an endpoint is counted in redis per hour, along with the time taken by
+//! the endpoint to respond, and the consecutive failed attempts since the last delivered webhook
+//! are tracked. Once an endpoint has failed a number of consecutive attempts over a long enough time,
+//! the delivery to it is paused and the merchant is notified: the new webhooks and the pending
+//! retries are held instead of endlessly retrying into a dead endpoint, and are delivered once the
+//! merchant resumes the delivery. The pause is stored in the configs, and lasts until the merchant
+//! resumes the delivery. The manual retries of the merchant are still sent to a paused endpoint.
+
+use std::collections::HashMap;
+
+use api_models::webhook_endpoints::{
+    WebhookEndpointHealthRequest, WebhookEndpointHealthResponse, WebhookEndpointHourlyHealth,
+    WebhookEndpointStatus,
+};
+use common_utils::{
+    date_time,
+    ext_traits::{StringExt, ValueExt},
+    id_type,
+};
+use diesel_models::{configs, process_tracker::business_status};
+use error_stack::{report, ResultExt};
+use masking::ExposeInterface;
+use router_env::{instrument, tracing};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+#[cfg(feature = "email")]
+use crate::services::email::types as email_types;
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        utils as core_utils,
+        webhooks::types::OutgoingWebhookTrackingData,
+    },
+    logger,
+    routes::SessionState,
+    services,
+    types::{domain, storage},
+};
+
+const SECONDS_IN_AN_HOUR: i64 = 60 * 60;
+
+const DELIVERIES_FIELD: &str = "deliveries";
+const FAILURES_FIELD: &str = "failures";
+/// The number of attempts which got a response, over which the latency of the endpoint is averaged
+const RESPONSES_FIELD: &str = "responses";
+const LATENCY_FIELD: &str = "latency_ms";
+
+const CONSECUTIVE_FAILURES_FIELD: &str = "consecutive_failures";
+/// The time of the first of the consecutive failures, only meaningful while there are any
+const FAILING_SINCE_FIELD: &str = "failing_since";
+const LAST_SUCCESS_AT_FIELD: &str = "last_success_at";
+const LAST_FAILURE_AT_FIELD: &str = "last_failure_at";
+
+/// The pause of the delivery of the webhooks to an endpoint, as stored
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct WebhookEndpointPause {
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    paused_at: PrimitiveDateTime,
+    consecutive_failures: u64,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    failing_since: PrimitiveDateTime,
+}
+
+/// The consecutive failures and the last deliveries of the webhooks to an endpoint
+#[derive(Debug, Default)]
+struct WebhookEndpointState {
+    consecutive_failures: u64,
+    failing_since: Option<PrimitiveDateTime>,
+    last_success_at: Option<PrimitiveDateTime>,
+    last_failure_at: Option<PrimitiveDateTime>,
+}
+
+impl WebhookEndpointState {
+    fn from_fields(fields: &HashMap<String, i64>) -> Self {
+        let consecutive_failures = fields
+            .get(CONSECUTIVE_FAILURES_FIELD)
+            .and_then(|value| u64::try_from(*value).ok())
+            .unwrap_or_default();
+        let get_time = |field| fields.get(field).copied().and_then(from_unix_timestamp);
+
+        Self {
+            consecutive_failures,
+            failing_since: get_time(FAILING_SINCE_FIELD).filter(|_| consecutive_failures > 0),
+            last_success_at: get_time(LAST_SUCCESS_AT_FIELD),
+            last_failure_at: get_time(LAST_FAILURE_AT_FIELD),
+        }
+    }
+}
+
+fn from_unix_timestamp(timestamp: i64) -> Option<PrimitiveDateTime> {
+    time::OffsetDateTime::from_unix_timestamp(timestamp)
+        .ok()
+        .map(date_time::convert_to_pdt)
+}
+
+fn get_hourly_health_redis_key(profile_id: &id_type::ProfileId, hour_start: i64) -> String {
+    format!(
+        "webhook_endpoint_health_{}_{hour_start}",
+        profile_id.get_string_repr()
+    )
+}
+
+fn get_state_redis_key(profile_id: &id_type::ProfileId) -> String {
+    format!("webhook_endpoint_state_{}", profile_id.get_string_repr())
+}
+
+async fn get_endpoint_pause(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<Option<WebhookEndpointPause>> {
+    // The absence of a pause is cached as well, as it is looked up for every delivered webhook
+    state
+        .store
+        .find_config_by_key_unwrap_or(
+            &profile_id.get_webhook_endpoint_pause_key(),
+            Some("null".to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch webhook endpoint pause")?
+        .config
+        .parse_struct("Option<WebhookEndpointPause>")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse webhook endpoint pause")
+}
+
+/// Whether the delivery of the webhooks to the endpoint of the profile is paused. The webhooks are
+/// delivered if the pause cannot be looked up.
+pub(crate) async fn is_endpoint_paused(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+) -> bool {
+    get_endpoint_pause(state, profile_id)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to check if webhook endpoint is paused"))
+        .ok()
+        .flatten()
+        .is_some()
+}
+
+/// Holds the delivery of a webhook to a paused endpoint, by keeping its task pending until the
+/// delivery to the endpoint is resumed. The task is checked again after a while, in case it is
+/// missed when the delivery is resumed.
+pub(crate) async fn hold_delivery_while_paused(
+    state: &SessionState,
+    process_tracker: storage::ProcessTracker,
+) -> RouterResult<()> {
+    let now = date_time::now();
+    state
+        .store
+        .as_scheduler()
+        .update_process(
+            process_tracker,
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: None,
+                schedule_time: Some(
+                    now + time::Duration::seconds(
+                        consts::WEBHOOK_ENDPOINT_PAUSED_TASK_RECHECK_IN_SECS,
+                    ),
+                ),
+                tracking_data: None,
+                business_status: Some(String::from(business_status::WEBHOOK_ENDPOINT_PAUSED)),
+                status: Some(storage::enums::ProcessTrackerStatus::Pending),
+                updated_at: Some(now),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to hold the webhook delivery task of a paused endpoint")?;
+    Ok(())
+}
+
+/// Reschedules the deliveries of the webhooks held while the endpoint of the profile was paused
+async fn release_held_deliveries(
+    state: &SessionState,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<usize> {
+    let scheduler = state.store.as_scheduler();
+    let (mut offset, mut released_tasks) = (0, 0);
+    loop {
+        let tasks = scheduler
+            .find_processes_by_runner_status(
+                storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow,
+                vec![storage::enums::ProcessTrackerStatus::Pending],
+                Some(vec![String::from(business_status::WEBHOOK_ENDPOINT_PAUSED)]),
+                consts::WEBHOOK_ENDPOINT_PAUSED_TASKS_BATCH_SIZE,
+                offset,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the held webhook delivery tasks")?;
+        let is_last_batch = i64::try_from(tasks.len()).unwrap_or(i64::MAX)
+            < consts::WEBHOOK_ENDPOINT_PAUSED_TASKS_BATCH_SIZE;
+
+        for task in tasks {
+            let is_task_of_profile = task
+                .tracking_data
+                .clone()
+                .parse_value::<OutgoingWebhookTrackingData>("OutgoingWebhookTrackingData")
+                .is_ok_and(|tracking_data| &tracking_data.business_profile_id == profile_id);
+            // The tasks of the other profiles remain held, and are skipped by the next batches
+            if !is_task_of_profile {
+                offset += 1;
+                continue;
+            }
+
+            let now = date_time::now();
+            scheduler
+                .update_process(
+                    task,
+                    storage::ProcessTrackerUpdate::Update {
+                        name: None,
+                        retry_count: None,
+                        schedule_time: Some(now),
+                        tracking_data: None,
+                        business_status: Some(String::from(business_status::PENDING)),
+                        status: Some(storage::enums::ProcessTrackerStatus::Pending),
+                        updated_at: Some(now),
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to reschedule a held webhook delivery task")?;
+            released_tasks += 1;
+        }
+
+        if is_last_batch {
+            return Ok(released_tasks);
+        }
+    }
+}
+
+/// Records an attempt to deliver a webhook to the endpoint of the profile, and pauses the delivery
+/// to the endpoint if it has been failing persistently. The latency is that of the attempts which
+/// got a response from the endpoint. The attempt is not recorded if redis is unavailable.
+#[instrument(skip_all)]
+pub(super) async fn record_delivery_attempt(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    merchant_key_store: &domain::MerchantKeyStore,
+    is_delivered: bool,
+    latency: Option<std::time::Duration>,
+) {
+    let Ok(redis_conn) = state
+        .store
+        .get_redis_conn()
+        .map_err(|error| logger::error!(?error, "Failed to get redis connection"))
+    else {
+        return;
+    };
+    let profile_id = business_profile.get_id();
+    let now = date_time::now_unix_timestamp();
+
+    let mut fields = vec![(DELIVERIES_FIELD, 1)];
+    if !is_delivered {
+        fields.push((FAILURES_FIELD, 1));
+    }
+    if let Some(latency) = latency {
+        let latency_in_ms = i64::try_from(latency.as_millis()).unwrap_or(i64::MAX);
+        fields.extend([(RESPONSES_FIELD, 1), (LATENCY_FIELD, latency_in_ms)]);
+    }
+    let hourly_key =
+        get_hourly_health_redis_key(profile_id, now - now.rem_euclid(SECONDS_IN_AN_HOUR));
+    if let Err(error) = redis_conn
+        .increment_fields_in_hash(&hourly_key.as_str().into(), &fields)
+        .await
+    {
+        logger::error!(?error, "Failed to record webhook delivery attempt");
+    }
+    if let Err(error) = redis_conn
+        .set_expiry(
+            &hourly_key.as_str().into(),
+            consts::WEBHOOK_ENDPOINT_HEALTH_TTL_IN_SECS,
+        )
+        .await
+    {
+        logger::error!(?error, "Failed to set expiry of webhook endpoint health");
+    }
+
+    let state_key = get_state_redis_key(profile_id);
+    if is_delivered {
+        if let Err(error) = redis_conn
+            .set_hash_fields(
+                &state_key.as_str().into(),
+                vec![
+                    (CONSECUTIVE_FAILURES_FIELD, 0),
+                    (LAST_SUCCESS_AT_FIELD, now),
+                ],
+                Some(consts::WEBHOOK_ENDPOINT_STATE_TTL_IN_SECS),
+            )
+            .await
+        {
+            logger::error!(?error, "Failed to record webhook endpoint success");
+        }
+        return;
+    }
+
+    let consecutive_failures = match redis_conn
+        .increment_fields_in_hash(
+            &state_key.as_str().into(),
+            &[(CONSECUTIVE_FAILURES_FIELD, 1)],
+        )
+        .await
+    {
+        Ok(values) => values
+            .first()
+            .and_then(|value| u64::try_from(*value).ok())
+            .unwrap_or_default(),
+        Err(error) => {
+            logger::error!(?error, "Failed to record webhook endpoint failure");
+            return;
+        }
+    };
+    let mut fields = vec![(LAST_FAILURE_AT_FIELD, now)];
+    if consecutive_failures == 1 {
+        fields.push((FAILING_SINCE_FIELD, now));
+    }
+    if let Err(error) = redis_conn
+        .set_hash_fields(
+            &state_key.as_str().into(),
+            fields,
+            Some(consts::WEBHOOK_ENDPOINT_STATE_TTL_IN_SECS),
+        )
+        .await
+    {
+        logger::error!(?error, "Failed to record webhook endpoint failure");
+    }
+
+    if consecutive_failures < consts::WEBHOOK_ENDPOINT_PAUSE_CONSECUTIVE_FAILURES {
+        return;
+    }
+    let endpoint_state = match redis_conn
+        .get_hash_fields::<HashMap<String, i64>>(&state_key.as_str().into())
+        .await
+    {
+        Ok(fields) => WebhookEndpointState::from_fields(&fields),
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch webhook endpoint state");
+            return;
+        }
+    };
+    let Some(failing_since) = endpoint_state.failing_since else {
+        return;
+    };
+    let failing_duration = date_time::now() - failing_since;
+    if failing_duration.whole_seconds() < consts::WEBHOOK_ENDPOINT_PAUSE_FAILING_DURATION_IN_SECS {
+        return;
+    }
+
+    if let Err(error) = pause_endpoint(
+        state,
+        business_profile,
+        merchant_key_store,
+        WebhookEndpointPause {
+            paused_at: date_time::now(),
+            consecutive_failures: endpoint_state.consecutive_failures,
+            failing_since,
+        },
+    )
+    .await
+    {
+        logger::error!(?error, "Failed to pause webhook endpoint");
+    }
+}
+
+/// Pauses the delivery of the webhooks to the endpoint of the profile and notifies the merchant,
+/// unless the delivery is already paused
+async fn pause_endpoint(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    merchant_key_store: &domain::MerchantKeyStore,
+    pause: WebhookEndpointPause,
+) -> RouterResult<()> {
+    let profile_id = business_profile.get_id();
+    if get_endpoint_pause(state, profile_id).await?.is_some() {
+        return Ok(());
+    }
+
+    let serialized_pause = serde_json::to_string(&pause)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize webhook endpoint pause")?;
+    match state
+        .store
+        .insert_config(configs::ConfigNew {
+            key: profile_id.get_webhook_endpoint_pause_key(),
+            config: serialized_pause,
+        })
+        .await
+    {
+        Ok(_) => {}
+        // The endpoint was paused by a concurrent delivery attempt, which notifies the merchant
+        Err(error) if error.current_context().is_db_unique_violation() => return Ok(()),
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert webhook endpoint pause");
+        }
+    }
+
+    logger::warn!(
+        ?profile_id,
+        consecutive_failures = pause.consecutive_failures,
+        failing_since = ?pause.failing_since,
+        "Paused the delivery of webhooks to a persistently failing endpoint"
+    );
+
+    #[cfg(feature = "email")]
+    notify_endpoint_paused(state, business_profile, merchant_key_store, &pause).await?;
+    #[cfg(not(feature = "email"))]
+    let _ = merchant_key_store;
+
+    Ok(())
+}
+
+/// Notifies the merchant by email of the pause of the delivery of the webhooks to the endpoint of
+/// the profile, at the primary email of the merchant account
+#[cfg(feature = "email")]
+async fn notify_endpoint_paused(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    merchant_key_store: &domain::MerchantKeyStore,
+    pause: &WebhookEndpointPause,
+) -> RouterResult<()> {
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(
+            &state.into(),
+            &business_profile.merchant_id,
+            merchant_key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the merchant account of the webhook endpoint")?;
+    let Some(recipient_email) = merchant_account
+        .merchant_details
+        .clone()
+        .map(|details| details.parse_value::<api_models::admin::MerchantDetails>("MerchantDetails"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse merchant details")?
+        .and_then(|details| details.primary_email)
+    else {
+        logger::warn!("Merchant has no primary email to notify of the webhook endpoint pause");
+        return Ok(());
+    };
+
+    let email_contents = email_types::WebhookEndpointPaused {
+        recipient_email,
+        subject: consts::EMAIL_SUBJECT_WEBHOOK_ENDPOINT_PAUSED,
+        profile_id: business_profile.get_id().get_string_repr().to_owned(),
+        webhook_url: get_webhook_url(business_profile).unwrap_or_default(),
+        consecutive_failures: pause.consecutive_failures,
+        failing_since: pause.failing_since.to_string(),
+    };
+
+    state
+        .email_client
+        .clone()
+        .compose_and_send_email(
+            email_types::get_base_url(state),
+            Box::new(email_contents),
+            state.conf.proxy.https_url.as_ref(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to send the webhook endpoint pause email")
+}
+
+fn get_webhook_url(business_profile: &domain::Profile) -> Option<String> {
+    business_profile
+        .webhook_details
+        .as_ref()
+        .and_then(|webhook_details| webhook_details.webhook_url.clone())
+        .map(ExposeInterface::expose)
+}
+
+#[allow(clippy::as_conversions)]
+async fn get_endpoint_health(
+    state: &SessionState,
+    business_profile: &domain::Profile,
+    window_in_hours: u32,
+) -> RouterResult<WebhookEndpointHealthResponse> {
+    let profile_id = business_profile.get_id();
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let now = date_time::now_unix_timestamp();
+    let current_hour_start = now - now.rem_euclid(SECONDS_IN_AN_HOUR);
+    let mut hourly = Vec::new();
+    let (mut total_deliveries, mut failed_deliveries, mut responses, mut total_latency) =
+        (0u64, 0u64, 0u64, 0u64);
+    for hours_ago in (0..i64::from(window_in_hours)).rev() {
+        let hour_start = current_hour_start - hours_ago * SECONDS_IN_AN_HOUR;
+        let fields = redis_conn
+            .get_hash_fields::<HashMap<String, u64>>(
+                &get_hourly_health_redis_key(profile_id, hour_start).into(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch webhook endpoint health")?;
+        let get_field = |field| fields.get(field).copied().unwrap_or_default();
+        let (hour_responses, hour_latency) = (get_field(RESPONSES_FIELD), get_field(LATENCY_FIELD));
+
+        total_deliveries += get_field(DELIVERIES_FIELD);
+        failed_deliveries += get_field(FAILURES_FIELD);
+        responses += hour_responses;
+        total_latency += hour_latency;
+
+        if let Some(hour_start) = from_unix_timestamp(hour_start) {
+            hourly.push(WebhookEndpointHourlyHealth {
+                hour_start,
+                total_deliveries: get_field(DELIVERIES_FIELD),
+                failed_deliveries: get_field(FAILURES_FIELD),
+                average_latency_in_ms: hour_latency.checked_div(hour_responses),
+            });
+        }
+    }
+
+    let endpoint_state = redis_conn
+        .get_hash_fields::<HashMap<String, i64>>(&get_state_redis_key(profile_id).into())
+        .await
+        .map(|fields| WebhookEndpointState::from_fields(&fields))
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch webhook endpoint state")?;
+    let pause = get_endpoint_pause(state, profile_id).await?;
+
+    let status = match (&pause, endpoint_state.consecutive_failures) {
+        (Some(_), _) => WebhookEndpointStatus::Paused,
+        (None, 0) => WebhookEndpointStatus::Healthy,
+        (None, _) => WebhookEndpointStatus::Failing,
+    };
+    let successful_deliveries = total_deliveries.saturating_sub(failed_deliveries);
+    let success_rate = (total_deliveries > 0)
+        .then(|| successful_deliveries as f64 * 100.0 / total_deliveries as f64);
+
+    Ok(WebhookEndpointHealthResponse {
+        endpoint_id: profile_id.clone(),
+        url: get_webhook_url(business_profile),
+        status,
+        window_in_hours,
+        total_deliveries,
+        successful_deliveries,
+        failed_deliveries,
+        success_rate,
+        average_latency_in_ms: total_latency.checked_div(responses),
+        consecutive_failures: endpoint_state.consecutive_failures,
+        failing_since: endpoint_state.failing_since,
+        last_success_at: endpoint_state.last_success_at,
+        last_failure_at: endpoint_state.last_failure_at,
+        paused_at: pause.map(|pause| pause.paused_at),
+        hourly,
+    })
+}
+
+async fn get_business_profile(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: &id_type::ProfileId,
+) -> RouterResult<domain::Profile> {
+    core_utils::validate_and_get_business_profile(
+        state.store.as_ref(),
+        &state.into(),
+        key_store,
+        Some(profile_id),
+        merchant_account.get_id(),
+    )
+    .await?
+    .ok_or(report!(errors::ApiErrorResponse::ProfileNotFound {
+        id: profile_id.get_string_repr().to_owned(),
+    }))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_endpoint_health(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+    request: WebhookEndpointHealthRequest,
+) -> RouterResponse<WebhookEndpointHealthResponse> {
+    let business_profile =
+        get_business_profile(&state, &merchant_account, &key_store, &profile_id).await?;
+    let window_in_hours =
+        request
+            .get_window_in_hours()
+            .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+                message: "window_in_hours must be between 1 and 168".to_string(),
+            })?;
+
+    get_endpoint_health(&state, &business_profile, window_in_hours)
+        .await
+        .map(services::ApplicationResponse::Json)
+}
+
+/// Resumes the paused delivery of the webhooks to the endpoint of the profile, and reschedules the
+/// deliveries held while it was paused. The consecutive failures of the endpoint are reset, for the
+/// endpoint not to be paused again by its next failure.
+#[instrument(skip_all)]
+pub async fn resume_endpoint(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    profile_id: id_type::ProfileId,
+) -> RouterResponse<WebhookEndpointHealthResponse> {
+    let business_profile =
+        get_business_profile(&state, &merchant_account, &key_store, &profile_id).await?;
+
+    state
+        .store
+        .delete_config_by_key(&profile_id.get_webhook_endpoint_pause_key())
+        .await
+        .map_err(|error| {
+            if error.current_context().is_db_not_found() {
+                error.change_context(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "The delivery of webhooks to the endpoint is not paused".to_string(),
+                })
+            } else {
+                error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to delete webhook endpoint pause")
+            }
+        })?;
+
+    state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?
+        .set_hash_fields(
+            &get_state_redis_key(&profile_id).as_str().into(),
+            vec![(CONSECUTIVE_FAILURES_FIELD, 0)],
+            Some(consts::WEBHOOK_ENDPOINT_STATE_TTL_IN_SECS),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to reset webhook endpoint failures")?;
+
+    let released_tasks = release_held_deliveries(&state, &profile_id).await?;
+    logger::info!(
+        ?profile_id,
+        released_tasks,
+        "Resumed the delivery of webhooks to the endpoint"
+    );
+
+    get_endpoint_health(
+        &state,
+        &business_profile,
+        api_models::webhook_endpoints::WEBHOOK_ENDPOINT_HEALTH_DEFAULT_WINDOW_IN_HOURS,
+    )
+    .await
+    .map(services::ApplicationResponse::Json)
+}
@@ -0,0 +1,206 @@
+//! Catalog of the event types of the outgoing webhooks, with the JSON schemas of their payloads as
+//! generated from the API models, so that merchants can generate the consumers of the webhooks and
+//! validate their payloads against the schemas.
+
+use std::collections::BTreeMap;
+
+use api_models::{
+    webhook_events::{EventTypeCatalogItem, EventTypeCatalogResponse},
+    webhooks,
+};
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+use strum::IntoEnumIterator;
+use utoipa::{
+    openapi::{schema::Schema, RefOr},
+    ToSchema,
+};
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult},
+    routes::SessionState,
+    services,
+    types::storage::enums,
+};
+
+/// Returns the class of the event type and the type of the object sent in its webhooks, if the
+/// event type is supported by this build
+fn get_event_class_and_object_type(
+    event_type: enums::EventType,
+) -> Option<(enums::EventClass, enums::EventObjectType)> {
+    match event_type {
+        enums::EventType::PaymentSucceeded
+        | enums::EventType::PaymentFailed
+        | enums::EventType::PaymentProcessing
+        | enums::EventType::PaymentCancelled
+        | enums::EventType::PaymentAuthorized
+        | enums::EventType::PaymentCaptured
+        | enums::EventType::ActionRequired
+        | enums::EventType::PaymentAuthenticationAbandoned
+        | enums::EventType::PaymentAuthorizationRemainderReleased
+        | enums::EventType::PaymentDunningRetryScheduled
+        | enums::EventType::PaymentUncollectible
+        | enums::EventType::ConnectorVolumeCapReached => Some((
+            enums::EventClass::Payments,
+            enums::EventObjectType::PaymentDetails,
+        )),
+        enums::EventType::RefundSucceeded | enums::EventType::RefundFailed => Some((
+            enums::EventClass::Refunds,
+            enums::EventObjectType::RefundDetails,
+        )),
+        enums::EventType::DisputeOpened
+        | enums::EventType::DisputeExpired
+        | enums::EventType::DisputeAccepted
+        | enums::EventType::DisputeCancelled
+        | enums::EventType::DisputeChallenged
+        | enums::EventType::DisputeWon
+        | enums::EventType::DisputeLost => Some((
+            enums::EventClass::Disputes,
+            enums::EventObjectType::DisputeDetails,
+        )),
+        enums::EventType::MandateActive | enums::EventType::MandateRevoked => Some((
+            enums::EventClass::Mandates,
+            enums::EventObjectType::MandateDetails,
+        )),
+        enums::EventType::PayoutSuccess
+        | enums::EventType::PayoutFailed
+        | enums::EventType::PayoutInitiated
+        | enums::EventType::PayoutProcessing
+        | enums::EventType::PayoutCancelled
+        | enums::EventType::PayoutExpired
+        | enums::EventType::PayoutReversed => {
+            #[cfg(feature = "payouts")]
+            {
+                Some((
+                    enums::EventClass::Payouts,
+                    enums::EventObjectType::PayoutDetails,
+                ))
+            }
+            #[cfg(not(feature = "payouts"))]
+            {
+                None
+            }
+        }
+        enums::EventType::MerchantAccountStatusUpdated => Some((
+            enums::EventClass::MerchantAccount,
+            enums::EventObjectType::MerchantAccountDetails,
+        )),
+        enums::EventType::ConnectorCredentialsExpiring
+        | enums::EventType::ConnectorCredentialsExpired => Some((
+            enums::EventClass::MerchantConnectorAccount,
+            enums::EventObjectType::ConnectorCredentialsDetails,
+        )),
+    }
+}
+
+/// Returns the name and the schema of the object sent in the webhooks of the object type, as in
+/// `OutgoingWebhookContent`
+fn get_content_schema(
+    object_type: enums::EventObjectType,
+) -> Option<(&'static str, RefOr<Schema>)> {
+    match object_type {
+        enums::EventObjectType::PaymentDetails => {
+            Some(api_models::payments::PaymentsResponse::schema())
+        }
+        enums::EventObjectType::RefundDetails => {
+            Some(api_models::refunds::RefundResponse::schema())
+        }
+        enums::EventObjectType::DisputeDetails => {
+            Some(api_models::disputes::DisputeResponse::schema())
+        }
+        enums::EventObjectType::MandateDetails => {
+            Some(api_models::mandates::MandateResponse::schema())
+        }
+        enums::EventObjectType::PayoutDetails => {
+            #[cfg(feature = "payouts")]
+            {
+                Some(api_models::payouts::PayoutCreateResponse::schema())
+            }
+            #[cfg(not(feature = "payouts"))]
+            {
+                None
+            }
+        }
+        enums::EventObjectType::MerchantAccountDetails => {
+            Some(api_models::admin::MerchantAccountStatusResponse::schema())
+        }
+        enums::EventObjectType::ConnectorCredentialsDetails => {
+            Some(api_models::admin::ConnectorCredentialsResponse::schema())
+        }
+    }
+}
+
+fn insert_schema(
+    schemas: &mut BTreeMap<String, serde_json::Value>,
+    (name, schema): (&str, RefOr<Schema>),
+) -> RouterResult<()> {
+    if !schemas.contains_key(name) {
+        let schema = serde_json::to_value(schema)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| format!("Failed to serialize the schema of {name}"))?;
+        schemas.insert(name.to_string(), schema);
+    }
+    Ok(())
+}
+
+pub fn get_event_type_catalog() -> RouterResult<EventTypeCatalogResponse> {
+    let mut schemas = BTreeMap::new();
+    insert_schema(&mut schemas, webhooks::OutgoingWebhook::schema())?;
+    insert_schema(&mut schemas, webhooks::OutgoingWebhookContent::schema())?;
+
+    let mut event_types = Vec::new();
+    for event_type in enums::EventType::iter() {
+        let Some((event_class, object_type)) = get_event_class_and_object_type(event_type) else {
+            continue;
+        };
+        let Some(content_schema) = get_content_schema(object_type) else {
+            continue;
+        };
+        let content_schema_name = content_schema.0.to_string();
+        insert_schema(&mut schemas, content_schema)?;
+
+        event_types.push(EventTypeCatalogItem {
+            event_type,
+            event_class,
+            content_type: object_type.to_string(),
+            content_schema: content_schema_name,
+        });
+    }
+
+    Ok(EventTypeCatalogResponse {
+        event_types,
+        schemas,
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn list_event_types(_state: SessionState) -> RouterResponse<EventTypeCatalogResponse> {
+    get_event_type_catalog().map(services::ApplicationResponse::Json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catalog_references_the_schemas_of_every_event_type() {
+        let catalog = get_event_type_catalog().expect("Failed to build the event type catalog");
+
+        assert!(catalog.schemas.contains_key("OutgoingWebhook"));
+        for item in &catalog.event_types {
+            assert!(
+                catalog.schemas.contains_key(&item.content_schema),
+                "Missing the schema of {}",
+                item.event_type
+            );
+        }
+
+        let payment_succeeded = catalog
+            .event_types
+            .iter()
+            .find(|item| item.event_type == enums::EventType::PaymentSucceeded)
+            .expect("Missing payment_succeeded in the event type catalog");
+        assert_eq!(payment_succeeded.content_type, "payment_details");
+        assert_eq!(payment_succeeded.content_schema, "PaymentsResponse");
+    }
+}
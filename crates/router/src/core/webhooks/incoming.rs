@@ -16,14 +16,15 @@ use hyperswitch_domain_models::{
 use hyperswitch_interfaces::webhooks::{IncomingWebhookFlowError, IncomingWebhookRequestDetails};
 use masking::{ExposeInterface, PeekInterface};
 use router_env::{instrument, tracing, tracing_actix_web::RequestId};
+use tracing_futures::Instrument;
 
-use super::{types, utils, MERCHANT_ID};
+use super::{ordering, source_ip_verification, types, utils, MERCHANT_ID};
 use crate::{
     consts,
     core::{
-        api_locking,
+        api_locking, disputes,
         errors::{self, ConnectorErrorExt, CustomResult, RouterResponse, StorageErrorExt},
-        metrics,
+        fault_injection, metrics,
         payments::{self, tokenization},
         refunds, relay, utils as core_utils,
         webhooks::utils::construct_webhook_router_data,
@@ -48,7 +49,7 @@ use crate::{
         storage::{self, enums},
         transformers::{ForeignFrom, ForeignInto, ForeignTryFrom},
     },
-    utils::{self as helper_utils, ext_traits::OptionExt, generate_id},
+    utils::{self as helper_utils, ext_traits::OptionExt},
 };
 #[cfg(feature = "payouts")]
 use crate::{core::payouts, types::storage::PayoutAttemptUpdate};
@@ -162,6 +163,9 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
     )
     .await?;
 
+    fault_injection::delay_incoming_webhook(&state, merchant_account.get_id(), &connector_name)
+        .await;
+
     let decoded_body = connector
         .decode_webhook_body(
             &request_details,
@@ -283,6 +287,14 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
             }
         };
 
+        source_ip_verification::verify_webhook_source_ip(
+            &state,
+            req,
+            connector_enum,
+            &merchant_connector_account,
+        )
+        .await?;
+
         let source_verified = if connectors_with_source_verification_call
             .connectors_with_webhook_source_verification_call
             .contains(&connector_enum)
@@ -432,6 +444,7 @@ async fn incoming_webhooks_core<W: types::OutgoingWebhookType>(
                     connector_name.as_str(),
                     source_verified,
                     event_type,
+                    ordering::get_event_timestamp(&connector, &request_details),
                 ))
                 .await
                 .attach_printable("Incoming webhook flow for refunds failed"),
@@ -607,6 +620,7 @@ async fn payments_incoming_webhook_flow(
     request_details: &IncomingWebhookRequestDetails<'_>,
     event_type: webhooks::IncomingWebhookEvent,
 ) -> CustomResult<WebhookResponseTracker, errors::ApiErrorResponse> {
+    let event_timestamp = ordering::get_event_timestamp(connector, request_details);
     let consume_or_trigger_flow = if source_verified {
         payments::CallConnectorAction::HandleResponse(webhook_details.resource_object)
     } else {
@@ -622,6 +636,19 @@ async fn payments_incoming_webhook_flow(
             )
             .await?;
 
+            payments::timeline::record_event(
+                &state,
+                merchant_account.get_id(),
+                &payment_id,
+                None,
+                api_models::payments::PaymentTimelineEventType::WebhookReceived,
+                Some(connector.id()),
+                Some(serde_json::json!({
+                    "event_type": event_type,
+                    "source_verified": source_verified,
+                })),
+            );
+
             let lock_action = api_locking::LockAction::Hold {
                 input: api_locking::LockingInput {
                     unique_locking_key: payment_id.get_string_repr().to_owned(),
@@ -635,6 +662,28 @@ async fn payments_incoming_webhook_flow(
                 .perform_locking_action(&state, merchant_account.get_id().to_owned())
                 .await?;
 
+            if source_verified {
+                let out_of_order_status = get_payment_status_if_out_of_order_webhook(
+                    &state,
+                    &merchant_account,
+                    &key_store,
+                    &payment_id,
+                    event_type,
+                    event_timestamp,
+                )
+                .await;
+
+                // The lock is released before any error is propagated
+                if matches!(out_of_order_status, Ok(Some(_)) | Err(_)) {
+                    lock_action
+                        .free_lock_action(&state, merchant_account.get_id().to_owned())
+                        .await?;
+                }
+                if let Some(status) = out_of_order_status? {
+                    return Ok(WebhookResponseTracker::Payment { payment_id, status });
+                }
+            }
+
             let response = Box::pin(payments::payments_core::<
                 api::PSync,
                 api::PaymentsResponse,
@@ -659,6 +708,7 @@ async fn payments_incoming_webhook_flow(
                     client_secret: None,
                     expand_attempts: None,
                     expand_captures: None,
+                    consistency_token: None,
                 },
                 services::AuthFlow::Merchant,
                 consume_or_trigger_flow.clone(),
@@ -719,6 +769,16 @@ async fn payments_incoming_webhook_flow(
 
             let status = payments_response.status;
 
+            if source_verified {
+                ordering::record_applied_event(
+                    &state,
+                    merchant_account.get_id(),
+                    payment_id.get_string_repr(),
+                    event_timestamp,
+                )
+                .await;
+            }
+
             let event_type: Option<enums::EventType> = payments_response.status.foreign_into();
 
             // If event is NOT an UnsupportedEvent, trigger Outgoing Webhook
@@ -749,6 +809,178 @@ async fn payments_incoming_webhook_flow(
     }
 }
 
+/// Returns the current status of the payment if the webhook was generated before the latest
+/// webhook applied to the payment, or if it would move the payment attempt to a status which is
+/// not reachable from its current status
+async fn get_payment_status_if_out_of_order_webhook(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &common_utils::id_type::PaymentId,
+    event_type: webhooks::IncomingWebhookEvent,
+    event_timestamp: Option<time::PrimitiveDateTime>,
+) -> errors::RouterResult<Option<enums::IntentStatus>> {
+    let db = state.store.as_ref();
+    let payment_intent = match db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            payment_id,
+            merchant_account.get_id(),
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(payment_intent) => payment_intent,
+        // The webhooks of the payments which are not found are handled by the payment sync
+        Err(error) if error.current_context().is_db_not_found() => return Ok(None),
+        Err(error) => {
+            return Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the payment intent");
+        }
+    };
+
+    if ordering::is_stale_event(
+        state,
+        merchant_account.get_id(),
+        payment_id.get_string_repr(),
+        event_timestamp,
+    )
+    .await
+    {
+        logger::info!("Discarding payment webhook generated before the latest applied webhook");
+        return Ok(Some(payment_intent.status));
+    }
+
+    let Some(next_status) = ordering::get_attempt_status_for_event(event_type) else {
+        return Ok(None);
+    };
+
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            payment_id,
+            merchant_account.get_id(),
+            payment_intent.active_attempt.get_id().as_str(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    if payment_attempt.status.is_valid_transition_to(next_status) {
+        Ok(None)
+    } else {
+        logger::info!(
+            current_status = ?payment_attempt.status,
+            ?next_status,
+            "Discarding payment webhook with an invalid status transition"
+        );
+        Ok(Some(payment_intent.status))
+    }
+}
+
+/// Applies the refund webhooks which were received before the capture of the payment, in the
+/// background, when the payment attempt moves to a captured status. This is done wherever the
+/// status of the attempt is updated from the response of the connector, so that the refund
+/// webhooks are applied whether the payment is captured by a webhook, a sync, a capture or a
+/// poll of the payment.
+pub(crate) fn apply_buffered_refund_webhooks_on_capture(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: &storage::PaymentIntent,
+    previous_attempt_status: enums::AttemptStatus,
+    attempt_status: enums::AttemptStatus,
+) {
+    if ordering::is_attempt_refundable(previous_attempt_status)
+        || !ordering::is_attempt_refundable(attempt_status)
+    {
+        return;
+    }
+
+    let state = state.clone();
+    let key_store = key_store.clone();
+    let merchant_id = payment_intent.merchant_id.clone();
+    let payment_id = payment_intent.payment_id.clone();
+    let profile_id = payment_intent.profile_id.clone();
+    tokio::spawn(
+        async move {
+            if let Err(error) = Box::pin(apply_buffered_refund_webhooks(
+                state,
+                key_store,
+                merchant_id,
+                payment_id,
+                profile_id,
+            ))
+            .await
+            {
+                logger::error!(?error, "Failed to apply the buffered refund webhooks");
+            }
+        }
+        .in_current_span(),
+    );
+}
+
+/// Applies the refund webhooks which were received before the capture of the payment. Failures of
+/// the individual webhooks are only logged, as the capture of the payment has already been applied.
+async fn apply_buffered_refund_webhooks(
+    state: SessionState,
+    key_store: domain::MerchantKeyStore,
+    merchant_id: common_utils::id_type::MerchantId,
+    payment_id: common_utils::id_type::PaymentId,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+) -> errors::RouterResult<()> {
+    let buffered_webhooks =
+        ordering::take_buffered_refund_webhooks(&state, &merchant_id, &payment_id).await;
+    if buffered_webhooks.is_empty() {
+        return Ok(());
+    }
+
+    let key_manager_state = &(&state).into();
+    let merchant_account = state
+        .store
+        .find_merchant_account_by_merchant_id(key_manager_state, &merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+    let profile_id = profile_id
+        .get_required_value("profile_id")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("profile_id is not set in payment_intent")?;
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(key_manager_state, &key_store, &profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    for buffered_webhook in buffered_webhooks {
+        let webhook_details = api::IncomingWebhookDetails {
+            object_reference_id: webhooks::ObjectReferenceId::RefundId(
+                webhooks::RefundIdType::RefundId(buffered_webhook.refund_id),
+            ),
+            resource_object: buffered_webhook.resource_object,
+        };
+
+        if let Err(error) = Box::pin(refunds_incoming_webhook_flow(
+            state.clone(),
+            merchant_account.clone(),
+            business_profile.clone(),
+            key_store.clone(),
+            webhook_details,
+            buffered_webhook.connector_name.as_str(),
+            true,
+            buffered_webhook.event_type,
+            buffered_webhook.event_timestamp,
+        ))
+        .await
+        {
+            logger::error!(?error, "Failed to apply the buffered refund webhook");
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(feature = "payouts")]
 #[instrument(skip_all)]
 async fn payouts_incoming_webhook_flow(
@@ -985,6 +1217,7 @@ async fn refunds_incoming_webhook_flow(
     connector_name: &str,
     source_verified: bool,
     event_type: webhooks::IncomingWebhookEvent,
+    event_timestamp: Option<time::PrimitiveDateTime>,
 ) -> CustomResult<WebhookResponseTracker, errors::ApiErrorResponse> {
     let db = &*state.store;
     //find refund by connector refund id
@@ -1016,23 +1249,91 @@ async fn refunds_incoming_webhook_flow(
     let refund_id = refund.refund_id.to_owned();
     //if source verified then update refund status else trigger refund sync
     let updated_refund = if source_verified {
+        let refund_status = common_enums::RefundStatus::foreign_try_from(event_type)
+            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+            .attach_printable("failed refund status mapping from event type")?;
+        let current_status_response = WebhookResponseTracker::Refund {
+            payment_id: refund.payment_id.clone(),
+            refund_id: refund_id.clone(),
+            status: refund.refund_status,
+        };
+
+        if ordering::is_stale_event(
+            &state,
+            merchant_account.get_id(),
+            &refund_id,
+            event_timestamp,
+        )
+        .await
+        {
+            logger::info!("Discarding refund webhook generated before the latest applied webhook");
+            return Ok(current_status_response);
+        }
+        if !refund.refund_status.is_valid_transition_to(refund_status) {
+            logger::info!(
+                current_status = ?refund.refund_status,
+                next_status = ?refund_status,
+                "Discarding refund webhook with an invalid status transition"
+            );
+            return Ok(current_status_response);
+        }
+
+        // A refund webhook received before the capture of its payment is applied once the payment
+        // is captured, so that the refund does not succeed for a payment which is not yet captured
+        let payment_attempt = db
+            .find_payment_attempt_by_attempt_id_merchant_id(
+                &refund.attempt_id,
+                merchant_account.get_id(),
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
+            .attach_printable("Failed to fetch the payment attempt of the refund")?;
+        if !ordering::is_attempt_refundable(payment_attempt.status)
+            && ordering::buffer_refund_webhook(
+                &state,
+                merchant_account.get_id(),
+                &refund.payment_id,
+                ordering::BufferedRefundWebhook {
+                    connector_name: connector_name.to_string(),
+                    event_type,
+                    refund_id: refund_id.clone(),
+                    resource_object: webhook_details.resource_object,
+                    event_timestamp,
+                },
+            )
+            .await
+        {
+            logger::info!("Buffering refund webhook until the capture of the payment");
+            return Ok(current_status_response);
+        }
+
         let refund_update = storage::RefundUpdate::StatusUpdate {
             connector_refund_id: None,
             sent_to_gateway: true,
-            refund_status: common_enums::RefundStatus::foreign_try_from(event_type)
-                .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-                .attach_printable("failed refund status mapping from event type")?,
+            refund_status,
             updated_by: merchant_account.storage_scheme.to_string(),
             processor_refund_data: None,
         };
-        db.update_refund(
-            refund.to_owned(),
-            refund_update,
-            merchant_account.storage_scheme,
+        let updated_refund = db
+            .update_refund(
+                refund.to_owned(),
+                refund_update,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
+            .attach_printable_lazy(|| {
+                format!("Failed while updating refund: refund_id: {refund_id}")
+            })?;
+        ordering::record_applied_event(
+            &state,
+            merchant_account.get_id(),
+            &refund_id,
+            event_timestamp,
         )
-        .await
-        .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
-        .attach_printable_lazy(|| format!("Failed while updating refund: refund_id: {refund_id}"))?
+        .await;
+        updated_refund
     } else {
         Box::pin(refunds::refund_retrieve_core_with_refund_id(
             state.clone(),
@@ -1052,8 +1353,12 @@ async fn refunds_incoming_webhook_flow(
 
     // If event is NOT an UnsupportedEvent, trigger Outgoing Webhook
     if let Some(outgoing_event_type) = event_type {
-        let refund_response: api_models::refunds::RefundResponse =
-            updated_refund.clone().foreign_into();
+        let refund_response = refunds::settlement_times::get_refund_response(
+            &state,
+            &merchant_account,
+            updated_refund.clone(),
+        )
+        .await;
         Box::pin(super::create_event_and_trigger_outgoing_webhook(
             state,
             merchant_account,
@@ -1166,7 +1471,7 @@ async fn get_or_update_dispute_object(
     match option_dispute {
         None => {
             metrics::INCOMING_DISPUTE_WEBHOOK_NEW_RECORD_METRIC.add(1, &[]);
-            let dispute_id = generate_id(consts::ID_LENGTH, "dp");
+            let dispute_id = common_utils::generate_resource_id(consts::ID_LENGTH, "dp");
             let new_dispute = diesel_models::dispute::DisputeNew {
                 dispute_id,
                 amount: dispute_details.amount.clone(),
@@ -1649,6 +1954,7 @@ async fn disputes_incoming_webhook_flow(
             connector.id(),
         )
         .await?;
+        disputes::ledger::record_dispute_ledger_entries(&state, &dispute_object).await;
         let disputes_response = Box::new(dispute_object.clone().foreign_into());
         let event_type: enums::EventType = dispute_object.dispute_status.foreign_into();
 
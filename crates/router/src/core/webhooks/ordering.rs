@@ -0,0 +1,313 @@
+//! Ordering guarantees for the connector webhooks, which can be delivered out of order. The
+//! timestamp of the latest webhook applied to a payment or a refund is stored, so that a webhook
+//! generated before it is discarded instead of regressing the status. Refund webhooks received
+//! before the capture of their payment are buffered, and are applied once the payment is captured,
+//! by whichever flow captures it.
+
+use api_models::webhooks::IncomingWebhookEvent;
+use common_utils::{ext_traits::StringExt, id_type};
+use hyperswitch_interfaces::webhooks::IncomingWebhookRequestDetails;
+use redis_interface::errors::RedisError;
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    routes::SessionState,
+    services::connector_integration_interface::ConnectorEnum,
+    types::{api::IncomingWebhook, storage::enums},
+};
+
+/// A refund webhook which is held until the payment of the refund is captured
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BufferedRefundWebhook {
+    pub connector_name: String,
+    pub event_type: IncomingWebhookEvent,
+    pub refund_id: String,
+    pub resource_object: Vec<u8>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub event_timestamp: Option<PrimitiveDateTime>,
+}
+
+fn get_last_event_time_redis_key(merchant_id: &id_type::MerchantId, object_id: &str) -> String {
+    format!(
+        "{}_{object_id}_webhook_last_event_time",
+        merchant_id.get_string_repr()
+    )
+}
+
+fn get_buffered_refund_webhooks_redis_key(
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> String {
+    format!(
+        "{}_{}_buffered_refund_webhooks",
+        merchant_id.get_string_repr(),
+        payment_id.get_string_repr()
+    )
+}
+
+/// Returns the time at which the webhook was generated by the connector, if the connector
+/// provides it
+pub fn get_event_timestamp(
+    connector: &ConnectorEnum,
+    request_details: &IncomingWebhookRequestDetails<'_>,
+) -> Option<PrimitiveDateTime> {
+    connector
+        .get_webhook_event_timestamp(request_details)
+        .map_err(|error| logger::error!(?error, "Failed to get the webhook event timestamp"))
+        .ok()
+        .flatten()
+}
+
+/// Returns the status the payment attempt is moved to by the webhook event, if the event
+/// determines it
+pub fn get_attempt_status_for_event(
+    event_type: IncomingWebhookEvent,
+) -> Option<enums::AttemptStatus> {
+    match event_type {
+        IncomingWebhookEvent::PaymentIntentSuccess
+        | IncomingWebhookEvent::PaymentIntentCaptureSuccess => Some(enums::AttemptStatus::Charged),
+        IncomingWebhookEvent::PaymentIntentFailure => Some(enums::AttemptStatus::Failure),
+        IncomingWebhookEvent::PaymentIntentProcessing => Some(enums::AttemptStatus::Pending),
+        IncomingWebhookEvent::PaymentIntentPartiallyFunded => {
+            Some(enums::AttemptStatus::PartialCharged)
+        }
+        IncomingWebhookEvent::PaymentIntentCancelled => Some(enums::AttemptStatus::Voided),
+        IncomingWebhookEvent::PaymentIntentCancelFailure => Some(enums::AttemptStatus::VoidFailed),
+        IncomingWebhookEvent::PaymentIntentAuthorizationSuccess => {
+            Some(enums::AttemptStatus::Authorized)
+        }
+        IncomingWebhookEvent::PaymentIntentAuthorizationFailure => {
+            Some(enums::AttemptStatus::AuthorizationFailed)
+        }
+        IncomingWebhookEvent::PaymentIntentCaptureFailure => {
+            Some(enums::AttemptStatus::CaptureFailed)
+        }
+        IncomingWebhookEvent::PaymentActionRequired => {
+            Some(enums::AttemptStatus::AuthenticationPending)
+        }
+        _ => None,
+    }
+}
+
+/// Indicates whether the refunds of a payment attempt with the given status can be processed
+pub fn is_attempt_refundable(status: enums::AttemptStatus) -> bool {
+    matches!(
+        status,
+        enums::AttemptStatus::Charged
+            | enums::AttemptStatus::PartialCharged
+            | enums::AttemptStatus::PartialChargedAndChargeable
+    )
+}
+
+/// Indicates whether a webhook applied to the object was generated after the given webhook. The
+/// webhook is not considered stale if the timestamps could not be compared.
+#[instrument(skip_all)]
+pub async fn is_stale_event(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    object_id: &str,
+    event_timestamp: Option<PrimitiveDateTime>,
+) -> bool {
+    let Some(event_timestamp) = event_timestamp else {
+        return false;
+    };
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection");
+            return false;
+        }
+    };
+
+    let key = get_last_event_time_redis_key(merchant_id, object_id);
+    match redis_conn
+        .get_and_deserialize_key::<i64>(&key.as_str().into(), "i64")
+        .await
+    {
+        Ok(last_event_time) => event_timestamp.assume_utc().unix_timestamp() < last_event_time,
+        Err(error) if error.current_context() == &RedisError::NotFound => false,
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch the timestamp of the last webhook");
+            false
+        }
+    }
+}
+
+/// Stores the timestamp of the webhook applied to the object, unless a newer webhook has already
+/// been applied. Failures are only logged, as the webhook has already been applied.
+#[instrument(skip_all)]
+pub async fn record_applied_event(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    object_id: &str,
+    event_timestamp: Option<PrimitiveDateTime>,
+) {
+    let Some(event_timestamp) = event_timestamp else {
+        return;
+    };
+
+    if is_stale_event(state, merchant_id, object_id, Some(event_timestamp)).await {
+        return;
+    }
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection");
+            return;
+        }
+    };
+
+    let key = get_last_event_time_redis_key(merchant_id, object_id);
+    if let Err(error) = redis_conn
+        .serialize_and_set_key_with_expiry(
+            &key.as_str().into(),
+            event_timestamp.assume_utc().unix_timestamp(),
+            consts::WEBHOOK_LAST_EVENT_TIME_EXPIRY_SECONDS,
+        )
+        .await
+    {
+        logger::error!(?error, "Failed to store the timestamp of the last webhook");
+    }
+}
+
+/// Holds the refund webhook until the payment of the refund is captured. Returns `false` if the
+/// webhook could not be buffered, in which case it has to be processed right away.
+#[instrument(skip_all)]
+pub async fn buffer_refund_webhook(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+    webhook: BufferedRefundWebhook,
+) -> bool {
+    let serialized_webhook = match serde_json::to_string(&webhook) {
+        Ok(serialized_webhook) => serialized_webhook,
+        Err(error) => {
+            logger::error!(?error, "Failed to serialize the refund webhook");
+            return false;
+        }
+    };
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection");
+            return false;
+        }
+    };
+
+    let key = get_buffered_refund_webhooks_redis_key(merchant_id, payment_id);
+    match redis_conn.get_list_length(&key.as_str().into()).await {
+        Ok(length) if length >= consts::MAX_BUFFERED_REFUND_WEBHOOKS => {
+            logger::warn!("Maximum number of refund webhooks are already buffered for the payment");
+            return false;
+        }
+        Ok(_) => (),
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch the buffered refund webhooks");
+            return false;
+        }
+    }
+
+    if let Err(error) = redis_conn
+        .append_elements_to_list(&key.as_str().into(), serialized_webhook)
+        .await
+    {
+        logger::error!(?error, "Failed to buffer the refund webhook");
+        return false;
+    }
+
+    if let Err(error) = redis_conn
+        .set_expiry(
+            &key.as_str().into(),
+            consts::BUFFERED_REFUND_WEBHOOKS_EXPIRY_SECONDS,
+        )
+        .await
+    {
+        logger::error!(
+            ?error,
+            "Failed to set expiry for the buffered refund webhooks"
+        );
+    }
+
+    true
+}
+
+/// Removes the refund webhooks buffered for the payment, ordered by the time at which they were
+/// generated by the connector
+#[instrument(skip_all)]
+pub async fn take_buffered_refund_webhooks(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> Vec<BufferedRefundWebhook> {
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(error) => {
+            logger::error!(?error, "Failed to get redis connection");
+            return Vec::new();
+        }
+    };
+
+    let key = get_buffered_refund_webhooks_redis_key(merchant_id, payment_id);
+    let serialized_webhooks = match redis_conn
+        .lpop_list_elements(
+            &key.as_str().into(),
+            Some(consts::MAX_BUFFERED_REFUND_WEBHOOKS),
+        )
+        .await
+    {
+        Ok(serialized_webhooks) => serialized_webhooks,
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch the buffered refund webhooks");
+            return Vec::new();
+        }
+    };
+
+    let mut webhooks = serialized_webhooks
+        .into_iter()
+        .filter_map(|serialized_webhook| {
+            serialized_webhook
+                .parse_struct::<BufferedRefundWebhook>("BufferedRefundWebhook")
+                .map_err(|error| {
+                    logger::error!(?error, "Failed to deserialize the buffered refund webhook");
+                })
+                .ok()
+        })
+        .collect::<Vec<_>>();
+    // The webhooks without a timestamp retain the order in which they were received
+    webhooks.sort_by_key(|webhook| webhook.event_timestamp);
+    webhooks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_late_capture_webhook_does_not_regress_refundable_payment() {
+        let processing =
+            get_attempt_status_for_event(IncomingWebhookEvent::PaymentIntentProcessing);
+        assert_eq!(processing, Some(enums::AttemptStatus::Pending));
+        assert!(
+            !enums::AttemptStatus::Charged.is_valid_transition_to(enums::AttemptStatus::Pending)
+        );
+        assert!(
+            enums::AttemptStatus::Authorized.is_valid_transition_to(enums::AttemptStatus::Charged)
+        );
+        assert!(is_attempt_refundable(enums::AttemptStatus::Charged));
+        assert!(!is_attempt_refundable(enums::AttemptStatus::Authorized));
+    }
+
+    #[test]
+    fn test_refund_status_transitions() {
+        assert!(enums::RefundStatus::Pending.is_valid_transition_to(enums::RefundStatus::Success));
+        assert!(enums::RefundStatus::Success.is_valid_transition_to(enums::RefundStatus::Failure));
+        assert!(!enums::RefundStatus::Success.is_valid_transition_to(enums::RefundStatus::Pending));
+        assert!(!enums::RefundStatus::Failure.is_valid_transition_to(enums::RefundStatus::Success));
+    }
+}
@@ -20,7 +20,7 @@ use router_env::{
     tracing::{self, Instrument},
 };
 
-use super::{types, utils, MERCHANT_ID};
+use super::{endpoint_health, payload_templates, types, utils, MERCHANT_ID};
 #[cfg(feature = "stripe")]
 use crate::compatibility::stripe::webhooks as stripe_webhooks;
 use crate::{
@@ -76,6 +76,39 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
             "Outgoing webhooks are disabled in application configuration, or merchant webhook URL \
              could not be obtained; skipping outgoing webhooks for event"
         );
+
+        // The event is still recorded, so that the event stream of the merchant is complete
+        // regardless of whether webhooks are delivered
+        let event = domain::Event {
+            event_id: utils::generate_event_id(),
+            event_type,
+            event_class,
+            is_webhook_notified: false,
+            primary_object_id,
+            primary_object_type,
+            created_at: common_utils::date_time::now(),
+            merchant_id: Some(business_profile.merchant_id.clone()),
+            business_profile_id: Some(business_profile.get_id().to_owned()),
+            primary_object_created_at,
+            idempotent_event_id: Some(idempotent_event_id.clone()),
+            initial_attempt_id: None,
+            request: None,
+            response: None,
+            delivery_attempt: None,
+            metadata: Some(storage::EventMetadata::foreign_from(&content)),
+        };
+        if let Err(error) = state
+            .store
+            .insert_event(&(&state).into(), event, merchant_key_store)
+            .await
+        {
+            if error.current_context().is_db_unique_violation() {
+                logger::debug!("Event with idempotent ID `{idempotent_event_id}` already exists in the database");
+            } else {
+                logger::error!(event_insertion_failure=?error);
+            }
+        }
+
         return Ok(());
     }
 
@@ -91,10 +124,25 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         timestamp: now,
     };
 
-    let request_content =
-        get_outgoing_webhook_request(&merchant_account, outgoing_webhook, &business_profile)
-            .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
-            .attach_printable("Failed to construct outgoing webhook request content")?;
+    // The webhook is sent with the default payload when its template cannot be fetched
+    let payload_template =
+        payload_templates::find_payload_template(&*state.store, &merchant_id, event_type)
+            .await
+            .inspect_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to fetch the payload template, sending the default payload"
+                )
+            })
+            .unwrap_or(None);
+    let request_content = get_outgoing_webhook_request(
+        &merchant_account,
+        outgoing_webhook,
+        &business_profile,
+        payload_template.as_ref(),
+    )
+    .change_context(errors::ApiErrorResponse::WebhookProcessingFailure)
+    .attach_printable("Failed to construct outgoing webhook request content")?;
 
     let event_metadata = storage::EventMetadata::foreign_from(&content);
     let key_manager_state = &(&state).into();
@@ -270,6 +318,28 @@ async fn trigger_webhook_to_merchant(
 
     let event_id = event.event_id;
 
+    // The manual retries are sent to a paused endpoint, which lets the merchant test the endpoint.
+    // The other deliveries are held, and are delivered once the delivery is resumed.
+    if delivery_attempt != enums::WebhookDeliveryAttempt::ManualRetry
+        && endpoint_health::is_endpoint_paused(&state, business_profile.get_id()).await
+    {
+        logger::info!("Webhook endpoint is paused, holding webhook delivery");
+        match process_tracker {
+            Some(process_tracker) => {
+                endpoint_health::hold_delivery_while_paused(&state, process_tracker)
+                    .await
+                    .change_context(
+                        errors::WebhooksFlowError::OutgoingWebhookProcessTrackerTaskUpdateFailed,
+                    )?;
+            }
+            None => logger::warn!(
+                %event_id,
+                "Webhook delivery to a paused endpoint has no retry task to be held with"
+            ),
+        }
+        return Err(report!(errors::WebhooksFlowError::WebhookEndpointPaused));
+    }
+
     let headers = request_content
         .headers
         .into_iter()
@@ -285,10 +355,19 @@ async fn trigger_webhook_to_merchant(
         ))
         .build();
 
+    let delivery_started_at = std::time::Instant::now();
     let response = state
         .api_client
         .send_request(&state, request, Some(OUTGOING_WEBHOOK_TIMEOUT_SECS), false)
         .await;
+    endpoint_health::record_delivery_attempt(
+        &state,
+        &business_profile,
+        merchant_key_store,
+        matches!(&response, Ok(response) if response.status().is_success()),
+        response.is_ok().then(|| delivery_started_at.elapsed()),
+    )
+    .await;
 
     metrics::WEBHOOK_OUTGOING_COUNT.add(
         1,
@@ -592,11 +671,13 @@ pub(crate) fn get_outgoing_webhook_request(
     merchant_account: &domain::MerchantAccount,
     outgoing_webhook: api::OutgoingWebhook,
     business_profile: &domain::Profile,
+    payload_template: Option<&serde_json::Value>,
 ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
     #[inline]
     fn get_outgoing_webhook_request_inner<WebhookType: types::OutgoingWebhookType>(
         outgoing_webhook: api::OutgoingWebhook,
         business_profile: &domain::Profile,
+        payload_template: Option<&serde_json::Value>,
     ) -> CustomResult<OutgoingWebhookRequestContent, errors::WebhooksFlowError> {
         let mut headers = vec![
             (
@@ -630,7 +711,7 @@ pub(crate) fn get_outgoing_webhook_request(
             );
         };
         let outgoing_webhooks_signature = transformed_outgoing_webhook
-            .get_outgoing_webhooks_signature(payment_response_hash_key)?;
+            .get_outgoing_webhooks_signature(payment_response_hash_key, payload_template)?;
 
         if let Some(signature) = outgoing_webhooks_signature.signature {
             WebhookType::add_webhook_header(&mut headers, signature)
@@ -647,12 +728,17 @@ pub(crate) fn get_outgoing_webhook_request(
 
     match merchant_account.get_compatible_connector() {
         #[cfg(feature = "stripe")]
-        Some(api_models::enums::Connector::Stripe) => get_outgoing_webhook_request_inner::<
-            stripe_webhooks::StripeOutgoingWebhook,
-        >(outgoing_webhook, business_profile),
+        Some(api_models::enums::Connector::Stripe) => {
+            get_outgoing_webhook_request_inner::<stripe_webhooks::StripeOutgoingWebhook>(
+                outgoing_webhook,
+                business_profile,
+                payload_template,
+            )
+        }
         _ => get_outgoing_webhook_request_inner::<webhooks::OutgoingWebhook>(
             outgoing_webhook,
             business_profile,
+            payload_template,
         ),
     }
 }
@@ -918,6 +1004,16 @@ impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
             webhooks::OutgoingWebhookContent::PayoutDetails(payout_response) => Self::Payout {
                 payout_id: payout_response.payout_id.clone(),
             },
+            webhooks::OutgoingWebhookContent::MerchantAccountDetails(merchant_account_response) => {
+                Self::MerchantAccount {
+                    merchant_id: merchant_account_response.merchant_id.clone(),
+                }
+            }
+            webhooks::OutgoingWebhookContent::ConnectorCredentialsDetails(
+                connector_credentials_response,
+            ) => Self::MerchantConnectorAccount {
+                merchant_connector_id: connector_credentials_response.merchant_connector_id.clone(),
+            },
         }
     }
 }
@@ -962,5 +1058,17 @@ fn get_outgoing_webhook_event_content_from_event_metadata(
             mandate_id,
             content: serde_json::Value::Null,
         },
+        diesel_models::EventMetadata::MerchantAccount { merchant_id } => {
+            OutgoingWebhookEventContent::MerchantAccount {
+                merchant_id,
+                content: serde_json::Value::Null,
+            }
+        }
+        diesel_models::EventMetadata::MerchantConnectorAccount {
+            merchant_connector_id,
+        } => OutgoingWebhookEventContent::MerchantConnectorAccount {
+            merchant_connector_id,
+            content: serde_json::Value::Null,
+        },
     })
 }
@@ -0,0 +1,731 @@
+//! Payload templates of the outgoing webhooks, which let a merchant receive the webhooks of an
+//! event type in the schema expected by its systems. A template is a JSON object whose strings can
+//! reference the fields of the default payload of the webhook with placeholders such as
+//! `{{ content.object.payment_id }}`, the path being the keys and the array indices leading to the
+//! field. A string made of a single placeholder is replaced by the referenced value as is, which
+//! can be an object or an array, while the placeholders within a longer string are replaced by the
+//! text of the values. A field which is not present in the default payload is rendered as null, or
+//! as an empty string within a longer string. Placeholders only reference fields, the templates
+//! cannot evaluate any expression.
+
+use std::collections::HashMap;
+
+use api_models::{
+    webhook_events::OutgoingWebhookRequestContent,
+    webhook_payload_templates::{
+        WebhookPayloadTemplateError, WebhookPayloadTemplateListResponse,
+        WebhookPayloadTemplatePreviewRequest, WebhookPayloadTemplatePreviewResponse,
+        WebhookPayloadTemplateRequest, WebhookPayloadTemplateResponse,
+        WebhookPayloadTemplateValidateRequest, WebhookPayloadTemplateValidateResponse,
+    },
+};
+use common_utils::{
+    ext_traits::{Encode, StringExt},
+    id_type,
+};
+use diesel_models::configs;
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
+use router_env::{instrument, tracing};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use time::PrimitiveDateTime;
+
+use crate::{
+    core::errors::{self, CustomResult, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    routes::SessionState,
+    services,
+    types::{domain, storage::enums},
+    utils::OptionExt,
+};
+
+const PLACEHOLDER_START: &str = "{{";
+const PLACEHOLDER_END: &str = "}}";
+/// The maximum depth of the objects and the arrays of a template
+const MAX_TEMPLATE_DEPTH: usize = 16;
+/// The maximum size of a template, in bytes of its JSON
+const MAX_TEMPLATE_SIZE: usize = 16 * 1024;
+
+/// The payload template of an event type, as stored
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayloadTemplate {
+    pub template: Value,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+type PayloadTemplates = HashMap<enums::EventType, PayloadTemplate>;
+
+impl PayloadTemplate {
+    fn to_response(&self, event_type: enums::EventType) -> WebhookPayloadTemplateResponse {
+        WebhookPayloadTemplateResponse {
+            event_type,
+            template: self.template.clone(),
+            referenced_paths: validate_template(&self.template).referenced_paths,
+            created_at: self.created_at,
+            modified_at: self.modified_at,
+        }
+    }
+}
+
+/// A part of a string of a template
+#[derive(Debug, PartialEq)]
+enum TemplatePart<'a> {
+    Text(&'a str),
+    Placeholder(Vec<&'a str>),
+}
+
+fn parse_path(placeholder: &str) -> Result<Vec<&str>, String> {
+    let path = placeholder.trim();
+    if path.is_empty() {
+        return Err("Placeholder does not reference any field".to_string());
+    }
+
+    path.split('.')
+        .map(|segment| {
+            let is_valid_segment = !segment.is_empty()
+                && segment
+                    .chars()
+                    .all(|character| character.is_ascii_alphanumeric() || character == '_');
+            is_valid_segment
+                .then_some(segment)
+                .ok_or_else(|| format!("Invalid field path `{path}` in placeholder"))
+        })
+        .collect()
+}
+
+fn parse_template_string(text: &str) -> Result<Vec<TemplatePart<'_>>, String> {
+    let mut parts = Vec::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find(PLACEHOLDER_START) {
+        if start > 0 {
+            parts.push(TemplatePart::Text(&rest[..start]));
+        }
+        let placeholder = &rest[start + PLACEHOLDER_START.len()..];
+        let end = placeholder
+            .find(PLACEHOLDER_END)
+            .ok_or_else(|| "Placeholder is not closed".to_string())?;
+        parts.push(TemplatePart::Placeholder(parse_path(&placeholder[..end])?));
+        rest = &placeholder[end + PLACEHOLDER_END.len()..];
+    }
+    if !rest.is_empty() {
+        parts.push(TemplatePart::Text(rest));
+    }
+
+    Ok(parts)
+}
+
+fn get_child_location(location: &str, key: &str) -> String {
+    if location.is_empty() {
+        key.to_string()
+    } else {
+        format!("{location}.{key}")
+    }
+}
+
+fn validate_template_value(
+    value: &Value,
+    location: &str,
+    depth: usize,
+    errors: &mut Vec<WebhookPayloadTemplateError>,
+    referenced_paths: &mut Vec<String>,
+) {
+    if depth > MAX_TEMPLATE_DEPTH {
+        errors.push(WebhookPayloadTemplateError {
+            location: location.to_string(),
+            message: format!("Template is nested deeper than {MAX_TEMPLATE_DEPTH} levels"),
+        });
+        return;
+    }
+
+    match value {
+        Value::String(text) => match parse_template_string(text) {
+            Ok(parts) => {
+                for part in parts {
+                    if let TemplatePart::Placeholder(path) = part {
+                        let path = path.join(".");
+                        if !referenced_paths.contains(&path) {
+                            referenced_paths.push(path);
+                        }
+                    }
+                }
+            }
+            Err(message) => errors.push(WebhookPayloadTemplateError {
+                location: location.to_string(),
+                message,
+            }),
+        },
+        Value::Array(values) => {
+            for (index, value) in values.iter().enumerate() {
+                validate_template_value(
+                    value,
+                    &get_child_location(location, &index.to_string()),
+                    depth + 1,
+                    errors,
+                    referenced_paths,
+                );
+            }
+        }
+        Value::Object(map) => {
+            for (key, value) in map {
+                validate_template_value(
+                    value,
+                    &get_child_location(location, key),
+                    depth + 1,
+                    errors,
+                    referenced_paths,
+                );
+            }
+        }
+        Value::Null | Value::Bool(_) | Value::Number(_) => {}
+    }
+}
+
+/// Validates the template, listing the fields of the default payload which it references
+pub fn validate_template(template: &Value) -> WebhookPayloadTemplateValidateResponse {
+    let mut errors = Vec::new();
+    let mut referenced_paths = Vec::new();
+
+    if !template.is_object() {
+        errors.push(WebhookPayloadTemplateError {
+            location: String::new(),
+            message: "Template must be a JSON object".to_string(),
+        });
+    }
+    let template_size = template.to_string().len();
+    if template_size > MAX_TEMPLATE_SIZE {
+        errors.push(WebhookPayloadTemplateError {
+            location: String::new(),
+            message: format!(
+                "Template is {template_size} bytes long, exceeding the limit of \
+                 {MAX_TEMPLATE_SIZE} bytes"
+            ),
+        });
+    }
+    validate_template_value(template, "", 0, &mut errors, &mut referenced_paths);
+
+    WebhookPayloadTemplateValidateResponse {
+        valid: errors.is_empty(),
+        errors,
+        referenced_paths,
+    }
+}
+
+fn resolve_path<'a>(payload: &'a Value, path: &[&str]) -> Option<&'a Value> {
+    path.iter().try_fold(payload, |value, segment| match value {
+        Value::Object(map) => map.get(*segment),
+        Value::Array(values) => segment
+            .parse::<usize>()
+            .ok()
+            .and_then(|index| values.get(index)),
+        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) => None,
+    })
+}
+
+fn resolve_placeholder<'a>(
+    payload: &'a Value,
+    path: &[&str],
+    unresolved_paths: &mut Vec<String>,
+) -> Option<&'a Value> {
+    let value = resolve_path(payload, path);
+    if value.is_none() {
+        let path = path.join(".");
+        if !unresolved_paths.contains(&path) {
+            unresolved_paths.push(path);
+        }
+    }
+    value
+}
+
+fn get_value_text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        Value::Null => String::new(),
+        Value::Bool(_) | Value::Number(_) | Value::Array(_) | Value::Object(_) => value.to_string(),
+    }
+}
+
+fn render_template_value(
+    template: &Value,
+    payload: &Value,
+    unresolved_paths: &mut Vec<String>,
+) -> Result<Value, String> {
+    match template {
+        Value::String(text) => match parse_template_string(text)?.as_slice() {
+            [TemplatePart::Placeholder(path)] => {
+                Ok(resolve_placeholder(payload, path, unresolved_paths)
+                    .cloned()
+                    .unwrap_or(Value::Null))
+            }
+            parts => {
+                let mut rendered_text = String::new();
+                for part in parts {
+                    match part {
+                        TemplatePart::Text(text) => rendered_text.push_str(text),
+                        TemplatePart::Placeholder(path) => {
+                            if let Some(value) =
+                                resolve_placeholder(payload, path, unresolved_paths)
+                            {
+                                rendered_text.push_str(&get_value_text(value));
+                            }
+                        }
+                    }
+                }
+                Ok(Value::String(rendered_text))
+            }
+        },
+        Value::Array(values) => values
+            .iter()
+            .map(|value| render_template_value(value, payload, unresolved_paths))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array),
+        Value::Object(map) => map
+            .iter()
+            .map(|(key, value)| {
+                render_template_value(value, payload, unresolved_paths)
+                    .map(|value| (key.clone(), value))
+            })
+            .collect::<Result<serde_json::Map<_, _>, _>>()
+            .map(Value::Object),
+        Value::Null | Value::Bool(_) | Value::Number(_) => Ok(template.clone()),
+    }
+}
+
+/// Renders the template from the default payload of a webhook, along with the referenced fields
+/// which are not present in the payload
+fn render_template(template: &Value, payload: &Value) -> Result<(Value, Vec<String>), String> {
+    let mut unresolved_paths = Vec::new();
+    let rendered_payload = render_template_value(template, payload, &mut unresolved_paths)?;
+    Ok((rendered_payload, unresolved_paths))
+}
+
+/// Renders the payload of an outgoing webhook with the template of its event type
+pub fn render_outgoing_webhook_payload(
+    outgoing_webhook: &impl Serialize,
+    template: &Value,
+) -> CustomResult<String, errors::WebhooksFlowError> {
+    let payload = serde_json::to_value(outgoing_webhook)
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
+        .attach_printable("Failed to encode outgoing webhook payload")?;
+
+    let (rendered_payload, _) = render_template(template, &payload).map_err(|message| {
+        report!(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed).attach_printable(format!(
+            "Failed to render webhook payload template: {message}"
+        ))
+    })?;
+
+    rendered_payload
+        .encode_to_string_of_json()
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
+        .attach_printable("Failed to encode rendered webhook payload")
+}
+
+fn get_invalid_template_error(
+    validation: &WebhookPayloadTemplateValidateResponse,
+) -> error_stack::Report<errors::ApiErrorResponse> {
+    let message = validation
+        .errors
+        .iter()
+        .map(|error| match error.location.as_str() {
+            "" => error.message.clone(),
+            location => format!("{location}: {}", error.message),
+        })
+        .collect::<Vec<_>>()
+        .join("; ");
+
+    report!(errors::ApiErrorResponse::InvalidRequestData {
+        message: format!("Invalid webhook payload template: {message}"),
+    })
+}
+
+/// The payload templates of the merchant, unless none was ever set
+async fn find_payload_templates(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+) -> RouterResult<Option<PayloadTemplates>> {
+    match db
+        .find_config_by_key_from_db(&merchant_id.get_webhook_payload_templates_key())
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("PayloadTemplates")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse webhook payload templates")
+            .map(Some),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch webhook payload templates")),
+    }
+}
+
+async fn store_payload_templates(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    templates: &PayloadTemplates,
+    is_existing: bool,
+) -> RouterResult<()> {
+    let key = merchant_id.get_webhook_payload_templates_key();
+    let serialized_templates = templates
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to serialize webhook payload templates")?;
+
+    if is_existing {
+        db.update_config_by_key(
+            &key,
+            configs::ConfigUpdate::Update {
+                config: Some(serialized_templates),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update webhook payload templates")?;
+    } else {
+        db.insert_config(configs::ConfigNew {
+            key,
+            config: serialized_templates,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert webhook payload templates")?;
+    }
+
+    Ok(())
+}
+
+/// The template of the event type, used to render the payload of the outgoing webhooks
+pub async fn find_payload_template(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    event_type: enums::EventType,
+) -> RouterResult<Option<Value>> {
+    // The templates are looked up for every outgoing webhook, so their absence is cached as well
+    let templates: Option<PayloadTemplates> = db
+        .find_config_by_key_unwrap_or(
+            &merchant_id.get_webhook_payload_templates_key(),
+            Some("null".to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch webhook payload templates")?
+        .config
+        .parse_struct("Option<PayloadTemplates>")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse webhook payload templates")?;
+
+    Ok(templates
+        .and_then(|mut templates| templates.remove(&event_type))
+        .map(|payload_template| payload_template.template))
+}
+
+#[instrument(skip_all)]
+pub async fn set_payload_template(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    event_type: enums::EventType,
+    req: WebhookPayloadTemplateRequest,
+) -> RouterResponse<WebhookPayloadTemplateResponse> {
+    let validation = validate_template(&req.template);
+    if !validation.valid {
+        return Err(get_invalid_template_error(&validation));
+    }
+
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+    let existing_templates = find_payload_templates(db, merchant_id).await?;
+    let is_existing = existing_templates.is_some();
+    let mut templates = existing_templates.unwrap_or_default();
+
+    let now = common_utils::date_time::now();
+    let created_at = templates
+        .get(&event_type)
+        .map_or(now, |payload_template| payload_template.created_at);
+    let payload_template = PayloadTemplate {
+        template: req.template,
+        created_at,
+        modified_at: now,
+    };
+    templates.insert(event_type, payload_template.clone());
+    store_payload_templates(db, merchant_id, &templates, is_existing).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        payload_template.to_response(event_type),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_payload_template(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    event_type: enums::EventType,
+) -> RouterResponse<WebhookPayloadTemplateResponse> {
+    let payload_template = find_payload_templates(state.store.as_ref(), merchant_account.get_id())
+        .await?
+        .and_then(|mut templates| templates.remove(&event_type))
+        .get_required_value("payload_template")
+        .change_context(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Webhook payload template not found for event type {event_type}"),
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        payload_template.to_response(event_type),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_payload_templates(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<WebhookPayloadTemplateListResponse> {
+    let mut templates = find_payload_templates(state.store.as_ref(), merchant_account.get_id())
+        .await?
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(event_type, payload_template)| payload_template.to_response(event_type))
+        .collect::<Vec<_>>();
+    templates.sort_by_key(|template| template.event_type.to_string());
+
+    Ok(services::ApplicationResponse::Json(
+        WebhookPayloadTemplateListResponse { templates },
+    ))
+}
+
+/// Deletes the template of the event type, the webhooks being sent with the default payload again
+#[instrument(skip_all)]
+pub async fn delete_payload_template(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    event_type: enums::EventType,
+) -> RouterResponse<WebhookPayloadTemplateResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+    let mut templates = find_payload_templates(db, merchant_id)
+        .await?
+        .unwrap_or_default();
+    let payload_template = templates
+        .remove(&event_type)
+        .get_required_value("payload_template")
+        .change_context(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Webhook payload template not found for event type {event_type}"),
+        })?;
+    store_payload_templates(db, merchant_id, &templates, true).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        payload_template.to_response(event_type),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn validate_payload_template(
+    req: WebhookPayloadTemplateValidateRequest,
+) -> RouterResponse<WebhookPayloadTemplateValidateResponse> {
+    Ok(services::ApplicationResponse::Json(validate_template(
+        &req.template,
+    )))
+}
+
+/// The payload of a past event, as it was delivered
+async fn get_event_payload(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    event_id: &str,
+) -> RouterResult<Value> {
+    let event = state
+        .store
+        .find_event_by_merchant_id_event_id(
+            &state.into(),
+            &key_store.merchant_id,
+            event_id,
+            key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
+
+    let request_content: OutgoingWebhookRequestContent = event
+        .request
+        .as_ref()
+        .get_required_value("request")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?
+        .peek()
+        .parse_struct("OutgoingWebhookRequestContent")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse webhook event request information")?;
+
+    request_content
+        .body
+        .peek()
+        .parse_struct("OutgoingWebhookPayload")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse webhook event payload")
+}
+
+/// Renders a template without sending any webhook, from a given default payload or from the
+/// payload of a past event
+#[instrument(skip_all)]
+pub async fn preview_payload_template(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: WebhookPayloadTemplatePreviewRequest,
+) -> RouterResponse<WebhookPayloadTemplatePreviewResponse> {
+    let template = match (req.template, req.event_type) {
+        (Some(template), _) => template,
+        (None, Some(event_type)) => {
+            find_payload_template(state.store.as_ref(), merchant_account.get_id(), event_type)
+                .await?
+                .get_required_value("payload_template")
+                .change_context(errors::ApiErrorResponse::GenericNotFoundError {
+                    message: format!(
+                        "Webhook payload template not found for event type {event_type}"
+                    ),
+                })?
+        }
+        (None, None) => {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Either template or event_type is required".to_string(),
+            }))
+        }
+    };
+
+    let validation = validate_template(&template);
+    if !validation.valid {
+        return Err(get_invalid_template_error(&validation));
+    }
+
+    let payload = match (req.payload, req.event_id) {
+        (Some(payload), None) => payload,
+        (None, Some(event_id)) => get_event_payload(&state, &key_store, &event_id).await?,
+        _ => {
+            return Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Exactly one of payload and event_id is required".to_string(),
+            }))
+        }
+    };
+
+    let (rendered_payload, unresolved_paths) =
+        render_template(&template, &payload).map_err(|message| {
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Invalid webhook payload template: {message}"),
+            })
+        })?;
+
+    Ok(services::ApplicationResponse::Json(
+        WebhookPayloadTemplatePreviewResponse {
+            rendered_payload,
+            unresolved_paths,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn get_payload() -> Value {
+        json!({
+            "merchant_id": "merchant_1",
+            "event_id": "evt_1",
+            "event_type": "payment_succeeded",
+            "content": {
+                "type": "payment_details",
+                "object": {
+                    "payment_id": "pay_1",
+                    "amount": 6540,
+                    "status": "succeeded",
+                    "customer_id": null,
+                    "refunds": [{ "refund_id": "ref_1" }],
+                    "metadata": { "order_id": "order_1" }
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn test_render_keeps_the_type_of_single_placeholders() {
+        let template = json!({
+            "id": "{{ event_id }}",
+            "payment": {
+                "reference": "{{content.object.payment_id}}",
+                "amount": "{{ content.object.amount }}",
+                "order": "{{ content.object.metadata }}",
+                "first_refund": "{{ content.object.refunds.0.refund_id }}"
+            },
+            "version": 2
+        });
+
+        let (rendered_payload, unresolved_paths) =
+            render_template(&template, &get_payload()).expect("template is valid");
+
+        assert_eq!(
+            rendered_payload,
+            json!({
+                "id": "evt_1",
+                "payment": {
+                    "reference": "pay_1",
+                    "amount": 6540,
+                    "order": { "order_id": "order_1" },
+                    "first_refund": "ref_1"
+                },
+                "version": 2
+            })
+        );
+        assert!(unresolved_paths.is_empty());
+    }
+
+    #[test]
+    fn test_render_interpolates_placeholders_within_text() {
+        let template = json!({
+            "summary": "Payment {{ content.object.payment_id }} of {{ content.object.amount }} is {{ content.object.status }}",
+            "customer": "customer:{{ content.object.customer_id }}",
+            "missing": "{{ content.object.shipping.city }}"
+        });
+
+        let (rendered_payload, unresolved_paths) =
+            render_template(&template, &get_payload()).expect("template is valid");
+
+        assert_eq!(
+            rendered_payload,
+            json!({
+                "summary": "Payment pay_1 of 6540 is succeeded",
+                "customer": "customer:",
+                "missing": null
+            })
+        );
+        assert_eq!(unresolved_paths, vec!["content.object.shipping.city"]);
+    }
+
+    #[test]
+    fn test_validate_template() {
+        let validation = validate_template(&json!({
+            "id": "{{ event_id }}",
+            "items": ["{{ content.object.payment_id }}", "{{ content.object.amount"],
+            "bad": { "path": "{{ content..object }}", "empty": "{{ }}" }
+        }));
+
+        assert!(!validation.valid);
+        let mut error_locations = validation
+            .errors
+            .iter()
+            .map(|error| error.location.as_str())
+            .collect::<Vec<_>>();
+        error_locations.sort();
+        assert_eq!(error_locations, vec!["bad.empty", "bad.path", "items.1"]);
+        let mut referenced_paths = validation.referenced_paths;
+        referenced_paths.sort();
+        assert_eq!(
+            referenced_paths,
+            vec!["content.object.payment_id", "event_id"]
+        );
+
+        assert!(!validate_template(&json!(["{{ event_id }}"])).valid);
+        assert!(validate_template(&json!({ "id": "{{ event_id }}" })).valid);
+    }
+}
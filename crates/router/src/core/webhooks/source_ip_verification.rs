@@ -0,0 +1,197 @@
+//! Verification of the addresses which the webhooks of the connectors are received from, against
+//! the IP ranges published by the connectors. This adds a layer of defense for the connectors with
+//! weak signature schemes, and is enforced for the connector accounts which opt into it. The ranges
+//! configured for a connector are replaced by the ranges held in the
+//! `webhook_source_ip_ranges_{connector}` config when present, so that they can be refreshed
+//! without a deployment whenever the connector publishes new ranges.
+
+use std::{collections::HashSet, net::IpAddr, str::FromStr};
+
+use api_models::{admin::MerchantConnectorWebhookDetails, enums};
+use common_utils::{ext_traits::ValueExt, types::IpRange};
+use error_stack::report;
+use router_env::{instrument, logger, tracing};
+
+use super::MERCHANT_ID;
+use crate::{
+    core::{errors, metrics},
+    routes::SessionState,
+    types::domain,
+};
+
+fn get_ip_ranges_config_key(connector: enums::Connector) -> String {
+    format!("webhook_source_ip_ranges_{connector}")
+}
+
+/// The IP ranges published by the connector, from the config table when present, or else from the
+/// configuration of the application
+async fn get_connector_ip_ranges(
+    state: &SessionState,
+    connector: enums::Connector,
+) -> Option<HashSet<IpRange>> {
+    match state
+        .store
+        .find_config_by_key(&get_ip_ranges_config_key(connector))
+        .await
+    {
+        Ok(config) => match serde_json::from_str::<HashSet<IpRange>>(&config.config) {
+            Ok(ip_ranges) => return Some(ip_ranges),
+            Err(error) => {
+                logger::error!(?error, %connector, "Failed to parse the webhook source IP ranges");
+            }
+        },
+        Err(error) if !error.current_context().is_db_not_found() => {
+            logger::error!(?error, %connector, "Failed to fetch the webhook source IP ranges");
+        }
+        Err(_) => {}
+    }
+
+    state
+        .conf
+        .webhook_source_ip_allowlist
+        .connectors
+        .get(&connector)
+        .map(|webhook_source_ip_ranges| webhook_source_ip_ranges.ip_ranges.clone())
+}
+
+fn is_source_ip_verification_enforced(
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+) -> bool {
+    merchant_connector_account
+        .connector_webhook_details
+        .clone()
+        .and_then(|connector_webhook_details| {
+            connector_webhook_details
+                .parse_value::<MerchantConnectorWebhookDetails>("MerchantConnectorWebhookDetails")
+                .inspect_err(|error| {
+                    logger::error!(?error, "Failed to parse the connector webhook details");
+                })
+                .ok()
+        })
+        .and_then(|connector_webhook_details| {
+            connector_webhook_details.enforce_source_ip_verification
+        })
+        .unwrap_or(false)
+}
+
+/// The address of the caller. Without trusted proxies, this is the address of the peer. Behind
+/// trusted proxies, this is the address appended to the `X-Forwarded-For` header by the outermost
+/// trusted proxy, read from the right, since the entries to its left are controlled by the caller.
+fn get_source_ip(req: &actix_web::HttpRequest, trusted_proxy_hops: usize) -> Option<IpAddr> {
+    if trusted_proxy_hops == 0 {
+        return req.peer_addr().map(|address| address.ip());
+    }
+
+    let forwarded_for = req
+        .headers()
+        .get_all("X-Forwarded-For")
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .collect::<Vec<_>>();
+    let source_ip = forwarded_for
+        .len()
+        .checked_sub(trusted_proxy_hops)
+        .and_then(|index| forwarded_for.get(index))?;
+
+    IpAddr::from_str(source_ip)
+        .or_else(|_| std::net::SocketAddr::from_str(source_ip).map(|address| address.ip()))
+        .inspect_err(|error| logger::error!(?error, "Failed to parse the webhook source IP"))
+        .ok()
+}
+
+/// Rejects the webhook when the connector account enforces the source IP verification and the
+/// webhook is not received from the IP ranges published by the connector. The webhooks of the
+/// connectors without any published IP ranges are rejected as well, as they cannot be verified.
+#[instrument(skip_all)]
+pub async fn verify_webhook_source_ip(
+    state: &SessionState,
+    req: &actix_web::HttpRequest,
+    connector: enums::Connector,
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+) -> errors::RouterResult<()> {
+    if !is_source_ip_verification_enforced(merchant_connector_account) {
+        return Ok(());
+    }
+
+    let Some(ip_ranges) = get_connector_ip_ranges(state, connector).await else {
+        logger::error!(%connector, "No webhook source IP ranges found for the connector");
+        return Err(report!(
+            errors::ApiErrorResponse::WebhookAuthenticationFailed
+        ));
+    };
+
+    let source_ip = get_source_ip(
+        req,
+        state.conf.webhook_source_ip_allowlist.trusted_proxy_hops,
+    );
+    let is_source_ip_allowed = source_ip.is_some_and(|source_ip| {
+        ip_ranges
+            .iter()
+            .any(|ip_range| ip_range.contains(source_ip))
+    });
+    if is_source_ip_allowed {
+        return Ok(());
+    }
+
+    logger::warn!(?source_ip, %connector, "Webhook received from an unknown source IP");
+    metrics::WEBHOOK_SOURCE_IP_VERIFICATION_FAILURE_COUNT.add(
+        1,
+        router_env::metric_attributes!(
+            (MERCHANT_ID, merchant_connector_account.merchant_id.clone()),
+            ("connector", connector.to_string())
+        ),
+    );
+    Err(report!(
+        errors::ApiErrorResponse::WebhookAuthenticationFailed
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_get_source_ip_without_trusted_proxies() {
+        let req = actix_web::test::TestRequest::default()
+            .peer_addr("198.51.100.7:443".parse().unwrap())
+            .to_http_request();
+        assert_eq!(
+            get_source_ip(&req, 0),
+            Some("198.51.100.7".parse().unwrap())
+        );
+
+        // The header is set by the caller, and must not be trusted
+        let req = actix_web::test::TestRequest::default()
+            .peer_addr("198.51.100.7:443".parse().unwrap())
+            .insert_header(("X-Forwarded-For", "192.0.2.17"))
+            .to_http_request();
+        assert_eq!(
+            get_source_ip(&req, 0),
+            Some("198.51.100.7".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_get_source_ip_behind_trusted_proxies() {
+        // The caller prepends a spoofed address, the trusted proxy appends the actual one
+        let req = actix_web::test::TestRequest::default()
+            .peer_addr("10.0.0.2:443".parse().unwrap())
+            .insert_header(("X-Forwarded-For", "192.0.2.17, 198.51.100.7"))
+            .to_http_request();
+        assert_eq!(
+            get_source_ip(&req, 1),
+            Some("198.51.100.7".parse().unwrap())
+        );
+        assert_eq!(get_source_ip(&req, 2), Some("192.0.2.17".parse().unwrap()));
+
+        // Fewer addresses than the trusted proxies means the request bypassed a proxy
+        assert_eq!(get_source_ip(&req, 3), None);
+
+        let req = actix_web::test::TestRequest::default()
+            .peer_addr("10.0.0.2:443".parse().unwrap())
+            .to_http_request();
+        assert_eq!(get_source_ip(&req, 1), None);
+    }
+}
@@ -4,6 +4,7 @@ use error_stack::ResultExt;
 use masking::Secret;
 use serde::Serialize;
 
+use super::payload_templates;
 use crate::{core::errors, headers, services::request::Maskable, types::storage::enums};
 
 pub struct OutgoingWebhookPayloadWithSignature {
@@ -17,8 +18,24 @@ pub trait OutgoingWebhookType:
     fn get_outgoing_webhooks_signature(
         &self,
         payment_response_hash_key: Option<impl AsRef<[u8]>>,
+        payload_template: Option<&serde_json::Value>,
     ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError>;
 
+    /// The payload of the webhook, rendered with the payload template of its event type if the
+    /// merchant has set one
+    fn get_outgoing_webhook_payload(
+        &self,
+        payload_template: Option<&serde_json::Value>,
+    ) -> errors::CustomResult<String, errors::WebhooksFlowError> {
+        match payload_template {
+            Some(template) => payload_templates::render_outgoing_webhook_payload(self, template),
+            None => self
+                .encode_to_string_of_json()
+                .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
+                .attach_printable("failed encoding outgoing webhook payload"),
+        }
+    }
+
     fn add_webhook_header(header: &mut Vec<(String, Maskable<String>)>, signature: String);
 }
 
@@ -26,11 +43,9 @@ impl OutgoingWebhookType for webhooks::OutgoingWebhook {
     fn get_outgoing_webhooks_signature(
         &self,
         payment_response_hash_key: Option<impl AsRef<[u8]>>,
+        payload_template: Option<&serde_json::Value>,
     ) -> errors::CustomResult<OutgoingWebhookPayloadWithSignature, errors::WebhooksFlowError> {
-        let webhook_signature_payload = self
-            .encode_to_string_of_json()
-            .change_context(errors::WebhooksFlowError::OutgoingWebhookEncodingFailed)
-            .attach_printable("failed encoding outgoing webhook payload")?;
+        let webhook_signature_payload = self.get_outgoing_webhook_payload(payload_template)?;
 
         let signature = payment_response_hash_key
             .map(|key| {
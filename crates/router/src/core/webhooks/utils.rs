@@ -127,6 +127,7 @@ pub async fn construct_webhook_router_data(
         connector_mandate_request_reference_id: None,
         authentication_id: None,
         psd2_sca_exemption_type: None,
+        profile_id: Some(merchant_connector_account.profile_id.clone()),
     };
     Ok(router_data)
 }
@@ -13,6 +13,8 @@ use crate::{
 
 const INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT: i64 = 100;
 const INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_DAYS: i64 = 90;
+const LIFECYCLE_EVENTS_LIST_DEFAULT_LIMIT: u16 = 20;
+const LIFECYCLE_EVENTS_LIST_MAX_LIMIT: u16 = 100;
 
 #[derive(Debug)]
 enum MerchantAccountOrProfile {
@@ -159,6 +161,102 @@ pub async fn list_initial_delivery_attempts(
     ))
 }
 
+/// Lists the lifecycle events of the merchant, regardless of whether webhooks were delivered for
+/// them, newest first, paginated with the `starting_after` cursor.
+#[instrument(skip(state))]
+pub async fn list_lifecycle_events(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    constraints: api::webhook_events::LifecycleEventListConstraints,
+) -> RouterResponse<api::webhook_events::LifecycleEventListResponse> {
+    let store = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let limit = constraints
+        .limit
+        .unwrap_or(LIFECYCLE_EVENTS_LIST_DEFAULT_LIMIT);
+    fp_utils::when(
+        limit == 0 || limit > LIFECYCLE_EVENTS_LIST_MAX_LIMIT,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "`limit` must be a number between 1 and {LIFECYCLE_EVENTS_LIST_MAX_LIMIT}"
+                ),
+            })
+        },
+    )?;
+
+    fp_utils::when(
+        constraints
+            .created_after
+            .zip(constraints.created_before)
+            .is_some_and(|(created_after, created_before)| created_after > created_before),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "The `created_after` timestamp must be an earlier timestamp compared to \
+                          the `created_before` timestamp"
+                    .to_string(),
+            })
+        },
+    )?;
+
+    let starting_after = match constraints.starting_after {
+        Some(event_id) => {
+            let cursor_event = store
+                .find_event_by_merchant_id_event_id(
+                    key_manager_state,
+                    merchant_account.get_id(),
+                    &event_id,
+                    &key_store,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "`starting_after` must be the identifier of an event".to_string(),
+                })?;
+            Some((cursor_event.created_at, cursor_event.event_id))
+        }
+        None => None,
+    };
+
+    // One more event than requested is fetched to find whether there is a next page
+    let mut events = store
+        .list_lifecycle_events_by_merchant_id_constraints(
+            key_manager_state,
+            merchant_account.get_id(),
+            constraints.profile_id,
+            constraints.event_class,
+            constraints.event_type,
+            constraints.created_after,
+            constraints.created_before,
+            starting_after,
+            i64::from(limit) + 1,
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list lifecycle events with specified constraints")?;
+
+    let has_more = events.len() > usize::from(limit);
+    events.truncate(usize::from(limit));
+    let next_cursor = has_more
+        .then(|| events.last().map(|event| event.event_id.clone()))
+        .flatten();
+
+    let data = events
+        .into_iter()
+        .map(api::webhook_events::LifecycleEventResponse::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::LifecycleEventListResponse {
+            data,
+            has_more,
+            next_cursor,
+        },
+    ))
+}
+
 #[instrument(skip(state))]
 pub async fn list_delivery_attempts(
     state: SessionState,
@@ -232,6 +330,13 @@ pub async fn retry_delivery_attempt(
         .await
         .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
 
+    // Events recorded while webhooks were disabled have no webhook request to deliver
+    fp_utils::when(event_to_retry.request.is_none(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "No webhook was sent for the specified event".to_string(),
+        })
+    })?;
+
     let business_profile_id = event_to_retry
         .business_profile_id
         .get_required_value("business_profile_id")
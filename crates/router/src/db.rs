@@ -12,6 +12,8 @@ pub mod cards_info;
 pub mod configs;
 pub mod customers;
 pub mod dashboard_metadata;
+pub mod data_residency;
+pub mod data_retention;
 pub mod dispute;
 pub mod dynamic_routing_stats;
 pub mod ephemeral_key;
@@ -21,25 +23,34 @@ pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
 pub mod health_check;
+pub mod issuer_authentication_stats;
 pub mod kafka_store;
+pub mod ledger_entry;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
 pub mod merchant_connector_account;
 pub mod merchant_key_store;
 pub mod organization;
+pub mod payment_changes;
+pub mod payment_custom_field;
 pub mod payment_link;
+pub mod payment_method_consent_event;
 pub mod payment_method_session;
+pub mod payment_note;
 pub mod refund;
 pub mod relay;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod store_credit_entry;
+pub mod test_data;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
 pub mod user_key_store;
 pub mod user_role;
+pub mod warehouse_sync;
 
 use ::payment_methods::state::PaymentMethodsStorageInterface;
 use common_utils::id_type;
@@ -112,18 +123,25 @@ pub trait StorageInterface:
     + PaymentAttemptInterface<Error = StorageError>
     + PaymentIntentInterface<Error = StorageError>
     + PaymentMethodInterface<Error = StorageError>
+    + payment_method_consent_event::PaymentMethodConsentEventInterface
+    + payment_note::PaymentNoteInterface
     + blocklist::BlocklistInterface
     + blocklist_fingerprint::BlocklistFingerprintInterface
     + dynamic_routing_stats::DynamicRoutingStatsInterface
+    + issuer_authentication_stats::IssuerAuthenticationStatsInterface
+    + ledger_entry::LedgerEntryInterface
+    + payment_custom_field::PaymentCustomFieldInterface
     + scheduler::SchedulerInterface
     + PayoutAttemptInterface<Error = StorageError>
     + PayoutsInterface<Error = StorageError>
     + refund::RefundInterface
+    + store_credit_entry::StoreCreditEntryInterface
     + reverse_lookup::ReverseLookupInterface
     + cards_info::CardsInfoInterface
     + merchant_key_store::MerchantKeyStoreInterface
     + MasterKeyInterface
     + payment_link::PaymentLinkInterface
+    + payment_changes::PaymentChangesInterface
     + RedisConnInterface
     + RequestIdStore
     + business_profile::ProfileInterface
@@ -132,6 +150,10 @@ pub trait StorageInterface:
     + unified_translations::UnifiedTranslationsInterface
     + authorization::AuthorizationInterface
     + user::sample_data::BatchSampleDataInterface
+    + test_data::TestDataInterface
+    + data_residency::DataResidencyInterface
+    + data_retention::DataRetentionInterface
+    + warehouse_sync::WarehouseSyncInterface
     + health_check::HealthCheckDbInterface
     + user_authentication_method::UserAuthenticationMethodInterface
     + authentication::AuthenticationInterface
@@ -233,6 +233,9 @@ impl ApiKeyInterface for MockDb {
             created_at: api_key.created_at,
             expires_at: api_key.expires_at,
             last_used: api_key.last_used,
+            response_version: api_key.response_version,
+            test_mode: api_key.test_mode,
+            profile_id: api_key.profile_id,
         };
         locked_api_keys.push(stored_key.clone());
 
@@ -258,6 +261,7 @@ impl ApiKeyInterface for MockDb {
                 description,
                 expires_at,
                 last_used,
+                response_version,
             } => {
                 if let Some(name) = name {
                     key_to_update.name = name;
@@ -272,6 +276,9 @@ impl ApiKeyInterface for MockDb {
                 if last_used.is_some() {
                     key_to_update.last_used = last_used
                 }
+                if let Some(response_version) = response_version {
+                    key_to_update.response_version = response_version;
+                }
             }
             storage::ApiKeyUpdate::LastUsedUpdate { last_used } => {
                 key_to_update.last_used = Some(last_used);
@@ -417,6 +424,9 @@ mod tests {
                 created_at: datetime!(2023-02-01 0:00),
                 expires_at: Some(datetime!(2023-03-01 0:00)),
                 last_used: None,
+                response_version: None,
+                test_mode: None,
+                profile_id: None,
             })
             .await
             .unwrap();
@@ -432,6 +442,9 @@ mod tests {
                 created_at: datetime!(2023-03-01 0:00),
                 expires_at: None,
                 last_used: None,
+                response_version: None,
+                test_mode: None,
+                profile_id: None,
             })
             .await
             .unwrap();
@@ -513,6 +526,9 @@ mod tests {
             created_at: datetime!(2023-06-01 0:00),
             expires_at: None,
             last_used: None,
+            response_version: None,
+            test_mode: None,
+            profile_id: None,
         };
 
         let api = db.insert_api_key(api).await.unwrap();
@@ -152,6 +152,7 @@ impl AuthenticationInterface for MockDb {
             acquirer_country_code: authentication.acquirer_country_code,
             service_details: authentication.service_details,
             organization_id: authentication.organization_id,
+            challenge_abandoned: None,
         };
         authentications.push(authentication.clone());
         Ok(authentication)
@@ -0,0 +1,303 @@
+#[cfg(feature = "v1")]
+use diesel_models::{
+    address::Address,
+    customers::Customer,
+    payment_method::PaymentMethod,
+    query::data_residency::{self as data_residency_queries, ResidencyMigrationCursor},
+};
+#[cfg(feature = "v1")]
+use error_stack::report;
+
+use crate::services::Store;
+#[cfg(feature = "v1")]
+use crate::{
+    connection::{pg_connection_read, pg_connection_write},
+    core::errors::{self, CustomResult},
+};
+
+/// Copying of the rows holding the personal data of a merchant between the databases of the
+/// deployment, as they are stored, without decrypting them
+#[async_trait::async_trait]
+pub trait DataResidencyInterface {
+    #[cfg(feature = "v1")]
+    async fn find_customers_for_residency_migration(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        modified_since: Option<time::PrimitiveDateTime>,
+        cursor: Option<ResidencyMigrationCursor>,
+        limit: i64,
+    ) -> CustomResult<Vec<Customer>, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn find_addresses_for_residency_migration(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        modified_since: Option<time::PrimitiveDateTime>,
+        cursor: Option<ResidencyMigrationCursor>,
+        limit: i64,
+    ) -> CustomResult<Vec<Address>, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn find_payment_methods_for_residency_migration(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        modified_since: Option<time::PrimitiveDateTime>,
+        cursor: Option<ResidencyMigrationCursor>,
+        limit: i64,
+    ) -> CustomResult<Vec<PaymentMethod>, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn find_customer_row_optional(
+        &self,
+        customer_id: &common_utils::id_type::CustomerId,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Option<Customer>, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn find_address_row_optional(
+        &self,
+        address_id: &str,
+    ) -> CustomResult<Option<Address>, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn insert_customer_row(
+        &self,
+        customer: Customer,
+    ) -> CustomResult<Customer, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn insert_address_row(
+        &self,
+        address: Address,
+    ) -> CustomResult<Address, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn delete_customer_row(
+        &self,
+        customer_id: &common_utils::id_type::CustomerId,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn delete_address_row(
+        &self,
+        address_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl DataResidencyInterface for Store {
+    #[cfg(feature = "v1")]
+    async fn find_customers_for_residency_migration(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        modified_since: Option<time::PrimitiveDateTime>,
+        cursor: Option<ResidencyMigrationCursor>,
+        limit: i64,
+    ) -> CustomResult<Vec<Customer>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        data_residency_queries::find_customers_for_residency_migration(
+            &conn,
+            merchant_id,
+            modified_since,
+            cursor,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_addresses_for_residency_migration(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        modified_since: Option<time::PrimitiveDateTime>,
+        cursor: Option<ResidencyMigrationCursor>,
+        limit: i64,
+    ) -> CustomResult<Vec<Address>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        data_residency_queries::find_addresses_for_residency_migration(
+            &conn,
+            merchant_id,
+            modified_since,
+            cursor,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_payment_methods_for_residency_migration(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        modified_since: Option<time::PrimitiveDateTime>,
+        cursor: Option<ResidencyMigrationCursor>,
+        limit: i64,
+    ) -> CustomResult<Vec<PaymentMethod>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        data_residency_queries::find_payment_methods_for_residency_migration(
+            &conn,
+            merchant_id,
+            modified_since,
+            cursor,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_customer_row_optional(
+        &self,
+        customer_id: &common_utils::id_type::CustomerId,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Option<Customer>, errors::StorageError> {
+        let conn = pg_connection_write(self).await?;
+        Customer::find_optional_by_customer_id_merchant_id(&conn, customer_id, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_address_row_optional(
+        &self,
+        address_id: &str,
+    ) -> CustomResult<Option<Address>, errors::StorageError> {
+        let conn = pg_connection_write(self).await?;
+        Address::find_optional_by_address_id(&conn, address_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    async fn insert_customer_row(
+        &self,
+        customer: Customer,
+    ) -> CustomResult<Customer, errors::StorageError> {
+        let conn = pg_connection_write(self).await?;
+        data_residency_queries::insert_customer(&conn, customer)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    async fn insert_address_row(
+        &self,
+        address: Address,
+    ) -> CustomResult<Address, errors::StorageError> {
+        let conn = pg_connection_write(self).await?;
+        data_residency_queries::insert_address(&conn, address)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    async fn delete_customer_row(
+        &self,
+        customer_id: &common_utils::id_type::CustomerId,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = pg_connection_write(self).await?;
+        Customer::delete_by_customer_id_merchant_id(&conn, customer_id, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    async fn delete_address_row(
+        &self,
+        address_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = pg_connection_write(self).await?;
+        Address::delete_by_address_id(&conn, address_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl DataResidencyInterface for storage_impl::MockDb {
+    #[cfg(feature = "v1")]
+    async fn find_customers_for_residency_migration(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _modified_since: Option<time::PrimitiveDateTime>,
+        _cursor: Option<ResidencyMigrationCursor>,
+        _limit: i64,
+    ) -> CustomResult<Vec<Customer>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_addresses_for_residency_migration(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _modified_since: Option<time::PrimitiveDateTime>,
+        _cursor: Option<ResidencyMigrationCursor>,
+        _limit: i64,
+    ) -> CustomResult<Vec<Address>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_payment_methods_for_residency_migration(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _modified_since: Option<time::PrimitiveDateTime>,
+        _cursor: Option<ResidencyMigrationCursor>,
+        _limit: i64,
+    ) -> CustomResult<Vec<PaymentMethod>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_customer_row_optional(
+        &self,
+        _customer_id: &common_utils::id_type::CustomerId,
+        _merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Option<Customer>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_address_row_optional(
+        &self,
+        _address_id: &str,
+    ) -> CustomResult<Option<Address>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "v1")]
+    async fn insert_customer_row(
+        &self,
+        _customer: Customer,
+    ) -> CustomResult<Customer, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "v1")]
+    async fn insert_address_row(
+        &self,
+        _address: Address,
+    ) -> CustomResult<Address, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "v1")]
+    async fn delete_customer_row(
+        &self,
+        _customer_id: &common_utils::id_type::CustomerId,
+        _merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<bool, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "v1")]
+    async fn delete_address_row(
+        &self,
+        _address_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -0,0 +1,108 @@
+#[cfg(feature = "v1")]
+use diesel_models::query::data_retention::{
+    self as data_retention_queries, AttemptRedactionCursor,
+};
+#[cfg(feature = "v1")]
+use error_stack::report;
+#[cfg(feature = "v1")]
+use hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt;
+#[cfg(feature = "v1")]
+use storage_impl::DataModelExt;
+
+use crate::services::Store;
+#[cfg(feature = "v1")]
+use crate::{
+    connection::{pg_connection_read, pg_connection_write},
+    core::errors::{self, CustomResult},
+    types::storage::enums,
+};
+
+/// Redaction of the sensitive data of the payment attempts held past their retention period
+#[async_trait::async_trait]
+pub trait DataRetentionInterface {
+    #[cfg(feature = "v1")]
+    async fn find_payment_attempts_for_redaction(
+        &self,
+        statuses: Vec<enums::AttemptStatus>,
+        modified_before: time::PrimitiveDateTime,
+        cursor: Option<AttemptRedactionCursor>,
+        limit: i64,
+    ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn redact_payment_attempt(
+        &self,
+        payment_attempt: &PaymentAttempt,
+        payment_method_data: Option<serde_json::Value>,
+    ) -> CustomResult<PaymentAttempt, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl DataRetentionInterface for Store {
+    #[cfg(feature = "v1")]
+    async fn find_payment_attempts_for_redaction(
+        &self,
+        statuses: Vec<enums::AttemptStatus>,
+        modified_before: time::PrimitiveDateTime,
+        cursor: Option<AttemptRedactionCursor>,
+        limit: i64,
+    ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        data_retention_queries::find_payment_attempts_for_redaction(
+            &conn,
+            statuses,
+            modified_before,
+            cursor,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+        .map(|payment_attempts| {
+            payment_attempts
+                .into_iter()
+                .map(PaymentAttempt::from_storage_model)
+                .collect()
+        })
+    }
+
+    #[cfg(feature = "v1")]
+    async fn redact_payment_attempt(
+        &self,
+        payment_attempt: &PaymentAttempt,
+        payment_method_data: Option<serde_json::Value>,
+    ) -> CustomResult<PaymentAttempt, errors::StorageError> {
+        let conn = pg_connection_write(self).await?;
+        data_retention_queries::redact_payment_attempt(
+            &conn,
+            &payment_attempt.merchant_id,
+            &payment_attempt.attempt_id,
+            payment_method_data,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+        .map(PaymentAttempt::from_storage_model)
+    }
+}
+
+#[async_trait::async_trait]
+impl DataRetentionInterface for storage_impl::MockDb {
+    #[cfg(feature = "v1")]
+    async fn find_payment_attempts_for_redaction(
+        &self,
+        _statuses: Vec<enums::AttemptStatus>,
+        _modified_before: time::PrimitiveDateTime,
+        _cursor: Option<AttemptRedactionCursor>,
+        _limit: i64,
+    ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "v1")]
+    async fn redact_payment_attempt(
+        &self,
+        _payment_attempt: &PaymentAttempt,
+        _payment_method_data: Option<serde_json::Value>,
+    ) -> CustomResult<PaymentAttempt, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -43,6 +43,12 @@ pub trait DisputeInterface {
         payment_id: &common_utils::id_type::PaymentId,
     ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError>;
 
+    async fn find_disputes_by_merchant_id_payment_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_ids: Vec<common_utils::id_type::PaymentId>,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError>;
+
     async fn update_dispute(
         &self,
         this: storage::Dispute,
@@ -55,6 +61,13 @@ pub trait DisputeInterface {
         profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
         time_range: &common_utils::types::TimeRange,
     ) -> CustomResult<Vec<(common_enums::enums::DisputeStatus, i64)>, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn get_payment_context_by_attempt_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        attempt_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::DisputePaymentContext>, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -125,6 +138,18 @@ impl DisputeInterface for Store {
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    #[instrument(skip_all)]
+    async fn find_disputes_by_merchant_id_payment_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_ids: Vec<common_utils::id_type::PaymentId>,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Dispute::find_by_merchant_id_payment_ids(&conn, merchant_id, payment_ids)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     #[instrument(skip_all)]
     async fn update_dispute(
         &self,
@@ -154,6 +179,19 @@ impl DisputeInterface for Store {
         .await
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn get_payment_context_by_attempt_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        attempt_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::DisputePaymentContext>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Dispute::get_payment_context_by_attempt_ids(&conn, merchant_id, attempt_ids)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -349,6 +387,20 @@ impl DisputeInterface for MockDb {
             .collect())
     }
 
+    async fn find_disputes_by_merchant_id_payment_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_ids: Vec<common_utils::id_type::PaymentId>,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        let locked_disputes = self.disputes.lock().await;
+
+        Ok(locked_disputes
+            .iter()
+            .filter(|d| d.merchant_id == *merchant_id && payment_ids.contains(&d.payment_id))
+            .cloned()
+            .collect())
+    }
+
     async fn update_dispute(
         &self,
         this: storage::Dispute,
@@ -455,6 +507,15 @@ impl DisputeInterface for MockDb {
             .into_iter()
             .collect::<Vec<(common_enums::DisputeStatus, i64)>>())
     }
+
+    #[cfg(feature = "v1")]
+    async fn get_payment_context_by_attempt_ids(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _attempt_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::DisputePaymentContext>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
 }
 
 #[cfg(test)]
@@ -56,6 +56,21 @@ where
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
 
+    #[allow(clippy::too_many_arguments)]
+    async fn list_lifecycle_events_by_merchant_id_constraints(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        event_class: Option<storage::enums::EventClass>,
+        event_type: Option<storage::enums::EventType>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        starting_after: Option<(time::PrimitiveDateTime, String)>,
+        limit: i64,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
+
     async fn list_events_by_merchant_id_initial_attempt_id(
         &self,
         state: &KeyManagerState,
@@ -225,6 +240,54 @@ impl EventInterface for Store {
         .await
     }
 
+    #[instrument(skip_all)]
+    #[allow(clippy::too_many_arguments)]
+    async fn list_lifecycle_events_by_merchant_id_constraints(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        event_class: Option<storage::enums::EventClass>,
+        event_type: Option<storage::enums::EventType>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        starting_after: Option<(time::PrimitiveDateTime, String)>,
+        limit: i64,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::Event::list_lifecycle_events_by_merchant_id_constraints(
+            &conn,
+            merchant_id,
+            profile_id,
+            event_class,
+            event_type,
+            created_after,
+            created_before,
+            starting_after,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+        .async_and_then(|events| async {
+            let mut domain_events = Vec::with_capacity(events.len());
+            for event in events.into_iter() {
+                domain_events.push(
+                    event
+                        .convert(
+                            state,
+                            merchant_key_store.key.get_inner(),
+                            merchant_key_store.merchant_id.clone().into(),
+                        )
+                        .await
+                        .change_context(errors::StorageError::DecryptionError)?,
+                );
+            }
+            Ok(domain_events)
+        })
+        .await
+    }
+
     #[instrument(skip_all)]
     async fn list_events_by_merchant_id_initial_attempt_id(
         &self,
@@ -538,6 +601,67 @@ impl EventInterface for MockDb {
         Ok(domain_events)
     }
 
+    #[allow(clippy::too_many_arguments)]
+    async fn list_lifecycle_events_by_merchant_id_constraints(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<common_utils::id_type::ProfileId>,
+        event_class: Option<storage::enums::EventClass>,
+        event_type: Option<storage::enums::EventType>,
+        created_after: Option<time::PrimitiveDateTime>,
+        created_before: Option<time::PrimitiveDateTime>,
+        starting_after: Option<(time::PrimitiveDateTime, String)>,
+        limit: i64,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        let locked_events = self.events.lock().await;
+        let mut events = locked_events
+            .iter()
+            .filter(|event| {
+                event.merchant_id == Some(merchant_id.to_owned())
+                    && (event.initial_attempt_id.is_none()
+                        || event.initial_attempt_id.as_ref() == Some(&event.event_id))
+                    && profile_id.as_ref().map_or(true, |profile_id| {
+                        event.business_profile_id.as_ref() == Some(profile_id)
+                    })
+                    && event_class.map_or(true, |event_class| event.event_class == event_class)
+                    && event_type.map_or(true, |event_type| event.event_type == event_type)
+                    && created_after.map_or(true, |created_after| event.created_at >= created_after)
+                    && created_before
+                        .map_or(true, |created_before| event.created_at <= created_before)
+                    && starting_after.as_ref().map_or(
+                        true,
+                        |(cursor_created_at, cursor_event_id)| {
+                            (event.created_at, &event.event_id)
+                                < (*cursor_created_at, cursor_event_id)
+                        },
+                    )
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        events.sort_by(|a, b| (b.created_at, &b.event_id).cmp(&(a.created_at, &a.event_id)));
+
+        let limit: usize = limit
+            .try_into()
+            .map_err(|_| errors::StorageError::MockDbError)?;
+        let mut domain_events = Vec::with_capacity(limit.min(events.len()));
+
+        for event in events.into_iter().take(limit) {
+            let domain_event = event
+                .convert(
+                    state,
+                    merchant_key_store.key.get_inner(),
+                    merchant_key_store.merchant_id.clone().into(),
+                )
+                .await
+                .change_context(errors::StorageError::DecryptionError)?;
+            domain_events.push(domain_event);
+        }
+
+        Ok(domain_events)
+    }
+
     async fn list_events_by_merchant_id_initial_attempt_id(
         &self,
         state: &KeyManagerState,
@@ -0,0 +1,90 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait IssuerAuthenticationStatsInterface {
+    async fn upsert_issuer_authentication_stats(
+        &self,
+        issuer_authentication_stats: storage::IssuerAuthenticationStatsNew,
+    ) -> CustomResult<storage::IssuerAuthenticationStats, errors::StorageError>;
+
+    async fn find_issuer_authentication_stats_optional_by_card_bin(
+        &self,
+        card_bin: &str,
+    ) -> CustomResult<Option<storage::IssuerAuthenticationStats>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl IssuerAuthenticationStatsInterface for Store {
+    #[instrument(skip_all)]
+    async fn upsert_issuer_authentication_stats(
+        &self,
+        issuer_authentication_stats: storage::IssuerAuthenticationStatsNew,
+    ) -> CustomResult<storage::IssuerAuthenticationStats, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        issuer_authentication_stats
+            .upsert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_issuer_authentication_stats_optional_by_card_bin(
+        &self,
+        card_bin: &str,
+    ) -> CustomResult<Option<storage::IssuerAuthenticationStats>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::IssuerAuthenticationStats::find_optional_by_card_bin(&conn, card_bin)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl IssuerAuthenticationStatsInterface for MockDb {
+    async fn upsert_issuer_authentication_stats(
+        &self,
+        _issuer_authentication_stats: storage::IssuerAuthenticationStatsNew,
+    ) -> CustomResult<storage::IssuerAuthenticationStats, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_issuer_authentication_stats_optional_by_card_bin(
+        &self,
+        _card_bin: &str,
+    ) -> CustomResult<Option<storage::IssuerAuthenticationStats>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl IssuerAuthenticationStatsInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn upsert_issuer_authentication_stats(
+        &self,
+        issuer_authentication_stats: storage::IssuerAuthenticationStatsNew,
+    ) -> CustomResult<storage::IssuerAuthenticationStats, errors::StorageError> {
+        self.diesel_store
+            .upsert_issuer_authentication_stats(issuer_authentication_stats)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_issuer_authentication_stats_optional_by_card_bin(
+        &self,
+        card_bin: &str,
+    ) -> CustomResult<Option<storage::IssuerAuthenticationStats>, errors::StorageError> {
+        self.diesel_store
+            .find_issuer_authentication_stats_optional_by_card_bin(card_bin)
+            .await
+    }
+}
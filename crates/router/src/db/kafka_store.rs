@@ -64,6 +64,8 @@ use crate::{
         cards_info::CardsInfoInterface,
         configs::ConfigInterface,
         customers::CustomerInterface,
+        data_residency::DataResidencyInterface,
+        data_retention::DataRetentionInterface,
         dispute::DisputeInterface,
         ephemeral_key::EphemeralKeyInterface,
         events::EventInterface,
@@ -76,11 +78,14 @@ use crate::{
         merchant_account::MerchantAccountInterface,
         merchant_connector_account::{ConnectorAccessToken, MerchantConnectorAccountInterface},
         merchant_key_store::MerchantKeyStoreInterface,
+        payment_changes::PaymentChangesInterface,
         payment_link::PaymentLinkInterface,
         refund::RefundInterface,
         reverse_lookup::ReverseLookupInterface,
         routing_algorithm::RoutingAlgorithmInterface,
+        test_data::TestDataInterface,
         unified_translations::UnifiedTranslationsInterface,
+        warehouse_sync::WarehouseSyncInterface,
         AccountsStorageInterface, CommonStorageInterface, GlobalStorageInterface,
         MasterKeyInterface, StorageInterface,
     },
@@ -654,6 +659,16 @@ impl DisputeInterface for KafkaStore {
             .await
     }
 
+    async fn find_disputes_by_merchant_id_payment_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_ids: Vec<id_type::PaymentId>,
+    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        self.diesel_store
+            .find_disputes_by_merchant_id_payment_ids(merchant_id, payment_ids)
+            .await
+    }
+
     async fn get_dispute_status_with_count(
         &self,
         merchant_id: &id_type::MerchantId,
@@ -664,6 +679,17 @@ impl DisputeInterface for KafkaStore {
             .get_dispute_status_with_count(merchant_id, profile_id_list, time_range)
             .await
     }
+
+    #[cfg(feature = "v1")]
+    async fn get_payment_context_by_attempt_ids(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        attempt_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::DisputePaymentContext>, errors::StorageError> {
+        self.diesel_store
+            .get_payment_context_by_attempt_ids(merchant_id, attempt_ids)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -787,6 +813,35 @@ impl EventInterface for KafkaStore {
             .await
     }
 
+    async fn list_lifecycle_events_by_merchant_id_constraints(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        profile_id: Option<id_type::ProfileId>,
+        event_class: Option<enums::EventClass>,
+        event_type: Option<enums::EventType>,
+        created_after: Option<PrimitiveDateTime>,
+        created_before: Option<PrimitiveDateTime>,
+        starting_after: Option<(PrimitiveDateTime, String)>,
+        limit: i64,
+        merchant_key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
+        self.diesel_store
+            .list_lifecycle_events_by_merchant_id_constraints(
+                state,
+                merchant_id,
+                profile_id,
+                event_class,
+                event_type,
+                created_after,
+                created_before,
+                starting_after,
+                limit,
+                merchant_key_store,
+            )
+            .await
+    }
+
     async fn list_events_by_merchant_id_initial_attempt_id(
         &self,
         state: &KeyManagerState,
@@ -1887,6 +1942,26 @@ impl PaymentIntentInterface for KafkaStore {
             .await
     }
 
+    #[cfg(feature = "v1")]
+    async fn find_payment_intent_by_payment_id_merchant_id_from_master(
+        &self,
+        state: &KeyManagerState,
+        payment_id: &id_type::PaymentId,
+        merchant_id: &id_type::MerchantId,
+        key_store: &domain::MerchantKeyStore,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<storage::PaymentIntent, errors::StorageError> {
+        self.diesel_store
+            .find_payment_intent_by_payment_id_merchant_id_from_master(
+                state,
+                payment_id,
+                merchant_id,
+                key_store,
+                storage_scheme,
+            )
+            .await
+    }
+
     #[cfg(feature = "v2")]
     async fn find_payment_intent_by_id(
         &self,
@@ -2569,6 +2644,16 @@ impl ProcessTrackerInterface for KafkaStore {
         self.diesel_store.update_process(this, process).await
     }
 
+    async fn update_process_if_status_unchanged(
+        &self,
+        this: storage::ProcessTracker,
+        process: storage::ProcessTrackerUpdate,
+    ) -> CustomResult<storage::ProcessTracker, errors::StorageError> {
+        self.diesel_store
+            .update_process_if_status_unchanged(this, process)
+            .await
+    }
+
     async fn process_tracker_update_process_status_by_ids(
         &self,
         task_ids: Vec<String>,
@@ -2622,6 +2707,49 @@ impl ProcessTrackerInterface for KafkaStore {
             .find_processes_by_time_status(time_lower_limit, time_upper_limit, status, limit)
             .await
     }
+
+    async fn find_merchant_processes_by_time_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        time_lower_limit: PrimitiveDateTime,
+        time_upper_limit: PrimitiveDateTime,
+        status: ProcessTrackerStatus,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        self.diesel_store
+            .find_merchant_processes_by_time_status(
+                merchant_id,
+                time_lower_limit,
+                time_upper_limit,
+                status,
+                limit,
+            )
+            .await
+    }
+
+    async fn find_processes_by_runner_status(
+        &self,
+        runner: storage::ProcessTrackerRunner,
+        statuses: Vec<ProcessTrackerStatus>,
+        business_statuses: Option<Vec<String>>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        self.diesel_store
+            .find_processes_by_runner_status(runner, statuses, business_statuses, limit, offset)
+            .await
+    }
+
+    async fn get_processes_count_by_runner_status(
+        &self,
+        runner: storage::ProcessTrackerRunner,
+        statuses: Vec<ProcessTrackerStatus>,
+        business_statuses: Option<Vec<String>>,
+    ) -> CustomResult<(i64, Option<PrimitiveDateTime>), errors::StorageError> {
+        self.diesel_store
+            .get_processes_count_by_runner_status(runner, statuses, business_statuses)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -2862,6 +2990,19 @@ impl RefundInterface for KafkaStore {
             .get_total_count_of_refunds(merchant_id, refund_details, storage_scheme)
             .await
     }
+
+    #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "refunds_v2")))]
+    async fn find_refunds_by_status_created_before(
+        &self,
+        refund_status: enums::RefundStatus,
+        created_before: PrimitiveDateTime,
+        cursor: Option<diesel_models::refund::RefundCreationCursor>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::Refund>, errors::StorageError> {
+        self.diesel_store
+            .find_refunds_by_status_created_before(refund_status, created_before, cursor, limit)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -3205,6 +3346,16 @@ impl UnifiedTranslationsInterface for KafkaStore {
             .await
     }
 
+    async fn find_translation_by_unified_code_locale(
+        &self,
+        unified_code: String,
+        locale: String,
+    ) -> CustomResult<String, errors::StorageError> {
+        self.diesel_store
+            .find_translation_by_unified_code_locale(unified_code, locale)
+            .await
+    }
+
     async fn update_translation(
         &self,
         unified_code: String,
@@ -3679,6 +3830,277 @@ impl BatchSampleDataInterface for KafkaStore {
     }
 }
 
+#[async_trait::async_trait]
+impl TestDataInterface for KafkaStore {
+    #[cfg(feature = "v1")]
+    async fn delete_test_payments(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        profile_id: &id_type::ProfileId,
+        created_before: PrimitiveDateTime,
+        batch_size: i64,
+        key_store: &hyperswitch_domain_models::merchant_key_store::MerchantKeyStore,
+    ) -> CustomResult<super::test_data::TestPaymentsDeletion, storage_impl::errors::StorageError>
+    {
+        let deletion = self
+            .diesel_store
+            .delete_test_payments(
+                state,
+                merchant_id,
+                profile_id,
+                created_before,
+                batch_size,
+                key_store,
+            )
+            .await?;
+
+        for payment_intent in deletion.payment_intents.iter() {
+            let _ = self
+                .kafka_producer
+                .log_payment_intent_delete(payment_intent, self.tenant_id.clone())
+                .await;
+        }
+        for payment_attempt in deletion.payment_attempts.iter() {
+            let _ = self
+                .kafka_producer
+                .log_payment_attempt_delete(payment_attempt, self.tenant_id.clone())
+                .await;
+        }
+        for refund in deletion.refunds.iter() {
+            let _ = self
+                .kafka_producer
+                .log_refund_delete(refund, self.tenant_id.clone())
+                .await;
+        }
+        Ok(deletion)
+    }
+}
+
+#[async_trait::async_trait]
+impl DataResidencyInterface for KafkaStore {
+    #[cfg(feature = "v1")]
+    async fn find_customers_for_residency_migration(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        modified_since: Option<PrimitiveDateTime>,
+        cursor: Option<diesel_models::query::data_residency::ResidencyMigrationCursor>,
+        limit: i64,
+    ) -> CustomResult<Vec<diesel_models::customers::Customer>, errors::StorageError> {
+        self.diesel_store
+            .find_customers_for_residency_migration(merchant_id, modified_since, cursor, limit)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_addresses_for_residency_migration(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        modified_since: Option<PrimitiveDateTime>,
+        cursor: Option<diesel_models::query::data_residency::ResidencyMigrationCursor>,
+        limit: i64,
+    ) -> CustomResult<Vec<diesel_models::address::Address>, errors::StorageError> {
+        self.diesel_store
+            .find_addresses_for_residency_migration(merchant_id, modified_since, cursor, limit)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_payment_methods_for_residency_migration(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        modified_since: Option<PrimitiveDateTime>,
+        cursor: Option<diesel_models::query::data_residency::ResidencyMigrationCursor>,
+        limit: i64,
+    ) -> CustomResult<Vec<diesel_models::payment_method::PaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_methods_for_residency_migration(
+                merchant_id,
+                modified_since,
+                cursor,
+                limit,
+            )
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_customer_row_optional(
+        &self,
+        customer_id: &id_type::CustomerId,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<Option<diesel_models::customers::Customer>, errors::StorageError> {
+        self.diesel_store
+            .find_customer_row_optional(customer_id, merchant_id)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_address_row_optional(
+        &self,
+        address_id: &str,
+    ) -> CustomResult<Option<diesel_models::address::Address>, errors::StorageError> {
+        self.diesel_store
+            .find_address_row_optional(address_id)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn insert_customer_row(
+        &self,
+        customer: diesel_models::customers::Customer,
+    ) -> CustomResult<diesel_models::customers::Customer, errors::StorageError> {
+        self.diesel_store.insert_customer_row(customer).await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn insert_address_row(
+        &self,
+        address: diesel_models::address::Address,
+    ) -> CustomResult<diesel_models::address::Address, errors::StorageError> {
+        self.diesel_store.insert_address_row(address).await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn delete_customer_row(
+        &self,
+        customer_id: &id_type::CustomerId,
+        merchant_id: &id_type::MerchantId,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_customer_row(customer_id, merchant_id)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn delete_address_row(
+        &self,
+        address_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store.delete_address_row(address_id).await
+    }
+}
+
+#[async_trait::async_trait]
+impl DataRetentionInterface for KafkaStore {
+    #[cfg(feature = "v1")]
+    async fn find_payment_attempts_for_redaction(
+        &self,
+        statuses: Vec<enums::AttemptStatus>,
+        modified_before: PrimitiveDateTime,
+        cursor: Option<diesel_models::query::data_retention::AttemptRedactionCursor>,
+        limit: i64,
+    ) -> CustomResult<
+        Vec<hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt>,
+        storage_impl::errors::StorageError,
+    > {
+        self.diesel_store
+            .find_payment_attempts_for_redaction(statuses, modified_before, cursor, limit)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn redact_payment_attempt(
+        &self,
+        payment_attempt: &hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt,
+        payment_method_data: Option<serde_json::Value>,
+    ) -> CustomResult<
+        hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt,
+        storage_impl::errors::StorageError,
+    > {
+        let attempt = self
+            .diesel_store
+            .redact_payment_attempt(payment_attempt, payment_method_data)
+            .await?;
+
+        // The negative event cancels the attempt logged before the redaction, without sending its
+        // sensitive data again
+        let negative_event = hyperswitch_domain_models::payments::payment_attempt::PaymentAttempt {
+            payment_method_data: attempt.payment_method_data.clone(),
+            encoded_data: attempt.encoded_data.clone(),
+            ..payment_attempt.clone()
+        };
+        if let Err(er) = self
+            .kafka_producer
+            .log_payment_attempt(&attempt, Some(negative_event), self.tenant_id.clone())
+            .await
+        {
+            logger::error!(message="Failed to log analytics event for payment attempt {attempt:?}", error_message=?er)
+        }
+
+        Ok(attempt)
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentChangesInterface for KafkaStore {
+    #[cfg(feature = "v1")]
+    async fn find_payment_intents_changed_after(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        profile_id: Option<&id_type::ProfileId>,
+        change_sequence: i64,
+        modified_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<diesel_models::PaymentIntent>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_intents_changed_after(
+                merchant_id,
+                profile_id,
+                change_sequence,
+                modified_before,
+                limit,
+            )
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl WarehouseSyncInterface for KafkaStore {
+    #[cfg(feature = "v1")]
+    async fn find_payment_intents_modified_after(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        modified_after: Option<(PrimitiveDateTime, id_type::PaymentId)>,
+        modified_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<diesel_models::PaymentIntent>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_intents_modified_after(
+                merchant_id,
+                modified_after,
+                modified_before,
+                limit,
+            )
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_refunds_modified_after(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        modified_after: Option<(PrimitiveDateTime, String)>,
+        modified_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<diesel_models::Refund>, errors::StorageError> {
+        self.diesel_store
+            .find_refunds_modified_after(merchant_id, modified_after, modified_before, limit)
+            .await
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_disputes_modified_after(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        modified_after: Option<(PrimitiveDateTime, String)>,
+        modified_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<diesel_models::Dispute>, errors::StorageError> {
+        self.diesel_store
+            .find_disputes_modified_after(merchant_id, modified_after, modified_before, limit)
+            .await
+    }
+}
+
 #[async_trait::async_trait]
 impl AuthorizationInterface for KafkaStore {
     async fn insert_authorization(
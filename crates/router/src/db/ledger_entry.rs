@@ -0,0 +1,138 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait LedgerEntryInterface {
+    async fn insert_ledger_entry_if_absent(
+        &self,
+        ledger_entry: storage::LedgerEntryNew,
+    ) -> CustomResult<Option<storage::LedgerEntry>, errors::StorageError>;
+
+    async fn find_ledger_entries_by_merchant_id_dispute_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        dispute_id: &str,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError>;
+
+    async fn get_ledger_total_amount_by_currency_and_entry_type(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+    ) -> CustomResult<Vec<(enums::Currency, enums::LedgerEntryType, i64)>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl LedgerEntryInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_ledger_entry_if_absent(
+        &self,
+        ledger_entry: storage::LedgerEntryNew,
+    ) -> CustomResult<Option<storage::LedgerEntry>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        ledger_entry
+            .insert_if_absent(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_ledger_entries_by_merchant_id_dispute_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        dispute_id: &str,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::LedgerEntry::find_by_merchant_id_dispute_id(&conn, merchant_id, dispute_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn get_ledger_total_amount_by_currency_and_entry_type(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+    ) -> CustomResult<Vec<(enums::Currency, enums::LedgerEntryType, i64)>, errors::StorageError>
+    {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::LedgerEntry::get_total_amount_by_currency_and_entry_type(
+            &conn,
+            merchant_id,
+            profile_id_list,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl LedgerEntryInterface for MockDb {
+    async fn insert_ledger_entry_if_absent(
+        &self,
+        _ledger_entry: storage::LedgerEntryNew,
+    ) -> CustomResult<Option<storage::LedgerEntry>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_ledger_entries_by_merchant_id_dispute_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _dispute_id: &str,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn get_ledger_total_amount_by_currency_and_entry_type(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+    ) -> CustomResult<Vec<(enums::Currency, enums::LedgerEntryType, i64)>, errors::StorageError>
+    {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl LedgerEntryInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_ledger_entry_if_absent(
+        &self,
+        ledger_entry: storage::LedgerEntryNew,
+    ) -> CustomResult<Option<storage::LedgerEntry>, errors::StorageError> {
+        self.diesel_store
+            .insert_ledger_entry_if_absent(ledger_entry)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_ledger_entries_by_merchant_id_dispute_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        dispute_id: &str,
+    ) -> CustomResult<Vec<storage::LedgerEntry>, errors::StorageError> {
+        self.diesel_store
+            .find_ledger_entries_by_merchant_id_dispute_id(merchant_id, dispute_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn get_ledger_total_amount_by_currency_and_entry_type(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
+    ) -> CustomResult<Vec<(enums::Currency, enums::LedgerEntryType, i64)>, errors::StorageError>
+    {
+        self.diesel_store
+            .get_ledger_total_amount_by_currency_and_entry_type(merchant_id, profile_id_list)
+            .await
+    }
+}
@@ -0,0 +1,67 @@
+#[cfg(feature = "v1")]
+use diesel_models::PaymentIntent;
+#[cfg(feature = "v1")]
+use error_stack::report;
+#[cfg(feature = "v1")]
+use time::PrimitiveDateTime;
+
+use crate::services::Store;
+#[cfg(feature = "v1")]
+use crate::{
+    connection::pg_connection_read,
+    core::errors::{self, CustomResult},
+};
+
+/// Reads of the payments of a merchant in the order in which their status changed
+#[async_trait::async_trait]
+pub trait PaymentChangesInterface {
+    #[cfg(feature = "v1")]
+    async fn find_payment_intents_changed_after(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<&common_utils::id_type::ProfileId>,
+        change_sequence: i64,
+        modified_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentChangesInterface for Store {
+    #[cfg(feature = "v1")]
+    async fn find_payment_intents_changed_after(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<&common_utils::id_type::ProfileId>,
+        change_sequence: i64,
+        modified_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        PaymentIntent::find_by_merchant_id_changed_after(
+            &conn,
+            merchant_id,
+            profile_id,
+            change_sequence,
+            modified_before,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentChangesInterface for storage_impl::MockDb {
+    #[cfg(feature = "v1")]
+    async fn find_payment_intents_changed_after(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _profile_id: Option<&common_utils::id_type::ProfileId>,
+        _change_sequence: i64,
+        _modified_before: PrimitiveDateTime,
+        _limit: i64,
+    ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -0,0 +1,97 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentCustomFieldInterface {
+    async fn insert_payment_custom_fields(
+        &self,
+        payment_custom_fields: Vec<storage::PaymentCustomFieldNew>,
+    ) -> CustomResult<(), errors::StorageError>;
+
+    async fn find_payment_custom_fields_by_merchant_id_payment_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_ids: Vec<common_utils::id_type::PaymentId>,
+    ) -> CustomResult<Vec<storage::PaymentCustomField>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentCustomFieldInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payment_custom_fields(
+        &self,
+        payment_custom_fields: Vec<storage::PaymentCustomFieldNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PaymentCustomFieldNew::batch_insert(payment_custom_fields, &conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_custom_fields_by_merchant_id_payment_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_ids: Vec<common_utils::id_type::PaymentId>,
+    ) -> CustomResult<Vec<storage::PaymentCustomField>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentCustomField::find_by_merchant_id_payment_ids(
+            &conn,
+            merchant_id,
+            payment_ids,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentCustomFieldInterface for MockDb {
+    async fn insert_payment_custom_fields(
+        &self,
+        _payment_custom_fields: Vec<storage::PaymentCustomFieldNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payment_custom_fields_by_merchant_id_payment_ids(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_ids: Vec<common_utils::id_type::PaymentId>,
+    ) -> CustomResult<Vec<storage::PaymentCustomField>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentCustomFieldInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_payment_custom_fields(
+        &self,
+        payment_custom_fields: Vec<storage::PaymentCustomFieldNew>,
+    ) -> CustomResult<(), errors::StorageError> {
+        self.diesel_store
+            .insert_payment_custom_fields(payment_custom_fields)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_custom_fields_by_merchant_id_payment_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_ids: Vec<common_utils::id_type::PaymentId>,
+    ) -> CustomResult<Vec<storage::PaymentCustomField>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_custom_fields_by_merchant_id_payment_ids(merchant_id, payment_ids)
+            .await
+    }
+}
@@ -0,0 +1,101 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentMethodConsentEventInterface {
+    async fn insert_payment_method_consent_event(
+        &self,
+        event: storage::PaymentMethodConsentEventNew,
+    ) -> CustomResult<storage::PaymentMethodConsentEvent, errors::StorageError>;
+
+    async fn find_payment_method_consent_events_by_merchant_id_payment_method_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::PaymentMethodConsentEvent>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentMethodConsentEventInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payment_method_consent_event(
+        &self,
+        event: storage::PaymentMethodConsentEventNew,
+    ) -> CustomResult<storage::PaymentMethodConsentEvent, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        event
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_method_consent_events_by_merchant_id_payment_method_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::PaymentMethodConsentEvent>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentMethodConsentEvent::find_by_merchant_id_payment_method_ids(
+            &conn,
+            merchant_id,
+            payment_method_ids,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentMethodConsentEventInterface for MockDb {
+    async fn insert_payment_method_consent_event(
+        &self,
+        _event: storage::PaymentMethodConsentEventNew,
+    ) -> CustomResult<storage::PaymentMethodConsentEvent, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payment_method_consent_events_by_merchant_id_payment_method_ids(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_method_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::PaymentMethodConsentEvent>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentMethodConsentEventInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_payment_method_consent_event(
+        &self,
+        event: storage::PaymentMethodConsentEventNew,
+    ) -> CustomResult<storage::PaymentMethodConsentEvent, errors::StorageError> {
+        self.diesel_store
+            .insert_payment_method_consent_event(event)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_method_consent_events_by_merchant_id_payment_method_ids(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_ids: Vec<String>,
+    ) -> CustomResult<Vec<storage::PaymentMethodConsentEvent>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_method_consent_events_by_merchant_id_payment_method_ids(
+                merchant_id,
+                payment_method_ids,
+            )
+            .await
+    }
+}
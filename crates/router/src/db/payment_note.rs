@@ -0,0 +1,91 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentNoteInterface {
+    async fn insert_payment_note(
+        &self,
+        note: storage::PaymentNoteNew,
+    ) -> CustomResult<storage::PaymentNote, errors::StorageError>;
+
+    async fn find_payment_notes_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::PaymentNote>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentNoteInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payment_note(
+        &self,
+        note: storage::PaymentNoteNew,
+    ) -> CustomResult<storage::PaymentNote, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        note.insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_notes_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::PaymentNote>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentNote::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentNoteInterface for MockDb {
+    async fn insert_payment_note(
+        &self,
+        _note: storage::PaymentNoteNew,
+    ) -> CustomResult<storage::PaymentNote, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payment_notes_by_merchant_id_payment_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::PaymentNote>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentNoteInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_payment_note(
+        &self,
+        note: storage::PaymentNoteNew,
+    ) -> CustomResult<storage::PaymentNote, errors::StorageError> {
+        self.diesel_store.insert_payment_note(note).await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_notes_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::PaymentNote>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_notes_by_merchant_id_payment_id(merchant_id, payment_id)
+            .await
+    }
+}
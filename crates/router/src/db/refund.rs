@@ -128,6 +128,17 @@ pub trait RefundInterface {
         refund_details: &refunds::RefundListConstraints,
         storage_scheme: enums::MerchantStorageScheme,
     ) -> CustomResult<i64, errors::StorageError>;
+
+    /// Finds the refunds of all the merchants in the given status which were created before the
+    /// given time, in the order of their creation
+    #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "refunds_v2")))]
+    async fn find_refunds_by_status_created_before(
+        &self,
+        refund_status: enums::RefundStatus,
+        created_before: time::PrimitiveDateTime,
+        cursor: Option<diesel_models::refund::RefundCreationCursor>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError>;
 }
 
 #[cfg(not(feature = "kv_store"))]
@@ -321,6 +332,27 @@ mod storage {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
         }
+
+        #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "refunds_v2")))]
+        #[instrument(skip_all)]
+        async fn find_refunds_by_status_created_before(
+            &self,
+            refund_status: enums::RefundStatus,
+            created_before: time::PrimitiveDateTime,
+            cursor: Option<diesel_models::refund::RefundCreationCursor>,
+            limit: i64,
+        ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Refund::find_by_status_created_before(
+                &conn,
+                refund_status,
+                created_before,
+                cursor,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
     }
 }
 
@@ -471,6 +503,8 @@ mod storage {
                         processor_transaction_data: new.processor_transaction_data.clone(),
                         issuer_error_code: None,
                         issuer_error_message: None,
+                        end_to_end_reference: new.end_to_end_reference.clone(),
+                        refund_destination: new.refund_destination,
                         // Below fields are deprecated. Please add any new fields above this line.
                         connector_refund_data: None,
                         connector_transaction_data: None,
@@ -966,6 +1000,27 @@ mod storage {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
         }
+
+        #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "refunds_v2")))]
+        #[instrument(skip_all)]
+        async fn find_refunds_by_status_created_before(
+            &self,
+            refund_status: enums::RefundStatus,
+            created_before: time::PrimitiveDateTime,
+            cursor: Option<diesel_models::refund::RefundCreationCursor>,
+            limit: i64,
+        ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError> {
+            let conn = connection::pg_connection_read(self).await?;
+            storage_types::Refund::find_by_status_created_before(
+                &conn,
+                refund_status,
+                created_before,
+                cursor,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
     }
 }
 
@@ -1036,6 +1091,8 @@ impl RefundInterface for MockDb {
             processor_transaction_data: new.processor_transaction_data.clone(),
             issuer_error_code: None,
             issuer_error_message: None,
+            end_to_end_reference: new.end_to_end_reference,
+            refund_destination: new.refund_destination,
             // Below fields are deprecated. Please add any new fields above this line.
             connector_refund_data: None,
             connector_transaction_data: None,
@@ -1570,4 +1627,45 @@ impl RefundInterface for MockDb {
 
         Ok(filtered_refunds_count)
     }
+
+    #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "refunds_v2")))]
+    async fn find_refunds_by_status_created_before(
+        &self,
+        refund_status: enums::RefundStatus,
+        created_before: time::PrimitiveDateTime,
+        cursor: Option<diesel_models::refund::RefundCreationCursor>,
+        limit: i64,
+    ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError> {
+        let refunds = self.refunds.lock().await;
+        let mut refunds = refunds
+            .iter()
+            .filter(|refund| {
+                refund.refund_status == refund_status && refund.created_at < created_before
+            })
+            .filter(|refund| {
+                cursor.as_ref().map_or(true, |cursor| {
+                    (
+                        refund.created_at,
+                        refund.merchant_id.get_string_repr(),
+                        &refund.refund_id,
+                    ) > (
+                        cursor.created_at,
+                        cursor.merchant_id.get_string_repr(),
+                        &cursor.refund_id,
+                    )
+                })
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        refunds.sort_by(|a, b| {
+            (a.created_at, a.merchant_id.get_string_repr(), &a.refund_id).cmp(&(
+                b.created_at,
+                b.merchant_id.get_string_repr(),
+                &b.refund_id,
+            ))
+        });
+        refunds.truncate(usize::try_from(limit).unwrap_or_default());
+
+        Ok(refunds)
+    }
 }
@@ -0,0 +1,195 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait StoreCreditEntryInterface {
+    async fn insert_store_credit_entry_if_absent(
+        &self,
+        store_credit_entry: storage::StoreCreditEntryNew,
+    ) -> CustomResult<Option<storage::StoreCreditEntry>, errors::StorageError>;
+
+    async fn find_store_credit_entries_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::StoreCreditEntry>, errors::StorageError>;
+
+    async fn find_store_credit_entries_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::StoreCreditEntry>, errors::StorageError>;
+
+    async fn get_store_credit_total_amount_by_currency_and_entry_type(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Vec<(enums::Currency, enums::StoreCreditEntryType, i64)>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl StoreCreditEntryInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_store_credit_entry_if_absent(
+        &self,
+        store_credit_entry: storage::StoreCreditEntryNew,
+    ) -> CustomResult<Option<storage::StoreCreditEntry>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        store_credit_entry
+            .insert_if_absent(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_store_credit_entries_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::StoreCreditEntry>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::StoreCreditEntry::find_by_merchant_id_customer_id(
+            &conn,
+            merchant_id,
+            customer_id,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_store_credit_entries_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::StoreCreditEntry>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::StoreCreditEntry::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn get_store_credit_total_amount_by_currency_and_entry_type(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Vec<(enums::Currency, enums::StoreCreditEntryType, i64)>, errors::StorageError>
+    {
+        // The balance is read from the primary, as the debits of payments are checked against it
+        let conn = connection::pg_connection_write(self).await?;
+        storage::StoreCreditEntry::get_total_amount_by_currency_and_entry_type(
+            &conn,
+            merchant_id,
+            customer_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl StoreCreditEntryInterface for MockDb {
+    async fn insert_store_credit_entry_if_absent(
+        &self,
+        _store_credit_entry: storage::StoreCreditEntryNew,
+    ) -> CustomResult<Option<storage::StoreCreditEntry>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_store_credit_entries_by_merchant_id_customer_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _customer_id: &common_utils::id_type::CustomerId,
+        _limit: Option<i64>,
+        _offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::StoreCreditEntry>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_store_credit_entries_by_merchant_id_payment_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::StoreCreditEntry>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn get_store_credit_total_amount_by_currency_and_entry_type(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Vec<(enums::Currency, enums::StoreCreditEntryType, i64)>, errors::StorageError>
+    {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl StoreCreditEntryInterface for KafkaStore {
+    #[instrument(skip_all)]
+    async fn insert_store_credit_entry_if_absent(
+        &self,
+        store_credit_entry: storage::StoreCreditEntryNew,
+    ) -> CustomResult<Option<storage::StoreCreditEntry>, errors::StorageError> {
+        self.diesel_store
+            .insert_store_credit_entry_if_absent(store_credit_entry)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_store_credit_entries_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> CustomResult<Vec<storage::StoreCreditEntry>, errors::StorageError> {
+        self.diesel_store
+            .find_store_credit_entries_by_merchant_id_customer_id(
+                merchant_id,
+                customer_id,
+                limit,
+                offset,
+            )
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn find_store_credit_entries_by_merchant_id_payment_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_id: &common_utils::id_type::PaymentId,
+    ) -> CustomResult<Vec<storage::StoreCreditEntry>, errors::StorageError> {
+        self.diesel_store
+            .find_store_credit_entries_by_merchant_id_payment_id(merchant_id, payment_id)
+            .await
+    }
+
+    #[instrument(skip_all)]
+    async fn get_store_credit_total_amount_by_currency_and_entry_type(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Vec<(enums::Currency, enums::StoreCreditEntryType, i64)>, errors::StorageError>
+    {
+        self.diesel_store
+            .get_store_credit_total_amount_by_currency_and_entry_type(merchant_id, customer_id)
+            .await
+    }
+}
@@ -0,0 +1,177 @@
+#[cfg(feature = "v1")]
+use async_bb8_diesel::AsyncConnection;
+#[cfg(feature = "v1")]
+use common_utils::types::keymanager::KeyManagerState;
+#[cfg(feature = "v1")]
+use diesel_models::{query::test_data as test_data_queries, refund::Refund};
+#[cfg(feature = "v1")]
+use error_stack::ResultExt;
+#[cfg(feature = "v1")]
+use futures::future::try_join_all;
+#[cfg(feature = "v1")]
+use hyperswitch_domain_models::{
+    behaviour::Conversion,
+    merchant_key_store::MerchantKeyStore,
+    payments::{payment_attempt::PaymentAttempt, PaymentIntent},
+};
+#[cfg(feature = "v1")]
+use router_env::{instrument, tracing};
+#[cfg(feature = "v1")]
+use storage_impl::{
+    redis::kv_store::{PartitionKey, RedisConnInterface},
+    DataModelExt,
+};
+
+use crate::services::Store;
+#[cfg(feature = "v1")]
+use crate::{
+    connection::pg_connection_write,
+    core::errors::{self, CustomResult},
+};
+
+/// The rows deleted along with a batch of the test payments of a profile
+#[cfg(feature = "v1")]
+#[derive(Debug)]
+pub struct TestPaymentsDeletion {
+    pub payment_intents: Vec<PaymentIntent>,
+    pub payment_attempts: Vec<PaymentAttempt>,
+    pub refunds: Vec<Refund>,
+}
+
+/// Deletion of the data created by the profiles in test mode
+#[async_trait::async_trait]
+pub trait TestDataInterface {
+    /// Deletes at most `batch_size` of the test payments of the profile which were created before
+    /// the given time, along with their attempts and refunds. The payments with no test marker are
+    /// never deleted, even if the profile is in test mode.
+    #[cfg(feature = "v1")]
+    async fn delete_test_payments(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_before: time::PrimitiveDateTime,
+        batch_size: i64,
+        key_store: &MerchantKeyStore,
+    ) -> CustomResult<TestPaymentsDeletion, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl TestDataInterface for Store {
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn delete_test_payments(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: &common_utils::id_type::ProfileId,
+        created_before: time::PrimitiveDateTime,
+        batch_size: i64,
+        key_store: &MerchantKeyStore,
+    ) -> CustomResult<TestPaymentsDeletion, errors::StorageError> {
+        let conn = pg_connection_write(self).await?;
+
+        // The payments are deleted along with their attempts and refunds, or not at all
+        let (payment_ids, payment_intents, payment_attempts, refunds) = conn
+            .transaction_async(|conn| async move {
+                let payment_ids = test_data_queries::find_test_payment_ids(
+                    &conn,
+                    merchant_id,
+                    profile_id,
+                    created_before,
+                    batch_size,
+                )
+                .await
+                .map_err(errors::StorageError::from)?;
+
+                if payment_ids.is_empty() {
+                    return Ok::<_, errors::StorageError>((
+                        payment_ids,
+                        Vec::new(),
+                        Vec::new(),
+                        Vec::new(),
+                    ));
+                }
+
+                let refunds =
+                    test_data_queries::delete_refunds(&conn, merchant_id, payment_ids.clone())
+                        .await
+                        .map_err(errors::StorageError::from)?;
+                let payment_attempts = test_data_queries::delete_payment_attempts(
+                    &conn,
+                    merchant_id,
+                    payment_ids.clone(),
+                )
+                .await
+                .map_err(errors::StorageError::from)?;
+                let payment_intents = test_data_queries::delete_payment_intents(
+                    &conn,
+                    merchant_id,
+                    payment_ids.clone(),
+                )
+                .await
+                .map_err(errors::StorageError::from)?;
+
+                Ok((payment_ids, payment_intents, payment_attempts, refunds))
+            })
+            .await?;
+
+        // The intents, the attempts and the refunds of a payment share its hash in redis, which
+        // would otherwise be served in place of the deleted rows to the merchants using KV
+        if !payment_ids.is_empty() {
+            let keys = payment_ids
+                .iter()
+                .map(|payment_id| {
+                    PartitionKey::MerchantIdPaymentId {
+                        merchant_id,
+                        payment_id,
+                    }
+                    .to_string()
+                    .into()
+                })
+                .collect::<Vec<_>>();
+
+            self.get_redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?
+                .delete_multiple_keys(&keys)
+                .await
+                .change_context(errors::StorageError::KVError)?;
+        }
+
+        let payment_intents = try_join_all(payment_intents.into_iter().map(|payment_intent| {
+            PaymentIntent::convert_back(
+                state,
+                payment_intent,
+                key_store.key.get_inner(),
+                key_store.merchant_id.clone().into(),
+            )
+        }))
+        .await
+        .change_context(errors::StorageError::DecryptionError)?;
+
+        Ok(TestPaymentsDeletion {
+            payment_intents,
+            payment_attempts: payment_attempts
+                .into_iter()
+                .map(PaymentAttempt::from_storage_model)
+                .collect(),
+            refunds,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl TestDataInterface for storage_impl::MockDb {
+    #[cfg(feature = "v1")]
+    async fn delete_test_payments(
+        &self,
+        _state: &KeyManagerState,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _profile_id: &common_utils::id_type::ProfileId,
+        _created_before: time::PrimitiveDateTime,
+        _batch_size: i64,
+        _key_store: &MerchantKeyStore,
+    ) -> CustomResult<TestPaymentsDeletion, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -30,6 +30,12 @@ pub trait UnifiedTranslationsInterface {
         locale: String,
     ) -> CustomResult<String, errors::StorageError>;
 
+    async fn find_translation_by_unified_code_locale(
+        &self,
+        unified_code: String,
+        locale: String,
+    ) -> CustomResult<String, errors::StorageError>;
+
     async fn delete_translation(
         &self,
         unified_code: String,
@@ -89,6 +95,19 @@ impl UnifiedTranslationsInterface for Store {
         Ok(translations.translation)
     }
 
+    async fn find_translation_by_unified_code_locale(
+        &self,
+        unified_code: String,
+        locale: String,
+    ) -> CustomResult<String, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        let translations =
+            storage::UnifiedTranslations::find_by_unified_code_locale(&conn, unified_code, locale)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))?;
+        Ok(translations.translation)
+    }
+
     async fn delete_translation(
         &self,
         unified_code: String,
@@ -125,6 +144,14 @@ impl UnifiedTranslationsInterface for MockDb {
         Err(errors::StorageError::MockDbError)?
     }
 
+    async fn find_translation_by_unified_code_locale(
+        &self,
+        _unified_code: String,
+        _locale: String,
+    ) -> CustomResult<String, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
     async fn update_translation(
         &self,
         _unified_code: String,
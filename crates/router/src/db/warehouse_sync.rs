@@ -0,0 +1,145 @@
+#[cfg(feature = "v1")]
+use diesel_models::{
+    query::warehouse_sync as warehouse_sync_queries, Dispute, PaymentIntent, Refund,
+};
+#[cfg(feature = "v1")]
+use error_stack::report;
+#[cfg(feature = "v1")]
+use time::PrimitiveDateTime;
+
+use crate::services::Store;
+#[cfg(feature = "v1")]
+use crate::{
+    connection::pg_connection_read,
+    core::errors::{self, CustomResult},
+};
+
+/// Incremental reads of the records of a merchant which are synced to its data warehouse
+#[async_trait::async_trait]
+pub trait WarehouseSyncInterface {
+    #[cfg(feature = "v1")]
+    async fn find_payment_intents_modified_after(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        modified_after: Option<(PrimitiveDateTime, common_utils::id_type::PaymentId)>,
+        modified_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn find_refunds_modified_after(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        modified_after: Option<(PrimitiveDateTime, String)>,
+        modified_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<Refund>, errors::StorageError>;
+
+    #[cfg(feature = "v1")]
+    async fn find_disputes_modified_after(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        modified_after: Option<(PrimitiveDateTime, String)>,
+        modified_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<Dispute>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl WarehouseSyncInterface for Store {
+    #[cfg(feature = "v1")]
+    async fn find_payment_intents_modified_after(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        modified_after: Option<(PrimitiveDateTime, common_utils::id_type::PaymentId)>,
+        modified_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        warehouse_sync_queries::find_payment_intents_modified_after(
+            &conn,
+            merchant_id,
+            modified_after,
+            modified_before,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_refunds_modified_after(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        modified_after: Option<(PrimitiveDateTime, String)>,
+        modified_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<Refund>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        warehouse_sync_queries::find_refunds_modified_after(
+            &conn,
+            merchant_id,
+            modified_after,
+            modified_before,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_disputes_modified_after(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        modified_after: Option<(PrimitiveDateTime, String)>,
+        modified_before: PrimitiveDateTime,
+        limit: i64,
+    ) -> CustomResult<Vec<Dispute>, errors::StorageError> {
+        let conn = pg_connection_read(self).await?;
+        warehouse_sync_queries::find_disputes_modified_after(
+            &conn,
+            merchant_id,
+            modified_after,
+            modified_before,
+            limit,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl WarehouseSyncInterface for storage_impl::MockDb {
+    #[cfg(feature = "v1")]
+    async fn find_payment_intents_modified_after(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _modified_after: Option<(PrimitiveDateTime, common_utils::id_type::PaymentId)>,
+        _modified_before: PrimitiveDateTime,
+        _limit: i64,
+    ) -> CustomResult<Vec<PaymentIntent>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_refunds_modified_after(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _modified_after: Option<(PrimitiveDateTime, String)>,
+        _modified_before: PrimitiveDateTime,
+        _limit: i64,
+    ) -> CustomResult<Vec<Refund>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    #[cfg(feature = "v1")]
+    async fn find_disputes_modified_after(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _modified_after: Option<(PrimitiveDateTime, String)>,
+        _modified_before: PrimitiveDateTime,
+        _limit: i64,
+    ) -> CustomResult<Vec<Dispute>, errors::StorageError> {
+        Err(errors::StorageError::MockDbError)?
+    }
+}
@@ -24,6 +24,10 @@ pub enum AuditEventType {
     PaymentCancelled {
         cancellation_reason: Option<String>,
     },
+    PaymentCancelRemaining {
+        released_amount: MinorUnit,
+        cancellation_reason: Option<String>,
+    },
     PaymentCapture {
         capture_amount: Option<MinorUnit>,
         multiple_capture_count: Option<i16>,
@@ -77,6 +81,7 @@ impl Event for AuditEvent {
             AuditEventType::RefundSuccess => "refund_success",
             AuditEventType::RefundFail => "refund_fail",
             AuditEventType::PaymentCancelled { .. } => "payment_cancelled",
+            AuditEventType::PaymentCancelRemaining { .. } => "payment_cancel_remaining",
             AuditEventType::PaymentUpdate { .. } => "payment_update",
             AuditEventType::PaymentApprove { .. } => "payment_approve",
             AuditEventType::PaymentCreate { .. } => "payment_create",
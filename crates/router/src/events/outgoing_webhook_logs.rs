@@ -64,6 +64,14 @@ pub enum OutgoingWebhookEventContent {
         mandate_id: String,
         content: Value,
     },
+    MerchantAccount {
+        merchant_id: common_utils::id_type::MerchantId,
+        content: Value,
+    },
+    MerchantConnectorAccount {
+        merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+        content: Value,
+    },
 }
 pub trait OutgoingWebhookEventMetric {
     fn get_outgoing_webhook_event_content(&self) -> Option<OutgoingWebhookEventContent>;
@@ -103,6 +111,22 @@ impl OutgoingWebhookEventMetric for OutgoingWebhookContent {
                 content: masking::masked_serialize(&payout_payload)
                     .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
             }),
+            Self::MerchantAccountDetails(merchant_account_payload) => {
+                Some(OutgoingWebhookEventContent::MerchantAccount {
+                    merchant_id: merchant_account_payload.merchant_id.clone(),
+                    content: masking::masked_serialize(&merchant_account_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
+            Self::ConnectorCredentialsDetails(connector_credentials_payload) => {
+                Some(OutgoingWebhookEventContent::MerchantConnectorAccount {
+                    merchant_connector_id: connector_credentials_payload
+                        .merchant_connector_id
+                        .clone(),
+                    content: masking::masked_serialize(&connector_credentials_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
         }
     }
 }
@@ -141,6 +165,22 @@ impl OutgoingWebhookEventMetric for OutgoingWebhookContent {
                 content: masking::masked_serialize(&payout_payload)
                     .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
             }),
+            Self::MerchantAccountDetails(merchant_account_payload) => {
+                Some(OutgoingWebhookEventContent::MerchantAccount {
+                    merchant_id: merchant_account_payload.merchant_id.clone(),
+                    content: masking::masked_serialize(&merchant_account_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
+            Self::ConnectorCredentialsDetails(connector_credentials_payload) => {
+                Some(OutgoingWebhookEventContent::MerchantConnectorAccount {
+                    merchant_connector_id: connector_credentials_payload
+                        .merchant_connector_id
+                        .clone(),
+                    content: masking::masked_serialize(&connector_credentials_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
         }
     }
 }
@@ -0,0 +1,76 @@
+use api_models::sdk_events::SdkEventRequest;
+use serde::Serialize;
+
+use super::EventType;
+use crate::services::kafka::KafkaMessage;
+
+/// An event generated by the SDK, in the format of the rows of the SDK events analytics source
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct SdkEvent {
+    payment_id: Option<String>,
+    /// The publishable key of the merchant, by which the SDK events are identified in analytics
+    merchant_id: String,
+    remote_ip: Option<String>,
+    log_type: Option<String>,
+    event_name: String,
+    first_event: Option<String>,
+    latency: Option<u32>,
+    /// Milliseconds since the epoch, as sent by the SDK
+    timestamp: String,
+    browser_name: Option<String>,
+    browser_version: Option<String>,
+    platform: Option<String>,
+    source: Option<String>,
+    category: Option<String>,
+    version: Option<String>,
+    value: Option<String>,
+    component: Option<String>,
+    payment_method: Option<String>,
+    payment_experience: Option<String>,
+    locale: Option<String>,
+}
+
+impl SdkEvent {
+    pub fn new(publishable_key: String, remote_ip: Option<String>, event: SdkEventRequest) -> Self {
+        Self {
+            payment_id: event.payment_id,
+            merchant_id: publishable_key,
+            remote_ip,
+            log_type: event.log_type,
+            event_name: event.event_name,
+            first_event: event.first_event,
+            latency: event.latency,
+            timestamp: event.timestamp.to_string(),
+            browser_name: event.browser_name,
+            browser_version: event.browser_version,
+            platform: event.platform,
+            source: event.source,
+            category: event.category,
+            version: event.version,
+            value: event.value,
+            component: event.component,
+            payment_method: event.payment_method,
+            payment_experience: event.payment_experience,
+            locale: event.locale,
+        }
+    }
+}
+
+impl KafkaMessage for SdkEvent {
+    fn event_type(&self) -> EventType {
+        EventType::SdkEvents
+    }
+
+    fn key(&self) -> String {
+        format!(
+            "{}_{}",
+            self.merchant_id,
+            self.payment_id.as_deref().unwrap_or_default()
+        )
+    }
+
+    fn creation_timestamp(&self) -> Option<i64> {
+        self.timestamp.parse().ok()
+    }
+}
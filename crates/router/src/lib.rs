@@ -92,6 +92,7 @@ pub mod headers {
     pub const X_CLIENT_SECRET: &str = "X-Client-Secret";
     pub const X_CUSTOMER_ID: &str = "X-Customer-Id";
     pub const X_CONNECTED_MERCHANT_ID: &str = "x-connected-merchant-id";
+    pub const X_HYPERSWITCH_REGION: &str = "x-hyperswitch-region";
 }
 
 pub mod pii {
@@ -114,7 +115,11 @@ pub fn mk_app(
         InitError = (),
     >,
 > {
-    let mut server_app = get_application_builder(request_body_limit, state.conf.cors.clone());
+    let mut server_app = get_application_builder(
+        request_body_limit,
+        state.conf.cors.clone(),
+        state.conf.region.id.clone(),
+    );
 
     #[cfg(all(feature = "dummy_connector", feature = "v1"))]
     {
@@ -132,7 +137,10 @@ pub fn mk_app(
             {
                 server_app = server_app
                     .service(routes::ProfileNew::server(state.clone()))
-                    .service(routes::Forex::server(state.clone()));
+                    .service(routes::Forex::server(state.clone()))
+                    // This is a more specific route as compared to `Webhooks`
+                    // so it is registered before `Webhooks`.
+                    .service(routes::WebhookEndpoints::server(state.clone()));
             }
 
             server_app = server_app.service(routes::Profile::server(state.clone()));
@@ -161,7 +169,11 @@ pub fn mk_app(
         {
             server_app = server_app
                 .service(routes::Refunds::server(state.clone()))
-                .service(routes::Mandates::server(state.clone()));
+                .service(routes::Mandates::server(state.clone()))
+                .service(routes::Sandbox::server(state.clone()))
+                .service(routes::Receipts::server(state.clone()))
+                .service(routes::CheckoutSessions::server(state.clone()))
+                .service(routes::SanctionsScreening::server(state.clone()));
         }
     }
 
@@ -178,6 +190,11 @@ pub fn mk_app(
         server_app = server_app.service(routes::Poll::server(state.clone()))
     }
 
+    #[cfg(feature = "oltp")]
+    {
+        server_app = server_app.service(routes::SdkEvents::server(state.clone()))
+    }
+
     #[cfg(feature = "olap")]
     {
         server_app = server_app
@@ -200,7 +217,11 @@ pub fn mk_app(
                 .service(routes::Verify::server(state.clone()))
                 .service(routes::Analytics::server(state.clone()))
                 .service(routes::WebhookEvents::server(state.clone()))
-                .service(routes::FeatureMatrix::server(state.clone()));
+                .service(routes::WebhookPayloadTemplates::server(state.clone()))
+                .service(routes::WarehouseSync::server(state.clone()))
+                .service(routes::ScaCompliance::server(state.clone()))
+                .service(routes::FeatureMatrix::server(state.clone()))
+                .service(routes::ProcessTracker::server(state.clone()));
         }
     }
 
@@ -243,9 +264,10 @@ pub async fn start_server(conf: settings::Settings<SecuredSecret>) -> Applicatio
     logger::debug!(startup_config=?conf);
     let server = conf.server.clone();
     let (tx, rx) = oneshot::channel();
-    let api_client = Box::new(services::ProxyClient::new(&conf.proxy).map_err(|error| {
+    let proxy_client = services::ProxyClient::new(&conf.proxy).map_err(|error| {
         errors::ApplicationError::ApiClientError(error.current_context().clone())
-    })?);
+    })?;
+    let api_client = services::get_api_client(proxy_client, &conf.connector_cassettes);
     let state = Box::pin(AppState::new(conf, tx, api_client)).await;
     let request_body_limit = server.request_body_limit;
 
@@ -344,6 +366,7 @@ impl Stop for mpsc::Sender<()> {
 pub fn get_application_builder(
     request_body_limit: usize,
     cors: settings::CorsSettings,
+    region_id: Option<common_utils::id_type::RegionId>,
 ) -> actix_web::App<
     impl ServiceFactory<
         ServiceRequest,
@@ -368,7 +391,7 @@ pub fn get_application_builder(
             StatusCode::METHOD_NOT_ALLOWED,
             errors::error_handlers::custom_error_handlers,
         ))
-        .wrap(middleware::default_response_headers())
+        .wrap(middleware::default_response_headers(region_id))
         .wrap(middleware::RequestId)
         .wrap(cors::cors(cors))
         // this middleware works only for Http1.1 requests
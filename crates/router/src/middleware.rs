@@ -78,11 +78,22 @@ where
 
 /// Middleware for attaching default response headers. Headers with the same key already set in a
 /// response will not be overwritten.
-pub fn default_response_headers() -> actix_web::middleware::DefaultHeaders {
+pub fn default_response_headers(
+    region_id: Option<common_utils::id_type::RegionId>,
+) -> actix_web::middleware::DefaultHeaders {
     use actix_web::http::header;
 
     let default_headers_middleware = actix_web::middleware::DefaultHeaders::new();
 
+    // The region serving the request, in an active-active deployment across multiple regions
+    let default_headers_middleware = match region_id {
+        Some(region_id) => default_headers_middleware.add((
+            crate::headers::X_HYPERSWITCH_REGION,
+            region_id.get_string_repr().to_owned(),
+        )),
+        None => default_headers_middleware,
+    };
+
     #[cfg(feature = "vergen")]
     let default_headers_middleware =
         default_headers_middleware.add(("x-hyperswitch-version", router_env::git_tag!()));
@@ -7,6 +7,8 @@ pub mod apple_pay_certificates_migration;
 pub mod blocklist;
 pub mod cache;
 pub mod cards_info;
+#[cfg(feature = "v1")]
+pub mod checkout_sessions;
 pub mod configs;
 #[cfg(feature = "olap")]
 pub mod connector_onboarding;
@@ -41,13 +43,25 @@ pub mod payouts;
 pub mod pm_auth;
 pub mod poll;
 #[cfg(feature = "olap")]
+pub mod process_tracker;
+#[cfg(feature = "olap")]
 pub mod profiles;
+#[cfg(feature = "v1")]
+pub mod receipts;
 #[cfg(feature = "recon")]
 pub mod recon;
 #[cfg(feature = "v1")]
 pub mod refunds;
 #[cfg(feature = "olap")]
 pub mod routing;
+#[cfg(feature = "v1")]
+pub mod sanctions_screening;
+#[cfg(feature = "v1")]
+pub mod sandbox;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod sca_compliance;
+#[cfg(feature = "oltp")]
+pub mod sdk_events;
 #[cfg(feature = "olap")]
 pub mod user;
 #[cfg(feature = "olap")]
@@ -57,7 +71,13 @@ pub mod verification;
 #[cfg(feature = "olap")]
 pub mod verify_connector;
 #[cfg(all(feature = "olap", feature = "v1"))]
+pub mod warehouse_sync;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod webhook_endpoints;
+#[cfg(all(feature = "olap", feature = "v1"))]
 pub mod webhook_events;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub mod webhook_payload_templates;
 pub mod webhooks;
 
 #[cfg(all(feature = "v2", feature = "revenue_recovery"))]
@@ -69,8 +89,12 @@ pub mod relay;
 pub use self::app::DummyConnector;
 #[cfg(feature = "v2")]
 pub use self::app::PaymentMethodSession;
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub use self::app::ProcessTracker;
 #[cfg(all(feature = "olap", feature = "recon", feature = "v1"))]
 pub use self::app::Recon;
+#[cfg(feature = "oltp")]
+pub use self::app::SdkEvents;
 pub use self::app::{
     ApiKeys, AppState, ApplePayCertificatesMigration, Cache, Cards, Configs, ConnectorOnboarding,
     Customers, Disputes, EphemeralKey, FeatureMatrix, Files, Forex, Gsm, Health, Hypersense,
@@ -78,7 +102,12 @@ pub use self::app::{
     Poll, Profile, ProfileNew, Refunds, Relay, RelayWebhooks, SessionState, User, Webhooks,
 };
 #[cfg(feature = "olap")]
-pub use self::app::{Blocklist, Organization, Routing, Verify, WebhookEvents};
+pub use self::app::{
+    Blocklist, Organization, Routing, ScaCompliance, Verify, WarehouseSync, WebhookEndpoints,
+    WebhookEvents, WebhookPayloadTemplates,
+};
+#[cfg(feature = "v1")]
+pub use self::app::{CheckoutSessions, Receipts, SanctionsScreening, Sandbox};
 #[cfg(feature = "payouts")]
 pub use self::app::{PayoutLink, Payouts};
 #[cfg(all(
@@ -2,6 +2,11 @@ use actix_web::{web, HttpRequest, HttpResponse};
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use crate::core::{
+    connector_transaction_id_backfill, data_residency, fault_injection, feature_flags,
+    merchant_lifecycle,
+};
 use crate::{
     core::{admin::*, api_locking},
     services::{api, authentication as auth, authorization::permissions::Permission},
@@ -842,6 +847,295 @@ pub async fn connector_update(
     .await
 }
 
+/// Merchant Connector - Bulk Config
+///
+/// Create or update the merchant connector account of a connector in many business profiles at
+/// once, from a template with per profile overrides
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantConnectorsBulkConfig))]
+pub async fn connector_bulk_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<admin::MerchantConnectorBulkConfigRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantConnectorsBulkConfig;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            crate::core::connector_bulk_config::apply_bulk_connector_config(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantConnectorWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantConnectorsMaintenanceWindowsUpsert))]
+pub async fn connector_maintenance_windows_upsert(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::MerchantConnectorAccountId,
+    )>,
+    json_payload: web::Json<api_models::admin::ConnectorMaintenanceWindowsRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantConnectorsMaintenanceWindowsUpsert;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            crate::core::connector_maintenance::upsert_maintenance_windows(
+                state,
+                merchant_id.clone(),
+                auth.profile_id,
+                merchant_connector_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromHeader,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::ProfileConnectorWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantConnectorsMaintenanceWindowsRetrieve))]
+pub async fn connector_maintenance_windows_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::MerchantConnectorAccountId,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::MerchantConnectorsMaintenanceWindowsRetrieve;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+    let payload = admin::MerchantConnectorId {
+        merchant_id: merchant_id.clone(),
+        merchant_connector_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            crate::core::connector_maintenance::retrieve_maintenance_windows(
+                state,
+                req.merchant_id,
+                auth.profile_id,
+                req.merchant_connector_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromHeader,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::ProfileConnectorRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantConnectorsCredentialsRetrieve))]
+pub async fn connector_credentials_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::MerchantConnectorAccountId,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::MerchantConnectorsCredentialsRetrieve;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+    let payload = admin::MerchantConnectorId {
+        merchant_id: merchant_id.clone(),
+        merchant_connector_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            crate::core::connector_credentials::retrieve_credentials_metadata(
+                state,
+                req.merchant_id,
+                auth.profile_id,
+                req.merchant_connector_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromHeader,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::ProfileConnectorRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantConnectorsCredentialsUpdate))]
+pub async fn connector_credentials_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::MerchantConnectorAccountId,
+    )>,
+    json_payload: web::Json<api_models::admin::ConnectorCredentialsUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantConnectorsCredentialsUpdate;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            crate::core::connector_credentials::update_credentials_metadata(
+                state,
+                merchant_id.clone(),
+                auth.profile_id,
+                merchant_connector_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromHeader,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::ProfileConnectorWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantConnectorsCredentialsVerify))]
+pub async fn connector_credentials_verify(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::MerchantConnectorAccountId,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::MerchantConnectorsCredentialsVerify;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+    let payload = admin::MerchantConnectorId {
+        merchant_id: merchant_id.clone(),
+        merchant_connector_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            crate::core::connector_credentials::verify_credentials(
+                state,
+                req.merchant_id,
+                auth.profile_id,
+                req.merchant_connector_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromHeader,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::ProfileConnectorWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantConnectorsCredentialsExpiringList))]
+pub async fn connector_credentials_expiring_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    query_params: web::Query<api_models::admin::ExpiringConnectorCredentialsRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantConnectorsCredentialsExpiringList;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_params.into_inner(),
+        |state, auth, req, _| {
+            crate::core::connector_credentials::list_expiring_credentials(
+                state,
+                merchant_id.clone(),
+                auth.profile_id,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromHeader,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::ProfileConnectorRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Merchant Connector - Update
 ///
 /// To update an existing Merchant Connector. Helpful in enabling / disabling different payment methods and other settings for the connector etc.
@@ -894,7 +1188,7 @@ pub async fn connector_update(
 }
 /// Merchant Connector - Delete
 ///
-/// Delete or Detach a Merchant Connector from Merchant Account
+/// Delete or Detach a Merchant Connector from Merchant Account. The Merchant Connector is disabled and kept for the payments made through it, and can be restored within 30 days of its deletion.
 #[cfg(feature = "v1")]
 #[utoipa::path(
     delete,
@@ -947,15 +1241,52 @@ pub async fn connector_delete(
     ))
     .await
 }
-/// Merchant Connector - Delete
-///
-/// Delete or Detach a Merchant Connector from Merchant Account
-#[cfg(feature = "v2")]
-#[instrument(skip_all, fields(flow = ?Flow::MerchantConnectorsDelete))]
-pub async fn connector_delete(
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantConnectorsRestore))]
+pub async fn connector_restore(
     state: web::Data<AppState>,
     req: HttpRequest,
-    path: web::Path<common_utils::id_type::MerchantConnectorAccountId>,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::MerchantConnectorAccountId,
+    )>,
+) -> HttpResponse {
+    let flow = Flow::MerchantConnectorsRestore;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+
+    let payload = web::Json(admin::MerchantConnectorId {
+        merchant_id: merchant_id.clone(),
+        merchant_connector_id,
+    })
+    .into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| restore_connector(state, req.merchant_id, req.merchant_connector_id),
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantConnectorWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+/// Merchant Connector - Delete
+///
+/// Delete or Detach a Merchant Connector from Merchant Account
+#[cfg(feature = "v2")]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantConnectorsDelete))]
+pub async fn connector_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantConnectorAccountId>,
 ) -> HttpResponse {
     let flow = Flow::MerchantConnectorsDelete;
     let id = path.into_inner();
@@ -1059,6 +1390,427 @@ pub async fn merchant_account_kv_status(
     .await
 }
 
+/// Merchant Account - Retrieve Feature Flags
+///
+/// Retrieve the feature flags of a merchant account
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantFeatureFlagsRetrieve))]
+pub async fn merchant_feature_flags_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::MerchantFeatureFlagsRetrieve;
+    let merchant_id = path.into_inner();
+    let payload = admin::MerchantId {
+        merchant_id: merchant_id.clone(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth_data, _, _| {
+            feature_flags::retrieve_merchant_feature_flags(state, auth_data.merchant_account)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Update Feature Flags
+///
+/// Set or clear the feature flags of a merchant account
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantFeatureFlagsUpdate))]
+pub async fn merchant_feature_flags_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<api_models::feature_flags::FeatureFlagsUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantFeatureFlagsUpdate;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth_data, req, _| {
+            feature_flags::update_merchant_feature_flags(state, auth_data.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Upsert Fault Injection Config
+///
+/// Create or replace the faults injected into the connector calls and webhooks of a merchant
+/// account
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantFaultInjectionUpsert))]
+pub async fn merchant_fault_injection_upsert(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<api_models::fault_injection::FaultInjectionConfig>,
+) -> HttpResponse {
+    let flow = Flow::MerchantFaultInjectionUpsert;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth_data, req, _| {
+            fault_injection::upsert_fault_injection_config(state, auth_data.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Retrieve Fault Injection Config
+///
+/// Retrieve the faults injected into the connector calls and webhooks of a merchant account
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantFaultInjectionRetrieve))]
+pub async fn merchant_fault_injection_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::MerchantFaultInjectionRetrieve;
+    let merchant_id = path.into_inner();
+    let payload = admin::MerchantId {
+        merchant_id: merchant_id.clone(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth_data, _, _| {
+            fault_injection::retrieve_fault_injection_config(state, auth_data.merchant_account)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Delete Fault Injection Config
+///
+/// Stop injecting faults into the connector calls and webhooks of a merchant account
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantFaultInjectionDelete))]
+pub async fn merchant_fault_injection_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::MerchantFaultInjectionDelete;
+    let merchant_id = path.into_inner();
+    let payload = admin::MerchantId {
+        merchant_id: merchant_id.clone(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth_data, _, _| {
+            fault_injection::delete_fault_injection_config(state, auth_data.merchant_account)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuthWithMerchantIdFromRoute(merchant_id.clone()),
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Update Status
+///
+/// Move a merchant account to the active, restricted or suspended status. A restricted merchant
+/// account can refund but cannot charge, and a suspended merchant account cannot be accessed with
+/// its API keys.
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantAccountStatusUpdate))]
+pub async fn merchant_account_status_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<api_models::admin::MerchantAccountStatusUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantAccountStatusUpdate;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req, _| {
+            merchant_lifecycle::update_merchant_account_status(state, merchant_id.clone(), req)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Offboard
+///
+/// Move a merchant account to the closing status, after which its pending payouts are drained and
+/// its data is exported
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantOffboard))]
+pub async fn merchant_account_offboard(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<api_models::admin::MerchantOffboardingRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantOffboard;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req, _| {
+            merchant_lifecycle::offboard_merchant_account(state, merchant_id.clone(), req)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Retrieve Offboarding
+///
+/// Retrieve the progress of the offboarding of a merchant account
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantOffboardingRetrieve))]
+pub async fn merchant_account_offboarding_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::MerchantOffboardingRetrieve;
+    let merchant_id = path.into_inner();
+    let payload = admin::MerchantId {
+        merchant_id: merchant_id.clone(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| {
+            merchant_lifecycle::retrieve_merchant_offboarding(state, req.merchant_id)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Backfill Connector Transaction IDs
+///
+/// Start a backfill of the connector transaction IDs of the payments of a merchant account created
+/// within a time range, which syncs the payments without one with their connectors
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorTransactionIdBackfillCreate))]
+pub async fn connector_transaction_id_backfill_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<api_models::admin::ConnectorTransactionIdBackfillRequest>,
+) -> HttpResponse {
+    let flow = Flow::ConnectorTransactionIdBackfillCreate;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req, _| {
+            connector_transaction_id_backfill::create_connector_transaction_id_backfill(
+                state,
+                merchant_id.clone(),
+                req,
+            )
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Retrieve Connector Transaction ID Backfill
+///
+/// Retrieve the progress of a backfill of the connector transaction IDs of a merchant account
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::ConnectorTransactionIdBackfillRetrieve))]
+pub async fn connector_transaction_id_backfill_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(common_utils::id_type::MerchantId, String)>,
+) -> HttpResponse {
+    let flow = Flow::ConnectorTransactionIdBackfillRetrieve;
+    let (merchant_id, backfill_id) = path.into_inner();
+    let payload = admin::ConnectorTransactionIdBackfillId {
+        merchant_id,
+        backfill_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| {
+            connector_transaction_id_backfill::retrieve_connector_transaction_id_backfill(
+                state,
+                req.merchant_id,
+                req.backfill_id,
+            )
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Data Residency
+///
+/// Pin the customers, addresses and locker entries of a merchant account to a data residency
+/// region, migrating the existing ones to the region
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::DataResidencyCreate))]
+pub async fn data_residency_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    json_payload: web::Json<api_models::admin::DataResidencyRequest>,
+) -> HttpResponse {
+    let flow = Flow::DataResidencyCreate;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req, _| data_residency::create_data_residency(state, merchant_id.clone(), req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Merchant Account - Retrieve Data Residency
+///
+/// Retrieve the data residency of a merchant account and the progress of its migration
+#[cfg(all(feature = "olap", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?Flow::DataResidencyRetrieve))]
+pub async fn data_residency_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::DataResidencyRetrieve;
+    let payload = admin::MerchantId {
+        merchant_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| data_residency::retrieve_data_residency(state, req.merchant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+///
+/// List the divergences between the KV store and Postgres found by the drainer
+#[instrument(skip_all, fields(flow = ?Flow::KvPgDivergencesList))]
+pub async fn kv_pg_divergences_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<admin::KvPgDivergencesListRequest>,
+) -> HttpResponse {
+    let flow = Flow::KvPgDivergencesList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_payload.into_inner(),
+        |state, _, req, _| list_kv_pg_divergences(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Merchant Account - KV Status
 ///
 /// Toggle KV mode for the Merchant Account
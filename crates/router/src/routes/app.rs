@@ -44,8 +44,8 @@ use super::payouts::*;
     not(feature = "customer_v2")
 ))]
 use super::pm_auth;
-#[cfg(feature = "oltp")]
-use super::poll;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::process_tracker;
 #[cfg(all(feature = "v2", feature = "revenue_recovery", feature = "oltp"))]
 use super::recovery_webhooks::*;
 #[cfg(feature = "olap")]
@@ -59,11 +59,18 @@ use super::{
     relay, user, user_role,
 };
 #[cfg(feature = "v1")]
-use super::{apple_pay_certificates_migration, blocklist, payment_link, webhook_events};
+use super::{
+    apple_pay_certificates_migration, blocklist, checkout_sessions, payment_link, receipts,
+    sanctions_screening, sandbox, webhook_events,
+};
 #[cfg(any(feature = "olap", feature = "oltp"))]
 use super::{configs::*, customers, payments};
 #[cfg(all(any(feature = "olap", feature = "oltp"), feature = "v1"))]
 use super::{mandates::*, refunds::*};
+#[cfg(feature = "oltp")]
+use super::{poll, sdk_events};
+#[cfg(all(feature = "olap", feature = "v1"))]
+use super::{sca_compliance, warehouse_sync, webhook_endpoints, webhook_payload_templates};
 #[cfg(feature = "olap")]
 pub use crate::analytics::opensearch::OpenSearchClient;
 #[cfg(feature = "olap")]
@@ -106,6 +113,9 @@ pub struct SessionState {
     /// Global store is used for global schema operations in tables like Users and Tenants
     pub global_store: Box<dyn GlobalStorageInterface>,
     pub accounts_store: Box<dyn AccountsStorageInterface>,
+    /// Stores holding the personal data of the merchants residing in a data residency region,
+    /// keyed by the name of the region
+    pub regional_stores: HashMap<String, Box<dyn StorageInterface>>,
     pub conf: Arc<settings::Settings<RawSecret>>,
     pub api_client: Box<dyn crate::services::ApiClient>,
     pub event_handler: EventsHandler,
@@ -215,6 +225,8 @@ pub struct AppState {
     // TODO: use a separate schema for accounts_store
     pub accounts_store: HashMap<id_type::TenantId, Box<dyn AccountsStorageInterface>>,
     pub stores: HashMap<id_type::TenantId, Box<dyn StorageInterface>>,
+    /// Stores of the data residency regions of each tenant, keyed by the name of the region
+    pub regional_stores: HashMap<id_type::TenantId, HashMap<String, Box<dyn StorageInterface>>>,
     pub conf: Arc<settings::Settings<RawSecret>>,
     pub event_handler: EventsHandler,
     #[cfg(feature = "email")]
@@ -324,6 +336,15 @@ impl AppState {
         ))
         .await;
 
+        // The region tag is embedded in the ids generated by the router and the scheduler alike
+        if let Some(region_id) = conf.region.id.clone() {
+            if !id_type::set_id_generation_region(region_id) {
+                router_env::logger::warn!(
+                    "The region for id generation was already set to a different region"
+                );
+            }
+        }
+
         #[allow(clippy::expect_used)]
         let encryption_client = conf
             .encryption_management
@@ -384,6 +405,16 @@ impl AppState {
                     testable,
                 )
                 .await;
+            let regional_stores = conf
+                .multitenancy
+                .tenants
+                .get_regional_store_interface_map(
+                    &storage_impl,
+                    &conf,
+                    Arc::clone(&cache_store),
+                    testable,
+                )
+                .await;
 
             #[cfg(feature = "email")]
             let email_client = Arc::new(create_email_client(&conf).await);
@@ -396,6 +427,7 @@ impl AppState {
             Self {
                 flow_name: String::from("default"),
                 stores,
+                regional_stores,
                 global_store,
                 accounts_store,
                 conf: Arc::new(conf),
@@ -489,6 +521,11 @@ impl AppState {
             store,
             global_store: self.global_store.clone(),
             accounts_store: self.accounts_store.get(tenant).ok_or_else(err)?.clone(),
+            regional_stores: self
+                .regional_stores
+                .get(tenant)
+                .cloned()
+                .unwrap_or_default(),
             conf: Arc::clone(&self.conf),
             api_client: self.api_client.clone(),
             event_handler,
@@ -544,6 +581,27 @@ impl DummyConnector {
             .service(
                 web::resource("/refunds/{refund_id}")
                     .route(web::get().to(dummy_connector_refund_data)),
+            )
+            .service(
+                web::resource("/mandates/{mandate_id}/payment")
+                    .route(web::post().to(dummy_connector_mandate_payment)),
+            )
+            .service(
+                web::resource("/mandates/{mandate_id}/revoke")
+                    .route(web::post().to(dummy_connector_mandate_revoke)),
+            )
+            .service(web::resource("/payout").route(web::post().to(dummy_connector_payout)))
+            .service(
+                web::resource("/payouts/{payout_id}")
+                    .route(web::get().to(dummy_connector_payout_data)),
+            )
+            .service(
+                web::resource("/disputes/{dispute_id}/accept")
+                    .route(web::post().to(dummy_connector_dispute_accept)),
+            )
+            .service(
+                web::resource("/disputes/{dispute_id}/evidence")
+                    .route(web::post().to(dummy_connector_dispute_evidence_submit)),
             );
         web::scope("/dummy-connector")
             .app_data(web::Data::new(state))
@@ -555,6 +613,9 @@ impl DummyConnector {
                 web::resource("/complete/{attempt_id}")
                     .route(web::get().to(dummy_connector_complete_payment)),
             )
+            .service(
+                web::resource("/scenarios").route(web::get().to(dummy_connector_scenario_list)),
+            )
             .service(routes_with_restricted_access)
     }
 }
@@ -654,6 +715,97 @@ impl Relay {
     }
 }
 
+pub struct CheckoutSessions;
+
+#[cfg(feature = "v1")]
+impl CheckoutSessions {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/checkout_sessions")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("").route(web::post().to(checkout_sessions::checkout_session_create)),
+            )
+            .service(
+                web::resource("/{session_id}")
+                    .route(web::get().to(checkout_sessions::checkout_session_retrieve))
+                    .route(web::post().to(checkout_sessions::checkout_session_update)),
+            )
+            .service(
+                web::resource("/{session_id}/confirm")
+                    .route(web::post().to(checkout_sessions::checkout_session_confirm)),
+            )
+    }
+}
+
+pub struct SanctionsScreening;
+
+#[cfg(feature = "v1")]
+impl SanctionsScreening {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/screening/cases")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/{case_id}")
+                    .route(web::get().to(sanctions_screening::screening_case_retrieve)),
+            )
+            .service(
+                web::resource("/{case_id}/resolve")
+                    .route(web::post().to(sanctions_screening::screening_case_resolve)),
+            )
+    }
+}
+
+pub struct Receipts;
+
+#[cfg(feature = "v1")]
+impl Receipts {
+    pub fn server(state: AppState) -> Scope {
+        let mut route = web::scope("/receipts").app_data(web::Data::new(state));
+
+        #[cfg(feature = "email")]
+        {
+            route = route.service(
+                web::resource("/{payment_id}/email")
+                    .route(web::post().to(receipts::send_payment_receipt_email)),
+            );
+        }
+
+        route.service(
+            web::resource("/{merchant_id}/{payment_id}")
+                .route(web::get().to(receipts::retrieve_payment_receipt)),
+        )
+    }
+}
+
+pub struct Sandbox;
+
+#[cfg(feature = "v1")]
+impl Sandbox {
+    pub fn server(state: AppState) -> Scope {
+        let mut route = web::scope("/sandbox").app_data(web::Data::new(state));
+
+        #[cfg(all(feature = "olap", feature = "dummy_connector"))]
+        {
+            route = route.service(
+                web::resource("/data")
+                    .route(web::post().to(sandbox::seed_sandbox_data))
+                    .route(web::delete().to(sandbox::delete_sandbox_data)),
+            );
+        }
+
+        route
+            .service(
+                web::resource("/test_clock")
+                    .route(web::get().to(sandbox::retrieve_test_clock))
+                    .route(web::delete().to(sandbox::reset_test_clock)),
+            )
+            .service(
+                web::resource("/test_clock/advance")
+                    .route(web::post().to(sandbox::advance_test_clock)),
+            )
+    }
+}
+
 #[cfg(feature = "v1")]
 impl Payments {
     pub fn server(state: AppState) -> Scope {
@@ -695,6 +847,17 @@ impl Payments {
                     web::resource("/{payment_id}/manual-update")
                         .route(web::put().to(payments::payments_manual_update)),
                 )
+                .service(
+                    web::resource("/search/contact")
+                        .route(web::post().to(payments::payments_search_by_contact)),
+                )
+                .service(web::resource("/lookup").route(web::get().to(payments::payments_lookup)))
+                .service(web::resource("/changes").route(web::get().to(payments::payments_changes)))
+                .service(
+                    web::resource("/acquirer_reference_numbers").route(
+                        web::post().to(payments::payments_import_acquirer_reference_numbers),
+                    ),
+                )
         }
         #[cfg(feature = "oltp")]
         {
@@ -722,6 +885,10 @@ impl Payments {
                 .service(
                     web::resource("/{payment_id}/cancel").route(web::post().to(payments::payments_cancel)),
                 )
+                .service(
+                    web::resource("/{payment_id}/cancel_remaining")
+                        .route(web::post().to(payments::payments_cancel_remaining)),
+                )
                 .service(
                     web::resource("/{payment_id}/capture").route(web::post().to(payments::payments_capture)),
                 )
@@ -771,9 +938,43 @@ impl Payments {
                 .service(
                     web::resource("/{payment_id}/3ds/authentication").route(web::post().to(payments::payments_external_authentication)),
                 )
+                .service(
+                    web::resource("/{payment_id}/resume_authentication").route(web::post().to(payments::payments_resume_authentication)),
+                )
                 .service(
                     web::resource("/{payment_id}/extended_card_info").route(web::get().to(payments::retrieve_extended_card_info)),
                 )
+                .service(
+                    web::resource("/{payment_id}/timeline").route(web::get().to(payments::payments_timeline)),
+                )
+                .service(
+                    web::resource("/{payment_id}/dunning").route(web::get().to(payments::payments_dunning_retrieve)),
+                )
+                .service(
+                    web::resource("/{payment_id}/metadata").route(web::patch().to(payments::payments_metadata_update)),
+                )
+                .service(
+                    web::resource("/{payment_id}/notes")
+                        .route(web::post().to(payments::payments_notes_add))
+                        .route(web::get().to(payments::payments_notes_list)),
+                )
+                .service(
+                    web::resource("/{payment_id}/capture_schedule")
+                        .route(web::post().to(payments::payments_capture_schedule))
+                        .route(web::delete().to(payments::payments_capture_schedule_cancel)),
+                )
+                .service(
+                    web::resource("/{payment_id}/capture_schedule/release")
+                        .route(web::post().to(payments::payments_capture_schedule_release)),
+                )
+                .service(
+                    web::resource("/{payment_id}/finalize_amount")
+                        .route(web::post().to(payments::payments_finalize_amount)),
+                )
+                .service(
+                    web::resource("/{payment_id}/gift_card_balance")
+                        .route(web::post().to(payments::payments_gift_card_balance)),
+                )
                 .service(
                 web::resource("{payment_id}/calculate_tax")
                     .route(web::post().to(payments::payments_dynamic_tax_calculation)),
@@ -909,7 +1110,30 @@ impl Routing {
                 web::resource("/default/profile").route(web::get().to(|state, req| {
                     routing::routing_retrieve_default_config(state, req, &TransactionType::Payment)
                 })),
-            );
+            )
+            .service(web::resource("/import").route(web::post().to(routing::routing_import_config)))
+            .service(
+                web::scope("/experiments")
+                    .service(
+                        web::resource("")
+                            .route(web::post().to(routing::routing_experiment_create))
+                            .route(web::get().to(routing::routing_experiment_list)),
+                    )
+                    .service(
+                        web::resource("/{experiment_id}")
+                            .route(web::get().to(routing::routing_experiment_retrieve)),
+                    )
+                    .service(
+                        web::resource("/{experiment_id}/conclude")
+                            .route(web::post().to(routing::routing_experiment_conclude)),
+                    ),
+            )
+            .service(
+                web::resource("/volume_caps")
+                    .route(web::put().to(routing::routing_volume_caps_upsert))
+                    .route(web::get().to(routing::routing_volume_caps_retrieve)),
+            )
+            .service(web::resource("/simulate").route(web::post().to(routing::routing_simulate)));
 
         #[cfg(feature = "payouts")]
         {
@@ -1019,6 +1243,10 @@ impl Routing {
                 web::resource("/{algorithm_id}")
                     .route(web::get().to(routing::routing_retrieve_config)),
             )
+            .service(
+                web::resource("/{algorithm_id}/export")
+                    .route(web::get().to(routing::routing_export_config)),
+            )
             .service(
                 web::resource("/{algorithm_id}/activate").route(web::post().to(
                     |state, req, path| {
@@ -1085,6 +1313,18 @@ impl Customers {
                     web::resource("/{customer_id}/mandates")
                         .route(web::get().to(customers::get_customer_mandates)),
                 )
+                .service(
+                    web::resource("/{customer_id}/payments")
+                        .route(web::get().to(customers::get_customer_payments)),
+                )
+                .service(
+                    web::resource("/{customer_id}/store_credit")
+                        .route(web::get().to(customers::get_customer_store_credit)),
+                )
+                .service(
+                    web::resource("/{customer_id}/store_credit/entries")
+                        .route(web::get().to(customers::list_customer_store_credit_entries)),
+                )
                 .service(web::resource("/list").route(web::get().to(customers::customers_list)))
         }
 
@@ -1105,6 +1345,11 @@ impl Customers {
                     web::resource("/{customer_id}/payment_methods/{payment_method_id}/default")
                         .route(web::post().to(payment_methods::default_payment_method_set_api)),
                 )
+                .service(
+                    web::resource("/{customer_id}/payment_methods/{payment_method_id}").route(
+                        web::patch().to(payment_methods::customer_payment_method_update_api),
+                    ),
+                )
                 .service(
                     web::resource("/{customer_id}")
                         .route(web::get().to(customers::customers_retrieve))
@@ -1149,6 +1394,15 @@ impl Refunds {
             route = route
                 .service(web::resource("").route(web::post().to(refunds_create)))
                 .service(web::resource("/sync").route(web::post().to(refunds_retrieve_with_body)))
+                .service(web::resource("/batch").route(web::post().to(refunds_batch_create)))
+                .service(
+                    web::resource("/batch/{batch_id}").route(web::get().to(refunds_batch_retrieve)),
+                )
+                .service(
+                    web::resource("/batch/{batch_id}/report")
+                        .route(web::get().to(refunds_batch_report)),
+                )
+                .service(web::resource("/{id}/resolve").route(web::post().to(refunds_resolve)))
                 .service(
                     web::resource("/{id}")
                         .route(web::get().to(refunds_retrieve))
@@ -1270,6 +1524,10 @@ impl PaymentMethods {
                     web::resource("/migrate-batch")
                         .route(web::post().to(payment_methods::migrate_payment_methods)),
                 )
+                .service(
+                    web::resource("/migrate-external-tokens")
+                        .route(web::post().to(payment_methods::migrate_external_tokens_api)),
+                )
                 .service(
                     web::resource("/tokenize-card")
                         .route(web::post().to(payment_methods::tokenize_card_api)),
@@ -1278,6 +1536,10 @@ impl PaymentMethods {
                     web::resource("/tokenize-card-batch")
                         .route(web::post().to(payment_methods::tokenize_card_batch_api)),
                 )
+                .service(
+                    web::resource("/tokenize-cvv")
+                        .route(web::post().to(payment_methods::tokenize_cvv_api)),
+                )
                 .service(
                     web::resource("/collect")
                         .route(web::post().to(payment_methods::initiate_pm_collect_link_flow)),
@@ -1286,6 +1548,11 @@ impl PaymentMethods {
                     web::resource("/collect/{merchant_id}/{collect_id}")
                         .route(web::get().to(payment_methods::render_pm_collect_link)),
                 )
+                .service(
+                    web::resource("/display_names").route(
+                        web::get().to(payment_methods::list_payment_method_display_names_api),
+                    ),
+                )
                 .service(
                     web::resource("/{payment_method_id}")
                         .route(web::get().to(payment_methods::payment_method_retrieve_api))
@@ -1299,6 +1566,15 @@ impl PaymentMethods {
                     web::resource("/{payment_method_id}/update")
                         .route(web::post().to(payment_methods::payment_method_update_api)),
                 )
+                .service(
+                    web::resource("/{payment_method_id}/consent/recollect").route(
+                        web::post().to(payment_methods::payment_method_consent_recollect_api),
+                    ),
+                )
+                .service(
+                    web::resource("/{payment_method_id}/consent/revoke")
+                        .route(web::post().to(payment_methods::payment_method_consent_revoke_api)),
+                )
                 .service(
                     web::resource("/{payment_method_id}/save")
                         .route(web::post().to(payment_methods::save_payment_method_api)),
@@ -1494,6 +1770,43 @@ impl MerchantAccount {
             .service(
                 web::resource("/kv").route(web::post().to(admin::merchant_account_toggle_all_kv)),
             )
+            .service(
+                web::resource("/kv/divergences")
+                    .route(web::get().to(admin::kv_pg_divergences_list)),
+            )
+            .service(
+                web::resource("/{id}/feature_flags")
+                    .route(web::get().to(admin::merchant_feature_flags_retrieve))
+                    .route(web::put().to(admin::merchant_feature_flags_update)),
+            )
+            .service(
+                web::resource("/{id}/fault_injection")
+                    .route(web::put().to(admin::merchant_fault_injection_upsert))
+                    .route(web::get().to(admin::merchant_fault_injection_retrieve))
+                    .route(web::delete().to(admin::merchant_fault_injection_delete)),
+            )
+            .service(
+                web::resource("/{id}/status")
+                    .route(web::post().to(admin::merchant_account_status_update)),
+            )
+            .service(
+                web::resource("/{id}/offboard")
+                    .route(web::post().to(admin::merchant_account_offboard))
+                    .route(web::get().to(admin::merchant_account_offboarding_retrieve)),
+            )
+            .service(
+                web::resource("/{id}/connector_transaction_id_backfill")
+                    .route(web::post().to(admin::connector_transaction_id_backfill_create)),
+            )
+            .service(
+                web::resource("/{id}/connector_transaction_id_backfill/{backfill_id}")
+                    .route(web::get().to(admin::connector_transaction_id_backfill_retrieve)),
+            )
+            .service(
+                web::resource("/{id}/data_residency")
+                    .route(web::post().to(admin::data_residency_create))
+                    .route(web::get().to(admin::data_residency_retrieve)),
+            )
             .service(
                 web::resource("/{id}")
                     .route(web::get().to(admin::retrieve_merchant_account))
@@ -1553,11 +1866,41 @@ impl MerchantConnectorAccount {
                         .route(web::post().to(connector_create))
                         .route(web::get().to(connector_list)),
                 )
+                .service(
+                    web::resource("/{merchant_id}/connectors/bulk")
+                        .route(web::post().to(connector_bulk_config)),
+                )
                 .service(
                     web::resource("/{merchant_id}/connectors/{merchant_connector_id}")
                         .route(web::get().to(connector_retrieve))
                         .route(web::post().to(connector_update))
                         .route(web::delete().to(connector_delete)),
+                )
+                .service(
+                    web::resource("/{merchant_id}/connectors/{merchant_connector_id}/restore")
+                        .route(web::post().to(connector_restore)),
+                )
+                .service(
+                    web::resource(
+                        "/{merchant_id}/connectors/{merchant_connector_id}/maintenance_windows",
+                    )
+                    .route(web::put().to(connector_maintenance_windows_upsert))
+                    .route(web::get().to(connector_maintenance_windows_retrieve)),
+                )
+                .service(
+                    web::resource("/{merchant_id}/connectors/credentials/expiring")
+                        .route(web::get().to(connector_credentials_expiring_list)),
+                )
+                .service(
+                    web::resource("/{merchant_id}/connectors/{merchant_connector_id}/credentials")
+                        .route(web::get().to(connector_credentials_retrieve))
+                        .route(web::put().to(connector_credentials_update)),
+                )
+                .service(
+                    web::resource(
+                        "/{merchant_id}/connectors/{merchant_connector_id}/credentials/verify",
+                    )
+                    .route(web::post().to(connector_credentials_verify)),
                 );
         }
         #[cfg(feature = "oltp")]
@@ -1742,6 +2085,17 @@ impl Poll {
     }
 }
 
+pub struct SdkEvents;
+
+#[cfg(feature = "oltp")]
+impl SdkEvents {
+    pub fn server(config: AppState) -> Scope {
+        web::scope("/sdk_events")
+            .app_data(web::Data::new(config))
+            .service(web::resource("/batch").route(web::post().to(sdk_events::ingest_sdk_events)))
+    }
+}
+
 pub struct ApiKeys;
 
 #[cfg(all(feature = "olap", feature = "v2"))]
@@ -1804,6 +2158,11 @@ impl Disputes {
                 web::resource("/profile/aggregate")
                     .route(web::get().to(disputes::get_disputes_aggregate_profile)),
             )
+            .service(web::resource("/balance").route(web::get().to(disputes::get_disputes_balance)))
+            .service(
+                web::resource("/profile/balance")
+                    .route(web::get().to(disputes::get_disputes_balance_profile)),
+            )
             .service(
                 web::resource("/evidence")
                     .route(web::post().to(disputes::submit_dispute_evidence))
@@ -2037,6 +2396,114 @@ impl Profile {
                 .service(
                     web::resource("/toggle_connector_agnostic_mit")
                         .route(web::post().to(profiles::toggle_connector_agnostic_mit)),
+                )
+                .service(
+                    web::resource("/payment_method_display_config")
+                        .route(web::put().to(profiles::payment_method_display_config_upsert))
+                        .route(web::get().to(profiles::payment_method_display_config_retrieve))
+                        .route(web::delete().to(profiles::payment_method_display_config_delete)),
+                )
+                .service(
+                    web::resource("/payment_method_display_names")
+                        .route(web::put().to(profiles::payment_method_display_names_upsert))
+                        .route(web::get().to(profiles::payment_method_display_names_retrieve))
+                        .route(web::delete().to(profiles::payment_method_display_names_delete)),
+                )
+                .service(
+                    web::resource("/external_vault_config")
+                        .route(web::put().to(profiles::external_vault_config_upsert))
+                        .route(web::get().to(profiles::external_vault_config_retrieve))
+                        .route(web::delete().to(profiles::external_vault_config_delete)),
+                )
+                .service(
+                    web::resource("/cvv_recollection_config")
+                        .route(web::put().to(profiles::cvv_recollection_config_upsert))
+                        .route(web::get().to(profiles::cvv_recollection_config_retrieve)),
+                )
+                .service(
+                    web::resource("/network_token_preference_config")
+                        .route(web::put().to(profiles::network_token_preference_config_upsert))
+                        .route(web::get().to(profiles::network_token_preference_config_retrieve)),
+                )
+                .service(
+                    web::resource("/feature_flags")
+                        .route(web::put().to(profiles::profile_feature_flags_update))
+                        .route(web::get().to(profiles::profile_feature_flags_retrieve)),
+                )
+                .service(
+                    web::resource("/payment_facilitator_details")
+                        .route(web::put().to(profiles::payment_facilitator_details_upsert))
+                        .route(web::get().to(profiles::payment_facilitator_details_retrieve))
+                        .route(web::delete().to(profiles::payment_facilitator_details_delete)),
+                )
+                .service(
+                    web::resource("/custom_payment_status_mapping")
+                        .route(web::put().to(profiles::custom_payment_status_mapping_upsert))
+                        .route(web::get().to(profiles::custom_payment_status_mapping_retrieve))
+                        .route(web::delete().to(profiles::custom_payment_status_mapping_delete)),
+                )
+                .service(
+                    web::resource("/custom_fields_schema")
+                        .route(web::put().to(profiles::custom_fields_schema_upsert))
+                        .route(web::get().to(profiles::custom_fields_schema_retrieve))
+                        .route(web::delete().to(profiles::custom_fields_schema_delete)),
+                )
+                .service(
+                    web::resource("/unified_error_message_overrides")
+                        .route(web::put().to(profiles::unified_error_message_overrides_upsert))
+                        .route(web::get().to(profiles::unified_error_message_overrides_retrieve))
+                        .route(web::delete().to(profiles::unified_error_message_overrides_delete)),
+                )
+                .service(
+                    web::resource("/post_authorization_rules")
+                        .route(web::put().to(profiles::post_authorization_rules_upsert))
+                        .route(web::get().to(profiles::post_authorization_rules_retrieve))
+                        .route(web::delete().to(profiles::post_authorization_rules_delete)),
+                )
+                .service(
+                    web::resource("/checkout_theme")
+                        .route(web::put().to(profiles::checkout_theme_upsert))
+                        .route(web::get().to(profiles::checkout_theme_retrieve))
+                        .route(web::delete().to(profiles::checkout_theme_delete)),
+                )
+                .service(
+                    web::resource("/checkout_theme/versions")
+                        .route(web::get().to(profiles::checkout_theme_versions_list)),
+                )
+                .service(
+                    web::resource("/checkout_theme/activate")
+                        .route(web::post().to(profiles::checkout_theme_activate)),
+                )
+                .service(
+                    web::resource("/dunning_config")
+                        .route(web::put().to(profiles::dunning_config_upsert))
+                        .route(web::get().to(profiles::dunning_config_retrieve))
+                        .route(web::delete().to(profiles::dunning_config_delete)),
+                )
+                .service(
+                    web::resource("/auto_capture_delay_config")
+                        .route(web::put().to(profiles::auto_capture_delay_config_upsert))
+                        .route(web::get().to(profiles::auto_capture_delay_config_retrieve))
+                        .route(web::delete().to(profiles::auto_capture_delay_config_delete)),
+                )
+                .service(
+                    web::resource("/step_up_authentication_config")
+                        .route(web::put().to(profiles::step_up_authentication_config_upsert))
+                        .route(web::get().to(profiles::step_up_authentication_config_retrieve))
+                        .route(web::delete().to(profiles::step_up_authentication_config_delete)),
+                )
+                .service(
+                    web::resource("/test_mode")
+                        .route(web::put().to(profiles::profile_test_mode_update))
+                        .route(web::get().to(profiles::profile_test_mode_retrieve)),
+                )
+                .service(
+                    web::resource("/test_data/purge")
+                        .route(web::post().to(profiles::test_data_purge)),
+                )
+                .service(
+                    web::resource("/coverage")
+                        .route(web::get().to(profiles::profile_coverage_retrieve)),
                 ),
         );
 
@@ -2079,6 +2546,30 @@ impl Gsm {
     }
 }
 
+#[cfg(all(feature = "olap", feature = "v1"))]
+pub struct ProcessTracker;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl ProcessTracker {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/process_tracker/tasks")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::get().to(process_tracker::list_scheduler_tasks)))
+            .service(
+                web::resource("/summary")
+                    .route(web::get().to(process_tracker::retrieve_scheduler_tasks_summary)),
+            )
+            .service(
+                web::resource("/{task_id}/requeue")
+                    .route(web::post().to(process_tracker::requeue_scheduler_task)),
+            )
+            .service(
+                web::resource("/{task_id}/abandon")
+                    .route(web::post().to(process_tracker::abandon_scheduler_task)),
+            )
+    }
+}
+
 #[cfg(feature = "olap")]
 pub struct Verify;
 
@@ -2450,6 +2941,18 @@ impl ConnectorOnboarding {
                 web::resource("/reset_tracking_id")
                     .route(web::post().to(connector_onboarding::reset_tracking_id)),
             )
+            .service(
+                web::resource("/oauth/authorize")
+                    .route(web::post().to(connector_onboarding::oauth_authorize)),
+            )
+            .service(
+                web::resource("/oauth/callback")
+                    .route(web::get().to(connector_onboarding::oauth_callback)),
+            )
+            .service(
+                web::resource("/oauth/status")
+                    .route(web::post().to(connector_onboarding::oauth_status)),
+            )
     }
 }
 
@@ -2461,6 +2964,8 @@ impl WebhookEvents {
     pub fn server(config: AppState) -> Scope {
         web::scope("/events")
             .app_data(web::Data::new(config))
+            .service(web::resource("").route(web::get().to(webhook_events::list_lifecycle_events)))
+            .service(web::resource("/types").route(web::get().to(webhook_events::list_event_types)))
             .service(web::scope("/profile/list").service(web::resource("").route(
                 web::get().to(webhook_events::list_initial_webhook_delivery_attempts_with_jwtauth),
             )))
@@ -2482,6 +2987,108 @@ impl WebhookEvents {
     }
 }
 
+#[cfg(feature = "olap")]
+pub struct WebhookPayloadTemplates;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl WebhookPayloadTemplates {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/webhook_payload_templates")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("").route(
+                    web::get().to(webhook_payload_templates::list_webhook_payload_templates),
+                ),
+            )
+            .service(web::resource("/validate").route(
+                web::post().to(webhook_payload_templates::validate_webhook_payload_template),
+            ))
+            .service(
+                web::resource("/preview").route(
+                    web::post().to(webhook_payload_templates::preview_webhook_payload_template),
+                ),
+            )
+            .service(
+                web::resource("/{event_type}")
+                    .route(
+                        web::get().to(webhook_payload_templates::retrieve_webhook_payload_template),
+                    )
+                    .route(web::post().to(webhook_payload_templates::set_webhook_payload_template))
+                    .route(
+                        web::delete()
+                            .to(webhook_payload_templates::delete_webhook_payload_template),
+                    ),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct WebhookEndpoints;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl WebhookEndpoints {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/webhooks/endpoints/{endpoint_id}")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/health")
+                    .route(web::get().to(webhook_endpoints::retrieve_webhook_endpoint_health)),
+            )
+            .service(
+                web::resource("/resume")
+                    .route(web::post().to(webhook_endpoints::resume_webhook_endpoint)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct WarehouseSync;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl WarehouseSync {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/warehouse_sync")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/config")
+                    .route(web::get().to(warehouse_sync::retrieve_warehouse_sync_config))
+                    .route(web::post().to(warehouse_sync::set_warehouse_sync_config))
+                    .route(web::delete().to(warehouse_sync::delete_warehouse_sync_config)),
+            )
+            .service(
+                web::resource("/status")
+                    .route(web::get().to(warehouse_sync::retrieve_warehouse_sync_status)),
+            )
+            .service(
+                web::resource("/trigger")
+                    .route(web::post().to(warehouse_sync::trigger_warehouse_sync)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct ScaCompliance;
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+impl ScaCompliance {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/compliance/sca_reports")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::post().to(sca_compliance::create_sca_compliance_report)),
+            )
+            .service(
+                web::resource("/{report_id}")
+                    .route(web::get().to(sca_compliance::retrieve_sca_compliance_report)),
+            )
+            .service(
+                web::resource("/{report_id}/download")
+                    .route(web::get().to(sca_compliance::download_sca_compliance_report)),
+            )
+    }
+}
+
 #[cfg(feature = "olap")]
 pub struct FeatureMatrix;
 
@@ -0,0 +1,184 @@
+use actix_web::{web, Responder};
+use hyperswitch_domain_models::payments::HeaderPayload;
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    core::{api_locking, checkout_sessions, errors::http_not_implemented},
+    routes::lock_utils,
+    services::{api, authentication as auth, authorization::permissions::Permission},
+    types::{api::enums as api_enums, transformers::ForeignTryFrom},
+    AppState,
+};
+
+fn get_checkout_session_locking_action(
+    checkout_session_id: &str,
+    flow: Flow,
+) -> api_locking::LockAction {
+    api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: checkout_session_id.to_owned(),
+            api_identifier: lock_utils::ApiIdentifier::from(flow),
+            override_lock_retries: None,
+        },
+    }
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::CheckoutSessionCreate))]
+pub async fn checkout_session_create(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<api_models::checkout_sessions::CheckoutSessionCreateRequest>,
+) -> impl Responder {
+    let flow = Flow::CheckoutSessionCreate;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            checkout_sessions::create_checkout_session(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::CheckoutSessionRetrieve))]
+pub async fn checkout_session_retrieve(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::CheckoutSessionRetrieve;
+    let checkout_session_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        checkout_session_id,
+        |state, auth: auth::AuthenticationData, checkout_session_id, _| {
+            checkout_sessions::retrieve_checkout_session(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                checkout_session_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::CheckoutSessionUpdate))]
+pub async fn checkout_session_update(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_models::checkout_sessions::CheckoutSessionUpdateRequest>,
+) -> impl Responder {
+    let flow = Flow::CheckoutSessionUpdate;
+    let checkout_session_id = path.into_inner();
+    let locking_action = get_checkout_session_locking_action(&checkout_session_id, flow.clone());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            checkout_sessions::update_checkout_session_cart(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                checkout_session_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentWrite,
+            },
+            req.headers(),
+        ),
+        locking_action,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::CheckoutSessionConfirm))]
+pub async fn checkout_session_confirm(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_models::payments::PaymentsRequest>,
+) -> impl Responder {
+    let flow = Flow::CheckoutSessionConfirm;
+    let checkout_session_id = path.into_inner();
+    let payload = json_payload.into_inner();
+
+    if let Some(api_enums::CaptureMethod::Scheduled) = payload.capture_method {
+        return http_not_implemented();
+    };
+
+    let header_payload = match HeaderPayload::foreign_try_from(req.headers()) {
+        Ok(headers) => headers,
+        Err(err) => {
+            return api::log_and_return_error_response(err);
+        }
+    };
+    let locking_action = get_checkout_session_locking_action(&checkout_session_id, flow.clone());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            checkout_sessions::confirm_checkout_session(
+                state,
+                req_state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                checkout_session_id.clone(),
+                req,
+                header_payload.clone(),
+                auth.platform_merchant_account,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentWrite,
+            },
+            req.headers(),
+        ),
+        locking_action,
+    ))
+    .await
+}
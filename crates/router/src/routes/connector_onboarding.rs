@@ -70,3 +70,69 @@ pub async fn reset_tracking_id(
     ))
     .await
 }
+
+#[cfg(feature = "v1")]
+pub async fn oauth_authorize(
+    state: web::Data<AppState>,
+    http_req: HttpRequest,
+    json_payload: web::Json<api_types::OAuthAuthorizeRequest>,
+) -> HttpResponse {
+    let flow = Flow::ConnectorOAuthAuthorize;
+    let req_payload = json_payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow.clone(),
+        state,
+        &http_req,
+        req_payload.clone(),
+        core::oauth::authorize,
+        &auth::JWTAuth {
+            permission: Permission::MerchantAccountWrite,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// The connector redirects the merchant to this endpoint, which is authenticated with the `state`
+/// parameter issued when the authorization was started
+#[cfg(feature = "v1")]
+pub async fn oauth_callback(
+    state: web::Data<AppState>,
+    http_req: HttpRequest,
+    query_payload: web::Query<api_types::OAuthCallbackRequest>,
+) -> HttpResponse {
+    let flow = Flow::ConnectorOAuthCallback;
+    let req_payload = query_payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow.clone(),
+        state,
+        &http_req,
+        req_payload,
+        core::oauth::handle_callback,
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+pub async fn oauth_status(
+    state: web::Data<AppState>,
+    http_req: HttpRequest,
+    json_payload: web::Json<api_types::OAuthStatusRequest>,
+) -> HttpResponse {
+    let flow = Flow::ConnectorOAuthStatus;
+    let req_payload = json_payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow.clone(),
+        state,
+        &http_req,
+        req_payload.clone(),
+        core::oauth::retrieve_status,
+        &auth::JWTAuth {
+            permission: Permission::MerchantAccountRead,
+        },
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
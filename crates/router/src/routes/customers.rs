@@ -386,3 +386,115 @@ pub async fn get_customer_mandates(
     ))
     .await
 }
+
+#[cfg(all(feature = "olap", feature = "v1", not(feature = "customer_v2")))]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersPaymentsList))]
+pub async fn get_customer_payments(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::CustomerId>,
+    query: web::Query<customers::CustomerPaymentsListRequest>,
+) -> impl Responder {
+    let flow = Flow::CustomersPaymentsList;
+    let customer_id = path.into_inner();
+    let payload = query.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, request, _| {
+            crate::core::customers::payment_history::list_customer_payments(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                customer_id.clone(),
+                request,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1", not(feature = "customer_v2")))]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersStoreCreditRetrieve))]
+pub async fn get_customer_store_credit(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::CustomerId>,
+) -> impl Responder {
+    let flow = Flow::CustomersStoreCreditRetrieve;
+    let customer_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            crate::core::customers::store_credit::get_customer_store_credit(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                customer_id.clone(),
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantCustomerRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1", not(feature = "customer_v2")))]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersStoreCreditEntriesList))]
+pub async fn list_customer_store_credit_entries(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::CustomerId>,
+    query: web::Query<customers::StoreCreditEntriesListRequest>,
+) -> impl Responder {
+    let flow = Flow::CustomersStoreCreditEntriesList;
+    let customer_id = path.into_inner();
+    let payload = query.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, request, _| {
+            crate::core::customers::store_credit::list_customer_store_credit_entries(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                customer_id.clone(),
+                request,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantCustomerRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -58,6 +58,8 @@ pub async fn retrieve_dispute(
     ))
     .await
 }
+
+#[cfg(feature = "v1")]
 /// Disputes - List Disputes
 #[utoipa::path(
     get,
@@ -73,6 +75,7 @@ pub async fn retrieve_dispute(
         ("received_time.gt" = Option<PrimitiveDateTime>, Query, description = "Time greater than the dispute received time"),
         ("received_time.lte" = Option<PrimitiveDateTime>, Query, description = "Time less than or equals to the dispute received time"),
         ("received_time.gte" = Option<PrimitiveDateTime>, Query, description = "Time greater than or equals to the dispute received time"),
+        ("payment_context" = Option<String>, Query, description = "The comma separated list of the fields of the disputed payment to embed in the disputes, among amount, payment_method, customer_email_hash and connector"),
     ),
     responses(
         (status = 200, description = "The dispute list was retrieved successfully", body = Vec<DisputeResponse>),
@@ -96,7 +99,13 @@ pub async fn retrieve_disputes_list(
         &req,
         payload,
         |state, auth: auth::AuthenticationData, req, _| {
-            disputes::retrieve_disputes_list(state, auth.merchant_account, None, req)
+            disputes::retrieve_disputes_list(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                None,
+                req,
+            )
         },
         auth::auth_type(
             &auth::HeaderAuth(auth::ApiKeyAuth),
@@ -126,6 +135,7 @@ pub async fn retrieve_disputes_list(
         ("received_time.gt" = Option<PrimitiveDateTime>, Query, description = "Time greater than the dispute received time"),
         ("received_time.lte" = Option<PrimitiveDateTime>, Query, description = "Time less than or equals to the dispute received time"),
         ("received_time.gte" = Option<PrimitiveDateTime>, Query, description = "Time greater than or equals to the dispute received time"),
+        ("payment_context" = Option<String>, Query, description = "The comma separated list of the fields of the disputed payment to embed in the disputes, among amount, payment_method, customer_email_hash and connector"),
     ),
     responses(
         (status = 200, description = "The dispute list was retrieved successfully", body = Vec<DisputeResponse>),
@@ -152,6 +162,7 @@ pub async fn retrieve_disputes_list_profile(
             disputes::retrieve_disputes_list(
                 state,
                 auth.merchant_account,
+                auth.key_store,
                 auth.profile_id.map(|profile_id| vec![profile_id]),
                 req,
             )
@@ -549,3 +560,59 @@ pub async fn get_disputes_aggregate_profile(
     ))
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::DisputesBalance))]
+pub async fn get_disputes_balance(state: web::Data<AppState>, req: HttpRequest) -> HttpResponse {
+    let flow = Flow::DisputesBalance;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            disputes::ledger::get_disputes_balance(state, auth.merchant_account, None)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantDisputeRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::DisputesBalance))]
+pub async fn get_disputes_balance_profile(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::DisputesBalance;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            disputes::ledger::get_disputes_balance(
+                state,
+                auth.merchant_account,
+                auth.profile_id.map(|profile_id| vec![profile_id]),
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfileDisputeRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
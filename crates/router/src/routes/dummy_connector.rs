@@ -10,8 +10,10 @@ use crate::{
 mod consts;
 mod core;
 mod errors;
+mod scenarios;
 pub mod types;
 mod utils;
+mod webhooks;
 
 #[cfg(all(feature = "dummy_connector", feature = "v1"))]
 #[instrument(skip_all, fields(flow = ?types::Flow::DummyPaymentCreate))]
@@ -148,3 +150,155 @@ pub async fn dummy_connector_refund_data(
     )
     .await
 }
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyScenarioList))]
+pub async fn dummy_connector_scenario_list(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyScenarioList;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _: (), _, _| core::scenario_list(state),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyMandatePayment))]
+pub async fn dummy_connector_mandate_payment(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<types::DummyConnectorMandatePaymentRequest>,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyMandatePayment;
+    let mut payload = json_payload.into_inner();
+    payload.mandate_id = Some(path.into_inner());
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::mandate_payment(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyMandateRevoke))]
+pub async fn dummy_connector_mandate_revoke(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyMandateRevoke;
+    let mandate_id = path.into_inner();
+    let payload = types::DummyConnectorMandateRevokeRequest { mandate_id };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::mandate_revoke(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyPayoutCreate))]
+pub async fn dummy_connector_payout(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<types::DummyConnectorPayoutRequest>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyPayoutCreate;
+    let payload = json_payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::payout(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyPayoutRetrieve))]
+pub async fn dummy_connector_payout_data(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyPayoutRetrieve;
+    let payout_id = path.into_inner();
+    let payload = types::DummyConnectorPayoutRetrieveRequest { payout_id };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::payout_data(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyDisputeAccept))]
+pub async fn dummy_connector_dispute_accept(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyDisputeAccept;
+    let dispute_id = path.into_inner();
+    let payload = types::DummyConnectorDisputeRequest { dispute_id };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::dispute_accept(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+#[instrument(skip_all, fields(flow = ?types::Flow::DummyDisputeEvidenceSubmit))]
+pub async fn dummy_connector_dispute_evidence_submit(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<types::DummyConnectorDisputeEvidenceRequest>,
+    path: web::Path<String>,
+) -> impl actix_web::Responder {
+    let flow = types::Flow::DummyDisputeEvidenceSubmit;
+    let mut payload = json_payload.into_inner();
+    payload.dispute_id = Some(path.into_inner());
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _: (), req, _| core::dispute_evidence_submit(state, req),
+        &auth::NoAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
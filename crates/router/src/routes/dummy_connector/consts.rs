@@ -1,3 +1,11 @@
 pub const ATTEMPT_ID_PREFIX: &str = "dummy_attempt";
 pub const REFUND_ID_PREFIX: &str = "dummy_ref";
+pub const DISPUTE_ID_PREFIX: &str = "dummy_dp";
+pub const MANDATE_ID_PREFIX: &str = "dummy_mandate";
+pub const PAYOUT_ID_PREFIX: &str = "dummy_po";
 pub const THREE_DS_CSS: &str = include_str!("threeds_page.css");
+
+/// Delay after which the dispute of a payment of the dispute creation scenario is opened
+pub const DISPUTE_WEBHOOK_DELAY_IN_SECONDS: u64 = 5;
+/// Delay after which the success of a payment of the delayed webhook scenario is notified
+pub const DELAYED_WEBHOOK_DELAY_IN_SECONDS: u64 = 30;
@@ -1,8 +1,9 @@
 use app::SessionState;
 use common_utils::generate_id_with_default_len;
-use error_stack::ResultExt;
+use error_stack::{report, ResultExt};
+use masking::PeekInterface;
 
-use super::{errors, types, utils};
+use super::{errors, scenarios, types, utils, webhooks};
 use crate::{
     routes::{app, dummy_connector::consts},
     services::api,
@@ -19,11 +20,40 @@ pub async fn payment(
         state.conf.dummy_connector.payment_tolerance,
     )
     .await;
+    utils::simulate_processor(req.simulation).await?;
 
+    let setup_mandate = req.setup_mandate;
     let payment_attempt: types::DummyConnectorPaymentAttempt = req.into();
-    let payment_data =
+    let payment_method_data = payment_attempt.payment_request.payment_method_data.clone();
+    let mut payment_data =
         types::DummyConnectorPaymentData::process_payment_attempt(&state, payment_attempt)?;
 
+    if setup_mandate {
+        let mandate = types::DummyConnectorMandate {
+            mandate_id: generate_id_with_default_len(consts::MANDATE_ID_PREFIX),
+            status: match payment_data.status {
+                types::DummyConnectorStatus::Succeeded => {
+                    types::DummyConnectorMandateStatus::Active
+                }
+                types::DummyConnectorStatus::Processing | types::DummyConnectorStatus::Failed => {
+                    types::DummyConnectorMandateStatus::Pending
+                }
+            },
+            payment_id: payment_data.payment_id.clone(),
+            payment_method_data,
+            connector: payment_data.connector.clone(),
+            created: common_utils::date_time::now(),
+        };
+        utils::store_data_in_redis(
+            &state,
+            mandate.mandate_id.clone(),
+            mandate.clone(),
+            state.conf.dummy_connector.mandate_ttl,
+        )
+        .await?;
+        payment_data.mandate_id = Some(mandate.mandate_id);
+    }
+
     utils::store_data_in_redis(
         &state,
         payment_data.attempt_id.clone(),
@@ -38,6 +68,7 @@ pub async fn payment(
         state.conf.dummy_connector.payment_ttl,
     )
     .await?;
+    webhooks::schedule_scenario_webhook(&state, &payment_data);
     Ok(api::ApplicationResponse::Json(payment_data.into()))
 }
 
@@ -158,6 +189,8 @@ pub async fn refund_payment(
     )
     .await;
 
+    utils::simulate_processor(req.simulation).await?;
+
     let payment_id = req
         .payment_id
         .get_required_value("payment_id")
@@ -227,3 +260,234 @@ pub async fn refund_data(
         .change_context(errors::DummyConnectorErrors::RefundNotFound)?;
     Ok(api::ApplicationResponse::Json(refund_data))
 }
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+pub async fn scenario_list(
+    _state: SessionState,
+) -> types::DummyConnectorResponse<types::DummyConnectorScenarioListResponse> {
+    Ok(api::ApplicationResponse::Json(
+        scenarios::get_scenario_registry(),
+    ))
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+async fn get_mandate(
+    state: &SessionState,
+    mandate_id: &str,
+) -> types::DummyConnectorResult<types::DummyConnectorMandate> {
+    utils::get_data_from_redis(
+        state,
+        mandate_id,
+        "DummyConnectorMandate",
+        errors::DummyConnectorErrors::MandateNotFound,
+    )
+    .await
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+pub async fn mandate_payment(
+    state: SessionState,
+    req: types::DummyConnectorMandatePaymentRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorPaymentResponse> {
+    let mandate_id = req
+        .mandate_id
+        .get_required_value("mandate_id")
+        .change_context(errors::DummyConnectorErrors::MissingRequiredField {
+            field_name: "mandate_id",
+        })?;
+    let mut mandate = get_mandate(&state, &mandate_id).await?;
+
+    // The mandate becomes active once the payment which set it up succeeds
+    if mandate.status == types::DummyConnectorMandateStatus::Pending {
+        let setup_payment = utils::get_payment_data_from_payment_id(
+            &state,
+            mandate.payment_id.get_string_repr().to_owned(),
+        )
+        .await?;
+        if setup_payment.status == types::DummyConnectorStatus::Succeeded {
+            mandate.status = types::DummyConnectorMandateStatus::Active;
+            utils::store_data_in_redis(
+                &state,
+                mandate_id.clone(),
+                mandate.clone(),
+                state.conf.dummy_connector.mandate_ttl,
+            )
+            .await?;
+        }
+    }
+    if mandate.status != types::DummyConnectorMandateStatus::Active {
+        return Err(report!(errors::DummyConnectorErrors::MandateNotActive));
+    }
+
+    let payment_request = types::DummyConnectorPaymentRequest {
+        amount: req.amount,
+        currency: req.currency,
+        payment_method_data: mandate.payment_method_data,
+        return_url: req.return_url,
+        connector: mandate.connector,
+        webhook_url: req.webhook_url,
+        setup_mandate: false,
+        simulation: req.simulation,
+    };
+    payment(state, payment_request).await
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+pub async fn mandate_revoke(
+    state: SessionState,
+    req: types::DummyConnectorMandateRevokeRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorMandateResponse> {
+    let mut mandate = get_mandate(&state, &req.mandate_id).await?;
+    mandate.status = types::DummyConnectorMandateStatus::Revoked;
+    utils::store_data_in_redis(
+        &state,
+        req.mandate_id,
+        mandate.clone(),
+        state.conf.dummy_connector.mandate_ttl,
+    )
+    .await?;
+    Ok(api::ApplicationResponse::Json(mandate.into()))
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+pub async fn payout(
+    state: SessionState,
+    req: types::DummyConnectorPayoutRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorPayoutResponse> {
+    utils::tokio_mock_sleep(
+        state.conf.dummy_connector.payment_duration,
+        state.conf.dummy_connector.payment_tolerance,
+    )
+    .await;
+    utils::simulate_processor(req.simulation).await?;
+
+    let types::DummyConnectorPayoutMethodData::Card(card) = req.payout_method_data;
+    let test_card = scenarios::get_test_card(card.number.peek())
+        .ok_or(errors::DummyConnectorErrors::CardNotSupported)
+        .attach_printable("The card is not supported")?;
+
+    // The payouts to the cards which decline payments fail as well
+    let (status, error_message) = match test_card.scenario {
+        types::DummyConnectorScenario::HardDecline | types::DummyConnectorScenario::SoftDecline => {
+            (
+                types::DummyConnectorStatus::Failed,
+                Some(
+                    test_card
+                        .decline_message
+                        .unwrap_or(scenarios::get_default_decline_message(test_card.scenario))
+                        .to_string(),
+                ),
+            )
+        }
+        types::DummyConnectorScenario::Success
+        | types::DummyConnectorScenario::ThreeDsChallenge
+        | types::DummyConnectorScenario::DisputeCreation
+        | types::DummyConnectorScenario::DelayedWebhook => {
+            (types::DummyConnectorStatus::Succeeded, None)
+        }
+    };
+
+    let payout_data = types::DummyConnectorPayoutResponse {
+        id: generate_id_with_default_len(consts::PAYOUT_ID_PREFIX),
+        status,
+        amount: req.amount,
+        currency: req.currency,
+        created: common_utils::date_time::now(),
+        error_message,
+    };
+    utils::store_data_in_redis(
+        &state,
+        payout_data.id.clone(),
+        payout_data.clone(),
+        state.conf.dummy_connector.payment_ttl,
+    )
+    .await?;
+    Ok(api::ApplicationResponse::Json(payout_data))
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+pub async fn payout_data(
+    state: SessionState,
+    req: types::DummyConnectorPayoutRetrieveRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorPayoutResponse> {
+    utils::tokio_mock_sleep(
+        state.conf.dummy_connector.payment_retrieve_duration,
+        state.conf.dummy_connector.payment_retrieve_tolerance,
+    )
+    .await;
+
+    let payout_data = utils::get_data_from_redis(
+        &state,
+        &req.payout_id,
+        "DummyConnectorPayoutResponse",
+        errors::DummyConnectorErrors::PayoutNotFound,
+    )
+    .await?;
+    Ok(api::ApplicationResponse::Json(payout_data))
+}
+
+/// Closes the open dispute with the given status
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+async fn update_dispute_status(
+    state: &SessionState,
+    dispute_id: &str,
+    status: types::DummyConnectorDisputeStatus,
+) -> types::DummyConnectorResponse<types::DummyConnectorDisputeResponse> {
+    let mut dispute: types::DummyConnectorDispute = utils::get_data_from_redis(
+        state,
+        dispute_id,
+        "DummyConnectorDispute",
+        errors::DummyConnectorErrors::DisputeNotFound,
+    )
+    .await?;
+    if dispute.status != types::DummyConnectorDisputeStatus::Opened {
+        return Err(report!(errors::DummyConnectorErrors::DisputeNotOpen));
+    }
+
+    dispute.status = status;
+    utils::store_data_in_redis(
+        state,
+        dispute.id.clone(),
+        dispute.clone(),
+        state.conf.dummy_connector.payment_ttl,
+    )
+    .await?;
+    Ok(api::ApplicationResponse::Json(
+        types::DummyConnectorDisputeResponse {
+            id: dispute.id,
+            status: dispute.status,
+        },
+    ))
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+pub async fn dispute_accept(
+    state: SessionState,
+    req: types::DummyConnectorDisputeRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorDisputeResponse> {
+    update_dispute_status(
+        &state,
+        &req.dispute_id,
+        types::DummyConnectorDisputeStatus::Accepted,
+    )
+    .await
+}
+
+#[cfg(all(feature = "dummy_connector", feature = "v1"))]
+pub async fn dispute_evidence_submit(
+    state: SessionState,
+    req: types::DummyConnectorDisputeEvidenceRequest,
+) -> types::DummyConnectorResponse<types::DummyConnectorDisputeResponse> {
+    let dispute_id = req
+        .dispute_id
+        .get_required_value("dispute_id")
+        .change_context(errors::DummyConnectorErrors::MissingRequiredField {
+            field_name: "dispute_id",
+        })?;
+    update_dispute_status(
+        &state,
+        &dispute_id,
+        types::DummyConnectorDisputeStatus::Challenged,
+    )
+    .await
+}
@@ -37,6 +37,27 @@ pub enum DummyConnectorErrors {
 
     #[error(error_type = ErrorType::InvalidRequestError, code = "DC_08", message = "Payment declined: {message}")]
     PaymentDeclined { message: &'static str },
+
+    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_09", message = "Payment declined temporarily: {message}")]
+    PaymentSoftDeclined { message: &'static str },
+
+    #[error(error_type = ErrorType::ObjectNotFound, code = "DC_10", message = "Mandate does not exist in our records")]
+    MandateNotFound,
+
+    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_11", message = "Mandate is not active")]
+    MandateNotActive,
+
+    #[error(error_type = ErrorType::ObjectNotFound, code = "DC_12", message = "Payout does not exist in our records")]
+    PayoutNotFound,
+
+    #[error(error_type = ErrorType::ObjectNotFound, code = "DC_13", message = "Dispute does not exist in our records")]
+    DisputeNotFound,
+
+    #[error(error_type = ErrorType::InvalidRequestError, code = "DC_14", message = "Dispute is not open")]
+    DisputeNotOpen,
+
+    #[error(error_type = ErrorType::ServerNotAvailable, code = "DC_15", message = "Processor is temporarily unavailable")]
+    SimulatedFailure,
 }
 
 impl core::fmt::Display for DummyConnectorErrors {
@@ -83,6 +104,27 @@ impl common_utils::errors::ErrorSwitch<api_models::errors::types::ApiErrorRespon
             Self::PaymentDeclined { message: _ } => {
                 AER::BadRequest(ApiError::new("DC", 8, self.error_message(), None))
             }
+            Self::PaymentSoftDeclined { message: _ } => {
+                AER::BadRequest(ApiError::new("DC", 9, self.error_message(), None))
+            }
+            Self::MandateNotFound => {
+                AER::NotFound(ApiError::new("DC", 10, self.error_message(), None))
+            }
+            Self::MandateNotActive => {
+                AER::BadRequest(ApiError::new("DC", 11, self.error_message(), None))
+            }
+            Self::PayoutNotFound => {
+                AER::NotFound(ApiError::new("DC", 12, self.error_message(), None))
+            }
+            Self::DisputeNotFound => {
+                AER::NotFound(ApiError::new("DC", 13, self.error_message(), None))
+            }
+            Self::DisputeNotOpen => {
+                AER::BadRequest(ApiError::new("DC", 14, self.error_message(), None))
+            }
+            Self::SimulatedFailure => {
+                AER::InternalServerError(ApiError::new("DC", 15, self.error_message(), None))
+            }
         }
     }
 }
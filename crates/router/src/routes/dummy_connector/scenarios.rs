@@ -0,0 +1,160 @@
+use strum::IntoEnumIterator;
+
+use super::types::{
+    DummyConnectorScenario, DummyConnectorScenarioDetails, DummyConnectorScenarioListResponse,
+    DummyConnectorTestAmount, DummyConnectorTestCard,
+};
+
+const fn test_card(
+    card_number: &'static str,
+    scenario: DummyConnectorScenario,
+    decline_message: Option<&'static str>,
+) -> DummyConnectorTestCard {
+    DummyConnectorTestCard {
+        card_number,
+        scenario,
+        decline_message,
+    }
+}
+
+const TEST_CARDS: &[DummyConnectorTestCard] = &[
+    test_card("4111111111111111", DummyConnectorScenario::Success, None),
+    test_card("4242424242424242", DummyConnectorScenario::Success, None),
+    test_card("5555555555554444", DummyConnectorScenario::Success, None),
+    test_card("38000000000006", DummyConnectorScenario::Success, None),
+    test_card("378282246310005", DummyConnectorScenario::Success, None),
+    test_card("6011111111111117", DummyConnectorScenario::Success, None),
+    test_card(
+        "5105105105105100",
+        DummyConnectorScenario::HardDecline,
+        Some("Card declined"),
+    ),
+    test_card(
+        "4000000000000002",
+        DummyConnectorScenario::HardDecline,
+        Some("Card declined"),
+    ),
+    test_card(
+        "4000000000009995",
+        DummyConnectorScenario::HardDecline,
+        Some("Insufficient funds"),
+    ),
+    test_card(
+        "4000000000009987",
+        DummyConnectorScenario::HardDecline,
+        Some("Lost card"),
+    ),
+    test_card(
+        "4000000000009979",
+        DummyConnectorScenario::HardDecline,
+        Some("Stolen card"),
+    ),
+    test_card(
+        "4000000000000119",
+        DummyConnectorScenario::SoftDecline,
+        Some("Issuer unavailable, try again later"),
+    ),
+    test_card(
+        "4000003800000446",
+        DummyConnectorScenario::ThreeDsChallenge,
+        None,
+    ),
+    test_card(
+        "4000000000000259",
+        DummyConnectorScenario::DisputeCreation,
+        None,
+    ),
+    test_card(
+        "4000000000000077",
+        DummyConnectorScenario::DelayedWebhook,
+        None,
+    ),
+];
+
+const TEST_AMOUNTS: &[DummyConnectorTestAmount] = &[
+    DummyConnectorTestAmount {
+        amount: 5101,
+        scenario: DummyConnectorScenario::HardDecline,
+    },
+    DummyConnectorTestAmount {
+        amount: 5102,
+        scenario: DummyConnectorScenario::SoftDecline,
+    },
+    DummyConnectorTestAmount {
+        amount: 5103,
+        scenario: DummyConnectorScenario::ThreeDsChallenge,
+    },
+    DummyConnectorTestAmount {
+        amount: 5104,
+        scenario: DummyConnectorScenario::DisputeCreation,
+    },
+    DummyConnectorTestAmount {
+        amount: 5105,
+        scenario: DummyConnectorScenario::DelayedWebhook,
+    },
+];
+
+pub fn get_test_card(card_number: &str) -> Option<&'static DummyConnectorTestCard> {
+    TEST_CARDS
+        .iter()
+        .find(|test_card| test_card.card_number == card_number)
+}
+
+pub fn get_test_amount_scenario(amount: i64) -> Option<DummyConnectorScenario> {
+    TEST_AMOUNTS
+        .iter()
+        .find(|test_amount| test_amount.amount == amount)
+        .map(|test_amount| test_amount.scenario)
+}
+
+/// The decline message of the scenario when it is triggered by a test amount
+pub fn get_default_decline_message(scenario: DummyConnectorScenario) -> &'static str {
+    match scenario {
+        DummyConnectorScenario::SoftDecline => "Try again later",
+        DummyConnectorScenario::Success
+        | DummyConnectorScenario::HardDecline
+        | DummyConnectorScenario::ThreeDsChallenge
+        | DummyConnectorScenario::DisputeCreation
+        | DummyConnectorScenario::DelayedWebhook => "Card declined",
+    }
+}
+
+pub fn get_scenario_registry() -> DummyConnectorScenarioListResponse {
+    DummyConnectorScenarioListResponse {
+        scenarios: DummyConnectorScenario::iter()
+            .map(|scenario| DummyConnectorScenarioDetails {
+                scenario,
+                description: scenario.get_description(),
+            })
+            .collect(),
+        test_cards: TEST_CARDS.to_vec(),
+        test_amounts: TEST_AMOUNTS.to_vec(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_every_scenario_can_be_triggered() {
+        let card_scenarios: HashSet<_> = TEST_CARDS.iter().map(|card| card.scenario).collect();
+        let amount_scenarios: HashSet<_> = TEST_AMOUNTS
+            .iter()
+            .map(|test_amount| test_amount.scenario)
+            .collect();
+
+        for scenario in DummyConnectorScenario::iter() {
+            assert!(
+                card_scenarios.contains(&scenario),
+                "No test card triggers {scenario}"
+            );
+            assert!(
+                scenario == DummyConnectorScenario::Success || amount_scenarios.contains(&scenario),
+                "No test amount triggers {scenario}"
+            );
+        }
+    }
+}
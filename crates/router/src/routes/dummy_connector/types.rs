@@ -18,6 +18,13 @@ pub enum Flow {
     DummyPaymentComplete,
     DummyRefundCreate,
     DummyRefundRetrieve,
+    DummyScenarioList,
+    DummyMandatePayment,
+    DummyMandateRevoke,
+    DummyPayoutCreate,
+    DummyPayoutRetrieve,
+    DummyDisputeAccept,
+    DummyDisputeEvidenceSubmit,
 }
 
 impl FlowMetric for Flow {}
@@ -66,6 +73,58 @@ pub enum DummyConnectorStatus {
     Failed,
 }
 
+/// The outcome of a payment which is simulated by the dummy connector, as triggered by the test
+/// card or the test amount of the payment
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumIter,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DummyConnectorScenario {
+    /// The payment succeeds
+    #[default]
+    Success,
+    /// The payment is declined by the issuer, and should not be retried
+    HardDecline,
+    /// The payment is declined temporarily, and can be retried
+    SoftDecline,
+    /// The customer is challenged by the issuer for 3DS authentication before the payment completes
+    ThreeDsChallenge,
+    /// The payment succeeds, and a dispute is opened for it shortly afterwards
+    DisputeCreation,
+    /// The payment stays in processing, and its success is notified later through a webhook
+    DelayedWebhook,
+}
+
+impl DummyConnectorScenario {
+    pub fn get_description(&self) -> &'static str {
+        match self {
+            Self::Success => "The payment succeeds",
+            Self::HardDecline => "The payment is declined by the issuer, and should not be retried",
+            Self::SoftDecline => "The payment is declined temporarily, and can be retried",
+            Self::ThreeDsChallenge => {
+                "The customer is redirected to a 3DS challenge page before the payment completes"
+            }
+            Self::DisputeCreation => {
+                "The payment succeeds, and a dispute is opened for it through a webhook shortly afterwards"
+            }
+            Self::DelayedWebhook => {
+                "The payment stays in processing, and its success is notified later through a webhook"
+            }
+        }
+    }
+}
+
 #[derive(Clone, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
 pub struct DummyConnectorPaymentAttempt {
     pub timestamp: PrimitiveDateTime,
@@ -91,6 +150,7 @@ impl From<DummyConnectorPaymentRequest> for DummyConnectorPaymentAttempt {
 impl DummyConnectorPaymentAttempt {
     pub fn build_payment_data(
         self,
+        scenario: DummyConnectorScenario,
         status: DummyConnectorStatus,
         next_action: Option<DummyConnectorNextAction>,
         return_url: Option<String>,
@@ -107,6 +167,9 @@ impl DummyConnectorPaymentAttempt {
             payment_method_type: self.payment_request.payment_method_data.into(),
             next_action,
             return_url,
+            scenario,
+            webhook_url: self.payment_request.webhook_url,
+            mandate_id: None,
         }
     }
 }
@@ -118,6 +181,23 @@ pub struct DummyConnectorPaymentRequest {
     pub payment_method_data: DummyConnectorPaymentMethodData,
     pub return_url: Option<String>,
     pub connector: DummyConnectors,
+    pub webhook_url: Option<String>,
+    /// Whether a mandate is to be set up with the payment method of the payment, for charging
+    /// the payment method later without the customer
+    #[serde(default)]
+    pub setup_mandate: bool,
+    #[serde(default)]
+    pub simulation: DummyConnectorSimulation,
+}
+
+/// The behaviour of the dummy connector configured in the metadata of the merchant connector
+/// account, to simulate the latency and the failures of a real processor
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorSimulation {
+    /// The additional latency of every request
+    pub latency_in_ms: Option<u64>,
+    /// The percentage of the requests which fail as if the processor was unavailable
+    pub failure_percentage: Option<u8>,
 }
 
 pub trait GetPaymentMethodDetails {
@@ -131,6 +211,7 @@ pub enum DummyConnectorPaymentMethodData {
     Card(DummyConnectorCard),
     Wallet(DummyConnectorWallet),
     PayLater(DummyConnectorPayLater),
+    NetworkToken(DummyConnectorNetworkToken),
 }
 
 #[derive(
@@ -147,7 +228,8 @@ pub enum DummyConnectorPaymentMethodType {
 impl From<DummyConnectorPaymentMethodData> for DummyConnectorPaymentMethodType {
     fn from(value: DummyConnectorPaymentMethodData) -> Self {
         match value {
-            DummyConnectorPaymentMethodData::Card(_) => Self::Card,
+            DummyConnectorPaymentMethodData::Card(_)
+            | DummyConnectorPaymentMethodData::NetworkToken(_) => Self::Card,
             DummyConnectorPaymentMethodData::Wallet(wallet) => Self::Wallet(wallet),
             DummyConnectorPaymentMethodData::PayLater(pay_later) => Self::PayLater(pay_later),
         }
@@ -181,9 +263,14 @@ pub struct DummyConnectorCard {
     pub cvc: Secret<String>,
 }
 
-pub enum DummyConnectorCardFlow {
-    NoThreeDS(DummyConnectorStatus, Option<DummyConnectorErrors>),
-    ThreeDS(DummyConnectorStatus, Option<DummyConnectorErrors>),
+/// A network token of a card, whose payments follow the scenario of the test card which has the
+/// number of the token
+#[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorNetworkToken {
+    pub token_number: cards::CardNumber,
+    pub expiry_month: Secret<String>,
+    pub expiry_year: Secret<String>,
+    pub cryptogram: Option<Secret<String>>,
 }
 
 #[derive(Clone, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
@@ -259,6 +346,11 @@ pub struct DummyConnectorPaymentData {
     pub connector: DummyConnectors,
     pub next_action: Option<DummyConnectorNextAction>,
     pub return_url: Option<String>,
+    #[serde(default)]
+    pub scenario: DummyConnectorScenario,
+    pub webhook_url: Option<String>,
+    #[serde(default)]
+    pub mandate_id: Option<String>,
 }
 
 impl DummyConnectorPaymentData {
@@ -293,6 +385,7 @@ pub struct DummyConnectorPaymentResponse {
     pub created: PrimitiveDateTime,
     pub payment_method_type: DummyConnectorPaymentMethodType,
     pub next_action: Option<DummyConnectorNextAction>,
+    pub mandate_id: Option<String>,
 }
 
 impl From<DummyConnectorPaymentData> for DummyConnectorPaymentResponse {
@@ -305,6 +398,7 @@ impl From<DummyConnectorPaymentData> for DummyConnectorPaymentResponse {
             created: value.created,
             payment_method_type: value.payment_method_type,
             next_action: value.next_action,
+            mandate_id: value.mandate_id,
         }
     }
 }
@@ -334,6 +428,8 @@ pub struct DummyConnectorPaymentCompleteBody {
 pub struct DummyConnectorRefundRequest {
     pub amount: i64,
     pub payment_id: Option<common_utils::id_type::PaymentId>,
+    #[serde(default)]
+    pub simulation: DummyConnectorSimulation,
 }
 
 #[derive(Clone, Debug, serde::Serialize, Eq, PartialEq, serde::Deserialize)]
@@ -372,6 +468,184 @@ pub struct DummyConnectorRefundRetrieveRequest {
     pub refund_id: String,
 }
 
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DummyConnectorScenarioDetails {
+    pub scenario: DummyConnectorScenario,
+    pub description: &'static str,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DummyConnectorTestCard {
+    pub card_number: &'static str,
+    pub scenario: DummyConnectorScenario,
+    pub decline_message: Option<&'static str>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DummyConnectorTestAmount {
+    /// The amount of the payment in the minor unit of its currency
+    pub amount: i64,
+    pub scenario: DummyConnectorScenario,
+}
+
+/// The registry of the scenarios which can be simulated with the dummy connector. The test cards
+/// trigger their scenario for card payments, and the test amounts trigger their scenario for the
+/// payments of every payment method, taking precedence over the test card of the payment.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DummyConnectorScenarioListResponse {
+    pub scenarios: Vec<DummyConnectorScenarioDetails>,
+    pub test_cards: Vec<DummyConnectorTestCard>,
+    pub test_amounts: Vec<DummyConnectorTestAmount>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorWebhookEventType {
+    PaymentSucceeded,
+    DisputeOpened,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorDispute {
+    pub id: String,
+    pub amount: i64,
+    pub currency: Currency,
+    pub reason: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+    #[serde(default)]
+    pub status: DummyConnectorDisputeStatus,
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorDisputeStatus {
+    #[default]
+    Opened,
+    Accepted,
+    Challenged,
+}
+
+#[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorDisputeRequest {
+    pub dispute_id: String,
+}
+
+/// The evidence submitted for challenging a dispute
+#[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorDisputeEvidenceRequest {
+    pub dispute_id: Option<String>,
+    pub product_description: Option<String>,
+    pub shipping_tracking_number: Option<String>,
+    pub uncategorized_text: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorDisputeResponse {
+    pub id: String,
+    pub status: DummyConnectorDisputeStatus,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DummyConnectorMandateStatus {
+    /// The payment setting up the mandate is yet to succeed
+    Pending,
+    Active,
+    Revoked,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorMandate {
+    pub mandate_id: String,
+    pub status: DummyConnectorMandateStatus,
+    /// The payment which set up the mandate
+    pub payment_id: common_utils::id_type::PaymentId,
+    pub payment_method_data: DummyConnectorPaymentMethodData,
+    pub connector: DummyConnectors,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+/// A payment charging the payment method of a mandate, without the customer
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorMandatePaymentRequest {
+    pub mandate_id: Option<String>,
+    pub amount: i64,
+    pub currency: Currency,
+    pub return_url: Option<String>,
+    pub webhook_url: Option<String>,
+    #[serde(default)]
+    pub simulation: DummyConnectorSimulation,
+}
+
+#[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorMandateRevokeRequest {
+    pub mandate_id: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorMandateResponse {
+    pub mandate_id: String,
+    pub status: DummyConnectorMandateStatus,
+}
+
+impl From<DummyConnectorMandate> for DummyConnectorMandateResponse {
+    fn from(value: DummyConnectorMandate) -> Self {
+        Self {
+            mandate_id: value.mandate_id,
+            status: value.status,
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorPayoutCard {
+    pub number: cards::CardNumber,
+    pub expiry_month: Secret<String>,
+    pub expiry_year: Secret<String>,
+    pub name: Option<Secret<String>>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DummyConnectorPayoutMethodData {
+    Card(DummyConnectorPayoutCard),
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorPayoutRequest {
+    pub amount: i64,
+    pub currency: Currency,
+    pub payout_method_data: DummyConnectorPayoutMethodData,
+    pub connector: DummyConnectors,
+    #[serde(default)]
+    pub simulation: DummyConnectorSimulation,
+}
+
+#[derive(Default, Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorPayoutRetrieveRequest {
+    pub payout_id: String,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct DummyConnectorPayoutResponse {
+    pub id: String,
+    pub status: DummyConnectorStatus,
+    pub amount: i64,
+    pub currency: Currency,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DummyConnectorWebhookBody {
+    pub event_type: DummyConnectorWebhookEventType,
+    pub payment: DummyConnectorPaymentResponse,
+    pub dispute: Option<DummyConnectorDispute>,
+}
+
 pub type DummyConnectorResponse<T> =
     CustomResult<services::ApplicationResponse<T>, DummyConnectorErrors>;
 
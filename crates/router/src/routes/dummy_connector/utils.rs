@@ -4,11 +4,11 @@ use common_utils::ext_traits::AsyncExt;
 use error_stack::{report, ResultExt};
 use masking::PeekInterface;
 use maud::html;
-use rand::{distributions::Uniform, prelude::Distribution};
+use rand::{distributions::Uniform, prelude::Distribution, Rng};
 use tokio::time as tokio;
 
 use super::{
-    consts, errors,
+    consts, errors, scenarios,
     types::{self, GetPaymentMethodDetails},
 };
 use crate::{configs::settings, routes::SessionState};
@@ -25,6 +25,22 @@ pub async fn tokio_mock_sleep(delay: u64, tolerance: u64) {
     .await
 }
 
+/// Simulates the latency and the failures of the processor as configured for the merchant
+/// connector account
+pub async fn simulate_processor(
+    simulation: types::DummyConnectorSimulation,
+) -> types::DummyConnectorResult<()> {
+    if let Some(latency_in_ms) = simulation.latency_in_ms {
+        tokio::sleep(tokio::Duration::from_millis(latency_in_ms)).await;
+    }
+    let failure_percentage = simulation.failure_percentage.unwrap_or_default();
+    if failure_percentage > 0 && rand::thread_rng().gen_range(0..100) < failure_percentage {
+        return Err(report!(errors::DummyConnectorErrors::SimulatedFailure)
+            .attach_printable("Simulated failure of the processor"));
+    }
+    Ok(())
+}
+
 pub async fn store_data_in_redis(
     state: &SessionState,
     key: String,
@@ -64,6 +80,24 @@ pub async fn get_payment_data_from_payment_id(
         .change_context(errors::DummyConnectorErrors::PaymentNotFound)
 }
 
+pub async fn get_data_from_redis<T: serde::de::DeserializeOwned>(
+    state: &SessionState,
+    key: &str,
+    type_name: &'static str,
+    not_found_error: errors::DummyConnectorErrors,
+) -> types::DummyConnectorResult<T> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    redis_conn
+        .get_and_deserialize_key::<T>(&key.into(), type_name)
+        .await
+        .change_context(not_found_error)
+}
+
 pub async fn get_payment_data_by_attempt_id(
     state: &SessionState,
     attempt_id: String,
@@ -219,69 +253,85 @@ impl ProcessPaymentAttempt for types::DummyConnectorCard {
         payment_attempt: types::DummyConnectorPaymentAttempt,
         redirect_url: String,
     ) -> types::DummyConnectorResult<types::DummyConnectorPaymentData> {
-        match self.get_flow_from_card_number()? {
-            types::DummyConnectorCardFlow::NoThreeDS(status, error) => {
-                if let Some(error) = error {
-                    Err(error)?;
+        let test_card = scenarios::get_test_card(self.number.peek())
+            .ok_or(errors::DummyConnectorErrors::CardNotSupported)
+            .attach_printable("The card is not supported")?;
+        payment_attempt.build_payment_data_for_scenario(
+            test_card.scenario,
+            test_card
+                .decline_message
+                .unwrap_or(scenarios::get_default_decline_message(test_card.scenario)),
+            redirect_url,
+        )
+    }
+}
+
+impl ProcessPaymentAttempt for types::DummyConnectorNetworkToken {
+    fn build_payment_data_from_payment_attempt(
+        self,
+        payment_attempt: types::DummyConnectorPaymentAttempt,
+        redirect_url: String,
+    ) -> types::DummyConnectorResult<types::DummyConnectorPaymentData> {
+        if self.cryptogram.is_none() {
+            return Err(report!(
+                errors::DummyConnectorErrors::MissingRequiredField {
+                    field_name: "cryptogram",
                 }
-                Ok(payment_attempt.build_payment_data(status, None, None))
-            }
-            types::DummyConnectorCardFlow::ThreeDS(_, _) => {
-                Ok(payment_attempt.clone().build_payment_data(
-                    types::DummyConnectorStatus::Processing,
-                    Some(types::DummyConnectorNextAction::RedirectToUrl(redirect_url)),
-                    payment_attempt.payment_request.return_url,
-                ))
-            }
+            ));
         }
+        let test_card = scenarios::get_test_card(self.token_number.peek())
+            .ok_or(errors::DummyConnectorErrors::CardNotSupported)
+            .attach_printable("The network token is not of a test card")?;
+        payment_attempt.build_payment_data_for_scenario(
+            test_card.scenario,
+            test_card
+                .decline_message
+                .unwrap_or(scenarios::get_default_decline_message(test_card.scenario)),
+            redirect_url,
+        )
     }
 }
 
-impl types::DummyConnectorCard {
-    pub fn get_flow_from_card_number(
+impl types::DummyConnectorPaymentAttempt {
+    pub fn build_payment_data_for_scenario(
         self,
-    ) -> types::DummyConnectorResult<types::DummyConnectorCardFlow> {
-        let card_number = self.number.peek();
-        match card_number.as_str() {
-            "4111111111111111" | "4242424242424242" | "5555555555554444" | "38000000000006"
-            | "378282246310005" | "6011111111111117" => {
-                Ok(types::DummyConnectorCardFlow::NoThreeDS(
-                    types::DummyConnectorStatus::Succeeded,
-                    None,
-                ))
+        scenario: types::DummyConnectorScenario,
+        decline_message: &'static str,
+        redirect_url: String,
+    ) -> types::DummyConnectorResult<types::DummyConnectorPaymentData> {
+        match scenario {
+            types::DummyConnectorScenario::Success
+            | types::DummyConnectorScenario::DisputeCreation => Ok(self.build_payment_data(
+                scenario,
+                types::DummyConnectorStatus::Succeeded,
+                None,
+                None,
+            )),
+            types::DummyConnectorScenario::HardDecline => {
+                Err(report!(errors::DummyConnectorErrors::PaymentDeclined {
+                    message: decline_message,
+                }))
+            }
+            types::DummyConnectorScenario::SoftDecline => {
+                Err(report!(errors::DummyConnectorErrors::PaymentSoftDeclined {
+                    message: decline_message,
+                }))
             }
-            "5105105105105100" | "4000000000000002" => {
-                Ok(types::DummyConnectorCardFlow::NoThreeDS(
-                    types::DummyConnectorStatus::Failed,
-                    Some(errors::DummyConnectorErrors::PaymentDeclined {
-                        message: "Card declined",
-                    }),
+            types::DummyConnectorScenario::ThreeDsChallenge => {
+                let return_url = self.payment_request.return_url.clone();
+                Ok(self.build_payment_data(
+                    scenario,
+                    types::DummyConnectorStatus::Processing,
+                    Some(types::DummyConnectorNextAction::RedirectToUrl(redirect_url)),
+                    return_url,
                 ))
             }
-            "4000000000009995" => Ok(types::DummyConnectorCardFlow::NoThreeDS(
-                types::DummyConnectorStatus::Failed,
-                Some(errors::DummyConnectorErrors::PaymentDeclined {
-                    message: "Insufficient funds",
-                }),
-            )),
-            "4000000000009987" => Ok(types::DummyConnectorCardFlow::NoThreeDS(
-                types::DummyConnectorStatus::Failed,
-                Some(errors::DummyConnectorErrors::PaymentDeclined {
-                    message: "Lost card",
-                }),
-            )),
-            "4000000000009979" => Ok(types::DummyConnectorCardFlow::NoThreeDS(
-                types::DummyConnectorStatus::Failed,
-                Some(errors::DummyConnectorErrors::PaymentDeclined {
-                    message: "Stolen card",
-                }),
-            )),
-            "4000003800000446" => Ok(types::DummyConnectorCardFlow::ThreeDS(
-                types::DummyConnectorStatus::Succeeded,
+            types::DummyConnectorScenario::DelayedWebhook => Ok(self.build_payment_data(
+                scenario,
+                types::DummyConnectorStatus::Processing,
+                None,
                 None,
             )),
-            _ => Err(report!(errors::DummyConnectorErrors::CardNotSupported)
-                .attach_printable("The card is not supported")),
         }
     }
 }
@@ -293,6 +343,7 @@ impl ProcessPaymentAttempt for types::DummyConnectorWallet {
         redirect_url: String,
     ) -> types::DummyConnectorResult<types::DummyConnectorPaymentData> {
         Ok(payment_attempt.clone().build_payment_data(
+            types::DummyConnectorScenario::default(),
             types::DummyConnectorStatus::Processing,
             Some(types::DummyConnectorNextAction::RedirectToUrl(redirect_url)),
             payment_attempt.payment_request.return_url,
@@ -307,6 +358,7 @@ impl ProcessPaymentAttempt for types::DummyConnectorPayLater {
         redirect_url: String,
     ) -> types::DummyConnectorResult<types::DummyConnectorPaymentData> {
         Ok(payment_attempt.clone().build_payment_data(
+            types::DummyConnectorScenario::default(),
             types::DummyConnectorStatus::Processing,
             Some(types::DummyConnectorNextAction::RedirectToUrl(redirect_url)),
             payment_attempt.payment_request.return_url,
@@ -330,6 +382,9 @@ impl ProcessPaymentAttempt for types::DummyConnectorPaymentMethodData {
             Self::PayLater(pay_later) => {
                 pay_later.build_payment_data_from_payment_attempt(payment_attempt, redirect_url)
             }
+            Self::NetworkToken(network_token) => {
+                network_token.build_payment_data_from_payment_attempt(payment_attempt, redirect_url)
+            }
         }
     }
 }
@@ -343,6 +398,17 @@ impl types::DummyConnectorPaymentData {
             "{}/dummy-connector/authorize/{}",
             state.base_url, payment_attempt.attempt_id
         );
+        // The scenario of a test amount applies to the payments of every payment method
+        if let Some(scenario) =
+            scenarios::get_test_amount_scenario(payment_attempt.payment_request.amount)
+        {
+            return payment_attempt.build_payment_data_for_scenario(
+                scenario,
+                scenarios::get_default_decline_message(scenario),
+                redirect_url,
+            );
+        }
+
         payment_attempt
             .clone()
             .payment_request
@@ -0,0 +1,118 @@
+use common_utils::{
+    generate_id_with_default_len,
+    request::{Method, RequestBuilder, RequestContent},
+};
+use error_stack::{report, ResultExt};
+use router_env::{logger, tracing::Instrument};
+
+use super::{consts, errors, types, utils};
+use crate::{routes::SessionState, services};
+
+/// Schedules the webhook of the scenario of the payment, for the scenarios which notify the outcome
+/// of the payment through a webhook
+pub fn schedule_scenario_webhook(
+    state: &SessionState,
+    payment_data: &types::DummyConnectorPaymentData,
+) {
+    let delay = match payment_data.scenario {
+        types::DummyConnectorScenario::DisputeCreation => consts::DISPUTE_WEBHOOK_DELAY_IN_SECONDS,
+        types::DummyConnectorScenario::DelayedWebhook => consts::DELAYED_WEBHOOK_DELAY_IN_SECONDS,
+        types::DummyConnectorScenario::Success
+        | types::DummyConnectorScenario::HardDecline
+        | types::DummyConnectorScenario::SoftDecline
+        | types::DummyConnectorScenario::ThreeDsChallenge => return,
+    };
+    let Some(webhook_url) = payment_data.webhook_url.clone() else {
+        logger::warn!(
+            "Webhook of the dummy connector scenario not scheduled, as the webhook url is missing"
+        );
+        return;
+    };
+
+    let state = state.clone();
+    let payment_id = payment_data.payment_id.get_string_repr().to_owned();
+    tokio::spawn(
+        async move {
+            tokio::time::sleep(std::time::Duration::from_secs(delay)).await;
+            if let Err(error) = send_scenario_webhook(&state, payment_id, webhook_url).await {
+                logger::error!(
+                    ?error,
+                    "Failed to send the webhook of the dummy connector scenario"
+                );
+            }
+        }
+        .in_current_span(),
+    );
+}
+
+async fn send_scenario_webhook(
+    state: &SessionState,
+    payment_id: String,
+    webhook_url: String,
+) -> types::DummyConnectorResult<()> {
+    let mut payment_data = utils::get_payment_data_from_payment_id(state, payment_id).await?;
+
+    let webhook_body = match payment_data.scenario {
+        types::DummyConnectorScenario::DisputeCreation => {
+            let dispute = types::DummyConnectorDispute {
+                id: generate_id_with_default_len(consts::DISPUTE_ID_PREFIX),
+                amount: payment_data.amount,
+                currency: payment_data.currency,
+                reason: "fraudulent".to_string(),
+                created: common_utils::date_time::now(),
+                status: types::DummyConnectorDisputeStatus::Opened,
+            };
+            utils::store_data_in_redis(
+                state,
+                dispute.id.clone(),
+                dispute.clone(),
+                state.conf.dummy_connector.payment_ttl,
+            )
+            .await?;
+            types::DummyConnectorWebhookBody {
+                event_type: types::DummyConnectorWebhookEventType::DisputeOpened,
+                payment: payment_data.into(),
+                dispute: Some(dispute),
+            }
+        }
+        types::DummyConnectorScenario::DelayedWebhook => {
+            payment_data.status = types::DummyConnectorStatus::Succeeded;
+            utils::store_data_in_redis(
+                state,
+                payment_data.payment_id.get_string_repr().to_owned(),
+                payment_data.clone(),
+                state.conf.dummy_connector.payment_ttl,
+            )
+            .await?;
+            types::DummyConnectorWebhookBody {
+                event_type: types::DummyConnectorWebhookEventType::PaymentSucceeded,
+                payment: payment_data.into(),
+                dispute: None,
+            }
+        }
+        types::DummyConnectorScenario::Success
+        | types::DummyConnectorScenario::HardDecline
+        | types::DummyConnectorScenario::SoftDecline
+        | types::DummyConnectorScenario::ThreeDsChallenge => return Ok(()),
+    };
+
+    let request = RequestBuilder::new()
+        .method(Method::Post)
+        .url(&webhook_url)
+        .attach_default_headers()
+        .set_body(RequestContent::Json(Box::new(webhook_body)))
+        .build();
+
+    let response = services::send_request(state, request, None)
+        .await
+        .change_context(errors::DummyConnectorErrors::InternalServerError)
+        .attach_printable("Failed to send the webhook")?;
+
+    if !response.status().is_success() {
+        return Err(report!(errors::DummyConnectorErrors::InternalServerError))
+            .attach_printable_lazy(|| {
+                format!("Webhook rejected with status {}", response.status())
+            });
+    }
+    Ok(())
+}
@@ -104,6 +104,7 @@ fn build_connector_feature_details(
             category: connector_about.map(|about| about.connector_type),
             supported_webhook_flows,
             supported_payment_methods,
+            refund_validity_days: connector.get_refund_validity_days(),
         }
     })
 }
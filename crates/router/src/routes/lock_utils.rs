@@ -36,12 +36,15 @@ pub enum ApiIdentifier {
     ConnectorOnboarding,
     Recon,
     Poll,
+    SdkEvents,
     ApplePayCertificatesMigration,
     Relay,
     Documentation,
     CardNetworkTokenization,
     Hypersense,
     PaymentMethodSession,
+    Sandbox,
+    ProcessTracker,
 }
 
 impl From<Flow> for ApiIdentifier {
@@ -52,6 +55,24 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantsAccountUpdate
             | Flow::MerchantsAccountDelete
             | Flow::MerchantTransferKey
+            | Flow::KvPgDivergencesList
+            | Flow::MerchantFeatureFlagsRetrieve
+            | Flow::MerchantFeatureFlagsUpdate
+            | Flow::MerchantFaultInjectionUpsert
+            | Flow::MerchantFaultInjectionRetrieve
+            | Flow::MerchantFaultInjectionDelete
+            | Flow::MerchantAccountStatusUpdate
+            | Flow::MerchantOffboard
+            | Flow::MerchantOffboardingRetrieve
+            | Flow::ConnectorTransactionIdBackfillCreate
+            | Flow::ConnectorTransactionIdBackfillRetrieve
+            | Flow::DataResidencyCreate
+            | Flow::DataResidencyRetrieve
+            | Flow::WarehouseSyncConfigSet
+            | Flow::WarehouseSyncConfigRetrieve
+            | Flow::WarehouseSyncConfigDelete
+            | Flow::WarehouseSyncStatus
+            | Flow::WarehouseSyncTrigger
             | Flow::MerchantAccountList
             | Flow::EnablePlatformAccount => Self::MerchantAccount,
 
@@ -69,6 +90,15 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RoutingUpdateConfig
             | Flow::RoutingUpdateDefaultConfig
             | Flow::RoutingDeleteConfig
+            | Flow::RoutingExportConfig
+            | Flow::RoutingImportConfig
+            | Flow::RoutingExperimentCreate
+            | Flow::RoutingExperimentList
+            | Flow::RoutingExperimentRetrieve
+            | Flow::RoutingExperimentConclude
+            | Flow::RoutingVolumeCapsUpsert
+            | Flow::RoutingVolumeCapsRetrieve
+            | Flow::RoutingSimulate
             | Flow::DecisionManagerDeleteConfig
             | Flow::DecisionManagerRetrieveConfig
             | Flow::ToggleDynamicRouting
@@ -87,6 +117,14 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantConnectorsRetrieve
             | Flow::MerchantConnectorsUpdate
             | Flow::MerchantConnectorsDelete
+            | Flow::MerchantConnectorsRestore
+            | Flow::MerchantConnectorsBulkConfig
+            | Flow::MerchantConnectorsMaintenanceWindowsUpsert
+            | Flow::MerchantConnectorsMaintenanceWindowsRetrieve
+            | Flow::MerchantConnectorsCredentialsRetrieve
+            | Flow::MerchantConnectorsCredentialsUpdate
+            | Flow::MerchantConnectorsCredentialsVerify
+            | Flow::MerchantConnectorsCredentialsExpiringList
             | Flow::MerchantConnectorsList => Self::MerchantConnector,
 
             Flow::ConfigKeyCreate
@@ -100,6 +138,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::CustomersUpdate
             | Flow::CustomersDelete
             | Flow::CustomersGetMandates
+            | Flow::CustomersPaymentsList
+            | Flow::CustomersStoreCreditRetrieve
+            | Flow::CustomersStoreCreditEntriesList
             | Flow::CustomersList => Self::Customers,
 
             Flow::EphemeralKeyCreate | Flow::EphemeralKeyDelete => Self::Ephemeral,
@@ -109,7 +150,10 @@ impl From<Flow> for ApiIdentifier {
 
             Flow::PaymentMethodsCreate
             | Flow::PaymentMethodsMigrate
+            | Flow::PaymentMethodsExternalTokenMigrate
+            | Flow::PaymentMethodCvvTokenize
             | Flow::PaymentMethodsList
+            | Flow::PaymentMethodDisplayNamesList
             | Flow::CustomerPaymentMethodsList
             | Flow::PaymentMethodsRetrieve
             | Flow::PaymentMethodsUpdate
@@ -118,6 +162,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ValidatePaymentMethod
             | Flow::ListCountriesCurrencies
             | Flow::DefaultPaymentMethodsSet
+            | Flow::CustomerPaymentMethodsUpdate
+            | Flow::PaymentMethodConsentRecollect
+            | Flow::PaymentMethodConsentRevoke
             | Flow::PaymentMethodSave
             | Flow::TotalPaymentMethodCount => Self::PaymentMethods,
 
@@ -130,6 +177,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsConfirm
             | Flow::PaymentsCapture
             | Flow::PaymentsCancel
+            | Flow::PaymentsCancelRemaining
             | Flow::PaymentsApprove
             | Flow::PaymentsReject
             | Flow::PaymentsSessionToken
@@ -140,10 +188,36 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsRedirect
             | Flow::PaymentsIncrementalAuthorization
             | Flow::PaymentsExternalAuthentication
+            | Flow::PaymentsResumeAuthentication
             | Flow::PaymentsAuthorize
             | Flow::GetExtendedCardInfo
             | Flow::PaymentsCompleteAuthorize
             | Flow::PaymentsManualUpdate
+            | Flow::PaymentsTimeline
+            | Flow::PaymentsDunningRetrieve
+            | Flow::PaymentsMetadataUpdate
+            | Flow::PaymentsNotesAdd
+            | Flow::PaymentsNotesList
+            | Flow::PaymentsCaptureSchedule
+            | Flow::PaymentsCaptureScheduleCancel
+            | Flow::PaymentsCaptureScheduleRelease
+            | Flow::PaymentsFinalizeAmount
+            | Flow::PaymentsGiftCardBalance
+            | Flow::PaymentsSearchByContact
+            | Flow::PaymentsLookup
+            | Flow::PaymentsChanges
+            | Flow::AcquirerReferenceNumbersImport
+            | Flow::PaymentReceiptRetrieve
+            | Flow::PaymentReceiptEmail
+            | Flow::CheckoutSessionCreate
+            | Flow::CheckoutSessionRetrieve
+            | Flow::CheckoutSessionUpdate
+            | Flow::CheckoutSessionConfirm
+            | Flow::SanctionsScreeningCaseRetrieve
+            | Flow::SanctionsScreeningCaseResolve
+            | Flow::ScaComplianceReportCreate
+            | Flow::ScaComplianceReportRetrieve
+            | Flow::ScaComplianceReportDownload
             | Flow::SessionUpdateTaxCalculation
             | Flow::PaymentsConfirmIntent
             | Flow::PaymentsCreateIntent
@@ -173,7 +247,11 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RefundsList
             | Flow::RefundsFilters
             | Flow::RefundsAggregate
-            | Flow::RefundsManualUpdate => Self::Refunds,
+            | Flow::RefundsManualUpdate
+            | Flow::RefundsBatchCreate
+            | Flow::RefundsBatchRetrieve
+            | Flow::RefundsBatchReport
+            | Flow::RefundsResolve => Self::Refunds,
             Flow::Relay | Flow::RelayRetrieve => Self::Relay,
 
             Flow::FrmFulfillment
@@ -182,6 +260,16 @@ impl From<Flow> for ApiIdentifier {
             | Flow::WebhookEventInitialDeliveryAttemptList
             | Flow::WebhookEventDeliveryAttemptList
             | Flow::WebhookEventDeliveryRetry
+            | Flow::WebhookEventTypeList
+            | Flow::WebhookEventLifecycleList
+            | Flow::WebhookPayloadTemplateSet
+            | Flow::WebhookPayloadTemplateRetrieve
+            | Flow::WebhookPayloadTemplateList
+            | Flow::WebhookPayloadTemplateDelete
+            | Flow::WebhookPayloadTemplateValidate
+            | Flow::WebhookPayloadTemplatePreview
+            | Flow::WebhookEndpointHealthRetrieve
+            | Flow::WebhookEndpointResume
             | Flow::RecoveryIncomingWebhookReceive => Self::Webhooks,
 
             Flow::ApiKeyCreate
@@ -197,6 +285,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::AttachDisputeEvidence
             | Flow::RetrieveDisputeEvidence
             | Flow::DisputesAggregate
+            | Flow::DisputesBalance
             | Flow::DeleteDisputeEvidence => Self::Disputes,
 
             Flow::CardsInfo
@@ -214,7 +303,55 @@ impl From<Flow> for ApiIdentifier {
             | Flow::ProfileDelete
             | Flow::ProfileList
             | Flow::ToggleExtendedCardInfo
-            | Flow::ToggleConnectorAgnosticMit => Self::Profile,
+            | Flow::ToggleConnectorAgnosticMit
+            | Flow::PaymentMethodDisplayConfigUpsert
+            | Flow::PaymentMethodDisplayConfigRetrieve
+            | Flow::PaymentMethodDisplayConfigDelete
+            | Flow::PaymentMethodDisplayNamesUpsert
+            | Flow::PaymentMethodDisplayNamesRetrieve
+            | Flow::PaymentMethodDisplayNamesDelete
+            | Flow::ExternalVaultConfigUpsert
+            | Flow::ExternalVaultConfigRetrieve
+            | Flow::ExternalVaultConfigDelete
+            | Flow::CvvRecollectionConfigUpsert
+            | Flow::CvvRecollectionConfigRetrieve
+            | Flow::NetworkTokenPreferenceConfigUpsert
+            | Flow::NetworkTokenPreferenceConfigRetrieve
+            | Flow::ProfileFeatureFlagsRetrieve
+            | Flow::ProfileFeatureFlagsUpdate
+            | Flow::PaymentFacilitatorDetailsUpsert
+            | Flow::PaymentFacilitatorDetailsRetrieve
+            | Flow::PaymentFacilitatorDetailsDelete
+            | Flow::CustomPaymentStatusMappingUpsert
+            | Flow::CustomPaymentStatusMappingRetrieve
+            | Flow::CustomPaymentStatusMappingDelete
+            | Flow::CustomFieldsSchemaUpsert
+            | Flow::CustomFieldsSchemaRetrieve
+            | Flow::CustomFieldsSchemaDelete
+            | Flow::UnifiedErrorMessageOverridesUpsert
+            | Flow::UnifiedErrorMessageOverridesRetrieve
+            | Flow::UnifiedErrorMessageOverridesDelete
+            | Flow::CheckoutThemeUpsert
+            | Flow::CheckoutThemeRetrieve
+            | Flow::CheckoutThemeDelete
+            | Flow::CheckoutThemeVersionsList
+            | Flow::CheckoutThemeActivate
+            | Flow::PostAuthorizationRulesUpsert
+            | Flow::PostAuthorizationRulesRetrieve
+            | Flow::PostAuthorizationRulesDelete
+            | Flow::DunningConfigUpsert
+            | Flow::DunningConfigRetrieve
+            | Flow::DunningConfigDelete
+            | Flow::AutoCaptureDelayConfigUpsert
+            | Flow::AutoCaptureDelayConfigRetrieve
+            | Flow::AutoCaptureDelayConfigDelete
+            | Flow::StepUpAuthenticationConfigUpsert
+            | Flow::StepUpAuthenticationConfigRetrieve
+            | Flow::StepUpAuthenticationConfigDelete
+            | Flow::ProfileTestModeUpdate
+            | Flow::ProfileTestModeRetrieve
+            | Flow::TestDataPurge
+            | Flow::ProfileCoverageRetrieve => Self::Profile,
 
             Flow::PaymentLinkRetrieve
             | Flow::PaymentLinkInitiate
@@ -306,9 +443,12 @@ impl From<Flow> for ApiIdentifier {
             | Flow::UserFromEmail
             | Flow::ListUsersInLineage => Self::UserRole,
 
-            Flow::GetActionUrl | Flow::SyncOnboardingStatus | Flow::ResetTrackingId => {
-                Self::ConnectorOnboarding
-            }
+            Flow::GetActionUrl
+            | Flow::SyncOnboardingStatus
+            | Flow::ResetTrackingId
+            | Flow::ConnectorOAuthAuthorize
+            | Flow::ConnectorOAuthCallback
+            | Flow::ConnectorOAuthStatus => Self::ConnectorOnboarding,
 
             Flow::ReconMerchantUpdate
             | Flow::ReconTokenRequest
@@ -317,6 +457,8 @@ impl From<Flow> for ApiIdentifier {
 
             Flow::RetrievePollStatus => Self::Poll,
 
+            Flow::SdkEventsBatchIngest => Self::SdkEvents,
+
             Flow::FeatureMatrix => Self::Documentation,
 
             Flow::TokenizeCard
@@ -332,6 +474,17 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentMethodSessionConfirm
             | Flow::PaymentMethodSessionUpdateSavedPaymentMethod
             | Flow::PaymentMethodSessionUpdate => Self::PaymentMethodSession,
+
+            Flow::SandboxDataSeed
+            | Flow::SandboxDataDelete
+            | Flow::TestClockRetrieve
+            | Flow::TestClockAdvance
+            | Flow::TestClockReset => Self::Sandbox,
+
+            Flow::SchedulerTasksSummary
+            | Flow::SchedulerTasksList
+            | Flow::SchedulerTaskRequeue
+            | Flow::SchedulerTaskAbandon => Self::ProcessTracker,
         }
     }
 }
@@ -25,6 +25,8 @@ histogram_metric_f64!(PAYMENT_LIST_LATENCY, GLOBAL_METER);
 
 counter_metric!(REFUND_COUNT, GLOBAL_METER);
 counter_metric!(SUCCESSFUL_REFUND, GLOBAL_METER);
+// Count of the pending refunds held for manual review by the reconciliation of the refunds
+counter_metric!(REFUND_RECONCILIATION_MANUAL_REVIEW_COUNT, GLOBAL_METER);
 
 counter_metric!(PAYMENT_CANCEL_COUNT, GLOBAL_METER);
 counter_metric!(SUCCESSFUL_CANCEL, GLOBAL_METER);
@@ -59,8 +61,11 @@ counter_metric!(MCA_CREATE, GLOBAL_METER);
 
 histogram_metric_f64!(CONNECTOR_REQUEST_TIME, GLOBAL_METER);
 counter_metric!(SESSION_TOKEN_CREATED, GLOBAL_METER);
+counter_metric!(SESSION_RESPONSE_CACHE_HIT, GLOBAL_METER);
+counter_metric!(GOOGLE_PAY_RETOKENIZATION_REQUIRED, GLOBAL_METER);
 
 counter_metric!(CONNECTOR_CALL_COUNT, GLOBAL_METER); // Attributes needed
+counter_metric!(CONNECTOR_CALL_THROTTLED_COUNT, GLOBAL_METER);
 
 counter_metric!(THREE_DS_PAYMENT_COUNT, GLOBAL_METER);
 counter_metric!(THREE_DS_DOWNGRADE_COUNT, GLOBAL_METER);
@@ -108,6 +113,7 @@ counter_metric!(AUTO_RETRY_GSM_FETCH_FAILURE_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_GSM_MATCH_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_EXHAUSTED_COUNT, GLOBAL_METER);
 counter_metric!(AUTO_RETRY_PAYMENT_COUNT, GLOBAL_METER);
+counter_metric!(AUTO_RETRY_CONNECTOR_CREDENTIALS_RESET_COUNT, GLOBAL_METER);
 
 // Metrics for Payout Auto Retries
 counter_metric!(AUTO_PAYOUT_RETRY_ELIGIBLE_REQUEST_COUNT, GLOBAL_METER);
@@ -13,12 +13,20 @@ use hyperswitch_domain_models::{
 use router_env::{instrument, logger, tracing, Flow};
 
 use super::app::{AppState, SessionState};
+#[cfg(feature = "v1")]
+use crate::core::payment_methods::preferences;
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+use crate::core::payment_methods::{consent, cvv_recollection};
 use crate::{
     core::{
         api_locking,
         errors::{self, utils::StorageErrorExt},
         payment_methods::{self as payment_methods_routes, cards},
     },
+    routes::lock_utils,
     services::{self, api, authentication as auth, authorization::permissions::Permission},
     types::{
         api::payment_methods::{self, PaymentMethodId},
@@ -363,6 +371,102 @@ pub async fn migrate_payment_methods(
     .await
 }
 
+#[cfg(all(
+    any(feature = "v1", feature = "v2", feature = "olap", feature = "oltp"),
+    not(feature = "customer_v2")
+))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsExternalTokenMigrate))]
+pub async fn migrate_external_tokens_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payment_methods::ExternalTokenMigrationRequest>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodsExternalTokenMigrate;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req, _| async move {
+            let merchant_id = req.merchant_id.clone();
+            let (key_store, merchant_account) = get_merchant_account(&state, &merchant_id).await?;
+            Box::pin(migration::migrate_external_tokens(
+                state,
+                req,
+                merchant_account,
+                key_store,
+            ))
+            .await
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodCvvTokenize))]
+pub async fn tokenize_cvv_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::payment_methods::CvvTokenizeRequest>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodCvvTokenize;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            cvv_recollection::tokenize_cvv(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::HeaderAuth(auth::PublishableKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "v1", not(feature = "customer_v2")))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodDisplayNamesList))]
+pub async fn list_payment_method_display_names_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query_payload: web::Query<api_models::payment_methods::PaymentMethodDisplayNamesRequest>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodDisplayNamesList;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            let profile_id = auth.profile_id.or(req.profile_id.clone());
+            payment_methods_routes::display_names::list_payment_method_display_names(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                profile_id,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::PublishableKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfileAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
@@ -758,6 +862,74 @@ pub async fn payment_method_update_api(
     .await
 }
 
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodConsentRecollect))]
+pub async fn payment_method_consent_recollect_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<payment_methods::PaymentMethodConsentRecollectRequest>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodConsentRecollect;
+    let payment_method_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            consent::recollect_payment_method_consent(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payment_method_id.clone(),
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodConsentRevoke))]
+pub async fn payment_method_consent_revoke_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<payment_methods::PaymentMethodConsentRevokeRequest>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodConsentRevoke;
+    let payment_method_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            consent::revoke_payment_method_consent(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payment_method_id.clone(),
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
@@ -867,6 +1039,54 @@ pub async fn default_payment_method_set_api(
     .await
 }
 
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::CustomerPaymentMethodsUpdate))]
+pub async fn customer_payment_method_update_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::CustomerId, String)>,
+    json_payload: web::Json<payment_methods::CustomerPaymentMethodUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::CustomerPaymentMethodsUpdate;
+    let (customer_id, payment_method_id) = path.into_inner();
+
+    let ephemeral_auth = match auth::is_ephemeral_auth(req.headers()) {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+    let ephemeral_api_key = auth::get_api_key(req.headers())
+        .ok()
+        .filter(|api_key| api_key.starts_with("epk"))
+        .map(ToOwned::to_owned);
+    let locking_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: customer_id.get_string_repr().to_owned(),
+            api_identifier: lock_utils::ApiIdentifier::from(flow.clone()),
+            override_lock_retries: None,
+        },
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            preferences::update_customer_payment_method(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                customer_id.clone(),
+                payment_method_id.clone(),
+                req,
+                ephemeral_api_key.as_deref(),
+            )
+        },
+        &*ephemeral_auth,
+        locking_action,
+    ))
+    .await
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used)]
@@ -3,6 +3,8 @@ use crate::{
     services::authorization::permissions::Permission,
 };
 pub mod helpers;
+#[cfg(all(feature = "olap", feature = "v1"))]
+use actix_multipart::form::MultipartForm;
 use actix_web::{web, Responder};
 use error_stack::report;
 use hyperswitch_domain_models::payments::HeaderPayload;
@@ -13,6 +15,7 @@ use super::app::ReqState;
 use crate::{
     self as app,
     core::{
+        authentication as authentication_core,
         errors::{self, http_not_implemented},
         payments::{self, PaymentRedirectFlow},
     },
@@ -397,6 +400,7 @@ pub async fn payments_retrieve(
         client_secret: json_payload.client_secret.clone(),
         expand_attempts: json_payload.expand_attempts,
         expand_captures: json_payload.expand_captures,
+        consistency_token: json_payload.consistency_token.clone(),
         ..Default::default()
     };
     let header_payload = match HeaderPayload::foreign_try_from(req.headers()) {
@@ -1255,6 +1259,56 @@ pub async fn payments_cancel(
     .await
 }
 
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCancelRemaining, payment_id))]
+pub async fn payments_cancel_remaining(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsCancelRequest>,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentsCancelRemaining;
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    payload.payment_id = payment_id;
+    let locking_action = payload.get_locking_input(flow.clone());
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            payments::payments_core::<
+                api_types::Void,
+                payment_types::PaymentsResponse,
+                _,
+                _,
+                _,
+                payments::PaymentData<api_types::Void>,
+            >(
+                state,
+                req_state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                payments::PaymentCancelRemaining,
+                req,
+                api::AuthFlow::Merchant,
+                payments::CallConnectorAction::Trigger,
+                None,
+                HeaderPayload::default(),
+                auth.platform_merchant_account,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        locking_action,
+    ))
+    .await
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::PaymentsList))]
 #[cfg(all(feature = "olap", feature = "v1"))]
 pub async fn payments_list(
@@ -1870,6 +1924,38 @@ pub async fn payments_external_authentication(
     .await
 }
 
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsResumeAuthentication, payment_id))]
+pub async fn payments_resume_authentication(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsExternalAuthenticationRequest>,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentsResumeAuthentication;
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    payload.payment_id = payment_id;
+    let locking_action = payload.get_locking_input(flow.clone());
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            authentication_core::abandonment::resume_authentication::<
+                hyperswitch_domain_models::router_flow_types::Authenticate,
+            >(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::HeaderAuth(auth::PublishableKeyAuth),
+        locking_action,
+    ))
+    .await
+}
+
 #[cfg(feature = "v1")]
 #[instrument(skip_all, fields(flow = ?Flow::PaymentsAuthorize, payment_id))]
 pub async fn post_3ds_payments_authorize(
@@ -1980,114 +2066,679 @@ pub async fn retrieve_extended_card_info(
 }
 
 #[cfg(feature = "v1")]
-pub fn get_or_generate_payment_id(
-    payload: &mut payment_types::PaymentsRequest,
-) -> errors::RouterResult<()> {
-    let given_payment_id = payload
-        .payment_id
-        .clone()
-        .map(|payment_id| {
-            payment_id
-                .get_payment_intent_id()
-                .map_err(|err| err.change_context(errors::ApiErrorResponse::PaymentNotFound))
-        })
-        .transpose()?;
-
-    let payment_id = given_payment_id.unwrap_or(common_utils::id_type::PaymentId::default());
+/// Retrieve endpoint for the timeline of events recorded for a payment
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsTimeline, payment_id))]
+pub async fn payments_timeline(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentsTimeline;
+    let payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
 
-    payload.payment_id = Some(api_models::payments::PaymentIdType::PaymentIntentId(
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
         payment_id,
-    ));
-
-    Ok(())
+        |state, auth: auth::AuthenticationData, payment_id, _| {
+            payments::timeline::get_payment_timeline(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                payment_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
 }
 
 #[cfg(feature = "v1")]
-impl GetLockingInput for payment_types::PaymentsRequest {
-    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
-    where
-        F: types::FlowMetric,
-        lock_utils::ApiIdentifier: From<F>,
-    {
-        match self.payment_id {
-            Some(payment_types::PaymentIdType::PaymentIntentId(ref id)) => {
-                api_locking::LockAction::Hold {
-                    input: api_locking::LockingInput {
-                        unique_locking_key: id.get_string_repr().to_owned(),
-                        api_identifier: lock_utils::ApiIdentifier::from(flow),
-                        override_lock_retries: None,
-                    },
-                }
-            }
-            _ => api_locking::LockAction::NotApplicable,
-        }
-    }
-}
+/// Retrieve endpoint for the dunning of a failed recurring payment
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsDunningRetrieve, payment_id))]
+pub async fn payments_dunning_retrieve(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentsDunningRetrieve;
+    let payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
 
-#[cfg(feature = "v1")]
-impl GetLockingInput for payment_types::PaymentsStartRequest {
-    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
-    where
-        F: types::FlowMetric,
-        lock_utils::ApiIdentifier: From<F>,
-    {
-        api_locking::LockAction::Hold {
-            input: api_locking::LockingInput {
-                unique_locking_key: self.payment_id.get_string_repr().to_owned(),
-                api_identifier: lock_utils::ApiIdentifier::from(flow),
-                override_lock_retries: None,
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_id,
+        |state, auth: auth::AuthenticationData, payment_id, _| {
+            payments::dunning::retrieve_dunning(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                payment_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentRead,
             },
-        }
-    }
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
 }
 
 #[cfg(feature = "v1")]
-impl GetLockingInput for payment_types::PaymentsRetrieveRequest {
-    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
-    where
-        F: types::FlowMetric,
-        lock_utils::ApiIdentifier: From<F>,
-    {
-        match self.resource_id {
-            payment_types::PaymentIdType::PaymentIntentId(ref id) if self.force_sync => {
-                api_locking::LockAction::Hold {
-                    input: api_locking::LockingInput {
-                        unique_locking_key: id.get_string_repr().to_owned(),
-                        api_identifier: lock_utils::ApiIdentifier::from(flow),
-                        override_lock_retries: None,
-                    },
-                }
-            }
-            _ => api_locking::LockAction::NotApplicable,
-        }
-    }
-}
+/// Update endpoint for the metadata of a payment
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsMetadataUpdate, payment_id))]
+pub async fn payments_metadata_update(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsMetadataUpdateRequest>,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentsMetadataUpdate;
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
 
-#[cfg(feature = "v1")]
-impl GetLockingInput for payment_types::PaymentsSessionRequest {
-    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
-    where
-        F: types::FlowMetric,
-        lock_utils::ApiIdentifier: From<F>,
-    {
-        api_locking::LockAction::Hold {
-            input: api_locking::LockingInput {
-                unique_locking_key: self.payment_id.get_string_repr().to_owned(),
-                api_identifier: lock_utils::ApiIdentifier::from(flow),
-                override_lock_retries: None,
+    payload.payment_id = payment_id;
+    let locking_action = payload.get_locking_input(flow.clone());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            payments::notes::update_payment_metadata(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentWrite,
             },
-        }
-    }
+            req.headers(),
+        ),
+        locking_action,
+    ))
+    .await
 }
 
-#[cfg(feature = "v2")]
-impl GetLockingInput for payment_types::PaymentsSessionRequest {
-    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
-    where
-        F: types::FlowMetric,
-    {
-        api_locking::LockAction::NotApplicable
-    }
+#[cfg(feature = "v1")]
+/// Add endpoint for the internal notes of a payment
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsNotesAdd, payment_id))]
+pub async fn payments_notes_add(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentNoteRequest>,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentsNotesAdd;
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    payload.payment_id = payment_id;
+    let locking_action = payload.get_locking_input(flow.clone());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, (auth, user_id): auth::AuthenticationDataWithUserId, req, _| {
+            payments::notes::add_payment_note(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                user_id,
+                req,
+            )
+        },
+        &auth::JWTAuth {
+            permission: Permission::ProfilePaymentWrite,
+        },
+        locking_action,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+/// List endpoint for the internal notes of a payment
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsNotesList, payment_id))]
+pub async fn payments_notes_list(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentsNotesList;
+    let payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_id,
+        |state, auth: auth::AuthenticationData, payment_id, _| {
+            payments::notes::list_payment_notes(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                payment_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+/// Reschedule endpoint for the capture of an authorized payment
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCaptureSchedule, payment_id))]
+pub async fn payments_capture_schedule(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsCaptureScheduleRequest>,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentsCaptureSchedule;
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    payload.payment_id = payment_id;
+    let locking_action = payload.get_locking_input(flow.clone());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            payments::scheduled_capture::reschedule_capture(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentWrite,
+            },
+            req.headers(),
+        ),
+        locking_action,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+/// Cancel endpoint for the scheduled capture of a payment
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCaptureScheduleCancel, payment_id))]
+pub async fn payments_capture_schedule_cancel(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentsCaptureScheduleCancel;
+    let payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    let locking_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: payment_id.get_string_repr().to_owned(),
+            api_identifier: lock_utils::ApiIdentifier::from(flow.clone()),
+            override_lock_retries: None,
+        },
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_id,
+        |state, auth: auth::AuthenticationData, payment_id, _| {
+            payments::scheduled_capture::cancel_scheduled_capture(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                payment_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentWrite,
+            },
+            req.headers(),
+        ),
+        locking_action,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+/// Release endpoint for the scheduled capture of a payment
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsCaptureScheduleRelease, payment_id))]
+pub async fn payments_capture_schedule_release(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentsCaptureScheduleRelease;
+    let payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    let locking_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: payment_id.get_string_repr().to_owned(),
+            api_identifier: lock_utils::ApiIdentifier::from(flow.clone()),
+            override_lock_retries: None,
+        },
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payment_id,
+        |state, auth: auth::AuthenticationData, payment_id, _| {
+            payments::scheduled_capture::release_scheduled_capture(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                payment_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentWrite,
+            },
+            req.headers(),
+        ),
+        locking_action,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+/// Finalize endpoint for the amount of a payment authorized with an estimated amount
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsFinalizeAmount, payment_id))]
+pub async fn payments_finalize_amount(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsFinalizeAmountRequest>,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentsFinalizeAmount;
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    payload.payment_id = payment_id;
+    let locking_action = payload.get_locking_input(flow.clone());
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, req_state| {
+            payments::estimated_authorization::finalize_amount(
+                state,
+                req_state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        locking_action,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+/// Endpoint to check the balance of a gift card for a payment
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsGiftCardBalance, payment_id))]
+pub async fn payments_gift_card_balance(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsGiftCardBalanceRequest>,
+    path: web::Path<common_utils::id_type::PaymentId>,
+) -> impl Responder {
+    let flow = Flow::PaymentsGiftCardBalance;
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+    tracing::Span::current().record("payment_id", payment_id.get_string_repr());
+
+    payload.payment_id = payment_id;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            payments::gift_card::get_gift_card_balance(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                req,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+/// Search endpoint for the payments of a customer by their email or phone number
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsSearchByContact))]
+pub async fn payments_search_by_contact(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<payment_types::PaymentsContactSearchRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentsSearchByContact;
+    let payload = json_payload.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            payments::contact_search::search_payments_by_contact(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+/// Lookup endpoint for a payment by the connector transaction id of one of its attempts
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsLookup))]
+pub async fn payments_lookup(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    query_payload: web::Query<payment_types::PaymentLookupRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentsLookup;
+    let payload = query_payload.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            payments::lookup::lookup_payment_by_connector_transaction_id(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+/// Import of the acquirer reference numbers of payments and refunds, read from the settlement
+/// files of the connectors
+#[instrument(skip_all, fields(flow = ?Flow::AcquirerReferenceNumbersImport))]
+pub async fn payments_import_acquirer_reference_numbers(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    payload: actix_web::Either<
+        web::Json<Vec<payment_types::AcquirerReferenceNumberRecord>>,
+        MultipartForm<payments::acquirer_reference_numbers::AcquirerReferenceNumberUploadForm>,
+    >,
+) -> impl Responder {
+    let flow = Flow::AcquirerReferenceNumbersImport;
+    let records = match payload {
+        actix_web::Either::Left(json_payload) => json_payload.into_inner(),
+        actix_web::Either::Right(MultipartForm(form)) => {
+            match payments::acquirer_reference_numbers::get_acquirer_reference_number_records(form)
+            {
+                Ok(records) => records,
+                Err(e) => return api::log_and_return_error_response(e.into()),
+            }
+        }
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        records,
+        |state, auth: auth::AuthenticationData, req, _| {
+            payments::acquirer_reference_numbers::import_acquirer_reference_numbers(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "v1"))]
+/// Polling endpoint for the payments whose status changed after a cursor
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsChanges))]
+pub async fn payments_changes(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    query_payload: web::Query<payment_types::PaymentsChangesRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentsChanges;
+    let payload = query_payload.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            payments::changes::list_payment_changes(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+pub fn get_or_generate_payment_id(
+    payload: &mut payment_types::PaymentsRequest,
+) -> errors::RouterResult<()> {
+    let given_payment_id = payload
+        .payment_id
+        .clone()
+        .map(|payment_id| {
+            payment_id
+                .get_payment_intent_id()
+                .map_err(|err| err.change_context(errors::ApiErrorResponse::PaymentNotFound))
+        })
+        .transpose()?;
+
+    let payment_id = given_payment_id.unwrap_or(common_utils::id_type::PaymentId::default());
+
+    payload.payment_id = Some(api_models::payments::PaymentIdType::PaymentIntentId(
+        payment_id,
+    ));
+
+    Ok(())
+}
+
+#[cfg(feature = "v1")]
+impl GetLockingInput for payment_types::PaymentsRequest {
+    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
+    where
+        F: types::FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        match self.payment_id {
+            Some(payment_types::PaymentIdType::PaymentIntentId(ref id)) => {
+                api_locking::LockAction::Hold {
+                    input: api_locking::LockingInput {
+                        unique_locking_key: id.get_string_repr().to_owned(),
+                        api_identifier: lock_utils::ApiIdentifier::from(flow),
+                        override_lock_retries: None,
+                    },
+                }
+            }
+            _ => api_locking::LockAction::NotApplicable,
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+impl GetLockingInput for payment_types::PaymentsStartRequest {
+    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
+    where
+        F: types::FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: self.payment_id.get_string_repr().to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::from(flow),
+                override_lock_retries: None,
+            },
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+impl GetLockingInput for payment_types::PaymentsRetrieveRequest {
+    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
+    where
+        F: types::FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        match self.resource_id {
+            payment_types::PaymentIdType::PaymentIntentId(ref id) if self.force_sync => {
+                api_locking::LockAction::Hold {
+                    input: api_locking::LockingInput {
+                        unique_locking_key: id.get_string_repr().to_owned(),
+                        api_identifier: lock_utils::ApiIdentifier::from(flow),
+                        override_lock_retries: None,
+                    },
+                }
+            }
+            _ => api_locking::LockAction::NotApplicable,
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+impl GetLockingInput for payment_types::PaymentsSessionRequest {
+    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
+    where
+        F: types::FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: self.payment_id.get_string_repr().to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::from(flow),
+                override_lock_retries: None,
+            },
+        }
+    }
+}
+
+#[cfg(feature = "v2")]
+impl GetLockingInput for payment_types::PaymentsSessionRequest {
+    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
+    where
+        F: types::FlowMetric,
+    {
+        api_locking::LockAction::NotApplicable
+    }
 }
 
 #[cfg(feature = "v1")]
@@ -2305,6 +2956,74 @@ impl GetLockingInput for payment_types::PaymentsManualUpdateRequest {
     }
 }
 
+#[cfg(feature = "v1")]
+impl GetLockingInput for payment_types::PaymentsMetadataUpdateRequest {
+    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
+    where
+        F: types::FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: self.payment_id.get_string_repr().to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::from(flow),
+                override_lock_retries: None,
+            },
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+impl GetLockingInput for payment_types::PaymentsCaptureScheduleRequest {
+    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
+    where
+        F: types::FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: self.payment_id.get_string_repr().to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::from(flow),
+                override_lock_retries: None,
+            },
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+impl GetLockingInput for payment_types::PaymentsFinalizeAmountRequest {
+    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
+    where
+        F: types::FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: self.payment_id.get_string_repr().to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::from(flow),
+                override_lock_retries: None,
+            },
+        }
+    }
+}
+
+#[cfg(feature = "v1")]
+impl GetLockingInput for payment_types::PaymentNoteRequest {
+    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
+    where
+        F: types::FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: self.payment_id.get_string_repr().to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::from(flow),
+                override_lock_retries: None,
+            },
+        }
+    }
+}
+
 #[instrument(skip_all, fields(flow = ?Flow::PaymentsAggregate))]
 #[cfg(all(feature = "olap", feature = "v1"))]
 pub async fn get_payments_aggregates_profile(
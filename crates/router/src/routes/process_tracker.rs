@@ -0,0 +1,90 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::process_tracker as process_tracker_api_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, process_tracker},
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::SchedulerTasksSummary))]
+pub async fn retrieve_scheduler_tasks_summary(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> impl Responder {
+    let flow = Flow::SchedulerTasksSummary;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _, _| process_tracker::retrieve_scheduler_tasks_summary(state),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::SchedulerTasksList))]
+pub async fn list_scheduler_tasks(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<process_tracker_api_types::SchedulerTasksListRequest>,
+) -> impl Responder {
+    let flow = Flow::SchedulerTasksList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, _, request, _| process_tracker::list_scheduler_tasks(state, request),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::SchedulerTaskRequeue))]
+pub async fn requeue_scheduler_task(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::SchedulerTaskRequeue;
+    let payload = process_tracker_api_types::SchedulerTaskId {
+        task_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| process_tracker::requeue_scheduler_task(state, payload.task_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::SchedulerTaskAbandon))]
+pub async fn abandon_scheduler_task(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::SchedulerTaskAbandon;
+    let payload = process_tracker_api_types::SchedulerTaskId {
+        task_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, payload, _| process_tracker::abandon_scheduler_task(state, payload.task_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
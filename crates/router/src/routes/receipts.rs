@@ -0,0 +1,86 @@
+use actix_web::{web, Responder};
+use router_env::{instrument, tracing, Flow};
+
+#[cfg(feature = "email")]
+use crate::services::authorization::permissions::Permission;
+use crate::{
+    core::{api_locking, payments::receipts},
+    services::{api, authentication as auth},
+    AppState,
+};
+
+/// Hosted receipt of a completed payment, as a web page or a PDF document. The link is signed, so
+/// that it can be shared with the customer of the payment.
+#[instrument(skip_all, fields(flow = ?Flow::PaymentReceiptRetrieve))]
+pub async fn retrieve_payment_receipt(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<(
+        common_utils::id_type::MerchantId,
+        common_utils::id_type::PaymentId,
+    )>,
+    query_payload: web::Query<api_models::payments::PaymentReceiptRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentReceiptRetrieve;
+    let (merchant_id, payment_id) = path.into_inner();
+    let payload = query_payload.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            receipts::retrieve_payment_receipt(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payment_id.clone(),
+                req,
+            )
+        },
+        &auth::MerchantIdAuth(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Emails the link to the receipt of a completed payment
+#[cfg(feature = "email")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentReceiptEmail))]
+pub async fn send_payment_receipt_email(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+    json_payload: web::Json<api_models::payments::PaymentReceiptEmailRequest>,
+) -> impl Responder {
+    let flow = Flow::PaymentReceiptEmail;
+    let payment_id = path.into_inner();
+    let payload = json_payload.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            receipts::send_payment_receipt_email(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                payment_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfilePaymentWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "v1")]
+use actix_multipart::form::MultipartForm;
 use actix_web::{web, HttpRequest, HttpResponse};
 use router_env::{instrument, tracing, Flow};
 
@@ -506,3 +508,138 @@ pub async fn get_refunds_aggregate_profile(
     ))
     .await
 }
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsBatchCreate))]
+pub async fn refunds_batch_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: actix_web::Either<
+        web::Json<Vec<api_models::refunds::RefundBatchRecord>>,
+        MultipartForm<batch::RefundBatchUploadForm>,
+    >,
+) -> HttpResponse {
+    let flow = Flow::RefundsBatchCreate;
+    let records = match payload {
+        actix_web::Either::Left(json_payload) => json_payload.into_inner(),
+        actix_web::Either::Right(MultipartForm(form)) => {
+            match batch::get_refund_batch_records(form) {
+                Ok(records) => records,
+                Err(e) => return api::log_and_return_error_response(e.into()),
+            }
+        }
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        records,
+        |state, auth: auth::AuthenticationData, req, _| {
+            batch::create_refund_batch(state, auth.merchant_account, auth.profile_id, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfileRefundWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsBatchRetrieve))]
+pub async fn refunds_batch_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::RefundsBatchRetrieve;
+    let batch_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        batch_id,
+        |state, auth: auth::AuthenticationData, batch_id, _| {
+            batch::retrieve_refund_batch(state, auth.merchant_account, auth.profile_id, batch_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfileRefundRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsBatchReport))]
+pub async fn refunds_batch_report(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::RefundsBatchReport;
+    let batch_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        batch_id,
+        |state, auth: auth::AuthenticationData, batch_id, _| {
+            batch::get_refund_batch_report(state, auth.merchant_account, auth.profile_id, batch_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfileRefundRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "v1")]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsResolve))]
+pub async fn refunds_resolve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<refunds::RefundResolveRequest>,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::RefundsResolve;
+    let mut refund_resolve_req = json_payload.into_inner();
+    refund_resolve_req.refund_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        refund_resolve_req,
+        |state, auth: auth::AuthenticationData, req, _| {
+            reconciliation::resolve_refund(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfileRefundWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
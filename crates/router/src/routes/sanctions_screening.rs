@@ -0,0 +1,81 @@
+use actix_web::{web, Responder};
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    core::{api_locking, sanctions_screening},
+    routes::lock_utils,
+    services::{api, authentication as auth, authorization::permissions::Permission},
+    AppState,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::SanctionsScreeningCaseRetrieve))]
+pub async fn screening_case_retrieve(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::SanctionsScreeningCaseRetrieve;
+    let case_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        case_id,
+        |state, auth: auth::AuthenticationData, case_id, _| {
+            sanctions_screening::retrieve_screening_case(state, auth.merchant_account, case_id)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::SanctionsScreeningCaseResolve))]
+pub async fn screening_case_resolve(
+    state: web::Data<AppState>,
+    req: actix_web::HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_models::sanctions_screening::ScreeningCaseResolveRequest>,
+) -> impl Responder {
+    let flow = Flow::SanctionsScreeningCaseResolve;
+    let case_id = path.into_inner();
+    let locking_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: case_id.clone(),
+            api_identifier: lock_utils::ApiIdentifier::from(flow.clone()),
+            override_lock_retries: None,
+        },
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            sanctions_screening::resolve_screening_case(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                case_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        locking_action,
+    ))
+    .await
+}
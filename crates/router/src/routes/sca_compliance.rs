@@ -0,0 +1,108 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::sca_compliance::ScaComplianceReportRequest;
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    core::{api_locking, sca_compliance},
+    routes::AppState,
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::ScaComplianceReportCreate))]
+pub async fn create_sca_compliance_report(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<ScaComplianceReportRequest>,
+) -> impl Responder {
+    let flow = Flow::ScaComplianceReportCreate;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            sca_compliance::create_sca_compliance_report(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfileReportRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ScaComplianceReportRetrieve))]
+pub async fn retrieve_sca_compliance_report(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ScaComplianceReportRetrieve;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, report_id, _| {
+            sca_compliance::retrieve_sca_compliance_report(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                report_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfileReportRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ScaComplianceReportDownload))]
+pub async fn download_sca_compliance_report(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ScaComplianceReportDownload;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth: auth::AuthenticationData, report_id, _| {
+            sca_compliance::download_sca_compliance_report(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                report_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::ProfileReportRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,33 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, sdk_events},
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::SdkEventsBatchIngest))]
+pub async fn ingest_sdk_events(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::sdk_events::SdkEventsBatchRequest>,
+) -> HttpResponse {
+    let flow = Flow::SdkEventsBatchIngest;
+    let remote_ip = req
+        .connection_info()
+        .realip_remote_addr()
+        .map(ToOwned::to_owned);
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            sdk_events::ingest_sdk_events(state, auth.merchant_account, remote_ip.clone(), req)
+        },
+        &auth::HeaderAuth(auth::PublishableKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
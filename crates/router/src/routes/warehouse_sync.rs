@@ -0,0 +1,155 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::warehouse_sync::{WarehouseSyncConfigRequest, WarehouseSyncTriggerRequest};
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    core::{api_locking, warehouse_sync},
+    routes::AppState,
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::WarehouseSyncConfigSet))]
+pub async fn set_warehouse_sync_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<WarehouseSyncConfigRequest>,
+) -> impl Responder {
+    let flow = Flow::WarehouseSyncConfigSet;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            warehouse_sync::set_warehouse_sync_config(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WarehouseSyncConfigRetrieve))]
+pub async fn retrieve_warehouse_sync_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> impl Responder {
+    let flow = Flow::WarehouseSyncConfigRetrieve;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            warehouse_sync::retrieve_warehouse_sync_config(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WarehouseSyncConfigDelete))]
+pub async fn delete_warehouse_sync_config(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> impl Responder {
+    let flow = Flow::WarehouseSyncConfigDelete;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            warehouse_sync::delete_warehouse_sync_config(state, auth.merchant_account)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WarehouseSyncStatus))]
+pub async fn retrieve_warehouse_sync_status(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> impl Responder {
+    let flow = Flow::WarehouseSyncStatus;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            warehouse_sync::retrieve_warehouse_sync_status(state, auth.merchant_account)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WarehouseSyncTrigger))]
+pub async fn trigger_warehouse_sync(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<WarehouseSyncTriggerRequest>,
+) -> impl Responder {
+    let flow = Flow::WarehouseSyncTrigger;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            warehouse_sync::trigger_warehouse_sync(state, auth.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
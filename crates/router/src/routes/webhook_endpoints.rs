@@ -0,0 +1,82 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::webhook_endpoints::WebhookEndpointHealthRequest;
+use common_utils::id_type;
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    core::{api_locking, webhooks::endpoint_health},
+    routes::AppState,
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointHealthRetrieve))]
+pub async fn retrieve_webhook_endpoint_health(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::ProfileId>,
+    query: web::Query<WebhookEndpointHealthRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEndpointHealthRetrieve;
+    let profile_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            endpoint_health::retrieve_endpoint_health(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                profile_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id: profile_id.clone(),
+                required_permission: Permission::ProfileWebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointResume))]
+pub async fn resume_webhook_endpoint(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::ProfileId>,
+) -> impl Responder {
+    let flow = Flow::WebhookEndpointResume;
+    let profile_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        profile_id.clone(),
+        |state, auth: auth::AuthenticationData, profile_id, _| {
+            endpoint_health::resume_endpoint(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                profile_id,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuthProfileFromRoute {
+                profile_id,
+                required_permission: Permission::ProfileWebhookEventWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -1,6 +1,8 @@
 use actix_web::{web, HttpRequest, Responder};
 use router_env::{instrument, tracing, Flow};
 
+#[cfg(feature = "v1")]
+use crate::core::webhooks::event_catalog;
 use crate::{
     core::{api_locking, webhooks::webhook_events},
     routes::AppState,
@@ -10,8 +12,8 @@ use crate::{
         authorization::permissions::Permission,
     },
     types::api::webhook_events::{
-        EventListConstraints, EventListRequestInternal, WebhookDeliveryAttemptListRequestInternal,
-        WebhookDeliveryRetryRequestInternal,
+        EventListConstraints, EventListRequestInternal, LifecycleEventListConstraints,
+        WebhookDeliveryAttemptListRequestInternal, WebhookDeliveryRetryRequestInternal,
     },
 };
 
@@ -174,3 +176,59 @@ pub async fn retry_webhook_delivery_attempt(
     ))
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventTypeList))]
+#[cfg(feature = "v1")]
+pub async fn list_event_types(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    let flow = Flow::WebhookEventTypeList;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _: auth::AuthenticationData, _, _| event_catalog::list_event_types(state),
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantWebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventLifecycleList))]
+pub async fn list_lifecycle_events(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<LifecycleEventListConstraints>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventLifecycleList;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth: auth::AuthenticationData, constraints, _| {
+            webhook_events::list_lifecycle_events(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                constraints,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantWebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
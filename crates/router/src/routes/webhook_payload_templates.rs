@@ -0,0 +1,187 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::webhook_payload_templates::{
+    WebhookPayloadTemplatePreviewRequest, WebhookPayloadTemplateRequest,
+    WebhookPayloadTemplateValidateRequest,
+};
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    core::{api_locking, webhooks::payload_templates},
+    routes::AppState,
+    services::{api, authentication as auth, authorization::permissions::Permission},
+    types::api::enums,
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookPayloadTemplateSet))]
+pub async fn set_webhook_payload_template(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<enums::EventType>,
+    json_payload: web::Json<WebhookPayloadTemplateRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookPayloadTemplateSet;
+    let event_type = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            payload_templates::set_payload_template(state, auth.merchant_account, event_type, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantWebhookEventWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookPayloadTemplateRetrieve))]
+pub async fn retrieve_webhook_payload_template(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<enums::EventType>,
+) -> impl Responder {
+    let flow = Flow::WebhookPayloadTemplateRetrieve;
+    let event_type = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            payload_templates::retrieve_payload_template(state, auth.merchant_account, event_type)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantWebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookPayloadTemplateList))]
+pub async fn list_webhook_payload_templates(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> impl Responder {
+    let flow = Flow::WebhookPayloadTemplateList;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            payload_templates::list_payload_templates(state, auth.merchant_account)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantWebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookPayloadTemplateDelete))]
+pub async fn delete_webhook_payload_template(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<enums::EventType>,
+) -> impl Responder {
+    let flow = Flow::WebhookPayloadTemplateDelete;
+    let event_type = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _, _| {
+            payload_templates::delete_payload_template(state, auth.merchant_account, event_type)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantWebhookEventWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookPayloadTemplateValidate))]
+pub async fn validate_webhook_payload_template(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<WebhookPayloadTemplateValidateRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookPayloadTemplateValidate;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |_, _: auth::AuthenticationData, req, _| payload_templates::validate_payload_template(req),
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantWebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookPayloadTemplatePreview))]
+pub async fn preview_webhook_payload_template(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<WebhookPayloadTemplatePreviewRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookPayloadTemplatePreview;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            payload_templates::preview_payload_template(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth {
+                permission: Permission::MerchantWebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -86,6 +86,7 @@ pub async fn get_store(
         store,
         config.drainer.stream_name.clone(),
         config.drainer.num_partitions,
+        config.drainer.priority_lanes.enabled,
         config.kv_config.ttl,
         config.kv_config.soft_kill,
     );
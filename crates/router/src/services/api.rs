@@ -1,6 +1,8 @@
+pub mod cassette;
 pub mod client;
 pub mod generic_link_response;
 pub mod request;
+pub mod response_versioning;
 use std::{
     collections::{HashMap, HashSet},
     error::Error,
@@ -15,8 +17,9 @@ use actix_http::header::HeaderMap;
 use actix_web::{
     body,
     http::header::{HeaderName, HeaderValue},
-    web, FromRequest, HttpRequest, HttpResponse, Responder, ResponseError,
+    web, FromRequest, HttpMessage, HttpRequest, HttpResponse, Responder, ResponseError,
 };
+pub use cassette::get_api_client;
 pub use client::{ApiClient, MockApiClient, ProxyClient};
 pub use common_enums::enums::PaymentAction;
 pub use common_utils::request::{ContentType, Method, Request, RequestBuilder};
@@ -54,16 +57,16 @@ use tera::{Context, Error as TeraError, Tera};
 
 use self::request::{HeaderExt, RequestBuilderExt};
 use super::{
-    authentication::AuthenticateAndFetch,
+    authentication::{AuthenticateAndFetch, AuthenticationType},
     connector_integration_interface::BoxedConnectorIntegrationInterface,
 };
 use crate::{
     configs::Settings,
     consts,
     core::{
-        api_locking,
+        api_locking, connector_rate_limit,
         errors::{self, CustomResult},
-        payments,
+        fault_injection, payments,
     },
     events::{
         api_logs::{ApiEvent, ApiEventMetric, ApiEventsType},
@@ -217,6 +220,21 @@ where
 
             match connector_request {
                 Some(request) => {
+                    // The throttled calls fail the request without updating the attempt, so
+                    // that the request can be retried
+                    if !connector_rate_limit::acquire_connector_call_permit(
+                        state,
+                        &req.merchant_id,
+                        req.profile_id.as_ref(),
+                        &req.connector,
+                    )
+                    .await
+                    {
+                        return Err(report!(errors::ConnectorError::ConnectorThrottled {
+                            connector: req.connector.clone(),
+                        }));
+                    }
+
                     let masked_request_body = match &request.body {
                         Some(request) => match request {
                             RequestContent::Json(i)
@@ -232,9 +250,21 @@ where
                     let request_url = request.url.clone();
                     let request_method = request.method;
                     let current_time = Instant::now();
-                    let response =
-                        call_connector_api(state, request, "execute_connector_processing_step")
-                            .await;
+                    let call_fault = fault_injection::get_connector_call_fault(
+                        state,
+                        &req.merchant_id,
+                        &req.connector,
+                    )
+                    .await;
+                    let response = match call_fault {
+                        Some(call_fault) => {
+                            fault_injection::get_injected_fault_response(call_fault)
+                        }
+                        None => {
+                            call_connector_api(state, request, "execute_connector_processing_step")
+                                .await
+                        }
+                    };
                     let external_latency = current_time.elapsed().as_millis();
                     logger::info!(raw_connector_request=?masked_request_body);
                     let status_code = response
@@ -784,6 +814,15 @@ where
 
     request_state.event_context.record_info(auth_type.clone());
 
+    // The responses of the api keys pinned to a version are adapted to it while being serialized
+    if let AuthenticationType::ApiKey {
+        response_version: Some(response_version),
+        ..
+    } = &auth_type
+    {
+        request.extensions_mut().insert(*response_version);
+    }
+
     let merchant_id = auth_type
         .get_merchant_id()
         .cloned()
@@ -949,17 +988,24 @@ where
         response
     });
 
+    let response_version = request
+        .extensions()
+        .get::<common_enums::ApiResponseVersion>()
+        .copied();
+
     let res = match server_wrap_util_res {
-        Ok(ApplicationResponse::Json(response)) => match serde_json::to_string(&response) {
-            Ok(res) => http_response_json(res),
-            Err(_) => http_response_err(
-                r#"{
+        Ok(ApplicationResponse::Json(response)) => {
+            match response_versioning::serialize_response(&response, response_version) {
+                Ok(res) => http_response_json(res),
+                Err(_) => http_response_err(
+                    r#"{
                     "error": {
                         "message": "Error serializing response from connector"
                     }
                 }"#,
-            ),
-        },
+                ),
+            }
+        }
         Ok(ApplicationResponse::StatusOk) => http_response_ok(),
         Ok(ApplicationResponse::TextPlain(text)) => http_response_plaintext(text),
         Ok(ApplicationResponse::FileData((file_data, content_type))) => {
@@ -1053,7 +1099,7 @@ where
                     None
                 }
             });
-            match serde_json::to_string(&response) {
+            match response_versioning::serialize_response(&response, response_version) {
                 Ok(res) => http_response_json_with_headers(res, headers, request_elapsed_time),
                 Err(_) => http_response_err(
                     r#"{
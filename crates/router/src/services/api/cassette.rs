@@ -0,0 +1,644 @@
+//! Recording of the HTTP exchanges with the connectors into cassettes, and their replay in place of
+//! the connectors. The exchanges are recorded while running the integration tests against the
+//! sandboxes of the connectors, with the credentials, the cards and the secrets of the requests
+//! and the responses redacted, and are replayed in CI so that the payment flows are tested without
+//! live credentials.
+//!
+//! A cassette is kept for each host called, and its exchanges are replayed in the order in which
+//! they were recorded, by matching the method and the sanitized URL of the requests. The sanitized
+//! fields are redacted from the query parameters of the URLs, the headers, and the JSON,
+//! form-encoded and XML bodies.
+
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap, HashSet},
+    path::PathBuf,
+    sync::Arc,
+};
+
+use common_utils::request::{Method, Request, RequestContent};
+use error_stack::{report, ResultExt};
+use http::Method as HttpMethod;
+use masking::Maskable;
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use router_env::{logger, tracing_actix_web::RequestId};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tokio::sync::Mutex;
+
+use super::client::{ApiClient, ProxyClient, RequestBuilder};
+use crate::{
+    configs::settings::{CassetteMode, ConnectorCassettes},
+    core::errors::{ApiClientError, CustomResult},
+    routes::SessionState,
+};
+
+const REDACTED: &str = "[REDACTED]";
+
+/// Leaf elements of XML bodies, along with their text or CDATA section
+static XML_ELEMENT_REGEX: Lazy<Result<Regex, regex::Error>> = Lazy::new(|| {
+    Regex::new(concat!(
+        r"<(?P<tag>(?:[\w.-]+:)?(?P<name>[\w.-]+)(?:\s[^<>]*)?)>",
+        r"(?:[^<]*|<!\[CDATA\[[\s\S]*?\]\]>)</"
+    ))
+});
+
+/// Attributes of the elements of XML bodies, along with their value
+static XML_ATTRIBUTE_REGEX: Lazy<Result<Regex, regex::Error>> = Lazy::new(|| {
+    Regex::new(r#"(?P<prefix>\s(?:[\w.-]+:)?(?P<name>[\w.-]+)\s*=\s*)(?:"[^"]*"|'[^']*')"#)
+});
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Cassette {
+    interactions: Vec<Interaction>,
+    /// Number of the interactions replayed, by the method and the URL of their requests
+    #[serde(skip)]
+    replayed: HashMap<String, usize>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Interaction {
+    request: RecordedRequest,
+    response: RecordedResponse,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecordedRequest {
+    method: Method,
+    url: String,
+    headers: BTreeMap<String, String>,
+    body: Option<Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecordedResponse {
+    status_code: u16,
+    headers: BTreeMap<String, String>,
+    body: String,
+}
+
+/// Api client which records the exchanges of the underlying client into cassettes, or replays the
+/// recorded exchanges without calling the connectors
+#[derive(Clone)]
+pub struct CassetteClient {
+    inner: ProxyClient,
+    config: ConnectorCassettes,
+    cassettes: Arc<Mutex<HashMap<String, Cassette>>>,
+}
+
+/// The api client of the application, which goes through the cassettes when they are enabled
+pub fn get_api_client(
+    proxy_client: ProxyClient,
+    config: &ConnectorCassettes,
+) -> Box<dyn ApiClient> {
+    match config.mode {
+        CassetteMode::Off => Box::new(proxy_client),
+        CassetteMode::Record | CassetteMode::Replay => {
+            logger::warn!(
+                mode = ?config.mode,
+                directory = ?config.directory,
+                "Connector cassettes are enabled"
+            );
+            Box::new(CassetteClient {
+                inner: proxy_client,
+                config: config.clone(),
+                cassettes: Arc::new(Mutex::new(HashMap::new())),
+            })
+        }
+    }
+}
+
+impl CassetteClient {
+    fn get_cassette_path(&self, host: &str) -> PathBuf {
+        self.config.directory.join(format!("{host}.json"))
+    }
+
+    async fn load_cassette(&self, host: &str) -> CustomResult<Cassette, ApiClientError> {
+        let path = self.get_cassette_path(host);
+        let contents = match tokio::task::spawn_blocking(move || std::fs::read(path))
+            .await
+            .change_context(ApiClientError::UnexpectedState)?
+        {
+            Ok(contents) => contents,
+            // A cassette is recorded from scratch for a host which has not been called yet
+            Err(error)
+                if error.kind() == std::io::ErrorKind::NotFound
+                    && self.config.mode == CassetteMode::Record =>
+            {
+                return Ok(Cassette::default());
+            }
+            Err(error) => {
+                return Err(report!(error)
+                    .change_context(ApiClientError::UnexpectedState)
+                    .attach_printable(format!("Failed to read the cassette of host {host}")));
+            }
+        };
+
+        serde_json::from_slice(&contents)
+            .change_context(ApiClientError::ResponseDecodingFailed)
+            .attach_printable(format!("Failed to parse the cassette of host {host}"))
+    }
+
+    async fn record(
+        &self,
+        state: &SessionState,
+        host: String,
+        request: Request,
+        option_timeout_secs: Option<u64>,
+        forward_to_kafka: bool,
+    ) -> CustomResult<reqwest::Response, ApiClientError> {
+        let recorded_request = self.get_recorded_request(&request);
+        let response = self
+            .inner
+            .send_request(state, request, option_timeout_secs, forward_to_kafka)
+            .await?;
+
+        let status_code = response.status().as_u16();
+        let headers = response
+            .headers()
+            .iter()
+            .map(|(name, value)| {
+                (
+                    name.to_string(),
+                    String::from_utf8_lossy(value.as_bytes()).into_owned(),
+                )
+            })
+            .collect::<BTreeMap<_, _>>();
+        let body = response
+            .bytes()
+            .await
+            .change_context(ApiClientError::ResponseDecodingFailed)
+            .attach_printable("Error while reading the response to be recorded")?;
+
+        self.record_interaction(host, recorded_request, status_code, headers, body.to_vec())
+            .await
+    }
+
+    /// Appends the exchange to the cassette of the host, with the response sanitized. The actual
+    /// response is returned.
+    async fn record_interaction(
+        &self,
+        host: String,
+        recorded_request: RecordedRequest,
+        status_code: u16,
+        headers: BTreeMap<String, String>,
+        body: Vec<u8>,
+    ) -> CustomResult<reqwest::Response, ApiClientError> {
+        let content_type = headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(http::header::CONTENT_TYPE.as_str()))
+            .map(|(_, value)| value.to_lowercase());
+        let interaction = Interaction {
+            request: recorded_request,
+            response: RecordedResponse {
+                status_code,
+                headers: self.sanitize_headers(headers.clone()),
+                body: self.sanitize_body(content_type.as_deref(), &body),
+            },
+        };
+        let actual_response = build_response(status_code, &headers, body)?;
+
+        let mut cassettes = self.cassettes.lock().await;
+        if !cassettes.contains_key(&host) {
+            let cassette = self.load_cassette(&host).await?;
+            cassettes.insert(host.clone(), cassette);
+        }
+        let cassette = cassettes
+            .get_mut(&host)
+            .ok_or(ApiClientError::UnexpectedState)?;
+        cassette.interactions.push(interaction);
+
+        let serialized_cassette = serde_json::to_vec_pretty(&cassette)
+            .change_context(ApiClientError::BodySerializationFailed)?;
+        let directory = self.config.directory.clone();
+        let path = self.get_cassette_path(&host);
+        // The lock is held while writing, so that the cassette is written in the order of the
+        // exchanges
+        tokio::task::spawn_blocking(move || {
+            std::fs::create_dir_all(directory)?;
+            std::fs::write(path, serialized_cassette)
+        })
+        .await
+        .change_context(ApiClientError::UnexpectedState)?
+        .map_err(|error| {
+            report!(error)
+                .change_context(ApiClientError::UnexpectedState)
+                .attach_printable(format!("Failed to write the cassette of host {host}"))
+        })?;
+
+        Ok(actual_response)
+    }
+
+    async fn replay(
+        &self,
+        host: String,
+        request: Request,
+    ) -> CustomResult<reqwest::Response, ApiClientError> {
+        let mut cassettes = self.cassettes.lock().await;
+        if !cassettes.contains_key(&host) {
+            let cassette = self.load_cassette(&host).await?;
+            cassettes.insert(host.clone(), cassette);
+        }
+        let cassette = cassettes
+            .get_mut(&host)
+            .ok_or(ApiClientError::UnexpectedState)?;
+
+        let url = sanitize_url(&request.url, &self.config.sanitized_fields);
+        let replayed = cassette
+            .replayed
+            .entry(format!("{} {}", request.method, url))
+            .or_default();
+        let interaction = cassette
+            .interactions
+            .iter()
+            .filter(|interaction| {
+                interaction.request.method == request.method && interaction.request.url == url
+            })
+            .nth(*replayed)
+            .ok_or(ApiClientError::UnexpectedState)
+            .attach_printable_lazy(|| {
+                format!(
+                    "No recorded interaction left in the cassette of host {host} for {} {}",
+                    request.method, request.url
+                )
+            })?;
+        *replayed += 1;
+
+        logger::debug!(
+            method = ?request.method,
+            url = request.url,
+            "Replaying recorded interaction"
+        );
+        build_response(
+            interaction.response.status_code,
+            &interaction.response.headers,
+            interaction.response.body.clone().into_bytes(),
+        )
+    }
+
+    fn get_recorded_request(&self, request: &Request) -> RecordedRequest {
+        let headers = request
+            .headers
+            .iter()
+            .map(|(name, value)| {
+                let value = match value {
+                    Maskable::Masked(_) => REDACTED.to_string(),
+                    Maskable::Normal(value) => value.clone(),
+                };
+                (name.clone(), value)
+            })
+            .collect();
+
+        let body = request.body.as_ref().map(|body| {
+            let mut body = match body {
+                RequestContent::Json(body)
+                | RequestContent::FormUrlEncoded(body)
+                | RequestContent::Xml(body) => body
+                    .masked_serialize()
+                    .unwrap_or(json!({ "error": "failed to mask serialize"})),
+                RequestContent::FormData(_) => json!({"request_type": "FORM_DATA"}),
+                RequestContent::RawBytes(_) => json!({"request_type": "RAW_BYTES"}),
+            };
+            sanitize_value(&mut body, &self.config.sanitized_fields);
+            body
+        });
+
+        RecordedRequest {
+            method: request.method,
+            url: sanitize_url(&request.url, &self.config.sanitized_fields),
+            headers: self.sanitize_headers(headers),
+            body,
+        }
+    }
+
+    fn sanitize_headers(&self, headers: BTreeMap<String, String>) -> BTreeMap<String, String> {
+        headers
+            .into_iter()
+            .map(|(name, value)| {
+                if self.config.sanitized_fields.contains(&name.to_lowercase()) {
+                    (name, REDACTED.to_string())
+                } else {
+                    (name, value)
+                }
+            })
+            .collect()
+    }
+
+    /// Redacts the sanitized fields of the JSON, form-encoded and XML bodies. The other bodies are
+    /// recorded as they are.
+    fn sanitize_body(&self, content_type: Option<&str>, body: &[u8]) -> String {
+        let sanitized_fields = &self.config.sanitized_fields;
+        if let Ok(mut value) = serde_json::from_slice::<Value>(body) {
+            sanitize_value(&mut value, sanitized_fields);
+            return value.to_string();
+        }
+
+        let body = String::from_utf8_lossy(body);
+        match content_type {
+            Some(content_type) if content_type.contains("x-www-form-urlencoded") => {
+                sanitize_form(&body, sanitized_fields)
+            }
+            Some(content_type) if content_type.contains("xml") => {
+                sanitize_xml(&body, sanitized_fields)
+            }
+            _ if body.trim_start().starts_with('<') => sanitize_xml(&body, sanitized_fields),
+            _ => body.into_owned(),
+        }
+    }
+}
+
+fn is_sanitized_field(name: &str, sanitized_fields: &HashSet<String>) -> bool {
+    sanitized_fields.contains(&name.to_lowercase())
+}
+
+/// Whether the form field is sanitized, including the nested fields such as `card[number]`
+fn is_sanitized_form_field(name: &str, sanitized_fields: &HashSet<String>) -> bool {
+    name.split(['[', ']'])
+        .filter(|segment| !segment.is_empty())
+        .any(|segment| is_sanitized_field(segment, sanitized_fields))
+}
+
+fn sanitize_value(value: &mut Value, sanitized_fields: &HashSet<String>) {
+    match value {
+        Value::Object(fields) => fields.iter_mut().for_each(|(name, value)| {
+            if is_sanitized_field(name, sanitized_fields) {
+                *value = Value::String(REDACTED.to_string());
+            } else {
+                sanitize_value(value, sanitized_fields);
+            }
+        }),
+        Value::Array(values) => values
+            .iter_mut()
+            .for_each(|value| sanitize_value(value, sanitized_fields)),
+        _ => {}
+    }
+}
+
+fn sanitize_form_pairs<'a>(
+    pairs: url::form_urlencoded::Parse<'a>,
+    sanitized_fields: &'a HashSet<String>,
+) -> impl Iterator<Item = (Cow<'a, str>, Cow<'a, str>)> {
+    pairs.map(|(name, value)| {
+        if is_sanitized_form_field(&name, sanitized_fields) {
+            (name, Cow::Borrowed(REDACTED))
+        } else {
+            (name, value)
+        }
+    })
+}
+
+fn sanitize_form(body: &str, sanitized_fields: &HashSet<String>) -> String {
+    url::form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(sanitize_form_pairs(
+            url::form_urlencoded::parse(body.as_bytes()),
+            sanitized_fields,
+        ))
+        .finish()
+}
+
+/// Redacts the query parameters of the URL which are sanitized. The URLs which cannot be parsed
+/// are recorded as they are, as the requests are not sent to them either.
+fn sanitize_url(url: &str, sanitized_fields: &HashSet<String>) -> String {
+    let Ok(mut parsed_url) = url::Url::parse(url) else {
+        return url.to_string();
+    };
+    if parsed_url.query().is_none() {
+        return url.to_string();
+    }
+
+    let query = parsed_url.query().map(str::to_string).unwrap_or_default();
+    parsed_url
+        .query_pairs_mut()
+        .clear()
+        .extend_pairs(sanitize_form_pairs(
+            url::form_urlencoded::parse(query.as_bytes()),
+            sanitized_fields,
+        ));
+    parsed_url.to_string()
+}
+
+/// Redacts the text of the leaf elements and the value of the attributes which are sanitized. The
+/// body is redacted as a whole if it could not be sanitized.
+fn sanitize_xml(body: &str, sanitized_fields: &HashSet<String>) -> String {
+    let (Ok(element_regex), Ok(attribute_regex)) =
+        (XML_ELEMENT_REGEX.as_ref(), XML_ATTRIBUTE_REGEX.as_ref())
+    else {
+        return REDACTED.to_string();
+    };
+
+    let body = element_regex.replace_all(body, |captures: &Captures<'_>| {
+        if is_sanitized_field(&captures["name"], sanitized_fields) {
+            format!("<{}>{REDACTED}</", &captures["tag"])
+        } else {
+            captures[0].to_string()
+        }
+    });
+    attribute_regex
+        .replace_all(&body, |captures: &Captures<'_>| {
+            if is_sanitized_field(&captures["name"], sanitized_fields) {
+                format!("{}\"{REDACTED}\"", &captures["prefix"])
+            } else {
+                captures[0].to_string()
+            }
+        })
+        .into_owned()
+}
+
+fn build_response(
+    status_code: u16,
+    headers: &BTreeMap<String, String>,
+    body: Vec<u8>,
+) -> CustomResult<reqwest::Response, ApiClientError> {
+    let response = headers
+        .iter()
+        .fold(
+            http::Response::builder().status(status_code),
+            |builder, (name, value)| builder.header(name.as_str(), value.as_str()),
+        )
+        .body(body)
+        .change_context(ApiClientError::UnexpectedState)
+        .attach_printable("Failed to construct the response from the cassette")?;
+
+    Ok(reqwest::Response::from(response))
+}
+
+fn get_host(url: &str) -> CustomResult<String, ApiClientError> {
+    url::Url::parse(url)
+        .change_context(ApiClientError::UrlParsingFailed)?
+        .host_str()
+        .map(ToOwned::to_owned)
+        .ok_or_else(|| report!(ApiClientError::UrlParsingFailed))
+        .attach_printable("The URL of the request has no host")
+}
+
+#[async_trait::async_trait]
+impl ApiClient for CassetteClient {
+    fn request(
+        &self,
+        method: HttpMethod,
+        url: String,
+    ) -> CustomResult<Box<dyn RequestBuilder>, ApiClientError> {
+        self.inner.request(method, url)
+    }
+
+    fn request_with_certificate(
+        &self,
+        method: HttpMethod,
+        url: String,
+        certificate: Option<masking::Secret<String>>,
+        certificate_key: Option<masking::Secret<String>>,
+    ) -> CustomResult<Box<dyn RequestBuilder>, ApiClientError> {
+        self.inner
+            .request_with_certificate(method, url, certificate, certificate_key)
+    }
+
+    async fn send_request(
+        &self,
+        state: &SessionState,
+        request: Request,
+        option_timeout_secs: Option<u64>,
+        forward_to_kafka: bool,
+    ) -> CustomResult<reqwest::Response, ApiClientError> {
+        let host = get_host(&request.url)?;
+        match self.config.mode {
+            CassetteMode::Record => {
+                self.record(state, host, request, option_timeout_secs, forward_to_kafka)
+                    .await
+            }
+            CassetteMode::Replay => self.replay(host, request).await,
+            CassetteMode::Off => {
+                self.inner
+                    .send_request(state, request, option_timeout_secs, forward_to_kafka)
+                    .await
+            }
+        }
+    }
+
+    fn add_request_id(&mut self, request_id: RequestId) {
+        self.inner.add_request_id(request_id)
+    }
+
+    fn get_request_id(&self) -> Option<String> {
+        self.inner.get_request_id()
+    }
+
+    fn add_flow_name(&mut self, flow_name: String) {
+        self.inner.add_flow_name(flow_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use masking::Mask;
+
+    use super::*;
+    use crate::configs::settings::Proxy;
+
+    fn get_cassette_client(mode: CassetteMode, directory: &std::path::Path) -> CassetteClient {
+        CassetteClient {
+            inner: ProxyClient::new(&Proxy::default()).unwrap(),
+            config: ConnectorCassettes {
+                mode,
+                directory: directory.to_path_buf(),
+                ..Default::default()
+            },
+            cassettes: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    fn get_request(url: &str) -> Request {
+        let mut request = Request::new(Method::Post, url);
+        request.add_header(
+            "Authorization",
+            "Bearer sk_test_123".to_string().into_masked(),
+        );
+        request.add_header("Idempotency-Key", "idem_123".to_string().into());
+        request.set_body(RequestContent::FormUrlEncoded(Box::new(json!({
+            "amount": 100,
+            "card": { "number": "4242424242424242", "cvc": "123" },
+        }))));
+        request
+    }
+
+    #[tokio::test]
+    async fn test_record_then_replay() {
+        let directory = std::env::temp_dir().join(format!("cassettes_{}", uuid::Uuid::new_v4()));
+        let host = "connector.example.com".to_string();
+        let url = "https://connector.example.com/v1/tokens?access_token=at_123&expand=card";
+        let responses = [
+            (
+                "application/x-www-form-urlencoded",
+                "access_token=at_456&refresh_token=rt_456&card%5Bnumber%5D=4242424242424242&\
+                 expires_in=3600",
+            ),
+            (
+                "text/xml",
+                "<?xml version=\"1.0\"?><response><card secret=\"s_123\"><Number>\
+                 4242424242424242</Number><cvc><![CDATA[123]]></cvc></card>\
+                 <status>approved</status></response>",
+            ),
+            ("application/json", r#"{"id":"tok_123","secret":"s_123"}"#),
+        ];
+
+        let recorder = get_cassette_client(CassetteMode::Record, &directory);
+        for (content_type, body) in responses {
+            let recorded_request = recorder.get_recorded_request(&get_request(url));
+            let response = recorder
+                .record_interaction(
+                    host.clone(),
+                    recorded_request,
+                    200,
+                    BTreeMap::from([("content-type".to_string(), content_type.to_string())]),
+                    body.as_bytes().to_vec(),
+                )
+                .await
+                .unwrap();
+            // The actual response is returned to the application while recording
+            assert_eq!(response.text().await.unwrap(), body);
+        }
+
+        let cassette = std::fs::read_to_string(directory.join(format!("{host}.json"))).unwrap();
+        for secret in [
+            "sk_test_123",
+            "4242424242424242",
+            "at_123",
+            "at_456",
+            "rt_456",
+            "s_123",
+            "CDATA",
+        ] {
+            assert!(!cassette.contains(secret), "{secret} is recorded");
+        }
+        for value in [
+            "idem_123",
+            "expand=card",
+            "expires_in=3600",
+            "approved",
+            "tok_123",
+        ] {
+            assert!(cassette.contains(value), "{value} is not recorded");
+        }
+
+        let replayer = get_cassette_client(CassetteMode::Replay, &directory);
+        let mut replayed_bodies = Vec::new();
+        for _ in responses {
+            let response = replayer
+                .replay(host.clone(), get_request(url))
+                .await
+                .unwrap();
+            assert_eq!(response.status().as_u16(), 200);
+            replayed_bodies.push(response.text().await.unwrap());
+        }
+        assert!(replayed_bodies[0].contains("expires_in=3600"));
+        assert!(replayed_bodies[1].contains("<Number>[REDACTED]</Number>"));
+        assert!(replayed_bodies[1].contains("secret=\"[REDACTED]\""));
+        assert!(replayed_bodies[2].contains("tok_123"));
+
+        // The recorded interactions are replayed once each
+        assert!(replayer.replay(host, get_request(url)).await.is_err());
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
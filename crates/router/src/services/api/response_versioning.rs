@@ -0,0 +1,154 @@
+//! Versioning of the contract of the payment responses. The changes made to the payment
+//! responses are declared against the version in which they were made, and the responses of an
+//! api key pinned to an older version are adapted by reverting the changes made after it.
+
+use common_enums::ApiResponseVersion;
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+use serde::Serialize;
+
+/// A change made to the contract of the payment responses
+enum ResponseChange {
+    /// A field was added to the response
+    FieldAdded(&'static str),
+}
+
+impl ResponseChange {
+    fn revert(&self, response: &mut serde_json::Map<String, serde_json::Value>) {
+        match self {
+            Self::FieldAdded(field) => {
+                response.remove(*field);
+            }
+        }
+    }
+}
+
+struct ResponseVersionChange {
+    version: ApiResponseVersion,
+    changes: &'static [ResponseChange],
+}
+
+/// The changes made to the payment responses, along with the version in which they were made
+const PAYMENTS_RESPONSE_VERSION_CHANGES: &[ResponseVersionChange] = &[
+    ResponseVersionChange {
+        version: ApiResponseVersion::V2025_02_01,
+        changes: &[ResponseChange::FieldAdded("card_discovery")],
+    },
+    ResponseVersionChange {
+        version: ApiResponseVersion::V2025_04_01,
+        changes: &[
+            ResponseChange::FieldAdded("issuer_error_code"),
+            ResponseChange::FieldAdded("issuer_error_message"),
+        ],
+    },
+    ResponseVersionChange {
+        version: ApiResponseVersion::V2025_06_01,
+        changes: &[
+            ResponseChange::FieldAdded("custom_status"),
+            ResponseChange::FieldAdded("localized_unified_message"),
+            ResponseChange::FieldAdded("test_mode"),
+            ResponseChange::FieldAdded("connector_response_details"),
+            ResponseChange::FieldAdded("avs_result"),
+            ResponseChange::FieldAdded("cvv_result"),
+            ResponseChange::FieldAdded("acquirer_reference_number"),
+            ResponseChange::FieldAdded("receipt_url"),
+            ResponseChange::FieldAdded("address_validation"),
+            ResponseChange::FieldAdded("consistency_token"),
+            ResponseChange::FieldAdded("scheduled_capture_at"),
+            ResponseChange::FieldAdded("is_amount_estimated"),
+            ResponseChange::FieldAdded("custom_fields"),
+            ResponseChange::FieldAdded("enable_partial_payments"),
+            ResponseChange::FieldAdded("amount_remaining"),
+            ResponseChange::FieldAdded("store_credit_amount"),
+        ],
+    },
+];
+
+/// Adapts the payment response of the latest version to the given version
+fn adapt_payments_response(response: &mut serde_json::Value, version: ApiResponseVersion) {
+    let Some(response) = response.as_object_mut() else {
+        return;
+    };
+
+    PAYMENTS_RESPONSE_VERSION_CHANGES
+        .iter()
+        .rev()
+        .filter(|version_change| version_change.version > version)
+        .flat_map(|version_change| version_change.changes)
+        .for_each(|change| change.revert(response));
+}
+
+/// Serializes the response, adapted to the version to which the api key is pinned if the
+/// response is a payment response
+pub fn serialize_response<Q: Serialize + ApiEventMetric>(
+    response: &Q,
+    version: Option<ApiResponseVersion>,
+) -> serde_json::Result<String> {
+    match version {
+        Some(version)
+            if version < ApiResponseVersion::LATEST
+                && matches!(
+                    response.get_api_event_type(),
+                    Some(ApiEventsType::Payment { .. })
+                ) =>
+        {
+            let mut response = serde_json::to_value(response)?;
+            adapt_payments_response(&mut response, version);
+            serde_json::to_string(&response)
+        }
+        _ => serde_json::to_string(response),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payments_response_is_adapted_to_pinned_version() {
+        let response = serde_json::json!({
+            "payment_id": "pay_123",
+            "card_discovery": "manual",
+            "issuer_error_code": "01",
+            "issuer_error_message": "Refer to issuer",
+        });
+
+        let mut adapted_response = response.clone();
+        adapt_payments_response(&mut adapted_response, ApiResponseVersion::V2025_02_01);
+        assert_eq!(
+            adapted_response,
+            serde_json::json!({"payment_id": "pay_123", "card_discovery": "manual"})
+        );
+
+        let mut adapted_response = response.clone();
+        adapt_payments_response(&mut adapted_response, ApiResponseVersion::V2024_12_01);
+        assert_eq!(
+            adapted_response,
+            serde_json::json!({"payment_id": "pay_123"})
+        );
+
+        let mut adapted_response = response.clone();
+        adapt_payments_response(&mut adapted_response, ApiResponseVersion::LATEST);
+        assert_eq!(adapted_response, response);
+    }
+
+    #[test]
+    fn test_fields_added_after_a_released_version_are_reverted_for_it() {
+        let response = serde_json::json!({
+            "payment_id": "pay_123",
+            "issuer_error_code": "01",
+            "custom_status": "awaiting_shipment",
+            "store_credit_amount": 100,
+        });
+
+        let mut adapted_response = response.clone();
+        adapt_payments_response(&mut adapted_response, ApiResponseVersion::V2025_04_01);
+        assert_eq!(
+            adapted_response,
+            serde_json::json!({"payment_id": "pay_123", "issuer_error_code": "01"})
+        );
+
+        let mut adapted_response = response.clone();
+        adapt_payments_response(&mut adapted_response, ApiResponseVersion::V2025_06_01);
+        assert_eq!(adapted_response, response);
+    }
+}
@@ -114,6 +114,7 @@ pub enum AuthenticationType {
     ApiKey {
         merchant_id: id_type::MerchantId,
         key_id: id_type::ApiKeyId,
+        response_version: Option<common_enums::ApiResponseVersion>,
     },
     AdminApiKey,
     AdminApiAuthWithMerchantId {
@@ -170,10 +171,7 @@ impl events::EventInfo for AuthenticationType {
 impl AuthenticationType {
     pub fn get_merchant_id(&self) -> Option<&id_type::MerchantId> {
         match self {
-            Self::ApiKey {
-                merchant_id,
-                key_id: _,
-            }
+            Self::ApiKey { merchant_id, .. }
             | Self::AdminApiAuthWithMerchantId { merchant_id }
             | Self::MerchantId { merchant_id }
             | Self::PublishableKey { merchant_id }
@@ -482,6 +480,8 @@ where
             (merchant, None)
         };
 
+        validate_merchant_account_status(&merchant, platform_merchant_account.as_ref())?;
+
         let key_store = if platform_merchant_account.is_some() {
             state
                 .store()
@@ -514,6 +514,7 @@ where
             AuthenticationType::ApiKey {
                 merchant_id: auth.merchant_account.get_id().clone(),
                 key_id: stored_api_key.key_id,
+                response_version: stored_api_key.response_version,
             },
         ))
     }
@@ -585,6 +586,21 @@ where
                 })
                 .change_context(errors::ApiErrorResponse::Unauthorized)?;
 
+        // Test API keys are bound to the profile in test mode they were created for
+        let profile_id = if stored_api_key.test_mode.unwrap_or(false) {
+            match (profile_id, stored_api_key.profile_id) {
+                (Some(header_profile_id), Some(key_profile_id))
+                    if header_profile_id != key_profile_id =>
+                {
+                    return Err(report!(errors::ApiErrorResponse::Unauthorized))
+                        .attach_printable("Test API key used for a different profile");
+                }
+                (header_profile_id, key_profile_id) => key_profile_id.or(header_profile_id),
+            }
+        } else {
+            profile_id
+        };
+
         let merchant = state
             .store()
             .find_merchant_account_by_merchant_id(
@@ -602,6 +618,8 @@ where
             (merchant, None)
         };
 
+        validate_merchant_account_status(&merchant, platform_merchant_account.as_ref())?;
+
         let key_store = if platform_merchant_account.is_some() {
             state
                 .store()
@@ -628,6 +646,7 @@ where
             AuthenticationType::ApiKey {
                 merchant_id: auth.merchant_account.get_id().clone(),
                 key_id: stored_api_key.key_id,
+                response_version: stored_api_key.response_version,
             },
         ))
     }
@@ -713,6 +732,7 @@ where
                         AuthenticationType::ApiKey {
                             merchant_id: auth.merchant_account.get_id().clone(),
                             key_id,
+                            response_version: None,
                         },
                     ))
                 }
@@ -836,6 +856,8 @@ where
         (merchant, None)
     };
 
+    validate_merchant_account_status(&merchant, platform_merchant_account.as_ref())?;
+
     let key_store = if platform_merchant_account.is_some() {
         state
             .store()
@@ -1773,6 +1795,7 @@ where
                     e.change_context(errors::ApiErrorResponse::InternalServerError)
                 }
             })?;
+        validate_merchant_account_status(&merchant_account, None)?;
 
         let profile = state
             .store()
@@ -1885,6 +1908,8 @@ where
             (merchant, None)
         };
 
+        validate_merchant_account_status(&merchant, platform_merchant_account.as_ref())?;
+
         let key_store = if platform_merchant_account.is_some() {
             state
                 .store()
@@ -1917,6 +1942,7 @@ where
             AuthenticationType::ApiKey {
                 merchant_id: auth.merchant_account.get_id().clone(),
                 key_id: stored_api_key.key_id,
+                response_version: stored_api_key.response_version,
             },
         ))
     }
@@ -2072,9 +2098,10 @@ where
             .find_merchant_account_by_publishable_key(key_manager_state, publishable_key)
             .await
             .to_not_found_response(errors::ApiErrorResponse::Unauthorized)
-            .map(|(merchant_account, key_store)| {
+            .and_then(|(merchant_account, key_store)| {
+                validate_merchant_account_status(&merchant_account, None)?;
                 let merchant_id = merchant_account.get_id().clone();
-                (
+                Ok((
                     AuthenticationData {
                         merchant_account,
                         platform_merchant_account: None,
@@ -2082,7 +2109,7 @@ where
                         profile_id: None,
                     },
                     AuthenticationType::PublishableKey { merchant_id },
-                )
+                ))
             })
     }
 }
@@ -2110,6 +2137,7 @@ where
             .find_merchant_account_by_publishable_key(key_manager_state, publishable_key)
             .await
             .to_not_found_response(errors::ApiErrorResponse::Unauthorized)?;
+        validate_merchant_account_status(&merchant_account, None)?;
         let merchant_id = merchant_account.get_id().clone();
         let profile = state
             .store()
@@ -3801,6 +3829,35 @@ fn get_and_validate_connected_merchant_id(
         .attach_printable("Non platform_merchant_account using X_CONNECTED_MERCHANT_ID header")
 }
 
+/// Rejects the requests authenticated with the API keys or the publishable key of the merchant
+/// accounts which are suspended. The restrictions on the operations of the merchant accounts in
+/// the other statuses are enforced by the flows themselves.
+fn validate_merchant_account_status(
+    merchant_account: &domain::MerchantAccount,
+    platform_merchant_account: Option<&domain::MerchantAccount>,
+) -> RouterResult<()> {
+    std::iter::once(merchant_account)
+        .chain(platform_merchant_account)
+        .try_for_each(|merchant_account| {
+            if merchant_account.status.can_authenticate() {
+                Ok(())
+            } else {
+                Err(report!(
+                    errors::ApiErrorResponse::MerchantAccountStatusNotAllowed {
+                        status: merchant_account.status.to_string(),
+                    }
+                ))
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Merchant account {} is {}",
+                        merchant_account.get_id().get_string_repr(),
+                        merchant_account.status
+                    )
+                })
+            }
+        })
+}
+
 fn throw_error_if_platform_merchant_authentication_required(
     request_headers: &HeaderMap,
 ) -> RouterResult<()> {
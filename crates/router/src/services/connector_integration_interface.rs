@@ -339,6 +339,16 @@ impl api::IncomingWebhook for ConnectorEnum {
         }
     }
 
+    fn get_webhook_event_timestamp(
+        &self,
+        request: &IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Option<time::PrimitiveDateTime>, errors::ConnectorError> {
+        match self {
+            Self::Old(connector) => connector.get_webhook_event_timestamp(request),
+            Self::New(connector) => connector.get_webhook_event_timestamp(request),
+        }
+    }
+
     #[cfg(all(feature = "revenue_recovery", feature = "v2"))]
     fn get_revenue_recovery_attempt_details(
         &self,
@@ -480,6 +490,22 @@ impl ConnectorSpecifications for ConnectorEnum {
             Self::New(connector) => connector.get_connector_about(),
         }
     }
+
+    /// Refund validity window of the connector
+    fn get_refund_validity_days(&self) -> Option<i64> {
+        match self {
+            Self::Old(connector) => connector.get_refund_validity_days(),
+            Self::New(connector) => connector.get_refund_validity_days(),
+        }
+    }
+
+    /// Release of the uncaptured remainder of partially captured authorizations by the connector
+    fn is_authorization_remainder_release_supported(&self) -> bool {
+        match self {
+            Self::Old(connector) => connector.is_authorization_remainder_release_supported(),
+            Self::New(connector) => connector.is_authorization_remainder_release_supported(),
+        }
+    }
 }
 
 impl api::ConnectorCommon for ConnectorEnum {
@@ -83,6 +83,7 @@ fn get_default_router_data<F, Req, Resp>(
         connector_mandate_request_reference_id: None,
         authentication_id: None,
         psd2_sca_exemption_type: None,
+        profile_id: None,
     }
 }
 
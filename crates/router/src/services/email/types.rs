@@ -55,6 +55,18 @@ pub enum EmailBody {
         prefix: String,
     },
     WelcomeToCommunity,
+    PaymentReceipt {
+        merchant_name: String,
+        amount: String,
+        payment_id: String,
+        receipt_url: String,
+    },
+    WebhookEndpointPaused {
+        profile_id: String,
+        webhook_url: String,
+        consecutive_failures: u64,
+        failing_since: String,
+    },
 }
 
 pub mod html {
@@ -146,6 +158,30 @@ Email         : {user_email}
             EmailBody::WelcomeToCommunity => {
                 include_str!("assets/welcome_to_community.html").to_string()
             }
+            EmailBody::PaymentReceipt {
+                merchant_name,
+                amount,
+                payment_id,
+                receipt_url,
+            } => format!(
+                include_str!("assets/payment_receipt.html"),
+                merchant_name = merchant_name,
+                amount = amount,
+                payment_id = payment_id,
+                receipt_url = receipt_url,
+            ),
+            EmailBody::WebhookEndpointPaused {
+                profile_id,
+                webhook_url,
+                consecutive_failures,
+                failing_since,
+            } => format!(
+                include_str!("assets/webhook_endpoint_paused.html"),
+                profile_id = profile_id,
+                webhook_url = webhook_url,
+                consecutive_failures = consecutive_failures,
+                failing_since = failing_since,
+            ),
         }
     }
 }
@@ -563,3 +599,57 @@ impl EmailData for WelcomeToCommunity {
         })
     }
 }
+
+pub struct PaymentReceipt {
+    pub recipient_email: pii::Email,
+    pub subject: String,
+    pub merchant_name: String,
+    pub amount: String,
+    pub payment_id: String,
+    pub receipt_url: String,
+}
+
+#[async_trait::async_trait]
+impl EmailData for PaymentReceipt {
+    async fn get_email_data(&self, _base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        let body = html::get_html_body(EmailBody::PaymentReceipt {
+            merchant_name: self.merchant_name.clone(),
+            amount: self.amount.clone(),
+            payment_id: self.payment_id.clone(),
+            receipt_url: self.receipt_url.clone(),
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.clone(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient: self.recipient_email.clone(),
+        })
+    }
+}
+
+pub struct WebhookEndpointPaused {
+    pub recipient_email: pii::Email,
+    pub subject: &'static str,
+    pub profile_id: String,
+    pub webhook_url: String,
+    pub consecutive_failures: u64,
+    pub failing_since: String,
+}
+
+#[async_trait::async_trait]
+impl EmailData for WebhookEndpointPaused {
+    async fn get_email_data(&self, _base_url: &str) -> CustomResult<EmailContents, EmailError> {
+        let body = html::get_html_body(EmailBody::WebhookEndpointPaused {
+            profile_id: self.profile_id.clone(),
+            webhook_url: self.webhook_url.clone(),
+            consecutive_failures: self.consecutive_failures,
+            failing_since: self.failing_since.clone(),
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient: self.recipient_email.clone(),
+        })
+    }
+}
@@ -58,7 +58,14 @@ pub struct KafkaPaymentAttemptEvent<'a> {
     pub profile_id: &'a id_type::ProfileId,
     pub organization_id: &'a id_type::OrganizationId,
     pub card_network: Option<String>,
+    pub card_issuer: Option<String>,
+    pub card_bin: Option<String>,
     pub card_discovery: Option<String>,
+    pub overcaptured_amount: Option<MinorUnit>,
+    pub avs_result: Option<storage_enums::AvsResult>,
+    pub cvv_result: Option<storage_enums::CvvResult>,
+    pub acquirer_reference_number: Option<&'a String>,
+    pub card_credential_type: Option<storage_enums::CardCredentialType>,
 }
 
 #[cfg(feature = "v1")]
@@ -117,9 +124,32 @@ impl<'a> KafkaPaymentAttemptEvent<'a> {
                 .and_then(|card| card.get("card_network"))
                 .and_then(|network| network.as_str())
                 .map(|network| network.to_string()),
+            card_issuer: attempt
+                .payment_method_data
+                .as_ref()
+                .and_then(|data| data.as_object())
+                .and_then(|pm| pm.get("card"))
+                .and_then(|data| data.as_object())
+                .and_then(|card| card.get("card_issuer"))
+                .and_then(|issuer| issuer.as_str())
+                .map(|issuer| issuer.to_string()),
+            card_bin: attempt
+                .payment_method_data
+                .as_ref()
+                .and_then(|data| data.as_object())
+                .and_then(|pm| pm.get("card"))
+                .and_then(|data| data.as_object())
+                .and_then(|card| card.get("card_isin"))
+                .and_then(|bin| bin.as_str())
+                .map(|bin| bin.to_string()),
             card_discovery: attempt
                 .card_discovery
                 .map(|discovery| discovery.to_string()),
+            overcaptured_amount: attempt.overcaptured_amount,
+            avs_result: attempt.avs_result,
+            cvv_result: attempt.cvv_result,
+            acquirer_reference_number: attempt.acquirer_reference_number.as_ref(),
+            card_credential_type: attempt.card_credential_type,
         }
     }
 }
@@ -92,6 +92,7 @@ impl ForeignTryFrom<domain::MerchantAccount> for MerchantAccountResponse {
             recon_status: item.recon_status,
             pm_collect_link_config,
             product_type: item.product_type,
+            status: item.status,
         })
     }
 }
@@ -118,6 +119,7 @@ impl ForeignTryFrom<domain::MerchantAccount> for MerchantAccountResponse {
             organization_id: item.organization_id,
             recon_status: item.recon_status,
             product_type: item.product_type,
+            status: item.status,
         })
     }
 }
@@ -191,6 +193,7 @@ impl ForeignTryFrom<domain::Profile> for ProfileResponse {
                 .map(ForeignInto::foreign_into),
             is_clear_pan_retries_enabled: item.is_clear_pan_retries_enabled,
             force_3ds_challenge: item.force_3ds_challenge,
+            test_mode: Some(item.is_test_mode),
         })
     }
 }
@@ -442,5 +445,6 @@ pub async fn create_profile_from_merchant_account(
             .attach_printable("error while generating card testing secret key")?,
         is_clear_pan_retries_enabled: request.is_clear_pan_retries_enabled.unwrap_or_default(),
         force_3ds_challenge: request.force_3ds_challenge.unwrap_or_default(),
+        is_test_mode: false,
     }))
 }
@@ -1,7 +1,7 @@
 use api_models::customers;
 pub use api_models::customers::{
-    CustomerDeleteResponse, CustomerListRequest, CustomerRequest, CustomerUpdateRequest,
-    CustomerUpdateRequestInternal,
+    CustomerDeleteResponse, CustomerListRequest, CustomerPaymentsListRequest, CustomerRequest,
+    CustomerUpdateRequest, CustomerUpdateRequestInternal, StoreCreditEntriesListRequest,
 };
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
 use hyperswitch_domain_models::customer;
@@ -26,8 +26,20 @@ impl common_utils::events::ApiEventMetric for CustomerResponse {
 }
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
-impl ForeignFrom<(domain::Customer, Option<payments::AddressDetails>)> for CustomerResponse {
-    fn foreign_from((cust, address): (domain::Customer, Option<payments::AddressDetails>)) -> Self {
+impl
+    ForeignFrom<(
+        domain::Customer,
+        Option<payments::AddressDetails>,
+        Option<payments::AddressValidationDetails>,
+    )> for CustomerResponse
+{
+    fn foreign_from(
+        (cust, address, address_validation): (
+            domain::Customer,
+            Option<payments::AddressDetails>,
+            Option<payments::AddressValidationDetails>,
+        ),
+    ) -> Self {
         customers::CustomerResponse {
             customer_id: cust.customer_id,
             name: cust.name,
@@ -39,6 +51,7 @@ impl ForeignFrom<(domain::Customer, Option<payments::AddressDetails>)> for Custo
             metadata: cust.metadata,
             address,
             default_payment_method_id: cust.default_payment_method_id,
+            address_validation,
         }
         .into()
     }
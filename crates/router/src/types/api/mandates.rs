@@ -106,6 +106,7 @@ impl MandateResponseExt for MandateResponse {
                     ip_address: mandate.customer_ip_address,
                     user_agent: mandate.customer_user_agent.unwrap_or_default(),
                 }),
+                consent_text_version: None,
             }),
             card,
             status: mandate.mandate_status,
@@ -19,7 +19,8 @@ impl PaymentLinkResponseExt for RetrievePaymentLinkResponse {
                 .created_at
                 .saturating_add(time::Duration::seconds(DEFAULT_SESSION_EXPIRY))
         });
-        let status = payment_link::check_payment_link_status(session_expiry);
+        let status =
+            payment_link::check_payment_link_status(session_expiry, common_utils::date_time::now());
         Ok(Self {
             link_to_pay: payment_link.link_to_pay,
             payment_link_id: payment_link.payment_link_id,
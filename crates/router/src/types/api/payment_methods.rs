@@ -1,3 +1,22 @@
+#[cfg(all(
+    any(feature = "v2", feature = "v1"),
+    not(feature = "payment_methods_v2")
+))]
+pub use api_models::payment_methods::{
+    BankDebitDetail, CardDetail, CardDetailFromLocker, CardDetailsPaymentMethod,
+    CardNetworkTokenizeRequest, CardNetworkTokenizeResponse, CustomerPaymentMethod,
+    CustomerPaymentMethodUpdateRequest, CustomerPaymentMethodsListResponse, DefaultPaymentMethod,
+    DeleteTokenizeByTokenRequest, ExternalTokenMigrationRequest, GetTokenizePayloadRequest,
+    GetTokenizePayloadResponse, ListCountriesCurrenciesRequest, MigrateCardDetail,
+    PaymentMethodCollectLinkRenderRequest, PaymentMethodCollectLinkRequest,
+    PaymentMethodConsentRecollectRequest, PaymentMethodConsentRevokeRequest, PaymentMethodCreate,
+    PaymentMethodCreateData, PaymentMethodDeleteResponse, PaymentMethodId,
+    PaymentMethodListRequest, PaymentMethodListResponse, PaymentMethodMigrate,
+    PaymentMethodMigrateResponse, PaymentMethodPreferenceUpdatedBy, PaymentMethodResponse,
+    PaymentMethodUpdate, PaymentMethodsData, TokenizeCardRequest, TokenizeDataRequest,
+    TokenizePayloadEncrypted, TokenizePayloadRequest, TokenizePaymentMethodRequest,
+    TokenizedCardValue1, TokenizedCardValue2, TokenizedWalletValue1, TokenizedWalletValue2,
+};
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
 pub use api_models::payment_methods::{
     CardDetail, CardDetailFromLocker, CardDetailsPaymentMethod, CardNetworkTokenizeRequest,
@@ -14,31 +33,15 @@ pub use api_models::payment_methods::{
     TokenizedCardValue2, TokenizedWalletValue1, TokenizedWalletValue2,
     TotalPaymentMethodCountResponse,
 };
-#[cfg(all(
-    any(feature = "v2", feature = "v1"),
-    not(feature = "payment_methods_v2")
-))]
-pub use api_models::payment_methods::{
-    CardDetail, CardDetailFromLocker, CardDetailsPaymentMethod, CardNetworkTokenizeRequest,
-    CardNetworkTokenizeResponse, CustomerPaymentMethod, CustomerPaymentMethodsListResponse,
-    DefaultPaymentMethod, DeleteTokenizeByTokenRequest, GetTokenizePayloadRequest,
-    GetTokenizePayloadResponse, ListCountriesCurrenciesRequest, MigrateCardDetail,
-    PaymentMethodCollectLinkRenderRequest, PaymentMethodCollectLinkRequest, PaymentMethodCreate,
-    PaymentMethodCreateData, PaymentMethodDeleteResponse, PaymentMethodId,
-    PaymentMethodListRequest, PaymentMethodListResponse, PaymentMethodMigrate,
-    PaymentMethodMigrateResponse, PaymentMethodResponse, PaymentMethodUpdate, PaymentMethodsData,
-    TokenizeCardRequest, TokenizeDataRequest, TokenizePayloadEncrypted, TokenizePayloadRequest,
-    TokenizePaymentMethodRequest, TokenizedCardValue1, TokenizedCardValue2, TokenizedWalletValue1,
-    TokenizedWalletValue2,
-};
 use error_stack::report;
 
-use crate::core::{
-    errors::{self, RouterResult},
-    payments::helpers::validate_payment_method_type_against_payment_method,
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        payments::helpers::validate_payment_method_type_against_payment_method,
+    },
+    utils,
 };
-#[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
-use crate::utils;
 
 pub(crate) trait PaymentMethodCreateExt {
     fn validate(&self) -> RouterResult<()>;
@@ -61,6 +64,28 @@ impl PaymentMethodCreateExt for PaymentMethodCreate {
                 }
             }
         }
+
+        if let Some(bank_debit) = &self.bank_debit {
+            utils::when(
+                self.payment_method != Some(common_enums::PaymentMethod::BankDebit)
+                    || self
+                        .payment_method_type
+                        .is_some_and(|pmt| pmt != bank_debit.get_payment_method_type()),
+                || {
+                    Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                        message: "'bank_debit' details do not match the 'payment_method' and \
+                                  'payment_method_type' provided"
+                            .to_string()
+                    }))
+                },
+            )?;
+
+            bank_debit.validate().map_err(|error| {
+                error.change_context(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "Invalid bank account details provided".to_string(),
+                })
+            })?;
+        }
         Ok(())
     }
 }
@@ -63,6 +63,8 @@ impl VerifyConnectorData {
             shipping_cost: None,
             merchant_account_id: None,
             merchant_config_currency: None,
+            payment_facilitator_details: None,
+            connector_idempotency_key: None,
         }
     }
 
@@ -125,6 +127,7 @@ impl VerifyConnectorData {
             connector_mandate_request_reference_id: None,
             authentication_id: None,
             psd2_sca_exemption_type: None,
+            profile_id: None,
         }
     }
 }
@@ -1,6 +1,7 @@
 pub use api_models::webhook_events::{
     EventListConstraints, EventListConstraintsInternal, EventListItemResponse,
-    EventListRequestInternal, EventRetrieveResponse, OutgoingWebhookRequestContent,
+    EventListRequestInternal, EventRetrieveResponse, LifecycleEventListConstraints,
+    LifecycleEventListResponse, LifecycleEventResponse, OutgoingWebhookRequestContent,
     OutgoingWebhookResponseContent, TotalEventsResponse, WebhookDeliveryAttemptListRequestInternal,
     WebhookDeliveryRetryRequestInternal,
 };
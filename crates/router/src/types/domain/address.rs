@@ -47,6 +47,10 @@ pub struct Address {
     pub updated_by: String,
     #[encrypt]
     pub email: Option<Encryptable<Secret<String, pii::EmailStrategy>>>,
+    /// The address as it was given along with its normalized form and the result of its
+    /// validation, serialized as JSON
+    #[encrypt]
+    pub normalized_address: Option<Encryptable<Secret<String>>>,
 }
 
 /// Based on the flow, appropriate address has to be used
@@ -185,6 +189,7 @@ impl behaviour::Conversion for Address {
             merchant_id: self.merchant_id,
             updated_by: self.updated_by,
             email: self.email.map(Encryption::from),
+            normalized_address: self.normalized_address.map(Encryption::from),
             payment_id: None,
             customer_id: None,
         })
@@ -211,6 +216,7 @@ impl behaviour::Conversion for Address {
                     last_name: other.last_name,
                     phone_number: other.phone_number,
                     email: other.email,
+                    normalized_address: other.normalized_address,
                 },
             )),
             identifier.clone(),
@@ -250,6 +256,7 @@ impl behaviour::Conversion for Address {
                 );
                 encryptable
             }),
+            normalized_address: encryptable_address.normalized_address,
         })
     }
 
@@ -273,6 +280,7 @@ impl behaviour::Conversion for Address {
             modified_at: now,
             updated_by: self.updated_by,
             email: self.email.map(Encryption::from),
+            normalized_address: self.normalized_address.map(Encryption::from),
             customer_id: None,
             payment_id: None,
         })
@@ -295,6 +303,8 @@ pub enum AddressUpdate {
         country_code: Option<String>,
         updated_by: String,
         email: crypto::OptionalEncryptableEmail,
+        /// The record of the validation of the updated address, if it was validated
+        normalized_address: crypto::OptionalEncryptableSecretString,
     },
 }
 
@@ -315,6 +325,7 @@ impl From<AddressUpdate> for AddressUpdateInternal {
                 country_code,
                 updated_by,
                 email,
+                normalized_address,
             } => Self {
                 city,
                 country,
@@ -330,6 +341,7 @@ impl From<AddressUpdate> for AddressUpdateInternal {
                 modified_at: date_time::convert_to_pdt(OffsetDateTime::now_utc()),
                 updated_by,
                 email: email.map(Encryption::from),
+                normalized_address: normalized_address.map(Encryption::from),
             },
         }
     }
@@ -21,8 +21,10 @@ pub mod file;
 pub mod fraud_check;
 pub mod generic_link;
 pub mod gsm;
+pub mod issuer_authentication_stats;
 #[cfg(feature = "kv_store")]
 pub mod kv;
+pub mod ledger_entry;
 pub mod locker_mock_up;
 pub mod mandate;
 pub mod merchant_account;
@@ -31,14 +33,18 @@ pub mod merchant_key_store;
 #[cfg(feature = "v2")]
 pub mod passive_churn_recovery;
 pub mod payment_attempt;
+pub mod payment_custom_field;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_method_consent_event;
+pub mod payment_note;
 pub mod payout_attempt;
 pub mod payouts;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod store_credit_entry;
 pub mod unified_translations;
 pub mod user;
 pub mod user_authentication_method;
@@ -69,10 +75,12 @@ pub use self::{
     blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, callback_mapper::*,
     capture::*, cards_info::*, configs::*, customers::*, dashboard_metadata::*, dispute::*,
     dynamic_routing_stats::*, ephemeral_key::*, events::*, file::*, fraud_check::*,
-    generic_link::*, gsm::*, locker_mock_up::*, mandate::*, merchant_account::*,
-    merchant_connector_account::*, merchant_key_store::*, payment_link::*, payment_method::*,
-    process_tracker::*, refund::*, reverse_lookup::*, role::*, routing_algorithm::*,
-    unified_translations::*, user::*, user_authentication_method::*, user_role::*,
+    generic_link::*, gsm::*, issuer_authentication_stats::*, ledger_entry::*, locker_mock_up::*,
+    mandate::*, merchant_account::*, merchant_connector_account::*, merchant_key_store::*,
+    payment_custom_field::*, payment_link::*, payment_method::*, payment_method_consent_event::*,
+    payment_note::*, process_tracker::*, refund::*, reverse_lookup::*, role::*,
+    routing_algorithm::*, store_credit_entry::*, unified_translations::*, user::*,
+    user_authentication_method::*, user_role::*,
 };
 use crate::types::api::routing;
 
@@ -1 +1,15 @@
 pub use diesel_models::authentication::{Authentication, AuthenticationNew, AuthenticationUpdate};
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct AuthenticationAbandonmentTrackingData {
+    pub authentication_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub payment_id: common_utils::id_type::PaymentId,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct ScaComplianceReportTrackingData {
+    pub report_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
@@ -8,6 +8,18 @@ use hyperswitch_domain_models::disputes;
 
 use crate::{connection::PgPooledConn, logger};
 
+/// Details of the payment of a disputed attempt, embedded in the disputes of the disputes list
+#[derive(Clone, Debug)]
+pub struct DisputePaymentContext {
+    pub attempt_id: String,
+    pub amount: common_utils::types::MinorUnit,
+    pub currency: Option<common_enums::Currency>,
+    pub payment_method: Option<common_enums::PaymentMethod>,
+    pub payment_method_type: Option<common_enums::PaymentMethodType>,
+    pub connector: Option<String>,
+    pub customer_details: Option<common_utils::encryption::Encryption>,
+}
+
 #[async_trait::async_trait]
 pub trait DisputeDbExt: Sized {
     async fn filter_by_constraints(
@@ -22,6 +34,13 @@ pub trait DisputeDbExt: Sized {
         profile_id_list: Option<Vec<common_utils::id_type::ProfileId>>,
         time_range: &common_utils::types::TimeRange,
     ) -> CustomResult<Vec<(common_enums::enums::DisputeStatus, i64)>, errors::DatabaseError>;
+
+    #[cfg(feature = "v1")]
+    async fn get_payment_context_by_attempt_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        attempt_ids: Vec<String>,
+    ) -> CustomResult<Vec<DisputePaymentContext>, errors::DatabaseError>;
 }
 
 #[async_trait::async_trait]
@@ -142,4 +161,74 @@ impl DisputeDbExt for Dispute {
         .change_context(errors::DatabaseError::NotFound)
         .attach_printable_lazy(|| "Error filtering records by predicate")
     }
+
+    /// Fetches the details of the payments of the disputed attempts in a single query, by joining
+    /// the attempts with their payment intents
+    #[cfg(feature = "v1")]
+    async fn get_payment_context_by_attempt_ids(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        attempt_ids: Vec<String>,
+    ) -> CustomResult<Vec<DisputePaymentContext>, errors::DatabaseError> {
+        use diesel_models::schema::{payment_attempt, payment_intent};
+
+        let query = payment_attempt::table
+            .inner_join(
+                payment_intent::table.on(payment_intent::dsl::payment_id
+                    .eq(payment_attempt::dsl::payment_id)
+                    .and(payment_intent::dsl::merchant_id.eq(payment_attempt::dsl::merchant_id))),
+            )
+            .filter(payment_attempt::dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(payment_attempt::dsl::attempt_id.eq_any(attempt_ids))
+            .select((
+                payment_attempt::dsl::attempt_id,
+                payment_intent::dsl::amount,
+                payment_intent::dsl::currency,
+                payment_attempt::dsl::payment_method,
+                payment_attempt::dsl::payment_method_type,
+                payment_attempt::dsl::connector,
+                payment_intent::dsl::customer_details,
+            ));
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<payment_attempt::table, _, _>(
+            query.get_results_async::<(
+                String,
+                common_utils::types::MinorUnit,
+                Option<common_enums::Currency>,
+                Option<common_enums::PaymentMethod>,
+                Option<common_enums::PaymentMethodType>,
+                Option<String>,
+                Option<common_utils::encryption::Encryption>,
+            )>(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .map(|rows| {
+            rows.into_iter()
+                .map(
+                    |(
+                        attempt_id,
+                        amount,
+                        currency,
+                        payment_method,
+                        payment_method_type,
+                        connector,
+                        customer_details,
+                    )| DisputePaymentContext {
+                        attempt_id,
+                        amount,
+                        currency,
+                        payment_method,
+                        payment_method_type,
+                        connector,
+                        customer_details,
+                    },
+                )
+                .collect()
+        })
+        .change_context(errors::DatabaseError::NotFound)
+        .attach_printable_lazy(|| "Error fetching the payments of the disputed attempts")
+    }
 }
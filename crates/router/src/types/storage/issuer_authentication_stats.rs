@@ -0,0 +1,3 @@
+pub use diesel_models::issuer_authentication_stats::{
+    IssuerAuthenticationStats, IssuerAuthenticationStatsNew,
+};
@@ -69,3 +69,10 @@ impl MandateDbExt for Mandate {
             .attach_printable("Error filtering mandates by specified constraints")
     }
 }
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct DunningTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub payment_id: common_utils::id_type::PaymentId,
+}
@@ -3,3 +3,18 @@ pub use diesel_models::merchant_account::{
 };
 
 pub use crate::types::domain::MerchantAccountUpdate;
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct MerchantOffboardingTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct WarehouseSyncTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct DataResidencyMigrationTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
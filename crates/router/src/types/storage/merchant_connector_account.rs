@@ -3,3 +3,15 @@ pub use diesel_models::merchant_connector_account::{
 };
 
 pub use crate::types::domain::MerchantConnectorAccountUpdate;
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct ConnectorOAuthTokenRefreshTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+}
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct ConnectorCredentialsExpiryTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+}
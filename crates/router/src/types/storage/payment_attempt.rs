@@ -118,6 +118,26 @@ impl AttemptStatusExt for enums::AttemptStatus {
     }
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct ScheduledCaptureTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_id: common_utils::id_type::PaymentId,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, Default, serde::Deserialize, serde::Serialize, Clone)]
+pub struct AttemptRedactionTrackingData {
+    /// The last attempt redacted by the previous redaction passes
+    pub cursor: Option<diesel_models::query::data_retention::AttemptRedactionCursor>,
+}
+
+#[cfg(feature = "v1")]
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct ConnectorTransactionIdBackfillTrackingData {
+    pub backfill_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
+
 #[cfg(test)]
 #[cfg(all(
     feature = "v1", // Ignoring tests for v2 since they aren't actively running
@@ -222,6 +242,7 @@ mod tests {
             extended_authorization_applied: Default::default(),
             capture_before: Default::default(),
             card_discovery: Default::default(),
+            connector_idempotency_key: Default::default(),
         };
 
         let store = state
@@ -309,6 +330,7 @@ mod tests {
             extended_authorization_applied: Default::default(),
             capture_before: Default::default(),
             card_discovery: Default::default(),
+            connector_idempotency_key: Default::default(),
         };
         let store = state
             .stores
@@ -409,6 +431,7 @@ mod tests {
             extended_authorization_applied: Default::default(),
             capture_before: Default::default(),
             card_discovery: Default::default(),
+            connector_idempotency_key: Default::default(),
         };
         let store = state
             .stores
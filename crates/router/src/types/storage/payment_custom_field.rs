@@ -0,0 +1 @@
+pub use diesel_models::payment_custom_field::{PaymentCustomField, PaymentCustomFieldNew};
@@ -51,6 +51,12 @@ pub struct WalletTokenData {
     pub payment_method_id: String,
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BankDebitTokenData {
+    pub payment_method_id: String,
+    pub locker_id: String,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 pub enum PaymentTokenData {
@@ -62,6 +68,7 @@ pub enum PaymentTokenData {
     PermanentCard(CardTokenData),
     AuthBankDebit(payment_methods::BankAccountTokenData),
     WalletToken(WalletTokenData),
+    PermanentBankDebit(BankDebitTokenData),
 }
 
 impl PaymentTokenData {
@@ -104,6 +111,13 @@ impl PaymentTokenData {
         Self::WalletToken(WalletTokenData { payment_method_id })
     }
 
+    pub fn permanent_bank_debit(payment_method_id: String, locker_id: String) -> Self {
+        Self::PermanentBankDebit(BankDebitTokenData {
+            payment_method_id,
+            locker_id,
+        })
+    }
+
     pub fn is_permanent_card(&self) -> bool {
         matches!(self, Self::PermanentCard(_) | Self::Permanent(_))
     }
@@ -160,3 +174,13 @@ pub struct PaymentMethodStatusTrackingData {
     pub curr_status: enums::PaymentMethodStatus,
     pub merchant_id: common_utils::id_type::MerchantId,
 }
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct DeferredVaultingTrackingData {
+    pub payment_method_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    /// Lookup key of the card details held in the temporary locker until they are vaulted
+    pub lookup_key: String,
+}
@@ -0,0 +1,3 @@
+pub use diesel_models::payment_method_consent_event::{
+    PaymentMethodConsentEvent, PaymentMethodConsentEventNew,
+};
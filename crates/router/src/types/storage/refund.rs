@@ -344,3 +344,17 @@ impl RefundDbExt for Refund {
         .attach_printable_lazy(|| "Error filtering status count of refunds")
     }
 }
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct RefundBatchTrackingData {
+    pub batch_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: Option<common_utils::id_type::ProfileId>,
+}
+
+#[derive(Debug, Default, serde::Deserialize, serde::Serialize, Clone)]
+pub struct RefundReconciliationTrackingData {
+    /// Time at which the previous reconciliation pass was completed
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub last_reconciled_at: Option<time::PrimitiveDateTime>,
+}
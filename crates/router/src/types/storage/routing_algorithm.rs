@@ -1,3 +1,10 @@
 pub use diesel_models::routing_algorithm::{
     RoutingAlgorithm, RoutingAlgorithmMetadata, RoutingProfileMetadata,
 };
+
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct RoutingExperimentTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub experiment_id: String,
+}
@@ -0,0 +1 @@
+pub use diesel_models::store_credit_entry::{StoreCreditEntry, StoreCreditEntryNew};
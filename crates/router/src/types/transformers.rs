@@ -2,7 +2,7 @@
 use actix_web::http::header::HeaderMap;
 use api_models::{
     cards_info as card_info_types, enums as api_enums, gsm as gsm_api_types, payment_methods,
-    payments, routing::ConnectorSelection,
+    payments, process_tracker as process_tracker_api_types, routing::ConnectorSelection,
 };
 use common_utils::{
     consts::X_HS_LATENCY,
@@ -19,7 +19,7 @@ use masking::{ExposeInterface, PeekInterface, Secret};
 
 use super::domain;
 use crate::{
-    core::errors,
+    core::{address_validation, errors},
     headers::{
         ACCEPT_LANGUAGE, BROWSER_NAME, X_APP_ID, X_CLIENT_PLATFORM, X_CLIENT_SOURCE,
         X_CLIENT_VERSION, X_MERCHANT_DOMAIN, X_PAYMENT_CONFIRM_SOURCE, X_REDIRECT_URI,
@@ -390,6 +390,7 @@ impl ForeignFrom<payments::MandateData> for hyperswitch_domain_models::mandates:
                             ip_address: d.ip_address,
                             user_agent: d.user_agent,
                         }),
+                    consent_text_version: d.consent_text_version,
                 }
             }),
             mandate_type: d.mandate_type.map(|d| match d {
@@ -632,7 +633,24 @@ impl ForeignFrom<storage_enums::PayoutStatus> for Option<storage_enums::EventTyp
             | storage_enums::PayoutStatus::RequiresFulfillment
             | storage_enums::PayoutStatus::RequiresPayoutMethodData
             | storage_enums::PayoutStatus::RequiresVendorAccountCreation
-            | storage_enums::PayoutStatus::RequiresConfirmation => None,
+            | storage_enums::PayoutStatus::RequiresConfirmation
+            | storage_enums::PayoutStatus::OnHold => None,
+        }
+    }
+}
+
+impl ForeignFrom<api_models::admin::ConnectorCredentialsStatus>
+    for Option<storage_enums::EventType>
+{
+    fn foreign_from(value: api_models::admin::ConnectorCredentialsStatus) -> Self {
+        match value {
+            api_models::admin::ConnectorCredentialsStatus::ExpiringSoon => {
+                Some(storage_enums::EventType::ConnectorCredentialsExpiring)
+            }
+            api_models::admin::ConnectorCredentialsStatus::Expired => {
+                Some(storage_enums::EventType::ConnectorCredentialsExpired)
+            }
+            api_models::admin::ConnectorCredentialsStatus::Active => None,
         }
     }
 }
@@ -762,6 +780,12 @@ impl From<&domain::Address> for hyperswitch_domain_models::address::Address {
             && address.last_name.is_none()
         {
             None
+        } else if let Some(normalized_address_details) =
+            address_validation::get_address_validation_record(address)
+                .and_then(|record| record.get_normalized_address_details())
+        {
+            // The normalized form of the address is used with the connectors once validated
+            Some(normalized_address_details.into())
         } else {
             Some(hyperswitch_domain_models::address::AddressDetails {
                 city: address.city.clone(),
@@ -863,6 +887,9 @@ impl
             api_key: StrongSecret::from(plaintext_api_key.peek().to_owned()),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            response_version: api_key.response_version,
+            test_mode: api_key.test_mode.unwrap_or(false),
+            profile_id: api_key.profile_id,
         }
     }
 }
@@ -877,6 +904,9 @@ impl ForeignFrom<diesel_models::api_keys::ApiKey> for api_models::api_keys::Retr
             prefix: api_key.prefix.into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            response_version: api_key.response_version,
+            test_mode: api_key.test_mode.unwrap_or(false),
+            profile_id: api_key.profile_id,
         }
     }
 }
@@ -890,6 +920,7 @@ impl ForeignFrom<api_models::api_keys::UpdateApiKeyRequest>
             description: api_key.description,
             expires_at: api_key.expiration.map(Into::into),
             last_used: None,
+            response_version: api_key.response_version,
         }
     }
 }
@@ -948,6 +979,7 @@ impl ForeignFrom<storage::Dispute> for api_models::disputes::DisputeResponse {
             created_at: dispute.created_at,
             profile_id: dispute.profile_id,
             merchant_connector_id: dispute.merchant_connector_id,
+            payment_context: None,
         }
     }
 }
@@ -1970,6 +2002,33 @@ impl TryFrom<domain::Event> for api_models::webhook_events::EventListItemRespons
     }
 }
 
+#[cfg(feature = "olap")]
+impl TryFrom<domain::Event> for api_models::webhook_events::LifecycleEventResponse {
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn try_from(item: domain::Event) -> Result<Self, Self::Error> {
+        use crate::utils::OptionExt;
+
+        let merchant_id = item
+            .merchant_id
+            .get_required_value("merchant_id")
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+        Ok(Self {
+            event_id: item.event_id,
+            merchant_id,
+            profile_id: item.business_profile_id,
+            object_id: item.primary_object_id,
+            event_type: item.event_type,
+            event_class: item.event_class,
+            initial_attempt_id: item.initial_attempt_id,
+            is_webhook_delivered: item.is_webhook_notified,
+            object_created: item.primary_object_created_at,
+            created: item.created_at,
+        })
+    }
+}
+
 #[cfg(feature = "olap")]
 impl TryFrom<domain::Event> for api_models::webhook_events::EventRetrieveResponse {
     type Error = error_stack::Report<errors::ApiErrorResponse>;
@@ -2323,3 +2382,137 @@ impl ForeignFrom<card_info_types::CardInfoUpdateRequest> for storage::CardInfo {
         }
     }
 }
+
+impl ForeignFrom<process_tracker_api_types::SchedulerTaskType> for storage::ProcessTrackerRunner {
+    fn foreign_from(task_type: process_tracker_api_types::SchedulerTaskType) -> Self {
+        match task_type {
+            process_tracker_api_types::SchedulerTaskType::PaymentSync => Self::PaymentsSyncWorkflow,
+            process_tracker_api_types::SchedulerTaskType::RefundSync => Self::RefundWorkflowRouter,
+            process_tracker_api_types::SchedulerTaskType::PayoutExecution => {
+                Self::AttachPayoutAccountWorkflow
+            }
+            process_tracker_api_types::SchedulerTaskType::WebhookRetry => {
+                Self::OutgoingWebhookRetryWorkflow
+            }
+            process_tracker_api_types::SchedulerTaskType::ApiKeyExpiry => {
+                Self::ApiKeyExpiryWorkflow
+            }
+            process_tracker_api_types::SchedulerTaskType::DeleteTokenizedData => {
+                Self::DeleteTokenizeDataWorkflow
+            }
+            process_tracker_api_types::SchedulerTaskType::PaymentMethodStatusUpdate => {
+                Self::PaymentMethodStatusUpdateWorkflow
+            }
+            process_tracker_api_types::SchedulerTaskType::PassiveRecovery => {
+                Self::PassiveRecoveryWorkflow
+            }
+            process_tracker_api_types::SchedulerTaskType::DeferredVaulting => {
+                Self::DeferredVaultingWorkflow
+            }
+            process_tracker_api_types::SchedulerTaskType::AuthenticationAbandonment => {
+                Self::AuthenticationAbandonmentWorkflow
+            }
+            process_tracker_api_types::SchedulerTaskType::RefundBatch => Self::RefundBatchWorkflow,
+            process_tracker_api_types::SchedulerTaskType::Dunning => Self::DunningWorkflow,
+            process_tracker_api_types::SchedulerTaskType::RoutingExperiment => {
+                Self::RoutingExperimentWorkflow
+            }
+            process_tracker_api_types::SchedulerTaskType::MerchantOffboarding => {
+                Self::MerchantOffboardingWorkflow
+            }
+            process_tracker_api_types::SchedulerTaskType::WarehouseSync => {
+                Self::WarehouseSyncWorkflow
+            }
+            process_tracker_api_types::SchedulerTaskType::ConnectorOAuthTokenRefresh => {
+                Self::ConnectorOAuthTokenRefreshWorkflow
+            }
+            process_tracker_api_types::SchedulerTaskType::ConnectorCredentialsExpiry => {
+                Self::ConnectorCredentialsExpiryWorkflow
+            }
+            process_tracker_api_types::SchedulerTaskType::ScheduledCapture => {
+                Self::ScheduledCaptureWorkflow
+            }
+            process_tracker_api_types::SchedulerTaskType::AttemptRedaction => {
+                Self::AttemptRedactionWorkflow
+            }
+            process_tracker_api_types::SchedulerTaskType::RefundReconciliation => {
+                Self::RefundReconciliationWorkflow
+            }
+            process_tracker_api_types::SchedulerTaskType::ScaComplianceReport => {
+                Self::ScaComplianceReportWorkflow
+            }
+            process_tracker_api_types::SchedulerTaskType::ConnectorTransactionIdBackfill => {
+                Self::ConnectorTransactionIdBackfillWorkflow
+            }
+            process_tracker_api_types::SchedulerTaskType::DataResidencyMigration => {
+                Self::DataResidencyMigrationWorkflow
+            }
+        }
+    }
+}
+
+impl ForeignFrom<storage::ProcessTrackerRunner> for process_tracker_api_types::SchedulerTaskType {
+    fn foreign_from(runner: storage::ProcessTrackerRunner) -> Self {
+        match runner {
+            storage::ProcessTrackerRunner::PaymentsSyncWorkflow => Self::PaymentSync,
+            storage::ProcessTrackerRunner::RefundWorkflowRouter => Self::RefundSync,
+            storage::ProcessTrackerRunner::AttachPayoutAccountWorkflow => Self::PayoutExecution,
+            storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow => Self::WebhookRetry,
+            storage::ProcessTrackerRunner::ApiKeyExpiryWorkflow => Self::ApiKeyExpiry,
+            storage::ProcessTrackerRunner::DeleteTokenizeDataWorkflow => Self::DeleteTokenizedData,
+            storage::ProcessTrackerRunner::PaymentMethodStatusUpdateWorkflow => {
+                Self::PaymentMethodStatusUpdate
+            }
+            storage::ProcessTrackerRunner::PassiveRecoveryWorkflow => Self::PassiveRecovery,
+            storage::ProcessTrackerRunner::DeferredVaultingWorkflow => Self::DeferredVaulting,
+            storage::ProcessTrackerRunner::AuthenticationAbandonmentWorkflow => {
+                Self::AuthenticationAbandonment
+            }
+            storage::ProcessTrackerRunner::RefundBatchWorkflow => Self::RefundBatch,
+            storage::ProcessTrackerRunner::DunningWorkflow => Self::Dunning,
+            storage::ProcessTrackerRunner::RoutingExperimentWorkflow => Self::RoutingExperiment,
+            storage::ProcessTrackerRunner::MerchantOffboardingWorkflow => Self::MerchantOffboarding,
+            storage::ProcessTrackerRunner::WarehouseSyncWorkflow => Self::WarehouseSync,
+            storage::ProcessTrackerRunner::ConnectorOAuthTokenRefreshWorkflow => {
+                Self::ConnectorOAuthTokenRefresh
+            }
+            storage::ProcessTrackerRunner::ConnectorCredentialsExpiryWorkflow => {
+                Self::ConnectorCredentialsExpiry
+            }
+            storage::ProcessTrackerRunner::ScheduledCaptureWorkflow => Self::ScheduledCapture,
+            storage::ProcessTrackerRunner::AttemptRedactionWorkflow => Self::AttemptRedaction,
+            storage::ProcessTrackerRunner::RefundReconciliationWorkflow => {
+                Self::RefundReconciliation
+            }
+            storage::ProcessTrackerRunner::ScaComplianceReportWorkflow => Self::ScaComplianceReport,
+            storage::ProcessTrackerRunner::ConnectorTransactionIdBackfillWorkflow => {
+                Self::ConnectorTransactionIdBackfill
+            }
+            storage::ProcessTrackerRunner::DataResidencyMigrationWorkflow => {
+                Self::DataResidencyMigration
+            }
+        }
+    }
+}
+
+impl ForeignFrom<storage::ProcessTracker> for process_tracker_api_types::SchedulerTask {
+    fn foreign_from(task: storage::ProcessTracker) -> Self {
+        let task_type = task
+            .runner
+            .as_deref()
+            .and_then(|runner| runner.parse::<storage::ProcessTrackerRunner>().ok())
+            .map(ForeignFrom::foreign_from);
+
+        Self {
+            id: task.id,
+            name: task.name,
+            task_type,
+            status: task.status.to_string(),
+            business_status: task.business_status,
+            retry_count: task.retry_count,
+            schedule_time: task.schedule_time,
+            created_at: task.created_at,
+            updated_at: task.updated_at,
+        }
+    }
+}
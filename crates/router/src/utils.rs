@@ -44,8 +44,10 @@ use tracing_futures::Instrument;
 use uuid::Uuid;
 
 pub use self::ext_traits::{OptionExt, ValidateCall};
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+use crate::core::address_validation::AddressValidationRecord;
 #[cfg(feature = "v1")]
-use crate::core::webhooks as webhooks_core;
+use crate::core::{refunds, webhooks as webhooks_core};
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 use crate::types::storage;
 use crate::{
@@ -757,6 +759,7 @@ pub trait CustomerAddress {
         customer_id: &id_type::CustomerId,
         key: &[u8],
         storage_scheme: storage::enums::MerchantStorageScheme,
+        address_validation: Option<&AddressValidationRecord>,
     ) -> CustomResult<domain::CustomerAddress, common_utils::errors::CryptoError>;
 }
 
@@ -788,6 +791,7 @@ impl CustomerAddress for api_models::customers::CustomerRequest {
                         .email
                         .as_ref()
                         .map(|a| a.clone().expose().switch_strategy()),
+                    normalized_address: None,
                 },
             )),
             Identifier::Merchant(merchant_id.to_owned()),
@@ -821,6 +825,7 @@ impl CustomerAddress for api_models::customers::CustomerRequest {
                     );
                 encryptable
             }),
+            normalized_address: encryptable_address.normalized_address,
         })
     }
 
@@ -832,7 +837,12 @@ impl CustomerAddress for api_models::customers::CustomerRequest {
         customer_id: &id_type::CustomerId,
         key: &[u8],
         storage_scheme: storage::enums::MerchantStorageScheme,
+        address_validation: Option<&AddressValidationRecord>,
     ) -> CustomResult<domain::CustomerAddress, common_utils::errors::CryptoError> {
+        let normalized_address = address_validation
+            .map(AddressValidationRecord::to_encryptable_value)
+            .transpose()
+            .change_context(common_utils::errors::CryptoError::EncodingFailed)?;
         let encrypted_data = crypto_operation(
             &state.into(),
             type_name!(storage::Address),
@@ -850,6 +860,7 @@ impl CustomerAddress for api_models::customers::CustomerRequest {
                         .email
                         .as_ref()
                         .map(|a| a.clone().expose().switch_strategy()),
+                    normalized_address,
                 },
             )),
             Identifier::Merchant(merchant_id.to_owned()),
@@ -887,6 +898,7 @@ impl CustomerAddress for api_models::customers::CustomerRequest {
                     );
                 encryptable
             }),
+            normalized_address: encryptable_address.normalized_address,
         };
 
         Ok(domain::CustomerAddress {
@@ -924,6 +936,7 @@ impl CustomerAddress for api_models::customers::CustomerUpdateRequest {
                         .email
                         .as_ref()
                         .map(|a| a.clone().expose().switch_strategy()),
+                    normalized_address: None,
                 },
             )),
             Identifier::Merchant(merchant_id.to_owned()),
@@ -956,6 +969,7 @@ impl CustomerAddress for api_models::customers::CustomerUpdateRequest {
                     );
                 encryptable
             }),
+            normalized_address: encryptable_address.normalized_address,
         })
     }
 
@@ -967,7 +981,12 @@ impl CustomerAddress for api_models::customers::CustomerUpdateRequest {
         customer_id: &id_type::CustomerId,
         key: &[u8],
         storage_scheme: storage::enums::MerchantStorageScheme,
+        address_validation: Option<&AddressValidationRecord>,
     ) -> CustomResult<domain::CustomerAddress, common_utils::errors::CryptoError> {
+        let normalized_address = address_validation
+            .map(AddressValidationRecord::to_encryptable_value)
+            .transpose()
+            .change_context(common_utils::errors::CryptoError::EncodingFailed)?;
         let encrypted_data = crypto_operation(
             &state.into(),
             type_name!(storage::Address),
@@ -985,6 +1004,7 @@ impl CustomerAddress for api_models::customers::CustomerUpdateRequest {
                         .email
                         .as_ref()
                         .map(|a| a.clone().expose().switch_strategy()),
+                    normalized_address,
                 },
             )),
             Identifier::Merchant(merchant_id.to_owned()),
@@ -1021,6 +1041,7 @@ impl CustomerAddress for api_models::customers::CustomerUpdateRequest {
                     );
                 encryptable
             }),
+            normalized_address: encryptable_address.normalized_address,
         };
 
         Ok(domain::CustomerAddress {
@@ -1205,7 +1226,13 @@ where
             None,
         )?;
 
-        let event_type = ForeignFrom::foreign_from(status);
+        let event_type = if payments_core::is_operation_cancel_remaining(&operation)
+            && status == enums::IntentStatus::PartiallyCaptured
+        {
+            Some(enums::EventType::PaymentAuthorizationRemainderReleased)
+        } else {
+            ForeignFrom::foreign_from(status)
+        };
 
         if let services::ApplicationResponse::JsonWithHeaders((payments_response_json, _)) =
             payments_response
@@ -1277,7 +1304,9 @@ pub async fn trigger_refund_outgoing_webhook(
             | enums::RefundStatus::TransactionFailure
     ) {
         let event_type = ForeignFrom::foreign_from(refund_status);
-        let refund_response: api_models::refunds::RefundResponse = refund.clone().foreign_into();
+        let refund_response =
+            refunds::settlement_times::get_refund_response(state, merchant_account, refund.clone())
+                .await;
         let key_manager_state = &(state).into();
         let refund_id = refund_response.refund_id.clone();
         let business_profile = state
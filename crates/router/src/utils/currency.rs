@@ -1,7 +1,9 @@
 use std::{collections::HashMap, ops::Deref, str::FromStr, sync::Arc};
 
 use api_models::enums;
-use common_utils::{date_time, errors::CustomResult, events::ApiEventMetric, ext_traits::AsyncExt};
+use common_utils::{
+    date_time, errors::CustomResult, events::ApiEventMetric, ext_traits::AsyncExt, types::MinorUnit,
+};
 use currency_conversion::types::{CurrencyFactors, ExchangeRates};
 use error_stack::ResultExt;
 use masking::PeekInterface;
@@ -536,9 +538,21 @@ pub async fn convert_currency(
         currency_conversion::conversion::convert(&rates.data, from_currency, to_currency, amount)
             .change_context(ForexError::ConversionError)
             .attach_printable("Unable to perform currency conversion")?;
+    let converted_amount = MinorUnit::from_major_unit_as_decimal(
+        converted_amount,
+        to_currency,
+        state
+            .conf
+            .rounding_policy
+            .get_rounding_mode(Some(to_currency)),
+    )
+    .change_context(ForexError::ConversionError)
+    .attach_printable("Unable to round the converted amount")?;
 
     Ok(api_models::currency::CurrencyConversionResponse {
-        converted_amount: converted_amount.to_string(),
+        converted_amount: converted_amount
+            .to_major_unit_as_decimal(to_currency)
+            .to_string(),
         currency: to_currency.to_string(),
     })
 }
@@ -277,6 +277,13 @@ pub async fn generate_sample_data(
             request_extended_authorization: None,
             psd2_sca_exemption_type: None,
             platform_merchant_id: None,
+            customer_email_hash: None,
+            customer_phone_hash: None,
+            change_sequence: None,
+            is_amount_estimated: None,
+            enable_partial_payments: None,
+            store_credit_amount: None,
+            is_test_payment: None,
         };
         let (connector_transaction_id, processor_transaction_data) =
             ConnectorTransactionId::form_id_and_data(attempt_id.clone());
@@ -407,6 +414,8 @@ pub async fn generate_sample_data(
                 organization_id: org_id.clone(),
                 processor_refund_data: None,
                 processor_transaction_data,
+                end_to_end_reference: None,
+                refund_destination: None,
             })
         } else {
             None
@@ -2,12 +2,30 @@
 pub mod api_key_expiry;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
+pub mod attempt_redaction;
+pub mod authentication_abandonment;
+pub mod connector_credentials_expiry;
+pub mod connector_oauth_token_refresh;
+pub mod connector_transaction_id_backfill;
+pub mod data_residency_migration;
+pub mod deferred_vaulting;
+pub mod dunning;
+pub mod merchant_offboarding;
 pub mod outgoing_webhook_retry;
 pub mod payment_method_status_update;
 pub mod payment_sync;
+pub mod routing_experiment;
+pub mod sca_compliance_report;
+pub mod scheduled_capture;
+
+pub mod refund_batch;
+
+pub mod refund_reconciliation;
 
 pub mod refund_router;
 
 pub mod tokenized_data;
 
 pub mod passive_churn_recovery_workflow;
+
+pub mod warehouse_sync;
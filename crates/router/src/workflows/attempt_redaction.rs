@@ -0,0 +1,121 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    consts, errors, logger,
+    routes::SessionState,
+    types::storage::{self, business_status},
+};
+#[cfg(feature = "v1")]
+use crate::{core::data_retention, types::storage::payment_attempt::AttemptRedactionTrackingData};
+
+pub struct AttemptRedactionWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for AttemptRedactionWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let config = &state.conf.attempt_data_retention;
+        if !config.enabled {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?;
+            return Ok(());
+        }
+
+        let mut tracking_data: AttemptRedactionTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AttemptRedactionTrackingData")?;
+
+        // The cursor is saved after each batch, so that a failed run resumes from the last batch
+        // redacted
+        let mut process = process;
+        let mut is_pass_completed = false;
+        for _ in 0..consts::ATTEMPT_REDACTION_MAX_BATCHES_PER_RUN {
+            let batch = data_retention::redact_attempts_batch(state, tracking_data).await?;
+            tracking_data = batch.tracking_data;
+            let serialized_tracking_data = serde_json::to_value(&tracking_data)
+                .change_context(errors::ProcessTrackerError::SerializationFailed)?;
+            process = db
+                .as_scheduler()
+                .update_process(
+                    process,
+                    storage::ProcessTrackerUpdate::Update {
+                        name: None,
+                        retry_count: None,
+                        schedule_time: None,
+                        tracking_data: Some(serialized_tracking_data),
+                        business_status: None,
+                        status: None,
+                        updated_at: Some(common_utils::date_time::now()),
+                    },
+                )
+                .await?;
+
+            if batch.is_last_batch {
+                is_pass_completed = true;
+                break;
+            }
+        }
+
+        // A pass which did not complete in this run is continued right away
+        let now = common_utils::date_time::now();
+        let schedule_time = if is_pass_completed {
+            now.saturating_add(time::Duration::seconds(config.interval))
+        } else {
+            now
+        };
+        let process_tracker_update = storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: Some(0),
+            schedule_time: Some(schedule_time),
+            tracking_data: None,
+            business_status: Some(String::from(business_status::PENDING)),
+            status: Some(storage::enums::ProcessTrackerStatus::New),
+            updated_at: Some(now),
+        };
+        db.as_scheduler()
+            .update_process(process, process_tracker_update)
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(%process.id, "Failed while executing workflow");
+
+        let schedule_time = common_utils::date_time::now().saturating_add(time::Duration::seconds(
+            consts::ATTEMPT_REDACTION_RETRY_IN_SECONDS,
+        ));
+        state
+            .store
+            .as_scheduler()
+            .reset_process(process, schedule_time)
+            .await
+            .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+
+        Ok(())
+    }
+}
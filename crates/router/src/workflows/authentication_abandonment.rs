@@ -0,0 +1,82 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+#[cfg(feature = "v1")]
+use crate::{
+    core::authentication::abandonment, types::storage::AuthenticationAbandonmentTrackingData,
+};
+use crate::{errors, logger, routes::SessionState, types::storage};
+
+pub struct AuthenticationAbandonmentWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for AuthenticationAbandonmentWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: AuthenticationAbandonmentTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AuthenticationAbandonmentTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let is_abandoned = abandonment::mark_challenge_abandoned(
+            state,
+            merchant_account,
+            key_store,
+            &tracking_data,
+        )
+        .await?;
+
+        let business_status = if is_abandoned {
+            "COMPLETED_BY_PT"
+        } else {
+            "PROCESS_ALREADY_COMPLETED"
+        };
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status)
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
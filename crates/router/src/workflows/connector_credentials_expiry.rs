@@ -0,0 +1,82 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{consts, errors, logger, routes::SessionState, types::storage};
+#[cfg(feature = "v1")]
+use crate::{core::connector_credentials, types::storage::ConnectorCredentialsExpiryTrackingData};
+
+pub struct ConnectorCredentialsExpiryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for ConnectorCredentialsExpiryWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: ConnectorCredentialsExpiryTrackingData =
+            process
+                .tracking_data
+                .clone()
+                .parse_value("ConnectorCredentialsExpiryTrackingData")?;
+
+        let next_schedule_time = connector_credentials::process_credentials_expiry(
+            state,
+            &tracking_data.merchant_id,
+            &tracking_data.merchant_connector_id,
+        )
+        .await?;
+
+        match next_schedule_time {
+            Some(schedule_time) => {
+                db.as_scheduler()
+                    .reset_process(process, schedule_time)
+                    .await?;
+            }
+            // The credentials have expired, or no longer have a known expiry
+            None => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, "COMPLETED_BY_PT")
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(%process.id, "Failed while executing workflow");
+
+        // The reminder is not dropped on a failure, as the credentials would otherwise expire
+        // without any notice
+        let schedule_time = common_utils::date_time::now().saturating_add(time::Duration::seconds(
+            consts::CONNECTOR_CREDENTIALS_EXPIRY_RETRY_IN_SECONDS,
+        ));
+        state
+            .store
+            .as_scheduler()
+            .reset_process(process, schedule_time)
+            .await
+            .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,84 @@
+#[cfg(all(feature = "v1", feature = "olap"))]
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{consts, errors, logger, routes::SessionState, types::storage};
+#[cfg(all(feature = "v1", feature = "olap"))]
+use crate::{
+    core::connector_onboarding::oauth, types::storage::ConnectorOAuthTokenRefreshTrackingData,
+};
+
+pub struct ConnectorOAuthTokenRefreshWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for ConnectorOAuthTokenRefreshWorkflow {
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: ConnectorOAuthTokenRefreshTrackingData =
+            process
+                .tracking_data
+                .clone()
+                .parse_value("ConnectorOAuthTokenRefreshTrackingData")?;
+
+        let next_schedule_time = oauth::refresh_oauth_token(
+            state,
+            &tracking_data.merchant_id,
+            &tracking_data.merchant_connector_id,
+        )
+        .await?;
+
+        match next_schedule_time {
+            Some(schedule_time) => {
+                db.as_scheduler()
+                    .reset_process(process, schedule_time)
+                    .await?;
+            }
+            // The access token does not expire, or can no longer be refreshed
+            None => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, "COMPLETED_BY_PT")
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(not(all(feature = "v1", feature = "olap")))]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(%process.id, "Failed while executing workflow");
+
+        // The connector account stops working once its access token expires, so the refresh is
+        // attempted again instead of being left failed
+        let schedule_time = common_utils::date_time::now().saturating_add(time::Duration::seconds(
+            consts::CONNECTOR_OAUTH_TOKEN_REFRESH_RETRY_IN_SECONDS,
+        ));
+        state
+            .store
+            .as_scheduler()
+            .reset_process(process, schedule_time)
+            .await
+            .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+
+        Ok(())
+    }
+}
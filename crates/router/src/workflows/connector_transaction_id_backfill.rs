@@ -0,0 +1,133 @@
+#[cfg(all(feature = "v1", feature = "olap"))]
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    consts, errors, logger,
+    routes::SessionState,
+    types::storage::{self, business_status},
+};
+#[cfg(all(feature = "v1", feature = "olap"))]
+use crate::{
+    core::connector_transaction_id_backfill,
+    types::storage::payment_attempt::ConnectorTransactionIdBackfillTrackingData,
+};
+
+pub struct ConnectorTransactionIdBackfillWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for ConnectorTransactionIdBackfillWorkflow {
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: ConnectorTransactionIdBackfillTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ConnectorTransactionIdBackfillTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let is_completed =
+            connector_transaction_id_backfill::process_connector_transaction_id_backfill(
+                state,
+                &merchant_account,
+                &key_store,
+                &tracking_data.backfill_id,
+            )
+            .await?;
+
+        if is_completed {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?;
+        } else {
+            // The syncs are rate limited per minute, so the next payments are picked up a minute
+            // later
+            let schedule_time =
+                common_utils::date_time::now().saturating_add(time::Duration::minutes(1));
+            db.as_scheduler()
+                .reset_process(process, schedule_time)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    #[cfg(not(all(feature = "v1", feature = "olap")))]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(%process.id, "Failed while executing workflow");
+
+        // The progress of the backfill is kept, so the scan resumes where it was interrupted
+        let scheduler = state.store.as_scheduler();
+        if process.retry_count < consts::CONNECTOR_TRANSACTION_ID_BACKFILL_MAX_RETRIES {
+            let schedule_time = common_utils::date_time::now().saturating_add(
+                time::Duration::seconds(consts::CONNECTOR_TRANSACTION_ID_BACKFILL_RETRY_IN_SECONDS),
+            );
+            return scheduler
+                .retry_process(process, schedule_time)
+                .await
+                .change_context(errors::ProcessTrackerError::ProcessUpdateFailed);
+        }
+
+        #[cfg(all(feature = "v1", feature = "olap"))]
+        {
+            let tracking_data: ConnectorTransactionIdBackfillTrackingData = process
+                .tracking_data
+                .clone()
+                .parse_value("ConnectorTransactionIdBackfillTrackingData")
+                .change_context(errors::ProcessTrackerError::DeserializationFailed)?;
+            if let Err(error) =
+                connector_transaction_id_backfill::fail_connector_transaction_id_backfill(
+                    &*state.store,
+                    &tracking_data,
+                )
+                .await
+            {
+                logger::error!(
+                    ?error,
+                    "Failed to mark the connector transaction ID backfill as failed"
+                );
+            }
+        }
+
+        scheduler
+            .finish_process_with_business_status(process, business_status::RETRIES_EXCEEDED)
+            .await
+            .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    consts, errors, logger,
+    routes::SessionState,
+    types::storage::{self, business_status},
+};
+#[cfg(feature = "v1")]
+use crate::{
+    core::data_residency, types::storage::merchant_account::DataResidencyMigrationTrackingData,
+};
+
+pub struct DataResidencyMigrationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for DataResidencyMigrationWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: DataResidencyMigrationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("DataResidencyMigrationTrackingData")?;
+
+        match data_residency::process_data_residency_migration(state, &tracking_data.merchant_id)
+            .await?
+        {
+            Some(schedule_time) => {
+                db.as_scheduler()
+                    .reset_process(process, schedule_time)
+                    .await?;
+            }
+            None => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(not(feature = "v1"))]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(%process.id, "Failed while executing workflow");
+
+        // The progress of the migration is kept, so the pass resumes where it was interrupted
+        let scheduler = state.store.as_scheduler();
+        if process.retry_count < consts::DATA_RESIDENCY_MIGRATION_MAX_RETRIES {
+            let schedule_time = common_utils::date_time::now().saturating_add(
+                time::Duration::seconds(consts::DATA_RESIDENCY_MIGRATION_RETRY_IN_SECONDS),
+            );
+            return scheduler
+                .retry_process(process, schedule_time)
+                .await
+                .change_context(errors::ProcessTrackerError::ProcessUpdateFailed);
+        }
+
+        #[cfg(feature = "v1")]
+        {
+            let tracking_data: DataResidencyMigrationTrackingData = process
+                .tracking_data
+                .clone()
+                .parse_value("DataResidencyMigrationTrackingData")
+                .change_context(errors::ProcessTrackerError::DeserializationFailed)?;
+            if let Err(error) =
+                data_residency::fail_data_residency_migration(&*state.store, &tracking_data).await
+            {
+                logger::error!(
+                    ?error,
+                    "Failed to mark the data residency migration as failed"
+                );
+            }
+        }
+
+        scheduler
+            .finish_process_with_business_status(process, business_status::RETRIES_EXCEEDED)
+            .await
+            .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+
+        Ok(())
+    }
+}
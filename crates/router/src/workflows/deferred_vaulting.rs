@@ -0,0 +1,119 @@
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+use common_utils::ext_traits::ValueExt;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+use crate::{
+    consts, core::payment_methods::deferred_vaulting, types::storage::DeferredVaultingTrackingData,
+};
+use crate::{errors, logger, routes::SessionState, types::storage};
+
+pub struct DeferredVaultingWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for DeferredVaultingWorkflow {
+    #[cfg(all(
+        any(feature = "v1", feature = "v2"),
+        not(feature = "payment_methods_v2")
+    ))]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: DeferredVaultingTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("DeferredVaultingTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        match deferred_vaulting::vault_deferred_payment_method(
+            state,
+            &merchant_account,
+            &key_store,
+            &tracking_data,
+        )
+        .await
+        {
+            Ok(true) => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, "COMPLETED_BY_PT")
+                    .await?
+            }
+            Ok(false) => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, "PROCESS_ALREADY_COMPLETED")
+                    .await?
+            }
+            Err(error) if process.retry_count < consts::DEFERRED_VAULTING_MAX_RETRIES => {
+                logger::warn!(?error, "Failed to vault the deferred payment method");
+                let schedule_time = common_utils::date_time::now().saturating_add(
+                    time::Duration::seconds(consts::DEFERRED_VAULTING_RETRY_INTERVAL_SECONDS),
+                );
+                db.as_scheduler()
+                    .retry_process(process, schedule_time)
+                    .await?
+            }
+            Err(error) => {
+                logger::error!(
+                    ?error,
+                    "Retries exceeded for vaulting the deferred payment method"
+                );
+                deferred_vaulting::deactivate_deferred_payment_method(
+                    state,
+                    &merchant_account,
+                    &key_store,
+                    &tracking_data,
+                )
+                .await?;
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, "RETRIES_EXCEEDED")
+                    .await?
+            }
+        };
+
+        Ok(())
+    }
+
+    #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(%process.id, "Failed while executing workflow");
+        Ok(())
+    }
+}
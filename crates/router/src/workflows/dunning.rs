@@ -0,0 +1,114 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    consts, errors, logger,
+    routes::SessionState,
+    types::storage::{self, business_status},
+};
+#[cfg(feature = "v1")]
+use crate::{core::payments::dunning, types::storage::DunningTrackingData};
+
+pub struct DunningWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for DunningWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: DunningTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("DunningTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let next_retry_at =
+            dunning::retry_dunning_payment(state, &merchant_account, &key_store, &tracking_data)
+                .await?;
+
+        match next_retry_at {
+            Some(next_retry_at) => {
+                db.as_scheduler()
+                    .reset_process(process, next_retry_at)
+                    .await?;
+            }
+            None => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, "COMPLETED_BY_PT")
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(%process.id, "Failed while executing workflow");
+
+        let scheduler = state.store.as_scheduler();
+        if process.retry_count < consts::DUNNING_TASK_MAX_RETRIES {
+            let schedule_time = common_utils::date_time::now().saturating_add(
+                time::Duration::seconds(consts::DUNNING_TASK_RETRY_IN_SECONDS),
+            );
+            return scheduler
+                .retry_process(process, schedule_time)
+                .await
+                .change_context(errors::ProcessTrackerError::ProcessUpdateFailed);
+        }
+
+        #[cfg(feature = "v1")]
+        {
+            let tracking_data: DunningTrackingData = process
+                .tracking_data
+                .clone()
+                .parse_value("DunningTrackingData")
+                .change_context(errors::ProcessTrackerError::DeserializationFailed)?;
+            if let Err(error) = dunning::fail_dunning(&*state.store, &tracking_data).await {
+                logger::error!(?error, "Failed to mark the dunning as uncollectible");
+            }
+        }
+
+        scheduler
+            .finish_process_with_business_status(process, business_status::RETRIES_EXCEEDED)
+            .await
+            .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,103 @@
+#[cfg(all(feature = "v1", feature = "olap"))]
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    consts, errors, logger,
+    routes::SessionState,
+    types::storage::{self, business_status},
+};
+#[cfg(all(feature = "v1", feature = "olap"))]
+use crate::{core::merchant_lifecycle, types::storage::MerchantOffboardingTrackingData};
+
+pub struct MerchantOffboardingWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for MerchantOffboardingWorkflow {
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: MerchantOffboardingTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("MerchantOffboardingTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let next_schedule_time =
+            merchant_lifecycle::process_merchant_offboarding(state, &merchant_account, &key_store)
+                .await?;
+
+        match next_schedule_time {
+            Some(schedule_time) => {
+                db.as_scheduler()
+                    .reset_process(process, schedule_time)
+                    .await?;
+            }
+            None => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, "COMPLETED_BY_PT")
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[cfg(not(all(feature = "v1", feature = "olap")))]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(%process.id, "Failed while executing workflow");
+
+        // The progress of the offboarding is kept, so the run resumes where it failed
+        let scheduler = state.store.as_scheduler();
+        if process.retry_count < consts::MERCHANT_OFFBOARDING_MAX_RETRIES {
+            let schedule_time = common_utils::date_time::now().saturating_add(
+                time::Duration::seconds(consts::MERCHANT_OFFBOARDING_RETRY_IN_SECONDS),
+            );
+            return scheduler
+                .retry_process(process, schedule_time)
+                .await
+                .change_context(errors::ProcessTrackerError::ProcessUpdateFailed);
+        }
+
+        scheduler
+            .finish_process_with_business_status(process, business_status::RETRIES_EXCEEDED)
+            .await
+            .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+
+        Ok(())
+    }
+}
@@ -19,12 +19,14 @@ use scheduler::{
     utils as scheduler_utils,
 };
 
+#[cfg(feature = "v1")]
+use crate::core::connector_credentials;
 #[cfg(feature = "payouts")]
 use crate::core::payouts;
 use crate::{
     core::{
         payments,
-        webhooks::{self as webhooks_core, types::OutgoingWebhookTrackingData},
+        webhooks::{self as webhooks_core, payload_templates, types::OutgoingWebhookTrackingData},
     },
     db::StorageInterface,
     errors, logger,
@@ -66,6 +68,15 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
             )
             .await?;
 
+        // The retry is held while the delivery to the endpoint is paused, before its event is
+        // inserted, so that the checks of a held retry do not pile up events
+        if webhooks_core::endpoint_health::is_endpoint_paused(state, business_profile.get_id())
+            .await
+        {
+            webhooks_core::endpoint_health::hold_delivery_while_paused(state, process).await?;
+            return Ok(());
+        }
+
         let event_id = webhooks_core::utils::generate_event_id();
         let idempotent_event_id = webhooks_core::utils::get_idempotent_event_id(
             &tracking_data.primary_object_id,
@@ -179,10 +190,26 @@ impl ProcessTrackerWorkflow<SessionState> for OutgoingWebhookRetryWorkflow {
                             timestamp: event.created_at,
                         };
 
+                        // The webhook is retried with the default payload when its template
+                        // cannot be fetched
+                        let payload_template = payload_templates::find_payload_template(
+                            db,
+                            &tracking_data.merchant_id,
+                            event_type,
+                        )
+                        .await
+                        .inspect_err(|error| {
+                            logger::error!(
+                                ?error,
+                                "Failed to fetch the payload template, sending the default payload"
+                            )
+                        })
+                        .unwrap_or(None);
                         let request_content = webhooks_core::get_outgoing_webhook_request(
                             &merchant_account,
                             outgoing_webhook,
                             &business_profile,
+                            payload_template.as_ref(),
                         )
                         .map_err(|error| {
                             logger::error!(
@@ -355,9 +382,10 @@ async fn get_outgoing_webhook_content_and_event_type(
     tracking_data: &OutgoingWebhookTrackingData,
 ) -> Result<(OutgoingWebhookContent, Option<EventType>), errors::ProcessTrackerError> {
     use api_models::{
+        admin::MerchantAccountStatusResponse,
         mandates::MandateId,
         payments::{PaymentIdType, PaymentsResponse, PaymentsRetrieveRequest},
-        refunds::{RefundResponse, RefundsRetrieveRequest},
+        refunds::RefundsRetrieveRequest,
     };
 
     use crate::{
@@ -365,7 +393,7 @@ async fn get_outgoing_webhook_content_and_event_type(
             disputes::retrieve_dispute,
             mandate::get_mandate,
             payments::{payments_core, CallConnectorAction, PaymentStatus},
-            refunds::refund_retrieve_core_with_refund_id,
+            refunds::{refund_retrieve_core_with_refund_id, settlement_times},
         },
         services::{ApplicationResponse, AuthFlow},
         types::{
@@ -450,8 +478,8 @@ async fn get_outgoing_webhook_content_and_event_type(
             };
 
             let refund = Box::pin(refund_retrieve_core_with_refund_id(
-                state,
-                merchant_account,
+                state.clone(),
+                merchant_account.clone(),
                 None,
                 key_store,
                 request,
@@ -459,7 +487,8 @@ async fn get_outgoing_webhook_content_and_event_type(
             .await?;
             let event_type = Option::<EventType>::foreign_from(refund.refund_status);
             logger::debug!(current_resource_status=%refund.refund_status);
-            let refund_response = RefundResponse::foreign_from(refund);
+            let refund_response =
+                settlement_times::get_refund_response(&state, &merchant_account, refund).await;
 
             Ok((
                 OutgoingWebhookContent::RefundDetails(Box::new(refund_response)),
@@ -565,5 +594,37 @@ async fn get_outgoing_webhook_content_and_event_type(
                 event_type,
             ))
         }
+
+        diesel_models::enums::EventClass::MerchantAccount => {
+            let merchant_account_response = MerchantAccountStatusResponse {
+                merchant_id: merchant_account.get_id().clone(),
+                status: merchant_account.status,
+                previous_status: None,
+                reason: None,
+                updated_at: merchant_account.modified_at,
+            };
+            logger::debug!(current_resource_status=%merchant_account.status);
+
+            Ok((
+                OutgoingWebhookContent::MerchantAccountDetails(Box::new(merchant_account_response)),
+                Some(EventType::MerchantAccountStatusUpdated),
+            ))
+        }
+
+        diesel_models::enums::EventClass::MerchantConnectorAccount => {
+            let (credentials_response, event_type) =
+                connector_credentials::get_credentials_webhook_content(
+                    &state,
+                    &key_store,
+                    &tracking_data.primary_object_id,
+                )
+                .await?;
+            logger::debug!(current_resource_status=%credentials_response.status);
+
+            Ok((
+                OutgoingWebhookContent::ConnectorCredentialsDetails(Box::new(credentials_response)),
+                event_type,
+            ))
+        }
     }
 }
@@ -0,0 +1,114 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    consts, errors, logger,
+    routes::SessionState,
+    types::storage::{self, business_status},
+};
+#[cfg(feature = "v1")]
+use crate::{core::refunds::batch, types::storage::RefundBatchTrackingData};
+
+pub struct RefundBatchWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for RefundBatchWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: RefundBatchTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("RefundBatchTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let is_completed =
+            batch::process_refund_batch_chunk(state, &merchant_account, &key_store, &tracking_data)
+                .await?;
+
+        if is_completed {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, "COMPLETED_BY_PT")
+                .await?;
+        } else {
+            // Pick up the next chunk of the batch in the next run of the scheduler
+            db.as_scheduler()
+                .reset_process(process, common_utils::date_time::now())
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(%process.id, "Failed while executing workflow");
+
+        // The refunds are initiated with deterministic identifiers, so the chunk is retried
+        // without refunding its rows twice
+        let scheduler = state.store.as_scheduler();
+        if process.retry_count < consts::REFUND_BATCH_MAX_RETRIES {
+            let schedule_time = common_utils::date_time::now().saturating_add(
+                time::Duration::seconds(consts::REFUND_BATCH_RETRY_IN_SECONDS),
+            );
+            return scheduler
+                .retry_process(process, schedule_time)
+                .await
+                .change_context(errors::ProcessTrackerError::ProcessUpdateFailed);
+        }
+
+        #[cfg(feature = "v1")]
+        {
+            let tracking_data: RefundBatchTrackingData = process
+                .tracking_data
+                .clone()
+                .parse_value("RefundBatchTrackingData")
+                .change_context(errors::ProcessTrackerError::DeserializationFailed)?;
+            if let Err(error) = batch::fail_refund_batch(&*state.store, &tracking_data).await {
+                logger::error!(?error, "Failed to mark the refund batch as failed");
+            }
+        }
+
+        scheduler
+            .finish_process_with_business_status(process, business_status::RETRIES_EXCEEDED)
+            .await
+            .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+
+        Ok(())
+    }
+}
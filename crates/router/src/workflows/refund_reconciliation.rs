@@ -0,0 +1,82 @@
+use error_stack::ResultExt;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+#[cfg(feature = "v1")]
+use crate::core::refunds::reconciliation;
+use crate::{
+    consts, errors, logger,
+    routes::SessionState,
+    types::storage::{self, business_status},
+};
+
+pub struct RefundReconciliationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for RefundReconciliationWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let config = &state.conf.refund_reconciliation;
+        if !config.enabled {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, business_status::COMPLETED_BY_PT)
+                .await?;
+            return Ok(());
+        }
+
+        let tracking_data = reconciliation::reconcile_pending_refunds(state).await?;
+        let tracking_data = serde_json::to_value(tracking_data)
+            .change_context(errors::ProcessTrackerError::SerializationFailed)?;
+
+        let schedule_time =
+            common_utils::date_time::now().saturating_add(time::Duration::seconds(config.interval));
+        let process_tracker_update = storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: Some(0),
+            schedule_time: Some(schedule_time),
+            tracking_data: Some(tracking_data),
+            business_status: Some(String::from(business_status::PENDING)),
+            status: Some(storage::enums::ProcessTrackerStatus::New),
+            updated_at: Some(common_utils::date_time::now()),
+        };
+        db.as_scheduler()
+            .update_process(process, process_tracker_update)
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(%process.id, "Failed while executing workflow");
+
+        let schedule_time = common_utils::date_time::now().saturating_add(time::Duration::seconds(
+            consts::REFUND_RECONCILIATION_RETRY_IN_SECONDS,
+        ));
+        state
+            .store
+            .as_scheduler()
+            .reset_process(process, schedule_time)
+            .await
+            .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use scheduler::workflows::ProcessTrackerWorkflow;
+use time::Duration;
+
+use crate::{consts, errors, logger, routes::SessionState, types::storage};
+#[cfg(feature = "v1")]
+use crate::{core::routing::experiments, types::storage::RoutingExperimentTrackingData};
+
+pub struct RoutingExperimentWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for RoutingExperimentWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: RoutingExperimentTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("RoutingExperimentTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &state.into(),
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let is_running =
+            experiments::evaluate_routing_experiment(state, &key_store, &tracking_data).await?;
+
+        if is_running {
+            let next_evaluation_at = common_utils::date_time::now().saturating_add(
+                Duration::seconds(consts::ROUTING_EXPERIMENT_EVALUATION_INTERVAL_IN_SECS),
+            );
+            db.as_scheduler()
+                .reset_process(process, next_evaluation_at)
+                .await?;
+        } else {
+            db.as_scheduler()
+                .finish_process_with_business_status(process, "COMPLETED_BY_PT")
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(%process.id, "Failed while executing workflow");
+
+        // The experiment keeps being evaluated while it runs, so a failed evaluation is attempted
+        // again at the next evaluation
+        let next_evaluation_at = common_utils::date_time::now().saturating_add(Duration::seconds(
+            consts::ROUTING_EXPERIMENT_EVALUATION_INTERVAL_IN_SECS,
+        ));
+        state
+            .store
+            .as_scheduler()
+            .reset_process(process, next_evaluation_at)
+            .await
+            .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+
+        Ok(())
+    }
+}
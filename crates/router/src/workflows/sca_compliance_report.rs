@@ -0,0 +1,111 @@
+#[cfg(all(feature = "v1", feature = "olap"))]
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    consts, errors, logger,
+    routes::SessionState,
+    types::storage::{self, business_status},
+};
+#[cfg(all(feature = "v1", feature = "olap"))]
+use crate::{core::sca_compliance, types::storage::ScaComplianceReportTrackingData};
+
+pub struct ScaComplianceReportWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for ScaComplianceReportWorkflow {
+    #[cfg(all(feature = "v1", feature = "olap"))]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: ScaComplianceReportTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ScaComplianceReportTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        sca_compliance::generate_sca_compliance_report(
+            state,
+            &merchant_account,
+            &key_store,
+            &tracking_data.report_id,
+        )
+        .await?;
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, "COMPLETED_BY_PT")
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(not(all(feature = "v1", feature = "olap")))]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(%process.id, "Failed while executing workflow");
+
+        let scheduler = state.store.as_scheduler();
+        if process.retry_count < consts::SCA_COMPLIANCE_REPORT_MAX_RETRIES {
+            let schedule_time = common_utils::date_time::now().saturating_add(
+                time::Duration::seconds(consts::SCA_COMPLIANCE_REPORT_RETRY_IN_SECONDS),
+            );
+            return scheduler
+                .retry_process(process, schedule_time)
+                .await
+                .change_context(errors::ProcessTrackerError::ProcessUpdateFailed);
+        }
+
+        #[cfg(all(feature = "v1", feature = "olap"))]
+        {
+            let tracking_data: ScaComplianceReportTrackingData = process
+                .tracking_data
+                .clone()
+                .parse_value("ScaComplianceReportTrackingData")
+                .change_context(errors::ProcessTrackerError::DeserializationFailed)?;
+            if let Err(error) =
+                sca_compliance::fail_sca_compliance_report(&*state.store, &tracking_data).await
+            {
+                logger::error!(?error, "Failed to mark the SCA compliance report as failed");
+            }
+        }
+
+        scheduler
+            .finish_process_with_business_status(process, business_status::RETRIES_EXCEEDED)
+            .await
+            .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+#[cfg(feature = "v1")]
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use scheduler::workflows::ProcessTrackerWorkflow;
+
+use crate::{
+    consts, errors, logger,
+    routes::SessionState,
+    types::storage::{self, business_status},
+};
+#[cfg(feature = "v1")]
+use crate::{
+    core::payments::scheduled_capture,
+    types::storage::payment_attempt::ScheduledCaptureTrackingData,
+};
+
+pub struct ScheduledCaptureWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for ScheduledCaptureWorkflow {
+    #[cfg(feature = "v1")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: ScheduledCaptureTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ScheduledCaptureTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let is_captured = scheduled_capture::execute_scheduled_capture(
+            state,
+            merchant_account,
+            key_store,
+            &tracking_data,
+        )
+        .await?;
+
+        let business_status = if is_captured {
+            business_status::COMPLETED_BY_PT
+        } else {
+            business_status::RESOURCE_STATUS_MISMATCH
+        };
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status)
+            .await?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "v2")]
+    async fn execute_workflow<'a>(
+        &'a self,
+        _state: &'a SessionState,
+        _process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        todo!()
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        _error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(%process.id, "Failed while executing workflow");
+
+        let scheduler = state.store.as_scheduler();
+        if process.retry_count >= consts::SCHEDULED_CAPTURE_MAX_RETRIES {
+            scheduler
+                .finish_process_with_business_status(process, business_status::RETRIES_EXCEEDED)
+                .await
+        } else {
+            let schedule_time = common_utils::date_time::now().saturating_add(
+                time::Duration::seconds(consts::SCHEDULED_CAPTURE_RETRY_IN_SECONDS),
+            );
+            scheduler.retry_process(process, schedule_time).await
+        }
+        .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)?;
+
+        Ok(())
+    }
+}
@@ -103,6 +103,28 @@ async fn should_only_authorize_payment() {
     assert_eq!(response.status, enums::AttemptStatus::Authorized);
 }
 
+// Retries an authorization with the same idempotency key, which must not create a second payment.
+#[serial_test::serial]
+#[actix_web::test]
+async fn should_not_authorize_payment_twice_for_same_idempotency_key() {
+    let payment_data = payment_method_details().map(|payment_data| types::PaymentsAuthorizeData {
+        connector_idempotency_key: Some(uuid::Uuid::new_v4().to_string()),
+        ..payment_data
+    });
+    let response = CONNECTOR
+        .authorize_payment(payment_data.clone(), get_default_payment_info())
+        .await
+        .expect("Authorize payment response");
+    let retry_response = CONNECTOR
+        .authorize_payment(payment_data, get_default_payment_info())
+        .await
+        .expect("Authorize payment response");
+    assert_eq!(
+        utils::get_connector_transaction_id(response.response),
+        utils::get_connector_transaction_id(retry_response.response)
+    );
+}
+
 // Captures a payment using the manual capture flow (Non 3DS).
 #[serial_test::serial]
 #[actix_web::test]
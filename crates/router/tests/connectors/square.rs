@@ -95,6 +95,28 @@ async fn should_only_authorize_payment() {
     assert_eq!(response.status, enums::AttemptStatus::Authorized);
 }
 
+// Retries an authorization with the same idempotency key, which must not create a second payment.
+#[actix_web::test]
+async fn should_not_authorize_payment_twice_for_same_idempotency_key() {
+    let payment_data = Some(types::PaymentsAuthorizeData {
+        connector_idempotency_key: Some(uuid::Uuid::new_v4().to_string()),
+        ..utils::PaymentAuthorizeType::default().0
+    });
+    let payment_info = get_default_payment_info(create_token().await);
+    let response = CONNECTOR
+        .authorize_payment(payment_data.clone(), payment_info.clone())
+        .await
+        .expect("Authorize payment response");
+    let retry_response = CONNECTOR
+        .authorize_payment(payment_data, payment_info)
+        .await
+        .expect("Authorize payment response");
+    assert_eq!(
+        get_connector_transaction_id(response.response),
+        get_connector_transaction_id(retry_response.response)
+    );
+}
+
 // Captures a payment using the manual capture flow (Non 3DS).
 #[actix_web::test]
 async fn should_capture_authorized_payment() {
@@ -410,6 +410,7 @@ pub trait ConnectorActions: Connector {
                 merchant_account_id: None,
                 merchant_config_currency: None,
                 capture_method: None,
+                end_to_end_reference: None,
             }),
             payment_info,
         );
@@ -554,6 +555,7 @@ pub trait ConnectorActions: Connector {
             header_payload: None,
             connector_mandate_request_reference_id: None,
             psd2_sca_exemption_type: None,
+            profile_id: None,
             authentication_id: None,
         }
     }
@@ -987,6 +989,8 @@ impl Default for PaymentAuthorizeType {
             shipping_cost: None,
             merchant_account_id: None,
             merchant_config_currency: None,
+            payment_facilitator_details: None,
+            connector_idempotency_key: None,
         };
         Self(data)
     }
@@ -1080,6 +1084,7 @@ impl Default for PaymentRefundType {
             merchant_account_id: None,
             merchant_config_currency: None,
             capture_method: None,
+            end_to_end_reference: None,
         };
         Self(data)
     }
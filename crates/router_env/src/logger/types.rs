@@ -80,10 +80,28 @@ pub enum Flow {
     MerchantConnectorsUpdate,
     /// Merchant Connectors delete flow.
     MerchantConnectorsDelete,
+    /// Merchant Connectors restore flow.
+    MerchantConnectorsRestore,
+    /// Merchant Connectors bulk config flow.
+    MerchantConnectorsBulkConfig,
+    /// Merchant Connectors maintenance windows upsert flow.
+    MerchantConnectorsMaintenanceWindowsUpsert,
+    /// Merchant Connectors maintenance windows retrieve flow.
+    MerchantConnectorsMaintenanceWindowsRetrieve,
+    /// Merchant Connectors credentials retrieve flow.
+    MerchantConnectorsCredentialsRetrieve,
+    /// Merchant Connectors credentials update flow.
+    MerchantConnectorsCredentialsUpdate,
+    /// Merchant Connectors credentials verify flow.
+    MerchantConnectorsCredentialsVerify,
+    /// Merchant Connectors expiring credentials list flow.
+    MerchantConnectorsCredentialsExpiringList,
     /// Merchant Connectors list flow.
     MerchantConnectorsList,
     /// Merchant Transfer Keys
     MerchantTransferKey,
+    /// KV and Postgres divergences list flow.
+    KvPgDivergencesList,
     /// ConfigKey create flow.
     ConfigKeyCreate,
     /// ConfigKey fetch flow.
@@ -104,6 +122,12 @@ pub enum Flow {
     CustomersDelete,
     /// Customers get mandates flow.
     CustomersGetMandates,
+    /// Customers payments list flow.
+    CustomersPaymentsList,
+    /// Customers store credit retrieve flow.
+    CustomersStoreCreditRetrieve,
+    /// Customers store credit entries list flow.
+    CustomersStoreCreditEntriesList,
     /// Create an Ephemeral Key.
     EphemeralKeyCreate,
     /// Delete an Ephemeral Key.
@@ -118,10 +142,14 @@ pub enum Flow {
     PaymentMethodsCreate,
     /// Payment methods migrate flow.
     PaymentMethodsMigrate,
+    /// Payment methods migrate from external token vault flow.
+    PaymentMethodsExternalTokenMigrate,
     /// Payment methods list flow.
     PaymentMethodsList,
     /// Payment method save flow
     PaymentMethodSave,
+    /// Saved card CVV tokenize flow
+    PaymentMethodCvvTokenize,
     /// Customer payment methods list flow.
     CustomerPaymentMethodsList,
     /// List Customers for a merchant
@@ -138,6 +166,12 @@ pub enum Flow {
     PaymentMethodsDelete,
     /// Default Payment method flow.
     DefaultPaymentMethodsSet,
+    /// Customer payment method update flow.
+    CustomerPaymentMethodsUpdate,
+    /// Payment method consent recollect flow.
+    PaymentMethodConsentRecollect,
+    /// Payment method consent revoke flow.
+    PaymentMethodConsentRevoke,
     /// Payments create flow.
     PaymentsCreate,
     /// Payments Retrieve flow.
@@ -154,6 +188,8 @@ pub enum Flow {
     PaymentsCapture,
     /// Payments cancel flow.
     PaymentsCancel,
+    /// Payments cancel remaining flow.
+    PaymentsCancelRemaining,
     /// Payments approve flow.
     PaymentsApprove,
     /// Payments reject flow.
@@ -253,6 +289,24 @@ pub enum Flow {
     RoutingUpdateDefaultConfig,
     /// Routing delete config
     RoutingDeleteConfig,
+    /// Routing export config
+    RoutingExportConfig,
+    /// Routing import config
+    RoutingImportConfig,
+    /// Routing experiment create
+    RoutingExperimentCreate,
+    /// Routing experiments list
+    RoutingExperimentList,
+    /// Routing experiment retrieve
+    RoutingExperimentRetrieve,
+    /// Routing experiment conclude
+    RoutingExperimentConclude,
+    /// Routing volume caps upsert
+    RoutingVolumeCapsUpsert,
+    /// Routing volume caps retrieve
+    RoutingVolumeCapsRetrieve,
+    /// Routing simulation of a hypothetical payment
+    RoutingSimulate,
     /// Toggle dynamic routing
     ToggleDynamicRouting,
     /// Update dynamic routing config
@@ -305,6 +359,8 @@ pub enum Flow {
     DeleteDisputeEvidence,
     /// Disputes aggregate flow
     DisputesAggregate,
+    /// Retrieve the funds of the merchant at risk due to the disputes
+    DisputesBalance,
     /// Retrieve Dispute Evidence flow
     RetrieveDisputeEvidence,
     /// Invalidate cache flow
@@ -443,6 +499,12 @@ pub enum Flow {
     SyncOnboardingStatus,
     /// Reset tracking id
     ResetTrackingId,
+    /// Start the OAuth authorization of a connector account
+    ConnectorOAuthAuthorize,
+    /// Complete the OAuth authorization of a connector account
+    ConnectorOAuthCallback,
+    /// Retrieve the OAuth connection of a connector account
+    ConnectorOAuthStatus,
     /// Verify email Token
     VerifyEmail,
     /// Send verify email
@@ -455,6 +517,8 @@ pub enum Flow {
     AcceptInvitationsPreAuth,
     /// Initiate external authentication for a payment
     PaymentsExternalAuthentication,
+    /// Resume the abandoned external authentication of a payment
+    PaymentsResumeAuthentication,
     /// Authorize the payment after external 3ds authentication
     PaymentsAuthorize,
     /// Create Role
@@ -519,8 +583,30 @@ pub enum Flow {
     WebhookEventDeliveryAttemptList,
     /// Manually retry the delivery for a webhook event
     WebhookEventDeliveryRetry,
+    /// List the event types of the outgoing webhooks with the schemas of their payloads
+    WebhookEventTypeList,
+    /// List the lifecycle events of a merchant
+    WebhookEventLifecycleList,
+    /// Set the payload template of the outgoing webhooks of an event type
+    WebhookPayloadTemplateSet,
+    /// Retrieve the payload template of the outgoing webhooks of an event type
+    WebhookPayloadTemplateRetrieve,
+    /// List the payload templates of the outgoing webhooks
+    WebhookPayloadTemplateList,
+    /// Delete the payload template of the outgoing webhooks of an event type
+    WebhookPayloadTemplateDelete,
+    /// Validate a payload template of the outgoing webhooks
+    WebhookPayloadTemplateValidate,
+    /// Preview the payload rendered with a payload template of the outgoing webhooks
+    WebhookPayloadTemplatePreview,
+    /// Retrieve the health of the delivery of the outgoing webhooks to an endpoint
+    WebhookEndpointHealthRetrieve,
+    /// Resume the paused delivery of the outgoing webhooks to an endpoint
+    WebhookEndpointResume,
     /// Retrieve status of the Poll
     RetrievePollStatus,
+    /// Ingest a batch of SDK events
+    SdkEventsBatchIngest,
     /// Toggles the extended card info feature in profile level
     ToggleExtendedCardInfo,
     /// Toggles the extended card info feature in profile level
@@ -529,8 +615,60 @@ pub enum Flow {
     GetExtendedCardInfo,
     /// Manually update the refund details like status, error code, error message etc.
     RefundsManualUpdate,
+    /// Create a batch of refunds from a JSON array or a CSV file
+    RefundsBatchCreate,
+    /// Retrieve the progress of a refund batch
+    RefundsBatchRetrieve,
+    /// Download the report of a refund batch
+    RefundsBatchReport,
+    /// Resolve a refund held for manual review
+    RefundsResolve,
     /// Manually update the payment details like status, error code, error message etc.
     PaymentsManualUpdate,
+    /// Retrieve the timeline of events recorded for a payment
+    PaymentsTimeline,
+    /// Retrieve the dunning of a failed recurring payment
+    PaymentsDunningRetrieve,
+    /// Update the metadata of a payment
+    PaymentsMetadataUpdate,
+    /// Add an internal note to a payment
+    PaymentsNotesAdd,
+    /// List the internal notes of a payment
+    PaymentsNotesList,
+    /// Reschedule the capture of a payment
+    PaymentsCaptureSchedule,
+    /// Cancel the scheduled capture of a payment
+    PaymentsCaptureScheduleCancel,
+    /// Release the capture of a payment held by the auto capture delay of its profile
+    PaymentsCaptureScheduleRelease,
+    /// Capture the final amount of a payment authorized with an estimated amount
+    PaymentsFinalizeAmount,
+    /// Check the balance of a gift card for a payment
+    PaymentsGiftCardBalance,
+    /// Search the payments by the contact details of the customer
+    PaymentsSearchByContact,
+    /// Lookup a payment by the connector transaction id of one of its attempts
+    PaymentsLookup,
+    /// Poll the payments whose status changed after a cursor
+    PaymentsChanges,
+    /// Import the acquirer reference numbers of payments and refunds
+    AcquirerReferenceNumbersImport,
+    /// Retrieve the hosted receipt of a payment
+    PaymentReceiptRetrieve,
+    /// Email the receipt of a payment
+    PaymentReceiptEmail,
+    /// Create a checkout session
+    CheckoutSessionCreate,
+    /// Retrieve a checkout session
+    CheckoutSessionRetrieve,
+    /// Update the cart of a checkout session
+    CheckoutSessionUpdate,
+    /// Confirm a checkout session, converting it to a payment
+    CheckoutSessionConfirm,
+    /// Retrieve a sanctions screening case
+    SanctionsScreeningCaseRetrieve,
+    /// Resolve a sanctions screening case which is pending review
+    SanctionsScreeningCaseResolve,
     /// Dynamic Tax Calcultion
     SessionUpdateTaxCalculation,
     ProxyConfirmIntent,
@@ -576,6 +714,162 @@ pub enum Flow {
     CardsInfoMigrate,
     ///Total payment method count for merchant
     TotalPaymentMethodCount,
+    /// Create or update the payment method display config of a profile
+    PaymentMethodDisplayConfigUpsert,
+    /// Retrieve the payment method display config of a profile
+    PaymentMethodDisplayConfigRetrieve,
+    /// Delete the payment method display config of a profile
+    PaymentMethodDisplayConfigDelete,
+    /// Create or update the overrides of the payment method display names of a profile
+    PaymentMethodDisplayNamesUpsert,
+    /// Retrieve the overrides of the payment method display names of a profile
+    PaymentMethodDisplayNamesRetrieve,
+    /// Delete the overrides of the payment method display names of a profile
+    PaymentMethodDisplayNamesDelete,
+    /// List the display names and icons of the payment methods
+    PaymentMethodDisplayNamesList,
+    /// Create or update the external vault config of a profile
+    ExternalVaultConfigUpsert,
+    /// Retrieve the external vault config of a profile
+    ExternalVaultConfigRetrieve,
+    /// Delete the external vault config of a profile
+    ExternalVaultConfigDelete,
+    /// Create or update the cvv re-collection config of a profile
+    CvvRecollectionConfigUpsert,
+    /// Retrieve the cvv re-collection config of a profile
+    CvvRecollectionConfigRetrieve,
+    /// Create or update the network token preference config of a profile
+    NetworkTokenPreferenceConfigUpsert,
+    /// Retrieve the network token preference config of a profile
+    NetworkTokenPreferenceConfigRetrieve,
+    /// Generate test payments, refunds and disputes for a merchant in the sandbox
+    SandboxDataSeed,
+    /// Delete the test payments, refunds and disputes of a merchant in the sandbox
+    SandboxDataDelete,
+    /// Retrieve the sandbox test clock of a merchant
+    TestClockRetrieve,
+    /// Advance the sandbox test clock of a merchant
+    TestClockAdvance,
+    /// Reset the sandbox test clock of a merchant
+    TestClockReset,
+    /// Summarize the scheduler tasks of each type in each state
+    SchedulerTasksSummary,
+    /// List the scheduler tasks of a type in a state
+    SchedulerTasksList,
+    /// Requeue a scheduler task
+    SchedulerTaskRequeue,
+    /// Abandon a scheduler task
+    SchedulerTaskAbandon,
+    /// Retrieve the feature flags of a merchant
+    MerchantFeatureFlagsRetrieve,
+    /// Update the feature flags of a merchant
+    MerchantFeatureFlagsUpdate,
+    /// Upsert the fault injection config of a merchant
+    MerchantFaultInjectionUpsert,
+    /// Retrieve the fault injection config of a merchant
+    MerchantFaultInjectionRetrieve,
+    /// Delete the fault injection config of a merchant
+    MerchantFaultInjectionDelete,
+    /// Retrieve the feature flags of a profile
+    ProfileFeatureFlagsRetrieve,
+    /// Update the feature flags of a profile
+    ProfileFeatureFlagsUpdate,
+    /// Create or update the payment facilitator details of a profile
+    PaymentFacilitatorDetailsUpsert,
+    /// Retrieve the payment facilitator details of a profile
+    PaymentFacilitatorDetailsRetrieve,
+    /// Delete the payment facilitator details of a profile
+    PaymentFacilitatorDetailsDelete,
+    /// Create or update the custom payment status mapping of a profile
+    CustomPaymentStatusMappingUpsert,
+    /// Retrieve the custom payment status mapping of a profile
+    CustomPaymentStatusMappingRetrieve,
+    /// Delete the custom payment status mapping of a profile
+    CustomPaymentStatusMappingDelete,
+    /// Create or update the schema of the custom fields of the payments of a profile
+    CustomFieldsSchemaUpsert,
+    /// Retrieve the schema of the custom fields of the payments of a profile
+    CustomFieldsSchemaRetrieve,
+    /// Delete the schema of the custom fields of the payments of a profile
+    CustomFieldsSchemaDelete,
+    /// Create or update the overrides of the unified error messages of a profile
+    UnifiedErrorMessageOverridesUpsert,
+    /// Retrieve the overrides of the unified error messages of a profile
+    UnifiedErrorMessageOverridesRetrieve,
+    /// Delete the overrides of the unified error messages of a profile
+    UnifiedErrorMessageOverridesDelete,
+    /// Create a new version of the checkout theme of a profile
+    CheckoutThemeUpsert,
+    /// Retrieve the checkout theme of a profile
+    CheckoutThemeRetrieve,
+    /// Delete the checkout theme of a profile
+    CheckoutThemeDelete,
+    /// List the versions of the checkout theme of a profile
+    CheckoutThemeVersionsList,
+    /// Apply a previous version of the checkout theme of a profile
+    CheckoutThemeActivate,
+    /// Create or update the post authorization rules of a profile
+    PostAuthorizationRulesUpsert,
+    /// Retrieve the post authorization rules of a profile
+    PostAuthorizationRulesRetrieve,
+    /// Delete the post authorization rules of a profile
+    PostAuthorizationRulesDelete,
+    /// Create or update the dunning configuration of a profile
+    DunningConfigUpsert,
+    /// Retrieve the dunning configuration of a profile
+    DunningConfigRetrieve,
+    /// Delete the dunning configuration of a profile
+    DunningConfigDelete,
+    /// Create or update the auto capture delay of a profile
+    AutoCaptureDelayConfigUpsert,
+    /// Retrieve the auto capture delay of a profile
+    AutoCaptureDelayConfigRetrieve,
+    /// Delete the auto capture delay of a profile
+    AutoCaptureDelayConfigDelete,
+    /// Create or update the step-up authentication rules of a profile
+    StepUpAuthenticationConfigUpsert,
+    /// Retrieve the step-up authentication rules of a profile
+    StepUpAuthenticationConfigRetrieve,
+    /// Delete the step-up authentication rules of a profile
+    StepUpAuthenticationConfigDelete,
+    /// Update the test mode of a profile
+    ProfileTestModeUpdate,
+    /// Retrieve the test mode of a profile
+    ProfileTestModeRetrieve,
+    /// Purge the test data of a profile in test mode
+    TestDataPurge,
+    /// Retrieve the payment methods, currencies and countries covered by a profile
+    ProfileCoverageRetrieve,
+    /// Update the lifecycle status of a merchant account
+    MerchantAccountStatusUpdate,
+    /// Offboard a merchant account
+    MerchantOffboard,
+    /// Retrieve the offboarding of a merchant account
+    MerchantOffboardingRetrieve,
+    /// Start a backfill of the connector transaction IDs of the payments of a merchant account
+    ConnectorTransactionIdBackfillCreate,
+    /// Retrieve the progress of a backfill of connector transaction IDs
+    ConnectorTransactionIdBackfillRetrieve,
+    /// Pin the personal data of a merchant account to a data residency region
+    DataResidencyCreate,
+    /// Retrieve the data residency of a merchant account
+    DataResidencyRetrieve,
+    /// Configure the sync of the records of a merchant to its data warehouse
+    WarehouseSyncConfigSet,
+    /// Retrieve the configuration of the data warehouse sync
+    WarehouseSyncConfigRetrieve,
+    /// Delete the configuration of the data warehouse sync
+    WarehouseSyncConfigDelete,
+    /// Retrieve the status of the data warehouse sync
+    WarehouseSyncStatus,
+    /// Trigger the data warehouse sync immediately
+    WarehouseSyncTrigger,
+    /// Request the SCA compliance report of a month
+    ScaComplianceReportCreate,
+    /// Retrieve an SCA compliance report
+    ScaComplianceReportRetrieve,
+    /// Download the classified payments of an SCA compliance report
+    ScaComplianceReportDownload,
 }
 
 /// Trait for providing generic behaviour to flow metric
@@ -1,4 +1,4 @@
-use common_utils::errors::CustomResult;
+use common_utils::{errors::CustomResult, id_type};
 pub use diesel_models as storage;
 use diesel_models::enums as storage_enums;
 use error_stack::{report, ResultExt};
@@ -26,6 +26,12 @@ pub trait ProcessTrackerInterface: Send + Sync + 'static {
         process: storage::ProcessTrackerUpdate,
     ) -> CustomResult<storage::ProcessTracker, errors::StorageError>;
 
+    async fn update_process_if_status_unchanged(
+        &self,
+        this: storage::ProcessTracker,
+        process: storage::ProcessTrackerUpdate,
+    ) -> CustomResult<storage::ProcessTracker, errors::StorageError>;
+
     async fn process_tracker_update_process_status_by_ids(
         &self,
         task_ids: Vec<String>,
@@ -62,6 +68,31 @@ pub trait ProcessTrackerInterface: Send + Sync + 'static {
         status: storage_enums::ProcessTrackerStatus,
         limit: Option<i64>,
     ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn find_merchant_processes_by_time_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        time_lower_limit: PrimitiveDateTime,
+        time_upper_limit: PrimitiveDateTime,
+        status: storage_enums::ProcessTrackerStatus,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn find_processes_by_runner_status(
+        &self,
+        runner: storage::ProcessTrackerRunner,
+        statuses: Vec<storage_enums::ProcessTrackerStatus>,
+        business_statuses: Option<Vec<String>>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn get_processes_count_by_runner_status(
+        &self,
+        runner: storage::ProcessTrackerRunner,
+        statuses: Vec<storage_enums::ProcessTrackerStatus>,
+        business_statuses: Option<Vec<String>>,
+    ) -> CustomResult<(i64, Option<PrimitiveDateTime>), errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -107,6 +138,66 @@ impl ProcessTrackerInterface for Store {
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    async fn find_merchant_processes_by_time_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        time_lower_limit: PrimitiveDateTime,
+        time_upper_limit: PrimitiveDateTime,
+        status: storage_enums::ProcessTrackerStatus,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ProcessTracker::find_merchant_processes_by_time_status(
+            &conn,
+            merchant_id,
+            time_lower_limit,
+            time_upper_limit,
+            status,
+            limit,
+            hyperswitch_domain_models::consts::API_VERSION,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    async fn find_processes_by_runner_status(
+        &self,
+        runner: storage::ProcessTrackerRunner,
+        statuses: Vec<storage_enums::ProcessTrackerStatus>,
+        business_statuses: Option<Vec<String>>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ProcessTracker::find_processes_by_runner_status(
+            &conn,
+            &runner.to_string(),
+            statuses,
+            business_statuses,
+            limit,
+            offset,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    async fn get_processes_count_by_runner_status(
+        &self,
+        runner: storage::ProcessTrackerRunner,
+        statuses: Vec<storage_enums::ProcessTrackerStatus>,
+        business_statuses: Option<Vec<String>>,
+    ) -> CustomResult<(i64, Option<PrimitiveDateTime>), errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ProcessTracker::get_processes_count_by_runner_status(
+            &conn,
+            &runner.to_string(),
+            statuses,
+            business_statuses,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
@@ -128,6 +219,17 @@ impl ProcessTrackerInterface for Store {
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    async fn update_process_if_status_unchanged(
+        &self,
+        this: storage::ProcessTracker,
+        process: storage::ProcessTrackerUpdate,
+    ) -> CustomResult<storage::ProcessTracker, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update_if_status_unchanged(&conn, process)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     async fn reset_process(
         &self,
         this: storage::ProcessTracker,
@@ -230,6 +332,40 @@ impl ProcessTrackerInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn find_merchant_processes_by_time_status(
+        &self,
+        _merchant_id: &id_type::MerchantId,
+        _time_lower_limit: PrimitiveDateTime,
+        _time_upper_limit: PrimitiveDateTime,
+        _status: storage_enums::ProcessTrackerStatus,
+        _limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_processes_by_runner_status(
+        &self,
+        _runner: storage::ProcessTrackerRunner,
+        _statuses: Vec<storage_enums::ProcessTrackerStatus>,
+        _business_statuses: Option<Vec<String>>,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn get_processes_count_by_runner_status(
+        &self,
+        _runner: storage::ProcessTrackerRunner,
+        _statuses: Vec<storage_enums::ProcessTrackerStatus>,
+        _business_statuses: Option<Vec<String>>,
+    ) -> CustomResult<(i64, Option<PrimitiveDateTime>), errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
@@ -263,6 +399,14 @@ impl ProcessTrackerInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+    async fn update_process_if_status_unchanged(
+        &self,
+        _this: storage::ProcessTracker,
+        _process: storage::ProcessTrackerUpdate,
+    ) -> CustomResult<storage::ProcessTracker, errors::StorageError> {
+        // [#172]: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
 
     async fn reset_process(
         &self,
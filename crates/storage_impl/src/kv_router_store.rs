@@ -38,6 +38,7 @@ pub struct KVRouterStore<T: DatabaseStore> {
     pub router_store: RouterStore<T>,
     drainer_stream_name: String,
     drainer_num_partitions: u8,
+    drainer_priority_lanes_enabled: bool,
     pub ttl_for_kv: u32,
     pub request_id: Option<String>,
     pub soft_kill_mode: bool,
@@ -105,18 +106,26 @@ where
     RouterStore<T>: DatabaseStore,
     T: DatabaseStore,
 {
-    type Config = (RouterStore<T>, String, u8, u32, Option<bool>);
+    type Config = (RouterStore<T>, String, u8, bool, u32, Option<bool>);
     async fn new(
         config: Self::Config,
         tenant_config: &dyn TenantConfig,
         _test_transaction: bool,
     ) -> StorageResult<Self> {
-        let (router_store, _, drainer_num_partitions, ttl_for_kv, soft_kill_mode) = config;
+        let (
+            router_store,
+            _,
+            drainer_num_partitions,
+            drainer_priority_lanes_enabled,
+            ttl_for_kv,
+            soft_kill_mode,
+        ) = config;
         let drainer_stream_name = format!("{}_{}", tenant_config.get_schema(), config.1);
         Ok(Self::from_store(
             router_store,
             drainer_stream_name,
             drainer_num_partitions,
+            drainer_priority_lanes_enabled,
             ttl_for_kv,
             soft_kill_mode,
         ))
@@ -148,6 +157,7 @@ impl<T: DatabaseStore> KVRouterStore<T> {
         store: RouterStore<T>,
         drainer_stream_name: String,
         drainer_num_partitions: u8,
+        drainer_priority_lanes_enabled: bool,
         ttl_for_kv: u32,
         soft_kill: Option<bool>,
     ) -> Self {
@@ -157,6 +167,7 @@ impl<T: DatabaseStore> KVRouterStore<T> {
             router_store: store,
             drainer_stream_name,
             drainer_num_partitions,
+            drainer_priority_lanes_enabled,
             ttl_for_kv,
             request_id,
             soft_kill_mode: soft_kill.unwrap_or(false),
@@ -184,6 +195,15 @@ impl<T: DatabaseStore> KVRouterStore<T> {
 
         let shard_key = R::shard_key(partition_key, self.drainer_num_partitions);
         let stream_name = self.get_drainer_stream_name(&shard_key);
+        // Entries are pushed to the lanes only when enabled, so that the entries are drained as
+        // before by the drainers which do not read the low priority lanes
+        let stream_name = if self.drainer_priority_lanes_enabled {
+            redis_entry
+                .lane_priority()
+                .get_lane_stream_name(&stream_name)
+        } else {
+            stream_name
+        };
         self.router_store
             .cache_store
             .redis_conn
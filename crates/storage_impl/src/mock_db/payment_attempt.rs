@@ -232,6 +232,12 @@ impl PaymentAttemptInterface for MockDb {
             charges: None,
             issuer_error_code: None,
             issuer_error_message: None,
+            overcaptured_amount: None,
+            connector_idempotency_key: payment_attempt.connector_idempotency_key,
+            avs_result: None,
+            cvv_result: None,
+            acquirer_reference_number: None,
+            card_credential_type: None,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
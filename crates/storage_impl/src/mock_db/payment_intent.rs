@@ -197,6 +197,25 @@ impl PaymentIntentInterface for MockDb {
             .unwrap())
     }
 
+    #[cfg(feature = "v1")]
+    async fn find_payment_intent_by_payment_id_merchant_id_from_master(
+        &self,
+        state: &KeyManagerState,
+        payment_id: &common_utils::id_type::PaymentId,
+        merchant_id: &common_utils::id_type::MerchantId,
+        key_store: &MerchantKeyStore,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<PaymentIntent, StorageError> {
+        self.find_payment_intent_by_payment_id_merchant_id(
+            state,
+            payment_id,
+            merchant_id,
+            key_store,
+            storage_scheme,
+        )
+        .await
+    }
+
     #[cfg(feature = "v2")]
     async fn find_payment_intent_by_id(
         &self,
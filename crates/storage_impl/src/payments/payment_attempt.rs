@@ -650,6 +650,12 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                     charges: None,
                     issuer_error_code: None,
                     issuer_error_message: None,
+                    overcaptured_amount: None,
+                    connector_idempotency_key: payment_attempt.connector_idempotency_key.clone(),
+                    avs_result: None,
+                    cvv_result: None,
+                    acquirer_reference_number: None,
+                    card_credential_type: None,
                 };
 
                 let field = format!("pa_{}", created_attempt.attempt_id);
@@ -1653,6 +1659,12 @@ impl DataModelExt for PaymentAttempt {
             charges: self.charges,
             issuer_error_code: self.issuer_error_code,
             issuer_error_message: self.issuer_error_message,
+            overcaptured_amount: self.overcaptured_amount,
+            connector_idempotency_key: self.connector_idempotency_key,
+            avs_result: self.avs_result,
+            cvv_result: self.cvv_result,
+            acquirer_reference_number: self.acquirer_reference_number,
+            card_credential_type: self.card_credential_type,
             // Below fields are deprecated. Please add any new fields above this line.
             connector_transaction_data: None,
         }
@@ -1738,6 +1750,12 @@ impl DataModelExt for PaymentAttempt {
             charges: storage_model.charges,
             issuer_error_code: storage_model.issuer_error_code,
             issuer_error_message: storage_model.issuer_error_message,
+            overcaptured_amount: storage_model.overcaptured_amount,
+            connector_idempotency_key: storage_model.connector_idempotency_key,
+            avs_result: storage_model.avs_result,
+            cvv_result: storage_model.cvv_result,
+            acquirer_reference_number: storage_model.acquirer_reference_number,
+            card_credential_type: storage_model.card_credential_type,
         }
     }
 }
@@ -1824,6 +1842,7 @@ impl DataModelExt for PaymentAttemptNew {
             extended_authorization_applied: self.extended_authorization_applied,
             capture_before: self.capture_before,
             card_discovery: self.card_discovery,
+            connector_idempotency_key: self.connector_idempotency_key,
         }
     }
 
@@ -1899,6 +1918,7 @@ impl DataModelExt for PaymentAttemptNew {
             extended_authorization_applied: storage_model.extended_authorization_applied,
             capture_before: storage_model.capture_before,
             card_discovery: storage_model.card_discovery,
+            connector_idempotency_key: storage_model.connector_idempotency_key,
         }
     }
 }
@@ -15,6 +15,7 @@ use diesel_models::query::generics::db_metrics;
 #[cfg(all(feature = "v1", feature = "olap"))]
 use diesel_models::schema::{
     payment_attempt::{self as payment_attempt_schema, dsl as pa_dsl},
+    payment_custom_field::{self as payment_custom_field_schema, dsl as pcf_dsl},
     payment_intent::dsl as pi_dsl,
 };
 #[cfg(all(feature = "v2", feature = "olap"))]
@@ -310,45 +311,43 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
         merchant_key_store: &MerchantKeyStore,
         storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<PaymentIntent, StorageError> {
-        let database_call = || async {
-            let conn = pg_connection_read(self).await?;
-            DieselPaymentIntent::find_by_payment_id_merchant_id(&conn, payment_id, merchant_id)
-                .await
-                .map_err(|er| {
-                    let new_err = diesel_error_to_data_error(*er.current_context());
-                    er.change_context(new_err)
-                })
-        };
-        let storage_scheme = Box::pin(decide_storage_scheme::<_, DieselPaymentIntent>(
-            self,
-            storage_scheme,
-            Op::Find,
-        ))
-        .await;
-        let diesel_payment_intent = match storage_scheme {
-            MerchantStorageScheme::PostgresOnly => database_call().await,
+        let diesel_payment_intent = self
+            .find_diesel_payment_intent_by_payment_id_merchant_id(
+                payment_id,
+                merchant_id,
+                storage_scheme,
+                false,
+            )
+            .await?;
 
-            MerchantStorageScheme::RedisKv => {
-                let key = PartitionKey::MerchantIdPaymentId {
-                    merchant_id,
-                    payment_id,
-                };
-                let field = payment_id.get_hash_key_for_kv_store();
-                Box::pin(utils::try_redis_get_else_try_database_get(
-                    async {
-                        Box::pin(kv_wrapper::<DieselPaymentIntent, _, _>(
-                            self,
-                            KvOperation::<DieselPaymentIntent>::HGet(&field),
-                            key,
-                        ))
-                        .await?
-                        .try_into_hget()
-                    },
-                    database_call,
-                ))
-                .await
-            }
-        }?;
+        PaymentIntent::convert_back(
+            state,
+            diesel_payment_intent,
+            merchant_key_store.key.get_inner(),
+            merchant_id.to_owned().into(),
+        )
+        .await
+        .change_context(StorageError::DecryptionError)
+    }
+
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_payment_intent_by_payment_id_merchant_id_from_master(
+        &self,
+        state: &KeyManagerState,
+        payment_id: &common_utils::id_type::PaymentId,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_key_store: &MerchantKeyStore,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<PaymentIntent, StorageError> {
+        let diesel_payment_intent = self
+            .find_diesel_payment_intent_by_payment_id_merchant_id(
+                payment_id,
+                merchant_id,
+                storage_scheme,
+                true,
+            )
+            .await?;
 
         PaymentIntent::convert_back(
             state,
@@ -524,6 +523,64 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
     }
 }
 
+impl<T: DatabaseStore> KVRouterStore<T> {
+    /// Finds the payment intent in the KV store, falling back to the database. The database is
+    /// read from the master when `read_from_master` is set, so that a write is never missed
+    /// because the replica lags behind.
+    #[cfg(feature = "v1")]
+    async fn find_diesel_payment_intent_by_payment_id_merchant_id(
+        &self,
+        payment_id: &common_utils::id_type::PaymentId,
+        merchant_id: &common_utils::id_type::MerchantId,
+        storage_scheme: MerchantStorageScheme,
+        read_from_master: bool,
+    ) -> error_stack::Result<DieselPaymentIntent, StorageError> {
+        let database_call = || async {
+            let conn = if read_from_master {
+                pg_connection_write(self).await?
+            } else {
+                pg_connection_read(self).await?
+            };
+            DieselPaymentIntent::find_by_payment_id_merchant_id(&conn, payment_id, merchant_id)
+                .await
+                .map_err(|er| {
+                    let new_err = diesel_error_to_data_error(*er.current_context());
+                    er.change_context(new_err)
+                })
+        };
+        let storage_scheme = Box::pin(decide_storage_scheme::<_, DieselPaymentIntent>(
+            self,
+            storage_scheme,
+            Op::Find,
+        ))
+        .await;
+        match storage_scheme {
+            MerchantStorageScheme::PostgresOnly => database_call().await,
+
+            MerchantStorageScheme::RedisKv => {
+                let key = PartitionKey::MerchantIdPaymentId {
+                    merchant_id,
+                    payment_id,
+                };
+                let field = payment_id.get_hash_key_for_kv_store();
+                Box::pin(utils::try_redis_get_else_try_database_get(
+                    async {
+                        Box::pin(kv_wrapper::<DieselPaymentIntent, _, _>(
+                            self,
+                            KvOperation::<DieselPaymentIntent>::HGet(&field),
+                            key,
+                        ))
+                        .await?
+                        .try_into_hget()
+                    },
+                    database_call,
+                ))
+                .await
+            }
+        }
+    }
+}
+
 #[async_trait::async_trait]
 impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
     type Error = StorageError;
@@ -657,6 +714,37 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
             .await
     }
 
+    #[cfg(feature = "v1")]
+    #[instrument(skip_all)]
+    async fn find_payment_intent_by_payment_id_merchant_id_from_master(
+        &self,
+        state: &KeyManagerState,
+        payment_id: &common_utils::id_type::PaymentId,
+        merchant_id: &common_utils::id_type::MerchantId,
+        merchant_key_store: &MerchantKeyStore,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<PaymentIntent, StorageError> {
+        let conn = pg_connection_write(self).await?;
+
+        DieselPaymentIntent::find_by_payment_id_merchant_id(&conn, payment_id, merchant_id)
+            .await
+            .map_err(|er| {
+                let new_err = diesel_error_to_data_error(*er.current_context());
+                er.change_context(new_err)
+            })
+            .async_and_then(|diesel_payment_intent| async {
+                PaymentIntent::convert_back(
+                    state,
+                    diesel_payment_intent,
+                    merchant_key_store.key.get_inner(),
+                    merchant_key_store.merchant_id.clone().into(),
+                )
+                .await
+                .change_context(StorageError::DecryptionError)
+            })
+            .await
+    }
+
     #[cfg(feature = "v2")]
     #[instrument(skip_all)]
     async fn find_payment_intent_by_id(
@@ -753,10 +841,30 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                 if let Some(customer_id) = &params.customer_id {
                     query = query.filter(pi_dsl::customer_id.eq(customer_id.clone()));
                 }
+                if let Some(customer_email_hash) = &params.customer_email_hash {
+                    query =
+                        query.filter(pi_dsl::customer_email_hash.eq(customer_email_hash.clone()));
+                }
+                if let Some(customer_phone_hash) = &params.customer_phone_hash {
+                    query =
+                        query.filter(pi_dsl::customer_phone_hash.eq(customer_phone_hash.clone()));
+                }
                 if let Some(profile_id) = &params.profile_id {
                     query = query.filter(pi_dsl::profile_id.eq_any(profile_id.clone()));
                 }
 
+                for (field_key, field_value) in params.custom_fields.iter().flatten() {
+                    query = query.filter(
+                        pi_dsl::payment_id.eq_any(
+                            payment_custom_field_schema::table
+                                .select(pcf_dsl::payment_id)
+                                .filter(pcf_dsl::merchant_id.eq(merchant_id.to_owned()))
+                                .filter(pcf_dsl::field_key.eq(field_key.clone()))
+                                .filter(pcf_dsl::field_value.eq(field_value.clone())),
+                        ),
+                    );
+                }
+
                 query = match (params.starting_at, &params.starting_after_id) {
                     (Some(starting_at), _) => query.filter(pi_dsl::created_at.ge(starting_at)),
                     (None, Some(starting_after_id)) => {
@@ -976,6 +1084,18 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                     query = query.filter(pi_dsl::profile_id.eq_any(profile_id.clone()));
                 }
 
+                for (field_key, field_value) in params.custom_fields.iter().flatten() {
+                    query = query.filter(
+                        pi_dsl::payment_id.eq_any(
+                            payment_custom_field_schema::table
+                                .select(pcf_dsl::payment_id)
+                                .filter(pcf_dsl::merchant_id.eq(merchant_id.to_owned()))
+                                .filter(pcf_dsl::field_key.eq(field_key.clone()))
+                                .filter(pcf_dsl::field_value.eq(field_value.clone())),
+                        ),
+                    );
+                }
+
                 query = match (params.starting_at, &params.starting_after_id) {
                     (Some(starting_at), _) => query.filter(pi_dsl::created_at.ge(starting_at)),
                     (None, Some(starting_after_id)) => {
@@ -1453,6 +1573,18 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
                     query = query.filter(pi_dsl::profile_id.eq_any(profile_id.clone()));
                 }
 
+                for (field_key, field_value) in params.custom_fields.iter().flatten() {
+                    query = query.filter(
+                        pi_dsl::payment_id.eq_any(
+                            payment_custom_field_schema::table
+                                .select(pcf_dsl::payment_id)
+                                .filter(pcf_dsl::merchant_id.eq(merchant_id.to_owned()))
+                                .filter(pcf_dsl::field_key.eq(field_key.clone()))
+                                .filter(pcf_dsl::field_value.eq(field_value.clone())),
+                        ),
+                    );
+                }
+
                 query = match params.starting_at {
                     Some(starting_at) => query.filter(pi_dsl::created_at.ge(starting_at)),
                     None => query,
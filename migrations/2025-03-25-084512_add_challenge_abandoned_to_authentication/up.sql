@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TABLE authentication ADD COLUMN IF NOT EXISTS challenge_abandoned BOOLEAN DEFAULT NULL;
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_dunning_retry_scheduled';
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_uncollectible';
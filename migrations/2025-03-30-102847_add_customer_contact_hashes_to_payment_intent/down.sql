@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_intent_merchant_id_customer_email_hash_index;
+
+DROP INDEX IF EXISTS payment_intent_merchant_id_customer_phone_hash_index;
+
+ALTER TABLE payment_intent
+DROP COLUMN IF EXISTS customer_email_hash,
+DROP COLUMN IF EXISTS customer_phone_hash;
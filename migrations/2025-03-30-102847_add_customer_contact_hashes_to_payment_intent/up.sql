@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TABLE payment_intent
+ADD COLUMN IF NOT EXISTS customer_email_hash VARCHAR(64) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS customer_phone_hash VARCHAR(64) DEFAULT NULL;
+
+CREATE INDEX IF NOT EXISTS payment_intent_merchant_id_customer_email_hash_index ON payment_intent (merchant_id, customer_email_hash);
+
+CREATE INDEX IF NOT EXISTS payment_intent_merchant_id_customer_phone_hash_index ON payment_intent (merchant_id, customer_phone_hash);
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE api_keys ADD COLUMN IF NOT EXISTS test_mode BOOLEAN DEFAULT NULL;
+
+ALTER TABLE api_keys ADD COLUMN IF NOT EXISTS profile_id VARCHAR(64) DEFAULT NULL;
@@ -0,0 +1,9 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS issuer_authentication_stats (
+    card_bin VARCHAR(8) PRIMARY KEY,
+    card_issuer VARCHAR(255),
+    frictionless_count BIGINT NOT NULL DEFAULT 0,
+    challenge_count BIGINT NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    last_modified_at TIMESTAMP NOT NULL DEFAULT now()
+);
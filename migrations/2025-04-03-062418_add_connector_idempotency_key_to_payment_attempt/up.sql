@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS connector_idempotency_key VARCHAR(64) DEFAULT NULL;
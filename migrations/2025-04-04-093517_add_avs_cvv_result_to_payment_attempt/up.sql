@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS avs_result VARCHAR(32) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS cvv_result VARCHAR(32) DEFAULT NULL;
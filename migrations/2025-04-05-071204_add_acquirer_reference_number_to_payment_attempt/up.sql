@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS acquirer_reference_number VARCHAR(64) DEFAULT NULL;
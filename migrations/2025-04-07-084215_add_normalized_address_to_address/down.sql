@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE address
+DROP COLUMN IF EXISTS normalized_address;
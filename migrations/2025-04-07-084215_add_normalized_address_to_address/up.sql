@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE address
+ADD COLUMN IF NOT EXISTS normalized_address BYTEA DEFAULT NULL;
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'merchant_account';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'merchant_account_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'merchant_account_status_updated';
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_intent_merchant_id_modified_at_index;
+
+DROP INDEX IF EXISTS refund_merchant_id_modified_at_index;
+
+DROP INDEX IF EXISTS dispute_merchant_id_modified_at_index;
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+CREATE INDEX IF NOT EXISTS payment_intent_merchant_id_modified_at_index ON payment_intent (merchant_id, modified_at, payment_id);
+
+CREATE INDEX IF NOT EXISTS refund_merchant_id_modified_at_index ON refund (merchant_id, modified_at, refund_id);
+
+CREATE INDEX IF NOT EXISTS dispute_merchant_id_modified_at_index ON dispute (merchant_id, modified_at, dispute_id);
@@ -0,0 +1,11 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_intent_merchant_id_change_sequence_index;
+
+DROP TRIGGER IF EXISTS set_payment_intent_change_sequence ON payment_intent;
+
+DROP FUNCTION IF EXISTS set_payment_intent_change_sequence();
+
+ALTER TABLE payment_intent
+DROP COLUMN IF EXISTS change_sequence;
+
+DROP SEQUENCE IF EXISTS payment_intent_change_sequence_seq;
@@ -0,0 +1,24 @@
+-- Your SQL goes here
+CREATE SEQUENCE IF NOT EXISTS payment_intent_change_sequence_seq;
+
+ALTER TABLE payment_intent
+ADD COLUMN IF NOT EXISTS change_sequence BIGINT DEFAULT NULL;
+
+-- Assigns the next change sequence to a payment intent when it is created and whenever its
+-- status changes, so that the payments whose status changed can be polled in order
+CREATE OR REPLACE FUNCTION set_payment_intent_change_sequence() RETURNS trigger AS $$
+BEGIN
+    IF (
+        TG_OP = 'INSERT' OR
+        NEW.status IS DISTINCT FROM OLD.status
+    ) THEN
+        NEW.change_sequence := nextval('payment_intent_change_sequence_seq');
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER set_payment_intent_change_sequence BEFORE INSERT OR UPDATE ON payment_intent
+FOR EACH ROW EXECUTE PROCEDURE set_payment_intent_change_sequence();
+
+CREATE INDEX IF NOT EXISTS payment_intent_merchant_id_change_sequence_index ON payment_intent (merchant_id, change_sequence);
@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'merchant_connector_account';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'connector_credentials_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'connector_credentials_expiring';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'connector_credentials_expired';
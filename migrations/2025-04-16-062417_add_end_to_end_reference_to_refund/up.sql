@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE refund
+ADD COLUMN IF NOT EXISTS end_to_end_reference VARCHAR(140) DEFAULT NULL;
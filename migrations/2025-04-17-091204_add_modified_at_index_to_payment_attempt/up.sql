@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE INDEX IF NOT EXISTS payment_attempt_modified_at_index ON payment_attempt (modified_at, merchant_id, attempt_id);
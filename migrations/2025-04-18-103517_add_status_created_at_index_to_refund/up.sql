@@ -0,0 +1,2 @@
+-- Your SQL goes here
+CREATE INDEX IF NOT EXISTS refund_refund_status_created_at_index ON refund (refund_status, created_at, merchant_id, refund_id);
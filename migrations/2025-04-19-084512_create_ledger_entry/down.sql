@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS ledger_entry_merchant_id_dispute_id_dispute_stage_entry_type_index;
+
+DROP TABLE IF EXISTS ledger_entry;
@@ -0,0 +1,18 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS ledger_entry (
+    entry_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64),
+    entry_type VARCHAR(64) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    dispute_id VARCHAR(64) NOT NULL,
+    dispute_stage "DisputeStage" NOT NULL,
+    connector VARCHAR(255) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+-- An entry of each type is recorded only once for each stage of a dispute
+CREATE UNIQUE INDEX IF NOT EXISTS ledger_entry_merchant_id_dispute_id_dispute_stage_entry_type_index
+    ON ledger_entry (merchant_id, dispute_id, dispute_stage, entry_type);
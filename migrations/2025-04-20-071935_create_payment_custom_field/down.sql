@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_custom_field_merchant_id_field_key_field_value_index;
+
+DROP TABLE IF EXISTS payment_custom_field;
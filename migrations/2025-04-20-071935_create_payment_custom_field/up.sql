@@ -0,0 +1,15 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payment_custom_field (
+    merchant_id VARCHAR(64) NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    field_key VARCHAR(64) NOT NULL,
+    field_type VARCHAR(64) NOT NULL,
+    field_value VARCHAR(255) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now(),
+    PRIMARY KEY (merchant_id, payment_id, field_key)
+);
+
+-- The payments are filtered by the values of their custom fields in the payments list
+CREATE INDEX IF NOT EXISTS payment_custom_field_merchant_id_field_key_field_value_index
+    ON payment_custom_field (merchant_id, field_key, field_value);
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS store_credit_entry_merchant_id_customer_id_created_at_index;
+
+DROP INDEX IF EXISTS store_credit_entry_payment_id_entry_type_refund_id_debit_sequence_index;
+
+DROP TABLE IF EXISTS store_credit_entry;
@@ -0,0 +1,21 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS store_credit_entry (
+    entry_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    entry_type VARCHAR(64) NOT NULL,
+    amount BIGINT NOT NULL,
+    currency "Currency" NOT NULL,
+    payment_id VARCHAR(64) NOT NULL,
+    refund_id VARCHAR(64),
+    debit_sequence INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+-- A refund is credited once, and each debit of the store credit of a payment is recorded and
+-- reversed once
+CREATE UNIQUE INDEX IF NOT EXISTS store_credit_entry_payment_id_entry_type_refund_id_debit_sequence_index
+    ON store_credit_entry (merchant_id, payment_id, entry_type, COALESCE(refund_id, ''), debit_sequence);
+
+CREATE INDEX IF NOT EXISTS store_credit_entry_merchant_id_customer_id_created_at_index
+    ON store_credit_entry (merchant_id, customer_id, created_at DESC);
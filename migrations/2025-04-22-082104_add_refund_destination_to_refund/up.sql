@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE refund
+ADD COLUMN IF NOT EXISTS refund_destination VARCHAR(32) DEFAULT NULL;
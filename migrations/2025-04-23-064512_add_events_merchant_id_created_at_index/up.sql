@@ -0,0 +1,6 @@
+-- Your SQL goes here
+CREATE INDEX IF NOT EXISTS events_merchant_id_created_at_event_id_index ON events (
+    merchant_id,
+    created_at DESC,
+    event_id DESC
+);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_method_consent_event_merchant_id_payment_method_id_index;
+
+DROP TABLE IF EXISTS payment_method_consent_event;
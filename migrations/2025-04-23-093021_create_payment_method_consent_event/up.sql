@@ -0,0 +1,11 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payment_method_consent_event (
+    event_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    payment_method_id VARCHAR(64) NOT NULL,
+    event_data JSONB NOT NULL,
+    recorded_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+CREATE INDEX IF NOT EXISTS payment_method_consent_event_merchant_id_payment_method_id_index
+    ON payment_method_consent_event (merchant_id, payment_method_id, recorded_at);
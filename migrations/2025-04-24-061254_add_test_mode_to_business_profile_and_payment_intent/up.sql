@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE business_profile ADD COLUMN IF NOT EXISTS is_test_mode BOOLEAN DEFAULT NULL;
+
+ALTER TABLE payment_intent ADD COLUMN IF NOT EXISTS is_test_payment BOOLEAN DEFAULT NULL;